@@ -0,0 +1,48 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+//! Writes the worst-case DIP proof corpus to SCALE-encoded fixture files.
+//!
+//! Each fixture contains the encoded `(root, proof)` tuple of one of the proof
+//! shapes in [`kilt_dip_primitives::merkle::corpus::CORPUS`].
+//!
+//! Usage: `cargo run -p kilt-dip-primitives --bin dip-proof-corpus --
+//! [output directory]`
+
+use std::{env, error::Error, fs, path::PathBuf};
+
+use kilt_dip_primitives::merkle::corpus::{generate_proof, CORPUS};
+use parity_scale_codec::Encode;
+
+fn main() -> Result<(), Box<dyn Error>> {
+	let output_dir = env::args()
+		.nth(1)
+		.map(PathBuf::from)
+		.unwrap_or_else(|| PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("fixtures"));
+	fs::create_dir_all(&output_dir)?;
+
+	for entry in CORPUS.iter() {
+		let (root, proof) = generate_proof(&entry.shape)?;
+		let encoded_fixture = (root, proof).encode();
+		let path = output_dir.join(format!("{}.scale", entry.name));
+		fs::write(&path, &encoded_fixture)?;
+		println!("Wrote {} ({} bytes).", path.display(), encoded_fixture.len());
+	}
+
+	Ok(())
+}
@@ -0,0 +1,156 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use frame_support::{
+	traits::{Get, IsSubType},
+	weights::{Weight, WeightToFee},
+};
+use pallet_transaction_payment::OnChargeTransaction;
+use parity_scale_codec::Encode;
+use sp_runtime::{
+	traits::{AtLeast32BitUnsigned, DispatchInfoOf, PostDispatchInfoOf},
+	transaction_validity::TransactionValidityError,
+};
+use sp_std::marker::PhantomData;
+
+/// Charges transaction fees via `OnCharge`, but charges the length fee of at
+/// most `MaxChargedProofBytes` bytes of the identity proof included in a
+/// `pallet_dip_consumer::dispatch_as` call. The length fee of the remaining
+/// proof bytes is computed with the `LengthToFee` of the runtime's
+/// transaction payment configuration.
+///
+/// The cost of verifying the proof is already covered by the weight of the
+/// call, so charging every proof byte on top of it makes DIP calls
+/// considerably more expensive than their local equivalents.
+pub struct CappedProofLengthFee<OnCharge, MaxChargedProofBytes>(PhantomData<(OnCharge, MaxChargedProofBytes)>);
+
+impl<R, OnCharge, MaxChargedProofBytes> OnChargeTransaction<R> for CappedProofLengthFee<OnCharge, MaxChargedProofBytes>
+where
+	R: pallet_transaction_payment::Config + pallet_dip_consumer::Config,
+	<R as frame_system::Config>::RuntimeCall: IsSubType<pallet_dip_consumer::Call<R>>,
+	<R as pallet_transaction_payment::Config>::LengthToFee: WeightToFee<Balance = OnCharge::Balance>,
+	OnCharge: OnChargeTransaction<R>,
+	MaxChargedProofBytes: Get<u32>,
+{
+	type Balance = OnCharge::Balance;
+	type LiquidityInfo = (OnCharge::LiquidityInfo, u32);
+
+	fn withdraw_fee(
+		who: &R::AccountId,
+		call: &<R as frame_system::Config>::RuntimeCall,
+		dispatch_info: &DispatchInfoOf<<R as frame_system::Config>::RuntimeCall>,
+		fee: Self::Balance,
+		tip: Self::Balance,
+	) -> Result<Self::LiquidityInfo, TransactionValidityError> {
+		let exempted_bytes = exempted_proof_bytes::<R>(call, MaxChargedProofBytes::get());
+		let fee = capped_proof_length_fee::<_, <R as pallet_transaction_payment::Config>::LengthToFee>(
+			fee,
+			tip,
+			exempted_bytes,
+		);
+
+		OnCharge::withdraw_fee(who, call, dispatch_info, fee, tip)
+			.map(|liquidity_info| (liquidity_info, exempted_bytes))
+	}
+
+	fn correct_and_deposit_fee(
+		who: &R::AccountId,
+		dispatch_info: &DispatchInfoOf<<R as frame_system::Config>::RuntimeCall>,
+		post_info: &PostDispatchInfoOf<<R as frame_system::Config>::RuntimeCall>,
+		corrected_fee: Self::Balance,
+		tip: Self::Balance,
+		(already_withdrawn, exempted_bytes): Self::LiquidityInfo,
+	) -> Result<(), TransactionValidityError> {
+		let corrected_fee = capped_proof_length_fee::<_, <R as pallet_transaction_payment::Config>::LengthToFee>(
+			corrected_fee,
+			tip,
+			exempted_bytes,
+		);
+
+		OnCharge::correct_and_deposit_fee(who, dispatch_info, post_info, corrected_fee, tip, already_withdrawn)
+	}
+}
+
+/// Returns the number of proof bytes of a `pallet_dip_consumer::dispatch_as`
+/// call that exceed `max_charged_proof_bytes`, or `0` for any other call.
+pub fn exempted_proof_bytes<R>(call: &<R as frame_system::Config>::RuntimeCall, max_charged_proof_bytes: u32) -> u32
+where
+	R: pallet_dip_consumer::Config,
+	<R as frame_system::Config>::RuntimeCall: IsSubType<pallet_dip_consumer::Call<R>>,
+{
+	let Some(pallet_dip_consumer::Call::dispatch_as { proof, .. }) = call.is_sub_type() else {
+		return 0;
+	};
+	let proof_length = u32::try_from(proof.encoded_size()).unwrap_or(u32::MAX);
+	proof_length.saturating_sub(max_charged_proof_bytes)
+}
+
+/// Removes the length fee of the exempted proof bytes from the fee, without
+/// ever going below the tip.
+pub fn capped_proof_length_fee<Balance, LengthToFee>(fee: Balance, tip: Balance, exempted_bytes: u32) -> Balance
+where
+	Balance: AtLeast32BitUnsigned + Copy,
+	LengthToFee: WeightToFee<Balance = Balance>,
+{
+	let exempted_fee = LengthToFee::weight_to_fee(&Weight::from_parts(exempted_bytes.into(), 0));
+	fee.saturating_sub(exempted_fee).max(tip)
+}
+
+#[cfg(test)]
+mod tests {
+	use frame_support::weights::{ConstantMultiplier, IdentityFee};
+	use sp_core::ConstU128;
+
+	use super::*;
+
+	const BASE_FEE: u128 = 1_000;
+	const WEIGHT_FEE: u128 = 50_000;
+
+	fn fee<LengthToFee: WeightToFee<Balance = u128>>(length: u32, tip: u128) -> u128 {
+		BASE_FEE + LengthToFee::weight_to_fee(&Weight::from_parts(length.into(), 0)) + WEIGHT_FEE + tip
+	}
+
+	#[test]
+	fn only_exempted_proof_bytes_are_not_charged() {
+		type LengthToFee = ConstantMultiplier<u128, ConstU128<10>>;
+
+		// A 2_000-byte extrinsic carrying a 1_800-byte proof, of which 500 bytes are
+		// charged.
+		let full_fee = fee::<LengthToFee>(2_000, 0);
+		let capped_fee = capped_proof_length_fee::<_, LengthToFee>(full_fee, 0, 1_300);
+
+		assert_eq!(full_fee, BASE_FEE + 20_000 + WEIGHT_FEE);
+		assert_eq!(capped_fee, fee::<LengthToFee>(700, 0));
+		assert_eq!(capped_fee, BASE_FEE + 7_000 + WEIGHT_FEE);
+	}
+
+	#[test]
+	fn fee_unchanged_without_exempted_bytes() {
+		let full_fee = fee::<IdentityFee<u128>>(2_000, 5);
+
+		assert_eq!(
+			capped_proof_length_fee::<_, IdentityFee<u128>>(full_fee, 5, 0),
+			full_fee
+		);
+	}
+
+	#[test]
+	fn capped_fee_never_below_tip() {
+		assert_eq!(capped_proof_length_fee::<_, IdentityFee<u128>>(100, 80, 1_000), 80);
+	}
+}
@@ -0,0 +1,663 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+//! Primitives to re-attest ("forward") the outcome of a DIP proof verification
+//! to other chains.
+//!
+//! A consumer chain that has verified a full DIP proof for a subject can issue
+//! a [`ForwardedDipAttestation`] about the revealed DID details, signed by a key
+//! under its control (e.g., a collator key or a key representing its sovereign
+//! account). Other chains that trust the issuer can then verify the much
+//! smaller [`ForwardedDipProof`] with the [`KiltForwardedAttestationVerifier`],
+//! without having to verify any state proof.
+
+use did::KeyIdOf;
+use frame_support::{ensure, traits::Contains};
+use frame_system::pallet_prelude::BlockNumberFor;
+use pallet_did_lookup::linkable_account::LinkableAccountId;
+use pallet_dip_consumer::{traits::IdentityProofVerifier, RuntimeCallOf};
+use pallet_web3_names::Web3NameOf;
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_core::ConstU32;
+use sp_runtime::{
+	traits::{BlockNumberProvider, IdentifyAccount, Verify, Zero},
+	BoundedVec,
+};
+use sp_std::{fmt::Debug, marker::PhantomData, vec::Vec};
+
+use crate::{
+	merkle::v0::{
+		DipRevealedDetailsAndUnverifiedDidSignature, RevealedDidKey, RevealedDidMerkleProofLeaf, TimeBoundDidSignature,
+	},
+	traits::{BenchmarkDefault, DipCallOriginFilter, GetWithoutArg, Incrementable},
+	DipOriginInfo, Error,
+};
+
+/// The domain separator prepended to the payload signed by the issuer of a
+/// forwarded attestation.
+pub const FORWARDED_ATTESTATION_SIGNING_DOMAIN: &[u8] = b"kilt-dip-forwarded-attestation";
+
+/// The account ID type identifying the signer of an `IssuerSignature`.
+pub type IssuerIdOf<IssuerSignature> = <<IssuerSignature as Verify>::Signer as IdentifyAccount>::AccountId;
+
+/// A statement, issued by a chain that has verified a full DIP proof, about
+/// the DID details of a subject.
+///
+/// The generic types indicate the following:
+/// * `Identifier`: The subject identifier type.
+/// * `KiltDidKeyId`: The DID key ID type configured by the KILT chain.
+/// * `KiltAccountId`: The `AccountId` type configured by the KILT chain.
+/// * `KiltBlockNumber`: The `BlockNumber` type configured by the KILT chain.
+/// * `KiltWeb3Name`: The web3name type configured by the KILT chain.
+/// * `KiltLinkableAccountId`: The linkable account ID type configured by the
+///   KILT chain.
+/// * `RelayBlockNumber`: The `BlockNumber` definition of the relaychain shared
+///   by the issuing and the verifying chains.
+/// * `MAX_REVEALED_LEAVES_COUNT`: The maximum number of leaves revealable in
+///   the attestation.
+#[derive(Clone, Debug, Encode, Decode, PartialEq, Eq, TypeInfo)]
+pub struct ForwardedDipAttestation<
+	Identifier,
+	KiltDidKeyId,
+	KiltAccountId,
+	KiltBlockNumber,
+	KiltWeb3Name,
+	KiltLinkableAccountId,
+	RelayBlockNumber,
+	const MAX_REVEALED_LEAVES_COUNT: u32,
+> {
+	/// The subject the attestation is about.
+	pub(crate) subject: Identifier,
+	/// The parts of the subject's DID details verified by the issuer.
+	pub(crate) revealed_leaves: BoundedVec<
+		RevealedDidMerkleProofLeaf<KiltDidKeyId, KiltAccountId, KiltBlockNumber, KiltWeb3Name, KiltLinkableAccountId>,
+		ConstU32<MAX_REVEALED_LEAVES_COUNT>,
+	>,
+	/// The relaychain block number until which the attestation is to be
+	/// considered valid.
+	pub(crate) valid_until: RelayBlockNumber,
+}
+
+impl<
+		Identifier,
+		KiltDidKeyId,
+		KiltAccountId,
+		KiltBlockNumber,
+		KiltWeb3Name,
+		KiltLinkableAccountId,
+		RelayBlockNumber,
+		const MAX_REVEALED_LEAVES_COUNT: u32,
+	>
+	ForwardedDipAttestation<
+		Identifier,
+		KiltDidKeyId,
+		KiltAccountId,
+		KiltBlockNumber,
+		KiltWeb3Name,
+		KiltLinkableAccountId,
+		RelayBlockNumber,
+		MAX_REVEALED_LEAVES_COUNT,
+	>
+{
+	pub fn new(
+		subject: Identifier,
+		revealed_leaves: BoundedVec<
+			RevealedDidMerkleProofLeaf<
+				KiltDidKeyId,
+				KiltAccountId,
+				KiltBlockNumber,
+				KiltWeb3Name,
+				KiltLinkableAccountId,
+			>,
+			ConstU32<MAX_REVEALED_LEAVES_COUNT>,
+		>,
+		valid_until: RelayBlockNumber,
+	) -> Self {
+		Self {
+			subject,
+			revealed_leaves,
+			valid_until,
+		}
+	}
+
+	/// Creates a new attestation from the result of a successful DIP proof
+	/// verification on the issuing chain.
+	pub fn from_origin_info(
+		subject: Identifier,
+		origin_info: DipOriginInfo<
+			KiltDidKeyId,
+			KiltAccountId,
+			KiltBlockNumber,
+			KiltWeb3Name,
+			KiltLinkableAccountId,
+			MAX_REVEALED_LEAVES_COUNT,
+		>,
+		valid_until: RelayBlockNumber,
+	) -> Self {
+		Self::new(subject, origin_info.into_revealed_leaves(), valid_until)
+	}
+}
+
+impl<
+		Identifier,
+		KiltDidKeyId,
+		KiltAccountId,
+		KiltBlockNumber,
+		KiltWeb3Name,
+		KiltLinkableAccountId,
+		RelayBlockNumber,
+		const MAX_REVEALED_LEAVES_COUNT: u32,
+	>
+	ForwardedDipAttestation<
+		Identifier,
+		KiltDidKeyId,
+		KiltAccountId,
+		KiltBlockNumber,
+		KiltWeb3Name,
+		KiltLinkableAccountId,
+		RelayBlockNumber,
+		MAX_REVEALED_LEAVES_COUNT,
+	> where
+	Self: Encode,
+{
+	/// Returns the payload the issuer must sign.
+	pub fn signing_payload(&self) -> Vec<u8> {
+		(FORWARDED_ATTESTATION_SIGNING_DOMAIN, self).encode()
+	}
+
+	/// Attaches the issuer and its signature over
+	/// [`Self::signing_payload`] to the attestation.
+	pub fn into_signed<IssuerId, IssuerSignature>(
+		self,
+		issuer: IssuerId,
+		signature: IssuerSignature,
+	) -> SignedForwardedDipAttestation<
+		Identifier,
+		KiltDidKeyId,
+		KiltAccountId,
+		KiltBlockNumber,
+		KiltWeb3Name,
+		KiltLinkableAccountId,
+		RelayBlockNumber,
+		IssuerId,
+		IssuerSignature,
+		MAX_REVEALED_LEAVES_COUNT,
+	> {
+		SignedForwardedDipAttestation {
+			attestation: self,
+			issuer,
+			signature,
+		}
+	}
+}
+
+/// A [`ForwardedDipAttestation`] together with its issuer and the issuer's
+/// signature.
+///
+/// The generic types are the same as for [`ForwardedDipAttestation`], with the
+/// addition of:
+/// * `IssuerId`: The identifier of the issuer, i.e., the account ID derived
+///   from the issuer's public key.
+/// * `IssuerSignature`: The signature type used by the issuer.
+#[derive(Clone, Debug, Encode, Decode, PartialEq, Eq, TypeInfo)]
+pub struct SignedForwardedDipAttestation<
+	Identifier,
+	KiltDidKeyId,
+	KiltAccountId,
+	KiltBlockNumber,
+	KiltWeb3Name,
+	KiltLinkableAccountId,
+	RelayBlockNumber,
+	IssuerId,
+	IssuerSignature,
+	const MAX_REVEALED_LEAVES_COUNT: u32,
+> {
+	/// The attestation.
+	pub(crate) attestation: ForwardedDipAttestation<
+		Identifier,
+		KiltDidKeyId,
+		KiltAccountId,
+		KiltBlockNumber,
+		KiltWeb3Name,
+		KiltLinkableAccountId,
+		RelayBlockNumber,
+		MAX_REVEALED_LEAVES_COUNT,
+	>,
+	/// The issuer of the attestation.
+	pub(crate) issuer: IssuerId,
+	/// The issuer's signature over the attestation signing payload.
+	pub(crate) signature: IssuerSignature,
+}
+
+impl<
+		Identifier,
+		KiltDidKeyId,
+		KiltAccountId,
+		KiltBlockNumber,
+		KiltWeb3Name,
+		KiltLinkableAccountId,
+		RelayBlockNumber,
+		IssuerId,
+		IssuerSignature,
+		const MAX_REVEALED_LEAVES_COUNT: u32,
+	>
+	SignedForwardedDipAttestation<
+		Identifier,
+		KiltDidKeyId,
+		KiltAccountId,
+		KiltBlockNumber,
+		KiltWeb3Name,
+		KiltLinkableAccountId,
+		RelayBlockNumber,
+		IssuerId,
+		IssuerSignature,
+		MAX_REVEALED_LEAVES_COUNT,
+	> where
+	IssuerSignature: Verify,
+	IssuerSignature::Signer: IdentifyAccount<AccountId = IssuerId>,
+	ForwardedDipAttestation<
+		Identifier,
+		KiltDidKeyId,
+		KiltAccountId,
+		KiltBlockNumber,
+		KiltWeb3Name,
+		KiltLinkableAccountId,
+		RelayBlockNumber,
+		MAX_REVEALED_LEAVES_COUNT,
+	>: Encode,
+{
+	/// Returns the issuer of the attestation.
+	pub fn issuer(&self) -> &IssuerId {
+		&self.issuer
+	}
+
+	/// Verifies the issuer signature and returns the attestation.
+	pub fn verify_issuer_signature(
+		self,
+	) -> Result<
+		ForwardedDipAttestation<
+			Identifier,
+			KiltDidKeyId,
+			KiltAccountId,
+			KiltBlockNumber,
+			KiltWeb3Name,
+			KiltLinkableAccountId,
+			RelayBlockNumber,
+			MAX_REVEALED_LEAVES_COUNT,
+		>,
+		ForwardedAttestationError,
+	> {
+		let payload = self.attestation.signing_payload();
+		ensure!(
+			self.signature.verify(&payload[..], &self.issuer),
+			ForwardedAttestationError::InvalidIssuerSignature
+		);
+		Ok(self.attestation)
+	}
+}
+
+#[derive(Debug, PartialEq, Eq, TypeInfo)]
+pub enum ForwardedAttestationError {
+	UntrustedIssuer,
+	InvalidIssuerSignature,
+	SubjectMismatch,
+	AttestationExpired,
+}
+
+impl From<ForwardedAttestationError> for u8 {
+	fn from(value: ForwardedAttestationError) -> Self {
+		match value {
+			// DO NOT USE 0
+			// Errors of different sub-parts are separated by a `u8::MAX`.
+			// A value of 0 would make it confusing whether it's the previous sub-part error (u8::MAX)
+			// or the new sub-part error (u8::MAX + 0).
+			ForwardedAttestationError::UntrustedIssuer => 1,
+			ForwardedAttestationError::InvalidIssuerSignature => 2,
+			ForwardedAttestationError::SubjectMismatch => 3,
+			ForwardedAttestationError::AttestationExpired => 4,
+		}
+	}
+}
+
+/// A forwarded DIP proof submitted to a consumer chain.
+///
+/// It contains the signed attestation issued by a trusted chain and the
+/// subject's DID signature over the cross-chain operation, which must be
+/// generated by one of the DID keys included in the attestation.
+#[derive(Clone, Debug, Encode, Decode, PartialEq, Eq, TypeInfo)]
+pub struct ForwardedDipProof<
+	Identifier,
+	KiltDidKeyId,
+	KiltAccountId,
+	KiltBlockNumber,
+	KiltWeb3Name,
+	KiltLinkableAccountId,
+	RelayBlockNumber,
+	IssuerId,
+	IssuerSignature,
+	ConsumerBlockNumber,
+	const MAX_REVEALED_LEAVES_COUNT: u32,
+> {
+	/// The signed attestation.
+	pub(crate) attestation: SignedForwardedDipAttestation<
+		Identifier,
+		KiltDidKeyId,
+		KiltAccountId,
+		KiltBlockNumber,
+		KiltWeb3Name,
+		KiltLinkableAccountId,
+		RelayBlockNumber,
+		IssuerId,
+		IssuerSignature,
+		MAX_REVEALED_LEAVES_COUNT,
+	>,
+	/// The cross-chain DID signature.
+	pub(crate) signature: TimeBoundDidSignature<ConsumerBlockNumber>,
+}
+
+impl<
+		Identifier,
+		KiltDidKeyId,
+		KiltAccountId,
+		KiltBlockNumber,
+		KiltWeb3Name,
+		KiltLinkableAccountId,
+		RelayBlockNumber,
+		IssuerId,
+		IssuerSignature,
+		ConsumerBlockNumber,
+		const MAX_REVEALED_LEAVES_COUNT: u32,
+	>
+	ForwardedDipProof<
+		Identifier,
+		KiltDidKeyId,
+		KiltAccountId,
+		KiltBlockNumber,
+		KiltWeb3Name,
+		KiltLinkableAccountId,
+		RelayBlockNumber,
+		IssuerId,
+		IssuerSignature,
+		ConsumerBlockNumber,
+		MAX_REVEALED_LEAVES_COUNT,
+	>
+{
+	pub fn new(
+		attestation: SignedForwardedDipAttestation<
+			Identifier,
+			KiltDidKeyId,
+			KiltAccountId,
+			KiltBlockNumber,
+			KiltWeb3Name,
+			KiltLinkableAccountId,
+			RelayBlockNumber,
+			IssuerId,
+			IssuerSignature,
+			MAX_REVEALED_LEAVES_COUNT,
+		>,
+		signature: TimeBoundDidSignature<ConsumerBlockNumber>,
+	) -> Self {
+		Self { attestation, signature }
+	}
+}
+
+pub enum ForwardedDipProofVerifierError<DidOriginError> {
+	Attestation(ForwardedAttestationError),
+	ProofVerification(Error),
+	DidOriginError(DidOriginError),
+	Internal,
+}
+
+impl<DidOriginError> From<ForwardedDipProofVerifierError<DidOriginError>> for u16
+where
+	DidOriginError: Into<u8>,
+{
+	fn from(value: ForwardedDipProofVerifierError<DidOriginError>) -> Self {
+		match value {
+			// DO NOT USE 0
+			// Errors of different sub-parts are separated by a `u8::MAX`.
+			// A value of 0 would make it confusing whether it's the previous sub-part error (u8::MAX)
+			// or the new sub-part error (u8::MAX + 0).
+			ForwardedDipProofVerifierError::Attestation(error) => u8::from(error) as u16,
+			ForwardedDipProofVerifierError::ProofVerification(error) => u8::MAX as u16 + u8::from(error) as u16,
+			ForwardedDipProofVerifierError::DidOriginError(error) => u8::MAX as u16 * 2 + error.into() as u16,
+			ForwardedDipProofVerifierError::Internal => u16::MAX,
+		}
+	}
+}
+
+/// Proof verifier for attestations forwarded by other chains that have
+/// verified a full DIP proof for the subject.
+///
+/// The generic types indicate the following:
+/// * `KiltRuntime`: A KILT runtime definition.
+/// * `IssuerSignature`: The signature type used by attestation issuers.
+/// * `TrustedIssuers`: The set of issuers whose attestations are accepted.
+/// * `RelayBlockNumberProvider`: A provider of the current relaychain block
+///   number, used to check the attestation validity.
+/// * `DidCallVerifier`: Logic to map `RuntimeCall`s to a specific DID key
+///   relationship. This information is used once the attestation is verified,
+///   to filter only the attested keys that match the provided relationship.
+/// * `SignedExtra`: Any additional information that must be signed by the DID
+///   subject in the cross-chain operation.
+/// * `MAX_DID_MERKLE_LEAVES_REVEALED`: The maximum number of DID leaves that
+///   can be included in the attestation.
+pub struct KiltForwardedAttestationVerifier<
+	KiltRuntime,
+	IssuerSignature,
+	TrustedIssuers,
+	RelayBlockNumberProvider,
+	DidCallVerifier,
+	SignedExtra = (),
+	const MAX_DID_MERKLE_LEAVES_REVEALED: u32 = 64,
+>(
+	PhantomData<(
+		KiltRuntime,
+		IssuerSignature,
+		TrustedIssuers,
+		RelayBlockNumberProvider,
+		DidCallVerifier,
+		SignedExtra,
+	)>,
+);
+
+impl<
+		ConsumerRuntime,
+		KiltRuntime,
+		IssuerSignature,
+		TrustedIssuers,
+		RelayBlockNumberProvider,
+		DidCallVerifier,
+		SignedExtra,
+		const MAX_DID_MERKLE_LEAVES_REVEALED: u32,
+	> IdentityProofVerifier<ConsumerRuntime>
+	for KiltForwardedAttestationVerifier<
+		KiltRuntime,
+		IssuerSignature,
+		TrustedIssuers,
+		RelayBlockNumberProvider,
+		DidCallVerifier,
+		SignedExtra,
+		MAX_DID_MERKLE_LEAVES_REVEALED,
+	> where
+	ConsumerRuntime: pallet_dip_consumer::Config<Identifier = KiltRuntime::Identifier>,
+	ConsumerRuntime::LocalIdentityInfo: Incrementable + Default,
+	KiltRuntime: frame_system::Config
+		+ pallet_dip_provider::Config
+		+ did::Config
+		+ pallet_web3_names::Config
+		+ pallet_did_lookup::Config,
+	IssuerSignature: Verify + Encode + Decode + TypeInfo + Clone + PartialEq + Eq + Debug,
+	IssuerIdOf<IssuerSignature>: Encode + Decode + TypeInfo + Clone + PartialEq + Eq + Debug,
+	TrustedIssuers: Contains<IssuerIdOf<IssuerSignature>>,
+	RelayBlockNumberProvider: BlockNumberProvider,
+	DidCallVerifier: DipCallOriginFilter<
+		RuntimeCallOf<ConsumerRuntime>,
+		OriginInfo = RevealedDidKey<KeyIdOf<KiltRuntime>, BlockNumberFor<KiltRuntime>, KiltRuntime::AccountId>,
+	>,
+	DidCallVerifier::Error: Into<u8>,
+	SignedExtra: GetWithoutArg,
+	SignedExtra::Result: Encode,
+	KeyIdOf<KiltRuntime>: BenchmarkDefault,
+	BlockNumberFor<KiltRuntime>: BenchmarkDefault,
+{
+	type Error = ForwardedDipProofVerifierError<DidCallVerifier::Error>;
+	type Proof = ForwardedDipProof<
+		ConsumerRuntime::Identifier,
+		KeyIdOf<KiltRuntime>,
+		KiltRuntime::AccountId,
+		BlockNumberFor<KiltRuntime>,
+		Web3NameOf<KiltRuntime>,
+		LinkableAccountId,
+		<RelayBlockNumberProvider as BlockNumberProvider>::BlockNumber,
+		IssuerIdOf<IssuerSignature>,
+		IssuerSignature,
+		BlockNumberFor<ConsumerRuntime>,
+		MAX_DID_MERKLE_LEAVES_REVEALED,
+	>;
+	type VerificationResult = DipOriginInfo<
+		KeyIdOf<KiltRuntime>,
+		KiltRuntime::AccountId,
+		BlockNumberFor<KiltRuntime>,
+		Web3NameOf<KiltRuntime>,
+		LinkableAccountId,
+		MAX_DID_MERKLE_LEAVES_REVEALED,
+	>;
+
+	fn verify_proof_for_call_against_details(
+		call: &RuntimeCallOf<ConsumerRuntime>,
+		subject: &ConsumerRuntime::Identifier,
+		submitter: &ConsumerRuntime::AccountId,
+		identity_details: &mut Option<ConsumerRuntime::LocalIdentityInfo>,
+		proof: Self::Proof,
+	) -> Result<Self::VerificationResult, Self::Error> {
+		// 1. Verify the attestation comes from a trusted issuer, is about the subject,
+		//    and is still valid.
+		ensure!(
+			TrustedIssuers::contains(proof.attestation.issuer()),
+			ForwardedDipProofVerifierError::Attestation(ForwardedAttestationError::UntrustedIssuer)
+		);
+		let attestation = proof
+			.attestation
+			.verify_issuer_signature()
+			.map_err(ForwardedDipProofVerifierError::Attestation)?;
+		ensure!(
+			attestation.subject == *subject,
+			ForwardedDipProofVerifierError::Attestation(ForwardedAttestationError::SubjectMismatch)
+		);
+		ensure!(
+			attestation.valid_until >= RelayBlockNumberProvider::current_block_number(),
+			ForwardedDipProofVerifierError::Attestation(ForwardedAttestationError::AttestationExpired)
+		);
+
+		// 2. Verify call is signed by one of the DID keys included in the attestation.
+		let current_block_number = frame_system::Pallet::<ConsumerRuntime>::block_number();
+		let consumer_genesis_hash =
+			frame_system::Pallet::<ConsumerRuntime>::block_hash(BlockNumberFor::<ConsumerRuntime>::zero());
+		let signed_extra = SignedExtra::get();
+		let encoded_payload = (
+			call,
+			&identity_details,
+			submitter,
+			&proof.signature.valid_until,
+			consumer_genesis_hash,
+			signed_extra,
+		)
+			.encode();
+		let revealed_did_info = DipRevealedDetailsAndUnverifiedDidSignature {
+			revealed_leaves: attestation.revealed_leaves,
+			signature: proof.signature,
+		}
+		.verify_signature_time(&current_block_number)
+		.and_then(|p| p.retrieve_signing_leaf_for_payload(&encoded_payload[..]))
+		.map_err(ForwardedDipProofVerifierError::ProofVerification)?;
+
+		// 3. Verify the signing key fulfills the requirements
+		let signing_key = revealed_did_info
+			.get_signing_leaf()
+			.map_err(ForwardedDipProofVerifierError::ProofVerification)?;
+		DidCallVerifier::check_call_origin_info(call, signing_key)
+			.map_err(ForwardedDipProofVerifierError::DidOriginError)?;
+
+		// 4. Increment the local details
+		if let Some(details) = identity_details {
+			details.increment();
+		} else {
+			*identity_details = Some(Default::default());
+		};
+
+		Ok(revealed_did_info)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use sp_core::{sr25519, Pair};
+	use sp_runtime::{AccountId32, MultiSignature};
+
+	use super::*;
+
+	type TestAttestation = ForwardedDipAttestation<AccountId32, u32, AccountId32, u64, Vec<u8>, AccountId32, u32, 8>;
+
+	fn attestation(subject: AccountId32) -> TestAttestation {
+		TestAttestation::new(subject, BoundedVec::default(), 100)
+	}
+
+	#[test]
+	fn issuer_signature_verification_succeeds() {
+		let issuer = sr25519::Pair::from_seed(&[1u8; 32]);
+		let attestation = attestation(AccountId32::new([10u8; 32]));
+		let signature = MultiSignature::from(issuer.sign(&attestation.signing_payload()));
+
+		let signed = attestation
+			.clone()
+			.into_signed(AccountId32::from(issuer.public()), signature);
+
+		assert_eq!(signed.verify_issuer_signature(), Ok(attestation));
+	}
+
+	#[test]
+	fn issuer_signature_verification_fails_for_tampered_attestation() {
+		let issuer = sr25519::Pair::from_seed(&[1u8; 32]);
+		let signature = MultiSignature::from(issuer.sign(&attestation(AccountId32::new([10u8; 32])).signing_payload()));
+
+		let signed =
+			attestation(AccountId32::new([11u8; 32])).into_signed(AccountId32::from(issuer.public()), signature);
+
+		assert_eq!(
+			signed.verify_issuer_signature(),
+			Err(ForwardedAttestationError::InvalidIssuerSignature)
+		);
+	}
+
+	#[test]
+	fn issuer_signature_verification_fails_for_different_issuer() {
+		let issuer = sr25519::Pair::from_seed(&[1u8; 32]);
+		let other = sr25519::Pair::from_seed(&[2u8; 32]);
+		let attestation = attestation(AccountId32::new([10u8; 32]));
+		let signature = MultiSignature::from(issuer.sign(&attestation.signing_payload()));
+
+		let signed = attestation.into_signed(AccountId32::from(other.public()), signature);
+
+		assert_eq!(
+			signed.verify_issuer_signature(),
+			Err(ForwardedAttestationError::InvalidIssuerSignature)
+		);
+	}
+}
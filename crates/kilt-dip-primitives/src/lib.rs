@@ -23,11 +23,24 @@
 //! Consumers of KILT identities should prefer directly using
 //! [`KiltVersionedRelaychainVerifier`] for consumer relaychains and
 //! [`KiltVersionedParachainVerifier`] for consumer sibling parachains.
+//! Consumers that do not share a relaychain with KILT can use
+//! [`KiltVersionedDirectChannelVerifier`] against KILT heads they store
+//! locally.
+//! Chains that trust another consumer's verification can use
+//! [`forwarding::KiltForwardedAttestationVerifier`] instead.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+/// Module to deal with the fees of DIP calls on consumer chains.
+pub mod fees;
+/// Module to re-attest verified DIP proofs to other chains.
+pub mod forwarding;
 /// Module to deal with cross-chain Merkle proof as generated by the KILT chain.
 pub mod merkle;
+/// Module to introspect the cost of DIP proof verifications, to calibrate the
+/// bounds and weights of consumer runtimes against real proofs.
+#[cfg(feature = "metrics")]
+pub mod metrics;
 /// Module to deal with cross-chain state proofs.
 pub mod state_proofs;
 /// Collection of traits used throughout the crate and useful for both providers
@@ -37,7 +50,16 @@ pub mod utils;
 /// Verifier module containing types that implement the verifier component to be
 /// deployed both on a sibling parachain and on a parent relaychain.
 pub mod verifier;
+/// Module with `wasm-bindgen` bindings to verify DID Merkle proofs in
+/// browsers.
+#[cfg(feature = "wasm")]
+pub mod wasm;
+/// Module with the primitives for the ZK-friendly identity commitment.
+pub mod zk;
 
 pub use merkle::latest::*;
-pub use traits::RelayStateRootsViaRelayStorePallet;
+pub use traits::{
+	AnyRelaychain, LatestRelayBlockViaRelayStorePallet, MaxAnchorAgePerCall, RelayStateRootsViaRelayStorePallet,
+	RelaychainWithGenesisHash,
+};
 pub use verifier::*;
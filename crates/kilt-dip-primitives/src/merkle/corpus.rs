@@ -0,0 +1,313 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+//! Generator of real, worst-case DIP Merkle proofs.
+//!
+//! The generated proofs use the same trie layout and leaf encoding as the KILT
+//! provider, so that benchmarks and tests exercise the real decoding and
+//! verification paths instead of relying on synthetic default leaves.
+
+use did::{
+	did_details::{DidEncryptionKey, DidPublicKey, DidPublicKeyDetails, DidVerificationKey},
+	DidVerificationKeyRelationship,
+};
+use pallet_did_lookup::{
+	account::AccountId20, bitcoin::BitcoinAccountId, linkable_account::LinkableAccountId, solana::SolanaAccountId,
+};
+use parity_scale_codec::Encode;
+use sp_core::{ecdsa, ed25519, sr25519, ConstU32, H256};
+use sp_runtime::{
+	traits::{BlakeTwo256, Hash},
+	AccountId32, BoundedVec,
+};
+use sp_std::{vec, vec::Vec};
+use sp_trie::{generate_trie_proof, LayoutV1, MemoryDB, TrieDBMutBuilder, TrieHash, TrieMut};
+
+use crate::merkle::v0::{
+	DidKeyRelationship, DidMerkleProof, RevealedAccountId, RevealedDidKey, RevealedDidMerkleProofLeaf, RevealedWeb3Name,
+};
+
+/// The maximum length of the web3name included in the generated proofs.
+pub const MAX_WEB3_NAME_LENGTH: u32 = 32;
+/// The maximum number of key agreement keys of the KILT runtimes.
+pub const MAX_KEY_AGREEMENT_KEYS: u32 = 19;
+/// The maximum number of linked accounts of the KILT runtimes.
+pub const MAX_LINKED_ACCOUNTS: u32 = 10;
+
+/// The web3name type used in the generated proofs. It has the same encoding
+/// as the web3name type of the KILT runtimes.
+pub type CorpusWeb3Name = BoundedVec<u8, ConstU32<MAX_WEB3_NAME_LENGTH>>;
+
+/// The DID Merkle proof type generated, which matches the one of the KILT
+/// runtimes.
+pub type CorpusDidMerkleProof = DidMerkleProof<H256, AccountId32, u64, CorpusWeb3Name, LinkableAccountId>;
+
+type CorpusLeaf = RevealedDidMerkleProofLeaf<H256, AccountId32, u64, CorpusWeb3Name, LinkableAccountId>;
+
+/// The shape of the DID identity to merkleize and of the proof to generate.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ProofShape {
+	/// The number of key agreement keys in the DID.
+	pub key_agreement_keys: u32,
+	/// The number of accounts linked to the DID.
+	pub linked_accounts: u32,
+	/// Whether the DID has a web3name.
+	pub web3_name: bool,
+	/// The maximum number of leaves to reveal in the proof. The remaining
+	/// leaves are blinded.
+	pub revealed_leaves: u32,
+}
+
+impl ProofShape {
+	/// The largest identity supported by the KILT runtimes, with all of its
+	/// leaves revealed.
+	pub const fn worst_case() -> Self {
+		Self {
+			key_agreement_keys: MAX_KEY_AGREEMENT_KEYS,
+			linked_accounts: MAX_LINKED_ACCOUNTS,
+			web3_name: true,
+			revealed_leaves: u32::MAX,
+		}
+	}
+
+	/// The largest identity supported by the KILT runtimes, with only the
+	/// authentication key revealed. This results in the largest number of
+	/// blinded trie nodes.
+	pub const fn deep_trie() -> Self {
+		Self {
+			revealed_leaves: 1,
+			..Self::worst_case()
+		}
+	}
+}
+
+/// A named proof shape that is written to a fixture file by the corpus
+/// generator.
+pub struct CorpusEntry {
+	pub name: &'static str,
+	pub shape: ProofShape,
+}
+
+/// The proof shapes that make up the worst-case corpus.
+pub const CORPUS: [CorpusEntry; 4] = [
+	CorpusEntry {
+		name: "worst_case",
+		shape: ProofShape::worst_case(),
+	},
+	CorpusEntry {
+		name: "deep_trie",
+		shape: ProofShape::deep_trie(),
+	},
+	CorpusEntry {
+		name: "max_keys",
+		shape: ProofShape {
+			key_agreement_keys: MAX_KEY_AGREEMENT_KEYS,
+			linked_accounts: 0,
+			web3_name: false,
+			revealed_leaves: u32::MAX,
+		},
+	},
+	CorpusEntry {
+		name: "max_linked_accounts",
+		shape: ProofShape {
+			key_agreement_keys: 0,
+			linked_accounts: MAX_LINKED_ACCOUNTS,
+			web3_name: false,
+			revealed_leaves: u32::MAX,
+		},
+	},
+];
+
+fn key_id(key: &DidPublicKey<AccountId32>) -> H256 {
+	BlakeTwo256::hash(&key.encode())
+}
+
+fn key_leaf(key: DidPublicKey<AccountId32>, relationship: DidKeyRelationship) -> CorpusLeaf {
+	RevealedDidKey {
+		id: key_id(&key),
+		relationship,
+		details: DidPublicKeyDetails {
+			key,
+			block_number: u64::MAX,
+		},
+	}
+	.into()
+}
+
+fn linked_account(index: u32) -> LinkableAccountId {
+	let seed = index.to_le_bytes();
+	let mut account_20 = [0u8; 20];
+	account_20[..4].copy_from_slice(&seed);
+	let mut account_32 = [0u8; 32];
+	account_32[..4].copy_from_slice(&seed);
+	let mut account_33 = [2u8; 33];
+	account_33[1..5].copy_from_slice(&seed);
+
+	// Cycle through all account types, so that each variant is decoded.
+	match index % 4 {
+		0 => AccountId32::new(account_32).into(),
+		1 => AccountId20(account_20).into(),
+		2 => SolanaAccountId(account_32).into(),
+		_ => BitcoinAccountId(account_33).into(),
+	}
+}
+
+/// Returns all the leaves of a DID identity with the provided shape.
+///
+/// The authentication key is always the first leaf. Keys are chosen to have
+/// the largest possible encoding for their relationship.
+pub fn identity_leaves(shape: &ProofShape) -> Vec<CorpusLeaf> {
+	let mut leaves = vec![
+		key_leaf(
+			DidVerificationKey::Sr25519(sr25519::Public::from_raw([1u8; 32])).into(),
+			DidVerificationKeyRelationship::Authentication.into(),
+		),
+		key_leaf(
+			DidVerificationKey::Ecdsa(ecdsa::Public::from_raw([2u8; 33])).into(),
+			DidVerificationKeyRelationship::AssertionMethod.into(),
+		),
+		key_leaf(
+			DidVerificationKey::Ed25519(ed25519::Public::from_raw([3u8; 32])).into(),
+			DidVerificationKeyRelationship::CapabilityDelegation.into(),
+		),
+	];
+	leaves.extend((0..shape.key_agreement_keys).map(|index| {
+		let mut key = [4u8; 32];
+		key[..4].copy_from_slice(&index.to_le_bytes());
+		key_leaf(
+			DidPublicKey::PublicEncryptionKey(DidEncryptionKey::X25519(key)),
+			DidKeyRelationship::Encryption,
+		)
+	}));
+	leaves.extend((0..shape.linked_accounts).map(|index| RevealedAccountId(linked_account(index)).into()));
+	if shape.web3_name {
+		let web3_name = CorpusWeb3Name::truncate_from(vec![b'w'; MAX_WEB3_NAME_LENGTH as usize]);
+		leaves.push(
+			RevealedWeb3Name {
+				web3_name,
+				claimed_at: u64::MAX,
+			}
+			.into(),
+		);
+	}
+	leaves
+}
+
+/// Generates the Merkle root of a DID identity with the provided shape and a
+/// proof revealing the first `shape.revealed_leaves` leaves of it.
+pub fn generate_proof(shape: &ProofShape) -> Result<(H256, CorpusDidMerkleProof), &'static str> {
+	let leaves = identity_leaves(shape);
+
+	let mut db = MemoryDB::<BlakeTwo256>::default();
+	let mut root = TrieHash::<LayoutV1<BlakeTwo256>>::default();
+	{
+		let mut trie_builder = TrieDBMutBuilder::<LayoutV1<BlakeTwo256>>::new(&mut db, &mut root).build();
+		for leaf in leaves.iter() {
+			trie_builder
+				.insert(leaf.encoded_key().as_slice(), leaf.encoded_value().as_slice())
+				.map_err(|_| "Failed to insert leaf in the trie.")?;
+		}
+		trie_builder.commit();
+	}
+
+	let revealed = leaves
+		.into_iter()
+		.take(shape.revealed_leaves as usize)
+		.collect::<Vec<_>>();
+	let encoded_keys = revealed.iter().map(|leaf| leaf.encoded_key()).collect::<Vec<_>>();
+	let blinded = generate_trie_proof::<LayoutV1<BlakeTwo256>, _, _, _>(&db, root, &encoded_keys)
+		.map_err(|_| "Failed to generate the trie proof.")?;
+
+	Ok((root, DidMerkleProof::new(blinded.into_iter().into(), revealed)))
+}
+
+#[cfg(test)]
+mod tests {
+	use parity_scale_codec::{Decode, Encode};
+	use sp_runtime::traits::BlakeTwo256;
+
+	use crate::merkle::v0::{DipDidProofWithVerifiedSubjectCommitment, TimeBoundDidSignature};
+
+	use super::*;
+
+	const MAX_REVEALED_LEAVES_COUNT: u32 = 64;
+
+	#[test]
+	fn corpus_proofs_verify_after_decoding() {
+		for entry in CORPUS.iter() {
+			let (root, proof) = generate_proof(&entry.shape).expect("Proof generation should not fail.");
+			let decoded_proof = CorpusDidMerkleProof::decode(&mut &proof.encode()[..])
+				.expect("Generated proof should decode correctly.");
+			assert_eq!(decoded_proof, proof);
+
+			let signature = TimeBoundDidSignature::new(
+				did::DidSignature::Sr25519(sp_core::sr25519::Signature::from_raw([0u8; 64])),
+				0u64,
+			);
+			let revealed = DipDidProofWithVerifiedSubjectCommitment::new(root, decoded_proof, signature)
+				.verify_dip_proof::<BlakeTwo256, MAX_REVEALED_LEAVES_COUNT>()
+				.unwrap_or_else(|_| panic!("Proof for {} should verify.", entry.name));
+			assert_eq!(
+				revealed.revealed_leaves.len(),
+				identity_leaves(&entry.shape)
+					.len()
+					.min(entry.shape.revealed_leaves as usize)
+			);
+		}
+	}
+
+	#[test]
+	fn deep_trie_proof_blinds_all_other_leaves() {
+		let (_, worst_case) = generate_proof(&ProofShape::worst_case()).unwrap();
+		let (_, deep_trie) = generate_proof(&ProofShape::deep_trie()).unwrap();
+
+		assert_eq!(deep_trie.revealed().len(), 1);
+		assert!(deep_trie.blinded().encoded_size() > 0);
+		assert_eq!(
+			worst_case.revealed().len() as u32,
+			3 + MAX_KEY_AGREEMENT_KEYS + MAX_LINKED_ACCOUNTS + 1
+		);
+	}
+
+	// Proof verification errors are ignored when benchmarking.
+	#[cfg(not(feature = "runtime-benchmarks"))]
+	#[test]
+	fn tampered_corpus_proof_fails_verification() {
+		let (root, proof) = generate_proof(&ProofShape::worst_case()).unwrap();
+		let mut revealed = proof.revealed().to_vec();
+		revealed.pop();
+		revealed.push(
+			RevealedWeb3Name {
+				web3_name: CorpusWeb3Name::truncate_from(b"tampered".to_vec()),
+				claimed_at: 0,
+			}
+			.into(),
+		);
+		let tampered_proof = DidMerkleProof::new(proof.blinded().clone(), revealed);
+
+		let signature = TimeBoundDidSignature::new(
+			did::DidSignature::Sr25519(sp_core::sr25519::Signature::from_raw([0u8; 64])),
+			0u64,
+		);
+		assert!(
+			DipDidProofWithVerifiedSubjectCommitment::new(root, tampered_proof, signature)
+				.verify_dip_proof::<BlakeTwo256, MAX_REVEALED_LEAVES_COUNT>()
+				.is_err()
+		);
+	}
+}
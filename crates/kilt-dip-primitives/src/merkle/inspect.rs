@@ -0,0 +1,296 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+//! Module to inspect DID Merkle proofs that fail verification.
+//!
+//! The verification logic used by the verifiers only reports an
+//! [`Error::InvalidDidMerkleProof`](crate::merkle::v0::Error) when a proof is
+//! rejected. The report generated by [`inspect_proof`] breaks the proof down
+//! into its components, so that SDK developers can tell what part of it is
+//! wrong.
+
+use parity_scale_codec::Encode;
+use sp_core::storage::TRIE_VALUE_NODE_THRESHOLD;
+use sp_runtime::traits::Hash;
+use sp_trie::{verify_trie_proof, LayoutV1, VerifyError};
+
+use crate::merkle::v0::{DidMerkleProof, RevealedDidMerkleProofLeaf};
+
+/// The type of a revealed leaf.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LeafKind {
+	DidKey,
+	Web3Name,
+	LinkedAccount,
+	Manifest,
+	AlsoKnownAs,
+	Web3NameRecord,
+}
+
+/// The details of a revealed leaf, as they are fed into the trie proof
+/// verification.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LeafReport<Output> {
+	/// The position of the leaf in the list of revealed leaves.
+	pub index: usize,
+	/// The type of the leaf.
+	pub kind: LeafKind,
+	/// The trie key of the leaf.
+	pub encoded_key: Vec<u8>,
+	/// The trie value of the leaf.
+	pub encoded_value: Vec<u8>,
+	/// The hash of the leaf value. It is only relevant if the value is not
+	/// inlined in its trie node.
+	pub value_hash: Output,
+	/// Whether the value is stored inline in its trie node instead of being
+	/// referenced by its hash.
+	pub value_inlined: bool,
+}
+
+/// The details of a blinded trie node.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BlindedNodeReport<Output> {
+	/// The position of the node in the blinded part of the proof.
+	pub index: usize,
+	/// The size of the encoded node, in bytes.
+	pub size: usize,
+	/// The hash of the encoded node.
+	pub hash: Output,
+}
+
+/// The outcome of verifying a DID Merkle proof against the expected root.
+///
+/// Leaves are referred to by their position in the list of revealed leaves, if
+/// the key reported by the trie verification logic matches any of them.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ProofOutcome<Output> {
+	/// The proof is valid.
+	Valid,
+	/// The proof is well-formed, but it computes a different root than the
+	/// expected one. This happens if the revealed leaves or the blinded nodes
+	/// have been altered, or if the proof was generated for a different
+	/// commitment.
+	RootMismatch { computed: Output },
+	/// The same leaf key is revealed more than once.
+	DuplicateLeaf { key: Vec<u8>, leaf: Option<usize> },
+	/// A revealed leaf value does not match the value found in the trie.
+	ValueMismatch { key: Vec<u8>, leaf: Option<usize> },
+	/// A revealed leaf is already contained in one of the blinded nodes.
+	ExtraneousLeafValue { key: Vec<u8>, leaf: Option<usize> },
+	/// A blinded node references a child with a key that does not belong to
+	/// any revealed leaf.
+	InvalidChildReference { key: Vec<u8>, leaf: Option<usize> },
+	/// A blinded node contains a child hash that should have been omitted,
+	/// since the child is part of the proof.
+	ExtraneousHashReference { hash: Output },
+	/// The blinded part contains more nodes than the ones needed to verify
+	/// the revealed leaves.
+	ExtraneousNode,
+	/// One or more blinded nodes needed to verify the revealed leaves are
+	/// missing.
+	MissingNode,
+	/// One of the blinded nodes could not be decoded as a trie node.
+	CorruptNode { error: String },
+}
+
+/// A structured report about a DID Merkle proof.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DidMerkleProofReport<Output> {
+	/// The root the proof was checked against.
+	pub expected_root: Output,
+	/// The revealed leaves.
+	pub leaves: Vec<LeafReport<Output>>,
+	/// The blinded nodes.
+	pub blinded_nodes: Vec<BlindedNodeReport<Output>>,
+	/// The outcome of the verification.
+	pub outcome: ProofOutcome<Output>,
+}
+
+impl<Output: PartialEq> DidMerkleProofReport<Output> {
+	/// Whether the proof is valid.
+	pub fn is_valid(&self) -> bool {
+		self.outcome == ProofOutcome::Valid
+	}
+}
+
+/// Verify the provided DID Merkle proof against the expected root, and
+/// generate a report about each of its components.
+///
+/// The verification logic is the same used in
+/// [`DipDidProofWithVerifiedSubjectCommitment::verify_dip_proof`](crate::merkle::v0::DipDidProofWithVerifiedSubjectCommitment::verify_dip_proof),
+/// except that the number of revealed leaves is not checked.
+pub fn inspect_proof<DidMerkleHasher, KeyId, AccountId, BlockNumber, Web3Name, LinkedAccountId>(
+	proof: &DidMerkleProof<KeyId, AccountId, BlockNumber, Web3Name, LinkedAccountId>,
+	expected_root: &DidMerkleHasher::Output,
+) -> DidMerkleProofReport<DidMerkleHasher::Output>
+where
+	DidMerkleHasher: Hash,
+	KeyId: Encode,
+	AccountId: Encode,
+	BlockNumber: Encode,
+	Web3Name: Encode,
+	LinkedAccountId: Encode,
+{
+	let leaves = proof
+		.revealed()
+		.iter()
+		.enumerate()
+		.map(|(index, leaf)| {
+			let encoded_value = leaf.encoded_value();
+			LeafReport {
+				index,
+				kind: match leaf {
+					RevealedDidMerkleProofLeaf::DidKey(_) => LeafKind::DidKey,
+					RevealedDidMerkleProofLeaf::Web3Name(_) => LeafKind::Web3Name,
+					RevealedDidMerkleProofLeaf::LinkedAccount(_) => LeafKind::LinkedAccount,
+					RevealedDidMerkleProofLeaf::Manifest(_) => LeafKind::Manifest,
+					RevealedDidMerkleProofLeaf::AlsoKnownAs(_) => LeafKind::AlsoKnownAs,
+					RevealedDidMerkleProofLeaf::Web3NameRecord(_) => LeafKind::Web3NameRecord,
+				},
+				encoded_key: leaf.encoded_key(),
+				value_hash: DidMerkleHasher::hash(&encoded_value),
+				value_inlined: encoded_value.len() < TRIE_VALUE_NODE_THRESHOLD as usize,
+				encoded_value,
+			}
+		})
+		.collect::<Vec<_>>();
+
+	let blinded_nodes = proof
+		.blinded()
+		.iter()
+		.enumerate()
+		.map(|(index, node)| BlindedNodeReport {
+			index,
+			size: node.len(),
+			hash: DidMerkleHasher::hash(node),
+		})
+		.collect::<Vec<_>>();
+
+	let leaf_with_key = |key: &[u8]| leaves.iter().position(|leaf| leaf.encoded_key == key);
+
+	let proof_leaves_key_value_pairs = leaves
+		.iter()
+		.map(|leaf| (leaf.encoded_key.clone(), Some(leaf.encoded_value.clone())))
+		.collect::<Vec<_>>();
+	let outcome = match verify_trie_proof::<LayoutV1<DidMerkleHasher>, _, _, _>(
+		expected_root,
+		proof.blinded().as_slice(),
+		proof_leaves_key_value_pairs.as_slice(),
+	) {
+		Ok(()) => ProofOutcome::Valid,
+		Err(VerifyError::RootMismatch(computed)) => ProofOutcome::RootMismatch { computed },
+		Err(VerifyError::DuplicateKey(key)) => ProofOutcome::DuplicateLeaf {
+			leaf: leaf_with_key(&key),
+			key,
+		},
+		Err(VerifyError::ValueMismatch(key)) => ProofOutcome::ValueMismatch {
+			leaf: leaf_with_key(&key),
+			key,
+		},
+		Err(VerifyError::ExtraneousValue(key)) => ProofOutcome::ExtraneousLeafValue {
+			leaf: leaf_with_key(&key),
+			key,
+		},
+		Err(VerifyError::InvalidChildReference(key)) => ProofOutcome::InvalidChildReference {
+			leaf: leaf_with_key(&key),
+			key,
+		},
+		Err(VerifyError::ExtraneousHashReference(hash)) => ProofOutcome::ExtraneousHashReference { hash },
+		Err(VerifyError::ExtraneousNode) => ProofOutcome::ExtraneousNode,
+		Err(VerifyError::IncompleteProof) => ProofOutcome::MissingNode,
+		Err(VerifyError::DecodeError(error)) => ProofOutcome::CorruptNode {
+			error: format!("{:?}", error),
+		},
+	};
+
+	DidMerkleProofReport {
+		expected_root: *expected_root,
+		leaves,
+		blinded_nodes,
+		outcome,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use sp_core::H256;
+	use sp_runtime::traits::BlakeTwo256;
+
+	use crate::merkle::{
+		corpus::{generate_proof, ProofShape},
+		v0::{DidMerkleProof, RevealedWeb3Name},
+	};
+
+	use super::*;
+
+	#[test]
+	fn valid_proof() {
+		let (root, proof) = generate_proof(&ProofShape::worst_case()).unwrap();
+
+		let report = inspect_proof::<BlakeTwo256, _, _, _, _, _>(&proof, &root);
+
+		assert!(report.is_valid());
+		assert_eq!(report.leaves.len(), proof.revealed().len());
+		assert_eq!(report.blinded_nodes.len(), proof.blinded().len());
+	}
+
+	#[test]
+	fn wrong_root() {
+		let (root, proof) = generate_proof(&ProofShape::worst_case()).unwrap();
+		let wrong_root = H256::repeat_byte(1);
+
+		let report = inspect_proof::<BlakeTwo256, _, _, _, _, _>(&proof, &wrong_root);
+
+		assert_eq!(report.outcome, ProofOutcome::RootMismatch { computed: root });
+	}
+
+	#[test]
+	fn tampered_leaf() {
+		let (root, proof) = generate_proof(&ProofShape::worst_case()).unwrap();
+		let mut revealed = proof.revealed().to_vec();
+		let tampered_leaf_index = revealed.len() - 1;
+		revealed[tampered_leaf_index] = RevealedWeb3Name {
+			web3_name: crate::merkle::corpus::CorpusWeb3Name::truncate_from(b"tampered".to_vec()),
+			claimed_at: 0,
+		}
+		.into();
+		let tampered_proof = DidMerkleProof::new(proof.blinded().clone(), revealed);
+
+		let report = inspect_proof::<BlakeTwo256, _, _, _, _, _>(&tampered_proof, &root);
+
+		assert!(!report.is_valid());
+		assert_eq!(report.leaves[tampered_leaf_index].kind, LeafKind::Web3Name);
+		assert_eq!(
+			report.leaves[tampered_leaf_index].encoded_key,
+			b"tampered".to_vec().encode()
+		);
+	}
+
+	#[test]
+	fn missing_node() {
+		let (root, proof) = generate_proof(&ProofShape::deep_trie()).unwrap();
+		let mut blinded = proof.blinded().clone().into_inner();
+		blinded.pop();
+		let incomplete_proof = DidMerkleProof::new(blinded.into_iter().into(), proof.revealed().to_vec());
+
+		let report = inspect_proof::<BlakeTwo256, _, _, _, _, _>(&incomplete_proof, &root);
+
+		assert!(!report.is_valid());
+		assert_eq!(report.blinded_nodes.len(), proof.blinded().len() - 1);
+	}
+}
@@ -18,6 +18,15 @@
 
 pub mod v0;
 
+/// Module to generate real worst-case DID Merkle proofs for benchmarks and
+/// tests.
+#[cfg(any(test, feature = "std", feature = "runtime-benchmarks"))]
+pub mod corpus;
+
+/// Module to inspect DID Merkle proofs that fail verification.
+#[cfg(feature = "std")]
+pub mod inspect;
+
 pub mod latest {
 	pub use super::v0::*;
 }
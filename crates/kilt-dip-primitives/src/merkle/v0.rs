@@ -22,16 +22,17 @@ use did::{
 	DidSignature, DidVerificationKeyRelationship,
 };
 use frame_support::ensure;
-use pallet_dip_provider::IdentityCommitmentOf;
-use parity_scale_codec::{Codec, Decode, Encode, MaxEncodedLen};
+use pallet_dip_consumer::DipOriginRequirement;
+use pallet_dip_provider::{IdentityCommitmentOf, IdentityScope};
+use parity_scale_codec::{Codec, Decode, DecodeAll, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
-use sp_core::{ConstU32, U256};
+use sp_core::{ConstU32, Get, U256};
 use sp_runtime::{
 	generic::Header,
 	traits::{AtLeast32BitUnsigned, Hash, Header as HeaderT, MaybeDisplay, Member},
 	BoundedVec, SaturatedConversion,
 };
-use sp_std::{fmt::Debug, vec::Vec};
+use sp_std::{fmt::Debug, marker::PhantomData, vec::Vec};
 use sp_trie::{verify_trie_proof, LayoutV1};
 
 use crate::{
@@ -39,7 +40,7 @@ use crate::{
 	traits::{BenchmarkDefault, GetWithArg},
 	utils::{
 		calculate_dip_identity_commitment_storage_key_for_runtime, calculate_parachain_head_storage_key,
-		BoundedBlindedValue, OutputOf,
+		BoundedBlindedValue, OutputOf, ProofComplexity,
 	},
 };
 
@@ -53,6 +54,20 @@ pub struct ProviderHeadStateProof<RelayBlockNumber> {
 	pub(crate) proof: BoundedBlindedValue<u8>,
 }
 
+impl<RelayBlockNumber> ProviderHeadStateProof<RelayBlockNumber> {
+	pub fn new(relay_block_number: RelayBlockNumber, proof: BoundedBlindedValue<u8>) -> Self {
+		Self {
+			relay_block_number,
+			proof,
+		}
+	}
+
+	/// The complexity of verifying the state proof.
+	pub fn complexity(&self) -> ProofComplexity {
+		self.proof.complexity()
+	}
+}
+
 #[cfg(feature = "runtime-benchmarks")]
 impl<RelayBlockNumber, Context> kilt_support::traits::GetWorstCase<Context> for ProviderHeadStateProof<RelayBlockNumber>
 where
@@ -70,6 +85,17 @@ where
 #[derive(Clone, Debug, Encode, Decode, PartialEq, Eq, TypeInfo)]
 pub struct DipCommitmentStateProof(pub(crate) BoundedBlindedValue<u8>);
 
+impl DipCommitmentStateProof {
+	pub fn new(proof: BoundedBlindedValue<u8>) -> Self {
+		Self(proof)
+	}
+
+	/// The complexity of verifying the state proof.
+	pub fn complexity(&self) -> ProofComplexity {
+		self.0.complexity()
+	}
+}
+
 #[cfg(feature = "runtime-benchmarks")]
 impl<Context> kilt_support::traits::GetWorstCase<Context> for DipCommitmentStateProof {
 	fn worst_case(context: Context) -> Self {
@@ -123,6 +149,73 @@ impl<ProviderDidKeyId, ProviderAccountId, ProviderBlockNumber, ProviderWeb3Name,
 	) -> Self {
 		Self { blinded, revealed }
 	}
+
+	/// The blinded part of the proof, i.e., the trie nodes needed to
+	/// reconstruct the Merkle root from the revealed leaves.
+	pub fn blinded(&self) -> &BoundedBlindedValue<u8> {
+		&self.blinded
+	}
+
+	/// The leaves revealed by the proof.
+	pub fn revealed(
+		&self,
+	) -> &[RevealedDidMerkleProofLeaf<
+		ProviderDidKeyId,
+		ProviderAccountId,
+		ProviderBlockNumber,
+		ProviderWeb3Name,
+		ProviderLinkableAccountId,
+	>] {
+		&self.revealed
+	}
+
+	/// The complexity of verifying the Merkle proof, counting each revealed
+	/// leaf as a processed leaf.
+	pub fn complexity(&self) -> ProofComplexity {
+		let revealed = ProofComplexity {
+			leaves: self.revealed.len().saturated_into(),
+			blinded_bytes: 0,
+		};
+		self.blinded.complexity() + revealed
+	}
+}
+
+impl<ProviderDidKeyId, ProviderAccountId, ProviderBlockNumber, ProviderWeb3Name, ProviderLinkableAccountId>
+	DidMerkleProof<ProviderDidKeyId, ProviderAccountId, ProviderBlockNumber, ProviderWeb3Name, ProviderLinkableAccountId>
+where
+	ProviderDidKeyId: Encode,
+	ProviderAccountId: Encode,
+	ProviderBlockNumber: Encode,
+	ProviderWeb3Name: Encode,
+	ProviderLinkableAccountId: Encode,
+{
+	/// Verifies the revealed leaves against the provided DID Merkle root. The
+	/// number of revealed leaves is not checked.
+	pub fn verify_against_root<DidMerkleHasher>(&self, root: &DidMerkleHasher::Output) -> Result<(), Error>
+	where
+		DidMerkleHasher: Hash,
+	{
+		let proof_leaves_key_value_pairs = self
+			.revealed
+			.iter()
+			.map(|revealed_leaf| (revealed_leaf.encoded_key(), Some(revealed_leaf.encoded_value())))
+			.collect::<Vec<_>>();
+		#[cfg(feature = "metrics")]
+		crate::metrics::record_trie_proof(
+			self.blinded.iter(),
+			proof_leaves_key_value_pairs.len(),
+			proof_leaves_key_value_pairs
+				.iter()
+				.map(|(key, value)| key.len().saturating_add(value.as_ref().map_or(0, Vec::len)))
+				.sum(),
+		);
+		verify_trie_proof::<LayoutV1<DidMerkleHasher>, _, _, _>(
+			root,
+			self.blinded.as_slice(),
+			proof_leaves_key_value_pairs.as_slice(),
+		)
+		.map_err(|_| Error::InvalidDidMerkleProof)
+	}
 }
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -146,12 +239,18 @@ impl<
 	ProviderBlockNumber: Default + Clone,
 	ProviderWeb3Name: Clone,
 	ProviderLinkableAccountId: Clone,
+	Self: Decode,
 {
 	fn worst_case(context: Context) -> Self {
-		Self {
+		// Use a real proof from the worst-case corpus if the provider types match the
+		// ones of the KILT runtimes, so that the real decoding paths are benchmarked.
+		let corpus_proof = crate::merkle::corpus::generate_proof(&crate::merkle::corpus::ProofShape::worst_case())
+			.ok()
+			.and_then(|(_, proof)| Self::decode_all(&mut proof.encode().as_slice()).ok());
+		corpus_proof.unwrap_or_else(|| Self {
 			blinded: BoundedBlindedValue::worst_case(context),
 			revealed: sp_std::vec![RevealedDidMerkleProofLeaf::default(); 64],
-		}
+		})
 	}
 }
 
@@ -197,6 +296,7 @@ pub enum Error {
 	TooManyLeavesRevealed,
 	InvalidSignatureTime,
 	InvalidDidKeyRevealed,
+	ProviderHeadNotFound,
 	ParaHeadMerkleProof(MerkleProofError),
 	DipCommitmentMerkleProof(MerkleProofError),
 	Internal,
@@ -216,6 +316,7 @@ impl From<Error> for u8 {
 			Error::TooManyLeavesRevealed => 5,
 			Error::InvalidSignatureTime => 6,
 			Error::InvalidDidKeyRevealed => 7,
+			Error::ProviderHeadNotFound => 8,
 			Error::ParaHeadMerkleProof(error) => match error {
 				MerkleProofError::InvalidProof => 11,
 				MerkleProofError::RequiredLeafNotRevealed => 12,
@@ -343,6 +444,11 @@ impl<
 		let relay_block_hash = RelayHashStore::get(&self.relay_header.number).ok_or(Error::RelayBlockNotFound)?;
 		self.verify_relay_header_with_block_hash(&relay_block_hash)
 	}
+
+	/// The complexity of verifying all the state and Merkle proofs.
+	pub fn complexity(&self) -> ProofComplexity {
+		self.provider_head_proof.complexity() + self.dip_commitment_proof.complexity() + self.dip_proof.complexity()
+	}
 }
 
 /// A DIP proof submitted to a relaychain consumer that has had the proof header
@@ -475,6 +581,45 @@ pub struct ParachainDipDidProof<
 	pub(crate) signature: TimeBoundDidSignature<ConsumerBlockNumber>,
 }
 
+impl<
+		RelayBlockNumber,
+		KiltDidKeyId,
+		KiltAccountId,
+		KiltBlockNumber,
+		KiltWeb3Name,
+		KiltLinkableAccountId,
+		ConsumerBlockNumber,
+	>
+	ParachainDipDidProof<
+		RelayBlockNumber,
+		KiltDidKeyId,
+		KiltAccountId,
+		KiltBlockNumber,
+		KiltWeb3Name,
+		KiltLinkableAccountId,
+		ConsumerBlockNumber,
+	>
+{
+	pub fn new(
+		provider_head_proof: ProviderHeadStateProof<RelayBlockNumber>,
+		dip_commitment_proof: DipCommitmentStateProof,
+		dip_proof: DidMerkleProof<KiltDidKeyId, KiltAccountId, KiltBlockNumber, KiltWeb3Name, KiltLinkableAccountId>,
+		signature: TimeBoundDidSignature<ConsumerBlockNumber>,
+	) -> Self {
+		Self {
+			provider_head_proof,
+			dip_commitment_proof,
+			dip_proof,
+			signature,
+		}
+	}
+
+	/// The complexity of verifying all the state and Merkle proofs.
+	pub fn complexity(&self) -> ProofComplexity {
+		self.provider_head_proof.complexity() + self.dip_commitment_proof.complexity() + self.dip_proof.complexity()
+	}
+}
+
 #[cfg(feature = "runtime-benchmarks")]
 impl<
 		RelayBlockNumber,
@@ -503,6 +648,7 @@ impl<
 	KiltLinkableAccountId: Clone,
 	ConsumerBlockNumber: Default,
 	Context: Clone,
+	DidMerkleProof<KiltDidKeyId, KiltAccountId, KiltBlockNumber, KiltWeb3Name, KiltLinkableAccountId>: Decode,
 {
 	fn worst_case(context: Context) -> Self {
 		Self {
@@ -540,7 +686,8 @@ impl<
 	/// The generic types indicate the following:
 	/// * `RelayHasher`: The head data hashing algorithm used by the relaychain.
 	/// * `ProviderHeader`: The type of the parachain header to be revealed in
-	///   the state proof.
+	///   the state proof. Its hashing algorithm does not need to match the
+	///   relaychain one.
 	#[allow(clippy::type_complexity)]
 	pub fn verify_provider_head_proof_with_state_root<RelayHasher, ProviderHeader>(
 		self,
@@ -548,7 +695,7 @@ impl<
 		relay_state_root: &OutputOf<RelayHasher>,
 	) -> Result<
 		DipDidProofWithVerifiedRelayStateRoot<
-			OutputOf<RelayHasher>,
+			<ProviderHeader as HeaderT>::Hash,
 			KiltDidKeyId,
 			KiltAccountId,
 			KiltBlockNumber,
@@ -560,7 +707,7 @@ impl<
 	>
 	where
 		RelayHasher: Hash,
-		ProviderHeader: Decode + HeaderT<Hash = OutputOf<RelayHasher>, Number = KiltBlockNumber>,
+		ProviderHeader: Decode + HeaderT<Number = KiltBlockNumber>,
 	{
 		let provider_head_storage_key = calculate_parachain_head_storage_key(provider_para_id);
 		// TODO: Figure out why RPC call returns 2 bytes in front which we don't need
@@ -608,7 +755,7 @@ impl<
 		provider_para_id: u32,
 	) -> Result<
 		DipDidProofWithVerifiedRelayStateRoot<
-			OutputOf<RelayHasher>,
+			<ProviderHeader as HeaderT>::Hash,
 			KiltDidKeyId,
 			KiltAccountId,
 			KiltBlockNumber,
@@ -621,7 +768,7 @@ impl<
 	where
 		RelayHasher: Hash,
 		StateRootStore: GetWithArg<RelayBlockNumber, Result = Option<OutputOf<RelayHasher>>>,
-		ProviderHeader: Decode + HeaderT<Hash = OutputOf<RelayHasher>, Number = KiltBlockNumber>,
+		ProviderHeader: Decode + HeaderT<Number = KiltBlockNumber>,
 	{
 		let relay_state_root = StateRootStore::get(&self.provider_head_proof.relay_block_number);
 		cfg_if::cfg_if! {
@@ -638,6 +785,150 @@ impl<
 	}
 }
 
+/// A DIP proof submitted to a consumer that stores the provider parachain
+/// heads locally, e.g., as relayed by a trusted bridge or set by governance,
+/// instead of verifying them against a relaychain state.
+///
+/// The generic types indicate the following:
+/// * `KiltDidKeyId`: The DID key ID type configured by the KILT chain.
+/// * `KiltAccountId`: The `AccountId` type configured by the KILT chain.
+/// * `KiltBlockNumber`: The `BlockNumber` type configured by the KILT chain.
+/// * `KiltWeb3Name`: The web3name type configured by the KILT chain.
+/// * `KiltLinkableAccountId`: The linkable account ID type configured by the
+///   KILT chain.
+/// * `ConsumerBlockNumber`: The `BlockNumber` definition of the consumer
+///   chain.
+#[derive(Clone, Debug, Encode, Decode, PartialEq, Eq, TypeInfo)]
+pub struct DirectChannelDipDidProof<
+	KiltDidKeyId,
+	KiltAccountId,
+	KiltBlockNumber,
+	KiltWeb3Name,
+	KiltLinkableAccountId,
+	ConsumerBlockNumber,
+> {
+	/// The number of the provider block whose head, as stored by the
+	/// consumer, the DIP commitment proof is generated for.
+	pub(crate) provider_block_number: KiltBlockNumber,
+	/// The raw state proof for the DIP commitment of the given subject.
+	pub(crate) dip_commitment_proof: DipCommitmentStateProof,
+	/// The Merkle proof of the subject's DID details.
+	pub(crate) dip_proof:
+		DidMerkleProof<KiltDidKeyId, KiltAccountId, KiltBlockNumber, KiltWeb3Name, KiltLinkableAccountId>,
+	/// The cross-chain DID signature.
+	pub(crate) signature: TimeBoundDidSignature<ConsumerBlockNumber>,
+}
+
+impl<KiltDidKeyId, KiltAccountId, KiltBlockNumber, KiltWeb3Name, KiltLinkableAccountId, ConsumerBlockNumber>
+	DirectChannelDipDidProof<
+		KiltDidKeyId,
+		KiltAccountId,
+		KiltBlockNumber,
+		KiltWeb3Name,
+		KiltLinkableAccountId,
+		ConsumerBlockNumber,
+	>
+{
+	pub fn new(
+		provider_block_number: KiltBlockNumber,
+		dip_commitment_proof: DipCommitmentStateProof,
+		dip_proof: DidMerkleProof<KiltDidKeyId, KiltAccountId, KiltBlockNumber, KiltWeb3Name, KiltLinkableAccountId>,
+		signature: TimeBoundDidSignature<ConsumerBlockNumber>,
+	) -> Self {
+		Self {
+			provider_block_number,
+			dip_commitment_proof,
+			dip_proof,
+			signature,
+		}
+	}
+
+	/// The complexity of verifying all the state and Merkle proofs.
+	pub fn complexity(&self) -> ProofComplexity {
+		self.dip_commitment_proof.complexity() + self.dip_proof.complexity()
+	}
+
+	/// Retrieves the provider head for the block specified in the proof from
+	/// the provided store, and returns its state root to verify the rest of
+	/// the proof against.
+	///
+	/// The generic types indicate the following:
+	/// * `ProviderHeadStore`: The type that returns a provider parachain
+	///   header, as stored by the consumer, given a provider block number.
+	/// * `ProviderHeader`: The type of the provider parachain header.
+	#[allow(clippy::type_complexity)]
+	pub fn verify_provider_head<ProviderHeadStore, ProviderHeader>(
+		self,
+	) -> Result<
+		DipDidProofWithVerifiedRelayStateRoot<
+			<ProviderHeader as HeaderT>::Hash,
+			KiltDidKeyId,
+			KiltAccountId,
+			KiltBlockNumber,
+			KiltWeb3Name,
+			KiltLinkableAccountId,
+			ConsumerBlockNumber,
+		>,
+		Error,
+	>
+	where
+		ProviderHeadStore: GetWithArg<KiltBlockNumber, Result = Option<ProviderHeader>>,
+		ProviderHeader: HeaderT<Number = KiltBlockNumber>,
+	{
+		let provider_header = ProviderHeadStore::get(&self.provider_block_number);
+		cfg_if::cfg_if! {
+			if #[cfg(feature = "runtime-benchmarks")] {
+				let state_root = provider_header.map(|header| *header.state_root()).unwrap_or_default();
+			} else {
+				let state_root = *provider_header.ok_or(Error::ProviderHeadNotFound)?.state_root();
+			}
+		}
+		Ok(DipDidProofWithVerifiedRelayStateRoot {
+			state_root,
+			dip_commitment_proof: self.dip_commitment_proof,
+			dip_proof: self.dip_proof,
+			signature: self.signature,
+		})
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl<
+		KiltDidKeyId,
+		KiltAccountId,
+		KiltBlockNumber,
+		KiltWeb3Name,
+		KiltLinkableAccountId,
+		ConsumerBlockNumber,
+		Context,
+	> kilt_support::traits::GetWorstCase<Context>
+	for DirectChannelDipDidProof<
+		KiltDidKeyId,
+		KiltAccountId,
+		KiltBlockNumber,
+		KiltWeb3Name,
+		KiltLinkableAccountId,
+		ConsumerBlockNumber,
+	> where
+	KiltDidKeyId: Default + Clone,
+	KiltAccountId: Clone,
+	KiltBlockNumber: Default + Clone,
+	KiltWeb3Name: Clone,
+	KiltLinkableAccountId: Clone,
+	ConsumerBlockNumber: Default,
+	Context: Clone,
+	DidMerkleProof<KiltDidKeyId, KiltAccountId, KiltBlockNumber, KiltWeb3Name, KiltLinkableAccountId>: Decode,
+{
+	fn worst_case(context: Context) -> Self {
+		Self {
+			provider_block_number: KiltBlockNumber::default(),
+			dip_commitment_proof: DipCommitmentStateProof::worst_case(context.clone()),
+			dip_proof: DidMerkleProof::worst_case(context.clone()),
+			signature: TimeBoundDidSignature::worst_case(context),
+		}
+	}
+}
+
 /// A DIP proof that has had the proof header and the relaychain state verified
 /// for the provided relaychain block number.
 ///
@@ -861,23 +1152,15 @@ impl<
 			Error::TooManyLeavesRevealed
 		);
 
-		let proof_leaves_key_value_pairs = self
+		let proof_verification_result = self
 			.dip_proof
-			.revealed
-			.iter()
-			.map(|revealed_leaf| (revealed_leaf.encoded_key(), Some(revealed_leaf.encoded_value())))
-			.collect::<Vec<_>>();
-		let proof_verification_result = verify_trie_proof::<LayoutV1<DidMerkleHasher>, _, _, _>(
-			&self.dip_commitment,
-			self.dip_proof.blinded.as_slice(),
-			proof_leaves_key_value_pairs.as_slice(),
-		);
+			.verify_against_root::<DidMerkleHasher>(&self.dip_commitment);
 
 		cfg_if::cfg_if! {
 			if #[cfg(feature = "runtime-benchmarks")] {
 				drop(proof_verification_result);
 			} else {
-				proof_verification_result.map_err(|_| Error::InvalidDidMerkleProof)?;
+				proof_verification_result?;
 			}
 		}
 		let revealed_leaves = BoundedVec::try_from(self.dip_proof.revealed).map_err(|_| {
@@ -1139,6 +1422,56 @@ impl<
 		self.revealed_leaves.iter()
 	}
 
+	/// Consumes the origin info and returns the revealed DID leaves.
+	pub fn into_revealed_leaves(
+		self,
+	) -> BoundedVec<
+		RevealedDidMerkleProofLeaf<KiltDidKeyId, KiltAccountId, KiltBlockNumber, KiltWeb3Name, KiltLinkableAccountId>,
+		ConstU32<MAX_REVEALED_LEAVES_COUNT>,
+	> {
+		self.revealed_leaves
+	}
+
+	/// Returns the commitment manifest revealed in the proof, if any. Only
+	/// commitments of version `2` contain a manifest.
+	pub fn manifest(&self) -> Option<&RevealedCommitmentManifest> {
+		self.revealed_leaves.iter().find_map(|leaf| match leaf {
+			RevealedDidMerkleProofLeaf::Manifest(manifest) => Some(manifest),
+			_ => None,
+		})
+	}
+
+	/// Returns how many leaves of each type are part of the identity
+	/// commitment but have not been revealed in the proof, or `None` if the
+	/// proof does not reveal the commitment manifest.
+	pub fn omitted_leaves(&self) -> Option<RevealedCommitmentManifest> {
+		let manifest = self.manifest()?;
+		let (revealed_did_keys, revealed_linked_accounts, revealed_web3_name) =
+			self.revealed_leaves
+				.iter()
+				.fold(
+					(0u32, 0u32, false),
+					|(did_keys, linked_accounts, web3_name), leaf| match leaf {
+						RevealedDidMerkleProofLeaf::DidKey(_) => {
+							(did_keys.saturating_add(1), linked_accounts, web3_name)
+						}
+						RevealedDidMerkleProofLeaf::LinkedAccount(_) => {
+							(did_keys, linked_accounts.saturating_add(1), web3_name)
+						}
+						RevealedDidMerkleProofLeaf::Web3Name(_) => (did_keys, linked_accounts, true),
+						RevealedDidMerkleProofLeaf::Manifest(_)
+						| RevealedDidMerkleProofLeaf::AlsoKnownAs(_)
+						| RevealedDidMerkleProofLeaf::Web3NameRecord(_) => (did_keys, linked_accounts, web3_name),
+					},
+				);
+		Some(RevealedCommitmentManifest {
+			did_keys: manifest.did_keys.saturating_sub(revealed_did_keys),
+			linked_accounts: manifest.linked_accounts.saturating_sub(revealed_linked_accounts),
+			web3_name: manifest.web3_name && !revealed_web3_name,
+			scope: manifest.scope,
+		})
+	}
+
 	/// Returns a reference to the leaf that signed the cross-chain operation.
 	/// This operation should never fail, so the only error it returns is an
 	/// `Error::Internal` which, anyway, should never happen.
@@ -1158,6 +1491,128 @@ impl<
 	}
 }
 
+/// A [`DipOriginRequirement`] satisfied by DIP origins that revealed a DID
+/// key with the relationship returned by `Relationship`, e.g., an
+/// authentication key.
+pub struct RevealsDidKey<Relationship>(PhantomData<Relationship>);
+
+impl<
+		Relationship,
+		KiltDidKeyId,
+		KiltAccountId,
+		KiltBlockNumber,
+		KiltWeb3Name,
+		KiltLinkableAccountId,
+		const MAX_REVEALED_LEAVES_COUNT: u32,
+	>
+	DipOriginRequirement<
+		DipOriginInfo<
+			KiltDidKeyId,
+			KiltAccountId,
+			KiltBlockNumber,
+			KiltWeb3Name,
+			KiltLinkableAccountId,
+			MAX_REVEALED_LEAVES_COUNT,
+		>,
+	> for RevealsDidKey<Relationship>
+where
+	Relationship: Get<DidKeyRelationship>,
+{
+	fn is_satisfied_by(
+		details: &DipOriginInfo<
+			KiltDidKeyId,
+			KiltAccountId,
+			KiltBlockNumber,
+			KiltWeb3Name,
+			KiltLinkableAccountId,
+			MAX_REVEALED_LEAVES_COUNT,
+		>,
+	) -> bool {
+		let relationship = Relationship::get();
+		details.iter_leaves().any(
+			|leaf| matches!(leaf, RevealedDidMerkleProofLeaf::DidKey(did_key) if did_key.relationship == relationship),
+		)
+	}
+}
+
+/// A [`DipOriginRequirement`] satisfied by DIP origins that revealed the
+/// web3name of the subject.
+pub struct RevealsWeb3Name;
+
+impl<
+		KiltDidKeyId,
+		KiltAccountId,
+		KiltBlockNumber,
+		KiltWeb3Name,
+		KiltLinkableAccountId,
+		const MAX_REVEALED_LEAVES_COUNT: u32,
+	>
+	DipOriginRequirement<
+		DipOriginInfo<
+			KiltDidKeyId,
+			KiltAccountId,
+			KiltBlockNumber,
+			KiltWeb3Name,
+			KiltLinkableAccountId,
+			MAX_REVEALED_LEAVES_COUNT,
+		>,
+	> for RevealsWeb3Name
+{
+	fn is_satisfied_by(
+		details: &DipOriginInfo<
+			KiltDidKeyId,
+			KiltAccountId,
+			KiltBlockNumber,
+			KiltWeb3Name,
+			KiltLinkableAccountId,
+			MAX_REVEALED_LEAVES_COUNT,
+		>,
+	) -> bool {
+		details
+			.iter_leaves()
+			.any(|leaf| matches!(leaf, RevealedDidMerkleProofLeaf::Web3Name(_)))
+	}
+}
+
+/// A [`DipOriginRequirement`] satisfied by DIP origins that revealed at least
+/// one account linked to the subject.
+pub struct RevealsLinkedAccount;
+
+impl<
+		KiltDidKeyId,
+		KiltAccountId,
+		KiltBlockNumber,
+		KiltWeb3Name,
+		KiltLinkableAccountId,
+		const MAX_REVEALED_LEAVES_COUNT: u32,
+	>
+	DipOriginRequirement<
+		DipOriginInfo<
+			KiltDidKeyId,
+			KiltAccountId,
+			KiltBlockNumber,
+			KiltWeb3Name,
+			KiltLinkableAccountId,
+			MAX_REVEALED_LEAVES_COUNT,
+		>,
+	> for RevealsLinkedAccount
+{
+	fn is_satisfied_by(
+		details: &DipOriginInfo<
+			KiltDidKeyId,
+			KiltAccountId,
+			KiltBlockNumber,
+			KiltWeb3Name,
+			KiltLinkableAccountId,
+			MAX_REVEALED_LEAVES_COUNT,
+		>,
+	) -> bool {
+		details
+			.iter_leaves()
+			.any(|leaf| matches!(leaf, RevealedDidMerkleProofLeaf::LinkedAccount(_)))
+	}
+}
+
 #[cfg(feature = "runtime-benchmarks")]
 impl<
 		KiltDidKeyId,
@@ -1228,11 +1683,14 @@ impl TryFrom<DidKeyRelationship> for DidVerificationKeyRelationship {
 
 /// All possible Merkle leaf types that can be revealed as part of a DIP
 /// identity Merkle proof.
-#[derive(Clone, Copy, Debug, Encode, Decode, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[derive(Clone, Debug, Encode, Decode, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
 pub enum RevealedDidMerkleProofLeaf<KeyId, AccountId, BlockNumber, Web3Name, LinkedAccountId> {
 	DidKey(RevealedDidKey<KeyId, BlockNumber, AccountId>),
 	Web3Name(RevealedWeb3Name<Web3Name, BlockNumber>),
 	LinkedAccount(RevealedAccountId<LinkedAccountId>),
+	Manifest(RevealedCommitmentManifest),
+	AlsoKnownAs(RevealedAlsoKnownAs),
+	Web3NameRecord(RevealedWeb3NameRecord),
 }
 
 impl<KeyId, AccountId, BlockNumber, Web3Name, LinkedAccountId> From<RevealedDidKey<KeyId, BlockNumber, AccountId>>
@@ -1259,6 +1717,30 @@ impl<KeyId, AccountId, BlockNumber, Web3Name, LinkedAccountId> From<RevealedAcco
 	}
 }
 
+impl<KeyId, AccountId, BlockNumber, Web3Name, LinkedAccountId> From<RevealedCommitmentManifest>
+	for RevealedDidMerkleProofLeaf<KeyId, AccountId, BlockNumber, Web3Name, LinkedAccountId>
+{
+	fn from(value: RevealedCommitmentManifest) -> Self {
+		Self::Manifest(value)
+	}
+}
+
+impl<KeyId, AccountId, BlockNumber, Web3Name, LinkedAccountId> From<RevealedAlsoKnownAs>
+	for RevealedDidMerkleProofLeaf<KeyId, AccountId, BlockNumber, Web3Name, LinkedAccountId>
+{
+	fn from(value: RevealedAlsoKnownAs) -> Self {
+		Self::AlsoKnownAs(value)
+	}
+}
+
+impl<KeyId, AccountId, BlockNumber, Web3Name, LinkedAccountId> From<RevealedWeb3NameRecord>
+	for RevealedDidMerkleProofLeaf<KeyId, AccountId, BlockNumber, Web3Name, LinkedAccountId>
+{
+	fn from(value: RevealedWeb3NameRecord) -> Self {
+		Self::Web3NameRecord(value)
+	}
+}
+
 #[cfg(feature = "runtime-benchmarks")]
 impl<KeyId, AccountId, BlockNumber, Web3Name, LinkedAccountId> Default
 	for RevealedDidMerkleProofLeaf<KeyId, AccountId, BlockNumber, Web3Name, LinkedAccountId>
@@ -1292,6 +1774,13 @@ where
 			RevealedDidMerkleProofLeaf::DidKey(RevealedDidKey { id, relationship, .. }) => (id, relationship).encode(),
 			RevealedDidMerkleProofLeaf::Web3Name(RevealedWeb3Name { web3_name, .. }) => web3_name.encode(),
 			RevealedDidMerkleProofLeaf::LinkedAccount(RevealedAccountId(account_id)) => account_id.encode(),
+			RevealedDidMerkleProofLeaf::Manifest(_) => MANIFEST_LEAF_KEY.to_vec(),
+			RevealedDidMerkleProofLeaf::AlsoKnownAs(RevealedAlsoKnownAs(uri)) => {
+				[ALSO_KNOWN_AS_LEAF_KEY_PREFIX, uri.encode().as_slice()].concat()
+			}
+			RevealedDidMerkleProofLeaf::Web3NameRecord(RevealedWeb3NameRecord { key, .. }) => {
+				[WEB3_NAME_RECORD_LEAF_KEY_PREFIX, key.encode().as_slice()].concat()
+			}
 		}
 	}
 }
@@ -1307,6 +1796,9 @@ where
 			RevealedDidMerkleProofLeaf::DidKey(RevealedDidKey { details, .. }) => details.encode(),
 			RevealedDidMerkleProofLeaf::Web3Name(RevealedWeb3Name { claimed_at, .. }) => claimed_at.encode(),
 			RevealedDidMerkleProofLeaf::LinkedAccount(_) => ().encode(),
+			RevealedDidMerkleProofLeaf::Manifest(manifest) => manifest.encode(),
+			RevealedDidMerkleProofLeaf::AlsoKnownAs(_) => ().encode(),
+			RevealedDidMerkleProofLeaf::Web3NameRecord(RevealedWeb3NameRecord { value, .. }) => value.encode(),
 		}
 	}
 }
@@ -1339,3 +1831,70 @@ pub struct RevealedWeb3Name<Web3Name, BlockNumber> {
 /// Merkle proof.
 #[derive(Clone, Copy, Debug, Encode, Decode, PartialEq, Eq, PartialOrd, Ord, TypeInfo, MaxEncodedLen)]
 pub struct RevealedAccountId<AccountId>(pub AccountId);
+
+/// The maximum length of an `alsoKnownAs` URI that can be revealed in a
+/// Merkle proof.
+pub const MAX_ALSO_KNOWN_AS_URI_LENGTH: u32 = 256;
+
+/// An `alsoKnownAs` URI of the subject's DID Document.
+pub type AlsoKnownAsUri = BoundedVec<u8, ConstU32<MAX_ALSO_KNOWN_AS_URI_LENGTH>>;
+
+/// The details of an `alsoKnownAs` URI after it has been successfully verified
+/// in a Merkle proof.
+#[derive(Clone, Debug, Encode, Decode, PartialEq, Eq, PartialOrd, Ord, TypeInfo, MaxEncodedLen)]
+pub struct RevealedAlsoKnownAs(pub AlsoKnownAsUri);
+
+/// The prefix of the names of `alsoKnownAs` leaves, followed by the encoded
+/// URI. Like the manifest leaf, they are not counted in the commitment
+/// manifest.
+pub const ALSO_KNOWN_AS_LEAF_KEY_PREFIX: &[u8] = b"kilt:dip:aka:";
+
+/// The maximum length of a web3name record key that can be revealed in a
+/// Merkle proof.
+pub const MAX_WEB3_NAME_RECORD_KEY_LENGTH: u32 = 32;
+/// The maximum length of a web3name record value that can be revealed in a
+/// Merkle proof.
+pub const MAX_WEB3_NAME_RECORD_VALUE_LENGTH: u32 = 256;
+
+/// The key of a text record of the subject's web3name.
+pub type Web3NameRecordKey = BoundedVec<u8, ConstU32<MAX_WEB3_NAME_RECORD_KEY_LENGTH>>;
+/// The value of a text record of the subject's web3name.
+pub type Web3NameRecordValue = BoundedVec<u8, ConstU32<MAX_WEB3_NAME_RECORD_VALUE_LENGTH>>;
+
+/// The details of a text record of the subject's web3name after it has been
+/// successfully verified in a Merkle proof.
+#[derive(Clone, Debug, Encode, Decode, PartialEq, Eq, PartialOrd, Ord, TypeInfo, MaxEncodedLen)]
+pub struct RevealedWeb3NameRecord {
+	/// The record key, e.g., `avatar` or `url`.
+	pub key: Web3NameRecordKey,
+	/// The record value.
+	pub value: Web3NameRecordValue,
+}
+
+/// The prefix of the names of web3name record leaves, followed by the encoded
+/// record key. They are not counted in the commitment manifest either.
+pub const WEB3_NAME_RECORD_LEAF_KEY_PREFIX: &[u8] = b"kilt:dip:w3n-record:";
+
+/// The name of the manifest leaf. It cannot collide with the names of the
+/// other leaves, since none of them encodes to a 17-byte value starting with
+/// `k`, apart from `alsoKnownAs` and web3name record leaves, which start with
+/// different prefixes.
+pub const MANIFEST_LEAF_KEY: &[u8] = b"kilt:dip:manifest";
+
+/// The content descriptor of an identity commitment, listing how many leaves
+/// of each type the commitment contains. It allows consumers to tell apart a
+/// proof that deliberately omits some identity information from a subject
+/// that has no such information.
+#[derive(Clone, Copy, Debug, Default, Encode, Decode, PartialEq, Eq, PartialOrd, Ord, TypeInfo, MaxEncodedLen)]
+pub struct RevealedCommitmentManifest {
+	/// The number of DID key leaves, one for each key relationship.
+	pub did_keys: u32,
+	/// The number of linked account leaves.
+	pub linked_accounts: u32,
+	/// Whether the commitment contains a web3name leaf.
+	pub web3_name: bool,
+	/// The parts of the identity the subject chose to commit. Leaves outside
+	/// of the scope are never part of the commitment, so they are not counted
+	/// in the fields above.
+	pub scope: IdentityScope,
+}
@@ -0,0 +1,147 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Instant;
+use std::{cell::RefCell, ops::Add, time::Duration};
+
+/// The cost of the DIP proof verification steps observed while running a
+/// verification via [`with_stats`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct VerificationStats {
+	/// The number of trie nodes decoded.
+	pub nodes_decoded: u32,
+	/// The number of bytes hashed, i.e., the size of the decoded trie nodes
+	/// and of the encoded revealed leaves.
+	pub bytes_hashed: u64,
+	/// The number of revealed leaves processed.
+	pub leaves_processed: u32,
+	/// The wall-clock time the verification took. Always zero on `wasm32`
+	/// targets.
+	pub elapsed: Duration,
+}
+
+impl Add for VerificationStats {
+	type Output = Self;
+
+	fn add(self, rhs: Self) -> Self::Output {
+		Self {
+			nodes_decoded: self.nodes_decoded.saturating_add(rhs.nodes_decoded),
+			bytes_hashed: self.bytes_hashed.saturating_add(rhs.bytes_hashed),
+			leaves_processed: self.leaves_processed.saturating_add(rhs.leaves_processed),
+			elapsed: self.elapsed.saturating_add(rhs.elapsed),
+		}
+	}
+}
+
+thread_local! {
+	static STATS: RefCell<Option<VerificationStats>> = RefCell::new(None);
+}
+
+/// Runs the provided verification, e.g., a call to one of the versioned
+/// verifiers, and returns its result together with the stats of all the proof
+/// verification steps it performed.
+///
+/// Calls can be nested, in which case the stats of the inner call are also
+/// accounted for in the outer one.
+pub fn with_stats<R>(verification: impl FnOnce() -> R) -> (R, VerificationStats) {
+	let outer = STATS.with(|stats| stats.replace(Some(VerificationStats::default())));
+
+	let (result, elapsed) = timed(verification);
+
+	let stats = VerificationStats {
+		elapsed,
+		..STATS.with(|stats| stats.take()).unwrap_or_default()
+	};
+	STATS.with(|cell| cell.replace(outer.map(|outer| outer + stats)));
+
+	(result, stats)
+}
+
+// Time is not available in browsers, where the verification is timed by the
+// caller instead.
+#[cfg(not(target_arch = "wasm32"))]
+fn timed<R>(f: impl FnOnce() -> R) -> (R, Duration) {
+	let start = Instant::now();
+	let result = f();
+	(result, start.elapsed())
+}
+
+#[cfg(target_arch = "wasm32")]
+fn timed<R>(f: impl FnOnce() -> R) -> (R, Duration) {
+	(f(), Duration::ZERO)
+}
+
+/// Records the verification of a trie proof made of the provided nodes,
+/// revealing `leaves` leaves with a total encoded size of `leaves_bytes`.
+pub(crate) fn record_trie_proof<'a>(nodes: impl IntoIterator<Item = &'a Vec<u8>>, leaves: usize, leaves_bytes: usize) {
+	STATS.with(|stats| {
+		let mut stats = stats.borrow_mut();
+		let Some(stats) = stats.as_mut() else {
+			return;
+		};
+		for node in nodes {
+			stats.nodes_decoded = stats.nodes_decoded.saturating_add(1);
+			stats.bytes_hashed = stats.bytes_hashed.saturating_add(node.len() as u64);
+		}
+		stats.bytes_hashed = stats.bytes_hashed.saturating_add(leaves_bytes as u64);
+		stats.leaves_processed = stats.leaves_processed.saturating_add(leaves as u32);
+	});
+}
+
+#[cfg(test)]
+mod tests {
+	use sp_runtime::traits::BlakeTwo256;
+
+	use crate::merkle::corpus::{generate_proof, ProofShape};
+
+	use super::*;
+
+	#[test]
+	fn collects_did_merkle_proof_stats() {
+		let (root, proof) = generate_proof(&ProofShape::worst_case()).unwrap();
+
+		let (result, stats) = with_stats(|| proof.verify_against_root::<BlakeTwo256>(&root));
+
+		assert!(result.is_ok());
+		assert_eq!(stats.nodes_decoded as usize, proof.blinded().len());
+		assert_eq!(stats.leaves_processed as usize, proof.revealed().len());
+		assert!(stats.bytes_hashed >= proof.complexity().blinded_bytes as u64);
+	}
+
+	#[test]
+	fn nested_stats_are_accumulated() {
+		let (root, proof) = generate_proof(&ProofShape::worst_case()).unwrap();
+
+		let ((_, inner), outer) = with_stats(|| {
+			let _ = proof.verify_against_root::<BlakeTwo256>(&root);
+			with_stats(|| proof.verify_against_root::<BlakeTwo256>(&root))
+		});
+
+		assert_eq!(outer.nodes_decoded, 2 * inner.nodes_decoded);
+		assert_eq!(outer.leaves_processed, 2 * inner.leaves_processed);
+	}
+
+	#[test]
+	fn nothing_recorded_outside_of_with_stats() {
+		let (root, proof) = generate_proof(&ProofShape::worst_case()).unwrap();
+		let _ = proof.verify_against_root::<BlakeTwo256>(&root);
+
+		STATS.with(|stats| assert!(stats.borrow().is_none()));
+	}
+}
@@ -96,8 +96,17 @@ where
 	OutputOf<MerkleHasher>: Ord,
 {
 	let storage_proof = StorageProof::new(state_proof);
-	let mut revealed_leaves = read_proof_check::<MerkleHasher, _>(state_root, storage_proof, [storage_key].iter())
-		.map_err(|_| MerkleProofError::InvalidProof)?;
+	#[cfg(feature = "metrics")]
+	let proof_nodes = storage_proof.iter_nodes().cloned().collect::<Vec<_>>();
+	let revealed_leaves_result = read_proof_check::<MerkleHasher, _>(state_root, storage_proof, [storage_key].iter());
+	#[cfg(feature = "metrics")]
+	{
+		let revealed_bytes = revealed_leaves_result
+			.as_ref()
+			.map_or(0, |leaves| leaves.values().flatten().map(|leaf| leaf.len()).sum());
+		crate::metrics::record_trie_proof(&proof_nodes, 1, revealed_bytes);
+	}
+	let mut revealed_leaves = revealed_leaves_result.map_err(|_| MerkleProofError::InvalidProof)?;
 
 	debug_assert!(
 		revealed_leaves.len() == 1usize,
@@ -16,8 +16,11 @@
 
 // If you feel like getting in touch with us, you can do so at info@botlabs.org
 
-use sp_core::H256;
-use sp_runtime::traits::{CheckedAdd, One, Zero};
+use sp_core::{Get, H256};
+use sp_runtime::{
+	traits::{CheckedAdd, One, Zero},
+	Saturating,
+};
 use sp_std::marker::PhantomData;
 
 // TODO: Switch to the `Incrementable` trait once it's added to the root of
@@ -99,6 +102,94 @@ impl GetWithoutArg for () {
 	fn get() -> Self::Result {}
 }
 
+/// Implementer of the [`GetWithoutArg`] trait that does not bind DIP proofs
+/// to any specific relaychain, i.e., proofs generated for any relaychain are
+/// accepted as long as they verify against the configured state roots.
+pub struct AnyRelaychain;
+
+impl GetWithoutArg for AnyRelaychain {
+	type Result = Option<H256>;
+
+	fn get() -> Self::Result {
+		None
+	}
+}
+
+/// Implementer of the [`GetWithoutArg`] trait that binds DIP proofs to the
+/// relaychain identified by the genesis hash returned by `GenesisHash`.
+pub struct RelaychainWithGenesisHash<GenesisHash>(PhantomData<GenesisHash>);
+
+impl<GenesisHash> GetWithoutArg for RelaychainWithGenesisHash<GenesisHash>
+where
+	GenesisHash: Get<H256>,
+{
+	type Result = Option<H256>;
+
+	fn get() -> Self::Result {
+		Some(GenesisHash::get())
+	}
+}
+
+/// Implementer of the [`GetWithoutArg`] trait that returns the number of the
+/// latest relaychain block stored in the [`pallet_relay_store::Pallet`]
+/// pallet, if any.
+pub struct LatestRelayBlockViaRelayStorePallet<Runtime>(PhantomData<Runtime>);
+
+impl<Runtime> GetWithoutArg for LatestRelayBlockViaRelayStorePallet<Runtime>
+where
+	Runtime: pallet_relay_store::Config,
+{
+	type Result = Option<u32>;
+
+	fn get() -> Self::Result {
+		pallet_relay_store::Pallet::<Runtime>::latest_relay_block_number()
+	}
+}
+
+/// A trait for types that define how recent the provider state a DIP proof is
+/// anchored to must be for the proof to authorize a given call.
+/// The generic types are the following:
+/// * `Call`: The type of the call being checked.
+/// * `BlockNumber`: The type of the block number the proof is anchored to.
+pub trait DipProofFreshnessPolicy<Call, BlockNumber> {
+	/// Check whether a proof anchored to the provided block is recent enough
+	/// to authorize the given call.
+	fn is_fresh_enough(call: &Call, anchor_block_number: &BlockNumber) -> bool;
+}
+
+impl<Call, BlockNumber> DipProofFreshnessPolicy<Call, BlockNumber> for () {
+	fn is_fresh_enough(_call: &Call, _anchor_block_number: &BlockNumber) -> bool {
+		true
+	}
+}
+
+/// Implementer of the [`DipProofFreshnessPolicy`] trait that maps each call to
+/// the maximum number of blocks the proof anchor can lag behind the latest
+/// known block.
+///
+/// `MaxAnchorAge` returns the maximum age for the category of the given call,
+/// or `None` if proofs of any age are accepted for it. `LatestBlockNumber`
+/// returns the latest known block number. If no latest block is known, only
+/// calls without a maximum age are accepted.
+pub struct MaxAnchorAgePerCall<LatestBlockNumber, MaxAnchorAge>(PhantomData<(LatestBlockNumber, MaxAnchorAge)>);
+
+impl<Call, BlockNumber, LatestBlockNumber, MaxAnchorAge> DipProofFreshnessPolicy<Call, BlockNumber>
+	for MaxAnchorAgePerCall<LatestBlockNumber, MaxAnchorAge>
+where
+	BlockNumber: Saturating + PartialOrd + Copy,
+	LatestBlockNumber: GetWithoutArg<Result = Option<BlockNumber>>,
+	MaxAnchorAge: GetWithArg<Call, Result = Option<BlockNumber>>,
+{
+	fn is_fresh_enough(call: &Call, anchor_block_number: &BlockNumber) -> bool {
+		let Some(max_age) = MaxAnchorAge::get(call) else {
+			return true;
+		};
+		LatestBlockNumber::get().map_or(false, |latest_block_number| {
+			latest_block_number.saturating_sub(*anchor_block_number) <= max_age
+		})
+	}
+}
+
 // Marker trait that requires a type to implement `Default` only for benchmarks.
 // Avoids code duplication.
 #[cfg(not(feature = "runtime-benchmarks"))]
@@ -110,3 +201,45 @@ impl<T> BenchmarkDefault for T {}
 pub trait BenchmarkDefault: Default {}
 #[cfg(feature = "runtime-benchmarks")]
 impl<T: Default> BenchmarkDefault for T {}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	struct Latest;
+
+	impl GetWithoutArg for Latest {
+		type Result = Option<u32>;
+
+		fn get() -> Self::Result {
+			Some(100)
+		}
+	}
+
+	/// Calls `0` must be anchored to the latest block, calls `1` can lag behind
+	/// by up to 10 blocks, and any other call is accepted with any anchor.
+	struct MaxAgeByCall;
+
+	impl GetWithArg<u8> for MaxAgeByCall {
+		type Result = Option<u32>;
+
+		fn get(arg: &u8) -> Self::Result {
+			match arg {
+				0 => Some(0),
+				1 => Some(10),
+				_ => None,
+			}
+		}
+	}
+
+	type Policy = MaxAnchorAgePerCall<Latest, MaxAgeByCall>;
+
+	#[test]
+	fn max_anchor_age_per_call() {
+		assert!(Policy::is_fresh_enough(&0u8, &100u32));
+		assert!(!Policy::is_fresh_enough(&0u8, &99u32));
+		assert!(Policy::is_fresh_enough(&1u8, &90u32));
+		assert!(!Policy::is_fresh_enough(&1u8, &89u32));
+		assert!(Policy::is_fresh_enough(&2u8, &0u32));
+	}
+}
@@ -16,10 +16,12 @@
 
 // If you feel like getting in touch with us, you can do so at info@botlabs.org
 
+use frame_support::weights::Weight;
 use pallet_dip_provider::IdentityCommitmentVersion;
 use parity_scale_codec::{Decode, Encode};
 use scale_info::TypeInfo;
 use sp_core::storage::StorageKey;
+use sp_runtime::{Perbill, SaturatedConversion};
 use sp_std::{fmt::Debug, vec::Vec};
 
 /// The output of a type implementing the [`sp_runtime::traits::Hash`] trait.
@@ -34,6 +36,83 @@ impl<T> BoundedBlindedValue<T> {
 	pub fn into_inner(self) -> Vec<Vec<T>> {
 		self.0
 	}
+
+	/// The complexity of decoding the blinded value.
+	pub fn complexity(&self) -> ProofComplexity {
+		ProofComplexity {
+			leaves: self.0.len().saturated_into(),
+			blinded_bytes: self
+				.0
+				.iter()
+				.fold(0usize, |total, leaf| total.saturating_add(leaf.len()))
+				.saturated_into(),
+		}
+	}
+}
+
+/// The complexity of verifying a proof, in terms of the number of leaves
+/// processed and of blinded bytes decoded.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ProofComplexity {
+	pub leaves: u32,
+	pub blinded_bytes: u32,
+}
+
+impl ProofComplexity {
+	/// The complexity of a proof with at most `leaves` leaves, each of at most
+	/// `leaf_size` bytes.
+	pub fn bounded(leaves: u32, leaf_size: u32) -> Self {
+		Self {
+			leaves,
+			blinded_bytes: leaves.saturating_mul(leaf_size),
+		}
+	}
+
+	/// The complexity of the largest DIP proof accepted given the provided
+	/// limits on the provider head proof, the DIP commitment proof and the DID
+	/// Merkle proof.
+	pub(crate) fn max_dip_proof(
+		max_provider_head_proof_leave_count: u32,
+		max_provider_head_proof_leave_size: u32,
+		max_dip_commitment_proof_leave_count: u32,
+		max_dip_commitment_proof_leave_size: u32,
+		max_did_merkle_proof_leave_count: u32,
+		max_did_merkle_proof_leave_size: u32,
+		max_did_merkle_leaves_revealed: u32,
+	) -> Self {
+		Self::bounded(max_provider_head_proof_leave_count, max_provider_head_proof_leave_size)
+			+ Self::bounded(
+				max_dip_commitment_proof_leave_count,
+				max_dip_commitment_proof_leave_size,
+			) + Self::bounded(max_did_merkle_proof_leave_count, max_did_merkle_proof_leave_size)
+			+ Self {
+				leaves: max_did_merkle_leaves_revealed,
+				blinded_bytes: 0,
+			}
+	}
+
+	/// Scales the weight of verifying a proof of `max` complexity down to the
+	/// complexity of `self`, by the larger of the leaves and blinded bytes
+	/// ratios. The weight is never scaled up.
+	pub fn scale_weight(&self, max: &Self, max_weight: Weight) -> Weight {
+		let ratio = Perbill::from_rational(self.leaves, max.leaves)
+			.max(Perbill::from_rational(self.blinded_bytes, max.blinded_bytes));
+		Weight::from_parts(
+			ratio.mul_ceil(max_weight.ref_time()),
+			ratio.mul_ceil(max_weight.proof_size()),
+		)
+	}
+}
+
+impl sp_std::ops::Add for ProofComplexity {
+	type Output = Self;
+
+	fn add(self, other: Self) -> Self {
+		Self {
+			leaves: self.leaves.saturating_add(other.leaves),
+			blinded_bytes: self.blinded_bytes.saturating_add(other.blinded_bytes),
+		}
+	}
 }
 
 impl<C, T> From<C> for BoundedBlindedValue<T>
@@ -104,3 +183,54 @@ where
 		subject, version,
 	))
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn scale_weight_by_larger_ratio() {
+		let max = ProofComplexity::bounded(10, 100);
+		let max_weight = Weight::from_parts(1_000, 200);
+
+		let few_large_leaves = ProofComplexity {
+			leaves: 2,
+			blinded_bytes: 500,
+		};
+		assert_eq!(
+			few_large_leaves.scale_weight(&max, max_weight),
+			Weight::from_parts(500, 100)
+		);
+
+		let many_small_leaves = ProofComplexity {
+			leaves: 8,
+			blinded_bytes: 100,
+		};
+		assert_eq!(
+			many_small_leaves.scale_weight(&max, max_weight),
+			Weight::from_parts(800, 160)
+		);
+	}
+
+	#[test]
+	fn scale_weight_never_exceeds_max() {
+		let max = ProofComplexity::bounded(10, 100);
+		let max_weight = Weight::from_parts(1_000, 200);
+		let oversized = ProofComplexity::bounded(20, 100);
+
+		assert_eq!(oversized.scale_weight(&max, max_weight), max_weight);
+	}
+
+	#[test]
+	fn blinded_value_complexity() {
+		let value = BoundedBlindedValue::<u8>::from(vec![vec![0u8; 3], vec![0u8; 5]]);
+
+		assert_eq!(
+			value.complexity(),
+			ProofComplexity {
+				leaves: 2,
+				blinded_bytes: 8
+			}
+		);
+	}
+}
@@ -0,0 +1,447 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+use did::KeyIdOf;
+use frame_support::weights::Weight;
+use frame_system::pallet_prelude::{BlockNumberFor, HeaderFor};
+use pallet_did_lookup::linkable_account::LinkableAccountId;
+use pallet_dip_consumer::{traits::IdentityProofVerifier, RuntimeCallOf};
+use pallet_dip_provider::traits::IdentityCommitmentGenerator;
+use pallet_web3_names::Web3NameOf;
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_std::{fmt::Debug, marker::PhantomData};
+
+use crate::{
+	merkle::v0::RevealedDidKey,
+	traits::{DipCallOriginFilter, GetWithArg, GetWithoutArg, Incrementable},
+	utils::ProofComplexity,
+	DipOriginInfo, Error,
+};
+
+/// A KILT-specific DIP identity proof for a consumer that stores the KILT
+/// parachain heads locally, and that supports versioning.
+///
+/// For more info, refer to the version-specific proofs.
+#[derive(Encode, Decode, PartialEq, Eq, Debug, TypeInfo, Clone)]
+pub enum VersionedDirectChannelProof<
+	KiltDidKeyId,
+	KiltAccountId,
+	KiltBlockNumber,
+	KiltWeb3Name,
+	KiltLinkableAccountId,
+	ConsumerBlockNumber,
+> {
+	V0(
+		crate::merkle::v0::DirectChannelDipDidProof<
+			KiltDidKeyId,
+			KiltAccountId,
+			KiltBlockNumber,
+			KiltWeb3Name,
+			KiltLinkableAccountId,
+			ConsumerBlockNumber,
+		>,
+	),
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl<
+		KiltDidKeyId,
+		KiltAccountId,
+		KiltBlockNumber,
+		KiltWeb3Name,
+		KiltLinkableAccountId,
+		ConsumerBlockNumber,
+		Context,
+	> kilt_support::traits::GetWorstCase<Context>
+	for VersionedDirectChannelProof<
+		KiltDidKeyId,
+		KiltAccountId,
+		KiltBlockNumber,
+		KiltWeb3Name,
+		KiltLinkableAccountId,
+		ConsumerBlockNumber,
+	> where
+	KiltDidKeyId: Default + Clone,
+	KiltAccountId: Clone,
+	KiltBlockNumber: Default + Clone,
+	KiltWeb3Name: Clone,
+	KiltLinkableAccountId: Clone,
+	ConsumerBlockNumber: Default,
+	Context: Clone,
+	crate::merkle::v0::DidMerkleProof<
+		KiltDidKeyId,
+		KiltAccountId,
+		KiltBlockNumber,
+		KiltWeb3Name,
+		KiltLinkableAccountId,
+	>: Decode,
+{
+	fn worst_case(context: Context) -> Self {
+		Self::V0(crate::merkle::v0::DirectChannelDipDidProof::worst_case(context))
+	}
+}
+
+pub enum DipDirectChannelProofVerifierError<DidOriginError> {
+	UnsupportedVersion,
+	ProofComponentTooLarge(u8),
+	ProofVerification(Error),
+	DidOriginError(DidOriginError),
+	Internal,
+}
+
+impl<DidOriginError> From<DipDirectChannelProofVerifierError<DidOriginError>> for u16
+where
+	DidOriginError: Into<u8>,
+{
+	fn from(value: DipDirectChannelProofVerifierError<DidOriginError>) -> Self {
+		match value {
+			// DO NOT USE 0
+			// Errors of different sub-parts are separated by a `u8::MAX`.
+			// A value of 0 would make it confusing whether it's the previous sub-part error (u8::MAX)
+			// or the new sub-part error (u8::MAX + 0).
+			DipDirectChannelProofVerifierError::UnsupportedVersion => 1,
+			DipDirectChannelProofVerifierError::ProofComponentTooLarge(component_id) => {
+				u8::MAX as u16 + component_id as u16
+			}
+			DipDirectChannelProofVerifierError::ProofVerification(error) => u8::MAX as u16 * 2 + u8::from(error) as u16,
+			DipDirectChannelProofVerifierError::DidOriginError(error) => u8::MAX as u16 * 3 + error.into() as u16,
+			DipDirectChannelProofVerifierError::Internal => u16::MAX,
+		}
+	}
+}
+
+/// Versioned proof verifier. For version-specific description, refer to each
+/// verifier's documentation.
+pub struct KiltVersionedDirectChannelVerifier<
+	ProviderHeadStore,
+	KiltRuntime,
+	DidCallVerifier,
+	SignedExtra = (),
+	const MAX_DIP_COMMITMENT_PROOF_LEAVE_COUNT: u32 = 64,
+	const MAX_DIP_COMMITMENT_PROOF_LEAVE_SIZE: u32 = 1024,
+	const MAX_DID_MERKLE_PROOF_LEAVE_COUNT: u32 = 64,
+	const MAX_DID_MERKLE_PROOF_LEAVE_SIZE: u32 = 1024,
+	const MAX_DID_MERKLE_LEAVES_REVEALED: u32 = 64,
+>(PhantomData<(ProviderHeadStore, KiltRuntime, DidCallVerifier, SignedExtra)>);
+
+impl<
+		ConsumerRuntime,
+		ProviderHeadStore,
+		KiltRuntime,
+		DidCallVerifier,
+		SignedExtra,
+		const MAX_DIP_COMMITMENT_PROOF_LEAVE_COUNT: u32,
+		const MAX_DIP_COMMITMENT_PROOF_LEAVE_SIZE: u32,
+		const MAX_DID_MERKLE_PROOF_LEAVE_COUNT: u32,
+		const MAX_DID_MERKLE_PROOF_LEAVE_SIZE: u32,
+		const MAX_DID_MERKLE_LEAVES_REVEALED: u32,
+	> IdentityProofVerifier<ConsumerRuntime>
+	for KiltVersionedDirectChannelVerifier<
+		ProviderHeadStore,
+		KiltRuntime,
+		DidCallVerifier,
+		SignedExtra,
+		MAX_DIP_COMMITMENT_PROOF_LEAVE_COUNT,
+		MAX_DIP_COMMITMENT_PROOF_LEAVE_SIZE,
+		MAX_DID_MERKLE_PROOF_LEAVE_COUNT,
+		MAX_DID_MERKLE_PROOF_LEAVE_SIZE,
+		MAX_DID_MERKLE_LEAVES_REVEALED,
+	> where
+	ConsumerRuntime: pallet_dip_consumer::Config<Identifier = KiltRuntime::Identifier>,
+	ConsumerRuntime::LocalIdentityInfo: Incrementable + Default,
+	ProviderHeadStore: GetWithArg<BlockNumberFor<KiltRuntime>, Result = Option<HeaderFor<KiltRuntime>>>,
+	KiltRuntime: frame_system::Config
+		+ pallet_dip_provider::Config
+		+ did::Config
+		+ pallet_web3_names::Config
+		+ pallet_did_lookup::Config,
+	KiltRuntime::IdentityCommitmentGenerator: IdentityCommitmentGenerator<KiltRuntime, Output = KiltRuntime::Hash>,
+	SignedExtra: GetWithoutArg,
+	SignedExtra::Result: Encode,
+	DidCallVerifier: DipCallOriginFilter<
+		RuntimeCallOf<ConsumerRuntime>,
+		OriginInfo = RevealedDidKey<KeyIdOf<KiltRuntime>, BlockNumberFor<KiltRuntime>, KiltRuntime::AccountId>,
+	>,
+	DidCallVerifier::Error: Into<u8>,
+{
+	type Error = DipDirectChannelProofVerifierError<DidCallVerifier::Error>;
+	type Proof = VersionedDirectChannelProof<
+		KeyIdOf<KiltRuntime>,
+		KiltRuntime::AccountId,
+		BlockNumberFor<KiltRuntime>,
+		Web3NameOf<KiltRuntime>,
+		LinkableAccountId,
+		BlockNumberFor<ConsumerRuntime>,
+	>;
+	type VerificationResult = DipOriginInfo<
+		KeyIdOf<KiltRuntime>,
+		KiltRuntime::AccountId,
+		BlockNumberFor<KiltRuntime>,
+		Web3NameOf<KiltRuntime>,
+		LinkableAccountId,
+		MAX_DID_MERKLE_LEAVES_REVEALED,
+	>;
+
+	fn verify_proof_for_call_against_details(
+		call: &RuntimeCallOf<ConsumerRuntime>,
+		subject: &ConsumerRuntime::Identifier,
+		submitter: &ConsumerRuntime::AccountId,
+		identity_details: &mut Option<ConsumerRuntime::LocalIdentityInfo>,
+		proof: Self::Proof,
+	) -> Result<Self::VerificationResult, Self::Error> {
+		match proof {
+			VersionedDirectChannelProof::V0(v0_proof) => <v0::DirectChannelVerifier<
+				ProviderHeadStore,
+				KiltRuntime,
+				DidCallVerifier,
+				SignedExtra,
+				MAX_DIP_COMMITMENT_PROOF_LEAVE_COUNT,
+				MAX_DIP_COMMITMENT_PROOF_LEAVE_SIZE,
+				MAX_DID_MERKLE_PROOF_LEAVE_COUNT,
+				MAX_DID_MERKLE_PROOF_LEAVE_SIZE,
+				MAX_DID_MERKLE_LEAVES_REVEALED,
+			> as IdentityProofVerifier<ConsumerRuntime>>::verify_proof_for_call_against_details(
+				call,
+				subject,
+				submitter,
+				identity_details,
+				v0_proof,
+			),
+		}
+	}
+
+	fn consumed_verification_weight(proof: &Self::Proof, worst_case_weight: Weight) -> Weight {
+		match proof {
+			VersionedDirectChannelProof::V0(v0_proof) => {
+				<v0::DirectChannelVerifier<
+					ProviderHeadStore,
+					KiltRuntime,
+					DidCallVerifier,
+					SignedExtra,
+					MAX_DIP_COMMITMENT_PROOF_LEAVE_COUNT,
+					MAX_DIP_COMMITMENT_PROOF_LEAVE_SIZE,
+					MAX_DID_MERKLE_PROOF_LEAVE_COUNT,
+					MAX_DID_MERKLE_PROOF_LEAVE_SIZE,
+					MAX_DID_MERKLE_LEAVES_REVEALED,
+				> as IdentityProofVerifier<ConsumerRuntime>>::consumed_verification_weight(v0_proof, worst_case_weight)
+			}
+		}
+	}
+}
+
+pub mod v0 {
+	use super::*;
+
+	use frame_support::ensure;
+	use sp_runtime::{traits::Zero, SaturatedConversion};
+
+	use crate::merkle::v0::DirectChannelDipDidProof;
+
+	/// Proof verifier configured given a specific KILT runtime implementation,
+	/// for consumers that do not share a relaychain with KILT and that store
+	/// the KILT parachain heads locally instead.
+	///
+	/// The generic types
+	/// indicate the following:
+	/// * `ProviderHeadStore`: A type providing the KILT parachain headers, as
+	///   stored by the consumer, e.g., as relayed by a trusted bridge or set by
+	///   governance.
+	/// * `KiltRuntime`: A KILT runtime definition.
+	/// * `DidCallVerifier`: Logic to map `RuntimeCall`s to a specific DID key
+	///   relationship. This information is used once the Merkle proof is
+	///   verified, to filter only the revealed keys that match the provided
+	///   relationship.
+	/// * `SignedExtra`: Any additional information that must be signed by the
+	///   DID subject in the cross-chain operation.
+	/// * `MAX_DIP_COMMITMENT_PROOF_LEAVE_COUNT`: The maximum number of leaves
+	///   that can be revealed as part of the DIP commitment storage proof.
+	/// * `MAX_DIP_COMMITMENT_PROOF_LEAVE_SIZE`: The maximum size of each leaf
+	///   revealed as part of the DIP commitment storage proof.
+	/// * `MAX_DID_MERKLE_PROOF_LEAVE_COUNT`: The maximum number of *blinded*
+	///   leaves that can be revealed as part of the DID Merkle proof.
+	/// * `MAX_DID_MERKLE_PROOF_LEAVE_SIZE`: The maximum size of each *blinded*
+	///   leaf revealed as part of the DID Merkle proof.
+	/// * `MAX_DID_MERKLE_LEAVES_REVEALED`: The maximum number of leaves that
+	///   can be revealed as part of the DID Merkle proof.
+	pub struct DirectChannelVerifier<
+		ProviderHeadStore,
+		KiltRuntime,
+		DidCallVerifier,
+		SignedExtra,
+		const MAX_DIP_COMMITMENT_PROOF_LEAVE_COUNT: u32,
+		const MAX_DIP_COMMITMENT_PROOF_LEAVE_SIZE: u32,
+		const MAX_DID_MERKLE_PROOF_LEAVE_COUNT: u32,
+		const MAX_DID_MERKLE_PROOF_LEAVE_SIZE: u32,
+		const MAX_DID_MERKLE_LEAVES_REVEALED: u32,
+	>(PhantomData<(ProviderHeadStore, KiltRuntime, DidCallVerifier, SignedExtra)>);
+
+	impl<
+			ConsumerRuntime,
+			ProviderHeadStore,
+			KiltRuntime,
+			DidCallVerifier,
+			SignedExtra,
+			const MAX_DIP_COMMITMENT_PROOF_LEAVE_COUNT: u32,
+			const MAX_DIP_COMMITMENT_PROOF_LEAVE_SIZE: u32,
+			const MAX_DID_MERKLE_PROOF_LEAVE_COUNT: u32,
+			const MAX_DID_MERKLE_PROOF_LEAVE_SIZE: u32,
+			const MAX_DID_MERKLE_LEAVES_REVEALED: u32,
+		> IdentityProofVerifier<ConsumerRuntime>
+		for DirectChannelVerifier<
+			ProviderHeadStore,
+			KiltRuntime,
+			DidCallVerifier,
+			SignedExtra,
+			MAX_DIP_COMMITMENT_PROOF_LEAVE_COUNT,
+			MAX_DIP_COMMITMENT_PROOF_LEAVE_SIZE,
+			MAX_DID_MERKLE_PROOF_LEAVE_COUNT,
+			MAX_DID_MERKLE_PROOF_LEAVE_SIZE,
+			MAX_DID_MERKLE_LEAVES_REVEALED,
+		> where
+		ConsumerRuntime: pallet_dip_consumer::Config<Identifier = KiltRuntime::Identifier>,
+		ConsumerRuntime::LocalIdentityInfo: Incrementable + Default,
+		ProviderHeadStore: GetWithArg<BlockNumberFor<KiltRuntime>, Result = Option<HeaderFor<KiltRuntime>>>,
+		KiltRuntime: frame_system::Config
+			+ pallet_dip_provider::Config
+			+ did::Config
+			+ pallet_web3_names::Config
+			+ pallet_did_lookup::Config,
+		KiltRuntime::IdentityCommitmentGenerator: IdentityCommitmentGenerator<KiltRuntime, Output = KiltRuntime::Hash>,
+		SignedExtra: GetWithoutArg,
+		SignedExtra::Result: Encode,
+		DidCallVerifier: DipCallOriginFilter<
+			RuntimeCallOf<ConsumerRuntime>,
+			OriginInfo = RevealedDidKey<KeyIdOf<KiltRuntime>, BlockNumberFor<KiltRuntime>, KiltRuntime::AccountId>,
+		>,
+		DidCallVerifier::Error: Into<u8>,
+	{
+		type Error = DipDirectChannelProofVerifierError<DidCallVerifier::Error>;
+		type Proof = DirectChannelDipDidProof<
+			KeyIdOf<KiltRuntime>,
+			KiltRuntime::AccountId,
+			BlockNumberFor<KiltRuntime>,
+			Web3NameOf<KiltRuntime>,
+			LinkableAccountId,
+			BlockNumberFor<ConsumerRuntime>,
+		>;
+		type VerificationResult = DipOriginInfo<
+			KeyIdOf<KiltRuntime>,
+			KiltRuntime::AccountId,
+			BlockNumberFor<KiltRuntime>,
+			Web3NameOf<KiltRuntime>,
+			LinkableAccountId,
+			MAX_DID_MERKLE_LEAVES_REVEALED,
+		>;
+
+		fn verify_proof_for_call_against_details(
+			call: &RuntimeCallOf<ConsumerRuntime>,
+			subject: &<ConsumerRuntime as pallet_dip_consumer::Config>::Identifier,
+			submitter: &<ConsumerRuntime>::AccountId,
+			identity_details: &mut Option<<ConsumerRuntime as pallet_dip_consumer::Config>::LocalIdentityInfo>,
+			proof: Self::Proof,
+		) -> Result<Self::VerificationResult, Self::Error> {
+			// 1. Retrieve the provider parachain head stored by the consumer.
+			let proof_without_provider_head = proof
+				.verify_provider_head::<ProviderHeadStore, HeaderFor<KiltRuntime>>()
+				.map_err(DipDirectChannelProofVerifierError::ProofVerification)?;
+
+			// 2. Verify commitment is included in provider parachain state.
+			ensure!(
+				proof_without_provider_head.dip_commitment_proof.0.len()
+					<= MAX_DIP_COMMITMENT_PROOF_LEAVE_COUNT.saturated_into(),
+				DipDirectChannelProofVerifierError::ProofComponentTooLarge(0)
+			);
+			ensure!(
+				proof_without_provider_head
+					.dip_commitment_proof
+					.0
+					.iter()
+					.all(|l| l.len() <= MAX_DIP_COMMITMENT_PROOF_LEAVE_SIZE.saturated_into()),
+				DipDirectChannelProofVerifierError::ProofComponentTooLarge(1)
+			);
+			let proof_without_parachain = proof_without_provider_head
+				.verify_dip_commitment_proof_for_subject::<KiltRuntime::Hashing, KiltRuntime>(subject)
+				.map_err(DipDirectChannelProofVerifierError::ProofVerification)?;
+
+			// 3. Verify DIP Merkle proof.
+			ensure!(
+				proof_without_parachain.dip_proof.blinded.len() <= MAX_DID_MERKLE_PROOF_LEAVE_COUNT.saturated_into(),
+				DipDirectChannelProofVerifierError::ProofComponentTooLarge(2)
+			);
+			ensure!(
+				proof_without_parachain
+					.dip_proof
+					.blinded
+					.iter()
+					.all(|l| l.len() <= MAX_DID_MERKLE_PROOF_LEAVE_SIZE.saturated_into()),
+				DipDirectChannelProofVerifierError::ProofComponentTooLarge(3)
+			);
+			let proof_without_dip_merkle = proof_without_parachain
+				.verify_dip_proof::<KiltRuntime::Hashing, MAX_DID_MERKLE_LEAVES_REVEALED>()
+				.map_err(DipDirectChannelProofVerifierError::ProofVerification)?;
+
+			// 4. Verify call is signed by one of the DID keys revealed in the proof
+			let current_block_number = frame_system::Pallet::<ConsumerRuntime>::block_number();
+			let consumer_genesis_hash =
+				frame_system::Pallet::<ConsumerRuntime>::block_hash(BlockNumberFor::<ConsumerRuntime>::zero());
+			let signed_extra = SignedExtra::get();
+			let encoded_payload = (
+				call,
+				&identity_details,
+				submitter,
+				proof_without_dip_merkle.signature.valid_until,
+				consumer_genesis_hash,
+				signed_extra,
+			)
+				.encode();
+			let revealed_did_info = proof_without_dip_merkle
+				.verify_signature_time(&current_block_number)
+				.and_then(|p| p.retrieve_signing_leaf_for_payload(&encoded_payload[..]))
+				.map_err(DipDirectChannelProofVerifierError::ProofVerification)?;
+
+			// 5. Verify the signing key fulfills the requirements
+			let signing_key = revealed_did_info
+				.get_signing_leaf()
+				.map_err(DipDirectChannelProofVerifierError::ProofVerification)?;
+			DidCallVerifier::check_call_origin_info(call, signing_key)
+				.map_err(DipDirectChannelProofVerifierError::DidOriginError)?;
+
+			// 6. Increment the local details
+			if let Some(details) = identity_details {
+				details.increment();
+			} else {
+				*identity_details = Some(Default::default());
+			};
+
+			Ok(revealed_did_info)
+		}
+
+		fn consumed_verification_weight(proof: &Self::Proof, worst_case_weight: Weight) -> Weight {
+			let max_complexity =
+				ProofComplexity::bounded(
+					MAX_DIP_COMMITMENT_PROOF_LEAVE_COUNT,
+					MAX_DIP_COMMITMENT_PROOF_LEAVE_SIZE,
+				) + ProofComplexity::bounded(MAX_DID_MERKLE_PROOF_LEAVE_COUNT, MAX_DID_MERKLE_PROOF_LEAVE_SIZE)
+					+ ProofComplexity {
+						leaves: MAX_DID_MERKLE_LEAVES_REVEALED,
+						blinded_bytes: 0,
+					};
+			proof.complexity().scale_weight(&max_complexity, worst_case_weight)
+		}
+	}
+}
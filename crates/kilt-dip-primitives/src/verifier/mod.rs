@@ -16,10 +16,14 @@
 
 // If you feel like getting in touch with us, you can do so at info@botlabs.org
 
+/// Verification logic to integrate a KILT chain whose heads are stored by the
+/// consumer as a DIP provider.
+pub mod direct;
 /// Verification logic to integrate a sibling chain as a DIP provider.
 pub mod parachain;
 /// Verification logic to integrate a child chain as a DIP provider.
 pub mod relaychain;
+pub use direct::{DipDirectChannelProofVerifierError, KiltVersionedDirectChannelVerifier, VersionedDirectChannelProof};
 pub use parachain::{
 	DipParachainStateProofVerifierError, KiltVersionedParachainVerifier, VersionedDipParachainStateProof,
 };
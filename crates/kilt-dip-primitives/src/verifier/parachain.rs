@@ -17,6 +17,7 @@
 // If you feel like getting in touch with us, you can do so at info@botlabs.org
 
 use did::KeyIdOf;
+use frame_support::{ensure, weights::Weight};
 use frame_system::pallet_prelude::{BlockNumberFor, HeaderFor};
 use pallet_did_lookup::linkable_account::LinkableAccountId;
 use pallet_dip_consumer::{traits::IdentityProofVerifier, RuntimeCallOf};
@@ -24,12 +25,13 @@ use pallet_dip_provider::traits::IdentityCommitmentGenerator;
 use pallet_web3_names::Web3NameOf;
 use parity_scale_codec::{Decode, Encode};
 use scale_info::TypeInfo;
+use sp_core::H256;
 use sp_std::{fmt::Debug, marker::PhantomData};
 
 use crate::{
 	merkle::v0::RevealedDidKey,
-	traits::{DipCallOriginFilter, GetWithArg, GetWithoutArg, Incrementable},
-	utils::OutputOf,
+	traits::{AnyRelaychain, DipCallOriginFilter, DipProofFreshnessPolicy, GetWithArg, GetWithoutArg, Incrementable},
+	utils::{OutputOf, ProofComplexity},
 	DipOriginInfo, Error,
 };
 
@@ -58,6 +60,22 @@ pub enum VersionedDipParachainStateProof<
 			ConsumerBlockNumber,
 		>,
 	),
+	/// A V0 proof bound to the relaychain it was generated for, so that
+	/// consumers can reject proofs for a different relaychain before verifying
+	/// any of its components.
+	V1 {
+		/// The genesis hash of the relaychain the proof was generated for.
+		relay_genesis_hash: H256,
+		proof: crate::merkle::v0::ParachainDipDidProof<
+			RelayBlockNumber,
+			KiltDidKeyId,
+			KiltAccountId,
+			KiltBlockNumber,
+			KiltWeb3Name,
+			KiltLinkableAccountId,
+			ConsumerBlockNumber,
+		>,
+	},
 }
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -88,6 +106,13 @@ impl<
 	KiltLinkableAccountId: Clone,
 	ConsumerBlockNumber: Default,
 	Context: Clone,
+	crate::merkle::v0::DidMerkleProof<
+		KiltDidKeyId,
+		KiltAccountId,
+		KiltBlockNumber,
+		KiltWeb3Name,
+		KiltLinkableAccountId,
+	>: Decode,
 {
 	fn worst_case(context: Context) -> Self {
 		Self::V0(crate::merkle::v0::ParachainDipDidProof::worst_case(context))
@@ -96,6 +121,8 @@ impl<
 
 pub enum DipParachainStateProofVerifierError<DidOriginError> {
 	UnsupportedVersion,
+	RelaychainMismatch,
+	ProofTooOld,
 	ProofComponentTooLarge(u8),
 	ProofVerification(Error),
 	DidOriginError(DidOriginError),
@@ -113,6 +140,8 @@ where
 			// A value of 0 would make it confusing whether it's the previous sub-part error (u8::MAX)
 			// or the new sub-part error (u8::MAX + 0).
 			DipParachainStateProofVerifierError::UnsupportedVersion => 1,
+			DipParachainStateProofVerifierError::RelaychainMismatch => 2,
+			DipParachainStateProofVerifierError::ProofTooOld => 3,
 			DipParachainStateProofVerifierError::ProofComponentTooLarge(component_id) => {
 				u8::MAX as u16 + component_id as u16
 			}
@@ -127,6 +156,21 @@ where
 
 /// Versioned proof verifier. For version-specific description, refer to each
 /// verifier's documentation.
+///
+/// The `RelaychainId` generic type returns the genesis hash of the relaychain
+/// proofs must be generated for. If a genesis hash is returned, only proofs
+/// bound to the same relaychain are accepted, and any other proof fails with
+/// [`DipParachainStateProofVerifierError::RelaychainMismatch`]. Relaychain
+/// block numbers and hashing are configured via `RelaychainRuntime`,
+/// independently of both the consumer and the KILT runtimes.
+///
+/// The `ProofFreshnessPolicy` generic type defines how recent the relaychain
+/// block the provider head is proven against must be for the proof to
+/// authorize the dispatched call, so that high-value calls can require proofs
+/// anchored to the latest relaychain blocks while low-value calls tolerate
+/// older ones. Proofs that are too old fail with
+/// [`DipParachainStateProofVerifierError::ProofTooOld`]. By default, proofs of
+/// any age are accepted.
 pub struct KiltVersionedParachainVerifier<
 	RelaychainRuntime,
 	RelaychainStateRootStore,
@@ -134,6 +178,8 @@ pub struct KiltVersionedParachainVerifier<
 	KiltRuntime,
 	DidCallVerifier,
 	SignedExtra = (),
+	RelaychainId = AnyRelaychain,
+	ProofFreshnessPolicy = (),
 	const MAX_PROVIDER_HEAD_PROOF_LEAVE_COUNT: u32 = 64,
 	const MAX_PROVIDER_HEAD_PROOF_LEAVE_SIZE: u32 = 1024,
 	const MAX_DIP_COMMITMENT_PROOF_LEAVE_COUNT: u32 = 64,
@@ -148,6 +194,8 @@ pub struct KiltVersionedParachainVerifier<
 		KiltRuntime,
 		DidCallVerifier,
 		SignedExtra,
+		RelaychainId,
+		ProofFreshnessPolicy,
 	)>,
 );
 
@@ -159,6 +207,8 @@ impl<
 		KiltRuntime,
 		DidCallVerifier,
 		SignedExtra,
+		RelaychainId,
+		ProofFreshnessPolicy,
 		const MAX_PROVIDER_HEAD_PROOF_LEAVE_COUNT: u32,
 		const MAX_PROVIDER_HEAD_PROOF_LEAVE_SIZE: u32,
 		const MAX_DIP_COMMITMENT_PROOF_LEAVE_COUNT: u32,
@@ -174,6 +224,8 @@ impl<
 		KiltRuntime,
 		DidCallVerifier,
 		SignedExtra,
+		RelaychainId,
+		ProofFreshnessPolicy,
 		MAX_PROVIDER_HEAD_PROOF_LEAVE_COUNT,
 		MAX_PROVIDER_HEAD_PROOF_LEAVE_SIZE,
 		MAX_DIP_COMMITMENT_PROOF_LEAVE_COUNT,
@@ -187,15 +239,16 @@ impl<
 	RelaychainRuntime: frame_system::Config,
 	RelaychainStateRootStore:
 		GetWithArg<BlockNumberFor<RelaychainRuntime>, Result = Option<OutputOf<RelaychainRuntime::Hashing>>>,
-	KiltRuntime: frame_system::Config<Hash = RelaychainRuntime::Hash>
+	KiltRuntime: frame_system::Config
 		+ pallet_dip_provider::Config
 		+ did::Config
 		+ pallet_web3_names::Config
 		+ pallet_did_lookup::Config,
-	KiltRuntime::IdentityCommitmentGenerator:
-		IdentityCommitmentGenerator<KiltRuntime, Output = RelaychainRuntime::Hash>,
+	KiltRuntime::IdentityCommitmentGenerator: IdentityCommitmentGenerator<KiltRuntime, Output = KiltRuntime::Hash>,
 	SignedExtra: GetWithoutArg,
 	SignedExtra::Result: Encode,
+	RelaychainId: GetWithoutArg<Result = Option<H256>>,
+	ProofFreshnessPolicy: DipProofFreshnessPolicy<RuntimeCallOf<ConsumerRuntime>, BlockNumberFor<RelaychainRuntime>>,
 	DidCallVerifier: DipCallOriginFilter<
 		RuntimeCallOf<ConsumerRuntime>,
 		OriginInfo = RevealedDidKey<KeyIdOf<KiltRuntime>, BlockNumberFor<KiltRuntime>, KiltRuntime::AccountId>,
@@ -228,36 +281,74 @@ impl<
 		identity_details: &mut Option<ConsumerRuntime::LocalIdentityInfo>,
 		proof: Self::Proof,
 	) -> Result<Self::VerificationResult, Self::Error> {
-		match proof {
-			VersionedDipParachainStateProof::V0(v0_proof) => <v0::ParachainVerifier<
-				RelaychainRuntime,
-				RelaychainStateRootStore,
-				KILT_PARA_ID,
-				KiltRuntime,
-				DidCallVerifier,
-				SignedExtra,
-				MAX_PROVIDER_HEAD_PROOF_LEAVE_COUNT,
-				MAX_PROVIDER_HEAD_PROOF_LEAVE_SIZE,
-				MAX_DIP_COMMITMENT_PROOF_LEAVE_COUNT,
-				MAX_DIP_COMMITMENT_PROOF_LEAVE_SIZE,
-				MAX_DID_MERKLE_PROOF_LEAVE_COUNT,
-				MAX_DID_MERKLE_PROOF_LEAVE_SIZE,
-				MAX_DID_MERKLE_LEAVES_REVEALED,
-			> as IdentityProofVerifier<ConsumerRuntime>>::verify_proof_for_call_against_details(
-				call,
-				subject,
-				submitter,
-				identity_details,
-				v0_proof,
-			),
+		let (relay_genesis_hash, v0_proof) = match proof {
+			VersionedDipParachainStateProof::V0(v0_proof) => (None, v0_proof),
+			VersionedDipParachainStateProof::V1 {
+				relay_genesis_hash,
+				proof,
+			} => (Some(relay_genesis_hash), proof),
+		};
+		// If the consumer is bound to a relaychain, proofs not generated for it are
+		// rejected before any of their components is verified.
+		if let Some(expected_relay_genesis_hash) = RelaychainId::get() {
+			ensure!(
+				relay_genesis_hash == Some(expected_relay_genesis_hash),
+				DipParachainStateProofVerifierError::RelaychainMismatch
+			);
 		}
+		ensure!(
+			ProofFreshnessPolicy::is_fresh_enough(call, &v0_proof.provider_head_proof.relay_block_number),
+			DipParachainStateProofVerifierError::ProofTooOld
+		);
+		<v0::ParachainVerifier<
+			RelaychainRuntime,
+			RelaychainStateRootStore,
+			KILT_PARA_ID,
+			KiltRuntime,
+			DidCallVerifier,
+			SignedExtra,
+			MAX_PROVIDER_HEAD_PROOF_LEAVE_COUNT,
+			MAX_PROVIDER_HEAD_PROOF_LEAVE_SIZE,
+			MAX_DIP_COMMITMENT_PROOF_LEAVE_COUNT,
+			MAX_DIP_COMMITMENT_PROOF_LEAVE_SIZE,
+			MAX_DID_MERKLE_PROOF_LEAVE_COUNT,
+			MAX_DID_MERKLE_PROOF_LEAVE_SIZE,
+			MAX_DID_MERKLE_LEAVES_REVEALED,
+		> as IdentityProofVerifier<ConsumerRuntime>>::verify_proof_for_call_against_details(
+			call,
+			subject,
+			submitter,
+			identity_details,
+			v0_proof,
+		)
+	}
+
+	fn consumed_verification_weight(proof: &Self::Proof, worst_case_weight: Weight) -> Weight {
+		let v0_proof = match proof {
+			VersionedDipParachainStateProof::V0(v0_proof) => v0_proof,
+			VersionedDipParachainStateProof::V1 { proof, .. } => proof,
+		};
+		<v0::ParachainVerifier<
+			RelaychainRuntime,
+			RelaychainStateRootStore,
+			KILT_PARA_ID,
+			KiltRuntime,
+			DidCallVerifier,
+			SignedExtra,
+			MAX_PROVIDER_HEAD_PROOF_LEAVE_COUNT,
+			MAX_PROVIDER_HEAD_PROOF_LEAVE_SIZE,
+			MAX_DIP_COMMITMENT_PROOF_LEAVE_COUNT,
+			MAX_DIP_COMMITMENT_PROOF_LEAVE_SIZE,
+			MAX_DID_MERKLE_PROOF_LEAVE_COUNT,
+			MAX_DID_MERKLE_PROOF_LEAVE_SIZE,
+			MAX_DID_MERKLE_LEAVES_REVEALED,
+		> as IdentityProofVerifier<ConsumerRuntime>>::consumed_verification_weight(v0_proof, worst_case_weight)
 	}
 }
 
 pub mod v0 {
 	use super::*;
 
-	use frame_support::ensure;
 	use sp_runtime::{traits::Zero, SaturatedConversion};
 
 	use crate::merkle::v0::ParachainDipDidProof;
@@ -351,13 +442,12 @@ pub mod v0 {
 		RelaychainRuntime: frame_system::Config,
 		RelaychainStateRootStore:
 			GetWithArg<BlockNumberFor<RelaychainRuntime>, Result = Option<OutputOf<RelaychainRuntime::Hashing>>>,
-		KiltRuntime: frame_system::Config<Hash = RelaychainRuntime::Hash>
+		KiltRuntime: frame_system::Config
 			+ pallet_dip_provider::Config
 			+ did::Config
 			+ pallet_web3_names::Config
 			+ pallet_did_lookup::Config,
-		KiltRuntime::IdentityCommitmentGenerator:
-			IdentityCommitmentGenerator<KiltRuntime, Output = RelaychainRuntime::Hash>,
+		KiltRuntime::IdentityCommitmentGenerator: IdentityCommitmentGenerator<KiltRuntime, Output = KiltRuntime::Hash>,
 		SignedExtra: GetWithoutArg,
 		SignedExtra::Result: Encode,
 		DidCallVerifier: DipCallOriginFilter<
@@ -482,5 +572,18 @@ pub mod v0 {
 
 			Ok(revealed_did_info)
 		}
+
+		fn consumed_verification_weight(proof: &Self::Proof, worst_case_weight: Weight) -> Weight {
+			let max_complexity = ProofComplexity::max_dip_proof(
+				MAX_PROVIDER_HEAD_PROOF_LEAVE_COUNT,
+				MAX_PROVIDER_HEAD_PROOF_LEAVE_SIZE,
+				MAX_DIP_COMMITMENT_PROOF_LEAVE_COUNT,
+				MAX_DIP_COMMITMENT_PROOF_LEAVE_SIZE,
+				MAX_DID_MERKLE_PROOF_LEAVE_COUNT,
+				MAX_DID_MERKLE_PROOF_LEAVE_SIZE,
+				MAX_DID_MERKLE_LEAVES_REVEALED,
+			);
+			proof.complexity().scale_weight(&max_complexity, worst_case_weight)
+		}
 	}
 }
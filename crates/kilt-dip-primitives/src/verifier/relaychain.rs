@@ -17,6 +17,7 @@
 // If you feel like getting in touch with us, you can do so at info@botlabs.org
 
 use did::KeyIdOf;
+use frame_support::weights::Weight;
 use frame_system::pallet_prelude::BlockNumberFor;
 use pallet_did_lookup::linkable_account::LinkableAccountId;
 use pallet_dip_consumer::{traits::IdentityProofVerifier, RuntimeCallOf};
@@ -31,7 +32,7 @@ use sp_std::{fmt::Debug, marker::PhantomData};
 use crate::{
 	merkle::v0::RevealedDidKey,
 	traits::{DipCallOriginFilter, GetWithArg, GetWithoutArg, Incrementable},
-	utils::OutputOf,
+	utils::{OutputOf, ProofComplexity},
 	DipOriginInfo, Error,
 };
 
@@ -207,6 +208,27 @@ impl<
 			),
 		}
 	}
+
+	fn consumed_verification_weight(proof: &Self::Proof, worst_case_weight: Weight) -> Weight {
+		match proof {
+			VersionedRelaychainStateProof::V0(v0_proof) => {
+				<v0::RelaychainVerifier<
+					ConsumerBlockHashStore,
+					KILT_PARA_ID,
+					KiltRuntime,
+					DidCallVerifier,
+					SignedExtra,
+					MAX_PROVIDER_HEAD_PROOF_LEAVE_COUNT,
+					MAX_PROVIDER_HEAD_PROOF_LEAVE_SIZE,
+					MAX_DIP_COMMITMENT_PROOF_LEAVE_COUNT,
+					MAX_DIP_COMMITMENT_PROOF_LEAVE_SIZE,
+					MAX_DID_MERKLE_PROOF_LEAVE_COUNT,
+					MAX_DID_MERKLE_PROOF_LEAVE_SIZE,
+					MAX_DID_MERKLE_LEAVES_REVEALED,
+				> as IdentityProofVerifier<ConsumerRuntime>>::consumed_verification_weight(v0_proof, worst_case_weight)
+			}
+		}
+	}
 }
 
 pub mod v0 {
@@ -425,5 +447,18 @@ pub mod v0 {
 
 			Ok(revealed_did_info)
 		}
+
+		fn consumed_verification_weight(proof: &Self::Proof, worst_case_weight: Weight) -> Weight {
+			let max_complexity = ProofComplexity::max_dip_proof(
+				MAX_PROVIDER_HEAD_PROOF_LEAVE_COUNT,
+				MAX_PROVIDER_HEAD_PROOF_LEAVE_SIZE,
+				MAX_DIP_COMMITMENT_PROOF_LEAVE_COUNT,
+				MAX_DIP_COMMITMENT_PROOF_LEAVE_SIZE,
+				MAX_DID_MERKLE_PROOF_LEAVE_COUNT,
+				MAX_DID_MERKLE_PROOF_LEAVE_SIZE,
+				MAX_DID_MERKLE_LEAVES_REVEALED,
+			);
+			proof.complexity().scale_weight(&max_complexity, worst_case_weight)
+		}
 	}
 }
@@ -0,0 +1,323 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+//! `wasm-bindgen` bindings to decode and verify DID Merkle proofs in browsers.
+//!
+//! The bindings use the same decoding and verification logic as the consumer
+//! runtime verifiers, instantiated with the types of the KILT runtimes. Proofs
+//! and roots are provided as hex strings, with or without the `0x` prefix, and
+//! results are returned as JSON strings.
+
+use did::{
+	did_details::{DidPublicKey, DidPublicKeyDetails},
+	DidVerificationKeyRelationship,
+};
+use pallet_did_lookup::linkable_account::LinkableAccountId;
+use pallet_dip_provider::IdentityScope;
+use parity_scale_codec::DecodeAll;
+use serde::Serialize;
+use sp_core::H256;
+use sp_runtime::{traits::BlakeTwo256, AccountId32};
+use wasm_bindgen::prelude::*;
+
+use crate::merkle::{
+	inspect::inspect_proof,
+	v0::{
+		DidKeyRelationship, DidMerkleProof, RevealedAccountId, RevealedAlsoKnownAs, RevealedCommitmentManifest,
+		RevealedDidKey, RevealedDidMerkleProofLeaf, RevealedWeb3Name, RevealedWeb3NameRecord,
+	},
+};
+
+/// The DID Merkle proof type generated by the KILT runtimes. Web3names are
+/// decoded as raw bytes, which have the same encoding as the web3name type of
+/// the KILT runtimes.
+pub type KiltDidMerkleProof = DidMerkleProof<H256, AccountId32, u64, Vec<u8>, LinkableAccountId>;
+
+type KiltDidMerkleProofLeaf = RevealedDidMerkleProofLeaf<H256, AccountId32, u64, Vec<u8>, LinkableAccountId>;
+
+/// The JSON representation of a revealed leaf.
+#[derive(Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+enum JsonLeaf {
+	#[serde(rename_all = "camelCase")]
+	DidKey {
+		id: H256,
+		relationship: &'static str,
+		public_key: DidPublicKey<AccountId32>,
+		block_number: u64,
+	},
+	#[serde(rename_all = "camelCase")]
+	Web3Name { web3_name: String, claimed_at: u64 },
+	#[serde(rename_all = "camelCase")]
+	LinkedAccount { account: LinkableAccountId },
+	#[serde(rename_all = "camelCase")]
+	Manifest {
+		did_keys: u32,
+		linked_accounts: u32,
+		web3_name: bool,
+		scope: &'static str,
+	},
+	#[serde(rename_all = "camelCase")]
+	AlsoKnownAs { uri: String },
+	#[serde(rename_all = "camelCase")]
+	Web3NameRecord { key: String, value: String },
+}
+
+impl From<&KiltDidMerkleProofLeaf> for JsonLeaf {
+	fn from(leaf: &KiltDidMerkleProofLeaf) -> Self {
+		match leaf {
+			RevealedDidMerkleProofLeaf::DidKey(RevealedDidKey {
+				id,
+				relationship,
+				details: DidPublicKeyDetails { key, block_number },
+			}) => Self::DidKey {
+				id: *id,
+				relationship: relationship_name(relationship),
+				public_key: key.clone(),
+				block_number: *block_number,
+			},
+			RevealedDidMerkleProofLeaf::Web3Name(RevealedWeb3Name { web3_name, claimed_at }) => Self::Web3Name {
+				web3_name: String::from_utf8_lossy(web3_name).into_owned(),
+				claimed_at: *claimed_at,
+			},
+			RevealedDidMerkleProofLeaf::LinkedAccount(RevealedAccountId(account)) => Self::LinkedAccount {
+				account: account.clone(),
+			},
+			RevealedDidMerkleProofLeaf::Manifest(RevealedCommitmentManifest {
+				did_keys,
+				linked_accounts,
+				web3_name,
+				scope,
+			}) => Self::Manifest {
+				did_keys: *did_keys,
+				linked_accounts: *linked_accounts,
+				web3_name: *web3_name,
+				scope: match scope {
+					IdentityScope::KeysOnly => "keysOnly",
+					IdentityScope::KeysAndWeb3Name => "keysAndWeb3Name",
+					IdentityScope::Full => "full",
+				},
+			},
+			RevealedDidMerkleProofLeaf::AlsoKnownAs(RevealedAlsoKnownAs(uri)) => Self::AlsoKnownAs {
+				uri: String::from_utf8_lossy(uri).into_owned(),
+			},
+			RevealedDidMerkleProofLeaf::Web3NameRecord(RevealedWeb3NameRecord { key, value }) => Self::Web3NameRecord {
+				key: String::from_utf8_lossy(key).into_owned(),
+				value: String::from_utf8_lossy(value).into_owned(),
+			},
+		}
+	}
+}
+
+fn relationship_name(relationship: &DidKeyRelationship) -> &'static str {
+	match relationship {
+		DidKeyRelationship::Encryption => "keyAgreement",
+		DidKeyRelationship::Verification(DidVerificationKeyRelationship::Authentication) => "authentication",
+		DidKeyRelationship::Verification(DidVerificationKeyRelationship::CapabilityDelegation) => {
+			"capabilityDelegation"
+		}
+		DidKeyRelationship::Verification(DidVerificationKeyRelationship::CapabilityInvocation) => {
+			"capabilityInvocation"
+		}
+		DidKeyRelationship::Verification(DidVerificationKeyRelationship::AssertionMethod) => "assertionMethod",
+	}
+}
+
+/// The JSON representation of a decoded DID Merkle proof.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonProof {
+	/// The revealed leaves.
+	revealed: Vec<JsonLeaf>,
+	/// The hex-encoded blinded trie nodes.
+	blinded: Vec<String>,
+}
+
+impl From<&KiltDidMerkleProof> for JsonProof {
+	fn from(proof: &KiltDidMerkleProof) -> Self {
+		Self {
+			revealed: proof.revealed().iter().map(JsonLeaf::from).collect(),
+			blinded: proof.blinded().iter().map(|node| to_hex(node)).collect(),
+		}
+	}
+}
+
+/// The JSON representation of the outcome of a proof verification.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonVerification {
+	/// Whether the proof is valid for the provided root.
+	valid: bool,
+	/// Why the proof is invalid, if it is.
+	reason: Option<String>,
+	/// The decoded proof.
+	proof: JsonProof,
+	/// The cost of the verification.
+	#[cfg(feature = "metrics")]
+	stats: JsonVerificationStats,
+}
+
+/// The JSON representation of the cost of a proof verification.
+#[cfg(feature = "metrics")]
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonVerificationStats {
+	nodes_decoded: u32,
+	bytes_hashed: u64,
+	leaves_processed: u32,
+}
+
+#[cfg(feature = "metrics")]
+impl From<crate::metrics::VerificationStats> for JsonVerificationStats {
+	fn from(stats: crate::metrics::VerificationStats) -> Self {
+		Self {
+			nodes_decoded: stats.nodes_decoded,
+			bytes_hashed: stats.bytes_hashed,
+			leaves_processed: stats.leaves_processed,
+		}
+	}
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+	format!("0x{}", hex::encode(bytes))
+}
+
+fn from_hex(input: &str) -> Result<Vec<u8>, String> {
+	hex::decode(input.trim_start_matches("0x")).map_err(|e| format!("Invalid hex input: {e}"))
+}
+
+fn decode_proof(proof: &str) -> Result<KiltDidMerkleProof, String> {
+	let bytes = from_hex(proof)?;
+	KiltDidMerkleProof::decode_all(&mut bytes.as_slice()).map_err(|e| format!("Invalid DID Merkle proof: {e}"))
+}
+
+fn decode_root(root: &str) -> Result<H256, String> {
+	let bytes = from_hex(root)?;
+	if bytes.len() != H256::len_bytes() {
+		return Err(format!("Invalid DID Merkle root length: {} bytes", bytes.len()));
+	}
+	Ok(H256::from_slice(&bytes))
+}
+
+fn to_json<T: Serialize>(value: &T) -> Result<String, String> {
+	serde_json::to_string(value).map_err(|e| format!("Failed to serialize result: {e}"))
+}
+
+/// Decode the provided hex-encoded DID Merkle proof into its JSON
+/// representation.
+pub fn decode_dip_merkle_proof_json(proof: &str) -> Result<String, String> {
+	let proof = decode_proof(proof)?;
+	to_json(&JsonProof::from(&proof))
+}
+
+/// Verify the provided hex-encoded DID Merkle proof against the provided
+/// hex-encoded DID Merkle root, and return the outcome as JSON.
+///
+/// The verification logic is the same used by the consumer runtime verifiers,
+/// except that the number of revealed leaves is not checked. If the proof is
+/// invalid, the reason is taken from the report of
+/// [`inspect_proof`](crate::merkle::inspect::inspect_proof). With the `metrics`
+/// feature, the cost of the verification is included as well.
+pub fn verify_dip_merkle_proof_json(proof: &str, root: &str) -> Result<String, String> {
+	let proof = decode_proof(proof)?;
+	let root = decode_root(root)?;
+
+	#[cfg(feature = "metrics")]
+	let (verification_result, stats) = crate::metrics::with_stats(|| proof.verify_against_root::<BlakeTwo256>(&root));
+	#[cfg(not(feature = "metrics"))]
+	let verification_result = proof.verify_against_root::<BlakeTwo256>(&root);
+
+	let reason = match verification_result {
+		Ok(()) => None,
+		Err(_) => Some(format!(
+			"{:?}",
+			inspect_proof::<BlakeTwo256, _, _, _, _, _>(&proof, &root).outcome
+		)),
+	};
+	to_json(&JsonVerification {
+		valid: reason.is_none(),
+		reason,
+		proof: JsonProof::from(&proof),
+		#[cfg(feature = "metrics")]
+		stats: stats.into(),
+	})
+}
+
+/// Decode the provided hex-encoded DID Merkle proof into its JSON
+/// representation.
+#[wasm_bindgen(js_name = decodeDipMerkleProof)]
+pub fn decode_dip_merkle_proof(proof: &str) -> Result<String, JsError> {
+	decode_dip_merkle_proof_json(proof).map_err(|e| JsError::new(&e))
+}
+
+/// Verify the provided hex-encoded DID Merkle proof against the provided
+/// hex-encoded DID Merkle root, and return the outcome as JSON.
+#[wasm_bindgen(js_name = verifyDipMerkleProof)]
+pub fn verify_dip_merkle_proof(proof: &str, root: &str) -> Result<String, JsError> {
+	verify_dip_merkle_proof_json(proof, root).map_err(|e| JsError::new(&e))
+}
+
+#[cfg(test)]
+mod tests {
+	use parity_scale_codec::Encode;
+
+	use crate::merkle::corpus::{generate_proof, ProofShape};
+
+	use super::*;
+
+	#[test]
+	fn decode_proof_to_json() {
+		let (_, proof) = generate_proof(&ProofShape::worst_case()).unwrap();
+
+		let json = decode_dip_merkle_proof_json(&to_hex(&proof.encode())).unwrap();
+		let decoded: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+		assert_eq!(decoded["revealed"].as_array().unwrap().len(), proof.revealed().len());
+		assert_eq!(decoded["blinded"].as_array().unwrap().len(), proof.blinded().len());
+	}
+
+	#[test]
+	fn verify_valid_proof() {
+		let (root, proof) = generate_proof(&ProofShape::worst_case()).unwrap();
+
+		let json = verify_dip_merkle_proof_json(&to_hex(&proof.encode()), &to_hex(root.as_bytes())).unwrap();
+		let verification: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+		assert_eq!(verification["valid"], true);
+		assert!(verification["reason"].is_null());
+	}
+
+	#[test]
+	fn verify_proof_with_wrong_root() {
+		let (_, proof) = generate_proof(&ProofShape::worst_case()).unwrap();
+
+		let json = verify_dip_merkle_proof_json(&to_hex(&proof.encode()), &to_hex(&[1u8; 32])).unwrap();
+		let verification: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+		assert_eq!(verification["valid"], false);
+		assert!(verification["reason"].as_str().unwrap().starts_with("RootMismatch"));
+	}
+
+	#[test]
+	fn invalid_inputs() {
+		assert!(decode_dip_merkle_proof_json("0xzz").is_err());
+		assert!(decode_dip_merkle_proof_json("0x00ff").is_err());
+		let (_, proof) = generate_proof(&ProofShape::worst_case()).unwrap();
+		assert!(verify_dip_merkle_proof_json(&to_hex(&proof.encode()), "0x0102").is_err());
+	}
+}
@@ -0,0 +1,241 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+//! Primitives for the ZK-friendly DIP identity commitment.
+//!
+//! The ZK-friendly commitment is a fixed-depth binary Merkle tree that uses a
+//! SNARK-friendly hash function over the BN254 scalar field. Each identity
+//! leaf is encoded as a fixed number of field elements, so that membership
+//! can be proven inside a circuit without revealing which leaf is being
+//! proven, e.g., to prove control of one of the keys of a DID without
+//! revealing which one.
+
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+
+use crate::merkle::v0::RevealedDidMerkleProofLeaf;
+
+/// The depth of the ZK-friendly commitment tree. Membership circuits are
+/// compiled for this fixed depth, hence a tree can contain at most `2^DEPTH`
+/// leaves.
+pub const ZK_COMMITMENT_TREE_DEPTH: u32 = 8;
+
+/// The number of bytes of each leaf component. A component is 31 bytes long
+/// so that it always fits in a single BN254 scalar field element.
+pub const ZK_LEAF_COMPONENT_LENGTH: usize = 31;
+
+/// The number of field elements each leaf is encoded into.
+pub const ZK_LEAF_FIELD_ELEMENTS: usize = 3;
+
+/// The kind of identity information a [`ZkLeaf`] commits to.
+#[derive(Clone, Copy, Debug, Encode, Decode, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub enum ZkLeafKind {
+	DidKey = 1,
+	Web3Name = 2,
+	LinkedAccount = 3,
+	Manifest = 4,
+	AlsoKnownAs = 5,
+	Web3NameRecord = 6,
+}
+
+/// A circuit-compatible, fixed-width encoding of a DID Merkle leaf.
+///
+/// Leaf keys and values of arbitrary length are hashed with `blake2_256` and
+/// truncated to [`ZK_LEAF_COMPONENT_LENGTH`] bytes.
+#[derive(Clone, Copy, Debug, Encode, Decode, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct ZkLeaf {
+	/// The kind of leaf.
+	pub kind: ZkLeafKind,
+	/// The truncated hash of the SCALE-encoded leaf key.
+	pub key: [u8; ZK_LEAF_COMPONENT_LENGTH],
+	/// The truncated hash of the SCALE-encoded leaf value.
+	pub value: [u8; ZK_LEAF_COMPONENT_LENGTH],
+}
+
+impl ZkLeaf {
+	/// Returns the leaf as a list of big-endian encoded field elements, in the
+	/// order in which they are hashed.
+	pub fn field_elements(&self) -> [[u8; 32]; ZK_LEAF_FIELD_ELEMENTS] {
+		let mut kind = [0u8; 32];
+		kind[31] = self.kind as u8;
+		[kind, pad_component(&self.key), pad_component(&self.value)]
+	}
+}
+
+fn pad_component(component: &[u8; ZK_LEAF_COMPONENT_LENGTH]) -> [u8; 32] {
+	let mut padded = [0u8; 32];
+	padded[1..].copy_from_slice(component);
+	padded
+}
+
+fn truncated_hash(input: &[u8]) -> [u8; ZK_LEAF_COMPONENT_LENGTH] {
+	let mut truncated = [0u8; ZK_LEAF_COMPONENT_LENGTH];
+	truncated.copy_from_slice(&sp_io::hashing::blake2_256(input)[..ZK_LEAF_COMPONENT_LENGTH]);
+	truncated
+}
+
+impl<KeyId, AccountId, BlockNumber, Web3Name, LinkedAccountId>
+	From<&RevealedDidMerkleProofLeaf<KeyId, AccountId, BlockNumber, Web3Name, LinkedAccountId>> for ZkLeaf
+where
+	KeyId: Encode,
+	AccountId: Encode,
+	BlockNumber: Encode,
+	Web3Name: Encode,
+	LinkedAccountId: Encode,
+{
+	fn from(leaf: &RevealedDidMerkleProofLeaf<KeyId, AccountId, BlockNumber, Web3Name, LinkedAccountId>) -> Self {
+		let kind = match leaf {
+			RevealedDidMerkleProofLeaf::DidKey(_) => ZkLeafKind::DidKey,
+			RevealedDidMerkleProofLeaf::Web3Name(_) => ZkLeafKind::Web3Name,
+			RevealedDidMerkleProofLeaf::LinkedAccount(_) => ZkLeafKind::LinkedAccount,
+			RevealedDidMerkleProofLeaf::Manifest(_) => ZkLeafKind::Manifest,
+			RevealedDidMerkleProofLeaf::AlsoKnownAs(_) => ZkLeafKind::AlsoKnownAs,
+			RevealedDidMerkleProofLeaf::Web3NameRecord(_) => ZkLeafKind::Web3NameRecord,
+		};
+		Self {
+			kind,
+			key: truncated_hash(&leaf.encoded_key()),
+			value: truncated_hash(&leaf.encoded_value()),
+		}
+	}
+}
+
+/// A Groth16 proof over BN254, with all points in uncompressed big-endian
+/// encoding.
+#[derive(Clone, Debug, Encode, Decode, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct Groth16Proof {
+	pub a: [u8; 64],
+	pub b: [u8; 128],
+	pub c: [u8; 64],
+}
+
+/// The public inputs of a ZK membership proof.
+#[derive(Clone, Debug, Encode, Decode, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct ZkMembershipStatement {
+	/// The ZK-friendly identity commitment, as stored on the provider chain.
+	pub commitment: [u8; 32],
+	/// The kind of leaf whose membership is proven.
+	pub leaf_kind: ZkLeafKind,
+	/// The hash of the payload that the key in the hidden leaf has signed.
+	pub payload_hash: [u8; 32],
+}
+
+impl ZkMembershipStatement {
+	/// Returns the statement as a list of big-endian encoded field elements, in
+	/// the order expected by the membership circuit.
+	pub fn public_inputs(&self) -> [[u8; 32]; 3] {
+		let mut leaf_kind = [0u8; 32];
+		leaf_kind[31] = self.leaf_kind as u8;
+		let mut payload_hash = [0u8; 32];
+		payload_hash[1..].copy_from_slice(&self.payload_hash[..ZK_LEAF_COMPONENT_LENGTH]);
+		[self.commitment, leaf_kind, payload_hash]
+	}
+}
+
+/// A verifier of Groth16 proofs for the ZK membership circuit.
+pub trait Groth16Verifier {
+	fn verify(public_inputs: &[[u8; 32]], proof: &Groth16Proof) -> bool;
+}
+
+/// A [`Groth16Verifier`] that rejects every proof. Runtimes can use it as a
+/// placeholder until a verifying key and a pairing implementation are
+/// available.
+pub struct RejectAllGroth16Proofs;
+
+impl Groth16Verifier for RejectAllGroth16Proofs {
+	fn verify(_public_inputs: &[[u8; 32]], _proof: &Groth16Proof) -> bool {
+		false
+	}
+}
+
+#[derive(Debug, PartialEq, Eq, TypeInfo)]
+pub enum ZkMembershipError {
+	InvalidProof,
+}
+
+impl From<ZkMembershipError> for u8 {
+	fn from(value: ZkMembershipError) -> Self {
+		match value {
+			// DO NOT USE 0
+			ZkMembershipError::InvalidProof => 1,
+		}
+	}
+}
+
+/// Verifies that the Groth16 proof attests the membership of a leaf of the
+/// given kind in the ZK-friendly commitment, without revealing the leaf.
+pub fn verify_zk_membership<Verifier>(
+	statement: &ZkMembershipStatement,
+	proof: &Groth16Proof,
+) -> Result<(), ZkMembershipError>
+where
+	Verifier: Groth16Verifier,
+{
+	frame_support::ensure!(
+		Verifier::verify(&statement.public_inputs(), proof),
+		ZkMembershipError::InvalidProof
+	);
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	use crate::merkle::v0::{RevealedAccountId, RevealedWeb3Name};
+
+	type Leaf = RevealedDidMerkleProofLeaf<u32, u64, u64, sp_std::vec::Vec<u8>, u64>;
+
+	#[test]
+	fn leaf_field_elements_fit_in_field() {
+		let leaf = ZkLeaf::from(&Leaf::from(RevealedAccountId(42)));
+
+		assert_eq!(leaf.kind, ZkLeafKind::LinkedAccount);
+		assert!(leaf.field_elements().iter().all(|element| element[0] == 0));
+	}
+
+	#[test]
+	fn leaf_encoding_distinguishes_kinds() {
+		let web3_name = ZkLeaf::from(&Leaf::from(RevealedWeb3Name {
+			web3_name: 42u64.encode(),
+			claimed_at: 0,
+		}));
+		let account = ZkLeaf::from(&Leaf::from(RevealedAccountId(42)));
+
+		assert_ne!(web3_name.field_elements(), account.field_elements());
+	}
+
+	#[test]
+	fn reject_all_verifier_rejects() {
+		let statement = ZkMembershipStatement {
+			commitment: [1u8; 32],
+			leaf_kind: ZkLeafKind::DidKey,
+			payload_hash: [2u8; 32],
+		};
+		let proof = Groth16Proof {
+			a: [0u8; 64],
+			b: [0u8; 128],
+			c: [0u8; 64],
+		};
+
+		assert_eq!(
+			verify_zk_membership::<RejectAllGroth16Proofs>(&statement, &proof),
+			Err(ZkMembershipError::InvalidProof)
+		);
+	}
+}
@@ -19,18 +19,16 @@
 use cumulus_primitives_core::ParaId;
 use dip_consumer_runtime_template::{
 	AccountId, AuraId, BalancesConfig, CollatorSelectionConfig, ParachainInfoConfig, RuntimeGenesisConfig,
-	SessionConfig, SessionKeys, Signature, SudoConfig, SystemConfig, EXISTENTIAL_DEPOSIT, SS58_PREFIX, WASM_BINARY,
+	SessionConfig, SessionKeys, SudoConfig, SystemConfig, EXISTENTIAL_DEPOSIT, SS58_PREFIX, WASM_BINARY,
 };
 use sc_chain_spec::{ChainSpecExtension, ChainSpecGroup, Properties};
 use sc_service::{ChainType, GenericChainSpec};
 use serde::{Deserialize, Serialize};
-use sp_core::{sr25519, Pair, Public};
-use sp_runtime::traits::{IdentifyAccount, Verify};
+use sp_core::{Pair, Public};
 
 const PARA_ID: u32 = 2_001;
 
 pub type ChainSpec = GenericChainSpec<RuntimeGenesisConfig, Extensions>;
-type AccountPublic = <Signature as Verify>::Signer;
 
 pub(crate) fn get_from_seed<TPublic: Public>(seed: &str) -> <TPublic::Pair as Pair>::Public {
 	TPublic::Pair::from_string(&format!("//{}", seed), None)
@@ -55,11 +53,22 @@ pub fn get_collator_keys_from_seed(seed: &str) -> AuraId {
 	get_from_seed::<AuraId>(seed)
 }
 
-pub fn get_account_id_from_seed<TPublic: Public>(seed: &str) -> AccountId
-where
-	AccountPublic: From<<TPublic::Pair as Pair>::Public>,
-{
-	AccountPublic::from(get_from_seed::<TPublic>(seed)).into_account()
+#[cfg(not(feature = "ethereum-accounts"))]
+pub fn get_account_id_from_seed(seed: &str) -> AccountId {
+	use dip_consumer_runtime_template::Signature;
+	use sp_core::sr25519;
+	use sp_runtime::traits::{IdentifyAccount, Verify};
+
+	<Signature as Verify>::Signer::from(get_from_seed::<sr25519::Public>(seed)).into_account()
+}
+
+#[cfg(feature = "ethereum-accounts")]
+pub fn get_account_id_from_seed(seed: &str) -> AccountId {
+	use dip_consumer_runtime_template::account::account_of;
+	use sp_core::ecdsa;
+
+	let pair = ecdsa::Pair::from_string(&format!("//{}", seed), None).expect("static values are valid; qed");
+	account_of(&pair)
 }
 
 pub fn template_session_keys(keys: AuraId) -> SessionKeys {
@@ -103,6 +112,7 @@ fn testnet_genesis(
 		},
 		aura: Default::default(),
 		aura_ext: Default::default(),
+		dip_consumer: Default::default(),
 	}
 }
 
@@ -118,17 +128,14 @@ pub fn development_config() -> ChainSpec {
 		ChainType::Development,
 		move || {
 			testnet_genesis(
-				vec![(
-					get_account_id_from_seed::<sr25519::Public>("Alice"),
-					get_collator_keys_from_seed("Alice"),
-				)],
+				vec![(get_account_id_from_seed("Alice"), get_collator_keys_from_seed("Alice"))],
 				vec![
-					get_account_id_from_seed::<sr25519::Public>("Alice"),
-					get_account_id_from_seed::<sr25519::Public>("Alice//stash"),
-					get_account_id_from_seed::<sr25519::Public>("Bob"),
-					get_account_id_from_seed::<sr25519::Public>("Bob//stash"),
-					get_account_id_from_seed::<sr25519::Public>("Charlie"),
-					get_account_id_from_seed::<sr25519::Public>("Charlie//stash"),
+					get_account_id_from_seed("Alice"),
+					get_account_id_from_seed("Alice//stash"),
+					get_account_id_from_seed("Bob"),
+					get_account_id_from_seed("Bob//stash"),
+					get_account_id_from_seed("Charlie"),
+					get_account_id_from_seed("Charlie//stash"),
 				],
 				PARA_ID.into(),
 			)
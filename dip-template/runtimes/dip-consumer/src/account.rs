@@ -0,0 +1,145 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+//! Ethereum-style accounts for the DIP consumer template, enabled with the
+//! `ethereum-accounts` feature.
+//!
+//! Accounts are 20 bytes long and are derived from a secp256k1 public key as
+//! the last 20 bytes of the Keccak-256 hash of its uncompressed
+//! representation. Extrinsic signatures are ECDSA signatures over the
+//! Keccak-256 hash of the signed payload, which is the same account model used
+//! by EVM-centric parachains.
+
+use frame_support::sp_io::crypto::secp256k1_ecdsa_recover;
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_core::{ecdsa, keccak_256, RuntimeDebug, H160};
+use sp_runtime::traits::{IdentifyAccount, Lazy, Verify};
+use sp_std::fmt;
+
+/// A 20-byte Ethereum-style account identifier.
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Encode, Decode, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(
+	feature = "std",
+	derive(sp_runtime::serde::Serialize, sp_runtime::serde::Deserialize)
+)]
+#[cfg_attr(feature = "std", serde(crate = "sp_runtime::serde"))]
+pub struct AccountId20(pub H160);
+
+impl AccountId20 {
+	/// Derive the account from a 64-byte uncompressed secp256k1 public key,
+	/// without the leading `0x04` tag.
+	pub fn from_uncompressed_public_key(public_key: &[u8; 64]) -> Self {
+		Self(H160::from_slice(&keccak_256(public_key)[12..]))
+	}
+}
+
+impl fmt::Debug for AccountId20 {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{:?}", self.0)
+	}
+}
+
+impl fmt::Display for AccountId20 {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{:?}", self.0)
+	}
+}
+
+impl From<[u8; 20]> for AccountId20 {
+	fn from(value: [u8; 20]) -> Self {
+		Self(H160(value))
+	}
+}
+
+impl From<H160> for AccountId20 {
+	fn from(value: H160) -> Self {
+		Self(value)
+	}
+}
+
+impl From<AccountId20> for [u8; 20] {
+	fn from(value: AccountId20) -> Self {
+		value.0 .0
+	}
+}
+
+impl AsRef<[u8]> for AccountId20 {
+	fn as_ref(&self) -> &[u8] {
+		self.0.as_bytes()
+	}
+}
+
+/// The signer of an [`EthereumSignature`], identified directly by its
+/// [`AccountId20`].
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Encode, Decode, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+pub struct EthereumSigner(AccountId20);
+
+impl IdentifyAccount for EthereumSigner {
+	type AccountId = AccountId20;
+
+	fn into_account(self) -> Self::AccountId {
+		self.0
+	}
+}
+
+impl From<AccountId20> for EthereumSigner {
+	fn from(value: AccountId20) -> Self {
+		Self(value)
+	}
+}
+
+/// An ECDSA signature over the Keccak-256 hash of the signed payload.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, TypeInfo, RuntimeDebug)]
+pub struct EthereumSignature(pub ecdsa::Signature);
+
+impl From<ecdsa::Signature> for EthereumSignature {
+	fn from(value: ecdsa::Signature) -> Self {
+		Self(value)
+	}
+}
+
+impl Verify for EthereumSignature {
+	type Signer = EthereumSigner;
+
+	fn verify<L: Lazy<[u8]>>(&self, mut msg: L, signer: &AccountId20) -> bool {
+		let message_hash = keccak_256(msg.get());
+		secp256k1_ecdsa_recover(self.0.as_ref(), &message_hash)
+			.map(|public_key| AccountId20::from_uncompressed_public_key(&public_key) == *signer)
+			.unwrap_or(false)
+	}
+}
+
+/// Sign the provided payload the way [`EthereumSignature`] expects it, i.e.,
+/// by signing its Keccak-256 hash.
+#[cfg(feature = "std")]
+pub fn sign(pair: &ecdsa::Pair, payload: &[u8]) -> EthereumSignature {
+	EthereumSignature(pair.sign_prehashed(&keccak_256(payload)))
+}
+
+/// Return the [`AccountId20`] controlled by the provided ECDSA key pair.
+#[cfg(feature = "std")]
+pub fn account_of(pair: &ecdsa::Pair) -> AccountId20 {
+	// The compressed public key cannot be expanded without an additional
+	// secp256k1 dependency, so recover the uncompressed one from a signature.
+	let message_hash = keccak_256(b"kilt:dip-consumer:account");
+	let signature = pair.sign_prehashed(&message_hash);
+	let public_key = secp256k1_ecdsa_recover(signature.as_ref(), &message_hash)
+		.expect("A freshly generated signature can always be recovered.");
+	AccountId20::from_uncompressed_public_key(&public_key)
+}
@@ -18,38 +18,79 @@
 
 use did::{DidVerificationKeyRelationship, KeyIdOf};
 use dip_provider_runtime_template::{AccountId as ProviderAccountId, Runtime as ProviderRuntime};
-use frame_support::traits::Contains;
+use frame_support::{parameter_types, traits::Contains};
 use frame_system::{pallet_prelude::BlockNumberFor, EnsureSigned};
 use kilt_dip_primitives::{
-	traits::DipCallOriginFilter, KiltVersionedParachainVerifier, RelayStateRootsViaRelayStorePallet, RevealedDidKey,
+	traits::{AnyRelaychain, DipCallOriginFilter, GetWithArg},
+	KiltVersionedParachainVerifier, LatestRelayBlockViaRelayStorePallet, MaxAnchorAgePerCall,
+	RelayStateRootsViaRelayStorePallet, RevealedDidKey,
 };
 use pallet_dip_consumer::traits::IdentityProofVerifier;
 use rococo_runtime::Runtime as RelaychainRuntime;
 use sp_core::ConstU32;
 use sp_std::marker::PhantomData;
 
-use crate::{weights, AccountId, DidIdentifier, Runtime, RuntimeCall, RuntimeOrigin};
+use crate::{weights, AccountId, BlockNumber, DidIdentifier, Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin, HOURS};
 
 pub type MerkleProofVerifierOutput = <ProofVerifier as IdentityProofVerifier<Runtime>>::VerificationResult;
 /// The verifier logic assumes the provider is a sibling KILT parachain, the relaychain is a Rococo relaychain, and
 /// that a KILT subject can provide DIP proof that reveal at most 10 DID keys
 /// and 10 linked accounts (defaults provided by the
 /// `KiltVersionedParachainVerifier` type). Calls that do not pass the
-/// [`DipCallFilter`] will be discarded early on in the verification process.
+/// [`DipCallFilter`] will be discarded early on in the verification process,
+/// and proofs that are too old for the call according to
+/// [`DipProofMaxAnchorAge`] are rejected.
 pub type ProofVerifier = KiltVersionedParachainVerifier<
 	RelaychainRuntime,
 	RelayStateRootsViaRelayStorePallet<Runtime>,
 	2_000,
 	ProviderRuntime,
 	DipCallFilter<KeyIdOf<ProviderRuntime>, BlockNumberFor<ProviderRuntime>, ProviderAccountId>,
+	(),
+	AnyRelaychain,
+	MaxAnchorAgePerCall<LatestRelayBlockViaRelayStorePallet<Runtime>, DipProofMaxAnchorAge>,
 >;
 
+/// The maximum number of relaychain blocks the provider head used in a DIP
+/// proof can lag behind the latest relaychain block known to this chain.
+///
+/// New posts must be authorized with a proof anchored to one of the last 5
+/// relaychain blocks, since they publish content on behalf of the subject.
+/// Comments and likes tolerate proofs as old as the relaychain state roots
+/// stored by the [`pallet_relay_store::Pallet`] pallet. Batches are subject to
+/// the strictest limit among their calls.
+pub struct DipProofMaxAnchorAge;
+
+impl GetWithArg<RuntimeCall> for DipProofMaxAnchorAge {
+	type Result = Option<u32>;
+
+	fn get(call: &RuntimeCall) -> Self::Result {
+		match call {
+			RuntimeCall::PostIt(pallet_postit::Call::post { .. }) => Some(5),
+			RuntimeCall::PostIt(_) => None,
+			RuntimeCall::Utility(pallet_utility::Call::batch { calls })
+			| RuntimeCall::Utility(pallet_utility::Call::batch_all { calls })
+			| RuntimeCall::Utility(pallet_utility::Call::force_batch { calls }) => calls.iter().filter_map(Self::get).min(),
+			_ => None,
+		}
+	}
+}
+
+parameter_types! {
+	// Identity entries of subjects that have not submitted a DIP transaction for
+	// 30 days are removed. Since removing an entry resets the subject's nonce, the
+	// lifetime is chosen well beyond the validity DIP signatures are expected to
+	// have.
+	pub const DipEntryLifetime: Option<BlockNumber> = Some(30 * 24 * HOURS);
+}
+
 impl pallet_dip_consumer::Config for Runtime {
 	type DipCallOriginFilter = PreliminaryDipOriginFilter;
 	// Any signed origin can submit a cross-chain DIP tx, since subject
 	// authentication (and optional binding to the tx submitter) is performed in the
 	// DIP proof verification step.
 	type DispatchOriginCheck = EnsureSigned<AccountId>;
+	type EntryLifetime = DipEntryLifetime;
 	type Identifier = DidIdentifier;
 	// Local identity info contains a simple `u128` representing a nonce. This means
 	// that two cross-chain operations targeting the same chain and with the same
@@ -57,6 +98,7 @@ impl pallet_dip_consumer::Config for Runtime {
 	type LocalIdentityInfo = u128;
 	type ProofVerifier = ProofVerifier;
 	type RuntimeCall = RuntimeCall;
+	type RuntimeEvent = RuntimeEvent;
 	type RuntimeOrigin = RuntimeOrigin;
 	type WeightInfo = weights::pallet_dip_consumer::WeightInfo<Runtime>;
 }
@@ -34,6 +34,7 @@
 include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
 
 use dip_provider_runtime_template::Web3Name;
+use kilt_dip_primitives::fees::CappedProofLengthFee;
 pub use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 pub use sp_runtime::{MultiAddress, Perbill, Permill};
 
@@ -65,15 +66,21 @@ use sp_api::impl_runtime_apis;
 use sp_consensus_aura::SlotDuration;
 use sp_core::{crypto::KeyTypeId, ConstBool, ConstU128, ConstU16, OpaqueMetadata};
 use sp_inherents::{CheckInherentsResult, InherentData};
+#[cfg(feature = "ethereum-accounts")]
+use sp_runtime::traits::IdentityLookup;
 use sp_runtime::{
 	create_runtime_str, generic, impl_opaque_keys,
-	traits::{AccountIdLookup, BlakeTwo256, Block as BlockT, OpaqueKeys},
+	traits::{BlakeTwo256, Block as BlockT, OpaqueKeys},
 	transaction_validity::{TransactionSource, TransactionValidity},
-	AccountId32, ApplyExtrinsicResult, MultiSignature, OpaqueExtrinsic,
+	ApplyExtrinsicResult, OpaqueExtrinsic,
 };
+#[cfg(not(feature = "ethereum-accounts"))]
+use sp_runtime::{traits::AccountIdLookup, AccountId32, MultiSignature};
 use sp_std::{prelude::*, time::Duration};
 use sp_version::RuntimeVersion;
 
+#[cfg(feature = "ethereum-accounts")]
+pub mod account;
 mod dip;
 mod origin_adapter;
 mod weights;
@@ -85,17 +92,28 @@ pub use sp_runtime::BuildStorage;
 #[cfg(feature = "std")]
 use sp_version::NativeVersion;
 
+#[cfg(not(feature = "ethereum-accounts"))]
 pub type AccountId = AccountId32;
+#[cfg(feature = "ethereum-accounts")]
+pub type AccountId = account::AccountId20;
+#[cfg(not(feature = "ethereum-accounts"))]
 pub type Address = MultiAddress<AccountId, ()>;
+#[cfg(feature = "ethereum-accounts")]
+pub type Address = AccountId;
 pub type Balance = u128;
 pub type Block = generic::Block<Header, UncheckedExtrinsic>;
 pub type BlockNumber = u64;
-pub type DidIdentifier = AccountId;
+// DIDs are always resolved against the provider, so the identifier does not
+// change with the local account model.
+pub type DidIdentifier = dip_provider_runtime_template::DidIdentifier;
 pub type Hasher = BlakeTwo256;
 pub type Hash = sp_core::H256;
 pub type Header = generic::Header<BlockNumber, Hasher>;
 pub type Nonce = u64;
+#[cfg(not(feature = "ethereum-accounts"))]
 pub type Signature = MultiSignature;
+#[cfg(feature = "ethereum-accounts")]
+pub type Signature = account::EthereumSignature;
 
 pub type SignedExtra = (
 	frame_system::CheckNonZeroSender<Runtime>,
@@ -235,7 +253,10 @@ impl frame_system::Config for Runtime {
 	type DbWeight = RocksDbWeight;
 	type Hash = Hash;
 	type Hashing = BlakeTwo256;
+	#[cfg(not(feature = "ethereum-accounts"))]
 	type Lookup = AccountIdLookup<AccountId, ()>;
+	#[cfg(feature = "ethereum-accounts")]
+	type Lookup = IdentityLookup<AccountId>;
 	type MaxConsumers = ConstU32<16>;
 	type Nonce = u64;
 	type OnKilledAccount = ();
@@ -302,8 +323,14 @@ impl pallet_balances::Config for Runtime {
 	type WeightInfo = ();
 }
 
+/// The maximum number of bytes of a DIP proof that are charged the length fee.
+/// The verification of the proof is already paid for via the weight of the
+/// `dispatch_as` call.
+pub const MAX_CHARGED_DIP_PROOF_BYTES: u32 = 1_024;
+
 impl pallet_transaction_payment::Config for Runtime {
-	type OnChargeTransaction = CurrencyAdapter<Balances, ()>;
+	type OnChargeTransaction =
+		CappedProofLengthFee<CurrencyAdapter<Balances, ()>, ConstU32<MAX_CHARGED_DIP_PROOF_BYTES>>;
 	type FeeMultiplierUpdate = ();
 	type LengthToFee = IdentityFee<Balance>;
 	type OperationalFeeMultiplier = ConstU8<1>;
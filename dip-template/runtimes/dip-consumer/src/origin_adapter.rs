@@ -18,8 +18,8 @@
 
 use crate::{AccountId, DidIdentifier, MerkleProofVerifierOutput, RuntimeOrigin, Web3Name};
 use frame_support::traits::EnsureOrigin;
-use kilt_dip_primitives::RevealedDidMerkleProofLeaf;
-use pallet_dip_consumer::{DipOrigin, EnsureDipOrigin};
+use kilt_dip_primitives::{RevealedDidMerkleProofLeaf, RevealsWeb3Name};
+use pallet_dip_consumer::{DipOrigin, EnsureDipOriginWith};
 use pallet_postit::traits::GetUsername;
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
@@ -36,12 +36,13 @@ impl EnsureOrigin<RuntimeOrigin> for EnsureDipOriginAdapter {
 	type Success = DipOriginAdapter;
 
 	fn try_origin(o: RuntimeOrigin) -> Result<Self::Success, RuntimeOrigin> {
-		EnsureDipOrigin::try_origin(o).map(DipOriginAdapter)
+		EnsureDipOriginWith::<DidIdentifier, AccountId, MerkleProofVerifierOutput, RevealsWeb3Name>::try_origin(o)
+			.map(DipOriginAdapter)
 	}
 
 	#[cfg(feature = "runtime-benchmarks")]
 	fn try_successful_origin() -> Result<RuntimeOrigin, ()> {
-		EnsureDipOrigin::<DidIdentifier, AccountId, MerkleProofVerifierOutput>::try_successful_origin()
+		EnsureDipOriginWith::<DidIdentifier, AccountId, MerkleProofVerifierOutput, RevealsWeb3Name>::try_successful_origin()
 	}
 }
 
@@ -17,7 +17,7 @@
 // If you feel like getting in touch with us, you can do so at info@botlabs.org
 
 use did::{DidRawOrigin, EnsureDidOrigin, KeyIdOf};
-use frame_system::EnsureSigned;
+use frame_system::{EnsureRoot, EnsureSigned};
 use pallet_did_lookup::linkable_account::LinkableAccountId;
 use pallet_dip_provider::IdentityCommitmentVersion;
 use parity_scale_codec::{Decode, Encode};
@@ -26,15 +26,17 @@ use runtime_common::dip::{
 	merkle::{DidMerkleProofError, DidMerkleRootGenerator},
 };
 use scale_info::TypeInfo;
-use sp_core::ConstU32;
+use sp_core::{ConstU128, ConstU32};
 use sp_std::vec::Vec;
 
 use crate::{
 	deposit::{DepositHooks, DepositNamespaces},
-	weights, AccountId, Balances, DidIdentifier, Runtime, RuntimeEvent, RuntimeHoldReason,
+	weights, AccountId, Balance, Balances, DidIdentifier, Runtime, RuntimeEvent, RuntimeHoldReason, UNIT,
 };
 
 const MAX_LINKED_ACCOUNTS: u32 = 20;
+/// The amount of tokens locked for each authorized committer.
+const COMMITTER_DEPOSIT: Balance = UNIT;
 
 pub mod runtime_api {
 	use super::*;
@@ -55,6 +57,12 @@ pub mod runtime_api {
 		/// A flag indicating whether the web3name claimed by the DID subject
 		/// should revealed in the DIP proof.
 		pub(crate) should_include_web3_name: bool,
+		/// A flag indicating whether the `alsoKnownAs` URIs of the DID subject
+		/// should be revealed in the DIP proof.
+		pub(crate) should_include_also_known_as: bool,
+		/// A flag indicating whether the records of the web3name claimed by
+		/// the DID subject should be revealed in the DIP proof.
+		pub(crate) should_include_web3_name_records: bool,
 	}
 
 	#[derive(Encode, Decode, TypeInfo)]
@@ -225,10 +233,13 @@ impl pallet_deposit_storage::Config for Runtime {
 	// The balances pallet is used to reserve/unreserve tokens.
 	type Currency = Balances;
 	type DepositHooks = DepositHooks;
+	type DepositParameter = ();
+	type DepositParameterBounds = ();
 	type MaxKeyLength = ConstU32<256>;
 	type Namespace = DepositNamespaces;
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeHoldReason = RuntimeHoldReason;
+	type SetDepositParameterOrigin = EnsureRoot<AccountId>;
 	type WeightInfo = weights::pallet_deposit_storage::WeightInfo<Runtime>;
 }
 
@@ -237,6 +248,8 @@ impl pallet_dip_provider::Config for Runtime {
 	// only if the DID in the origin matches the identifier specified in the tx.
 	type CommitOriginCheck = EnsureDidOrigin<DidIdentifier, AccountId>;
 	type CommitOrigin = DidRawOrigin<DidIdentifier, AccountId>;
+	type CommitterDeposit = ConstU128<COMMITTER_DEPOSIT>;
+	type Currency = Balances;
 	type Identifier = DidIdentifier;
 	// The identity commitment is defined as the Merkle root of the linked identity
 	// info, as specified by the [`LinkedDidInfoProvider`].
@@ -244,7 +257,9 @@ impl pallet_dip_provider::Config for Runtime {
 	// Identity info is defined as the collection of DID keys, linked accounts, and
 	// the optional web3name of a given DID subject.
 	type IdentityProvider = LinkedDidInfoProvider<MAX_LINKED_ACCOUNTS>;
+	type MaxCommitters = ConstU32<10>;
 	type ProviderHooks = deposit::DepositCollectorHooks;
 	type RuntimeEvent = RuntimeEvent;
+	type RuntimeHoldReason = RuntimeHoldReason;
 	type WeightInfo = weights::pallet_dip_provider::WeightInfo<Runtime>;
 }
@@ -397,6 +397,17 @@ impl did::Config for Runtime {
 	type MaxNumberOfServicesPerDid = ConstU32<1>;
 	type MaxNumberOfTypesPerService = ConstU32<1>;
 	type MaxNumberOfUrlsPerService = ConstU32<1>;
+	type MaxKeyHistoryLength = ConstU32<10>;
+	type MaxRemovedKeys = ConstU32<10>;
+	type KeyHistoryDeposit = ConstU128<UNIT>;
+	type MaxEndorsementsPerDid = ConstU32<10>;
+	type MaxAlsoKnownAs = ConstU32<10>;
+	type MaxAlsoKnownAsUriLength = ConstU32<256>;
+	type MaxDidDocumentSize = ConstU32<131_072>;
+	type MaxMultisigSignatories = ConstU32<64>;
+	type AllowedKeyTypes = Everything;
+	type FreeTrialOperations = ConstU32<0>;
+	type LegacySignatureDeadline = ConstU64<0>;
 	type MaxPublicKeysPerDid = ConstU32<53>;
 	type MaxServiceIdLength = ConstU32<100>;
 	type MaxServiceTypeLength = ConstU32<100>;
@@ -413,10 +424,17 @@ impl did::Config for Runtime {
 
 impl pallet_did_lookup::Config for Runtime {
 	type BalanceMigrationManager = ();
+	#[cfg(not(feature = "runtime-benchmarks"))]
+	type ContractSignatureVerifier = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type ContractSignatureVerifier = runtime_common::benchmarks::AlwaysVerifyContractSignature;
 	type Currency = Balances;
 	type Deposit = ConstU128<UNIT>;
+	type LabelDeposit = ConstU128<UNIT>;
 	type DidIdentifier = DidIdentifier;
 	type EnsureOrigin = EnsureDidOrigin<DidIdentifier, AccountId>;
+	type MaxAssociationRemovals = ConstU32<25>;
+	type MaxProofValidity = ConstU64<{ 7 * 24 * HOURS }>;
 	type OriginSuccess = DidRawOrigin<AccountId, DidIdentifier>;
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeHoldReason = RuntimeHoldReason;
@@ -425,17 +443,34 @@ impl pallet_did_lookup::Config for Runtime {
 
 pub type Web3Name = AsciiWeb3Name<Runtime>;
 
+parameter_types! {
+	pub const NoTreasuryAccount: AccountId = AccountId::new([0u8; 32]);
+}
+
 impl pallet_web3_names::Config for Runtime {
 	type BalanceMigrationManager = ();
+	type BanAppealOrigin = EnsureRoot<AccountId>;
+	type BanDelay = ConstU64<{ 6 * HOURS }>;
 	type BanOrigin = EnsureRoot<AccountId>;
 	type Currency = Balances;
 	type Deposit = ConstU128<UNIT>;
+	type MaxBansPerBlock = ConstU32<16>;
+	type MaxClaimBatchSize = ConstU32<50>;
 	type MaxNameLength = ConstU32<32>;
+	type MaxRecordKeyLength = ConstU32<32>;
+	type MaxRecordValueLength = ConstU32<256>;
+	type MaxRecordsPerName = ConstU32<16>;
 	type MinNameLength = ConstU32<3>;
 	type OriginSuccess = DidRawOrigin<AccountId, DidIdentifier>;
 	type OwnerOrigin = EnsureDidOrigin<DidIdentifier, AccountId>;
+	type RecordDeposit = ConstU128<UNIT>;
+	type RegistrarApprovalOrigin = EnsureRoot<AccountId>;
+	type RegistrarOrigin = EnsureRoot<AccountId>;
+	// There is no treasury in this runtime, so registrars retain the whole fee.
+	type RegistrarTreasuryShare = ();
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeHoldReason = RuntimeHoldReason;
+	type TreasuryAccount = NoTreasuryAccount;
 	type Web3Name = Web3Name;
 	type Web3NameOwner = DidIdentifier;
 	type WeightInfo = weights::pallet_web3_names::WeightInfo<Runtime>;
@@ -688,13 +723,103 @@ impl_runtime_apis! {
 				details: details.into(),
 			})
 		}
+
+		fn linked_did_info(key: kilt_runtime_api_did::RawDidLookupKey<DidIdentifier, LinkableAccountId>) -> Option<
+			kilt_runtime_api_did::RawDidLinkedInfo<
+				DidIdentifier,
+				AccountId,
+				LinkableAccountId,
+				Balance,
+				Hash,
+				BlockNumber
+			>
+		> {
+			let did = match key {
+				kilt_runtime_api_did::DidLookupKey::Did(did) => did,
+				kilt_runtime_api_did::DidLookupKey::Web3Name(name) => {
+					let name: pallet_web3_names::web3_name::AsciiWeb3Name<Runtime> = name.try_into().ok()?;
+					pallet_web3_names::Owner::<Runtime>::get(&name)?.owner
+				}
+				kilt_runtime_api_did::DidLookupKey::Account(account) => pallet_did_lookup::ConnectedDids::<Runtime>::get(account)?.did,
+			};
+			let details = did::Did::<Runtime>::get(&did)?;
+			let w3n = pallet_web3_names::Names::<Runtime>::get(&did).map(Into::into);
+			let accounts = pallet_did_lookup::ConnectedAccounts::<Runtime>::iter_key_prefix(&did).collect();
+			let service_endpoints = did::ServiceEndpoints::<Runtime>::iter_prefix(&did).map(|e| From::from(e.1)).collect();
+
+			Some(kilt_runtime_api_did::RawDidLinkedInfo {
+				identifier: did,
+				w3n,
+				accounts,
+				service_endpoints,
+				details: details.into(),
+			})
+		}
+
+		fn key_history(did: DidIdentifier, key_id: Hash) -> Vec<kilt_runtime_api_did::KeyHistoryEntry<BlockNumber>> {
+			did::KeyHistory::<Runtime>::get(did, key_id).into_inner()
+		}
+
+		fn verify_signature_at(
+			did: DidIdentifier,
+			payload: Vec<u8>,
+			signature: kilt_runtime_api_did::DidSignature,
+			block_number: BlockNumber
+		) -> Vec<kilt_runtime_api_did::DidVerificationKeyRelationship> {
+			did::Pallet::<Runtime>::verify_signature_at(&did, &payload, &signature, block_number)
+		}
+
+		fn linked_accounts_with_labels(did: DidIdentifier) -> Vec<(LinkableAccountId, Option<Vec<u8>>)> {
+			pallet_did_lookup::ConnectedAccounts::<Runtime>::iter_key_prefix(&did)
+				.map(|account| {
+					let label = pallet_did_lookup::AccountLabels::<Runtime>::get(&account).map(|record| record.label.into());
+					(account, label)
+				})
+				.collect()
+		}
+
+		fn service_endpoints(did: DidIdentifier) -> Vec<kilt_runtime_api_did::RawServiceEndpointEntry> {
+			let inline_endpoints = did::ServiceEndpoints::<Runtime>::iter_prefix_values(&did)
+				.map(|endpoint| kilt_runtime_api_did::ServiceEndpointEntry::Inline(endpoint.into()));
+			let hashed_endpoints = did::HashedServiceEndpoints::<Runtime>::iter_prefix_values(&did)
+				.map(|endpoint| kilt_runtime_api_did::ServiceEndpointEntry::Hashed(endpoint.into()));
+			inline_endpoints.chain(hashed_endpoints).collect()
+		}
+
+		fn endorsements(did: DidIdentifier) -> Vec<(DidIdentifier, kilt_runtime_api_did::EndorsementDetails<Hash, BlockNumber>)> {
+			did::Endorsements::<Runtime>::iter_prefix(&did)
+				.filter(|(endorser, _)| did::Did::<Runtime>::contains_key(endorser))
+				.collect()
+		}
+		fn also_known_as(did: DidIdentifier) -> Vec<Vec<u8>> {
+			did::AlsoKnownAs::<Runtime>::get(&did).into_iter().map(|uri| uri.into_inner()).collect()
+		}
+
+		fn estimate_document_size(
+			did: DidIdentifier,
+			operations: Vec<kilt_runtime_api_did::DidDocumentOperation<AccountId, Hash>>
+		) -> Result<kilt_runtime_api_did::DidDocumentSizeEstimate<Balance>, kilt_runtime_api_did::DidDocumentEstimateError> {
+			did::Pallet::<Runtime>::estimate_document_size(&did, operations)
+		}
+
+		fn web3_name_records(name: Vec<u8>) -> Vec<(Vec<u8>, Vec<u8>)> {
+			let Ok(name) = pallet_web3_names::web3_name::AsciiWeb3Name::<Runtime>::try_from(name) else {
+				return Vec::new();
+			};
+			pallet_web3_names::Records::<Runtime>::iter_prefix(&name)
+				.map(|(key, record)| (key.into_inner(), record.value.into_inner()))
+				.collect()
+		}
+
 	}
 
 	impl kilt_runtime_api_dip_provider::DipProvider<Block, runtime_api::DipProofRequest, CompleteMerkleProof<Hash, DidMerkleProofOf<Runtime>>, runtime_api::DipProofError> for Runtime {
 		fn generate_proof(request: runtime_api::DipProofRequest) -> Result<CompleteMerkleProof<Hash, DidMerkleProofOf<Runtime>>, runtime_api::DipProofError> {
 			let identity_details = IdentityProviderOf::<Runtime>::retrieve(&request.identifier).map_err(runtime_api::DipProofError::IdentityProvider)?;
 
-			DidMerkleRootGenerator::<Runtime>::generate_proof(&identity_details, request.version, request.keys.iter(), request.should_include_web3_name, request.accounts.iter()).map_err(runtime_api::DipProofError::MerkleProof)
+			let scope = pallet_dip_provider::IdentityCommitmentScopes::<Runtime>::get(&request.identifier, request.version);
+
+			DidMerkleRootGenerator::<Runtime>::generate_scoped_proof(&identity_details, request.version, scope, request.keys.iter(), request.should_include_web3_name, request.accounts.iter(), request.should_include_also_known_as, request.should_include_web3_name_records).map_err(runtime_api::DipProofError::MerkleProof)
 		}
 	}
 
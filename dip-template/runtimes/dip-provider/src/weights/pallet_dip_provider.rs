@@ -48,51 +48,28 @@ use sp_std::marker::PhantomData;
 /// Weight functions for `pallet_dip_provider`.
 pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> pallet_dip_provider::WeightInfo for WeightInfo<T> {
-	/// Storage: `Did::Did` (r:1 w:0)
-	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(1447), added: 3922, mode: `MaxEncodedLen`)
-	/// Storage: `Did::DidBlacklist` (r:1 w:0)
-	/// Proof: `Did::DidBlacklist` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
-	/// Storage: `Web3Names::Names` (r:1 w:0)
-	/// Proof: `Web3Names::Names` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
-	/// Storage: `Web3Names::Owner` (r:1 w:0)
-	/// Proof: `Web3Names::Owner` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
-	/// Storage: `DidLookup::ConnectedAccounts` (r:21 w:0)
-	/// Proof: `DidLookup::ConnectedAccounts` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
-	/// Storage: `DipProvider::IdentityCommitments` (r:1 w:1)
-	/// Proof: `DipProvider::IdentityCommitments` (`max_values`: None, `max_size`: Some(82), added: 2557, mode: `MaxEncodedLen`)
-	/// Storage: `DepositStorage::Deposits` (r:1 w:1)
-	/// Proof: `DepositStorage::Deposits` (`max_values`: None, `max_size`: Some(325), added: 2800, mode: `MaxEncodedLen`)
-	/// Storage: `System::Account` (r:1 w:1)
-	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Holds` (r:1 w:1)
-	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(949), added: 3424, mode: `MaxEncodedLen`)
+	// The calls below are not covered by the benchmark run above and use the
+	// estimated weights of the pallet.
 	fn commit_identity() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `3190`
-		//  Estimated: `55002`
-		// Minimum execution time: 236_589_000 picoseconds.
-		Weight::from_parts(266_691_000, 0)
-			.saturating_add(Weight::from_parts(0, 55002))
-			.saturating_add(T::DbWeight::get().reads(29))
-			.saturating_add(T::DbWeight::get().writes(4))
+		<pallet_dip_provider::default_weights::SubstrateWeight<T> as pallet_dip_provider::WeightInfo>::commit_identity()
 	}
-	/// Storage: `DipProvider::IdentityCommitments` (r:1 w:1)
-	/// Proof: `DipProvider::IdentityCommitments` (`max_values`: None, `max_size`: Some(82), added: 2557, mode: `MaxEncodedLen`)
-	/// Storage: `DepositStorage::Deposits` (r:1 w:1)
-	/// Proof: `DepositStorage::Deposits` (`max_values`: None, `max_size`: Some(325), added: 2800, mode: `MaxEncodedLen`)
-	/// Storage: `System::Account` (r:1 w:1)
-	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Holds` (r:1 w:1)
-	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(949), added: 3424, mode: `MaxEncodedLen`)
 	fn delete_identity_commitment() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `498`
-		//  Estimated: `4414`
-		// Minimum execution time: 51_471_000 picoseconds.
-		Weight::from_parts(56_814_000, 0)
-			.saturating_add(Weight::from_parts(0, 4414))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(4))
+		<pallet_dip_provider::default_weights::SubstrateWeight<T> as pallet_dip_provider::WeightInfo>::delete_identity_commitment()
+	}
+	fn commit_identity_scoped() -> Weight {
+		<pallet_dip_provider::default_weights::SubstrateWeight<T> as pallet_dip_provider::WeightInfo>::commit_identity_scoped()
+	}
+	fn authorize_committer() -> Weight {
+		<pallet_dip_provider::default_weights::SubstrateWeight<T> as pallet_dip_provider::WeightInfo>::authorize_committer()
+	}
+	fn revoke_committer() -> Weight {
+		<pallet_dip_provider::default_weights::SubstrateWeight<T> as pallet_dip_provider::WeightInfo>::revoke_committer()
+	}
+	fn reclaim_committer_deposit() -> Weight {
+		<pallet_dip_provider::default_weights::SubstrateWeight<T> as pallet_dip_provider::WeightInfo>::reclaim_committer_deposit()
+	}
+	fn initialize_identity_commitments_counter_step() -> Weight {
+		<pallet_dip_provider::default_weights::SubstrateWeight<T> as pallet_dip_provider::WeightInfo>::initialize_identity_commitments_counter_step()
 	}
 }
 
@@ -108,27 +108,6 @@ impl<T: frame_system::Config> pallet_web3_names::WeightInfo for WeightInfo<T> {
 	}
 	/// Storage: `Web3Names::Banned` (r:1 w:1)
 	/// Proof: `Web3Names::Banned` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
-	/// Storage: `Web3Names::Owner` (r:1 w:1)
-	/// Proof: `Web3Names::Owner` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
-	/// Storage: `System::Account` (r:1 w:1)
-	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Holds` (r:1 w:1)
-	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(949), added: 3424, mode: `MaxEncodedLen`)
-	/// Storage: `Web3Names::Names` (r:0 w:1)
-	/// Proof: `Web3Names::Names` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
-	/// The range of component `n` is `[3, 32]`.
-	fn ban(_n: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `324 + n * (1 ±0)`
-		//  Estimated: `4414`
-		// Minimum execution time: 36_341_000 picoseconds.
-		Weight::from_parts(44_569_163, 0)
-			.saturating_add(Weight::from_parts(0, 4414))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(5))
-	}
-	/// Storage: `Web3Names::Banned` (r:1 w:1)
-	/// Proof: `Web3Names::Banned` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
 	/// The range of component `n` is `[3, 32]`.
 	fn unban(_n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -174,6 +153,50 @@ impl<T: frame_system::Config> pallet_web3_names::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
+	// The calls below are not covered by the benchmark run above and use the
+	// estimated weights of the pallet.
+	fn ban(n: u32, ) -> Weight {
+		<pallet_web3_names::default_weights::SubstrateWeight<T> as pallet_web3_names::WeightInfo>::ban(n)
+	}
+	fn appeal_ban(n: u32, ) -> Weight {
+		<pallet_web3_names::default_weights::SubstrateWeight<T> as pallet_web3_names::WeightInfo>::appeal_ban(n)
+	}
+	fn resolve_ban_appeal(n: u32, ) -> Weight {
+		<pallet_web3_names::default_weights::SubstrateWeight<T> as pallet_web3_names::WeightInfo>::resolve_ban_appeal(n)
+	}
+	fn cancel_ban(n: u32, ) -> Weight {
+		<pallet_web3_names::default_weights::SubstrateWeight<T> as pallet_web3_names::WeightInfo>::cancel_ban(n)
+	}
+	fn enact_bans(n: u32, ) -> Weight {
+		<pallet_web3_names::default_weights::SubstrateWeight<T> as pallet_web3_names::WeightInfo>::enact_bans(n)
+	}
+	fn claim_batch(n: u32, ) -> Weight {
+		<pallet_web3_names::default_weights::SubstrateWeight<T> as pallet_web3_names::WeightInfo>::claim_batch(n)
+	}
+	fn approve_registrar() -> Weight {
+		<pallet_web3_names::default_weights::SubstrateWeight<T> as pallet_web3_names::WeightInfo>::approve_registrar()
+	}
+	fn remove_registrar() -> Weight {
+		<pallet_web3_names::default_weights::SubstrateWeight<T> as pallet_web3_names::WeightInfo>::remove_registrar()
+	}
+	fn claim_by_registrar(n: u32, ) -> Weight {
+		<pallet_web3_names::default_weights::SubstrateWeight<T> as pallet_web3_names::WeightInfo>::claim_by_registrar(n)
+	}
+	fn set_record() -> Weight {
+		<pallet_web3_names::default_weights::SubstrateWeight<T> as pallet_web3_names::WeightInfo>::set_record()
+	}
+	fn clear_record() -> Weight {
+		<pallet_web3_names::default_weights::SubstrateWeight<T> as pallet_web3_names::WeightInfo>::clear_record()
+	}
+	fn accept_pending_claim() -> Weight {
+		<pallet_web3_names::default_weights::SubstrateWeight<T> as pallet_web3_names::WeightInfo>::accept_pending_claim()
+	}
+	fn decline_pending_claim() -> Weight {
+		<pallet_web3_names::default_weights::SubstrateWeight<T> as pallet_web3_names::WeightInfo>::decline_pending_claim()
+	}
+	fn initialize_owner_counter_step() -> Weight {
+		<pallet_web3_names::default_weights::SubstrateWeight<T> as pallet_web3_names::WeightInfo>::initialize_owner_counter_step()
+	}
 }
 
 #[cfg(test)]
@@ -0,0 +1,32 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+//! XCM integration tests for the KILT runtimes.
+//!
+//! The tests run the actual runtimes of the relay chain, of AssetHub and of
+//! Spiritnet in a network emulated via the Cumulus XCM emulator, which routes
+//! the XCM messages between the chains.
+//!
+//! A second network runs the DIP provider and consumer templates on top of
+//! Rococo, and serves as an executable reference of the whole DIP flow for
+//! parachains integrating KILT identities.
+
+#![cfg(test)]
+
+mod network;
+mod tests;
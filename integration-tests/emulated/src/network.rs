@@ -0,0 +1,262 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use integration_tests_common::constants::{asset_hub_polkadot, polkadot, rococo};
+use polkadot_parachain::primitives::Sibling;
+use sp_runtime::{AccountId32, BuildStorage, Storage};
+use xcm_builder::{ParentIsPreset, SiblingParachainConvertsVia};
+use xcm_emulator::{decl_test_networks, decl_test_parachains, decl_test_relay_chains};
+
+use runtime_common::{constants::KILT, Balance};
+
+/// The parachain id of Spiritnet.
+pub(crate) const SPIRITNET_PARA_ID: u32 = 2086;
+/// The account that owns KILT on Spiritnet at genesis.
+pub(crate) const ALICE: AccountId32 = AccountId32::new([1u8; 32]);
+/// The account that receives assets in the tests.
+pub(crate) const BOB: AccountId32 = AccountId32::new([2u8; 32]);
+/// The initial balance of [`ALICE`] on Spiritnet.
+pub(crate) const INITIAL_BALANCE: Balance = 1_000 * KILT;
+/// The parachain id of the DIP provider template, as expected by the proof
+/// verifier of the DIP consumer template.
+pub(crate) const DIP_PROVIDER_PARA_ID: u32 = 2_000;
+/// The parachain id of the DIP consumer template.
+pub(crate) const DIP_CONSUMER_PARA_ID: u32 = 2_001;
+
+/// The DIP templates have no XCM configuration, hence the emulator is given
+/// the minimal set of location converters needed to derive sovereign accounts.
+type DipTemplateLocationToAccountId = (
+	ParentIsPreset<AccountId32>,
+	SiblingParachainConvertsVia<Sibling, AccountId32>,
+);
+
+fn spiritnet_genesis() -> Storage {
+	let genesis_config = spiritnet_runtime::RuntimeGenesisConfig {
+		system: spiritnet_runtime::SystemConfig {
+			code: spiritnet_runtime::WASM_BINARY
+				.expect("WASM binary was not built, please build it!")
+				.to_vec(),
+			..Default::default()
+		},
+		balances: spiritnet_runtime::BalancesConfig {
+			balances: vec![(ALICE, INITIAL_BALANCE)],
+		},
+		parachain_info: spiritnet_runtime::ParachainInfoConfig {
+			parachain_id: SPIRITNET_PARA_ID.into(),
+			..Default::default()
+		},
+		polkadot_xcm: spiritnet_runtime::PolkadotXcmConfig {
+			safe_xcm_version: Some(xcm::prelude::XCM_VERSION),
+			..Default::default()
+		},
+		..Default::default()
+	};
+
+	genesis_config
+		.build_storage()
+		.expect("Failed to build Spiritnet genesis storage.")
+}
+
+fn dip_provider_genesis() -> Storage {
+	let genesis_config = dip_provider_runtime_template::RuntimeGenesisConfig {
+		system: dip_provider_runtime_template::SystemConfig {
+			code: dip_provider_runtime_template::WASM_BINARY
+				.expect("WASM binary was not built, please build it!")
+				.to_vec(),
+			..Default::default()
+		},
+		balances: dip_provider_runtime_template::BalancesConfig {
+			balances: vec![(ALICE, 1_000 * dip_provider_runtime_template::UNIT)],
+		},
+		parachain_info: dip_provider_runtime_template::ParachainInfoConfig {
+			parachain_id: DIP_PROVIDER_PARA_ID.into(),
+			..Default::default()
+		},
+		..Default::default()
+	};
+
+	genesis_config
+		.build_storage()
+		.expect("Failed to build DIP provider genesis storage.")
+}
+
+fn dip_consumer_genesis() -> Storage {
+	let genesis_config = dip_consumer_runtime_template::RuntimeGenesisConfig {
+		system: dip_consumer_runtime_template::SystemConfig {
+			code: dip_consumer_runtime_template::WASM_BINARY
+				.expect("WASM binary was not built, please build it!")
+				.to_vec(),
+			..Default::default()
+		},
+		balances: dip_consumer_runtime_template::BalancesConfig {
+			balances: vec![(ALICE, 1_000 * dip_consumer_runtime_template::UNIT)],
+		},
+		parachain_info: dip_consumer_runtime_template::ParachainInfoConfig {
+			parachain_id: DIP_CONSUMER_PARA_ID.into(),
+			..Default::default()
+		},
+		..Default::default()
+	};
+
+	genesis_config
+		.build_storage()
+		.expect("Failed to build DIP consumer genesis storage.")
+}
+
+decl_test_relay_chains! {
+	#[api_version(5)]
+	pub struct Polkadot {
+		genesis = polkadot::genesis(),
+		on_init = (),
+		runtime = {
+			Runtime: polkadot_runtime::Runtime,
+			RuntimeOrigin: polkadot_runtime::RuntimeOrigin,
+			RuntimeCall: polkadot_runtime::RuntimeCall,
+			RuntimeEvent: polkadot_runtime::RuntimeEvent,
+			MessageQueue: polkadot_runtime::MessageQueue,
+			XcmConfig: polkadot_runtime::xcm_config::XcmConfig,
+			SovereignAccountOf: polkadot_runtime::xcm_config::SovereignAccountOf,
+			System: polkadot_runtime::System,
+			Balances: polkadot_runtime::Balances,
+		},
+		pallets_extra = {
+			XcmPallet: polkadot_runtime::XcmPallet,
+		}
+	},
+	#[api_version(5)]
+	pub struct Rococo {
+		genesis = rococo::genesis(),
+		on_init = (),
+		runtime = {
+			Runtime: rococo_runtime::Runtime,
+			RuntimeOrigin: rococo_runtime::RuntimeOrigin,
+			RuntimeCall: rococo_runtime::RuntimeCall,
+			RuntimeEvent: rococo_runtime::RuntimeEvent,
+			MessageQueue: rococo_runtime::MessageQueue,
+			XcmConfig: rococo_runtime::xcm_config::XcmConfig,
+			SovereignAccountOf: rococo_runtime::xcm_config::LocationConverter,
+			System: rococo_runtime::System,
+			Balances: rococo_runtime::Balances,
+		},
+		pallets_extra = {
+			XcmPallet: rococo_runtime::XcmPallet,
+		}
+	}
+}
+
+decl_test_parachains! {
+	pub struct AssetHub {
+		genesis = asset_hub_polkadot::genesis(),
+		on_init = (),
+		runtime = {
+			Runtime: asset_hub_polkadot_runtime::Runtime,
+			RuntimeOrigin: asset_hub_polkadot_runtime::RuntimeOrigin,
+			RuntimeCall: asset_hub_polkadot_runtime::RuntimeCall,
+			RuntimeEvent: asset_hub_polkadot_runtime::RuntimeEvent,
+			XcmpMessageHandler: asset_hub_polkadot_runtime::XcmpQueue,
+			DmpMessageHandler: asset_hub_polkadot_runtime::DmpQueue,
+			LocationToAccountId: asset_hub_polkadot_runtime::xcm_config::LocationToAccountId,
+			System: asset_hub_polkadot_runtime::System,
+			Balances: asset_hub_polkadot_runtime::Balances,
+			ParachainSystem: asset_hub_polkadot_runtime::ParachainSystem,
+			ParachainInfo: asset_hub_polkadot_runtime::ParachainInfo,
+		},
+		pallets_extra = {
+			PolkadotXcm: asset_hub_polkadot_runtime::PolkadotXcm,
+		}
+	},
+	pub struct Spiritnet {
+		genesis = spiritnet_genesis(),
+		on_init = (),
+		runtime = {
+			Runtime: spiritnet_runtime::Runtime,
+			RuntimeOrigin: spiritnet_runtime::RuntimeOrigin,
+			RuntimeCall: spiritnet_runtime::RuntimeCall,
+			RuntimeEvent: spiritnet_runtime::RuntimeEvent,
+			XcmpMessageHandler: spiritnet_runtime::XcmpQueue,
+			DmpMessageHandler: spiritnet_runtime::DmpQueue,
+			LocationToAccountId: runtime_common::xcm_config::LocationToAccountId<spiritnet_runtime::xcm_config::RelayNetworkId>,
+			System: spiritnet_runtime::System,
+			Balances: spiritnet_runtime::Balances,
+			ParachainSystem: spiritnet_runtime::ParachainSystem,
+			ParachainInfo: spiritnet_runtime::ParachainInfo,
+		},
+		pallets_extra = {
+			PolkadotXcm: spiritnet_runtime::PolkadotXcm,
+			ForeignAssets: spiritnet_runtime::ForeignAssets,
+		}
+	},
+	pub struct DipProvider {
+		genesis = dip_provider_genesis(),
+		on_init = (),
+		runtime = {
+			Runtime: dip_provider_runtime_template::Runtime,
+			RuntimeOrigin: dip_provider_runtime_template::RuntimeOrigin,
+			RuntimeCall: dip_provider_runtime_template::RuntimeCall,
+			RuntimeEvent: dip_provider_runtime_template::RuntimeEvent,
+			XcmpMessageHandler: (),
+			DmpMessageHandler: (),
+			LocationToAccountId: DipTemplateLocationToAccountId,
+			System: dip_provider_runtime_template::System,
+			Balances: dip_provider_runtime_template::Balances,
+			ParachainSystem: dip_provider_runtime_template::ParachainSystem,
+			ParachainInfo: dip_provider_runtime_template::ParachainInfo,
+		},
+		pallets_extra = {
+			Did: dip_provider_runtime_template::Did,
+			DipProvider: dip_provider_runtime_template::DipProvider,
+		}
+	},
+	pub struct DipConsumer {
+		genesis = dip_consumer_genesis(),
+		on_init = (),
+		runtime = {
+			Runtime: dip_consumer_runtime_template::Runtime,
+			RuntimeOrigin: dip_consumer_runtime_template::RuntimeOrigin,
+			RuntimeCall: dip_consumer_runtime_template::RuntimeCall,
+			RuntimeEvent: dip_consumer_runtime_template::RuntimeEvent,
+			XcmpMessageHandler: (),
+			DmpMessageHandler: (),
+			LocationToAccountId: DipTemplateLocationToAccountId,
+			System: dip_consumer_runtime_template::System,
+			Balances: dip_consumer_runtime_template::Balances,
+			ParachainSystem: dip_consumer_runtime_template::ParachainSystem,
+			ParachainInfo: dip_consumer_runtime_template::ParachainInfo,
+		},
+		pallets_extra = {
+			DipConsumer: dip_consumer_runtime_template::DipConsumer,
+		}
+	}
+}
+
+decl_test_networks! {
+	pub struct PolkadotNetwork {
+		relay_chain = Polkadot,
+		parachains = vec![
+			AssetHub,
+			Spiritnet,
+		],
+	},
+	pub struct RococoNetwork {
+		relay_chain = Rococo,
+		parachains = vec![
+			DipProvider,
+			DipConsumer,
+		],
+	}
+}
@@ -0,0 +1,260 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+//! End-to-end DIP flow: a DID is created and updated on the provider, its
+//! identity is committed, the provider head is included in the relaychain
+//! state, and the resulting proof is used to dispatch a call on the consumer.
+//!
+//! The emulator does not back parachain candidates on the relaychain, nor does
+//! it feed the relaychain state roots to the parachains. Hence, the provider
+//! head is set on the relaychain via the root-only
+//! `Paras::force_set_current_head` call, and the relaychain state root is
+//! written to the relay store pallet of the consumer, which a live network
+//! does via the validation data inherent provided by the collators.
+
+use did::did_details::{DidAuthorizedCallOperation, DidCreationDetails};
+use dip_consumer_runtime_template as consumer;
+use dip_provider_runtime_template as provider;
+use frame_support::{assert_ok, storage_alias, Twox64Concat};
+use frame_system::pallet_prelude::HeaderFor;
+use kilt_dip_primitives::{
+	DipCommitmentStateProof, ParachainDipDidProof, ProviderHeadStateProof, TimeBoundDidSignature,
+	VersionedDipParachainStateProof,
+};
+use pallet_dip_provider::{traits::IdentityProvider, IdentityProviderOf};
+use pallet_relay_store::RelayParentInfo;
+use parity_scale_codec::Encode;
+use runtime_common::dip::merkle::{CompleteMerkleProof, DidMerkleProofOf, DidMerkleRootGenerator};
+use sp_core::{sr25519, Pair, H256};
+use sp_runtime::{traits::Header as HeaderT, Storage};
+use xcm_emulator::TestExt;
+
+use crate::network::{DipConsumer, DipProvider, Rococo, RococoNetwork, ALICE, DIP_PROVIDER_PARA_ID};
+
+/// The DIP commitment version used throughout the tests.
+const COMMITMENT_VERSION: u16 = 0;
+
+/// The relaychain `Paras::Heads` storage, keyed by the raw parachain id and
+/// storing the SCALE-encoded head data. Only used to compute the storage key
+/// of the provider head.
+mod relay {
+	use super::*;
+
+	#[storage_alias]
+	pub(super) type Heads = StorageMap<Paras, Twox64Concat, u32, Vec<u8>>;
+}
+
+/// The consumer `RelayStore::LatestRelayHeads` storage.
+mod relay_store {
+	use super::*;
+
+	#[storage_alias]
+	pub(super) type LatestRelayHeads = StorageMap<RelayStore, Twox64Concat, u32, RelayParentInfo<H256>>;
+}
+
+fn did_auth_key() -> sr25519::Pair {
+	sr25519::Pair::from_seed(&[10u8; 32])
+}
+
+fn did_identifier() -> provider::DidIdentifier {
+	did_auth_key().public().into()
+}
+
+fn web3_name() -> Vec<u8> {
+	b"dip-user".to_vec()
+}
+
+/// Returns the state root of the externalities in scope, together with a
+/// storage proof for the provided keys.
+///
+/// The proof is generated over a copy of the whole top-level storage, so that
+/// its root matches the one a live node would compute for the same state.
+fn state_root_and_proof_for_keys(keys: &[Vec<u8>]) -> (H256, Vec<Vec<u8>>) {
+	let mut storage = Storage::default();
+	let mut next_key = sp_io::storage::next_key(&[]);
+	while let Some(key) = next_key {
+		let value = sp_io::storage::get(&key).expect("Iterated key should have a value.");
+		next_key = sp_io::storage::next_key(&key);
+		storage.top.insert(key, value.to_vec());
+	}
+	let backend = sp_io::TestExternalities::new(storage).as_backend();
+	let state_root = *backend.root();
+	let proof = sp_state_machine::prove_read(backend, keys).expect("Failed to generate storage proof.");
+	(state_root, proof.into_iter_nodes().collect())
+}
+
+/// Dispatches the provided call on the provider, authorized by the DID
+/// authentication key.
+fn submit_provider_did_call(call: provider::RuntimeCall) {
+	let did = did_identifier();
+	let tx_counter = did::Did::<provider::Runtime>::get(&did)
+		.expect("DID should exist.")
+		.last_tx_counter
+		+ 1;
+	let operation = DidAuthorizedCallOperation {
+		did,
+		tx_counter,
+		call,
+		block_number: provider::System::block_number(),
+		submitter: ALICE,
+	};
+	let signature = did_auth_key().sign(&operation.encode());
+	assert_ok!(provider::Did::submit_did_call(
+		provider::RuntimeOrigin::signed(ALICE),
+		Box::new(operation),
+		signature.into()
+	));
+}
+
+/// Creates a DID on the provider, links a web3name to it, and commits its
+/// identity. Returns the DID Merkle proof revealing the authentication key and
+/// the web3name, the storage proof for the identity commitment, and the
+/// provider header committing to the resulting state.
+fn create_and_commit_identity_on_provider() -> (
+	DidMerkleProofOf<provider::Runtime>,
+	Vec<Vec<u8>>,
+	HeaderFor<provider::Runtime>,
+) {
+	DipProvider::execute_with(|| {
+		let did = did_identifier();
+		let creation_details = DidCreationDetails {
+			did: did.clone(),
+			submitter: ALICE,
+			new_key_agreement_keys: Default::default(),
+			new_attestation_key: None,
+			new_delegation_key: None,
+			new_service_details: vec![],
+		};
+		let signature = did_auth_key().sign(&creation_details.encode());
+		assert_ok!(provider::Did::create(
+			provider::RuntimeOrigin::signed(ALICE),
+			Box::new(creation_details),
+			signature.into()
+		));
+
+		submit_provider_did_call(provider::RuntimeCall::Web3Names(pallet_web3_names::Call::claim {
+			name: web3_name().try_into().unwrap(),
+		}));
+		assert!(pallet_web3_names::Names::<provider::Runtime>::get(&did).is_some());
+
+		submit_provider_did_call(provider::RuntimeCall::DipProvider(
+			pallet_dip_provider::Call::commit_identity {
+				identifier: did.clone(),
+				version: Some(COMMITMENT_VERSION),
+			},
+		));
+		assert!(pallet_dip_provider::IdentityCommitments::<provider::Runtime>::get(&did, COMMITMENT_VERSION).is_some());
+
+		let identity = IdentityProviderOf::<provider::Runtime>::retrieve(&did).expect("Identity should exist.");
+		let authentication_key = did::Did::<provider::Runtime>::get(&did)
+			.expect("DID should exist.")
+			.authentication_key;
+		let CompleteMerkleProof { proof: did_proof, .. } = DidMerkleRootGenerator::<provider::Runtime>::generate_proof(
+			&identity,
+			COMMITMENT_VERSION,
+			[authentication_key].iter(),
+			true,
+			[].iter(),
+		)
+		.expect("Failed to generate DID Merkle proof.");
+
+		let commitment_key =
+			pallet_dip_provider::IdentityCommitments::<provider::Runtime>::hashed_key_for(&did, COMMITMENT_VERSION);
+		let (provider_state_root, commitment_proof) = state_root_and_proof_for_keys(&[commitment_key]);
+		let provider_header = HeaderFor::<provider::Runtime>::new(
+			provider::System::block_number(),
+			H256::default(),
+			provider_state_root,
+			provider::System::parent_hash(),
+			Default::default(),
+		);
+
+		(did_proof, commitment_proof, provider_header)
+	})
+}
+
+/// Includes the provider header in the relaychain state. Returns the
+/// relaychain block number and state root, and the storage proof for the
+/// provider head.
+fn include_provider_head_on_relay(provider_header: &HeaderFor<provider::Runtime>) -> (u32, H256, Vec<Vec<u8>>) {
+	Rococo::execute_with(|| {
+		assert_ok!(rococo_runtime::Paras::force_set_current_head(
+			rococo_runtime::RuntimeOrigin::root(),
+			DIP_PROVIDER_PARA_ID.into(),
+			provider_header.encode().into(),
+		));
+		assert_eq!(relay::Heads::get(DIP_PROVIDER_PARA_ID), Some(provider_header.encode()));
+
+		let head_key = relay::Heads::hashed_key_for(DIP_PROVIDER_PARA_ID);
+		let (relay_state_root, head_proof) = state_root_and_proof_for_keys(&[head_key]);
+
+		(rococo_runtime::System::block_number(), relay_state_root, head_proof)
+	})
+}
+
+#[test]
+fn dip_identity_committed_on_provider_dispatches_call_on_consumer() {
+	RococoNetwork::reset();
+
+	let (did_proof, commitment_proof, provider_header) = create_and_commit_identity_on_provider();
+	let (relay_block_number, relay_state_root, head_proof) = include_provider_head_on_relay(&provider_header);
+
+	DipConsumer::execute_with(|| {
+		relay_store::LatestRelayHeads::insert(
+			relay_block_number,
+			RelayParentInfo {
+				relay_parent_storage_root: relay_state_root,
+			},
+		);
+
+		let did = did_identifier();
+		let call = consumer::RuntimeCall::PostIt(pallet_postit::Call::post {
+			text: b"Hello from the DIP provider!".to_vec().try_into().unwrap(),
+		});
+		let identity_details = consumer::DipConsumer::identity_proofs(&did);
+		let valid_until = consumer::System::block_number() + 10;
+		let genesis_hash = consumer::System::block_hash(0);
+		let signature =
+			did_auth_key().sign(&(&call, &identity_details, &ALICE, valid_until, genesis_hash, ()).encode());
+		let proof = VersionedDipParachainStateProof::V0(ParachainDipDidProof::new(
+			ProviderHeadStateProof::new(relay_block_number, head_proof.into_iter().into()),
+			DipCommitmentStateProof::new(commitment_proof.into_iter().into()),
+			did_proof,
+			TimeBoundDidSignature::new(signature.into(), valid_until),
+		));
+
+		assert_ok!(consumer::DipConsumer::dispatch_as(
+			consumer::RuntimeOrigin::signed(ALICE),
+			did.clone(),
+			proof.clone(),
+			Box::new(call.clone()),
+		));
+		assert_eq!(consumer::DipConsumer::identity_proofs(&did), Some(0));
+		assert!(consumer::System::events().iter().any(|record| matches!(
+			&record.event,
+			consumer::RuntimeEvent::PostIt(pallet_postit::Event::NewPost { author, .. }) if author.as_ref() == web3_name().as_slice()
+		)));
+
+		// The consumed signature is bound to the previous identity details, so
+		// the same proof cannot be replayed.
+		assert!(
+			consumer::DipConsumer::dispatch_as(consumer::RuntimeOrigin::signed(ALICE), did, proof, Box::new(call))
+				.is_err()
+		);
+	});
+}
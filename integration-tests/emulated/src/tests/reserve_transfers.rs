@@ -0,0 +1,165 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use frame_support::assert_ok;
+use polkadot_parachain::primitives::Sibling;
+use runtime_common::{
+	constants::KILT,
+	xcm_config::{AssetHubLocation, ASSET_HUB_PARA_ID},
+	AccountId,
+};
+use sp_runtime::traits::{AccountIdConversion, Zero};
+use xcm::prelude::*;
+use xcm_emulator::TestExt;
+
+use crate::network::{AssetHub, PolkadotNetwork, Spiritnet, ALICE, BOB, INITIAL_BALANCE, SPIRITNET_PARA_ID};
+
+const TRANSFER_AMOUNT: u128 = 10 * KILT;
+
+fn asset_hub_sovereign_account() -> AccountId {
+	Sibling::from(ASSET_HUB_PARA_ID).into_account_truncating()
+}
+
+fn spiritnet_location() -> MultiLocation {
+	MultiLocation::new(1, X1(Parachain(SPIRITNET_PARA_ID)))
+}
+
+fn bob_location() -> MultiLocation {
+	AccountId32 {
+		network: None,
+		id: BOB.into(),
+	}
+	.into()
+}
+
+/// Reserve-transfers [`TRANSFER_AMOUNT`] KILT from [`ALICE`] on Spiritnet to
+/// [`BOB`] on AssetHub.
+fn reserve_transfer_kilt_to_asset_hub() {
+	Spiritnet::execute_with(|| {
+		assert_ok!(spiritnet_runtime::PolkadotXcm::limited_reserve_transfer_assets(
+			spiritnet_runtime::RuntimeOrigin::signed(ALICE),
+			Box::new(AssetHubLocation::get().into_versioned()),
+			Box::new(bob_location().into_versioned()),
+			Box::new(VersionedMultiAssets::V3((Here, TRANSFER_AMOUNT).into())),
+			0,
+			WeightLimit::Unlimited,
+		));
+	});
+}
+
+/// Sends an XCM from AssetHub to Spiritnet, as AssetHub would when
+/// reserve-transferring KILT back to Spiritnet. The assets in the message are
+/// expressed as seen from Spiritnet.
+fn send_from_asset_hub(message: Xcm<()>) {
+	AssetHub::execute_with(|| {
+		assert_ok!(pallet_xcm::Pallet::<asset_hub_polkadot_runtime::Runtime>::send_xcm(
+			Here,
+			spiritnet_location(),
+			message
+		));
+	});
+}
+
+#[test]
+fn reserve_transfer_kilt_to_asset_hub_locks_funds_in_sovereign_account() {
+	PolkadotNetwork::reset();
+
+	reserve_transfer_kilt_to_asset_hub();
+
+	Spiritnet::execute_with(|| {
+		assert_eq!(
+			spiritnet_runtime::Balances::free_balance(&ALICE),
+			INITIAL_BALANCE - TRANSFER_AMOUNT
+		);
+		assert_eq!(
+			spiritnet_runtime::Balances::free_balance(&asset_hub_sovereign_account()),
+			TRANSFER_AMOUNT
+		);
+	});
+}
+
+#[test]
+fn reserve_transfer_foreign_assets_is_filtered() {
+	PolkadotNetwork::reset();
+
+	Spiritnet::execute_with(|| {
+		assert!(spiritnet_runtime::PolkadotXcm::limited_reserve_transfer_assets(
+			spiritnet_runtime::RuntimeOrigin::signed(ALICE),
+			Box::new(AssetHubLocation::get().into_versioned()),
+			Box::new(bob_location().into_versioned()),
+			Box::new(VersionedMultiAssets::V3((Parent, TRANSFER_AMOUNT).into())),
+			0,
+			WeightLimit::Unlimited,
+		)
+		.is_err());
+	});
+}
+
+#[test]
+fn kilt_round_trip_via_asset_hub() {
+	PolkadotNetwork::reset();
+
+	reserve_transfer_kilt_to_asset_hub();
+
+	send_from_asset_hub(Xcm(vec![
+		WithdrawAsset((Here, TRANSFER_AMOUNT).into()),
+		ClearOrigin,
+		BuyExecution {
+			fees: (Here, TRANSFER_AMOUNT).into(),
+			weight_limit: Unlimited,
+		},
+		DepositAsset {
+			assets: Wild(AllCounted(1)),
+			beneficiary: bob_location(),
+		},
+	]));
+
+	Spiritnet::execute_with(|| {
+		let bob_balance = spiritnet_runtime::Balances::free_balance(&BOB);
+
+		// The funds are released from the sovereign account of AssetHub and
+		// deposited to Bob, minus the execution fees.
+		assert!(spiritnet_runtime::Balances::free_balance(&asset_hub_sovereign_account()).is_zero());
+		assert!(bob_balance > 0 && bob_balance < TRANSFER_AMOUNT);
+	});
+}
+
+#[test]
+fn unused_assets_returned_from_asset_hub_are_trapped() {
+	PolkadotNetwork::reset();
+
+	reserve_transfer_kilt_to_asset_hub();
+
+	// The assets are withdrawn, but never deposited.
+	send_from_asset_hub(Xcm(vec![
+		WithdrawAsset((Here, TRANSFER_AMOUNT).into()),
+		ClearOrigin,
+		BuyExecution {
+			fees: (Here, TRANSFER_AMOUNT).into(),
+			weight_limit: Unlimited,
+		},
+	]));
+
+	Spiritnet::execute_with(|| {
+		assert!(spiritnet_runtime::Balances::free_balance(&asset_hub_sovereign_account()).is_zero());
+		assert!(spiritnet_runtime::System::events().iter().any(|record| matches!(
+			record.event,
+			spiritnet_runtime::RuntimeEvent::PolkadotXcm(pallet_xcm::Event::AssetsTrapped { .. })
+		)));
+	});
+}
@@ -0,0 +1,87 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use std::path::PathBuf;
+
+use frame_remote_externalities::{Builder, Mode, OfflineConfig, OnlineConfig, RemoteExternalities, SnapshotConfig};
+use serde::de::DeserializeOwned;
+use sp_runtime::traits::Block as BlockT;
+
+/// The environment variable pointing to the directory where state snapshots
+/// are cached.
+const SNAPSHOT_DIR_ENV: &str = "STATE_FORK_SNAPSHOT_DIR";
+
+/// A live chain whose state can be forked.
+pub(crate) struct Chain {
+	/// The name of the chain, used to name its state snapshot.
+	pub(crate) name: &'static str,
+	/// The environment variable that overrides the default endpoint.
+	pub(crate) uri_env: &'static str,
+	/// The endpoint used when no override is provided.
+	pub(crate) default_uri: &'static str,
+}
+
+pub(crate) const SPIRITNET: Chain = Chain {
+	name: "spiritnet",
+	uri_env: "SPIRITNET_URI",
+	default_uri: "wss://spiritnet.kilt.io:443",
+};
+
+pub(crate) const PEREGRINE: Chain = Chain {
+	name: "peregrine",
+	uri_env: "PEREGRINE_URI",
+	default_uri: "wss://peregrine.kilt.io:443/parachain-public-ws",
+};
+
+/// Fork the latest finalized state of the given chain.
+///
+/// If a snapshot directory is configured, the state is read from the
+/// snapshot of the chain if present, and downloaded and stored in it
+/// otherwise.
+pub(crate) async fn fork<B>(chain: &Chain) -> RemoteExternalities<B>
+where
+	B: BlockT + DeserializeOwned,
+	B::Header: DeserializeOwned,
+{
+	let uri = std::env::var(chain.uri_env).unwrap_or_else(|_| chain.default_uri.to_owned());
+	let online = OnlineConfig {
+		transport: uri.into(),
+		..Default::default()
+	};
+	let mode = match std::env::var(SNAPSHOT_DIR_ENV) {
+		Ok(dir) => {
+			let snapshot_path = PathBuf::from(dir).join(format!("{}.snap", chain.name));
+			Mode::OfflineOrElseOnline(
+				OfflineConfig {
+					state_snapshot: SnapshotConfig::new(snapshot_path.clone()),
+				},
+				OnlineConfig {
+					state_snapshot: Some(SnapshotConfig::new(snapshot_path)),
+					..online
+				},
+			)
+		}
+		Err(_) => Mode::Online(online),
+	};
+
+	Builder::<B>::new()
+		.mode(mode)
+		.build()
+		.await
+		.unwrap_or_else(|e| panic!("Failed to fork the state of {}: {e}", chain.name))
+}
@@ -0,0 +1,146 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+//! Invariants that must hold for the forked state of a KILT runtime, both
+//! before and after the runtime upgrade and after any extrinsic executed on
+//! top of it.
+
+/// Implement the invariants shared by all KILT runtimes for the given
+/// runtime crate.
+macro_rules! impl_common_invariants {
+	($runtime:ident) => {
+		use runtime_common::Balance;
+		use $runtime::{Runtime, RuntimeHoldReason};
+
+		/// Assert that the total issuance equals the sum of the free and
+		/// reserved balances of all accounts.
+		pub(crate) fn assert_total_issuance() {
+			let accounts_total = frame_system::Account::<Runtime>::iter_values().fold(0 as Balance, |total, info| {
+				total
+					.saturating_add(info.data.free)
+					.saturating_add(info.data.reserved)
+			});
+			assert_eq!(
+				accounts_total,
+				pallet_balances::Pallet::<Runtime>::total_issuance(),
+				"Total issuance does not match the sum of all account balances."
+			);
+		}
+
+		/// Return the total balance held for the given reason across all
+		/// accounts.
+		fn total_held_for(reason: RuntimeHoldReason) -> Balance {
+			pallet_balances::Holds::<Runtime>::iter_values()
+				.flatten()
+				.filter(|hold| hold.id == reason)
+				.fold(0, |total, hold| total.saturating_add(hold.amount))
+		}
+
+		/// Assert that the balance held by the DID, web3 name and DID lookup
+		/// pallets equals the deposits recorded in their storage.
+		///
+		/// This assumes that the migration of deposits from reserves to holds
+		/// has been completed on the forked chain.
+		pub(crate) fn assert_deposits_match_holds() {
+			let did_deposits = did::Did::<Runtime>::iter_values().fold(0 as Balance, |total, details| {
+				total.saturating_add(details.deposit.amount)
+			});
+			assert_eq!(
+				did_deposits,
+				total_held_for(did::HoldReason::Deposit.into()),
+				"DID deposits do not match the held balance."
+			);
+
+			let web3_name_deposits = pallet_web3_names::Owner::<Runtime>::iter_values()
+				.fold(0 as Balance, |total, ownership| {
+					total.saturating_add(ownership.deposit.amount)
+				});
+			assert_eq!(
+				web3_name_deposits,
+				total_held_for(pallet_web3_names::HoldReason::Deposit.into()),
+				"Web3 name deposits do not match the held balance."
+			);
+
+			let linking_deposits = pallet_did_lookup::ConnectedDids::<Runtime>::iter_values()
+				.fold(0 as Balance, |total, record| {
+					total.saturating_add(record.deposit.amount)
+				});
+			assert_eq!(
+				linking_deposits,
+				total_held_for(pallet_did_lookup::HoldReason::Deposit.into()),
+				"Account linking deposits do not match the held balance."
+			);
+		}
+
+		/// Assert all the invariants shared by the KILT runtimes.
+		pub(crate) fn assert_all() {
+			assert_total_issuance();
+			assert_deposits_match_holds();
+		}
+	};
+}
+
+pub(crate) mod spiritnet {
+	impl_common_invariants!(spiritnet_runtime);
+}
+
+pub(crate) mod peregrine {
+	use std::collections::BTreeMap;
+
+	use pallet_dip_provider::{
+		traits::{IdentityCommitmentGenerator, IdentityProvider},
+		IdentityCommitmentOf, IdentityCommitmentVersion,
+	};
+	use runtime_common::DidIdentifier;
+
+	impl_common_invariants!(peregrine_runtime);
+
+	/// The stored identity commitment of a DID, together with the one
+	/// regenerated from its current identity information, if any.
+	#[derive(Debug, PartialEq, Eq)]
+	pub(crate) struct CommitmentSnapshot {
+		pub(crate) stored: IdentityCommitmentOf<Runtime>,
+		pub(crate) regenerated: Option<IdentityCommitmentOf<Runtime>>,
+	}
+
+	/// Return all the stored identity commitments, together with the ones
+	/// regenerated from the current identity information.
+	///
+	/// Stored commitments are not updated when the identity changes, so they
+	/// can legitimately differ from the regenerated ones. However, neither of
+	/// them must be changed by a runtime upgrade.
+	pub(crate) fn identity_commitments() -> BTreeMap<(DidIdentifier, IdentityCommitmentVersion), CommitmentSnapshot> {
+		pallet_dip_provider::IdentityCommitments::<Runtime>::iter()
+			.map(|(identifier, version, stored)| {
+				let regenerated = <Runtime as pallet_dip_provider::Config>::IdentityProvider::retrieve(&identifier)
+					.ok()
+					.and_then(|identity| {
+						let scope = pallet_dip_provider::IdentityCommitmentScopes::<Runtime>::get(&identifier, version);
+						<Runtime as pallet_dip_provider::Config>::IdentityCommitmentGenerator::generate_scoped_commitment(
+							&identifier,
+							&identity,
+							version,
+							scope,
+						)
+						.ok()
+					});
+				((identifier, version), CommitmentSnapshot { stored, regenerated })
+			})
+			.collect()
+	}
+}
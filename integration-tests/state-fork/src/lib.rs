@@ -0,0 +1,37 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+//! State-fork regression tests for the KILT runtimes.
+//!
+//! The tests fork the live state of Spiritnet and Peregrine via remote
+//! externalities, execute the migrations of the runtimes in this repository
+//! on top of it, followed by a scripted set of DID, web3 name and staking
+//! extrinsics, and assert that the chain invariants still hold. This catches
+//! migration regressions against real data before a runtime is released.
+//!
+//! The tests require network access and are therefore ignored by default.
+//! They can be run with `cargo test -p state-fork-tests -- --ignored`. The
+//! endpoints can be overridden with the `SPIRITNET_URI` and `PEREGRINE_URI`
+//! environment variables. If `STATE_FORK_SNAPSHOT_DIR` is set, the forked
+//! state is cached in that directory and re-used by subsequent runs.
+
+#![cfg(test)]
+
+mod ext;
+mod invariants;
+mod tests;
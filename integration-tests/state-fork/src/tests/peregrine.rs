@@ -0,0 +1,46 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use frame_support::assert_ok;
+use frame_try_runtime::UpgradeCheckSelect;
+use peregrine_runtime::{Block, Executive};
+
+use crate::{
+	ext::{fork, PEREGRINE},
+	invariants::peregrine as invariants,
+};
+
+#[tokio::test]
+#[ignore = "requires network access to a Peregrine node"]
+async fn runtime_upgrade_preserves_invariants_and_commitments() {
+	let mut ext = fork::<Block>(&PEREGRINE).await;
+
+	ext.execute_with(|| {
+		invariants::assert_all();
+		let commitments_before = invariants::identity_commitments();
+
+		assert_ok!(Executive::try_runtime_upgrade(UpgradeCheckSelect::PreAndPost));
+
+		invariants::assert_all();
+		assert_eq!(
+			invariants::identity_commitments(),
+			commitments_before,
+			"Identity commitments changed during the runtime upgrade."
+		);
+	});
+}
@@ -0,0 +1,120 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use did::did_details::DidVerificationKey;
+use frame_support::{
+	assert_ok,
+	traits::{Get, TryState, TryStateSelect},
+};
+use frame_system::RawOrigin;
+use frame_try_runtime::UpgradeCheckSelect;
+use runtime_common::{
+	constants::{
+		staking::{MinCollatorStake, MIN_DELEGATOR_STAKE},
+		KILT,
+	},
+	AccountId,
+};
+use spiritnet_runtime::{
+	AllPalletsWithSystem, Balances, Block, Did, Executive, ParachainStaking, Runtime, RuntimeCall, RuntimeOrigin,
+	System,
+};
+
+use crate::{
+	ext::{fork, SPIRITNET},
+	invariants::spiritnet as invariants,
+};
+
+const DID_OWNER: AccountId = AccountId::new([1u8; 32]);
+const COLLATOR: AccountId = AccountId::new([2u8; 32]);
+const DELEGATOR: AccountId = AccountId::new([3u8; 32]);
+
+/// Create a DID with a linked account and a web3 name, and join the
+/// collator candidates and delegators with new accounts.
+fn execute_scripted_extrinsics() {
+	for account in [DID_OWNER, COLLATOR, DELEGATOR] {
+		assert_ok!(Balances::force_set_balance(
+			RawOrigin::Root.into(),
+			account.into(),
+			100_000 * KILT
+		));
+	}
+
+	assert_ok!(Did::create_from_account(
+		RuntimeOrigin::signed(DID_OWNER),
+		DidVerificationKey::Account(DID_OWNER)
+	));
+	assert_ok!(Did::dispatch_as(
+		RuntimeOrigin::signed(DID_OWNER),
+		DID_OWNER,
+		Box::new(RuntimeCall::Web3Names(pallet_web3_names::Call::claim {
+			name: b"kilt-state-fork".to_vec().try_into().unwrap(),
+		}))
+	));
+	assert_ok!(Did::dispatch_as(
+		RuntimeOrigin::signed(DID_OWNER),
+		DID_OWNER,
+		Box::new(RuntimeCall::DidLookup(pallet_did_lookup::Call::associate_sender {}))
+	));
+
+	assert_ok!(ParachainStaking::join_candidates(
+		RuntimeOrigin::signed(COLLATOR),
+		MinCollatorStake::get()
+	));
+	assert_ok!(ParachainStaking::join_delegators(
+		RuntimeOrigin::signed(DELEGATOR),
+		COLLATOR.into(),
+		MIN_DELEGATOR_STAKE
+	));
+}
+
+#[tokio::test]
+#[ignore = "requires network access to a Spiritnet node"]
+async fn runtime_upgrade_preserves_invariants() {
+	let mut ext = fork::<Block>(&SPIRITNET).await;
+
+	ext.execute_with(|| {
+		invariants::assert_all();
+
+		assert_ok!(Executive::try_runtime_upgrade(UpgradeCheckSelect::PreAndPost));
+
+		invariants::assert_all();
+	});
+}
+
+#[tokio::test]
+#[ignore = "requires network access to a Spiritnet node"]
+async fn extrinsics_after_runtime_upgrade_preserve_invariants() {
+	let mut ext = fork::<Block>(&SPIRITNET).await;
+
+	ext.execute_with(|| {
+		assert_ok!(Executive::try_runtime_upgrade(UpgradeCheckSelect::None));
+
+		execute_scripted_extrinsics();
+
+		invariants::assert_all();
+		assert_ok!(<AllPalletsWithSystem as TryState<_>>::try_state(
+			System::block_number(),
+			TryStateSelect::All
+		));
+		assert!(pallet_web3_names::Names::<Runtime>::contains_key(DID_OWNER));
+		assert!(pallet_did_lookup::ConnectedDids::<Runtime>::contains_key(
+			pallet_did_lookup::linkable_account::LinkableAccountId::from(DID_OWNER)
+		));
+	});
+}
@@ -0,0 +1,286 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+//! Setup code for [`super::command`] which would otherwise bloat that module.
+//!
+//! Should only be used for benchmarking as it may break in other contexts.
+
+use cumulus_primitives_core::PersistedValidationData;
+use cumulus_primitives_parachain_inherent::ParachainInherentData;
+use cumulus_test_relay_sproof_builder::RelayStateSproofBuilder;
+use parity_scale_codec::Encode;
+use runtime_common::{AccountId, Balance, Block, BlockNumber, Hash, Signature};
+use sc_cli::Result;
+use sc_client_api::BlockBackend;
+use sp_blockchain::HeaderBackend;
+use sp_core::{sr25519, Pair};
+use sp_inherents::{InherentData, InherentDataProvider};
+use sp_keyring::Sr25519Keyring;
+use sp_runtime::{generic::Era, OpaqueExtrinsic, SaturatedConversion};
+
+use std::{marker::PhantomData, sync::Arc, time::Duration};
+
+/// The runtime-specific parts of generating extrinsics for the benchmarks.
+///
+/// Note: Should only be used for benchmarking.
+pub trait BenchmarkRuntime {
+	/// The outer call type of the runtime.
+	type RuntimeCall;
+
+	/// Creates a `System::remark` call.
+	fn remark_call() -> Self::RuntimeCall;
+
+	/// Creates a `Balances::transfer_keep_alive` call.
+	fn transfer_keep_alive_call(dest: AccountId, value: Balance) -> Self::RuntimeCall;
+
+	/// Creates a signed transaction using the given `call`.
+	fn create_extrinsic(
+		call: Self::RuntimeCall,
+		sender: sr25519::Pair,
+		nonce: u32,
+		genesis_hash: Hash,
+		best_hash: Hash,
+		best_block: BlockNumber,
+	) -> OpaqueExtrinsic;
+}
+
+macro_rules! impl_benchmark_runtime {
+	($runtime:ident) => {
+		impl BenchmarkRuntime for $runtime::Runtime {
+			type RuntimeCall = $runtime::RuntimeCall;
+
+			fn remark_call() -> Self::RuntimeCall {
+				frame_system::Call::remark { remark: vec![] }.into()
+			}
+
+			fn transfer_keep_alive_call(dest: AccountId, value: Balance) -> Self::RuntimeCall {
+				pallet_balances::Call::transfer_keep_alive {
+					dest: dest.into(),
+					value,
+				}
+				.into()
+			}
+
+			fn create_extrinsic(
+				call: Self::RuntimeCall,
+				sender: sr25519::Pair,
+				nonce: u32,
+				genesis_hash: Hash,
+				best_hash: Hash,
+				best_block: BlockNumber,
+			) -> OpaqueExtrinsic {
+				let period = runtime_common::BlockHashCount::get()
+					.checked_next_power_of_two()
+					.map(|c| c / 2)
+					.unwrap_or(2);
+				let extra: $runtime::SignedExtra = (
+					frame_system::CheckNonZeroSender::<Self>::new(),
+					frame_system::CheckSpecVersion::<Self>::new(),
+					frame_system::CheckTxVersion::<Self>::new(),
+					frame_system::CheckGenesis::<Self>::new(),
+					frame_system::CheckEra::<Self>::from(Era::mortal(period.into(), best_block.saturated_into())),
+					frame_system::CheckNonce::<Self>::from(nonce.into()),
+					frame_system::CheckWeight::<Self>::new(),
+					pallet_asset_tx_payment::ChargeAssetTxPayment::<Self>::from(0, None),
+					runtime_common::metadata_hash::CheckMetadataHash::<Self>::new(false),
+					pallet_did_lookup::signed_extension::AutoLinkAccount::<
+						Self,
+						runtime_common::did_lookup::DidCallSubjectOf<Self>,
+					>::new(false),
+					runtime_common::identity_priority::IdentityPriorityBoost::<
+						Self,
+						runtime_common::constants::identity_priority::PriorityBoost,
+						runtime_common::constants::identity_priority::MinNameAge,
+					>::new(),
+				);
+
+				let raw_payload = sp_runtime::generic::SignedPayload::from_raw(
+					call.clone(),
+					extra.clone(),
+					(
+						(),
+						$runtime::VERSION.spec_version,
+						$runtime::VERSION.transaction_version,
+						genesis_hash,
+						best_hash,
+						(),
+						(),
+						(),
+						None,
+						(),
+						(),
+					),
+				);
+				let signature = raw_payload.using_encoded(|e| sender.sign(e));
+
+				$runtime::UncheckedExtrinsic::new_signed(
+					call,
+					AccountId::from(sender.public()).into(),
+					Signature::Sr25519(signature),
+					extra,
+				)
+				.into()
+			}
+		}
+	};
+}
+
+impl_benchmark_runtime!(spiritnet_runtime);
+impl_benchmark_runtime!(peregrine_runtime);
+
+/// Creates a transaction using the given `call`, signed for the current best
+/// block of the client.
+fn create_benchmark_extrinsic<R, C>(
+	client: &C,
+	sender: sr25519::Pair,
+	call: R::RuntimeCall,
+	nonce: u32,
+) -> OpaqueExtrinsic
+where
+	R: BenchmarkRuntime,
+	C: BlockBackend<Block> + HeaderBackend<Block>,
+{
+	let genesis_hash = client.block_hash(0).ok().flatten().expect("Genesis block exists; qed");
+	let chain_info = client.info();
+
+	R::create_extrinsic(
+		call,
+		sender,
+		nonce,
+		genesis_hash,
+		chain_info.best_hash,
+		chain_info.best_number,
+	)
+}
+
+/// Generates extrinsics for the `benchmark overhead` command.
+///
+/// Note: Should only be used for benchmarking.
+pub struct RemarkBuilder<R, C> {
+	client: Arc<C>,
+	_phantom: PhantomData<R>,
+}
+
+impl<R, C> RemarkBuilder<R, C> {
+	/// Creates a new [`Self`] from the given client.
+	pub fn new(client: Arc<C>) -> Self {
+		Self {
+			client,
+			_phantom: PhantomData,
+		}
+	}
+}
+
+impl<R, C> frame_benchmarking_cli::ExtrinsicBuilder for RemarkBuilder<R, C>
+where
+	R: BenchmarkRuntime,
+	C: BlockBackend<Block> + HeaderBackend<Block>,
+{
+	fn pallet(&self) -> &str {
+		"system"
+	}
+
+	fn extrinsic(&self) -> &str {
+		"remark"
+	}
+
+	fn build(&self, nonce: u32) -> std::result::Result<OpaqueExtrinsic, &'static str> {
+		Ok(create_benchmark_extrinsic::<R, C>(
+			self.client.as_ref(),
+			Sr25519Keyring::Bob.pair(),
+			R::remark_call(),
+			nonce,
+		))
+	}
+}
+
+/// Generates `Balances::TransferKeepAlive` extrinsics for the benchmarks.
+///
+/// Note: Should only be used for benchmarking.
+pub struct TransferKeepAliveBuilder<R, C> {
+	client: Arc<C>,
+	dest: AccountId,
+	value: Balance,
+	_phantom: PhantomData<R>,
+}
+
+impl<R, C> TransferKeepAliveBuilder<R, C> {
+	/// Creates a new [`Self`] from the given client.
+	pub fn new(client: Arc<C>, dest: AccountId, value: Balance) -> Self {
+		Self {
+			client,
+			dest,
+			value,
+			_phantom: PhantomData,
+		}
+	}
+}
+
+impl<R, C> frame_benchmarking_cli::ExtrinsicBuilder for TransferKeepAliveBuilder<R, C>
+where
+	R: BenchmarkRuntime,
+	C: BlockBackend<Block> + HeaderBackend<Block>,
+{
+	fn pallet(&self) -> &str {
+		"balances"
+	}
+
+	fn extrinsic(&self) -> &str {
+		"transfer_keep_alive"
+	}
+
+	fn build(&self, nonce: u32) -> std::result::Result<OpaqueExtrinsic, &'static str> {
+		Ok(create_benchmark_extrinsic::<R, C>(
+			self.client.as_ref(),
+			Sr25519Keyring::Bob.pair(),
+			R::transfer_keep_alive_call(self.dest.clone(), self.value),
+			nonce,
+		))
+	}
+}
+
+/// Generates inherent data for the `benchmark overhead` and `benchmark
+/// extrinsic` commands.
+///
+/// Besides the timestamp, parachain blocks require the validation data
+/// inherent, which is mocked with an empty relay chain state proof.
+///
+/// Note: Should only be used for benchmarking.
+pub fn inherent_benchmark_data() -> Result<InherentData> {
+	let mut inherent_data = InherentData::new();
+
+	let timestamp = sp_timestamp::InherentDataProvider::new(Duration::from_millis(0).into());
+	futures::executor::block_on(timestamp.provide_inherent_data(&mut inherent_data))
+		.map_err(|e| format!("creating timestamp inherent data: {:?}", e))?;
+
+	let (relay_parent_storage_root, relay_chain_state) = RelayStateSproofBuilder::default().into_state_root_and_proof();
+	let parachain_inherent = ParachainInherentData {
+		validation_data: PersistedValidationData {
+			parent_head: Default::default(),
+			relay_parent_number: 1,
+			relay_parent_storage_root,
+			max_pov_size: 0,
+		},
+		relay_chain_state,
+		downward_messages: Default::default(),
+		horizontal_messages: Default::default(),
+	};
+	futures::executor::block_on(parachain_inherent.provide_inherent_data(&mut inherent_data))
+		.map_err(|e| format!("creating parachain inherent data: {:?}", e))?;
+
+	Ok(inherent_data)
+}
@@ -29,6 +29,7 @@ use sc_service::Properties;
 use serde::{Deserialize, Serialize};
 use sp_core::{Pair, Public};
 use sp_runtime::traits::IdentifyAccount;
+use std::str::FromStr;
 
 pub mod clone;
 pub mod peregrine;
@@ -37,6 +38,116 @@ pub mod spiritnet;
 const TELEMETRY_URL: &str = "wss://telemetry-backend.kilt.io:8080/submit";
 const DEFAULT_PARA_ID: ParaId = LOWEST_PUBLIC_ID;
 
+/// The runtimes embedded in the node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParachainRuntime {
+	Spiritnet,
+	Peregrine,
+	Clone,
+}
+
+impl ParachainRuntime {
+	/// The name used to select the runtime via `--runtime`.
+	pub fn name(&self) -> &'static str {
+		match self {
+			Self::Spiritnet => "spiritnet",
+			Self::Peregrine => "peregrine",
+			Self::Clone => "clone",
+		}
+	}
+}
+
+impl FromStr for ParachainRuntime {
+	type Err = String;
+
+	fn from_str(name: &str) -> Result<Self, Self::Err> {
+		match name {
+			"spiritnet" => Ok(Self::Spiritnet),
+			"peregrine" => Ok(Self::Peregrine),
+			"clone" => Ok(Self::Clone),
+			_ => Err(format!("Unknown KILT parachain runtime {:?}", name)),
+		}
+	}
+}
+
+/// A chain specification shipped with the node, which can be selected by its
+/// name via `--chain`.
+pub struct ChainSpecPreset {
+	/// The name used to select the preset.
+	pub name: &'static str,
+	/// The name of the runtime the chain specification is built for.
+	pub runtime: &'static str,
+	/// Whether the preset is used if no chain is specified for its runtime.
+	pub is_default: bool,
+	/// Builds the chain specification.
+	pub build: fn() -> Result<Box<dyn sc_service::ChainSpec>, String>,
+}
+
+/// All chain specification presets known to the node.
+pub const PRESETS: &[ChainSpecPreset] = &[
+	ChainSpecPreset {
+		name: "dev",
+		runtime: "peregrine",
+		is_default: true,
+		build: || Ok(Box::new(peregrine::get_chain_spec_dev()?)),
+	},
+	ChainSpecPreset {
+		name: "peregrine-new",
+		runtime: "peregrine",
+		is_default: false,
+		build: || Ok(Box::new(peregrine::make_new_spec()?)),
+	},
+	ChainSpecPreset {
+		name: "rilt-new",
+		runtime: "peregrine",
+		is_default: false,
+		build: || Ok(Box::new(peregrine::get_chain_spec_rilt()?)),
+	},
+	ChainSpecPreset {
+		name: "rilt",
+		runtime: "peregrine",
+		is_default: false,
+		build: || Ok(Box::new(peregrine::load_rilt_spec()?)),
+	},
+	ChainSpecPreset {
+		name: "spiritnet-dev",
+		runtime: "spiritnet",
+		is_default: true,
+		build: || Ok(Box::new(spiritnet::get_chain_spec_dev()?)),
+	},
+	ChainSpecPreset {
+		name: "spiritnet",
+		runtime: "spiritnet",
+		is_default: false,
+		build: || Ok(Box::new(spiritnet::load_spiritnet_spec()?)),
+	},
+	ChainSpecPreset {
+		name: "clone-dev",
+		runtime: "clone",
+		is_default: true,
+		build: || Ok(Box::new(clone::get_chain_spec_dev()?)),
+	},
+	ChainSpecPreset {
+		name: "clone-new",
+		runtime: "clone",
+		is_default: false,
+		build: || Ok(Box::new(clone::new_chain_spec()?)),
+	},
+];
+
+/// Returns the preset with the given name.
+///
+/// An empty name selects the default preset of the given runtime.
+pub fn get_preset(name: &str, runtime: &str) -> Option<&'static ChainSpecPreset> {
+	if name.is_empty() {
+		PRESETS
+			.iter()
+			.find(|preset| preset.is_default && preset.runtime == runtime)
+	} else {
+		PRESETS.iter().find(|preset| preset.name == name)
+	}
+}
+
 /// Helper function to generate a crypto pair from seed
 pub fn get_from_seed<TPublic: Public>(seed: &str) -> <TPublic::Pair as Pair>::Public {
 	TPublic::Pair::from_string(&format!("//{}", seed), None)
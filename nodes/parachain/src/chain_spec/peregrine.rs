@@ -28,7 +28,7 @@ use sp_runtime::traits::Zero;
 use crate::chain_spec::Extensions;
 use peregrine_runtime::{
 	BalancesConfig, CouncilConfig, InflationInfo, ParachainInfoConfig, ParachainStakingConfig, PolkadotXcmConfig,
-	RuntimeGenesisConfig, SessionConfig, SudoConfig, SystemConfig, TechnicalCommitteeConfig, VestingConfig,
+	RuntimeGenesisConfig, SessionConfig, SystemConfig, TechnicalCommitteeConfig, VestingConfig,
 };
 use runtime_common::{AccountId, AuthorityId, Balance, BlockNumber};
 
@@ -50,7 +50,6 @@ fn testnet_genesis(
 	initial_authorities: Vec<(AccountId, AuthorityId)>,
 	endowed_accounts: Vec<(AccountId, Balance)>,
 	id: ParaId,
-	root_key: AccountId,
 ) -> RuntimeGenesisConfig {
 	type VestingPeriod = BlockNumber;
 	type LockingPeriod = BlockNumber;
@@ -84,7 +83,6 @@ fn testnet_genesis(
 				.chain(owned_accounts.iter().cloned().map(|(who, total, _, _)| (who, total)))
 				.collect(),
 		},
-		sudo: SudoConfig { key: Some(root_key) },
 		parachain_info: ParachainInfoConfig {
 			parachain_id: id,
 			..Default::default()
@@ -134,5 +132,7 @@ fn testnet_genesis(
 			..Default::default()
 		},
 		did_lookup: Default::default(),
+		assets: Default::default(),
+		foreign_assets: Default::default(),
 	}
 }
@@ -81,7 +81,6 @@ pub fn get_chain_spec_dev() -> Result<ChainSpec, String> {
 					),
 				],
 				DEFAULT_PARA_ID,
-				get_account_id_from_seed::<sr25519::Public>("Alice"),
 			)
 		},
 		vec![],
@@ -43,7 +43,6 @@ pub fn get_chain_spec_rilt() -> Result<ChainSpec, String> {
 					(RILT_COL_ACC_2.into(), 1_000_000 * KILT),
 				],
 				id,
-				RILT_COL_ACC_1.into(),
 			)
 		},
 		vec![
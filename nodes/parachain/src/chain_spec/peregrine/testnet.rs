@@ -1,5 +1,4 @@
 use cumulus_primitives_core::ParaId;
-use hex_literal::hex;
 use sc_service::ChainType;
 
 use peregrine_runtime::WASM_BINARY;
@@ -9,6 +8,8 @@ use crate::chain_spec::{get_properties, peregrine::ChainSpec, Extensions};
 
 use super::testnet_genesis;
 
+/// Create a new Peregrine testnet spec. The chain is governed by its on-chain
+/// collectives and identity referenda from genesis.
 pub fn make_new_spec() -> Result<ChainSpec, String> {
 	let properties = get_properties("PILT", 15, 38);
 	let wasm = WASM_BINARY.ok_or("No WASM")?;
@@ -27,7 +28,6 @@ pub fn make_new_spec() -> Result<ChainSpec, String> {
 				vec![],
 				vec![],
 				id,
-				hex!["d206033ba2eadf615c510f2c11f32d931b27442e5cfb64884afa2241dfa66e70"].into(),
 			)
 		},
 		Vec::new(),
@@ -217,5 +217,7 @@ fn testnet_genesis(
 			..Default::default()
 		},
 		did_lookup: Default::default(),
+		assets: Default::default(),
+		foreign_assets: Default::default(),
 	}
 }
@@ -17,7 +17,7 @@
 // If you feel like getting in touch with us, you can do so at info@botlabs.org
 
 use clap::Parser;
-use std::{ops::Deref, path::PathBuf};
+use std::{num::NonZeroU32, ops::Deref, path::PathBuf};
 
 pub(crate) const DEFAULT_RUNTIME: &str = "peregrine";
 
@@ -47,10 +47,13 @@ pub(crate) enum Subcommand {
 	PurgeChain(cumulus_client_cli::PurgeChainCmd),
 
 	/// Export the genesis state of the parachain.
-	ExportGenesisState(cumulus_client_cli::ExportGenesisStateCommand),
+	ExportGenesisState(ExportGenesisStateCmd),
 
 	/// Export the genesis wasm of the parachain.
-	ExportGenesisWasm(cumulus_client_cli::ExportGenesisWasmCommand),
+	ExportGenesisWasm(ExportGenesisWasmCmd),
+
+	/// Export the chain specification for light clients, e.g., smoldot.
+	ExportLightSpec(ExportLightSpecCmd),
 
 	/// Sub-commands concerned with benchmarking.
 	/// The pallet benchmarking moved to the `pallet` sub-command.
@@ -59,7 +62,7 @@ pub(crate) enum Subcommand {
 
 	/// Try some command against runtime state.
 	#[cfg(feature = "try-runtime")]
-	TryRuntime(try_runtime_cli::TryRuntimeCmd),
+	TryRuntime(TryRuntimeCmd),
 
 	/// Try some command against runtime state. Note: `try-runtime` feature must
 	/// be enabled.
@@ -74,7 +77,8 @@ pub(crate) struct BuildSpecCmd {
 	#[command(flatten)]
 	pub(crate) inner_args: sc_cli::BuildSpecCmd,
 
-	/// The name of the runtime which should get executed.
+	/// The name of the runtime which should get executed, if it cannot be
+	/// detected from the identifier of the chain spec.
 	#[arg(long, default_value = DEFAULT_RUNTIME)]
 	pub(crate) runtime: String,
 }
@@ -87,6 +91,102 @@ impl Deref for BuildSpecCmd {
 	}
 }
 
+/// Command for exporting the genesis state of the parachain
+#[derive(Debug, Parser)]
+#[group(skip)]
+pub(crate) struct ExportGenesisStateCmd {
+	#[command(flatten)]
+	pub(crate) inner_args: cumulus_client_cli::ExportGenesisStateCommand,
+
+	/// The name of the runtime which should get executed, if it cannot be
+	/// detected from the identifier of the chain spec.
+	#[arg(long, default_value = DEFAULT_RUNTIME)]
+	pub(crate) runtime: String,
+}
+
+impl Deref for ExportGenesisStateCmd {
+	type Target = cumulus_client_cli::ExportGenesisStateCommand;
+
+	fn deref(&self) -> &Self::Target {
+		&self.inner_args
+	}
+}
+
+/// Command for exporting the genesis wasm of the parachain
+#[derive(Debug, Parser)]
+#[group(skip)]
+pub(crate) struct ExportGenesisWasmCmd {
+	#[command(flatten)]
+	pub(crate) inner_args: cumulus_client_cli::ExportGenesisWasmCommand,
+
+	/// The name of the runtime which should get executed, if it cannot be
+	/// detected from the identifier of the chain spec.
+	#[arg(long, default_value = DEFAULT_RUNTIME)]
+	pub(crate) runtime: String,
+}
+
+impl Deref for ExportGenesisWasmCmd {
+	type Target = cumulus_client_cli::ExportGenesisWasmCommand;
+
+	fn deref(&self) -> &Self::Target {
+		&self.inner_args
+	}
+}
+
+/// Command for exporting the chain specification for light clients
+#[derive(Debug, Parser)]
+pub(crate) struct ExportLightSpecCmd {
+	#[command(flatten)]
+	pub(crate) shared_params: sc_cli::SharedParams,
+
+	/// Output file name or stdout if unspecified.
+	#[arg()]
+	pub(crate) output: Option<PathBuf>,
+
+	/// The name of the runtime which should get executed, if it cannot be
+	/// detected from the identifier of the chain spec.
+	#[arg(long, default_value = DEFAULT_RUNTIME)]
+	pub(crate) runtime: String,
+}
+
+/// Command for trying runtime upgrades and blocks against runtime state
+#[cfg(feature = "try-runtime")]
+#[derive(Debug, Parser)]
+#[group(skip)]
+pub(crate) struct TryRuntimeCmd {
+	#[command(flatten)]
+	pub(crate) inner_args: try_runtime_cli::TryRuntimeCmd,
+
+	/// The name of the runtime whose host functions should be used, if no chain
+	/// spec is provided.
+	#[arg(long, default_value = DEFAULT_RUNTIME)]
+	pub(crate) runtime: String,
+}
+
+#[cfg(feature = "try-runtime")]
+impl Deref for TryRuntimeCmd {
+	type Target = try_runtime_cli::TryRuntimeCmd;
+
+	fn deref(&self) -> &Self::Target {
+		&self.inner_args
+	}
+}
+
+impl Subcommand {
+	/// The runtime selected via the sub-command, if it supports selecting one.
+	pub(crate) fn runtime(&self) -> Option<&str> {
+		match self {
+			Self::BuildSpec(cmd) => Some(&cmd.runtime),
+			Self::ExportGenesisState(cmd) => Some(&cmd.runtime),
+			Self::ExportGenesisWasm(cmd) => Some(&cmd.runtime),
+			Self::ExportLightSpec(cmd) => Some(&cmd.runtime),
+			#[cfg(feature = "try-runtime")]
+			Self::TryRuntime(cmd) => Some(&cmd.runtime),
+			_ => None,
+		}
+	}
+}
+
 #[derive(Debug, clap::Parser)]
 #[command(
 	propagate_version = true,
@@ -100,6 +200,9 @@ pub(crate) struct Cli {
 	#[command(flatten)]
 	pub(crate) run: cumulus_client_cli::RunCmd,
 
+	#[command(flatten)]
+	pub(crate) rpc_guard: RpcGuardParams,
+
 	// Disable automatic hardware benchmarks.
 	///
 	/// By default these benchmarks are automatically ran at startup and measure
@@ -110,7 +213,17 @@ pub(crate) struct Cli {
 	#[arg(long)]
 	pub no_hardware_benchmarks: bool,
 
-	/// The name of the runtime which should get executed.
+	/// Keep the full history of the DID, web3name, attestation and DIP
+	/// provider storage in the offchain database, while the rest of the state
+	/// is pruned according to `--state-pruning`.
+	///
+	/// The history is available from the block at which the archive is first
+	/// enabled, via the `identityArchive_*` RPC methods.
+	#[arg(long)]
+	pub(crate) identity_archive: bool,
+
+	/// The name of the runtime which should get executed, if it cannot be
+	/// detected from the identifier of the chain spec.
 	#[arg(long, default_value = DEFAULT_RUNTIME)]
 	pub(crate) runtime: String,
 
@@ -119,6 +232,40 @@ pub(crate) struct Cli {
 	pub(crate) relay_chain_args: Vec<String>,
 }
 
+/// Parameters to protect the node-specific RPC methods of public nodes.
+///
+/// Unsafe Substrate RPC methods, e.g., `state_queryStorage`, are disabled
+/// with `--rpc-methods safe`.
+#[derive(Debug, Clone, clap::Args)]
+pub(crate) struct RpcGuardParams {
+	/// Only expose the given node-specific RPC methods, e.g., `did_query`.
+	#[arg(long, value_delimiter = ',')]
+	pub(crate) rpc_allow_methods: Option<Vec<String>>,
+
+	/// Do not expose the given node-specific RPC methods, e.g.,
+	/// `dip_generateProof`.
+	#[arg(long, value_delimiter = ',')]
+	pub(crate) rpc_deny_methods: Vec<String>,
+
+	/// Maximum number of DID resolution and DIP proof generation calls per
+	/// second, shared by all connections.
+	#[arg(long)]
+	pub(crate) rpc_identity_rate_limit: Option<NonZeroU32>,
+}
+
+impl Cli {
+	/// Whether the sync mode of the relay chain node has been explicitly set
+	/// via the relay chain arguments.
+	///
+	/// If not, the relay chain node uses the same sync mode as the parachain
+	/// node, so that `--sync warp` or `--sync fast` speeds up both.
+	pub(crate) fn relay_chain_sync_mode_overridden(&self) -> bool {
+		self.relay_chain_args
+			.iter()
+			.any(|arg| arg == "--sync" || arg.starts_with("--sync="))
+	}
+}
+
 #[derive(Debug)]
 pub(crate) struct RelayChainCli {
 	/// The actual relay chain cli object.
@@ -17,18 +17,19 @@
 // If you feel like getting in touch with us, you can do so at info@botlabs.org
 
 use crate::{
-	chain_spec::{self},
+	benchmarking::{inherent_benchmark_data, RemarkBuilder, TransferKeepAliveBuilder},
+	chain_spec::{self, ParachainRuntime},
 	cli::{Cli, RelayChainCli, Subcommand},
 	service::{new_partial, CloneRuntimeExecutor, PeregrineRuntimeExecutor, SpiritnetRuntimeExecutor},
 };
 use cumulus_client_cli::generate_genesis_block;
 use cumulus_primitives_core::ParaId;
-use frame_benchmarking_cli::{BenchmarkCmd, SUBSTRATE_REFERENCE_HARDWARE};
+use frame_benchmarking_cli::{BenchmarkCmd, ExtrinsicFactory, SUBSTRATE_REFERENCE_HARDWARE};
 use log::{info, warn};
 use parity_scale_codec::Encode;
 #[cfg(feature = "try-runtime")]
 use polkadot_service::TaskManager;
-use runtime_common::Block;
+use runtime_common::{constants::EXISTENTIAL_DEPOSIT, Block};
 use sc_cli::{
 	ChainSpec, CliConfiguration, DefaultConfigurationValues, ImportParams, KeystoreParams, NetworkParams, Result,
 	RuntimeVersion, SharedParams, SubstrateCli,
@@ -36,80 +37,85 @@ use sc_cli::{
 use sc_executor::NativeExecutionDispatch;
 use sc_service::config::{BasePath, PrometheusConfig};
 use sp_core::hexdisplay::HexDisplay;
-use sp_runtime::traits::{AccountIdConversion, Block as BlockT, Zero};
+use sp_keyring::Sr25519Keyring;
+use sp_runtime::traits::{AccountIdConversion, Block as BlockT, Header as HeaderT, Zero};
 use std::net::SocketAddr;
 
 trait IdentifyChain {
-	fn is_peregrine(&self) -> bool;
-	fn is_spiritnet(&self) -> bool;
-	fn is_clone(&self) -> bool;
+	/// The runtime of the chain, if it can be derived from the identifier of
+	/// the chain spec.
+	fn runtime(&self) -> Option<ParachainRuntime>;
 }
 
 impl IdentifyChain for dyn sc_service::ChainSpec {
-	fn is_peregrine(&self) -> bool {
-		self.id().contains("peregrine") || self.id().eq("kilt_parachain_testnet")
-	}
-	fn is_spiritnet(&self) -> bool {
-		self.id().eq("kilt")
-			|| self.id().contains("spiritnet")
-			|| self.id().eq("kilt_westend")
-			|| self.id().eq("kilt_rococo")
-	}
-	fn is_clone(&self) -> bool {
-		self.id().to_lowercase().contains("clone")
+	fn runtime(&self) -> Option<ParachainRuntime> {
+		let id = self.id();
+		if id.contains("peregrine") || id.eq("kilt_parachain_testnet") {
+			Some(ParachainRuntime::Peregrine)
+		} else if id.eq("kilt") || id.contains("spiritnet") || id.eq("kilt_westend") || id.eq("kilt_rococo") {
+			Some(ParachainRuntime::Spiritnet)
+		} else if id.to_lowercase().contains("clone") {
+			Some(ParachainRuntime::Clone)
+		} else {
+			None
+		}
 	}
 }
 
 impl<T: sc_service::ChainSpec + 'static> IdentifyChain for T {
-	fn is_peregrine(&self) -> bool {
-		<dyn sc_service::ChainSpec>::is_peregrine(self)
-	}
-	fn is_spiritnet(&self) -> bool {
-		<dyn sc_service::ChainSpec>::is_spiritnet(self)
-	}
-	fn is_clone(&self) -> bool {
-		<dyn sc_service::ChainSpec>::is_clone(self)
+	fn runtime(&self) -> Option<ParachainRuntime> {
+		<dyn sc_service::ChainSpec>::runtime(self)
 	}
 }
 
-fn load_spec(id: &str) -> std::result::Result<Box<dyn sc_service::ChainSpec>, String> {
+/// Selects the runtime to execute for the given chain spec.
+///
+/// The runtime is detected from the chain spec identifier, so that a single
+/// binary can run all KILT chains. The runtime selected via `--runtime` is
+/// only used if the identifier is unknown.
+fn select_runtime(spec: &dyn sc_service::ChainSpec, fallback: &str) -> std::result::Result<ParachainRuntime, String> {
+	spec.runtime().map_or_else(|| fallback.parse(), Ok)
+}
+
+fn load_spec(id: &str, default_runtime: &str) -> std::result::Result<Box<dyn sc_service::ChainSpec>, String> {
+	eprintln!("Load spec id: {}", id);
+
+	if let Some(preset) = chain_spec::get_preset(id, default_runtime) {
+		eprintln!(
+			"Using the chain spec preset {:?} for the {} runtime",
+			preset.name, preset.runtime
+		);
+		return (preset.build)();
+	}
+
 	let runtime = if id.to_lowercase().contains("spiritnet")
 		|| id.to_lowercase().contains("wilt")
 		|| id.to_lowercase().contains("rilt")
 	{
-		"spiritnet"
+		ParachainRuntime::Spiritnet
 	} else if id.to_lowercase().contains("clone") {
-		"clone"
+		ParachainRuntime::Clone
 	} else {
-		"peregrine"
+		default_runtime.parse()?
 	};
 
-	eprintln!("Load spec id: {}", id);
-	eprintln!("The following runtime was chosen based on the spec id: {}", runtime);
-
-	match (id, runtime) {
-		("clone-dev", _) => Ok(Box::new(chain_spec::clone::get_chain_spec_dev()?)),
-		("clone-new", _) => Ok(Box::new(chain_spec::clone::new_chain_spec()?)),
-		("dev", _) => Ok(Box::new(chain_spec::peregrine::get_chain_spec_dev()?)),
-		("spiritnet-dev", _) => Ok(Box::new(chain_spec::spiritnet::get_chain_spec_dev()?)),
-		("peregrine-new", _) => Ok(Box::new(chain_spec::peregrine::make_new_spec()?)),
-		("rilt-new", _) => Ok(Box::new(chain_spec::peregrine::get_chain_spec_rilt()?)),
-		("rilt", _) => Ok(Box::new(chain_spec::peregrine::load_rilt_spec()?)),
-		("spiritnet", _) => Ok(Box::new(chain_spec::spiritnet::load_spiritnet_spec()?)),
-		("", "spiritnet") => Ok(Box::new(chain_spec::spiritnet::get_chain_spec_dev()?)),
-		("", "peregrine") => Ok(Box::new(chain_spec::peregrine::get_chain_spec_dev()?)),
-		(path, "spiritnet") => Ok(Box::new(chain_spec::spiritnet::ChainSpec::from_json_file(path.into())?)),
-		(path, "peregrine") => Ok(Box::new(chain_spec::peregrine::ChainSpec::from_json_file(path.into())?)),
-		(path, "clone") => Ok(Box::new(chain_spec::clone::ChainSpec::from_json_file(path.into())?)),
-		_ => Err("Unknown KILT parachain spec".to_owned()),
+	eprintln!(
+		"The following runtime was chosen based on the spec id: {}",
+		runtime.name()
+	);
+
+	match runtime {
+		ParachainRuntime::Spiritnet => Ok(Box::new(chain_spec::spiritnet::ChainSpec::from_json_file(id.into())?)),
+		ParachainRuntime::Peregrine => Ok(Box::new(chain_spec::peregrine::ChainSpec::from_json_file(id.into())?)),
+		ParachainRuntime::Clone => Ok(Box::new(chain_spec::clone::ChainSpec::from_json_file(id.into())?)),
 	}
 }
 
-fn native_runtime_version(is_spiritnet: bool) -> &'static RuntimeVersion {
-	if is_spiritnet {
-		&spiritnet_runtime::VERSION
-	} else {
-		&peregrine_runtime::VERSION
+fn native_runtime_version(runtime: ParachainRuntime) -> &'static RuntimeVersion {
+	match runtime {
+		ParachainRuntime::Spiritnet => &spiritnet_runtime::VERSION,
+		ParachainRuntime::Peregrine => &peregrine_runtime::VERSION,
+		ParachainRuntime::Clone => &clone_runtime::VERSION,
 	}
 }
 
@@ -145,7 +151,7 @@ impl SubstrateCli for Cli {
 	}
 
 	fn load_spec(&self, id: &str) -> std::result::Result<Box<dyn sc_service::ChainSpec>, String> {
-		load_spec(id)
+		load_spec(id, &self.runtime)
 	}
 }
 
@@ -187,8 +193,9 @@ impl SubstrateCli for RelayChainCli {
 macro_rules! construct_async_run {
 	(|$components:ident, $cli:ident, $cmd:ident, $config:ident| $( $code:tt )* ) => {{
 		let runner = $cli.create_runner($cmd)?;
-		match $cli.runtime.as_str() {
-			"spiritnet" => {
+		let runtime = select_runtime(&*runner.config().chain_spec, &$cli.runtime)?;
+		match runtime {
+			ParachainRuntime::Spiritnet => {
 					runner.async_run(|$config| {
 						let $components = new_partial::<spiritnet_runtime::RuntimeApi, SpiritnetRuntimeExecutor, _>(
 							&$config,
@@ -198,7 +205,7 @@ macro_rules! construct_async_run {
 						{ $( $code )* }.map(|v| (v, task_manager))
 					})
 				},
-			"peregrine" => {
+			ParachainRuntime::Peregrine => {
 					runner.async_run(|$config| {
 						let $components = new_partial::<peregrine_runtime::RuntimeApi, PeregrineRuntimeExecutor, _>(
 							&$config,
@@ -208,7 +215,7 @@ macro_rules! construct_async_run {
 						{ $( $code )* }.map(|v| (v, task_manager))
 					})
 				}
-			"clone" => {
+			ParachainRuntime::Clone => {
 				runner.async_run(|$config| {
 					let $components = new_partial::<clone_runtime::RuntimeApi, CloneRuntimeExecutor, _>(
 						&$config,
@@ -218,14 +225,18 @@ macro_rules! construct_async_run {
 					{ $( $code )* }.map(|v| (v, task_manager))
 				})
 			}
-			_ => panic!("unknown runtime"),
 		}
 	}}
 }
 
 /// Parse command line arguments into service configuration.
 pub fn run() -> Result<()> {
-	let cli = Cli::from_args();
+	let mut cli = Cli::from_args();
+	// Global arguments conflict with sub-commands, so the runtime can also be
+	// selected via the sub-command itself.
+	if let Some(runtime) = cli.subcommand.as_ref().and_then(Subcommand::runtime) {
+		cli.runtime = runtime.to_owned();
+	}
 
 	match &cli.subcommand {
 		Some(Subcommand::BuildSpec(cmd)) => {
@@ -272,50 +283,77 @@ pub fn run() -> Result<()> {
 			})
 		}
 		Some(Subcommand::ExportGenesisState(cmd)) => {
-			let runner = cli.create_runner(cmd)?;
+			let runner = cli.create_runner(&cmd.inner_args)?;
 			runner.sync_run(|config| {
 				let spec = cli.load_spec(&cmd.shared_params.chain.clone().unwrap_or_default())?;
 
-				if spec.is_spiritnet() {
-					let partials = new_partial::<spiritnet_runtime::RuntimeApi, SpiritnetRuntimeExecutor, _>(
-						&config,
-						crate::service::build_import_queue,
-					)?;
-					cmd.run::<Block>(&*spec, &*partials.client)
-				} else {
-					let partials = new_partial::<peregrine_runtime::RuntimeApi, PeregrineRuntimeExecutor, _>(
-						&config,
-						crate::service::build_import_queue,
-					)?;
-					cmd.run::<Block>(&*spec, &*partials.client)
+				match select_runtime(&*spec, &cli.runtime)? {
+					ParachainRuntime::Spiritnet => {
+						let partials = new_partial::<spiritnet_runtime::RuntimeApi, SpiritnetRuntimeExecutor, _>(
+							&config,
+							crate::service::build_import_queue,
+						)?;
+						cmd.run::<Block>(&*spec, &*partials.client)
+					}
+					ParachainRuntime::Peregrine => {
+						let partials = new_partial::<peregrine_runtime::RuntimeApi, PeregrineRuntimeExecutor, _>(
+							&config,
+							crate::service::build_import_queue,
+						)?;
+						cmd.run::<Block>(&*spec, &*partials.client)
+					}
+					ParachainRuntime::Clone => {
+						let partials = new_partial::<clone_runtime::RuntimeApi, CloneRuntimeExecutor, _>(
+							&config,
+							crate::service::build_import_queue,
+						)?;
+						cmd.run::<Block>(&*spec, &*partials.client)
+					}
 				}
 			})
 		}
 		Some(Subcommand::ExportGenesisWasm(cmd)) => {
-			let runner = cli.create_runner(cmd)?;
+			let runner = cli.create_runner(&cmd.inner_args)?;
 			runner.sync_run(|_config| {
 				let spec = cli.load_spec(&cmd.shared_params.chain.clone().unwrap_or_default())?;
 				cmd.run(&*spec)
 			})
 		}
+		Some(Subcommand::ExportLightSpec(cmd)) => {
+			let spec = cli.load_spec(&cmd.shared_params.chain.clone().unwrap_or_default())?;
+			let state_version = native_runtime_version(select_runtime(&*spec, &cli.runtime)?).state_version();
+			let block: Block = generate_genesis_block(&*spec, state_version).map_err(|e| format!("{:?}", e))?;
+
+			let light_spec = crate::light_spec::light_chain_spec(&*spec, *block.header().state_root())?;
+			let output = serde_json::to_string_pretty(&light_spec).map_err(|e| e.to_string())?;
+
+			if let Some(path) = &cmd.output {
+				std::fs::write(path, output)?;
+			} else {
+				println!("{}", output);
+			}
+
+			Ok(())
+		}
 		Some(Subcommand::Benchmark(cmd)) => {
 			let runner = cli.create_runner(cmd)?;
 
+			let runtime = select_runtime(&*runner.config().chain_spec, &cli.runtime)?;
+
 			// Switch on the concrete benchmark sub-command
-			match (cmd, cli.runtime.as_str()) {
+			match (cmd, runtime) {
 				(BenchmarkCmd::Pallet(cmd), runtime) => {
 					if cfg!(feature = "runtime-benchmarks") {
 						match runtime {
-							"spiritnet" => runner.sync_run(|config| {
+							ParachainRuntime::Spiritnet => runner.sync_run(|config| {
 								cmd.run::<Block, <SpiritnetRuntimeExecutor as NativeExecutionDispatch>::ExtendHostFunctions>(config)
 							}),
-							"peregrine" => runner.sync_run(|config| {
+							ParachainRuntime::Peregrine => runner.sync_run(|config| {
 								cmd.run::<Block, <PeregrineRuntimeExecutor as NativeExecutionDispatch>::ExtendHostFunctions>(config)
 							}),
-							"clone" => runner.sync_run(|config| {
+							ParachainRuntime::Clone => runner.sync_run(|config| {
 								cmd.run::<Block, <CloneRuntimeExecutor as NativeExecutionDispatch>::ExtendHostFunctions>(config)
 							}),
-							_ => Err("Unknown parachain runtime".into()),
 						}
 					} else {
 						Err("Benchmarking wasn't enabled when building the node. \
@@ -323,14 +361,14 @@ pub fn run() -> Result<()> {
 							.into())
 					}
 				}
-				(BenchmarkCmd::Block(cmd), "spiritnet") => runner.sync_run(|config| {
+				(BenchmarkCmd::Block(cmd), ParachainRuntime::Spiritnet) => runner.sync_run(|config| {
 					let partials = new_partial::<spiritnet_runtime::RuntimeApi, SpiritnetRuntimeExecutor, _>(
 						&config,
 						crate::service::build_import_queue,
 					)?;
 					cmd.run(partials.client)
 				}),
-				(BenchmarkCmd::Block(cmd), "peregrine") => runner.sync_run(|config| {
+				(BenchmarkCmd::Block(cmd), ParachainRuntime::Peregrine) => runner.sync_run(|config| {
 					let partials = new_partial::<peregrine_runtime::RuntimeApi, PeregrineRuntimeExecutor, _>(
 						&config,
 						crate::service::build_import_queue,
@@ -344,7 +382,7 @@ pub fn run() -> Result<()> {
 						.into(),
 				)),
 				#[cfg(feature = "runtime-benchmarks")]
-				(BenchmarkCmd::Storage(cmd), "spiritnet") => runner.sync_run(|config| {
+				(BenchmarkCmd::Storage(cmd), ParachainRuntime::Spiritnet) => runner.sync_run(|config| {
 					let partials = new_partial::<spiritnet_runtime::RuntimeApi, SpiritnetRuntimeExecutor, _>(
 						&config,
 						crate::service::build_import_queue,
@@ -356,7 +394,7 @@ pub fn run() -> Result<()> {
 					cmd.run(config, partials.client.clone(), db, storage)
 				}),
 				#[cfg(feature = "runtime-benchmarks")]
-				(BenchmarkCmd::Storage(cmd), "peregrine") => runner.sync_run(|config| {
+				(BenchmarkCmd::Storage(cmd), ParachainRuntime::Peregrine) => runner.sync_run(|config| {
 					let partials = new_partial::<peregrine_runtime::RuntimeApi, PeregrineRuntimeExecutor, _>(
 						&config,
 						crate::service::build_import_queue,
@@ -367,15 +405,84 @@ pub fn run() -> Result<()> {
 
 					cmd.run(config, partials.client.clone(), db, storage)
 				}),
-				(BenchmarkCmd::Overhead(_), _) => Err("Unsupported benchmarking command".into()),
+				(BenchmarkCmd::Overhead(cmd), ParachainRuntime::Spiritnet) => runner.sync_run(|config| {
+					let partials = new_partial::<spiritnet_runtime::RuntimeApi, SpiritnetRuntimeExecutor, _>(
+						&config,
+						crate::service::build_import_queue,
+					)?;
+					let ext_builder = RemarkBuilder::<spiritnet_runtime::Runtime, _>::new(partials.client.clone());
+
+					cmd.run(
+						config,
+						partials.client,
+						inherent_benchmark_data()?,
+						Vec::new(),
+						&ext_builder,
+					)
+				}),
+				(BenchmarkCmd::Overhead(cmd), ParachainRuntime::Peregrine) => runner.sync_run(|config| {
+					let partials = new_partial::<peregrine_runtime::RuntimeApi, PeregrineRuntimeExecutor, _>(
+						&config,
+						crate::service::build_import_queue,
+					)?;
+					let ext_builder = RemarkBuilder::<peregrine_runtime::Runtime, _>::new(partials.client.clone());
+
+					cmd.run(
+						config,
+						partials.client,
+						inherent_benchmark_data()?,
+						Vec::new(),
+						&ext_builder,
+					)
+				}),
+				(BenchmarkCmd::Extrinsic(cmd), ParachainRuntime::Spiritnet) => runner.sync_run(|config| {
+					let partials = new_partial::<spiritnet_runtime::RuntimeApi, SpiritnetRuntimeExecutor, _>(
+						&config,
+						crate::service::build_import_queue,
+					)?;
+					// Register the *Remark* and *TKA* builders.
+					let ext_factory = ExtrinsicFactory(vec![
+						Box::new(RemarkBuilder::<spiritnet_runtime::Runtime, _>::new(
+							partials.client.clone(),
+						)),
+						Box::new(TransferKeepAliveBuilder::<spiritnet_runtime::Runtime, _>::new(
+							partials.client.clone(),
+							Sr25519Keyring::Alice.to_account_id(),
+							EXISTENTIAL_DEPOSIT,
+						)),
+					]);
+
+					cmd.run(partials.client, inherent_benchmark_data()?, Vec::new(), &ext_factory)
+				}),
+				(BenchmarkCmd::Extrinsic(cmd), ParachainRuntime::Peregrine) => runner.sync_run(|config| {
+					let partials = new_partial::<peregrine_runtime::RuntimeApi, PeregrineRuntimeExecutor, _>(
+						&config,
+						crate::service::build_import_queue,
+					)?;
+					// Register the *Remark* and *TKA* builders.
+					let ext_factory = ExtrinsicFactory(vec![
+						Box::new(RemarkBuilder::<peregrine_runtime::Runtime, _>::new(
+							partials.client.clone(),
+						)),
+						Box::new(TransferKeepAliveBuilder::<peregrine_runtime::Runtime, _>::new(
+							partials.client.clone(),
+							Sr25519Keyring::Alice.to_account_id(),
+							EXISTENTIAL_DEPOSIT,
+						)),
+					]);
+
+					cmd.run(partials.client, inherent_benchmark_data()?, Vec::new(), &ext_factory)
+				}),
 				(BenchmarkCmd::Machine(cmd), _) => {
 					runner.sync_run(|config| cmd.run(&config, SUBSTRATE_REFERENCE_HARDWARE.clone()))
 				}
 				// NOTE: this allows the Client to leniently implement
 				// new benchmark commands without requiring a companion MR.
 				#[allow(unreachable_patterns)]
-				(_, "spiritnet") | (_, "peregrine") => Err("Benchmarking sub-command unsupported".into()),
-				(_, _) => Err("Unknown parachain runtime".into()),
+				(_, ParachainRuntime::Spiritnet) | (_, ParachainRuntime::Peregrine) => {
+					Err("Benchmarking sub-command unsupported".into())
+				}
+				(_, ParachainRuntime::Clone) => Err("Benchmarking is not supported for the clone runtime".into()),
 			}
 		}
 		#[cfg(feature = "try-runtime")]
@@ -384,14 +491,15 @@ pub fn run() -> Result<()> {
 			use sc_executor::sp_wasm_interface::ExtendedHostFunctions;
 			use try_runtime_cli::block_building_info::timestamp_with_aura_info;
 
-			let runner = cli.create_runner(cmd)?;
+			let runner = cli.create_runner(&cmd.inner_args)?;
 			let registry = &runner.config().prometheus_config.as_ref().map(|cfg| &cfg.registry);
 			let task_manager = TaskManager::new(runner.config().tokio_handle.clone(), *registry)
 				.map_err(|e| format!("Error: {:?}", e))?;
 			let info_provider = timestamp_with_aura_info(MILLISECS_PER_BLOCK);
 
-			if runner.config().chain_spec.is_peregrine() {
-				runner.async_run(|_| {
+			let runtime = select_runtime(&*runner.config().chain_spec, &cli.runtime)?;
+			match runtime {
+				ParachainRuntime::Peregrine => runner.async_run(|_| {
 					Ok((
 						cmd.run::<Block, ExtendedHostFunctions<
 							sp_io::SubstrateHostFunctions,
@@ -399,9 +507,8 @@ pub fn run() -> Result<()> {
 						>, _>(Some(info_provider)),
 						task_manager,
 					))
-				})
-			} else if runner.config().chain_spec.is_spiritnet() {
-				runner.async_run(|_| {
+				}),
+				ParachainRuntime::Spiritnet => runner.async_run(|_| {
 					Ok((
 						cmd.run::<Block, ExtendedHostFunctions<
 							sp_io::SubstrateHostFunctions,
@@ -409,9 +516,8 @@ pub fn run() -> Result<()> {
 						>, _>(Some(info_provider)),
 						task_manager,
 					))
-				})
-			} else if runner.config().chain_spec.is_clone() {
-				runner.async_run(|_| {
+				}),
+				ParachainRuntime::Clone => runner.async_run(|_| {
 					Ok((
 						cmd.run::<Block, ExtendedHostFunctions<
 							sp_io::SubstrateHostFunctions,
@@ -419,9 +525,7 @@ pub fn run() -> Result<()> {
 						>, _>(Some(info_provider)),
 						task_manager,
 					))
-				})
-			} else {
-				Err("Chain doesn't support try-runtime".into())
+				}),
 			}
 		}
 		#[cfg(not(feature = "try-runtime"))]
@@ -456,18 +560,26 @@ pub fn run() -> Result<()> {
 				let parachain_account =
 					AccountIdConversion::<polkadot_primitives::AccountId>::into_account_truncating(&id);
 
-				let state_version = native_runtime_version(config.chain_spec.is_spiritnet()).state_version();
+				let runtime = select_runtime(&*config.chain_spec, &cli.runtime)?;
+				info!("Parachain runtime: {}", runtime.name());
+
+				let state_version = native_runtime_version(runtime).state_version();
 				let block: Block =
 					generate_genesis_block(&*config.chain_spec, state_version).map_err(|e| format!("{:?}", e))?;
 				let genesis_state = format!("0x{:?}", HexDisplay::from(&block.header().encode()));
 
 				let tokio_handle = config.tokio_handle.clone();
-				let polkadot_config = SubstrateCli::create_configuration(&polkadot_cli, &polkadot_cli, tokio_handle)
+				let mut polkadot_config = SubstrateCli::create_configuration(&polkadot_cli, &polkadot_cli, tokio_handle)
 					.map_err(|err| format!("Relay chain argument error: {}", err))?;
+				if !cli.relay_chain_sync_mode_overridden() {
+					polkadot_config.network.sync_mode = config.network.sync_mode;
+				}
 
 				info!("Parachain id: {:?}", id);
 				info!("Parachain Account: {}", parachain_account);
 				info!("Parachain genesis state: {}", genesis_state);
+				info!("Parachain sync mode: {:?}", config.network.sync_mode);
+				info!("Relay chain sync mode: {:?}", polkadot_config.network.sync_mode);
 				info!(
 					"Is collating: {}",
 					if config.role.is_authority() { "yes" } else { "no" }
@@ -477,41 +589,82 @@ pub fn run() -> Result<()> {
 					warn!("Detected relay chain node arguments together with --relay-chain-rpc-urls. This command starts a minimal Polkadot node that only uses a network-related subset of all relay chain CLI options.");
 				}
 
-				if config.chain_spec.is_peregrine() {
-					crate::service::start_node::<PeregrineRuntimeExecutor, peregrine_runtime::RuntimeApi>(
+				if cli.identity_archive && config.state_pruning.as_ref().map_or(false, |mode| mode.is_archive()) {
+					warn!("The identity archive is redundant on nodes that keep the full state history.");
+				}
+
+				let rpc_method_filter = crate::rpc::RpcMethodFilter {
+					allowed: cli.rpc_guard.rpc_allow_methods.clone(),
+					denied: cli.rpc_guard.rpc_deny_methods.clone(),
+				};
+				let rate_limiter = cli
+					.rpc_guard
+					.rpc_identity_rate_limit
+					.map(|limit| std::sync::Arc::new(kilt_rpc_rate_limiter::RateLimiter::new(limit)));
+
+				match runtime {
+					ParachainRuntime::Peregrine => crate::service::start_node::<
+						PeregrineRuntimeExecutor,
+						peregrine_runtime::RuntimeApi,
+						peregrine_runtime::Runtime,
+						_,
+					>(
 						config,
 						polkadot_config,
 						collator_options,
 						id,
 						hwbench,
+						move |client, metrics, executor, relay_chain_deps| {
+							crate::rpc::create_dip_provider(
+								client,
+								metrics,
+								executor,
+								relay_chain_deps,
+								rate_limiter.clone(),
+							)
+						},
+						rpc_method_filter,
+						cli.identity_archive,
 					)
 					.await
 					.map(|r| r.0)
-					.map_err(Into::into)
-				} else if config.chain_spec.is_spiritnet() {
-					crate::service::start_node::<SpiritnetRuntimeExecutor, spiritnet_runtime::RuntimeApi>(
+					.map_err(Into::into),
+					ParachainRuntime::Spiritnet => crate::service::start_node::<
+						SpiritnetRuntimeExecutor,
+						spiritnet_runtime::RuntimeApi,
+						spiritnet_runtime::Runtime,
+						_,
+					>(
 						config,
 						polkadot_config,
 						collator_options,
 						id,
 						hwbench,
+						move |client, _, executor, _| crate::rpc::create_did(client, executor, rate_limiter.clone()),
+						rpc_method_filter,
+						cli.identity_archive,
 					)
 					.await
 					.map(|r| r.0)
-					.map_err(Into::into)
-				} else if config.chain_spec.is_clone() {
-					crate::service::start_node::<CloneRuntimeExecutor, clone_runtime::RuntimeApi>(
+					.map_err(Into::into),
+					ParachainRuntime::Clone => crate::service::start_node::<
+						CloneRuntimeExecutor,
+						clone_runtime::RuntimeApi,
+						clone_runtime::Runtime,
+						_,
+					>(
 						config,
 						polkadot_config,
 						collator_options,
 						id,
 						hwbench,
+						|_, _, _, _| Ok(crate::rpc::RpcExtension::new(())),
+						rpc_method_filter,
+						cli.identity_archive,
 					)
 					.await
 					.map(|r| r.0)
-					.map_err(Into::into)
-				} else {
-					Err("Unknown KILT parachain runtime, neither Spiritnet nor Peregrine".into())
+					.map_err(Into::into),
 				}
 			})
 		}
@@ -0,0 +1,532 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+//! Retention of the full history of the identity storage on pruned nodes.
+//!
+//! The state database of a node prunes the state of all storage items alike.
+//! Credential verifiers that need to look up historical DIDs, web3names,
+//! attestations and DIP commitments would therefore have to run full archive
+//! nodes. Instead, when started with `--identity-archive`, the node records
+//! every change to the storage of the identity pallets in finalized blocks in
+//! its offchain database, while the rest of the state is pruned according to
+//! `--state-pruning`.
+//!
+//! The archive is made of ranges of consecutive blocks. Each range starts with
+//! a snapshot of the whole identity storage, followed by the changes of each
+//! block. A new range is started when the archive is first enabled, and
+//! whenever the node cannot compute the changes of the blocks finalized while
+//! it was not running, e.g., because their state has already been pruned.
+
+use std::{
+	collections::{BTreeMap, HashMap},
+	sync::Arc,
+};
+
+use futures::StreamExt;
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject},
+};
+use parity_scale_codec::{Decode, Encode};
+use sc_client_api::{BlockchainEvents, StorageProvider};
+use sp_blockchain::HeaderBackend;
+use sp_core::{
+	offchain::OffchainStorage,
+	storage::{StorageData, StorageKey},
+	twox_128,
+};
+use sp_runtime::traits::Header as HeaderT;
+
+use runtime_common::{Block, BlockNumber, Hash};
+
+const LOG_TARGET: &str = "kilt::identity-archive";
+
+/// The pallets whose storage is archived.
+const IDENTITY_PALLETS: [&str; 4] = ["Did", "Web3Names", "Attestation", "DipProvider"];
+
+/// The maximum number of blocks finalized while the archive was not running
+/// whose changes are computed from the state of the node. If more blocks are
+/// missing, a new range is started instead.
+const MAX_CATCH_UP_BLOCKS: BlockNumber = 256;
+
+/// The prefix of all the archive entries in the offchain database.
+const ARCHIVE_PREFIX: &[u8] = b"kilt::identity-archive";
+/// The key of the archived block ranges.
+const RANGES_KEY: &[u8] = b":ranges";
+
+/// The changes to the identity storage in a block. A `None` value indicates
+/// that the key has been removed.
+type Changes = Vec<(Vec<u8>, Option<Vec<u8>>)>;
+
+fn identity_prefixes() -> impl Iterator<Item = [u8; 16]> {
+	IDENTITY_PALLETS.iter().map(|pallet| twox_128(pallet.as_bytes()))
+}
+
+/// Whether the given storage key belongs to one of the archived pallets.
+pub(crate) fn is_identity_key(key: &[u8]) -> bool {
+	identity_prefixes().any(|prefix| key.starts_with(&prefix))
+}
+
+/// The number of blocks in which the given storage key changed.
+fn history_length_key(storage_key: &[u8]) -> Vec<u8> {
+	[b":history-length".as_slice(), storage_key].concat()
+}
+
+/// The number of the `index`-th block in which the given storage key changed.
+/// Each entry is written once, so that recording a change does not rewrite
+/// the whole history of the key.
+fn history_key(storage_key: &[u8], index: u32) -> Vec<u8> {
+	[b":history".as_slice(), storage_key, &index.to_be_bytes()].concat()
+}
+
+fn value_key(storage_key: &[u8], block_number: BlockNumber) -> Vec<u8> {
+	[b":value".as_slice(), storage_key, &block_number.to_be_bytes()].concat()
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) enum ArchiveError {
+	/// The storage key does not belong to one of the archived pallets.
+	NotIdentityKey,
+	/// The block is not part of any archived range.
+	NotArchived,
+	/// An archive entry is missing or cannot be decoded.
+	Corrupted,
+}
+
+/// The archive of the identity storage, stored in the offchain database.
+#[derive(Clone)]
+pub(crate) struct IdentityArchive<S> {
+	storage: S,
+}
+
+impl<S: OffchainStorage> IdentityArchive<S> {
+	pub(crate) fn new(storage: S) -> Self {
+		Self { storage }
+	}
+
+	fn get<T: Decode>(&self, key: &[u8]) -> Option<T> {
+		self.storage
+			.get(ARCHIVE_PREFIX, key)
+			.and_then(|raw| T::decode(&mut raw.as_slice()).ok())
+	}
+
+	/// The archived ranges of block numbers, both ends included.
+	pub(crate) fn ranges(&self) -> Vec<(BlockNumber, BlockNumber)> {
+		self.get(RANGES_KEY).unwrap_or_default()
+	}
+
+	/// The number of the last archived block, if any.
+	pub(crate) fn last_block(&self) -> Option<BlockNumber> {
+		self.ranges().last().map(|(_, end)| *end)
+	}
+
+	fn write_changes(&mut self, block_number: BlockNumber, changes: Changes) {
+		for (key, value) in changes {
+			let history_length: u32 = self.get(&history_length_key(&key)).unwrap_or_default();
+			let last_changed_at = history_length
+				.checked_sub(1)
+				.and_then(|index| self.get::<BlockNumber>(&history_key(&key, index)));
+			if last_changed_at != Some(block_number) {
+				self.storage.set(
+					ARCHIVE_PREFIX,
+					&history_key(&key, history_length),
+					&block_number.encode(),
+				);
+				self.storage.set(
+					ARCHIVE_PREFIX,
+					&history_length_key(&key),
+					&history_length.saturating_add(1).encode(),
+				);
+			}
+			self.storage
+				.set(ARCHIVE_PREFIX, &value_key(&key, block_number), &value.encode());
+		}
+	}
+
+	/// Start a new range with the whole identity storage at the given block.
+	pub(crate) fn record_snapshot(&mut self, block_number: BlockNumber, state: BTreeMap<Vec<u8>, Vec<u8>>) {
+		self.write_changes(
+			block_number,
+			state.into_iter().map(|(key, value)| (key, Some(value))).collect(),
+		);
+		let mut ranges = self.ranges();
+		ranges.push((block_number, block_number));
+		self.storage.set(ARCHIVE_PREFIX, RANGES_KEY, &ranges.encode());
+	}
+
+	/// Extend the last range with the changes of the next block.
+	pub(crate) fn record_block(&mut self, block_number: BlockNumber, changes: Changes) {
+		self.write_changes(block_number, changes);
+		let mut ranges = self.ranges();
+		if let Some((_, end)) = ranges.last_mut() {
+			*end = block_number;
+		}
+		self.storage.set(ARCHIVE_PREFIX, RANGES_KEY, &ranges.encode());
+	}
+
+	/// Return the value of the given storage key at the given block.
+	pub(crate) fn storage_at(&self, key: &[u8], block_number: BlockNumber) -> Result<Option<Vec<u8>>, ArchiveError> {
+		if !is_identity_key(key) {
+			return Err(ArchiveError::NotIdentityKey);
+		}
+		let (range_start, _) = self
+			.ranges()
+			.into_iter()
+			.find(|(start, end)| (*start..=*end).contains(&block_number))
+			.ok_or(ArchiveError::NotArchived)?;
+
+		let nth_change = |index: u32| {
+			self.get::<BlockNumber>(&history_key(key, index))
+				.ok_or(ArchiveError::Corrupted)
+		};
+
+		// Binary search for the number of changes up to the given block.
+		let (mut low, mut high) = (0u32, self.get::<u32>(&history_length_key(key)).unwrap_or_default());
+		while low < high {
+			let middle = low + (high - low) / 2;
+			if nth_change(middle)? <= block_number {
+				low = middle + 1;
+			} else {
+				high = middle;
+			}
+		}
+		match low.checked_sub(1).map(nth_change).transpose()? {
+			// Every key present at the beginning of a range is part of its snapshot, so a
+			// key last changed before the range was not present.
+			Some(changed_at) if changed_at >= range_start => self
+				.get::<Option<Vec<u8>>>(&value_key(key, changed_at))
+				.ok_or(ArchiveError::Corrupted),
+			_ => Ok(None),
+		}
+	}
+}
+
+/// Return the whole identity storage at the given block.
+fn identity_state<Client, Backend>(client: &Client, hash: Hash) -> sp_blockchain::Result<BTreeMap<Vec<u8>, Vec<u8>>>
+where
+	Client: StorageProvider<Block, Backend>,
+	Backend: sc_client_api::Backend<Block>,
+{
+	let mut state = BTreeMap::new();
+	for prefix in identity_prefixes() {
+		for (key, value) in client.storage_pairs(hash, Some(&StorageKey(prefix.to_vec())), None)? {
+			state.insert(key.0, value.0);
+		}
+	}
+	Ok(state)
+}
+
+/// Compute the changes to the identity storage in the given block from the
+/// state of the block and of its parent.
+fn identity_state_diff<Client, Backend>(
+	client: &Client,
+	parent_hash: Hash,
+	hash: Hash,
+) -> sp_blockchain::Result<Changes>
+where
+	Client: StorageProvider<Block, Backend>,
+	Backend: sc_client_api::Backend<Block>,
+{
+	let before = identity_state(client, parent_hash)?;
+	let after = identity_state(client, hash)?;
+
+	let removed = before
+		.keys()
+		.filter(|key| !after.contains_key(*key))
+		.map(|key| (key.clone(), None));
+	let updated = after
+		.iter()
+		.filter(|(key, value)| before.get(*key) != Some(value))
+		.map(|(key, value)| (key.clone(), Some(value.clone())));
+	Ok(removed.chain(updated).collect())
+}
+
+/// Archive the given finalized block, together with all the blocks finalized
+/// before it that have not been archived yet.
+fn archive_finalized<Client, Backend, S>(
+	client: &Client,
+	archive: &mut IdentityArchive<S>,
+	pending: &mut HashMap<Hash, Changes>,
+	hash: Hash,
+) -> sp_blockchain::Result<()>
+where
+	Client: StorageProvider<Block, Backend> + HeaderBackend<Block>,
+	Backend: sc_client_api::Backend<Block>,
+	S: OffchainStorage,
+{
+	let number = client
+		.number(hash)?
+		.ok_or(sp_blockchain::Error::UnknownBlock(format!("{:?}", hash)))?;
+	let snapshot = |archive: &mut IdentityArchive<S>| -> sp_blockchain::Result<()> {
+		log::info!(
+			target: LOG_TARGET,
+			"Starting a new identity archive range at block #{}",
+			number
+		);
+		archive.record_snapshot(number, identity_state(client, hash)?);
+		Ok(())
+	};
+
+	let Some(last) = archive.last_block() else {
+		return snapshot(archive);
+	};
+	if number <= last {
+		return Ok(());
+	}
+	if number - last > MAX_CATCH_UP_BLOCKS {
+		log::warn!(
+			target: LOG_TARGET,
+			"The identity storage history between blocks #{} and #{} is not available.",
+			last,
+			number
+		);
+		return snapshot(archive);
+	}
+
+	// Blocks finalized implicitly, or while the node was not running, are archived
+	// in order.
+	let mut blocks = Vec::new();
+	let mut current = hash;
+	for _ in last..number {
+		let header = client
+			.header(current)?
+			.ok_or(sp_blockchain::Error::UnknownBlock(format!("{:?}", current)))?;
+		blocks.push((current, *header.number(), *header.parent_hash()));
+		current = *header.parent_hash();
+	}
+	for (block_hash, block_number, parent_hash) in blocks.into_iter().rev() {
+		let changes = match pending.remove(&block_hash) {
+			Some(changes) => changes,
+			None => match identity_state_diff(client, parent_hash, block_hash) {
+				Ok(changes) => changes,
+				Err(e) => {
+					log::warn!(
+						target: LOG_TARGET,
+						"Failed to compute the identity storage changes of block #{}: {:?}",
+						block_number,
+						e
+					);
+					return snapshot(archive);
+				}
+			},
+		};
+		archive.record_block(block_number, changes);
+	}
+	Ok(())
+}
+
+enum ArchiveEvent<Changes, Finality> {
+	Imported(Changes),
+	Finalized(Finality),
+}
+
+/// Record the changes to the identity storage of every finalized block in the
+/// archive.
+pub(crate) async fn run_identity_archive<Client, Backend, S>(client: Arc<Client>, mut archive: IdentityArchive<S>)
+where
+	Client: BlockchainEvents<Block> + StorageProvider<Block, Backend> + HeaderBackend<Block>,
+	Backend: sc_client_api::Backend<Block>,
+	S: OffchainStorage,
+{
+	let storage_changes = match client.storage_changes_notification_stream(None, None) {
+		Ok(stream) => stream,
+		Err(e) => {
+			log::error!(target: LOG_TARGET, "Failed to subscribe to storage changes: {:?}", e);
+			return;
+		}
+	};
+	let mut events = futures::stream::select(
+		storage_changes.map(ArchiveEvent::Imported),
+		client.finality_notification_stream().map(ArchiveEvent::Finalized),
+	);
+	// The identity storage changes of imported blocks, until they are finalized.
+	// Every block changes the system storage, so blocks without an entry here
+	// were imported before the archive was started.
+	let mut pending = HashMap::<Hash, Changes>::new();
+
+	if let Err(e) = archive_finalized(&*client, &mut archive, &mut pending, client.info().finalized_hash) {
+		log::error!(target: LOG_TARGET, "Failed to initialize the identity archive: {:?}", e);
+	}
+
+	while let Some(event) = events.next().await {
+		match event {
+			ArchiveEvent::Imported(notification) => {
+				let changes = notification
+					.changes
+					.iter()
+					.filter(|(child_key, key, _)| child_key.is_none() && is_identity_key(&key.0))
+					.map(|(_, key, value)| (key.0.clone(), value.map(|value| value.0.clone())))
+					.collect();
+				pending.insert(notification.block, changes);
+			}
+			ArchiveEvent::Finalized(notification) => {
+				if let Err(e) = archive_finalized(&*client, &mut archive, &mut pending, notification.hash) {
+					log::error!(
+						target: LOG_TARGET,
+						"Failed to archive the identity storage of block {:?}: {:?}",
+						notification.hash,
+						e
+					);
+				}
+				// Blocks on other forks can no longer be finalized.
+				let finalized_number = *notification.header.number();
+				pending.retain(|hash, _| {
+					client
+						.number(*hash)
+						.ok()
+						.flatten()
+						.map_or(false, |number| number > finalized_number)
+				});
+			}
+		}
+	}
+}
+
+#[rpc(server)]
+pub trait IdentityArchiveApi {
+	/// Returns the value of a storage key of the DID, web3name, attestation or
+	/// DIP provider pallets at the given finalized block, or at the last
+	/// archived block if not specified.
+	#[method(name = "identityArchive_storage")]
+	fn storage(&self, key: StorageKey, at: Option<Hash>) -> RpcResult<Option<StorageData>>;
+
+	/// Returns the ranges of block numbers, both ends included, for which the
+	/// identity storage is available.
+	#[method(name = "identityArchive_ranges")]
+	fn ranges(&self) -> RpcResult<Vec<(BlockNumber, BlockNumber)>>;
+}
+
+/// Provides the archived identity storage to credential verifiers.
+pub struct IdentityArchiveRpc<C, S> {
+	client: Arc<C>,
+	archive: IdentityArchive<S>,
+}
+
+impl<C, S> IdentityArchiveRpc<C, S> {
+	/// Creates a new instance of the identity archive RPC handler.
+	pub(crate) fn new(client: Arc<C>, archive: IdentityArchive<S>) -> Self {
+		Self { client, archive }
+	}
+}
+
+fn rpc_error(code: i32, message: &str) -> jsonrpsee::core::Error {
+	CallError::Custom(ErrorObject::owned(code, message, None::<()>)).into()
+}
+
+impl<C, S> IdentityArchiveApiServer for IdentityArchiveRpc<C, S>
+where
+	C: HeaderBackend<Block> + Send + Sync + 'static,
+	S: OffchainStorage + 'static,
+{
+	fn storage(&self, key: StorageKey, at: Option<Hash>) -> RpcResult<Option<StorageData>> {
+		let block_number = match at {
+			Some(hash) => {
+				let number = self
+					.client
+					.number(hash)
+					.ok()
+					.flatten()
+					.ok_or_else(|| rpc_error(1, "Unknown block."))?;
+				// Only blocks on the finalized chain are archived.
+				if self.client.hash(number).ok().flatten() != Some(hash) {
+					return Err(rpc_error(2, "The block is not part of the finalized chain."));
+				}
+				number
+			}
+			None => self
+				.archive
+				.last_block()
+				.ok_or_else(|| rpc_error(3, "The identity archive is empty."))?,
+		};
+
+		self.archive
+			.storage_at(&key.0, block_number)
+			.map(|value| value.map(StorageData))
+			.map_err(|e| match e {
+				ArchiveError::NotIdentityKey => rpc_error(4, "The key is not part of the identity storage."),
+				ArchiveError::NotArchived => rpc_error(3, "The block has not been archived."),
+				ArchiveError::Corrupted => rpc_error(5, "The identity archive is corrupted."),
+			})
+	}
+
+	fn ranges(&self) -> RpcResult<Vec<(BlockNumber, BlockNumber)>> {
+		Ok(self.archive.ranges())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use sp_core::offchain::storage::InMemOffchainStorage;
+
+	use super::*;
+
+	fn did_key(suffix: u8) -> Vec<u8> {
+		[twox_128(b"Did").as_slice(), &[suffix]].concat()
+	}
+
+	#[test]
+	fn storage_at_follows_ranges_and_changes() {
+		let mut archive = IdentityArchive::new(InMemOffchainStorage::default());
+		assert_eq!(archive.storage_at(&did_key(1), 10), Err(ArchiveError::NotArchived));
+
+		archive.record_snapshot(10, BTreeMap::from([(did_key(1), vec![1]), (did_key(2), vec![2])]));
+		archive.record_block(11, vec![(did_key(1), Some(vec![3])), (did_key(3), Some(vec![4]))]);
+		archive.record_block(12, vec![(did_key(2), None)]);
+
+		assert_eq!(archive.storage_at(&did_key(1), 10), Ok(Some(vec![1])));
+		assert_eq!(archive.storage_at(&did_key(1), 12), Ok(Some(vec![3])));
+		assert_eq!(archive.storage_at(&did_key(2), 11), Ok(Some(vec![2])));
+		assert_eq!(archive.storage_at(&did_key(2), 12), Ok(None));
+		assert_eq!(archive.storage_at(&did_key(3), 10), Ok(None));
+		assert_eq!(archive.storage_at(&did_key(3), 11), Ok(Some(vec![4])));
+		assert_eq!(archive.storage_at(&did_key(1), 13), Err(ArchiveError::NotArchived));
+
+		// A key removed while the archive was not running is not part of the new
+		// range's snapshot.
+		archive.record_snapshot(20, BTreeMap::from([(did_key(1), vec![5])]));
+		assert_eq!(archive.ranges(), vec![(10, 12), (20, 20)]);
+		assert_eq!(archive.storage_at(&did_key(3), 20), Ok(None));
+		assert_eq!(archive.storage_at(&did_key(1), 20), Ok(Some(vec![5])));
+		assert_eq!(archive.storage_at(&did_key(1), 15), Err(ArchiveError::NotArchived));
+	}
+
+	#[test]
+	fn storage_at_finds_the_last_change_in_a_long_history() {
+		let mut archive = IdentityArchive::new(InMemOffchainStorage::default());
+		archive.record_snapshot(0, BTreeMap::from([(did_key(1), vec![0])]));
+		for block_number in (2..100).step_by(2) {
+			archive.record_block(block_number - 1, vec![]);
+			archive.record_block(block_number, vec![(did_key(1), Some(vec![block_number as u8]))]);
+		}
+
+		assert_eq!(archive.storage_at(&did_key(1), 0), Ok(Some(vec![0])));
+		assert_eq!(archive.storage_at(&did_key(1), 1), Ok(Some(vec![0])));
+		assert_eq!(archive.storage_at(&did_key(1), 51), Ok(Some(vec![50])));
+		assert_eq!(archive.storage_at(&did_key(1), 98), Ok(Some(vec![98])));
+	}
+
+	#[test]
+	fn storage_at_rejects_other_keys() {
+		let mut archive = IdentityArchive::new(InMemOffchainStorage::default());
+		archive.record_snapshot(1, BTreeMap::new());
+
+		let balances_key = twox_128(b"Balances").to_vec();
+		assert_eq!(archive.storage_at(&balances_key, 1), Err(ArchiveError::NotIdentityKey));
+	}
+}
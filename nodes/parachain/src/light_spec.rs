@@ -0,0 +1,109 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+//! Chain specifications for light clients, e.g., smoldot and
+//! substrate-connect.
+//!
+//! Parachain light clients follow finality via the relay chain, so there is
+//! no GRANDPA or BABE light sync state to embed. Instead, the raw genesis
+//! storage is replaced by the genesis state root, which is all a light client
+//! needs to verify the chain starting from the parachain head it obtains from
+//! the relay chain.
+
+use std::sync::Arc;
+
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject},
+};
+use sc_chain_spec::ChainSpec;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Header as HeaderT;
+
+use runtime_common::{Block, Hash};
+
+/// Returns the raw chain specification with the genesis storage replaced by
+/// the given genesis state root.
+pub(crate) fn light_chain_spec(spec: &dyn ChainSpec, genesis_state_root: Hash) -> Result<serde_json::Value, String> {
+	let mut json: serde_json::Value = serde_json::from_str(&spec.as_json(true)?).map_err(|e| e.to_string())?;
+	json["genesis"] = serde_json::json!({ "stateRootHash": genesis_state_root });
+
+	Ok(json)
+}
+
+#[rpc(server)]
+pub trait LightSpecApi {
+	/// Returns the chain specification of the node for light clients.
+	///
+	/// If `raw` is false, the human-readable chain specification is returned
+	/// instead.
+	#[method(name = "sync_state_genSyncSpec")]
+	fn gen_sync_spec(&self, raw: bool) -> RpcResult<serde_json::Value>;
+}
+
+/// Provides the chain specification of the node to light clients.
+pub struct LightSpec<C> {
+	chain_spec: Box<dyn ChainSpec>,
+	client: Arc<C>,
+}
+
+impl<C> LightSpec<C> {
+	/// Creates a new instance of the light client chain spec RPC handler.
+	pub fn new(chain_spec: Box<dyn ChainSpec>, client: Arc<C>) -> Self {
+		Self { chain_spec, client }
+	}
+
+	fn light_chain_spec(&self) -> Result<serde_json::Value, String>
+	where
+		C: HeaderBackend<Block>,
+	{
+		let genesis_hash = self.client.info().genesis_hash;
+		let genesis_header = self
+			.client
+			.header(genesis_hash)
+			.map_err(|e| e.to_string())?
+			.ok_or("Genesis header not found")?;
+
+		light_chain_spec(&*self.chain_spec, *genesis_header.state_root())
+	}
+}
+
+impl<C> LightSpecApiServer for LightSpec<C>
+where
+	C: HeaderBackend<Block> + Send + Sync + 'static,
+{
+	fn gen_sync_spec(&self, raw: bool) -> RpcResult<serde_json::Value> {
+		let spec = if raw {
+			self.light_chain_spec()
+		} else {
+			self.chain_spec
+				.as_json(false)
+				.and_then(|json| serde_json::from_str(&json).map_err(|e| e.to_string()))
+		};
+
+		spec.map_err(|e| {
+			CallError::Custom(ErrorObject::owned(
+				1,
+				"Unable to generate the light client chain spec.",
+				Some(e),
+			))
+			.into()
+		})
+	}
+}
@@ -20,11 +20,15 @@
 
 #![warn(missing_docs)]
 
+mod benchmarking;
 mod chain_spec;
 #[macro_use]
 mod service;
 mod cli;
 mod command;
+mod identity_archive;
+mod light_spec;
+mod metrics;
 mod rpc;
 
 fn main() -> sc_cli::Result<()> {
@@ -0,0 +1,223 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+//! Prometheus metrics about the activity of the identity layer, i.e., DIDs,
+//! web3names and DIP commitments.
+
+use std::sync::Arc;
+
+use futures::StreamExt;
+use parity_scale_codec::Decode;
+use sc_client_api::{BlockchainEvents, StorageProvider};
+use sp_core::{storage::StorageKey, twox_128};
+use substrate_prometheus_endpoint::{register, Counter, Gauge, PrometheusError, Registry, U64};
+
+use runtime_common::{Block, Hash};
+
+const LOG_TARGET: &str = "kilt::metrics";
+
+/// Identity-related activities tracked by the [`IdentityMetrics`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum IdentityActivity {
+	/// A DID has been created, updated or deleted, or has dispatched a call.
+	DidOperation,
+	/// A web3name has been claimed.
+	Web3NameClaimed,
+	/// A DIP identity commitment has been stored.
+	CommitmentStored,
+}
+
+/// Runtimes whose events can be mapped to [`IdentityActivity`]s.
+pub(crate) trait IdentityEvents {
+	/// The runtime event type.
+	type RuntimeEvent: Decode;
+
+	/// Returns the identity activity the event represents, if any.
+	fn activity(event: &Self::RuntimeEvent) -> Option<IdentityActivity>;
+}
+
+impl IdentityEvents for peregrine_runtime::Runtime {
+	type RuntimeEvent = peregrine_runtime::RuntimeEvent;
+
+	fn activity(event: &Self::RuntimeEvent) -> Option<IdentityActivity> {
+		use peregrine_runtime::RuntimeEvent;
+
+		match event {
+			RuntimeEvent::Did(
+				did::Event::DidCreated(..)
+				| did::Event::DidUpdated(..)
+				| did::Event::DidDeleted(..)
+				| did::Event::DidCallDispatched(..),
+			) => Some(IdentityActivity::DidOperation),
+			RuntimeEvent::Web3Names(pallet_web3_names::Event::Web3NameClaimed { .. }) => {
+				Some(IdentityActivity::Web3NameClaimed)
+			}
+			RuntimeEvent::DipProvider(pallet_dip_provider::Event::VersionedIdentityCommitted { .. }) => {
+				Some(IdentityActivity::CommitmentStored)
+			}
+			_ => None,
+		}
+	}
+}
+
+impl IdentityEvents for spiritnet_runtime::Runtime {
+	type RuntimeEvent = spiritnet_runtime::RuntimeEvent;
+
+	fn activity(event: &Self::RuntimeEvent) -> Option<IdentityActivity> {
+		use spiritnet_runtime::RuntimeEvent;
+
+		match event {
+			RuntimeEvent::Did(
+				did::Event::DidCreated(..)
+				| did::Event::DidUpdated(..)
+				| did::Event::DidDeleted(..)
+				| did::Event::DidCallDispatched(..),
+			) => Some(IdentityActivity::DidOperation),
+			RuntimeEvent::Web3Names(pallet_web3_names::Event::Web3NameClaimed { .. }) => {
+				Some(IdentityActivity::Web3NameClaimed)
+			}
+			_ => None,
+		}
+	}
+}
+
+impl IdentityEvents for clone_runtime::Runtime {
+	type RuntimeEvent = clone_runtime::RuntimeEvent;
+
+	fn activity(_event: &Self::RuntimeEvent) -> Option<IdentityActivity> {
+		None
+	}
+}
+
+/// Prometheus metrics about the activity of the identity layer.
+#[derive(Clone)]
+pub(crate) struct IdentityMetrics {
+	did_operations: Counter<U64>,
+	did_operations_per_block: Gauge<U64>,
+	web3name_claims: Counter<U64>,
+	commitments_stored: Counter<U64>,
+	proofs_generated: Counter<U64>,
+}
+
+impl IdentityMetrics {
+	pub(crate) fn register(registry: &Registry) -> Result<Self, PrometheusError> {
+		Ok(Self {
+			did_operations: register(
+				Counter::new("kilt_did_operations_total", "Number of finalized DID operations.")?,
+				registry,
+			)?,
+			did_operations_per_block: register(
+				Gauge::new(
+					"kilt_did_operations_per_block",
+					"Number of DID operations in the last finalized block.",
+				)?,
+				registry,
+			)?,
+			web3name_claims: register(
+				Counter::new("kilt_web3name_claims_total", "Number of finalized web3name claims.")?,
+				registry,
+			)?,
+			commitments_stored: register(
+				Counter::new(
+					"kilt_dip_commitments_stored_total",
+					"Number of finalized DIP identity commitments.",
+				)?,
+				registry,
+			)?,
+			proofs_generated: register(
+				Counter::new(
+					"kilt_dip_rpc_proofs_generated_total",
+					"Number of DIP proofs generated via RPC.",
+				)?,
+				registry,
+			)?,
+		})
+	}
+
+	/// The counter of DIP proofs generated via RPC.
+	pub(crate) fn proofs_generated(&self) -> Counter<U64> {
+		self.proofs_generated.clone()
+	}
+
+	fn note_block_activities(&self, activities: impl Iterator<Item = IdentityActivity>) {
+		let mut did_operations = 0u64;
+		for activity in activities {
+			match activity {
+				IdentityActivity::DidOperation => did_operations = did_operations.saturating_add(1),
+				IdentityActivity::Web3NameClaimed => self.web3name_claims.inc(),
+				IdentityActivity::CommitmentStored => self.commitments_stored.inc(),
+			}
+		}
+		self.did_operations.inc_by(did_operations);
+		self.did_operations_per_block.set(did_operations);
+	}
+}
+
+/// Minimal version of `frame_system::EventRecord`, only decoding what is
+/// needed to classify events.
+#[derive(Decode)]
+struct EventRecord<RuntimeEvent> {
+	_phase: frame_system::Phase,
+	event: RuntimeEvent,
+	_topics: Vec<Hash>,
+}
+
+/// Update the identity metrics with the events of every finalized block.
+pub(crate) async fn run_identity_metrics<R, Client, Backend>(client: Arc<Client>, metrics: IdentityMetrics)
+where
+	R: IdentityEvents,
+	Client: BlockchainEvents<Block> + StorageProvider<Block, Backend>,
+	Backend: sc_client_api::Backend<Block>,
+{
+	let events_key = StorageKey([twox_128(b"System"), twox_128(b"Events")].concat());
+	let mut finality_notifications = client.finality_notification_stream();
+
+	while let Some(notification) = finality_notifications.next().await {
+		// Blocks finalized implicitly are part of the tree route.
+		let finalized = notification
+			.tree_route
+			.iter()
+			.chain(std::iter::once(&notification.hash));
+
+		for hash in finalized {
+			let events = match client.storage(*hash, &events_key) {
+				Ok(Some(raw_events)) => {
+					match Vec::<EventRecord<R::RuntimeEvent>>::decode(&mut raw_events.0.as_slice()) {
+						Ok(events) => events,
+						Err(e) => {
+							log::debug!(
+								target: LOG_TARGET,
+								"Failed to decode events of block {:?}: {:?}",
+								hash,
+								e
+							);
+							continue;
+						}
+					}
+				}
+				Ok(None) => Vec::new(),
+				Err(e) => {
+					log::debug!(target: LOG_TARGET, "Failed to read events of block {:?}: {:?}", hash, e);
+					continue;
+				}
+			};
+
+			metrics.note_block_activities(events.iter().filter_map(|record| R::activity(&record.event)));
+		}
+	}
+}
@@ -25,18 +25,65 @@
 
 use std::sync::Arc;
 
-use sc_client_api::AuxStore;
+use cumulus_primitives_core::ParaId;
+use cumulus_relay_chain_interface::RelayChainInterface;
+use sc_client_api::{AuxStore, BlockchainEvents, ProofProvider};
 pub use sc_rpc_api::DenyUnsafe;
 use sc_transaction_pool_api::TransactionPool;
 use sp_api::ProvideRuntimeApi;
 use sp_block_builder::BlockBuilder;
 use sp_blockchain::{Error as BlockChainError, HeaderBackend, HeaderMetadata};
+use sp_core::traits::SpawnNamed;
 
-use runtime_common::{AccountId, Balance, Block, Nonce};
+use crate::metrics::IdentityMetrics;
+
+use kilt_rpc_rate_limiter::RateLimiter;
+
+use pallet_did_lookup::linkable_account::LinkableAccountId;
+use runtime_common::{
+	dip::merkle::{CompleteMerkleProof, DidMerkleProofOf},
+	AccountId, Balance, Block, BlockNumber, DidIdentifier, Hash, Nonce,
+};
 
 /// A type representing all RPC extensions.
 pub type RpcExtension = jsonrpsee::RpcModule<()>;
 
+/// Restricts the node-specific RPC methods that are exposed.
+#[derive(Clone, Debug, Default)]
+pub struct RpcMethodFilter {
+	/// If set, only these methods are exposed.
+	pub allowed: Option<Vec<String>>,
+	/// These methods are never exposed.
+	pub denied: Vec<String>,
+}
+
+impl RpcMethodFilter {
+	fn is_allowed(&self, method: &str) -> bool {
+		let allowed = self
+			.allowed
+			.as_ref()
+			.map_or(true, |allowed| allowed.iter().any(|m| m == method));
+		allowed && !self.denied.iter().any(|m| m == method)
+	}
+
+	/// Remove all methods from the module that are not allowed.
+	pub fn apply(&self, module: &mut RpcExtension) {
+		let removed: Vec<&'static str> = module.method_names().filter(|m| !self.is_allowed(m)).collect();
+		for method in removed {
+			module.remove_method(method);
+		}
+	}
+}
+
+/// Access to the relay chain the parachain is connected to.
+#[derive(Clone)]
+pub struct RelayChainDeps {
+	/// The interface to the relay chain.
+	pub relay_chain_interface: Arc<dyn RelayChainInterface>,
+	/// The ID of the parachain.
+	pub para_id: ParaId,
+}
+
 /// Full client dependencies.
 pub struct FullDeps<C, P> {
 	/// The client instance to use.
@@ -45,6 +92,8 @@ pub struct FullDeps<C, P> {
 	pub pool: Arc<P>,
 	/// Whether to deny unsafe calls
 	pub deny_unsafe: DenyUnsafe,
+	/// The chain specification of the node, provided to light clients.
+	pub chain_spec: Box<dyn sc_chain_spec::ChainSpec>,
 }
 
 /// Instantiate all RPC extensions.
@@ -65,15 +114,19 @@ where
 	use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
 	use substrate_frame_rpc_system::{System, SystemApiServer};
 
+	use crate::light_spec::{LightSpec, LightSpecApiServer};
+
 	let mut module = RpcExtension::new(());
 	let FullDeps {
 		client,
 		pool,
 		deny_unsafe,
+		chain_spec,
 	} = deps;
 
 	module.merge(System::new(client.clone(), pool, deny_unsafe).into_rpc())?;
-	module.merge(TransactionPayment::new(client).into_rpc())?;
+	module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
+	module.merge(LightSpec::new(chain_spec, client).into_rpc())?;
 	// Extend this RPC with a custom API by using the following syntax.
 	// `YourRpcStruct` should have a reference to a client, which is needed
 	// to call into the runtime.
@@ -82,3 +135,86 @@ where
 
 	Ok(module)
 }
+
+/// Instantiate the DID resolution and DID document subscription RPC
+/// extensions, for runtimes that include the DID, web3name and DID lookup
+/// pallets.
+pub fn create_did<C>(
+	client: Arc<C>,
+	subscription_executor: Arc<dyn SpawnNamed>,
+	rate_limiter: Option<Arc<RateLimiter>>,
+) -> Result<RpcExtension, Box<dyn std::error::Error + Send + Sync>>
+where
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + BlockchainEvents<Block> + Send + Sync + 'static,
+	C::Api: kilt_did_rpc::DidRuntimeApi<Block, DidIdentifier, AccountId, LinkableAccountId, Balance, Hash, BlockNumber>,
+{
+	use kilt_did_rpc::{DidApiServer, DidQuery, DidSubscription, DidSubscriptionApiServer};
+
+	let mut module = RpcExtension::new(());
+	module.merge(DidQuery::new(client.clone()).with_rate_limiter(rate_limiter).into_rpc())?;
+	module.merge(DidSubscription::new(client, subscription_executor).into_rpc())?;
+
+	Ok(module)
+}
+
+/// The storage key of the identity commitments in the Peregrine runtime.
+struct PeregrineIdentityCommitmentStorageKey;
+
+impl kilt_dip_provider_rpc::IdentityCommitmentStorageKey<DidIdentifier, pallet_dip_provider::IdentityCommitmentVersion>
+	for PeregrineIdentityCommitmentStorageKey
+{
+	fn identity_commitment_storage_key(
+		identifier: &DidIdentifier,
+		version: &pallet_dip_provider::IdentityCommitmentVersion,
+	) -> Vec<u8> {
+		pallet_dip_provider::IdentityCommitments::<peregrine_runtime::Runtime>::hashed_key_for(identifier, version)
+	}
+}
+
+/// Instantiate the DIP proof generation and DIP proof bundle RPC extensions,
+/// for runtimes that include the DIP provider pallet.
+pub fn create_dip_provider<C>(
+	client: Arc<C>,
+	metrics: Option<&IdentityMetrics>,
+	subscription_executor: Arc<dyn SpawnNamed>,
+	relay_chain_deps: RelayChainDeps,
+	rate_limiter: Option<Arc<RateLimiter>>,
+) -> Result<RpcExtension, Box<dyn std::error::Error + Send + Sync>>
+where
+	C: ProvideRuntimeApi<Block>
+		+ HeaderBackend<Block>
+		+ BlockchainEvents<Block>
+		+ ProofProvider<Block>
+		+ Send
+		+ Sync
+		+ 'static,
+	C::Api: kilt_did_rpc::DidRuntimeApi<Block, DidIdentifier, AccountId, LinkableAccountId, Balance, Hash, BlockNumber>,
+	C::Api: kilt_dip_provider_rpc::DipProviderRuntimeApi<
+		Block,
+		peregrine_runtime::dip::runtime_api::DipProofRequest,
+		CompleteMerkleProof<Hash, DidMerkleProofOf<peregrine_runtime::Runtime>>,
+		peregrine_runtime::dip::runtime_api::DipProofError,
+	>,
+{
+	use kilt_dip_provider_rpc::{DipProof, DipProofBundle, DipProofBundleApiServer, DipProviderApiServer};
+
+	let RelayChainDeps {
+		relay_chain_interface,
+		para_id,
+	} = relay_chain_deps;
+
+	let mut module = create_did(client.clone(), subscription_executor, rate_limiter.clone())?;
+	module.merge(
+		DipProof::new(client.clone())
+			.with_proofs_counter(metrics.map(IdentityMetrics::proofs_generated))
+			.with_rate_limiter(rate_limiter.clone())
+			.into_rpc(),
+	)?;
+	module.merge(
+		DipProofBundle::<_, _, PeregrineIdentityCommitmentStorageKey>::new(client, relay_chain_interface, para_id)
+			.with_rate_limiter(rate_limiter)
+			.into_rpc(),
+	)?;
+
+	Ok(module)
+}
@@ -21,29 +21,46 @@ use jsonrpsee::RpcModule;
 
 use cumulus_client_cli::CollatorOptions;
 use cumulus_client_consensus_aura::{AuraConsensus, BuildAuraConsensusParams, SlotProportion};
-use cumulus_client_consensus_common::{ParachainBlockImport as TParachainBlockImport, ParachainConsensus};
+use cumulus_client_consensus_common::{
+	ParachainBlockImport as TParachainBlockImport, ParachainCandidate, ParachainConsensus,
+};
+use cumulus_client_consensus_relay_chain::Verifier as RelayChainVerifier;
 use cumulus_client_service::{
 	prepare_node_config, start_collator, start_full_node, StartCollatorParams, StartFullNodeParams,
 };
-use cumulus_primitives_core::ParaId;
+use cumulus_primitives_core::{relay_chain::Hash as PHash, ParaId, PersistedValidationData};
 use cumulus_relay_chain_inprocess_interface::build_inprocess_relay_chain;
 use cumulus_relay_chain_interface::{RelayChainInterface, RelayChainResult};
 use cumulus_relay_chain_minimal_node::build_minimal_relay_chain_node;
+use futures::{lock::Mutex, FutureExt};
 use polkadot_service::{CollatorPair, NativeExecutionDispatch};
-use sc_consensus::ImportQueue;
+use sc_client_api::Backend;
+use sc_consensus::{
+	import_queue::{BasicQueue, Verifier as VerifierT},
+	BlockImportParams, ImportQueue,
+};
 use sc_executor::NativeElseWasmExecutor;
 use sc_network::NetworkBlock;
 use sc_network_sync::SyncingService;
 use sc_service::{Configuration, TFullBackend, TFullClient, TaskManager};
 use sc_telemetry::{Telemetry, TelemetryHandle, TelemetryWorker, TelemetryWorkerHandle};
-use sp_api::ConstructRuntimeApi;
+use sc_transaction_pool_api::OffchainTransactionPoolFactory;
+use sp_api::{ApiExt, ConstructRuntimeApi, ProvideRuntimeApi};
+use sp_consensus_aura::AuraApi;
+use sp_core::traits::SpawnNamed;
 use sp_keystore::KeystorePtr;
-use sp_runtime::traits::BlakeTwo256;
+use sp_runtime::traits::{BlakeTwo256, Header as HeaderT};
 use std::{sync::Arc, time::Duration};
 use substrate_prometheus_endpoint::Registry;
 
 use runtime_common::{AccountId, AuthorityId, Balance, BlockNumber, Nonce};
 
+use crate::{
+	identity_archive::{run_identity_archive, IdentityArchive, IdentityArchiveApiServer, IdentityArchiveRpc},
+	metrics::{run_identity_metrics, IdentityEvents, IdentityMetrics},
+	rpc::{RelayChainDeps, RpcMethodFilter},
+};
+
 type Header = sp_runtime::generic::Header<BlockNumber, sp_runtime::traits::BlakeTwo256>;
 
 pub type Block = sp_runtime::generic::Block<Header, sp_runtime::OpaqueExtrinsic>;
@@ -235,12 +252,14 @@ async fn build_relay_chain_interface(
 /// runtime api.
 #[allow(clippy::too_many_arguments)]
 #[sc_tracing::logging::prefix_logs_with("Parachain")]
-async fn start_node_impl<RuntimeApi, Executor, RB, BIQ, BIC>(
+async fn start_node_impl<RuntimeApi, Executor, Runtime, RB, BIQ, BIC>(
 	parachain_config: Configuration,
 	polkadot_config: Configuration,
 	collator_options: CollatorOptions,
 	id: ParaId,
-	_rpc_ext_builder: RB,
+	rpc_ext_builder: RB,
+	rpc_method_filter: RpcMethodFilter,
+	identity_archive: bool,
 	build_import_queue: BIQ,
 	build_consensus: BIC,
 	hwbench: Option<sc_sysinfo::HwBench>,
@@ -264,9 +283,13 @@ where
 		+ substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
 	sc_client_api::StateBackendFor<TFullBackend<Block>, Block>: sp_api::StateBackend<BlakeTwo256>,
 	Executor: sc_executor::NativeExecutionDispatch + 'static,
-	RB: FnOnce(
+	Runtime: IdentityEvents,
+	RB: Fn(
 			Arc<TFullClient<Block, RuntimeApi, NativeElseWasmExecutor<Executor>>>,
-		) -> Result<RpcModule<()>, sc_service::Error>
+			Option<&IdentityMetrics>,
+			Arc<dyn SpawnNamed>,
+			RelayChainDeps,
+		) -> Result<RpcModule<()>, Box<dyn std::error::Error + Send + Sync>>
 		+ Send
 		+ 'static,
 	BIQ: FnOnce(
@@ -316,6 +339,11 @@ where
 	let force_authoring = parachain_config.force_authoring;
 	let validator = parachain_config.role.is_authority();
 	let prometheus_registry = parachain_config.prometheus_registry().cloned();
+	let identity_metrics = prometheus_registry
+		.as_ref()
+		.map(IdentityMetrics::register)
+		.transpose()
+		.map_err(|e| sc_service::Error::Application(Box::new(e)))?;
 	let transaction_pool = params.transaction_pool.clone();
 	let import_queue_service = params.import_queue.service();
 	let net_config = sc_network::config::FullNetworkConfiguration::new(&parachain_config.network);
@@ -332,18 +360,73 @@ where
 		})
 		.await?;
 
+	// Offchain indexing is configured on the client via
+	// `--enable-offchain-indexing`, the workers are only run if enabled via
+	// `--offchain-worker`.
+	if parachain_config.offchain_worker.enabled {
+		task_manager.spawn_handle().spawn(
+			"offchain-workers-runner",
+			"offchain-worker",
+			sc_offchain::OffchainWorkers::new(sc_offchain::OffchainWorkerOptions {
+				runtime_api_provider: client.clone(),
+				is_validator: validator,
+				keystore: Some(params.keystore_container.keystore()),
+				offchain_db: backend.offchain_storage(),
+				transaction_pool: Some(OffchainTransactionPoolFactory::new(transaction_pool.clone())),
+				network_provider: network.clone(),
+				enable_http_requests: false,
+				custom_extensions: |_| vec![],
+			})
+			.run(client.clone(), task_manager.spawn_handle())
+			.boxed(),
+		);
+	}
+
+	let identity_archive = identity_archive
+		.then(|| {
+			backend
+				.offchain_storage()
+				.map(IdentityArchive::new)
+				.ok_or_else(|| sc_service::Error::Other("The identity archive requires the offchain database.".into()))
+		})
+		.transpose()?;
+
 	let rpc_builder = {
 		let client = client.clone();
+		let identity_archive = identity_archive.clone();
 		let transaction_pool = transaction_pool.clone();
+		let identity_metrics = identity_metrics.clone();
+		let chain_spec = parachain_config.chain_spec.cloned_box();
+		let relay_chain_deps = RelayChainDeps {
+			relay_chain_interface: relay_chain_interface.clone(),
+			para_id: id,
+		};
 
-		Box::new(move |deny_unsafe, _| {
+		Box::new(move |deny_unsafe, subscription_executor| {
 			let deps = crate::rpc::FullDeps {
 				client: client.clone(),
 				pool: transaction_pool.clone(),
 				deny_unsafe,
+				chain_spec: chain_spec.cloned_box(),
 			};
 
-			crate::rpc::create_full(deps).map_err(Into::into)
+			let mut module = crate::rpc::create_full(deps)?;
+			module
+				.merge(rpc_ext_builder(
+					client.clone(),
+					identity_metrics.as_ref(),
+					subscription_executor,
+					relay_chain_deps.clone(),
+				)?)
+				.map_err(|e| sc_service::Error::Application(Box::new(e)))?;
+			if let Some(identity_archive) = identity_archive.clone() {
+				module
+					.merge(IdentityArchiveRpc::new(client.clone(), identity_archive).into_rpc())
+					.map_err(|e| sc_service::Error::Application(Box::new(e)))?;
+			}
+			rpc_method_filter.apply(&mut module);
+
+			Ok(module)
 		})
 	};
 
@@ -362,6 +445,22 @@ where
 		telemetry: telemetry.as_mut(),
 	})?;
 
+	if let Some(identity_metrics) = identity_metrics {
+		task_manager.spawn_handle().spawn(
+			"identity-metrics",
+			None,
+			run_identity_metrics::<Runtime, _, _>(client.clone(), identity_metrics),
+		);
+	}
+
+	if let Some(identity_archive) = identity_archive {
+		task_manager.spawn_handle().spawn(
+			"identity-archive",
+			None,
+			run_identity_archive(client.clone(), identity_archive),
+		);
+	}
+
 	if let Some(hwbench) = hwbench {
 		sc_sysinfo::print_hwbench(&hwbench);
 
@@ -440,8 +539,65 @@ where
 	Ok((task_manager, client))
 }
 
+/// A value which is only built the first time it is accessed.
+///
+/// Used to delay the setup of the AURA components until the runtime actually
+/// exposes the AURA runtime API, which is not the case for chains that are
+/// still (or were historically) running with relay-chain consensus.
+enum BuildOnAccess<R> {
+	Uninitialized(Option<Box<dyn FnOnce() -> R + Send + Sync>>),
+	Initialized(R),
+}
+
+impl<R> BuildOnAccess<R> {
+	fn get_mut(&mut self) -> &mut R {
+		loop {
+			match self {
+				Self::Uninitialized(f) => {
+					*self = Self::Initialized((f.take().expect("Value is only taken once. qed"))());
+				}
+				Self::Initialized(ref mut r) => return r,
+			}
+		}
+	}
+}
+
+/// Import queue verifier that verifies blocks with AURA if the runtime of the
+/// parent block supports it, and with relay-chain consensus otherwise.
+///
+/// This allows nodes to sync blocks that were authored before the chain
+/// switched from relay-chain consensus to AURA.
+struct Verifier<Client> {
+	client: Arc<Client>,
+	aura_verifier: BuildOnAccess<Box<dyn VerifierT<Block>>>,
+	relay_chain_verifier: Box<dyn VerifierT<Block>>,
+}
+
+#[async_trait::async_trait]
+impl<Client> VerifierT<Block> for Verifier<Client>
+where
+	Client: ProvideRuntimeApi<Block> + Send + Sync,
+	Client::Api: AuraApi<Block, AuthorityId>,
+{
+	async fn verify(&mut self, block_import: BlockImportParams<Block>) -> Result<BlockImportParams<Block>, String> {
+		if self
+			.client
+			.runtime_api()
+			.has_api::<dyn AuraApi<Block, AuthorityId>>(*block_import.header.parent_hash())
+			.unwrap_or(false)
+		{
+			self.aura_verifier.get_mut().verify(block_import).await
+		} else {
+			self.relay_chain_verifier.verify(block_import).await
+		}
+	}
+}
+
 #[allow(clippy::type_complexity)]
 /// Build the import queue for THE runtime.
+///
+/// Blocks are verified with AURA if the runtime of their parent supports it,
+/// and with relay-chain consensus otherwise.
 pub fn build_import_queue<RE, API>(
 	client: Arc<TFullClient<Block, API, NativeElseWasmExecutor<RE>>>,
 	block_import: ParachainBlockImport<API, RE>,
@@ -467,40 +623,79 @@ where
 		+ cumulus_primitives_core::CollectCollationInfo<Block>,
 	sc_client_api::StateBackendFor<TFullBackend<Block>, Block>: sp_api::StateBackend<BlakeTwo256>,
 {
-	let slot_duration = cumulus_client_consensus_aura::slot_duration(&*client)?;
+	let aura_verifier = {
+		let client = client.clone();
 
-	cumulus_client_consensus_aura::import_queue::<sp_consensus_aura::sr25519::AuthorityPair, _, _, _, _, _>(
-		cumulus_client_consensus_aura::ImportQueueParams {
-			block_import,
-			client,
-			create_inherent_data_providers: move |_, _| async move {
-				let timestamp = sp_timestamp::InherentDataProvider::from_system_time();
+		move || {
+			let slot_duration = cumulus_client_consensus_aura::slot_duration(&*client)
+				.expect("AURA verifier is only built once the runtime exposes the AURA API. qed");
+
+			Box::new(cumulus_client_consensus_aura::build_verifier::<
+				sp_consensus_aura::sr25519::AuthorityPair,
+				_,
+				_,
+				_,
+			>(cumulus_client_consensus_aura::BuildVerifierParams {
+				client,
+				create_inherent_data_providers: move |_, _| async move {
+					let timestamp = sp_timestamp::InherentDataProvider::from_system_time();
+
+					let slot = sp_consensus_aura::inherents::InherentDataProvider::from_timestamp_and_slot_duration(
+						*timestamp,
+						slot_duration,
+					);
+
+					Ok((slot, timestamp))
+				},
+				telemetry,
+			})) as Box<_>
+		}
+	};
 
-				let slot = sp_consensus_aura::inherents::InherentDataProvider::from_timestamp_and_slot_duration(
-					*timestamp,
-					slot_duration,
-				);
+	let relay_chain_verifier = Box::new(RelayChainVerifier::new(client.clone(), |_, _| async { Ok(()) })) as Box<_>;
 
-				Ok((slot, timestamp))
-			},
-			registry: config.prometheus_registry(),
-			spawner: &task_manager.spawn_essential_handle(),
-			telemetry,
-		},
-	)
-	.map_err(Into::into)
+	let verifier = Verifier {
+		client,
+		aura_verifier: BuildOnAccess::Uninitialized(Some(Box::new(aura_verifier))),
+		relay_chain_verifier,
+	};
+
+	Ok(BasicQueue::new(
+		verifier,
+		Box::new(block_import),
+		None,
+		&task_manager.spawn_essential_handle(),
+		config.prometheus_registry(),
+	))
 }
 
 /// Start a parachain node.
-pub async fn start_node<RE, API>(
+///
+/// The RPC methods returned by `rpc_ext_builder` are exposed in addition to
+/// the ones common to all runtimes, can run subscriptions on the given
+/// executor and can read the relay chain state. If Prometheus is enabled, the identity activity is tracked using
+/// the events of the given `Runtime`.
+pub async fn start_node<RE, API, Runtime, RB>(
 	parachain_config: Configuration,
 	polkadot_config: Configuration,
 	collator_options: CollatorOptions,
 	id: ParaId,
 	hwbench: Option<sc_sysinfo::HwBench>,
+	rpc_ext_builder: RB,
+	rpc_method_filter: RpcMethodFilter,
+	identity_archive: bool,
 ) -> sc_service::error::Result<(TaskManager, Arc<TFullClient<Block, API, NativeElseWasmExecutor<RE>>>)>
 where
 	RE: sc_executor::NativeExecutionDispatch + 'static,
+	Runtime: IdentityEvents,
+	RB: Fn(
+			Arc<TFullClient<Block, API, NativeElseWasmExecutor<RE>>>,
+			Option<&IdentityMetrics>,
+			Arc<dyn SpawnNamed>,
+			RelayChainDeps,
+		) -> Result<RpcModule<()>, Box<dyn std::error::Error + Send + Sync>>
+		+ Send
+		+ 'static,
 	API: ConstructRuntimeApi<Block, TFullClient<Block, API, NativeElseWasmExecutor<RE>>> + Send + Sync + 'static,
 	API::RuntimeApi: sp_transaction_pool::runtime_api::TaggedTransactionQueue<Block>
 		+ sp_api::Metadata<Block>
@@ -514,12 +709,14 @@ where
 		+ cumulus_primitives_core::CollectCollationInfo<Block>,
 	sc_client_api::StateBackendFor<TFullBackend<Block>, Block>: sp_api::StateBackend<BlakeTwo256>,
 {
-	start_node_impl::<API, RE, _, _, _>(
+	start_node_impl::<API, RE, Runtime, _, _, _>(
 		parachain_config,
 		polkadot_config,
 		collator_options,
 		id,
-		|_| Ok(RpcModule::new(())),
+		rpc_ext_builder,
+		rpc_method_filter,
+		identity_archive,
 		build_import_queue::<RE, API>,
 		build_consensus::<RE, API>,
 		hwbench,
@@ -527,6 +724,58 @@ where
 	.await
 }
 
+/// Collator consensus that authors blocks with relay-chain consensus until the
+/// runtime exposes the AURA runtime API, and with AURA from then on.
+struct WaitForAuraConsensus<Client> {
+	client: Arc<Client>,
+	aura_consensus: Arc<Mutex<BuildOnAccess<Box<dyn ParachainConsensus<Block>>>>>,
+	relay_chain_consensus: Arc<Mutex<Box<dyn ParachainConsensus<Block>>>>,
+}
+
+impl<Client> Clone for WaitForAuraConsensus<Client> {
+	fn clone(&self) -> Self {
+		Self {
+			client: self.client.clone(),
+			aura_consensus: self.aura_consensus.clone(),
+			relay_chain_consensus: self.relay_chain_consensus.clone(),
+		}
+	}
+}
+
+#[async_trait::async_trait]
+impl<Client> ParachainConsensus<Block> for WaitForAuraConsensus<Client>
+where
+	Client: ProvideRuntimeApi<Block> + Send + Sync,
+	Client::Api: AuraApi<Block, AuthorityId>,
+{
+	async fn produce_candidate(
+		&mut self,
+		parent: &Header,
+		relay_parent: PHash,
+		validation_data: &PersistedValidationData,
+	) -> Option<ParachainCandidate<Block>> {
+		if self
+			.client
+			.runtime_api()
+			.has_api::<dyn AuraApi<Block, AuthorityId>>(parent.hash())
+			.unwrap_or(false)
+		{
+			self.aura_consensus
+				.lock()
+				.await
+				.get_mut()
+				.produce_candidate(parent, relay_parent, validation_data)
+				.await
+		} else {
+			self.relay_chain_consensus
+				.lock()
+				.await
+				.produce_candidate(parent, relay_parent, validation_data)
+				.await
+		}
+	}
+}
+
 #[allow(clippy::too_many_arguments)]
 fn build_consensus<RE, API>(
 	client: Arc<ParachainClient<API, RE>>,
@@ -556,62 +805,108 @@ where
 		+ cumulus_primitives_core::CollectCollationInfo<Block>,
 	sc_client_api::StateBackendFor<TFullBackend<Block>, Block>: sp_api::StateBackend<BlakeTwo256>,
 {
-	let slot_duration = cumulus_client_consensus_aura::slot_duration(&*client)?;
+	let spawn_handle = task_manager.spawn_handle();
 
-	let proposer_factory = sc_basic_authorship::ProposerFactory::with_proof_recording(
-		task_manager.spawn_handle(),
-		client.clone(),
-		transaction_pool,
-		prometheus_registry,
-		telemetry.clone(),
-	);
+	let relay_chain_consensus = {
+		let proposer_factory = sc_basic_authorship::ProposerFactory::with_proof_recording(
+			spawn_handle.clone(),
+			client.clone(),
+			transaction_pool.clone(),
+			prometheus_registry,
+			telemetry.clone(),
+		);
+		let relay_chain_interface = relay_chain_interface.clone();
+
+		cumulus_client_consensus_relay_chain::build_relay_chain_consensus(
+			cumulus_client_consensus_relay_chain::BuildRelayChainConsensusParams {
+				para_id: id,
+				proposer_factory,
+				block_import: block_import.clone(),
+				relay_chain_interface: relay_chain_interface.clone(),
+				create_inherent_data_providers: move |_, (relay_parent, validation_data)| {
+					let relay_chain_interface = relay_chain_interface.clone();
+					async move {
+						let parachain_inherent =
+							cumulus_primitives_parachain_inherent::ParachainInherentData::create_at(
+								relay_parent,
+								&relay_chain_interface,
+								&validation_data,
+								id,
+							)
+							.await;
+						let parachain_inherent = parachain_inherent.ok_or_else(|| {
+							Box::<dyn std::error::Error + Send + Sync>::from("Failed to create parachain inherent")
+						})?;
+						Ok(parachain_inherent)
+					}
+				},
+			},
+		)
+	};
 
-	let params = BuildAuraConsensusParams {
-		proposer_factory,
-		create_inherent_data_providers: move |_, (relay_parent, validation_data)| {
-			let relay_chain_interface = relay_chain_interface.clone();
-			async move {
-				let parachain_inherent = cumulus_primitives_parachain_inherent::ParachainInherentData::create_at(
-					relay_parent,
-					&relay_chain_interface,
-					&validation_data,
-					id,
-				)
-				.await;
-				let timestamp = sp_timestamp::InherentDataProvider::from_system_time();
-
-				let slot = sp_consensus_aura::inherents::InherentDataProvider::from_timestamp_and_slot_duration(
-					*timestamp,
-					slot_duration,
-				);
-
-				let parachain_inherent = parachain_inherent.ok_or_else(|| {
-					Box::<dyn std::error::Error + Send + Sync>::from("Failed to create parachain inherent")
-				})?;
-				Ok((slot, timestamp, parachain_inherent))
-			}
-		},
-		block_import,
-		para_client: client,
-		backoff_authoring_blocks: Option::<()>::None,
-		sync_oracle,
-		keystore,
-		force_authoring,
-		slot_duration,
-		// We got around 500ms for proposing
-		block_proposal_slot_portion: SlotProportion::new(1f32 / 24f32),
-		// And a maximum of 750ms if slots are skipped
-		max_block_proposal_slot_portion: Some(SlotProportion::new(1f32 / 16f32)),
-		telemetry,
+	let aura_consensus = {
+		let client = client.clone();
+		let prometheus_registry = prometheus_registry.cloned();
+
+		move || {
+			let slot_duration = cumulus_client_consensus_aura::slot_duration(&*client)
+				.expect("AURA consensus is only built once the runtime exposes the AURA API. qed");
+
+			let proposer_factory = sc_basic_authorship::ProposerFactory::with_proof_recording(
+				spawn_handle,
+				client.clone(),
+				transaction_pool,
+				prometheus_registry.as_ref(),
+				telemetry.clone(),
+			);
+
+			let params = BuildAuraConsensusParams {
+				proposer_factory,
+				create_inherent_data_providers: move |_, (relay_parent, validation_data)| {
+					let relay_chain_interface = relay_chain_interface.clone();
+					async move {
+						let parachain_inherent =
+							cumulus_primitives_parachain_inherent::ParachainInherentData::create_at(
+								relay_parent,
+								&relay_chain_interface,
+								&validation_data,
+								id,
+							)
+							.await;
+						let timestamp = sp_timestamp::InherentDataProvider::from_system_time();
+
+						let slot = sp_consensus_aura::inherents::InherentDataProvider::from_timestamp_and_slot_duration(
+							*timestamp,
+							slot_duration,
+						);
+
+						let parachain_inherent = parachain_inherent.ok_or_else(|| {
+							Box::<dyn std::error::Error + Send + Sync>::from("Failed to create parachain inherent")
+						})?;
+						Ok((slot, timestamp, parachain_inherent))
+					}
+				},
+				block_import,
+				para_client: client,
+				backoff_authoring_blocks: Option::<()>::None,
+				sync_oracle,
+				keystore,
+				force_authoring,
+				slot_duration,
+				// We got around 500ms for proposing
+				block_proposal_slot_portion: SlotProportion::new(1f32 / 24f32),
+				// And a maximum of 750ms if slots are skipped
+				max_block_proposal_slot_portion: Some(SlotProportion::new(1f32 / 16f32)),
+				telemetry,
+			};
+
+			AuraConsensus::build::<sp_consensus_aura::sr25519::AuthorityPair, _, _, _, _, _, _>(params)
+		}
 	};
 
-	Ok(AuraConsensus::build::<
-		sp_consensus_aura::sr25519::AuthorityPair,
-		_,
-		_,
-		_,
-		_,
-		_,
-		_,
-	>(params))
+	Ok(Box::new(WaitForAuraConsensus {
+		client,
+		aura_consensus: Arc::new(Mutex::new(BuildOnAccess::Uninitialized(Some(Box::new(aura_consensus))))),
+		relay_chain_consensus: Arc::new(Mutex::new(relay_chain_consensus)),
+	}))
 }
@@ -0,0 +1,41 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use frame_support::dispatch::Weight;
+
+/// Allow for restricting which attesters can issue attestations for a given
+/// CType, e.g., to only accept accredited attesters for regulated credentials.
+pub trait AttesterAccreditation<AttesterId, Ctype> {
+	/// Decides whether the attester is accredited to attest claims of the
+	/// given CType.
+	fn is_accredited(attester: &AttesterId, ctype: &Ctype) -> bool;
+
+	/// The worst-case weight of `is_accredited`.
+	fn is_accredited_weight() -> Weight;
+}
+
+/// Any attester can attest any CType.
+impl<AttesterId, Ctype> AttesterAccreditation<AttesterId, Ctype> for () {
+	fn is_accredited(_attester: &AttesterId, _ctype: &Ctype) -> bool {
+		true
+	}
+
+	fn is_accredited_weight() -> Weight {
+		Weight::zero()
+	}
+}
@@ -22,7 +22,7 @@ use scale_info::TypeInfo;
 
 /// An on-chain attestation written by an attester.
 #[derive(Clone, Debug, Encode, Decode, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
-pub struct AttestationDetails<CtypeHash, AttesterId, AuthorizationId, AccountId, Balance> {
+pub struct AttestationDetails<CtypeHash, AttesterId, AuthorizationId, AccountId, Balance, Cid> {
 	/// The hash of the CType used for this attestation.
 	pub ctype_hash: CtypeHash,
 	/// The ID of the attester.
@@ -35,6 +35,12 @@ pub struct AttestationDetails<CtypeHash, AttesterId, AuthorizationId, AccountId,
 	/// The deposit that was taken to incentivise fair use of the on chain
 	/// storage.
 	pub deposit: Deposit<AccountId, Balance>,
+	/// \[OPTIONAL\] The content identifier (e.g., an IPFS CID) of the
+	/// off-chain credential content.
+	pub cid: Option<Cid>,
+	/// The flag indicating whether the claimer has acknowledged the
+	/// attestation by signing the claim hash at issuance time.
+	pub claimer_acknowledged: bool,
 }
 
 #[cfg(test)]
@@ -42,7 +48,7 @@ mod tests {
 	use ctype::CtypeHashOf;
 
 	use super::*;
-	use crate::{mock::*, AccountIdOf, AttestationDetailsOf, AttesterOf, BalanceOf};
+	use crate::{migrations::AttestationDetailsV1Of, mock::*, AccountIdOf, AttesterOf, BalanceOf};
 
 	type OldAttestationDetailsOf<Test> =
 		OldAttestationDetails<CtypeHashOf<Test>, AttesterOf<Test>, AccountIdOf<Test>, BalanceOf<Test>>;
@@ -78,10 +84,10 @@ mod tests {
 		};
 		let encoded = old.encode();
 
-		let new = AttestationDetailsOf::<Test>::decode(&mut &encoded[..]);
+		let new = AttestationDetailsV1Of::<Test>::decode(&mut &encoded[..]);
 		assert_eq!(
 			new,
-			Ok(AttestationDetailsOf::<Test> {
+			Ok(AttestationDetailsV1Of::<Test> {
 				ctype_hash: claim_hash_from_seed(CLAIM_HASH_SEED_01),
 				attester: sr25519_did_from_public_key(&ALICE_SEED),
 				authorization_id: None,
@@ -22,7 +22,8 @@ use scale_info::TypeInfo;
 /// Describes who authorized the associated action.
 ///
 /// This can either be the attester that issued this attestation, another
-/// attester who is authorized by the `authorization_id` or the deposit owner.
+/// attester who is authorized by the `authorization_id`, the deposit owner or
+/// an upheld challenge.
 #[derive(Clone, Debug, Encode, Decode, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
 pub enum AuthorizedBy<Account, Attester> {
 	/// Authorized by the deposit owner.
@@ -31,4 +32,6 @@ pub enum AuthorizedBy<Account, Attester> {
 	Attester(Attester),
 	/// Authorized by the authorization_id.
 	Authorization(Attester),
+	/// Authorized by an upheld challenge of the attestation.
+	Challenge,
 }
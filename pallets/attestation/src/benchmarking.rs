@@ -17,12 +17,20 @@
 // If you feel like getting in touch with us, you can do so at info@botlabs.org
 
 use frame_benchmarking::{account, benchmarks};
-use frame_support::traits::{fungible::Mutate, Get};
+use frame_support::traits::{
+	fungible::{Mutate, MutateHold},
+	EnsureOrigin, Get,
+};
 use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
-use sp_runtime::traits::Hash;
+use parity_scale_codec::Decode;
+use sp_runtime::{
+	traits::{Hash, TrailingZeroInput},
+	BoundedVec,
+};
+use sp_std::{vec, vec::Vec};
 
 use ctype::CtypeEntryOf;
-use kilt_support::traits::GenerateBenchmarkOrigin;
+use kilt_support::{migration::SteppedMigration, traits::GenerateBenchmarkOrigin};
 
 use crate::*;
 
@@ -33,9 +41,11 @@ benchmarks! {
 		where
 		T: core::fmt::Debug,
 		<T as Config>::EnsureOrigin: GenerateBenchmarkOrigin<T::RuntimeOrigin, T::AccountId, T::AttesterId>,
+		<T as ctype::Config>::EnsureOrigin: GenerateBenchmarkOrigin<T::RuntimeOrigin, T::AccountId, T::AttesterId>,
 		T: ctype::Config<CtypeCreatorId = T::AttesterId>,
 		BlockNumberFor<T>: From<u64>,
-		<T as Config>::Currency: Mutate<T::AccountId>
+		<T as Config>::Currency: Mutate<T::AccountId>,
+		ClaimerSignatureOf<T>: From<(T::AttesterId, Vec<u8>)>,
 	}
 
 	add {
@@ -48,10 +58,15 @@ benchmarks! {
 			creator: attester.clone(),
 			created_at: 0u64.into()
 		});
-		<T as Config>::Currency::set_balance(&sender, <T as Config>::Deposit::get() + <T as Config>::Deposit::get());
+		<T as Config>::Currency::set_balance(
+			&sender,
+			<T as Config>::Deposit::get() + <T as Config>::IssuanceDeposit::get() + <T as Config>::Deposit::get(),
+		);
 
 		let origin = <T as Config>::EnsureOrigin::generate_origin(sender.clone(), attester.clone());
-	}: _<T::RuntimeOrigin>(origin, claim_hash, ctype_hash, None)
+		let cid: AttestationCidOf<T> = BoundedVec::try_from(vec![0u8; <T as Config>::MaxCidLength::get() as usize])
+			.expect("CID of maximum length should fit.");
+	}: _<T::RuntimeOrigin>(origin, claim_hash, ctype_hash, None, Some(cid.clone()))
 	verify {
 		assert!(Attestations::<T>::contains_key(claim_hash));
 		assert_eq!(Pallet::<T>::attestations(claim_hash), Some(AttestationDetails {
@@ -62,7 +77,48 @@ benchmarks! {
 			deposit: kilt_support::Deposit {
 				owner: sender,
 				amount: <T as Config>::Deposit::get(),
-			}
+			},
+			cid: Some(cid),
+			claimer_acknowledged: false
+		}));
+	}
+
+	add_with_claimer_ack {
+		let sender: T::AccountId = account("sender", 0, SEED);
+		let attester: T::AttesterId = account("attester", 0, SEED);
+		let claimer: T::AttesterId = account("claimer", 0, SEED);
+		let claim_hash: T::Hash = T::Hashing::hash(b"claim");
+		let ctype_hash: T::Hash = T::Hash::default();
+
+		ctype::Ctypes::<T>::insert(ctype_hash, CtypeEntryOf::<T> {
+			creator: attester.clone(),
+			created_at: 0u64.into()
+		});
+		<T as Config>::Currency::set_balance(
+			&sender,
+			<T as Config>::Deposit::get() + <T as Config>::IssuanceDeposit::get() + <T as Config>::Deposit::get(),
+		);
+
+		let origin = <T as Config>::EnsureOrigin::generate_origin(sender.clone(), attester.clone());
+		let cid: AttestationCidOf<T> = BoundedVec::try_from(vec![0u8; <T as Config>::MaxCidLength::get() as usize])
+			.expect("CID of maximum length should fit.");
+		let claimer_signature = ClaimerSignatureOf::<T>::from((
+			claimer.clone(),
+			Pallet::<T>::claimer_acknowledgement_payload(&claim_hash, &attester, &ctype_hash),
+		));
+	}: _<T::RuntimeOrigin>(origin, claim_hash, ctype_hash, None, Some(cid.clone()), claimer, claimer_signature)
+	verify {
+		assert_eq!(Pallet::<T>::attestations(claim_hash), Some(AttestationDetails {
+			ctype_hash,
+			attester,
+			authorization_id: None,
+			revoked: false,
+			deposit: kilt_support::Deposit {
+				owner: sender,
+				amount: <T as Config>::Deposit::get(),
+			},
+			cid: Some(cid),
+			claimer_acknowledged: true
 		}));
 	}
 
@@ -79,7 +135,7 @@ benchmarks! {
 		<T as Config>::Currency::set_balance(&sender, <T as Config>::Deposit::get() + <T as Config>::Deposit::get());
 
 		let origin = <T as Config>::EnsureOrigin::generate_origin(sender.clone(), attester.clone());
-		Pallet::<T>::add(origin.clone(), claim_hash, ctype_hash, None)?;
+		Pallet::<T>::add(origin.clone(), claim_hash, ctype_hash, None, None)?;
 	}: _<T::RuntimeOrigin>(origin, claim_hash, None)
 	verify {
 		assert!(Attestations::<T>::contains_key(claim_hash));
@@ -91,7 +147,9 @@ benchmarks! {
 			deposit: kilt_support::Deposit {
 				owner: sender,
 				amount: <T as Config>::Deposit::get(),
-			}
+			},
+			cid: None,
+			claimer_acknowledged: false
 		}));
 	}
 
@@ -108,7 +166,7 @@ benchmarks! {
 		<T as Config>::Currency::set_balance(&sender, <T as Config>::Deposit::get() + <T as Config>::Deposit::get());
 
 		let origin = <T as Config>::EnsureOrigin::generate_origin(sender.clone(), attester.clone());
-		Pallet::<T>::add(origin, claim_hash, ctype_hash, None)?;
+		Pallet::<T>::add(origin, claim_hash, ctype_hash, None, None)?;
 		let origin = <T as Config>::EnsureOrigin::generate_origin(sender, attester);
 	}: _<T::RuntimeOrigin>(origin, claim_hash, None)
 	verify {
@@ -128,7 +186,7 @@ benchmarks! {
 		<T as Config>::Currency::set_balance(&sender, <T as Config>::Deposit::get() + <T as Config>::Deposit::get());
 
 		let origin = <T as Config>::EnsureOrigin::generate_origin(sender.clone(), attester);
-		Pallet::<T>::add(origin, claim_hash, ctype_hash, None)?;
+		Pallet::<T>::add(origin, claim_hash, ctype_hash, None, None)?;
 		let origin = RawOrigin::Signed(sender);
 	}: _(origin, claim_hash)
 	verify {
@@ -150,7 +208,7 @@ benchmarks! {
 		<T as Config>::Currency::set_balance(&deposit_owner_new, <T as Config>::Deposit::get() + <T as Config>::Deposit::get());
 
 		let origin = <T as Config>::EnsureOrigin::generate_origin(deposit_owner_old, attester.clone());
-		Pallet::<T>::add(origin, claim_hash, ctype_hash, None)?;
+		Pallet::<T>::add(origin, claim_hash, ctype_hash, None, None)?;
 		let origin = <T as Config>::EnsureOrigin::generate_origin(deposit_owner_new.clone(), attester.clone());
 	}: _<T::RuntimeOrigin>(origin, claim_hash)
 	verify {
@@ -162,7 +220,9 @@ benchmarks! {
 			deposit: kilt_support::Deposit {
 				owner: deposit_owner_new,
 				amount: <T as Config>::Deposit::get(),
-			}
+			},
+			cid: None,
+			claimer_acknowledged: false
 		}));
 	}
 
@@ -179,7 +239,7 @@ benchmarks! {
 		<T as Config>::Currency::set_balance(&deposit_owner, <T as Config>::Deposit::get() + <T as Config>::Deposit::get());
 
 		let origin = <T as Config>::EnsureOrigin::generate_origin(deposit_owner.clone(), attester.clone());
-		Pallet::<T>::add(origin, claim_hash, ctype_hash, None).expect("claim should be added");
+		Pallet::<T>::add(origin, claim_hash, ctype_hash, None, None).expect("claim should be added");
 
 		let origin = RawOrigin::Signed(deposit_owner.clone());
 	}: _(origin, claim_hash)
@@ -192,10 +252,312 @@ benchmarks! {
 			deposit: kilt_support::Deposit {
 				owner: deposit_owner,
 				amount: <T as Config>::Deposit::get(),
-			}
+			},
+			cid: None,
+			claimer_acknowledged: false
 		}));
 	}
 
+	challenge {
+		let attester: T::AttesterId = account("attester", 0, SEED);
+		let deposit_owner: T::AccountId = account("sender", 0, SEED);
+		let challenger: T::AccountId = account("challenger", 0, SEED);
+		let claim_hash: T::Hash = T::Hashing::hash(b"claim");
+		let ctype_hash: T::Hash = T::Hash::default();
+
+		ctype::Ctypes::<T>::insert(ctype_hash, CtypeEntryOf::<T> {
+			creator: attester.clone(),
+			created_at: 0u64.into()
+		});
+		<T as Config>::Currency::set_balance(&deposit_owner, <T as Config>::Deposit::get() + <T as Config>::Deposit::get());
+		<T as Config>::Currency::set_balance(&challenger, <T as Config>::ChallengeBond::get() + <T as Config>::ChallengeBond::get());
+
+		let origin = <T as Config>::EnsureOrigin::generate_origin(deposit_owner, attester);
+		Pallet::<T>::add(origin, claim_hash, ctype_hash, None, None)?;
+		let origin = RawOrigin::Signed(challenger.clone());
+	}: _(origin, claim_hash)
+	verify {
+		assert_eq!(Challenges::<T>::get(claim_hash).map(|challenge| challenge.challenger_bond.owner), Some(challenger));
+	}
+
+	contest_challenge {
+		let attester: T::AttesterId = account("attester", 0, SEED);
+		let deposit_owner: T::AccountId = account("sender", 0, SEED);
+		let challenger: T::AccountId = account("challenger", 0, SEED);
+		let claim_hash: T::Hash = T::Hashing::hash(b"claim");
+		let ctype_hash: T::Hash = T::Hash::default();
+
+		ctype::Ctypes::<T>::insert(ctype_hash, CtypeEntryOf::<T> {
+			creator: attester.clone(),
+			created_at: 0u64.into()
+		});
+		<T as Config>::Currency::set_balance(&deposit_owner, <T as Config>::Deposit::get() + <T as Config>::ChallengeBond::get() + <T as Config>::ChallengeBond::get());
+		<T as Config>::Currency::set_balance(&challenger, <T as Config>::ChallengeBond::get() + <T as Config>::ChallengeBond::get());
+
+		let origin = <T as Config>::EnsureOrigin::generate_origin(deposit_owner.clone(), attester.clone());
+		Pallet::<T>::add(origin.clone(), claim_hash, ctype_hash, None, None)?;
+		Pallet::<T>::challenge(RawOrigin::Signed(challenger).into(), claim_hash)?;
+	}: _<T::RuntimeOrigin>(origin, claim_hash)
+	verify {
+		assert_eq!(
+			Challenges::<T>::get(claim_hash).and_then(|challenge| challenge.attester_bond).map(|bond| bond.owner),
+			Some(deposit_owner)
+		);
+	}
+
+	resolve_challenge {
+		let attester: T::AttesterId = account("attester", 0, SEED);
+		let deposit_owner: T::AccountId = account("sender", 0, SEED);
+		let challenger: T::AccountId = account("challenger", 0, SEED);
+		let claim_hash: T::Hash = T::Hashing::hash(b"claim");
+		let ctype_hash: T::Hash = T::Hash::default();
+
+		ctype::Ctypes::<T>::insert(ctype_hash, CtypeEntryOf::<T> {
+			creator: attester.clone(),
+			created_at: 0u64.into()
+		});
+		<T as Config>::Currency::set_balance(&deposit_owner, <T as Config>::Deposit::get() + <T as Config>::ChallengeBond::get() + <T as Config>::ChallengeBond::get());
+		<T as Config>::Currency::set_balance(&challenger, <T as Config>::ChallengeBond::get() + <T as Config>::ChallengeBond::get());
+
+		let origin = <T as Config>::EnsureOrigin::generate_origin(deposit_owner, attester);
+		Pallet::<T>::add(origin.clone(), claim_hash, ctype_hash, None, None)?;
+		Pallet::<T>::challenge(RawOrigin::Signed(challenger).into(), claim_hash)?;
+		Pallet::<T>::contest_challenge(origin.clone(), claim_hash)?;
+	}: _<T::RuntimeOrigin>(origin, claim_hash)
+	verify {
+		assert!(!Challenges::<T>::contains_key(claim_hash));
+		assert!(Attestations::<T>::get(claim_hash).is_some_and(|attestation| attestation.revoked));
+	}
+
+	adjudicate_challenge {
+		let attester: T::AttesterId = account("attester", 0, SEED);
+		let deposit_owner: T::AccountId = account("sender", 0, SEED);
+		let challenger: T::AccountId = account("challenger", 0, SEED);
+		let claim_hash: T::Hash = T::Hashing::hash(b"claim");
+		let ctype_hash: T::Hash = T::Hash::default();
+
+		ctype::Ctypes::<T>::insert(ctype_hash, CtypeEntryOf::<T> {
+			creator: attester.clone(),
+			created_at: 0u64.into()
+		});
+		<T as Config>::Currency::set_balance(&deposit_owner, <T as Config>::Deposit::get() + <T as Config>::ChallengeBond::get() + <T as Config>::ChallengeBond::get());
+		<T as Config>::Currency::set_balance(&challenger, <T as Config>::ChallengeBond::get() + <T as Config>::ChallengeBond::get());
+
+		let origin = <T as Config>::EnsureOrigin::generate_origin(deposit_owner, attester);
+		Pallet::<T>::add(origin.clone(), claim_hash, ctype_hash, None, None)?;
+		Pallet::<T>::challenge(RawOrigin::Signed(challenger).into(), claim_hash)?;
+		Pallet::<T>::contest_challenge(origin, claim_hash)?;
+		let origin = T::ChallengeAdjudicatorOrigin::try_successful_origin().expect("Successful origin creation should not fail.");
+	}: _<T::RuntimeOrigin>(origin, claim_hash, true)
+	verify {
+		assert!(!Challenges::<T>::contains_key(claim_hash));
+		assert!(Attestations::<T>::get(claim_hash).is_some_and(|attestation| attestation.revoked));
+	}
+
+	expire_challenge {
+		let attester: T::AttesterId = account("attester", 0, SEED);
+		let deposit_owner: T::AccountId = account("sender", 0, SEED);
+		let challenger: T::AccountId = account("challenger", 0, SEED);
+		let claim_hash: T::Hash = T::Hashing::hash(b"claim");
+		let ctype_hash: T::Hash = T::Hash::default();
+
+		ctype::Ctypes::<T>::insert(ctype_hash, CtypeEntryOf::<T> {
+			creator: attester.clone(),
+			created_at: 0u64.into()
+		});
+		<T as Config>::Currency::set_balance(&deposit_owner, <T as Config>::Deposit::get() + <T as Config>::Deposit::get());
+		<T as Config>::Currency::set_balance(&challenger, <T as Config>::ChallengeBond::get() + <T as Config>::ChallengeBond::get());
+
+		let origin = <T as Config>::EnsureOrigin::generate_origin(deposit_owner, attester);
+		Pallet::<T>::add(origin, claim_hash, ctype_hash, None, None)?;
+		Pallet::<T>::challenge(RawOrigin::Signed(challenger.clone()).into(), claim_hash)?;
+		frame_system::Pallet::<T>::set_block_number(
+			frame_system::Pallet::<T>::block_number() + T::ChallengeResponsePeriod::get() + 1u64.into()
+		);
+		let origin = RawOrigin::Signed(challenger);
+	}: _(origin, claim_hash)
+	verify {
+		assert!(!Challenges::<T>::contains_key(claim_hash));
+		assert!(Attestations::<T>::get(claim_hash).is_some_and(|attestation| attestation.revoked));
+	}
+
+	set_issuance_limits {
+		let sender: T::AccountId = account("sender", 0, SEED);
+		let creator: T::AttesterId = account("creator", 0, SEED);
+		let ctype_hash: T::Hash = T::Hash::default();
+		let limits = IssuanceLimits {
+			max_issuance: Some(u64::MAX),
+			max_per_block: Some(u32::MAX),
+		};
+
+		ctype::Ctypes::<T>::insert(ctype_hash, CtypeEntryOf::<T> {
+			creator: creator.clone(),
+			created_at: 0u64.into()
+		});
+		<T as Config>::Currency::set_balance(&sender, <T as Config>::IssuanceDeposit::get() + <T as Config>::Deposit::get());
+
+		let origin = <T as ctype::Config>::EnsureOrigin::generate_origin(sender, creator);
+	}: _<T::RuntimeOrigin>(origin, ctype_hash, Some(limits))
+	verify {
+		assert_eq!(CtypeIssuanceLimits::<T>::get(ctype_hash).map(|details| details.limits), Some(limits));
+	}
+
+	remove_issuance_counter {
+		let sender: T::AccountId = account("sender", 0, SEED);
+		let attester: T::AttesterId = account("attester", 0, SEED);
+		let ctype_hash: T::Hash = T::Hash::default();
+		let deposit_amount = <T as Config>::IssuanceDeposit::get();
+
+		<T as Config>::Currency::set_balance(&sender, deposit_amount + <T as Config>::Deposit::get());
+		<T as Config>::Currency::hold(&HoldReason::Deposit.into(), &sender, deposit_amount)
+			.expect("Sender should have enough balance for the deposit.");
+		IssuanceCounters::<T>::insert(ctype_hash, &attester, IssuanceCounterDetails {
+			counter: IssuanceCounter::default(),
+			deposit: Some(kilt_support::Deposit {
+				owner: sender.clone(),
+				amount: deposit_amount,
+			}),
+		});
+
+		let origin = <T as Config>::EnsureOrigin::generate_origin(sender, attester.clone());
+	}: _<T::RuntimeOrigin>(origin, ctype_hash)
+	verify {
+		assert!(!IssuanceCounters::<T>::contains_key(ctype_hash, attester));
+	}
+
+	add_attestation_cid_step {
+		let sender: T::AccountId = account("sender", 0, SEED);
+		let attester: T::AttesterId = account("attester", 0, SEED);
+		let claim_hash: T::Hash = T::Hashing::hash(b"claim");
+
+		frame_support::storage::unhashed::put(
+			&Attestations::<T>::hashed_key_for(claim_hash),
+			&migrations::AttestationDetailsV1Of::<T> {
+				ctype_hash: T::Hash::default(),
+				attester,
+				authorization_id: None,
+				revoked: false,
+				deposit: kilt_support::Deposit {
+					owner: sender,
+					amount: <T as Config>::Deposit::get(),
+				},
+			},
+		);
+	}: {
+		<migrations::AddAttestationCid<T> as SteppedMigration>::step(None);
+	}
+	verify {
+		assert!(frame_support::storage::unhashed::get::<migrations::AttestationDetailsV2Of<T>>(
+			&Attestations::<T>::hashed_key_for(claim_hash)
+		).is_some_and(|attestation| attestation.cid.is_none()));
+	}
+
+	populate_attestations_by_delegation_step {
+		let sender: T::AccountId = account("sender", 0, SEED);
+		let attester: T::AttesterId = account("attester", 0, SEED);
+		let claim_hash: T::Hash = T::Hashing::hash(b"claim");
+		let authorization_id: T::AuthorizationId = Decode::decode(&mut TrailingZeroInput::zeroes())
+			.expect("Authorization id should be decodable from zeroes.");
+
+		frame_support::storage::unhashed::put(
+			&Attestations::<T>::hashed_key_for(claim_hash),
+			&migrations::AttestationDetailsV2Of::<T> {
+				ctype_hash: T::Hash::default(),
+				attester,
+				authorization_id: Some(authorization_id.clone()),
+				revoked: false,
+				deposit: kilt_support::Deposit {
+					owner: sender,
+					amount: <T as Config>::Deposit::get(),
+				},
+				cid: None,
+			},
+		);
+	}: {
+		<migrations::PopulateAttestationsByDelegation<T> as SteppedMigration>::step(None);
+	}
+	verify {
+		assert_eq!(AttestationsByDelegation::<T>::get(&authorization_id).into_inner(), vec![claim_hash]);
+	}
+
+	add_claimer_acknowledgement_step {
+		let sender: T::AccountId = account("sender", 0, SEED);
+		let attester: T::AttesterId = account("attester", 0, SEED);
+		let claim_hash: T::Hash = T::Hashing::hash(b"claim");
+		let cid: AttestationCidOf<T> = BoundedVec::try_from(vec![0u8; <T as Config>::MaxCidLength::get() as usize])
+			.expect("CID of maximum length should fit.");
+
+		frame_support::storage::unhashed::put(
+			&Attestations::<T>::hashed_key_for(claim_hash),
+			&migrations::AttestationDetailsV2Of::<T> {
+				ctype_hash: T::Hash::default(),
+				attester,
+				authorization_id: None,
+				revoked: false,
+				deposit: kilt_support::Deposit {
+					owner: sender,
+					amount: <T as Config>::Deposit::get(),
+				},
+				cid: Some(cid),
+			},
+		);
+	}: {
+		<migrations::AddClaimerAcknowledgement<T> as SteppedMigration>::step(None);
+	}
+	verify {
+		assert!(Attestations::<T>::get(claim_hash).is_some_and(|attestation| !attestation.claimer_acknowledged));
+	}
+
+	initialize_attestation_counter_step {
+		let sender: T::AccountId = account("sender", 0, SEED);
+		let attester: T::AttesterId = account("attester", 0, SEED);
+		let claim_hash: T::Hash = T::Hashing::hash(b"claim");
+
+		Attestations::<T>::insert(claim_hash, AttestationDetails {
+			ctype_hash: T::Hash::default(),
+			attester,
+			authorization_id: None,
+			revoked: false,
+			deposit: kilt_support::Deposit {
+				owner: sender,
+				amount: <T as Config>::Deposit::get(),
+			},
+			cid: None,
+			claimer_acknowledged: false,
+		});
+		frame_support::storage::unhashed::kill(&Attestations::<T>::counter_storage_final_key());
+	}: {
+		<migrations::InitializeAttestationCounter<T> as SteppedMigration>::step(None);
+	}
+	verify {
+		assert_eq!(Attestations::<T>::count(), 1);
+	}
+
+	initialize_issuance_counters_step {
+		let sender: T::AccountId = account("sender", 0, SEED);
+		let attester: T::AttesterId = account("attester", 0, SEED);
+		let claim_hash: T::Hash = T::Hashing::hash(b"claim");
+		let ctype_hash: T::Hash = T::Hash::default();
+
+		Attestations::<T>::insert(claim_hash, AttestationDetails {
+			ctype_hash,
+			attester: attester.clone(),
+			authorization_id: None,
+			revoked: false,
+			deposit: kilt_support::Deposit {
+				owner: sender,
+				amount: <T as Config>::Deposit::get(),
+			},
+			cid: None,
+			claimer_acknowledged: false,
+		});
+	}: {
+		<migrations::InitializeIssuanceCounters<T> as SteppedMigration>::step(None);
+	}
+	verify {
+		assert!(IssuanceCounters::<T>::contains_key(ctype_hash, attester));
+	}
+
 	impl_benchmark_test_suite!(
 		Pallet,
 		crate::mock::ExtBuilder::default().build_with_keystore(),
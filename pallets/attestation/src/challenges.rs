@@ -0,0 +1,35 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use kilt_support::Deposit;
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+
+/// An open dispute over an attestation, backed by the bond of the challenger
+/// and, if contested, by the bond of the attester.
+#[derive(Clone, Debug, Encode, Decode, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
+pub struct ChallengeDetails<AccountId, Balance, BlockNumber> {
+	/// The bond held from the account that challenged the attestation.
+	pub challenger_bond: Deposit<AccountId, Balance>,
+	/// \[OPTIONAL\] The bond held from the account that contested the
+	/// challenge on behalf of the attester.
+	pub attester_bond: Option<Deposit<AccountId, Balance>>,
+	/// The last block in which the attester can contest the challenge. An
+	/// uncontested challenge is upheld after this block.
+	pub response_deadline: BlockNumber,
+}
@@ -56,169 +56,428 @@ pub trait WeightInfo {
 	fn reclaim_deposit() -> Weight;
 	fn change_deposit_owner() -> Weight;
 	fn update_deposit() -> Weight;
+	fn challenge() -> Weight;
+	fn contest_challenge() -> Weight;
+	fn resolve_challenge() -> Weight;
+	fn adjudicate_challenge() -> Weight;
+	fn expire_challenge() -> Weight;
+	fn set_issuance_limits() -> Weight;
+	fn add_with_claimer_ack() -> Weight;
+	fn remove_issuance_counter() -> Weight;
+	fn add_attestation_cid_step() -> Weight;
+	fn populate_attestations_by_delegation_step() -> Weight;
+	fn add_claimer_acknowledgement_step() -> Weight;
+	fn initialize_attestation_counter_step() -> Weight;
+	fn initialize_issuance_counters_step() -> Weight;
 }
 
 /// Weights for attestation using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// The weights below are not taken from the benchmark run above. They are
+	// estimated from the storage accesses of each call.
 	/// Storage: Ctype Ctypes (r:1 w:0)
 	/// Proof: Ctype Ctypes (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
 	/// Storage: Attestation Attestations (r:1 w:1)
-	/// Proof: Attestation Attestations (max_values: None, max_size: Some(195), added: 2670, mode: MaxEncodedLen)
+	/// Proof: Attestation Attestations (max_values: None, max_size: Some(263), added: 2738, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: Attestation IssuanceCounters (r:1 w:1)
+	/// Proof: Attestation IssuanceCounters (max_values: None, max_size: Some(165), added: 2640, mode: MaxEncodedLen)
+	/// Storage: Attestation CtypeIssuanceLimits (r:1 w:0)
+	/// Proof: Attestation CtypeIssuanceLimits (max_values: None, max_size: Some(110), added: 2585, mode: MaxEncodedLen)
+	/// Storage: Attestation AttestationsByDelegation (r:1 w:1)
+	/// Proof Skipped: Attestation AttestationsByDelegation (max_values: None, max_size: None, mode: Measured)
 	fn add() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `793`
-		//  Estimated: `7840`
-		// Minimum execution time: 18_852 nanoseconds.
-		Weight::from_parts(19_658_000, 7840)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+		Weight::from_parts(19_658_000, 13133)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: Attestation Attestations (r:1 w:1)
-	/// Proof: Attestation Attestations (max_values: None, max_size: Some(195), added: 2670, mode: MaxEncodedLen)
+	/// Proof: Attestation Attestations (max_values: None, max_size: Some(263), added: 2738, mode: MaxEncodedLen)
+	/// Storage: Attestation AttestationsByDelegation (r:1 w:1)
+	/// Proof Skipped: Attestation AttestationsByDelegation (max_values: None, max_size: None, mode: Measured)
 	fn revoke() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `227`
-		//  Estimated: `2670`
-		// Minimum execution time: 9_106 nanoseconds.
-		Weight::from_parts(9_580_000, 2670)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+		Weight::from_parts(9_580_000, 2738)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: Attestation Attestations (r:1 w:1)
-	/// Proof: Attestation Attestations (max_values: None, max_size: Some(195), added: 2670, mode: MaxEncodedLen)
+	/// Proof: Attestation Attestations (max_values: None, max_size: Some(263), added: 2738, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: Attestation AttestationsByDelegation (r:1 w:1)
+	/// Proof Skipped: Attestation AttestationsByDelegation (max_values: None, max_size: None, mode: Measured)
 	fn remove() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `905`
-		//  Estimated: `5277`
-		// Minimum execution time: 17_595 nanoseconds.
-		Weight::from_parts(18_133_000, 5277)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+		Weight::from_parts(18_133_000, 5345)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: Attestation Attestations (r:1 w:1)
-	/// Proof: Attestation Attestations (max_values: None, max_size: Some(195), added: 2670, mode: MaxEncodedLen)
+	/// Proof: Attestation Attestations (max_values: None, max_size: Some(263), added: 2738, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	fn reclaim_deposit() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `905`
-		//  Estimated: `5277`
-		// Minimum execution time: 18_054 nanoseconds.
-		Weight::from_parts(18_690_000, 5277)
+		Weight::from_parts(18_690_000, 5345)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: Attestation Attestations (r:1 w:1)
-	/// Proof: Attestation Attestations (max_values: None, max_size: Some(195), added: 2670, mode: MaxEncodedLen)
+	/// Proof: Attestation Attestations (max_values: None, max_size: Some(263), added: 2738, mode: MaxEncodedLen)
 	/// Storage: System Account (r:2 w:2)
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	fn change_deposit_owner() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1484`
-		//  Estimated: `7884`
-		// Minimum execution time: 25_988 nanoseconds.
-		Weight::from_parts(26_675_000, 7884)
+		Weight::from_parts(26_675_000, 7952)
 			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: Attestation Attestations (r:1 w:1)
-	/// Proof: Attestation Attestations (max_values: None, max_size: Some(195), added: 2670, mode: MaxEncodedLen)
+	/// Proof: Attestation Attestations (max_values: None, max_size: Some(263), added: 2738, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	fn update_deposit() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `905`
-		//  Estimated: `5277`
-		// Minimum execution time: 22_977 nanoseconds.
-		Weight::from_parts(23_725_000, 5277)
+		Weight::from_parts(23_725_000, 5345)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
+	/// Storage: Attestation Attestations (r:1 w:0)
+	/// Proof: Attestation Attestations (max_values: None, max_size: Some(263), added: 2738, mode: MaxEncodedLen)
+	/// Storage: Attestation Challenges (r:1 w:1)
+	/// Proof: Attestation Challenges (max_values: None, max_size: Some(153), added: 2628, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	fn challenge() -> Weight {
+		Weight::from_parts(22_201_000, 7973)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: Attestation Attestations (r:1 w:0)
+	/// Proof: Attestation Attestations (max_values: None, max_size: Some(263), added: 2738, mode: MaxEncodedLen)
+	/// Storage: Attestation Challenges (r:1 w:1)
+	/// Proof: Attestation Challenges (max_values: None, max_size: Some(153), added: 2628, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	fn contest_challenge() -> Weight {
+		Weight::from_parts(22_990_000, 7973)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: Attestation Attestations (r:1 w:1)
+	/// Proof: Attestation Attestations (max_values: None, max_size: Some(263), added: 2738, mode: MaxEncodedLen)
+	/// Storage: Attestation Challenges (r:1 w:1)
+	/// Proof: Attestation Challenges (max_values: None, max_size: Some(153), added: 2628, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	fn resolve_challenge() -> Weight {
+		Weight::from_parts(33_149_000, 10580)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: Attestation Attestations (r:1 w:1)
+	/// Proof: Attestation Attestations (max_values: None, max_size: Some(263), added: 2738, mode: MaxEncodedLen)
+	/// Storage: Attestation Challenges (r:1 w:1)
+	/// Proof: Attestation Challenges (max_values: None, max_size: Some(153), added: 2628, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	fn adjudicate_challenge() -> Weight {
+		Weight::from_parts(33_296_000, 10580)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: Attestation Attestations (r:1 w:1)
+	/// Proof: Attestation Attestations (max_values: None, max_size: Some(263), added: 2738, mode: MaxEncodedLen)
+	/// Storage: Attestation Challenges (r:1 w:1)
+	/// Proof: Attestation Challenges (max_values: None, max_size: Some(153), added: 2628, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	fn expire_challenge() -> Weight {
+		Weight::from_parts(25_625_000, 7973)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: Ctype Ctypes (r:1 w:0)
+	/// Proof: Ctype Ctypes (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Attestation CtypeIssuanceLimits (r:1 w:1)
+	/// Proof: Attestation CtypeIssuanceLimits (max_values: None, max_size: Some(110), added: 2585, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	fn set_issuance_limits() -> Weight {
+		Weight::from_parts(29_381_000, 7755)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: Ctype Ctypes (r:1 w:0)
+	/// Proof: Ctype Ctypes (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Attestation Attestations (r:1 w:1)
+	/// Proof: Attestation Attestations (max_values: None, max_size: Some(263), added: 2738, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: Attestation IssuanceCounters (r:1 w:1)
+	/// Proof: Attestation IssuanceCounters (max_values: None, max_size: Some(165), added: 2640, mode: MaxEncodedLen)
+	/// Storage: Attestation CtypeIssuanceLimits (r:1 w:0)
+	/// Proof: Attestation CtypeIssuanceLimits (max_values: None, max_size: Some(110), added: 2585, mode: MaxEncodedLen)
+	/// Storage: Attestation AttestationsByDelegation (r:1 w:1)
+	/// Proof Skipped: Attestation AttestationsByDelegation (max_values: None, max_size: None, mode: Measured)
+	fn add_with_claimer_ack() -> Weight {
+		Weight::from_parts(20_174_000, 13133)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: Attestation CtypeIssuanceLimits (r:1 w:0)
+	/// Proof: Attestation CtypeIssuanceLimits (max_values: None, max_size: Some(110), added: 2585, mode: MaxEncodedLen)
+	/// Storage: Attestation IssuanceCounters (r:1 w:1)
+	/// Proof: Attestation IssuanceCounters (max_values: None, max_size: Some(165), added: 2640, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	fn remove_issuance_counter() -> Weight {
+		Weight::from_parts(27_452_000, 7832)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: Attestation Attestations (r:2 w:1)
+	/// Proof: Attestation Attestations (max_values: None, max_size: Some(263), added: 2738, mode: MaxEncodedLen)
+	fn add_attestation_cid_step() -> Weight {
+		Weight::from_parts(12_542_000, 6465)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Attestation Attestations (r:2 w:0)
+	/// Proof: Attestation Attestations (max_values: None, max_size: Some(263), added: 2738, mode: MaxEncodedLen)
+	/// Storage: Attestation AttestationsByDelegation (r:1 w:1)
+	/// Proof Skipped: Attestation AttestationsByDelegation (max_values: None, max_size: None, mode: Measured)
+	fn populate_attestations_by_delegation_step() -> Weight {
+		Weight::from_parts(15_087_000, 6782)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Attestation Attestations (r:2 w:1)
+	/// Proof: Attestation Attestations (max_values: None, max_size: Some(263), added: 2738, mode: MaxEncodedLen)
+	fn add_claimer_acknowledgement_step() -> Weight {
+		Weight::from_parts(12_871_000, 6466)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Attestation Attestations (r:1 w:0)
+	/// Proof: Attestation Attestations (max_values: None, max_size: Some(263), added: 2738, mode: MaxEncodedLen)
+	/// Storage: Attestation CounterForAttestations (r:1 w:1)
+	/// Proof: Attestation CounterForAttestations (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	fn initialize_attestation_counter_step() -> Weight {
+		Weight::from_parts(10_268_000, 3728)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Attestation Attestations (r:2 w:0)
+	/// Proof: Attestation Attestations (max_values: None, max_size: Some(263), added: 2738, mode: MaxEncodedLen)
+	/// Storage: Attestation IssuanceCounters (r:1 w:1)
+	/// Proof: Attestation IssuanceCounters (max_values: None, max_size: Some(165), added: 2640, mode: MaxEncodedLen)
+	fn initialize_issuance_counters_step() -> Weight {
+		Weight::from_parts(14_219_000, 8116)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
+	// The weights below are not taken from the benchmark run above. They are
+	// estimated from the storage accesses of each call.
 	/// Storage: Ctype Ctypes (r:1 w:0)
 	/// Proof: Ctype Ctypes (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
 	/// Storage: Attestation Attestations (r:1 w:1)
-	/// Proof: Attestation Attestations (max_values: None, max_size: Some(195), added: 2670, mode: MaxEncodedLen)
+	/// Proof: Attestation Attestations (max_values: None, max_size: Some(263), added: 2738, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: Attestation IssuanceCounters (r:1 w:1)
+	/// Proof: Attestation IssuanceCounters (max_values: None, max_size: Some(165), added: 2640, mode: MaxEncodedLen)
+	/// Storage: Attestation CtypeIssuanceLimits (r:1 w:0)
+	/// Proof: Attestation CtypeIssuanceLimits (max_values: None, max_size: Some(110), added: 2585, mode: MaxEncodedLen)
+	/// Storage: Attestation AttestationsByDelegation (r:1 w:1)
+	/// Proof Skipped: Attestation AttestationsByDelegation (max_values: None, max_size: None, mode: Measured)
 	fn add() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `793`
-		//  Estimated: `7840`
-		// Minimum execution time: 18_852 nanoseconds.
-		Weight::from_parts(19_658_000, 7840)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+		Weight::from_parts(19_658_000, 13133)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: Attestation Attestations (r:1 w:1)
-	/// Proof: Attestation Attestations (max_values: None, max_size: Some(195), added: 2670, mode: MaxEncodedLen)
+	/// Proof: Attestation Attestations (max_values: None, max_size: Some(263), added: 2738, mode: MaxEncodedLen)
+	/// Storage: Attestation AttestationsByDelegation (r:1 w:1)
+	/// Proof Skipped: Attestation AttestationsByDelegation (max_values: None, max_size: None, mode: Measured)
 	fn revoke() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `227`
-		//  Estimated: `2670`
-		// Minimum execution time: 9_106 nanoseconds.
-		Weight::from_parts(9_580_000, 2670)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		Weight::from_parts(9_580_000, 2738)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: Attestation Attestations (r:1 w:1)
-	/// Proof: Attestation Attestations (max_values: None, max_size: Some(195), added: 2670, mode: MaxEncodedLen)
+	/// Proof: Attestation Attestations (max_values: None, max_size: Some(263), added: 2738, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: Attestation AttestationsByDelegation (r:1 w:1)
+	/// Proof Skipped: Attestation AttestationsByDelegation (max_values: None, max_size: None, mode: Measured)
 	fn remove() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `905`
-		//  Estimated: `5277`
-		// Minimum execution time: 17_595 nanoseconds.
-		Weight::from_parts(18_133_000, 5277)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+		Weight::from_parts(18_133_000, 5345)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: Attestation Attestations (r:1 w:1)
-	/// Proof: Attestation Attestations (max_values: None, max_size: Some(195), added: 2670, mode: MaxEncodedLen)
+	/// Proof: Attestation Attestations (max_values: None, max_size: Some(263), added: 2738, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	fn reclaim_deposit() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `905`
-		//  Estimated: `5277`
-		// Minimum execution time: 18_054 nanoseconds.
-		Weight::from_parts(18_690_000, 5277)
+		Weight::from_parts(18_690_000, 5345)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: Attestation Attestations (r:1 w:1)
-	/// Proof: Attestation Attestations (max_values: None, max_size: Some(195), added: 2670, mode: MaxEncodedLen)
+	/// Proof: Attestation Attestations (max_values: None, max_size: Some(263), added: 2738, mode: MaxEncodedLen)
 	/// Storage: System Account (r:2 w:2)
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	fn change_deposit_owner() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1484`
-		//  Estimated: `7884`
-		// Minimum execution time: 25_988 nanoseconds.
-		Weight::from_parts(26_675_000, 7884)
+		Weight::from_parts(26_675_000, 7952)
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: Attestation Attestations (r:1 w:1)
-	/// Proof: Attestation Attestations (max_values: None, max_size: Some(195), added: 2670, mode: MaxEncodedLen)
+	/// Proof: Attestation Attestations (max_values: None, max_size: Some(263), added: 2738, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	fn update_deposit() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `905`
-		//  Estimated: `5277`
-		// Minimum execution time: 22_977 nanoseconds.
-		Weight::from_parts(23_725_000, 5277)
+		Weight::from_parts(23_725_000, 5345)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
+	/// Storage: Attestation Attestations (r:1 w:0)
+	/// Proof: Attestation Attestations (max_values: None, max_size: Some(263), added: 2738, mode: MaxEncodedLen)
+	/// Storage: Attestation Challenges (r:1 w:1)
+	/// Proof: Attestation Challenges (max_values: None, max_size: Some(153), added: 2628, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	fn challenge() -> Weight {
+		Weight::from_parts(22_201_000, 7973)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: Attestation Attestations (r:1 w:0)
+	/// Proof: Attestation Attestations (max_values: None, max_size: Some(263), added: 2738, mode: MaxEncodedLen)
+	/// Storage: Attestation Challenges (r:1 w:1)
+	/// Proof: Attestation Challenges (max_values: None, max_size: Some(153), added: 2628, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	fn contest_challenge() -> Weight {
+		Weight::from_parts(22_990_000, 7973)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: Attestation Attestations (r:1 w:1)
+	/// Proof: Attestation Attestations (max_values: None, max_size: Some(263), added: 2738, mode: MaxEncodedLen)
+	/// Storage: Attestation Challenges (r:1 w:1)
+	/// Proof: Attestation Challenges (max_values: None, max_size: Some(153), added: 2628, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	fn resolve_challenge() -> Weight {
+		Weight::from_parts(33_149_000, 10580)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: Attestation Attestations (r:1 w:1)
+	/// Proof: Attestation Attestations (max_values: None, max_size: Some(263), added: 2738, mode: MaxEncodedLen)
+	/// Storage: Attestation Challenges (r:1 w:1)
+	/// Proof: Attestation Challenges (max_values: None, max_size: Some(153), added: 2628, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	fn adjudicate_challenge() -> Weight {
+		Weight::from_parts(33_296_000, 10580)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: Attestation Attestations (r:1 w:1)
+	/// Proof: Attestation Attestations (max_values: None, max_size: Some(263), added: 2738, mode: MaxEncodedLen)
+	/// Storage: Attestation Challenges (r:1 w:1)
+	/// Proof: Attestation Challenges (max_values: None, max_size: Some(153), added: 2628, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	fn expire_challenge() -> Weight {
+		Weight::from_parts(25_625_000, 7973)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: Ctype Ctypes (r:1 w:0)
+	/// Proof: Ctype Ctypes (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Attestation CtypeIssuanceLimits (r:1 w:1)
+	/// Proof: Attestation CtypeIssuanceLimits (max_values: None, max_size: Some(110), added: 2585, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	fn set_issuance_limits() -> Weight {
+		Weight::from_parts(29_381_000, 7755)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: Ctype Ctypes (r:1 w:0)
+	/// Proof: Ctype Ctypes (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Attestation Attestations (r:1 w:1)
+	/// Proof: Attestation Attestations (max_values: None, max_size: Some(263), added: 2738, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: Attestation IssuanceCounters (r:1 w:1)
+	/// Proof: Attestation IssuanceCounters (max_values: None, max_size: Some(165), added: 2640, mode: MaxEncodedLen)
+	/// Storage: Attestation CtypeIssuanceLimits (r:1 w:0)
+	/// Proof: Attestation CtypeIssuanceLimits (max_values: None, max_size: Some(110), added: 2585, mode: MaxEncodedLen)
+	/// Storage: Attestation AttestationsByDelegation (r:1 w:1)
+	/// Proof Skipped: Attestation AttestationsByDelegation (max_values: None, max_size: None, mode: Measured)
+	fn add_with_claimer_ack() -> Weight {
+		Weight::from_parts(20_174_000, 13133)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: Attestation CtypeIssuanceLimits (r:1 w:0)
+	/// Proof: Attestation CtypeIssuanceLimits (max_values: None, max_size: Some(110), added: 2585, mode: MaxEncodedLen)
+	/// Storage: Attestation IssuanceCounters (r:1 w:1)
+	/// Proof: Attestation IssuanceCounters (max_values: None, max_size: Some(165), added: 2640, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	fn remove_issuance_counter() -> Weight {
+		Weight::from_parts(27_452_000, 7832)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: Attestation Attestations (r:2 w:1)
+	/// Proof: Attestation Attestations (max_values: None, max_size: Some(263), added: 2738, mode: MaxEncodedLen)
+	fn add_attestation_cid_step() -> Weight {
+		Weight::from_parts(12_542_000, 6465)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Attestation Attestations (r:2 w:0)
+	/// Proof: Attestation Attestations (max_values: None, max_size: Some(263), added: 2738, mode: MaxEncodedLen)
+	/// Storage: Attestation AttestationsByDelegation (r:1 w:1)
+	/// Proof Skipped: Attestation AttestationsByDelegation (max_values: None, max_size: None, mode: Measured)
+	fn populate_attestations_by_delegation_step() -> Weight {
+		Weight::from_parts(15_087_000, 6782)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Attestation Attestations (r:2 w:1)
+	/// Proof: Attestation Attestations (max_values: None, max_size: Some(263), added: 2738, mode: MaxEncodedLen)
+	fn add_claimer_acknowledgement_step() -> Weight {
+		Weight::from_parts(12_871_000, 6466)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Attestation Attestations (r:1 w:0)
+	/// Proof: Attestation Attestations (max_values: None, max_size: Some(263), added: 2738, mode: MaxEncodedLen)
+	/// Storage: Attestation CounterForAttestations (r:1 w:1)
+	/// Proof: Attestation CounterForAttestations (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	fn initialize_attestation_counter_step() -> Weight {
+		Weight::from_parts(10_268_000, 3728)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Attestation Attestations (r:2 w:0)
+	/// Proof: Attestation Attestations (max_values: None, max_size: Some(263), added: 2738, mode: MaxEncodedLen)
+	/// Storage: Attestation IssuanceCounters (r:1 w:1)
+	/// Proof: Attestation IssuanceCounters (max_values: None, max_size: Some(165), added: 2640, mode: MaxEncodedLen)
+	fn initialize_issuance_counters_step() -> Weight {
+		Weight::from_parts(14_219_000, 8116)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
@@ -0,0 +1,85 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use kilt_support::Deposit;
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+
+/// The number of attestations an attester has issued for a CType.
+#[derive(Clone, Debug, Default, Encode, Decode, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
+pub struct IssuanceCounter<BlockNumber> {
+	/// The total number of attestations issued, including the ones that have
+	/// since been revoked or removed.
+	pub total: u64,
+	/// The block in which the last attestation was issued.
+	pub last_issued_at: BlockNumber,
+	/// The number of attestations issued in `last_issued_at`.
+	pub issued_in_last_block: u32,
+}
+
+impl<BlockNumber> IssuanceCounter<BlockNumber>
+where
+	BlockNumber: PartialEq,
+{
+	/// Return the counter after issuing a new attestation at the given
+	/// block, or `None` if the counter would overflow.
+	pub fn increased_at(&self, block_number: BlockNumber) -> Option<Self> {
+		let issued_in_last_block = if self.last_issued_at == block_number {
+			self.issued_in_last_block.checked_add(1)?
+		} else {
+			1
+		};
+		Some(Self {
+			total: self.total.checked_add(1)?,
+			last_issued_at: block_number,
+			issued_in_last_block,
+		})
+	}
+}
+
+/// The issuance limits a CType creator can set for their CType. The limits
+/// apply to each attester separately.
+#[derive(Clone, Copy, Debug, Default, Encode, Decode, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
+pub struct IssuanceLimits {
+	/// \[OPTIONAL\] The maximum number of attestations each attester can
+	/// ever issue for the CType.
+	pub max_issuance: Option<u64>,
+	/// \[OPTIONAL\] The maximum number of attestations each attester can
+	/// issue for the CType within a single block.
+	pub max_per_block: Option<u32>,
+}
+
+/// The issuance counter of an attester for a CType as stored on chain.
+#[derive(Clone, Debug, Encode, Decode, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
+pub struct IssuanceCounterDetails<BlockNumber, AccountId, Balance> {
+	/// The number of attestations issued.
+	pub counter: IssuanceCounter<BlockNumber>,
+	/// The deposit taken from the payer of the first attestation counted.
+	/// Counters backfilled for attestations issued before the counters were
+	/// introduced have no deposit.
+	pub deposit: Option<Deposit<AccountId, Balance>>,
+}
+
+/// The issuance limits of a CType as stored on chain.
+#[derive(Clone, Debug, Encode, Decode, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
+pub struct IssuanceLimitsDetails<AccountId, Balance> {
+	/// The limits set by the CType creator.
+	pub limits: IssuanceLimits,
+	/// The deposit taken from the payer who first set the limits.
+	pub deposit: Deposit<AccountId, Balance>,
+}
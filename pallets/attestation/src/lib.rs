@@ -51,6 +51,16 @@
 //!   attester. This could be an employe of a company which is authorized to
 //!   sign documents for their superiors.
 //!
+//! - **Issuance limits:**: Optional limits set by the creator of a CType on
+//!   how many attestations each attester can issue for it, in total and
+//!   within a single block. The number of attestations issued by each
+//!   attester for each CType is always tracked.
+//!
+//! - **Challenge:**: A bonded dispute of an attestation. The attester can
+//!   concede by revoking the attestation or contest the challenge, in which
+//!   case it is adjudicated by governance. The bond of the losing party is
+//!   transferred to the winning party.
+//!
 //! ## Assumptions
 //!
 //! - The claim which shall be attested is based on a CType and signed by the
@@ -62,7 +72,9 @@
 #![allow(clippy::unused_unit)]
 
 pub mod attestations;
+pub mod challenges;
 pub mod default_weights;
+pub mod issuance;
 pub mod migrations;
 
 #[cfg(any(feature = "mock", test))]
@@ -75,12 +87,19 @@ pub mod benchmarking;
 mod try_state;
 
 mod access_control;
+mod accreditation;
 pub mod authorized_by;
 #[cfg(test)]
 mod tests;
 
 pub use crate::{
-	access_control::AttestationAccessControl, attestations::AttestationDetails, default_weights::WeightInfo, pallet::*,
+	access_control::AttestationAccessControl,
+	accreditation::AttesterAccreditation,
+	attestations::AttestationDetails,
+	challenges::ChallengeDetails,
+	default_weights::WeightInfo,
+	issuance::{IssuanceCounter, IssuanceCounterDetails, IssuanceLimits, IssuanceLimitsDetails},
+	pallet::*,
 };
 
 #[frame_support::pallet]
@@ -93,19 +112,27 @@ pub mod pallet {
 		pallet_prelude::*,
 		traits::{
 			fungible::{Inspect, MutateHold},
+			tokens::{Fortitude, Precision, Restriction},
 			Get, StorageVersion,
 		},
 	};
 	use frame_system::pallet_prelude::*;
+	use sp_std::vec::Vec;
 
 	use ctype::CtypeHashOf;
 	use kilt_support::{
+		signature::{SignatureVerificationError, VerifySignature},
 		traits::{BalanceMigrationManager, CallSources, StorageDepositCollector},
 		Deposit,
 	};
 
 	/// The current storage version.
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+	pub(crate) const STORAGE_VERSION: StorageVersion =
+		StorageVersion::new(crate::migrations::ISSUANCE_COUNTER_STORAGE_VERSION);
+
+	/// The prefix of the payload the claimer signs to acknowledge an
+	/// attestation.
+	pub const CLAIMER_ACKNOWLEDGEMENT_PREFIX: &[u8; 20] = b"kilt:attestation:ack";
 
 	/// Type of a claim hash.
 	pub type ClaimHashOf<T> = <T as frame_system::Config>::Hash;
@@ -128,12 +155,39 @@ pub mod pallet {
 
 	pub(crate) type AuthorizedByOf<T> = authorized_by::AuthorizedBy<AccountIdOf<T>, AttesterOf<T>>;
 
-	pub type AttestationDetailsOf<T> =
-		AttestationDetails<CtypeHashOf<T>, AttesterOf<T>, AuthorizationIdOf<T>, AccountIdOf<T>, BalanceOf<T>>;
+	/// Type of the content identifier of the off-chain credential content.
+	pub type AttestationCidOf<T> = BoundedVec<u8, <T as Config>::MaxCidLength>;
+
+	/// Type of the signature that the claimer generates over the claim hash
+	/// to acknowledge an attestation.
+	pub type ClaimerSignatureOf<T> = <<T as Config>::ClaimerSignatureVerification as VerifySignature>::Signature;
+
+	pub type AttestationDetailsOf<T> = AttestationDetails<
+		CtypeHashOf<T>,
+		AttesterOf<T>,
+		AuthorizationIdOf<T>,
+		AccountIdOf<T>,
+		BalanceOf<T>,
+		AttestationCidOf<T>,
+	>;
+
+	/// Type of an attestation challenge.
+	pub type ChallengeDetailsOf<T> = ChallengeDetails<AccountIdOf<T>, BalanceOf<T>, BlockNumberFor<T>>;
+
+	/// Type of the issuance counter of an attester for a CType.
+	pub type IssuanceCounterOf<T> = IssuanceCounter<BlockNumberFor<T>>;
+
+	/// Type of the issuance counter of an attester for a CType as stored on
+	/// chain.
+	pub type IssuanceCounterDetailsOf<T> = IssuanceCounterDetails<BlockNumberFor<T>, AccountIdOf<T>, BalanceOf<T>>;
+
+	/// Type of the issuance limits of a CType as stored on chain.
+	pub type IssuanceLimitsDetailsOf<T> = IssuanceLimitsDetails<AccountIdOf<T>, BalanceOf<T>>;
 
 	#[pallet::composite_enum]
 	pub enum HoldReason {
 		Deposit,
+		ChallengeBond,
 	}
 
 	#[pallet::config]
@@ -155,11 +209,21 @@ pub mod pallet {
 		#[pallet::constant]
 		type Deposit: Get<BalanceOf<Self>>;
 
+		/// The deposit that is required for storing the issuance counter of an
+		/// attester for a CType, or the issuance limits of a CType.
+		#[pallet::constant]
+		type IssuanceDeposit: Get<BalanceOf<Self>>;
+
 		/// The maximum number of delegated attestations which can be made by
 		/// the same delegation.
 		#[pallet::constant]
 		type MaxDelegatedAttestations: Get<u32>;
 
+		/// The maximum length of the content identifier that can be stored
+		/// along with an attestation.
+		#[pallet::constant]
+		type MaxCidLength: Get<u32>;
+
 		type AttesterId: Parameter + MaxEncodedLen;
 
 		type AuthorizationId: Parameter + MaxEncodedLen;
@@ -169,6 +233,35 @@ pub mod pallet {
 
 		/// Migration manager to handle new created entries
 		type BalanceMigrationManager: BalanceMigrationManager<AccountIdOf<Self>, BalanceOf<Self>>;
+
+		/// The check deciding which attesters are allowed to attest which
+		/// CTypes. Use `()` to allow any attester for any CType.
+		type AttesterAccreditation: AttesterAccreditation<Self::AttesterId, CtypeHashOf<Self>>;
+
+		/// The bond that is required to challenge an attestation, and to
+		/// contest a challenge. The bond of the losing party is transferred to
+		/// the winning party.
+		#[pallet::constant]
+		type ChallengeBond: Get<BalanceOf<Self>>;
+
+		/// The number of blocks the attester has to contest a challenge before
+		/// it is upheld.
+		#[pallet::constant]
+		type ChallengeResponsePeriod: Get<BlockNumberFor<Self>>;
+
+		/// The origin allowed to adjudicate challenges.
+		type ChallengeAdjudicatorOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+
+		/// The signature that a claimer generates over the claim hash to
+		/// acknowledge an attestation.
+		type ClaimerSignature: Parameter;
+
+		/// The verification of the claimer signature over the claim hash.
+		type ClaimerSignatureVerification: VerifySignature<
+			SignerId = Self::AttesterId,
+			Payload = Vec<u8>,
+			Signature = Self::ClaimerSignature,
+		>;
 	}
 
 	#[pallet::pallet]
@@ -185,10 +278,11 @@ pub mod pallet {
 
 	/// Attestations stored on chain.
 	///
-	/// It maps from a claim hash to the full attestation.
+	/// It maps from a claim hash to the full attestation. The number of
+	/// attestations is tracked in the map counter.
 	#[pallet::storage]
 	#[pallet::getter(fn attestations)]
-	pub type Attestations<T> = StorageMap<_, Blake2_128Concat, ClaimHashOf<T>, AttestationDetailsOf<T>>;
+	pub type Attestations<T> = CountedStorageMap<_, Blake2_128Concat, ClaimHashOf<T>, AttestationDetailsOf<T>>;
 
 	/// Delegated attestations stored on chain.
 	///
@@ -198,8 +292,50 @@ pub mod pallet {
 	pub type ExternalAttestations<T> =
 		StorageDoubleMap<_, Twox64Concat, AuthorizationIdOf<T>, Blake2_128Concat, ClaimHashOf<T>, bool, ValueQuery>;
 
+	/// Index of the non-revoked delegated attestations.
+	///
+	/// It maps from a delegation ID to the claim hashes of the attestations
+	/// issued under it which have not been revoked yet.
+	#[pallet::storage]
+	#[pallet::getter(fn attestations_by_delegation)]
+	pub type AttestationsByDelegation<T> = StorageMap<
+		_,
+		Twox64Concat,
+		AuthorizationIdOf<T>,
+		BoundedVec<ClaimHashOf<T>, <T as Config>::MaxDelegatedAttestations>,
+		ValueQuery,
+	>;
+
+	/// Open challenges of attestations.
+	///
+	/// It maps from a claim hash to the challenge of its attestation.
+	#[pallet::storage]
+	#[pallet::getter(fn challenges)]
+	pub type Challenges<T> = StorageMap<_, Blake2_128Concat, ClaimHashOf<T>, ChallengeDetailsOf<T>>;
+
+	/// The number of attestations issued for each CType by each attester.
+	///
+	/// It maps from a CType hash and an attester to the issuance counter and
+	/// its deposit.
+	#[pallet::storage]
+	#[pallet::getter(fn issuance_counters)]
+	pub type IssuanceCounters<T> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CtypeHashOf<T>,
+		Blake2_128Concat,
+		AttesterOf<T>,
+		IssuanceCounterDetailsOf<T>,
+	>;
+
+	/// The issuance limits set by CType creators.
+	///
+	/// It maps from a CType hash to its issuance limits and their deposit.
+	#[pallet::storage]
+	#[pallet::getter(fn ctype_issuance_limits)]
+	pub type CtypeIssuanceLimits<T> = StorageMap<_, Blake2_128Concat, CtypeHashOf<T>, IssuanceLimitsDetailsOf<T>>;
+
 	#[pallet::event]
-	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
 		/// A new attestation has been created.
 		AttestationCreated {
@@ -212,6 +348,9 @@ pub mod pallet {
 			/// The authorization information. If this is available, it
 			/// authorizes a group of attesters to manage this attestation.
 			authorization: Option<AuthorizationIdOf<T>>,
+			/// The content identifier of the off-chain credential content, if
+			/// provided.
+			cid: Option<AttestationCidOf<T>>,
 		},
 		/// An attestation has been revoked.
 		AttestationRevoked {
@@ -236,6 +375,52 @@ pub mod pallet {
 			/// was deleted.
 			claim_hash: ClaimHashOf<T>,
 		},
+		/// An attestation has been challenged.
+		AttestationChallenged {
+			/// The claim hash of the challenged attestation.
+			claim_hash: ClaimHashOf<T>,
+			/// The account that bonded the challenge.
+			challenger: AccountIdOf<T>,
+			/// The last block in which the attester can contest the challenge.
+			response_deadline: BlockNumberFor<T>,
+		},
+		/// The attester has contested a challenge, which now has to be
+		/// adjudicated.
+		ChallengeContested {
+			/// The claim hash of the challenged attestation.
+			claim_hash: ClaimHashOf<T>,
+			/// The attester of the challenged attestation.
+			attester: AttesterOf<T>,
+		},
+		/// A challenge has been settled.
+		ChallengeSettled {
+			/// The claim hash of the challenged attestation.
+			claim_hash: ClaimHashOf<T>,
+			/// Whether the challenge has been upheld, in which case the
+			/// attestation has been revoked.
+			upheld: bool,
+		},
+		/// The issuance limits of a CType have been updated.
+		IssuanceLimitsSet {
+			/// The hash of the CType.
+			ctype_hash: CtypeHashOf<T>,
+			/// The new issuance limits, if any.
+			limits: Option<IssuanceLimits>,
+		},
+		/// The issuance counter of an attester for a CType has been removed.
+		IssuanceCounterRemoved {
+			/// The hash of the CType.
+			ctype_hash: CtypeHashOf<T>,
+			/// The attester whose counter has been removed.
+			attester: AttesterOf<T>,
+		},
+		/// The claimer has acknowledged a new attestation.
+		ClaimerAcknowledged {
+			/// The claim hash of the attested credential.
+			claim_hash: ClaimHashOf<T>,
+			/// The DID of the claimer who signed the claim hash.
+			claimer: AttesterOf<T>,
+		},
 	}
 
 	#[pallet::error]
@@ -256,8 +441,41 @@ pub mod pallet {
 		/// reached for the corresponding delegation id such that another one
 		/// cannot be added.
 		MaxDelegatedAttestationsExceeded,
+		/// The attester is not accredited to attest claims of the given CType.
+		AttesterNotAccredited,
+		/// The attestation has already been challenged.
+		AlreadyChallenged,
+		/// The attestation is challenged and can only be revoked or removed
+		/// once the challenge has been settled.
+		UnderChallenge,
+		/// No open challenge matching the claim hash.
+		ChallengeNotFound,
+		/// The challenge has already been contested.
+		ChallengeAlreadyContested,
+		/// The response period of the challenge is over.
+		ResponsePeriodOver,
+		/// The response period of the challenge is not over yet, or the
+		/// challenge has been contested.
+		ChallengeNotExpired,
+		/// The attester has already issued the maximum number of attestations
+		/// allowed for the CType.
+		MaxIssuanceReached,
+		/// The attester has already issued the maximum number of attestations
+		/// allowed for the CType in the current block.
+		IssuanceRateExceeded,
+		/// The attester has not issued any attestation for the CType.
+		IssuanceCounterNotFound,
+		/// The issuance counter cannot be removed while the CType has issuance
+		/// limits.
+		IssuanceLimited,
+		/// The claimer could not be found.
+		ClaimerNotFound,
+		/// The claimer signature over the claim hash is invalid.
+		InvalidClaimerSignature,
 	}
 
+	kilt_support::impl_identity_event_deposit!();
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Create a new attestation.
@@ -266,16 +484,27 @@ pub mod pallet {
 		/// delegation that will be saved along with the attestation itself in
 		/// the form of an attested delegation.
 		///
-		/// The referenced CType hash must already be present on chain.
+		/// The referenced CType hash must already be present on chain, and the
+		/// attester must be accredited for it according to the configured
+		/// `AttesterAccreditation`.
 		///
 		/// If an optional delegation id is provided, the dispatch origin must
 		/// be the owner of the delegation. Otherwise, it could be any
 		/// `DelegationEntityId`.
 		///
+		/// The attester can optionally provide the content identifier (e.g.,
+		/// an IPFS CID) of the off-chain credential content, so that verifiers
+		/// can retrieve it. Only the CID is stored on chain, not the content.
+		///
+		/// The attestation is counted towards the issuance counter of the
+		/// attester for the CType, and it fails if that exceeds the issuance
+		/// limits set by the CType creator.
+		///
 		/// Emits `AttestationCreated`.
 		#[pallet::call_index(0)]
 		#[pallet::weight(
 			<T as pallet::Config>::WeightInfo::add()
+			.saturating_add(T::AttesterAccreditation::is_accredited_weight())
 			.saturating_add(authorization.as_ref().map(|ac| ac.can_attest_weight()).unwrap_or(Weight::zero()))
 		)]
 		pub fn add(
@@ -283,55 +512,71 @@ pub mod pallet {
 			claim_hash: ClaimHashOf<T>,
 			ctype_hash: CtypeHashOf<T>,
 			authorization: Option<T::AccessControl>,
+			cid: Option<AttestationCidOf<T>>,
 		) -> DispatchResult {
 			let source = <T as Config>::EnsureOrigin::ensure_origin(origin)?;
-			let payer = source.sender();
-			let who = source.subject();
-			let deposit_amount = <T as Config>::Deposit::get();
 
-			ensure!(
-				ctype::Ctypes::<T>::contains_key(ctype_hash),
-				ctype::Error::<T>::NotFound
-			);
-			ensure!(
-				!Attestations::<T>::contains_key(claim_hash),
-				Error::<T>::AlreadyAttested
-			);
-
-			// Check for validity of the delegation node if specified.
-			authorization
-				.as_ref()
-				.map(|ac| ac.can_attest(&who, &ctype_hash, &claim_hash))
-				.transpose()?;
-			let authorization_id = authorization.as_ref().map(|ac| ac.authorization_id());
-
-			let deposit = AttestationStorageDepositCollector::<T>::create_deposit(payer, deposit_amount)?;
-			<T as Config>::BalanceMigrationManager::exclude_key_from_migration(&Attestations::<T>::hashed_key_for(
+			Self::do_add(
+				source.sender(),
+				source.subject(),
 				claim_hash,
-			));
+				ctype_hash,
+				authorization,
+				cid,
+				false,
+			)
+		}
 
-			log::debug!("insert Attestation");
+		/// Create a new attestation which the claimer acknowledged by signing
+		/// it with their DID.
+		///
+		/// The claimer signs the payload returned by
+		/// `Pallet::claimer_acknowledgement_payload`, which binds the
+		/// signature to the claim hash, the attester and the CType, so that it
+		/// cannot be reused for attestations by other attesters or of other
+		/// CTypes. Apart from verifying the claimer signature, the same
+		/// conditions as for `add` apply. The stored attestation is
+		/// marked as acknowledged by the claimer, so that verifiers can tell
+		/// it apart from an attestation issued unilaterally by the attester.
+		///
+		/// Emits `AttestationCreated` and `ClaimerAcknowledged`.
+		#[pallet::call_index(12)]
+		#[pallet::weight(
+			<T as pallet::Config>::WeightInfo::add_with_claimer_ack()
+			.saturating_add(T::ClaimerSignatureVerification::weight(Pallet::<T>::max_claimer_acknowledgement_payload_len()))
+			.saturating_add(T::AttesterAccreditation::is_accredited_weight())
+			.saturating_add(authorization.as_ref().map(|ac| ac.can_attest_weight()).unwrap_or(Weight::zero()))
+		)]
+		pub fn add_with_claimer_ack(
+			origin: OriginFor<T>,
+			claim_hash: ClaimHashOf<T>,
+			ctype_hash: CtypeHashOf<T>,
+			authorization: Option<T::AccessControl>,
+			cid: Option<AttestationCidOf<T>>,
+			claimer: AttesterOf<T>,
+			claimer_signature: ClaimerSignatureOf<T>,
+		) -> DispatchResult {
+			let source = <T as Config>::EnsureOrigin::ensure_origin(origin)?;
 
-			Attestations::<T>::insert(
-				claim_hash,
-				AttestationDetails {
-					ctype_hash,
-					attester: who.clone(),
-					authorization_id: authorization_id.clone(),
-					revoked: false,
-					deposit,
+			let payload = Self::claimer_acknowledgement_payload(&claim_hash, &source.subject(), &ctype_hash);
+			T::ClaimerSignatureVerification::verify(&claimer, &payload, &claimer_signature).map_err(
+				|err| match err {
+					SignatureVerificationError::SignerInformationNotPresent => Error::<T>::ClaimerNotFound,
+					SignatureVerificationError::SignatureInvalid => Error::<T>::InvalidClaimerSignature,
 				},
-			);
-			if let Some(authorization_id) = &authorization_id {
-				ExternalAttestations::<T>::insert(authorization_id, claim_hash, true);
-			}
+			)?;
 
-			Self::deposit_event(Event::AttestationCreated {
-				attester: who,
+			Self::do_add(
+				source.sender(),
+				source.subject(),
 				claim_hash,
 				ctype_hash,
-				authorization: authorization_id,
-			});
+				authorization,
+				cid,
+				true,
+			)?;
+
+			Self::deposit_event(Event::ClaimerAcknowledged { claim_hash, claimer });
 
 			Ok(())
 		}
@@ -358,11 +603,11 @@ pub mod pallet {
 			let who = source.subject();
 
 			let attestation = Attestations::<T>::get(claim_hash).ok_or(Error::<T>::NotFound)?;
-			let attester = attestation.attester.clone();
 
 			ensure!(!attestation.revoked, Error::<T>::AlreadyRevoked);
+			ensure!(!Challenges::<T>::contains_key(claim_hash), Error::<T>::UnderChallenge);
 
-			let authorized_by = if attester != who {
+			let authorized_by = if attestation.attester != who {
 				let attestation_auth_id = attestation.authorization_id.as_ref().ok_or(Error::<T>::NotAuthorized)?;
 				authorization.ok_or(Error::<T>::NotAuthorized)?.can_revoke(
 					&who,
@@ -377,20 +622,7 @@ pub mod pallet {
 			};
 
 			log::debug!("revoking Attestation");
-			Attestations::<T>::insert(
-				claim_hash,
-				AttestationDetails {
-					revoked: true,
-					..attestation
-				},
-			);
-
-			Self::deposit_event(Event::AttestationRevoked {
-				attester,
-				authorized_by,
-				ctype_hash: attestation.ctype_hash,
-				claim_hash,
-			});
+			Self::revoke_attestation(authorized_by, attestation, claim_hash)?;
 
 			Ok(Some(<T as pallet::Config>::WeightInfo::revoke()).into())
 		}
@@ -498,28 +730,471 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Challenge an attestation by bonding `ChallengeBond`.
+		///
+		/// The attester can contest the challenge within
+		/// `ChallengeResponsePeriod` blocks, in which case the challenge is
+		/// adjudicated by the `ChallengeAdjudicatorOrigin`. An uncontested
+		/// challenge is upheld once the response period is over.
+		///
+		/// Emits `AttestationChallenged`.
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::challenge())]
+		pub fn challenge(origin: OriginFor<T>, claim_hash: ClaimHashOf<T>) -> DispatchResult {
+			let challenger = ensure_signed(origin)?;
+
+			let attestation = Attestations::<T>::get(claim_hash).ok_or(Error::<T>::NotFound)?;
+			ensure!(!attestation.revoked, Error::<T>::AlreadyRevoked);
+			ensure!(
+				!Challenges::<T>::contains_key(claim_hash),
+				Error::<T>::AlreadyChallenged
+			);
+
+			let amount = <T as Config>::ChallengeBond::get();
+			CurrencyOf::<T>::hold(&HoldReason::ChallengeBond.into(), &challenger, amount)?;
+
+			let response_deadline =
+				frame_system::Pallet::<T>::block_number().saturating_add(T::ChallengeResponsePeriod::get());
+			Challenges::<T>::insert(
+				claim_hash,
+				ChallengeDetails {
+					challenger_bond: Deposit {
+						owner: challenger.clone(),
+						amount,
+					},
+					attester_bond: None,
+					response_deadline,
+				},
+			);
+
+			Self::deposit_event(Event::AttestationChallenged {
+				claim_hash,
+				challenger,
+				response_deadline,
+			});
+
+			Ok(())
+		}
+
+		/// Contest the challenge of an attestation by bonding `ChallengeBond`,
+		/// so that the challenge is adjudicated by the
+		/// `ChallengeAdjudicatorOrigin`.
+		///
+		/// The subject of the call must be the attester who issued the
+		/// attestation. The sender of the call pays the bond.
+		///
+		/// Emits `ChallengeContested`.
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::contest_challenge())]
+		pub fn contest_challenge(origin: OriginFor<T>, claim_hash: ClaimHashOf<T>) -> DispatchResult {
+			let source = <T as Config>::EnsureOrigin::ensure_origin(origin)?;
+			let attester = source.subject();
+			let payer = source.sender();
+
+			let attestation = Attestations::<T>::get(claim_hash).ok_or(Error::<T>::NotFound)?;
+			ensure!(attestation.attester == attester, Error::<T>::NotAuthorized);
+
+			let mut challenge = Challenges::<T>::get(claim_hash).ok_or(Error::<T>::ChallengeNotFound)?;
+			ensure!(challenge.attester_bond.is_none(), Error::<T>::ChallengeAlreadyContested);
+			ensure!(
+				frame_system::Pallet::<T>::block_number() <= challenge.response_deadline,
+				Error::<T>::ResponsePeriodOver
+			);
+
+			let amount = <T as Config>::ChallengeBond::get();
+			CurrencyOf::<T>::hold(&HoldReason::ChallengeBond.into(), &payer, amount)?;
+			challenge.attester_bond = Some(Deposit { owner: payer, amount });
+			Challenges::<T>::insert(claim_hash, challenge);
+
+			Self::deposit_event(Event::ChallengeContested { claim_hash, attester });
+
+			Ok(())
+		}
+
+		/// Resolve the challenge of an attestation by revoking the
+		/// attestation. The challenger gets back its bond, plus the bond of
+		/// the attester if the challenge was contested.
+		///
+		/// The subject of the call must be the attester who issued the
+		/// attestation.
+		///
+		/// Emits `AttestationRevoked` and `ChallengeSettled`.
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::resolve_challenge())]
+		pub fn resolve_challenge(origin: OriginFor<T>, claim_hash: ClaimHashOf<T>) -> DispatchResult {
+			let source = <T as Config>::EnsureOrigin::ensure_origin(origin)?;
+			let who = source.subject();
+
+			let attestation = Attestations::<T>::get(claim_hash).ok_or(Error::<T>::NotFound)?;
+			ensure!(attestation.attester == who, Error::<T>::NotAuthorized);
+			ensure!(Challenges::<T>::contains_key(claim_hash), Error::<T>::ChallengeNotFound);
+
+			Self::revoke_attestation(AuthorizedBy::Attester(who), attestation, claim_hash)
+		}
+
+		/// Adjudicate the challenge of an attestation.
+		///
+		/// If the challenge is upheld, the attestation is revoked and the
+		/// challenger gets back its bond, plus the bond of the attester if the
+		/// challenge was contested. Otherwise, the bond of the challenger is
+		/// transferred to the account that contested the challenge or, if
+		/// uncontested, to the deposit owner of the attestation.
+		///
+		/// The dispatch origin must be `ChallengeAdjudicatorOrigin`.
+		///
+		/// Emits `ChallengeSettled`, and `AttestationRevoked` if the challenge
+		/// is upheld.
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::adjudicate_challenge())]
+		pub fn adjudicate_challenge(origin: OriginFor<T>, claim_hash: ClaimHashOf<T>, upheld: bool) -> DispatchResult {
+			T::ChallengeAdjudicatorOrigin::ensure_origin(origin)?;
+
+			let attestation = Attestations::<T>::get(claim_hash).ok_or(Error::<T>::NotFound)?;
+			ensure!(Challenges::<T>::contains_key(claim_hash), Error::<T>::ChallengeNotFound);
+
+			if upheld {
+				Self::revoke_attestation(AuthorizedBy::Challenge, attestation, claim_hash)
+			} else {
+				Self::settle_challenge(&attestation, claim_hash, false)
+			}
+		}
+
+		/// Uphold a challenge that the attester has not contested within the
+		/// response period, revoking the attestation. The challenger gets back
+		/// its bond.
+		///
+		/// The dispatch origin can be any signed account.
+		///
+		/// Emits `AttestationRevoked` and `ChallengeSettled`.
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::expire_challenge())]
+		pub fn expire_challenge(origin: OriginFor<T>, claim_hash: ClaimHashOf<T>) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let challenge = Challenges::<T>::get(claim_hash).ok_or(Error::<T>::ChallengeNotFound)?;
+			ensure!(
+				challenge.attester_bond.is_none()
+					&& frame_system::Pallet::<T>::block_number() > challenge.response_deadline,
+				Error::<T>::ChallengeNotExpired
+			);
+			let attestation = Attestations::<T>::get(claim_hash).ok_or(Error::<T>::NotFound)?;
+
+			Self::revoke_attestation(AuthorizedBy::Challenge, attestation, claim_hash)
+		}
+
+		/// Set or clear the issuance limits of a CType.
+		///
+		/// The origin must be the creator of the CType. The limits apply to
+		/// each attester separately, and only to attestations created after
+		/// the limits have been set. A deposit is taken from the sender when
+		/// the limits are first set, and released when they are cleared.
+		///
+		/// Emits `IssuanceLimitsSet`.
+		#[pallet::call_index(11)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_issuance_limits())]
+		pub fn set_issuance_limits(
+			origin: OriginFor<T>,
+			ctype_hash: CtypeHashOf<T>,
+			limits: Option<IssuanceLimits>,
+		) -> DispatchResult {
+			let source = <T as ctype::Config>::EnsureOrigin::ensure_origin(origin)?;
+			let ctype = ctype::Ctypes::<T>::get(ctype_hash).ok_or(ctype::Error::<T>::NotFound)?;
+
+			ensure!(ctype.creator == source.subject(), Error::<T>::NotAuthorized);
+
+			let old_deposit = CtypeIssuanceLimits::<T>::take(ctype_hash).map(|details| details.deposit);
+			match (limits, old_deposit) {
+				(Some(limits), Some(deposit)) => {
+					CtypeIssuanceLimits::<T>::insert(ctype_hash, IssuanceLimitsDetails { limits, deposit })
+				}
+				(Some(limits), None) => {
+					let deposit = Self::reserve_issuance_deposit(source.sender())?;
+					CtypeIssuanceLimits::<T>::insert(ctype_hash, IssuanceLimitsDetails { limits, deposit });
+				}
+				(None, Some(deposit)) => Self::free_issuance_deposit(&deposit)?,
+				(None, None) => {}
+			}
+
+			Self::deposit_event(Event::IssuanceLimitsSet { ctype_hash, limits });
+
+			Ok(())
+		}
+
+		/// Remove the issuance counter of the attester for a CType and release
+		/// its deposit.
+		///
+		/// As this resets the number of attestations counted for the attester,
+		/// it is only possible while the CType has no issuance limits.
+		///
+		/// The origin must be the attester.
+		///
+		/// Emits `IssuanceCounterRemoved`.
+		#[pallet::call_index(13)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::remove_issuance_counter())]
+		pub fn remove_issuance_counter(origin: OriginFor<T>, ctype_hash: CtypeHashOf<T>) -> DispatchResult {
+			let attester = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
+
+			ensure!(
+				!CtypeIssuanceLimits::<T>::contains_key(ctype_hash),
+				Error::<T>::IssuanceLimited
+			);
+			let details =
+				IssuanceCounters::<T>::take(ctype_hash, &attester).ok_or(Error::<T>::IssuanceCounterNotFound)?;
+			if let Some(deposit) = details.deposit {
+				Self::free_issuance_deposit(&deposit)?;
+			}
+
+			Self::deposit_event(Event::IssuanceCounterRemoved { ctype_hash, attester });
+
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
-		fn remove_attestation(
+		/// Returns the payload the claimer signs to acknowledge the attestation
+		/// of the claim hash by the attester for the CType.
+		pub fn claimer_acknowledgement_payload(
+			claim_hash: &ClaimHashOf<T>,
+			attester: &AttesterOf<T>,
+			ctype_hash: &CtypeHashOf<T>,
+		) -> Vec<u8> {
+			(CLAIMER_ACKNOWLEDGEMENT_PREFIX, claim_hash, attester, ctype_hash).encode()
+		}
+
+		/// Returns the maximum length of the payload signed by the claimer.
+		pub(crate) fn max_claimer_acknowledgement_payload_len() -> usize {
+			CLAIMER_ACKNOWLEDGEMENT_PREFIX
+				.len()
+				.saturating_add(ClaimHashOf::<T>::max_encoded_len())
+				.saturating_add(AttesterOf::<T>::max_encoded_len())
+				.saturating_add(CtypeHashOf::<T>::max_encoded_len())
+		}
+
+		/// Stores a new attestation issued by `who`, taking the deposit from
+		/// `payer`.
+		fn do_add(
+			payer: AccountIdOf<T>,
+			who: AttesterOf<T>,
+			claim_hash: ClaimHashOf<T>,
+			ctype_hash: CtypeHashOf<T>,
+			authorization: Option<T::AccessControl>,
+			cid: Option<AttestationCidOf<T>>,
+			claimer_acknowledged: bool,
+		) -> DispatchResult {
+			let deposit_amount = <T as Config>::Deposit::get();
+
+			ensure!(
+				ctype::Ctypes::<T>::contains_key(ctype_hash),
+				ctype::Error::<T>::NotFound
+			);
+			ensure!(
+				!Attestations::<T>::contains_key(claim_hash),
+				Error::<T>::AlreadyAttested
+			);
+			ensure!(
+				T::AttesterAccreditation::is_accredited(&who, &ctype_hash),
+				Error::<T>::AttesterNotAccredited
+			);
+
+			let issuance_counter = Self::increased_issuance_counter(&ctype_hash, &who, &payer)?;
+
+			// Check for validity of the delegation node if specified.
+			authorization
+				.as_ref()
+				.map(|ac| ac.can_attest(&who, &ctype_hash, &claim_hash))
+				.transpose()?;
+			let authorization_id = authorization.as_ref().map(|ac| ac.authorization_id());
+
+			let deposit = AttestationStorageDepositCollector::<T>::create_deposit_for_key::<
+				BalanceMigrationManagerOf<T>,
+			>(&claim_hash, payer, deposit_amount)?;
+
+			log::debug!("insert Attestation");
+
+			if let Some(authorization_id) = &authorization_id {
+				AttestationsByDelegation::<T>::try_mutate(authorization_id, |claim_hashes| {
+					claim_hashes.try_push(claim_hash)
+				})
+				.map_err(|_| Error::<T>::MaxDelegatedAttestationsExceeded)?;
+			}
+			Attestations::<T>::insert(
+				claim_hash,
+				AttestationDetails {
+					ctype_hash,
+					attester: who.clone(),
+					authorization_id: authorization_id.clone(),
+					revoked: false,
+					deposit,
+					cid: cid.clone(),
+					claimer_acknowledged,
+				},
+			);
+			if let Some(authorization_id) = &authorization_id {
+				ExternalAttestations::<T>::insert(authorization_id, claim_hash, true);
+			}
+			IssuanceCounters::<T>::insert(ctype_hash, &who, issuance_counter);
+
+			Self::deposit_event(Event::AttestationCreated {
+				attester: who,
+				claim_hash,
+				ctype_hash,
+				authorization: authorization_id,
+				cid,
+			});
+
+			Ok(())
+		}
+
+		/// Returns the issuance counter of the attester for the CType after
+		/// issuing a new attestation in the current block, or an error if that
+		/// would exceed the issuance limits of the CType. The deposit for a new
+		/// counter is taken from `payer`.
+		fn increased_issuance_counter(
+			ctype_hash: &CtypeHashOf<T>,
+			attester: &AttesterOf<T>,
+			payer: &AccountIdOf<T>,
+		) -> Result<IssuanceCounterDetailsOf<T>, DispatchError> {
+			let mut details = match IssuanceCounters::<T>::get(ctype_hash, attester) {
+				Some(details) => details,
+				None => IssuanceCounterDetails {
+					counter: IssuanceCounter::default(),
+					deposit: Some(Self::reserve_issuance_deposit(payer.clone())?),
+				},
+			};
+			details.counter = details
+				.counter
+				.increased_at(frame_system::Pallet::<T>::block_number())
+				.ok_or(Error::<T>::MaxIssuanceReached)?;
+
+			if let Some(IssuanceLimitsDetails { limits, .. }) = CtypeIssuanceLimits::<T>::get(ctype_hash) {
+				ensure!(
+					limits.max_issuance.map_or(true, |max| details.counter.total <= max),
+					Error::<T>::MaxIssuanceReached
+				);
+				ensure!(
+					limits
+						.max_per_block
+						.map_or(true, |max| details.counter.issued_in_last_block <= max),
+					Error::<T>::IssuanceRateExceeded
+				);
+			}
+
+			Ok(details)
+		}
+
+		/// Holds the deposit for an issuance counter or issuance limits from
+		/// the payer.
+		fn reserve_issuance_deposit(
+			payer: AccountIdOf<T>,
+		) -> Result<Deposit<AccountIdOf<T>, BalanceOf<T>>, DispatchError> {
+			let amount = <T as Config>::IssuanceDeposit::get();
+			CurrencyOf::<T>::hold(&HoldReason::Deposit.into(), &payer, amount)?;
+			Ok(Deposit { owner: payer, amount })
+		}
+
+		/// Releases the deposit of an issuance counter or issuance limits.
+		fn free_issuance_deposit(deposit: &Deposit<AccountIdOf<T>, BalanceOf<T>>) -> DispatchResult {
+			CurrencyOf::<T>::release(
+				&HoldReason::Deposit.into(),
+				&deposit.owner,
+				deposit.amount,
+				Precision::BestEffort,
+			)?;
+			Ok(())
+		}
+
+		/// Marks the attestation as revoked and upholds its open challenge, if
+		/// any.
+		fn revoke_attestation(
 			authorized_by: AuthorizedByOf<T>,
 			attestation: AttestationDetailsOf<T>,
 			claim_hash: ClaimHashOf<T>,
 		) -> DispatchResult {
-			let is_key_migrated =
-				<T as Config>::BalanceMigrationManager::is_key_migrated(&Attestations::<T>::hashed_key_for(claim_hash));
-			if is_key_migrated {
-				AttestationStorageDepositCollector::<T>::free_deposit(attestation.deposit)?;
+			Attestations::<T>::insert(
+				claim_hash,
+				AttestationDetails {
+					revoked: true,
+					..attestation.clone()
+				},
+			);
+			if let Some(authorization_id) = &attestation.authorization_id {
+				Self::remove_from_delegation_index(authorization_id, &claim_hash);
+			}
+
+			Self::deposit_event(Event::AttestationRevoked {
+				attester: attestation.attester.clone(),
+				authorized_by,
+				ctype_hash: attestation.ctype_hash,
+				claim_hash,
+			});
+
+			Self::settle_challenge(&attestation, claim_hash, true)
+		}
+
+		/// Settles the open challenge of the attestation, if any.
+		///
+		/// The winning party gets back its bond, and the bond of the losing
+		/// party is transferred to it. If a challenge is rejected without
+		/// having been contested, the bond of the challenger is transferred to
+		/// the deposit owner of the attestation.
+		fn settle_challenge(
+			attestation: &AttestationDetailsOf<T>,
+			claim_hash: ClaimHashOf<T>,
+			upheld: bool,
+		) -> DispatchResult {
+			let Some(challenge) = Challenges::<T>::take(claim_hash) else {
+				return Ok(());
+			};
+			let reason: HoldReasonOf<T> = HoldReason::ChallengeBond.into();
+
+			let (winner_bond, loser_bond) = if upheld {
+				(Some(challenge.challenger_bond), challenge.attester_bond)
 			} else {
-				<T as Config>::BalanceMigrationManager::release_reserved_deposit(
-					&attestation.deposit.owner,
-					&attestation.deposit.amount,
-				)
+				(challenge.attester_bond, Some(challenge.challenger_bond))
+			};
+			let winner = winner_bond
+				.as_ref()
+				.map_or_else(|| attestation.deposit.owner.clone(), |bond| bond.owner.clone());
+
+			if let Some(bond) = winner_bond {
+				CurrencyOf::<T>::release(&reason, &bond.owner, bond.amount, Precision::BestEffort)?;
+			}
+			if let Some(bond) = loser_bond {
+				CurrencyOf::<T>::transfer_on_hold(
+					&reason,
+					&bond.owner,
+					&winner,
+					bond.amount,
+					Precision::BestEffort,
+					Restriction::Free,
+					Fortitude::Force,
+				)?;
 			}
 
+			Self::deposit_event(Event::ChallengeSettled { claim_hash, upheld });
+
+			Ok(())
+		}
+
+		fn remove_attestation(
+			authorized_by: AuthorizedByOf<T>,
+			attestation: AttestationDetailsOf<T>,
+			claim_hash: ClaimHashOf<T>,
+		) -> DispatchResult {
+			ensure!(!Challenges::<T>::contains_key(claim_hash), Error::<T>::UnderChallenge);
+
+			AttestationStorageDepositCollector::<T>::release_deposit::<BalanceMigrationManagerOf<T>>(
+				&claim_hash,
+				attestation.deposit.clone(),
+			)?;
+
 			Attestations::<T>::remove(claim_hash);
 			if let Some(authorization_id) = &attestation.authorization_id {
 				ExternalAttestations::<T>::remove(authorization_id, claim_hash);
+				if !attestation.revoked {
+					Self::remove_from_delegation_index(authorization_id, &claim_hash);
+				}
 			}
 			if !attestation.revoked {
 				Self::deposit_event(Event::AttestationRevoked {
@@ -537,6 +1212,19 @@ pub mod pallet {
 			});
 			Ok(())
 		}
+
+		/// Removes the claim hash from the index of the attestations issued
+		/// under the given delegation, dropping the entry once it is empty.
+		fn remove_from_delegation_index(authorization_id: &AuthorizationIdOf<T>, claim_hash: &ClaimHashOf<T>) {
+			AttestationsByDelegation::<T>::mutate_exists(authorization_id, |maybe_claim_hashes| {
+				if let Some(claim_hashes) = maybe_claim_hashes {
+					claim_hashes.retain(|hash| hash != claim_hash);
+					if claim_hashes.is_empty() {
+						*maybe_claim_hashes = None;
+					}
+				}
+			});
+		}
 	}
 
 	pub(crate) struct AttestationStorageDepositCollector<T: Config>(PhantomData<T>);
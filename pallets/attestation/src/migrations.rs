@@ -19,10 +19,20 @@
 use frame_support::{
 	pallet_prelude::DispatchResult,
 	traits::{fungible::Inspect, ReservableCurrency},
+	weights::Weight,
 };
-use kilt_support::migration::switch_reserved_to_hold;
+use kilt_support::{
+	migration::{switch_reserved_to_hold, SteppedMigration},
+	Deposit,
+};
+use parity_scale_codec::{Decode, Encode};
+use sp_std::{marker::PhantomData, vec, vec::Vec};
 
-use crate::{AccountIdOf, Attestations, ClaimHashOf, Config, CurrencyOf, Error, HoldReason};
+use crate::{
+	AccountIdOf, AttestationCidOf, AttestationDetails, Attestations, AttestationsByDelegation, AttesterOf,
+	AuthorizationIdOf, BalanceOf, Call, ClaimHashOf, Config, CurrencyOf, Error, HoldReason, IssuanceCounter,
+	IssuanceCounterDetails, IssuanceCounters, Pallet, WeightInfo,
+};
 
 pub fn update_balance_for_attestation<T: Config>(key: &ClaimHashOf<T>) -> DispatchResult
 where
@@ -37,20 +47,337 @@ where
 	)
 }
 
+/// The attestation details as stored before the introduction of the CID.
+#[derive(Clone, Debug, Decode, Encode, Eq, PartialEq)]
+pub(crate) struct AttestationDetailsV1<CtypeHash, AttesterId, AuthorizationId, AccountId, Balance> {
+	pub(crate) ctype_hash: CtypeHash,
+	pub(crate) attester: AttesterId,
+	pub(crate) authorization_id: Option<AuthorizationId>,
+	pub(crate) revoked: bool,
+	pub(crate) deposit: Deposit<AccountId, Balance>,
+}
+
+pub(crate) type AttestationDetailsV1Of<T> =
+	AttestationDetailsV1<ctype::CtypeHashOf<T>, AttesterOf<T>, AuthorizationIdOf<T>, AccountIdOf<T>, BalanceOf<T>>;
+
+/// The attestation details as stored before the introduction of the claimer
+/// acknowledgement.
+#[derive(Decode, Encode)]
+pub(crate) struct AttestationDetailsV2<CtypeHash, AttesterId, AuthorizationId, AccountId, Balance, Cid> {
+	pub(crate) ctype_hash: CtypeHash,
+	pub(crate) attester: AttesterId,
+	pub(crate) authorization_id: Option<AuthorizationId>,
+	pub(crate) revoked: bool,
+	pub(crate) deposit: Deposit<AccountId, Balance>,
+	pub(crate) cid: Option<Cid>,
+}
+
+pub(crate) type AttestationDetailsV2Of<T> = AttestationDetailsV2<
+	ctype::CtypeHashOf<T>,
+	AttesterOf<T>,
+	AuthorizationIdOf<T>,
+	AccountIdOf<T>,
+	BalanceOf<T>,
+	AttestationCidOf<T>,
+>;
+
+/// The storage version which introduced the attestation CID.
+pub(crate) const CID_STORAGE_VERSION: u16 = 2;
+
+/// The storage version which introduced the index of the attestations by
+/// delegation.
+pub(crate) const DELEGATION_INDEX_STORAGE_VERSION: u16 = 3;
+
+/// The storage version which introduced the claimer acknowledgement.
+pub(crate) const CLAIMER_ACKNOWLEDGEMENT_STORAGE_VERSION: u16 = 4;
+
+/// The storage version which initialized the counter of the attestations.
+pub(crate) const ATTESTATION_COUNTER_STORAGE_VERSION: u16 = 5;
+
+/// The storage version which backfilled the issuance counters.
+pub(crate) const ISSUANCE_COUNTER_STORAGE_VERSION: u16 = 6;
+
+/// Returns the claim hash of the next attestation after the provided raw
+/// storage key, or of the first attestation if no key is provided.
+fn next_claim_hash<T: Config>(cursor: Option<Vec<u8>>) -> Option<ClaimHashOf<T>> {
+	match cursor {
+		Some(cursor) => Attestations::<T>::iter_keys_from(cursor).next(),
+		None => Attestations::<T>::iter_keys().next(),
+	}
+}
+
+/// Adds an empty CID to all the attestations stored before the CID was
+/// introduced, one attestation per step.
+pub struct AddAttestationCid<T>(PhantomData<T>);
+
+impl<T: Config> SteppedMigration for AddAttestationCid<T> {
+	type Pallet = Pallet<T>;
+
+	const FROM: u16 = 1;
+	const TO: u16 = CID_STORAGE_VERSION;
+
+	fn max_step_weight() -> Weight {
+		<T as Config>::WeightInfo::add_attestation_cid_step()
+	}
+
+	fn step(cursor: Option<Vec<u8>>) -> Option<Vec<u8>> {
+		let claim_hash = next_claim_hash::<T>(cursor)?;
+		let key = Attestations::<T>::hashed_key_for(claim_hash);
+		match frame_support::storage::unhashed::get::<AttestationDetailsV1Of<T>>(&key) {
+			Some(old) => frame_support::storage::unhashed::put(
+				&key,
+				&AttestationDetailsV2Of::<T> {
+					ctype_hash: old.ctype_hash,
+					attester: old.attester,
+					authorization_id: old.authorization_id,
+					revoked: old.revoked,
+					deposit: old.deposit,
+					cid: None,
+				},
+			),
+			None => log::error!("Failed to decode attestation {:?}.", claim_hash),
+		}
+		Some(key)
+	}
+}
+
+/// Reads an attestation as stored between the introduction of the CID and the
+/// introduction of the claimer acknowledgement.
+fn get_attestation_v2<T: Config>(claim_hash: &ClaimHashOf<T>) -> Option<AttestationDetailsV2Of<T>> {
+	frame_support::storage::unhashed::get(&Attestations::<T>::hashed_key_for(claim_hash))
+}
+
+/// Builds the index of the non-revoked delegated attestations from the
+/// attestations stored before the index was introduced, one attestation per
+/// step.
+pub struct PopulateAttestationsByDelegation<T>(PhantomData<T>);
+
+impl<T: Config> SteppedMigration for PopulateAttestationsByDelegation<T> {
+	type Pallet = Pallet<T>;
+
+	const FROM: u16 = CID_STORAGE_VERSION;
+	const TO: u16 = DELEGATION_INDEX_STORAGE_VERSION;
+
+	fn max_step_weight() -> Weight {
+		<T as Config>::WeightInfo::populate_attestations_by_delegation_step()
+	}
+
+	fn step(cursor: Option<Vec<u8>>) -> Option<Vec<u8>> {
+		let claim_hash = next_claim_hash::<T>(cursor)?;
+		if let Some(attestation) = get_attestation_v2::<T>(&claim_hash) {
+			if let (Some(authorization_id), false) = (attestation.authorization_id, attestation.revoked) {
+				if AttestationsByDelegation::<T>::try_append(&authorization_id, claim_hash).is_err() {
+					log::error!(
+						"Failed to index attestation {:?} under delegation {:?}.",
+						claim_hash,
+						authorization_id
+					);
+				}
+			}
+		}
+		Some(Attestations::<T>::hashed_key_for(claim_hash))
+	}
+}
+
+/// Marks all the attestations stored before the claimer acknowledgement was
+/// introduced as not acknowledged by the claimer, one attestation per step.
+pub struct AddClaimerAcknowledgement<T>(PhantomData<T>);
+
+impl<T: Config> SteppedMigration for AddClaimerAcknowledgement<T> {
+	type Pallet = Pallet<T>;
+
+	const FROM: u16 = DELEGATION_INDEX_STORAGE_VERSION;
+	const TO: u16 = CLAIMER_ACKNOWLEDGEMENT_STORAGE_VERSION;
+
+	fn max_step_weight() -> Weight {
+		<T as Config>::WeightInfo::add_claimer_acknowledgement_step()
+	}
+
+	fn step(cursor: Option<Vec<u8>>) -> Option<Vec<u8>> {
+		let claim_hash = next_claim_hash::<T>(cursor)?;
+		match get_attestation_v2::<T>(&claim_hash) {
+			Some(old) => Attestations::<T>::insert(
+				claim_hash,
+				AttestationDetails {
+					ctype_hash: old.ctype_hash,
+					attester: old.attester,
+					authorization_id: old.authorization_id,
+					revoked: old.revoked,
+					deposit: old.deposit,
+					cid: old.cid,
+					claimer_acknowledged: false,
+				},
+			),
+			None => log::error!("Failed to decode attestation {:?}.", claim_hash),
+		}
+		Some(Attestations::<T>::hashed_key_for(claim_hash))
+	}
+}
+
+/// Initializes the counter of the attestations, which were stored before the
+/// map was counted, by counting one attestation per step.
+///
+/// Calls adding or removing an attestation which has not been counted yet are
+/// blocked until it has been counted, so that it is neither counted twice nor
+/// missed.
+pub struct InitializeAttestationCounter<T>(PhantomData<T>);
+
+impl<T: Config> SteppedMigration for InitializeAttestationCounter<T> {
+	type Pallet = Pallet<T>;
+
+	const FROM: u16 = CLAIMER_ACKNOWLEDGEMENT_STORAGE_VERSION;
+	const TO: u16 = ATTESTATION_COUNTER_STORAGE_VERSION;
+
+	fn max_step_weight() -> Weight {
+		<T as Config>::WeightInfo::initialize_attestation_counter_step()
+	}
+
+	fn step(cursor: Option<Vec<u8>>) -> Option<Vec<u8>> {
+		let claim_hash = next_claim_hash::<T>(cursor)?;
+		frame_support::storage::unhashed::put(
+			&Attestations::<T>::counter_storage_final_key(),
+			&Attestations::<T>::count().saturating_add(1),
+		);
+		Some(Attestations::<T>::hashed_key_for(claim_hash))
+	}
+}
+
+/// Counts the attestations stored before the issuance counters were
+/// introduced towards the issuance counters of their attesters, one
+/// attestation per step. The attestations which had already been removed
+/// cannot be counted.
+///
+/// The backfilled counters have no deposit. Calls adding an attestation which
+/// has not been counted yet are blocked until it has been counted, so that it
+/// is not counted twice.
+pub struct InitializeIssuanceCounters<T>(PhantomData<T>);
+
+impl<T: Config> SteppedMigration for InitializeIssuanceCounters<T> {
+	type Pallet = Pallet<T>;
+
+	const FROM: u16 = ATTESTATION_COUNTER_STORAGE_VERSION;
+	const TO: u16 = ISSUANCE_COUNTER_STORAGE_VERSION;
+
+	fn max_step_weight() -> Weight {
+		<T as Config>::WeightInfo::initialize_issuance_counters_step()
+	}
+
+	fn step(cursor: Option<Vec<u8>>) -> Option<Vec<u8>> {
+		let claim_hash = next_claim_hash::<T>(cursor)?;
+		if let Some(attestation) = Attestations::<T>::get(claim_hash) {
+			IssuanceCounters::<T>::mutate(attestation.ctype_hash, &attestation.attester, |maybe_details| {
+				let details = maybe_details.get_or_insert_with(|| IssuanceCounterDetails {
+					counter: IssuanceCounter::default(),
+					deposit: None,
+				});
+				details.counter.total = details.counter.total.saturating_add(1);
+			});
+		}
+		Some(Attestations::<T>::hashed_key_for(claim_hash))
+	}
+}
+
+/// Returns the storage keys of the attestations accessed by the call, in the
+/// key space of the cursors of the attestation migrations.
+pub fn migrated_storage_keys<T: Config>(call: &Call<T>) -> Option<Vec<Vec<u8>>> {
+	let claim_hash = match call {
+		Call::add { claim_hash, .. }
+		| Call::add_with_claimer_ack { claim_hash, .. }
+		| Call::revoke { claim_hash, .. }
+		| Call::remove { claim_hash, .. }
+		| Call::reclaim_deposit { claim_hash }
+		| Call::change_deposit_owner { claim_hash }
+		| Call::update_deposit { claim_hash }
+		| Call::challenge { claim_hash }
+		| Call::contest_challenge { claim_hash }
+		| Call::resolve_challenge { claim_hash }
+		| Call::adjudicate_challenge { claim_hash, .. }
+		| Call::expire_challenge { claim_hash } => claim_hash,
+		Call::set_issuance_limits { .. } => return Some(Vec::new()),
+		_ => return None,
+	};
+	Some(vec![Attestations::<T>::hashed_key_for(claim_hash)])
+}
+
 #[cfg(test)]
 pub mod test {
 	use ctype::mock::get_ctype_hash;
 	use frame_support::{
 		assert_noop,
-		traits::{fungible::InspectHold, ReservableCurrency},
+		traits::{fungible::InspectHold, GetStorageVersion, ReservableCurrency},
 	};
+	use kilt_support::migration::SteppedMigration;
 	use sp_runtime::traits::Zero;
 
 	use crate::{
-		migrations::update_balance_for_attestation, mock::*, AccountIdOf, Attestations, AttesterOf, Config, Error,
-		HoldReason,
+		migrations::{
+			get_attestation_v2, update_balance_for_attestation, AddAttestationCid, AddClaimerAcknowledgement,
+			AttestationDetailsV1, AttestationDetailsV2, InitializeAttestationCounter, InitializeIssuanceCounters,
+			PopulateAttestationsByDelegation,
+		},
+		mock::*,
+		AccountIdOf, AttestationDetailsOf, Attestations, AttestationsByDelegation, AttesterOf, ClaimHashOf, Config,
+		Error, HoldReason, IssuanceCounters, Pallet,
 	};
 
+	/// Overwrites the attestation with its encoding from before the claimer
+	/// acknowledgement was introduced.
+	fn put_attestation_v2(claim_hash: ClaimHashOf<Test>, attestation: &AttestationDetailsOf<Test>) {
+		let old = AttestationDetailsV2 {
+			ctype_hash: attestation.ctype_hash,
+			attester: attestation.attester.clone(),
+			authorization_id: attestation.authorization_id.clone(),
+			revoked: attestation.revoked,
+			deposit: attestation.deposit.clone(),
+			cid: attestation.cid.clone(),
+		};
+		frame_support::storage::unhashed::put(&Attestations::<Test>::hashed_key_for(claim_hash), &old);
+	}
+
+	#[test]
+	fn test_stepped_migrations_bump_one_version_each() {
+		assert_eq!(
+			<AddAttestationCid<Test> as SteppedMigration>::TO,
+			<AddAttestationCid<Test> as SteppedMigration>::FROM + 1
+		);
+		assert_eq!(
+			<PopulateAttestationsByDelegation<Test> as SteppedMigration>::FROM,
+			<AddAttestationCid<Test> as SteppedMigration>::TO
+		);
+		assert_eq!(
+			<PopulateAttestationsByDelegation<Test> as SteppedMigration>::TO,
+			<PopulateAttestationsByDelegation<Test> as SteppedMigration>::FROM + 1
+		);
+		assert_eq!(
+			<AddClaimerAcknowledgement<Test> as SteppedMigration>::FROM,
+			<PopulateAttestationsByDelegation<Test> as SteppedMigration>::TO
+		);
+		assert_eq!(
+			<AddClaimerAcknowledgement<Test> as SteppedMigration>::TO,
+			<AddClaimerAcknowledgement<Test> as SteppedMigration>::FROM + 1
+		);
+		assert_eq!(
+			<InitializeAttestationCounter<Test> as SteppedMigration>::FROM,
+			<AddClaimerAcknowledgement<Test> as SteppedMigration>::TO
+		);
+		assert_eq!(
+			<InitializeAttestationCounter<Test> as SteppedMigration>::TO,
+			<InitializeAttestationCounter<Test> as SteppedMigration>::FROM + 1
+		);
+		assert_eq!(
+			<InitializeIssuanceCounters<Test> as SteppedMigration>::FROM,
+			<InitializeAttestationCounter<Test> as SteppedMigration>::TO
+		);
+		assert_eq!(
+			<InitializeIssuanceCounters<Test> as SteppedMigration>::TO,
+			<InitializeIssuanceCounters<Test> as SteppedMigration>::FROM + 1
+		);
+		assert_eq!(
+			Pallet::<Test>::current_storage_version(),
+			<InitializeIssuanceCounters<Test> as SteppedMigration>::TO
+		);
+	}
+
 	#[test]
 	fn test_setup() {
 		let attester: AttesterOf<Test> = sr25519_did_from_public_key(&ALICE_SEED);
@@ -150,4 +477,180 @@ pub mod test {
 				);
 			});
 	}
+
+	#[test]
+	fn test_add_attestation_cid() {
+		let attester: AttesterOf<Test> = sr25519_did_from_public_key(&ALICE_SEED);
+		let claim_hash = claim_hash_from_seed(CLAIM_HASH_SEED_01);
+		let claim_hash2 = claim_hash_from_seed(CLAIM_HASH_SEED_02);
+		let ctype_hash = get_ctype_hash::<Test>(true);
+		let attestation = generate_base_attestation::<Test>(attester.clone(), ACCOUNT_00);
+
+		ExtBuilder::default()
+			.with_ctypes(vec![(ctype_hash, attester)])
+			.with_balances(vec![(ACCOUNT_00, <Test as Config>::Deposit::get() * 100)])
+			.with_attestations(vec![
+				(claim_hash, attestation.clone()),
+				(claim_hash2, attestation.clone()),
+			])
+			// The sanity tests cannot decode the attestations before the claimer
+			// acknowledgement is added.
+			.build()
+			.execute_with(|| {
+				let old = AttestationDetailsV1 {
+					ctype_hash: attestation.ctype_hash,
+					attester: attestation.attester.clone(),
+					authorization_id: attestation.authorization_id.clone(),
+					revoked: attestation.revoked,
+					deposit: attestation.deposit.clone(),
+				};
+				frame_support::storage::unhashed::put(&Attestations::<Test>::hashed_key_for(claim_hash), &old);
+				frame_support::storage::unhashed::put(&Attestations::<Test>::hashed_key_for(claim_hash2), &old);
+
+				let first_cursor =
+					AddAttestationCid::<Test>::step(None).expect("The first attestation should be migrated.");
+				assert!(
+					get_attestation_v2::<Test>(&claim_hash).is_some()
+						^ get_attestation_v2::<Test>(&claim_hash2).is_some()
+				);
+
+				let second_cursor = AddAttestationCid::<Test>::step(Some(first_cursor))
+					.expect("The second attestation should be migrated.");
+				assert!(AddAttestationCid::<Test>::step(Some(second_cursor)).is_none());
+
+				for claim_hash in [claim_hash, claim_hash2] {
+					let migrated = get_attestation_v2::<Test>(&claim_hash).expect("Attestation should be migrated.");
+					assert_eq!(migrated.attester, attestation.attester);
+					assert_eq!(migrated.deposit, attestation.deposit);
+					assert_eq!(migrated.cid, None);
+				}
+			});
+	}
+
+	#[test]
+	fn test_populate_attestations_by_delegation() {
+		let attester: AttesterOf<Test> = sr25519_did_from_public_key(&ALICE_SEED);
+		let delegation: AttesterOf<Test> = sr25519_did_from_public_key(&BOB_SEED);
+		let claim_hash = claim_hash_from_seed(CLAIM_HASH_SEED_01);
+		let revoked_claim_hash = claim_hash_from_seed(CLAIM_HASH_SEED_02);
+		let ctype_hash = get_ctype_hash::<Test>(true);
+		let mut attestation = generate_base_attestation::<Test>(attester.clone(), ACCOUNT_00);
+		attestation.authorization_id = Some(delegation.clone());
+		let mut revoked_attestation = attestation.clone();
+		revoked_attestation.revoked = true;
+
+		ExtBuilder::default()
+			.with_ctypes(vec![(ctype_hash, attester)])
+			.with_balances(vec![(ACCOUNT_00, <Test as Config>::Deposit::get() * 100)])
+			.with_attestations(vec![
+				(claim_hash, attestation.clone()),
+				(revoked_claim_hash, revoked_attestation.clone()),
+			])
+			// The sanity tests cannot decode the attestations before the claimer
+			// acknowledgement is added.
+			.build()
+			.execute_with(|| {
+				put_attestation_v2(claim_hash, &attestation);
+				put_attestation_v2(revoked_claim_hash, &revoked_attestation);
+				AttestationsByDelegation::<Test>::remove(&delegation);
+
+				let mut cursor = None;
+				for _ in 0..2 {
+					cursor = PopulateAttestationsByDelegation::<Test>::step(cursor);
+					assert!(cursor.is_some());
+				}
+				assert!(PopulateAttestationsByDelegation::<Test>::step(cursor).is_none());
+
+				assert_eq!(
+					AttestationsByDelegation::<Test>::get(&delegation).into_inner(),
+					vec![claim_hash]
+				);
+			});
+	}
+
+	#[test]
+	fn test_add_claimer_acknowledgement() {
+		let attester: AttesterOf<Test> = sr25519_did_from_public_key(&ALICE_SEED);
+		let claim_hash = claim_hash_from_seed(CLAIM_HASH_SEED_01);
+		let ctype_hash = get_ctype_hash::<Test>(true);
+		let attestation = generate_base_attestation::<Test>(attester.clone(), ACCOUNT_00);
+
+		ExtBuilder::default()
+			.with_ctypes(vec![(ctype_hash, attester)])
+			.with_balances(vec![(ACCOUNT_00, <Test as Config>::Deposit::get() * 100)])
+			.with_attestations(vec![(claim_hash, attestation.clone())])
+			.build_and_execute_with_sanity_tests(|| {
+				put_attestation_v2(claim_hash, &attestation);
+				assert!(Attestations::<Test>::get(claim_hash).is_none());
+
+				let cursor = AddClaimerAcknowledgement::<Test>::step(None);
+				assert!(cursor.is_some());
+				assert!(AddClaimerAcknowledgement::<Test>::step(cursor).is_none());
+
+				assert_eq!(Attestations::<Test>::get(claim_hash), Some(attestation));
+			});
+	}
+
+	#[test]
+	fn test_initialize_attestation_counter() {
+		let attester: AttesterOf<Test> = sr25519_did_from_public_key(&ALICE_SEED);
+		let ctype_hash = get_ctype_hash::<Test>(true);
+		let attestation = generate_base_attestation::<Test>(attester.clone(), ACCOUNT_00);
+
+		ExtBuilder::default()
+			.with_ctypes(vec![(ctype_hash, attester)])
+			.with_balances(vec![(ACCOUNT_00, <Test as Config>::Deposit::get() * 100)])
+			.with_attestations(vec![
+				(claim_hash_from_seed(CLAIM_HASH_SEED_01), attestation.clone()),
+				(claim_hash_from_seed(CLAIM_HASH_SEED_02), attestation),
+			])
+			.build_and_execute_with_sanity_tests(|| {
+				// The attestations were stored before the map was counted.
+				frame_support::storage::unhashed::kill(&Attestations::<Test>::counter_storage_final_key());
+				assert_eq!(Attestations::<Test>::count(), 0);
+
+				let mut cursor = None;
+				for counted in 1..=2 {
+					cursor = InitializeAttestationCounter::<Test>::step(cursor);
+					assert!(cursor.is_some());
+					assert_eq!(Attestations::<Test>::count(), counted);
+				}
+				assert!(InitializeAttestationCounter::<Test>::step(cursor).is_none());
+				assert_eq!(Attestations::<Test>::count(), 2);
+			});
+	}
+
+	#[test]
+	fn test_initialize_issuance_counters() {
+		let attester: AttesterOf<Test> = sr25519_did_from_public_key(&ALICE_SEED);
+		let other_attester: AttesterOf<Test> = sr25519_did_from_public_key(&BOB_SEED);
+		let ctype_hash = get_ctype_hash::<Test>(true);
+		let attestation = generate_base_attestation::<Test>(attester.clone(), ACCOUNT_00);
+		let other_attestation = generate_base_attestation::<Test>(other_attester.clone(), ACCOUNT_00);
+
+		ExtBuilder::default()
+			.with_ctypes(vec![(ctype_hash, attester.clone())])
+			.with_balances(vec![(ACCOUNT_00, <Test as Config>::Deposit::get() * 100)])
+			.with_attestations(vec![
+				(claim_hash_from_seed(CLAIM_HASH_SEED_01), attestation.clone()),
+				(claim_hash_from_seed(CLAIM_HASH_SEED_02), attestation),
+				(claim_hash_from_seed(3), other_attestation),
+			])
+			.build_and_execute_with_sanity_tests(|| {
+				let mut cursor = None;
+				for _ in 0..3 {
+					cursor = InitializeIssuanceCounters::<Test>::step(cursor);
+					assert!(cursor.is_some());
+				}
+				assert!(InitializeIssuanceCounters::<Test>::step(cursor).is_none());
+
+				let counter = IssuanceCounters::<Test>::get(ctype_hash, &attester).expect("Counter should exist.");
+				assert_eq!(counter.counter.total, 2);
+				assert_eq!(counter.deposit, None);
+				assert_eq!(
+					IssuanceCounters::<Test>::get(ctype_hash, &other_attester).map(|details| details.counter.total),
+					Some(1)
+				);
+			});
+	}
 }
@@ -85,6 +85,8 @@ where
 			owner: payer,
 			amount: deposit,
 		},
+		cid: None,
+		claimer_acknowledged: false,
 	}
 }
 
@@ -163,7 +165,11 @@ pub fn insert_attestation<T: Config>(claim_hash: ClaimHashOf<T>, details: Attest
 
 	crate::Attestations::<T>::insert(claim_hash, details.clone());
 	if let Some(delegation_id) = details.authorization_id.as_ref() {
-		crate::ExternalAttestations::<T>::insert(delegation_id, claim_hash, true)
+		crate::ExternalAttestations::<T>::insert(delegation_id, claim_hash, true);
+		if !details.revoked {
+			crate::AttestationsByDelegation::<T>::try_append(delegation_id, claim_hash)
+				.expect("Should not exceed the maximum number of delegated attestations");
+		}
 	}
 }
 
@@ -181,7 +187,7 @@ pub(crate) mod runtime {
 	use super::*;
 
 	use frame_support::{parameter_types, weights::constants::RocksDbWeight};
-	use frame_system::EnsureSigned;
+	use frame_system::{EnsureRoot, EnsureSigned};
 
 	use sp_core::{ed25519, Pair};
 	use sp_runtime::{
@@ -190,9 +196,12 @@ pub(crate) mod runtime {
 	};
 
 	use ctype::{CtypeCreatorOf, CtypeEntryOf};
-	use kilt_support::mock::{mock_origin, SubjectId};
+	use kilt_support::{
+		mock::{mock_origin, pallet_events, SubjectId},
+		signature::EqualVerify,
+	};
 
-	use crate::{self as attestation, Event};
+	use crate::{self as attestation, AttesterAccreditation, Event};
 
 	type Block = frame_system::mocking::MockBlock<Test>;
 
@@ -204,19 +213,12 @@ pub(crate) mod runtime {
 	pub const UNIT: Balance = 10u128.pow(15);
 	pub const MILLI_UNIT: Balance = 10u128.pow(12);
 	pub const ATTESTATION_DEPOSIT: Balance = 10 * MILLI_UNIT;
+	pub const ISSUANCE_DEPOSIT: Balance = 5 * MILLI_UNIT;
+	pub const CHALLENGE_BOND: Balance = 50 * MILLI_UNIT;
+	pub const CHALLENGE_RESPONSE_PERIOD: u64 = 10;
 
 	pub(crate) fn events() -> Vec<Event<Test>> {
-		System::events()
-			.into_iter()
-			.map(|r| r.event)
-			.filter_map(|e| {
-				if let RuntimeEvent::Attestation(e) = e {
-					Some(e)
-				} else {
-					None
-				}
-			})
-			.collect::<Vec<_>>()
+		pallet_events::<Test, Event<Test>>()
 	}
 
 	frame_support::construct_runtime!(
@@ -301,6 +303,11 @@ pub(crate) mod runtime {
 		type Currency = Balances;
 		type Fee = Fee;
 		type FeeCollector = ();
+		type RuntimeHoldReason = RuntimeHoldReason;
+		type MaxSchemaLength = frame_support::traits::ConstU32<1024>;
+		type SchemaBaseDeposit = frame_support::traits::ConstU128<1_000>;
+		type SchemaTierSize = frame_support::traits::ConstU32<256>;
+		type SchemaTierDeposit = frame_support::traits::ConstU128<1_000>;
 	}
 
 	impl mock_origin::Config for Test {
@@ -311,7 +318,29 @@ pub(crate) mod runtime {
 
 	parameter_types! {
 		pub const MaxDelegatedAttestations: u32 = 1000;
+		pub const MaxCidLength: u32 = 64;
 		pub const Deposit: Balance = ATTESTATION_DEPOSIT;
+		pub const IssuanceDeposit: Balance = ISSUANCE_DEPOSIT;
+		pub const ChallengeBond: Balance = CHALLENGE_BOND;
+		pub const ChallengeResponsePeriod: u64 = CHALLENGE_RESPONSE_PERIOD;
+	}
+
+	parameter_types! {
+		/// CTypes that can only be attested by accredited attesters.
+		pub static RegulatedCtypes: Vec<CtypeHashOf<Test>> = Vec::new();
+		pub static AccreditedAttesters: Vec<SubjectId> = Vec::new();
+	}
+
+	pub struct MockAttesterAccreditation;
+
+	impl AttesterAccreditation<SubjectId, CtypeHashOf<Test>> for MockAttesterAccreditation {
+		fn is_accredited(attester: &SubjectId, ctype: &CtypeHashOf<Test>) -> bool {
+			!RegulatedCtypes::get().contains(ctype) || AccreditedAttesters::get().contains(attester)
+		}
+
+		fn is_accredited_weight() -> Weight {
+			Weight::zero()
+		}
 	}
 
 	impl Config for Test {
@@ -322,11 +351,19 @@ pub(crate) mod runtime {
 		type RuntimeHoldReason = RuntimeHoldReason;
 		type Currency = Balances;
 		type Deposit = Deposit;
+		type IssuanceDeposit = IssuanceDeposit;
 		type MaxDelegatedAttestations = MaxDelegatedAttestations;
+		type MaxCidLength = MaxCidLength;
 		type AttesterId = SubjectId;
 		type AuthorizationId = SubjectId;
 		type AccessControl = MockAccessControl<Self>;
 		type BalanceMigrationManager = ();
+		type AttesterAccreditation = MockAttesterAccreditation;
+		type ChallengeBond = ChallengeBond;
+		type ChallengeResponsePeriod = ChallengeResponsePeriod;
+		type ChallengeAdjudicatorOrigin = EnsureRoot<AccountId>;
+		type ClaimerSignature = (SubjectId, Vec<u8>);
+		type ClaimerSignatureVerification = EqualVerify<SubjectId, Vec<u8>>;
 	}
 
 	pub(crate) const ACCOUNT_00: AccountId = AccountId::new([1u8; 32]);
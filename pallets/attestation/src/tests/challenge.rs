@@ -0,0 +1,267 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::fungible::{Inspect, InspectHold},
+};
+use kilt_support::mock::mock_origin::DoubleOrigin;
+use sp_runtime::{traits::Zero, DispatchError};
+
+use crate::{self as attestation, mock::*, AttesterOf, Config, Event, HoldReason};
+
+#[test]
+fn test_expire_uncontested_challenge() {
+	let attester: AttesterOf<Test> = sr25519_did_from_public_key(&ALICE_SEED);
+	let claim_hash = claim_hash_from_seed(CLAIM_HASH_SEED_01);
+	let attestation = generate_base_attestation::<Test>(attester.clone(), ACCOUNT_00);
+	let ctype_hash = attestation.ctype_hash;
+
+	ExtBuilder::default()
+		.with_balances(vec![
+			(ACCOUNT_00, <Test as Config>::Deposit::get() * 100),
+			(ACCOUNT_01, <Test as Config>::ChallengeBond::get() * 100),
+		])
+		.with_ctypes(vec![(ctype_hash, attester.clone())])
+		.with_attestations(vec![(claim_hash, attestation)])
+		.build_and_execute_with_sanity_tests(|| {
+			let response_deadline = System::block_number() + CHALLENGE_RESPONSE_PERIOD;
+
+			assert_ok!(Attestation::challenge(RuntimeOrigin::signed(ACCOUNT_01), claim_hash));
+			assert_eq!(
+				Balances::balance_on_hold(&HoldReason::ChallengeBond.into(), &ACCOUNT_01),
+				<Test as Config>::ChallengeBond::get()
+			);
+			assert_eq!(
+				Attestation::challenges(claim_hash).map(|challenge| challenge.response_deadline),
+				Some(response_deadline)
+			);
+
+			assert_noop!(
+				Attestation::expire_challenge(RuntimeOrigin::signed(ACCOUNT_01), claim_hash),
+				attestation::Error::<Test>::ChallengeNotExpired
+			);
+
+			System::set_block_number(response_deadline + 1);
+			System::reset_events();
+			assert_ok!(Attestation::expire_challenge(
+				RuntimeOrigin::signed(ACCOUNT_01),
+				claim_hash
+			));
+
+			assert!(Attestation::challenges(claim_hash).is_none());
+			assert!(
+				Attestation::attestations(claim_hash)
+					.expect("Attestation should be present on chain.")
+					.revoked
+			);
+			assert!(Balances::balance_on_hold(&HoldReason::ChallengeBond.into(), &ACCOUNT_01).is_zero());
+			assert_eq!(
+				Balances::balance(&ACCOUNT_01),
+				<Test as Config>::ChallengeBond::get() * 100
+			);
+			assert_eq!(
+				events(),
+				vec![
+					Event::AttestationRevoked {
+						attester,
+						claim_hash,
+						ctype_hash,
+						authorized_by: attestation::authorized_by::AuthorizedBy::Challenge
+					},
+					Event::ChallengeSettled {
+						claim_hash,
+						upheld: true
+					}
+				]
+			);
+		});
+}
+
+#[test]
+fn test_resolve_contested_challenge() {
+	let attester: AttesterOf<Test> = sr25519_did_from_public_key(&ALICE_SEED);
+	let claim_hash = claim_hash_from_seed(CLAIM_HASH_SEED_01);
+	let attestation = generate_base_attestation::<Test>(attester.clone(), ACCOUNT_00);
+	let ctype_hash = attestation.ctype_hash;
+	let bond = <Test as Config>::ChallengeBond::get();
+
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, bond * 100), (ACCOUNT_01, bond * 100)])
+		.with_ctypes(vec![(ctype_hash, attester.clone())])
+		.with_attestations(vec![(claim_hash, attestation)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(Attestation::challenge(RuntimeOrigin::signed(ACCOUNT_01), claim_hash));
+			assert_ok!(Attestation::contest_challenge(
+				DoubleOrigin(ACCOUNT_00, attester.clone()).into(),
+				claim_hash
+			));
+			assert_eq!(
+				Balances::balance_on_hold(&HoldReason::ChallengeBond.into(), &ACCOUNT_00),
+				bond
+			);
+
+			// A contested challenge does not expire.
+			System::set_block_number(System::block_number() + CHALLENGE_RESPONSE_PERIOD + 1);
+			assert_noop!(
+				Attestation::expire_challenge(RuntimeOrigin::signed(ACCOUNT_01), claim_hash),
+				attestation::Error::<Test>::ChallengeNotExpired
+			);
+
+			assert_ok!(Attestation::resolve_challenge(
+				DoubleOrigin(ACCOUNT_00, attester.clone()).into(),
+				claim_hash
+			));
+
+			assert!(Attestation::challenges(claim_hash).is_none());
+			assert!(
+				Attestation::attestations(claim_hash)
+					.expect("Attestation should be present on chain.")
+					.revoked
+			);
+			assert!(Balances::balance_on_hold(&HoldReason::ChallengeBond.into(), &ACCOUNT_00).is_zero());
+			assert!(Balances::balance_on_hold(&HoldReason::ChallengeBond.into(), &ACCOUNT_01).is_zero());
+			assert_eq!(Balances::balance(&ACCOUNT_01), bond * 101);
+		});
+}
+
+#[test]
+fn test_adjudicate_challenge() {
+	let attester: AttesterOf<Test> = sr25519_did_from_public_key(&ALICE_SEED);
+	let claim_hash_01 = claim_hash_from_seed(CLAIM_HASH_SEED_01);
+	let claim_hash_02 = claim_hash_from_seed(CLAIM_HASH_SEED_02);
+	let attestation = generate_base_attestation::<Test>(attester.clone(), ACCOUNT_00);
+	let ctype_hash = attestation.ctype_hash;
+	let bond = <Test as Config>::ChallengeBond::get();
+
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, bond * 100), (ACCOUNT_01, bond * 100)])
+		.with_ctypes(vec![(ctype_hash, attester.clone())])
+		.with_attestations(vec![(claim_hash_01, attestation.clone()), (claim_hash_02, attestation)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(Attestation::challenge(RuntimeOrigin::signed(ACCOUNT_01), claim_hash_01));
+			assert_ok!(Attestation::contest_challenge(
+				DoubleOrigin(ACCOUNT_00, attester.clone()).into(),
+				claim_hash_01
+			));
+			assert_ok!(Attestation::challenge(RuntimeOrigin::signed(ACCOUNT_01), claim_hash_02));
+
+			assert_noop!(
+				Attestation::adjudicate_challenge(RuntimeOrigin::signed(ACCOUNT_01), claim_hash_01, false),
+				DispatchError::BadOrigin
+			);
+
+			// A contested challenge is rejected in favour of the attester.
+			System::reset_events();
+			assert_ok!(Attestation::adjudicate_challenge(
+				RuntimeOrigin::root(),
+				claim_hash_01,
+				false
+			));
+			assert!(Attestation::challenges(claim_hash_01).is_none());
+			assert!(
+				!Attestation::attestations(claim_hash_01)
+					.expect("Attestation should be present on chain.")
+					.revoked
+			);
+			assert!(Balances::balance_on_hold(&HoldReason::ChallengeBond.into(), &ACCOUNT_00).is_zero());
+			assert_eq!(
+				Balances::balance(&ACCOUNT_00),
+				bond * 101 - 2 * <Test as Config>::Deposit::get()
+			);
+			assert_eq!(
+				events(),
+				vec![Event::ChallengeSettled {
+					claim_hash: claim_hash_01,
+					upheld: false
+				}]
+			);
+
+			// An uncontested challenge is upheld and the attestation revoked.
+			assert_ok!(Attestation::adjudicate_challenge(
+				RuntimeOrigin::root(),
+				claim_hash_02,
+				true
+			));
+			assert!(Attestation::challenges(claim_hash_02).is_none());
+			assert!(
+				Attestation::attestations(claim_hash_02)
+					.expect("Attestation should be present on chain.")
+					.revoked
+			);
+			assert!(Balances::balance_on_hold(&HoldReason::ChallengeBond.into(), &ACCOUNT_01).is_zero());
+			assert_eq!(Balances::balance(&ACCOUNT_01), bond * 99);
+		});
+}
+
+#[test]
+fn test_challenge_errors() {
+	let attester: AttesterOf<Test> = sr25519_did_from_public_key(&ALICE_SEED);
+	let other_attester: AttesterOf<Test> = sr25519_did_from_public_key(&BOB_SEED);
+	let claim_hash = claim_hash_from_seed(CLAIM_HASH_SEED_01);
+	let revoked_claim_hash = claim_hash_from_seed(CLAIM_HASH_SEED_02);
+	let attestation = generate_base_attestation::<Test>(attester.clone(), ACCOUNT_00);
+	let mut revoked_attestation = attestation.clone();
+	revoked_attestation.revoked = true;
+	let bond = <Test as Config>::ChallengeBond::get();
+
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, bond * 100), (ACCOUNT_01, bond * 100)])
+		.with_ctypes(vec![(attestation.ctype_hash, attester.clone())])
+		.with_attestations(vec![
+			(claim_hash, attestation),
+			(revoked_claim_hash, revoked_attestation),
+		])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_noop!(
+				Attestation::challenge(RuntimeOrigin::signed(ACCOUNT_01), revoked_claim_hash),
+				attestation::Error::<Test>::AlreadyRevoked
+			);
+			assert_noop!(
+				Attestation::contest_challenge(DoubleOrigin(ACCOUNT_00, attester.clone()).into(), claim_hash),
+				attestation::Error::<Test>::ChallengeNotFound
+			);
+
+			assert_ok!(Attestation::challenge(RuntimeOrigin::signed(ACCOUNT_01), claim_hash));
+			assert_noop!(
+				Attestation::challenge(RuntimeOrigin::signed(ACCOUNT_00), claim_hash),
+				attestation::Error::<Test>::AlreadyChallenged
+			);
+			assert_noop!(
+				Attestation::revoke(DoubleOrigin(ACCOUNT_00, attester.clone()).into(), claim_hash, None),
+				attestation::Error::<Test>::UnderChallenge
+			);
+			assert_noop!(
+				Attestation::reclaim_deposit(RuntimeOrigin::signed(ACCOUNT_00), claim_hash),
+				attestation::Error::<Test>::UnderChallenge
+			);
+			assert_noop!(
+				Attestation::contest_challenge(DoubleOrigin(ACCOUNT_00, other_attester.clone()).into(), claim_hash),
+				attestation::Error::<Test>::NotAuthorized
+			);
+			assert_noop!(
+				Attestation::resolve_challenge(DoubleOrigin(ACCOUNT_00, other_attester).into(), claim_hash),
+				attestation::Error::<Test>::NotAuthorized
+			);
+
+			System::set_block_number(System::block_number() + CHALLENGE_RESPONSE_PERIOD + 1);
+			assert_noop!(
+				Attestation::contest_challenge(DoubleOrigin(ACCOUNT_00, attester).into(), claim_hash),
+				attestation::Error::<Test>::ResponsePeriodOver
+			);
+		});
+}
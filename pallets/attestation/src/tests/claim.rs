@@ -17,11 +17,15 @@
 // If you feel like getting in touch with us, you can do so at info@botlabs.org
 
 use ctype::mock::get_ctype_hash;
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{assert_noop, assert_ok, traits::Get, BoundedVec};
 use kilt_support::mock::mock_origin::DoubleOrigin;
+use parity_scale_codec::Encode;
 use sp_runtime::DispatchError;
 
-use crate::{self as attestation, mock::*, AttestationAccessControl, AttesterOf, Config, Event};
+use crate::{
+	self as attestation, mock::*, AttestationAccessControl, AttestationCidOf, AttestationsByDelegation, AttesterOf,
+	Config, Event,
+};
 
 #[test]
 fn test_attest_without_authorization() {
@@ -38,7 +42,8 @@ fn test_attest_without_authorization() {
 				DoubleOrigin(ACCOUNT_00, attester.clone()).into(),
 				claim_hash,
 				ctype,
-				authorization_info.clone()
+				authorization_info.clone(),
+				None
 			));
 			let stored_attestation =
 				Attestation::attestations(claim_hash).expect("Attestation should be present on chain.");
@@ -50,18 +55,178 @@ fn test_attest_without_authorization() {
 				authorization_info.map(|ac| ac.authorization_id())
 			);
 			assert!(!stored_attestation.revoked);
+			assert!(!stored_attestation.claimer_acknowledged);
 			assert_eq!(
 				events(),
 				vec![Event::AttestationCreated {
 					attester: attester.clone(),
 					claim_hash,
 					ctype_hash: ctype,
-					authorization: None
+					authorization: None,
+					cid: None
 				}]
 			);
 		});
 }
 
+#[test]
+fn test_attest_with_cid() {
+	let attester: AttesterOf<Test> = sr25519_did_from_public_key(&ALICE_SEED);
+	let claim_hash = claim_hash_from_seed(CLAIM_HASH_SEED_01);
+	let ctype = get_ctype_hash::<Test>(true);
+	let cid: AttestationCidOf<Test> = b"bafkreihdwdcefgh4dqkjv67uzcmw7ojee6xedzdetojuzjevtenxquvyku"
+		.to_vec()
+		.try_into()
+		.unwrap();
+
+	ExtBuilder::default()
+		.with_ctypes(vec![(ctype, attester.clone())])
+		.with_balances(vec![(ACCOUNT_00, <Test as Config>::Deposit::get() * 100)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(Attestation::add(
+				DoubleOrigin(ACCOUNT_00, attester.clone()).into(),
+				claim_hash,
+				ctype,
+				None,
+				Some(cid.clone())
+			));
+			let stored_attestation =
+				Attestation::attestations(claim_hash).expect("Attestation should be present on chain.");
+
+			assert_eq!(stored_attestation.cid, Some(cid.clone()));
+			assert_eq!(
+				events(),
+				vec![Event::AttestationCreated {
+					attester: attester.clone(),
+					claim_hash,
+					ctype_hash: ctype,
+					authorization: None,
+					cid: Some(cid)
+				}]
+			);
+		});
+}
+
+#[test]
+fn test_attest_with_claimer_ack() {
+	let attester: AttesterOf<Test> = sr25519_did_from_public_key(&ALICE_SEED);
+	let claimer: AttesterOf<Test> = sr25519_did_from_public_key(&BOB_SEED);
+	let claim_hash = claim_hash_from_seed(CLAIM_HASH_SEED_01);
+	let ctype = get_ctype_hash::<Test>(true);
+
+	ExtBuilder::default()
+		.with_ctypes(vec![(ctype, attester.clone())])
+		.with_balances(vec![(ACCOUNT_00, <Test as Config>::Deposit::get() * 100)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(Attestation::add_with_claimer_ack(
+				DoubleOrigin(ACCOUNT_00, attester.clone()).into(),
+				claim_hash,
+				ctype,
+				None,
+				None,
+				claimer.clone(),
+				(
+					claimer.clone(),
+					Attestation::claimer_acknowledgement_payload(&claim_hash, &attester, &ctype)
+				)
+			));
+			let stored_attestation =
+				Attestation::attestations(claim_hash).expect("Attestation should be present on chain.");
+
+			assert_eq!(stored_attestation.attester, attester);
+			assert!(stored_attestation.claimer_acknowledged);
+			assert_eq!(
+				events(),
+				vec![
+					Event::AttestationCreated {
+						attester: attester.clone(),
+						claim_hash,
+						ctype_hash: ctype,
+						authorization: None,
+						cid: None
+					},
+					Event::ClaimerAcknowledged { claim_hash, claimer }
+				]
+			);
+		});
+}
+
+#[test]
+fn test_attest_with_claimer_ack_invalid_signature() {
+	let attester: AttesterOf<Test> = sr25519_did_from_public_key(&ALICE_SEED);
+	let claimer: AttesterOf<Test> = sr25519_did_from_public_key(&BOB_SEED);
+	let claim_hash = claim_hash_from_seed(CLAIM_HASH_SEED_01);
+	let other_claim_hash = claim_hash_from_seed(CLAIM_HASH_SEED_02);
+	let ctype = get_ctype_hash::<Test>(true);
+
+	ExtBuilder::default()
+		.with_ctypes(vec![(ctype, attester.clone())])
+		.with_balances(vec![(ACCOUNT_00, <Test as Config>::Deposit::get() * 100)])
+		.build_and_execute_with_sanity_tests(|| {
+			// The claimer signed a different claim hash.
+			assert_noop!(
+				Attestation::add_with_claimer_ack(
+					DoubleOrigin(ACCOUNT_00, attester.clone()).into(),
+					claim_hash,
+					ctype,
+					None,
+					None,
+					claimer.clone(),
+					(
+						claimer.clone(),
+						Attestation::claimer_acknowledgement_payload(&other_claim_hash, &attester, &ctype)
+					)
+				),
+				attestation::Error::<Test>::InvalidClaimerSignature
+			);
+			// The claimer acknowledged an attestation by a different attester.
+			assert_noop!(
+				Attestation::add_with_claimer_ack(
+					DoubleOrigin(ACCOUNT_00, attester.clone()).into(),
+					claim_hash,
+					ctype,
+					None,
+					None,
+					claimer.clone(),
+					(
+						claimer.clone(),
+						Attestation::claimer_acknowledgement_payload(&claim_hash, &claimer, &ctype)
+					)
+				),
+				attestation::Error::<Test>::InvalidClaimerSignature
+			);
+			// The claimer only signed the claim hash.
+			assert_noop!(
+				Attestation::add_with_claimer_ack(
+					DoubleOrigin(ACCOUNT_00, attester.clone()).into(),
+					claim_hash,
+					ctype,
+					None,
+					None,
+					claimer.clone(),
+					(claimer.clone(), claim_hash.encode())
+				),
+				attestation::Error::<Test>::InvalidClaimerSignature
+			);
+			// The signature was not generated by the claimer.
+			assert_noop!(
+				Attestation::add_with_claimer_ack(
+					DoubleOrigin(ACCOUNT_00, attester.clone()).into(),
+					claim_hash,
+					ctype,
+					None,
+					None,
+					claimer,
+					(
+						attester.clone(),
+						Attestation::claimer_acknowledgement_payload(&claim_hash, &attester, &ctype)
+					)
+				),
+				attestation::Error::<Test>::InvalidClaimerSignature
+			);
+		});
+}
+
 #[test]
 fn test_attest_authorized() {
 	let attester: AttesterOf<Test> = sr25519_did_from_public_key(&ALICE_SEED);
@@ -77,11 +242,16 @@ fn test_attest_authorized() {
 				DoubleOrigin(ACCOUNT_00, attester.clone()).into(),
 				claim_hash,
 				ctype,
-				authorization_info.clone()
+				authorization_info.clone(),
+				None
 			));
 			let stored_attestation =
 				Attestation::attestations(claim_hash).expect("Attestation should be present on chain.");
 			assert!(Attestation::external_attestations(attester.clone(), claim_hash));
+			assert_eq!(
+				Attestation::attestations_by_delegation(attester.clone()).into_inner(),
+				vec![claim_hash]
+			);
 
 			assert_eq!(stored_attestation.ctype_hash, ctype);
 			assert_eq!(stored_attestation.attester, attester);
@@ -96,12 +266,46 @@ fn test_attest_authorized() {
 					attester: attester.clone(),
 					claim_hash,
 					ctype_hash: ctype,
-					authorization: Some(attester)
+					authorization: Some(attester),
+					cid: None
 				}]
 			);
 		});
 }
 
+#[test]
+fn test_attest_max_delegated_attestations_exceeded() {
+	let attester: AttesterOf<Test> = sr25519_did_from_public_key(&ALICE_SEED);
+	let claim_hash = claim_hash_from_seed(CLAIM_HASH_SEED_01);
+	let ctype = get_ctype_hash::<Test>(true);
+	let authorization_info = Some(MockAccessControl(attester.clone()));
+	let max_delegated_attestations = <Test as Config>::MaxDelegatedAttestations::get();
+
+	ExtBuilder::default()
+		.with_ctypes(vec![(ctype, attester.clone())])
+		.with_balances(vec![(ACCOUNT_00, <Test as Config>::Deposit::get() * 100)])
+		.build()
+		.execute_with(|| {
+			let indexed_claims = (0..max_delegated_attestations)
+				.map(|i| claim_hash_from_seed(u64::from(i) + 100))
+				.collect::<Vec<_>>();
+			let indexed_claims = BoundedVec::<_, <Test as Config>::MaxDelegatedAttestations>::try_from(indexed_claims)
+				.expect("Should fit the maximum number of delegated attestations");
+			AttestationsByDelegation::<Test>::insert(&attester, indexed_claims);
+
+			assert_noop!(
+				Attestation::add(
+					DoubleOrigin(ACCOUNT_00, attester.clone()).into(),
+					claim_hash,
+					ctype,
+					authorization_info,
+					None
+				),
+				attestation::Error::<Test>::MaxDelegatedAttestationsExceeded
+			);
+		});
+}
+
 #[test]
 fn test_attest_unauthorized() {
 	let attester: AttesterOf<Test> = sr25519_did_from_public_key(&ALICE_SEED);
@@ -119,7 +323,8 @@ fn test_attest_unauthorized() {
 					DoubleOrigin(ACCOUNT_00, attester.clone()).into(),
 					claim_hash,
 					ctype,
-					authorization_info
+					authorization_info,
+					None
 				),
 				DispatchError::Other("Unauthorized")
 			);
@@ -140,6 +345,7 @@ fn test_attest_ctype_not_found() {
 					DoubleOrigin(ACCOUNT_00, attester.clone()).into(),
 					claim_hash,
 					ctype_hash,
+					None,
 					None
 				),
 				ctype::Error::<Test>::NotFound
@@ -147,6 +353,53 @@ fn test_attest_ctype_not_found() {
 		});
 }
 
+#[test]
+fn test_attest_regulated_ctype_accredited() {
+	let attester: AttesterOf<Test> = sr25519_did_from_public_key(&ALICE_SEED);
+	let claim_hash = claim_hash_from_seed(CLAIM_HASH_SEED_01);
+	let ctype = get_ctype_hash::<Test>(true);
+	RegulatedCtypes::set(vec![ctype]);
+	AccreditedAttesters::set(vec![attester.clone()]);
+
+	ExtBuilder::default()
+		.with_ctypes(vec![(ctype, attester.clone())])
+		.with_balances(vec![(ACCOUNT_00, <Test as Config>::Deposit::get() * 100)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(Attestation::add(
+				DoubleOrigin(ACCOUNT_00, attester.clone()).into(),
+				claim_hash,
+				ctype,
+				None,
+				None
+			));
+			assert!(Attestation::attestations(claim_hash).is_some());
+		});
+}
+
+#[test]
+fn test_attest_regulated_ctype_not_accredited() {
+	let attester: AttesterOf<Test> = sr25519_did_from_public_key(&ALICE_SEED);
+	let claim_hash = claim_hash_from_seed(CLAIM_HASH_SEED_01);
+	let ctype = get_ctype_hash::<Test>(true);
+	RegulatedCtypes::set(vec![ctype]);
+
+	ExtBuilder::default()
+		.with_ctypes(vec![(ctype, attester.clone())])
+		.with_balances(vec![(ACCOUNT_00, <Test as Config>::Deposit::get() * 100)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_noop!(
+				Attestation::add(
+					DoubleOrigin(ACCOUNT_00, attester.clone()).into(),
+					claim_hash,
+					ctype,
+					None,
+					None
+				),
+				attestation::Error::<Test>::AttesterNotAccredited
+			);
+		});
+}
+
 #[test]
 fn test_attest_already_exists() {
 	let attester: AttesterOf<Test> = sr25519_did_from_public_key(&ALICE_SEED);
@@ -163,6 +416,7 @@ fn test_attest_already_exists() {
 					DoubleOrigin(ACCOUNT_00, attester.clone()).into(),
 					claim_hash,
 					attestation.ctype_hash,
+					None,
 					None
 				),
 				attestation::Error::<Test>::AlreadyAttested
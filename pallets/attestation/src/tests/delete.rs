@@ -113,6 +113,7 @@ fn test_remove_authorized() {
 			));
 			assert!(Attestation::attestations(claim_hash).is_none());
 			assert!(!Attestation::external_attestations(revoker.clone(), claim_hash));
+			assert!(Attestation::attestations_by_delegation(revoker.clone()).is_empty());
 			assert_eq!(
 				events(),
 				vec![
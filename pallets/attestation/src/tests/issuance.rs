@@ -0,0 +1,342 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use ctype::mock::get_ctype_hash;
+use frame_support::{assert_noop, assert_ok, traits::fungible::InspectHold};
+use kilt_support::mock::mock_origin::DoubleOrigin;
+use sp_runtime::traits::Zero;
+
+use crate::{self as attestation, mock::*, AttesterOf, Config, Event, HoldReason, IssuanceCounter, IssuanceLimits};
+
+#[test]
+fn test_attest_increases_issuance_counter() {
+	let attester: AttesterOf<Test> = sr25519_did_from_public_key(&ALICE_SEED);
+	let ctype = get_ctype_hash::<Test>(true);
+
+	ExtBuilder::default()
+		.with_ctypes(vec![(ctype, attester.clone())])
+		.with_balances(vec![(ACCOUNT_00, <Test as Config>::Deposit::get() * 100)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(Attestation::add(
+				DoubleOrigin(ACCOUNT_00, attester.clone()).into(),
+				claim_hash_from_seed(CLAIM_HASH_SEED_01),
+				ctype,
+				None,
+				None
+			));
+			assert_ok!(Attestation::add(
+				DoubleOrigin(ACCOUNT_00, attester.clone()).into(),
+				claim_hash_from_seed(CLAIM_HASH_SEED_02),
+				ctype,
+				None,
+				None
+			));
+			assert_eq!(
+				Attestation::issuance_counters(ctype, &attester).map(|details| details.counter),
+				Some(IssuanceCounter {
+					total: 2,
+					last_issued_at: System::block_number(),
+					issued_in_last_block: 2,
+				})
+			);
+			assert_eq!(
+				Balances::balance_on_hold(&HoldReason::Deposit.into(), &ACCOUNT_00),
+				<Test as Config>::Deposit::get() * 2 + <Test as Config>::IssuanceDeposit::get()
+			);
+
+			// Removing an attestation does not decrease the counter.
+			assert_ok!(Attestation::remove(
+				DoubleOrigin(ACCOUNT_00, attester.clone()).into(),
+				claim_hash_from_seed(CLAIM_HASH_SEED_01),
+				None
+			));
+			System::set_block_number(System::block_number() + 1);
+			assert_ok!(Attestation::add(
+				DoubleOrigin(ACCOUNT_00, attester.clone()).into(),
+				claim_hash_from_seed(CLAIM_HASH_SEED_01),
+				ctype,
+				None,
+				None
+			));
+			assert_eq!(
+				Attestation::issuance_counters(ctype, &attester).map(|details| details.counter),
+				Some(IssuanceCounter {
+					total: 3,
+					last_issued_at: System::block_number(),
+					issued_in_last_block: 1,
+				})
+			);
+		});
+}
+
+#[test]
+fn test_attest_max_issuance_reached() {
+	let attester: AttesterOf<Test> = sr25519_did_from_public_key(&ALICE_SEED);
+	let ctype = get_ctype_hash::<Test>(true);
+
+	ExtBuilder::default()
+		.with_ctypes(vec![(ctype, attester.clone())])
+		.with_balances(vec![(ACCOUNT_00, <Test as Config>::Deposit::get() * 100)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(Attestation::set_issuance_limits(
+				DoubleOrigin(ACCOUNT_00, attester.clone()).into(),
+				ctype,
+				Some(IssuanceLimits {
+					max_issuance: Some(1),
+					max_per_block: None,
+				})
+			));
+			assert_ok!(Attestation::add(
+				DoubleOrigin(ACCOUNT_00, attester.clone()).into(),
+				claim_hash_from_seed(CLAIM_HASH_SEED_01),
+				ctype,
+				None,
+				None
+			));
+			System::set_block_number(System::block_number() + 1);
+			assert_noop!(
+				Attestation::add(
+					DoubleOrigin(ACCOUNT_00, attester.clone()).into(),
+					claim_hash_from_seed(CLAIM_HASH_SEED_02),
+					ctype,
+					None,
+					None
+				),
+				attestation::Error::<Test>::MaxIssuanceReached
+			);
+		});
+}
+
+#[test]
+fn test_attest_issuance_rate_exceeded() {
+	let attester: AttesterOf<Test> = sr25519_did_from_public_key(&ALICE_SEED);
+	let other_attester: AttesterOf<Test> = sr25519_did_from_public_key(&BOB_SEED);
+	let ctype = get_ctype_hash::<Test>(true);
+
+	ExtBuilder::default()
+		.with_ctypes(vec![(ctype, attester.clone())])
+		.with_balances(vec![(ACCOUNT_00, <Test as Config>::Deposit::get() * 100)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(Attestation::set_issuance_limits(
+				DoubleOrigin(ACCOUNT_00, attester.clone()).into(),
+				ctype,
+				Some(IssuanceLimits {
+					max_issuance: None,
+					max_per_block: Some(1),
+				})
+			));
+			assert_ok!(Attestation::add(
+				DoubleOrigin(ACCOUNT_00, attester.clone()).into(),
+				claim_hash_from_seed(CLAIM_HASH_SEED_01),
+				ctype,
+				None,
+				None
+			));
+			assert_noop!(
+				Attestation::add(
+					DoubleOrigin(ACCOUNT_00, attester.clone()).into(),
+					claim_hash_from_seed(CLAIM_HASH_SEED_02),
+					ctype,
+					None,
+					None
+				),
+				attestation::Error::<Test>::IssuanceRateExceeded
+			);
+			// The limit applies to each attester separately.
+			assert_ok!(Attestation::add(
+				DoubleOrigin(ACCOUNT_00, other_attester.clone()).into(),
+				claim_hash_from_seed(CLAIM_HASH_SEED_02),
+				ctype,
+				None,
+				None
+			));
+			// The limit is reset in the next block.
+			System::set_block_number(System::block_number() + 1);
+			assert_ok!(Attestation::add(
+				DoubleOrigin(ACCOUNT_00, attester.clone()).into(),
+				claim_hash_from_seed(3),
+				ctype,
+				None,
+				None
+			));
+		});
+}
+
+#[test]
+fn test_set_issuance_limits() {
+	let creator: AttesterOf<Test> = sr25519_did_from_public_key(&ALICE_SEED);
+	let ctype = get_ctype_hash::<Test>(true);
+	let limits = IssuanceLimits {
+		max_issuance: Some(10),
+		max_per_block: Some(2),
+	};
+
+	ExtBuilder::default()
+		.with_ctypes(vec![(ctype, creator.clone())])
+		.with_balances(vec![(ACCOUNT_00, <Test as Config>::Deposit::get() * 100)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(Attestation::set_issuance_limits(
+				DoubleOrigin(ACCOUNT_00, creator.clone()).into(),
+				ctype,
+				Some(limits)
+			));
+			assert_eq!(
+				Attestation::ctype_issuance_limits(ctype).map(|details| details.limits),
+				Some(limits)
+			);
+			assert_eq!(
+				Balances::balance_on_hold(&HoldReason::Deposit.into(), &ACCOUNT_00),
+				<Test as Config>::IssuanceDeposit::get()
+			);
+			assert_ok!(Attestation::set_issuance_limits(
+				DoubleOrigin(ACCOUNT_00, creator.clone()).into(),
+				ctype,
+				None
+			));
+			assert_eq!(Attestation::ctype_issuance_limits(ctype), None);
+			assert!(Balances::balance_on_hold(&HoldReason::Deposit.into(), &ACCOUNT_00).is_zero());
+			assert_eq!(
+				events(),
+				vec![
+					Event::IssuanceLimitsSet {
+						ctype_hash: ctype,
+						limits: Some(limits)
+					},
+					Event::IssuanceLimitsSet {
+						ctype_hash: ctype,
+						limits: None
+					}
+				]
+			);
+		});
+}
+
+#[test]
+fn test_set_issuance_limits_not_creator() {
+	let creator: AttesterOf<Test> = sr25519_did_from_public_key(&ALICE_SEED);
+	let other: AttesterOf<Test> = sr25519_did_from_public_key(&BOB_SEED);
+	let ctype = get_ctype_hash::<Test>(true);
+
+	ExtBuilder::default()
+		.with_ctypes(vec![(ctype, creator)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_noop!(
+				Attestation::set_issuance_limits(
+					DoubleOrigin(ACCOUNT_00, other).into(),
+					ctype,
+					Some(IssuanceLimits::default())
+				),
+				attestation::Error::<Test>::NotAuthorized
+			);
+		});
+}
+
+#[test]
+fn test_set_issuance_limits_ctype_not_found() {
+	let creator: AttesterOf<Test> = sr25519_did_from_public_key(&ALICE_SEED);
+	let ctype = get_ctype_hash::<Test>(true);
+
+	ExtBuilder::default().build_and_execute_with_sanity_tests(|| {
+		assert_noop!(
+			Attestation::set_issuance_limits(
+				DoubleOrigin(ACCOUNT_00, creator).into(),
+				ctype,
+				Some(IssuanceLimits::default())
+			),
+			ctype::Error::<Test>::NotFound
+		);
+	});
+}
+
+#[test]
+fn test_remove_issuance_counter() {
+	let attester: AttesterOf<Test> = sr25519_did_from_public_key(&ALICE_SEED);
+	let ctype = get_ctype_hash::<Test>(true);
+
+	ExtBuilder::default()
+		.with_ctypes(vec![(ctype, attester.clone())])
+		.with_balances(vec![(ACCOUNT_00, <Test as Config>::Deposit::get() * 100)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(Attestation::add(
+				DoubleOrigin(ACCOUNT_00, attester.clone()).into(),
+				claim_hash_from_seed(CLAIM_HASH_SEED_01),
+				ctype,
+				None,
+				None
+			));
+			assert_ok!(Attestation::remove_issuance_counter(
+				DoubleOrigin(ACCOUNT_00, attester.clone()).into(),
+				ctype
+			));
+			assert_eq!(Attestation::issuance_counters(ctype, &attester), None);
+			assert_eq!(
+				Balances::balance_on_hold(&HoldReason::Deposit.into(), &ACCOUNT_00),
+				<Test as Config>::Deposit::get()
+			);
+			assert_eq!(
+				events().last(),
+				Some(&Event::IssuanceCounterRemoved {
+					ctype_hash: ctype,
+					attester
+				})
+			);
+		});
+}
+
+#[test]
+fn test_remove_issuance_counter_limited() {
+	let attester: AttesterOf<Test> = sr25519_did_from_public_key(&ALICE_SEED);
+	let ctype = get_ctype_hash::<Test>(true);
+
+	ExtBuilder::default()
+		.with_ctypes(vec![(ctype, attester.clone())])
+		.with_balances(vec![(ACCOUNT_00, <Test as Config>::Deposit::get() * 100)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(Attestation::add(
+				DoubleOrigin(ACCOUNT_00, attester.clone()).into(),
+				claim_hash_from_seed(CLAIM_HASH_SEED_01),
+				ctype,
+				None,
+				None
+			));
+			assert_ok!(Attestation::set_issuance_limits(
+				DoubleOrigin(ACCOUNT_00, attester.clone()).into(),
+				ctype,
+				Some(IssuanceLimits::default())
+			));
+			assert_noop!(
+				Attestation::remove_issuance_counter(DoubleOrigin(ACCOUNT_00, attester).into(), ctype),
+				attestation::Error::<Test>::IssuanceLimited
+			);
+		});
+}
+
+#[test]
+fn test_remove_issuance_counter_not_found() {
+	let attester: AttesterOf<Test> = sr25519_did_from_public_key(&ALICE_SEED);
+	let ctype = get_ctype_hash::<Test>(true);
+
+	ExtBuilder::default()
+		.with_ctypes(vec![(ctype, attester.clone())])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_noop!(
+				Attestation::remove_issuance_counter(DoubleOrigin(ACCOUNT_00, attester).into(), ctype),
+				attestation::Error::<Test>::IssuanceCounterNotFound
+			);
+		});
+}
@@ -16,7 +16,9 @@
 
 // If you feel like getting in touch with us, you can do so at info@botlabs.org
 
+mod challenge;
 mod claim;
 mod delete;
 mod deposit;
+mod issuance;
 mod revoke;
@@ -101,6 +101,7 @@ fn test_authorized_revoke() {
 			let stored_attestation =
 				Attestation::attestations(claim_hash).expect("Attestation should be present on chain.");
 			assert!(Attestation::external_attestations(revoker.clone(), claim_hash));
+			assert!(Attestation::attestations_by_delegation(revoker.clone()).is_empty());
 
 			assert!(stored_attestation.revoked);
 			assert_eq!(
@@ -16,14 +16,20 @@
 
 // If you feel like getting in touch with us, you can do so at info@botlabs.org
 
-use frame_support::ensure;
+use frame_support::{ensure, traits::GetStorageVersion};
 use kilt_support::test_utils::log_and_return_error_message;
 use scale_info::prelude::format;
 use sp_runtime::TryRuntimeError;
 
-use crate::{Attestations, Config, ExternalAttestations};
+use crate::{Attestations, AttestationsByDelegation, Challenges, Config, ExternalAttestations, Pallet};
 
 pub(crate) fn do_try_state<T: Config>() -> Result<(), TryRuntimeError> {
+	// The attestations cannot be decoded, and are not indexed yet, until the
+	// stepped migrations of the pallet have completed.
+	if Pallet::<T>::on_chain_storage_version() < Pallet::<T>::current_storage_version() {
+		return Ok(());
+	}
+
 	Attestations::<T>::iter().try_for_each(|(claim_hash, attestation_details)| -> Result<(), TryRuntimeError> {
 		if let Some(authorization_id) = attestation_details.authorization_id {
 			ensure!(
@@ -33,7 +39,51 @@ pub(crate) fn do_try_state<T: Config>() -> Result<(), TryRuntimeError> {
 					authorization_id, claim_hash
 				))
 			);
+			ensure!(
+				attestation_details.revoked
+					|| AttestationsByDelegation::<T>::get(&authorization_id).contains(&claim_hash),
+				log_and_return_error_message(format!(
+					"Attestation with claim_hash {:?} is not indexed under authorization_id {:?}",
+					claim_hash, authorization_id
+				))
+			);
 		}
 		Ok(())
+	})?;
+
+	AttestationsByDelegation::<T>::iter().try_for_each(
+		|(authorization_id, claim_hashes)| -> Result<(), TryRuntimeError> {
+			ensure!(
+				!claim_hashes.is_empty(),
+				log_and_return_error_message(format!(
+					"Empty attestation index for authorization_id {:?}",
+					authorization_id
+				))
+			);
+			claim_hashes
+				.iter()
+				.try_for_each(|claim_hash| -> Result<(), TryRuntimeError> {
+					ensure!(
+						Attestations::<T>::get(claim_hash).is_some_and(|attestation| !attestation.revoked
+							&& attestation.authorization_id.as_ref() == Some(&authorization_id)),
+						log_and_return_error_message(format!(
+							"Indexed attestation with claim_hash {:?} does not exist or is revoked",
+							claim_hash
+						))
+					);
+					Ok(())
+				})
+		},
+	)?;
+
+	Challenges::<T>::iter_keys().try_for_each(|claim_hash| -> Result<(), TryRuntimeError> {
+		ensure!(
+			Attestations::<T>::get(claim_hash).is_some_and(|attestation| !attestation.revoked),
+			log_and_return_error_message(format!(
+				"Challenged attestation with claim_hash {:?} does not exist or is revoked",
+				claim_hash
+			))
+		);
+		Ok(())
 	})
 }
@@ -24,7 +24,7 @@ use frame_support::{
 		EnsureOrigin, Get,
 	},
 };
-use frame_system::pallet_prelude::BlockNumberFor;
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
 use sp_std::{
 	convert::{TryFrom, TryInto},
 	fmt::Debug,
@@ -92,6 +92,47 @@ benchmarks! {
 		assert_eq!(stored_ctype_entry.created_at, new_block_number);
 	}
 
+	register_with_content {
+		let l in 1 .. T::MaxSchemaLength::get();
+
+		let caller = account("caller", 0, SEED);
+		let did: T::CtypeCreatorId = account("did", 0, SEED);
+
+		let content: Vec<u8> = (0u8..u8::MAX).cycle().take(l.try_into().unwrap()).collect();
+		let content = CtypeSchemaContentOf::<T>::try_from(content).expect("Schema should fit the maximum length.");
+		let ctype_hash = <T as frame_system::Config>::Hashing::hash(&content[..]);
+
+		let initial_balance = <T as Config>::Fee::get() + Pallet::<T>::schema_deposit(l) + <T as Config>::Currency::minimum_balance() + <T as Config>::Currency::minimum_balance();
+		<T as Config>::Currency::set_balance(&caller, initial_balance);
+		let origin = T::EnsureOrigin::generate_origin(caller, did.clone());
+
+	}: _<T::RuntimeOrigin>(origin, content.clone())
+	verify {
+		let stored_schema = CtypeSchemas::<T>::get(ctype_hash).expect("CType schema should be present on chain.");
+
+		// Verify the schema has been stored as-is
+		assert_eq!(stored_schema.content, content);
+	}
+
+	remove_content {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let did: T::CtypeCreatorId = account("did", 0, SEED);
+		let max_length = T::MaxSchemaLength::get();
+
+		let content: Vec<u8> = (0u8..u8::MAX).cycle().take(max_length.try_into().unwrap()).collect();
+		let content = CtypeSchemaContentOf::<T>::try_from(content).expect("Schema should fit the maximum length.");
+		let ctype_hash = <T as frame_system::Config>::Hashing::hash(&content[..]);
+
+		let initial_balance = <T as Config>::Fee::get() + Pallet::<T>::schema_deposit(max_length) + <T as Config>::Currency::minimum_balance() + <T as Config>::Currency::minimum_balance();
+		<T as Config>::Currency::set_balance(&caller, initial_balance);
+		let origin = T::EnsureOrigin::generate_origin(caller.clone(), did);
+		Pallet::<T>::register_with_content(origin, content).expect("CType creation should not fail.");
+
+	}: _(RawOrigin::Signed(caller), ctype_hash)
+	verify {
+		assert!(!CtypeSchemas::<T>::contains_key(ctype_hash));
+	}
+
 	impl_benchmark_test_suite!(
 		Pallet,
 		crate::mock::runtime::ExtBuilder::default().build_with_keystore(),
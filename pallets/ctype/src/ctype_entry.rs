@@ -1,4 +1,5 @@
 use frame_support::RuntimeDebug;
+use kilt_support::Deposit;
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 
@@ -10,3 +11,12 @@ pub struct CtypeEntry<Creator, BlockNumber> {
 	/// Block number in which the creation tx was dispatched.
 	pub created_at: BlockNumber,
 }
+
+/// The schema of a CType stored on chain.
+#[derive(Clone, Encode, Decode, RuntimeDebug, MaxEncodedLen, Eq, PartialEq, TypeInfo)]
+pub struct CtypeSchema<Content, AccountId, Balance> {
+	/// The schema bytes the CType hash was computed from.
+	pub content: Content,
+	/// The deposit held for storing the schema.
+	pub deposit: Deposit<AccountId, Balance>,
+}
@@ -52,6 +52,8 @@ use sp_std::marker::PhantomData;
 pub trait WeightInfo {
 	fn add(l: u32, ) -> Weight;
 	fn set_block_number() -> Weight;
+	fn register_with_content(l: u32, ) -> Weight;
+	fn remove_content() -> Weight;
 }
 
 /// Weights for ctype using the Substrate node and recommended hardware.
@@ -84,6 +86,29 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	// The weights below are not taken from the benchmark run above. They are
+	// estimated from the storage accesses of each call.
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: Ctype Ctypes (r:1 w:1)
+	/// Proof: Ctype Ctypes (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Ctype CtypeSchemas (r:0 w:1)
+	/// Proof: Ctype CtypeSchemas (max_values: None, max_size: Some(65612), added: 68087, mode: MaxEncodedLen)
+	fn register_with_content(l: u32, ) -> Weight {
+		Weight::from_parts(63_412_000, 75864)
+			.saturating_add(Weight::from_parts(1_253, 0).saturating_mul(l.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: Ctype CtypeSchemas (r:1 w:1)
+	/// Proof: Ctype CtypeSchemas (max_values: None, max_size: Some(65612), added: 68087, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	fn remove_content() -> Weight {
+		Weight::from_parts(32_118_000, 68087)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -115,4 +140,27 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	// The weights below are not taken from the benchmark run above. They are
+	// estimated from the storage accesses of each call.
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: Ctype Ctypes (r:1 w:1)
+	/// Proof: Ctype Ctypes (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Ctype CtypeSchemas (r:0 w:1)
+	/// Proof: Ctype CtypeSchemas (max_values: None, max_size: Some(65612), added: 68087, mode: MaxEncodedLen)
+	fn register_with_content(l: u32, ) -> Weight {
+		Weight::from_parts(63_412_000, 75864)
+			.saturating_add(Weight::from_parts(1_253, 0).saturating_mul(l.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: Ctype CtypeSchemas (r:1 w:1)
+	/// Proof: Ctype CtypeSchemas (max_values: None, max_size: Some(65612), added: 68087, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	fn remove_content() -> Weight {
+		Weight::from_parts(32_118_000, 68087)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
 }
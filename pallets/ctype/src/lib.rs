@@ -33,6 +33,12 @@
 //!   full name, date of birth, access level and id number. Each of these are
 //!   referred to as an attribute of a credential.
 //!
+//! CType creators can additionally opt into storing the CType schema itself on
+//! chain, for deployments that cannot rely on the off-chain availability of
+//! the schema. The schema is stored as-is, e.g., compressed, and the CType hash
+//! is computed from the stored bytes. A deposit which grows with the size of
+//! the schema is held for as long as it is stored.
+//!
 //! ## Assumptions
 //!
 //! - The CType hash was created using our KILT JS-SDK.
@@ -64,17 +70,23 @@ pub mod pallet {
 		pallet_prelude::*,
 		sp_runtime::traits::Hash,
 		traits::{
-			fungible::{Balanced, Credit, Inspect},
+			fungible::{Balanced, Credit, Inspect, MutateHold},
 			tokens::{Fortitude, Precision, Preservation},
 			OnUnbalanced, StorageVersion,
 		},
 	};
 	use frame_system::pallet_prelude::*;
-	use kilt_support::traits::CallSources;
-	use sp_runtime::SaturatedConversion;
+	use kilt_support::{
+		traits::{CallSources, StorageDepositCollector},
+		Deposit,
+	};
+	use sp_runtime::{
+		traits::{Saturating, Zero},
+		SaturatedConversion,
+	};
 	use sp_std::vec::Vec;
 
-	use crate::ctype_entry::CtypeEntry;
+	use crate::ctype_entry::{CtypeEntry, CtypeSchema};
 
 	/// The current storage version.
 	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
@@ -92,17 +104,45 @@ pub mod pallet {
 	pub(crate) type BalanceOf<T> = <<T as Config>::Currency as Inspect<AccountIdOf<T>>>::Balance;
 	type CreditOf<T> = Credit<<T as frame_system::Config>::AccountId, <T as Config>::Currency>;
 
+	/// Type of the schema bytes of a CType stored on chain.
+	pub type CtypeSchemaContentOf<T> = BoundedVec<u8, <T as Config>::MaxSchemaLength>;
+
+	pub type CtypeSchemaOf<T> = CtypeSchema<CtypeSchemaContentOf<T>, AccountIdOf<T>, BalanceOf<T>>;
+
+	#[pallet::composite_enum]
+	pub enum HoldReason {
+		SchemaDeposit,
+	}
+
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
 		type EnsureOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Self::OriginSuccess>;
 		type OverarchingOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
 		type OriginSuccess: CallSources<AccountIdOf<Self>, CtypeCreatorOf<Self>>;
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
-		type Currency: Balanced<AccountIdOf<Self>>;
+		type RuntimeHoldReason: From<HoldReason>;
+		type Currency: Balanced<AccountIdOf<Self>> + MutateHold<AccountIdOf<Self>, Reason = Self::RuntimeHoldReason>;
 		type WeightInfo: WeightInfo;
 		type CtypeCreatorId: Parameter + MaxEncodedLen;
 		type Fee: Get<BalanceOf<Self>>;
 		type FeeCollector: OnUnbalanced<CreditOf<Self>>;
+
+		/// The maximum length of a CType schema that can be stored on chain.
+		#[pallet::constant]
+		type MaxSchemaLength: Get<u32>;
+
+		/// The deposit that is required for storing any CType schema on chain.
+		#[pallet::constant]
+		type SchemaBaseDeposit: Get<BalanceOf<Self>>;
+
+		/// The size in bytes of each tier of a CType schema stored on chain.
+		#[pallet::constant]
+		type SchemaTierSize: Get<u32>;
+
+		/// The deposit that is additionally required for each started tier of
+		/// a CType schema stored on chain.
+		#[pallet::constant]
+		type SchemaTierDeposit: Get<BalanceOf<Self>>;
 	}
 
 	#[pallet::pallet]
@@ -120,8 +160,14 @@ pub mod pallet {
 	#[pallet::getter(fn ctypes)]
 	pub type Ctypes<T> = StorageMap<_, Blake2_128Concat, CtypeHashOf<T>, CtypeEntryOf<T>>;
 
+	/// CType schemas stored on chain.
+	///
+	/// It maps from a CType hash to its schema and the deposit held for it.
+	#[pallet::storage]
+	#[pallet::getter(fn ctype_schemas)]
+	pub type CtypeSchemas<T> = StorageMap<_, Blake2_128Concat, CtypeHashOf<T>, CtypeSchemaOf<T>>;
+
 	#[pallet::event]
-	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
 		/// A new CType has been created.
 		/// \[creator identifier, CType hash\]
@@ -129,6 +175,12 @@ pub mod pallet {
 		/// Information about a CType has been updated.
 		/// \[CType hash\]
 		CTypeUpdated(CtypeHashOf<T>),
+		/// The schema of a CType has been stored on chain.
+		/// \[CType hash\]
+		CTypeSchemaStored(CtypeHashOf<T>),
+		/// The schema of a CType has been removed from the chain.
+		/// \[CType hash\]
+		CTypeSchemaRemoved(CtypeHashOf<T>),
 	}
 
 	#[pallet::error]
@@ -139,47 +191,50 @@ pub mod pallet {
 		AlreadyExists,
 		/// The paying account was unable to pay the fees for creating a ctype.
 		UnableToPayFees,
+		/// There is no schema stored for the CType with the given hash.
+		SchemaNotFound,
+		/// The origin is not the owner of the deposit of the CType schema.
+		NotAuthorized,
 	}
 
-	#[pallet::call]
+	kilt_support::impl_identity_event_deposit!();
+
 	impl<T: Config> Pallet<T> {
-		/// Create a new CType from the given unique CType hash and associates
-		/// it with its creator.
-		///
-		/// A CType with the same hash must not be stored on chain.
-		///
-		/// Emits `CTypeCreated`.
-		///
-		/// # <weight>
-		/// Weight: O(1)
-		/// - Reads: Ctypes, Balance
-		/// - Writes: Ctypes, Balance
-		/// # </weight>
-		#[pallet::call_index(0)]
-		#[pallet::weight(<T as pallet::Config>::WeightInfo::add(ctype.len().saturated_into()))]
-		pub fn add(origin: OriginFor<T>, ctype: Vec<u8>) -> DispatchResult {
-			let source = <T as Config>::EnsureOrigin::ensure_origin(origin)?;
-			let creator = source.subject();
-			let payer = source.sender();
+		/// The deposit required for storing a CType schema of the given length
+		/// on chain.
+		pub fn schema_deposit(length: u32) -> BalanceOf<T> {
+			let tier_size = T::SchemaTierSize::get().max(1);
+			let tiers = length.saturating_add(tier_size - 1) / tier_size;
+			T::SchemaTierDeposit::get()
+				.saturating_mul(tiers.into())
+				.saturating_add(T::SchemaBaseDeposit::get())
+		}
 
+		/// Charge the creation fee to the payer and store the CType with the
+		/// given schema, returning its hash.
+		fn create_ctype(
+			creator: CtypeCreatorOf<T>,
+			payer: &AccountIdOf<T>,
+			ctype: &[u8],
+		) -> Result<CtypeHashOf<T>, DispatchError> {
 			// Check the free balance before we do any heavy work (e.g. calculate the ctype
 			// hash)
 			let balance = <T::Currency as Inspect<AccountIdOf<T>>>::reducible_balance(
-				&payer,
+				payer,
 				Preservation::Preserve,
 				Fortitude::Polite,
 			);
 
 			ensure!(balance >= T::Fee::get(), Error::<T>::UnableToPayFees);
 
-			let hash = <T as frame_system::Config>::Hashing::hash(&ctype[..]);
+			let hash = <T as frame_system::Config>::Hashing::hash(ctype);
 
 			ensure!(!Ctypes::<T>::contains_key(hash), Error::<T>::AlreadyExists);
 
 			// Collect the fees. This should not fail since we checked the free balance in
 			// the beginning.
 			let imbalance = <T::Currency as Balanced<AccountIdOf<T>>>::withdraw(
-				&payer,
+				payer,
 				T::Fee::get(),
 				Precision::Exact,
 				Preservation::Protect,
@@ -199,6 +254,33 @@ pub mod pallet {
 
 			Self::deposit_event(Event::CTypeCreated(creator, hash));
 
+			Ok(hash)
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Create a new CType from the given unique CType hash and associates
+		/// it with its creator.
+		///
+		/// A CType with the same hash must not be stored on chain.
+		///
+		/// Emits `CTypeCreated`.
+		///
+		/// # <weight>
+		/// Weight: O(1)
+		/// - Reads: Ctypes, Balance
+		/// - Writes: Ctypes, Balance
+		/// # </weight>
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::add(ctype.len().saturated_into()))]
+		pub fn add(origin: OriginFor<T>, ctype: Vec<u8>) -> DispatchResult {
+			let source = <T as Config>::EnsureOrigin::ensure_origin(origin)?;
+			let creator = source.subject();
+			let payer = source.sender();
+
+			Self::create_ctype(creator, &payer, &ctype[..])?;
+
 			Ok(())
 		}
 
@@ -226,5 +308,108 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Create a new CType from the given schema like [`Pallet::add`], and
+		/// additionally store the schema on chain.
+		///
+		/// On top of the creation fee, a deposit depending on the size of the
+		/// schema is held from the sender of the call. It is released when the
+		/// schema is removed with [`Pallet::remove_content`].
+		///
+		/// Emits `CTypeCreated` and `CTypeSchemaStored`.
+		///
+		/// # <weight>
+		/// Weight: O(L) where L is the length of the schema bounded by
+		/// `MaxSchemaLength`.
+		/// - Reads: Ctypes, Balance
+		/// - Writes: Ctypes, CtypeSchemas, Balance
+		/// # </weight>
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::register_with_content(content.len().saturated_into()))]
+		pub fn register_with_content(origin: OriginFor<T>, content: CtypeSchemaContentOf<T>) -> DispatchResult {
+			let source = <T as Config>::EnsureOrigin::ensure_origin(origin)?;
+			let creator = source.subject();
+			let payer = source.sender();
+
+			let deposit_amount = Self::schema_deposit(content.len().saturated_into());
+			ensure!(
+				CtypeSchemaDepositCollector::<T>::can_create_deposit(&payer, deposit_amount),
+				Error::<T>::UnableToPayFees
+			);
+
+			let hash = Self::create_ctype(creator, &payer, &content[..])?;
+
+			let deposit = CtypeSchemaDepositCollector::<T>::create_deposit(payer, deposit_amount)?;
+			CtypeSchemas::<T>::insert(hash, CtypeSchemaOf::<T> { content, deposit });
+
+			Self::deposit_event(Event::CTypeSchemaStored(hash));
+
+			Ok(())
+		}
+
+		/// Remove the schema of a CType from the chain and release its
+		/// deposit. The CType itself is not affected.
+		///
+		/// The origin must be the owner of the deposit of the schema.
+		///
+		/// Emits `CTypeSchemaRemoved`.
+		///
+		/// # <weight>
+		/// Weight: O(1)
+		/// - Reads: CtypeSchemas, Balance
+		/// - Writes: CtypeSchemas, Balance
+		/// # </weight>
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::remove_content())]
+		pub fn remove_content(origin: OriginFor<T>, ctype_hash: CtypeHashOf<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let schema = CtypeSchemas::<T>::get(ctype_hash).ok_or(Error::<T>::SchemaNotFound)?;
+			ensure!(schema.deposit.owner == who, Error::<T>::NotAuthorized);
+
+			CtypeSchemaDepositCollector::<T>::free_deposit(schema.deposit)?;
+			CtypeSchemas::<T>::remove(ctype_hash);
+
+			Self::deposit_event(Event::CTypeSchemaRemoved(ctype_hash));
+
+			Ok(())
+		}
+	}
+
+	pub(crate) struct CtypeSchemaDepositCollector<T: Config>(PhantomData<T>);
+	impl<T: Config> StorageDepositCollector<AccountIdOf<T>, CtypeHashOf<T>, T::RuntimeHoldReason>
+		for CtypeSchemaDepositCollector<T>
+	{
+		type Currency = <T as Config>::Currency;
+		type Reason = HoldReason;
+
+		fn reason() -> Self::Reason {
+			HoldReason::SchemaDeposit
+		}
+
+		fn get_hashed_key(key: &CtypeHashOf<T>) -> Result<Vec<u8>, DispatchError> {
+			Ok(CtypeSchemas::<T>::hashed_key_for(key))
+		}
+
+		fn deposit(key: &CtypeHashOf<T>) -> Result<Deposit<AccountIdOf<T>, BalanceOf<T>>, DispatchError> {
+			let schema = CtypeSchemas::<T>::get(key).ok_or(Error::<T>::SchemaNotFound)?;
+			Ok(schema.deposit)
+		}
+
+		fn deposit_amount(key: &CtypeHashOf<T>) -> BalanceOf<T> {
+			CtypeSchemas::<T>::get(key)
+				.map(|schema| Pallet::<T>::schema_deposit(schema.content.len().saturated_into()))
+				.unwrap_or_else(Zero::zero)
+		}
+
+		fn store_deposit(
+			key: &CtypeHashOf<T>,
+			deposit: Deposit<AccountIdOf<T>, BalanceOf<T>>,
+		) -> Result<(), DispatchError> {
+			CtypeSchemas::<T>::try_mutate(key, |schema| {
+				let schema = schema.as_mut().ok_or(Error::<T>::SchemaNotFound)?;
+				schema.deposit = deposit;
+				Ok(())
+			})
+		}
 	}
 }
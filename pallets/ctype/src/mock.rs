@@ -63,7 +63,7 @@ pub mod runtime {
 		pub enum Test
 		{
 			System: frame_system,
-			Ctype: crate::{Pallet, Call, Storage, Event<T>},
+			Ctype: crate::{Pallet, Call, Storage, Event<T>, HoldReason},
 			Balances: pallet_balances,
 			MockOrigin: mock_origin,
 		}
@@ -106,13 +106,14 @@ pub mod runtime {
 		pub const ExistentialDeposit: Balance = 500;
 		pub const MaxLocks: u32 = 50;
 		pub const MaxReserves: u32 = 50;
+		pub const MaxHolds: u32 = 50;
 	}
 
 	impl pallet_balances::Config for Test {
 		type FreezeIdentifier = ();
-		type RuntimeHoldReason = ();
+		type RuntimeHoldReason = RuntimeHoldReason;
 		type MaxFreezes = ();
-		type MaxHolds = ();
+		type MaxHolds = MaxHolds;
 		type Balance = Balance;
 		type DustRemoval = ();
 		type RuntimeEvent = ();
@@ -132,6 +133,10 @@ pub mod runtime {
 
 	parameter_types! {
 		pub const Fee: Balance = 500;
+		pub const MaxSchemaLength: u32 = 4 * 1024;
+		pub const SchemaBaseDeposit: Balance = 1_000;
+		pub const SchemaTierSize: u32 = 1024;
+		pub const SchemaTierDeposit: Balance = 2_000;
 	}
 
 	ord_parameter_types! {
@@ -146,9 +151,14 @@ pub mod runtime {
 		type RuntimeEvent = ();
 		type WeightInfo = ();
 
+		type RuntimeHoldReason = RuntimeHoldReason;
 		type Currency = Balances;
 		type Fee = Fee;
 		type FeeCollector = ();
+		type MaxSchemaLength = MaxSchemaLength;
+		type SchemaBaseDeposit = SchemaBaseDeposit;
+		type SchemaTierSize = SchemaTierSize;
+		type SchemaTierDeposit = SchemaTierDeposit;
 	}
 
 	pub(crate) const DID_00: SubjectId = SubjectId(AccountId32::new([1u8; 32]));
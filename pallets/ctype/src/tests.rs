@@ -16,13 +16,17 @@
 
 // If you feel like getting in touch with us, you can do so at info@botlabs.org
 
-use frame_support::{assert_noop, assert_ok, sp_runtime::traits::Hash, traits::fungible::Inspect};
+use frame_support::{
+	assert_noop, assert_ok,
+	sp_runtime::traits::Hash,
+	traits::fungible::{Inspect, InspectHold},
+};
 use frame_system::RawOrigin;
-use sp_runtime::DispatchError;
+use sp_runtime::{traits::Zero, DispatchError};
 
 use kilt_support::mock::mock_origin::DoubleOrigin;
 
-use crate::{self as ctype, mock::runtime::*};
+use crate::{self as ctype, mock::runtime::*, CtypeSchemaContentOf, HoldReason};
 
 // submit_ctype_creation_operation
 
@@ -56,6 +60,8 @@ fn check_successful_ctype_creation() {
 				Balances::balance(&deposit_owner),
 				initial_balance.saturating_sub(<Test as ctype::Config>::Fee::get())
 			);
+			// Verify the event is indexed under the identity events topic
+			assert!(!System::event_topics(kilt_support::events::identity_events_topic::<Test>()).is_empty());
 		});
 }
 
@@ -92,6 +98,147 @@ fn check_duplicate_ctype_creation() {
 		});
 }
 
+// register_with_content
+
+#[test]
+fn check_successful_ctype_creation_with_content() {
+	let creator = DID_00;
+	let deposit_owner = ACCOUNT_00;
+	let content = CtypeSchemaContentOf::<Test>::try_from([9u8; 1500].to_vec()).unwrap();
+	let ctype_hash = <Test as frame_system::Config>::Hashing::hash(&content[..]);
+	// The schema spans two tiers.
+	let deposit =
+		<Test as ctype::Config>::SchemaBaseDeposit::get() + 2 * <Test as ctype::Config>::SchemaTierDeposit::get();
+	let initial_balance = (<Test as ctype::Config>::Fee::get() + deposit) * 2;
+	ExtBuilder::default()
+		.with_balances(vec![(deposit_owner.clone(), initial_balance)])
+		.build()
+		.execute_with(|| {
+			assert_eq!(Ctype::schema_deposit(content.len() as u32), deposit);
+			assert_ok!(Ctype::register_with_content(
+				DoubleOrigin(deposit_owner.clone(), creator.clone()).into(),
+				content.clone()
+			));
+
+			assert_eq!(
+				Ctype::ctypes(ctype_hash)
+					.expect("CType hash should be present on chain.")
+					.creator,
+				creator
+			);
+			let stored_schema = Ctype::ctype_schemas(ctype_hash).expect("CType schema should be present on chain.");
+			assert_eq!(stored_schema.content, content);
+			assert_eq!(stored_schema.deposit.owner, deposit_owner);
+			assert_eq!(stored_schema.deposit.amount, deposit);
+			assert_eq!(
+				Balances::balance_on_hold(&HoldReason::SchemaDeposit.into(), &deposit_owner),
+				deposit
+			);
+			assert_eq!(
+				Balances::balance(&deposit_owner),
+				initial_balance - <Test as ctype::Config>::Fee::get() - deposit
+			);
+		});
+}
+
+#[test]
+fn register_with_content_insufficient_funds() {
+	let creator = DID_00;
+	let deposit_owner = ACCOUNT_00;
+	let content = CtypeSchemaContentOf::<Test>::try_from([9u8; 1500].to_vec()).unwrap();
+
+	ExtBuilder::default()
+		.with_balances(vec![(deposit_owner.clone(), <Test as ctype::Config>::Fee::get() * 2)])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				Ctype::register_with_content(DoubleOrigin(deposit_owner, creator).into(), content),
+				ctype::Error::<Test>::UnableToPayFees
+			);
+		});
+}
+
+#[test]
+fn register_with_content_duplicate_ctype() {
+	let creator = DID_00;
+	let deposit_owner = ACCOUNT_00;
+	let content = CtypeSchemaContentOf::<Test>::try_from([9u8; 256].to_vec()).unwrap();
+	let ctype_hash = <Test as frame_system::Config>::Hashing::hash(&content[..]);
+
+	ExtBuilder::default()
+		.with_ctypes(vec![(ctype_hash, creator.clone())])
+		.with_balances(vec![(deposit_owner.clone(), <Test as ctype::Config>::Fee::get() * 100)])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				Ctype::register_with_content(DoubleOrigin(deposit_owner, creator).into(), content),
+				ctype::Error::<Test>::AlreadyExists
+			);
+		});
+}
+
+// remove_content
+
+#[test]
+fn remove_content_ok() {
+	let creator = DID_00;
+	let deposit_owner = ACCOUNT_00;
+	let content = CtypeSchemaContentOf::<Test>::try_from([9u8; 256].to_vec()).unwrap();
+	let ctype_hash = <Test as frame_system::Config>::Hashing::hash(&content[..]);
+
+	ExtBuilder::default()
+		.with_balances(vec![(deposit_owner.clone(), <Test as ctype::Config>::Fee::get() * 100)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(Ctype::register_with_content(
+				DoubleOrigin(deposit_owner.clone(), creator).into(),
+				content
+			));
+			assert_ok!(Ctype::remove_content(
+				RawOrigin::Signed(deposit_owner.clone()).into(),
+				ctype_hash
+			));
+
+			assert!(Ctype::ctype_schemas(ctype_hash).is_none());
+			assert!(Ctype::ctypes(ctype_hash).is_some());
+			assert!(Balances::balance_on_hold(&HoldReason::SchemaDeposit.into(), &deposit_owner).is_zero());
+		});
+}
+
+#[test]
+fn remove_content_not_authorized() {
+	let creator = DID_00;
+	let deposit_owner = ACCOUNT_00;
+	let content = CtypeSchemaContentOf::<Test>::try_from([9u8; 256].to_vec()).unwrap();
+	let ctype_hash = <Test as frame_system::Config>::Hashing::hash(&content[..]);
+
+	ExtBuilder::default()
+		.with_balances(vec![(deposit_owner.clone(), <Test as ctype::Config>::Fee::get() * 100)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(Ctype::register_with_content(
+				DoubleOrigin(deposit_owner, creator).into(),
+				content
+			));
+			assert_noop!(
+				Ctype::remove_content(RawOrigin::Signed(ACCOUNT_01).into(), ctype_hash),
+				ctype::Error::<Test>::NotAuthorized
+			);
+		});
+}
+
+#[test]
+fn remove_content_not_found() {
+	let ctype_hash = <Test as frame_system::Config>::Hashing::hash(&[9u8; 256][..]);
+
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Ctype::remove_content(RawOrigin::Signed(ACCOUNT_00).into(), ctype_hash),
+			ctype::Error::<Test>::SchemaNotFound
+		);
+	});
+}
+
 // set_block_number
 
 #[test]
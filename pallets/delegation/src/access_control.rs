@@ -266,7 +266,8 @@ mod tests {
 					DoubleOrigin(ACCOUNT_00, delegate.clone()).into(),
 					claim_hash,
 					ctype_hash,
-					ac_info.clone()
+					ac_info.clone(),
+					None
 				));
 				let stored_attestation =
 					Attestation::attestations(claim_hash).expect("Attestation should be present on chain.");
@@ -322,7 +323,8 @@ mod tests {
 						DoubleOrigin(ACCOUNT_00, delegate.clone()).into(),
 						claim_hash,
 						ctype_hash,
-						ac_info.clone()
+						ac_info.clone(),
+						None
 					),
 					Error::<Test>::AccessDenied
 				);
@@ -370,7 +372,8 @@ mod tests {
 						DoubleOrigin(ACCOUNT_00, delegate.clone()).into(),
 						claim_hash,
 						ctype_hash,
-						ac_info.clone()
+						ac_info.clone(),
+						None
 					),
 					Error::<Test>::AccessDenied
 				);
@@ -403,7 +406,8 @@ mod tests {
 						DoubleOrigin(ACCOUNT_00, delegate.clone()).into(),
 						claim_hash,
 						ctype_hash,
-						ac_info.clone()
+						ac_info.clone(),
+						None
 					),
 					Error::<Test>::DelegationNotFound
 				);
@@ -451,7 +455,8 @@ mod tests {
 						DoubleOrigin(ACCOUNT_00, delegate.clone()).into(),
 						claim_hash,
 						ctype_hash,
-						ac_info.clone()
+						ac_info.clone(),
+						None
 					),
 					Error::<Test>::AccessDenied
 				);
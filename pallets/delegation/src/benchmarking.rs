@@ -399,6 +399,20 @@ benchmarks! {
 		assert!(<T as Config>::Currency::total_balance_on_hold(&sender).is_zero());
 	}
 
+	// worst case is achieved by a hierarchy that cannot be revoked within a
+	// single call, since the revocation cursor has to be stored
+	revoke_hierarchy_paged {
+		let n in 1 .. T::MaxRevocations::get();
+
+		let sender: T::AccountId = account("sender", 0, SEED);
+		let (root_acc, hierarchy_id, _, leaf_id) = setup_delegations::<T>(n, ONE_CHILD_PER_LEVEL.expect(">0"), Permissions::DELEGATE)?;
+		let origin = <T as Config>::EnsureOrigin::generate_origin(sender, root_acc.into());
+	}: _<T::RuntimeOrigin>(origin, hierarchy_id, n)
+	verify {
+		assert!(HierarchyRevocations::<T>::contains_key(hierarchy_id));
+		assert!(DelegationNodes::<T>::contains_key(leaf_id));
+	}
+
 	can_attest {
 		let c = T::MaxParentChecks::get();
 
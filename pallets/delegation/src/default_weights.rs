@@ -61,6 +61,7 @@ pub trait WeightInfo {
 	fn can_remove(c: u32, ) -> Weight;
 	fn change_deposit_owner() -> Weight;
 	fn update_deposit() -> Weight;
+	fn revoke_hierarchy_paged(n: u32, ) -> Weight;
 }
 
 /// Weights for delegation using the Substrate node and recommended hardware.
@@ -246,6 +247,24 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
+	// The weights below are not taken from the benchmark run above. They are
+	// estimated from the storage accesses of each call.
+	/// Storage: Delegation DelegationHierarchies (r:1 w:0)
+	/// Proof: Delegation DelegationHierarchies (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Delegation HierarchyRevocations (r:1 w:1)
+	/// Proof: Delegation HierarchyRevocations (max_values: None, max_size: Some(145), added: 2620, mode: MaxEncodedLen)
+	/// Storage: Delegation DelegationNodes (r:5 w:5)
+	/// Proof: Delegation DelegationNodes (max_values: None, max_size: Some(32200), added: 34675, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 5]`.
+	fn revoke_hierarchy_paged(n: u32, ) -> Weight {
+		Weight::from_parts(14_212_380, 5175)
+			.saturating_add(Weight::from_parts(6_871_233, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0,34675).saturating_mul(n.into()))
+	}
 }
 
 // For backwards compatibility and tests
@@ -430,4 +449,22 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
+	// The weights below are not taken from the benchmark run above. They are
+	// estimated from the storage accesses of each call.
+	/// Storage: Delegation DelegationHierarchies (r:1 w:0)
+	/// Proof: Delegation DelegationHierarchies (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Delegation HierarchyRevocations (r:1 w:1)
+	/// Proof: Delegation HierarchyRevocations (max_values: None, max_size: Some(145), added: 2620, mode: MaxEncodedLen)
+	/// Storage: Delegation DelegationNodes (r:5 w:5)
+	/// Proof: Delegation DelegationNodes (max_values: None, max_size: Some(32200), added: 34675, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 5]`.
+	fn revoke_hierarchy_paged(n: u32, ) -> Weight {
+		Weight::from_parts(14_212_380, 5175)
+			.saturating_add(Weight::from_parts(6_871_233, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 34675).saturating_mul(n.into()))
+	}
 }
@@ -21,6 +21,7 @@ use frame_support::{storage::bounded_btree_set::BoundedBTreeSet, traits::Get};
 use kilt_support::Deposit;
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
+use sp_std::vec::Vec;
 
 bitflags! {
 	/// Bitflags for permissions.
@@ -157,3 +158,31 @@ pub struct DelegationHierarchyDetails<CtypeHash> {
 	/// delegation hierarchy.
 	pub ctype_hash: CtypeHash,
 }
+
+/// The progress of a paged hierarchy revocation.
+///
+/// Nodes are revoked in depth-first order, each node only after all of its
+/// children, so that no revoked node is ever left with an unrevoked subtree.
+#[derive(Clone, Debug, Encode, Decode, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
+pub struct HierarchyRevocationCursor<DelegationNodeId, DelegatorId> {
+	/// The owner of the hierarchy root who started the revocation.
+	pub revoker: DelegatorId,
+	/// The node the traversal continues from.
+	pub current: DelegationNodeId,
+	/// The last child of the current node whose subtree has been revoked, if
+	/// any.
+	pub last_revoked_child: Option<DelegationNodeId>,
+}
+
+/// A page of the nodes of a delegation subtree.
+///
+/// Nodes are listed in depth-first order, each node before its children.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DelegationSubtreePage<DelegationNodeId, DelegationNode> {
+	/// The nodes in the page, each with its ID and its depth relative to the
+	/// root of the subtree.
+	pub nodes: Vec<(DelegationNodeId, u32, DelegationNode)>,
+	/// The ID of the last node in the page, to continue the traversal from,
+	/// if the subtree has more nodes.
+	pub next_cursor: Option<DelegationNodeId>,
+}
@@ -85,7 +85,7 @@ use frame_support::{dispatch::DispatchResult, ensure, pallet_prelude::Weight, tr
 use kilt_support::traits::{BalanceMigrationManager, StorageDepositCollector};
 use parity_scale_codec::Encode;
 use sp_runtime::{traits::Hash, DispatchError};
-use sp_std::{marker::PhantomData, vec::Vec};
+use sp_std::{marker::PhantomData, ops::Bound, vec::Vec};
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -135,6 +135,10 @@ pub mod pallet {
 
 	pub(crate) type BalanceMigrationManagerOf<T> = <T as Config>::BalanceMigrationManager;
 
+	pub type HierarchyRevocationCursorOf<T> = HierarchyRevocationCursor<DelegationNodeIdOf<T>, DelegatorIdOf<T>>;
+
+	pub type DelegationSubtreePageOf<T> = DelegationSubtreePage<DelegationNodeIdOf<T>, DelegationNodeOf<T>>;
+
 	pub type DelegationNodeOf<T> = DelegationNode<
 		DelegationNodeIdOf<T>,
 		<T as Config>::MaxChildren,
@@ -219,8 +223,16 @@ pub mod pallet {
 	pub type DelegationHierarchies<T> =
 		StorageMap<_, Blake2_128Concat, DelegationNodeIdOf<T>, DelegationHierarchyDetails<CtypeHashOf<T>>>;
 
+	/// Paged hierarchy revocations that have been started but not completed
+	/// yet.
+	///
+	/// It maps from a (root) node ID to the revocation progress.
+	#[pallet::storage]
+	#[pallet::getter(fn hierarchy_revocations)]
+	pub type HierarchyRevocations<T> =
+		StorageMap<_, Blake2_128Concat, DelegationNodeIdOf<T>, HierarchyRevocationCursorOf<T>>;
+
 	#[pallet::event]
-	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
 		/// A new hierarchy has been created.
 		/// \[creator ID, root node ID, CTYPE hash\]
@@ -248,6 +260,10 @@ pub mod pallet {
 		/// A delegation has been removed.
 		/// \[remover ID, delegation node ID\]
 		DelegationRemoved(AccountIdOf<T>, DelegationNodeIdOf<T>),
+		/// A paged hierarchy revocation has made progress but has not
+		/// completed yet.
+		/// \[revoker ID, root node ID\]
+		HierarchyRevocationPaused(DelegatorIdOf<T>, DelegationNodeIdOf<T>),
 	}
 
 	#[pallet::error]
@@ -302,6 +318,9 @@ pub mod pallet {
 		/// The max number of all children has been reached for the
 		/// corresponding delegation node.
 		MaxChildrenExceeded,
+		/// The hierarchy is being revoked in multiple steps and cannot be
+		/// modified until the revocation completes.
+		HierarchyRevocationInProgress,
 	}
 
 	#[pallet::hooks]
@@ -312,6 +331,8 @@ pub mod pallet {
 		}
 	}
 
+	kilt_support::impl_identity_event_deposit!();
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Create a new delegation root associated with a given CType hash.
@@ -429,6 +450,10 @@ pub mod pallet {
 
 			let parent_node = <DelegationNodes<T>>::get(parent_id).ok_or(Error::<T>::ParentDelegationNotFound)?;
 			let hierarchy_root_id = parent_node.hierarchy_root_id;
+			ensure!(
+				!HierarchyRevocations::<T>::contains_key(hierarchy_root_id),
+				Error::<T>::HierarchyRevocationInProgress
+			);
 
 			// Calculate the hash root
 			let hash_root =
@@ -606,6 +631,11 @@ pub mod pallet {
 
 			ensure!(max_removals <= T::MaxRemovals::get(), Error::<T>::MaxRemovalsTooLarge);
 
+			ensure!(
+				!HierarchyRevocations::<T>::contains_key(delegation.hierarchy_root_id),
+				Error::<T>::HierarchyRevocationInProgress
+			);
+
 			// Remove the delegation and recursively all of its children (add 1 to
 			// max_removals to account for the node itself)
 			let (removal_checks, _) = Self::remove(&delegation_id, max_removals.saturating_add(1))?;
@@ -659,6 +689,11 @@ pub mod pallet {
 
 			ensure!(max_removals <= T::MaxRemovals::get(), Error::<T>::MaxRemovalsTooLarge);
 
+			ensure!(
+				!HierarchyRevocations::<T>::contains_key(delegation.hierarchy_root_id),
+				Error::<T>::HierarchyRevocationInProgress
+			);
+
 			// Remove the delegation and recursively all of its children (add 1 to
 			// max_removals to account for the node itself), releasing the associated
 			// deposit
@@ -714,6 +749,77 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Revoke a whole delegation hierarchy over multiple calls.
+		///
+		/// Each call visits up to `max_nodes` nodes of the hierarchy, revoking
+		/// each node only after all of its children, and stores its progress
+		/// on chain so that the next call continues where the previous one
+		/// left off. This allows hierarchies of any size to be revoked without
+		/// exceeding the block weight limit. While the revocation is in
+		/// progress, no delegations can be added to or removed from the
+		/// hierarchy.
+		///
+		/// The revocation can only be started by the owner of the root node,
+		/// but it can be continued by anyone.
+		///
+		/// Emits N * `DelegationRevoked`, and `HierarchyRevoked` once the
+		/// whole hierarchy is revoked or `HierarchyRevocationPaused`
+		/// otherwise.
+		///
+		/// # <weight>
+		/// Weight: O(N) where N is the number of visited nodes which is
+		/// bounded by `max_nodes`.
+		/// - Reads: [Origin Account], Roots, HierarchyRevocations, N *
+		///   Delegations
+		/// - Writes: HierarchyRevocations, N * Delegations
+		/// # </weight>
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config>::WeightInfo::revoke_hierarchy_paged(*max_nodes))]
+		pub fn revoke_hierarchy_paged(
+			origin: OriginFor<T>,
+			hierarchy_id: DelegationNodeIdOf<T>,
+			max_nodes: u32,
+		) -> DispatchResultWithPostInfo {
+			let invoker = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
+
+			ensure!(
+				DelegationHierarchies::<T>::contains_key(hierarchy_id),
+				Error::<T>::HierarchyNotFound
+			);
+
+			ensure!(
+				max_nodes <= T::MaxRevocations::get(),
+				Error::<T>::MaxRevocationsTooLarge
+			);
+			ensure!(max_nodes > 0, Error::<T>::ExceededRevocationBounds);
+
+			let cursor = match HierarchyRevocations::<T>::get(hierarchy_id) {
+				Some(cursor) => cursor,
+				None => {
+					let root_node = DelegationNodes::<T>::get(hierarchy_id).ok_or(Error::<T>::DelegationNotFound)?;
+					ensure!(root_node.details.owner == invoker, Error::<T>::UnauthorizedRevocation);
+					HierarchyRevocationCursorOf::<T> {
+						revoker: invoker,
+						current: hierarchy_id,
+						last_revoked_child: None,
+					}
+				}
+			};
+			let revoker = cursor.revoker.clone();
+
+			let (visited_nodes, next_cursor) = Self::revoke_hierarchy_page(&hierarchy_id, cursor, max_nodes)?;
+
+			if let Some(next_cursor) = next_cursor {
+				HierarchyRevocations::<T>::insert(hierarchy_id, next_cursor);
+				Self::deposit_event(Event::HierarchyRevocationPaused(revoker, hierarchy_id));
+			} else {
+				HierarchyRevocations::<T>::remove(hierarchy_id);
+				Self::deposit_event(Event::HierarchyRevoked(revoker, hierarchy_id));
+			}
+
+			Ok(Some(<T as Config>::WeightInfo::revoke_hierarchy_paged(visited_nodes)).into())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
@@ -833,6 +939,96 @@ pub mod pallet {
 			}
 		}
 
+		/// Returns up to `max_nodes` nodes of the subtree rooted at the given
+		/// delegation node, in depth-first order and without the nodes deeper
+		/// than `max_depth` below the subtree root.
+		///
+		/// The traversal starts from the subtree root, or right after the
+		/// `cursor` node returned with the previous page. Each page contains at
+		/// least one node, unless the traversal is complete.
+		/// Returns `None` if the subtree root does not exist, or if the cursor
+		/// is not a node of the subtree within `max_depth`.
+		///
+		/// This function does not modify the state, and is meant to be used
+		/// by runtime APIs.
+		pub fn delegation_subtree_page(
+			root_id: &DelegationNodeIdOf<T>,
+			max_depth: u32,
+			cursor: Option<DelegationNodeIdOf<T>>,
+			max_nodes: u32,
+		) -> Option<DelegationSubtreePageOf<T>> {
+			let mut nodes = Vec::new();
+			let (mut current_id, mut current_node, mut depth) = match cursor {
+				Some(cursor) => {
+					let depth = Self::depth_in_subtree(root_id, &cursor)?;
+					(cursor, <DelegationNodes<T>>::get(cursor)?, depth)
+				}
+				None => {
+					let root_node = <DelegationNodes<T>>::get(root_id)?;
+					nodes.push((*root_id, 0, root_node.clone()));
+					(*root_id, root_node, 0)
+				}
+			};
+			if depth > max_depth {
+				return None;
+			}
+
+			let mut last_visited_child = None;
+			loop {
+				// Children are iterated in order, continuing after the last visited one.
+				let next_child = if depth < max_depth {
+					match last_visited_child {
+						Some(last_visited_child) => current_node
+							.children
+							.range((Bound::Excluded(last_visited_child), Bound::Unbounded))
+							.next(),
+						None => current_node.children.iter().next(),
+					}
+					.copied()
+				} else {
+					None
+				};
+
+				if let Some(child) = next_child {
+					if nodes.len() >= max_nodes.max(1) as usize {
+						return Some(DelegationSubtreePage {
+							next_cursor: nodes.last().map(|(id, _, _)| *id),
+							nodes,
+						});
+					}
+					current_node = <DelegationNodes<T>>::get(child)?;
+					current_id = child;
+					depth = depth.saturating_add(1);
+					last_visited_child = None;
+					nodes.push((current_id, depth, current_node.clone()));
+				} else if current_id == *root_id {
+					return Some(DelegationSubtreePage {
+						nodes,
+						next_cursor: None,
+					});
+				} else {
+					// Go back to the parent to continue with its remaining children.
+					let parent = current_node.parent?;
+					last_visited_child = Some(current_id);
+					current_node = <DelegationNodes<T>>::get(parent)?;
+					current_id = parent;
+					depth = depth.saturating_sub(1);
+				}
+			}
+		}
+
+		/// Returns the depth of the given node below the given subtree root,
+		/// or `None` if the node is not part of the subtree.
+		fn depth_in_subtree(root_id: &DelegationNodeIdOf<T>, node_id: &DelegationNodeIdOf<T>) -> Option<u32> {
+			let mut depth: u32 = 0;
+			let mut current_id = *node_id;
+			while current_id != *root_id {
+				current_id = <DelegationNodes<T>>::get(current_id)?.parent?;
+				depth = depth.saturating_add(1);
+			}
+			Some(depth)
+		}
+
 		/// Revokes all children of a delegation.
 		/// Returns the number of revoked delegations and the consumed weight.
 		///
@@ -915,6 +1111,65 @@ pub mod pallet {
 			Ok((revocations, consumed_weight))
 		}
 
+		/// Visit up to `max_nodes` nodes of a hierarchy starting from the
+		/// provided cursor, revoking every node whose children have all been
+		/// revoked.
+		/// Returns the number of visited nodes and the cursor to continue from,
+		/// or `None` if the whole hierarchy has been revoked.
+		///
+		/// # <weight>
+		/// Weight: O(N) where N is the number of visited nodes which is
+		/// bounded by `max_nodes`.
+		/// - Reads: N * Delegations
+		/// - Writes: N * Delegations
+		/// # </weight>
+		fn revoke_hierarchy_page(
+			hierarchy_id: &DelegationNodeIdOf<T>,
+			mut cursor: HierarchyRevocationCursorOf<T>,
+			max_nodes: u32,
+		) -> Result<(u32, Option<HierarchyRevocationCursorOf<T>>), DispatchError> {
+			let mut visited_nodes: u32 = 0;
+			while visited_nodes < max_nodes {
+				visited_nodes = visited_nodes.saturating_add(1);
+
+				// Nodes cannot be removed while the revocation is in progress.
+				let mut delegation_node = <DelegationNodes<T>>::get(cursor.current).ok_or(Error::<T>::Internal)?;
+				let parent = delegation_node.parent;
+
+				// A revoked node always has a revoked subtree, so it can be skipped entirely.
+				if !delegation_node.details.revoked {
+					// Children are iterated in order, continuing after the last revoked one.
+					let next_child = match cursor.last_revoked_child {
+						Some(last_revoked_child) => delegation_node
+							.children
+							.range((Bound::Excluded(last_revoked_child), Bound::Unbounded))
+							.next(),
+						None => delegation_node.children.iter().next(),
+					}
+					.copied();
+
+					if let Some(child) = next_child {
+						cursor.current = child;
+						cursor.last_revoked_child = None;
+						continue;
+					}
+
+					delegation_node.details.revoked = true;
+					<DelegationNodes<T>>::insert(cursor.current, delegation_node);
+					Self::deposit_event(Event::DelegationRevoked(cursor.revoker.clone(), cursor.current));
+				}
+
+				if cursor.current == *hierarchy_id {
+					return Ok((visited_nodes, None));
+				}
+
+				// Go back to the parent to continue with its remaining children.
+				cursor.last_revoked_child = Some(cursor.current);
+				cursor.current = parent.ok_or(Error::<T>::Internal)?;
+			}
+			Ok((visited_nodes, Some(cursor)))
+		}
+
 		/// Removes all children of a delegation.
 		/// Returns the number of removed delegations and the consumed weight.
 		///
@@ -176,7 +176,7 @@ pub(crate) mod runtime {
 	use super::*;
 
 	use frame_support::{parameter_types, weights::constants::RocksDbWeight};
-	use frame_system::EnsureSigned;
+	use frame_system::{EnsureRoot, EnsureSigned};
 	use parity_scale_codec::Encode;
 	use scale_info::TypeInfo;
 	use sp_core::{ed25519, sr25519, Pair};
@@ -295,11 +295,18 @@ pub(crate) mod runtime {
 		type Currency = Balances;
 		type Fee = Fee;
 		type FeeCollector = ();
+		type RuntimeHoldReason = RuntimeHoldReason;
+		type MaxSchemaLength = frame_support::traits::ConstU32<1024>;
+		type SchemaBaseDeposit = frame_support::traits::ConstU128<1_000>;
+		type SchemaTierSize = frame_support::traits::ConstU32<256>;
+		type SchemaTierDeposit = frame_support::traits::ConstU128<1_000>;
 	}
 
 	parameter_types! {
 		pub const MaxDelegatedAttestations: u32 = 1000;
+		pub const MaxCidLength: u32 = 64;
 		pub const Deposit: Balance = ATTESTATION_DEPOSIT;
+		pub const ChallengeBond: Balance = ATTESTATION_DEPOSIT;
 	}
 
 	impl attestation::Config for Test {
@@ -311,11 +318,19 @@ pub(crate) mod runtime {
 
 		type Currency = Balances;
 		type Deposit = Deposit;
+		type IssuanceDeposit = Deposit;
 		type MaxDelegatedAttestations = MaxDelegatedAttestations;
+		type MaxCidLength = MaxCidLength;
 		type AttesterId = SubjectId;
 		type AuthorizationId = DelegationNodeIdOf<Self>;
 		type AccessControl = DelegationAc<Self>;
 		type BalanceMigrationManager = ();
+		type AttesterAccreditation = ();
+		type ChallengeBond = ChallengeBond;
+		type ChallengeResponsePeriod = frame_support::traits::ConstU64<10>;
+		type ChallengeAdjudicatorOrigin = EnsureRoot<AccountId>;
+		type ClaimerSignature = (SubjectId, Vec<u8>);
+		type ClaimerSignatureVerification = EqualVerify<SubjectId, Vec<u8>>;
 	}
 
 	parameter_types! {
@@ -372,3 +372,113 @@ fn calculate_reference_root_hash() {
 		])
 	)
 }
+
+#[test]
+fn delegation_subtree_page_successful() {
+	let owner = ed25519_did_from_seed(&ALICE_SEED);
+	let delegate = ed25519_did_from_seed(&BOB_SEED);
+
+	let hierarchy_root_id = get_delegation_hierarchy_id::<Test>(true);
+	let hierarchy_details = generate_base_delegation_hierarchy_details::<Test>();
+	let parent_id = delegation_id_from_seed::<Test>(DELEGATION_ID_SEED_1);
+	let parent_node =
+		generate_base_delegation_node::<Test>(hierarchy_root_id, delegate.clone(), Some(hierarchy_root_id), ACCOUNT_00);
+	let delegation_id_1 = delegation_id_from_seed::<Test>(DELEGATION_ID_SEED_2);
+	let delegation_node_1 =
+		generate_base_delegation_node::<Test>(hierarchy_root_id, delegate.clone(), Some(parent_id), ACCOUNT_00);
+	let delegation_id_2 = delegation_id_from_seed::<Test>(DELEGATION_ID_SEED_3);
+	let delegation_node_2 =
+		generate_base_delegation_node::<Test>(hierarchy_root_id, delegate, Some(hierarchy_root_id), ACCOUNT_00);
+
+	// Root -> Parent -> Delegation 1
+	// Root -> Delegation 2
+	ExtBuilder::default()
+		.with_ctypes(vec![(hierarchy_details.ctype_hash, owner.clone())])
+		.with_delegation_hierarchies(vec![(hierarchy_root_id, hierarchy_details, owner, ACCOUNT_00)])
+		.with_delegations(vec![
+			(parent_id, parent_node),
+			(delegation_id_1, delegation_node_1),
+			(delegation_id_2, delegation_node_2),
+		])
+		.build_and_execute_with_sanity_tests(|| {
+			let page = Delegation::delegation_subtree_page(&hierarchy_root_id, u32::MAX, None, 10)
+				.expect("Subtree should be found.");
+			assert!(page.next_cursor.is_none());
+			let nodes = page
+				.nodes
+				.iter()
+				.map(|(id, depth, _)| (*id, *depth))
+				.collect::<Vec<_>>();
+			assert_eq!(nodes.len(), 4);
+			assert_eq!(nodes[0], (hierarchy_root_id, 0));
+			// Each node is listed before its children.
+			let parent_position = nodes.iter().position(|n| *n == (parent_id, 1)).unwrap();
+			let delegation_1_position = nodes.iter().position(|n| *n == (delegation_id_1, 2)).unwrap();
+			assert_eq!(delegation_1_position, parent_position + 1);
+			assert!(nodes.contains(&(delegation_id_2, 1)));
+
+			// Paging through the subtree one node at a time returns the same nodes.
+			let mut paged_nodes = vec![];
+			let mut cursor = None;
+			loop {
+				let page = Delegation::delegation_subtree_page(&hierarchy_root_id, u32::MAX, cursor, 1)
+					.expect("Subtree should be found.");
+				assert!(page.nodes.len() <= 1);
+				paged_nodes.extend(page.nodes.into_iter().map(|(id, depth, _)| (id, depth)));
+				cursor = page.next_cursor;
+				if cursor.is_none() {
+					break;
+				}
+			}
+			assert_eq!(paged_nodes, nodes);
+
+			// Nodes deeper than the maximum depth are skipped.
+			let page =
+				Delegation::delegation_subtree_page(&hierarchy_root_id, 1, None, 10).expect("Subtree should be found.");
+			assert_eq!(page.nodes.len(), 3);
+			assert!(page.nodes.iter().all(|(id, _, _)| *id != delegation_id_1));
+
+			// Subtrees can be rooted at any node.
+			let page =
+				Delegation::delegation_subtree_page(&parent_id, u32::MAX, None, 10).expect("Subtree should be found.");
+			assert_eq!(
+				page.nodes
+					.into_iter()
+					.map(|(id, depth, node)| (id, depth, node.parent))
+					.collect::<Vec<_>>(),
+				vec![
+					(parent_id, 0, Some(hierarchy_root_id)),
+					(delegation_id_1, 1, Some(parent_id))
+				]
+			);
+		});
+}
+
+#[test]
+fn delegation_subtree_page_not_found() {
+	let owner = ed25519_did_from_seed(&ALICE_SEED);
+	let delegate = ed25519_did_from_seed(&BOB_SEED);
+
+	let hierarchy_root_id = get_delegation_hierarchy_id::<Test>(true);
+	let hierarchy_details = generate_base_delegation_hierarchy_details::<Test>();
+	let parent_id = delegation_id_from_seed::<Test>(DELEGATION_ID_SEED_1);
+	let parent_node =
+		generate_base_delegation_node::<Test>(hierarchy_root_id, delegate.clone(), Some(hierarchy_root_id), ACCOUNT_00);
+	let delegation_id = delegation_id_from_seed::<Test>(DELEGATION_ID_SEED_2);
+	let delegation_node =
+		generate_base_delegation_node::<Test>(hierarchy_root_id, delegate, Some(parent_id), ACCOUNT_00);
+
+	ExtBuilder::default()
+		.with_ctypes(vec![(hierarchy_details.ctype_hash, owner.clone())])
+		.with_delegation_hierarchies(vec![(hierarchy_root_id, hierarchy_details, owner, ACCOUNT_00)])
+		.with_delegations(vec![(parent_id, parent_node), (delegation_id, delegation_node)])
+		.build_and_execute_with_sanity_tests(|| {
+			let missing_id = delegation_id_from_seed::<Test>(DELEGATION_ID_SEED_3);
+			assert!(Delegation::delegation_subtree_page(&missing_id, u32::MAX, None, 10).is_none());
+			// The cursor must be part of the subtree.
+			assert!(Delegation::delegation_subtree_page(&delegation_id, u32::MAX, Some(parent_id), 10).is_none());
+			assert!(Delegation::delegation_subtree_page(&hierarchy_root_id, u32::MAX, Some(missing_id), 10).is_none());
+			// The cursor must be within the maximum depth.
+			assert!(Delegation::delegation_subtree_page(&hierarchy_root_id, 1, Some(delegation_id), 10).is_none());
+		});
+}
@@ -252,3 +252,192 @@ fn is_delegating_root_owner_revoked() {
 			);
 		});
 }
+
+#[test]
+fn revoke_hierarchy_paged_successful() {
+	let revoker = ed25519_did_from_seed(&ALICE_SEED);
+	let delegate = ed25519_did_from_seed(&BOB_SEED);
+
+	let hierarchy_root_id = get_delegation_hierarchy_id::<Test>(true);
+	let hierarchy_details = generate_base_delegation_hierarchy_details::<Test>();
+	let parent_id = delegation_id_from_seed::<Test>(DELEGATION_ID_SEED_1);
+	let parent_node =
+		generate_base_delegation_node::<Test>(hierarchy_root_id, delegate.clone(), Some(hierarchy_root_id), ACCOUNT_00);
+	let delegation_id_1 = delegation_id_from_seed::<Test>(DELEGATION_ID_SEED_2);
+	let delegation_node_1 =
+		generate_base_delegation_node::<Test>(hierarchy_root_id, delegate.clone(), Some(parent_id), ACCOUNT_00);
+	let delegation_id_2 = delegation_id_from_seed::<Test>(DELEGATION_ID_SEED_3);
+	let delegation_node_2 =
+		generate_base_delegation_node::<Test>(hierarchy_root_id, delegate.clone(), Some(hierarchy_root_id), ACCOUNT_00);
+
+	// Root -> Parent -> Delegation 1
+	// Root -> Delegation 2
+	ExtBuilder::default()
+		.with_ctypes(vec![(hierarchy_details.ctype_hash, revoker.clone())])
+		.with_delegation_hierarchies(vec![(
+			hierarchy_root_id,
+			hierarchy_details,
+			revoker.clone(),
+			ACCOUNT_00,
+		)])
+		.with_delegations(vec![
+			(parent_id, parent_node),
+			(delegation_id_1, delegation_node_1),
+			(delegation_id_2, delegation_node_2),
+		])
+		.with_balances(vec![(ACCOUNT_00, <Test as Config>::Deposit::get())])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(Delegation::revoke_hierarchy_paged(
+				DoubleOrigin(ACCOUNT_00, revoker.clone()).into(),
+				hierarchy_root_id,
+				3u32,
+			));
+
+			let cursor = Delegation::hierarchy_revocations(hierarchy_root_id).expect("Revocation should be paused.");
+			assert_eq!(cursor.revoker, revoker);
+			assert!(
+				!Delegation::delegation_nodes(hierarchy_root_id)
+					.expect("Root should be present on chain.")
+					.details
+					.revoked
+			);
+
+			// Anyone can continue a paused revocation.
+			assert_ok!(Delegation::revoke_hierarchy_paged(
+				DoubleOrigin(ACCOUNT_01, delegate.clone()).into(),
+				hierarchy_root_id,
+				MaxRevocations::get(),
+			));
+
+			assert!(Delegation::hierarchy_revocations(hierarchy_root_id).is_none());
+			for node_id in [hierarchy_root_id, parent_id, delegation_id_1, delegation_id_2] {
+				assert!(
+					Delegation::delegation_nodes(node_id)
+						.expect("Delegation should be present on chain.")
+						.details
+						.revoked
+				);
+			}
+		});
+}
+
+#[test]
+fn revoke_hierarchy_paged_unauthorized_error() {
+	let owner = ed25519_did_from_seed(&ALICE_SEED);
+	let delegate = ed25519_did_from_seed(&BOB_SEED);
+
+	let hierarchy_root_id = get_delegation_hierarchy_id::<Test>(true);
+	let hierarchy_details = generate_base_delegation_hierarchy_details::<Test>();
+	let delegation_id = delegation_id_from_seed::<Test>(DELEGATION_ID_SEED_1);
+	let delegation_node =
+		generate_base_delegation_node::<Test>(hierarchy_root_id, delegate.clone(), Some(hierarchy_root_id), ACCOUNT_00);
+
+	ExtBuilder::default()
+		.with_ctypes(vec![(hierarchy_details.ctype_hash, owner.clone())])
+		.with_delegation_hierarchies(vec![(hierarchy_root_id, hierarchy_details, owner, ACCOUNT_00)])
+		.with_delegations(vec![(delegation_id, delegation_node)])
+		.with_balances(vec![(ACCOUNT_00, <Test as Config>::Deposit::get())])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_noop!(
+				Delegation::revoke_hierarchy_paged(
+					DoubleOrigin(ACCOUNT_01, delegate.clone()).into(),
+					hierarchy_root_id,
+					MaxRevocations::get(),
+				),
+				Error::<Test>::UnauthorizedRevocation
+			);
+		});
+}
+
+#[test]
+fn revoke_hierarchy_paged_invalid_bounds_error() {
+	let owner = ed25519_did_from_seed(&ALICE_SEED);
+
+	let hierarchy_root_id = get_delegation_hierarchy_id::<Test>(true);
+	let hierarchy_details = generate_base_delegation_hierarchy_details::<Test>();
+
+	ExtBuilder::default()
+		.with_ctypes(vec![(hierarchy_details.ctype_hash, owner.clone())])
+		.with_delegation_hierarchies(vec![(hierarchy_root_id, hierarchy_details, owner.clone(), ACCOUNT_00)])
+		.with_balances(vec![(ACCOUNT_00, <Test as Config>::Deposit::get())])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_noop!(
+				Delegation::revoke_hierarchy_paged(
+					DoubleOrigin(ACCOUNT_00, owner.clone()).into(),
+					hierarchy_root_id,
+					0u32,
+				),
+				Error::<Test>::ExceededRevocationBounds
+			);
+			assert_noop!(
+				Delegation::revoke_hierarchy_paged(
+					DoubleOrigin(ACCOUNT_00, owner.clone()).into(),
+					hierarchy_root_id,
+					MaxRevocations::get() + 1,
+				),
+				Error::<Test>::MaxRevocationsTooLarge
+			);
+		});
+}
+
+#[test]
+fn revoke_hierarchy_paged_blocks_hierarchy_changes() {
+	let owner = ed25519_did_from_seed(&ALICE_SEED);
+	let delegate = sr25519_did_from_seed(&BOB_SEED);
+
+	let hierarchy_root_id = get_delegation_hierarchy_id::<Test>(true);
+	let hierarchy_details = generate_base_delegation_hierarchy_details::<Test>();
+	let parent_id = delegation_id_from_seed::<Test>(DELEGATION_ID_SEED_1);
+	let parent_node =
+		generate_base_delegation_node::<Test>(hierarchy_root_id, owner.clone(), Some(hierarchy_root_id), ACCOUNT_00);
+
+	let delegation_id = delegation_id_from_seed::<Test>(DELEGATION_ID_SEED_2);
+	let delegation_node =
+		generate_base_delegation_node::<Test>(hierarchy_root_id, delegate.clone(), Some(parent_id), ACCOUNT_00);
+	let delegation_info = Delegation::calculate_delegation_creation_hash(
+		&delegation_id,
+		&hierarchy_root_id,
+		&parent_id,
+		&delegation_node.details.permissions,
+	);
+	let delegate_signature = (delegate.clone(), hash_to_u8(delegation_info));
+	let operation = generate_base_delegation_creation_operation(delegation_id, delegate_signature, delegation_node);
+
+	ExtBuilder::default()
+		.with_ctypes(vec![(hierarchy_details.ctype_hash, owner.clone())])
+		.with_delegation_hierarchies(vec![(hierarchy_root_id, hierarchy_details, owner.clone(), ACCOUNT_00)])
+		.with_delegations(vec![(parent_id, parent_node)])
+		.with_balances(vec![(ACCOUNT_00, 2 * <Test as Config>::Deposit::get())])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(Delegation::revoke_hierarchy_paged(
+				DoubleOrigin(ACCOUNT_00, owner.clone()).into(),
+				hierarchy_root_id,
+				1u32,
+			));
+			assert!(Delegation::hierarchy_revocations(hierarchy_root_id).is_some());
+
+			assert_noop!(
+				Delegation::add_delegation(
+					DoubleOrigin(ACCOUNT_00, owner.clone()).into(),
+					operation.delegation_id,
+					operation.parent_id,
+					delegate.clone(),
+					operation.permissions,
+					operation.delegate_signature.clone(),
+				),
+				Error::<Test>::HierarchyRevocationInProgress
+			);
+			assert_noop!(
+				Delegation::remove_delegation(
+					DoubleOrigin(ACCOUNT_00, owner.clone()).into(),
+					parent_id,
+					MaxRevocations::get(),
+				),
+				Error::<Test>::HierarchyRevocationInProgress
+			);
+			assert_noop!(
+				Delegation::reclaim_deposit(RuntimeOrigin::signed(ACCOUNT_00), parent_id, MaxRevocations::get()),
+				Error::<Test>::HierarchyRevocationInProgress
+			);
+		});
+}
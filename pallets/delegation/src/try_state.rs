@@ -21,7 +21,7 @@ use kilt_support::test_utils::log_and_return_error_message;
 use scale_info::prelude::format;
 use sp_runtime::TryRuntimeError;
 
-use crate::{Config, DelegationHierarchies, DelegationNodeOf, DelegationNodes};
+use crate::{Config, DelegationHierarchies, DelegationNodeOf, DelegationNodes, HierarchyRevocations};
 
 pub(crate) fn do_try_state<T: Config>() -> Result<(), TryRuntimeError> {
 	DelegationNodes::<T>::iter().try_for_each(
@@ -40,7 +40,22 @@ pub(crate) fn do_try_state<T: Config>() -> Result<(), TryRuntimeError> {
 			// if a node is revoked, the subtree should be revoked as well.
 			check_subtree_is_revoked::<T>(delegation_details, &delegation_node_id)
 		},
-	)
+	)?;
+
+	HierarchyRevocations::<T>::iter().try_for_each(|(hierarchy_id, cursor)| -> Result<(), TryRuntimeError> {
+		// a paged revocation must continue from a node of the same hierarchy.
+		let current_node = DelegationNodes::<T>::get(cursor.current).ok_or_else(|| {
+			log_and_return_error_message(format!("Revocation cursor node {:?} not found", cursor.current))
+		})?;
+		ensure!(
+			current_node.hierarchy_root_id == hierarchy_id,
+			log_and_return_error_message(format!(
+				"Revocation cursor node {:?} not part of hierarchy {:?}",
+				cursor.current, hierarchy_id
+			))
+		);
+		Ok(())
+	})
 }
 
 fn get_merged_subtree<T: Config>(node: DelegationNodeOf<T>) -> sp_std::vec::Vec<DelegationNodeOf<T>> {
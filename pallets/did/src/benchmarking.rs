@@ -21,27 +21,31 @@ use frame_benchmarking::{account, benchmarks, Zero};
 use frame_support::{
 	assert_ok,
 	traits::fungible::{Inspect, Mutate, MutateHold},
+	BoundedVec,
 };
 use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
 use parity_scale_codec::Encode;
 use sp_core::{crypto::KeyTypeId, ecdsa, ed25519, sr25519};
 use sp_io::crypto::{ecdsa_generate, ecdsa_sign, ed25519_generate, ed25519_sign, sr25519_generate, sr25519_sign};
-use sp_runtime::{traits::IdentifyAccount, AccountId32, MultiSigner};
-use sp_std::{convert::TryInto, vec::Vec};
+use sp_runtime::{
+	traits::{Hash, IdentifyAccount},
+	AccountId32, MultiSigner,
+};
+use sp_std::{convert::TryInto, vec, vec::Vec};
 
-use kilt_support::{signature::VerifySignature, Deposit};
+use kilt_support::{migration::SteppedMigration, signature::VerifySignature, Deposit};
 
 use crate::{
 	did_details::{
-		DeriveDidCallAuthorizationVerificationKeyRelationship, DidAuthorizedCallOperation, DidPublicKey, DidSignature,
-		DidVerificationKey,
+		DeriveDidCallAuthorizationVerificationKeyRelationship, DidAuthorizedCallOperation, DidKeyRelationship,
+		DidPublicKey, DidSignature, DidVerificationKey, KeyChange, KeyHistoryEntry,
 	},
 	mock_utils::{
 		generate_base_did_creation_details, generate_base_did_details, get_key_agreement_keys, get_service_endpoints,
 	},
-	service_endpoints::DidEndpoint,
+	service_endpoints::{DidEndpoint, HashedDidEndpoint},
 	signature::DidSignatureVerify,
-	AccountIdOf, DidAuthorizedCallOperationOf, DidIdentifierOf, HoldReason,
+	AccountIdOf, AlsoKnownAsUriOf, DidAuthorizedCallOperationOf, DidIdentifierOf, HoldReason, KeyIdOf, RemovedKeysOf,
 };
 
 const DEFAULT_ACCOUNT_ID: &str = "tx_submitter";
@@ -123,6 +127,40 @@ fn save_service_endpoints<T: Config>(did_subject: &DidIdentifierOf<T>, endpoints
 	DidEndpointsCount::<T>::insert(did_subject, endpoints.len().saturated_into::<u32>());
 }
 
+/// Fills the removed keys of the DID, with a key history and a public key for
+/// each of them.
+fn save_removed_keys<T: Config>(did_subject: &DidIdentifierOf<T>) {
+	let public_key: DidPublicKey<AccountIdOf<T>> =
+		DidVerificationKey::from(get_ed25519_public_authentication_key()).into();
+	let removed_keys: Vec<KeyIdOf<T>> = (0..T::MaxRemovedKeys::get())
+		.map(|index| T::Hashing::hash_of(&index))
+		.collect();
+	for key_id in removed_keys.iter() {
+		HistoricalPublicKeys::<T>::insert(did_subject, key_id, public_key.clone());
+		KeyHistory::<T>::mutate(did_subject, key_id, |history| {
+			let _ = history.try_push(KeyHistoryEntry {
+				relationship: DidKeyRelationship::Verification(DidVerificationKeyRelationship::AssertionMethod),
+				change: KeyChange::Removed,
+				block_number: BlockNumberFor::<T>::zero(),
+			});
+		});
+	}
+	RemovedKeys::<T>::insert(
+		did_subject,
+		RemovedKeysOf::<T>::try_from(removed_keys).expect("Should not exceed the maximum number of removed keys."),
+	);
+}
+
+/// Returns a valid `alsoKnownAs` URI of the maximum length, which is unique
+/// for the provided index.
+fn get_also_known_as_uri<T: Config>(index: u32) -> AlsoKnownAsUriOf<T> {
+	let mut uri = b"did:web:".to_vec();
+	uri.extend_from_slice(&index.to_be_bytes().map(|byte| b'a' + byte % 26));
+	uri.resize(T::MaxAlsoKnownAsUriLength::get().saturated_into(), b'a');
+	uri.try_into()
+		.expect("Failed to convert alsoKnownAs URI to bounded vector.")
+}
+
 benchmarks! {
 	where_clause {
 		where
@@ -359,10 +397,16 @@ benchmarks! {
 		make_free_for_did::<T>(&deposit_owner);
 		Pallet::<T>::try_insert_did(did_subject.clone(), did_details, deposit_owner).expect("DID should be created!");
 
+		// Inline service endpoints are larger than hashed ones, so filling the DID
+		// with inline endpoints only is the worst case. The hashed service endpoints
+		// are still cleared when deleting the DID.
 		save_service_endpoints(&did_subject, &service_endpoints);
+		save_removed_keys::<T>(&did_subject);
 		let origin = RawOrigin::Signed(did_subject.clone());
 	}: _(origin, c)
 	verify {
+		assert!(KeyHistory::<T>::iter_prefix(&did_subject).next().is_none());
+		assert!(HistoricalPublicKeys::<T>::iter_prefix(&did_subject).next().is_none());
 		assert!(
 			Did::<T>::get(&did_subject).is_none()
 		);
@@ -373,6 +417,10 @@ benchmarks! {
 			ServiceEndpoints::<T>::iter_prefix(&did_subject).count(),
 			0
 		);
+		assert_eq!(
+			HashedServiceEndpoints::<T>::iter_prefix(&did_subject).count(),
+			0
+		);
 	}
 
 	reclaim_deposit {
@@ -396,11 +444,17 @@ benchmarks! {
 		make_free_for_did::<T>(&deposit_owner);
 		Pallet::<T>::try_insert_did(did_subject.clone(), did_details.clone(), deposit_owner).expect("DID should be created!");
 
+		// Inline service endpoints are larger than hashed ones, so filling the DID
+		// with inline endpoints only is the worst case. The hashed service endpoints
+		// are still cleared when deleting the DID.
 		save_service_endpoints(&did_subject, &service_endpoints);
+		save_removed_keys::<T>(&did_subject);
 		let origin = RawOrigin::Signed(did_details.deposit.owner);
 		let subject_clone = did_subject.clone();
 	}: _(origin, subject_clone, c)
 	verify {
+		assert!(KeyHistory::<T>::iter_prefix(&did_subject).next().is_none());
+		assert!(HistoricalPublicKeys::<T>::iter_prefix(&did_subject).next().is_none());
 		assert!(
 			Did::<T>::get(&did_subject).is_none()
 		);
@@ -411,6 +465,10 @@ benchmarks! {
 			ServiceEndpoints::<T>::iter_prefix(&did_subject).count(),
 			0
 		);
+		assert_eq!(
+			HashedServiceEndpoints::<T>::iter_prefix(&did_subject).count(),
+			0
+		);
 	}
 
 	/* submit_did_call extrinsic */
@@ -425,7 +483,7 @@ benchmarks! {
 
 		let did_call_op = generate_base_did_call_operation::<T>(did_subject, submitter.clone());
 
-		let did_call_signature = DidSignature::from(ed25519_sign(AUTHENTICATION_KEY_ID, &did_public_auth_key, did_call_op.encode().as_ref()).expect("Failed to create DID signature from raw ed25519 signature."));
+		let did_call_signature = DidSignature::from(ed25519_sign(AUTHENTICATION_KEY_ID, &did_public_auth_key, Pallet::<T>::did_operation_signing_payload(&did_call_op).as_ref()).expect("Failed to create DID signature from raw ed25519 signature."));
 		let origin = RawOrigin::Signed(submitter);
 		let boxed_did_call = Box::new(did_call_op);
 	}: submit_did_call(origin, boxed_did_call, did_call_signature)
@@ -441,7 +499,7 @@ benchmarks! {
 
 		let did_call_op = generate_base_did_call_operation::<T>(did_subject, submitter.clone());
 
-		let did_call_signature = DidSignature::from(sr25519_sign(AUTHENTICATION_KEY_ID, &did_public_auth_key, did_call_op.encode().as_ref()).expect("Failed to create DID signature from raw sr25519 signature."));
+		let did_call_signature = DidSignature::from(sr25519_sign(AUTHENTICATION_KEY_ID, &did_public_auth_key, Pallet::<T>::did_operation_signing_payload(&did_call_op).as_ref()).expect("Failed to create DID signature from raw sr25519 signature."));
 		let origin = RawOrigin::Signed(submitter);
 		let boxed_did_call = Box::new(did_call_op);
 	}: submit_did_call(origin, boxed_did_call, did_call_signature)
@@ -457,7 +515,7 @@ benchmarks! {
 
 		let did_call_op = generate_base_did_call_operation::<T>(did_subject, submitter.clone());
 
-		let did_call_signature = DidSignature::from(ecdsa_sign(AUTHENTICATION_KEY_ID, &did_public_auth_key, did_call_op.encode().as_ref()).expect("Failed to create DID signature from raw ecdsa signature."));
+		let did_call_signature = DidSignature::from(ecdsa_sign(AUTHENTICATION_KEY_ID, &did_public_auth_key, Pallet::<T>::did_operation_signing_payload(&did_call_op).as_ref()).expect("Failed to create DID signature from raw ecdsa signature."));
 		let origin = RawOrigin::Signed(submitter);
 		let boxed_did_call = Box::new(did_call_op);
 	}: submit_did_call(origin, boxed_did_call, did_call_signature)
@@ -535,6 +593,33 @@ benchmarks! {
 		assert_eq!(Did::<T>::get(&did_subject).unwrap().authentication_key, auth_key_id);
 	}
 
+	set_multisig_authentication_key {
+		let s in 2 .. T::MaxMultisigSignatories::get();
+
+		let block_number = BlockNumberFor::<T>::zero();
+		let did_public_auth_key = get_ed25519_public_authentication_key();
+		let did_subject: DidIdentifierOf<T> = MultiSigner::from(did_public_auth_key).into_account().into();
+		let did_account: AccountIdOf<T> = MultiSigner::from(did_public_auth_key).into_account().into();
+		make_free_for_did::<T>(&did_account);
+
+		// fill up public keys to its max size because max public keys = # of max key agreement keys + 3
+		let mut did_details = generate_base_did_details::<T>(DidVerificationKey::from(did_public_auth_key), Some(did_account));
+		assert_ok!(did_details.add_key_agreement_keys(get_key_agreement_keys::<T>(T::MaxNewKeyAgreementKeys::get()), block_number));
+		assert_ok!(did_details.update_attestation_key(DidVerificationKey::from(get_ed25519_public_attestation_key()), block_number));
+		assert_ok!(did_details.update_delegation_key(DidVerificationKey::from(get_ed25519_public_delegation_key()), block_number));
+
+		Did::<T>::insert(&did_subject, did_details);
+
+		let signatories: Vec<AccountIdOf<T>> = (0..s).map(|i| account("signatory", i, DEFAULT_ACCOUNT_SEED)).collect();
+		let bounded_signatories: BoundedVec<AccountIdOf<T>, T::MaxMultisigSignatories> = signatories.clone().try_into().expect("Too many signatories.");
+		let origin = RawOrigin::Signed(did_subject.clone());
+	}: _(origin, bounded_signatories, 2)
+	verify {
+		let multisig_account = utils::multisig_account_id::<AccountIdOf<T>>(signatories, 2).expect("Multisig account should be derived.");
+		let auth_key_id = utils::calculate_key_id::<T>(&DidPublicKey::from(DidVerificationKey::Account(multisig_account)));
+		assert_eq!(Did::<T>::get(&did_subject).unwrap().authentication_key, auth_key_id);
+	}
+
 	/* set_delegation_key extrinsic */
 	set_ed25519_delegation_key {
 		let block_number = BlockNumberFor::<T>::zero();
@@ -1066,6 +1151,44 @@ benchmarks! {
 		);
 	}
 
+	add_hashed_service_endpoint {
+		let public_auth_key = get_ecdsa_public_authentication_key();
+		let did_subject: DidIdentifierOf<T> = MultiSigner::from(public_auth_key).into_account().into();
+		let did_account: AccountIdOf<T> = MultiSigner::from(public_auth_key).into_account().into();
+		make_free_for_did::<T>(&did_account);
+		// Max allowed - 1.
+		let old_service_endpoints = get_service_endpoints::<T>(
+			T::MaxNumberOfServicesPerDid::get() - 1,
+			T::MaxServiceIdLength::get(),
+			T::MaxNumberOfTypesPerService::get(),
+			T::MaxServiceTypeLength::get(),
+			T::MaxNumberOfUrlsPerService::get(),
+			T::MaxServiceUrlLength::get(),
+		);
+		// New hashed endpoint with max length for all the properties.
+		let new_service_endpoint = HashedDidEndpoint::<T>::new(
+			vec![b'h'; T::MaxServiceIdLength::get().saturated_into()],
+			vec![b't'; T::MaxServiceTypeLength::get().saturated_into()],
+			<T as frame_system::Config>::Hash::default(),
+		);
+
+		let did_details = generate_base_did_details::<T>(DidVerificationKey::from(public_auth_key), Some(did_account));
+		Did::<T>::insert(&did_subject, did_details);
+		save_service_endpoints(&did_subject, &old_service_endpoints);
+		let origin = RawOrigin::Signed(did_subject.clone());
+		let cloned_service_endpoint = new_service_endpoint.clone();
+	}: _(origin, cloned_service_endpoint)
+	verify {
+		assert_eq!(
+			HashedServiceEndpoints::<T>::get(&did_subject, &new_service_endpoint.id),
+			Some(new_service_endpoint)
+		);
+		assert_eq!(
+			DidEndpointsCount::<T>::get(&did_subject),
+			T::MaxNumberOfServicesPerDid::get()
+		);
+	}
+
 	signature_verification_sr25519 {
 		let l in 1 .. MAX_PAYLOAD_BYTE_LENGTH;
 
@@ -1228,6 +1351,150 @@ benchmarks! {
 			Did::<T>::get(&did_subject).expect("DID entry should be created");
 	}
 
+	did_remark {
+		let l in 1 .. MAX_PAYLOAD_BYTE_LENGTH;
+
+		let did_public_auth_key = get_ed25519_public_authentication_key();
+		let did_subject: DidIdentifierOf<T> = MultiSigner::from(did_public_auth_key).into_account().into();
+		let data = vec![0u8; l as usize];
+		let origin = RawOrigin::Signed(did_subject);
+	}: _(origin, data)
+
+	endorse {
+		let endorser_public_auth_key = get_ed25519_public_authentication_key();
+		let endorser: DidIdentifierOf<T> = MultiSigner::from(endorser_public_auth_key).into_account().into();
+		let endorsed_public_auth_key = get_sr25519_public_authentication_key();
+		let endorsed: DidIdentifierOf<T> = MultiSigner::from(endorsed_public_auth_key).into_account().into();
+
+		let did_details = generate_base_did_details::<T>(DidVerificationKey::from(endorsed_public_auth_key), None);
+		Did::<T>::insert(&endorsed, did_details);
+
+		let context_hash = <T as frame_system::Config>::Hashing::hash(b"context");
+		let origin = RawOrigin::Signed(endorser.clone());
+	}: _(origin, endorsed.clone(), context_hash)
+	verify {
+		assert!(Endorsements::<T>::contains_key(&endorsed, &endorser));
+	}
+
+	revoke_endorsement {
+		let endorser_public_auth_key = get_ed25519_public_authentication_key();
+		let endorser: DidIdentifierOf<T> = MultiSigner::from(endorser_public_auth_key).into_account().into();
+		let endorsed_public_auth_key = get_sr25519_public_authentication_key();
+		let endorsed: DidIdentifierOf<T> = MultiSigner::from(endorsed_public_auth_key).into_account().into();
+
+		Endorsements::<T>::insert(&endorsed, &endorser, EndorsementDetails {
+			context_hash: <T as frame_system::Config>::Hashing::hash(b"context"),
+			created_at: frame_system::Pallet::<T>::block_number(),
+		});
+		EndorsementsCount::<T>::insert(&endorser, 1);
+
+		let origin = RawOrigin::Signed(endorser.clone());
+	}: _(origin, endorsed.clone())
+	verify {
+		assert!(!Endorsements::<T>::contains_key(&endorsed, &endorser));
+	}
+
+	add_also_known_as {
+		let public_auth_key = get_ecdsa_public_authentication_key();
+		let did_subject: DidIdentifierOf<T> = MultiSigner::from(public_auth_key).into_account().into();
+		let did_account: AccountIdOf<T> = MultiSigner::from(public_auth_key).into_account().into();
+		make_free_for_did::<T>(&did_account);
+		// Max allowed - 1.
+		let old_uris = (1..T::MaxAlsoKnownAs::get()).map(get_also_known_as_uri::<T>).collect::<Vec<_>>();
+		let new_uri = get_also_known_as_uri::<T>(0);
+
+		let did_details = generate_base_did_details::<T>(DidVerificationKey::from(public_auth_key), Some(did_account));
+		Did::<T>::insert(&did_subject, did_details);
+		AlsoKnownAs::<T>::insert(&did_subject, BoundedVec::try_from(old_uris).expect("Failed to store alsoKnownAs URIs."));
+		let origin = RawOrigin::Signed(did_subject.clone());
+		let cloned_uri = new_uri.clone();
+	}: _(origin, cloned_uri)
+	verify {
+		let stored_uris = AlsoKnownAs::<T>::get(&did_subject);
+		assert_eq!(stored_uris.len(), T::MaxAlsoKnownAs::get().saturated_into::<usize>());
+		assert!(stored_uris.contains(&new_uri));
+	}
+
+	remove_also_known_as {
+		let public_auth_key = get_ecdsa_public_authentication_key();
+		let did_subject: DidIdentifierOf<T> = MultiSigner::from(public_auth_key).into_account().into();
+		let did_account: AccountIdOf<T> = MultiSigner::from(public_auth_key).into_account().into();
+		make_free_for_did::<T>(&did_account);
+		// All set to max.
+		let old_uris = (0..T::MaxAlsoKnownAs::get()).map(get_also_known_as_uri::<T>).collect::<Vec<_>>();
+		let uri = old_uris[0].clone();
+
+		let did_details = generate_base_did_details::<T>(DidVerificationKey::from(public_auth_key), Some(did_account));
+		Did::<T>::insert(&did_subject, did_details);
+		AlsoKnownAs::<T>::insert(&did_subject, BoundedVec::try_from(old_uris).expect("Failed to store alsoKnownAs URIs."));
+		let origin = RawOrigin::Signed(did_subject.clone());
+		let cloned_uri = uri.clone();
+	}: _(origin, cloned_uri)
+	verify {
+		let stored_uris = AlsoKnownAs::<T>::get(&did_subject);
+		assert_eq!(stored_uris.len(), T::MaxAlsoKnownAs::get().saturating_sub(1).saturated_into::<usize>());
+		assert!(!stored_uris.contains(&uri));
+	}
+
+	backfill_key_history_step {
+		let n in 1 .. T::MaxNewKeyAgreementKeys::get();
+
+		let block_number = BlockNumberFor::<T>::zero();
+		let did_public_auth_key = get_ed25519_public_authentication_key();
+		let did_subject: DidIdentifierOf<T> = MultiSigner::from(did_public_auth_key).into_account().into();
+
+		let mut did_details = generate_base_did_details::<T>(DidVerificationKey::from(did_public_auth_key), None);
+		assert_ok!(did_details.add_key_agreement_keys(get_key_agreement_keys::<T>(n), block_number));
+		assert_ok!(did_details.update_attestation_key(DidVerificationKey::from(get_ed25519_public_attestation_key()), block_number));
+		assert_ok!(did_details.update_delegation_key(DidVerificationKey::from(get_ed25519_public_delegation_key()), block_number));
+		Did::<T>::insert(&did_subject, did_details);
+	}: {
+		<migrations::BackfillKeyHistory<T> as SteppedMigration>::step(None);
+	}
+	verify {
+		assert_eq!(KeyHistory::<T>::iter_prefix(&did_subject).count(), n as usize + 3);
+		assert_eq!(HistoricalPublicKeys::<T>::iter_prefix(&did_subject).count(), n as usize + 3);
+	}
+
+	reprice_did_deposit_step {
+		let did_public_auth_key = get_ed25519_public_authentication_key();
+		let did_subject: DidIdentifierOf<T> = MultiSigner::from(did_public_auth_key).into_account().into();
+		let did_account: AccountIdOf<T> = MultiSigner::from(did_public_auth_key).into_account().into();
+
+		let mut did_details = generate_base_did_details::<T>(DidVerificationKey::from(did_public_auth_key), None);
+		did_details.deposit.amount = <T as Config>::BaseDeposit::get() + <T as Config>::BaseDeposit::get();
+		did_details.deposit.owner = did_account.clone();
+
+		Did::<T>::insert(&did_subject, did_details.clone());
+		make_free_for_did::<T>(&did_account);
+		CurrencyOf::<T>::hold(&HoldReason::Deposit.into(), &did_account, did_details.deposit.amount).expect("should reserve currency");
+	}: {
+		<migrations::RepriceDidDeposits<T> as SteppedMigration>::step(None);
+	}
+	verify {
+		assert_eq!(
+			Did::<T>::get(&did_subject).expect("DID entry should be retained").deposit,
+			Deposit {
+				owner: did_account,
+				amount: <T as Config>::BaseDeposit::get()
+			},
+		)
+	}
+
+	initialize_did_counter_step {
+		let did_public_auth_key = get_ed25519_public_authentication_key();
+		let did_subject: DidIdentifierOf<T> = MultiSigner::from(did_public_auth_key).into_account().into();
+
+		let did_details = generate_base_did_details::<T>(DidVerificationKey::from(did_public_auth_key), None);
+		Did::<T>::insert(&did_subject, did_details);
+		frame_support::storage::unhashed::kill(&Did::<T>::counter_storage_final_key());
+	}: {
+		<migrations::InitializeDidCounter<T> as SteppedMigration>::step(None);
+	}
+	verify {
+		assert_eq!(Did::<T>::count(), 1);
+	}
+
 	impl_benchmark_test_suite!(
 		Pallet,
 		crate::mock::ExtBuilder::default().build_with_keystore(),
@@ -43,6 +43,7 @@ pub trait WeightInfo {
 	fn set_ed25519_authentication_key() -> Weight;
 	fn set_sr25519_authentication_key() -> Weight;
 	fn set_ecdsa_authentication_key() -> Weight;
+	fn set_multisig_authentication_key(s: u32, ) -> Weight;
 	fn set_ed25519_delegation_key() -> Weight;
 	fn set_sr25519_delegation_key() -> Weight;
 	fn set_ecdsa_delegation_key() -> Weight;
@@ -70,11 +71,104 @@ pub trait WeightInfo {
 	fn update_deposit() -> Weight;
 	fn dispatch_as() -> Weight;
 	fn create_from_account() -> Weight;
+	fn add_hashed_service_endpoint() -> Weight;
+	fn did_remark(l: u32, ) -> Weight;
+	fn endorse() -> Weight;
+	fn revoke_endorsement() -> Weight;
+	fn add_also_known_as() -> Weight;
+	fn remove_also_known_as() -> Weight;
+	fn backfill_key_history_step(n: u32, ) -> Weight;
+	fn reprice_did_deposit_step() -> Weight;
+	fn initialize_did_counter_step() -> Weight;
 }
 
 /// Weights for did using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: Did Did (r:1 w:1)
+	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
+	fn submit_did_call_ed25519_key() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `352`
+		//  Estimated: `5777`
+		// Minimum execution time: 76_219 nanoseconds.
+		Weight::from_parts(77_317_000, 5777)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Did Did (r:1 w:1)
+	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
+	fn submit_did_call_sr25519_key() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `352`
+		//  Estimated: `5777`
+		// Minimum execution time: 77_413 nanoseconds.
+		Weight::from_parts(78_633_000, 5777)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Did Did (r:1 w:1)
+	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
+	fn submit_did_call_ecdsa_key() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `353`
+		//  Estimated: `5777`
+		// Minimum execution time: 64_607 nanoseconds.
+		Weight::from_parts(65_338_000, 5777)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Did Did (r:1 w:0)
+	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
+	/// The range of component `l` is `[1, 5242880]`.
+	fn signature_verification_sr25519(l: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1626`
+		//  Estimated: `5777`
+		// Minimum execution time: 63_343 nanoseconds.
+		Weight::from_parts(32_311_843, 5777)
+			// Standard Error: 0
+			.saturating_add(Weight::from_parts(4_303, 0).saturating_mul(l.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: Did Did (r:1 w:0)
+	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
+	/// The range of component `l` is `[1, 5242880]`.
+	fn signature_verification_ed25519(l: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1626`
+		//  Estimated: `5777`
+		// Minimum execution time: 62_148 nanoseconds.
+		Weight::from_parts(40_931_723, 5777)
+			// Standard Error: 0
+			.saturating_add(Weight::from_parts(2_520, 0).saturating_mul(l.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: Did Did (r:1 w:0)
+	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
+	/// The range of component `l` is `[1, 5242880]`.
+	fn signature_verification_ecdsa(l: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1627`
+		//  Estimated: `5777`
+		// Minimum execution time: 49_962 nanoseconds.
+		Weight::from_parts(28_598_102, 5777)
+			// Standard Error: 0
+			.saturating_add(Weight::from_parts(1_151, 0).saturating_mul(l.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: Did Did (r:1 w:0)
+	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
+	fn dispatch_as() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `353`
+		//  Estimated: `5777`
+		// Minimum execution time: 187_140 nanoseconds.
+		Weight::from_parts(190_030_000, 5777)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	// The weights below are not taken from the benchmark run above. They are
+	// estimated from the storage accesses of each call.
 	/// Storage: Did DidBlacklist (r:1 w:0)
 	/// Proof: Did DidBlacklist (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
 	/// Storage: Did Did (r:1 w:1)
@@ -87,21 +181,24 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
 	/// Storage: Did ServiceEndpoints (r:0 w:25)
 	/// Proof: Did ServiceEndpoints (max_values: None, max_size: Some(615), added: 3090, mode: MaxEncodedLen)
+	/// Storage: Did KeyHistory (r:13 w:13)
+	/// Proof: Did KeyHistory (max_values: None, max_size: Some(309), added: 2784, mode: MaxEncodedLen)
+	/// Storage: Did RemovedKeys (r:1 w:1)
+	/// Proof: Did RemovedKeys (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	/// Storage: Did HistoricalPublicKeys (r:0 w:13)
+	/// Proof: Did HistoricalPublicKeys (max_values: None, max_size: Some(123), added: 2598, mode: MaxEncodedLen)
 	/// The range of component `n` is `[1, 10]`.
 	/// The range of component `c` is `[1, 25]`.
 	fn create_ed25519_keys(n: u32, c: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1131`
-		//  Estimated: `6204`
-		// Minimum execution time: 178_857 nanoseconds.
-		Weight::from_parts(159_185_984, 6204)
-			// Standard Error: 13_705
-			.saturating_add(Weight::from_parts(1_180_217, 0).saturating_mul(n.into()))
-			// Standard Error: 5_298
+		Weight::from_parts(175_185_984, 17720)
+			.saturating_add(Weight::from_parts(4_680_217, 0).saturating_mul(n.into()))
 			.saturating_add(Weight::from_parts(10_341_805, 0).saturating_mul(c.into()))
-			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(12_u64))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2784).saturating_mul(n.into()))
 	}
 	/// Storage: Did DidBlacklist (r:1 w:0)
 	/// Proof: Did DidBlacklist (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
@@ -115,21 +212,24 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
 	/// Storage: Did ServiceEndpoints (r:0 w:25)
 	/// Proof: Did ServiceEndpoints (max_values: None, max_size: Some(615), added: 3090, mode: MaxEncodedLen)
+	/// Storage: Did KeyHistory (r:13 w:13)
+	/// Proof: Did KeyHistory (max_values: None, max_size: Some(309), added: 2784, mode: MaxEncodedLen)
+	/// Storage: Did RemovedKeys (r:1 w:1)
+	/// Proof: Did RemovedKeys (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	/// Storage: Did HistoricalPublicKeys (r:0 w:13)
+	/// Proof: Did HistoricalPublicKeys (max_values: None, max_size: Some(123), added: 2598, mode: MaxEncodedLen)
 	/// The range of component `n` is `[1, 10]`.
 	/// The range of component `c` is `[1, 25]`.
 	fn create_sr25519_keys(n: u32, c: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1131`
-		//  Estimated: `6204`
-		// Minimum execution time: 181_138 nanoseconds.
-		Weight::from_parts(161_168_698, 6204)
-			// Standard Error: 13_581
-			.saturating_add(Weight::from_parts(1_159_533, 0).saturating_mul(n.into()))
-			// Standard Error: 5_251
+		Weight::from_parts(177_168_698, 17720)
+			.saturating_add(Weight::from_parts(4_659_533, 0).saturating_mul(n.into()))
 			.saturating_add(Weight::from_parts(11_216_570, 0).saturating_mul(c.into()))
-			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(12_u64))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2784).saturating_mul(n.into()))
 	}
 	/// Storage: Did DidBlacklist (r:1 w:0)
 	/// Proof: Did DidBlacklist (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
@@ -143,26 +243,31 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
 	/// Storage: Did ServiceEndpoints (r:0 w:25)
 	/// Proof: Did ServiceEndpoints (max_values: None, max_size: Some(615), added: 3090, mode: MaxEncodedLen)
+	/// Storage: Did KeyHistory (r:13 w:13)
+	/// Proof: Did KeyHistory (max_values: None, max_size: Some(309), added: 2784, mode: MaxEncodedLen)
+	/// Storage: Did RemovedKeys (r:1 w:1)
+	/// Proof: Did RemovedKeys (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	/// Storage: Did HistoricalPublicKeys (r:0 w:13)
+	/// Proof: Did HistoricalPublicKeys (max_values: None, max_size: Some(123), added: 2598, mode: MaxEncodedLen)
 	/// The range of component `n` is `[1, 10]`.
 	/// The range of component `c` is `[1, 25]`.
 	fn create_ecdsa_keys(n: u32, c: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1131`
-		//  Estimated: `6204`
-		// Minimum execution time: 164_794 nanoseconds.
-		Weight::from_parts(146_895_935, 6204)
-			// Standard Error: 12_576
-			.saturating_add(Weight::from_parts(1_116_789, 0).saturating_mul(n.into()))
-			// Standard Error: 4_862
+		Weight::from_parts(162_895_935, 17720)
+			.saturating_add(Weight::from_parts(4_616_789, 0).saturating_mul(n.into()))
 			.saturating_add(Weight::from_parts(9_587_259, 0).saturating_mul(c.into()))
-			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(12_u64))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2784).saturating_mul(n.into()))
 	}
 	/// Storage: Did DidEndpointsCount (r:1 w:1)
 	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
 	/// Storage: Did ServiceEndpoints (r:25 w:25)
 	/// Proof: Did ServiceEndpoints (max_values: None, max_size: Some(615), added: 3090, mode: MaxEncodedLen)
+	/// Storage: Did HashedServiceEndpoints (r:1 w:0)
+	/// Proof: Did HashedServiceEndpoints (max_values: None, max_size: Some(223), added: 2698, mode: MaxEncodedLen)
 	/// Storage: Did Did (r:1 w:1)
 	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
 	/// Storage: Balances Holds (r:1 w:1)
@@ -171,18 +276,21 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: Did DidBlacklist (r:0 w:1)
 	/// Proof: Did DidBlacklist (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Did AlsoKnownAs (r:0 w:1)
+	/// Proof: Did AlsoKnownAs (max_values: None, max_size: Some(2629), added: 5104, mode: MaxEncodedLen)
+	/// Storage: Did KeyHistory (r:0 w:40)
+	/// Proof: Did KeyHistory (max_values: None, max_size: Some(309), added: 2784, mode: MaxEncodedLen)
+	/// Storage: Did RemovedKeys (r:0 w:1)
+	/// Proof: Did RemovedKeys (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	/// Storage: Did HistoricalPublicKeys (r:0 w:40)
+	/// Proof: Did HistoricalPublicKeys (max_values: None, max_size: Some(123), added: 2598, mode: MaxEncodedLen)
 	/// The range of component `c` is `[1, 25]`.
 	fn delete(c: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `969 + c * (105 ±0)`
-		//  Estimated: `5777 + c * (3090 ±0)`
-		// Minimum execution time: 52_353 nanoseconds.
-		Weight::from_parts(52_869_284, 5777)
-			// Standard Error: 4_975
+		Weight::from_parts(141_869_284, 8475)
 			.saturating_add(Weight::from_parts(1_374_154, 0).saturating_mul(c.into()))
-			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
 			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(c.into())))
-			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().writes(87_u64))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
 			.saturating_add(Weight::from_parts(0 ,3090).saturating_mul(c.into()))
 	}
@@ -192,76 +300,50 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
 	/// Storage: Did ServiceEndpoints (r:25 w:25)
 	/// Proof: Did ServiceEndpoints (max_values: None, max_size: Some(615), added: 3090, mode: MaxEncodedLen)
+	/// Storage: Did HashedServiceEndpoints (r:1 w:0)
+	/// Proof: Did HashedServiceEndpoints (max_values: None, max_size: Some(223), added: 2698, mode: MaxEncodedLen)
 	/// Storage: Balances Holds (r:1 w:1)
 	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: Did DidBlacklist (r:0 w:1)
 	/// Proof: Did DidBlacklist (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Did AlsoKnownAs (r:0 w:1)
+	/// Proof: Did AlsoKnownAs (max_values: None, max_size: Some(2629), added: 5104, mode: MaxEncodedLen)
+	/// Storage: Did KeyHistory (r:0 w:40)
+	/// Proof: Did KeyHistory (max_values: None, max_size: Some(309), added: 2784, mode: MaxEncodedLen)
+	/// Storage: Did RemovedKeys (r:0 w:1)
+	/// Proof: Did RemovedKeys (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	/// Storage: Did HistoricalPublicKeys (r:0 w:40)
+	/// Proof: Did HistoricalPublicKeys (max_values: None, max_size: Some(123), added: 2598, mode: MaxEncodedLen)
 	/// The range of component `c` is `[1, 25]`.
 	fn reclaim_deposit(c: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `969 + c * (105 ±0)`
-		//  Estimated: `5777 + c * (3090 ±0)`
-		// Minimum execution time: 54_594 nanoseconds.
-		Weight::from_parts(55_344_841, 5777)
-			// Standard Error: 5_107
+		Weight::from_parts(144_344_841, 8475)
 			.saturating_add(Weight::from_parts(1_391_081, 0).saturating_mul(c.into()))
-			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
 			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(c.into())))
-			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().writes(87_u64))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
 			.saturating_add(Weight::from_parts(0 ,3090).saturating_mul(c.into()))
 	}
 	/// Storage: Did Did (r:1 w:1)
 	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	fn submit_did_call_ed25519_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `352`
-		//  Estimated: `5777`
-		// Minimum execution time: 76_219 nanoseconds.
-		Weight::from_parts(77_317_000, 5777)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
-	}
-	/// Storage: Did Did (r:1 w:1)
-	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	fn submit_did_call_sr25519_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `352`
-		//  Estimated: `5777`
-		// Minimum execution time: 77_413 nanoseconds.
-		Weight::from_parts(78_633_000, 5777)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
-	}
-	/// Storage: Did Did (r:1 w:1)
-	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	fn submit_did_call_ecdsa_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `353`
-		//  Estimated: `5777`
-		// Minimum execution time: 64_607 nanoseconds.
-		Weight::from_parts(65_338_000, 5777)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
-	}
-	/// Storage: Did Did (r:1 w:1)
-	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
 	/// Storage: Did DidEndpointsCount (r:1 w:0)
 	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: Balances Holds (r:1 w:1)
 	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
+	/// Storage: Did KeyHistory (r:2 w:3)
+	/// Proof: Did KeyHistory (max_values: None, max_size: Some(309), added: 2784, mode: MaxEncodedLen)
+	/// Storage: Did RemovedKeys (r:1 w:1)
+	/// Proof: Did RemovedKeys (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	/// Storage: Did HistoricalPublicKeys (r:0 w:2)
+	/// Proof: Did HistoricalPublicKeys (max_values: None, max_size: Some(123), added: 2598, mode: MaxEncodedLen)
 	fn set_ed25519_authentication_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2138`
-		//  Estimated: `5777`
-		// Minimum execution time: 67_233 nanoseconds.
-		Weight::from_parts(68_053_000, 5777)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+		Weight::from_parts(80_053_000, 14509)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
 	}
 	/// Storage: Did Did (r:1 w:1)
 	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
@@ -271,14 +353,16 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: Balances Holds (r:1 w:1)
 	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
+	/// Storage: Did KeyHistory (r:2 w:3)
+	/// Proof: Did KeyHistory (max_values: None, max_size: Some(309), added: 2784, mode: MaxEncodedLen)
+	/// Storage: Did RemovedKeys (r:1 w:1)
+	/// Proof: Did RemovedKeys (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	/// Storage: Did HistoricalPublicKeys (r:0 w:2)
+	/// Proof: Did HistoricalPublicKeys (max_values: None, max_size: Some(123), added: 2598, mode: MaxEncodedLen)
 	fn set_sr25519_authentication_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2099`
-		//  Estimated: `5777`
-		// Minimum execution time: 67_442 nanoseconds.
-		Weight::from_parts(68_494_000, 5777)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+		Weight::from_parts(80_494_000, 14509)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
 	}
 	/// Storage: Did Did (r:1 w:1)
 	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
@@ -288,14 +372,16 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: Balances Holds (r:1 w:1)
 	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
+	/// Storage: Did KeyHistory (r:2 w:3)
+	/// Proof: Did KeyHistory (max_values: None, max_size: Some(309), added: 2784, mode: MaxEncodedLen)
+	/// Storage: Did RemovedKeys (r:1 w:1)
+	/// Proof: Did RemovedKeys (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	/// Storage: Did HistoricalPublicKeys (r:0 w:2)
+	/// Proof: Did HistoricalPublicKeys (max_values: None, max_size: Some(123), added: 2598, mode: MaxEncodedLen)
 	fn set_ecdsa_authentication_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2135`
-		//  Estimated: `5777`
-		// Minimum execution time: 67_291 nanoseconds.
-		Weight::from_parts(68_434_000, 5777)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+		Weight::from_parts(80_434_000, 14509)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
 	}
 	/// Storage: Did Did (r:1 w:1)
 	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
@@ -305,14 +391,37 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: Balances Holds (r:1 w:1)
 	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
+	/// Storage: Did KeyHistory (r:2 w:3)
+	/// Proof: Did KeyHistory (max_values: None, max_size: Some(309), added: 2784, mode: MaxEncodedLen)
+	/// Storage: Did RemovedKeys (r:1 w:1)
+	/// Proof: Did RemovedKeys (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	/// Storage: Did HistoricalPublicKeys (r:0 w:2)
+	/// Proof: Did HistoricalPublicKeys (max_values: None, max_size: Some(123), added: 2598, mode: MaxEncodedLen)
+	/// The range of component `s` is `[2, 64]`.
+	fn set_multisig_authentication_key(s: u32, ) -> Weight {
+		Weight::from_parts(80_434_000, 14509)
+			.saturating_add(Weight::from_parts(151_487, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	/// Storage: Did Did (r:1 w:1)
+	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
+	/// Storage: Did DidEndpointsCount (r:1 w:0)
+	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
+	/// Storage: Did KeyHistory (r:2 w:3)
+	/// Proof: Did KeyHistory (max_values: None, max_size: Some(309), added: 2784, mode: MaxEncodedLen)
+	/// Storage: Did RemovedKeys (r:1 w:1)
+	/// Proof: Did RemovedKeys (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	/// Storage: Did HistoricalPublicKeys (r:0 w:2)
+	/// Proof: Did HistoricalPublicKeys (max_values: None, max_size: Some(123), added: 2598, mode: MaxEncodedLen)
 	fn set_ed25519_delegation_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2170`
-		//  Estimated: `5777`
-		// Minimum execution time: 66_735 nanoseconds.
-		Weight::from_parts(67_958_000, 5777)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+		Weight::from_parts(79_958_000, 14509)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
 	}
 	/// Storage: Did Did (r:1 w:1)
 	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
@@ -322,14 +431,16 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: Balances Holds (r:1 w:1)
 	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
+	/// Storage: Did KeyHistory (r:2 w:3)
+	/// Proof: Did KeyHistory (max_values: None, max_size: Some(309), added: 2784, mode: MaxEncodedLen)
+	/// Storage: Did RemovedKeys (r:1 w:1)
+	/// Proof: Did RemovedKeys (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	/// Storage: Did HistoricalPublicKeys (r:0 w:2)
+	/// Proof: Did HistoricalPublicKeys (max_values: None, max_size: Some(123), added: 2598, mode: MaxEncodedLen)
 	fn set_sr25519_delegation_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2138`
-		//  Estimated: `5777`
-		// Minimum execution time: 66_615 nanoseconds.
-		Weight::from_parts(67_943_000, 5777)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+		Weight::from_parts(79_943_000, 14509)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
 	}
 	/// Storage: Did Did (r:1 w:1)
 	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
@@ -339,14 +450,16 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: Balances Holds (r:1 w:1)
 	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
+	/// Storage: Did KeyHistory (r:2 w:3)
+	/// Proof: Did KeyHistory (max_values: None, max_size: Some(309), added: 2784, mode: MaxEncodedLen)
+	/// Storage: Did RemovedKeys (r:1 w:1)
+	/// Proof: Did RemovedKeys (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	/// Storage: Did HistoricalPublicKeys (r:0 w:2)
+	/// Proof: Did HistoricalPublicKeys (max_values: None, max_size: Some(123), added: 2598, mode: MaxEncodedLen)
 	fn set_ecdsa_delegation_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2041`
-		//  Estimated: `5777`
-		// Minimum execution time: 66_785 nanoseconds.
-		Weight::from_parts(68_316_000, 5777)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+		Weight::from_parts(80_316_000, 14509)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
 	}
 	/// Storage: Did Did (r:1 w:1)
 	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
@@ -356,14 +469,16 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: Balances Holds (r:1 w:1)
 	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
+	/// Storage: Did KeyHistory (r:1 w:2)
+	/// Proof: Did KeyHistory (max_values: None, max_size: Some(309), added: 2784, mode: MaxEncodedLen)
+	/// Storage: Did RemovedKeys (r:1 w:1)
+	/// Proof: Did RemovedKeys (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	/// Storage: Did HistoricalPublicKeys (r:0 w:1)
+	/// Proof: Did HistoricalPublicKeys (max_values: None, max_size: Some(123), added: 2598, mode: MaxEncodedLen)
 	fn remove_ed25519_delegation_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2137`
-		//  Estimated: `5777`
-		// Minimum execution time: 64_183 nanoseconds.
-		Weight::from_parts(65_103_000, 5777)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+		Weight::from_parts(72_603_000, 11725)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
 	}
 	/// Storage: Did Did (r:1 w:1)
 	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
@@ -373,14 +488,16 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: Balances Holds (r:1 w:1)
 	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
+	/// Storage: Did KeyHistory (r:1 w:2)
+	/// Proof: Did KeyHistory (max_values: None, max_size: Some(309), added: 2784, mode: MaxEncodedLen)
+	/// Storage: Did RemovedKeys (r:1 w:1)
+	/// Proof: Did RemovedKeys (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	/// Storage: Did HistoricalPublicKeys (r:0 w:1)
+	/// Proof: Did HistoricalPublicKeys (max_values: None, max_size: Some(123), added: 2598, mode: MaxEncodedLen)
 	fn remove_sr25519_delegation_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2104`
-		//  Estimated: `5777`
-		// Minimum execution time: 63_289 nanoseconds.
-		Weight::from_parts(65_226_000, 5777)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+		Weight::from_parts(72_726_000, 11725)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
 	}
 	/// Storage: Did Did (r:1 w:1)
 	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
@@ -390,14 +507,16 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: Balances Holds (r:1 w:1)
 	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
+	/// Storage: Did KeyHistory (r:1 w:2)
+	/// Proof: Did KeyHistory (max_values: None, max_size: Some(309), added: 2784, mode: MaxEncodedLen)
+	/// Storage: Did RemovedKeys (r:1 w:1)
+	/// Proof: Did RemovedKeys (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	/// Storage: Did HistoricalPublicKeys (r:0 w:1)
+	/// Proof: Did HistoricalPublicKeys (max_values: None, max_size: Some(123), added: 2598, mode: MaxEncodedLen)
 	fn remove_ecdsa_delegation_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2140`
-		//  Estimated: `5777`
-		// Minimum execution time: 64_181 nanoseconds.
-		Weight::from_parts(65_040_000, 5777)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+		Weight::from_parts(72_540_000, 11725)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
 	}
 	/// Storage: Did Did (r:1 w:1)
 	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
@@ -407,14 +526,16 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: Balances Holds (r:1 w:1)
 	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
+	/// Storage: Did KeyHistory (r:2 w:3)
+	/// Proof: Did KeyHistory (max_values: None, max_size: Some(309), added: 2784, mode: MaxEncodedLen)
+	/// Storage: Did RemovedKeys (r:1 w:1)
+	/// Proof: Did RemovedKeys (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	/// Storage: Did HistoricalPublicKeys (r:0 w:2)
+	/// Proof: Did HistoricalPublicKeys (max_values: None, max_size: Some(123), added: 2598, mode: MaxEncodedLen)
 	fn set_ed25519_attestation_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2099`
-		//  Estimated: `5777`
-		// Minimum execution time: 66_521 nanoseconds.
-		Weight::from_parts(67_642_000, 5777)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+		Weight::from_parts(79_642_000, 14509)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
 	}
 	/// Storage: Did Did (r:1 w:1)
 	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
@@ -424,14 +545,16 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: Balances Holds (r:1 w:1)
 	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
+	/// Storage: Did KeyHistory (r:2 w:3)
+	/// Proof: Did KeyHistory (max_values: None, max_size: Some(309), added: 2784, mode: MaxEncodedLen)
+	/// Storage: Did RemovedKeys (r:1 w:1)
+	/// Proof: Did RemovedKeys (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	/// Storage: Did HistoricalPublicKeys (r:0 w:2)
+	/// Proof: Did HistoricalPublicKeys (max_values: None, max_size: Some(123), added: 2598, mode: MaxEncodedLen)
 	fn set_sr25519_attestation_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2099`
-		//  Estimated: `5777`
-		// Minimum execution time: 66_605 nanoseconds.
-		Weight::from_parts(67_712_000, 5777)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+		Weight::from_parts(79_712_000, 14509)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
 	}
 	/// Storage: Did Did (r:1 w:1)
 	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
@@ -441,14 +564,16 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: Balances Holds (r:1 w:1)
 	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
+	/// Storage: Did KeyHistory (r:2 w:3)
+	/// Proof: Did KeyHistory (max_values: None, max_size: Some(309), added: 2784, mode: MaxEncodedLen)
+	/// Storage: Did RemovedKeys (r:1 w:1)
+	/// Proof: Did RemovedKeys (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	/// Storage: Did HistoricalPublicKeys (r:0 w:2)
+	/// Proof: Did HistoricalPublicKeys (max_values: None, max_size: Some(123), added: 2598, mode: MaxEncodedLen)
 	fn set_ecdsa_attestation_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2135`
-		//  Estimated: `5777`
-		// Minimum execution time: 66_348 nanoseconds.
-		Weight::from_parts(68_046_000, 5777)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+		Weight::from_parts(80_046_000, 14509)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
 	}
 	/// Storage: Did Did (r:1 w:1)
 	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
@@ -458,14 +583,16 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: Balances Holds (r:1 w:1)
 	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
+	/// Storage: Did KeyHistory (r:1 w:2)
+	/// Proof: Did KeyHistory (max_values: None, max_size: Some(309), added: 2784, mode: MaxEncodedLen)
+	/// Storage: Did RemovedKeys (r:1 w:1)
+	/// Proof: Did RemovedKeys (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	/// Storage: Did HistoricalPublicKeys (r:0 w:1)
+	/// Proof: Did HistoricalPublicKeys (max_values: None, max_size: Some(123), added: 2598, mode: MaxEncodedLen)
 	fn remove_ed25519_attestation_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2165`
-		//  Estimated: `5777`
-		// Minimum execution time: 63_844 nanoseconds.
-		Weight::from_parts(65_421_000, 5777)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+		Weight::from_parts(72_921_000, 11725)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
 	}
 	/// Storage: Did Did (r:1 w:1)
 	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
@@ -475,14 +602,16 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: Balances Holds (r:1 w:1)
 	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
+	/// Storage: Did KeyHistory (r:1 w:2)
+	/// Proof: Did KeyHistory (max_values: None, max_size: Some(309), added: 2784, mode: MaxEncodedLen)
+	/// Storage: Did RemovedKeys (r:1 w:1)
+	/// Proof: Did RemovedKeys (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	/// Storage: Did HistoricalPublicKeys (r:0 w:1)
+	/// Proof: Did HistoricalPublicKeys (max_values: None, max_size: Some(123), added: 2598, mode: MaxEncodedLen)
 	fn remove_sr25519_attestation_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2132`
-		//  Estimated: `5777`
-		// Minimum execution time: 63_508 nanoseconds.
-		Weight::from_parts(65_180_000, 5777)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+		Weight::from_parts(72_680_000, 11725)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
 	}
 	/// Storage: Did Did (r:1 w:1)
 	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
@@ -492,14 +621,16 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: Balances Holds (r:1 w:1)
 	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
+	/// Storage: Did KeyHistory (r:1 w:2)
+	/// Proof: Did KeyHistory (max_values: None, max_size: Some(309), added: 2784, mode: MaxEncodedLen)
+	/// Storage: Did RemovedKeys (r:1 w:1)
+	/// Proof: Did RemovedKeys (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	/// Storage: Did HistoricalPublicKeys (r:0 w:1)
+	/// Proof: Did HistoricalPublicKeys (max_values: None, max_size: Some(123), added: 2598, mode: MaxEncodedLen)
 	fn remove_ecdsa_attestation_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2042`
-		//  Estimated: `5777`
-		// Minimum execution time: 64_008 nanoseconds.
-		Weight::from_parts(65_141_000, 5777)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+		Weight::from_parts(72_641_000, 11725)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
 	}
 	/// Storage: Did Did (r:1 w:1)
 	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
@@ -509,14 +640,16 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: Balances Holds (r:1 w:1)
 	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
+	/// Storage: Did KeyHistory (r:1 w:1)
+	/// Proof: Did KeyHistory (max_values: None, max_size: Some(309), added: 2784, mode: MaxEncodedLen)
+	/// Storage: Did RemovedKeys (r:1 w:1)
+	/// Proof: Did RemovedKeys (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	/// Storage: Did HistoricalPublicKeys (r:0 w:1)
+	/// Proof: Did HistoricalPublicKeys (max_values: None, max_size: Some(123), added: 2598, mode: MaxEncodedLen)
 	fn add_ed25519_key_agreement_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2031`
-		//  Estimated: `5777`
-		// Minimum execution time: 65_688 nanoseconds.
-		Weight::from_parts(67_265_000, 5777)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+		Weight::from_parts(73_765_000, 11725)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 	/// Storage: Did Did (r:1 w:1)
 	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
@@ -526,14 +659,16 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: Balances Holds (r:1 w:1)
 	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
+	/// Storage: Did KeyHistory (r:1 w:1)
+	/// Proof: Did KeyHistory (max_values: None, max_size: Some(309), added: 2784, mode: MaxEncodedLen)
+	/// Storage: Did RemovedKeys (r:1 w:1)
+	/// Proof: Did RemovedKeys (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	/// Storage: Did HistoricalPublicKeys (r:0 w:1)
+	/// Proof: Did HistoricalPublicKeys (max_values: None, max_size: Some(123), added: 2598, mode: MaxEncodedLen)
 	fn add_sr25519_key_agreement_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1933`
-		//  Estimated: `5777`
-		// Minimum execution time: 65_674 nanoseconds.
-		Weight::from_parts(67_627_000, 5777)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+		Weight::from_parts(74_127_000, 11725)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 	/// Storage: Did Did (r:1 w:1)
 	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
@@ -543,14 +678,16 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: Balances Holds (r:1 w:1)
 	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
+	/// Storage: Did KeyHistory (r:1 w:1)
+	/// Proof: Did KeyHistory (max_values: None, max_size: Some(309), added: 2784, mode: MaxEncodedLen)
+	/// Storage: Did RemovedKeys (r:1 w:1)
+	/// Proof: Did RemovedKeys (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	/// Storage: Did HistoricalPublicKeys (r:0 w:1)
+	/// Proof: Did HistoricalPublicKeys (max_values: None, max_size: Some(123), added: 2598, mode: MaxEncodedLen)
 	fn add_ecdsa_key_agreement_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2001`
-		//  Estimated: `5777`
-		// Minimum execution time: 65_424 nanoseconds.
-		Weight::from_parts(67_229_000, 5777)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+		Weight::from_parts(73_729_000, 11725)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 	/// Storage: Did Did (r:1 w:1)
 	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
@@ -560,14 +697,16 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: Balances Holds (r:1 w:1)
 	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
+	/// Storage: Did KeyHistory (r:1 w:2)
+	/// Proof: Did KeyHistory (max_values: None, max_size: Some(309), added: 2784, mode: MaxEncodedLen)
+	/// Storage: Did RemovedKeys (r:1 w:1)
+	/// Proof: Did RemovedKeys (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	/// Storage: Did HistoricalPublicKeys (r:0 w:1)
+	/// Proof: Did HistoricalPublicKeys (max_values: None, max_size: Some(123), added: 2598, mode: MaxEncodedLen)
 	fn remove_ed25519_key_agreement_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2099`
-		//  Estimated: `5777`
-		// Minimum execution time: 64_401 nanoseconds.
-		Weight::from_parts(65_925_000, 5777)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+		Weight::from_parts(73_425_000, 11725)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
 	}
 	/// Storage: Did Did (r:1 w:1)
 	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
@@ -577,14 +716,16 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: Balances Holds (r:1 w:1)
 	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
+	/// Storage: Did KeyHistory (r:1 w:2)
+	/// Proof: Did KeyHistory (max_values: None, max_size: Some(309), added: 2784, mode: MaxEncodedLen)
+	/// Storage: Did RemovedKeys (r:1 w:1)
+	/// Proof: Did RemovedKeys (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	/// Storage: Did HistoricalPublicKeys (r:0 w:1)
+	/// Proof: Did HistoricalPublicKeys (max_values: None, max_size: Some(123), added: 2598, mode: MaxEncodedLen)
 	fn remove_sr25519_key_agreement_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2137`
-		//  Estimated: `5777`
-		// Minimum execution time: 64_589 nanoseconds.
-		Weight::from_parts(65_962_000, 5777)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+		Weight::from_parts(73_462_000, 11725)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
 	}
 	/// Storage: Did Did (r:1 w:1)
 	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
@@ -594,14 +735,16 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: Balances Holds (r:1 w:1)
 	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
+	/// Storage: Did KeyHistory (r:1 w:2)
+	/// Proof: Did KeyHistory (max_values: None, max_size: Some(309), added: 2784, mode: MaxEncodedLen)
+	/// Storage: Did RemovedKeys (r:1 w:1)
+	/// Proof: Did RemovedKeys (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	/// Storage: Did HistoricalPublicKeys (r:0 w:1)
+	/// Proof: Did HistoricalPublicKeys (max_values: None, max_size: Some(123), added: 2598, mode: MaxEncodedLen)
 	fn remove_ecdsa_key_agreement_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2173`
-		//  Estimated: `5777`
-		// Minimum execution time: 65_111 nanoseconds.
-		Weight::from_parts(65_846_000, 5777)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+		Weight::from_parts(73_346_000, 11725)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
 	}
 	/// Storage: Did Did (r:1 w:1)
 	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
@@ -613,13 +756,11 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: Balances Holds (r:1 w:1)
 	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
+	/// Storage: Did RemovedKeys (r:1 w:0)
+	/// Proof: Did RemovedKeys (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
 	fn add_service_endpoint() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1538`
-		//  Estimated: `5777`
-		// Minimum execution time: 75_764 nanoseconds.
-		Weight::from_parts(76_710_000, 5777)
-			.saturating_add(T::DbWeight::get().reads(5_u64))
+		Weight::from_parts(78_210_000, 8941)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
 			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: Did Did (r:1 w:1)
@@ -632,120 +773,272 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: Balances Holds (r:1 w:1)
 	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
+	/// Storage: Did RemovedKeys (r:1 w:0)
+	/// Proof: Did RemovedKeys (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
 	fn remove_service_endpoint() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2153`
-		//  Estimated: `5777`
-		// Minimum execution time: 72_429 nanoseconds.
-		Weight::from_parts(73_903_000, 5777)
-			.saturating_add(T::DbWeight::get().reads(5_u64))
+		Weight::from_parts(75_403_000, 8941)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
 			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
-	/// Storage: Did Did (r:1 w:0)
+	/// Storage: Did Did (r:1 w:1)
 	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	/// The range of component `l` is `[1, 5242880]`.
-	fn signature_verification_sr25519(l: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1626`
-		//  Estimated: `5777`
-		// Minimum execution time: 63_343 nanoseconds.
-		Weight::from_parts(32_311_843, 5777)
-			// Standard Error: 0
-			.saturating_add(Weight::from_parts(4_303, 0).saturating_mul(l.into()))
-			.saturating_add(T::DbWeight::get().reads(1_u64))
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
+	/// Storage: Did RemovedKeys (r:1 w:0)
+	/// Proof: Did RemovedKeys (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	fn change_deposit_owner() -> Weight {
+		Weight::from_parts(74_855_000, 8941)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
-	/// Storage: Did Did (r:1 w:0)
+	/// Storage: Did Did (r:1 w:1)
 	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	/// The range of component `l` is `[1, 5242880]`.
-	fn signature_verification_ed25519(l: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1626`
-		//  Estimated: `5777`
-		// Minimum execution time: 62_148 nanoseconds.
-		Weight::from_parts(40_931_723, 5777)
-			// Standard Error: 0
-			.saturating_add(Weight::from_parts(2_520, 0).saturating_mul(l.into()))
-			.saturating_add(T::DbWeight::get().reads(1_u64))
+	/// Storage: Did DidEndpointsCount (r:1 w:0)
+	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
+	/// Storage: Did RemovedKeys (r:1 w:0)
+	/// Proof: Did RemovedKeys (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	fn update_deposit() -> Weight {
+		Weight::from_parts(46_802_000, 8941)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
-	/// Storage: Did Did (r:1 w:0)
+	/// Storage: Did DidBlacklist (r:1 w:0)
+	/// Proof: Did DidBlacklist (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Did Did (r:1 w:1)
 	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	/// The range of component `l` is `[1, 5242880]`.
-	fn signature_verification_ecdsa(l: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1627`
-		//  Estimated: `5777`
-		// Minimum execution time: 49_962 nanoseconds.
-		Weight::from_parts(28_598_102, 5777)
-			// Standard Error: 0
-			.saturating_add(Weight::from_parts(1_151, 0).saturating_mul(l.into()))
-			.saturating_add(T::DbWeight::get().reads(1_u64))
+	/// Storage: Did DidEndpointsCount (r:1 w:0)
+	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
+	/// Storage: Did KeyHistory (r:1 w:1)
+	/// Proof: Did KeyHistory (max_values: None, max_size: Some(309), added: 2784, mode: MaxEncodedLen)
+	/// Storage: Did RemovedKeys (r:1 w:1)
+	/// Proof: Did RemovedKeys (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	/// Storage: Did HistoricalPublicKeys (r:0 w:1)
+	/// Proof: Did HistoricalPublicKeys (max_values: None, max_size: Some(123), added: 2598, mode: MaxEncodedLen)
+	fn create_from_account() -> Weight {
+		Weight::from_parts(989_757_000, 12152)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
 	}
 	/// Storage: Did Did (r:1 w:1)
 	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
+	/// Storage: Did DidEndpointsCount (r:1 w:1)
+	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Did ServiceEndpoints (r:1 w:0)
+	/// Proof: Did ServiceEndpoints (max_values: None, max_size: Some(615), added: 3090, mode: MaxEncodedLen)
+	/// Storage: Did HashedServiceEndpoints (r:1 w:1)
+	/// Proof: Did HashedServiceEndpoints (max_values: None, max_size: Some(223), added: 2698, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: Balances Holds (r:1 w:1)
 	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
-	fn change_deposit_owner() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `916`
-		//  Estimated: `5777`
-		// Minimum execution time: 72_073 nanoseconds.
-		Weight::from_parts(73_355_000, 5777)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+	/// Storage: Did RemovedKeys (r:1 w:0)
+	/// Proof: Did RemovedKeys (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	fn add_hashed_service_endpoint() -> Weight {
+		Weight::from_parts(73_818_000, 8941)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	fn did_remark(l: u32, ) -> Weight {
+		Weight::from_parts(7_402_000, 0)
+			.saturating_add(Weight::from_parts(1_312, 0).saturating_mul(l.into()))
+	}
+	/// Storage: Did Did (r:1 w:0)
+	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
+	/// Storage: Did Endorsements (r:1 w:1)
+	/// Proof: Did Endorsements (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Did EndorsementsCount (r:1 w:1)
+	/// Proof: Did EndorsementsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn endorse() -> Weight {
+		Weight::from_parts(21_474_000, 0)
+			.saturating_add(Weight::from_parts(0, 5777))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: Did Endorsements (r:1 w:1)
+	/// Proof: Did Endorsements (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Did EndorsementsCount (r:1 w:1)
+	/// Proof: Did EndorsementsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn revoke_endorsement() -> Weight {
+		Weight::from_parts(17_128_000, 0)
+			.saturating_add(Weight::from_parts(0, 3593))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: Did Did (r:1 w:1)
+	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
+	/// Storage: Did AlsoKnownAs (r:1 w:1)
+	/// Proof: Did AlsoKnownAs (max_values: None, max_size: Some(2629), added: 5104, mode: MaxEncodedLen)
+	/// Storage: Did DidEndpointsCount (r:1 w:0)
+	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
+	/// Storage: Did RemovedKeys (r:1 w:0)
+	/// Proof: Did RemovedKeys (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	fn add_also_known_as() -> Weight {
+		Weight::from_parts(76_312_000, 0)
+			.saturating_add(Weight::from_parts(0, 9258))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: Did Did (r:1 w:1)
 	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
+	/// Storage: Did AlsoKnownAs (r:1 w:1)
+	/// Proof: Did AlsoKnownAs (max_values: None, max_size: Some(2629), added: 5104, mode: MaxEncodedLen)
 	/// Storage: Did DidEndpointsCount (r:1 w:0)
 	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: Balances Holds (r:1 w:1)
 	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
-	fn update_deposit() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `888`
-		//  Estimated: `5777`
-		// Minimum execution time: 44_498 nanoseconds.
-		Weight::from_parts(45_302_000, 5777)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+	/// Storage: Did RemovedKeys (r:1 w:0)
+	/// Proof: Did RemovedKeys (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	fn remove_also_known_as() -> Weight {
+		Weight::from_parts(72_805_000, 0)
+			.saturating_add(Weight::from_parts(0, 9258))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(4))
 	}
-
 	/// Storage: Did Did (r:1 w:0)
 	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	fn dispatch_as() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `353`
-		//  Estimated: `5777`
-		// Minimum execution time: 187_140 nanoseconds.
-		Weight::from_parts(190_030_000, 5777)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
+	/// Storage: Did KeyHistory (r:13 w:13)
+	/// Proof: Did KeyHistory (max_values: None, max_size: Some(309), added: 2784, mode: MaxEncodedLen)
+	/// Storage: Did HistoricalPublicKeys (r:0 w:13)
+	/// Proof: Did HistoricalPublicKeys (max_values: None, max_size: Some(123), added: 2598, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 10]`.
+	fn backfill_key_history_step(n: u32, ) -> Weight {
+		Weight::from_parts(47_318_256, 0)
+			.saturating_add(Weight::from_parts(0, 13139))
+			.saturating_add(Weight::from_parts(6_817_402, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(6))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2784).saturating_mul(n.into()))
 	}
-	/// Storage: Did DidBlacklist (r:1 w:0)
-	/// Proof: Did DidBlacklist (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
 	/// Storage: Did Did (r:1 w:1)
 	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
 	/// Storage: Did DidEndpointsCount (r:1 w:0)
 	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
-	/// Storage: System Account (r:2 w:2)
+	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: Balances Holds (r:1 w:1)
 	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
-	fn create_from_account() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1097`
-		//  Estimated: `6204`
-		// Minimum execution time: 970_542 nanoseconds.
-		Weight::from_parts(983_257_000, 6204)
-			.saturating_add(T::DbWeight::get().reads(6_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+	fn reprice_did_deposit_step() -> Weight {
+		Weight::from_parts(49_876_000, 0)
+			.saturating_add(Weight::from_parts(0, 5777))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: Did Did (r:1 w:0)
+	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
+	/// Storage: Did CounterForDid (r:1 w:1)
+	/// Proof: Did CounterForDid (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	fn initialize_did_counter_step() -> Weight {
+		Weight::from_parts(11_042_000, 0)
+			.saturating_add(Weight::from_parts(0, 5777))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
 	}
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
+	/// Storage: Did Did (r:1 w:1)
+	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
+	fn submit_did_call_ed25519_key() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `352`
+		//  Estimated: `5777`
+		// Minimum execution time: 76_219 nanoseconds.
+		Weight::from_parts(77_317_000, 5777)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Did Did (r:1 w:1)
+	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
+	fn submit_did_call_sr25519_key() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `352`
+		//  Estimated: `5777`
+		// Minimum execution time: 77_413 nanoseconds.
+		Weight::from_parts(78_633_000, 5777)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Did Did (r:1 w:1)
+	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
+	fn submit_did_call_ecdsa_key() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `353`
+		//  Estimated: `5777`
+		// Minimum execution time: 64_607 nanoseconds.
+		Weight::from_parts(65_338_000, 5777)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Did Did (r:1 w:0)
+	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
+	/// The range of component `l` is `[1, 5242880]`.
+	fn signature_verification_sr25519(l: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1626`
+		//  Estimated: `5777`
+		// Minimum execution time: 63_343 nanoseconds.
+		Weight::from_parts(32_311_843, 5777)
+			// Standard Error: 0
+			.saturating_add(Weight::from_parts(4_303, 0).saturating_mul(l.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	/// Storage: Did Did (r:1 w:0)
+	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
+	/// The range of component `l` is `[1, 5242880]`.
+	fn signature_verification_ed25519(l: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1626`
+		//  Estimated: `5777`
+		// Minimum execution time: 62_148 nanoseconds.
+		Weight::from_parts(40_931_723, 5777)
+			// Standard Error: 0
+			.saturating_add(Weight::from_parts(2_520, 0).saturating_mul(l.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	/// Storage: Did Did (r:1 w:0)
+	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
+	/// The range of component `l` is `[1, 5242880]`.
+	fn signature_verification_ecdsa(l: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1627`
+		//  Estimated: `5777`
+		// Minimum execution time: 49_962 nanoseconds.
+		Weight::from_parts(28_598_102, 5777)
+			// Standard Error: 0
+			.saturating_add(Weight::from_parts(1_151, 0).saturating_mul(l.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	/// Storage: Did Did (r:1 w:0)
+	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
+	fn dispatch_as() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `353`
+		//  Estimated: `5777`
+		// Minimum execution time: 187_140 nanoseconds.
+		Weight::from_parts(190_030_000, 5777)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	// The weights below are not taken from the benchmark run above. They are
+	// estimated from the storage accesses of each call.
 	/// Storage: Did DidBlacklist (r:1 w:0)
 	/// Proof: Did DidBlacklist (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
 	/// Storage: Did Did (r:1 w:1)
@@ -758,21 +1051,24 @@ impl WeightInfo for () {
 	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
 	/// Storage: Did ServiceEndpoints (r:0 w:25)
 	/// Proof: Did ServiceEndpoints (max_values: None, max_size: Some(615), added: 3090, mode: MaxEncodedLen)
+	/// Storage: Did KeyHistory (r:13 w:13)
+	/// Proof: Did KeyHistory (max_values: None, max_size: Some(309), added: 2784, mode: MaxEncodedLen)
+	/// Storage: Did RemovedKeys (r:1 w:1)
+	/// Proof: Did RemovedKeys (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	/// Storage: Did HistoricalPublicKeys (r:0 w:13)
+	/// Proof: Did HistoricalPublicKeys (max_values: None, max_size: Some(123), added: 2598, mode: MaxEncodedLen)
 	/// The range of component `n` is `[1, 10]`.
 	/// The range of component `c` is `[1, 25]`.
 	fn create_ed25519_keys(n: u32, c: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1131`
-		//  Estimated: `6204`
-		// Minimum execution time: 178_857 nanoseconds.
-		Weight::from_parts(159_185_984, 6204)
-			// Standard Error: 13_705
-			.saturating_add(Weight::from_parts(1_180_217, 0).saturating_mul(n.into()))
-			// Standard Error: 5_298
+		Weight::from_parts(175_185_984, 17720)
+			.saturating_add(Weight::from_parts(4_680_217, 0).saturating_mul(n.into()))
 			.saturating_add(Weight::from_parts(10_341_805, 0).saturating_mul(c.into()))
-			.saturating_add(RocksDbWeight::get().reads(5_u64))
-			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(12_u64))
 			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2784).saturating_mul(n.into()))
 	}
 	/// Storage: Did DidBlacklist (r:1 w:0)
 	/// Proof: Did DidBlacklist (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
@@ -786,21 +1082,24 @@ impl WeightInfo for () {
 	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
 	/// Storage: Did ServiceEndpoints (r:0 w:25)
 	/// Proof: Did ServiceEndpoints (max_values: None, max_size: Some(615), added: 3090, mode: MaxEncodedLen)
+	/// Storage: Did KeyHistory (r:13 w:13)
+	/// Proof: Did KeyHistory (max_values: None, max_size: Some(309), added: 2784, mode: MaxEncodedLen)
+	/// Storage: Did RemovedKeys (r:1 w:1)
+	/// Proof: Did RemovedKeys (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	/// Storage: Did HistoricalPublicKeys (r:0 w:13)
+	/// Proof: Did HistoricalPublicKeys (max_values: None, max_size: Some(123), added: 2598, mode: MaxEncodedLen)
 	/// The range of component `n` is `[1, 10]`.
 	/// The range of component `c` is `[1, 25]`.
 	fn create_sr25519_keys(n: u32, c: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1131`
-		//  Estimated: `6204`
-		// Minimum execution time: 181_138 nanoseconds.
-		Weight::from_parts(161_168_698, 6204)
-			// Standard Error: 13_581
-			.saturating_add(Weight::from_parts(1_159_533, 0).saturating_mul(n.into()))
-			// Standard Error: 5_251
+		Weight::from_parts(177_168_698, 17720)
+			.saturating_add(Weight::from_parts(4_659_533, 0).saturating_mul(n.into()))
 			.saturating_add(Weight::from_parts(11_216_570, 0).saturating_mul(c.into()))
-			.saturating_add(RocksDbWeight::get().reads(5_u64))
-			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(12_u64))
 			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2784).saturating_mul(n.into()))
 	}
 	/// Storage: Did DidBlacklist (r:1 w:0)
 	/// Proof: Did DidBlacklist (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
@@ -814,26 +1113,31 @@ impl WeightInfo for () {
 	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
 	/// Storage: Did ServiceEndpoints (r:0 w:25)
 	/// Proof: Did ServiceEndpoints (max_values: None, max_size: Some(615), added: 3090, mode: MaxEncodedLen)
+	/// Storage: Did KeyHistory (r:13 w:13)
+	/// Proof: Did KeyHistory (max_values: None, max_size: Some(309), added: 2784, mode: MaxEncodedLen)
+	/// Storage: Did RemovedKeys (r:1 w:1)
+	/// Proof: Did RemovedKeys (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	/// Storage: Did HistoricalPublicKeys (r:0 w:13)
+	/// Proof: Did HistoricalPublicKeys (max_values: None, max_size: Some(123), added: 2598, mode: MaxEncodedLen)
 	/// The range of component `n` is `[1, 10]`.
 	/// The range of component `c` is `[1, 25]`.
 	fn create_ecdsa_keys(n: u32, c: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1131`
-		//  Estimated: `6204`
-		// Minimum execution time: 164_794 nanoseconds.
-		Weight::from_parts(146_895_935, 6204)
-			// Standard Error: 12_576
-			.saturating_add(Weight::from_parts(1_116_789, 0).saturating_mul(n.into()))
-			// Standard Error: 4_862
+		Weight::from_parts(162_895_935, 17720)
+			.saturating_add(Weight::from_parts(4_616_789, 0).saturating_mul(n.into()))
 			.saturating_add(Weight::from_parts(9_587_259, 0).saturating_mul(c.into()))
-			.saturating_add(RocksDbWeight::get().reads(5_u64))
-			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(12_u64))
 			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2784).saturating_mul(n.into()))
 	}
 	/// Storage: Did DidEndpointsCount (r:1 w:1)
 	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
 	/// Storage: Did ServiceEndpoints (r:25 w:25)
 	/// Proof: Did ServiceEndpoints (max_values: None, max_size: Some(615), added: 3090, mode: MaxEncodedLen)
+	/// Storage: Did HashedServiceEndpoints (r:1 w:0)
+	/// Proof: Did HashedServiceEndpoints (max_values: None, max_size: Some(223), added: 2698, mode: MaxEncodedLen)
 	/// Storage: Did Did (r:1 w:1)
 	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
 	/// Storage: Balances Holds (r:1 w:1)
@@ -842,18 +1146,21 @@ impl WeightInfo for () {
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: Did DidBlacklist (r:0 w:1)
 	/// Proof: Did DidBlacklist (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Did AlsoKnownAs (r:0 w:1)
+	/// Proof: Did AlsoKnownAs (max_values: None, max_size: Some(2629), added: 5104, mode: MaxEncodedLen)
+	/// Storage: Did KeyHistory (r:0 w:40)
+	/// Proof: Did KeyHistory (max_values: None, max_size: Some(309), added: 2784, mode: MaxEncodedLen)
+	/// Storage: Did RemovedKeys (r:0 w:1)
+	/// Proof: Did RemovedKeys (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	/// Storage: Did HistoricalPublicKeys (r:0 w:40)
+	/// Proof: Did HistoricalPublicKeys (max_values: None, max_size: Some(123), added: 2598, mode: MaxEncodedLen)
 	/// The range of component `c` is `[1, 25]`.
 	fn delete(c: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `969 + c * (105 ±0)`
-		//  Estimated: `5777 + c * (3090 ±0)`
-		// Minimum execution time: 52_353 nanoseconds.
-		Weight::from_parts(52_869_284, 5777)
-			// Standard Error: 4_975
+		Weight::from_parts(141_869_284, 8475)
 			.saturating_add(Weight::from_parts(1_374_154, 0).saturating_mul(c.into()))
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
 			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(c.into())))
-			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(87_u64))
 			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(c.into())))
 			.saturating_add(Weight::from_parts(0, 3090).saturating_mul(c.into()))
 	}
@@ -863,76 +1170,50 @@ impl WeightInfo for () {
 	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
 	/// Storage: Did ServiceEndpoints (r:25 w:25)
 	/// Proof: Did ServiceEndpoints (max_values: None, max_size: Some(615), added: 3090, mode: MaxEncodedLen)
+	/// Storage: Did HashedServiceEndpoints (r:1 w:0)
+	/// Proof: Did HashedServiceEndpoints (max_values: None, max_size: Some(223), added: 2698, mode: MaxEncodedLen)
 	/// Storage: Balances Holds (r:1 w:1)
 	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: Did DidBlacklist (r:0 w:1)
 	/// Proof: Did DidBlacklist (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Did AlsoKnownAs (r:0 w:1)
+	/// Proof: Did AlsoKnownAs (max_values: None, max_size: Some(2629), added: 5104, mode: MaxEncodedLen)
+	/// Storage: Did KeyHistory (r:0 w:40)
+	/// Proof: Did KeyHistory (max_values: None, max_size: Some(309), added: 2784, mode: MaxEncodedLen)
+	/// Storage: Did RemovedKeys (r:0 w:1)
+	/// Proof: Did RemovedKeys (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	/// Storage: Did HistoricalPublicKeys (r:0 w:40)
+	/// Proof: Did HistoricalPublicKeys (max_values: None, max_size: Some(123), added: 2598, mode: MaxEncodedLen)
 	/// The range of component `c` is `[1, 25]`.
 	fn reclaim_deposit(c: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `969 + c * (105 ±0)`
-		//  Estimated: `5777 + c * (3090 ±0)`
-		// Minimum execution time: 54_594 nanoseconds.
-		Weight::from_parts(55_344_841, 5777)
-			// Standard Error: 5_107
+		Weight::from_parts(144_344_841, 8475)
 			.saturating_add(Weight::from_parts(1_391_081, 0).saturating_mul(c.into()))
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
 			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(c.into())))
-			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(87_u64))
 			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(c.into())))
 			.saturating_add(Weight::from_parts(0, 3090).saturating_mul(c.into()))
 	}
 	/// Storage: Did Did (r:1 w:1)
 	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	fn submit_did_call_ed25519_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `352`
-		//  Estimated: `5777`
-		// Minimum execution time: 76_219 nanoseconds.
-		Weight::from_parts(77_317_000, 5777)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
-	}
-	/// Storage: Did Did (r:1 w:1)
-	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	fn submit_did_call_sr25519_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `352`
-		//  Estimated: `5777`
-		// Minimum execution time: 77_413 nanoseconds.
-		Weight::from_parts(78_633_000, 5777)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
-	}
-	/// Storage: Did Did (r:1 w:1)
-	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	fn submit_did_call_ecdsa_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `353`
-		//  Estimated: `5777`
-		// Minimum execution time: 64_607 nanoseconds.
-		Weight::from_parts(65_338_000, 5777)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
-	}
-	/// Storage: Did Did (r:1 w:1)
-	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
 	/// Storage: Did DidEndpointsCount (r:1 w:0)
 	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: Balances Holds (r:1 w:1)
 	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
+	/// Storage: Did KeyHistory (r:2 w:3)
+	/// Proof: Did KeyHistory (max_values: None, max_size: Some(309), added: 2784, mode: MaxEncodedLen)
+	/// Storage: Did RemovedKeys (r:1 w:1)
+	/// Proof: Did RemovedKeys (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	/// Storage: Did HistoricalPublicKeys (r:0 w:2)
+	/// Proof: Did HistoricalPublicKeys (max_values: None, max_size: Some(123), added: 2598, mode: MaxEncodedLen)
 	fn set_ed25519_authentication_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2138`
-		//  Estimated: `5777`
-		// Minimum execution time: 67_233 nanoseconds.
-		Weight::from_parts(68_053_000, 5777)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+		Weight::from_parts(80_053_000, 14509)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
 	}
 	/// Storage: Did Did (r:1 w:1)
 	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
@@ -942,14 +1223,16 @@ impl WeightInfo for () {
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: Balances Holds (r:1 w:1)
 	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
+	/// Storage: Did KeyHistory (r:2 w:3)
+	/// Proof: Did KeyHistory (max_values: None, max_size: Some(309), added: 2784, mode: MaxEncodedLen)
+	/// Storage: Did RemovedKeys (r:1 w:1)
+	/// Proof: Did RemovedKeys (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	/// Storage: Did HistoricalPublicKeys (r:0 w:2)
+	/// Proof: Did HistoricalPublicKeys (max_values: None, max_size: Some(123), added: 2598, mode: MaxEncodedLen)
 	fn set_sr25519_authentication_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2099`
-		//  Estimated: `5777`
-		// Minimum execution time: 67_442 nanoseconds.
-		Weight::from_parts(68_494_000, 5777)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+		Weight::from_parts(80_494_000, 14509)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
 	}
 	/// Storage: Did Did (r:1 w:1)
 	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
@@ -959,14 +1242,37 @@ impl WeightInfo for () {
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: Balances Holds (r:1 w:1)
 	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
+	/// Storage: Did KeyHistory (r:2 w:3)
+	/// Proof: Did KeyHistory (max_values: None, max_size: Some(309), added: 2784, mode: MaxEncodedLen)
+	/// Storage: Did RemovedKeys (r:1 w:1)
+	/// Proof: Did RemovedKeys (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	/// Storage: Did HistoricalPublicKeys (r:0 w:2)
+	/// Proof: Did HistoricalPublicKeys (max_values: None, max_size: Some(123), added: 2598, mode: MaxEncodedLen)
 	fn set_ecdsa_authentication_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2135`
-		//  Estimated: `5777`
-		// Minimum execution time: 67_291 nanoseconds.
-		Weight::from_parts(68_434_000, 5777)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+		Weight::from_parts(80_434_000, 14509)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
+	/// Storage: Did Did (r:1 w:1)
+	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
+	/// Storage: Did DidEndpointsCount (r:1 w:0)
+	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
+	/// Storage: Did KeyHistory (r:2 w:3)
+	/// Proof: Did KeyHistory (max_values: None, max_size: Some(309), added: 2784, mode: MaxEncodedLen)
+	/// Storage: Did RemovedKeys (r:1 w:1)
+	/// Proof: Did RemovedKeys (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	/// Storage: Did HistoricalPublicKeys (r:0 w:2)
+	/// Proof: Did HistoricalPublicKeys (max_values: None, max_size: Some(123), added: 2598, mode: MaxEncodedLen)
+	/// The range of component `s` is `[2, 64]`.
+	fn set_multisig_authentication_key(s: u32, ) -> Weight {
+		Weight::from_parts(80_434_000, 14509)
+			.saturating_add(Weight::from_parts(151_487, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
 	}
 	/// Storage: Did Did (r:1 w:1)
 	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
@@ -976,14 +1282,16 @@ impl WeightInfo for () {
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: Balances Holds (r:1 w:1)
 	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
+	/// Storage: Did KeyHistory (r:2 w:3)
+	/// Proof: Did KeyHistory (max_values: None, max_size: Some(309), added: 2784, mode: MaxEncodedLen)
+	/// Storage: Did RemovedKeys (r:1 w:1)
+	/// Proof: Did RemovedKeys (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	/// Storage: Did HistoricalPublicKeys (r:0 w:2)
+	/// Proof: Did HistoricalPublicKeys (max_values: None, max_size: Some(123), added: 2598, mode: MaxEncodedLen)
 	fn set_ed25519_delegation_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2170`
-		//  Estimated: `5777`
-		// Minimum execution time: 66_735 nanoseconds.
-		Weight::from_parts(67_958_000, 5777)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+		Weight::from_parts(79_958_000, 14509)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
 	}
 	/// Storage: Did Did (r:1 w:1)
 	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
@@ -993,14 +1301,16 @@ impl WeightInfo for () {
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: Balances Holds (r:1 w:1)
 	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
+	/// Storage: Did KeyHistory (r:2 w:3)
+	/// Proof: Did KeyHistory (max_values: None, max_size: Some(309), added: 2784, mode: MaxEncodedLen)
+	/// Storage: Did RemovedKeys (r:1 w:1)
+	/// Proof: Did RemovedKeys (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	/// Storage: Did HistoricalPublicKeys (r:0 w:2)
+	/// Proof: Did HistoricalPublicKeys (max_values: None, max_size: Some(123), added: 2598, mode: MaxEncodedLen)
 	fn set_sr25519_delegation_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2138`
-		//  Estimated: `5777`
-		// Minimum execution time: 66_615 nanoseconds.
-		Weight::from_parts(67_943_000, 5777)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+		Weight::from_parts(79_943_000, 14509)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
 	}
 	/// Storage: Did Did (r:1 w:1)
 	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
@@ -1010,14 +1320,16 @@ impl WeightInfo for () {
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: Balances Holds (r:1 w:1)
 	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
+	/// Storage: Did KeyHistory (r:2 w:3)
+	/// Proof: Did KeyHistory (max_values: None, max_size: Some(309), added: 2784, mode: MaxEncodedLen)
+	/// Storage: Did RemovedKeys (r:1 w:1)
+	/// Proof: Did RemovedKeys (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	/// Storage: Did HistoricalPublicKeys (r:0 w:2)
+	/// Proof: Did HistoricalPublicKeys (max_values: None, max_size: Some(123), added: 2598, mode: MaxEncodedLen)
 	fn set_ecdsa_delegation_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2041`
-		//  Estimated: `5777`
-		// Minimum execution time: 66_785 nanoseconds.
-		Weight::from_parts(68_316_000, 5777)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+		Weight::from_parts(80_316_000, 14509)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
 	}
 	/// Storage: Did Did (r:1 w:1)
 	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
@@ -1027,14 +1339,16 @@ impl WeightInfo for () {
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: Balances Holds (r:1 w:1)
 	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
+	/// Storage: Did KeyHistory (r:1 w:2)
+	/// Proof: Did KeyHistory (max_values: None, max_size: Some(309), added: 2784, mode: MaxEncodedLen)
+	/// Storage: Did RemovedKeys (r:1 w:1)
+	/// Proof: Did RemovedKeys (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	/// Storage: Did HistoricalPublicKeys (r:0 w:1)
+	/// Proof: Did HistoricalPublicKeys (max_values: None, max_size: Some(123), added: 2598, mode: MaxEncodedLen)
 	fn remove_ed25519_delegation_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2137`
-		//  Estimated: `5777`
-		// Minimum execution time: 64_183 nanoseconds.
-		Weight::from_parts(65_103_000, 5777)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+		Weight::from_parts(72_603_000, 11725)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
 	}
 	/// Storage: Did Did (r:1 w:1)
 	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
@@ -1044,14 +1358,16 @@ impl WeightInfo for () {
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: Balances Holds (r:1 w:1)
 	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
+	/// Storage: Did KeyHistory (r:1 w:2)
+	/// Proof: Did KeyHistory (max_values: None, max_size: Some(309), added: 2784, mode: MaxEncodedLen)
+	/// Storage: Did RemovedKeys (r:1 w:1)
+	/// Proof: Did RemovedKeys (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	/// Storage: Did HistoricalPublicKeys (r:0 w:1)
+	/// Proof: Did HistoricalPublicKeys (max_values: None, max_size: Some(123), added: 2598, mode: MaxEncodedLen)
 	fn remove_sr25519_delegation_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2104`
-		//  Estimated: `5777`
-		// Minimum execution time: 63_289 nanoseconds.
-		Weight::from_parts(65_226_000, 5777)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+		Weight::from_parts(72_726_000, 11725)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
 	}
 	/// Storage: Did Did (r:1 w:1)
 	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
@@ -1061,14 +1377,16 @@ impl WeightInfo for () {
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: Balances Holds (r:1 w:1)
 	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
+	/// Storage: Did KeyHistory (r:1 w:2)
+	/// Proof: Did KeyHistory (max_values: None, max_size: Some(309), added: 2784, mode: MaxEncodedLen)
+	/// Storage: Did RemovedKeys (r:1 w:1)
+	/// Proof: Did RemovedKeys (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	/// Storage: Did HistoricalPublicKeys (r:0 w:1)
+	/// Proof: Did HistoricalPublicKeys (max_values: None, max_size: Some(123), added: 2598, mode: MaxEncodedLen)
 	fn remove_ecdsa_delegation_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2140`
-		//  Estimated: `5777`
-		// Minimum execution time: 64_181 nanoseconds.
-		Weight::from_parts(65_040_000, 5777)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+		Weight::from_parts(72_540_000, 11725)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
 	}
 	/// Storage: Did Did (r:1 w:1)
 	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
@@ -1078,14 +1396,16 @@ impl WeightInfo for () {
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: Balances Holds (r:1 w:1)
 	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
+	/// Storage: Did KeyHistory (r:2 w:3)
+	/// Proof: Did KeyHistory (max_values: None, max_size: Some(309), added: 2784, mode: MaxEncodedLen)
+	/// Storage: Did RemovedKeys (r:1 w:1)
+	/// Proof: Did RemovedKeys (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	/// Storage: Did HistoricalPublicKeys (r:0 w:2)
+	/// Proof: Did HistoricalPublicKeys (max_values: None, max_size: Some(123), added: 2598, mode: MaxEncodedLen)
 	fn set_ed25519_attestation_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2099`
-		//  Estimated: `5777`
-		// Minimum execution time: 66_521 nanoseconds.
-		Weight::from_parts(67_642_000, 5777)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+		Weight::from_parts(79_642_000, 14509)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
 	}
 	/// Storage: Did Did (r:1 w:1)
 	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
@@ -1095,14 +1415,16 @@ impl WeightInfo for () {
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: Balances Holds (r:1 w:1)
 	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
+	/// Storage: Did KeyHistory (r:2 w:3)
+	/// Proof: Did KeyHistory (max_values: None, max_size: Some(309), added: 2784, mode: MaxEncodedLen)
+	/// Storage: Did RemovedKeys (r:1 w:1)
+	/// Proof: Did RemovedKeys (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	/// Storage: Did HistoricalPublicKeys (r:0 w:2)
+	/// Proof: Did HistoricalPublicKeys (max_values: None, max_size: Some(123), added: 2598, mode: MaxEncodedLen)
 	fn set_sr25519_attestation_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2099`
-		//  Estimated: `5777`
-		// Minimum execution time: 66_605 nanoseconds.
-		Weight::from_parts(67_712_000, 5777)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+		Weight::from_parts(79_712_000, 14509)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
 	}
 	/// Storage: Did Did (r:1 w:1)
 	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
@@ -1112,14 +1434,16 @@ impl WeightInfo for () {
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: Balances Holds (r:1 w:1)
 	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
+	/// Storage: Did KeyHistory (r:2 w:3)
+	/// Proof: Did KeyHistory (max_values: None, max_size: Some(309), added: 2784, mode: MaxEncodedLen)
+	/// Storage: Did RemovedKeys (r:1 w:1)
+	/// Proof: Did RemovedKeys (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	/// Storage: Did HistoricalPublicKeys (r:0 w:2)
+	/// Proof: Did HistoricalPublicKeys (max_values: None, max_size: Some(123), added: 2598, mode: MaxEncodedLen)
 	fn set_ecdsa_attestation_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2135`
-		//  Estimated: `5777`
-		// Minimum execution time: 66_348 nanoseconds.
-		Weight::from_parts(68_046_000, 5777)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+		Weight::from_parts(80_046_000, 14509)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
 	}
 	/// Storage: Did Did (r:1 w:1)
 	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
@@ -1129,14 +1453,16 @@ impl WeightInfo for () {
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: Balances Holds (r:1 w:1)
 	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
+	/// Storage: Did KeyHistory (r:1 w:2)
+	/// Proof: Did KeyHistory (max_values: None, max_size: Some(309), added: 2784, mode: MaxEncodedLen)
+	/// Storage: Did RemovedKeys (r:1 w:1)
+	/// Proof: Did RemovedKeys (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	/// Storage: Did HistoricalPublicKeys (r:0 w:1)
+	/// Proof: Did HistoricalPublicKeys (max_values: None, max_size: Some(123), added: 2598, mode: MaxEncodedLen)
 	fn remove_ed25519_attestation_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2165`
-		//  Estimated: `5777`
-		// Minimum execution time: 63_844 nanoseconds.
-		Weight::from_parts(65_421_000, 5777)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+		Weight::from_parts(72_921_000, 11725)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
 	}
 	/// Storage: Did Did (r:1 w:1)
 	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
@@ -1146,14 +1472,16 @@ impl WeightInfo for () {
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: Balances Holds (r:1 w:1)
 	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
+	/// Storage: Did KeyHistory (r:1 w:2)
+	/// Proof: Did KeyHistory (max_values: None, max_size: Some(309), added: 2784, mode: MaxEncodedLen)
+	/// Storage: Did RemovedKeys (r:1 w:1)
+	/// Proof: Did RemovedKeys (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	/// Storage: Did HistoricalPublicKeys (r:0 w:1)
+	/// Proof: Did HistoricalPublicKeys (max_values: None, max_size: Some(123), added: 2598, mode: MaxEncodedLen)
 	fn remove_sr25519_attestation_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2132`
-		//  Estimated: `5777`
-		// Minimum execution time: 63_508 nanoseconds.
-		Weight::from_parts(65_180_000, 5777)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+		Weight::from_parts(72_680_000, 11725)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
 	}
 	/// Storage: Did Did (r:1 w:1)
 	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
@@ -1163,14 +1491,16 @@ impl WeightInfo for () {
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: Balances Holds (r:1 w:1)
 	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
+	/// Storage: Did KeyHistory (r:1 w:2)
+	/// Proof: Did KeyHistory (max_values: None, max_size: Some(309), added: 2784, mode: MaxEncodedLen)
+	/// Storage: Did RemovedKeys (r:1 w:1)
+	/// Proof: Did RemovedKeys (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	/// Storage: Did HistoricalPublicKeys (r:0 w:1)
+	/// Proof: Did HistoricalPublicKeys (max_values: None, max_size: Some(123), added: 2598, mode: MaxEncodedLen)
 	fn remove_ecdsa_attestation_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2042`
-		//  Estimated: `5777`
-		// Minimum execution time: 64_008 nanoseconds.
-		Weight::from_parts(65_141_000, 5777)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+		Weight::from_parts(72_641_000, 11725)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
 	}
 	/// Storage: Did Did (r:1 w:1)
 	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
@@ -1180,14 +1510,16 @@ impl WeightInfo for () {
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: Balances Holds (r:1 w:1)
 	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
+	/// Storage: Did KeyHistory (r:1 w:1)
+	/// Proof: Did KeyHistory (max_values: None, max_size: Some(309), added: 2784, mode: MaxEncodedLen)
+	/// Storage: Did RemovedKeys (r:1 w:1)
+	/// Proof: Did RemovedKeys (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	/// Storage: Did HistoricalPublicKeys (r:0 w:1)
+	/// Proof: Did HistoricalPublicKeys (max_values: None, max_size: Some(123), added: 2598, mode: MaxEncodedLen)
 	fn add_ed25519_key_agreement_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2031`
-		//  Estimated: `5777`
-		// Minimum execution time: 65_688 nanoseconds.
-		Weight::from_parts(67_265_000, 5777)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+		Weight::from_parts(73_765_000, 11725)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
 	/// Storage: Did Did (r:1 w:1)
 	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
@@ -1197,14 +1529,16 @@ impl WeightInfo for () {
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: Balances Holds (r:1 w:1)
 	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
+	/// Storage: Did KeyHistory (r:1 w:1)
+	/// Proof: Did KeyHistory (max_values: None, max_size: Some(309), added: 2784, mode: MaxEncodedLen)
+	/// Storage: Did RemovedKeys (r:1 w:1)
+	/// Proof: Did RemovedKeys (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	/// Storage: Did HistoricalPublicKeys (r:0 w:1)
+	/// Proof: Did HistoricalPublicKeys (max_values: None, max_size: Some(123), added: 2598, mode: MaxEncodedLen)
 	fn add_sr25519_key_agreement_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1933`
-		//  Estimated: `5777`
-		// Minimum execution time: 65_674 nanoseconds.
-		Weight::from_parts(67_627_000, 5777)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+		Weight::from_parts(74_127_000, 11725)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
 	/// Storage: Did Did (r:1 w:1)
 	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
@@ -1214,14 +1548,16 @@ impl WeightInfo for () {
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: Balances Holds (r:1 w:1)
 	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
+	/// Storage: Did KeyHistory (r:1 w:1)
+	/// Proof: Did KeyHistory (max_values: None, max_size: Some(309), added: 2784, mode: MaxEncodedLen)
+	/// Storage: Did RemovedKeys (r:1 w:1)
+	/// Proof: Did RemovedKeys (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	/// Storage: Did HistoricalPublicKeys (r:0 w:1)
+	/// Proof: Did HistoricalPublicKeys (max_values: None, max_size: Some(123), added: 2598, mode: MaxEncodedLen)
 	fn add_ecdsa_key_agreement_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2001`
-		//  Estimated: `5777`
-		// Minimum execution time: 65_424 nanoseconds.
-		Weight::from_parts(67_229_000, 5777)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+		Weight::from_parts(73_729_000, 11725)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
 	/// Storage: Did Did (r:1 w:1)
 	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
@@ -1231,14 +1567,16 @@ impl WeightInfo for () {
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: Balances Holds (r:1 w:1)
 	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
+	/// Storage: Did KeyHistory (r:1 w:2)
+	/// Proof: Did KeyHistory (max_values: None, max_size: Some(309), added: 2784, mode: MaxEncodedLen)
+	/// Storage: Did RemovedKeys (r:1 w:1)
+	/// Proof: Did RemovedKeys (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	/// Storage: Did HistoricalPublicKeys (r:0 w:1)
+	/// Proof: Did HistoricalPublicKeys (max_values: None, max_size: Some(123), added: 2598, mode: MaxEncodedLen)
 	fn remove_ed25519_key_agreement_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2099`
-		//  Estimated: `5777`
-		// Minimum execution time: 64_401 nanoseconds.
-		Weight::from_parts(65_925_000, 5777)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+		Weight::from_parts(73_425_000, 11725)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
 	}
 	/// Storage: Did Did (r:1 w:1)
 	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
@@ -1248,14 +1586,16 @@ impl WeightInfo for () {
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: Balances Holds (r:1 w:1)
 	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
+	/// Storage: Did KeyHistory (r:1 w:2)
+	/// Proof: Did KeyHistory (max_values: None, max_size: Some(309), added: 2784, mode: MaxEncodedLen)
+	/// Storage: Did RemovedKeys (r:1 w:1)
+	/// Proof: Did RemovedKeys (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	/// Storage: Did HistoricalPublicKeys (r:0 w:1)
+	/// Proof: Did HistoricalPublicKeys (max_values: None, max_size: Some(123), added: 2598, mode: MaxEncodedLen)
 	fn remove_sr25519_key_agreement_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2137`
-		//  Estimated: `5777`
-		// Minimum execution time: 64_589 nanoseconds.
-		Weight::from_parts(65_962_000, 5777)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+		Weight::from_parts(73_462_000, 11725)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
 	}
 	/// Storage: Did Did (r:1 w:1)
 	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
@@ -1265,14 +1605,16 @@ impl WeightInfo for () {
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: Balances Holds (r:1 w:1)
 	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
+	/// Storage: Did KeyHistory (r:1 w:2)
+	/// Proof: Did KeyHistory (max_values: None, max_size: Some(309), added: 2784, mode: MaxEncodedLen)
+	/// Storage: Did RemovedKeys (r:1 w:1)
+	/// Proof: Did RemovedKeys (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	/// Storage: Did HistoricalPublicKeys (r:0 w:1)
+	/// Proof: Did HistoricalPublicKeys (max_values: None, max_size: Some(123), added: 2598, mode: MaxEncodedLen)
 	fn remove_ecdsa_key_agreement_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2173`
-		//  Estimated: `5777`
-		// Minimum execution time: 65_111 nanoseconds.
-		Weight::from_parts(65_846_000, 5777)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+		Weight::from_parts(73_346_000, 11725)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
 	}
 	/// Storage: Did Did (r:1 w:1)
 	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
@@ -1284,13 +1626,11 @@ impl WeightInfo for () {
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: Balances Holds (r:1 w:1)
 	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
+	/// Storage: Did RemovedKeys (r:1 w:0)
+	/// Proof: Did RemovedKeys (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
 	fn add_service_endpoint() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1538`
-		//  Estimated: `5777`
-		// Minimum execution time: 75_764 nanoseconds.
-		Weight::from_parts(76_710_000, 5777)
-			.saturating_add(RocksDbWeight::get().reads(5_u64))
+		Weight::from_parts(78_210_000, 8941)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
 			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: Did Did (r:1 w:1)
@@ -1303,114 +1643,182 @@ impl WeightInfo for () {
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: Balances Holds (r:1 w:1)
 	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
+	/// Storage: Did RemovedKeys (r:1 w:0)
+	/// Proof: Did RemovedKeys (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
 	fn remove_service_endpoint() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2153`
-		//  Estimated: `5777`
-		// Minimum execution time: 72_429 nanoseconds.
-		Weight::from_parts(73_903_000, 5777)
-			.saturating_add(RocksDbWeight::get().reads(5_u64))
+		Weight::from_parts(75_403_000, 8941)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
 			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
-	/// Storage: Did Did (r:1 w:0)
+	/// Storage: Did Did (r:1 w:1)
 	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	/// The range of component `l` is `[1, 5242880]`.
-	fn signature_verification_sr25519(l: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1626`
-		//  Estimated: `5777`
-		// Minimum execution time: 63_343 nanoseconds.
-		Weight::from_parts(32_311_843, 5777)
-			// Standard Error: 0
-			.saturating_add(Weight::from_parts(4_303, 0).saturating_mul(l.into()))
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
+	/// Storage: Did RemovedKeys (r:1 w:0)
+	/// Proof: Did RemovedKeys (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	fn change_deposit_owner() -> Weight {
+		Weight::from_parts(74_855_000, 8941)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
-	/// Storage: Did Did (r:1 w:0)
+	/// Storage: Did Did (r:1 w:1)
 	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	/// The range of component `l` is `[1, 5242880]`.
-	fn signature_verification_ed25519(l: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1626`
-		//  Estimated: `5777`
-		// Minimum execution time: 62_148 nanoseconds.
-		Weight::from_parts(40_931_723, 5777)
-			// Standard Error: 0
-			.saturating_add(Weight::from_parts(2_520, 0).saturating_mul(l.into()))
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	/// Storage: Did DidEndpointsCount (r:1 w:0)
+	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
+	/// Storage: Did RemovedKeys (r:1 w:0)
+	/// Proof: Did RemovedKeys (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	fn update_deposit() -> Weight {
+		Weight::from_parts(46_802_000, 8941)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
-	/// Storage: Did Did (r:1 w:0)
+	/// Storage: Did DidBlacklist (r:1 w:0)
+	/// Proof: Did DidBlacklist (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Did Did (r:1 w:1)
 	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	/// The range of component `l` is `[1, 5242880]`.
-	fn signature_verification_ecdsa(l: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1627`
-		//  Estimated: `5777`
-		// Minimum execution time: 49_962 nanoseconds.
-		Weight::from_parts(28_598_102, 5777)
-			// Standard Error: 0
-			.saturating_add(Weight::from_parts(1_151, 0).saturating_mul(l.into()))
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	/// Storage: Did DidEndpointsCount (r:1 w:0)
+	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
+	/// Storage: Did KeyHistory (r:1 w:1)
+	/// Proof: Did KeyHistory (max_values: None, max_size: Some(309), added: 2784, mode: MaxEncodedLen)
+	/// Storage: Did RemovedKeys (r:1 w:1)
+	/// Proof: Did RemovedKeys (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	/// Storage: Did HistoricalPublicKeys (r:0 w:1)
+	/// Proof: Did HistoricalPublicKeys (max_values: None, max_size: Some(123), added: 2598, mode: MaxEncodedLen)
+	fn create_from_account() -> Weight {
+		Weight::from_parts(989_757_000, 12152)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
 	}
 	/// Storage: Did Did (r:1 w:1)
 	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
+	/// Storage: Did DidEndpointsCount (r:1 w:1)
+	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Did ServiceEndpoints (r:1 w:0)
+	/// Proof: Did ServiceEndpoints (max_values: None, max_size: Some(615), added: 3090, mode: MaxEncodedLen)
+	/// Storage: Did HashedServiceEndpoints (r:1 w:1)
+	/// Proof: Did HashedServiceEndpoints (max_values: None, max_size: Some(223), added: 2698, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: Balances Holds (r:1 w:1)
 	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
-	fn change_deposit_owner() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `916`
-		//  Estimated: `5777`
-		// Minimum execution time: 72_073 nanoseconds.
-		Weight::from_parts(73_355_000, 5777)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	/// Storage: Did RemovedKeys (r:1 w:0)
+	/// Proof: Did RemovedKeys (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	fn add_hashed_service_endpoint() -> Weight {
+		Weight::from_parts(73_818_000, 8941)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	fn did_remark(l: u32, ) -> Weight {
+		Weight::from_parts(7_402_000, 0)
+			.saturating_add(Weight::from_parts(1_312, 0).saturating_mul(l.into()))
+	}
+	/// Storage: Did Did (r:1 w:0)
+	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
+	/// Storage: Did Endorsements (r:1 w:1)
+	/// Proof: Did Endorsements (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Did EndorsementsCount (r:1 w:1)
+	/// Proof: Did EndorsementsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn endorse() -> Weight {
+		Weight::from_parts(21_474_000, 0)
+			.saturating_add(Weight::from_parts(0, 5777))
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	/// Storage: Did Endorsements (r:1 w:1)
+	/// Proof: Did Endorsements (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Did EndorsementsCount (r:1 w:1)
+	/// Proof: Did EndorsementsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn revoke_endorsement() -> Weight {
+		Weight::from_parts(17_128_000, 0)
+			.saturating_add(Weight::from_parts(0, 3593))
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	/// Storage: Did Did (r:1 w:1)
+	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
+	/// Storage: Did AlsoKnownAs (r:1 w:1)
+	/// Proof: Did AlsoKnownAs (max_values: None, max_size: Some(2629), added: 5104, mode: MaxEncodedLen)
+	/// Storage: Did DidEndpointsCount (r:1 w:0)
+	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
+	/// Storage: Did RemovedKeys (r:1 w:0)
+	/// Proof: Did RemovedKeys (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	fn add_also_known_as() -> Weight {
+		Weight::from_parts(76_312_000, 0)
+			.saturating_add(Weight::from_parts(0, 9258))
+			.saturating_add(RocksDbWeight::get().reads(6))
+			.saturating_add(RocksDbWeight::get().writes(4))
 	}
 	/// Storage: Did Did (r:1 w:1)
 	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
+	/// Storage: Did AlsoKnownAs (r:1 w:1)
+	/// Proof: Did AlsoKnownAs (max_values: None, max_size: Some(2629), added: 5104, mode: MaxEncodedLen)
 	/// Storage: Did DidEndpointsCount (r:1 w:0)
 	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: Balances Holds (r:1 w:1)
 	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
-	fn update_deposit() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `888`
-		//  Estimated: `5777`
-		// Minimum execution time: 44_498 nanoseconds.
-		Weight::from_parts(45_302_000, 5777)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	/// Storage: Did RemovedKeys (r:1 w:0)
+	/// Proof: Did RemovedKeys (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	fn remove_also_known_as() -> Weight {
+		Weight::from_parts(72_805_000, 0)
+			.saturating_add(Weight::from_parts(0, 9258))
+			.saturating_add(RocksDbWeight::get().reads(6))
+			.saturating_add(RocksDbWeight::get().writes(4))
 	}
-
 	/// Storage: Did Did (r:1 w:0)
 	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	fn dispatch_as() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `353`
-		//  Estimated: `5777`
-		// Minimum execution time: 187_140 nanoseconds.
-		Weight::from_parts(190_030_000, 5777)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	/// Storage: Did KeyHistory (r:13 w:13)
+	/// Proof: Did KeyHistory (max_values: None, max_size: Some(309), added: 2784, mode: MaxEncodedLen)
+	/// Storage: Did HistoricalPublicKeys (r:0 w:13)
+	/// Proof: Did HistoricalPublicKeys (max_values: None, max_size: Some(123), added: 2598, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 10]`.
+	fn backfill_key_history_step(n: u32, ) -> Weight {
+		Weight::from_parts(47_318_256, 0)
+			.saturating_add(Weight::from_parts(0, 13139))
+			.saturating_add(Weight::from_parts(6_817_402, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(4))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(6))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2784).saturating_mul(n.into()))
 	}
-	/// Storage: Did DidBlacklist (r:1 w:0)
-	/// Proof: Did DidBlacklist (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
 	/// Storage: Did Did (r:1 w:1)
 	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
 	/// Storage: Did DidEndpointsCount (r:1 w:0)
 	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
-	/// Storage: System Account (r:2 w:2)
+	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: Balances Holds (r:1 w:1)
 	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
-	fn create_from_account() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1097`
-		//  Estimated: `6204`
-		// Minimum execution time: 970_542 nanoseconds.
-		Weight::from_parts(983_257_000, 6204)
-			.saturating_add(RocksDbWeight::get().reads(6_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	fn reprice_did_deposit_step() -> Weight {
+		Weight::from_parts(49_876_000, 0)
+			.saturating_add(Weight::from_parts(0, 5777))
+			.saturating_add(RocksDbWeight::get().reads(4))
+			.saturating_add(RocksDbWeight::get().writes(3))
+	}
+	/// Storage: Did Did (r:1 w:0)
+	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
+	/// Storage: Did CounterForDid (r:1 w:1)
+	/// Proof: Did CounterForDid (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	fn initialize_did_counter_step() -> Weight {
+		Weight::from_parts(11_042_000, 0)
+			.saturating_add(Weight::from_parts(0, 5777))
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(1))
 	}
 }
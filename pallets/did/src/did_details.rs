@@ -26,6 +26,8 @@ use frame_system::pallet_prelude::BlockNumberFor;
 use kilt_support::Deposit;
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen, WrapperTypeEncode};
 use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
 use sp_core::{ecdsa, ed25519, sr25519};
 use sp_runtime::{
 	traits::{IdentifyAccount, Verify, Zero},
@@ -40,6 +42,7 @@ use crate::{
 
 /// Public verification key that a DID can control.
 #[derive(Clone, Copy, Decode, RuntimeDebug, Encode, Eq, Ord, PartialEq, PartialOrd, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum DidVerificationKey<AccountId> {
 	/// An Ed25519 public key.
 	Ed25519(ed25519::Public),
@@ -112,6 +115,7 @@ impl<AccountId> From<ecdsa::Public> for DidVerificationKey<AccountId> {
 
 /// Types of encryption keys a DID can control.
 #[derive(Clone, Copy, Decode, RuntimeDebug, Encode, Eq, Ord, PartialEq, PartialOrd, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum DidEncryptionKey {
 	/// An X25519 public key.
 	X25519([u8; 32]),
@@ -119,6 +123,7 @@ pub enum DidEncryptionKey {
 
 /// A general public key under the control of the DID.
 #[derive(Clone, Copy, Decode, RuntimeDebug, Encode, Eq, Ord, PartialEq, PartialOrd, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum DidPublicKey<AccountId> {
 	/// A verification key, used to generate and verify signatures.
 	PublicVerificationKey(DidVerificationKey<AccountId>),
@@ -141,6 +146,7 @@ impl<AccountId> From<DidEncryptionKey> for DidPublicKey<AccountId> {
 /// Verification methods a verification key can
 /// fulfil, according to the [DID specification](https://w3c.github.io/did-spec-registries/#verification-relationships).
 #[derive(Clone, Copy, RuntimeDebug, Decode, Encode, PartialEq, Eq, TypeInfo, MaxEncodedLen, PartialOrd, Ord)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum DidVerificationKeyRelationship {
 	/// Key used to authenticate all the DID operations.
 	Authentication,
@@ -152,6 +158,78 @@ pub enum DidVerificationKeyRelationship {
 	AssertionMethod,
 }
 
+/// The relationship a key has with a DID.
+#[derive(Clone, Copy, RuntimeDebug, Decode, Encode, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum DidKeyRelationship {
+	/// The key is used to encrypt data addressed to the DID subject.
+	Encryption,
+	/// The key is used for the given verification relationship.
+	Verification(DidVerificationKeyRelationship),
+}
+
+/// The algorithm of a DID public key.
+///
+/// Runtimes can restrict the algorithms allowed for each key relationship via
+/// [`Config::AllowedKeyTypes`].
+#[derive(Clone, Copy, RuntimeDebug, Decode, Encode, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum DidKeyType {
+	/// An Ed25519 verification key.
+	Ed25519,
+	/// A Sr25519 verification key.
+	Sr25519,
+	/// An ECDSA verification key.
+	Ecdsa,
+	/// An account identifier used as verification key.
+	Account,
+	/// An X25519 encryption key.
+	X25519,
+}
+
+impl<AccountId> DidVerificationKey<AccountId> {
+	/// The algorithm of the verification key.
+	pub fn key_type(&self) -> DidKeyType {
+		match self {
+			DidVerificationKey::Ed25519(_) => DidKeyType::Ed25519,
+			DidVerificationKey::Sr25519(_) => DidKeyType::Sr25519,
+			DidVerificationKey::Ecdsa(_) => DidKeyType::Ecdsa,
+			DidVerificationKey::Account(_) => DidKeyType::Account,
+		}
+	}
+}
+
+impl DidEncryptionKey {
+	/// The algorithm of the encryption key.
+	pub fn key_type(&self) -> DidKeyType {
+		match self {
+			DidEncryptionKey::X25519(_) => DidKeyType::X25519,
+		}
+	}
+}
+
+/// The kind of change a DID key went through.
+#[derive(Clone, Copy, RuntimeDebug, Decode, Encode, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum KeyChange {
+	/// The key was added for the relationship.
+	Added,
+	/// The key was removed from the relationship.
+	Removed,
+}
+
+/// A change of a DID key, as recorded in the key history.
+#[derive(Clone, Copy, RuntimeDebug, Decode, Encode, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct KeyHistoryEntry<BlockNumber> {
+	/// The relationship the key was added to or removed from.
+	pub relationship: DidKeyRelationship,
+	/// The kind of change.
+	pub change: KeyChange,
+	/// The block number in which the change happened.
+	pub block_number: BlockNumber,
+}
+
 /// Types of signatures supported by this pallet.
 #[derive(Clone, Debug, Encode, Decode, PartialEq, Eq, TypeInfo)]
 pub enum DidSignature {
@@ -265,6 +343,7 @@ impl<I: AsRef<[u8; 32]>, AccountId> DidVerifiableIdentifier<AccountId> for I {
 /// It is currently used to keep track of all the past and current
 /// attestation keys a DID might control.
 #[derive(Clone, Copy, RuntimeDebug, Decode, Encode, PartialEq, Ord, PartialOrd, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct DidPublicKeyDetails<BlockNumber, AccountId> {
 	/// A public key the DID controls.
 	pub key: DidPublicKey<AccountId>,
@@ -617,6 +696,30 @@ impl<T: Config> DidDetails<T> {
 		self.last_tx_counter = self.last_tx_counter.wrapping_add(1);
 		self.last_tx_counter
 	}
+
+	/// Returns the IDs of the keys currently in use by the DID, together with
+	/// their relationship.
+	pub fn key_relationships(&self) -> Vec<(KeyIdOf<T>, DidKeyRelationship)> {
+		let verification_keys = [
+			(
+				Some(self.authentication_key),
+				DidVerificationKeyRelationship::Authentication,
+			),
+			(
+				self.delegation_key,
+				DidVerificationKeyRelationship::CapabilityDelegation,
+			),
+			(self.attestation_key, DidVerificationKeyRelationship::AssertionMethod),
+		]
+		.into_iter()
+		.filter_map(|(key_id, relationship)| Some((key_id?, DidKeyRelationship::Verification(relationship))));
+		let encryption_keys = self
+			.key_agreement_keys
+			.iter()
+			.map(|key_id| (*key_id, DidKeyRelationship::Encryption));
+
+		verification_keys.chain(encryption_keys).collect()
+	}
 }
 
 pub(crate) type DidNewKeyAgreementKeySet<MaxNewKeyAgreementKeys> =
@@ -698,6 +801,58 @@ pub struct DidAuthorizedCallOperation<DidIdentifier, DidCallable, BlockNumber, A
 	pub submitter: AccountId,
 }
 
+/// The domain separator of the canonical signing payload of
+/// [DidAuthorizedCallOperation]s.
+pub const DID_OPERATION_SIGNING_DOMAIN: &[u8] = b"kilt:did:operation";
+
+/// The version of the canonical signing payload of
+/// [DidAuthorizedCallOperation]s.
+pub const DID_OPERATION_SIGNING_PAYLOAD_VERSION: u8 = 1;
+
+/// Builds the canonical signing payload of a SCALE-encoded
+/// [DidAuthorizedCallOperation].
+///
+/// The payload is the concatenation of:
+/// * the raw bytes of [DID_OPERATION_SIGNING_DOMAIN],
+/// * the [DID_OPERATION_SIGNING_PAYLOAD_VERSION] byte,
+/// * the raw bytes of the genesis hash of the chain,
+/// * the SCALE-encoded (i.e., little-endian) spec version of the runtime,
+/// * the SCALE-encoded operation.
+///
+/// Since it only takes encoded values, remote signers and hardware wallets
+/// can build the payload without depending on the pallet types.
+pub fn did_operation_signing_payload(genesis_hash: &[u8], spec_version: u32, encoded_operation: &[u8]) -> Vec<u8> {
+	let mut payload = Vec::with_capacity(
+		DID_OPERATION_SIGNING_DOMAIN
+			.len()
+			.saturating_add(genesis_hash.len())
+			.saturating_add(encoded_operation.len())
+			.saturating_add(5),
+	);
+	payload.extend_from_slice(DID_OPERATION_SIGNING_DOMAIN);
+	payload.push(DID_OPERATION_SIGNING_PAYLOAD_VERSION);
+	payload.extend_from_slice(genesis_hash);
+	spec_version.encode_to(&mut payload);
+	payload.extend_from_slice(encoded_operation);
+	payload
+}
+
+impl<DidIdentifier, DidCallable, BlockNumber, AccountId, TxCounter>
+	DidAuthorizedCallOperation<DidIdentifier, DidCallable, BlockNumber, AccountId, TxCounter>
+where
+	Self: Encode,
+{
+	/// The canonical signing payload of the operation for the chain with the
+	/// given genesis hash and runtime spec version.
+	///
+	/// Signatures over this payload cannot be replayed on other chains or
+	/// runtime versions. See [did_operation_signing_payload] for the exact
+	/// encoding.
+	pub fn signing_payload(&self, genesis_hash: &[u8], spec_version: u32) -> Vec<u8> {
+		did_operation_signing_payload(genesis_hash, spec_version, &self.encode())
+	}
+}
+
 /// Wrapper around a [DidAuthorizedCallOperation].
 ///
 /// It contains additional information about the type of DID key to used for
@@ -0,0 +1,307 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use frame_support::{
+	ensure,
+	traits::{Contains, Get},
+	RuntimeDebug,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_runtime::SaturatedConversion;
+use sp_std::{
+	collections::{btree_map::BTreeMap, btree_set::BTreeSet},
+	str,
+	vec::Vec,
+};
+
+use crate::{
+	did_details::{DidDetails, DidEncryptionKey, DidKeyRelationship, DidKeyType, DidVerificationKey},
+	service_endpoints::{DidEndpoint, HashedDidEndpoint, ServiceEndpointId},
+	utils, AccountIdOf, AlsoKnownAs, AlsoKnownAsUriOf, BalanceOf, Config, DidIdentifierOf,
+	DidVerificationKeyRelationship, Error, HashedServiceEndpoints, KeyIdOf, RemovedKeys, ServiceEndpoints,
+};
+
+/// A change to a DID Document, whose effect on the size and on the deposit of
+/// the document can be estimated before it is submitted.
+///
+/// Each variant mirrors the DID extrinsic with the same name.
+#[derive(Clone, Decode, RuntimeDebug, Encode, PartialEq, Eq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum DidDocumentOperation<AccountId, Hash> {
+	SetAuthenticationKey(DidVerificationKey<AccountId>),
+	SetDelegationKey(DidVerificationKey<AccountId>),
+	RemoveDelegationKey,
+	SetAttestationKey(DidVerificationKey<AccountId>),
+	RemoveAttestationKey,
+	AddKeyAgreementKey(DidEncryptionKey),
+	RemoveKeyAgreementKey(Hash),
+	AddServiceEndpoint {
+		id: Vec<u8>,
+		service_types: Vec<Vec<u8>>,
+		urls: Vec<Vec<u8>>,
+	},
+	AddHashedServiceEndpoint {
+		id: Vec<u8>,
+		tag: Vec<u8>,
+		descriptor_hash: Hash,
+	},
+	RemoveServiceEndpoint(Vec<u8>),
+	AddAlsoKnownAs(Vec<u8>),
+	RemoveAlsoKnownAs(Vec<u8>),
+}
+
+pub type DidDocumentOperationOf<T> = DidDocumentOperation<AccountIdOf<T>, <T as frame_system::Config>::Hash>;
+
+/// The size and the deposit of a DID Document after a batch of
+/// [DidDocumentOperation]s.
+#[derive(Clone, Decode, RuntimeDebug, Encode, PartialEq, Eq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct DidDocumentSizeEstimate<Balance> {
+	/// The size of the DID Document, in bytes.
+	pub size: u32,
+	/// The maximum size of a DID Document allowed by the runtime, in bytes.
+	pub max_size: u32,
+	/// The deposit required for the DID Document.
+	pub deposit: Balance,
+}
+
+impl<Balance> DidDocumentSizeEstimate<Balance> {
+	/// Whether the DID Document is larger than allowed, i.e., whether the
+	/// operations that result in it would fail.
+	pub fn exceeds_max_size(&self) -> bool {
+		self.size > self.max_size
+	}
+}
+
+/// The reasons why the effect of a batch of [DidDocumentOperation]s cannot be
+/// estimated.
+#[derive(Clone, Decode, RuntimeDebug, Encode, PartialEq, Eq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum DidDocumentEstimateError {
+	/// No DID with the given identifier is present on chain.
+	NotFound,
+	/// The operation at the given index would fail regardless of the size of
+	/// the DID Document, e.g., because it removes a key that is not present.
+	InvalidOperation(u32),
+}
+
+/// The parts of a DID Document that are accounted for in its size: the DID
+/// details, with all its keys, the service endpoints, both inline and hashed,
+/// and the `alsoKnownAs` URIs.
+pub(crate) struct DidDocument<T: Config> {
+	details: DidDetails<T>,
+	/// The encoded size of each service endpoint, by service ID.
+	service_endpoints: BTreeMap<ServiceEndpointId<T>, u32>,
+	also_known_as: Vec<AlsoKnownAsUriOf<T>>,
+	/// The keys whose history is charged a deposit: the keys already
+	/// removed from the DID and the keys the DID uses before any operation.
+	historical_key_ids: BTreeSet<KeyIdOf<T>>,
+}
+
+impl<T: Config> DidDocument<T> {
+	/// Combines the given DID details with the service endpoints and the
+	/// `alsoKnownAs` URIs stored under the DID.
+	pub(crate) fn new(did_subject: &DidIdentifierOf<T>, details: DidDetails<T>) -> Self {
+		let inline_endpoints = ServiceEndpoints::<T>::iter_prefix(did_subject)
+			.map(|(service_id, service_endpoint)| (service_id, service_endpoint.encoded_size()));
+		let hashed_endpoints = HashedServiceEndpoints::<T>::iter_prefix(did_subject)
+			.map(|(service_id, service_endpoint)| (service_id, service_endpoint.encoded_size()));
+		let service_endpoints = inline_endpoints
+			.chain(hashed_endpoints)
+			.map(|(service_id, size)| (service_id, size.saturated_into()))
+			.collect();
+
+		let historical_key_ids = RemovedKeys::<T>::get(did_subject)
+			.into_iter()
+			.chain(details.key_relationships().into_iter().map(|(key_id, _)| key_id))
+			.collect();
+
+		Self {
+			details,
+			service_endpoints,
+			also_known_as: AlsoKnownAs::<T>::get(did_subject).into_inner(),
+			historical_key_ids,
+		}
+	}
+
+	/// The size of the DID Document, in bytes, as the sum of the encoded
+	/// sizes of its parts.
+	pub(crate) fn size(&self) -> u32 {
+		let details_size: u32 = self.details.encoded_size().saturated_into();
+		let service_endpoints_size = self
+			.service_endpoints
+			.values()
+			.fold(0u32, |total, size| total.saturating_add(*size));
+		let also_known_as_size = self.also_known_as.iter().fold(0u32, |total, uri| {
+			total.saturating_add(uri.encoded_size().saturated_into())
+		});
+
+		details_size
+			.saturating_add(service_endpoints_size)
+			.saturating_add(also_known_as_size)
+	}
+
+	/// The deposit required for the DID Document.
+	pub(crate) fn deposit(&self) -> BalanceOf<T> {
+		let service_deposit_count = self.service_endpoints.len().saturating_add(self.also_known_as.len());
+		let current_key_ids: BTreeSet<KeyIdOf<T>> = self
+			.details
+			.key_relationships()
+			.into_iter()
+			.map(|(key_id, _)| key_id)
+			.collect();
+		let removed_keys_count: BalanceOf<T> = self
+			.historical_key_ids
+			.difference(&current_key_ids)
+			.count()
+			.min(T::MaxRemovedKeys::get().saturated_into())
+			.saturated_into();
+
+		self.details
+			.calculate_deposit(service_deposit_count.saturated_into())
+			.saturating_add(removed_keys_count.saturating_mul(T::KeyHistoryDeposit::get()))
+	}
+
+	/// Applies the operation with the same checks as the extrinsic it
+	/// mirrors, except for the maximum size of the DID Document.
+	pub(crate) fn apply(
+		&mut self,
+		operation: DidDocumentOperationOf<T>,
+		block_number: BlockNumberFor<T>,
+	) -> Result<(), Error<T>> {
+		match operation {
+			DidDocumentOperation::SetAuthenticationKey(new_key) => {
+				ensure_key_type_allowed::<T>(
+					DidKeyRelationship::Verification(DidVerificationKeyRelationship::Authentication),
+					new_key.key_type(),
+				)?;
+				self.details.update_authentication_key(new_key, block_number)?;
+			}
+			DidDocumentOperation::SetDelegationKey(new_key) => {
+				ensure_key_type_allowed::<T>(
+					DidKeyRelationship::Verification(DidVerificationKeyRelationship::CapabilityDelegation),
+					new_key.key_type(),
+				)?;
+				self.details.update_delegation_key(new_key, block_number)?;
+			}
+			DidDocumentOperation::RemoveDelegationKey => self.details.remove_delegation_key()?,
+			DidDocumentOperation::SetAttestationKey(new_key) => {
+				ensure_key_type_allowed::<T>(
+					DidKeyRelationship::Verification(DidVerificationKeyRelationship::AssertionMethod),
+					new_key.key_type(),
+				)?;
+				self.details.update_attestation_key(new_key, block_number)?;
+			}
+			DidDocumentOperation::RemoveAttestationKey => self.details.remove_attestation_key()?,
+			DidDocumentOperation::AddKeyAgreementKey(new_key) => {
+				ensure_key_type_allowed::<T>(DidKeyRelationship::Encryption, new_key.key_type())?;
+				self.details.add_key_agreement_key(new_key, block_number)?;
+			}
+			DidDocumentOperation::RemoveKeyAgreementKey(key_id) => self.details.remove_key_agreement_key(key_id)?,
+			DidDocumentOperation::AddServiceEndpoint {
+				id,
+				service_types,
+				urls,
+			} => {
+				let service_endpoint = DidEndpoint::<T> {
+					id: id.try_into().map_err(|_| Error::<T>::MaxServiceIdLengthExceeded)?,
+					service_types: service_types
+						.into_iter()
+						.map(|service_type| service_type.try_into())
+						.collect::<Result<Vec<_>, _>>()
+						.map_err(|_| Error::<T>::MaxServiceTypeLengthExceeded)?
+						.try_into()
+						.map_err(|_| Error::<T>::MaxNumberOfTypesPerServiceExceeded)?,
+					urls: urls
+						.into_iter()
+						.map(|url| url.try_into())
+						.collect::<Result<Vec<_>, _>>()
+						.map_err(|_| Error::<T>::MaxServiceUrlLengthExceeded)?
+						.try_into()
+						.map_err(|_| Error::<T>::MaxNumberOfUrlsPerServiceExceeded)?,
+				};
+				service_endpoint.validate_against_constraints()?;
+				self.add_service_endpoint(service_endpoint.id.clone(), service_endpoint.encoded_size())?;
+			}
+			DidDocumentOperation::AddHashedServiceEndpoint {
+				id,
+				tag,
+				descriptor_hash,
+			} => {
+				let service_endpoint = HashedDidEndpoint::<T> {
+					id: id.try_into().map_err(|_| Error::<T>::MaxServiceIdLengthExceeded)?,
+					tag: tag.try_into().map_err(|_| Error::<T>::MaxServiceTypeLengthExceeded)?,
+					descriptor_hash,
+				};
+				service_endpoint.validate_against_constraints()?;
+				self.add_service_endpoint(service_endpoint.id.clone(), service_endpoint.encoded_size())?;
+			}
+			DidDocumentOperation::RemoveServiceEndpoint(id) => {
+				let service_id: ServiceEndpointId<T> = id.try_into().map_err(|_| Error::<T>::ServiceNotFound)?;
+				self.service_endpoints
+					.remove(&service_id)
+					.ok_or(Error::<T>::ServiceNotFound)?;
+			}
+			DidDocumentOperation::AddAlsoKnownAs(uri) => {
+				let uri: AlsoKnownAsUriOf<T> = uri.try_into().map_err(|_| Error::<T>::InvalidAlsoKnownAs)?;
+				let str_uri = str::from_utf8(&uri).map_err(|_| Error::<T>::InvalidAlsoKnownAs)?;
+				ensure!(utils::is_valid_uri(str_uri), Error::<T>::InvalidAlsoKnownAs);
+				ensure!(!self.also_known_as.contains(&uri), Error::<T>::AlsoKnownAsAlreadyExists);
+				ensure!(
+					self.also_known_as.len() < T::MaxAlsoKnownAs::get().saturated_into::<usize>(),
+					Error::<T>::MaxAlsoKnownAsExceeded
+				);
+				self.also_known_as.push(uri);
+			}
+			DidDocumentOperation::RemoveAlsoKnownAs(uri) => {
+				let position = self
+					.also_known_as
+					.iter()
+					.position(|stored_uri| stored_uri.as_slice() == uri.as_slice())
+					.ok_or(Error::<T>::AlsoKnownAsNotFound)?;
+				self.also_known_as.remove(position);
+			}
+		}
+		Ok(())
+	}
+
+	fn add_service_endpoint(&mut self, service_id: ServiceEndpointId<T>, size: usize) -> Result<(), Error<T>> {
+		ensure!(
+			self.service_endpoints.len() < T::MaxNumberOfServicesPerDid::get().saturated_into::<usize>(),
+			Error::<T>::MaxNumberOfServicesExceeded
+		);
+		ensure!(
+			!self.service_endpoints.contains_key(&service_id),
+			Error::<T>::ServiceAlreadyExists
+		);
+		self.service_endpoints.insert(service_id, size.saturated_into());
+		Ok(())
+	}
+}
+
+fn ensure_key_type_allowed<T: Config>(relationship: DidKeyRelationship, key_type: DidKeyType) -> Result<(), Error<T>> {
+	ensure!(
+		T::AllowedKeyTypes::contains(&(relationship, key_type)),
+		Error::<T>::KeyTypeNotAllowed
+	);
+	Ok(())
+}
@@ -85,6 +85,8 @@
 pub mod benchmarking;
 pub mod default_weights;
 pub mod did_details;
+pub mod document;
+pub mod endorsements;
 pub mod errors;
 pub mod migrations;
 pub mod origin;
@@ -110,6 +112,8 @@ pub use crate::{
 		DidAuthorizedCallOperationWithVerificationRelationship, DidSignature, DidVerificationKeyRelationship,
 		RelationshipDeriveError,
 	},
+	document::{DidDocumentEstimateError, DidDocumentOperation, DidDocumentSizeEstimate},
+	endorsements::EndorsementDetails,
 	origin::{DidRawOrigin, EnsureDidOrigin},
 	pallet::*,
 	signature::DidSignatureVerify,
@@ -144,7 +148,7 @@ pub mod pallet {
 		traits::{
 			fungible::{Balanced, Credit, Inspect, MutateHold},
 			tokens::{Fortitude, Precision, Preservation},
-			StorageVersion,
+			Contains, IsSubType, StorageVersion,
 		},
 	};
 	use frame_system::pallet_prelude::*;
@@ -152,19 +156,24 @@ pub mod pallet {
 		traits::{BalanceMigrationManager, CallSources, StorageDepositCollector},
 		Deposit,
 	};
-	use service_endpoints::DidEndpoint;
-	use sp_runtime::traits::{BadOrigin, IdentifyAccount};
+	use service_endpoints::{DidEndpoint, HashedDidEndpoint};
+	use sp_runtime::traits::{BadOrigin, Hash, IdentifyAccount};
+	use sp_std::{collections::btree_set::BTreeSet, str, vec::Vec};
 
 	use crate::{
 		did_details::{
 			DeriveDidCallAuthorizationVerificationKeyRelationship, DidAuthorizedCallOperation, DidDetails,
-			DidEncryptionKey, DidSignature, DidVerifiableIdentifier, DidVerificationKey, RelationshipDeriveError,
+			DidEncryptionKey, DidKeyRelationship, DidKeyType, DidPublicKey, DidSignature, DidVerifiableIdentifier,
+			DidVerificationKey, KeyChange, KeyHistoryEntry, RelationshipDeriveError,
 		},
+		document::{DidDocument, DidDocumentOperationOf},
 		service_endpoints::{utils as service_endpoints_utils, ServiceEndpointId},
+		utils as crate_utils,
 	};
 
-	/// The current storage version.
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(4);
+	/// The current storage version, which is set by the last stepped migration
+	/// of the pallet.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(crate::migrations::DID_COUNTER_STORAGE_VERSION);
 
 	/// Reference to a payload of data of variable size.
 	pub type Payload = [u8];
@@ -186,6 +195,21 @@ pub mod pallet {
 	pub type Origin<T> = DidRawOrigin<DidIdentifierOf<T>, AccountIdOf<T>>;
 
 	pub type BalanceOf<T> = <CurrencyOf<T> as Inspect<AccountIdOf<T>>>::Balance;
+
+	/// Type for the history of changes to a DID key.
+	pub type KeyHistoryOf<T> = BoundedVec<KeyHistoryEntry<BlockNumberFor<T>>, <T as Config>::MaxKeyHistoryLength>;
+
+	/// Type for the keys removed from a DID whose history is kept.
+	pub type RemovedKeysOf<T> = BoundedVec<KeyIdOf<T>, <T as Config>::MaxRemovedKeys>;
+
+	/// Type for the endorsement of a DID by another DID.
+	pub type EndorsementDetailsOf<T> = EndorsementDetails<<T as frame_system::Config>::Hash, BlockNumberFor<T>>;
+
+	/// Type for an `alsoKnownAs` URI of a DID.
+	pub type AlsoKnownAsUriOf<T> = BoundedVec<u8, <T as Config>::MaxAlsoKnownAsUriLength>;
+
+	/// Type for the `alsoKnownAs` URIs of a DID.
+	pub type AlsoKnownAsOf<T> = BoundedVec<AlsoKnownAsUriOf<T>, <T as Config>::MaxAlsoKnownAs>;
 	pub(crate) type CurrencyOf<T> = <T as Config>::Currency;
 	pub(crate) type CreditOf<T> = Credit<<T as frame_system::Config>::AccountId, <T as Config>::Currency>;
 
@@ -313,6 +337,64 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxNumberOfUrlsPerService: Get<u32>;
 
+		/// The maximum number of changes kept in the history of a single DID
+		/// key.
+		#[pallet::constant]
+		type MaxKeyHistoryLength: Get<u32>;
+
+		/// The maximum number of keys removed from a single DID whose history
+		/// is kept.
+		#[pallet::constant]
+		type MaxRemovedKeys: Get<u32>;
+
+		/// The amount of balance that will be taken for each removed key whose
+		/// history and public key are kept.
+		#[pallet::constant]
+		type KeyHistoryDeposit: Get<BalanceOf<Self>>;
+
+		/// The maximum number of endorsements a single DID can give to other
+		/// DIDs.
+		#[pallet::constant]
+		type MaxEndorsementsPerDid: Get<u32>;
+
+		/// The maximum number of `alsoKnownAs` URIs that can be stored under a
+		/// DID.
+		#[pallet::constant]
+		type MaxAlsoKnownAs: Get<u32>;
+
+		/// The maximum length of an `alsoKnownAs` URI.
+		#[pallet::constant]
+		type MaxAlsoKnownAsUriLength: Get<u32>;
+
+		/// The maximum size, in bytes, of a DID Document, i.e., of the encoded
+		/// DID details together with its service endpoints and its
+		/// `alsoKnownAs` URIs.
+		#[pallet::constant]
+		type MaxDidDocumentSize: Get<u32>;
+
+		/// The maximum number of signatories of a multisig account that can be
+		/// set as DID authentication key.
+		#[pallet::constant]
+		type MaxMultisigSignatories: Get<u32>;
+
+		/// The key algorithms allowed for each key relationship, checked when
+		/// a DID is created and when a key is added to it. Keys already stored
+		/// are not affected by changes to the policy.
+		type AllowedKeyTypes: Contains<(DidKeyRelationship, DidKeyType)>;
+
+		/// The number of DID management operations after the creation of a
+		/// DID whose transaction fees can be paid by the runtime instead of
+		/// the submitter. Set to `0` to disable free trial operations.
+		#[pallet::constant]
+		type FreeTrialOperations: Get<u32>;
+
+		/// The block number until which signatures over the bare SCALE-encoded
+		/// DID operation are still accepted, for signers that do not support
+		/// the canonical signing payload yet. From this block on, only
+		/// signatures over the canonical signing payload are valid.
+		#[pallet::constant]
+		type LegacySignatureDeadline: Get<BlockNumberFor<Self>>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 
@@ -326,10 +408,11 @@ pub mod pallet {
 
 	/// DIDs stored on chain.
 	///
-	/// It maps from a DID identifier to the DID details.
+	/// It maps from a DID identifier to the DID details. The number of DIDs
+	/// is tracked in the map counter.
 	#[pallet::storage]
 	#[pallet::getter(fn get_did)]
-	pub type Did<T> = StorageMap<_, Blake2_128Concat, DidIdentifierOf<T>, DidDetails<T>>;
+	pub type Did<T> = CountedStorageMap<_, Blake2_128Concat, DidIdentifierOf<T>, DidDetails<T>>;
 
 	/// Service endpoints associated with DIDs.
 	///
@@ -339,7 +422,30 @@ pub mod pallet {
 	pub type ServiceEndpoints<T> =
 		StorageDoubleMap<_, Twox64Concat, DidIdentifierOf<T>, Blake2_128Concat, ServiceEndpointId<T>, DidEndpoint<T>>;
 
-	/// Counter of service endpoints for each DID.
+	/// Service endpoints associated with DIDs whose descriptors are stored
+	/// off-chain.
+	///
+	/// It maps from (DID identifier, service ID) to the hashed service details.
+	#[pallet::storage]
+	#[pallet::getter(fn get_hashed_service_endpoints)]
+	pub type HashedServiceEndpoints<T> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		DidIdentifierOf<T>,
+		Blake2_128Concat,
+		ServiceEndpointId<T>,
+		HashedDidEndpoint<T>,
+	>;
+
+	/// The `alsoKnownAs` URIs of each DID, i.e., other identifiers of the
+	/// DID subject, in the order they were added.
+	///
+	/// It maps from a DID identifier to the URIs.
+	#[pallet::storage]
+	#[pallet::getter(fn get_also_known_as)]
+	pub type AlsoKnownAs<T> = StorageMap<_, Blake2_128Concat, DidIdentifierOf<T>, AlsoKnownAsOf<T>, ValueQuery>;
+
+	/// Counter of service endpoints for each DID, both inline and hashed.
 	///
 	/// It maps from (DID identifier) to a 32-bit counter.
 	#[pallet::storage]
@@ -354,8 +460,79 @@ pub mod pallet {
 	#[pallet::getter(fn get_deleted_did)]
 	pub(crate) type DidBlacklist<T> = StorageMap<_, Blake2_128Concat, DidIdentifierOf<T>, ()>;
 
+	/// The number of free trial operations left for each DID.
+	///
+	/// It maps from a DID identifier to the number of remaining operations.
+	/// DIDs without free trial operations left are not stored.
+	#[pallet::storage]
+	#[pallet::getter(fn get_remaining_free_trial_operations)]
+	pub type RemainingFreeTrialOperations<T> = StorageMap<_, Blake2_128Concat, DidIdentifierOf<T>, u32, ValueQuery>;
+
+	/// The history of changes to the keys of DIDs, which allows to verify
+	/// signatures made in the past against the keys valid at that time.
+	///
+	/// It maps from (DID identifier, key ID) to the latest changes of the key,
+	/// oldest first. The history is removed when the DID is deleted.
+	#[pallet::storage]
+	#[pallet::getter(fn get_key_history)]
+	pub type KeyHistory<T> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		DidIdentifierOf<T>,
+		Blake2_128Concat,
+		KeyIdOf<T>,
+		KeyHistoryOf<T>,
+		ValueQuery,
+	>;
+
+	/// The keys removed from DIDs whose history is kept in [`KeyHistory`]
+	/// and [`HistoricalPublicKeys`].
+	///
+	/// It maps from a DID identifier to the IDs of its removed keys, oldest
+	/// removal first. Each of them is charged `KeyHistoryDeposit`. When the
+	/// maximum number of removed keys is reached, the history of the oldest
+	/// removed key is dropped.
+	#[pallet::storage]
+	#[pallet::getter(fn get_removed_keys)]
+	pub type RemovedKeys<T> = StorageMap<_, Blake2_128Concat, DidIdentifierOf<T>, RemovedKeysOf<T>, ValueQuery>;
+
+	/// The public keys of the keys in [`KeyHistory`], so that signatures can
+	/// be verified after the keys have been removed.
+	///
+	/// It maps from (DID identifier, key ID) to the public key. A public key
+	/// is removed together with the history of its key.
+	#[pallet::storage]
+	pub type HistoricalPublicKeys<T> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		DidIdentifierOf<T>,
+		Blake2_128Concat,
+		KeyIdOf<T>,
+		DidPublicKey<AccountIdOf<T>>,
+	>;
+
+	/// The endorsements given by DIDs to other DIDs.
+	///
+	/// It maps from (endorsed DID identifier, endorser DID identifier) to the
+	/// endorsement details. Endorsements are kept when either DID is deleted.
+	#[pallet::storage]
+	#[pallet::getter(fn get_endorsement)]
+	pub type Endorsements<T> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		DidIdentifierOf<T>,
+		Blake2_128Concat,
+		DidIdentifierOf<T>,
+		EndorsementDetailsOf<T>,
+	>;
+
+	/// Counter of the endorsements given by each DID.
+	///
+	/// It maps from (endorser DID identifier) to a 32-bit counter.
+	#[pallet::storage]
+	pub(crate) type EndorsementsCount<T> = StorageMap<_, Blake2_128Concat, DidIdentifierOf<T>, u32, ValueQuery>;
+
 	#[pallet::event]
-	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
 		/// A new DID has been created.
 		/// \[transaction signer, DID identifier\]
@@ -369,6 +546,18 @@ pub mod pallet {
 		/// A DID-authorised call has been executed.
 		/// \[DID caller, dispatch result\]
 		DidCallDispatched(DidIdentifierOf<T>, DispatchResult),
+		/// A DID has anchored the hash of some content on chain.
+		/// \[DID identifier, content hash, block number\]
+		DidRemarked(DidIdentifierOf<T>, T::Hash, BlockNumberFor<T>),
+		/// A DID has endorsed another DID.
+		/// \[endorser DID identifier, endorsed DID identifier, context hash\]
+		DidEndorsed(DidIdentifierOf<T>, DidIdentifierOf<T>, T::Hash),
+		/// A DID has revoked its endorsement of another DID.
+		/// \[endorser DID identifier, endorsed DID identifier\]
+		EndorsementRevoked(DidIdentifierOf<T>, DidIdentifierOf<T>),
+		/// A light DID has been promoted to a full DID.
+		/// \[DID identifier\]
+		LightDidPromoted(DidIdentifierOf<T>),
 	}
 
 	#[pallet::error]
@@ -436,6 +625,32 @@ pub mod pallet {
 		/// The number of service endpoints stored under the DID is larger than
 		/// the number of endpoints to delete.
 		MaxStoredEndpointsCountExceeded,
+		/// The multisig signatories or threshold are invalid, e.g., the
+		/// threshold is larger than the number of signatories.
+		InvalidMultisig,
+		/// The key algorithm is not allowed for the key relationship.
+		KeyTypeNotAllowed,
+		/// A DID cannot endorse itself.
+		SelfEndorsement,
+		/// The maximum number of endorsements given by the DID has been
+		/// reached.
+		MaxEndorsementsExceeded,
+		/// The DID has not endorsed the given DID.
+		EndorsementNotFound,
+		/// The details of a light DID promotion contain keys that a light DID
+		/// cannot have.
+		InvalidLightDidDetails,
+		/// The maximum number of `alsoKnownAs` URIs for the DID has been
+		/// reached.
+		MaxAlsoKnownAsExceeded,
+		/// The `alsoKnownAs` URI is already present for the given DID.
+		AlsoKnownAsAlreadyExists,
+		/// The `alsoKnownAs` URI is not present under the given DID.
+		AlsoKnownAsNotFound,
+		/// The `alsoKnownAs` URI is not a valid URI.
+		InvalidAlsoKnownAs,
+		/// The DID Document would be larger than the maximum size allowed.
+		MaxDidDocumentSizeExceeded,
 		/// An error that is not supposed to take place, yet it happened.
 		Internal,
 	}
@@ -508,6 +723,94 @@ pub mod pallet {
 		}
 	}
 
+	kilt_support::impl_identity_event_deposit!();
+
+	impl<T: Config> Pallet<T> {
+		/// The weight of creating a DID with the given details, for the most
+		/// expensive authentication key type.
+		pub(crate) fn creation_weight(details: &DidCreationDetailsOf<T>) -> Weight {
+			let new_key_agreement_keys = details.new_key_agreement_keys.len().saturated_into::<u32>();
+			// We only consider the number of new endpoints.
+			let new_services_count = details.new_service_details.len().saturated_into::<u32>();
+
+			let ed25519_weight =
+				<T as pallet::Config>::WeightInfo::create_ed25519_keys(new_key_agreement_keys, new_services_count);
+			let sr25519_weight =
+				<T as pallet::Config>::WeightInfo::create_sr25519_keys(new_key_agreement_keys, new_services_count);
+			let ecdsa_weight =
+				<T as pallet::Config>::WeightInfo::create_ecdsa_keys(new_key_agreement_keys, new_services_count);
+
+			ed25519_weight.max(sr25519_weight).max(ecdsa_weight)
+		}
+
+		/// Estimate the size and the deposit of the DID Document of the given
+		/// DID after applying the given operations in order, without changing
+		/// any state.
+		///
+		/// Exceeding [`Config::MaxDidDocumentSize`] is not an error, so that
+		/// clients can check the estimate before submitting the operations.
+		pub fn estimate_document_size(
+			did_subject: &DidIdentifierOf<T>,
+			operations: Vec<DidDocumentOperationOf<T>>,
+		) -> Result<DidDocumentSizeEstimate<BalanceOf<T>>, DidDocumentEstimateError> {
+			let did_details = Did::<T>::get(did_subject).ok_or(DidDocumentEstimateError::NotFound)?;
+			let mut document = DidDocument::new(did_subject, did_details);
+
+			let block_number = frame_system::Pallet::<T>::block_number();
+			operations.into_iter().enumerate().try_for_each(|(index, operation)| {
+				document
+					.apply(operation, block_number)
+					.map_err(|_| DidDocumentEstimateError::InvalidOperation(index.saturated_into()))
+			})?;
+
+			Ok(DidDocumentSizeEstimate {
+				size: document.size(),
+				max_size: T::MaxDidDocumentSize::get(),
+				deposit: document.deposit(),
+			})
+		}
+
+		/// Check that the DID Document made of the given DID details and of
+		/// the service endpoints and `alsoKnownAs` URIs currently stored under
+		/// the DID is not larger than [`Config::MaxDidDocumentSize`].
+		///
+		/// Only operations that can grow the DID Document are checked, so that
+		/// a DID Document that exceeds a lowered limit can still be reduced.
+		pub(crate) fn ensure_document_size_within_limit(
+			did_subject: &DidIdentifierOf<T>,
+			did_details: &DidDetails<T>,
+		) -> Result<(), Error<T>> {
+			let document = DidDocument::new(did_subject, did_details.clone());
+			ensure!(
+				document.size() <= T::MaxDidDocumentSize::get(),
+				Error::<T>::MaxDidDocumentSizeExceeded
+			);
+			Ok(())
+		}
+
+		/// The weight of verifying the signature of a DID-authorized call of
+		/// the given encoded length, for the most expensive key type, excluding
+		/// the weight of the call itself.
+		///
+		/// Until the legacy signature deadline, an invalid signature over the
+		/// canonical signing payload is verified again over the bare encoded
+		/// operation, so the weight of the second verification is included.
+		pub(crate) fn max_did_call_signature_weight(operation_length: u32) -> Weight {
+			let signature_weight = <T as pallet::Config>::WeightInfo::submit_did_call_ed25519_key()
+				.max(<T as pallet::Config>::WeightInfo::submit_did_call_sr25519_key())
+				.max(<T as pallet::Config>::WeightInfo::submit_did_call_ecdsa_key());
+			let legacy_signature_weight =
+				<T as pallet::Config>::WeightInfo::signature_verification_ed25519(operation_length)
+					.max(<T as pallet::Config>::WeightInfo::signature_verification_sr25519(
+						operation_length,
+					))
+					.max(<T as pallet::Config>::WeightInfo::signature_verification_ecdsa(
+						operation_length,
+					));
+			signature_weight.saturating_add(legacy_signature_weight)
+		}
+	}
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T>
 	where
@@ -545,60 +848,14 @@ pub mod pallet {
 		///   (with N new service endpoints), DidEndpointsCount
 		/// # </weight>
 		#[pallet::call_index(0)]
-		#[pallet::weight({
-			let new_key_agreement_keys = details.new_key_agreement_keys.len().saturated_into::<u32>();
-			// We only consider the number of new endpoints.
-			let new_services_count = details.new_service_details.len().saturated_into::<u32>();
-
-			let ed25519_weight = <T as pallet::Config>::WeightInfo::create_ed25519_keys(
-				new_key_agreement_keys,
-				new_services_count,
-			);
-			let sr25519_weight = <T as pallet::Config>::WeightInfo::create_sr25519_keys(
-				new_key_agreement_keys,
-				new_services_count,
-			);
-			let ecdsa_weight = <T as pallet::Config>::WeightInfo::create_ecdsa_keys(
-				new_key_agreement_keys,
-				new_services_count,
-			);
-
-			ed25519_weight.max(sr25519_weight).max(ecdsa_weight)
-		})]
+		#[pallet::weight(Pallet::<T>::creation_weight(details))]
 		pub fn create(
 			origin: OriginFor<T>,
 			details: Box<DidCreationDetailsOf<T>>,
 			signature: DidSignature,
 		) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
-			let did_identifier = details.did.clone();
-
-			ensure!(sender == details.submitter, BadOrigin);
-
-			let account_did_auth_key = did_identifier
-				.verify_and_recover_signature(&details.encode(), &signature)
-				.map_err(Error::<T>::from)?;
-
-			// Validate all the size constraints for the service endpoints.
-			let input_service_endpoints = details.new_service_details.clone();
-			service_endpoints_utils::validate_new_service_endpoints(&input_service_endpoints)
-				.map_err(Error::<T>::from)?;
-
-			input_service_endpoints.iter().for_each(|service| {
-				ServiceEndpoints::<T>::insert(&did_identifier, &service.id, service.clone());
-			});
-			DidEndpointsCount::<T>::insert(&did_identifier, input_service_endpoints.len().saturated_into::<u32>());
-
-			let mut did_entry =
-				DidDetails::new_with_creation_details(*details, account_did_auth_key).map_err(Error::<T>::from)?;
-			did_entry.deposit.amount =
-				did_entry.calculate_deposit(input_service_endpoints.len().saturated_into::<u32>());
-
-			log::debug!("Creating DID {:?}", &did_identifier);
-
-			Self::try_insert_did(did_identifier, did_entry, sender)?;
-
-			Ok(())
+			Self::do_create(sender, *details, signature, false)
 		}
 
 		/// Update the DID authentication key.
@@ -624,6 +881,10 @@ pub mod pallet {
 			new_key: DidVerificationKey<AccountIdOf<T>>,
 		) -> DispatchResult {
 			let did_subject = T::EnsureOrigin::ensure_origin(origin)?.subject();
+			Self::ensure_key_type_allowed(
+				DidKeyRelationship::Verification(DidVerificationKeyRelationship::Authentication),
+				new_key.key_type(),
+			)?;
 			let mut did_details = Did::<T>::get(&did_subject).ok_or(Error::<T>::NotFound)?;
 
 			log::debug!(
@@ -636,6 +897,7 @@ pub mod pallet {
 				.update_authentication_key(new_key, frame_system::Pallet::<T>::block_number())
 				.map_err(Error::<T>::from)?;
 
+			Self::ensure_document_size_within_limit(&did_subject, &did_details)?;
 			Self::try_update_did(&did_subject, did_details)?;
 			log::debug!("Authentication key set");
 
@@ -663,6 +925,10 @@ pub mod pallet {
 		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_ed25519_delegation_key().max(<T as pallet::Config>::WeightInfo::set_sr25519_delegation_key()).max(<T as pallet::Config>::WeightInfo::set_ecdsa_delegation_key()))]
 		pub fn set_delegation_key(origin: OriginFor<T>, new_key: DidVerificationKey<AccountIdOf<T>>) -> DispatchResult {
 			let did_subject = T::EnsureOrigin::ensure_origin(origin)?.subject();
+			Self::ensure_key_type_allowed(
+				DidKeyRelationship::Verification(DidVerificationKeyRelationship::CapabilityDelegation),
+				new_key.key_type(),
+			)?;
 			let mut did_details = Did::<T>::get(&did_subject).ok_or(Error::<T>::NotFound)?;
 
 			log::debug!("Setting new delegation key {:?} for DID {:?}", &new_key, &did_subject);
@@ -670,6 +936,7 @@ pub mod pallet {
 				.update_delegation_key(new_key, frame_system::Pallet::<T>::block_number())
 				.map_err(Error::<T>::from)?;
 
+			Self::ensure_document_size_within_limit(&did_subject, &did_details)?;
 			Self::try_update_did(&did_subject, did_details)?;
 			log::debug!("Delegation key set");
 
@@ -731,6 +998,10 @@ pub mod pallet {
 			new_key: DidVerificationKey<AccountIdOf<T>>,
 		) -> DispatchResult {
 			let did_subject = T::EnsureOrigin::ensure_origin(origin)?.subject();
+			Self::ensure_key_type_allowed(
+				DidKeyRelationship::Verification(DidVerificationKeyRelationship::AssertionMethod),
+				new_key.key_type(),
+			)?;
 			let mut did_details = Did::<T>::get(&did_subject).ok_or(Error::<T>::NotFound)?;
 
 			log::debug!("Setting new attestation key {:?} for DID {:?}", &new_key, &did_subject);
@@ -738,6 +1009,7 @@ pub mod pallet {
 				.update_attestation_key(new_key, frame_system::Pallet::<T>::block_number())
 				.map_err(Error::<T>::from)?;
 
+			Self::ensure_document_size_within_limit(&did_subject, &did_details)?;
 			Self::try_update_did(&did_subject, did_details)?;
 			log::debug!("Attestation key set");
 
@@ -794,6 +1066,7 @@ pub mod pallet {
 		#[pallet::weight(<T as pallet::Config>::WeightInfo::add_ed25519_key_agreement_key().max(<T as pallet::Config>::WeightInfo::add_sr25519_key_agreement_key()).max(<T as pallet::Config>::WeightInfo::add_ecdsa_key_agreement_key()))]
 		pub fn add_key_agreement_key(origin: OriginFor<T>, new_key: DidEncryptionKey) -> DispatchResult {
 			let did_subject = T::EnsureOrigin::ensure_origin(origin)?.subject();
+			Self::ensure_key_type_allowed(DidKeyRelationship::Encryption, new_key.key_type())?;
 			let mut did_details = Did::<T>::get(&did_subject).ok_or(Error::<T>::NotFound)?;
 
 			log::debug!("Adding new key agreement key {:?} for DID {:?}", &new_key, &did_subject);
@@ -801,6 +1074,7 @@ pub mod pallet {
 				.add_key_agreement_key(new_key, frame_system::Pallet::<T>::block_number())
 				.map_err(Error::<T>::from)?;
 
+			Self::ensure_document_size_within_limit(&did_subject, &did_details)?;
 			Self::try_update_did(&did_subject, did_details)?;
 			log::debug!("Key agreement key set");
 
@@ -846,7 +1120,8 @@ pub mod pallet {
 		///
 		/// # <weight>
 		/// Weight: O(1)
-		/// - Reads: [Origin Account], Did, ServiceEndpoints, DidEndpointsCount
+		/// - Reads: [Origin Account], Did, ServiceEndpoints,
+		///   HashedServiceEndpoints, DidEndpointsCount
 		/// - Writes: Did, ServiceEndpoints, DidEndpointsCount
 		/// # </weight>
 		#[pallet::call_index(8)]
@@ -869,6 +1144,10 @@ pub mod pallet {
 				Error::<T>::MaxNumberOfServicesExceeded
 			);
 
+			ensure!(
+				!HashedServiceEndpoints::<T>::contains_key(&did_subject, &service_endpoint.id),
+				Error::<T>::ServiceAlreadyExists
+			);
 			ServiceEndpoints::<T>::try_mutate(
 				&did_subject,
 				service_endpoint.id.clone(),
@@ -880,6 +1159,7 @@ pub mod pallet {
 			)?;
 			DidEndpointsCount::<T>::insert(&did_subject, currently_stored_endpoints_count.saturating_add(1));
 
+			Self::ensure_document_size_within_limit(&did_subject, &did_details)?;
 			Self::try_update_did(&did_subject, did_details)?;
 
 			Self::deposit_event(Event::DidUpdated(did_subject));
@@ -887,7 +1167,8 @@ pub mod pallet {
 			Ok(())
 		}
 
-		/// Remove the service with the provided ID from the DID.
+		/// Remove the service with the provided ID from the DID, regardless of
+		/// whether it is an inline or a hashed service endpoint.
 		///
 		/// The dispatch origin must be a DID origin proxied via the
 		/// `submit_did_call` extrinsic.
@@ -896,8 +1177,10 @@ pub mod pallet {
 		///
 		/// # <weight>
 		/// Weight: O(1)
-		/// - Reads: [Origin Account], ServiceEndpoints, DidEndpointsCount
-		/// - Writes: Did, ServiceEndpoints, DidEndpointsCount
+		/// - Reads: [Origin Account], ServiceEndpoints, HashedServiceEndpoints,
+		///   DidEndpointsCount
+		/// - Writes: Did, ServiceEndpoints, HashedServiceEndpoints,
+		///   DidEndpointsCount
 		/// # </weight>
 		#[pallet::call_index(9)]
 		#[pallet::weight(<T as pallet::Config>::WeightInfo::remove_service_endpoint())]
@@ -906,10 +1189,11 @@ pub mod pallet {
 
 			let did_details = Did::<T>::get(&did_subject).ok_or(Error::<T>::NotFound)?;
 
-			ensure!(
-				ServiceEndpoints::<T>::take(&did_subject, &service_id).is_some(),
-				Error::<T>::ServiceNotFound
-			);
+			// Service IDs are unique across inline and hashed service endpoints, so at most
+			// one of them is removed.
+			let is_removed = ServiceEndpoints::<T>::take(&did_subject, &service_id).is_some()
+				|| HashedServiceEndpoints::<T>::take(&did_subject, &service_id).is_some();
+			ensure!(is_removed, Error::<T>::ServiceNotFound);
 
 			// Decrease the endpoints counter or delete the entry if it reaches 0.
 			DidEndpointsCount::<T>::mutate_exists(&did_subject, |existing_endpoint_count| {
@@ -1012,6 +1296,13 @@ pub mod pallet {
 		/// signature, and the block number the operation was targeting for
 		/// inclusion, when it was created and signed.
 		///
+		/// The operation signature should be generated over its canonical
+		/// signing payload, as returned by
+		/// [`did_details::did_operation_signing_payload`], which binds it to
+		/// this chain and runtime version. Signatures over the bare
+		/// SCALE-encoded operation are only accepted until
+		/// [`Config::LegacySignatureDeadline`].
+		///
 		/// In case the signature is incorrect, the nonce is not valid, the
 		/// required key is not present for the specified DID, or the block
 		/// specified is too old the verification fails and the call is not
@@ -1036,11 +1327,8 @@ pub mod pallet {
 		#[pallet::call_index(12)]
 		#[pallet::weight({
 			let di = did_call.call.get_dispatch_info();
-			let max_sig_weight = <T as pallet::Config>::WeightInfo::submit_did_call_ed25519_key()
-			.max(<T as pallet::Config>::WeightInfo::submit_did_call_sr25519_key())
-			.max(<T as pallet::Config>::WeightInfo::submit_did_call_ecdsa_key());
-
-			(max_sig_weight.saturating_add(di.weight), di.class)
+			let operation_length = did_call.encoded_size().saturated_into::<u32>();
+			(Pallet::<T>::max_did_call_signature_weight(operation_length).saturating_add(di.weight), di.class)
 		})]
 		pub fn submit_did_call(
 			origin: OriginFor<T>,
@@ -1048,47 +1336,7 @@ pub mod pallet {
 			signature: DidSignature,
 		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
-			ensure!(did_call.submitter == who, Error::<T>::BadDidOrigin);
-
-			let did_identifier = did_call.did.clone();
-
-			// Compute the right DID verification key to use to verify the operation
-			// signature
-			let verification_key_relationship = did_call
-				.call
-				.derive_verification_key_relationship()
-				.map_err(Error::<T>::from)?;
-
-			// Wrap the operation in the expected structure, specifying the key retrieved
-			let wrapped_operation = DidAuthorizedCallOperationWithVerificationRelationship {
-				operation: *did_call,
-				verification_key_relationship,
-			};
-
-			Self::verify_did_operation_signature_and_increase_nonce(&wrapped_operation, &signature)
-				.map_err(Error::<T>::from)?;
-
-			log::debug!("Dispatch call from DID {:?}", did_identifier);
-
-			// Dispatch the referenced [Call] instance and return its result
-			let DidAuthorizedCallOperation { did, call, .. } = wrapped_operation.operation;
-
-			#[cfg(not(feature = "runtime-benchmarks"))]
-			let result = call.dispatch(
-				DidRawOrigin {
-					id: did,
-					submitter: who,
-				}
-				.into(),
-			);
-			#[cfg(feature = "runtime-benchmarks")]
-			let result = call.dispatch(RawOrigin::Signed(did).into());
-
-			let dispatch_event_payload = result.map(|_| ()).map_err(|e| e.error);
-
-			Self::deposit_event(Event::DidCallDispatched(did_identifier, dispatch_event_payload));
-
-			result
+			Self::do_submit_did_call(who, *did_call, signature)
 		}
 
 		/// Changes the deposit owner.
@@ -1216,6 +1464,10 @@ pub mod pallet {
 		) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
 			let did_identifier: DidIdentifierOf<T> = sender.clone().into();
+			Self::ensure_key_type_allowed(
+				DidKeyRelationship::Verification(DidVerificationKeyRelationship::Authentication),
+				authentication_key.key_type(),
+			)?;
 
 			log::debug!("Creating DID {:?}", &did_identifier);
 
@@ -1227,88 +1479,829 @@ pub mod pallet {
 
 			Ok(())
 		}
-	}
 
-	impl<T: Config> Pallet<T>
-	where
-		T::AccountId: AsRef<[u8; 32]> + From<[u8; 32]>,
-	{
-		/// Try creating a DID.
+		/// Set the DID authentication key to the multisig account of the
+		/// given signatories and threshold.
 		///
-		/// # Errors
+		/// The multisig account is derived the same way `pallet_multisig`
+		/// does, so that DID calls can be authorized by the multisig via the
+		/// `dispatch_as` extrinsic once the threshold of signatories approved
+		/// it. This enables organizational DIDs without threshold signatures.
 		///
-		/// * When the DID was deleted, this function returns a `AlreadyDeleted`
-		///   error.
-		/// * When the DID already exists, this function returns a
-		///   `AlreadyExists` error.
-		/// * When the `sender` doesn't have enough free balance, this function
-		///   returns a `UnableToPayFees` error.
-		pub fn try_insert_did(
-			did_identifier: DidIdentifierOf<T>,
-			did_entry: DidDetails<T>,
-			sender: AccountIdOf<T>,
+		/// The signatories are sorted and must not contain duplicates, and
+		/// there can be at most `MaxMultisigSignatories` of them. The
+		/// threshold must be at least 2 and at most the number of
+		/// signatories.
+		///
+		/// The dispatch origin must be a DID origin proxied via the
+		/// `submit_did_call` extrinsic.
+		///
+		/// Emits `DidUpdated`.
+		///
+		/// # <weight>
+		/// Weight: O(S) where S is the number of signatories.
+		/// - Reads: [Origin Account], Did
+		/// - Writes: Did
+		/// # </weight>
+		#[pallet::call_index(17)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_multisig_authentication_key(signatories.len().saturated_into::<u32>()))]
+		pub fn set_multisig_authentication_key(
+			origin: OriginFor<T>,
+			signatories: BoundedVec<AccountIdOf<T>, T::MaxMultisigSignatories>,
+			threshold: u16,
 		) -> DispatchResult {
-			// Make sure that DIDs cannot be created again after they have been deleted.
-			ensure!(
-				!DidBlacklist::<T>::contains_key(&did_identifier),
-				Error::<T>::AlreadyDeleted
-			);
-
-			// There has to be no other DID with the same identifier already saved on chain,
-			// otherwise generate a AlreadyExists error.
-			ensure!(!Did::<T>::contains_key(&did_identifier), Error::<T>::AlreadyExists);
-
-			// Collect fee
-			let imbalance: CreditOf<T> = <T::Currency as Balanced<AccountIdOf<T>>>::withdraw(
-				&did_entry.deposit.owner,
-				T::Fee::get(),
-				Precision::Exact,
-				Preservation::Preserve,
-				Fortitude::Polite,
-			)
-			.map_err(|_| Error::<T>::UnableToPayFees)?;
-			T::FeeCollector::on_unbalanced(imbalance);
+			let did_subject = T::EnsureOrigin::ensure_origin(origin)?.subject();
+			let mut did_details = Did::<T>::get(&did_subject).ok_or(Error::<T>::NotFound)?;
 
-			DidDepositCollector::<T>::create_deposit(sender.clone(), did_entry.deposit.amount)
-				.map_err(|_| Error::<T>::UnableToPayFees)?;
+			let multisig_account = utils::multisig_account_id::<AccountIdOf<T>>(signatories.into_inner(), threshold)
+				.ok_or(Error::<T>::InvalidMultisig)?;
+			Self::ensure_key_type_allowed(
+				DidKeyRelationship::Verification(DidVerificationKeyRelationship::Authentication),
+				DidKeyType::Account,
+			)?;
 
-			<T as Config>::BalanceMigrationManager::exclude_key_from_migration(&Did::<T>::hashed_key_for(
-				&did_identifier,
-			));
+			log::debug!(
+				"Setting multisig account {:?} as authentication key for DID {:?}",
+				&multisig_account,
+				&did_subject
+			);
 
-			Did::<T>::insert(&did_identifier, did_entry);
+			did_details
+				.update_authentication_key(
+					DidVerificationKey::Account(multisig_account),
+					frame_system::Pallet::<T>::block_number(),
+				)
+				.map_err(Error::<T>::from)?;
 
-			Pallet::<T>::deposit_event(Event::DidCreated(sender, did_identifier));
+			Self::ensure_document_size_within_limit(&did_subject, &did_details)?;
+			Self::try_update_did(&did_subject, did_details)?;
 
+			Self::deposit_event(Event::DidUpdated(did_subject));
 			Ok(())
 		}
 
-		/// Try updating the DID.
+		/// Add a new hashed service endpoint under the given DID.
 		///
-		/// # Errors
+		/// Only the hash of the endpoint descriptor and its tag are stored on
+		/// chain, while the descriptor is served off-chain. Hashed service
+		/// endpoints share the ID namespace, the maximum count and the deposit
+		/// of the inline service endpoints.
 		///
-		/// This can fail when the deposit owner doesn't have enough free
-		/// balance.
-		pub fn try_update_did(did_identifier: &DidIdentifierOf<T>, mut did_details: DidDetails<T>) -> DispatchResult {
-			Self::try_update_deposit(&mut did_details, did_identifier)?;
-			Did::<T>::insert(did_identifier, did_details);
-
-			Ok(())
-		}
+		/// The dispatch origin must be a DID origin proxied via the
+		/// `submit_did_call` extrinsic.
+		///
+		/// Emits `DidUpdated`.
+		///
+		/// # <weight>
+		/// Weight: O(1)
+		/// - Reads: [Origin Account], Did, ServiceEndpoints,
+		///   HashedServiceEndpoints, DidEndpointsCount
+		/// - Writes: Did, HashedServiceEndpoints, DidEndpointsCount
+		/// # </weight>
+		#[pallet::call_index(18)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::add_hashed_service_endpoint())]
+		pub fn add_hashed_service_endpoint(
+			origin: OriginFor<T>,
+			service_endpoint: HashedDidEndpoint<T>,
+		) -> DispatchResult {
+			let did_subject = T::EnsureOrigin::ensure_origin(origin)?.subject();
 
-		fn try_update_deposit(did_details: &mut DidDetails<T>, did_subject: &DidIdentifierOf<T>) -> DispatchResult {
-			let endpoint_count = DidEndpointsCount::<T>::get(did_subject);
-			let new_required_deposit = did_details.calculate_deposit(endpoint_count);
-			let hashed_key = Did::<T>::hashed_key_for(did_subject);
+			service_endpoint
+				.validate_against_constraints()
+				.map_err(Error::<T>::from)?;
 
-			let is_key_migrated = <T as Config>::BalanceMigrationManager::is_key_migrated(&hashed_key);
+			// Verify that the DID is present.
+			let did_details = Did::<T>::get(&did_subject).ok_or(Error::<T>::NotFound)?;
 
-			match new_required_deposit.cmp(&did_details.deposit.amount) {
-				core::cmp::Ordering::Greater => {
-					let deposit_to_reserve = new_required_deposit.saturating_sub(did_details.deposit.amount);
+			let currently_stored_endpoints_count = DidEndpointsCount::<T>::get(&did_subject);
 
-					if is_key_migrated {
-						DidDepositCollector::<T>::create_deposit(
+			// Verify that there are less than the maximum limit of services stored.
+			ensure!(
+				currently_stored_endpoints_count < T::MaxNumberOfServicesPerDid::get(),
+				Error::<T>::MaxNumberOfServicesExceeded
+			);
+
+			ensure!(
+				!ServiceEndpoints::<T>::contains_key(&did_subject, &service_endpoint.id),
+				Error::<T>::ServiceAlreadyExists
+			);
+			HashedServiceEndpoints::<T>::try_mutate(
+				&did_subject,
+				service_endpoint.id.clone(),
+				|existing_service| -> Result<(), Error<T>> {
+					ensure!(existing_service.is_none(), Error::<T>::ServiceAlreadyExists);
+					*existing_service = Some(service_endpoint);
+					Ok(())
+				},
+			)?;
+			DidEndpointsCount::<T>::insert(&did_subject, currently_stored_endpoints_count.saturating_add(1));
+
+			Self::ensure_document_size_within_limit(&did_subject, &did_details)?;
+			Self::try_update_did(&did_subject, did_details)?;
+
+			Self::deposit_event(Event::DidUpdated(did_subject));
+
+			Ok(())
+		}
+
+		/// Anchor the hash of some arbitrary data on chain, bound to the DID
+		/// that authorised the operation and to the current block.
+		///
+		/// Only the hash of the data is part of the emitted event, which is
+		/// indexed by the hash so that it can be looked up by anyone holding
+		/// the original data. No deposit is taken, as nothing is stored.
+		///
+		/// The dispatch origin must be a DID origin proxied via the
+		/// `submit_did_call` extrinsic.
+		///
+		/// Emits `DidRemarked`.
+		///
+		/// # <weight>
+		/// Weight: O(L) where L is the length of the data
+		/// - Reads: [Origin Account]
+		/// # </weight>
+		#[pallet::call_index(19)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::did_remark(data.len().saturated_into()))]
+		pub fn did_remark(origin: OriginFor<T>, data: Vec<u8>) -> DispatchResult {
+			let did_subject = T::EnsureOrigin::ensure_origin(origin)?.subject();
+
+			let content_hash = T::Hashing::hash(&data);
+			let event = <T as Config>::RuntimeEvent::from(Event::DidRemarked(
+				did_subject,
+				content_hash,
+				frame_system::Pallet::<T>::block_number(),
+			));
+			frame_system::Pallet::<T>::deposit_event_indexed(&[content_hash], event.into());
+
+			Ok(())
+		}
+
+		/// Publicly endorse another DID, e.g., to vouch for the DID of an
+		/// employee of the organization controlling the origin DID.
+		///
+		/// The endorsement is bound to the hash of its context, which is
+		/// replaced if the DID has already been endorsed. No deposit is taken,
+		/// but the number of endorsements each DID can give is bounded by
+		/// `MaxEndorsementsPerDid`.
+		///
+		/// The dispatch origin must be a DID origin proxied via the
+		/// `submit_did_call` extrinsic.
+		///
+		/// Emits `DidEndorsed`.
+		///
+		/// # <weight>
+		/// Weight: O(1)
+		/// - Reads: [Origin Account], Did, Endorsements, EndorsementsCount
+		/// - Writes: Endorsements, EndorsementsCount
+		/// # </weight>
+		#[pallet::call_index(20)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::endorse())]
+		pub fn endorse(origin: OriginFor<T>, did: DidIdentifierOf<T>, context_hash: T::Hash) -> DispatchResult {
+			let endorser = T::EnsureOrigin::ensure_origin(origin)?.subject();
+
+			ensure!(endorser != did, Error::<T>::SelfEndorsement);
+			ensure!(Did::<T>::contains_key(&did), Error::<T>::NotFound);
+
+			let endorsement = EndorsementDetails {
+				context_hash,
+				created_at: frame_system::Pallet::<T>::block_number(),
+			};
+			// Replacing the context of an existing endorsement does not count towards
+			// the limit.
+			if !Endorsements::<T>::contains_key(&did, &endorser) {
+				let endorsements_count = EndorsementsCount::<T>::get(&endorser);
+				ensure!(
+					endorsements_count < T::MaxEndorsementsPerDid::get(),
+					Error::<T>::MaxEndorsementsExceeded
+				);
+				EndorsementsCount::<T>::insert(&endorser, endorsements_count.saturating_add(1));
+			}
+			Endorsements::<T>::insert(&did, &endorser, endorsement);
+
+			Self::deposit_event(Event::DidEndorsed(endorser, did, context_hash));
+
+			Ok(())
+		}
+
+		/// Revoke the endorsement of another DID, which can be deleted.
+		///
+		/// The dispatch origin must be a DID origin proxied via the
+		/// `submit_did_call` extrinsic.
+		///
+		/// Emits `EndorsementRevoked`.
+		///
+		/// # <weight>
+		/// Weight: O(1)
+		/// - Reads: [Origin Account], Endorsements, EndorsementsCount
+		/// - Writes: Endorsements, EndorsementsCount
+		/// # </weight>
+		#[pallet::call_index(21)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::revoke_endorsement())]
+		pub fn revoke_endorsement(origin: OriginFor<T>, did: DidIdentifierOf<T>) -> DispatchResult {
+			let endorser = T::EnsureOrigin::ensure_origin(origin)?.subject();
+
+			Endorsements::<T>::take(&did, &endorser).ok_or(Error::<T>::EndorsementNotFound)?;
+			EndorsementsCount::<T>::mutate_exists(&endorser, |count| {
+				*count = count.map(|count| count.saturating_sub(1)).filter(|count| *count > 0);
+			});
+
+			Self::deposit_event(Event::EndorsementRevoked(endorser, did));
+
+			Ok(())
+		}
+
+		/// Promote an off-chain light DID to a full DID with the same
+		/// identifier.
+		///
+		/// The creation details must only contain what a light DID can carry,
+		/// i.e., key agreement keys and service endpoints, and must be signed
+		/// by the light DID authentication key, which becomes the
+		/// authentication key of the full DID. Since the identifier of a light
+		/// DID is derived from its authentication key the same way the one of
+		/// a full DID is, the identifier is preserved.
+		///
+		/// Optionally, a DID-authorized call previously signed by the light DID
+		/// authentication key, e.g., a web3name claim, is dispatched right
+		/// after the full DID has been created. If the call fails, the whole
+		/// promotion is reverted.
+		///
+		/// The promotion is deposit-free: the new DID is stored without a
+		/// deposit, which is only collected in full from the deposit owner
+		/// the first time the DID is updated. The DID creation fee is still
+		/// charged.
+		///
+		/// The dispatch origin must be the submitter specified in the creation
+		/// details, which pays the fee and becomes the deposit owner of the new
+		/// DID.
+		///
+		/// Emits `DidCreated`, `LightDidPromoted` and, if a call is provided,
+		/// `DidCallDispatched`.
+		///
+		/// # <weight>
+		/// Weight: O(K) + O(N) + weight of the dispatched call, where K is the
+		/// number of new key agreement keys bounded by
+		/// `MaxNewKeyAgreementKeys`, while N is the number of new service
+		/// endpoints bounded by `MaxNumberOfServicesPerDid`.
+		/// - Reads: [Origin Account], Did, DidBlacklist
+		/// - Writes: Did (with K new key agreement keys), ServiceEndpoints
+		///   (with N new service endpoints), DidEndpointsCount
+		/// # </weight>
+		#[pallet::call_index(22)]
+		#[pallet::weight({
+			let did_call_weight = did_call.as_ref().map(|(did_call, _)| {
+				let operation_length = did_call.encoded_size().saturated_into::<u32>();
+				Pallet::<T>::max_did_call_signature_weight(operation_length).saturating_add(did_call.call.get_dispatch_info().weight)
+			}).unwrap_or_default();
+			Pallet::<T>::creation_weight(details).saturating_add(did_call_weight)
+		})]
+		pub fn promote_light_did(
+			origin: OriginFor<T>,
+			details: Box<DidCreationDetailsOf<T>>,
+			signature: DidSignature,
+			did_call: Option<(Box<DidAuthorizedCallOperationOf<T>>, DidSignature)>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			ensure!(
+				details.new_attestation_key.is_none() && details.new_delegation_key.is_none(),
+				Error::<T>::InvalidLightDidDetails
+			);
+			let did_identifier = details.did.clone();
+
+			Self::do_create(sender.clone(), *details, signature, true)?;
+			Self::deposit_event(Event::LightDidPromoted(did_identifier.clone()));
+
+			let Some((did_call, did_call_signature)) = did_call else {
+				return Ok(());
+			};
+			ensure!(did_call.did == did_identifier, Error::<T>::InvalidDidAuthorizationCall);
+			Self::do_submit_did_call(sender, *did_call, did_call_signature)
+				.map(|_| ())
+				.map_err(|e| e.error)
+		}
+
+		/// Add an `alsoKnownAs` URI to the DID, i.e., another identifier of the
+		/// DID subject, such as a `did:web` DID or a profile URL.
+		///
+		/// The URI increases the DID deposit by `ServiceEndpointDeposit`.
+		///
+		/// The dispatch origin must be a DID origin proxied via the
+		/// `submit_did_call` extrinsic.
+		///
+		/// Emits `DidUpdated`.
+		///
+		/// # <weight>
+		/// Weight: O(1)
+		/// - Reads: [Origin Account], Did, AlsoKnownAs, DidEndpointsCount
+		/// - Writes: Did, AlsoKnownAs
+		/// # </weight>
+		#[pallet::call_index(23)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::add_also_known_as())]
+		pub fn add_also_known_as(origin: OriginFor<T>, uri: AlsoKnownAsUriOf<T>) -> DispatchResult {
+			let did_subject = T::EnsureOrigin::ensure_origin(origin)?.subject();
+
+			let str_uri = str::from_utf8(&uri).map_err(|_| Error::<T>::InvalidAlsoKnownAs)?;
+			ensure!(crate_utils::is_valid_uri(str_uri), Error::<T>::InvalidAlsoKnownAs);
+
+			let did_details = Did::<T>::get(&did_subject).ok_or(Error::<T>::NotFound)?;
+
+			AlsoKnownAs::<T>::try_mutate(&did_subject, |also_known_as| -> Result<(), Error<T>> {
+				ensure!(!also_known_as.contains(&uri), Error::<T>::AlsoKnownAsAlreadyExists);
+				also_known_as
+					.try_push(uri)
+					.map_err(|_| Error::<T>::MaxAlsoKnownAsExceeded)
+			})?;
+
+			Self::ensure_document_size_within_limit(&did_subject, &did_details)?;
+			Self::try_update_did(&did_subject, did_details)?;
+
+			Self::deposit_event(Event::DidUpdated(did_subject));
+
+			Ok(())
+		}
+
+		/// Remove an `alsoKnownAs` URI from the DID.
+		///
+		/// The dispatch origin must be a DID origin proxied via the
+		/// `submit_did_call` extrinsic.
+		///
+		/// Emits `DidUpdated`.
+		///
+		/// # <weight>
+		/// Weight: O(1)
+		/// - Reads: [Origin Account], Did, AlsoKnownAs, DidEndpointsCount
+		/// - Writes: Did, AlsoKnownAs
+		/// # </weight>
+		#[pallet::call_index(24)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::remove_also_known_as())]
+		pub fn remove_also_known_as(origin: OriginFor<T>, uri: AlsoKnownAsUriOf<T>) -> DispatchResult {
+			let did_subject = T::EnsureOrigin::ensure_origin(origin)?.subject();
+
+			let did_details = Did::<T>::get(&did_subject).ok_or(Error::<T>::NotFound)?;
+
+			AlsoKnownAs::<T>::try_mutate_exists(&did_subject, |also_known_as| -> Result<(), Error<T>> {
+				let uris = also_known_as.as_mut().ok_or(Error::<T>::AlsoKnownAsNotFound)?;
+				let position = uris
+					.iter()
+					.position(|stored_uri| *stored_uri == uri)
+					.ok_or(Error::<T>::AlsoKnownAsNotFound)?;
+				uris.remove(position);
+				if uris.is_empty() {
+					*also_known_as = None;
+				}
+				Ok(())
+			})?;
+
+			Self::try_update_did(&did_subject, did_details)?;
+
+			Self::deposit_event(Event::DidUpdated(did_subject));
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T>
+	where
+		T::AccountId: AsRef<[u8; 32]> + From<[u8; 32]>,
+	{
+		/// Create a DID from the given details, after verifying that they have
+		/// been signed by the authentication key of the DID and submitted by
+		/// the expected account.
+		fn do_create(
+			sender: AccountIdOf<T>,
+			details: DidCreationDetailsOf<T>,
+			signature: DidSignature,
+			waive_deposit: bool,
+		) -> DispatchResult {
+			let did_identifier = details.did.clone();
+
+			ensure!(sender == details.submitter, BadOrigin);
+
+			let account_did_auth_key = did_identifier
+				.verify_and_recover_signature(&details.encode(), &signature)
+				.map_err(Error::<T>::from)?;
+
+			Self::ensure_creation_key_types_allowed(&details, &account_did_auth_key)?;
+
+			// Validate all the size constraints for the service endpoints.
+			let input_service_endpoints = details.new_service_details.clone();
+			service_endpoints_utils::validate_new_service_endpoints(&input_service_endpoints)
+				.map_err(Error::<T>::from)?;
+
+			input_service_endpoints.iter().for_each(|service| {
+				ServiceEndpoints::<T>::insert(&did_identifier, &service.id, service.clone());
+			});
+			DidEndpointsCount::<T>::insert(&did_identifier, input_service_endpoints.len().saturated_into::<u32>());
+
+			let mut did_entry =
+				DidDetails::new_with_creation_details(details, account_did_auth_key).map_err(Error::<T>::from)?;
+			did_entry.deposit.amount = if waive_deposit {
+				Zero::zero()
+			} else {
+				did_entry.calculate_deposit(input_service_endpoints.len().saturated_into::<u32>())
+			};
+
+			log::debug!("Creating DID {:?}", &did_identifier);
+
+			Self::try_insert_did(did_identifier, did_entry, sender)?;
+
+			Ok(())
+		}
+
+		/// Verify the signature of a DID-authorized call and dispatch it with a
+		/// DID origin.
+		fn do_submit_did_call(
+			who: AccountIdOf<T>,
+			did_call: DidAuthorizedCallOperationOf<T>,
+			signature: DidSignature,
+		) -> DispatchResultWithPostInfo {
+			ensure!(did_call.submitter == who, Error::<T>::BadDidOrigin);
+
+			let did_identifier = did_call.did.clone();
+
+			// Compute the right DID verification key to use to verify the operation
+			// signature
+			let verification_key_relationship = did_call
+				.call
+				.derive_verification_key_relationship()
+				.map_err(Error::<T>::from)?;
+
+			// Wrap the operation in the expected structure, specifying the key retrieved
+			let wrapped_operation = DidAuthorizedCallOperationWithVerificationRelationship {
+				operation: did_call,
+				verification_key_relationship,
+			};
+
+			Self::verify_did_operation_signature_and_increase_nonce(&wrapped_operation, &signature)
+				.map_err(Error::<T>::from)?;
+
+			log::debug!("Dispatch call from DID {:?}", did_identifier);
+
+			// Dispatch the referenced [Call] instance and return its result
+			let DidAuthorizedCallOperation { did, call, .. } = wrapped_operation.operation;
+
+			#[cfg(not(feature = "runtime-benchmarks"))]
+			let result = call.dispatch(
+				DidRawOrigin {
+					id: did,
+					submitter: who,
+				}
+				.into(),
+			);
+			#[cfg(feature = "runtime-benchmarks")]
+			let result = call.dispatch(RawOrigin::Signed(did).into());
+
+			let dispatch_event_payload = result.map(|_| ()).map_err(|e| e.error);
+
+			Self::deposit_event(Event::DidCallDispatched(did_identifier, dispatch_event_payload));
+
+			result
+		}
+
+		/// Check that the key algorithm is allowed for the given relationship
+		/// by the runtime policy.
+		fn ensure_key_type_allowed(relationship: DidKeyRelationship, key_type: DidKeyType) -> Result<(), Error<T>> {
+			ensure!(
+				T::AllowedKeyTypes::contains(&(relationship, key_type)),
+				Error::<T>::KeyTypeNotAllowed
+			);
+			Ok(())
+		}
+
+		/// Check that all the keys of a DID creation operation are allowed by
+		/// the runtime policy.
+		fn ensure_creation_key_types_allowed(
+			details: &DidCreationDetailsOf<T>,
+			authentication_key: &DidVerificationKey<AccountIdOf<T>>,
+		) -> Result<(), Error<T>> {
+			Self::ensure_key_type_allowed(
+				DidKeyRelationship::Verification(DidVerificationKeyRelationship::Authentication),
+				authentication_key.key_type(),
+			)?;
+			for key_agreement_key in details.new_key_agreement_keys.iter() {
+				Self::ensure_key_type_allowed(DidKeyRelationship::Encryption, key_agreement_key.key_type())?;
+			}
+			if let Some(attestation_key) = &details.new_attestation_key {
+				Self::ensure_key_type_allowed(
+					DidKeyRelationship::Verification(DidVerificationKeyRelationship::AssertionMethod),
+					attestation_key.key_type(),
+				)?;
+			}
+			if let Some(delegation_key) = &details.new_delegation_key {
+				Self::ensure_key_type_allowed(
+					DidKeyRelationship::Verification(DidVerificationKeyRelationship::CapabilityDelegation),
+					delegation_key.key_type(),
+				)?;
+			}
+			Ok(())
+		}
+
+		/// Try creating a DID.
+		///
+		/// # Errors
+		///
+		/// * When the DID was deleted, this function returns a `AlreadyDeleted`
+		///   error.
+		/// * When the DID already exists, this function returns a
+		///   `AlreadyExists` error.
+		/// * When the DID Document would be larger than the maximum size
+		///   allowed, this function returns a `MaxDidDocumentSizeExceeded`
+		///   error.
+		/// * When the `sender` doesn't have enough free balance, this function
+		///   returns a `UnableToPayFees` error.
+		pub fn try_insert_did(
+			did_identifier: DidIdentifierOf<T>,
+			did_entry: DidDetails<T>,
+			sender: AccountIdOf<T>,
+		) -> DispatchResult {
+			// Make sure that DIDs cannot be created again after they have been deleted.
+			ensure!(
+				!DidBlacklist::<T>::contains_key(&did_identifier),
+				Error::<T>::AlreadyDeleted
+			);
+
+			// There has to be no other DID with the same identifier already saved on chain,
+			// otherwise generate a AlreadyExists error.
+			ensure!(!Did::<T>::contains_key(&did_identifier), Error::<T>::AlreadyExists);
+
+			Self::ensure_document_size_within_limit(&did_identifier, &did_entry)?;
+
+			// Collect fee
+			let imbalance: CreditOf<T> = <T::Currency as Balanced<AccountIdOf<T>>>::withdraw(
+				&did_entry.deposit.owner,
+				T::Fee::get(),
+				Precision::Exact,
+				Preservation::Preserve,
+				Fortitude::Polite,
+			)
+			.map_err(|_| Error::<T>::UnableToPayFees)?;
+			T::FeeCollector::on_unbalanced(imbalance);
+
+			DidDepositCollector::<T>::create_deposit(sender.clone(), did_entry.deposit.amount)
+				.map_err(|_| Error::<T>::UnableToPayFees)?;
+
+			<T as Config>::BalanceMigrationManager::exclude_key_from_migration(&Did::<T>::hashed_key_for(
+				&did_identifier,
+			));
+
+			Self::record_key_changes(&did_identifier, None, Some(&did_entry));
+			Did::<T>::insert(&did_identifier, did_entry);
+
+			let free_trial_operations = T::FreeTrialOperations::get();
+			if !free_trial_operations.is_zero() {
+				RemainingFreeTrialOperations::<T>::insert(&did_identifier, free_trial_operations);
+			}
+
+			Pallet::<T>::deposit_event(Event::DidCreated(sender, did_identifier));
+
+			Ok(())
+		}
+
+		/// Try updating the DID.
+		///
+		/// # Errors
+		///
+		/// This can fail when the deposit owner doesn't have enough free
+		/// balance.
+		pub fn try_update_did(did_identifier: &DidIdentifierOf<T>, mut did_details: DidDetails<T>) -> DispatchResult {
+			// The key changes are recorded first, as the removed keys are part of the
+			// deposit.
+			Self::record_key_changes(
+				did_identifier,
+				Did::<T>::get(did_identifier).as_ref(),
+				Some(&did_details),
+			);
+			Self::try_update_deposit(&mut did_details, did_identifier)?;
+			Did::<T>::insert(did_identifier, did_details);
+
+			Ok(())
+		}
+
+		/// Records the key changes between the old and the new DID details
+		/// in the key history.
+		///
+		/// A key rotation is recorded as the removal of the old key and the
+		/// addition of the new key for the same relationship. When the history
+		/// of a key is full, its oldest change is dropped.
+		///
+		/// Keys that are no longer used by the DID are added to its
+		/// [`RemovedKeys`], and keys that are used again are taken out of it.
+		fn record_key_changes(
+			did_identifier: &DidIdentifierOf<T>,
+			old_details: Option<&DidDetails<T>>,
+			new_details: Option<&DidDetails<T>>,
+		) {
+			let old_keys = old_details.map(DidDetails::key_relationships).unwrap_or_default();
+			let new_keys = new_details.map(DidDetails::key_relationships).unwrap_or_default();
+			let block_number = frame_system::Pallet::<T>::block_number();
+
+			let removed_keys = old_keys
+				.iter()
+				.filter(|key| !new_keys.contains(key))
+				.map(|(key_id, relationship)| (key_id, relationship, KeyChange::Removed));
+			let added_keys = new_keys
+				.iter()
+				.filter(|key| !old_keys.contains(key))
+				.map(|(key_id, relationship)| (key_id, relationship, KeyChange::Added));
+
+			removed_keys
+				.chain(added_keys)
+				.for_each(|(key_id, relationship, change)| {
+					if let Some(key_details) = new_details.and_then(|details| details.public_keys.get(key_id)) {
+						HistoricalPublicKeys::<T>::insert(did_identifier, key_id, key_details.key.clone());
+					}
+					KeyHistory::<T>::mutate(did_identifier, key_id, |history| {
+						if history.is_full() {
+							history.remove(0);
+						}
+						// The push can only fail if the history is configured to be empty.
+						let _ = history.try_push(KeyHistoryEntry {
+							relationship: *relationship,
+							change,
+							block_number,
+						});
+					})
+				});
+
+			let key_ids = |keys: &[(KeyIdOf<T>, DidKeyRelationship)]| -> BTreeSet<KeyIdOf<T>> {
+				keys.iter().map(|(key_id, _)| *key_id).collect()
+			};
+			let (old_key_ids, new_key_ids) = (key_ids(&old_keys), key_ids(&new_keys));
+			let removed_key_ids: Vec<_> = old_key_ids.difference(&new_key_ids).collect();
+			let added_key_ids: Vec<_> = new_key_ids.difference(&old_key_ids).collect();
+			if removed_key_ids.is_empty() && added_key_ids.is_empty() {
+				return;
+			}
+
+			RemovedKeys::<T>::mutate(did_identifier, |removed_keys| {
+				// Keys that are used again are no longer removed.
+				removed_keys.retain(|key_id| !added_key_ids.contains(&key_id));
+				removed_key_ids.into_iter().for_each(|key_id| {
+					if removed_keys.is_full() && !removed_keys.is_empty() {
+						let oldest_key_id = removed_keys.remove(0);
+						Self::remove_key_history(did_identifier, &oldest_key_id);
+					}
+					// The push can only fail if no removed keys are configured to be kept.
+					if removed_keys.try_push(*key_id).is_err() {
+						Self::remove_key_history(did_identifier, key_id);
+					}
+				});
+			});
+		}
+
+		/// Removes the history and the public key of a key of the DID.
+		fn remove_key_history(did_identifier: &DidIdentifierOf<T>, key_id: &KeyIdOf<T>) {
+			KeyHistory::<T>::remove(did_identifier, key_id);
+			HistoricalPublicKeys::<T>::remove(did_identifier, key_id);
+		}
+
+		/// Records the current keys of the DID in the key history, if they do
+		/// not have any history yet, e.g., because they were added before the
+		/// key history was introduced.
+		pub(crate) fn backfill_key_history(did_identifier: &DidIdentifierOf<T>, did_details: &DidDetails<T>) {
+			let block_number = frame_system::Pallet::<T>::block_number();
+			let missing_keys: Vec<_> = did_details
+				.key_relationships()
+				.into_iter()
+				.filter(|(key_id, _)| !KeyHistory::<T>::contains_key(did_identifier, key_id))
+				.collect();
+
+			missing_keys.into_iter().for_each(|(key_id, relationship)| {
+				if let Some(key_details) = did_details.public_keys.get(&key_id) {
+					HistoricalPublicKeys::<T>::insert(did_identifier, key_id, key_details.key.clone());
+				}
+				KeyHistory::<T>::mutate(did_identifier, key_id, |history| {
+					// The push can only fail if the history is configured to be empty.
+					let _ = history.try_push(KeyHistoryEntry {
+						relationship,
+						change: KeyChange::Added,
+						block_number,
+					});
+				})
+			});
+		}
+
+		/// Returns the verification relationships for which the signature over
+		/// the payload was valid at the given block.
+		///
+		/// The keys of the DID at that block are resolved via the key history.
+		/// An empty result means that the signature was not generated by any of
+		/// the verification keys of the DID at that block.
+		pub fn verify_signature_at(
+			did_identifier: &DidIdentifierOf<T>,
+			payload: &Payload,
+			signature: &DidSignature,
+			block_number: BlockNumberFor<T>,
+		) -> Vec<DidVerificationKeyRelationship> {
+			let current_details = Did::<T>::get(did_identifier);
+			let current_keys = current_details
+				.as_ref()
+				.map(DidDetails::key_relationships)
+				.unwrap_or_default();
+			let key_ids: BTreeSet<KeyIdOf<T>> = KeyHistory::<T>::iter_key_prefix(did_identifier)
+				.chain(current_keys.iter().map(|(key_id, _)| *key_id))
+				.collect();
+
+			key_ids
+				.into_iter()
+				.filter_map(|key_id| {
+					let key = HistoricalPublicKeys::<T>::get(did_identifier, key_id).or_else(|| {
+						current_details
+							.as_ref()
+							.and_then(|details| details.public_keys.get(&key_id))
+							.map(|key_details| key_details.key.clone())
+					})?;
+					let DidPublicKey::PublicVerificationKey(verification_key) = key else {
+						return None;
+					};
+					verification_key.verify_signature(payload, signature).ok()?;
+					Some(Self::key_relationships_at(
+						did_identifier,
+						key_id,
+						current_details.as_ref(),
+						&current_keys,
+						block_number,
+					))
+				})
+				.flatten()
+				.collect()
+		}
+
+		/// Returns the verification relationships the key had at the given
+		/// block.
+		fn key_relationships_at(
+			did_identifier: &DidIdentifierOf<T>,
+			key_id: KeyIdOf<T>,
+			current_details: Option<&DidDetails<T>>,
+			current_keys: &[(KeyIdOf<T>, DidKeyRelationship)],
+			block_number: BlockNumberFor<T>,
+		) -> Vec<DidVerificationKeyRelationship> {
+			let history = KeyHistory::<T>::get(did_identifier, key_id);
+			[
+				DidVerificationKeyRelationship::Authentication,
+				DidVerificationKeyRelationship::CapabilityDelegation,
+				DidVerificationKeyRelationship::AssertionMethod,
+			]
+			.into_iter()
+			.filter(|relationship| {
+				let relationship = DidKeyRelationship::Verification(*relationship);
+				let mut changes = history.iter().filter(|entry| entry.relationship == relationship);
+				if let Some(last_change) = changes
+					.clone()
+					.filter(|entry| entry.block_number <= block_number)
+					.last()
+				{
+					return last_change.change == KeyChange::Added;
+				}
+				match changes.next() {
+					// The key was already in use before its oldest recorded change.
+					Some(first_change) => first_change.change == KeyChange::Removed,
+					// The key predates the key history, so only its addition block is known.
+					None => {
+						current_keys.contains(&(key_id, relationship))
+							&& current_details
+								.and_then(|details| details.public_keys.get(&key_id))
+								.map_or(false, |key_details| key_details.block_number <= block_number)
+					}
+				}
+			})
+			.collect()
+		}
+
+		/// The number of items under the DID that are charged
+		/// `ServiceEndpointDeposit`, i.e., its service endpoints and its
+		/// `alsoKnownAs` URIs.
+		fn service_deposit_count(did_subject: &DidIdentifierOf<T>) -> u32 {
+			DidEndpointsCount::<T>::get(did_subject).saturating_add(
+				AlsoKnownAs::<T>::decode_len(did_subject)
+					.unwrap_or_default()
+					.saturated_into(),
+			)
+		}
+
+		/// The deposit required for the DID, which includes the deposit for
+		/// the items stored under the DID outside of its details.
+		fn required_deposit(did_details: &DidDetails<T>, did_subject: &DidIdentifierOf<T>) -> BalanceOf<T> {
+			let removed_keys_count: BalanceOf<T> = RemovedKeys::<T>::decode_len(did_subject)
+				.unwrap_or_default()
+				.saturated_into();
+
+			did_details
+				.calculate_deposit(Self::service_deposit_count(did_subject))
+				.saturating_add(removed_keys_count.saturating_mul(T::KeyHistoryDeposit::get()))
+		}
+
+		fn try_update_deposit(did_details: &mut DidDetails<T>, did_subject: &DidIdentifierOf<T>) -> DispatchResult {
+			let new_required_deposit = Self::required_deposit(did_details, did_subject);
+			let hashed_key = Did::<T>::hashed_key_for(did_subject);
+
+			let is_key_migrated = <T as Config>::BalanceMigrationManager::is_key_migrated(&hashed_key);
+
+			match new_required_deposit.cmp(&did_details.deposit.amount) {
+				core::cmp::Ordering::Greater => {
+					let deposit_to_reserve = new_required_deposit.saturating_sub(did_details.deposit.amount);
+
+					if is_key_migrated {
+						DidDepositCollector::<T>::create_deposit(
 							did_details.deposit.clone().owner,
 							deposit_to_reserve,
 						)?;
@@ -1346,6 +2339,65 @@ pub mod pallet {
 			Ok(())
 		}
 
+		/// Returns the DID whose free trial operations can pay the transaction
+		/// fees of the given DID-authorized operation, if any.
+		///
+		/// Only operations that manage the keys and service endpoints of the
+		/// DID are covered, and only if their submitter, nonce, mortality and
+		/// signature are valid, so that no other account can use up the free
+		/// trial operations of a DID.
+		///
+		/// The submitter must also hold the creation deposit of the DID, so
+		/// free trial operations cannot be used without locking a deposit for
+		/// the DID, and are gone with it once the DID is deleted.
+		pub fn free_trial_did(
+			did_call: &DidAuthorizedCallOperationOf<T>,
+			signature: &DidSignature,
+			submitter: &AccountIdOf<T>,
+		) -> Option<DidIdentifierOf<T>>
+		where
+			<T as Config>::RuntimeCall: IsSubType<Call<T>>,
+		{
+			if did_call.submitter != *submitter || RemainingFreeTrialOperations::<T>::get(&did_call.did).is_zero() {
+				return None;
+			}
+			if !did_call.call.is_sub_type()?.is_free_trial_operation() {
+				return None;
+			}
+			let verification_key_relationship = did_call.call.derive_verification_key_relationship().ok()?;
+
+			Self::validate_block_number_value(did_call.block_number).ok()?;
+			let did_details = Did::<T>::get(&did_call.did)?;
+			if did_details.deposit.owner != *submitter || did_details.deposit.amount < T::BaseDeposit::get() {
+				return None;
+			}
+			Self::validate_counter_value(did_call.tx_counter, &did_details).ok()?;
+			Self::verify_did_operation_signature(did_call, signature, &did_details, verification_key_relationship)
+				.ok()?;
+
+			Some(did_call.did.clone())
+		}
+
+		/// The weight of [`Self::free_trial_did`] for the given operation,
+		/// including using up one of the free trial operations of the DID.
+		pub fn free_trial_did_weight(did_call: &DidAuthorizedCallOperationOf<T>) -> Weight {
+			let operation_length = did_call.encoded_size().saturated_into::<u32>();
+			Self::max_did_call_signature_weight(operation_length).saturating_add(T::DbWeight::get().reads_writes(2, 1))
+		}
+
+		/// Uses up one of the free trial operations of the DID.
+		///
+		/// Returns `false` if the DID has no free trial operations left.
+		pub fn consume_free_trial_operation(did_subject: &DidIdentifierOf<T>) -> bool {
+			RemainingFreeTrialOperations::<T>::mutate_exists(did_subject, |remaining| {
+				let Some(left) = remaining.filter(|left| !left.is_zero()) else {
+					return false;
+				};
+				*remaining = Some(left.saturating_sub(1)).filter(|left| !left.is_zero());
+				true
+			})
+		}
+
 		/// Verify the validity (i.e., nonce, signature and mortality) of a
 		/// DID-authorized operation and, if valid, update the DID state with
 		/// the latest nonce.
@@ -1363,8 +2415,8 @@ pub mod pallet {
 			// Increase the tx counter as soon as it is considered valid, no matter if the
 			// signature is valid or not.
 			did_details.increase_tx_counter();
-			Self::verify_payload_signature_with_did_key_type(
-				&operation.encode(),
+			Self::verify_did_operation_signature(
+				&operation.operation,
 				signature,
 				&did_details,
 				operation.verification_key_relationship,
@@ -1375,6 +2427,61 @@ pub mod pallet {
 			Ok(())
 		}
 
+		/// Verify the validity (i.e., key relationship, nonce, signature and
+		/// mortality) of a DID-authorized operation without modifying the DID
+		/// state, e.g., to check it in the transaction pool.
+		pub fn validate_did_call(
+			operation: &DidAuthorizedCallOperationOf<T>,
+			signature: &DidSignature,
+		) -> Result<(), Error<T>> {
+			let verification_key_relationship = operation.call.derive_verification_key_relationship()?;
+			Self::validate_block_number_value(operation.block_number)?;
+
+			let did_details = Did::<T>::get(&operation.did).ok_or(Error::<T>::NotFound)?;
+			Self::validate_counter_value(operation.tx_counter, &did_details)?;
+			Self::verify_did_operation_signature(operation, signature, &did_details, verification_key_relationship)?;
+
+			Ok(())
+		}
+
+		/// Returns the canonical signing payload of the given DID-authorized
+		/// operation for this chain and the current runtime version.
+		pub fn did_operation_signing_payload(operation: &DidAuthorizedCallOperationOf<T>) -> Vec<u8> {
+			let genesis_hash = frame_system::Pallet::<T>::block_hash(BlockNumberFor::<T>::zero());
+			let spec_version = <T as frame_system::Config>::Version::get().spec_version;
+			operation.signing_payload(genesis_hash.as_ref(), spec_version)
+		}
+
+		/// Verify the signature of a DID-authorized operation with the DID key
+		/// of the given type.
+		///
+		/// Signatures over the canonical signing payload are checked first.
+		/// Until [`Config::LegacySignatureDeadline`], signatures over the bare
+		/// SCALE-encoded operation are still accepted for signers that do not
+		/// support the canonical payload yet.
+		pub fn verify_did_operation_signature(
+			operation: &DidAuthorizedCallOperationOf<T>,
+			signature: &DidSignature,
+			did_details: &DidDetails<T>,
+			key_type: DidVerificationKeyRelationship,
+		) -> Result<(), DidError> {
+			let result = Self::verify_payload_signature_with_did_key_type(
+				&Self::did_operation_signing_payload(operation),
+				signature,
+				did_details,
+				key_type,
+			);
+			if result.is_err() && frame_system::Pallet::<T>::block_number() < T::LegacySignatureDeadline::get() {
+				return Self::verify_payload_signature_with_did_key_type(
+					&operation.encode(),
+					signature,
+					did_details,
+					key_type,
+				);
+			}
+			result
+		}
+
 		/// Verify that `account` is authorized to dispatch DID calls on behave
 		/// of `did_identifier`.
 		///
@@ -1481,11 +2588,35 @@ pub mod pallet {
 			{
 				return Err(Error::<T>::Internal.into());
 			};
+			if HashedServiceEndpoints::<T>::clear_prefix(&did_subject, current_endpoints_count, None)
+				.maybe_cursor
+				.is_some()
+			{
+				return Err(Error::<T>::Internal.into());
+			};
 
 			// `take` calls `kill` internally
 			let did_entry = Did::<T>::take(&did_subject).ok_or(Error::<T>::NotFound)?;
 
+			// The history only contains the current and the removed keys of the DID.
+			let max_key_history_count = T::MaxPublicKeysPerDid::get().saturating_add(T::MaxRemovedKeys::get());
+			if KeyHistory::<T>::clear_prefix(&did_subject, max_key_history_count, None)
+				.maybe_cursor
+				.is_some()
+			{
+				return Err(Error::<T>::Internal.into());
+			};
+			if HistoricalPublicKeys::<T>::clear_prefix(&did_subject, max_key_history_count, None)
+				.maybe_cursor
+				.is_some()
+			{
+				return Err(Error::<T>::Internal.into());
+			};
+			RemovedKeys::<T>::remove(&did_subject);
+
 			DidEndpointsCount::<T>::remove(&did_subject);
+			AlsoKnownAs::<T>::remove(&did_subject);
+			RemainingFreeTrialOperations::<T>::remove(&did_subject);
 
 			let is_key_migrated =
 				<T as Config>::BalanceMigrationManager::is_key_migrated(&Did::<T>::hashed_key_for(did_subject.clone()));
@@ -1509,6 +2640,29 @@ pub mod pallet {
 		}
 	}
 
+	impl<T: Config> Call<T> {
+		/// Whether the call is a DID management operation whose fees can be
+		/// paid with the free trial operations of the DID.
+		pub fn is_free_trial_operation(&self) -> bool {
+			matches!(
+				self,
+				Self::set_authentication_key { .. }
+					| Self::set_multisig_authentication_key { .. }
+					| Self::set_delegation_key { .. }
+					| Self::remove_delegation_key { .. }
+					| Self::set_attestation_key { .. }
+					| Self::remove_attestation_key { .. }
+					| Self::add_key_agreement_key { .. }
+					| Self::remove_key_agreement_key { .. }
+					| Self::add_service_endpoint { .. }
+					| Self::add_hashed_service_endpoint { .. }
+					| Self::remove_service_endpoint { .. }
+					| Self::add_also_known_as { .. }
+					| Self::remove_also_known_as { .. }
+			)
+		}
+	}
+
 	pub(crate) struct DidDepositCollector<T: Config>(PhantomData<T>);
 	impl<T: Config> StorageDepositCollector<AccountIdOf<T>, DidIdentifierOf<T>, T::RuntimeHoldReason>
 		for DidDepositCollector<T>
@@ -1534,10 +2688,7 @@ pub mod pallet {
 		fn deposit_amount(key: &DidIdentifierOf<T>) -> <Self::Currency as Inspect<AccountIdOf<T>>>::Balance {
 			let did_entry = Did::<T>::get(key);
 			match did_entry {
-				Some(entry) => {
-					let endpoint_count = DidEndpointsCount::<T>::get(key);
-					entry.calculate_deposit(endpoint_count)
-				}
+				Some(entry) => Pallet::<T>::required_deposit(&entry, key),
 				// If there is no entry return 0
 				_ => Zero::zero(),
 			}
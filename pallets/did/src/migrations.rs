@@ -18,11 +18,26 @@
 
 use frame_support::{
 	pallet_prelude::DispatchResult,
-	traits::{fungible::Inspect, ReservableCurrency},
+	storage::with_storage_layer,
+	traits::{fungible::Inspect, Get, ReservableCurrency},
+	weights::Weight,
 };
-use kilt_support::migration::switch_reserved_to_hold;
+use kilt_support::migration::{switch_reserved_to_hold, SteppedMigration};
+use sp_std::{marker::PhantomData, vec, vec::Vec};
 
-use crate::{AccountIdOf, Config, CurrencyOf, Did, DidIdentifierOf, Error, HoldReason};
+use crate::{
+	did_details::DidDetails, AccountIdOf, Call, Config, CurrencyOf, Did, DidIdentifierOf, Error, HoldReason, Pallet,
+	WeightInfo,
+};
+
+/// The storage version which introduced the key history.
+pub(crate) const KEY_HISTORY_STORAGE_VERSION: u16 = 5;
+
+/// The storage version which repriced the DID deposits.
+pub(crate) const REPRICED_DEPOSITS_STORAGE_VERSION: u16 = 6;
+
+/// The storage version which initialized the counter of the DIDs.
+pub(crate) const DID_COUNTER_STORAGE_VERSION: u16 = 7;
 
 pub fn update_balance_for_did<T: Config>(key: &DidIdentifierOf<T>) -> DispatchResult
 where
@@ -37,23 +52,218 @@ where
 	)
 }
 
+/// Returns the next DID after the provided raw storage key, or the first DID
+/// if no key is provided.
+fn next_did<T: Config>(cursor: Option<Vec<u8>>) -> Option<(DidIdentifierOf<T>, DidDetails<T>)> {
+	match cursor {
+		Some(cursor) => Did::<T>::iter_from(cursor).next(),
+		None => Did::<T>::iter().next(),
+	}
+}
+
+/// Records the keys of DIDs which were created before the key history was
+/// introduced in the key history, one DID per step.
+pub struct BackfillKeyHistory<T>(PhantomData<T>);
+
+impl<T: Config> SteppedMigration for BackfillKeyHistory<T>
+where
+	T::AccountId: AsRef<[u8; 32]> + From<[u8; 32]>,
+{
+	type Pallet = Pallet<T>;
+
+	const FROM: u16 = 4;
+	const TO: u16 = KEY_HISTORY_STORAGE_VERSION;
+
+	fn max_step_weight() -> Weight {
+		<T as Config>::WeightInfo::backfill_key_history_step(T::MaxTotalKeyAgreementKeys::get())
+	}
+
+	fn step(cursor: Option<Vec<u8>>) -> Option<Vec<u8>> {
+		let (did_identifier, did_details) = next_did::<T>(cursor)?;
+		Pallet::<T>::backfill_key_history(&did_identifier, &did_details);
+		Some(Did::<T>::hashed_key_for(&did_identifier))
+	}
+}
+
+/// Updates the deposit of every DID to the currently configured deposit
+/// parameters, one DID per step.
+///
+/// The deposit of DIDs whose deposit owner cannot pay for the updated deposit
+/// is left untouched.
+pub struct RepriceDidDeposits<T>(PhantomData<T>);
+
+impl<T: Config> SteppedMigration for RepriceDidDeposits<T>
+where
+	T::AccountId: AsRef<[u8; 32]> + From<[u8; 32]>,
+{
+	type Pallet = Pallet<T>;
+
+	const FROM: u16 = KEY_HISTORY_STORAGE_VERSION;
+	const TO: u16 = REPRICED_DEPOSITS_STORAGE_VERSION;
+
+	fn max_step_weight() -> Weight {
+		<T as Config>::WeightInfo::reprice_did_deposit_step()
+	}
+
+	fn step(cursor: Option<Vec<u8>>) -> Option<Vec<u8>> {
+		let (did_identifier, did_details) = next_did::<T>(cursor)?;
+		if let Err(err) = with_storage_layer(|| Pallet::<T>::try_update_did(&did_identifier, did_details)) {
+			log::warn!("Failed to update the deposit of DID {:?}: {:?}", did_identifier, err);
+		}
+		Some(Did::<T>::hashed_key_for(&did_identifier))
+	}
+}
+
+/// Initializes the counter of the DIDs, which were stored before the map was
+/// counted, by counting one DID per step.
+///
+/// Calls creating or deleting a DID which has not been counted yet are blocked
+/// until it has been counted, so that it is neither counted twice nor missed.
+pub struct InitializeDidCounter<T>(PhantomData<T>);
+
+impl<T: Config> SteppedMigration for InitializeDidCounter<T> {
+	type Pallet = Pallet<T>;
+
+	const FROM: u16 = REPRICED_DEPOSITS_STORAGE_VERSION;
+	const TO: u16 = DID_COUNTER_STORAGE_VERSION;
+
+	fn max_step_weight() -> Weight {
+		<T as Config>::WeightInfo::initialize_did_counter_step()
+	}
+
+	fn step(cursor: Option<Vec<u8>>) -> Option<Vec<u8>> {
+		let did_identifier = match cursor {
+			Some(cursor) => Did::<T>::iter_keys_from(cursor).next(),
+			None => Did::<T>::iter_keys().next(),
+		}?;
+		frame_support::storage::unhashed::put(
+			&Did::<T>::counter_storage_final_key(),
+			&Did::<T>::count().saturating_add(1),
+		);
+		Some(Did::<T>::hashed_key_for(&did_identifier))
+	}
+}
+
+/// Returns the storage keys of the DIDs accessed by the call, in the key space
+/// of the cursors of the DID migrations.
+///
+/// Calls which require a DID origin do not access any key themselves, as they
+/// can only be dispatched via `submit_did_call` or `dispatch_as`, which access
+/// the key of the dispatching DID. Calls which derive the DID from the origin
+/// return `None`.
+pub fn migrated_storage_keys<T: Config>(call: &Call<T>) -> Option<Vec<Vec<u8>>> {
+	let did = match call {
+		Call::create { details, .. } | Call::promote_light_did { details, .. } => &details.did,
+		Call::submit_did_call { did_call, .. } => &did_call.did,
+		Call::dispatch_as { did_identifier, .. } => did_identifier,
+		Call::reclaim_deposit { did_subject, .. } => did_subject,
+		Call::update_deposit { did } => did,
+		Call::set_authentication_key { .. }
+		| Call::set_delegation_key { .. }
+		| Call::remove_delegation_key { .. }
+		| Call::set_attestation_key { .. }
+		| Call::remove_attestation_key { .. }
+		| Call::add_key_agreement_key { .. }
+		| Call::remove_key_agreement_key { .. }
+		| Call::add_service_endpoint { .. }
+		| Call::remove_service_endpoint { .. }
+		| Call::delete { .. }
+		| Call::change_deposit_owner { .. }
+		| Call::set_multisig_authentication_key { .. }
+		| Call::add_hashed_service_endpoint { .. }
+		| Call::did_remark { .. }
+		| Call::endorse { .. }
+		| Call::revoke_endorsement { .. }
+		| Call::add_also_known_as { .. }
+		| Call::remove_also_known_as { .. } => return Some(Vec::new()),
+		_ => return None,
+	};
+	Some(vec![Did::<T>::hashed_key_for(did)])
+}
+
 #[cfg(test)]
 pub mod test {
 	use frame_support::{
 		assert_noop,
 		traits::{
 			fungible::{Inspect, InspectHold},
-			ReservableCurrency,
+			GetStorageVersion, ReservableCurrency,
 		},
 	};
+	use kilt_support::migration::SteppedMigration;
 	use sp_core::Pair;
 	use sp_runtime::traits::Zero;
 
 	use crate::{
-		self as did, did_details::DidVerificationKey, migrations::update_balance_for_did, mock::*, mock_utils::*,
-		AccountIdOf, Config, Did, Error, HoldReason,
+		self as did,
+		did_details::DidVerificationKey,
+		migrations::{update_balance_for_did, BackfillKeyHistory, InitializeDidCounter, RepriceDidDeposits},
+		mock::*,
+		mock_utils::*,
+		AccountIdOf, Config, Did, Error, HoldReason, Pallet,
 	};
 
+	#[test]
+	fn test_stepped_migrations_bump_one_version_each() {
+		assert_eq!(
+			<BackfillKeyHistory<Test> as SteppedMigration>::TO,
+			<BackfillKeyHistory<Test> as SteppedMigration>::FROM + 1
+		);
+		assert_eq!(
+			<RepriceDidDeposits<Test> as SteppedMigration>::FROM,
+			<BackfillKeyHistory<Test> as SteppedMigration>::TO
+		);
+		assert_eq!(
+			<RepriceDidDeposits<Test> as SteppedMigration>::TO,
+			<RepriceDidDeposits<Test> as SteppedMigration>::FROM + 1
+		);
+		assert_eq!(
+			<InitializeDidCounter<Test> as SteppedMigration>::FROM,
+			<RepriceDidDeposits<Test> as SteppedMigration>::TO
+		);
+		assert_eq!(
+			<InitializeDidCounter<Test> as SteppedMigration>::TO,
+			<InitializeDidCounter<Test> as SteppedMigration>::FROM + 1
+		);
+		assert_eq!(
+			Pallet::<Test>::current_storage_version(),
+			<InitializeDidCounter<Test> as SteppedMigration>::TO
+		);
+	}
+
+	#[test]
+	fn test_initialize_did_counter() {
+		let alice_did = get_did_identifier_from_ed25519_key(get_ed25519_authentication_key(&AUTH_SEED_0).public());
+		let bob_did = get_did_identifier_from_sr25519_key(get_sr25519_authentication_key(&AUTH_SEED_1).public());
+		let alice_details = generate_base_did_details::<Test>(
+			DidVerificationKey::from(get_ed25519_authentication_key(&AUTH_SEED_0).public()),
+			Some(alice_did.clone()),
+		);
+		let bob_details = generate_base_did_details::<Test>(
+			DidVerificationKey::from(get_sr25519_authentication_key(&AUTH_SEED_1).public()),
+			Some(bob_did.clone()),
+		);
+
+		ExtBuilder::default()
+			.with_balances(vec![
+				(alice_did.clone(), <Test as did::Config>::BaseDeposit::get() * 2),
+				(bob_did.clone(), <Test as did::Config>::BaseDeposit::get() * 2),
+			])
+			.with_dids(vec![(alice_did, alice_details), (bob_did, bob_details)])
+			.build_and_execute_with_sanity_tests(None, || {
+				// The DIDs were stored before the map was counted.
+				frame_support::storage::unhashed::kill(&Did::<Test>::counter_storage_final_key());
+				assert_eq!(Did::<Test>::count(), 0);
+
+				let first_cursor = InitializeDidCounter::<Test>::step(None).expect("The first DID should be counted.");
+				assert_eq!(Did::<Test>::count(), 1);
+				let second_cursor =
+					InitializeDidCounter::<Test>::step(Some(first_cursor)).expect("The second DID should be counted.");
+				assert!(InitializeDidCounter::<Test>::step(Some(second_cursor)).is_none());
+				assert_eq!(Did::<Test>::count(), 2);
+			})
+	}
+
 	#[test]
 	fn test_setup() {
 		let auth_key = get_ed25519_authentication_key(&AUTH_SEED_0);
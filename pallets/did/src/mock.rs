@@ -23,7 +23,7 @@ use frame_support::{
 	parameter_types,
 	traits::{
 		fungible::{Balanced, Credit, MutateHold},
-		OnUnbalanced,
+		Contains, OnUnbalanced,
 	},
 	weights::constants::RocksDbWeight,
 };
@@ -44,8 +44,8 @@ use crate::{
 	did_details::{
 		DeriveDidCallAuthorizationVerificationKeyRelationship, DeriveDidCallKeyRelationshipResult,
 		DidAuthorizedCallOperation, DidAuthorizedCallOperationWithVerificationRelationship, DidDetails,
-		DidEncryptionKey, DidPublicKey, DidPublicKeyDetails, DidVerificationKey, DidVerificationKeyRelationship,
-		RelationshipDeriveError,
+		DidEncryptionKey, DidKeyRelationship, DidKeyType, DidPublicKey, DidPublicKeyDetails, DidVerificationKey,
+		DidVerificationKeyRelationship, RelationshipDeriveError,
 	},
 	service_endpoints::DidEndpoint,
 	utils as crate_utils, AccountIdOf, Config, CurrencyOf, DidBlacklist, DidEndpointsCount, HoldReason, KeyIdOf,
@@ -123,7 +123,14 @@ parameter_types! {
 	pub const MaxServiceUrlLength: u32 = 100u32;
 	pub const MaxNumberOfTypesPerService: u32 = 1u32;
 	pub const MaxNumberOfUrlsPerService: u32 = 1u32;
+	pub const MaxKeyHistoryLength: u32 = 3u32;
+	pub const MaxRemovedKeys: u32 = 2u32;
+	pub const MaxMultisigSignatories: u32 = 10u32;
+	pub const MaxEndorsementsPerDid: u32 = 2u32;
+	pub const MaxAlsoKnownAs: u32 = 2u32;
+	pub const MaxAlsoKnownAsUriLength: u32 = 100u32;
 	pub const KeyDeposit :Balance = 32 * MICRO_KILT;
+	pub const KeyHistoryDeposit: Balance = 20 * MICRO_KILT;
 	pub const ServiceEndpointDeposit :Balance = 50 * MICRO_KILT;
 	pub const BaseDeposit: Balance = 100 * MILLI_KILT;
 }
@@ -173,7 +180,35 @@ impl Config for Test {
 	type MaxServiceUrlLength = MaxServiceUrlLength;
 	type MaxNumberOfTypesPerService = MaxNumberOfTypesPerService;
 	type MaxNumberOfUrlsPerService = MaxNumberOfUrlsPerService;
+	type MaxKeyHistoryLength = MaxKeyHistoryLength;
+	type MaxRemovedKeys = MaxRemovedKeys;
+	type KeyHistoryDeposit = KeyHistoryDeposit;
+	type MaxEndorsementsPerDid = MaxEndorsementsPerDid;
+	type MaxAlsoKnownAs = MaxAlsoKnownAs;
+	type MaxAlsoKnownAsUriLength = MaxAlsoKnownAsUriLength;
+	type MaxDidDocumentSize = MaxDidDocumentSize;
+	type MaxMultisigSignatories = MaxMultisigSignatories;
 	type BalanceMigrationManager = ();
+	type AllowedKeyTypes = MockAllowedKeyTypes;
+	type FreeTrialOperations = FreeTrialOperations;
+	type LegacySignatureDeadline = LegacySignatureDeadline;
+}
+
+parameter_types! {
+	pub static ForbiddenKeyTypes: Vec<(DidKeyRelationship, DidKeyType)> = Vec::new();
+	pub static FreeTrialOperations: u32 = 0;
+	pub static LegacySignatureDeadline: u64 = u64::MAX;
+	pub static MaxDidDocumentSize: u32 = 100_000;
+}
+
+/// Key type policy that allows every combination except the ones listed in
+/// [`ForbiddenKeyTypes`], so that tests can tighten the policy as needed.
+pub struct MockAllowedKeyTypes;
+
+impl Contains<(DidKeyRelationship, DidKeyType)> for MockAllowedKeyTypes {
+	fn contains(t: &(DidKeyRelationship, DidKeyType)) -> bool {
+		!ForbiddenKeyTypes::get().contains(t)
+	}
 }
 
 parameter_types! {
@@ -222,6 +257,11 @@ impl ctype::Config for Test {
 	type Currency = Balances;
 	type Fee = Fee;
 	type FeeCollector = ();
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type MaxSchemaLength = frame_support::traits::ConstU32<1024>;
+	type SchemaBaseDeposit = frame_support::traits::ConstU128<1_000>;
+	type SchemaTierSize = frame_support::traits::ConstU32<256>;
+	type SchemaTierDeposit = frame_support::traits::ConstU128<1_000>;
 }
 
 pub(crate) const DEFAULT_BALANCE: Balance = 10 * KILT;
@@ -366,6 +406,8 @@ impl DeriveDidCallAuthorizationVerificationKeyRelationship for RuntimeCall {
 			Ok(DidVerificationKeyRelationship::Authentication)
 		} else if *self == get_delegation_key_call() {
 			Ok(DidVerificationKeyRelationship::CapabilityDelegation)
+		} else if matches!(self, RuntimeCall::Did(..)) {
+			Ok(DidVerificationKeyRelationship::Authentication)
 		} else {
 			#[cfg(feature = "runtime-benchmarks")]
 			if *self == Self::get_call_for_did_call_benchmark() {
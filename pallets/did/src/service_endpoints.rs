@@ -42,6 +42,9 @@ pub(crate) type ServiceEndpointUrl<T> = BoundedVec<u8, <T as Config>::MaxService
 pub(crate) type ServiceEndpointUrlEntries<T> =
 	BoundedVec<ServiceEndpointUrl<T>, <T as Config>::MaxNumberOfUrlsPerService>;
 
+/// A bounded vector of bytes for the tag of a hashed service endpoint.
+pub(crate) type HashedServiceEndpointTag<T> = BoundedVec<u8, <T as Config>::MaxServiceTypeLength>;
+
 /// A single service endpoint description.
 #[derive(Clone, Decode, RuntimeDebug, Encode, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
 #[scale_info(skip_type_params(T))]
@@ -108,6 +111,65 @@ impl<T: Config> DidEndpoint<T> {
 	}
 }
 
+/// A service endpoint whose descriptor is stored off-chain.
+///
+/// Only the hash of the descriptor and a short tag indicating how to resolve
+/// it are stored on chain, so that the descriptor itself is not subject to the
+/// on-chain bounds of a [DidEndpoint].
+#[derive(Clone, Decode, RuntimeDebug, Encode, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(T))]
+#[codec(mel_bound())]
+pub struct HashedDidEndpoint<T: Config> {
+	/// The ID of the service endpoint. It shares the namespace of the IDs of
+	/// the inline service endpoints of the same DID.
+	pub id: ServiceEndpointId<T>,
+	/// The tag describing the type of the descriptor and how to resolve it.
+	pub tag: HashedServiceEndpointTag<T>,
+	/// The hash of the off-chain descriptor.
+	pub descriptor_hash: <T as frame_system::Config>::Hash,
+}
+
+impl<T: Config> HashedDidEndpoint<T> {
+	/// Validates a given [HashedDidEndpoint] instance against the constraint
+	/// set in the pallet's [Config].
+	pub(crate) fn validate_against_constraints(&self) -> Result<(), errors::InputError> {
+		// Check that the ID is the maximum allowed length and only contain URI fragment
+		// characters.
+		ensure!(
+			self.id.len() <= T::MaxServiceIdLength::get().saturated_into(),
+			errors::InputError::MaxIdLengthExceeded
+		);
+		let str_id = str::from_utf8(&self.id).map_err(|_| errors::InputError::InvalidEncoding)?;
+		ensure!(
+			crate_utils::is_valid_uri_fragment(str_id),
+			errors::InputError::InvalidEncoding
+		);
+		// Check that the tag is the maximum allowed length and only contains ASCII
+		// characters.
+		ensure!(
+			self.tag.len() <= T::MaxServiceTypeLength::get().saturated_into(),
+			errors::InputError::MaxTypeLengthExceeded
+		);
+		let str_tag = str::from_utf8(&self.tag).map_err(|_| errors::InputError::InvalidEncoding)?;
+		ensure!(
+			crate_utils::is_valid_ascii_string(str_tag),
+			errors::InputError::InvalidEncoding
+		);
+		Ok(())
+	}
+}
+
+#[cfg(any(test, feature = "runtime-benchmarks", feature = "mock"))]
+impl<T: Config> HashedDidEndpoint<T> {
+	pub(crate) fn new(id: Vec<u8>, tag: Vec<u8>, descriptor_hash: <T as frame_system::Config>::Hash) -> Self {
+		Self {
+			id: id.try_into().expect("Service ID too long."),
+			tag: tag.try_into().expect("Service tag too long."),
+			descriptor_hash,
+		}
+	}
+}
+
 #[cfg(any(test, feature = "runtime-benchmarks", feature = "mock"))]
 impl<T: Config> DidEndpoint<T> {
 	pub(crate) fn new(id: Vec<u8>, types: Vec<Vec<u8>>, urls: Vec<Vec<u8>>) -> Self {
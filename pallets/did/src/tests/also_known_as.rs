@@ -0,0 +1,245 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{fungible::InspectHold, Get},
+	BoundedVec,
+};
+use sp_core::Pair;
+use sp_runtime::traits::BadOrigin;
+
+use crate::{self as did, did_details::DidVerificationKey, mock::*, mock_utils::*, AlsoKnownAsUriOf, HoldReason};
+
+fn uri(input: &[u8]) -> AlsoKnownAsUriOf<Test> {
+	input
+		.to_vec()
+		.try_into()
+		.expect("URI should fit into the bounded vector.")
+}
+
+#[test]
+fn check_successful_also_known_as_addition() {
+	let auth_key = get_ed25519_authentication_key(&AUTH_SEED_0);
+	let alice_did = get_did_identifier_from_ed25519_key(auth_key.public());
+	let mut did_details =
+		generate_base_did_details::<Test>(DidVerificationKey::from(auth_key.public()), Some(alice_did.clone()));
+	did_details.deposit.amount = <Test as did::Config>::BaseDeposit::get();
+
+	let origin = build_test_origin(alice_did.clone(), alice_did.clone());
+
+	ExtBuilder::default()
+		.with_balances(vec![(alice_did.clone(), DEFAULT_BALANCE)])
+		.with_dids(vec![(alice_did.clone(), did_details)])
+		.build_and_execute_with_sanity_tests(None, || {
+			// Events are not registered for the genesis block.
+			System::set_block_number(1);
+			assert_ok!(Did::add_also_known_as(origin.clone(), uri(b"did:web:example.com")));
+			assert_ok!(Did::add_also_known_as(origin, uri(b"https://example.com/alice")));
+			assert_eq!(
+				Did::get_also_known_as(&alice_did).into_inner(),
+				vec![uri(b"did:web:example.com"), uri(b"https://example.com/alice")]
+			);
+			System::assert_last_event(did::Event::DidUpdated(alice_did.clone()).into());
+
+			// Each URI is charged like a service endpoint.
+			assert_eq!(
+				Balances::balance_on_hold(&HoldReason::Deposit.into(), &alice_did),
+				<Test as did::Config>::BaseDeposit::get() + 2 * <Test as did::Config>::ServiceEndpointDeposit::get()
+			);
+			assert_eq!(
+				Did::get_did(&alice_did).expect("DID should be present.").deposit.amount,
+				<Test as did::Config>::BaseDeposit::get() + 2 * <Test as did::Config>::ServiceEndpointDeposit::get()
+			);
+		});
+}
+
+#[test]
+fn check_also_known_as_addition_invalid_uri() {
+	let auth_key = get_ed25519_authentication_key(&AUTH_SEED_0);
+	let alice_did = get_did_identifier_from_ed25519_key(auth_key.public());
+	let did_details = generate_base_did_details::<Test>(DidVerificationKey::from(auth_key.public()), None);
+
+	let origin = build_test_origin(alice_did.clone(), alice_did.clone());
+
+	ExtBuilder::default()
+		.with_balances(vec![(alice_did.clone(), DEFAULT_BALANCE)])
+		.with_dids(vec![(alice_did, did_details)])
+		.build_and_execute_with_sanity_tests(None, || {
+			assert_noop!(
+				Did::add_also_known_as(origin.clone(), uri(b"not a uri")),
+				did::Error::<Test>::InvalidAlsoKnownAs
+			);
+			assert_noop!(
+				Did::add_also_known_as(origin, uri(&[0xff, b':', 0xfe])),
+				did::Error::<Test>::InvalidAlsoKnownAs
+			);
+		});
+}
+
+#[test]
+fn check_also_known_as_addition_duplicate() {
+	let auth_key = get_ed25519_authentication_key(&AUTH_SEED_0);
+	let alice_did = get_did_identifier_from_ed25519_key(auth_key.public());
+	let did_details = generate_base_did_details::<Test>(DidVerificationKey::from(auth_key.public()), None);
+
+	let origin = build_test_origin(alice_did.clone(), alice_did.clone());
+
+	ExtBuilder::default()
+		.with_balances(vec![(alice_did.clone(), DEFAULT_BALANCE)])
+		.with_dids(vec![(alice_did, did_details)])
+		.build_and_execute_with_sanity_tests(None, || {
+			assert_ok!(Did::add_also_known_as(origin.clone(), uri(b"did:web:example.com")));
+			assert_noop!(
+				Did::add_also_known_as(origin, uri(b"did:web:example.com")),
+				did::Error::<Test>::AlsoKnownAsAlreadyExists
+			);
+		});
+}
+
+#[test]
+fn check_max_also_known_as_exceeded() {
+	let auth_key = get_ed25519_authentication_key(&AUTH_SEED_0);
+	let alice_did = get_did_identifier_from_ed25519_key(auth_key.public());
+	let did_details = generate_base_did_details::<Test>(DidVerificationKey::from(auth_key.public()), None);
+
+	let origin = build_test_origin(alice_did.clone(), alice_did.clone());
+
+	ExtBuilder::default()
+		.with_balances(vec![(alice_did.clone(), DEFAULT_BALANCE)])
+		.with_dids(vec![(alice_did, did_details)])
+		.build_and_execute_with_sanity_tests(None, || {
+			for i in 0..<Test as did::Config>::MaxAlsoKnownAs::get() {
+				assert_ok!(Did::add_also_known_as(
+					origin.clone(),
+					uri(format!("did:web:example{i}.com").as_bytes())
+				));
+			}
+			assert_noop!(
+				Did::add_also_known_as(origin, uri(b"did:web:example.com")),
+				did::Error::<Test>::MaxAlsoKnownAsExceeded
+			);
+		});
+}
+
+#[test]
+fn check_also_known_as_addition_did_not_present() {
+	let auth_key = get_ed25519_authentication_key(&AUTH_SEED_0);
+	let alice_did = get_did_identifier_from_ed25519_key(auth_key.public());
+
+	let origin = build_test_origin(alice_did.clone(), alice_did);
+
+	ExtBuilder::default().build_and_execute_with_sanity_tests(None, || {
+		assert_noop!(
+			Did::add_also_known_as(origin, uri(b"did:web:example.com")),
+			did::Error::<Test>::NotFound
+		);
+	});
+}
+
+#[test]
+fn check_also_known_as_addition_bad_origin() {
+	ExtBuilder::default().build_and_execute_with_sanity_tests(None, || {
+		assert_noop!(
+			Did::add_also_known_as(RuntimeOrigin::signed(ACCOUNT_00), uri(b"did:web:example.com")),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn check_successful_also_known_as_removal() {
+	let auth_key = get_ed25519_authentication_key(&AUTH_SEED_0);
+	let alice_did = get_did_identifier_from_ed25519_key(auth_key.public());
+	let mut did_details =
+		generate_base_did_details::<Test>(DidVerificationKey::from(auth_key.public()), Some(alice_did.clone()));
+	did_details.deposit.amount = <Test as did::Config>::BaseDeposit::get();
+
+	let origin = build_test_origin(alice_did.clone(), alice_did.clone());
+
+	ExtBuilder::default()
+		.with_balances(vec![(alice_did.clone(), DEFAULT_BALANCE)])
+		.with_dids(vec![(alice_did.clone(), did_details)])
+		.build_and_execute_with_sanity_tests(None, || {
+			assert_ok!(Did::add_also_known_as(origin.clone(), uri(b"did:web:example.com")));
+			assert_ok!(Did::add_also_known_as(
+				origin.clone(),
+				uri(b"https://example.com/alice")
+			));
+
+			assert_ok!(Did::remove_also_known_as(origin.clone(), uri(b"did:web:example.com")));
+			assert_eq!(
+				Did::get_also_known_as(&alice_did).into_inner(),
+				vec![uri(b"https://example.com/alice")]
+			);
+			assert_eq!(
+				Balances::balance_on_hold(&HoldReason::Deposit.into(), &alice_did),
+				<Test as did::Config>::BaseDeposit::get() + <Test as did::Config>::ServiceEndpointDeposit::get()
+			);
+
+			// Removing the last URI removes the storage entry.
+			assert_ok!(Did::remove_also_known_as(origin, uri(b"https://example.com/alice")));
+			assert!(!did::AlsoKnownAs::<Test>::contains_key(&alice_did));
+			assert_eq!(
+				Balances::balance_on_hold(&HoldReason::Deposit.into(), &alice_did),
+				<Test as did::Config>::BaseDeposit::get()
+			);
+		});
+}
+
+#[test]
+fn check_also_known_as_removal_not_found() {
+	let auth_key = get_ed25519_authentication_key(&AUTH_SEED_0);
+	let alice_did = get_did_identifier_from_ed25519_key(auth_key.public());
+	let did_details = generate_base_did_details::<Test>(DidVerificationKey::from(auth_key.public()), None);
+
+	let origin = build_test_origin(alice_did.clone(), alice_did.clone());
+
+	ExtBuilder::default()
+		.with_balances(vec![(alice_did.clone(), DEFAULT_BALANCE)])
+		.with_dids(vec![(alice_did, did_details)])
+		.build_and_execute_with_sanity_tests(None, || {
+			assert_noop!(
+				Did::remove_also_known_as(origin.clone(), uri(b"did:web:example.com")),
+				did::Error::<Test>::AlsoKnownAsNotFound
+			);
+			assert_ok!(Did::add_also_known_as(origin.clone(), uri(b"did:web:example.com")));
+			assert_noop!(
+				Did::remove_also_known_as(origin, uri(b"did:web:example.org")),
+				did::Error::<Test>::AlsoKnownAsNotFound
+			);
+		});
+}
+
+#[test]
+fn check_also_known_as_removed_on_deletion() {
+	let auth_key = get_ed25519_authentication_key(&AUTH_SEED_0);
+	let alice_did = get_did_identifier_from_ed25519_key(auth_key.public());
+	let did_details = generate_base_did_details::<Test>(DidVerificationKey::from(auth_key.public()), None);
+
+	let origin = build_test_origin(alice_did.clone(), alice_did.clone());
+
+	ExtBuilder::default()
+		.with_balances(vec![(alice_did.clone(), DEFAULT_BALANCE)])
+		.with_dids(vec![(alice_did.clone(), did_details)])
+		.build_and_execute_with_sanity_tests(None, || {
+			assert_ok!(Did::add_also_known_as(origin.clone(), uri(b"did:web:example.com")));
+			assert_ok!(Did::delete(origin, 0));
+			assert_eq!(Did::get_also_known_as(&alice_did), BoundedVec::default());
+			assert_eq!(Balances::balance_on_hold(&HoldReason::Deposit.into(), &alice_did), 0);
+		});
+}
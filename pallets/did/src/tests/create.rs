@@ -27,7 +27,9 @@ use sp_std::{collections::btree_set::BTreeSet, convert::TryFrom};
 
 use crate::{
 	self as did,
-	did_details::{DidEncryptionKey, DidVerificationKey},
+	did_details::{
+		DidEncryptionKey, DidKeyRelationship, DidKeyType, DidVerificationKey, DidVerificationKeyRelationship,
+	},
 	mock::*,
 	mock_utils::*,
 	service_endpoints::DidEndpoint,
@@ -416,6 +418,35 @@ fn check_invalid_signature_format_did_creation() {
 		});
 }
 
+#[test]
+fn check_key_type_not_allowed_did_creation() {
+	let auth_key = get_sr25519_authentication_key(&AUTH_SEED_0);
+	let alice_did = get_did_identifier_from_sr25519_key(auth_key.public());
+	let details = generate_base_did_creation_details::<Test>(alice_did, ACCOUNT_00);
+
+	let signature = auth_key.sign(details.encode().as_ref());
+
+	let balance = <Test as did::Config>::BaseDeposit::get()
+		+ <Test as did::Config>::Fee::get()
+		+ <<Test as did::Config>::Currency as Inspect<did::AccountIdOf<Test>>>::minimum_balance();
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, balance)])
+		.build_and_execute_with_sanity_tests(None, || {
+			ForbiddenKeyTypes::set(vec![(
+				DidKeyRelationship::Verification(DidVerificationKeyRelationship::Authentication),
+				DidKeyType::Sr25519,
+			)]);
+			assert_noop!(
+				Did::create(
+					RuntimeOrigin::signed(ACCOUNT_00),
+					Box::new(details),
+					did::DidSignature::from(signature)
+				),
+				did::Error::<Test>::KeyTypeNotAllowed
+			);
+		});
+}
+
 #[test]
 fn check_invalid_signature_did_creation() {
 	let auth_key = get_sr25519_authentication_key(&AUTH_SEED_0);
@@ -0,0 +1,159 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use frame_support::{assert_noop, assert_ok, traits::Get};
+use sp_core::Pair;
+
+use crate::{
+	self as did, did_details::DidVerificationKey, mock::*, mock_utils::*, service_endpoints::DidEndpoint,
+	DidDocumentEstimateError, DidDocumentOperation,
+};
+
+fn add_service_endpoint_operation(id: &[u8]) -> did::document::DidDocumentOperationOf<Test> {
+	DidDocumentOperation::AddServiceEndpoint {
+		id: id.to_vec(),
+		service_types: vec![b"type".to_vec()],
+		urls: vec![b"url".to_vec()],
+	}
+}
+
+#[test]
+fn check_document_size_estimate_matches_applied_operations() {
+	let auth_key = get_ed25519_authentication_key(&AUTH_SEED_0);
+	let alice_did = get_did_identifier_from_ed25519_key(auth_key.public());
+	let mut did_details =
+		generate_base_did_details::<Test>(DidVerificationKey::from(auth_key.public()), Some(alice_did.clone()));
+	did_details.deposit.amount = <Test as did::Config>::BaseDeposit::get();
+
+	let origin = build_test_origin(alice_did.clone(), alice_did.clone());
+
+	ExtBuilder::default()
+		.with_balances(vec![(alice_did.clone(), DEFAULT_BALANCE)])
+		.with_dids(vec![(alice_did.clone(), did_details)])
+		.build_and_execute_with_sanity_tests(None, || {
+			let current_estimate =
+				Did::estimate_document_size(&alice_did, vec![]).expect("Estimate of the current DID should succeed.");
+			assert_eq!(current_estimate.deposit, <Test as did::Config>::BaseDeposit::get());
+
+			let estimate = Did::estimate_document_size(
+				&alice_did,
+				vec![
+					add_service_endpoint_operation(b"id"),
+					DidDocumentOperation::AddAlsoKnownAs(b"did:web:example.com".to_vec()),
+				],
+			)
+			.expect("Estimate of valid operations should succeed.");
+			assert!(estimate.size > current_estimate.size);
+			assert_eq!(estimate.max_size, <Test as did::Config>::MaxDidDocumentSize::get());
+			assert!(!estimate.exceeds_max_size());
+			// The estimate does not change any state.
+			assert_eq!(Did::estimate_document_size(&alice_did, vec![]), Ok(current_estimate));
+
+			assert_ok!(Did::add_service_endpoint(
+				origin.clone(),
+				DidEndpoint::new(b"id".to_vec(), vec![b"type".to_vec()], vec![b"url".to_vec()])
+			));
+			assert_ok!(Did::add_also_known_as(
+				origin,
+				b"did:web:example.com"
+					.to_vec()
+					.try_into()
+					.expect("URI should fit into the bounded vector.")
+			));
+
+			assert_eq!(Did::estimate_document_size(&alice_did, vec![]), Ok(estimate.clone()));
+			assert_eq!(
+				Did::get_did(&alice_did).expect("DID should be present.").deposit.amount,
+				estimate.deposit
+			);
+		});
+}
+
+#[test]
+fn check_document_size_exceeded() {
+	let auth_key = get_ed25519_authentication_key(&AUTH_SEED_0);
+	let alice_did = get_did_identifier_from_ed25519_key(auth_key.public());
+	let did_details =
+		generate_base_did_details::<Test>(DidVerificationKey::from(auth_key.public()), Some(alice_did.clone()));
+
+	let origin = build_test_origin(alice_did.clone(), alice_did.clone());
+
+	ExtBuilder::default()
+		.with_balances(vec![(alice_did.clone(), DEFAULT_BALANCE)])
+		.with_dids(vec![(alice_did.clone(), did_details)])
+		.build_and_execute_with_sanity_tests(None, || {
+			let current_size = Did::estimate_document_size(&alice_did, vec![])
+				.expect("Estimate of the current DID should succeed.")
+				.size;
+			MaxDidDocumentSize::set(current_size);
+
+			let estimate = Did::estimate_document_size(&alice_did, vec![add_service_endpoint_operation(b"id")])
+				.expect("Estimate of valid operations should succeed.");
+			assert!(estimate.exceeds_max_size());
+
+			assert_noop!(
+				Did::add_service_endpoint(
+					origin,
+					DidEndpoint::new(b"id".to_vec(), vec![b"type".to_vec()], vec![b"url".to_vec()])
+				),
+				did::Error::<Test>::MaxDidDocumentSizeExceeded
+			);
+		});
+	MaxDidDocumentSize::set(100_000);
+}
+
+#[test]
+fn check_document_size_estimate_invalid_operation() {
+	let auth_key = get_ed25519_authentication_key(&AUTH_SEED_0);
+	let alice_did = get_did_identifier_from_ed25519_key(auth_key.public());
+	let did_details =
+		generate_base_did_details::<Test>(DidVerificationKey::from(auth_key.public()), Some(alice_did.clone()));
+
+	ExtBuilder::default()
+		.with_balances(vec![(alice_did.clone(), DEFAULT_BALANCE)])
+		.with_dids(vec![(alice_did.clone(), did_details)])
+		.build_and_execute_with_sanity_tests(None, || {
+			assert_eq!(
+				Did::estimate_document_size(
+					&alice_did,
+					vec![
+						add_service_endpoint_operation(b"id"),
+						add_service_endpoint_operation(b"id")
+					]
+				),
+				Err(DidDocumentEstimateError::InvalidOperation(1))
+			);
+			assert_eq!(
+				Did::estimate_document_size(&alice_did, vec![DidDocumentOperation::RemoveDelegationKey]),
+				Err(DidDocumentEstimateError::InvalidOperation(0))
+			);
+		});
+}
+
+#[test]
+fn check_document_size_estimate_did_not_present() {
+	let auth_key = get_ed25519_authentication_key(&AUTH_SEED_0);
+	let alice_did = get_did_identifier_from_ed25519_key(auth_key.public());
+
+	ExtBuilder::default().build_and_execute_with_sanity_tests(None, || {
+		assert_eq!(
+			Did::estimate_document_size(&alice_did, vec![]),
+			Err(DidDocumentEstimateError::NotFound)
+		);
+	});
+}
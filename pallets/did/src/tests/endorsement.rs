@@ -0,0 +1,196 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use frame_support::{assert_noop, assert_ok, traits::Get};
+use sp_core::Pair;
+use sp_runtime::traits::{BadOrigin, Hash};
+
+use crate::{self as did, did_details::DidVerificationKey, mock::*, mock_utils::*, EndorsementDetails};
+
+fn context_hash(context: &[u8]) -> <Test as frame_system::Config>::Hash {
+	<Test as frame_system::Config>::Hashing::hash(context)
+}
+
+#[test]
+fn check_successful_endorsement() {
+	let alice_auth_key = get_ed25519_authentication_key(&AUTH_SEED_0);
+	let alice_did = get_did_identifier_from_ed25519_key(alice_auth_key.public());
+	let bob_auth_key = get_sr25519_authentication_key(&AUTH_SEED_1);
+	let bob_did = get_did_identifier_from_sr25519_key(bob_auth_key.public());
+	let bob_did_details = generate_base_did_details::<Test>(DidVerificationKey::from(bob_auth_key.public()), None);
+
+	let origin = build_test_origin(alice_did.clone(), alice_did.clone());
+
+	ExtBuilder::default()
+		.with_dids(vec![(bob_did.clone(), bob_did_details)])
+		.build_and_execute_with_sanity_tests(None, || {
+			// Events are not registered for the genesis block.
+			System::set_block_number(1);
+			assert_ok!(Did::endorse(origin.clone(), bob_did.clone(), context_hash(b"employee")));
+			assert_eq!(
+				Did::get_endorsement(&bob_did, &alice_did),
+				Some(EndorsementDetails {
+					context_hash: context_hash(b"employee"),
+					created_at: 1,
+				})
+			);
+			assert_eq!(did::EndorsementsCount::<Test>::get(&alice_did), 1);
+			System::assert_last_event(
+				did::Event::DidEndorsed(alice_did.clone(), bob_did.clone(), context_hash(b"employee")).into(),
+			);
+
+			// Endorsing the same DID again replaces the context.
+			System::set_block_number(2);
+			assert_ok!(Did::endorse(origin, bob_did.clone(), context_hash(b"manager")));
+			assert_eq!(
+				Did::get_endorsement(&bob_did, &alice_did),
+				Some(EndorsementDetails {
+					context_hash: context_hash(b"manager"),
+					created_at: 2,
+				})
+			);
+			assert_eq!(did::EndorsementsCount::<Test>::get(&alice_did), 1);
+		});
+}
+
+#[test]
+fn check_self_endorsement() {
+	let auth_key = get_ed25519_authentication_key(&AUTH_SEED_0);
+	let alice_did = get_did_identifier_from_ed25519_key(auth_key.public());
+	let did_details = generate_base_did_details::<Test>(DidVerificationKey::from(auth_key.public()), None);
+
+	let origin = build_test_origin(alice_did.clone(), alice_did.clone());
+
+	ExtBuilder::default()
+		.with_dids(vec![(alice_did.clone(), did_details)])
+		.build_and_execute_with_sanity_tests(None, || {
+			assert_noop!(
+				Did::endorse(origin, alice_did, context_hash(b"self")),
+				did::Error::<Test>::SelfEndorsement
+			);
+		});
+}
+
+#[test]
+fn check_endorsement_did_not_present() {
+	let alice_auth_key = get_ed25519_authentication_key(&AUTH_SEED_0);
+	let alice_did = get_did_identifier_from_ed25519_key(alice_auth_key.public());
+	let bob_auth_key = get_sr25519_authentication_key(&AUTH_SEED_1);
+	let bob_did = get_did_identifier_from_sr25519_key(bob_auth_key.public());
+
+	let origin = build_test_origin(alice_did.clone(), alice_did);
+
+	ExtBuilder::default().build_and_execute_with_sanity_tests(None, || {
+		assert_noop!(
+			Did::endorse(origin, bob_did, context_hash(b"employee")),
+			did::Error::<Test>::NotFound
+		);
+	});
+}
+
+#[test]
+fn check_max_endorsements_exceeded() {
+	let alice_auth_key = get_ed25519_authentication_key(&AUTH_SEED_0);
+	let alice_did = get_did_identifier_from_ed25519_key(alice_auth_key.public());
+	let endorsed_keys = [
+		get_sr25519_authentication_key(&AUTH_SEED_0).public(),
+		get_sr25519_authentication_key(&AUTH_SEED_1).public(),
+		get_sr25519_authentication_key(&DEL_SEED_0).public(),
+	];
+	let endorsed_dids: Vec<_> = endorsed_keys
+		.iter()
+		.map(|key| {
+			(
+				get_did_identifier_from_sr25519_key(*key),
+				generate_base_did_details::<Test>(DidVerificationKey::from(*key), None),
+			)
+		})
+		.collect();
+	assert_eq!(
+		endorsed_dids.len(),
+		<Test as did::Config>::MaxEndorsementsPerDid::get() as usize + 1
+	);
+
+	let origin = build_test_origin(alice_did.clone(), alice_did.clone());
+
+	ExtBuilder::default()
+		.with_dids(endorsed_dids.clone())
+		.build_and_execute_with_sanity_tests(None, || {
+			let (last, endorsed) = endorsed_dids.split_last().expect("There should be endorsed DIDs.");
+			for (did, _) in endorsed {
+				assert_ok!(Did::endorse(origin.clone(), did.clone(), context_hash(b"employee")));
+			}
+			assert_noop!(
+				Did::endorse(origin.clone(), last.0.clone(), context_hash(b"employee")),
+				did::Error::<Test>::MaxEndorsementsExceeded
+			);
+
+			// Revoking an endorsement frees up a slot.
+			assert_ok!(Did::revoke_endorsement(origin.clone(), endorsed[0].0.clone()));
+			assert_ok!(Did::endorse(origin, last.0.clone(), context_hash(b"employee")));
+		});
+}
+
+#[test]
+fn check_successful_endorsement_revocation() {
+	let alice_auth_key = get_ed25519_authentication_key(&AUTH_SEED_0);
+	let alice_did = get_did_identifier_from_ed25519_key(alice_auth_key.public());
+	let bob_auth_key = get_sr25519_authentication_key(&AUTH_SEED_1);
+	let bob_did = get_did_identifier_from_sr25519_key(bob_auth_key.public());
+	let bob_did_details = generate_base_did_details::<Test>(DidVerificationKey::from(bob_auth_key.public()), None);
+
+	let origin = build_test_origin(alice_did.clone(), alice_did.clone());
+
+	ExtBuilder::default()
+		.with_dids(vec![(bob_did.clone(), bob_did_details)])
+		.build_and_execute_with_sanity_tests(None, || {
+			System::set_block_number(1);
+			assert_ok!(Did::endorse(origin.clone(), bob_did.clone(), context_hash(b"employee")));
+			assert_ok!(Did::revoke_endorsement(origin.clone(), bob_did.clone()));
+
+			assert!(Did::get_endorsement(&bob_did, &alice_did).is_none());
+			assert!(!did::EndorsementsCount::<Test>::contains_key(&alice_did));
+			System::assert_last_event(did::Event::EndorsementRevoked(alice_did.clone(), bob_did.clone()).into());
+
+			assert_noop!(
+				Did::revoke_endorsement(origin, bob_did),
+				did::Error::<Test>::EndorsementNotFound
+			);
+		});
+}
+
+#[test]
+fn check_endorsement_requires_did_origin() {
+	let bob_auth_key = get_sr25519_authentication_key(&AUTH_SEED_1);
+	let bob_did = get_did_identifier_from_sr25519_key(bob_auth_key.public());
+
+	ExtBuilder::default().build_and_execute_with_sanity_tests(None, || {
+		assert_noop!(
+			Did::endorse(
+				RuntimeOrigin::signed(ACCOUNT_00),
+				bob_did.clone(),
+				context_hash(b"employee")
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			Did::revoke_endorsement(RuntimeOrigin::signed(ACCOUNT_00), bob_did),
+			BadOrigin
+		);
+	});
+}
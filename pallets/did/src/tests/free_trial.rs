@@ -0,0 +1,197 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use frame_support::{assert_ok, traits::fungible::Inspect};
+use parity_scale_codec::Encode;
+use sp_core::Pair;
+
+use crate::{
+	self as did, did_details::DidVerificationKey, mock::*, mock_utils::*, DidAuthorizedCallOperationOf,
+	RemainingFreeTrialOperations,
+};
+
+fn generate_management_call(did: DidIdentifier, submitter: AccountId) -> DidAuthorizedCallOperationOf<Test> {
+	DidAuthorizedCallOperationOf::<Test> {
+		did,
+		call: RuntimeCall::Did(did::Call::remove_delegation_key {}),
+		tx_counter: 1u64,
+		block_number: 0u64,
+		submitter,
+	}
+}
+
+#[test]
+fn check_free_trial_operations_granted_on_creation() {
+	let auth_key = get_ed25519_authentication_key(&AUTH_SEED_0);
+	let alice_did = get_did_identifier_from_ed25519_key(auth_key.public());
+	let details = generate_base_did_creation_details::<Test>(alice_did.clone(), ACCOUNT_00);
+	let signature = auth_key.sign(details.encode().as_ref());
+
+	let balance = <Test as did::Config>::BaseDeposit::get()
+		+ <Test as did::Config>::Fee::get()
+		+ <<Test as did::Config>::Currency as Inspect<did::AccountIdOf<Test>>>::minimum_balance();
+
+	FreeTrialOperations::set(2);
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, balance)])
+		.build_and_execute_with_sanity_tests(None, || {
+			assert_ok!(Did::create(
+				RuntimeOrigin::signed(ACCOUNT_00),
+				Box::new(details),
+				did::DidSignature::from(signature),
+			));
+			assert_eq!(Did::get_remaining_free_trial_operations(&alice_did), 2);
+
+			assert!(Did::consume_free_trial_operation(&alice_did));
+			assert_eq!(Did::get_remaining_free_trial_operations(&alice_did), 1);
+			assert!(Did::consume_free_trial_operation(&alice_did));
+			assert!(!RemainingFreeTrialOperations::<Test>::contains_key(&alice_did));
+			assert!(!Did::consume_free_trial_operation(&alice_did));
+		});
+	FreeTrialOperations::set(0);
+}
+
+#[test]
+fn check_no_free_trial_operations_when_disabled() {
+	let auth_key = get_ed25519_authentication_key(&AUTH_SEED_0);
+	let alice_did = get_did_identifier_from_ed25519_key(auth_key.public());
+	let details = generate_base_did_creation_details::<Test>(alice_did.clone(), ACCOUNT_00);
+	let signature = auth_key.sign(details.encode().as_ref());
+
+	let balance = <Test as did::Config>::BaseDeposit::get()
+		+ <Test as did::Config>::Fee::get()
+		+ <<Test as did::Config>::Currency as Inspect<did::AccountIdOf<Test>>>::minimum_balance();
+
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, balance)])
+		.build_and_execute_with_sanity_tests(None, || {
+			assert_ok!(Did::create(
+				RuntimeOrigin::signed(ACCOUNT_00),
+				Box::new(details),
+				did::DidSignature::from(signature),
+			));
+			assert!(!RemainingFreeTrialOperations::<Test>::contains_key(&alice_did));
+			assert!(!Did::consume_free_trial_operation(&alice_did));
+		});
+}
+
+#[test]
+fn check_free_trial_did_only_for_valid_management_operations() {
+	let auth_key = get_ed25519_authentication_key(&AUTH_SEED_0);
+	let alice_did = get_did_identifier_from_ed25519_key(auth_key.public());
+	let mut did_details = generate_base_did_details::<Test>(DidVerificationKey::from(auth_key.public()), None);
+	did_details.deposit.owner = ACCOUNT_00;
+	did_details.deposit.amount = <Test as did::Config>::BaseDeposit::get();
+
+	let balance = <Test as did::Config>::BaseDeposit::get()
+		+ <<Test as did::Config>::Currency as Inspect<did::AccountIdOf<Test>>>::minimum_balance();
+
+	let management_call = generate_management_call(alice_did.clone(), ACCOUNT_00);
+	let management_signature = did::DidSignature::from(auth_key.sign(management_call.encode().as_ref()));
+
+	let mut other_call = management_call.clone();
+	other_call.call = get_authentication_key_call();
+	let other_signature = did::DidSignature::from(auth_key.sign(other_call.encode().as_ref()));
+
+	let mut expired_call = management_call.clone();
+	expired_call.tx_counter = 2u64;
+	let expired_signature = did::DidSignature::from(auth_key.sign(expired_call.encode().as_ref()));
+
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, balance)])
+		.with_dids(vec![(alice_did.clone(), did_details)])
+		.build_and_execute_with_sanity_tests(None, || {
+			// No free trial operations left.
+			assert_eq!(
+				Did::free_trial_did(&management_call, &management_signature, &ACCOUNT_00),
+				None
+			);
+
+			RemainingFreeTrialOperations::<Test>::insert(&alice_did, 1);
+			assert_eq!(
+				Did::free_trial_did(&management_call, &management_signature, &ACCOUNT_00),
+				Some(alice_did.clone())
+			);
+			// Other submitter.
+			assert_eq!(
+				Did::free_trial_did(&management_call, &management_signature, &ACCOUNT_01),
+				None
+			);
+			// Signature over another operation.
+			assert_eq!(
+				Did::free_trial_did(&management_call, &other_signature, &ACCOUNT_00),
+				None
+			);
+			// Not a DID management operation.
+			assert_eq!(Did::free_trial_did(&other_call, &other_signature, &ACCOUNT_00), None);
+			// Invalid nonce.
+			assert_eq!(
+				Did::free_trial_did(&expired_call, &expired_signature, &ACCOUNT_00),
+				None
+			);
+
+			// The creation deposit of the DID is held by another account.
+			let set_deposit_owner = |owner: AccountId| {
+				did::Did::<Test>::mutate(&alice_did, |details| {
+					details.as_mut().expect("DID should exist").deposit.owner = owner;
+				})
+			};
+			set_deposit_owner(ACCOUNT_01);
+			assert_eq!(
+				Did::free_trial_did(&management_call, &management_signature, &ACCOUNT_00),
+				None
+			);
+			set_deposit_owner(ACCOUNT_00);
+
+			// The deposit of the DID does not cover the creation deposit.
+			let set_deposit_amount = |amount: Balance| {
+				did::Did::<Test>::mutate(&alice_did, |details| {
+					details.as_mut().expect("DID should exist").deposit.amount = amount;
+				})
+			};
+			set_deposit_amount(<Test as did::Config>::BaseDeposit::get() - 1);
+			assert_eq!(
+				Did::free_trial_did(&management_call, &management_signature, &ACCOUNT_00),
+				None
+			);
+			set_deposit_amount(<Test as did::Config>::BaseDeposit::get());
+		});
+}
+
+#[test]
+fn check_free_trial_operations_removed_on_deletion() {
+	let auth_key = get_ed25519_authentication_key(&AUTH_SEED_0);
+	let alice_did = get_did_identifier_from_ed25519_key(auth_key.public());
+	let mut did_details = generate_base_did_details::<Test>(DidVerificationKey::from(auth_key.public()), None);
+	did_details.deposit.owner = ACCOUNT_00;
+	did_details.deposit.amount = <Test as did::Config>::BaseDeposit::get();
+
+	let balance = <Test as did::Config>::BaseDeposit::get() * 2
+		+ <Test as did::Config>::Fee::get() * 2
+		+ <<Test as did::Config>::Currency as Inspect<did::AccountIdOf<Test>>>::minimum_balance();
+
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, balance)])
+		.with_dids(vec![(alice_did.clone(), did_details)])
+		.build_and_execute_with_sanity_tests(None, || {
+			RemainingFreeTrialOperations::<Test>::insert(&alice_did, 3);
+
+			assert_ok!(Did::delete(build_test_origin(alice_did.clone(), alice_did.clone()), 0));
+			assert!(!RemainingFreeTrialOperations::<Test>::contains_key(&alice_did));
+		});
+}
@@ -21,7 +21,14 @@ use frame_system::pallet_prelude::BlockNumberFor;
 use sp_core::Pair;
 use sp_runtime::SaturatedConversion;
 
-use crate::{self as did, did_details::DidVerificationKey, mock::*, mock_utils::*};
+use crate::{
+	self as did,
+	did_details::{
+		DidKeyRelationship, DidKeyType, DidVerificationKey, DidVerificationKeyRelationship, KeyChange, KeyHistoryEntry,
+	},
+	mock::*,
+	mock_utils::*,
+};
 
 #[test]
 fn check_successful_authentication_key_update() {
@@ -596,6 +603,38 @@ fn check_successful_attestation_key_max_public_keys_update() {
 		});
 }
 
+#[test]
+fn check_key_type_not_allowed_attestation_key_update() {
+	let auth_key = get_ed25519_authentication_key(&AUTH_SEED_0);
+	let alice_did = get_did_identifier_from_ed25519_key(auth_key.public());
+	let new_att_key = get_sr25519_attestation_key(&ATT_SEED_0);
+
+	let old_did_details =
+		generate_base_did_details::<Test>(DidVerificationKey::from(auth_key.public()), Some(alice_did.clone()));
+
+	let origin = build_test_origin(alice_did.clone(), alice_did.clone());
+
+	ExtBuilder::default()
+		.with_balances(vec![(alice_did.clone(), DEFAULT_BALANCE)])
+		.with_dids(vec![(alice_did.clone(), old_did_details)])
+		.build_and_execute_with_sanity_tests(None, || {
+			ForbiddenKeyTypes::set(vec![(
+				DidKeyRelationship::Verification(DidVerificationKeyRelationship::AssertionMethod),
+				DidKeyType::Sr25519,
+			)]);
+			assert_noop!(
+				Did::set_attestation_key(origin.clone(), DidVerificationKey::from(new_att_key.public())),
+				did::Error::<Test>::KeyTypeNotAllowed
+			);
+
+			// The same key type is still allowed for other relationships.
+			assert_ok!(Did::set_delegation_key(
+				origin,
+				DidVerificationKey::from(new_att_key.public())
+			));
+		});
+}
+
 #[test]
 fn check_reused_key_attestation_key_update() {
 	let old_auth_key = get_ed25519_authentication_key(&AUTH_SEED_0);
@@ -968,3 +1007,327 @@ fn check_key_not_found_key_agreement_key_deletion_error() {
 			);
 		});
 }
+
+#[test]
+fn check_successful_multisig_authentication_key_update() {
+	let old_auth_key = get_ed25519_authentication_key(&AUTH_SEED_0);
+	let alice_did = get_did_identifier_from_ed25519_key(old_auth_key.public());
+	let signatories = vec![ACCOUNT_02, ACCOUNT_00, ACCOUNT_01];
+	let multisig_account = crate::utils::multisig_account_id(signatories.clone(), 2)
+		.expect("Multisig account should be derived for valid signatories.");
+
+	let old_did_details =
+		generate_base_did_details::<Test>(DidVerificationKey::from(old_auth_key.public()), Some(alice_did.clone()));
+
+	let origin = build_test_origin(alice_did.clone(), alice_did.clone());
+
+	ExtBuilder::default()
+		.with_dids(vec![(alice_did.clone(), old_did_details)])
+		.with_balances(vec![(alice_did.clone(), DEFAULT_BALANCE)])
+		.build_and_execute_with_sanity_tests(None, || {
+			System::set_block_number(1);
+			assert_ok!(Did::set_multisig_authentication_key(
+				origin,
+				signatories.try_into().expect("Too many signatories."),
+				2
+			));
+
+			let new_did_details = Did::get_did(&alice_did).expect("ALICE_DID should be present on chain.");
+			assert_eq!(
+				new_did_details.authentication_key,
+				generate_key_id(&DidVerificationKey::Account(multisig_account.clone()).into())
+			);
+			assert_eq!(new_did_details.public_keys.len(), 1);
+			// Calls submitted by the multisig account are authorized by the DID.
+			assert_ok!(Did::verify_account_authorization(
+				&alice_did,
+				&multisig_account,
+				did::DidVerificationKeyRelationship::Authentication
+			));
+		});
+}
+
+#[test]
+fn check_multisig_account_ignores_signatories_order() {
+	assert_eq!(
+		crate::utils::multisig_account_id(vec![ACCOUNT_00, ACCOUNT_01, ACCOUNT_02], 2),
+		crate::utils::multisig_account_id(vec![ACCOUNT_02, ACCOUNT_01, ACCOUNT_00], 2)
+	);
+	assert_ne!(
+		crate::utils::multisig_account_id(vec![ACCOUNT_00, ACCOUNT_01, ACCOUNT_02], 2),
+		crate::utils::multisig_account_id(vec![ACCOUNT_00, ACCOUNT_01, ACCOUNT_02], 3)
+	);
+}
+
+#[test]
+fn check_invalid_multisig_authentication_key_update() {
+	let old_auth_key = get_ed25519_authentication_key(&AUTH_SEED_0);
+	let alice_did = get_did_identifier_from_ed25519_key(old_auth_key.public());
+
+	let old_did_details =
+		generate_base_did_details::<Test>(DidVerificationKey::from(old_auth_key.public()), Some(alice_did.clone()));
+
+	let origin = build_test_origin(alice_did.clone(), alice_did.clone());
+
+	ExtBuilder::default()
+		.with_dids(vec![(alice_did.clone(), old_did_details)])
+		.with_balances(vec![(alice_did.clone(), DEFAULT_BALANCE)])
+		.build_and_execute_with_sanity_tests(None, || {
+			// Threshold larger than the number of signatories.
+			assert_noop!(
+				Did::set_multisig_authentication_key(
+					origin.clone(),
+					vec![ACCOUNT_00, ACCOUNT_01].try_into().expect("Too many signatories."),
+					3
+				),
+				did::Error::<Test>::InvalidMultisig
+			);
+			// Threshold of a single signatory.
+			assert_noop!(
+				Did::set_multisig_authentication_key(
+					origin.clone(),
+					vec![ACCOUNT_00, ACCOUNT_01].try_into().expect("Too many signatories."),
+					1
+				),
+				did::Error::<Test>::InvalidMultisig
+			);
+			// Duplicate signatories.
+			assert_noop!(
+				Did::set_multisig_authentication_key(
+					origin,
+					vec![ACCOUNT_00, ACCOUNT_00, ACCOUNT_01]
+						.try_into()
+						.expect("Too many signatories."),
+					2
+				),
+				did::Error::<Test>::InvalidMultisig
+			);
+		});
+}
+
+#[test]
+fn check_key_history_on_authentication_key_update() {
+	let old_auth_key = get_ed25519_authentication_key(&AUTH_SEED_0);
+	let alice_did = get_did_identifier_from_ed25519_key(old_auth_key.public());
+	let new_auth_key = get_ed25519_authentication_key(&AUTH_SEED_1);
+	let old_key_id = generate_key_id(&DidVerificationKey::from(old_auth_key.public()).into());
+	let new_key_id = generate_key_id(&DidVerificationKey::from(new_auth_key.public()).into());
+
+	let old_did_details =
+		generate_base_did_details::<Test>(DidVerificationKey::from(old_auth_key.public()), Some(alice_did.clone()));
+
+	let origin = build_test_origin(alice_did.clone(), alice_did.clone());
+
+	ExtBuilder::default()
+		.with_dids(vec![(alice_did.clone(), old_did_details)])
+		.with_balances(vec![(alice_did.clone(), DEFAULT_BALANCE)])
+		.build_and_execute_with_sanity_tests(None, || {
+			System::set_block_number(5);
+			assert_ok!(Did::set_authentication_key(
+				origin,
+				DidVerificationKey::from(new_auth_key.public())
+			));
+			assert_eq!(
+				Did::get_key_history(&alice_did, old_key_id).into_inner(),
+				vec![KeyHistoryEntry {
+					relationship: DidKeyRelationship::Verification(DidVerificationKeyRelationship::Authentication),
+					change: KeyChange::Removed,
+					block_number: 5,
+				}]
+			);
+			assert_eq!(
+				Did::get_key_history(&alice_did, new_key_id).into_inner(),
+				vec![KeyHistoryEntry {
+					relationship: DidKeyRelationship::Verification(DidVerificationKeyRelationship::Authentication),
+					change: KeyChange::Added,
+					block_number: 5,
+				}]
+			);
+		});
+}
+
+#[test]
+fn check_key_history_drops_oldest_change() {
+	let auth_key = get_ed25519_authentication_key(&AUTH_SEED_0);
+	let alice_did = get_did_identifier_from_ed25519_key(auth_key.public());
+	let att_key = get_sr25519_attestation_key(&ATT_SEED_0);
+	let att_key_id = generate_key_id(&DidVerificationKey::from(att_key.public()).into());
+
+	let did_details =
+		generate_base_did_details::<Test>(DidVerificationKey::from(auth_key.public()), Some(alice_did.clone()));
+
+	let origin = build_test_origin(alice_did.clone(), alice_did.clone());
+
+	ExtBuilder::default()
+		.with_dids(vec![(alice_did.clone(), did_details)])
+		.with_balances(vec![(alice_did.clone(), DEFAULT_BALANCE)])
+		.build_and_execute_with_sanity_tests(None, || {
+			for block_number in 1..=2 {
+				System::set_block_number(block_number);
+				assert_ok!(Did::set_attestation_key(
+					origin.clone(),
+					DidVerificationKey::from(att_key.public())
+				));
+				assert_ok!(Did::remove_attestation_key(origin.clone()));
+			}
+			let assertion_method = DidKeyRelationship::Verification(DidVerificationKeyRelationship::AssertionMethod);
+			// The history is limited to three entries, so the first addition is dropped.
+			assert_eq!(
+				Did::get_key_history(&alice_did, att_key_id).into_inner(),
+				vec![
+					KeyHistoryEntry {
+						relationship: assertion_method,
+						change: KeyChange::Removed,
+						block_number: 1,
+					},
+					KeyHistoryEntry {
+						relationship: assertion_method,
+						change: KeyChange::Added,
+						block_number: 2,
+					},
+					KeyHistoryEntry {
+						relationship: assertion_method,
+						change: KeyChange::Removed,
+						block_number: 2,
+					},
+				]
+			);
+		});
+}
+
+#[test]
+fn check_verify_signature_at_after_authentication_key_update() {
+	let old_auth_key = get_ed25519_authentication_key(&AUTH_SEED_0);
+	let alice_did = get_did_identifier_from_ed25519_key(old_auth_key.public());
+	let new_auth_key = get_ed25519_authentication_key(&AUTH_SEED_1);
+	let payload = b"credential presentation".to_vec();
+	let old_signature = did::DidSignature::from(old_auth_key.sign(&payload));
+	let new_signature = did::DidSignature::from(new_auth_key.sign(&payload));
+
+	let old_did_details =
+		generate_base_did_details::<Test>(DidVerificationKey::from(old_auth_key.public()), Some(alice_did.clone()));
+
+	let origin = build_test_origin(alice_did.clone(), alice_did.clone());
+
+	ExtBuilder::default()
+		.with_dids(vec![(alice_did.clone(), old_did_details)])
+		.with_balances(vec![(alice_did.clone(), DEFAULT_BALANCE)])
+		.build_and_execute_with_sanity_tests(None, || {
+			// The old key predates the key history.
+			assert_eq!(
+				Did::verify_signature_at(&alice_did, &payload, &old_signature, 4),
+				vec![DidVerificationKeyRelationship::Authentication]
+			);
+
+			System::set_block_number(5);
+			assert_ok!(Did::set_authentication_key(
+				origin,
+				DidVerificationKey::from(new_auth_key.public())
+			));
+
+			assert_eq!(
+				Did::verify_signature_at(&alice_did, &payload, &old_signature, 4),
+				vec![DidVerificationKeyRelationship::Authentication]
+			);
+			assert!(Did::verify_signature_at(&alice_did, &payload, &old_signature, 5).is_empty());
+			assert!(Did::verify_signature_at(&alice_did, &payload, &new_signature, 4).is_empty());
+			assert_eq!(
+				Did::verify_signature_at(&alice_did, &payload, &new_signature, 5),
+				vec![DidVerificationKeyRelationship::Authentication]
+			);
+		});
+}
+
+#[test]
+fn check_removed_keys_deposit_and_pruning() {
+	let auth_key = get_ed25519_authentication_key(&AUTH_SEED_0);
+	let alice_did = get_did_identifier_from_ed25519_key(auth_key.public());
+	let att_keys: Vec<DidVerificationKey<AccountId>> = vec![
+		get_ed25519_attestation_key(&ATT_SEED_0).public().into(),
+		get_sr25519_attestation_key(&ATT_SEED_0).public().into(),
+		get_ecdsa_attestation_key(&ATT_SEED_0).public().into(),
+		get_ed25519_attestation_key(&ATT_SEED_1).public().into(),
+	];
+	let att_key_ids: Vec<_> = att_keys
+		.iter()
+		.map(|key| generate_key_id(&key.clone().into()))
+		.collect();
+
+	let did_details =
+		generate_base_did_details::<Test>(DidVerificationKey::from(auth_key.public()), Some(alice_did.clone()));
+
+	let origin = build_test_origin(alice_did.clone(), alice_did.clone());
+
+	ExtBuilder::default()
+		.with_dids(vec![(alice_did.clone(), did_details)])
+		.with_balances(vec![(alice_did.clone(), DEFAULT_BALANCE)])
+		.build_and_execute_with_sanity_tests(None, || {
+			att_keys.iter().for_each(|att_key| {
+				assert_ok!(Did::set_attestation_key(origin.clone(), att_key.clone()));
+			});
+
+			// Only two removed keys are kept, so the history of the oldest one is dropped.
+			assert_eq!(
+				Did::get_removed_keys(&alice_did).into_inner(),
+				vec![att_key_ids[1], att_key_ids[2]]
+			);
+			assert!(Did::get_key_history(&alice_did, att_key_ids[0]).is_empty());
+			assert!(!did::HistoricalPublicKeys::<Test>::contains_key(
+				&alice_did,
+				att_key_ids[0]
+			));
+			assert!(did::HistoricalPublicKeys::<Test>::contains_key(
+				&alice_did,
+				att_key_ids[1]
+			));
+			assert!(!Did::get_key_history(&alice_did, att_key_ids[1]).is_empty());
+			assert_eq!(
+				Did::get_did(&alice_did)
+					.expect("DID should be present on chain.")
+					.deposit
+					.amount,
+				<Test as did::Config>::BaseDeposit::get()
+					+ <Test as did::Config>::KeyDeposit::get()
+					+ 2 * <Test as did::Config>::KeyHistoryDeposit::get()
+			);
+
+			// A removed key that is used again is no longer charged as removed key.
+			assert_ok!(Did::set_attestation_key(origin.clone(), att_keys[1].clone()));
+			assert_eq!(
+				Did::get_removed_keys(&alice_did).into_inner(),
+				vec![att_key_ids[2], att_key_ids[3]]
+			);
+		});
+}
+
+#[test]
+fn check_key_history_removed_on_delete() {
+	let auth_key = get_ed25519_authentication_key(&AUTH_SEED_0);
+	let alice_did = get_did_identifier_from_ed25519_key(auth_key.public());
+	let att_key = get_sr25519_attestation_key(&ATT_SEED_0);
+
+	let did_details =
+		generate_base_did_details::<Test>(DidVerificationKey::from(auth_key.public()), Some(alice_did.clone()));
+
+	let origin = build_test_origin(alice_did.clone(), alice_did.clone());
+
+	ExtBuilder::default()
+		.with_dids(vec![(alice_did.clone(), did_details)])
+		.with_balances(vec![(alice_did.clone(), DEFAULT_BALANCE)])
+		.build_and_execute_with_sanity_tests(None, || {
+			assert_ok!(Did::set_attestation_key(
+				origin.clone(),
+				DidVerificationKey::from(att_key.public())
+			));
+			assert_ok!(Did::remove_attestation_key(origin.clone()));
+			assert_eq!(Did::get_removed_keys(&alice_did).len(), 1);
+
+			assert_ok!(Did::delete(origin, 0));
+
+			assert!(did::KeyHistory::<Test>::iter_prefix(&alice_did).next().is_none());
+			assert!(did::HistoricalPublicKeys::<Test>::iter_prefix(&alice_did)
+				.next()
+				.is_none());
+			assert!(Did::get_removed_keys(&alice_did).is_empty());
+		});
+}
@@ -16,11 +16,17 @@
 
 // If you feel like getting in touch with us, you can do so at info@botlabs.org
 
+mod also_known_as;
 mod create;
 mod create_from_account;
 mod delete;
 mod dispatch_as;
+mod document_size;
+mod endorsement;
+mod free_trial;
 mod manage_keys;
+mod promotion;
+mod remark;
 mod service_endpoint;
 mod submit;
 mod verify_did_op;
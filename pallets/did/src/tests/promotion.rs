@@ -0,0 +1,183 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::fungible::{Inspect, InspectHold},
+};
+use parity_scale_codec::Encode;
+use sp_core::Pair;
+use sp_runtime::traits::Hash;
+
+use crate::{
+	self as did,
+	did_details::{DidVerificationKey, DidVerificationKeyRelationship},
+	mock::*,
+	mock_utils::*,
+};
+
+#[test]
+fn check_successful_promotion() {
+	let auth_key = get_sr25519_authentication_key(&AUTH_SEED_0);
+	let alice_did = get_did_identifier_from_sr25519_key(auth_key.public());
+	let auth_did_key = DidVerificationKey::from(auth_key.public());
+	let mut details = generate_base_did_creation_details::<Test>(alice_did.clone(), ACCOUNT_00);
+	details.new_key_agreement_keys = get_key_agreement_keys::<Test>(1);
+	let signature = auth_key.sign(details.encode().as_ref());
+
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, DEFAULT_BALANCE)])
+		.build_and_execute_with_sanity_tests(None, || {
+			assert_ok!(Did::promote_light_did(
+				RuntimeOrigin::signed(ACCOUNT_00),
+				Box::new(details),
+				did::DidSignature::from(signature),
+				None,
+			));
+			let stored_did = Did::get_did(&alice_did).expect("ALICE_DID should be present on chain.");
+			assert_eq!(stored_did.authentication_key, generate_key_id(&auth_did_key.into()));
+			assert_eq!(stored_did.key_agreement_keys.len(), 1);
+			assert_eq!(stored_did.last_tx_counter, 0u64);
+			assert_eq!(stored_did.deposit.owner, ACCOUNT_00);
+			assert_eq!(stored_did.deposit.amount, 0);
+
+			assert_eq!(Balances::balance_on_hold(&HoldReason::Deposit.into(), &ACCOUNT_00), 0);
+			assert_eq!(Balances::balance(&ACCOUNT_FEE), <Test as did::Config>::Fee::get());
+		});
+}
+
+#[test]
+fn check_promotion_deposit_collected_on_update() {
+	let auth_key = get_sr25519_authentication_key(&AUTH_SEED_0);
+	let alice_did = get_did_identifier_from_sr25519_key(auth_key.public());
+	let details = generate_base_did_creation_details::<Test>(alice_did.clone(), ACCOUNT_00);
+	let signature = auth_key.sign(details.encode().as_ref());
+	let new_key_agreement_key = get_x25519_encryption_key(&ENC_SEED_0);
+
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, DEFAULT_BALANCE)])
+		.build_and_execute_with_sanity_tests(None, || {
+			assert_ok!(Did::promote_light_did(
+				RuntimeOrigin::signed(ACCOUNT_00),
+				Box::new(details),
+				did::DidSignature::from(signature),
+				None,
+			));
+			assert_eq!(Balances::balance_on_hold(&HoldReason::Deposit.into(), &ACCOUNT_00), 0);
+
+			assert_ok!(Did::add_key_agreement_key(
+				build_test_origin(ACCOUNT_00, alice_did.clone()),
+				new_key_agreement_key,
+			));
+			let stored_did = Did::get_did(&alice_did).expect("ALICE_DID should be present on chain.");
+			assert_eq!(stored_did.deposit.amount, stored_did.calculate_deposit(0));
+			assert_eq!(
+				Balances::balance_on_hold(&HoldReason::Deposit.into(), &ACCOUNT_00),
+				<Test as did::Config>::BaseDeposit::get() + <Test as did::Config>::KeyDeposit::get()
+			);
+		});
+}
+
+#[test]
+fn check_successful_promotion_with_call() {
+	let auth_key = get_sr25519_authentication_key(&AUTH_SEED_0);
+	let alice_did = get_did_identifier_from_sr25519_key(auth_key.public());
+	let details = generate_base_did_creation_details::<Test>(alice_did.clone(), ACCOUNT_00);
+	let signature = auth_key.sign(details.encode().as_ref());
+
+	let call_operation = generate_test_did_call(
+		DidVerificationKeyRelationship::Authentication,
+		alice_did.clone(),
+		ACCOUNT_00,
+	);
+	let call_signature = auth_key.sign(call_operation.encode().as_ref());
+
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, DEFAULT_BALANCE)])
+		.build_and_execute_with_sanity_tests(None, || {
+			assert_ok!(Did::promote_light_did(
+				RuntimeOrigin::signed(ACCOUNT_00),
+				Box::new(details),
+				did::DidSignature::from(signature),
+				Some((
+					Box::new(call_operation.operation),
+					did::DidSignature::from(call_signature)
+				)),
+			));
+			let stored_did = Did::get_did(&alice_did).expect("ALICE_DID should be present on chain.");
+			assert_eq!(stored_did.last_tx_counter, 1u64);
+			assert!(ctype::Ctypes::<Test>::contains_key(
+				<Test as frame_system::Config>::Hashing::hash(&get_authentication_key_test_input())
+			));
+		});
+}
+
+#[test]
+fn check_promotion_with_attestation_key() {
+	let auth_key = get_sr25519_authentication_key(&AUTH_SEED_0);
+	let alice_did = get_did_identifier_from_sr25519_key(auth_key.public());
+	let att_key = get_sr25519_attestation_key(&ATT_SEED_0);
+	let mut details = generate_base_did_creation_details::<Test>(alice_did, ACCOUNT_00);
+	details.new_attestation_key = Some(DidVerificationKey::from(att_key.public()));
+	let signature = auth_key.sign(details.encode().as_ref());
+
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, DEFAULT_BALANCE)])
+		.build_and_execute_with_sanity_tests(None, || {
+			assert_noop!(
+				Did::promote_light_did(
+					RuntimeOrigin::signed(ACCOUNT_00),
+					Box::new(details),
+					did::DidSignature::from(signature),
+					None,
+				),
+				did::Error::<Test>::InvalidLightDidDetails
+			);
+		});
+}
+
+#[test]
+fn check_promotion_with_call_for_other_did() {
+	let auth_key = get_sr25519_authentication_key(&AUTH_SEED_0);
+	let alice_did = get_did_identifier_from_sr25519_key(auth_key.public());
+	let bob_key = get_sr25519_authentication_key(&AUTH_SEED_1);
+	let bob_did = get_did_identifier_from_sr25519_key(bob_key.public());
+	let details = generate_base_did_creation_details::<Test>(alice_did.clone(), ACCOUNT_00);
+	let signature = auth_key.sign(details.encode().as_ref());
+
+	let call_operation = generate_test_did_call(DidVerificationKeyRelationship::Authentication, bob_did, ACCOUNT_00);
+	let call_signature = bob_key.sign(call_operation.encode().as_ref());
+
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, DEFAULT_BALANCE)])
+		.build_and_execute_with_sanity_tests(None, || {
+			assert_noop!(
+				Did::promote_light_did(
+					RuntimeOrigin::signed(ACCOUNT_00),
+					Box::new(details),
+					did::DidSignature::from(signature),
+					Some((
+						Box::new(call_operation.operation),
+						did::DidSignature::from(call_signature)
+					)),
+				),
+				did::Error::<Test>::InvalidDidAuthorizationCall
+			);
+			assert!(Did::get_did(&alice_did).is_none());
+		});
+}
@@ -0,0 +1,58 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+use frame_support::{assert_noop, assert_ok};
+use sp_core::Pair;
+use sp_runtime::traits::{BadOrigin, Hash};
+
+use crate::{self as did, did_details::DidVerificationKey, mock::*, mock_utils::*};
+
+#[test]
+fn check_successful_did_remark() {
+	let auth_key = get_ed25519_authentication_key(&AUTH_SEED_0);
+	let alice_did = get_did_identifier_from_ed25519_key(auth_key.public());
+	let did_details = generate_base_did_details::<Test>(DidVerificationKey::from(auth_key.public()), None);
+	let data = b"credential-root".to_vec();
+	let content_hash = <Test as frame_system::Config>::Hashing::hash(&data);
+
+	let origin = build_test_origin(alice_did.clone(), alice_did.clone());
+
+	ExtBuilder::default()
+		.with_dids(vec![(alice_did.clone(), did_details)])
+		.build_and_execute_with_sanity_tests(None, || {
+			// Events are not registered for the genesis block.
+			System::set_block_number(1);
+			assert_ok!(Did::did_remark(origin, data));
+
+			let record = System::events().pop().expect("An event should have been emitted.");
+			assert_eq!(
+				record.event,
+				RuntimeEvent::Did(did::Event::DidRemarked(alice_did, content_hash, System::block_number()))
+			);
+			assert_eq!(record.topics, vec![content_hash]);
+		});
+}
+
+#[test]
+fn check_did_remark_requires_did_origin() {
+	ExtBuilder::default().build_and_execute_with_sanity_tests(None, || {
+		assert_noop!(
+			Did::did_remark(RuntimeOrigin::signed(ACCOUNT_00), b"credential-root".to_vec()),
+			BadOrigin
+		);
+	});
+}
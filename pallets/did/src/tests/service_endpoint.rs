@@ -25,7 +25,12 @@ use sp_runtime::{SaturatedConversion, TokenError};
 use sp_std::convert::TryInto;
 
 use crate::{
-	self as did, did_details::DidVerificationKey, mock::*, mock_utils::*, service_endpoints::DidEndpoint, HoldReason,
+	self as did,
+	did_details::DidVerificationKey,
+	mock::*,
+	mock_utils::*,
+	service_endpoints::{DidEndpoint, HashedDidEndpoint},
+	HoldReason,
 };
 
 #[test]
@@ -481,3 +486,129 @@ fn check_service_not_present_deletion_error() {
 			);
 		});
 }
+
+#[test]
+fn check_hashed_service_addition_successful() {
+	let auth_key = get_ed25519_authentication_key(&AUTH_SEED_0);
+	let alice_did = get_did_identifier_from_ed25519_key(auth_key.public());
+	let old_service_endpoint = DidEndpoint::new(b"id".to_vec(), vec![b"type".to_vec()], vec![b"url".to_vec()]);
+	let new_service_endpoint = HashedDidEndpoint::new(b"id2".to_vec(), b"ipfs".to_vec(), Hash::repeat_byte(1));
+
+	let old_did_details =
+		generate_base_did_details::<Test>(DidVerificationKey::from(auth_key.public()), Some(alice_did.clone()));
+
+	let origin = build_test_origin(alice_did.clone(), alice_did.clone());
+
+	ExtBuilder::default()
+		.with_balances(vec![(alice_did.clone(), DEFAULT_BALANCE)])
+		.with_dids(vec![(alice_did.clone(), old_did_details)])
+		.with_endpoints(vec![(alice_did.clone(), vec![old_service_endpoint])])
+		.build_and_execute_with_sanity_tests(None, || {
+			assert_ok!(Did::add_hashed_service_endpoint(origin, new_service_endpoint.clone()));
+			let stored_endpoint =
+				did::pallet::HashedServiceEndpoints::<Test>::get(&alice_did, &new_service_endpoint.id)
+					.expect("Hashed service endpoint should be stored.");
+			assert_eq!(stored_endpoint, new_service_endpoint);
+			// Hashed service endpoints count towards the same limit and deposit.
+			assert_eq!(did::pallet::DidEndpointsCount::<Test>::get(&alice_did), 2);
+			assert_eq!(
+				Balances::balance_on_hold(&HoldReason::Deposit.into(), &alice_did),
+				Did::get_did(&alice_did).unwrap().calculate_deposit(2)
+			);
+		});
+}
+
+#[test]
+fn check_hashed_service_addition_id_already_used_error() {
+	let auth_key = get_ed25519_authentication_key(&AUTH_SEED_0);
+	let alice_did = get_did_identifier_from_ed25519_key(auth_key.public());
+	let old_service_endpoint = DidEndpoint::new(b"id".to_vec(), vec![b"type".to_vec()], vec![b"url".to_vec()]);
+	let new_service_endpoint = HashedDidEndpoint::new(b"id".to_vec(), b"ipfs".to_vec(), Hash::repeat_byte(1));
+
+	let old_did_details =
+		generate_base_did_details::<Test>(DidVerificationKey::from(auth_key.public()), Some(alice_did.clone()));
+
+	let origin = build_test_origin(alice_did.clone(), alice_did.clone());
+
+	ExtBuilder::default()
+		.with_balances(vec![(alice_did.clone(), DEFAULT_BALANCE)])
+		.with_dids(vec![(alice_did.clone(), old_did_details)])
+		.with_endpoints(vec![(alice_did.clone(), vec![old_service_endpoint.clone()])])
+		.build_and_execute_with_sanity_tests(None, || {
+			assert_noop!(
+				Did::add_hashed_service_endpoint(origin.clone(), new_service_endpoint.clone()),
+				did::Error::<Test>::ServiceAlreadyExists
+			);
+
+			// The same holds the other way around.
+			assert_ok!(Did::remove_service_endpoint(
+				origin.clone(),
+				old_service_endpoint.id.clone()
+			));
+			assert_ok!(Did::add_hashed_service_endpoint(origin.clone(), new_service_endpoint));
+			assert_noop!(
+				Did::add_service_endpoint(origin, old_service_endpoint),
+				did::Error::<Test>::ServiceAlreadyExists
+			);
+		});
+}
+
+#[test]
+fn check_hashed_service_addition_max_services_exceeded_error() {
+	let auth_key = get_ed25519_authentication_key(&AUTH_SEED_0);
+	let alice_did = get_did_identifier_from_ed25519_key(auth_key.public());
+	let old_service_endpoints = get_service_endpoints(
+		<Test as did::Config>::MaxNumberOfServicesPerDid::get(),
+		<Test as did::Config>::MaxServiceIdLength::get(),
+		<Test as did::Config>::MaxNumberOfTypesPerService::get(),
+		<Test as did::Config>::MaxServiceTypeLength::get(),
+		<Test as did::Config>::MaxNumberOfUrlsPerService::get(),
+		<Test as did::Config>::MaxServiceUrlLength::get(),
+	);
+	let new_service_endpoint = HashedDidEndpoint::new(b"id".to_vec(), b"ipfs".to_vec(), Hash::repeat_byte(1));
+
+	let old_did_details =
+		generate_base_did_details::<Test>(DidVerificationKey::from(auth_key.public()), Some(alice_did.clone()));
+
+	let origin = build_test_origin(alice_did.clone(), alice_did.clone());
+
+	ExtBuilder::default()
+		.with_balances(vec![(alice_did.clone(), DEFAULT_BALANCE)])
+		.with_dids(vec![(alice_did.clone(), old_did_details)])
+		.with_endpoints(vec![(alice_did, old_service_endpoints)])
+		.build_and_execute_with_sanity_tests(None, || {
+			assert_noop!(
+				Did::add_hashed_service_endpoint(origin, new_service_endpoint),
+				did::Error::<Test>::MaxNumberOfServicesExceeded
+			);
+		});
+}
+
+#[test]
+fn check_hashed_service_deletion_successful() {
+	let auth_key = get_ed25519_authentication_key(&AUTH_SEED_0);
+	let alice_did = get_did_identifier_from_ed25519_key(auth_key.public());
+	let service_endpoint = HashedDidEndpoint::new(b"id".to_vec(), b"ipfs".to_vec(), Hash::repeat_byte(1));
+
+	let old_did_details =
+		generate_base_did_details::<Test>(DidVerificationKey::from(auth_key.public()), Some(alice_did.clone()));
+
+	let origin = build_test_origin(alice_did.clone(), alice_did.clone());
+
+	ExtBuilder::default()
+		.with_dids(vec![(alice_did.clone(), old_did_details)])
+		.with_balances(vec![(alice_did.clone(), DEFAULT_BALANCE)])
+		.build_and_execute_with_sanity_tests(None, || {
+			assert_ok!(Did::add_hashed_service_endpoint(
+				origin.clone(),
+				service_endpoint.clone()
+			));
+			assert_ok!(Did::remove_service_endpoint(origin, service_endpoint.id));
+			// Counter should be deleted from the storage.
+			assert_eq!(did::pallet::DidEndpointsCount::<Test>::get(&alice_did), 0);
+			assert_eq!(
+				did::pallet::HashedServiceEndpoints::<Test>::iter_prefix(&alice_did).count(),
+				0
+			);
+		});
+}
@@ -590,3 +590,154 @@ fn check_null_key_error() {
 		);
 	});
 }
+
+#[test]
+fn check_call_canonical_signing_payload_successful() {
+	let auth_key = get_sr25519_authentication_key(&AUTH_SEED_0);
+	let did = get_did_identifier_from_sr25519_key(auth_key.public());
+	let caller = ACCOUNT_00;
+	let mock_did = generate_base_did_details::<Test>(DidVerificationKey::from(auth_key.public()), Some(did.clone()));
+
+	let call_operation = generate_test_did_call(
+		DidVerificationKeyRelationship::Authentication,
+		did.clone(),
+		caller.clone(),
+	);
+
+	ExtBuilder::default()
+		.with_balances(vec![(did.clone(), DEFAULT_BALANCE)])
+		.with_dids(vec![(did, mock_did)])
+		.build_and_execute_with_sanity_tests(None, || {
+			let genesis_hash = System::block_hash(0);
+			let signing_payload = call_operation.operation.signing_payload(genesis_hash.as_ref(), 0);
+			assert_eq!(
+				signing_payload,
+				[
+					did::did_details::DID_OPERATION_SIGNING_DOMAIN,
+					&[did::did_details::DID_OPERATION_SIGNING_PAYLOAD_VERSION],
+					genesis_hash.as_ref(),
+					&0u32.to_le_bytes(),
+					&call_operation.operation.encode()
+				]
+				.concat()
+			);
+			assert_eq!(
+				signing_payload,
+				Did::did_operation_signing_payload(&call_operation.operation)
+			);
+			let signature = auth_key.sign(&signing_payload);
+
+			assert_ok!(Did::submit_did_call(
+				RuntimeOrigin::signed(caller),
+				Box::new(call_operation.operation),
+				did::DidSignature::from(signature)
+			));
+		});
+}
+
+#[test]
+fn check_call_canonical_signing_payload_wrong_chain_error() {
+	let auth_key = get_sr25519_authentication_key(&AUTH_SEED_0);
+	let did = get_did_identifier_from_sr25519_key(auth_key.public());
+	let caller = ACCOUNT_00;
+	let mock_did = generate_base_did_details::<Test>(DidVerificationKey::from(auth_key.public()), Some(did.clone()));
+
+	let call_operation = generate_test_did_call(
+		DidVerificationKeyRelationship::Authentication,
+		did.clone(),
+		caller.clone(),
+	);
+	let signature = auth_key.sign(&call_operation.operation.signing_payload(&[1u8; 32], 0));
+
+	ExtBuilder::default()
+		.with_balances(vec![(did.clone(), DEFAULT_BALANCE)])
+		.with_dids(vec![(did, mock_did)])
+		.build_and_execute_with_sanity_tests(None, || {
+			assert_noop!(
+				Did::submit_did_call(
+					RuntimeOrigin::signed(caller),
+					Box::new(call_operation.operation),
+					did::DidSignature::from(signature)
+				),
+				did::Error::<Test>::InvalidSignature
+			);
+		});
+}
+
+#[test]
+fn check_call_legacy_signing_payload_after_deadline_error() {
+	let auth_key = get_sr25519_authentication_key(&AUTH_SEED_0);
+	let did = get_did_identifier_from_sr25519_key(auth_key.public());
+	let caller = ACCOUNT_00;
+	let mock_did = generate_base_did_details::<Test>(DidVerificationKey::from(auth_key.public()), Some(did.clone()));
+
+	let call_operation = generate_test_did_call(
+		DidVerificationKeyRelationship::Authentication,
+		did.clone(),
+		caller.clone(),
+	);
+	let legacy_signature = auth_key.sign(call_operation.encode().as_ref());
+
+	LegacySignatureDeadline::set(1);
+	ExtBuilder::default()
+		.with_balances(vec![(did.clone(), DEFAULT_BALANCE)])
+		.with_dids(vec![(did, mock_did)])
+		.build_and_execute_with_sanity_tests(None, || {
+			System::set_block_number(1);
+			assert_noop!(
+				Did::submit_did_call(
+					RuntimeOrigin::signed(caller.clone()),
+					Box::new(call_operation.operation.clone()),
+					did::DidSignature::from(legacy_signature)
+				),
+				did::Error::<Test>::InvalidSignature
+			);
+
+			let signature = auth_key.sign(&Did::did_operation_signing_payload(&call_operation.operation));
+			assert_ok!(Did::submit_did_call(
+				RuntimeOrigin::signed(caller),
+				Box::new(call_operation.operation),
+				did::DidSignature::from(signature)
+			));
+		});
+	LegacySignatureDeadline::set(u64::MAX);
+}
+
+#[test]
+fn check_validate_did_call() {
+	let auth_key = get_sr25519_authentication_key(&AUTH_SEED_0);
+	let did = get_did_identifier_from_sr25519_key(auth_key.public());
+	let caller = ACCOUNT_00;
+	let mock_did = generate_base_did_details::<Test>(DidVerificationKey::from(auth_key.public()), Some(did.clone()));
+
+	let call_operation = generate_test_did_call(
+		DidVerificationKeyRelationship::Authentication,
+		did.clone(),
+		caller.clone(),
+	);
+	let signature = did::DidSignature::from(auth_key.sign(call_operation.encode().as_ref()));
+	let mut invalid_nonce_operation = call_operation.clone();
+	invalid_nonce_operation.operation.tx_counter = mock_did.last_tx_counter + 2u64;
+	let invalid_nonce_signature = did::DidSignature::from(auth_key.sign(invalid_nonce_operation.encode().as_ref()));
+
+	ExtBuilder::default()
+		.with_balances(vec![(did.clone(), DEFAULT_BALANCE)])
+		.with_dids(vec![(did.clone(), mock_did.clone())])
+		.build_and_execute_with_sanity_tests(None, || {
+			assert_ok!(Did::validate_did_call(&call_operation.operation, &signature));
+			// The validation does not consume the nonce.
+			assert_eq!(
+				Did::get_did(&did).expect("DID should be present.").last_tx_counter,
+				mock_did.last_tx_counter
+			);
+
+			assert_err!(
+				Did::validate_did_call(&invalid_nonce_operation.operation, &invalid_nonce_signature),
+				did::Error::<Test>::InvalidNonce
+			);
+			assert_err!(
+				Did::validate_did_call(&call_operation.operation, &invalid_nonce_signature),
+				did::Error::<Test>::InvalidSignature
+			);
+		});
+}
@@ -22,17 +22,21 @@ use scale_info::prelude::format;
 use sp_core::Get;
 use sp_runtime::{SaturatedConversion, TryRuntimeError};
 
-use crate::{did_details::DidDetails, Config, Did, DidBlacklist, DidEndpointsCount, DidIdentifierOf, ServiceEndpoints};
+use crate::{
+	did_details::DidDetails, AlsoKnownAs, Config, Did, DidBlacklist, DidEndpointsCount, DidIdentifierOf, Endorsements,
+	EndorsementsCount, HashedServiceEndpoints, HistoricalPublicKeys, KeyHistory, RemovedKeys, ServiceEndpoints,
+};
 
 pub(crate) fn do_try_state<T: Config>() -> Result<(), TryRuntimeError> {
 	Did::<T>::iter().try_for_each(
 		|(did_subject, did_details): (DidIdentifierOf<T>, DidDetails<T>)| -> Result<(), TryRuntimeError> {
-			let service_endpoints_count = ServiceEndpoints::<T>::iter_prefix(&did_subject).count();
+			let service_endpoints_count = ServiceEndpoints::<T>::iter_prefix(&did_subject).count()
+				+ HashedServiceEndpoints::<T>::iter_prefix(&did_subject).count();
 
 			ensure!(
 				service_endpoints_count == DidEndpointsCount::<T>::get(&did_subject).saturated_into::<usize>(),
 				log_and_return_error_message(format!(
-					"Did {:?} has not matching service endpoints. In [ServiceEndpoints]: {:?} in [DidEndpointsCount]: {:?}",
+					"Did {:?} has not matching service endpoints. In [ServiceEndpoints] and [HashedServiceEndpoints]: {:?} in [DidEndpointsCount]: {:?}",
 					did_subject,
 					service_endpoints_count,
 					DidEndpointsCount::<T>::get(&did_subject)
@@ -70,7 +74,8 @@ pub(crate) fn do_try_state<T: Config>() -> Result<(), TryRuntimeError> {
 	)?;
 
 	DidBlacklist::<T>::iter_keys().try_for_each(|deleted_did_subject| -> Result<(), TryRuntimeError> {
-		let service_endpoints_count = ServiceEndpoints::<T>::iter_prefix(&deleted_did_subject).count();
+		let service_endpoints_count = ServiceEndpoints::<T>::iter_prefix(&deleted_did_subject).count()
+			+ HashedServiceEndpoints::<T>::iter_prefix(&deleted_did_subject).count();
 		ensure!(
 			service_endpoints_count == 0,
 			log_and_return_error_message(format!(
@@ -78,6 +83,89 @@ pub(crate) fn do_try_state<T: Config>() -> Result<(), TryRuntimeError> {
 				deleted_did_subject,
 			))
 		);
+		ensure!(
+			KeyHistory::<T>::iter_key_prefix(&deleted_did_subject).next().is_none(),
+			log_and_return_error_message(format!("Blacklisted did {:?} has a key history.", deleted_did_subject,))
+		);
+		ensure!(
+			HistoricalPublicKeys::<T>::iter_key_prefix(&deleted_did_subject)
+				.next()
+				.is_none(),
+			log_and_return_error_message(format!(
+				"Blacklisted did {:?} has historical public keys.",
+				deleted_did_subject,
+			))
+		);
+		Ok(())
+	})?;
+
+	RemovedKeys::<T>::iter().try_for_each(|(did_subject, removed_keys)| -> Result<(), TryRuntimeError> {
+		ensure!(
+			Did::<T>::contains_key(&did_subject),
+			log_and_return_error_message(format!("Did {:?} has removed keys but does not exist.", did_subject))
+		);
+		ensure!(
+			removed_keys
+				.iter()
+				.all(|key_id| KeyHistory::<T>::contains_key(&did_subject, key_id)),
+			log_and_return_error_message(format!("Did {:?} has removed keys without a key history.", did_subject))
+		);
+		Ok(())
+	})?;
+
+	HistoricalPublicKeys::<T>::iter_keys().try_for_each(|(did_subject, key_id)| -> Result<(), TryRuntimeError> {
+		ensure!(
+			KeyHistory::<T>::contains_key(&did_subject, key_id),
+			log_and_return_error_message(format!(
+				"Did {:?} has a historical public key without a key history.",
+				did_subject
+			))
+		);
+		Ok(())
+	})?;
+
+	AlsoKnownAs::<T>::iter_keys().try_for_each(|did_subject| -> Result<(), TryRuntimeError> {
+		ensure!(
+			Did::<T>::contains_key(&did_subject),
+			log_and_return_error_message(format!(
+				"Did {:?} has alsoKnownAs URIs but does not exist.",
+				did_subject
+			))
+		);
+		Ok(())
+	})?;
+
+	let mut total_endorsements_count: usize = 0;
+	EndorsementsCount::<T>::iter().try_for_each(|(endorser, count)| -> Result<(), TryRuntimeError> {
+		let endorsements_count = Endorsements::<T>::iter_keys()
+			.filter(|(_, endorsement_endorser)| *endorsement_endorser == endorser)
+			.count();
+		ensure!(
+			endorsements_count == count.saturated_into::<usize>(),
+			log_and_return_error_message(format!(
+				"Did {:?} has not matching endorsements. In [Endorsements]: {:?} in [EndorsementsCount]: {:?}",
+				endorser, endorsements_count, count
+			))
+		);
+		ensure!(
+			count <= <T as Config>::MaxEndorsementsPerDid::get(),
+			log_and_return_error_message(format!(
+				"Did {:?} has given too many endorsements. Allowed: {:?} found: {:?}",
+				endorser,
+				<T as Config>::MaxEndorsementsPerDid::get(),
+				count
+			))
+		);
+		total_endorsements_count = total_endorsements_count.saturating_add(endorsements_count);
 		Ok(())
-	})
+	})?;
+	ensure!(
+		total_endorsements_count == Endorsements::<T>::iter_keys().count(),
+		log_and_return_error_message(format!(
+			"Some endorsements are not tracked in [EndorsementsCount]. Tracked: {:?} found: {:?}",
+			total_endorsements_count,
+			Endorsements::<T>::iter_keys().count()
+		))
+	);
+	Ok(())
 }
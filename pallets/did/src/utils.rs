@@ -17,9 +17,9 @@
 // If you feel like getting in touch with us, you can do so at info@botlabs.org
 
 use fluent_uri::Uri;
-use parity_scale_codec::Encode;
+use parity_scale_codec::{Decode, Encode};
 use scale_info::prelude::format;
-use sp_runtime::traits::Hash;
+use sp_runtime::traits::{Hash, TrailingZeroInput};
 use sp_std::vec::Vec;
 
 use crate::{did_details::DidPublicKey, AccountIdOf, Config, KeyIdOf};
@@ -32,6 +32,30 @@ pub fn calculate_key_id<T: Config>(key: &DidPublicKey<AccountIdOf<T>>) -> KeyIdO
 	T::Hashing::hash(&hashed_values)
 }
 
+/// Derives the account of the multisig with the given signatories and
+/// threshold, in the same way `pallet_multisig` does.
+///
+/// Returns `None` if the signatories contain duplicates or if the threshold is
+/// smaller than 2 or larger than the number of signatories.
+pub fn multisig_account_id<AccountId>(mut signatories: Vec<AccountId>, threshold: u16) -> Option<AccountId>
+where
+	AccountId: Encode + Decode + Ord,
+{
+	let signatories_count = signatories.len();
+	if threshold < 2 || usize::from(threshold) > signatories_count {
+		return None;
+	}
+
+	signatories.sort();
+	signatories.dedup();
+	if signatories.len() != signatories_count {
+		return None;
+	}
+
+	let entropy = (b"modlpy/utilisuba", signatories, threshold).using_encoded(sp_io::hashing::blake2_256);
+	AccountId::decode(&mut TrailingZeroInput::new(entropy.as_ref())).ok()
+}
+
 /// Verifies that an input string contains only traditional (non-extended) ASCII
 /// characters.
 pub(crate) fn is_valid_ascii_string(input: &str) -> bool {
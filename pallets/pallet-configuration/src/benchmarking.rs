@@ -17,10 +17,28 @@
 // If you feel like getting in touch with us, you can do so at info@botlabs.org
 
 use frame_benchmarking::benchmarks;
-use frame_support::traits::EnsureOriginWithArg;
+use frame_support::traits::{EnsureOrigin, EnsureOriginWithArg};
+use sp_std::vec;
 
 use crate::*;
 
+/// Pauses `count` distinct calls with names of the maximum length.
+fn fill_paused_calls<T: Config>(count: u32) {
+	PausedCalls::<T>::mutate(|paused_calls| {
+		for i in 0..count {
+			let mut call_name = vec![0u8; MAX_NAME_LENGTH as usize];
+			call_name[..4].copy_from_slice(&i.to_le_bytes());
+			let pallet_name = vec![1u8; MAX_NAME_LENGTH as usize];
+			paused_calls
+				.try_insert((
+					pallet_name.try_into().expect("Should fit the name bound"),
+					call_name.try_into().expect("Should fit the name bound"),
+				))
+				.expect("Should not exceed the maximum number of paused calls");
+		}
+	});
+}
+
 benchmarks! {
 
 	set_configuration {
@@ -32,6 +50,45 @@ benchmarks! {
 		assert_eq!(ConfigurationStore::<T>::get(), Configuration { relay_block_strictly_increasing: true });
 	}
 
+	pause_xcm_identity_ops {
+		let origin = T::PauseOrigin::try_successful_origin().expect("Should build successful origin");
+
+	}: _<T::RuntimeOrigin>(origin)
+	verify {
+		assert!(XcmIdentityOpsPaused::<T>::get());
+	}
+
+	resume_xcm_identity_ops {
+		XcmIdentityOpsPaused::<T>::set(true);
+		let origin = T::PauseOrigin::try_successful_origin().expect("Should build successful origin");
+
+	}: _<T::RuntimeOrigin>(origin)
+	verify {
+		assert!(!XcmIdentityOpsPaused::<T>::get());
+	}
+
+	pause_call {
+		// All but one slot are taken, so that the set is as large as possible.
+		fill_paused_calls::<T>(T::MaxPausedCalls::get().saturating_sub(1));
+		let full_call_name: FullCallName = (b"Pallet".to_vec().try_into().unwrap(), b"call".to_vec().try_into().unwrap());
+		let origin = T::PauseOrigin::try_successful_origin().expect("Should build successful origin");
+
+	}: _<T::RuntimeOrigin>(origin, full_call_name.clone())
+	verify {
+		assert!(PausedCalls::<T>::get().contains(&full_call_name));
+	}
+
+	unpause_call {
+		fill_paused_calls::<T>(T::MaxPausedCalls::get().saturating_sub(1));
+		let full_call_name: FullCallName = (b"Pallet".to_vec().try_into().unwrap(), b"call".to_vec().try_into().unwrap());
+		PausedCalls::<T>::mutate(|paused_calls| paused_calls.try_insert(full_call_name.clone()).expect("Should not exceed the maximum number of paused calls"));
+		let origin = T::PauseOrigin::try_successful_origin().expect("Should build successful origin");
+
+	}: _<T::RuntimeOrigin>(origin, full_call_name.clone())
+	verify {
+		assert!(!PausedCalls::<T>::get().contains(&full_call_name));
+	}
+
 	impl_benchmark_test_suite!(
 		Pallet,
 		crate::mock::runtime::ExtBuilder::default().build_with_keystore(),
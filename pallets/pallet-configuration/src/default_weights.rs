@@ -51,6 +51,10 @@ use sp_std::marker::PhantomData;
 /// Weight functions needed for pallet_configuration.
 pub trait WeightInfo {
 	fn set_configuration() -> Weight;
+	fn pause_xcm_identity_ops() -> Weight;
+	fn resume_xcm_identity_ops() -> Weight;
+	fn pause_call() -> Weight;
+	fn unpause_call() -> Weight;
 }
 
 /// Weights for pallet_configuration using the Substrate node and recommended hardware.
@@ -66,6 +70,36 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(4_494_000, 0)
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	// The weights below are not taken from the benchmark run above. They are
+	// estimated from the storage accesses of each call.
+	/// Storage: Configuration XcmIdentityOpsPaused (r:1 w:1)
+	/// Proof: Configuration XcmIdentityOpsPaused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	fn pause_xcm_identity_ops() -> Weight {
+		Weight::from_parts(5_563_000, 1486)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Configuration XcmIdentityOpsPaused (r:1 w:1)
+	/// Proof: Configuration XcmIdentityOpsPaused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	fn resume_xcm_identity_ops() -> Weight {
+		Weight::from_parts(5_871_000, 1486)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Configuration PausedCalls (r:1 w:1)
+	/// Proof: Configuration PausedCalls (max_values: Some(1), max_size: Some(2601), added: 3096, mode: MaxEncodedLen)
+	fn pause_call() -> Weight {
+		Weight::from_parts(16_812_000, 4086)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Configuration PausedCalls (r:1 w:1)
+	/// Proof: Configuration PausedCalls (max_values: Some(1), max_size: Some(2601), added: 3096, mode: MaxEncodedLen)
+	fn unpause_call() -> Weight {
+		Weight::from_parts(17_134_000, 4086)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -80,4 +114,34 @@ impl WeightInfo for () {
 		Weight::from_parts(4_494_000, 0)
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	// The weights below are not taken from the benchmark run above. They are
+	// estimated from the storage accesses of each call.
+	/// Storage: Configuration XcmIdentityOpsPaused (r:1 w:1)
+	/// Proof: Configuration XcmIdentityOpsPaused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	fn pause_xcm_identity_ops() -> Weight {
+		Weight::from_parts(5_563_000, 1486)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Configuration XcmIdentityOpsPaused (r:1 w:1)
+	/// Proof: Configuration XcmIdentityOpsPaused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	fn resume_xcm_identity_ops() -> Weight {
+		Weight::from_parts(5_871_000, 1486)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Configuration PausedCalls (r:1 w:1)
+	/// Proof: Configuration PausedCalls (max_values: Some(1), max_size: Some(2601), added: 3096, mode: MaxEncodedLen)
+	fn pause_call() -> Weight {
+		Weight::from_parts(16_812_000, 4086)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Configuration PausedCalls (r:1 w:1)
+	/// Proof: Configuration PausedCalls (max_values: Some(1), max_size: Some(2601), added: 3096, mode: MaxEncodedLen)
+	fn unpause_call() -> Weight {
+		Weight::from_parts(17_134_000, 4086)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
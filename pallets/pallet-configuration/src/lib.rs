@@ -24,6 +24,10 @@
 //! Currently the following configurations are supported:
 //!
 //! * `CheckAssociatedRelayNumber` of the parachain-system pallet
+//! * A circuit breaker for inbound XCM `Transact`s targeting identity pallets,
+//!   exposed via [`IsXcmIdentityOpsPaused`] to be used in the XCM barrier
+//! * A circuit breaker for individual calls, exposed via [`UnpausedCalls`] to
+//!   be used in the `BaseCallFilter`
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -42,6 +46,63 @@ mod tests;
 
 pub use crate::{configuration::Configuration, default_weights::WeightInfo, pallet::*};
 
+use frame_support::{
+	pallet_prelude::ConstU32,
+	storage::bounded_btree_set::BoundedBTreeSet,
+	traits::{CallMetadata, Contains, Get, GetCallMetadata},
+	BoundedVec,
+};
+use sp_std::marker::PhantomData;
+
+/// The maximum length of a pallet or call name that can be paused.
+pub const MAX_NAME_LENGTH: u32 = 64;
+
+/// The name of a pallet or of a call as returned by `GetCallMetadata`.
+pub type CallName = BoundedVec<u8, ConstU32<MAX_NAME_LENGTH>>;
+
+/// The pallet name and the call name identifying a pausable call.
+pub type FullCallName = (CallName, CallName);
+
+/// Returns whether inbound XCM operations on identity pallets are currently
+/// paused.
+pub struct IsXcmIdentityOpsPaused<T>(PhantomData<T>);
+
+impl<T: Config> Get<bool> for IsXcmIdentityOpsPaused<T> {
+	fn get() -> bool {
+		XcmIdentityOpsPaused::<T>::get()
+	}
+}
+
+/// Allows all calls except the ones in `Scope` which have been paused via
+/// `pause_call`. Meant to be used in the `BaseCallFilter`, with `Scope`
+/// restricting which calls can be paused at all.
+pub struct UnpausedCalls<T, Scope>(PhantomData<(T, Scope)>);
+
+impl<T, Scope, Call> Contains<Call> for UnpausedCalls<T, Scope>
+where
+	T: Config,
+	Scope: Contains<Call>,
+	Call: GetCallMetadata,
+{
+	fn contains(call: &Call) -> bool {
+		if !Scope::contains(call) {
+			return true;
+		}
+		let CallMetadata {
+			pallet_name,
+			function_name,
+		} = call.get_call_metadata();
+		// Names that are too long cannot have been paused.
+		let (Ok(pallet_name), Ok(function_name)) = (
+			CallName::try_from(pallet_name.as_bytes().to_vec()),
+			CallName::try_from(function_name.as_bytes().to_vec()),
+		) else {
+			return true;
+		};
+		!PausedCalls::<T>::get().contains(&(pallet_name, function_name))
+	}
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -63,6 +124,14 @@ pub mod pallet {
 
 		/// The origin that is allowed to change the configuration
 		type EnsureOrigin: EnsureOriginWithArg<<Self as frame_system::Config>::RuntimeOrigin, Configuration>;
+
+		/// The origin that is allowed to pause and resume inbound XCM
+		/// operations on identity pallets as well as individual calls.
+		type PauseOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+
+		/// The maximum number of calls that can be paused at the same time.
+		#[pallet::constant]
+		type MaxPausedCalls: Get<u32>;
 	}
 
 	#[pallet::pallet]
@@ -70,21 +139,62 @@ pub mod pallet {
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+			// The paused calls are checked by the call filter for every dispatched
+			// call. Reading them once per block here accounts for that read, while
+			// all the following reads within the block hit the storage cache.
+			let _ = PausedCalls::<T>::get();
+			T::DbWeight::get().reads(1)
+		}
+	}
 
 	/// Stores for the dynamic configuration of the runtime
 	#[pallet::storage]
 	pub type ConfigurationStore<T> = StorageValue<_, Configuration, ValueQuery>;
 
+	/// Whether inbound XCM `Transact`s targeting identity pallets are
+	/// currently refused.
+	#[pallet::storage]
+	pub type XcmIdentityOpsPaused<T> = StorageValue<_, bool, ValueQuery>;
+
+	/// The calls that are currently refused by [`UnpausedCalls`], identified
+	/// by their pallet name and call name.
+	///
+	/// The calls are kept in a single value, as the whole set is read by the
+	/// call filter in every block.
+	#[pallet::storage]
+	pub type PausedCalls<T: Config> =
+		StorageValue<_, BoundedBTreeSet<FullCallName, <T as Config>::MaxPausedCalls>, ValueQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
 		/// The configuration was updated.
 		ConfigurationUpdate(Configuration),
+		/// Inbound XCM operations on identity pallets have been paused.
+		XcmIdentityOpsPaused,
+		/// Inbound XCM operations on identity pallets have been resumed.
+		XcmIdentityOpsResumed,
+		/// A call has been paused. \[pallet name, call name\]
+		CallPaused(CallName, CallName),
+		/// A call has been unpaused. \[pallet name, call name\]
+		CallUnpaused(CallName, CallName),
 	}
 
 	#[pallet::error]
-	pub enum Error<T> {}
+	pub enum Error<T> {
+		/// Inbound XCM operations on identity pallets are already paused.
+		AlreadyPaused,
+		/// Inbound XCM operations on identity pallets are not paused.
+		NotPaused,
+		/// The call is already paused.
+		CallAlreadyPaused,
+		/// The call is not paused.
+		CallNotPaused,
+		/// The maximum number of paused calls has been reached.
+		TooManyPausedCalls,
+	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
@@ -99,6 +209,76 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Refuse inbound XCM `Transact`s targeting identity pallets, while
+		/// leaving any other XCM operation, e.g., token transfers, untouched.
+		///
+		/// Meant for incident response, e.g., when a consumer chain
+		/// misbehaves.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::pause_xcm_identity_ops())]
+		pub fn pause_xcm_identity_ops(origin: OriginFor<T>) -> DispatchResult {
+			T::PauseOrigin::ensure_origin(origin)?;
+			ensure!(!XcmIdentityOpsPaused::<T>::get(), Error::<T>::AlreadyPaused);
+
+			XcmIdentityOpsPaused::<T>::set(true);
+			Self::deposit_event(Event::<T>::XcmIdentityOpsPaused);
+
+			Ok(())
+		}
+
+		/// Accept inbound XCM `Transact`s targeting identity pallets again.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::resume_xcm_identity_ops())]
+		pub fn resume_xcm_identity_ops(origin: OriginFor<T>) -> DispatchResult {
+			T::PauseOrigin::ensure_origin(origin)?;
+			ensure!(XcmIdentityOpsPaused::<T>::get(), Error::<T>::NotPaused);
+
+			XcmIdentityOpsPaused::<T>::kill();
+			Self::deposit_event(Event::<T>::XcmIdentityOpsResumed);
+
+			Ok(())
+		}
+
+		/// Refuse the call with the given pallet and call name, as returned by
+		/// `GetCallMetadata`, without a runtime upgrade.
+		///
+		/// Meant for incident response, e.g., when a bug is found in a single
+		/// extrinsic. The runtime decides which calls are affected by pausing
+		/// them via the scope of [`UnpausedCalls`].
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::pause_call())]
+		pub fn pause_call(origin: OriginFor<T>, full_call_name: FullCallName) -> DispatchResult {
+			T::PauseOrigin::ensure_origin(origin)?;
+
+			PausedCalls::<T>::try_mutate(|paused_calls| -> DispatchResult {
+				ensure!(!paused_calls.contains(&full_call_name), Error::<T>::CallAlreadyPaused);
+				paused_calls
+					.try_insert(full_call_name.clone())
+					.map_err(|_| Error::<T>::TooManyPausedCalls)?;
+				Ok(())
+			})?;
+			let (pallet_name, call_name) = full_call_name;
+			Self::deposit_event(Event::<T>::CallPaused(pallet_name, call_name));
+
+			Ok(())
+		}
+
+		/// Accept a previously paused call again.
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::unpause_call())]
+		pub fn unpause_call(origin: OriginFor<T>, full_call_name: FullCallName) -> DispatchResult {
+			T::PauseOrigin::ensure_origin(origin)?;
+
+			PausedCalls::<T>::try_mutate(|paused_calls| -> DispatchResult {
+				ensure!(paused_calls.remove(&full_call_name), Error::<T>::CallNotPaused);
+				Ok(())
+			})?;
+			let (pallet_name, call_name) = full_call_name;
+			Self::deposit_event(Event::<T>::CallUnpaused(pallet_name, call_name));
+
+			Ok(())
+		}
 	}
 
 	impl<T: Config> CheckAssociatedRelayNumber for Pallet<T> {
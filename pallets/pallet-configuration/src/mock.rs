@@ -107,6 +107,7 @@ pub mod runtime {
 
 	parameter_types! {
 		pub const Fee: Balance = 500;
+		pub const MaxPausedCalls: u32 = 2;
 	}
 
 	ord_parameter_types! {
@@ -115,6 +116,8 @@ pub mod runtime {
 
 	impl Config for Test {
 		type EnsureOrigin = AsEnsureOriginWithArg<EnsureSignedBy<PrivilegedAccount, AccountId>>;
+		type PauseOrigin = EnsureSignedBy<PrivilegedAccount, AccountId>;
+		type MaxPausedCalls = MaxPausedCalls;
 		type RuntimeEvent = ();
 		type WeightInfo = ();
 	}
@@ -17,10 +17,16 @@
 // If you feel like getting in touch with us, you can do so at info@botlabs.org
 
 use cumulus_pallet_parachain_system::CheckAssociatedRelayNumber;
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{Contains, Everything, Get, Nothing},
+};
 use sp_runtime::traits::BadOrigin;
 
-use crate::{mock::runtime::*, Configuration, ConfigurationStore, Pallet};
+use crate::{
+	mock::runtime::*, Configuration, ConfigurationStore, Error, FullCallName, IsXcmIdentityOpsPaused, Pallet,
+	PausedCalls, UnpausedCalls,
+};
 
 #[test]
 #[should_panic(expected = "Relay chain block number needs to strictly increase between Parachain blocks!")]
@@ -79,3 +85,127 @@ fn test_set_config_unauthorized() {
 		);
 	});
 }
+
+#[test]
+fn test_pause_and_resume_xcm_identity_ops() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert!(!IsXcmIdentityOpsPaused::<Test>::get());
+
+		assert_ok!(Pallet::<Test>::pause_xcm_identity_ops(RuntimeOrigin::signed(
+			ACCOUNT_00
+		)));
+		assert!(IsXcmIdentityOpsPaused::<Test>::get());
+		assert_noop!(
+			Pallet::<Test>::pause_xcm_identity_ops(RuntimeOrigin::signed(ACCOUNT_00)),
+			Error::<Test>::AlreadyPaused
+		);
+
+		assert_ok!(Pallet::<Test>::resume_xcm_identity_ops(RuntimeOrigin::signed(
+			ACCOUNT_00
+		)));
+		assert!(!IsXcmIdentityOpsPaused::<Test>::get());
+		assert_noop!(
+			Pallet::<Test>::resume_xcm_identity_ops(RuntimeOrigin::signed(ACCOUNT_00)),
+			Error::<Test>::NotPaused
+		);
+	});
+}
+
+#[test]
+fn test_pause_xcm_identity_ops_unauthorized() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Pallet::<Test>::pause_xcm_identity_ops(RuntimeOrigin::signed(ACCOUNT_01)),
+			BadOrigin
+		);
+	});
+}
+
+fn full_call_name(pallet_name: &[u8], call_name: &[u8]) -> FullCallName {
+	(
+		pallet_name.to_vec().try_into().unwrap(),
+		call_name.to_vec().try_into().unwrap(),
+	)
+}
+
+#[test]
+fn test_pause_and_unpause_call() {
+	ExtBuilder::default().build().execute_with(|| {
+		let name = full_call_name(b"Balances", b"transfer_allow_death");
+
+		assert_ok!(Pallet::<Test>::pause_call(
+			RuntimeOrigin::signed(ACCOUNT_00),
+			name.clone()
+		));
+		assert!(PausedCalls::<Test>::get().contains(&name));
+		assert_noop!(
+			Pallet::<Test>::pause_call(RuntimeOrigin::signed(ACCOUNT_00), name.clone()),
+			Error::<Test>::CallAlreadyPaused
+		);
+
+		assert_ok!(Pallet::<Test>::unpause_call(
+			RuntimeOrigin::signed(ACCOUNT_00),
+			name.clone()
+		));
+		assert!(!PausedCalls::<Test>::get().contains(&name));
+		assert_noop!(
+			Pallet::<Test>::unpause_call(RuntimeOrigin::signed(ACCOUNT_00), name),
+			Error::<Test>::CallNotPaused
+		);
+	});
+}
+
+#[test]
+fn test_pause_call_too_many() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(Pallet::<Test>::pause_call(
+			RuntimeOrigin::signed(ACCOUNT_00),
+			full_call_name(b"Balances", b"transfer_allow_death")
+		));
+		assert_ok!(Pallet::<Test>::pause_call(
+			RuntimeOrigin::signed(ACCOUNT_00),
+			full_call_name(b"Balances", b"transfer_keep_alive")
+		));
+		assert_noop!(
+			Pallet::<Test>::pause_call(
+				RuntimeOrigin::signed(ACCOUNT_00),
+				full_call_name(b"Balances", b"transfer_all")
+			),
+			Error::<Test>::TooManyPausedCalls
+		);
+	});
+}
+
+#[test]
+fn test_pause_call_unauthorized() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Pallet::<Test>::pause_call(
+				RuntimeOrigin::signed(ACCOUNT_01),
+				full_call_name(b"Balances", b"transfer_allow_death")
+			),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn test_unpaused_calls_filter() {
+	ExtBuilder::default().build().execute_with(|| {
+		let transfer = RuntimeCall::Balances(pallet_balances::Call::transfer_allow_death {
+			dest: ACCOUNT_01,
+			value: UNIT,
+		});
+		let remark = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+		assert!(UnpausedCalls::<Test, Everything>::contains(&transfer));
+
+		assert_ok!(Pallet::<Test>::pause_call(
+			RuntimeOrigin::signed(ACCOUNT_00),
+			full_call_name(b"Balances", b"transfer_allow_death")
+		));
+		assert!(!UnpausedCalls::<Test, Everything>::contains(&transfer));
+		assert!(UnpausedCalls::<Test, Everything>::contains(&remark));
+		// Calls outside of the scope are never paused.
+		assert!(UnpausedCalls::<Test, Nothing>::contains(&transfer));
+	});
+}
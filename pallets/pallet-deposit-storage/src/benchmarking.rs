@@ -21,6 +21,7 @@ use frame_benchmarking::v2::*;
 #[benchmarks(
 	where
         T: Config + pallet_balances::Config,
+        T::DepositParameter: Default,
 )]
 mod benchmarks {
 	use frame_support::traits::fungible::Mutate;
@@ -28,7 +29,10 @@ mod benchmarks {
 	use kilt_support::Deposit;
 	use sp_runtime::SaturatedConversion;
 
-	use crate::{traits::BenchmarkHooks, Call, Config, DepositEntryOf, Deposits, HoldReason, Pallet};
+	use crate::{
+		traits::{BenchmarkHooks, DepositParameterBounds},
+		Call, Config, DepositEntryOf, DepositParameters, Deposits, HoldReason, Pallet,
+	};
 
 	use super::*;
 
@@ -71,6 +75,21 @@ mod benchmarks {
 		T::BenchmarkHooks::post_reclaim_deposit();
 	}
 
+	#[benchmark]
+	fn set_deposit_parameter() {
+		let parameter = T::DepositParameter::default();
+		let (_, max) = T::DepositParameterBounds::bounds(&parameter);
+
+		let origin =
+			T::SetDepositParameterOrigin::try_successful_origin().expect("Successful origin creation should not fail.");
+		let cloned_parameter = parameter.clone();
+
+		#[extrinsic_call]
+		Pallet::<T>::set_deposit_parameter(origin as T::RuntimeOrigin, cloned_parameter, max);
+
+		assert_eq!(DepositParameters::<T>::get(&parameter), Some(max));
+	}
+
 	#[cfg(test)]
 	mod benchmarks_tests {
 		use crate::Pallet;
@@ -31,6 +31,7 @@ use sp_std::marker::PhantomData;
 /// Weight functions needed for pallet_deposit_storage.
 pub trait WeightInfo {
 	fn reclaim_deposit() -> Weight;
+	fn set_deposit_parameter() -> Weight;
 }
 
 /// Weights for pallet_deposit_storage using the Substrate node and recommended hardware.
@@ -51,6 +52,14 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
+	// The weights below are not taken from the benchmark run above. They are
+	// estimated from the storage accesses of each call.
+	/// Storage: `StorageDeposit::DepositParameters` (r:0 w:1)
+	/// Proof: `StorageDeposit::DepositParameters` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_deposit_parameter() -> Weight {
+		Weight::from_parts(10_142_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -70,4 +79,12 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
+	// The weights below are not taken from the benchmark run above. They are
+	// estimated from the storage accesses of each call.
+	/// Storage: `StorageDeposit::DepositParameters` (r:0 w:1)
+	/// Proof: `StorageDeposit::DepositParameters` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_deposit_parameter() -> Weight {
+		Weight::from_parts(10_142_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
@@ -116,11 +116,15 @@ pub(crate) type DepositCollectorHook<Runtime> = FixedDepositCollectorViaDeposits
 impl pallet_dip_provider::Config for TestRuntime {
 	type CommitOrigin = AccountId32;
 	type CommitOriginCheck = EnsureSigned<AccountId32>;
+	type CommitterDeposit = ConstU128<1_000>;
+	type Currency = Balances;
 	type Identifier = AccountId32;
 	type IdentityCommitmentGenerator = DefaultIdentityCommitmentGenerator<u32>;
 	type IdentityProvider = DefaultIdentityProvider<u32>;
+	type MaxCommitters = ConstU32<10>;
 	type ProviderHooks = DepositCollectorHook<Self>;
 	type RuntimeEvent = RuntimeEvent;
+	type RuntimeHoldReason = RuntimeHoldReason;
 	type WeightInfo = ();
 }
 
@@ -22,11 +22,16 @@
 //! Each deposit is identified by a namespace and a key. There cannot be two
 //! equal keys under the same namespace, but the same key can be present under
 //! different namespaces.
+//!
+//! The pallet also stores a set of deposit parameters, e.g., the deposit
+//! amounts of other pallets, which can be adjusted within bounded ranges by
+//! the configured origin.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
-mod default_weights;
+pub mod default_weights;
 mod deposit;
+pub mod migrations;
 pub mod traits;
 
 #[cfg(test)]
@@ -45,7 +50,7 @@ pub mod pallet {
 	use crate::{
 		default_weights::WeightInfo,
 		deposit::{free_deposit, reserve_deposit, DepositEntry},
-		traits::DepositStorageHooks,
+		traits::{DepositParameterBounds, DepositStorageHooks},
 	};
 
 	use super::*;
@@ -64,7 +69,7 @@ pub mod pallet {
 	use sp_std::fmt::Debug;
 
 	/// The current storage version.
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+	pub(crate) const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
 
 	pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
 	pub type BalanceOf<T> = <<T as Config>::Currency as Inspect<AccountIdOf<T>>>::Balance;
@@ -87,12 +92,19 @@ pub mod pallet {
 		/// Additional logic to execute whenever a new deposit a created or a
 		/// deposit is released.
 		type DepositHooks: DepositStorageHooks<Self>;
+		/// The type of a deposit parameter.
+		type DepositParameter: Parameter + MaxEncodedLen;
+		/// The default values and the allowed ranges of the deposit
+		/// parameters.
+		type DepositParameterBounds: DepositParameterBounds<Self::DepositParameter, BalanceOf<Self>>;
 		/// The type of a deposit namespace.
 		type Namespace: Parameter + MaxEncodedLen;
 		/// The aggregated `Event` type.
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 		/// The aggregated `HoldReason` type.
 		type RuntimeHoldReason: From<HoldReason> + Clone + PartialEq + Debug + FullCodec + MaxEncodedLen + TypeInfo;
+		/// The origin allowed to change the deposit parameters.
+		type SetDepositParameterOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 		type WeightInfo: WeightInfo;
 	}
 
@@ -119,6 +131,9 @@ pub mod pallet {
 		FailedToRelease,
 		/// The external hook failed.
 		Hook(u16),
+		/// The new value of a deposit parameter is outside of its allowed
+		/// range.
+		DepositParameterOutOfBounds,
 	}
 
 	#[pallet::event]
@@ -142,6 +157,13 @@ pub mod pallet {
 			/// The deposit details.
 			deposit_entry: DepositEntryOf<T>,
 		},
+		/// A deposit parameter has been updated.
+		DepositParameterSet {
+			/// The deposit parameter.
+			parameter: T::DepositParameter,
+			/// The new value of the parameter.
+			value: BalanceOf<T>,
+		},
 	}
 
 	/// Storage of all deposits. Its first key is a namespace, and the second
@@ -152,6 +174,11 @@ pub mod pallet {
 	pub(crate) type Deposits<T> =
 		StorageDoubleMap<_, Twox64Concat, <T as Config>::Namespace, Twox64Concat, DepositKeyOf<T>, DepositEntryOf<T>>;
 
+	/// Storage of the deposit parameters that have been set. Parameters that
+	/// have never been set take their default value.
+	#[pallet::storage]
+	pub(crate) type DepositParameters<T> = StorageMap<_, Twox64Concat, <T as Config>::DepositParameter, BalanceOf<T>>;
+
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
@@ -173,9 +200,40 @@ pub mod pallet {
 			T::DepositHooks::on_deposit_reclaimed(&namespace, &key, deposit).map_err(|e| Error::<T>::Hook(e.into()))?;
 			Ok(())
 		}
+
+		/// Set the value of a deposit parameter. The value must be within the
+		/// range allowed for the parameter.
+		///
+		/// Deposits that have already been taken are not affected by the new
+		/// value.
+		#[pallet::call_index(1)]
+		#[pallet::weight({
+			<T as Config>::WeightInfo::set_deposit_parameter()
+		})]
+		pub fn set_deposit_parameter(
+			origin: OriginFor<T>,
+			parameter: T::DepositParameter,
+			value: BalanceOf<T>,
+		) -> DispatchResult {
+			T::SetDepositParameterOrigin::ensure_origin(origin)?;
+
+			let (min, max) = T::DepositParameterBounds::bounds(&parameter);
+			ensure!(min <= value && value <= max, Error::<T>::DepositParameterOutOfBounds);
+
+			DepositParameters::<T>::insert(&parameter, value);
+			Self::deposit_event(Event::<T>::DepositParameterSet { parameter, value });
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
+		/// Returns the current value of the given deposit parameter, or its
+		/// default value if it has never been set.
+		pub fn deposit_parameter(parameter: &T::DepositParameter) -> BalanceOf<T> {
+			DepositParameters::<T>::get(parameter)
+				.unwrap_or_else(|| T::DepositParameterBounds::default_value(parameter))
+		}
+
 		/// Add a deposit identified by the given key under the given namespace.
 		/// If there is already a deposit entry for the same key under the same
 		/// namespace, it returns an error. It also returns an error if the
@@ -230,3 +288,17 @@ pub mod pallet {
 		}
 	}
 }
+
+/// Provides the current value of the deposit parameter `Parameter`, so that it
+/// can be used as the deposit amount of other pallets.
+pub struct DepositParameterOf<T, Parameter>(sp_std::marker::PhantomData<(T, Parameter)>);
+
+impl<T, Parameter> frame_support::traits::Get<BalanceOf<T>> for DepositParameterOf<T, Parameter>
+where
+	T: Config,
+	Parameter: frame_support::traits::Get<T::DepositParameter>,
+{
+	fn get() -> BalanceOf<T> {
+		Pallet::<T>::deposit_parameter(&Parameter::get())
+	}
+}
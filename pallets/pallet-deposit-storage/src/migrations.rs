@@ -0,0 +1,82 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use frame_support::{
+	traits::{Get, GetStorageVersion, OnRuntimeUpgrade},
+	weights::Weight,
+};
+use sp_std::{marker::PhantomData, vec::Vec};
+
+use crate::{traits::DepositParameterBounds, Config, DepositParameters, Pallet, STORAGE_VERSION};
+
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+
+/// Stores the default value of each of the given deposit parameters that has
+/// not been set yet.
+///
+/// The default values must match the deposits the runtime used before the
+/// parameters were introduced, so that the deposits already taken stay
+/// consistent with the new ones. Deposits already taken are not modified.
+pub struct InitializeDepositParameters<T, Parameters>(PhantomData<(T, Parameters)>);
+
+impl<T, Parameters> OnRuntimeUpgrade for InitializeDepositParameters<T, Parameters>
+where
+	T: Config,
+	Parameters: Get<Vec<T::DepositParameter>>,
+{
+	fn on_runtime_upgrade() -> Weight {
+		let on_chain_version = Pallet::<T>::on_chain_storage_version();
+		if on_chain_version >= STORAGE_VERSION {
+			log::info!(
+				"Deposit parameters already initialized at storage version {:?}.",
+				on_chain_version
+			);
+			return T::DbWeight::get().reads(1);
+		}
+
+		let parameters = Parameters::get();
+		let mut writes = 1u64;
+		for parameter in parameters.iter() {
+			if !DepositParameters::<T>::contains_key(parameter) {
+				DepositParameters::<T>::insert(parameter, T::DepositParameterBounds::default_value(parameter));
+				writes = writes.saturating_add(1);
+			}
+		}
+		STORAGE_VERSION.put::<Pallet<T>>();
+
+		log::info!("Initialized {} deposit parameters.", writes.saturating_sub(1));
+
+		T::DbWeight::get().reads_writes((parameters.len() as u64).saturating_add(1), writes)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(_state: Vec<u8>) -> Result<(), TryRuntimeError> {
+		frame_support::ensure!(
+			Pallet::<T>::on_chain_storage_version() == STORAGE_VERSION,
+			"Wrong storage version after deposit parameters initialization."
+		);
+		for parameter in Parameters::get().iter() {
+			frame_support::ensure!(
+				DepositParameters::<T>::contains_key(parameter),
+				"Deposit parameter not initialized."
+			);
+		}
+		Ok(())
+	}
+}
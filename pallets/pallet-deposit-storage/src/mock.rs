@@ -25,12 +25,12 @@ use frame_support::{
 	},
 	traits::{ConstU16, ConstU32, ConstU64, Currency, Everything},
 };
-use frame_system::{mocking::MockBlock, EnsureSigned};
+use frame_system::{mocking::MockBlock, EnsureRoot, EnsureSigned};
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use sp_runtime::RuntimeDebug;
 
-use crate::{self as storage_deposit_pallet, DepositEntryOf, DepositKeyOf, Pallet};
+use crate::{self as storage_deposit_pallet, traits::DepositParameterBounds, DepositEntryOf, DepositKeyOf, Pallet};
 
 pub(crate) type Balance = u128;
 
@@ -40,6 +40,26 @@ pub enum DepositNamespace {
 	ExampleNamespace,
 }
 
+#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, PartialEq, Eq, RuntimeDebug, Default)]
+pub enum DepositParameter {
+	#[default]
+	ExampleParameter,
+}
+
+pub(crate) const DEFAULT_DEPOSIT: Balance = 1_000;
+
+pub struct MockDepositParameterBounds;
+
+impl DepositParameterBounds<DepositParameter, Balance> for MockDepositParameterBounds {
+	fn default_value(_parameter: &DepositParameter) -> Balance {
+		DEFAULT_DEPOSIT
+	}
+
+	fn bounds(_parameter: &DepositParameter) -> (Balance, Balance) {
+		(DEFAULT_DEPOSIT / 10, DEFAULT_DEPOSIT * 10)
+	}
+}
+
 construct_runtime!(
 	pub struct TestRuntime {
 		System: frame_system,
@@ -102,8 +122,11 @@ impl crate::Config for TestRuntime {
 	type CheckOrigin = EnsureSigned<Self::AccountId>;
 	type Currency = Balances;
 	type DepositHooks = ();
+	type DepositParameter = DepositParameter;
+	type DepositParameterBounds = MockDepositParameterBounds;
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeHoldReason = RuntimeHoldReason;
+	type SetDepositParameterOrigin = EnsureRoot<Self::AccountId>;
 	type MaxKeyLength = ConstU32<256>;
 	type Namespace = DepositNamespace;
 	#[cfg(feature = "runtime-benchmarks")]
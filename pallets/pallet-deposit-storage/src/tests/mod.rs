@@ -18,3 +18,4 @@
 
 mod add_deposit;
 mod reclaim_deposit;
+mod set_deposit_parameter;
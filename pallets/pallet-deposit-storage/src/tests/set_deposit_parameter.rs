@@ -0,0 +1,101 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use frame_support::{assert_noop, assert_ok};
+use frame_system::RawOrigin;
+use sp_runtime::DispatchError;
+
+use crate::{
+	mock::{DepositParameter, ExtBuilder, TestRuntime, DEFAULT_DEPOSIT, OWNER},
+	DepositParameters, Error, Pallet,
+};
+
+#[test]
+fn set_deposit_parameter_successful() {
+	ExtBuilder::default().build().execute_with(|| {
+		let parameter = DepositParameter::ExampleParameter;
+		assert_eq!(Pallet::<TestRuntime>::deposit_parameter(&parameter), DEFAULT_DEPOSIT);
+
+		assert_ok!(Pallet::<TestRuntime>::set_deposit_parameter(
+			RawOrigin::Root.into(),
+			parameter.clone(),
+			DEFAULT_DEPOSIT * 2
+		));
+
+		assert_eq!(
+			DepositParameters::<TestRuntime>::get(&parameter),
+			Some(DEFAULT_DEPOSIT * 2)
+		);
+		assert_eq!(
+			Pallet::<TestRuntime>::deposit_parameter(&parameter),
+			DEFAULT_DEPOSIT * 2
+		);
+	});
+}
+
+#[test]
+fn set_deposit_parameter_bounds_inclusive() {
+	ExtBuilder::default().build().execute_with(|| {
+		let parameter = DepositParameter::ExampleParameter;
+
+		assert_ok!(Pallet::<TestRuntime>::set_deposit_parameter(
+			RawOrigin::Root.into(),
+			parameter.clone(),
+			DEFAULT_DEPOSIT / 10
+		));
+		assert_ok!(Pallet::<TestRuntime>::set_deposit_parameter(
+			RawOrigin::Root.into(),
+			parameter,
+			DEFAULT_DEPOSIT * 10
+		));
+	});
+}
+
+#[test]
+fn set_deposit_parameter_out_of_bounds() {
+	ExtBuilder::default().build().execute_with(|| {
+		let parameter = DepositParameter::ExampleParameter;
+
+		assert_noop!(
+			Pallet::<TestRuntime>::set_deposit_parameter(
+				RawOrigin::Root.into(),
+				parameter.clone(),
+				DEFAULT_DEPOSIT / 10 - 1
+			),
+			Error::<TestRuntime>::DepositParameterOutOfBounds
+		);
+		assert_noop!(
+			Pallet::<TestRuntime>::set_deposit_parameter(RawOrigin::Root.into(), parameter, DEFAULT_DEPOSIT * 10 + 1),
+			Error::<TestRuntime>::DepositParameterOutOfBounds
+		);
+	});
+}
+
+#[test]
+fn set_deposit_parameter_bad_origin() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Pallet::<TestRuntime>::set_deposit_parameter(
+				RawOrigin::Signed(OWNER).into(),
+				DepositParameter::ExampleParameter,
+				DEFAULT_DEPOSIT
+			),
+			DispatchError::BadOrigin
+		);
+	});
+}
@@ -16,6 +16,8 @@
 
 // If you feel like getting in touch with us, you can do so at info@botlabs.org
 
+use sp_runtime::traits::{Bounded, Zero};
+
 use crate::{Config, DepositEntryOf, DepositKeyOf};
 
 /// A trait to configure additional custom logic whenever a deposit-related
@@ -50,6 +52,28 @@ where
 	}
 }
 
+/// A trait to provide the default values and the allowed ranges of the deposit
+/// parameters.
+pub trait DepositParameterBounds<Parameter, Balance> {
+	/// The value of the parameter if it has never been set.
+	fn default_value(parameter: &Parameter) -> Balance;
+	/// The inclusive range of values the parameter can be set to.
+	fn bounds(parameter: &Parameter) -> (Balance, Balance);
+}
+
+impl<Parameter, Balance> DepositParameterBounds<Parameter, Balance> for ()
+where
+	Balance: Bounded + Zero,
+{
+	fn default_value(_parameter: &Parameter) -> Balance {
+		Balance::zero()
+	}
+
+	fn bounds(_parameter: &Parameter) -> (Balance, Balance) {
+		(Balance::zero(), Balance::max_value())
+	}
+}
+
 // Could be expanded to include traits to set up stuff before all benchmarks,
 // and before each benchmark case specifically.
 #[cfg(feature = "runtime-benchmarks")]
@@ -0,0 +1,54 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+#![cfg(feature = "runtime-benchmarks")]
+
+use frame_benchmarking::{account, benchmarks};
+use sp_std::{boxed::Box, vec};
+
+use kilt_support::traits::GenerateBenchmarkOrigin;
+
+use crate::{AccountIdOf, Call, Config, DidIdentifierOf, Pallet};
+
+const CALLER_SEED: u32 = 0;
+const DID_SEED: u32 = 1;
+
+benchmarks! {
+	where_clause {
+		where
+		T::EnsureOrigin: GenerateBenchmarkOrigin<T::RuntimeOrigin, T::AccountId, T::DidIdentifier>,
+		<T as Config>::RuntimeCall: From<frame_system::Call<T>>,
+	}
+
+	// The dispatched remark must be allowed by `CuratorCalls` when benchmarking.
+	// It is cheap enough for the measured weight to be the overhead of
+	// dispatching a call as curator.
+	dispatch_as_curator {
+		let caller: AccountIdOf<T> = account("caller", 0, CALLER_SEED);
+		let did: DidIdentifierOf<T> = account("did", 0, DID_SEED);
+		let origin = T::EnsureOrigin::generate_origin(caller, did);
+		let call: Box<<T as Config>::RuntimeCall> = Box::new(frame_system::Call::<T>::remark_with_event {
+			remark: vec![],
+		}.into());
+	}: _<T::RuntimeOrigin>(origin, call)
+
+	impl_benchmark_test_suite!(
+		Pallet,
+		crate::mock::ExtBuilder::default().build(),
+		crate::mock::Test
+	)
+}
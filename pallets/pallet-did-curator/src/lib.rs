@@ -0,0 +1,158 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+//! # DID curator pallet
+//!
+//! Lets DIDs act as curators of treasury bounties.
+//!
+//! Bounty curators are accounts, so each DID is given a curator account that
+//! is derived from the DID identifier and the pallet ID. No key controls the
+//! curator account: calls authorized by the DID origin are the only way to
+//! dispatch calls from it, and only bounty curator calls are allowed.
+//!
+//! To appoint a DID as curator, the curator account of the DID is proposed
+//! as the curator of the bounty.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod default_weights;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub use crate::{default_weights::WeightInfo, pallet::*};
+
+#[frame_support::pallet]
+pub mod pallet {
+	use frame_support::{
+		dispatch::{extract_actual_weight, GetDispatchInfo, PostDispatchInfo},
+		pallet_prelude::*,
+		traits::{Contains, StorageVersion},
+		PalletId,
+	};
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::traits::{AccountIdConversion, Dispatchable};
+	use sp_std::boxed::Box;
+
+	use kilt_support::traits::CallSources;
+
+	use super::WeightInfo;
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+
+	pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
+	pub type DidIdentifierOf<T> = <T as Config>::DidIdentifier;
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// The overarching call type.
+		type RuntimeCall: Parameter
+			+ Dispatchable<RuntimeOrigin = <Self as frame_system::Config>::RuntimeOrigin, PostInfo = PostDispatchInfo>
+			+ GetDispatchInfo;
+		/// The origin allowed to dispatch curator calls.
+		type EnsureOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin, Success = Self::OriginSuccess>;
+		/// The type of origin after a successful origin check.
+		type OriginSuccess: CallSources<AccountIdOf<Self>, DidIdentifierOf<Self>>;
+		/// The type of a DID identifier.
+		type DidIdentifier: Parameter + MaxEncodedLen;
+		/// The ID the curator accounts of DIDs are derived from.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+		/// The calls that can be dispatched as curator.
+		type CuratorCalls: Contains<<Self as Config>::RuntimeCall>;
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A curator call has been dispatched from the curator account of a
+		/// DID.
+		CuratorCallDispatched {
+			did: DidIdentifierOf<T>,
+			curator: AccountIdOf<T>,
+			result: DispatchResult,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The call cannot be dispatched as curator.
+		NotACuratorCall,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Dispatch a bounty curator call from the curator account of the DID
+		/// subject.
+		///
+		/// The dispatch origin must be a DID origin.
+		///
+		/// Emits `CuratorCallDispatched`.
+		#[allow(clippy::boxed_local)]
+		#[pallet::call_index(0)]
+		#[pallet::weight({
+			let dispatch_info = call.get_dispatch_info();
+
+			(<T as Config>::WeightInfo::dispatch_as_curator().saturating_add(dispatch_info.weight), dispatch_info.class)
+		})]
+		pub fn dispatch_as_curator(
+			origin: OriginFor<T>,
+			call: Box<<T as Config>::RuntimeCall>,
+		) -> DispatchResultWithPostInfo {
+			let did = T::EnsureOrigin::ensure_origin(origin)?.subject();
+			ensure!(T::CuratorCalls::contains(&call), Error::<T>::NotACuratorCall);
+
+			let curator = Self::curator_account(&did);
+			let call_info = call.get_dispatch_info();
+			let result = call.dispatch(frame_system::RawOrigin::Signed(curator.clone()).into());
+
+			Self::deposit_event(Event::<T>::CuratorCallDispatched {
+				did,
+				curator,
+				result: result.map(|_| ()).map_err(|e| e.error),
+			});
+
+			let actual_weight = <T as Config>::WeightInfo::dispatch_as_curator()
+				.saturating_add(extract_actual_weight(&result, &call_info));
+			result.map(|_| Some(actual_weight).into()).map_err(|mut e| {
+				e.post_info = Some(actual_weight).into();
+				e
+			})
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// The account a DID acts as when curating bounties.
+		pub fn curator_account(did: &DidIdentifierOf<T>) -> AccountIdOf<T> {
+			T::PalletId::get().into_sub_account_truncating(did)
+		}
+	}
+}
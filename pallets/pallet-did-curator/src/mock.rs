@@ -0,0 +1,126 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+use frame_support::{parameter_types, traits::Contains, PalletId};
+use kilt_support::mock::{mock_origin, SubjectId};
+use sp_runtime::{
+	traits::{BlakeTwo256, IdentifyAccount, IdentityLookup, Verify},
+	BuildStorage, MultiSignature,
+};
+
+use crate as pallet_did_curator;
+
+type BlockNumber = u64;
+
+type Hash = sp_core::H256;
+type Signature = MultiSignature;
+type AccountPublic = <Signature as Verify>::Signer;
+pub(crate) type AccountId = <AccountPublic as IdentifyAccount>::AccountId;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		DidCurator: pallet_did_curator,
+		MockOrigin: mock_origin,
+	}
+);
+
+parameter_types! {
+	pub const SS58Prefix: u8 = 38;
+	pub const BlockHashCount: BlockNumber = 2400;
+}
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type Block = Block;
+	type Nonce = u64;
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Hash = Hash;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = BlockHashCount;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = SS58Prefix;
+	type OnSetCode = ();
+	type MaxConsumers = frame_support::traits::ConstU32<16>;
+}
+
+/// Only remarks with an event can be dispatched as curator, so that the
+/// origin they are dispatched with can be checked.
+pub struct TestCuratorCalls;
+
+impl Contains<RuntimeCall> for TestCuratorCalls {
+	fn contains(call: &RuntimeCall) -> bool {
+		matches!(call, RuntimeCall::System(frame_system::Call::remark_with_event { .. }))
+	}
+}
+
+parameter_types! {
+	pub const DidCuratorPalletId: PalletId = PalletId(*b"test/cur");
+}
+
+impl pallet_did_curator::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type EnsureOrigin = mock_origin::EnsureDoubleOrigin<AccountId, SubjectId>;
+	type OriginSuccess = mock_origin::DoubleOrigin<AccountId, SubjectId>;
+	type DidIdentifier = SubjectId;
+	type PalletId = DidCuratorPalletId;
+	type CuratorCalls = TestCuratorCalls;
+	type WeightInfo = ();
+}
+
+impl mock_origin::Config for Test {
+	type RuntimeOrigin = RuntimeOrigin;
+	type AccountId = AccountId;
+	type SubjectId = SubjectId;
+}
+
+pub(crate) const ACCOUNT_00: AccountId = AccountId::new([1u8; 32]);
+pub(crate) const DID_00: SubjectId = SubjectId(AccountId::new([10u8; 32]));
+pub(crate) const DID_01: SubjectId = SubjectId(AccountId::new([11u8; 32]));
+
+pub(crate) fn did_origin(did: SubjectId) -> RuntimeOrigin {
+	mock_origin::DoubleOrigin(ACCOUNT_00, did).into()
+}
+
+#[derive(Clone, Default)]
+pub struct ExtBuilder;
+
+impl ExtBuilder {
+	pub fn build(self) -> sp_io::TestExternalities {
+		let storage = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+		let mut ext = sp_io::TestExternalities::new(storage);
+
+		ext.execute_with(|| System::set_block_number(1));
+		ext
+	}
+}
@@ -0,0 +1,88 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::traits::{BadOrigin, BlakeTwo256, Hash};
+
+use crate::{mock::*, Error, Event, Pallet};
+
+fn remark_with_event() -> Box<RuntimeCall> {
+	Box::new(RuntimeCall::System(frame_system::Call::remark_with_event {
+		remark: b"curated".to_vec(),
+	}))
+}
+
+#[test]
+fn test_dispatch_as_curator() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(Pallet::<Test>::dispatch_as_curator(
+			did_origin(DID_00),
+			remark_with_event()
+		));
+
+		let curator = Pallet::<Test>::curator_account(&DID_00);
+		System::assert_has_event(
+			frame_system::Event::<Test>::Remarked {
+				sender: curator.clone(),
+				hash: BlakeTwo256::hash(b"curated"),
+			}
+			.into(),
+		);
+		System::assert_last_event(
+			Event::<Test>::CuratorCallDispatched {
+				did: DID_00,
+				curator,
+				result: Ok(()),
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn test_curator_account_is_not_the_did_identifier() {
+	ExtBuilder::default().build().execute_with(|| {
+		let curator = Pallet::<Test>::curator_account(&DID_00);
+
+		assert_ne!(curator, DID_00.0);
+		assert_ne!(curator, ACCOUNT_00);
+		assert_ne!(curator, Pallet::<Test>::curator_account(&DID_01));
+	});
+}
+
+#[test]
+fn test_dispatch_as_curator_not_a_curator_call() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Pallet::<Test>::dispatch_as_curator(
+				did_origin(DID_00),
+				Box::new(RuntimeCall::System(frame_system::Call::remark { remark: vec![] }))
+			),
+			Error::<Test>::NotACuratorCall
+		);
+	});
+}
+
+#[test]
+fn test_dispatch_as_curator_bad_origin() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Pallet::<Test>::dispatch_as_curator(RuntimeOrigin::signed(ACCOUNT_00), remark_with_event()),
+			BadOrigin
+		);
+	});
+}
@@ -18,62 +18,115 @@
 
 use crate::{
 	account::{AccountId20, EthereumSignature},
+	bitcoin::{BitcoinAccountId, BitcoinSignature},
 	linkable_account::LinkableAccountId,
 	signature::get_wrapped_payload,
+	solana::{SolanaAccountId, SolanaSignature},
 };
 
 use base58::ToBase58;
 use blake2::{Blake2b512, Digest};
+use frame_support::{traits::ConstU32, BoundedVec};
+use kilt_support::signature::{
+	Erc1271Signature, Erc1271Verify, EthereumPersonalSignVerify, VerifyErc1271Proof, VerifySignature,
+};
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::{
 	prelude::{format, string::String},
 	TypeInfo,
 };
+use sp_core::hexdisplay::HexDisplay;
 use sp_runtime::{traits::Verify, AccountId32, MultiSignature};
 use sp_std::{fmt::Debug, vec, vec::Vec};
 
+/// The maximum length of a signature passed to an ERC-1271 contract wallet.
+pub const MAX_CONTRACT_SIGNATURE_LENGTH: u32 = 1_024;
+/// The maximum number of nodes in the proof of an ERC-1271 contract signature.
+pub const MAX_CONTRACT_SIGNATURE_PROOF_NODES: u32 = 64;
+/// The maximum length of a single node in the proof of an ERC-1271 contract
+/// signature.
+pub const MAX_CONTRACT_SIGNATURE_PROOF_NODE_LENGTH: u32 = 1_024;
+
+/// The signature passed to an ERC-1271 contract wallet.
+pub type ContractSignature = BoundedVec<u8, ConstU32<MAX_CONTRACT_SIGNATURE_LENGTH>>;
+/// The proof that an ERC-1271 contract wallet accepts a signature.
+pub type ContractSignatureProof = BoundedVec<
+	BoundedVec<u8, ConstU32<MAX_CONTRACT_SIGNATURE_PROOF_NODE_LENGTH>>,
+	ConstU32<MAX_CONTRACT_SIGNATURE_PROOF_NODES>,
+>;
+
 #[derive(Clone, Debug, Eq, PartialEq, Encode, Decode, MaxEncodedLen, TypeInfo)]
 pub enum AssociateAccountRequest {
 	Polkadot(AccountId32, MultiSignature),
 	Ethereum(AccountId20, EthereumSignature),
+	/// An ERC-1271 contract wallet, which accepted the challenge signature.
+	EthereumContract(AccountId20, Erc1271Signature<ContractSignature, ContractSignatureProof>),
+	/// A Solana wallet, which signed the challenge as an off-chain message.
+	Solana(SolanaAccountId, SolanaSignature),
+	/// A Bitcoin wallet, which signed the challenge as a Bitcoin signed
+	/// message.
+	Bitcoin(BitcoinAccountId, BitcoinSignature),
 }
 
 impl AssociateAccountRequest {
-	pub fn verify<DidIdentifier: AsRef<[u8]>, BlockNumber: Debug>(
+	pub fn verify<DidIdentifier, BlockNumber, ContractVerifier>(
 		&self,
 		did_identifier: &DidIdentifier,
 		expiration: BlockNumber,
-	) -> bool {
-		let encoded_payload = get_challenge(did_identifier, expiration).into_bytes();
+		genesis_hash: &[u8],
+	) -> bool
+	where
+		DidIdentifier: AsRef<[u8]>,
+		BlockNumber: Debug,
+		ContractVerifier: VerifyErc1271Proof<AccountId20, ContractSignature, ContractSignatureProof>,
+	{
+		let encoded_payload = get_challenge(did_identifier, expiration, genesis_hash).into_bytes();
 		match self {
 			AssociateAccountRequest::Polkadot(acc, proof) => proof.verify(
 				&get_wrapped_payload(&encoded_payload[..], crate::signature::WrapType::Substrate)[..],
 				acc,
 			),
-			AssociateAccountRequest::Ethereum(acc, proof) => proof.verify(
-				&get_wrapped_payload(&encoded_payload[..], crate::signature::WrapType::Ethereum)[..],
-				acc,
-			),
+			AssociateAccountRequest::Ethereum(acc, proof) => {
+				EthereumPersonalSignVerify::<EthereumSignature>::verify(acc, &encoded_payload, proof).is_ok()
+			}
+			AssociateAccountRequest::EthereumContract(acc, proof) => Erc1271Verify::<
+				AccountId20,
+				ContractSignature,
+				ContractSignatureProof,
+				ContractVerifier,
+			>::verify(acc, &encoded_payload, proof)
+			.is_ok(),
+			AssociateAccountRequest::Solana(acc, proof) => proof.verify(&encoded_payload, acc),
+			AssociateAccountRequest::Bitcoin(acc, proof) => proof.verify(&encoded_payload, acc),
 		}
 	}
 
 	pub fn get_linkable_account(&self) -> LinkableAccountId {
 		match self {
 			AssociateAccountRequest::Polkadot(acc, _) => LinkableAccountId::AccountId32(acc.clone()),
-			AssociateAccountRequest::Ethereum(acc, _) => LinkableAccountId::AccountId20(*acc),
+			AssociateAccountRequest::Ethereum(acc, _) | AssociateAccountRequest::EthereumContract(acc, _) => {
+				LinkableAccountId::AccountId20(*acc)
+			}
+			AssociateAccountRequest::Solana(acc, _) => LinkableAccountId::Solana(*acc),
+			AssociateAccountRequest::Bitcoin(acc, _) => LinkableAccountId::Bitcoin(*acc),
 		}
 	}
 }
 
 /// Build the challenge that must be signed to prove the consent for an
 /// account to be linked to a DID.
+///
+/// The challenge is bound to the chain with the provided genesis hash, so
+/// that it cannot be replayed on a different chain or fork.
 pub fn get_challenge<DidIdentifier: AsRef<[u8]>, BlockNumber: Debug>(
 	did_identifier: &DidIdentifier,
 	expiration: BlockNumber,
+	genesis_hash: &[u8],
 ) -> String {
 	format!(
-		"Publicly link the signing address to did:kilt:{} before block number {:?}",
+		"Publicly link the signing address to did:kilt:{} on chain 0x{} before block number {:?}",
 		to_ss58(did_identifier.as_ref(), 38),
+		HexDisplay::from(&genesis_hash),
 		expiration
 	)
 }
@@ -119,8 +172,8 @@ mod tests {
 	#[test]
 	fn test_get_challenge() {
 		assert_eq!(
-			get_challenge(&[1u8; 32], 5),
-			"Publicly link the signing address to did:kilt:4nwPAmtsK5toZfBM9WvmAe4Fa3LyZ3X3JHt7EUFfrcPPAZAm before block number 5"
+			get_challenge(&[1u8; 32], 5, &[2u8; 4]),
+			"Publicly link the signing address to did:kilt:4nwPAmtsK5toZfBM9WvmAe4Fa3LyZ3X3JHt7EUFfrcPPAZAm on chain 0x02020202 before block number 5"
 		);
 	}
 }
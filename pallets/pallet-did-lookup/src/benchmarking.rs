@@ -35,15 +35,28 @@ use sp_runtime::{
 	traits::IdentifyAccount,
 	AccountId32, KeyTypeId,
 };
+use sp_std::vec;
 
-use kilt_support::{traits::GenerateBenchmarkOrigin, Deposit};
+use kilt_support::{
+	migration::SteppedMigration,
+	signature::{ethereum_personal_sign_payload, Erc1271Signature},
+	traits::GenerateBenchmarkOrigin,
+	Deposit,
+};
 
 use crate::{
 	account::AccountId20,
-	associate_account_request::{get_challenge, AssociateAccountRequest},
+	associate_account_request::{
+		get_challenge, AssociateAccountRequest, ContractSignature, ContractSignatureProof,
+		MAX_CONTRACT_SIGNATURE_LENGTH,
+	},
+	bitcoin::{get_signed_message_hash, BitcoinAccountId, BitcoinSignature},
 	linkable_account::LinkableAccountId,
+	migrations,
 	signature::get_wrapped_payload,
-	AccountIdOf, Call, Config, ConnectedAccounts, ConnectedDids, CurrencyOf, Pallet,
+	solana::{get_offchain_message, SolanaAccountId, SolanaSignature},
+	AccountIdOf, AccountLabels, Call, Config, ConnectedAccounts, ConnectedDids, CurrencyOf, LinkLabel, Pallet,
+	MAX_LINK_LABEL_LENGTH,
 };
 
 const SEED: u32 = 0;
@@ -56,7 +69,8 @@ where
 {
 	let balance = <CurrencyOf<T> as Inspect<AccountIdOf<T>>>::minimum_balance()
 		+ <T as Config>::Deposit::get()
-		+ <T as Config>::Deposit::get();
+		+ <T as Config>::Deposit::get()
+		+ <T as Config>::LabelDeposit::get();
 	CurrencyOf::<T>::set_balance(account, balance);
 }
 
@@ -82,7 +96,7 @@ benchmarks! {
 			KeyTypeId(*b"aura"),
 			&connected_acc,
 			&get_wrapped_payload(
-				get_challenge(&did, expire_at).as_bytes(),
+				get_challenge(&did, expire_at, Pallet::<T>::genesis_hash().as_ref()).as_bytes(),
 				crate::signature::WrapType::Substrate,
 			))
 			.ok_or("Error while building signature.")?;
@@ -115,7 +129,7 @@ benchmarks! {
 			KeyTypeId(*b"aura"),
 			&connected_acc,
 			&get_wrapped_payload(
-				get_challenge(&did, expire_at).as_bytes(),
+				get_challenge(&did, expire_at, Pallet::<T>::genesis_hash().as_ref()).as_bytes(),
 				crate::signature::WrapType::Substrate,
 			))
 			.ok_or("Error while building signature.")?;
@@ -148,7 +162,7 @@ benchmarks! {
 			KeyTypeId(*b"aura"),
 			&connected_acc,
 			&get_wrapped_payload(
-				get_challenge(&did, expire_at).as_bytes(),
+				get_challenge(&did, expire_at, Pallet::<T>::genesis_hash().as_ref()).as_bytes(),
 				crate::signature::WrapType::Substrate,
 			))
 			.ok_or("Error while building signature.")?;
@@ -177,10 +191,7 @@ benchmarks! {
 		let eth_public_key = ecdsa_generate(KeyTypeId(*b"aura"), None);
 		let eth_account = AccountId20(eth_public_key.to_eth_address().unwrap());
 
-		let wrapped_payload = get_wrapped_payload(
-			get_challenge(&did, expire_at).as_bytes(),
-			crate::signature::WrapType::Ethereum,
-		);
+		let wrapped_payload = ethereum_personal_sign_payload(get_challenge(&did, expire_at, Pallet::<T>::genesis_hash().as_ref()).as_bytes());
 
 		let sig = sp_io::crypto::ecdsa_sign_prehashed(
 			KeyTypeId(*b"aura"),
@@ -202,6 +213,100 @@ benchmarks! {
 		assert!(ConnectedAccounts::<T>::get(did, LinkableAccountId::from(eth_account)).is_some());
 	}
 
+	associate_eth_contract_account {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let did: T::DidIdentifier = account("did", 0, SEED);
+		let previous_did: T::DidIdentifier = account("prev", 0, SEED + 1);
+		let expire_at: BlockNumberFor<T> = 500_u32.into();
+		let contract_account = AccountId20([1u8; 20]);
+
+		let wrapped_payload = ethereum_personal_sign_payload(get_challenge(&did, expire_at, Pallet::<T>::genesis_hash().as_ref()).as_bytes());
+
+		// The proof verification is weighed by the `ContractSignatureVerifier`. The
+		// proof consisting of the signed message hash is accepted by the mock
+		// verifier.
+		let signature: ContractSignature = vec![0u8; MAX_CONTRACT_SIGNATURE_LENGTH as usize].try_into().expect("signature should fit");
+		let proof: ContractSignatureProof = vec![Keccak256::digest(wrapped_payload).to_vec().try_into().expect("hash should fit")].try_into().expect("proof should fit");
+
+		make_free_for_did::<T>(&caller);
+
+		// Add existing connected_acc -> previous_did connection that will be replaced
+		Pallet::<T>::add_association(caller.clone(), previous_did.clone(), contract_account.into()).expect("should create previous association");
+		assert!(ConnectedAccounts::<T>::get(&previous_did, LinkableAccountId::from(contract_account)).is_some());
+		let origin = T::EnsureOrigin::generate_origin(caller, did.clone());
+		let req = AssociateAccountRequest::EthereumContract(contract_account, Erc1271Signature { signature, proof });
+	}: associate_account<T::RuntimeOrigin>(origin, req, expire_at)
+	verify {
+		assert!(ConnectedDids::<T>::get(LinkableAccountId::from(contract_account)).is_some());
+		assert!(ConnectedAccounts::<T>::get(&previous_did, LinkableAccountId::from(contract_account)).is_none());
+		assert!(ConnectedAccounts::<T>::get(did, LinkableAccountId::from(contract_account)).is_some());
+	}
+
+	associate_solana_account {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let did: T::DidIdentifier = account("did", 0, SEED);
+		let previous_did: T::DidIdentifier = account("prev", 0, SEED + 1);
+		let expire_at: BlockNumberFor<T> = 500_u32.into();
+
+		let solana_public_key = ed25519_generate(KeyTypeId(*b"aura"), None);
+		let solana_account = SolanaAccountId::from(solana_public_key);
+
+		let message = get_offchain_message(get_challenge(&did, expire_at, Pallet::<T>::genesis_hash().as_ref()).as_bytes())
+			.ok_or("Error while building message.")?;
+
+		let sig = sp_io::crypto::ed25519_sign(
+			KeyTypeId(*b"aura"),
+			&solana_public_key,
+			&message,
+		).ok_or("Error while building signature.")?;
+
+		make_free_for_did::<T>(&caller);
+
+		// Add existing connected_acc -> previous_did connection that will be replaced
+		Pallet::<T>::add_association(caller.clone(), previous_did.clone(), solana_account.into()).expect("should create previous association");
+		assert!(ConnectedAccounts::<T>::get(&previous_did, LinkableAccountId::from(solana_account)).is_some());
+		let origin = T::EnsureOrigin::generate_origin(caller, did.clone());
+		let req = AssociateAccountRequest::Solana(solana_account, SolanaSignature::from(sig));
+	}: associate_account<T::RuntimeOrigin>(origin, req, expire_at)
+	verify {
+		assert!(ConnectedDids::<T>::get(LinkableAccountId::from(solana_account)).is_some());
+		assert!(ConnectedAccounts::<T>::get(&previous_did, LinkableAccountId::from(solana_account)).is_none());
+		assert!(ConnectedAccounts::<T>::get(did, LinkableAccountId::from(solana_account)).is_some());
+	}
+
+	associate_bitcoin_account {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let did: T::DidIdentifier = account("did", 0, SEED);
+		let previous_did: T::DidIdentifier = account("prev", 0, SEED + 1);
+		let expire_at: BlockNumberFor<T> = 500_u32.into();
+
+		let bitcoin_public_key = ecdsa_generate(KeyTypeId(*b"aura"), None);
+		let bitcoin_account = BitcoinAccountId::from(bitcoin_public_key.0);
+
+		let sig = sp_io::crypto::ecdsa_sign_prehashed(
+			KeyTypeId(*b"aura"),
+			&bitcoin_public_key,
+			&get_signed_message_hash(get_challenge(&did, expire_at, Pallet::<T>::genesis_hash().as_ref()).as_bytes()),
+		).ok_or("Error while building signature.")?;
+		// Move the recovery ID into the header byte for a compressed public key.
+		let mut bitcoin_sig = [0u8; 65];
+		bitcoin_sig[0] = 31 + sig.0[64];
+		bitcoin_sig[1..].copy_from_slice(&sig.0[..64]);
+
+		make_free_for_did::<T>(&caller);
+
+		// Add existing connected_acc -> previous_did connection that will be replaced
+		Pallet::<T>::add_association(caller.clone(), previous_did.clone(), bitcoin_account.into()).expect("should create previous association");
+		assert!(ConnectedAccounts::<T>::get(&previous_did, LinkableAccountId::from(bitcoin_account)).is_some());
+		let origin = T::EnsureOrigin::generate_origin(caller, did.clone());
+		let req = AssociateAccountRequest::Bitcoin(bitcoin_account, BitcoinSignature::from(bitcoin_sig));
+	}: associate_account<T::RuntimeOrigin>(origin, req, expire_at)
+	verify {
+		assert!(ConnectedDids::<T>::get(LinkableAccountId::from(bitcoin_account)).is_some());
+		assert!(ConnectedAccounts::<T>::get(&previous_did, LinkableAccountId::from(bitcoin_account)).is_none());
+		assert!(ConnectedAccounts::<T>::get(did, LinkableAccountId::from(bitcoin_account)).is_some());
+	}
+
 	associate_sender {
 		let caller: T::AccountId = account("caller", 0, SEED);
 		let linkable_id: LinkableAccountId = caller.clone().into();
@@ -299,6 +404,86 @@ benchmarks! {
 			},
 		);
 	}
+
+	remove_all_account_associations {
+		let n in 1 .. T::MaxAssociationRemovals::get();
+
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let did: T::DidIdentifier = account("did", 0, SEED);
+		let balance = <CurrencyOf<T> as Inspect<AccountIdOf<T>>>::minimum_balance()
+			+ <T as Config>::Deposit::get() * n.into();
+		CurrencyOf::<T>::set_balance(&caller, balance);
+
+		for i in 0..n {
+			let linked_account: T::AccountId = account("linked", i, SEED);
+			Pallet::<T>::add_association(caller.clone(), did.clone(), linked_account.into()).expect("should create association");
+		}
+		let origin = T::EnsureOrigin::generate_origin(caller, did.clone());
+	}: _<T::RuntimeOrigin>(origin, n)
+	verify {
+		assert!(ConnectedAccounts::<T>::iter_key_prefix(&did).next().is_none());
+	}
+
+	migrate_association {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let old_linkable_id: LinkableAccountId = caller.clone().into();
+		let did: T::DidIdentifier = account("did", 0, SEED);
+		let expire_at: BlockNumberFor<T> = 500_u32.into();
+
+		let eth_public_key = ecdsa_generate(KeyTypeId(*b"aura"), None);
+		let eth_account = AccountId20(eth_public_key.to_eth_address().unwrap());
+
+		let wrapped_payload = ethereum_personal_sign_payload(get_challenge(&did, expire_at, Pallet::<T>::genesis_hash().as_ref()).as_bytes());
+
+		let sig = sp_io::crypto::ecdsa_sign_prehashed(
+			KeyTypeId(*b"aura"),
+			&eth_public_key,
+			&Keccak256::digest(wrapped_payload).try_into().unwrap(),
+		).ok_or("Error while building signature.")?;
+
+		make_free_for_did::<T>(&caller);
+
+		Pallet::<T>::add_association(caller.clone(), did.clone(), old_linkable_id.clone()).expect("should create association");
+		let origin = T::EnsureOrigin::generate_origin(caller, did.clone());
+		let id_arg = old_linkable_id.clone();
+		let req = AssociateAccountRequest::Ethereum(eth_account, sig.into());
+	}: _<T::RuntimeOrigin>(origin, id_arg, req, expire_at)
+	verify {
+		assert!(ConnectedDids::<T>::get(&old_linkable_id).is_none());
+		assert!(ConnectedAccounts::<T>::get(&did, old_linkable_id).is_none());
+		assert!(ConnectedAccounts::<T>::get(did, LinkableAccountId::from(eth_account)).is_some());
+	}
+
+	set_account_label {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let linkable_id: LinkableAccountId = caller.clone().into();
+		let did: T::DidIdentifier = account("did", 0, SEED);
+		make_free_for_did::<T>(&caller);
+
+		Pallet::<T>::add_association(caller.clone(), did.clone(), linkable_id.clone()).expect("should create association");
+
+		let origin = T::EnsureOrigin::generate_origin(caller, did);
+		let id_arg = linkable_id.clone();
+		let label: LinkLabel = vec![b'a'; MAX_LINK_LABEL_LENGTH as usize].try_into().expect("label should fit");
+	}: _<T::RuntimeOrigin>(origin, id_arg, Some(label.clone()))
+	verify {
+		assert_eq!(AccountLabels::<T>::get(&linkable_id).map(|record| record.label), Some(label));
+	}
+
+	initialize_connected_dids_counter_step {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let linkable_id: LinkableAccountId = caller.clone().into();
+		let did: T::DidIdentifier = account("did", 0, SEED);
+		make_free_for_did::<T>(&caller);
+
+		Pallet::<T>::add_association(caller, did, linkable_id).expect("should create association");
+		frame_support::storage::unhashed::kill(&ConnectedDids::<T>::counter_storage_final_key());
+	}: {
+		<migrations::InitializeConnectedDidsCounter<T> as SteppedMigration>::step(None);
+	}
+	verify {
+		assert_eq!(ConnectedDids::<T>::count(), 1);
+	}
 }
 
 #[cfg(test)]
@@ -0,0 +1,129 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+//! The Bitcoin account and signature types.
+//!
+//! Bitcoin wallets sign messages in the Bitcoin Signed Message format, i.e.,
+//! the double SHA-256 hash of the prefixed payload is signed and the signature
+//! allows to recover the public key of the signer.
+
+use frame_support::RuntimeDebug;
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use sp_io::hashing::sha2_256;
+use sp_std::vec::Vec;
+
+const BITCOIN_SIGNED_MESSAGE_PREFIX: &[u8; 25] = b"\x18Bitcoin Signed Message:\n";
+
+/// The BitcoinAccountId type.
+/// It is the 33-byte compressed secp256k1 public key of a Bitcoin wallet.
+///
+/// Bitcoin addresses are derived from the public key with RIPEMD-160, which is
+/// not available to the runtime, so accounts are identified by the public key
+/// itself.
+#[derive(Eq, PartialEq, Copy, Clone, Encode, Decode, TypeInfo, MaxEncodedLen, PartialOrd, Ord, RuntimeDebug)]
+pub struct BitcoinAccountId(pub [u8; 33]);
+
+// Serde only supports arrays of up to 32 elements, hence the key is
+// (de)serialized as its prefix byte and its x coordinate.
+impl Serialize for BitcoinAccountId {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		let mut x_coordinate = [0u8; 32];
+		x_coordinate.copy_from_slice(&self.0[1..]);
+		(self.0[0], x_coordinate).serialize(serializer)
+	}
+}
+
+impl<'de> Deserialize<'de> for BitcoinAccountId {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		let (prefix, x_coordinate) = <(u8, [u8; 32])>::deserialize(deserializer)?;
+		let mut public_key = [0u8; 33];
+		public_key[0] = prefix;
+		public_key[1..].copy_from_slice(&x_coordinate);
+		Ok(Self(public_key))
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::fmt::Display for BitcoinAccountId {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "{}", hex::encode(self.0))
+	}
+}
+
+impl From<[u8; 33]> for BitcoinAccountId {
+	fn from(bytes: [u8; 33]) -> Self {
+		Self(bytes)
+	}
+}
+
+/// A signature in the Bitcoin Signed Message format.
+///
+/// It consists of the header byte, which encodes the recovery ID, followed by
+/// the `r` and `s` values of the signature.
+#[derive(Eq, PartialEq, Clone, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub struct BitcoinSignature(pub [u8; 65]);
+
+impl From<[u8; 65]> for BitcoinSignature {
+	fn from(bytes: [u8; 65]) -> Self {
+		Self(bytes)
+	}
+}
+
+impl BitcoinSignature {
+	/// Verifies that the signer signed the payload as a Bitcoin signed
+	/// message.
+	pub fn verify(&self, payload: &[u8], signer: &BitcoinAccountId) -> bool {
+		// Headers 31-34 are used for P2PKH, 35-38 for P2SH-P2WPKH and 39-42 for
+		// P2WPKH addresses of compressed public keys. Headers 27-30 are used for
+		// uncompressed public keys, whose addresses differ from the ones of the
+		// compressed key identifying the account, and are hence rejected.
+		let header = self.0[0];
+		if !(31..=42).contains(&header) {
+			return false;
+		}
+		let mut signature = [0u8; 65];
+		signature[..64].copy_from_slice(&self.0[1..]);
+		signature[64] = (header - 31) % 4;
+
+		sp_io::crypto::secp256k1_ecdsa_recover_compressed(&signature, &get_signed_message_hash(payload))
+			.map_or(false, |public_key| public_key == signer.0)
+	}
+}
+
+/// Returns the hash of the payload in the Bitcoin Signed Message format.
+pub(crate) fn get_signed_message_hash(payload: &[u8]) -> [u8; 32] {
+	let message: Vec<u8> = BITCOIN_SIGNED_MESSAGE_PREFIX
+		.iter()
+		.chain(get_compact_size(payload.len()).iter())
+		.chain(payload.iter())
+		.copied()
+		.collect();
+	sha2_256(&sha2_256(&message))
+}
+
+// The variable length integer encoding used by Bitcoin.
+fn get_compact_size(length: usize) -> Vec<u8> {
+	match length {
+		0..=0xfc => sp_std::vec![length as u8],
+		0xfd..=0xffff => [0xfd].into_iter().chain((length as u16).to_le_bytes()).collect(),
+		0x10000..=0xffff_ffff => [0xfe].into_iter().chain((length as u32).to_le_bytes()).collect(),
+		_ => [0xff].into_iter().chain((length as u64).to_le_bytes()).collect(),
+	}
+}
@@ -16,11 +16,28 @@
 
 // If you feel like getting in touch with us, you can do so at info@botlabs.org
 
+use frame_support::{traits::ConstU32, BoundedVec};
 use kilt_support::Deposit;
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use serde::{Deserialize, Serialize};
 
+/// The maximum length in bytes of the label of an account link.
+pub const MAX_LINK_LABEL_LENGTH: u32 = 32;
+
+/// A short description of an account link, e.g., "cold wallet".
+pub type LinkLabel = BoundedVec<u8, ConstU32<MAX_LINK_LABEL_LENGTH>>;
+
+/// The label of an account link, together with the deposit reserved for it.
+#[derive(Clone, Decode, Debug, Encode, TypeInfo, Eq, PartialEq, MaxEncodedLen)]
+pub struct LabelRecord<Account, Balance> {
+	/// The label of the link.
+	pub label: LinkLabel,
+
+	/// The deposit that was reserved for storing the label.
+	pub deposit: Deposit<Account, Balance>,
+}
+
 /// A record in the ConnectedDid map.
 #[derive(Clone, Decode, Debug, Encode, TypeInfo, Eq, PartialEq, MaxEncodedLen, Serialize, Deserialize)]
 pub struct ConnectionRecord<DidIdentifier, Account, Balance> {
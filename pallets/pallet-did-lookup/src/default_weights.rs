@@ -54,280 +54,379 @@ pub trait WeightInfo {
 	fn associate_account_multisig_ed25519() -> Weight;
 	fn associate_account_multisig_ecdsa() -> Weight;
 	fn associate_eth_account() -> Weight;
+	fn associate_eth_contract_account() -> Weight;
+	fn associate_solana_account() -> Weight;
+	fn associate_bitcoin_account() -> Weight;
 	fn associate_sender() -> Weight;
 	fn remove_sender_association() -> Weight;
 	fn remove_account_association() -> Weight;
 	fn change_deposit_owner() -> Weight;
 	fn update_deposit() -> Weight;
+	fn remove_all_account_associations(n: u32, ) -> Weight;
+	fn migrate_association() -> Weight;
+	fn set_account_label() -> Weight;
+	fn initialize_connected_dids_counter_step() -> Weight;
 }
 
 /// Weights for pallet_did_lookup using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// The weights below are not taken from the benchmark run above. They are
+	// estimated from the storage accesses of each call.
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: DidLookup ConnectedDids (r:1 w:1)
-	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
+	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(130), added: 2605, mode: MaxEncodedLen)
 	/// Storage: DidLookup ConnectedAccounts (r:0 w:2)
-	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(98), added: 2573, mode: MaxEncodedLen)
 	fn associate_account_multisig_sr25519() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `403`
-		//  Estimated: `5211`
-		// Minimum execution time: 64_706 nanoseconds.
-		Weight::from_parts(66_077_000, 5211)
+		Weight::from_parts(66_077_000, 5212)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: DidLookup ConnectedDids (r:1 w:1)
-	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
+	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(130), added: 2605, mode: MaxEncodedLen)
 	/// Storage: DidLookup ConnectedAccounts (r:0 w:2)
-	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(98), added: 2573, mode: MaxEncodedLen)
 	fn associate_account_multisig_ed25519() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `403`
-		//  Estimated: `5211`
-		// Minimum execution time: 63_377 nanoseconds.
-		Weight::from_parts(64_358_000, 5211)
+		Weight::from_parts(64_358_000, 5212)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: DidLookup ConnectedDids (r:1 w:1)
-	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
+	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(130), added: 2605, mode: MaxEncodedLen)
 	/// Storage: DidLookup ConnectedAccounts (r:0 w:2)
-	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(98), added: 2573, mode: MaxEncodedLen)
 	fn associate_account_multisig_ecdsa() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `403`
-		//  Estimated: `5211`
-		// Minimum execution time: 59_043 nanoseconds.
-		Weight::from_parts(63_304_000, 5211)
+		Weight::from_parts(63_304_000, 5212)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: DidLookup ConnectedDids (r:1 w:1)
-	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
+	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(130), added: 2605, mode: MaxEncodedLen)
 	/// Storage: DidLookup ConnectedAccounts (r:0 w:2)
-	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(98), added: 2573, mode: MaxEncodedLen)
 	fn associate_eth_account() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `391`
-		//  Estimated: `5211`
-		// Minimum execution time: 59_169 nanoseconds.
-		Weight::from_parts(63_871_000, 5211)
+		Weight::from_parts(63_871_000, 5212)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: DidLookup ConnectedDids (r:1 w:1)
-	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
+	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(130), added: 2605, mode: MaxEncodedLen)
 	/// Storage: DidLookup ConnectedAccounts (r:0 w:2)
-	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(98), added: 2573, mode: MaxEncodedLen)
+	fn associate_eth_contract_account() -> Weight {
+		Weight::from_parts(35_127_000, 5212)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: DidLookup ConnectedDids (r:1 w:1)
+	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(130), added: 2605, mode: MaxEncodedLen)
+	/// Storage: DidLookup ConnectedAccounts (r:0 w:2)
+	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(98), added: 2573, mode: MaxEncodedLen)
+	fn associate_solana_account() -> Weight {
+		Weight::from_parts(62_871_000, 5212)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: DidLookup ConnectedDids (r:1 w:1)
+	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(130), added: 2605, mode: MaxEncodedLen)
+	/// Storage: DidLookup ConnectedAccounts (r:0 w:2)
+	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(98), added: 2573, mode: MaxEncodedLen)
+	fn associate_bitcoin_account() -> Weight {
+		Weight::from_parts(64_539_000, 5212)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: DidLookup ConnectedDids (r:1 w:1)
+	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(130), added: 2605, mode: MaxEncodedLen)
+	/// Storage: DidLookup ConnectedAccounts (r:0 w:2)
+	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(98), added: 2573, mode: MaxEncodedLen)
 	fn associate_sender() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `403`
-		//  Estimated: `5211`
-		// Minimum execution time: 28_354 nanoseconds.
-		Weight::from_parts(29_272_000, 5211)
+		Weight::from_parts(29_272_000, 5212)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: DidLookup ConnectedDids (r:1 w:1)
-	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
+	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(130), added: 2605, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: DidLookup ConnectedAccounts (r:0 w:1)
-	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(98), added: 2573, mode: MaxEncodedLen)
 	fn remove_sender_association() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `403`
-		//  Estimated: `5211`
-		// Minimum execution time: 17_432 nanoseconds.
-		Weight::from_parts(17_906_000, 5211)
+		Weight::from_parts(17_906_000, 5212)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: DidLookup ConnectedDids (r:1 w:1)
-	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
+	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(130), added: 2605, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: DidLookup ConnectedAccounts (r:0 w:1)
-	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(98), added: 2573, mode: MaxEncodedLen)
 	fn remove_account_association() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `403`
-		//  Estimated: `5211`
-		// Minimum execution time: 18_685 nanoseconds.
-		Weight::from_parts(19_115_000, 5211)
+		Weight::from_parts(19_115_000, 5212)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: DidLookup ConnectedDids (r:1 w:1)
-	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
+	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(130), added: 2605, mode: MaxEncodedLen)
 	/// Storage: System Account (r:2 w:2)
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	fn change_deposit_owner() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `816`
-		//  Estimated: `7818`
-		// Minimum execution time: 25_338 nanoseconds.
-		Weight::from_parts(25_951_000, 7818)
+		Weight::from_parts(25_951_000, 7819)
 			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: DidLookup ConnectedDids (r:1 w:1)
-	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
+	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(130), added: 2605, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	fn update_deposit() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `403`
-		//  Estimated: `5211`
-		// Minimum execution time: 21_996 nanoseconds.
-		Weight::from_parts(22_991_000, 5211)
+		Weight::from_parts(22_991_000, 5212)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
+	/// Storage: DidLookup ConnectedAccounts (r:26 w:25)
+	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(98), added: 2573, mode: MaxEncodedLen)
+	/// Storage: DidLookup ConnectedDids (r:25 w:25)
+	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(130), added: 2605, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 25]`.
+	fn remove_all_account_associations(n: u32, ) -> Weight {
+		Weight::from_parts(12_412_577, 5213)
+			.saturating_add(Weight::from_parts(9_381_264, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2605).saturating_mul(n.into()))
+	}
+	/// Storage: DidLookup ConnectedDids (r:2 w:2)
+	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(130), added: 2605, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: DidLookup ConnectedAccounts (r:0 w:3)
+	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(98), added: 2573, mode: MaxEncodedLen)
+	fn migrate_association() -> Weight {
+		Weight::from_parts(79_502_000, 7816)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: DidLookup ConnectedDids (r:1 w:0)
+	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(130), added: 2605, mode: MaxEncodedLen)
+	/// Storage: DidLookup AccountLabels (r:1 w:1)
+	/// Proof: DidLookup AccountLabels (max_values: None, max_size: Some(151), added: 2626, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	fn set_account_label() -> Weight {
+		Weight::from_parts(15_881_000, 7838)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: DidLookup ConnectedDids (r:1 w:0)
+	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(130), added: 2605, mode: MaxEncodedLen)
+	/// Storage: DidLookup CounterForConnectedDids (r:1 w:1)
+	/// Proof: DidLookup CounterForConnectedDids (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	fn initialize_connected_dids_counter_step() -> Weight {
+		Weight::from_parts(9_968_000, 3595)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
+	// The weights below are not taken from the benchmark run above. They are
+	// estimated from the storage accesses of each call.
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: DidLookup ConnectedDids (r:1 w:1)
-	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
+	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(130), added: 2605, mode: MaxEncodedLen)
 	/// Storage: DidLookup ConnectedAccounts (r:0 w:2)
-	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(98), added: 2573, mode: MaxEncodedLen)
 	fn associate_account_multisig_sr25519() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `403`
-		//  Estimated: `5211`
-		// Minimum execution time: 64_706 nanoseconds.
-		Weight::from_parts(66_077_000, 5211)
+		Weight::from_parts(66_077_000, 5212)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: DidLookup ConnectedDids (r:1 w:1)
-	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
+	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(130), added: 2605, mode: MaxEncodedLen)
 	/// Storage: DidLookup ConnectedAccounts (r:0 w:2)
-	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(98), added: 2573, mode: MaxEncodedLen)
 	fn associate_account_multisig_ed25519() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `403`
-		//  Estimated: `5211`
-		// Minimum execution time: 63_377 nanoseconds.
-		Weight::from_parts(64_358_000, 5211)
+		Weight::from_parts(64_358_000, 5212)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: DidLookup ConnectedDids (r:1 w:1)
-	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
+	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(130), added: 2605, mode: MaxEncodedLen)
 	/// Storage: DidLookup ConnectedAccounts (r:0 w:2)
-	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(98), added: 2573, mode: MaxEncodedLen)
 	fn associate_account_multisig_ecdsa() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `403`
-		//  Estimated: `5211`
-		// Minimum execution time: 59_043 nanoseconds.
-		Weight::from_parts(63_304_000, 5211)
+		Weight::from_parts(63_304_000, 5212)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: DidLookup ConnectedDids (r:1 w:1)
-	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
+	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(130), added: 2605, mode: MaxEncodedLen)
 	/// Storage: DidLookup ConnectedAccounts (r:0 w:2)
-	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(98), added: 2573, mode: MaxEncodedLen)
 	fn associate_eth_account() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `391`
-		//  Estimated: `5211`
-		// Minimum execution time: 59_169 nanoseconds.
-		Weight::from_parts(63_871_000, 5211)
+		Weight::from_parts(63_871_000, 5212)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: DidLookup ConnectedDids (r:1 w:1)
-	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
+	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(130), added: 2605, mode: MaxEncodedLen)
 	/// Storage: DidLookup ConnectedAccounts (r:0 w:2)
-	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(98), added: 2573, mode: MaxEncodedLen)
+	fn associate_eth_contract_account() -> Weight {
+		Weight::from_parts(35_127_000, 5212)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: DidLookup ConnectedDids (r:1 w:1)
+	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(130), added: 2605, mode: MaxEncodedLen)
+	/// Storage: DidLookup ConnectedAccounts (r:0 w:2)
+	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(98), added: 2573, mode: MaxEncodedLen)
+	fn associate_solana_account() -> Weight {
+		Weight::from_parts(62_871_000, 5212)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: DidLookup ConnectedDids (r:1 w:1)
+	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(130), added: 2605, mode: MaxEncodedLen)
+	/// Storage: DidLookup ConnectedAccounts (r:0 w:2)
+	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(98), added: 2573, mode: MaxEncodedLen)
+	fn associate_bitcoin_account() -> Weight {
+		Weight::from_parts(64_539_000, 5212)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: DidLookup ConnectedDids (r:1 w:1)
+	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(130), added: 2605, mode: MaxEncodedLen)
+	/// Storage: DidLookup ConnectedAccounts (r:0 w:2)
+	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(98), added: 2573, mode: MaxEncodedLen)
 	fn associate_sender() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `403`
-		//  Estimated: `5211`
-		// Minimum execution time: 28_354 nanoseconds.
-		Weight::from_parts(29_272_000, 5211)
+		Weight::from_parts(29_272_000, 5212)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: DidLookup ConnectedDids (r:1 w:1)
-	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
+	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(130), added: 2605, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: DidLookup ConnectedAccounts (r:0 w:1)
-	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(98), added: 2573, mode: MaxEncodedLen)
 	fn remove_sender_association() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `403`
-		//  Estimated: `5211`
-		// Minimum execution time: 17_432 nanoseconds.
-		Weight::from_parts(17_906_000, 5211)
+		Weight::from_parts(17_906_000, 5212)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: DidLookup ConnectedDids (r:1 w:1)
-	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
+	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(130), added: 2605, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: DidLookup ConnectedAccounts (r:0 w:1)
-	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(98), added: 2573, mode: MaxEncodedLen)
 	fn remove_account_association() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `403`
-		//  Estimated: `5211`
-		// Minimum execution time: 18_685 nanoseconds.
-		Weight::from_parts(19_115_000, 5211)
+		Weight::from_parts(19_115_000, 5212)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: DidLookup ConnectedDids (r:1 w:1)
-	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
+	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(130), added: 2605, mode: MaxEncodedLen)
 	/// Storage: System Account (r:2 w:2)
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	fn change_deposit_owner() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `816`
-		//  Estimated: `7818`
-		// Minimum execution time: 25_338 nanoseconds.
-		Weight::from_parts(25_951_000, 7818)
+		Weight::from_parts(25_951_000, 7819)
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: DidLookup ConnectedDids (r:1 w:1)
-	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
+	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(130), added: 2605, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	fn update_deposit() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `403`
-		//  Estimated: `5211`
-		// Minimum execution time: 21_996 nanoseconds.
-		Weight::from_parts(22_991_000, 5211)
+		Weight::from_parts(22_991_000, 5212)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
+	/// Storage: DidLookup ConnectedAccounts (r:26 w:25)
+	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(98), added: 2573, mode: MaxEncodedLen)
+	/// Storage: DidLookup ConnectedDids (r:25 w:25)
+	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(130), added: 2605, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 25]`.
+	fn remove_all_account_associations(n: u32, ) -> Weight {
+		Weight::from_parts(12_412_577, 5213)
+			.saturating_add(Weight::from_parts(9_381_264, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2605).saturating_mul(n.into()))
+	}
+	/// Storage: DidLookup ConnectedDids (r:2 w:2)
+	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(130), added: 2605, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: DidLookup ConnectedAccounts (r:0 w:3)
+	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(98), added: 2573, mode: MaxEncodedLen)
+	fn migrate_association() -> Weight {
+		Weight::from_parts(79_502_000, 7816)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: DidLookup ConnectedDids (r:1 w:0)
+	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(130), added: 2605, mode: MaxEncodedLen)
+	/// Storage: DidLookup AccountLabels (r:1 w:1)
+	/// Proof: DidLookup AccountLabels (max_values: None, max_size: Some(151), added: 2626, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	fn set_account_label() -> Weight {
+		Weight::from_parts(15_881_000, 7838)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: DidLookup ConnectedDids (r:1 w:0)
+	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(130), added: 2605, mode: MaxEncodedLen)
+	/// Storage: DidLookup CounterForConnectedDids (r:1 w:1)
+	/// Proof: DidLookup CounterForConnectedDids (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	fn initialize_connected_dids_counter_step() -> Weight {
+		Weight::from_parts(9_968_000, 3595)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
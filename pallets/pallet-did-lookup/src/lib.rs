@@ -26,9 +26,12 @@
 
 pub mod account;
 pub mod associate_account_request;
+pub mod bitcoin;
 pub mod default_weights;
 pub mod linkable_account;
 pub mod migrations;
+pub mod signed_extension;
+pub mod solana;
 
 mod connection_record;
 mod signature;
@@ -50,25 +53,28 @@ pub use crate::{default_weights::WeightInfo, pallet::*};
 #[frame_support::pallet]
 pub mod pallet {
 	use crate::{
-		associate_account_request::AssociateAccountRequest, default_weights::WeightInfo,
+		account::AccountId20,
+		associate_account_request::{AssociateAccountRequest, ContractSignature, ContractSignatureProof},
+		default_weights::WeightInfo,
 		linkable_account::LinkableAccountId,
 	};
 	use frame_support::{
 		ensure,
 		pallet_prelude::*,
 		traits::{
-			fungible::{Inspect, InspectHold, MutateHold},
+			fungible::{Inspect, MutateHold},
 			StorageVersion,
 		},
 	};
 	use frame_system::pallet_prelude::*;
 	use kilt_support::{
+		signature::VerifyErc1271Proof,
 		traits::{BalanceMigrationManager, CallSources, StorageDepositCollector},
 		Deposit,
 	};
-	use sp_runtime::traits::{BlockNumberProvider, MaybeSerializeDeserialize};
+	use sp_runtime::traits::{BlockNumberProvider, MaybeSerializeDeserialize, Saturating, Zero};
 
-	pub use crate::connection_record::ConnectionRecord;
+	pub use crate::connection_record::{ConnectionRecord, LabelRecord, LinkLabel, MAX_LINK_LABEL_LENGTH};
 
 	/// The native identifier for accounts in this runtime.
 	pub(crate) type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
@@ -81,11 +87,14 @@ pub mod pallet {
 
 	pub type BalanceOf<T> = <CurrencyOf<T> as Inspect<AccountIdOf<T>>>::Balance;
 	/// The connection record type.
-	pub(crate) type ConnectionRecordOf<T> = ConnectionRecord<DidIdentifierOf<T>, AccountIdOf<T>, BalanceOf<T>>;
+	pub type ConnectionRecordOf<T> = ConnectionRecord<DidIdentifierOf<T>, AccountIdOf<T>, BalanceOf<T>>;
+	/// The label record type.
+	pub type LabelRecordOf<T> = LabelRecord<AccountIdOf<T>, BalanceOf<T>>;
 
 	pub(crate) type BalanceMigrationManagerOf<T> = <T as Config>::BalanceMigrationManager;
 
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(4);
+	const STORAGE_VERSION: StorageVersion =
+		StorageVersion::new(crate::migrations::CONNECTED_DIDS_COUNTER_STORAGE_VERSION);
 
 	#[pallet::composite_enum]
 	pub enum HoldReason {
@@ -115,21 +124,42 @@ pub mod pallet {
 		#[pallet::constant]
 		type Deposit: Get<BalanceOf<Self>>;
 
+		/// The amount of balance that will be taken for each label of an
+		/// account link. The deposit is freed when the label or the link is
+		/// removed.
+		#[pallet::constant]
+		type LabelDeposit: Get<BalanceOf<Self>>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 
 		/// Migration manager to handle new created entries
 		type BalanceMigrationManager: BalanceMigrationManager<AccountIdOf<Self>, BalanceOf<Self>>;
+
+		/// Verifies that an ERC-1271 contract wallet accepts the signature of
+		/// an association request, based on the provided proof.
+		type ContractSignatureVerifier: VerifyErc1271Proof<AccountId20, ContractSignature, ContractSignatureProof>;
+
+		/// The maximum number of associations that can be removed with a
+		/// single call to `remove_all_account_associations`.
+		#[pallet::constant]
+		type MaxAssociationRemovals: Get<u32>;
+
+		/// The maximum number of blocks an association proof can be valid for,
+		/// i.e., how far after the current block its expiration block can be.
+		#[pallet::constant]
+		type MaxProofValidity: Get<BlockNumberFor<Self>>;
 	}
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
-	/// Mapping from account identifiers to DIDs.
+	/// Mapping from account identifiers to DIDs, counting the linked
+	/// accounts.
 	#[pallet::storage]
 	#[pallet::getter(fn connected_dids)]
-	pub type ConnectedDids<T> = StorageMap<_, Blake2_128Concat, LinkableAccountId, ConnectionRecordOf<T>>;
+	pub type ConnectedDids<T> = CountedStorageMap<_, Blake2_128Concat, LinkableAccountId, ConnectionRecordOf<T>>;
 
 	/// Mapping from (DID + account identifier) -> ().
 	/// The empty tuple is used as a sentinel value to simply indicate the
@@ -139,8 +169,13 @@ pub mod pallet {
 	pub type ConnectedAccounts<T> =
 		StorageDoubleMap<_, Blake2_128Concat, DidIdentifierOf<T>, Blake2_128Concat, LinkableAccountId, ()>;
 
+	/// Mapping from account identifiers to the label that the connected DID
+	/// attached to the association, and its deposit.
+	#[pallet::storage]
+	#[pallet::getter(fn account_labels)]
+	pub type AccountLabels<T> = StorageMap<_, Blake2_128Concat, LinkableAccountId, LabelRecordOf<T>>;
+
 	#[pallet::event]
-	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
 		/// A new association between a DID and an account ID was created.
 		AssociationEstablished(LinkableAccountId, DidIdentifierOf<T>),
@@ -148,6 +183,17 @@ pub mod pallet {
 		/// An association between a DID and an account ID was removed.
 		AssociationRemoved(LinkableAccountId, DidIdentifierOf<T>),
 
+		/// A batch of associations of a DID was removed. The last value is the
+		/// next account that is still linked to the DID, if any.
+		AssociationsRemoved(DidIdentifierOf<T>, u32, Option<LinkableAccountId>),
+
+		/// An association of a DID was moved from the first account to the
+		/// second account.
+		AssociationMigrated(LinkableAccountId, LinkableAccountId, DidIdentifierOf<T>),
+
+		/// The label of an association was set or cleared.
+		AccountLabelUpdated(LinkableAccountId, DidIdentifierOf<T>, Option<LinkLabel>),
+
 		/// There was some progress in the migration process.
 		MigrationProgress,
 
@@ -167,6 +213,9 @@ pub mod pallet {
 		/// The supplied proof of ownership was outdated.
 		OutdatedProof,
 
+		/// The supplied proof of ownership expires too far in the future.
+		ProofValidityTooLong,
+
 		/// The account has insufficient funds and can't pay the fees or reserve
 		/// the deposit.
 		InsufficientFunds,
@@ -208,6 +257,8 @@ pub mod pallet {
 		}
 	}
 
+	kilt_support::impl_identity_event_deposit!();
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T>
 	where
@@ -217,12 +268,15 @@ pub mod pallet {
 	{
 		/// Associate the given account to the DID that authorized this call.
 		///
-		/// The account has to sign the DID and a blocknumber after which the
-		/// signature expires in order to authorize the association.
+		/// The account has to sign the DID, the genesis hash of the chain and a
+		/// blocknumber after which the signature expires in order to authorize
+		/// the association. The expiration must not be further than
+		/// `MaxProofValidity` blocks in the future.
 		///
-		/// The signature will be checked against the scale encoded tuple of the
-		/// method specific id of the did identifier and the block number after
-		/// which the signature should be regarded invalid.
+		/// The signature will be checked against the challenge built from the
+		/// method specific id of the did identifier, the genesis hash of the
+		/// chain and the block number after which the signature should be
+		/// regarded invalid.
 		///
 		/// Emits `AssociationEstablished` and, optionally, `AssociationRemoved`
 		/// if there was a previous association for the account.
@@ -237,8 +291,12 @@ pub mod pallet {
 			<T as Config>::WeightInfo::associate_account_multisig_sr25519().max(
 			<T as Config>::WeightInfo::associate_account_multisig_ed25519().max(
 			<T as Config>::WeightInfo::associate_account_multisig_ecdsa().max(
-			<T as Config>::WeightInfo::associate_eth_account()
-		))))]
+			<T as Config>::WeightInfo::associate_eth_account().max(
+			<T as Config>::WeightInfo::associate_eth_contract_account().max(
+			<T as Config>::WeightInfo::associate_solana_account().max(
+			<T as Config>::WeightInfo::associate_bitcoin_account()
+		))))))
+		.saturating_add(Pallet::<T>::contract_signature_verification_weight(req)))]
 		pub fn associate_account(
 			origin: OriginFor<T>,
 			req: AssociateAccountRequest,
@@ -248,22 +306,19 @@ pub mod pallet {
 			let did_identifier = source.subject();
 			let sender = source.sender();
 
-			ensure!(
-				frame_system::Pallet::<T>::current_block_number() <= expiration,
-				Error::<T>::OutdatedProof
-			);
+			Self::ensure_valid_expiration(expiration)?;
 
 			ensure!(
-				<T::Currency as InspectHold<AccountIdOf<T>>>::can_hold(
-					&HoldReason::Deposit.into(),
-					&sender,
-					<T as Config>::Deposit::get()
-				),
+				LinkableAccountDepositCollector::<T>::can_create_deposit(&sender, <T as Config>::Deposit::get()),
 				Error::<T>::InsufficientFunds
 			);
 
 			ensure!(
-				req.verify::<T::DidIdentifier, BlockNumberFor<T>>(&did_identifier, expiration),
+				req.verify::<T::DidIdentifier, BlockNumberFor<T>, T::ContractSignatureVerifier>(
+					&did_identifier,
+					expiration,
+					Self::genesis_hash().as_ref()
+				),
 				Error::<T>::NotAuthorized
 			);
 
@@ -289,8 +344,7 @@ pub mod pallet {
 			let source = <T as Config>::EnsureOrigin::ensure_origin(origin)?;
 
 			ensure!(
-				<T::Currency as InspectHold<AccountIdOf<T>>>::can_hold(
-					&HoldReason::Deposit.into(),
+				LinkableAccountDepositCollector::<T>::can_create_deposit(
 					&source.sender(),
 					<T as Config>::Deposit::get()
 				),
@@ -398,12 +452,203 @@ pub mod pallet {
 			LinkableAccountDepositCollector::<T>::update_deposit::<BalanceMigrationManagerOf<T>>(&account)
 		}
 
+		/// Remove up to `limit` associations of the DID that authorized this
+		/// call. The reserved deposits are freed.
+		///
+		/// If more associations are left, the `AssociationsRemoved` event
+		/// contains the next linked account and the call can be repeated
+		/// until no associations are left.
+		///
+		/// Emits `AssociationRemoved` for each removed association and
+		/// `AssociationsRemoved` once.
+		///
+		/// # <weight>
+		/// Weight: O(N) where N is the number of removed associations, bounded
+		/// by `MaxAssociationRemovals`
+		/// - Reads: ConnectedAccounts + N * ConnectedDids + DID Origin Check
+		/// - Writes: N * (ConnectedDids + ConnectedAccounts)
+		/// # </weight>
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_all_account_associations(
+			(*limit).min(T::MaxAssociationRemovals::get())
+		))]
+		pub fn remove_all_account_associations(origin: OriginFor<T>, limit: u32) -> DispatchResultWithPostInfo {
+			let source = <T as Config>::EnsureOrigin::ensure_origin(origin)?;
+			let did_identifier = source.subject();
+			let limit = limit.min(T::MaxAssociationRemovals::get());
+
+			let mut linked_accounts = ConnectedAccounts::<T>::iter_key_prefix(&did_identifier);
+			let accounts_to_remove: sp_std::vec::Vec<LinkableAccountId> =
+				linked_accounts.by_ref().take(limit as usize).collect();
+			let next_account = linked_accounts.next();
+			ensure!(!accounts_to_remove.is_empty(), Error::<T>::NotFound);
+
+			let removed = accounts_to_remove.len() as u32;
+			for account in accounts_to_remove {
+				Self::remove_association(account)?;
+			}
+			Self::deposit_event(Event::AssociationsRemoved(did_identifier, removed, next_account));
+
+			Ok(Some(<T as Config>::WeightInfo::remove_all_account_associations(removed)).into())
+		}
+
+		/// Move the association of `old_account` to the account in `req`.
+		///
+		/// Both accounts must belong to the DID that authorized this call. The
+		/// old account must currently be linked to it, and the new account has
+		/// to sign the DID and expiration block number in the same way as for
+		/// `associate_account`. The deposit of the old association is freed
+		/// and a new deposit is reserved from the sender of the call.
+		///
+		/// Emits `AssociationRemoved`, `AssociationEstablished` and
+		/// `AssociationMigrated`.
+		///
+		/// # <weight>
+		/// Weight: O(1)
+		/// - Reads: 2 * ConnectedDids + DID Origin Check
+		/// - Writes: 2 * ConnectedDids + 2 * ConnectedAccounts
+		/// # </weight>
+		#[pallet::call_index(8)]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::migrate_association()
+			.saturating_add(Pallet::<T>::contract_signature_verification_weight(req))
+		)]
+		pub fn migrate_association(
+			origin: OriginFor<T>,
+			old_account: LinkableAccountId,
+			req: AssociateAccountRequest,
+			expiration: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let source = <T as Config>::EnsureOrigin::ensure_origin(origin)?;
+			let did_identifier = source.subject();
+			let sender = source.sender();
+
+			let record = ConnectedDids::<T>::get(&old_account).ok_or(Error::<T>::NotFound)?;
+			ensure!(record.did == did_identifier, Error::<T>::NotAuthorized);
+
+			Self::ensure_valid_expiration(expiration)?;
+
+			ensure!(
+				req.verify::<T::DidIdentifier, BlockNumberFor<T>, T::ContractSignatureVerifier>(
+					&did_identifier,
+					expiration,
+					Self::genesis_hash().as_ref()
+				),
+				Error::<T>::NotAuthorized
+			);
+
+			Self::remove_association(old_account.clone())?;
+
+			ensure!(
+				LinkableAccountDepositCollector::<T>::can_create_deposit(&sender, <T as Config>::Deposit::get()),
+				Error::<T>::InsufficientFunds
+			);
+
+			let new_account = req.get_linkable_account();
+			Self::add_association(sender, did_identifier.clone(), new_account.clone())?;
+			Self::deposit_event(Event::AssociationMigrated(old_account, new_account, did_identifier));
+
+			Ok(())
+		}
+
+		/// Set or clear the label of the association of the provided account.
+		/// The associated DID needs to match the DID that authorized this
+		/// call.
+		///
+		/// Setting a label on an association without one reserves
+		/// `LabelDeposit` from the sender of the call. The deposit is freed
+		/// when the label is cleared or the association is removed.
+		///
+		/// Emits `AccountLabelUpdated`.
+		///
+		/// # <weight>
+		/// Weight: O(1)
+		/// - Reads: ConnectedDids + AccountLabels + DID Origin Check
+		/// - Writes: AccountLabels
+		/// # </weight>
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_account_label())]
+		pub fn set_account_label(
+			origin: OriginFor<T>,
+			account: LinkableAccountId,
+			label: Option<LinkLabel>,
+		) -> DispatchResult {
+			let source = <T as Config>::EnsureOrigin::ensure_origin(origin)?;
+			let did_identifier = source.subject();
+
+			let record = ConnectedDids::<T>::get(&account).ok_or(Error::<T>::NotFound)?;
+			ensure!(record.did == did_identifier, Error::<T>::NotAuthorized);
+
+			if let Some(new_label) = label.clone() {
+				let deposit = match AccountLabels::<T>::get(&account) {
+					Some(label_record) => label_record.deposit,
+					None => {
+						let sender = source.sender();
+						ensure!(
+							LinkableAccountDepositCollector::<T>::can_create_deposit(
+								&sender,
+								<T as Config>::LabelDeposit::get()
+							),
+							Error::<T>::InsufficientFunds
+						);
+						LinkableAccountDepositCollector::<T>::create_deposit(
+							sender,
+							<T as Config>::LabelDeposit::get(),
+						)?
+					}
+				};
+				AccountLabels::<T>::insert(
+					&account,
+					LabelRecord {
+						label: new_label,
+						deposit,
+					},
+				);
+			} else {
+				Self::remove_label(&account)?;
+			}
+			Self::deposit_event(Event::AccountLabelUpdated(account, did_identifier, label));
+
+			Ok(())
+		}
+
 		// Old call that was used to migrate
 		// #[pallet::call_index(254)]
 		// pub fn migrate(origin: OriginFor<T>, limit: u32) -> DispatchResult
 	}
 
 	impl<T: Config> Pallet<T> {
+		/// The genesis hash of the chain, which association proofs must be
+		/// bound to.
+		pub fn genesis_hash() -> T::Hash {
+			frame_system::Pallet::<T>::block_hash(BlockNumberFor::<T>::zero())
+		}
+
+		/// The weight of checking the proof of an ERC-1271 contract signature,
+		/// which is not part of the benchmarked weights.
+		fn contract_signature_verification_weight(req: &AssociateAccountRequest) -> Weight {
+			match req {
+				AssociateAccountRequest::EthereumContract(_, signature) => {
+					T::ContractSignatureVerifier::weight(signature.proof.encoded_size())
+				}
+				_ => Weight::zero(),
+			}
+		}
+
+		/// Verify that an association proof with the provided expiration has
+		/// not expired yet, and that it does not expire further than
+		/// `MaxProofValidity` blocks in the future.
+		fn ensure_valid_expiration(expiration: BlockNumberFor<T>) -> DispatchResult {
+			let current_block_number = frame_system::Pallet::<T>::current_block_number();
+			ensure!(current_block_number <= expiration, Error::<T>::OutdatedProof);
+			ensure!(
+				expiration <= current_block_number.saturating_add(T::MaxProofValidity::get()),
+				Error::<T>::ProofValidityTooLong
+			);
+
+			Ok(())
+		}
+
 		pub fn add_association(
 			sender: AccountIdOf<T>,
 			did_identifier: DidIdentifierOf<T>,
@@ -418,14 +663,16 @@ pub mod pallet {
 				did: did_identifier.clone(),
 			};
 
-			LinkableAccountDepositCollector::<T>::create_deposit(record.clone().deposit.owner, record.deposit.amount)?;
-			<T as Config>::BalanceMigrationManager::exclude_key_from_migration(&ConnectedDids::<T>::hashed_key_for(
+			LinkableAccountDepositCollector::<T>::create_deposit_for_key::<BalanceMigrationManagerOf<T>>(
 				&account,
-			));
+				record.clone().deposit.owner,
+				record.deposit.amount,
+			)?;
 
 			ConnectedDids::<T>::mutate(&account, |did_entry| -> DispatchResult {
 				if let Some(old_connection) = did_entry.replace(record) {
 					ConnectedAccounts::<T>::remove(&old_connection.did, &account);
+					Self::remove_label(&account)?;
 					Self::deposit_event(Event::<T>::AssociationRemoved(account.clone(), old_connection.did));
 					LinkableAccountDepositCollector::<T>::free_deposit(old_connection.deposit)?;
 				}
@@ -439,26 +686,28 @@ pub mod pallet {
 
 		pub(crate) fn remove_association(account: LinkableAccountId) -> DispatchResult {
 			if let Some(connection) = ConnectedDids::<T>::take(&account) {
-				let is_key_migrated = <T as Config>::BalanceMigrationManager::is_key_migrated(
-					&ConnectedDids::<T>::hashed_key_for(&account),
-				);
-
-				if is_key_migrated {
-					LinkableAccountDepositCollector::<T>::free_deposit(connection.deposit)?;
-				} else {
-					<T as Config>::BalanceMigrationManager::release_reserved_deposit(
-						&connection.deposit.owner,
-						&connection.deposit.amount,
-					)
-				}
+				LinkableAccountDepositCollector::<T>::release_deposit::<BalanceMigrationManagerOf<T>>(
+					&account,
+					connection.deposit,
+				)?;
 
 				ConnectedAccounts::<T>::remove(&connection.did, &account);
+				Self::remove_label(&account)?;
 				Self::deposit_event(Event::AssociationRemoved(account, connection.did));
 				Ok(())
 			} else {
 				Err(Error::<T>::NotFound.into())
 			}
 		}
+
+		/// Remove the label of the provided account, if any, and free its
+		/// deposit.
+		fn remove_label(account: &LinkableAccountId) -> DispatchResult {
+			if let Some(label_record) = AccountLabels::<T>::take(account) {
+				LinkableAccountDepositCollector::<T>::free_deposit(label_record.deposit)?;
+			}
+			Ok(())
+		}
 	}
 
 	pub(crate) struct LinkableAccountDepositCollector<T: Config>(PhantomData<T>);
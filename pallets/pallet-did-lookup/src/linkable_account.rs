@@ -21,7 +21,7 @@ use scale_info::TypeInfo;
 use serde::{Deserialize, Serialize};
 use sp_runtime::AccountId32;
 
-use crate::account::AccountId20;
+use crate::{account::AccountId20, bitcoin::BitcoinAccountId, solana::SolanaAccountId};
 
 #[derive(
 	Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Encode, Decode, MaxEncodedLen, TypeInfo, Serialize, Deserialize,
@@ -29,6 +29,8 @@ use crate::account::AccountId20;
 pub enum LinkableAccountId {
 	AccountId20(AccountId20),
 	AccountId32(AccountId32),
+	Solana(SolanaAccountId),
+	Bitcoin(BitcoinAccountId),
 }
 
 impl From<AccountId20> for LinkableAccountId {
@@ -43,6 +45,18 @@ impl From<AccountId32> for LinkableAccountId {
 	}
 }
 
+impl From<SolanaAccountId> for LinkableAccountId {
+	fn from(account_id: SolanaAccountId) -> Self {
+		Self::Solana(account_id)
+	}
+}
+
+impl From<BitcoinAccountId> for LinkableAccountId {
+	fn from(account_id: BitcoinAccountId) -> Self {
+		Self::Bitcoin(account_id)
+	}
+}
+
 impl From<[u8; 20]> for LinkableAccountId {
 	fn from(account_id: [u8; 20]) -> Self {
 		Self::AccountId20(account_id.into())
@@ -60,6 +74,8 @@ impl AsRef<[u8]> for LinkableAccountId {
 		match self {
 			LinkableAccountId::AccountId20(value) => &value.0,
 			LinkableAccountId::AccountId32(value) => value.as_ref(),
+			LinkableAccountId::Solana(value) => &value.0,
+			LinkableAccountId::Bitcoin(value) => &value.0,
 		}
 	}
 }
@@ -70,6 +86,8 @@ impl std::fmt::Display for LinkableAccountId {
 		match self {
 			Self::AccountId20(account_id) => write!(f, "{}", account_id),
 			Self::AccountId32(account_id) => write!(f, "{}", account_id),
+			Self::Solana(account_id) => write!(f, "{}", account_id),
+			Self::Bitcoin(account_id) => write!(f, "{}", account_id),
 		}
 	}
 }
@@ -19,10 +19,18 @@
 use frame_support::{
 	pallet_prelude::DispatchResult,
 	traits::{fungible::Inspect, ReservableCurrency},
+	weights::Weight,
 };
-use kilt_support::migration::switch_reserved_to_hold;
+use kilt_support::migration::{switch_reserved_to_hold, SteppedMigration};
+use sp_std::{marker::PhantomData, vec::Vec};
 
-use crate::{linkable_account::LinkableAccountId, AccountIdOf, Config, ConnectedDids, CurrencyOf, Error, HoldReason};
+use crate::{
+	linkable_account::LinkableAccountId, AccountIdOf, Config, ConnectedDids, CurrencyOf, Error, HoldReason, Pallet,
+	WeightInfo,
+};
+
+/// The storage version which initialized the counter of the linked accounts.
+pub(crate) const CONNECTED_DIDS_COUNTER_STORAGE_VERSION: u16 = 5;
 
 pub fn update_balance_for_did_lookup<T: Config>(key: &LinkableAccountId) -> DispatchResult
 where
@@ -37,18 +45,90 @@ where
 	)
 }
 
+/// Initializes the counter of the linked accounts, which were stored before
+/// the map was counted, by counting one linked account per step.
+///
+/// The calls of the pallet are blocked until the migration has completed, so
+/// that no linked account is counted twice or missed.
+pub struct InitializeConnectedDidsCounter<T>(PhantomData<T>);
+
+impl<T: Config> SteppedMigration for InitializeConnectedDidsCounter<T> {
+	type Pallet = Pallet<T>;
+
+	const FROM: u16 = 4;
+	const TO: u16 = CONNECTED_DIDS_COUNTER_STORAGE_VERSION;
+
+	fn max_step_weight() -> Weight {
+		<T as Config>::WeightInfo::initialize_connected_dids_counter_step()
+	}
+
+	fn step(cursor: Option<Vec<u8>>) -> Option<Vec<u8>> {
+		let account = match cursor {
+			Some(cursor) => ConnectedDids::<T>::iter_keys_from(cursor).next(),
+			None => ConnectedDids::<T>::iter_keys().next(),
+		}?;
+		frame_support::storage::unhashed::put(
+			&ConnectedDids::<T>::counter_storage_final_key(),
+			&ConnectedDids::<T>::count().saturating_add(1),
+		);
+		Some(ConnectedDids::<T>::hashed_key_for(&account))
+	}
+}
+
 #[cfg(test)]
 pub mod test {
 	use frame_support::{
 		assert_noop,
-		traits::{fungible::InspectHold, ReservableCurrency},
+		traits::{fungible::InspectHold, GetStorageVersion, ReservableCurrency},
 	};
+	use kilt_support::migration::SteppedMigration;
 	use sp_runtime::traits::Zero;
 
 	use crate::{
-		migrations::update_balance_for_did_lookup, mock::*, AccountIdOf, Config, ConnectedDids, Error, HoldReason,
+		migrations::{update_balance_for_did_lookup, InitializeConnectedDidsCounter},
+		mock::*,
+		AccountIdOf, Config, ConnectedDids, Error, HoldReason, Pallet,
 	};
 
+	#[test]
+	fn test_stepped_migration_bumps_one_version() {
+		assert_eq!(
+			<InitializeConnectedDidsCounter<Test> as SteppedMigration>::TO,
+			<InitializeConnectedDidsCounter<Test> as SteppedMigration>::FROM + 1
+		);
+		assert_eq!(
+			Pallet::<Test>::current_storage_version(),
+			<InitializeConnectedDidsCounter<Test> as SteppedMigration>::TO
+		);
+	}
+
+	#[test]
+	fn test_initialize_connected_dids_counter() {
+		ExtBuilder::default()
+			.with_balances(vec![
+				(ACCOUNT_00, <Test as crate::Config>::Deposit::get() * 50),
+				(ACCOUNT_01, <Test as crate::Config>::Deposit::get() * 50),
+			])
+			.with_connections(vec![
+				(ACCOUNT_00, DID_00, LINKABLE_ACCOUNT_00),
+				(ACCOUNT_01, DID_01, LINKABLE_ACCOUNT_01),
+			])
+			.build_and_execute_with_sanity_tests(|| {
+				// The linked accounts were stored before the map was counted.
+				frame_support::storage::unhashed::kill(&ConnectedDids::<Test>::counter_storage_final_key());
+				assert_eq!(ConnectedDids::<Test>::count(), 0);
+
+				let mut cursor = None;
+				for counted in 1..=2 {
+					cursor = InitializeConnectedDidsCounter::<Test>::step(cursor);
+					assert!(cursor.is_some());
+					assert_eq!(ConnectedDids::<Test>::count(), counted);
+				}
+				assert!(InitializeConnectedDidsCounter::<Test>::step(cursor).is_none());
+				assert_eq!(ConnectedDids::<Test>::count(), 2);
+			})
+	}
+
 	#[test]
 	fn test_setup() {
 		ExtBuilder::default()
@@ -16,10 +16,11 @@
 
 // If you feel like getting in touch with us, you can do so at info@botlabs.org
 
-use frame_support::parameter_types;
+use frame_support::{parameter_types, weights::Weight};
 use frame_system::pallet_prelude::BlockNumberFor;
 use kilt_support::{
 	mock::{mock_origin, SubjectId},
+	signature::{SignatureVerificationError, SignatureVerificationResult, VerifyErc1271Proof},
 	traits::StorageDepositCollector,
 };
 
@@ -29,8 +30,13 @@ use sp_runtime::{
 };
 
 use crate::{
-	self as pallet_did_lookup, linkable_account::LinkableAccountId, AccountIdOf, BalanceOf, Config, ConnectedAccounts,
-	ConnectedDids, ConnectionRecord, DidIdentifierOf, LinkableAccountDepositCollector,
+	self as pallet_did_lookup,
+	account::AccountId20,
+	associate_account_request::{ContractSignature, ContractSignatureProof},
+	linkable_account::LinkableAccountId,
+	signed_extension::DidCallSubject,
+	AccountIdOf, BalanceOf, Config, ConnectedAccounts, ConnectedDids, ConnectionRecord, DidIdentifierOf,
+	LinkableAccountDepositCollector,
 };
 
 pub(crate) type Block = frame_system::mocking::MockBlock<Test>;
@@ -107,17 +113,46 @@ impl pallet_balances::Config for Test {
 
 parameter_types! {
 	pub const DidLookupDeposit: Balance = 10;
+	pub const LabelDeposit: Balance = 5;
+	pub const MaxAssociationRemovals: u32 = 2;
+	pub const MaxProofValidity: u64 = 1_000;
+}
+
+/// Accepts contract signatures whose proof consists of the signed message
+/// hash only.
+pub struct MockContractSignatureVerifier;
+
+impl VerifyErc1271Proof<AccountId20, ContractSignature, ContractSignatureProof> for MockContractSignatureVerifier {
+	fn verify_is_valid_signature(
+		_contract: &AccountId20,
+		hash: &[u8; 32],
+		_signature: &ContractSignature,
+		proof: &ContractSignatureProof,
+	) -> SignatureVerificationResult {
+		match &proof[..] {
+			[node] if node[..] == hash[..] => Ok(()),
+			_ => Err(SignatureVerificationError::SignatureInvalid),
+		}
+	}
+
+	fn weight(_: usize) -> Weight {
+		Weight::zero()
+	}
 }
 
 impl pallet_did_lookup::Config for Test {
 	type BalanceMigrationManager = ();
+	type ContractSignatureVerifier = MockContractSignatureVerifier;
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeHoldReason = RuntimeHoldReason;
 	type Currency = Balances;
 	type Deposit = DidLookupDeposit;
+	type LabelDeposit = LabelDeposit;
 	type EnsureOrigin = mock_origin::EnsureDoubleOrigin<AccountId, SubjectId>;
 	type OriginSuccess = mock_origin::DoubleOrigin<AccountId, SubjectId>;
 	type DidIdentifier = SubjectId;
+	type MaxAssociationRemovals = MaxAssociationRemovals;
+	type MaxProofValidity = MaxProofValidity;
 	type WeightInfo = ();
 }
 
@@ -127,6 +162,19 @@ impl mock_origin::Config for Test {
 	type SubjectId = SubjectId;
 }
 
+/// Treats remarks as calls authorized by `DID_01`.
+#[derive(Clone, PartialEq, Eq)]
+pub struct MockDidCallSubject;
+
+impl DidCallSubject<RuntimeCall, AccountId, SubjectId> for MockDidCallSubject {
+	fn did_subject(call: &RuntimeCall, _submitter: &AccountId) -> Option<SubjectId> {
+		match call {
+			RuntimeCall::System(frame_system::Call::remark { .. }) => Some(DID_01),
+			_ => None,
+		}
+	}
+}
+
 pub(crate) const ACCOUNT_00: AccountId = AccountId::new([1u8; 32]);
 pub(crate) const ACCOUNT_01: AccountId = AccountId::new([2u8; 32]);
 pub(crate) const DID_00: SubjectId = SubjectId(ACCOUNT_00);
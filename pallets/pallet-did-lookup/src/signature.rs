@@ -16,16 +16,13 @@
 
 // If you feel like getting in touch with us, you can do so at info@botlabs.org
 
-use parity_scale_codec::alloc::string::ToString;
 use sp_std::vec::Vec;
 
 // According to https://github.com/polkadot-js/common/blob/5d5c7e4c0ace06e3301ccadfd3c3351955f1e251/packages/util/src/u8a/wrap.ts#L13
 const PAYLOAD_BYTES_WRAPPER_PREFIX: &[u8; 7] = b"<Bytes>";
 const PAYLOAD_BYTES_WRAPPER_POSTFIX: &[u8; 8] = b"</Bytes>";
-const ETHEREUM_SIGNATURE_PREFIX: &[u8; 26] = b"\x19Ethereum Signed Message:\n";
 pub(crate) enum WrapType {
 	Substrate,
-	Ethereum,
 }
 
 pub(crate) fn get_wrapped_payload(payload: &[u8], wrap_type: WrapType) -> Vec<u8> {
@@ -36,12 +33,5 @@ pub(crate) fn get_wrapped_payload(payload: &[u8], wrap_type: WrapType) -> Vec<u8
 			.chain(PAYLOAD_BYTES_WRAPPER_POSTFIX.iter())
 			.copied()
 			.collect(),
-		WrapType::Ethereum => ETHEREUM_SIGNATURE_PREFIX
-			.iter()
-			// eth wrapping also contains the length of the payload
-			.chain(payload.len().to_string().as_bytes().iter())
-			.chain(payload.iter())
-			.copied()
-			.collect(),
 	}
 }
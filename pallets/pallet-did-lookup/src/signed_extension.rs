@@ -0,0 +1,181 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+//! Signed extension linking the submitter of a DID call to the DID, so that
+//! creating a DID and linking the account to it does not require two separate
+//! transactions.
+
+use frame_support::{
+	dispatch::{DispatchInfo, DispatchResult, PostDispatchInfo},
+	storage::with_storage_layer,
+	traits::Get,
+};
+use kilt_support::traits::StorageDepositCollector;
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{DispatchInfoOf, Dispatchable, PostDispatchInfoOf, SignedExtension},
+	transaction_validity::{InvalidTransaction, TransactionValidity, TransactionValidityError, ValidTransaction},
+};
+use sp_std::marker::PhantomData;
+
+use crate::{
+	linkable_account::LinkableAccountId, AccountIdOf, Config, ConnectedDids, DidIdentifierOf,
+	LinkableAccountDepositCollector, Pallet, WeightInfo,
+};
+
+/// Returns the DID on behalf of which the given account submits the call, if
+/// the call is authorized by, or creates, a DID.
+pub trait DidCallSubject<Call, AccountId, DidIdentifier> {
+	fn did_subject(call: &Call, submitter: &AccountId) -> Option<DidIdentifier>;
+}
+
+impl<Call, AccountId, DidIdentifier> DidCallSubject<Call, AccountId, DidIdentifier> for () {
+	fn did_subject(_call: &Call, _submitter: &AccountId) -> Option<DidIdentifier> {
+		None
+	}
+}
+
+/// Links the submitting account to the DID of a successful DID call, if the
+/// signer opted in and the account is not linked to any DID yet.
+///
+/// The link deposit is paid by the submitter, and transactions of submitters
+/// that cannot pay it are rejected. The link is only created if the DID call
+/// succeeded.
+#[derive(Encode, Decode, TypeInfo, Clone, Eq, PartialEq)]
+#[scale_info(skip_type_params(T, Subject))]
+pub struct AutoLinkAccount<T, Subject> {
+	enabled: bool,
+	_phantom: PhantomData<(T, Subject)>,
+}
+
+impl<T, Subject> AutoLinkAccount<T, Subject> {
+	/// Creates the extension, linking the submitter if `enable` is true.
+	pub fn new(enable: bool) -> Self {
+		Self {
+			enabled: enable,
+			_phantom: PhantomData,
+		}
+	}
+}
+
+impl<T, Subject> sp_std::fmt::Debug for AutoLinkAccount<T, Subject> {
+	fn fmt(&self, f: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		write!(f, "AutoLinkAccount({})", self.enabled)
+	}
+}
+
+impl<T, Subject> AutoLinkAccount<T, Subject>
+where
+	T: Config,
+	AccountIdOf<T>: Into<LinkableAccountId>,
+	Subject: DidCallSubject<<T as frame_system::Config>::RuntimeCall, AccountIdOf<T>, DidIdentifierOf<T>>,
+{
+	/// Returns the DID the submitter should be linked to, if any.
+	fn link_target(
+		&self,
+		who: &AccountIdOf<T>,
+		call: &<T as frame_system::Config>::RuntimeCall,
+	) -> Option<DidIdentifierOf<T>> {
+		let account: LinkableAccountId = who.clone().into();
+		if !self.enabled || ConnectedDids::<T>::contains_key(&account) {
+			return None;
+		}
+		Subject::did_subject(call, who)
+	}
+}
+
+impl<T, Subject> SignedExtension for AutoLinkAccount<T, Subject>
+where
+	T: Config + Send + Sync,
+	AccountIdOf<T>: Into<LinkableAccountId>,
+	<T as frame_system::Config>::RuntimeCall: Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>,
+	Subject: DidCallSubject<<T as frame_system::Config>::RuntimeCall, AccountIdOf<T>, DidIdentifierOf<T>>
+		+ Send
+		+ Sync
+		+ 'static,
+{
+	const IDENTIFIER: &'static str = "AutoLinkAccount";
+
+	type AccountId = AccountIdOf<T>;
+	type Call = <T as frame_system::Config>::RuntimeCall;
+	type AdditionalSigned = ();
+	type Pre = Option<(AccountIdOf<T>, DidIdentifierOf<T>)>;
+
+	fn additional_signed(&self) -> Result<Self::AdditionalSigned, TransactionValidityError> {
+		Ok(())
+	}
+
+	fn validate(
+		&self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		_info: &DispatchInfoOf<Self::Call>,
+		_len: usize,
+	) -> TransactionValidity {
+		if self.link_target(who, call).is_some()
+			&& !LinkableAccountDepositCollector::<T>::can_create_deposit(who, <T as Config>::Deposit::get())
+		{
+			return Err(InvalidTransaction::Payment.into());
+		}
+		Ok(ValidTransaction::default())
+	}
+
+	fn pre_dispatch(
+		self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		len: usize,
+	) -> Result<Self::Pre, TransactionValidityError> {
+		self.validate(who, call, info, len)?;
+
+		let link_target = self.link_target(who, call);
+		if link_target.is_some() {
+			frame_system::Pallet::<T>::register_extra_weight_unchecked(
+				<T as Config>::WeightInfo::associate_sender(),
+				info.class,
+			);
+		}
+		Ok(link_target.map(|did| (who.clone(), did)))
+	}
+
+	fn post_dispatch(
+		pre: Option<Self::Pre>,
+		_info: &DispatchInfoOf<Self::Call>,
+		_post_info: &PostDispatchInfoOf<Self::Call>,
+		_len: usize,
+		result: &DispatchResult,
+	) -> Result<(), TransactionValidityError> {
+		let (Some(Some((who, did))), Ok(())) = (pre, result) else {
+			return Ok(());
+		};
+		let account: LinkableAccountId = who.clone().into();
+		// The DID call might have linked the account itself.
+		if ConnectedDids::<T>::contains_key(&account) {
+			return Ok(());
+		}
+
+		// The call has been dispatched already, so failing here would only
+		// invalidate the block.
+		if let Err(err) = with_storage_layer(|| Pallet::<T>::add_association(who, did, account)) {
+			log::warn!("Failed to link the submitter of a DID call: {:?}", err);
+		}
+		Ok(())
+	}
+}
@@ -0,0 +1,113 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+//! The Solana account and signature types.
+//!
+//! Solana wallets sign messages in the off-chain message format, i.e., the
+//! payload is prefixed with a signing domain and a header.
+
+use frame_support::RuntimeDebug;
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use serde::{Deserialize, Serialize};
+use sp_core::ed25519;
+use sp_std::vec::Vec;
+
+// According to https://github.com/solana-labs/solana/blob/v1.17.0/sdk/src/offchain_message.rs
+const SOLANA_OFFCHAIN_SIGNING_DOMAIN: &[u8; 16] = b"\xffsolana offchain";
+const SOLANA_OFFCHAIN_HEADER_VERSION: u8 = 0;
+// The challenge only consists of printable ASCII characters.
+const SOLANA_OFFCHAIN_RESTRICTED_ASCII_FORMAT: u8 = 0;
+
+/// The SolanaAccountId type.
+/// It is the 32-byte ed25519 public key of a Solana wallet.
+#[derive(
+	Eq,
+	PartialEq,
+	Copy,
+	Clone,
+	Encode,
+	Decode,
+	TypeInfo,
+	MaxEncodedLen,
+	Default,
+	PartialOrd,
+	Ord,
+	RuntimeDebug,
+	Serialize,
+	Deserialize,
+)]
+pub struct SolanaAccountId(pub [u8; 32]);
+
+#[cfg(feature = "std")]
+impl std::fmt::Display for SolanaAccountId {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		use base58::ToBase58;
+
+		write!(f, "{}", self.0.to_base58())
+	}
+}
+
+impl From<[u8; 32]> for SolanaAccountId {
+	fn from(bytes: [u8; 32]) -> Self {
+		Self(bytes)
+	}
+}
+
+impl From<ed25519::Public> for SolanaAccountId {
+	fn from(public: ed25519::Public) -> Self {
+		Self(public.0)
+	}
+}
+
+/// A signature over a Solana off-chain message.
+#[derive(Eq, PartialEq, Clone, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub struct SolanaSignature(ed25519::Signature);
+
+impl From<ed25519::Signature> for SolanaSignature {
+	fn from(x: ed25519::Signature) -> Self {
+		SolanaSignature(x)
+	}
+}
+
+impl SolanaSignature {
+	/// Verifies that the signer signed the payload as a Solana off-chain
+	/// message.
+	pub fn verify(&self, payload: &[u8], signer: &SolanaAccountId) -> bool {
+		let Some(message) = get_offchain_message(payload) else {
+			return false;
+		};
+		sp_io::crypto::ed25519_verify(&self.0, &message, &ed25519::Public::from_raw(signer.0))
+	}
+}
+
+/// Wraps the payload into a Solana off-chain message.
+///
+/// Returns `None` if the payload is too long for the message format.
+pub(crate) fn get_offchain_message(payload: &[u8]) -> Option<Vec<u8>> {
+	let message_length = u16::try_from(payload.len()).ok()?;
+	Some(
+		SOLANA_OFFCHAIN_SIGNING_DOMAIN
+			.iter()
+			.chain([SOLANA_OFFCHAIN_HEADER_VERSION, SOLANA_OFFCHAIN_RESTRICTED_ASCII_FORMAT].iter())
+			.chain(message_length.to_le_bytes().iter())
+			.chain(payload.iter())
+			.copied()
+			.collect(),
+	)
+}
@@ -18,21 +18,26 @@
 
 use frame_support::{assert_noop, assert_ok, crypto::ecdsa::ECDSAExt, traits::fungible::InspectHold};
 use frame_system::pallet_prelude::BlockNumberFor;
-use kilt_support::{mock::mock_origin, Deposit};
+use kilt_support::{
+	mock::mock_origin,
+	signature::{ethereum_personal_sign_payload, Erc1271Signature},
+	Deposit,
+};
 use parity_scale_codec::Encode;
 use sha3::{Digest, Keccak256};
 use sp_runtime::{
-	app_crypto::{ecdsa, sr25519, Pair},
+	app_crypto::{ecdsa, ed25519, sr25519, Pair},
 	traits::IdentifyAccount,
 	MultiSignature, MultiSigner,
 };
 
 use crate::{
 	account::{AccountId20, EthereumSignature},
-	associate_account_request::{get_challenge, AssociateAccountRequest},
+	associate_account_request::{get_challenge, AssociateAccountRequest, ContractSignature},
+	bitcoin::{get_signed_message_hash, BitcoinAccountId},
 	linkable_account::LinkableAccountId,
 	mock::*,
-	signature::get_wrapped_payload,
+	solana::{get_offchain_message, SolanaAccountId, SolanaSignature},
 	ConnectedAccounts, ConnectedDids, ConnectionRecord, Error, HoldReason,
 };
 
@@ -95,10 +100,24 @@ fn test_add_association_account() {
 			let expire_at: BlockNumberFor<Test> = 500;
 			let account_hash_alice = MultiSigner::from(pair_alice.public()).into_account();
 			let sig_alice_0 = MultiSignature::from(
-				pair_alice.sign(&[b"<Bytes>", get_challenge(&DID_00, expire_at).as_bytes(), b"</Bytes>"].concat()[..]),
+				pair_alice.sign(
+					&[
+						b"<Bytes>",
+						get_challenge(&DID_00, expire_at, DidLookup::genesis_hash().as_ref()).as_bytes(),
+						b"</Bytes>",
+					]
+					.concat()[..],
+				),
 			);
 			let sig_alice_1 = MultiSignature::from(
-				pair_alice.sign(&[b"<Bytes>", get_challenge(&DID_01, expire_at).as_bytes(), b"</Bytes>"].concat()[..]),
+				pair_alice.sign(
+					&[
+						b"<Bytes>",
+						get_challenge(&DID_01, expire_at, DidLookup::genesis_hash().as_ref()).as_bytes(),
+						b"</Bytes>",
+					]
+					.concat()[..],
+				),
 			);
 
 			// new association. No overwrite
@@ -198,9 +217,8 @@ fn test_add_eth_association() {
 			let eth_pair = ecdsa::Pair::generate().0;
 			let eth_account = AccountId20(eth_pair.public().to_eth_address().unwrap());
 
-			let wrapped_payload = get_wrapped_payload(
-				get_challenge(&DID_00, expire_at).as_bytes(),
-				crate::signature::WrapType::Ethereum,
+			let wrapped_payload = ethereum_personal_sign_payload(
+				get_challenge(&DID_00, expire_at, DidLookup::genesis_hash().as_ref()).as_bytes(),
 			);
 
 			let sig = eth_pair.sign_prehashed(&Keccak256::digest(wrapped_payload).try_into().unwrap());
@@ -230,6 +248,212 @@ fn test_add_eth_association() {
 		});
 }
 
+#[test]
+fn test_add_eth_contract_association() {
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, <Test as crate::Config>::Deposit::get() * 50)])
+		.build_and_execute_with_sanity_tests(|| {
+			let expire_at: BlockNumberFor<Test> = 500;
+			let contract_account = AccountId20([1u8; 20]);
+			let hash = Keccak256::digest(ethereum_personal_sign_payload(
+				get_challenge(&DID_00, expire_at, DidLookup::genesis_hash().as_ref()).as_bytes(),
+			));
+			let signature = Erc1271Signature {
+				signature: ContractSignature::default(),
+				proof: vec![hash.to_vec().try_into().unwrap()].try_into().unwrap(),
+			};
+
+			assert_ok!(DidLookup::associate_account(
+				mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(),
+				AssociateAccountRequest::EthereumContract(contract_account, signature),
+				expire_at,
+			));
+			assert_eq!(
+				ConnectedDids::<Test>::get(LinkableAccountId::from(contract_account)),
+				Some(ConnectionRecord {
+					did: DID_00,
+					deposit: Deposit {
+						owner: ACCOUNT_00,
+						amount: 10,
+					}
+				})
+			);
+			assert!(ConnectedAccounts::<Test>::get(DID_00, LinkableAccountId::from(contract_account)).is_some());
+		});
+}
+
+#[test]
+fn test_add_eth_contract_association_invalid_proof() {
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, <Test as crate::Config>::Deposit::get() * 50)])
+		.build_and_execute_with_sanity_tests(|| {
+			let expire_at: BlockNumberFor<Test> = 500;
+			// The proof is generated for a different DID.
+			let hash = Keccak256::digest(ethereum_personal_sign_payload(
+				get_challenge(&DID_01, expire_at, DidLookup::genesis_hash().as_ref()).as_bytes(),
+			));
+			let signature = Erc1271Signature {
+				signature: ContractSignature::default(),
+				proof: vec![hash.to_vec().try_into().unwrap()].try_into().unwrap(),
+			};
+
+			assert_noop!(
+				DidLookup::associate_account(
+					mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(),
+					AssociateAccountRequest::EthereumContract(AccountId20([1u8; 20]), signature),
+					expire_at,
+				),
+				Error::<Test>::NotAuthorized
+			);
+		});
+}
+
+#[test]
+fn test_add_solana_association() {
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, <Test as crate::Config>::Deposit::get() * 50)])
+		.build_and_execute_with_sanity_tests(|| {
+			let expire_at: BlockNumberFor<Test> = 500;
+			let solana_pair = ed25519::Pair::generate().0;
+			let solana_account = SolanaAccountId::from(solana_pair.public());
+
+			let message =
+				get_offchain_message(get_challenge(&DID_00, expire_at, DidLookup::genesis_hash().as_ref()).as_bytes())
+					.unwrap();
+			let sig = SolanaSignature::from(solana_pair.sign(&message));
+
+			assert_ok!(DidLookup::associate_account(
+				mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(),
+				AssociateAccountRequest::Solana(solana_account, sig),
+				expire_at,
+			));
+			assert_eq!(
+				ConnectedDids::<Test>::get(LinkableAccountId::from(solana_account)),
+				Some(ConnectionRecord {
+					did: DID_00,
+					deposit: Deposit {
+						owner: ACCOUNT_00,
+						amount: 10,
+					}
+				})
+			);
+			assert!(ConnectedAccounts::<Test>::get(DID_00, LinkableAccountId::from(solana_account)).is_some());
+		});
+}
+
+#[test]
+fn test_add_solana_association_without_offchain_message() {
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, <Test as crate::Config>::Deposit::get() * 50)])
+		.build_and_execute_with_sanity_tests(|| {
+			let expire_at: BlockNumberFor<Test> = 500;
+			let solana_pair = ed25519::Pair::generate().0;
+
+			// Sign the challenge without the off-chain message domain and header.
+			let sig = SolanaSignature::from(
+				solana_pair.sign(get_challenge(&DID_00, expire_at, DidLookup::genesis_hash().as_ref()).as_bytes()),
+			);
+
+			assert_noop!(
+				DidLookup::associate_account(
+					mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(),
+					AssociateAccountRequest::Solana(solana_pair.public().into(), sig),
+					expire_at,
+				),
+				Error::<Test>::NotAuthorized
+			);
+		});
+}
+
+#[test]
+fn test_add_bitcoin_association() {
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, <Test as crate::Config>::Deposit::get() * 50)])
+		.build_and_execute_with_sanity_tests(|| {
+			let expire_at: BlockNumberFor<Test> = 500;
+			let bitcoin_pair = ecdsa::Pair::generate().0;
+			let bitcoin_account = BitcoinAccountId::from(bitcoin_pair.public().0);
+
+			let sig = bitcoin_pair.sign_prehashed(&get_signed_message_hash(
+				get_challenge(&DID_00, expire_at, DidLookup::genesis_hash().as_ref()).as_bytes(),
+			));
+			// Move the recovery ID into the header byte for a compressed public key.
+			let mut bitcoin_sig = [0u8; 65];
+			bitcoin_sig[0] = 31 + sig.0[64];
+			bitcoin_sig[1..].copy_from_slice(&sig.0[..64]);
+
+			assert_ok!(DidLookup::associate_account(
+				mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(),
+				AssociateAccountRequest::Bitcoin(bitcoin_account, bitcoin_sig.into()),
+				expire_at,
+			));
+			assert_eq!(
+				ConnectedDids::<Test>::get(LinkableAccountId::from(bitcoin_account)),
+				Some(ConnectionRecord {
+					did: DID_00,
+					deposit: Deposit {
+						owner: ACCOUNT_00,
+						amount: 10,
+					}
+				})
+			);
+			assert!(ConnectedAccounts::<Test>::get(DID_00, LinkableAccountId::from(bitcoin_account)).is_some());
+		});
+}
+
+#[test]
+fn test_add_bitcoin_association_segwit_header() {
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, <Test as crate::Config>::Deposit::get() * 50)])
+		.build_and_execute_with_sanity_tests(|| {
+			let expire_at: BlockNumberFor<Test> = 500;
+			let bitcoin_pair = ecdsa::Pair::generate().0;
+			let bitcoin_account = BitcoinAccountId::from(bitcoin_pair.public().0);
+
+			let sig = bitcoin_pair.sign_prehashed(&get_signed_message_hash(
+				get_challenge(&DID_00, expire_at, DidLookup::genesis_hash().as_ref()).as_bytes(),
+			));
+			// Native segwit wallets use headers 39-42 for the same compressed public key.
+			let mut bitcoin_sig = [0u8; 65];
+			bitcoin_sig[0] = 39 + sig.0[64];
+			bitcoin_sig[1..].copy_from_slice(&sig.0[..64]);
+
+			assert_ok!(DidLookup::associate_account(
+				mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(),
+				AssociateAccountRequest::Bitcoin(bitcoin_account, bitcoin_sig.into()),
+				expire_at,
+			));
+			assert!(ConnectedAccounts::<Test>::get(DID_00, LinkableAccountId::from(bitcoin_account)).is_some());
+		});
+}
+
+#[test]
+fn test_add_bitcoin_association_invalid_header() {
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, <Test as crate::Config>::Deposit::get() * 50)])
+		.build_and_execute_with_sanity_tests(|| {
+			let expire_at: BlockNumberFor<Test> = 500;
+			let bitcoin_pair = ecdsa::Pair::generate().0;
+
+			let sig = bitcoin_pair.sign_prehashed(&get_signed_message_hash(
+				get_challenge(&DID_00, expire_at, DidLookup::genesis_hash().as_ref()).as_bytes(),
+			));
+			// Headers 27-30 are used for uncompressed public keys.
+			let mut bitcoin_sig = [0u8; 65];
+			bitcoin_sig[0] = 27 + sig.0[64];
+			bitcoin_sig[1..].copy_from_slice(&sig.0[..64]);
+
+			assert_noop!(
+				DidLookup::associate_account(
+					mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(),
+					AssociateAccountRequest::Bitcoin(bitcoin_pair.public().0.into(), bitcoin_sig.into()),
+					expire_at,
+				),
+				Error::<Test>::NotAuthorized
+			);
+		});
+}
+
 #[test]
 fn test_add_association_account_invalid_signature() {
 	ExtBuilder::default()
@@ -282,6 +506,86 @@ fn test_add_association_account_expired() {
 		});
 }
 
+#[test]
+fn test_add_association_account_validity_too_long() {
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, <Test as crate::Config>::Deposit::get() * 50)])
+		.build_and_execute_with_sanity_tests(|| {
+			let pair_alice = sr25519::Pair::from_seed(b"Alice                           ");
+			let account_hash_alice = MultiSigner::from(pair_alice.public()).into_account();
+			System::set_block_number(10);
+			let expire_at: BlockNumberFor<Test> = 10 + MaxProofValidity::get() + 1;
+			let sig_alice_0 = MultiSignature::from(
+				pair_alice.sign(
+					&[
+						b"<Bytes>",
+						get_challenge(&DID_00, expire_at, DidLookup::genesis_hash().as_ref()).as_bytes(),
+						b"</Bytes>",
+					]
+					.concat()[..],
+				),
+			);
+
+			assert_noop!(
+				DidLookup::associate_account(
+					mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(),
+					AssociateAccountRequest::Polkadot(account_hash_alice.clone(), sig_alice_0),
+					expire_at,
+				),
+				Error::<Test>::ProofValidityTooLong
+			);
+
+			// The maximum validity itself is still accepted.
+			let expire_at: BlockNumberFor<Test> = 10 + MaxProofValidity::get();
+			let sig_alice_0 = MultiSignature::from(
+				pair_alice.sign(
+					&[
+						b"<Bytes>",
+						get_challenge(&DID_00, expire_at, DidLookup::genesis_hash().as_ref()).as_bytes(),
+						b"</Bytes>",
+					]
+					.concat()[..],
+				),
+			);
+			assert_ok!(DidLookup::associate_account(
+				mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(),
+				AssociateAccountRequest::Polkadot(account_hash_alice, sig_alice_0),
+				expire_at,
+			));
+		});
+}
+
+#[test]
+fn test_add_association_account_wrong_genesis() {
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, <Test as crate::Config>::Deposit::get() * 50)])
+		.build_and_execute_with_sanity_tests(|| {
+			let pair_alice = sr25519::Pair::from_seed(b"Alice                           ");
+			let account_hash_alice = MultiSigner::from(pair_alice.public()).into_account();
+			let expire_at: BlockNumberFor<Test> = 500;
+			// The proof is generated for a different chain.
+			let sig_alice_0 = MultiSignature::from(
+				pair_alice.sign(
+					&[
+						b"<Bytes>",
+						get_challenge(&DID_00, expire_at, &[0u8; 32]).as_bytes(),
+						b"</Bytes>",
+					]
+					.concat()[..],
+				),
+			);
+
+			assert_noop!(
+				DidLookup::associate_account(
+					mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(),
+					AssociateAccountRequest::Polkadot(account_hash_alice, sig_alice_0),
+					expire_at,
+				),
+				Error::<Test>::NotAuthorized
+			);
+		});
+}
+
 #[test]
 fn test_remove_association_sender() {
 	ExtBuilder::default()
@@ -376,3 +680,131 @@ fn test_remove_association_account_not_authorized() {
 			);
 		});
 }
+
+#[test]
+fn test_remove_all_account_associations() {
+	let linkable_account_02 = LinkableAccountId::from(AccountId::new([3u8; 32]));
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_01, <Test as crate::Config>::Deposit::get() * 50)])
+		.with_connections(vec![
+			(ACCOUNT_01, DID_01, LINKABLE_ACCOUNT_00),
+			(ACCOUNT_01, DID_01, LINKABLE_ACCOUNT_01),
+			(ACCOUNT_01, DID_01, linkable_account_02.clone()),
+		])
+		.build_and_execute_with_sanity_tests(|| {
+			System::set_block_number(1);
+
+			// The limit is capped by `MaxAssociationRemovals`.
+			assert_ok!(DidLookup::remove_all_account_associations(
+				mock_origin::DoubleOrigin(ACCOUNT_00, DID_01).into(),
+				10
+			));
+			assert_eq!(ConnectedAccounts::<Test>::iter_key_prefix(DID_01).count(), 1);
+			assert_eq!(
+				Balances::balance_on_hold(&HoldReason::Deposit.into(), &ACCOUNT_01),
+				<Test as crate::Config>::Deposit::get()
+			);
+			let next_account = ConnectedAccounts::<Test>::iter_key_prefix(DID_01).next();
+			System::assert_last_event(crate::Event::<Test>::AssociationsRemoved(DID_01, 2, next_account).into());
+
+			assert_ok!(DidLookup::remove_all_account_associations(
+				mock_origin::DoubleOrigin(ACCOUNT_00, DID_01).into(),
+				10
+			));
+			assert_eq!(ConnectedAccounts::<Test>::iter_key_prefix(DID_01).count(), 0);
+			assert_eq!(ConnectedDids::<Test>::get(&linkable_account_02), None);
+			assert_eq!(Balances::balance_on_hold(&HoldReason::Deposit.into(), &ACCOUNT_01), 0);
+			System::assert_last_event(crate::Event::<Test>::AssociationsRemoved(DID_01, 1, None).into());
+
+			assert_noop!(
+				DidLookup::remove_all_account_associations(mock_origin::DoubleOrigin(ACCOUNT_00, DID_01).into(), 10),
+				Error::<Test>::NotFound
+			);
+		});
+}
+
+#[test]
+fn test_migrate_association() {
+	ExtBuilder::default()
+		.with_balances(vec![
+			(ACCOUNT_00, <Test as crate::Config>::Deposit::get() * 50),
+			(ACCOUNT_01, <Test as crate::Config>::Deposit::get() * 50),
+		])
+		.with_connections(vec![(ACCOUNT_01, DID_01, LINKABLE_ACCOUNT_00)])
+		.build_and_execute_with_sanity_tests(|| {
+			let pair_alice = sr25519::Pair::from_seed(b"Alice                           ");
+			let expire_at: BlockNumberFor<Test> = 500;
+			let account_hash_alice = MultiSigner::from(pair_alice.public()).into_account();
+			let sig_alice = MultiSignature::from(
+				pair_alice.sign(
+					&[
+						b"<Bytes>",
+						get_challenge(&DID_01, expire_at, DidLookup::genesis_hash().as_ref()).as_bytes(),
+						b"</Bytes>",
+					]
+					.concat()[..],
+				),
+			);
+
+			assert_ok!(DidLookup::migrate_association(
+				mock_origin::DoubleOrigin(ACCOUNT_00, DID_01).into(),
+				LINKABLE_ACCOUNT_00,
+				AssociateAccountRequest::Polkadot(account_hash_alice.clone(), sig_alice),
+				expire_at,
+			));
+			assert_eq!(ConnectedDids::<Test>::get(LINKABLE_ACCOUNT_00), None);
+			assert!(ConnectedAccounts::<Test>::get(DID_01, LINKABLE_ACCOUNT_00).is_none());
+			assert_eq!(
+				ConnectedDids::<Test>::get(LinkableAccountId::from(account_hash_alice.clone())),
+				Some(ConnectionRecord {
+					did: DID_01,
+					deposit: Deposit {
+						owner: ACCOUNT_00,
+						amount: 10,
+					}
+				})
+			);
+			assert!(ConnectedAccounts::<Test>::get(DID_01, LinkableAccountId::from(account_hash_alice)).is_some());
+			assert_eq!(Balances::balance_on_hold(&HoldReason::Deposit.into(), &ACCOUNT_01), 0);
+			assert_eq!(
+				Balances::balance_on_hold(&HoldReason::Deposit.into(), &ACCOUNT_00),
+				<Test as crate::Config>::Deposit::get()
+			);
+		});
+}
+
+#[test]
+fn test_migrate_association_not_authorized() {
+	ExtBuilder::default()
+		.with_balances(vec![
+			(ACCOUNT_00, <Test as crate::Config>::Deposit::get() * 50),
+			(ACCOUNT_01, <Test as crate::Config>::Deposit::get() * 50),
+		])
+		.with_connections(vec![(ACCOUNT_01, DID_01, LINKABLE_ACCOUNT_00)])
+		.build_and_execute_with_sanity_tests(|| {
+			let pair_alice = sr25519::Pair::from_seed(b"Alice                           ");
+			let expire_at: BlockNumberFor<Test> = 500;
+			let account_hash_alice = MultiSigner::from(pair_alice.public()).into_account();
+			let sig_alice = MultiSignature::from(
+				pair_alice.sign(
+					&[
+						b"<Bytes>",
+						get_challenge(&DID_00, expire_at, DidLookup::genesis_hash().as_ref()).as_bytes(),
+						b"</Bytes>",
+					]
+					.concat()[..],
+				),
+			);
+
+			// The old account is linked to a different DID.
+			assert_noop!(
+				DidLookup::migrate_association(
+					mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(),
+					LINKABLE_ACCOUNT_00,
+					AssociateAccountRequest::Polkadot(account_hash_alice, sig_alice),
+					expire_at,
+				),
+				Error::<Test>::NotAuthorized
+			);
+		});
+}
@@ -0,0 +1,157 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use frame_support::{assert_noop, assert_ok, traits::fungible::InspectHold};
+use kilt_support::{mock::mock_origin, Deposit};
+
+use crate::{mock::*, AccountLabels, Error, HoldReason, LabelRecord, LinkLabel};
+
+fn label(value: &[u8]) -> LinkLabel {
+	value.to_vec().try_into().expect("label should fit")
+}
+
+#[test]
+fn test_set_account_label() {
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, <Test as crate::Config>::Deposit::get() * 50)])
+		.with_connections(vec![(ACCOUNT_00, DID_00, LINKABLE_ACCOUNT_00)])
+		.build_and_execute_with_sanity_tests(|| {
+			let link_deposit = <Test as crate::Config>::Deposit::get();
+			let label_deposit = <Test as crate::Config>::LabelDeposit::get();
+
+			assert_ok!(DidLookup::set_account_label(
+				mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(),
+				LINKABLE_ACCOUNT_00,
+				Some(label(b"cold wallet"))
+			));
+			assert_eq!(
+				AccountLabels::<Test>::get(LINKABLE_ACCOUNT_00),
+				Some(LabelRecord {
+					label: label(b"cold wallet"),
+					deposit: Deposit {
+						owner: ACCOUNT_00,
+						amount: label_deposit,
+					}
+				})
+			);
+			assert_eq!(
+				Balances::balance_on_hold(&HoldReason::Deposit.into(), &ACCOUNT_00),
+				link_deposit + label_deposit
+			);
+
+			// Changing the label keeps the existing deposit.
+			assert_ok!(DidLookup::set_account_label(
+				mock_origin::DoubleOrigin(ACCOUNT_01, DID_00).into(),
+				LINKABLE_ACCOUNT_00,
+				Some(label(b"hot wallet"))
+			));
+			assert_eq!(
+				AccountLabels::<Test>::get(LINKABLE_ACCOUNT_00).map(|record| (record.label, record.deposit.owner)),
+				Some((label(b"hot wallet"), ACCOUNT_00))
+			);
+			assert_eq!(Balances::balance_on_hold(&HoldReason::Deposit.into(), &ACCOUNT_01), 0);
+
+			assert_ok!(DidLookup::set_account_label(
+				mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(),
+				LINKABLE_ACCOUNT_00,
+				None
+			));
+			assert_eq!(AccountLabels::<Test>::get(LINKABLE_ACCOUNT_00), None);
+			assert_eq!(
+				Balances::balance_on_hold(&HoldReason::Deposit.into(), &ACCOUNT_00),
+				link_deposit
+			);
+		});
+}
+
+#[test]
+fn test_set_account_label_not_authorized() {
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, <Test as crate::Config>::Deposit::get() * 50)])
+		.with_connections(vec![(ACCOUNT_00, DID_00, LINKABLE_ACCOUNT_00)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_noop!(
+				DidLookup::set_account_label(
+					mock_origin::DoubleOrigin(ACCOUNT_01, DID_01).into(),
+					LINKABLE_ACCOUNT_00,
+					Some(label(b"exchange"))
+				),
+				Error::<Test>::NotAuthorized
+			);
+			assert_noop!(
+				DidLookup::set_account_label(
+					mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(),
+					LINKABLE_ACCOUNT_01,
+					Some(label(b"exchange"))
+				),
+				Error::<Test>::NotFound
+			);
+		});
+}
+
+#[test]
+fn test_set_account_label_insufficient_funds() {
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, <Test as crate::Config>::Deposit::get() * 50)])
+		.with_connections(vec![(ACCOUNT_00, DID_00, LINKABLE_ACCOUNT_00)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_noop!(
+				DidLookup::set_account_label(
+					mock_origin::DoubleOrigin(ACCOUNT_01, DID_00).into(),
+					LINKABLE_ACCOUNT_00,
+					Some(label(b"exchange"))
+				),
+				Error::<Test>::InsufficientFunds
+			);
+		});
+}
+
+#[test]
+fn test_remove_association_removes_label() {
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, <Test as crate::Config>::Deposit::get() * 50)])
+		.with_connections(vec![(ACCOUNT_00, DID_00, LINKABLE_ACCOUNT_00)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(DidLookup::set_account_label(
+				mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(),
+				LINKABLE_ACCOUNT_00,
+				Some(label(b"cold wallet"))
+			));
+			assert_ok!(DidLookup::remove_sender_association(RuntimeOrigin::signed(ACCOUNT_00)));
+			assert_eq!(AccountLabels::<Test>::get(LINKABLE_ACCOUNT_00), None);
+			assert_eq!(Balances::balance_on_hold(&HoldReason::Deposit.into(), &ACCOUNT_00), 0);
+		});
+}
+
+#[test]
+fn test_relink_account_removes_label() {
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, <Test as crate::Config>::Deposit::get() * 50)])
+		.with_connections(vec![(ACCOUNT_00, DID_00, LINKABLE_ACCOUNT_00)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(DidLookup::set_account_label(
+				mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(),
+				LINKABLE_ACCOUNT_00,
+				Some(label(b"cold wallet"))
+			));
+			assert_ok!(DidLookup::associate_sender(
+				mock_origin::DoubleOrigin(ACCOUNT_00, DID_01).into()
+			));
+			assert_eq!(AccountLabels::<Test>::get(LINKABLE_ACCOUNT_00), None);
+		});
+}
@@ -17,3 +17,5 @@
 // If you feel like getting in touch with us, you can do so at info@botlabs.org
 mod associate;
 mod deposit;
+mod label;
+mod signed_extension;
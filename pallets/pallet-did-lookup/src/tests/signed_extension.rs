@@ -0,0 +1,139 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use frame_support::{
+	dispatch::{DispatchInfo, PostDispatchInfo},
+	traits::fungible::InspectHold,
+};
+use sp_runtime::{
+	traits::SignedExtension,
+	transaction_validity::{InvalidTransaction, TransactionValidityError},
+	DispatchError,
+};
+
+use crate::{mock::*, signed_extension::AutoLinkAccount, ConnectedAccounts, ConnectedDids, HoldReason};
+
+type Extension = AutoLinkAccount<Test, MockDidCallSubject>;
+
+fn did_call() -> RuntimeCall {
+	RuntimeCall::System(frame_system::Call::remark { remark: vec![] })
+}
+
+fn other_call() -> RuntimeCall {
+	RuntimeCall::System(frame_system::Call::remark_with_event { remark: vec![] })
+}
+
+fn dispatch_with_extension(
+	extension: Extension,
+	call: &RuntimeCall,
+	result: Result<(), DispatchError>,
+) -> Result<(), TransactionValidityError> {
+	let info = DispatchInfo::default();
+	let pre = extension.pre_dispatch(&ACCOUNT_00, call, &info, 0)?;
+	Extension::post_dispatch(Some(pre), &info, &PostDispatchInfo::default(), 0, &result)
+}
+
+#[test]
+fn links_submitter_on_did_call() {
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, 100)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_eq!(
+				dispatch_with_extension(Extension::new(true), &did_call(), Ok(())),
+				Ok(())
+			);
+
+			assert_eq!(
+				ConnectedDids::<Test>::get(LINKABLE_ACCOUNT_00).map(|record| record.did),
+				Some(DID_01)
+			);
+			assert!(ConnectedAccounts::<Test>::contains_key(DID_01, LINKABLE_ACCOUNT_00));
+			assert_eq!(
+				Balances::balance_on_hold(&HoldReason::Deposit.into(), &ACCOUNT_00),
+				<Test as crate::Config>::Deposit::get()
+			);
+		});
+}
+
+#[test]
+fn does_not_link_if_disabled_or_no_did_call() {
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, 100)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_eq!(
+				dispatch_with_extension(Extension::new(false), &did_call(), Ok(())),
+				Ok(())
+			);
+			assert_eq!(
+				dispatch_with_extension(Extension::new(true), &other_call(), Ok(())),
+				Ok(())
+			);
+
+			assert!(!ConnectedDids::<Test>::contains_key(LINKABLE_ACCOUNT_00));
+		});
+}
+
+#[test]
+fn does_not_link_if_call_failed() {
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, 100)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_eq!(
+				dispatch_with_extension(Extension::new(true), &did_call(), Err(DispatchError::BadOrigin)),
+				Ok(())
+			);
+
+			assert!(!ConnectedDids::<Test>::contains_key(LINKABLE_ACCOUNT_00));
+			assert_eq!(Balances::balance_on_hold(&HoldReason::Deposit.into(), &ACCOUNT_00), 0);
+		});
+}
+
+#[test]
+fn does_not_replace_existing_link() {
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, 100)])
+		.with_connections(vec![(ACCOUNT_00, DID_00, LINKABLE_ACCOUNT_00)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_eq!(
+				dispatch_with_extension(Extension::new(true), &did_call(), Ok(())),
+				Ok(())
+			);
+
+			assert_eq!(
+				ConnectedDids::<Test>::get(LINKABLE_ACCOUNT_00).map(|record| record.did),
+				Some(DID_00)
+			);
+			assert!(!ConnectedAccounts::<Test>::contains_key(DID_01, LINKABLE_ACCOUNT_00));
+		});
+}
+
+#[test]
+fn rejects_transaction_if_deposit_cannot_be_paid() {
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, 15)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_eq!(
+				Extension::new(true).validate(&ACCOUNT_00, &did_call(), &DispatchInfo::default(), 0),
+				Err(InvalidTransaction::Payment.into())
+			);
+			// Opting out still works.
+			assert!(Extension::new(false)
+				.validate(&ACCOUNT_00, &did_call(), &DispatchInfo::default(), 0)
+				.is_ok());
+		});
+}
@@ -21,7 +21,7 @@ use kilt_support::test_utils::log_and_return_error_message;
 use scale_info::prelude::format;
 use sp_runtime::TryRuntimeError;
 
-use crate::{Config, ConnectedAccounts, ConnectedDids};
+use crate::{AccountLabels, Config, ConnectedAccounts, ConnectedDids};
 
 pub(crate) fn do_try_state<T: Config>() -> Result<(), TryRuntimeError> {
 	ConnectedDids::<T>::iter().try_for_each(|(account, record)| -> Result<(), TryRuntimeError> {
@@ -43,5 +43,13 @@ pub(crate) fn do_try_state<T: Config>() -> Result<(), TryRuntimeError> {
 			);
 			Ok(())
 		},
-	)
+	)?;
+
+	AccountLabels::<T>::iter_keys().try_for_each(|account| -> Result<(), TryRuntimeError> {
+		ensure!(
+			ConnectedDids::<T>::contains_key(&account),
+			log_and_return_error_message(format!("Label for unlinked account {:?} found", account))
+		);
+		Ok(())
+	})
 }
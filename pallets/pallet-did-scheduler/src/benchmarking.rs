@@ -0,0 +1,133 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use frame_benchmarking::{account, benchmarks};
+use frame_support::traits::{
+	fungible::{Inspect, Mutate},
+	schedule::v3::TaskName,
+	Get,
+};
+use frame_system::RawOrigin;
+use sp_runtime::traits::Saturating;
+use sp_std::{boxed::Box, vec};
+
+use kilt_support::traits::GenerateBenchmarkOrigin;
+
+use crate::{AccountIdOf, BalanceOf, Call, Config, DidIdentifierOf, Pallet, ScheduledCalls};
+
+const CALLER_SEED: u32 = 0;
+const DID_SEED: u32 = 1;
+
+/// The length of a remark whose call just fits inline in the agenda.
+const MAX_INLINE_REMARK_LENGTH: usize = 124;
+
+fn make_free_for_deposits<T: Config>(account: &AccountIdOf<T>)
+where
+	<T as Config>::Currency: Mutate<T::AccountId>,
+{
+	let balance = <T::Currency as Inspect<AccountIdOf<T>>>::minimum_balance()
+		.saturating_add(T::Deposit::get().saturating_mul(T::MaxScheduledCallsPerDid::get().into()));
+	T::Currency::set_balance(account, balance);
+}
+
+fn call_id(index: u32) -> TaskName {
+	let mut id = [0u8; 32];
+	id[..4].copy_from_slice(&index.to_le_bytes());
+	id
+}
+
+fn largest_call<T: Config>() -> Box<<T as Config>::RuntimeCall>
+where
+	<T as Config>::RuntimeCall: From<frame_system::Call<T>>,
+{
+	Box::new(
+		frame_system::Call::<T>::remark {
+			remark: vec![0u8; MAX_INLINE_REMARK_LENGTH],
+		}
+		.into(),
+	)
+}
+
+/// Schedule `count` calls, with IDs starting from `1`.
+fn schedule_calls<T: Config>(origin: T::RuntimeOrigin, count: u32)
+where
+	<T as Config>::RuntimeCall: From<frame_system::Call<T>>,
+{
+	let when = frame_system::Pallet::<T>::block_number().saturating_add(10u32.into());
+	for index in 1..=count {
+		Pallet::<T>::schedule(origin.clone(), call_id(index), when, largest_call::<T>())
+			.expect("Should schedule the call.");
+	}
+}
+
+benchmarks! {
+	where_clause {
+		where
+		T::EnsureOrigin: GenerateBenchmarkOrigin<T::RuntimeOrigin, T::AccountId, T::DidIdentifier>,
+		<T as Config>::Currency: Mutate<T::AccountId>,
+		<T as Config>::RuntimeCall: From<frame_system::Call<T>>,
+	}
+
+	schedule {
+		let caller: AccountIdOf<T> = account("caller", 0, CALLER_SEED);
+		let did: DidIdentifierOf<T> = account("did", 0, DID_SEED);
+		make_free_for_deposits::<T>(&caller);
+		let origin = T::EnsureOrigin::generate_origin(caller.clone(), did.clone());
+
+		// Worst case: the DID has all but one of its calls scheduled, all in the
+		// same agenda.
+		schedule_calls::<T>(origin.clone(), T::MaxScheduledCallsPerDid::get().saturating_sub(1));
+		let when = frame_system::Pallet::<T>::block_number().saturating_add(10u32.into());
+	}: _<T::RuntimeOrigin>(origin, call_id(0), when, largest_call::<T>())
+	verify {
+		let deposit = ScheduledCalls::<T>::get(&did, call_id(0)).expect("Call should be scheduled.");
+		assert_eq!(deposit.owner, caller);
+	}
+
+	remove {
+		let caller: AccountIdOf<T> = account("caller", 0, CALLER_SEED);
+		let did: DidIdentifierOf<T> = account("did", 0, DID_SEED);
+		make_free_for_deposits::<T>(&caller);
+		let origin = T::EnsureOrigin::generate_origin(caller, did.clone());
+
+		schedule_calls::<T>(origin.clone(), T::MaxScheduledCallsPerDid::get());
+	}: _<T::RuntimeOrigin>(origin, call_id(1))
+	verify {
+		assert!(ScheduledCalls::<T>::get(&did, call_id(1)).is_none());
+	}
+
+	reclaim_deposit {
+		let caller: AccountIdOf<T> = account("caller", 0, CALLER_SEED);
+		let did: DidIdentifierOf<T> = account("did", 0, DID_SEED);
+		make_free_for_deposits::<T>(&caller);
+		let origin = T::EnsureOrigin::generate_origin(caller.clone(), did.clone());
+
+		schedule_calls::<T>(origin, T::MaxScheduledCallsPerDid::get());
+	}: _(RawOrigin::Signed(caller), did.clone(), call_id(1))
+	verify {
+		assert!(ScheduledCalls::<T>::get(&did, call_id(1)).is_none());
+	}
+
+	impl_benchmark_test_suite!(
+		Pallet,
+		crate::mock::ExtBuilder::default().build(),
+		crate::mock::Test
+	)
+}
@@ -0,0 +1,318 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+//! # DID scheduler pallet
+//!
+//! Lets DIDs schedule calls that are dispatched with their DID origin at a
+//! later block, via the scheduler of the runtime.
+//!
+//! Since the agenda of the scheduler is shared with governance, scheduling is
+//! bounded: each scheduled call holds a deposit from the account submitting
+//! it, each DID can only have a limited number of scheduled calls, calls are
+//! scheduled with the lowest priority and must be small enough to be stored
+//! inline in the agenda.
+//!
+//! The deposit is released when the scheduled call is removed, either before
+//! it is dispatched, which cancels it, or after it has been dispatched.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod default_weights;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub use crate::{default_weights::WeightInfo, pallet::*};
+
+#[frame_support::pallet]
+pub mod pallet {
+	use frame_support::{
+		dispatch::GetDispatchInfo,
+		pallet_prelude::*,
+		traits::{
+			fungible::{Inspect, MutateHold},
+			schedule::{
+				v3::{Named as ScheduleNamed, TaskName},
+				DispatchTime, LOWEST_PRIORITY,
+			},
+			Bounded, BoundedInline, StorageVersion,
+		},
+	};
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::traits::{BlakeTwo256, Dispatchable, Hash};
+
+	use kilt_support::{
+		traits::{CallSources, StorageDepositCollector},
+		Deposit,
+	};
+
+	use super::WeightInfo;
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+
+	/// Prefix of the scheduler task names, so that the names of the calls
+	/// scheduled by DIDs never clash with other named tasks.
+	const TASK_NAME_PREFIX: &[u8] = b"DidScheduler";
+
+	pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
+	pub type BalanceOf<T> = <<T as Config>::Currency as Inspect<AccountIdOf<T>>>::Balance;
+	pub type DidIdentifierOf<T> = <T as Config>::DidIdentifier;
+	pub type ScheduledCallOf<T> = Deposit<AccountIdOf<T>, BalanceOf<T>>;
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	/// Map of (DID, call ID) -> deposit of the scheduled call.
+	#[pallet::storage]
+	#[pallet::getter(fn scheduled_calls)]
+	pub type ScheduledCalls<T> =
+		StorageDoubleMap<_, Blake2_128Concat, DidIdentifierOf<T>, Blake2_128Concat, TaskName, ScheduledCallOf<T>>;
+
+	/// Map of DID -> number of scheduled calls.
+	#[pallet::storage]
+	#[pallet::getter(fn scheduled_calls_count)]
+	pub type ScheduledCallsCount<T> = StorageMap<_, Blake2_128Concat, DidIdentifierOf<T>, u32, ValueQuery>;
+
+	#[pallet::composite_enum]
+	pub enum HoldReason {
+		Deposit,
+	}
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// The overarching call type, of which calls can be scheduled.
+		type RuntimeCall: Parameter
+			+ Dispatchable<RuntimeOrigin = <Self as frame_system::Config>::RuntimeOrigin>
+			+ GetDispatchInfo;
+		/// The caller origin the scheduled calls are dispatched with.
+		type PalletsOrigin: From<Self::OriginSuccess>;
+		/// Aggregated hold reason.
+		type RuntimeHoldReason: From<HoldReason>;
+		/// The currency type to hold and release deposits.
+		type Currency: MutateHold<AccountIdOf<Self>, Reason = Self::RuntimeHoldReason>;
+		/// The origin allowed to schedule calls.
+		type EnsureOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin, Success = Self::OriginSuccess>;
+		/// The type of origin after a successful origin check.
+		type OriginSuccess: CallSources<AccountIdOf<Self>, DidIdentifierOf<Self>>;
+		/// The type of a DID identifier.
+		type DidIdentifier: Parameter + MaxEncodedLen;
+		/// The scheduler used to dispatch the scheduled calls.
+		type Scheduler: ScheduleNamed<BlockNumberFor<Self>, <Self as Config>::RuntimeCall, Self::PalletsOrigin>;
+		/// The maximum number of calls each DID can have scheduled at the same
+		/// time.
+		#[pallet::constant]
+		type MaxScheduledCallsPerDid: Get<u32>;
+		/// The amount of KILT to deposit for each scheduled call.
+		#[pallet::constant]
+		type Deposit: Get<BalanceOf<Self>>;
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A DID has scheduled a call.
+		CallScheduled {
+			did: DidIdentifierOf<T>,
+			id: TaskName,
+			when: BlockNumberFor<T>,
+		},
+		/// A call scheduled by a DID has been removed and its deposit
+		/// released.
+		ScheduledCallRemoved { did: DidIdentifierOf<T>, id: TaskName },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The DID already has a call scheduled with the same ID.
+		AlreadyScheduled,
+		/// The DID has no call scheduled with the given ID.
+		NotFound,
+		/// The DID has reached the maximum number of scheduled calls.
+		TooManyScheduledCalls,
+		/// The call is too large to be stored inline in the agenda.
+		CallTooLarge,
+		/// The tx submitter does not have enough funds to pay for the deposit.
+		InsufficientFunds,
+		/// The actor cannot perform the specified operation.
+		NotAuthorized,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Schedule a call to be dispatched with the DID origin of the caller
+		/// at the given block.
+		///
+		/// The deposit is held from the sender of the call until the scheduled
+		/// call is removed.
+		///
+		/// The dispatch origin must be a DID origin.
+		///
+		/// Emits `CallScheduled`.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::schedule())]
+		pub fn schedule(
+			origin: OriginFor<T>,
+			id: TaskName,
+			when: BlockNumberFor<T>,
+			call: Box<<T as Config>::RuntimeCall>,
+		) -> DispatchResult {
+			let origin = T::EnsureOrigin::ensure_origin(origin)?;
+			let payer = origin.sender();
+			let did = origin.subject();
+
+			ensure!(
+				!ScheduledCalls::<T>::contains_key(&did, id),
+				Error::<T>::AlreadyScheduled
+			);
+			let count = ScheduledCallsCount::<T>::get(&did);
+			ensure!(
+				count < T::MaxScheduledCallsPerDid::get(),
+				Error::<T>::TooManyScheduledCalls
+			);
+			let call = BoundedInline::try_from(call.encode()).map_err(|_| Error::<T>::CallTooLarge)?;
+
+			let deposit = ScheduledCallDepositCollector::<T>::create_deposit(payer, T::Deposit::get())
+				.map_err(|_| Error::<T>::InsufficientFunds)?;
+
+			T::Scheduler::schedule_named(
+				Self::task_name(&did, &id),
+				DispatchTime::At(when),
+				None,
+				LOWEST_PRIORITY,
+				origin.into(),
+				Bounded::Inline(call),
+			)?;
+			ScheduledCalls::<T>::insert(&did, id, deposit);
+			ScheduledCallsCount::<T>::insert(&did, count.saturating_add(1));
+
+			Self::deposit_event(Event::<T>::CallScheduled { did, id, when });
+
+			Ok(())
+		}
+
+		/// Remove a scheduled call, cancelling it if it has not been
+		/// dispatched yet, and release its deposit.
+		///
+		/// The dispatch origin must be the DID that scheduled the call.
+		///
+		/// Emits `ScheduledCallRemoved`.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove())]
+		pub fn remove(origin: OriginFor<T>, id: TaskName) -> DispatchResult {
+			let did = T::EnsureOrigin::ensure_origin(origin)?.subject();
+			let deposit = ScheduledCalls::<T>::get(&did, id).ok_or(Error::<T>::NotFound)?;
+
+			Self::do_remove(did, id, deposit)
+		}
+
+		/// Remove a scheduled call, cancelling it if it has not been
+		/// dispatched yet, and release its deposit, e.g., after the DID that
+		/// scheduled it has been deleted.
+		///
+		/// The dispatch origin must be the account that paid for the
+		/// deposit of the scheduled call.
+		///
+		/// Emits `ScheduledCallRemoved`.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::reclaim_deposit())]
+		pub fn reclaim_deposit(origin: OriginFor<T>, did: DidIdentifierOf<T>, id: TaskName) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
+			let deposit = ScheduledCalls::<T>::get(&did, id).ok_or(Error::<T>::NotFound)?;
+			ensure!(deposit.owner == caller, Error::<T>::NotAuthorized);
+
+			Self::do_remove(did, id, deposit)
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// The name under which the call with the given ID scheduled by the
+		/// given DID is stored in the scheduler.
+		pub fn task_name(did: &DidIdentifierOf<T>, id: &TaskName) -> TaskName {
+			BlakeTwo256::hash_of(&(TASK_NAME_PREFIX, did, id)).0
+		}
+
+		/// Cancel the scheduled call if it has not been dispatched yet, remove
+		/// it and release its deposit.
+		fn do_remove(did: DidIdentifierOf<T>, id: TaskName, deposit: ScheduledCallOf<T>) -> DispatchResult {
+			let task_name = Self::task_name(&did, &id);
+			if T::Scheduler::next_dispatch_time(task_name).is_ok() {
+				T::Scheduler::cancel_named(task_name)?;
+			}
+
+			ScheduledCalls::<T>::remove(&did, id);
+			ScheduledCallsCount::<T>::mutate_exists(&did, |count| {
+				*count = count.map(|count| count.saturating_sub(1)).filter(|count| *count > 0);
+			});
+			ScheduledCallDepositCollector::<T>::free_deposit(deposit)?;
+
+			Self::deposit_event(Event::<T>::ScheduledCallRemoved { did, id });
+
+			Ok(())
+		}
+	}
+
+	pub(crate) struct ScheduledCallDepositCollector<T: Config>(PhantomData<T>);
+	impl<T: Config> StorageDepositCollector<AccountIdOf<T>, (DidIdentifierOf<T>, TaskName), T::RuntimeHoldReason>
+		for ScheduledCallDepositCollector<T>
+	{
+		type Currency = T::Currency;
+		type Reason = HoldReason;
+
+		fn get_hashed_key(key: &(DidIdentifierOf<T>, TaskName)) -> Result<sp_std::vec::Vec<u8>, DispatchError> {
+			Ok(ScheduledCalls::<T>::hashed_key_for(&key.0, key.1))
+		}
+
+		fn reason() -> Self::Reason {
+			HoldReason::Deposit
+		}
+
+		fn deposit(
+			key: &(DidIdentifierOf<T>, TaskName),
+		) -> Result<Deposit<AccountIdOf<T>, BalanceOf<T>>, DispatchError> {
+			let deposit = ScheduledCalls::<T>::get(&key.0, key.1).ok_or(Error::<T>::NotFound)?;
+
+			Ok(deposit)
+		}
+
+		fn deposit_amount(_key: &(DidIdentifierOf<T>, TaskName)) -> BalanceOf<T> {
+			T::Deposit::get()
+		}
+
+		fn store_deposit(
+			key: &(DidIdentifierOf<T>, TaskName),
+			deposit: Deposit<AccountIdOf<T>, BalanceOf<T>>,
+		) -> Result<(), DispatchError> {
+			ScheduledCalls::<T>::try_mutate(&key.0, key.1, |stored| {
+				let stored = stored.as_mut().ok_or(Error::<T>::NotFound)?;
+				*stored = deposit;
+				Ok(())
+			})
+		}
+	}
+}
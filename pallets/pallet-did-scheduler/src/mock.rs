@@ -0,0 +1,246 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use frame_support::{
+	parameter_types,
+	traits::{
+		schedule::{
+			v3::{Named, TaskName},
+			DispatchTime, Period, Priority,
+		},
+		Bounded,
+	},
+};
+use kilt_support::mock::{mock_origin, SubjectId};
+use parity_scale_codec::Decode;
+use sp_runtime::{
+	traits::{BlakeTwo256, IdentifyAccount, IdentityLookup, Verify},
+	BuildStorage, DispatchError, MultiSignature,
+};
+use sp_std::{cell::RefCell, collections::btree_map::BTreeMap};
+
+use crate as pallet_did_scheduler;
+
+type BlockNumber = u64;
+pub(crate) type Balance = u128;
+
+type Hash = sp_core::H256;
+type Signature = MultiSignature;
+type AccountPublic = <Signature as Verify>::Signer;
+pub(crate) type AccountId = <AccountPublic as IdentifyAccount>::AccountId;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		Balances: pallet_balances,
+		DidScheduler: pallet_did_scheduler,
+		MockOrigin: mock_origin,
+	}
+);
+
+parameter_types! {
+	pub const SS58Prefix: u8 = 38;
+	pub const BlockHashCount: BlockNumber = 2400;
+}
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type Block = Block;
+	type Nonce = u64;
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Hash = Hash;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = BlockHashCount;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = SS58Prefix;
+	type OnSetCode = ();
+	type MaxConsumers = frame_support::traits::ConstU32<16>;
+}
+
+parameter_types! {
+	pub const ExistentialDeposit: Balance = 10;
+	pub const MaxLocks: u32 = 50;
+	pub const MaxReserves: u32 = 50;
+	pub const MaxHolds: u32 = 50;
+	pub const MaxFreezes: u32 = 50;
+}
+
+impl pallet_balances::Config for Test {
+	type FreezeIdentifier = RuntimeFreezeReason;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type MaxFreezes = MaxFreezes;
+	type MaxHolds = MaxHolds;
+	type Balance = Balance;
+	type DustRemoval = ();
+	type RuntimeEvent = RuntimeEvent;
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type MaxLocks = MaxLocks;
+	type MaxReserves = MaxReserves;
+	type ReserveIdentifier = [u8; 8];
+	type WeightInfo = ();
+}
+
+/// A call stored by the [`TestScheduler`], together with the block and the
+/// origin it is scheduled with.
+#[derive(Clone)]
+pub(crate) struct TestTask {
+	pub(crate) when: BlockNumber,
+	pub(crate) priority: Priority,
+	pub(crate) origin: OriginCaller,
+	pub(crate) call: RuntimeCall,
+}
+
+thread_local! {
+	static AGENDA: RefCell<BTreeMap<TaskName, TestTask>> = RefCell::new(BTreeMap::new());
+}
+
+pub(crate) fn scheduled_task(name: TaskName) -> Option<TestTask> {
+	AGENDA.with(|agenda| agenda.borrow().get(&name).cloned())
+}
+
+/// Remove the task from the agenda, as the scheduler does when dispatching
+/// it.
+pub(crate) fn dispatch_task(name: TaskName) -> Option<TestTask> {
+	AGENDA.with(|agenda| agenda.borrow_mut().remove(&name))
+}
+
+/// A scheduler that keeps named tasks in memory, only accepting inline calls.
+pub struct TestScheduler;
+
+impl Named<BlockNumber, RuntimeCall, OriginCaller> for TestScheduler {
+	type Address = ();
+
+	fn schedule_named(
+		id: TaskName,
+		when: DispatchTime<BlockNumber>,
+		_maybe_periodic: Option<Period<BlockNumber>>,
+		priority: Priority,
+		origin: OriginCaller,
+		call: Bounded<RuntimeCall>,
+	) -> Result<(), DispatchError> {
+		let (DispatchTime::At(when), Bounded::Inline(call)) = (when, call) else {
+			return Err(DispatchError::Other("Unsupported task"));
+		};
+		let call = RuntimeCall::decode(&mut &call[..]).map_err(|_| DispatchError::Other("Invalid call"))?;
+		AGENDA.with(|agenda| {
+			let mut agenda = agenda.borrow_mut();
+			if agenda.contains_key(&id) {
+				return Err(DispatchError::Other("Task already scheduled"));
+			}
+			agenda.insert(
+				id,
+				TestTask {
+					when,
+					priority,
+					origin,
+					call,
+				},
+			);
+			Ok(())
+		})
+	}
+
+	fn cancel_named(id: TaskName) -> Result<(), DispatchError> {
+		dispatch_task(id).map(|_| ()).ok_or(DispatchError::Unavailable)
+	}
+
+	fn reschedule_named(_id: TaskName, _when: DispatchTime<BlockNumber>) -> Result<(), DispatchError> {
+		Err(DispatchError::Unavailable)
+	}
+
+	fn next_dispatch_time(id: TaskName) -> Result<BlockNumber, ()> {
+		scheduled_task(id).map(|task| task.when).ok_or(())
+	}
+}
+
+parameter_types! {
+	pub const MaxScheduledCallsPerDid: u32 = 2;
+	pub const ScheduledCallDeposit: Balance = 100;
+}
+
+impl pallet_did_scheduler::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type PalletsOrigin = OriginCaller;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type Currency = Balances;
+	type EnsureOrigin = mock_origin::EnsureDoubleOrigin<AccountId, SubjectId>;
+	type OriginSuccess = mock_origin::DoubleOrigin<AccountId, SubjectId>;
+	type DidIdentifier = SubjectId;
+	type Scheduler = TestScheduler;
+	type MaxScheduledCallsPerDid = MaxScheduledCallsPerDid;
+	type Deposit = ScheduledCallDeposit;
+	type WeightInfo = ();
+}
+
+impl mock_origin::Config for Test {
+	type RuntimeOrigin = RuntimeOrigin;
+	type AccountId = AccountId;
+	type SubjectId = SubjectId;
+}
+
+pub(crate) const ACCOUNT_00: AccountId = AccountId::new([1u8; 32]);
+pub(crate) const ACCOUNT_01: AccountId = AccountId::new([2u8; 32]);
+pub(crate) const DID_00: SubjectId = SubjectId(AccountId::new([10u8; 32]));
+
+pub(crate) fn did_origin(did: SubjectId) -> RuntimeOrigin {
+	mock_origin::DoubleOrigin(ACCOUNT_00, did).into()
+}
+
+#[derive(Clone, Default)]
+pub struct ExtBuilder {
+	balances: Vec<(AccountId, Balance)>,
+}
+
+impl ExtBuilder {
+	#[must_use]
+	pub fn with_balances(mut self, balances: Vec<(AccountId, Balance)>) -> Self {
+		self.balances = balances;
+		self
+	}
+
+	pub fn build(self) -> sp_io::TestExternalities {
+		let mut storage = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+		pallet_balances::GenesisConfig::<Test> {
+			balances: self.balances,
+		}
+		.assimilate_storage(&mut storage)
+		.expect("assimilate should not fail");
+		let mut ext = sp_io::TestExternalities::new(storage);
+
+		AGENDA.with(|agenda| agenda.borrow_mut().clear());
+		ext.execute_with(|| System::set_block_number(1));
+		ext
+	}
+}
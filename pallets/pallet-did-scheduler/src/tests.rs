@@ -0,0 +1,210 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{fungible::InspectHold, schedule::LOWEST_PRIORITY},
+};
+use kilt_support::{mock::mock_origin, Deposit};
+use sp_runtime::traits::{BadOrigin, Zero};
+
+use crate::{mock::*, Error, HoldReason, Pallet, ScheduledCalls, ScheduledCallsCount};
+
+fn remark(length: usize) -> Box<RuntimeCall> {
+	Box::new(RuntimeCall::System(frame_system::Call::remark {
+		remark: vec![0u8; length],
+	}))
+}
+
+fn held(account: &AccountId) -> Balance {
+	Balances::balance_on_hold(&HoldReason::Deposit.into(), account)
+}
+
+#[test]
+fn test_schedule() {
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, 1_000)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(Pallet::<Test>::schedule(did_origin(DID_00), [0u8; 32], 10, remark(10)));
+
+			assert_eq!(
+				ScheduledCalls::<Test>::get(DID_00, [0u8; 32]),
+				Some(Deposit {
+					owner: ACCOUNT_00,
+					amount: ScheduledCallDeposit::get(),
+				})
+			);
+			assert_eq!(ScheduledCallsCount::<Test>::get(DID_00), 1);
+			assert_eq!(held(&ACCOUNT_00), ScheduledCallDeposit::get());
+
+			let task =
+				scheduled_task(Pallet::<Test>::task_name(&DID_00, &[0u8; 32])).expect("Call should be in the agenda.");
+			assert_eq!(task.when, 10);
+			assert_eq!(task.priority, LOWEST_PRIORITY);
+			assert_eq!(task.call, *remark(10));
+			assert_eq!(
+				task.origin,
+				OriginCaller::MockOrigin(mock_origin::DoubleOrigin(ACCOUNT_00, DID_00))
+			);
+		});
+}
+
+#[test]
+fn test_schedule_already_scheduled() {
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, 1_000)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(Pallet::<Test>::schedule(did_origin(DID_00), [0u8; 32], 10, remark(10)));
+
+			assert_noop!(
+				Pallet::<Test>::schedule(did_origin(DID_00), [0u8; 32], 20, remark(10)),
+				Error::<Test>::AlreadyScheduled
+			);
+		});
+}
+
+#[test]
+fn test_schedule_too_many_calls() {
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, 1_000)])
+		.build()
+		.execute_with(|| {
+			for id in 0..MaxScheduledCallsPerDid::get() {
+				assert_ok!(Pallet::<Test>::schedule(
+					did_origin(DID_00),
+					[id as u8; 32],
+					10,
+					remark(10)
+				));
+			}
+
+			assert_noop!(
+				Pallet::<Test>::schedule(did_origin(DID_00), [u8::MAX; 32], 10, remark(10)),
+				Error::<Test>::TooManyScheduledCalls
+			);
+		});
+}
+
+#[test]
+fn test_schedule_call_too_large() {
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, 1_000)])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				Pallet::<Test>::schedule(did_origin(DID_00), [0u8; 32], 10, remark(200)),
+				Error::<Test>::CallTooLarge
+			);
+		});
+}
+
+#[test]
+fn test_schedule_insufficient_funds() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Pallet::<Test>::schedule(did_origin(DID_00), [0u8; 32], 10, remark(10)),
+			Error::<Test>::InsufficientFunds
+		);
+		assert!(scheduled_task(Pallet::<Test>::task_name(&DID_00, &[0u8; 32])).is_none());
+	});
+}
+
+#[test]
+fn test_schedule_unauthorized() {
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, 1_000)])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				Pallet::<Test>::schedule(RuntimeOrigin::signed(ACCOUNT_00), [0u8; 32], 10, remark(10)),
+				BadOrigin
+			);
+		});
+}
+
+#[test]
+fn test_remove() {
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, 1_000)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(Pallet::<Test>::schedule(did_origin(DID_00), [0u8; 32], 10, remark(10)));
+
+			assert_ok!(Pallet::<Test>::remove(did_origin(DID_00), [0u8; 32]));
+			assert!(scheduled_task(Pallet::<Test>::task_name(&DID_00, &[0u8; 32])).is_none());
+			assert!(ScheduledCalls::<Test>::get(DID_00, [0u8; 32]).is_none());
+			assert!(!ScheduledCallsCount::<Test>::contains_key(DID_00));
+			assert!(held(&ACCOUNT_00).is_zero());
+
+			assert_noop!(
+				Pallet::<Test>::remove(did_origin(DID_00), [0u8; 32]),
+				Error::<Test>::NotFound
+			);
+		});
+}
+
+#[test]
+fn test_remove_after_dispatch() {
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, 1_000)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(Pallet::<Test>::schedule(did_origin(DID_00), [0u8; 32], 10, remark(10)));
+			assert!(dispatch_task(Pallet::<Test>::task_name(&DID_00, &[0u8; 32])).is_some());
+
+			assert_ok!(Pallet::<Test>::remove(did_origin(DID_00), [0u8; 32]));
+			assert!(ScheduledCalls::<Test>::get(DID_00, [0u8; 32]).is_none());
+			assert!(held(&ACCOUNT_00).is_zero());
+		});
+}
+
+#[test]
+fn test_reclaim_deposit() {
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, 1_000)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(Pallet::<Test>::schedule(did_origin(DID_00), [0u8; 32], 10, remark(10)));
+
+			assert_ok!(Pallet::<Test>::reclaim_deposit(
+				RuntimeOrigin::signed(ACCOUNT_00),
+				DID_00,
+				[0u8; 32]
+			));
+			assert!(scheduled_task(Pallet::<Test>::task_name(&DID_00, &[0u8; 32])).is_none());
+			assert!(ScheduledCalls::<Test>::get(DID_00, [0u8; 32]).is_none());
+			assert!(held(&ACCOUNT_00).is_zero());
+		});
+}
+
+#[test]
+fn test_reclaim_deposit_unauthorized() {
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, 1_000)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(Pallet::<Test>::schedule(did_origin(DID_00), [0u8; 32], 10, remark(10)));
+
+			assert_noop!(
+				Pallet::<Test>::reclaim_deposit(RuntimeOrigin::signed(ACCOUNT_01), DID_00, [0u8; 32]),
+				Error::<Test>::NotAuthorized
+			);
+		});
+}
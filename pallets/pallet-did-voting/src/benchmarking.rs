@@ -0,0 +1,130 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use frame_benchmarking::{account, benchmarks};
+use frame_support::traits::{
+	fungible::{Inspect, Mutate},
+	Get, PollStatus, Polling,
+};
+use frame_system::RawOrigin;
+use sp_runtime::traits::Saturating;
+
+use kilt_support::traits::GenerateBenchmarkOrigin;
+
+use crate::{
+	vote::{Conviction, DidVote},
+	AccountIdOf, BenchmarkHelper, Call, Config, Pallet, TallyOf, VoterOf, VotingFor,
+};
+
+const CALLER_SEED: u32 = 0;
+const VOTER_SEED: u32 = 1;
+
+const WORST_CASE_VOTE: DidVote = DidVote {
+	aye: true,
+	conviction: Conviction::Locked6x,
+};
+
+fn make_free_for_deposit<T: Config>(account: &AccountIdOf<T>)
+where
+	<T as Config>::Currency: Mutate<T::AccountId>,
+{
+	// Enough for two votes with the highest conviction.
+	let balance = <T::Currency as Inspect<AccountIdOf<T>>>::minimum_balance()
+		.saturating_add(Pallet::<T>::deposit_amount(&WORST_CASE_VOTE))
+		.saturating_add(Pallet::<T>::deposit_amount(&WORST_CASE_VOTE));
+	T::Currency::set_balance(account, balance);
+}
+
+fn create_poll<T: Config>() -> crate::PollIndexOf<T> {
+	let class = <T::Polls as Polling<TallyOf<T>>>::classes()
+		.into_iter()
+		.next()
+		.expect("Should have at least one poll class.");
+	T::Polls::create_ongoing(class).expect("Should create an ongoing poll.")
+}
+
+benchmarks! {
+	where_clause {
+		where
+		T::EnsureOrigin: GenerateBenchmarkOrigin<T::RuntimeOrigin, T::AccountId, T::Voter>,
+		<T as Config>::Currency: Mutate<T::AccountId>,
+	}
+
+	vote {
+		let caller: AccountIdOf<T> = account("caller", 0, CALLER_SEED);
+		let voter: VoterOf<T> = account("voter", 0, VOTER_SEED);
+		make_free_for_deposit::<T>(&caller);
+		let origin = T::EnsureOrigin::generate_origin(caller.clone(), voter.clone());
+		T::BenchmarkHelper::make_eligible(&voter);
+
+		let poll_index = create_poll::<T>();
+		// Worst case: a previous vote has to be removed from the tally and its
+		// deposit released first.
+		Pallet::<T>::vote(origin.clone(), poll_index, DidVote { aye: false, conviction: Conviction::Locked6x }).expect("Should vote on the ongoing poll.");
+	}: _<T::RuntimeOrigin>(origin, poll_index, WORST_CASE_VOTE)
+	verify {
+		let record = VotingFor::<T>::get(&voter, poll_index).expect("Vote should be stored.");
+		assert_eq!(record.vote, WORST_CASE_VOTE);
+		assert_eq!(record.deposit.owner, caller);
+	}
+
+	remove_vote {
+		let caller: AccountIdOf<T> = account("caller", 0, CALLER_SEED);
+		let voter: VoterOf<T> = account("voter", 0, VOTER_SEED);
+		make_free_for_deposit::<T>(&caller);
+		let origin = T::EnsureOrigin::generate_origin(caller, voter.clone());
+		T::BenchmarkHelper::make_eligible(&voter);
+
+		let poll_index = create_poll::<T>();
+		Pallet::<T>::vote(origin.clone(), poll_index, WORST_CASE_VOTE).expect("Should vote on the ongoing poll.");
+	}: _<T::RuntimeOrigin>(origin, poll_index)
+	verify {
+		assert!(VotingFor::<T>::get(&voter, poll_index).is_none());
+	}
+
+	reclaim_deposit {
+		let caller: AccountIdOf<T> = account("caller", 0, CALLER_SEED);
+		let voter: VoterOf<T> = account("voter", 0, VOTER_SEED);
+		make_free_for_deposit::<T>(&caller);
+		let origin = T::EnsureOrigin::generate_origin(caller.clone(), voter.clone());
+		T::BenchmarkHelper::make_eligible(&voter);
+
+		let poll_index = create_poll::<T>();
+		Pallet::<T>::vote(origin, poll_index, WORST_CASE_VOTE).expect("Should vote on the ongoing poll.");
+		// Worst case: the poll completed in favour of the vote, whose lock has to
+		// be checked before it is removed.
+		T::Polls::end_ongoing(poll_index, true).expect("Should end the ongoing poll.");
+		let unlock_at = T::Polls::access_poll(poll_index, |status| match status {
+			PollStatus::Completed(end, _) => end,
+			_ => panic!("Poll should be completed."),
+		})
+		.saturating_add(T::VoteLockingPeriod::get().saturating_mul(WORST_CASE_VOTE.conviction.lock_periods().into()));
+		frame_system::Pallet::<T>::set_block_number(unlock_at);
+	}: _(RawOrigin::Signed(caller), voter.clone(), poll_index)
+	verify {
+		assert!(VotingFor::<T>::get(&voter, poll_index).is_none());
+	}
+
+	impl_benchmark_test_suite!(
+		Pallet,
+		crate::mock::ExtBuilder::default().build(),
+		crate::mock::Test
+	)
+}
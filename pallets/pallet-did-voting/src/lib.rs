@@ -0,0 +1,363 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+//! # DID voting pallet
+//!
+//! Tallies votes cast by DIDs on polls, such as referenda, following a
+//! one-DID-one-vote model. Each DID can optionally vote with a conviction,
+//! which multiplies the weight of its vote and, in exchange, keeps the vote
+//! and the funds held for it locked for a number of periods if the poll ends
+//! in its favour.
+//!
+//! Which DIDs can vote is configurable via the `VoterEligibility` type, e.g.,
+//! to only give a voice to DIDs that have claimed a web3name. Eligibility is
+//! evaluated at the start of each poll, so that the same eligibility, e.g., a
+//! web3name passed from one DID to another, cannot be used to vote twice.
+//!
+//! Each vote holds a deposit from the account submitting it, which grows with
+//! the conviction of the vote and is released when the vote is removed.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod default_weights;
+
+pub mod vote;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub use crate::{default_weights::WeightInfo, pallet::*};
+
+/// Provides the block since which a voter is eligible to vote.
+pub trait VoterEligibility<Voter, BlockNumber> {
+	/// Returns the block since which the voter is eligible to vote, or `None`
+	/// if the voter is not eligible.
+	fn eligible_since(voter: &Voter) -> Option<BlockNumber>;
+}
+
+/// Provides the block at which a poll started.
+pub trait PollStart<Index, BlockNumber> {
+	/// Returns the block at which the poll started, or `None` if the poll is
+	/// not ongoing.
+	fn poll_start(index: &Index) -> Option<BlockNumber>;
+}
+
+/// Helper to make a voter eligible to vote when running benchmarks.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<Voter> {
+	fn make_eligible(voter: &Voter);
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl<Voter> BenchmarkHelper<Voter> for () {
+	fn make_eligible(_voter: &Voter) {}
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{
+			fungible::{Inspect, MutateHold},
+			PollStatus, Polling, StorageVersion,
+		},
+	};
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::traits::Saturating;
+
+	use kilt_support::{
+		traits::{CallSources, StorageDepositCollector},
+		Deposit,
+	};
+
+	use super::WeightInfo;
+	use crate::vote::{DidTally, DidVote, VoteRecord};
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+
+	pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
+	pub type BalanceOf<T> = <<T as Config>::Currency as Inspect<AccountIdOf<T>>>::Balance;
+	pub type VoterOf<T> = <T as Config>::Voter;
+	pub type TallyOf<T> = DidTally<<T as Config>::Electorate>;
+	pub type PollIndexOf<T> = <<T as Config>::Polls as Polling<TallyOf<T>>>::Index;
+	pub type VoteRecordOf<T> = VoteRecord<Deposit<AccountIdOf<T>, BalanceOf<T>>>;
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	/// Map of (voter, poll index) -> vote and its deposit.
+	#[pallet::storage]
+	#[pallet::getter(fn voting_for)]
+	pub type VotingFor<T> =
+		StorageDoubleMap<_, Blake2_128Concat, VoterOf<T>, Twox64Concat, PollIndexOf<T>, VoteRecordOf<T>>;
+
+	#[pallet::composite_enum]
+	pub enum HoldReason {
+		Deposit,
+	}
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// Aggregated hold reason.
+		type RuntimeHoldReason: From<HoldReason>;
+		/// The currency type to hold and release vote deposits.
+		type Currency: MutateHold<AccountIdOf<Self>, Reason = Self::RuntimeHoldReason>;
+		/// The polls DIDs can vote on.
+		type Polls: Polling<TallyOf<Self>, Votes = u32, Moment = BlockNumberFor<Self>>;
+		/// The start of the polls DIDs can vote on, used to evaluate the
+		/// eligibility of voters.
+		type PollStart: crate::PollStart<PollIndexOf<Self>, BlockNumberFor<Self>>;
+		/// The origin allowed to vote.
+		type EnsureOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin, Success = Self::OriginSuccess>;
+		/// The type of origin after a successful origin check.
+		type OriginSuccess: CallSources<AccountIdOf<Self>, VoterOf<Self>>;
+		/// The type of a voter, e.g., a DID.
+		type Voter: Parameter + MaxEncodedLen;
+		/// The voters that are allowed to cast a vote on a poll, i.e., that
+		/// have been eligible since the start of the poll.
+		type VoterEligibility: crate::VoterEligibility<VoterOf<Self>, BlockNumberFor<Self>>;
+		/// The number of voters that could take part in a poll, used to
+		/// compute its support.
+		type Electorate: Get<u32>;
+		/// The number of blocks a winning vote with a conviction of
+		/// `Locked1x` stays locked after the poll ended.
+		#[pallet::constant]
+		type VoteLockingPeriod: Get<BlockNumberFor<Self>>;
+		/// The amount of KILT to deposit for each vote, regardless of its
+		/// conviction.
+		#[pallet::constant]
+		type VoteDeposit: Get<BalanceOf<Self>>;
+		/// The amount of KILT additionally held for each vote per unit of
+		/// conviction multiplier, e.g., six times this amount for a vote with
+		/// a conviction of `Locked6x`.
+		#[pallet::constant]
+		type ConvictionDeposit: Get<BalanceOf<Self>>;
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+		/// Helper to set up eligible voters when running benchmarks.
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: crate::BenchmarkHelper<VoterOf<Self>>;
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A voter has voted on a poll, replacing any previous vote.
+		Voted {
+			voter: VoterOf<T>,
+			poll_index: PollIndexOf<T>,
+			vote: DidVote,
+		},
+		/// A vote has been removed from a poll.
+		VoteRemoved {
+			voter: VoterOf<T>,
+			poll_index: PollIndexOf<T>,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The voter is not allowed to vote on the poll.
+		NotEligible,
+		/// The poll is not ongoing.
+		NotOngoing,
+		/// The voter has not voted on the poll.
+		NotVoted,
+		/// The vote is on the winning side of a completed poll and its
+		/// conviction lock has not expired yet.
+		VoteLocked,
+		/// The tx submitter does not have enough funds to pay for the deposit.
+		InsufficientFunds,
+		/// The actor cannot perform the specified operation.
+		NotAuthorized,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Vote on an ongoing poll.
+		///
+		/// If the voter has already voted on the poll, the previous vote is
+		/// replaced and its deposit released. The deposit of the new vote is
+		/// held from the sender of the call.
+		///
+		/// The dispatch origin must be a voter that has been eligible since
+		/// the start of the poll.
+		///
+		/// Emits `Voted`.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::vote())]
+		pub fn vote(origin: OriginFor<T>, poll_index: PollIndexOf<T>, vote: DidVote) -> DispatchResult {
+			let origin = T::EnsureOrigin::ensure_origin(origin)?;
+			let payer = origin.sender();
+			let voter = origin.subject();
+
+			let poll_start =
+				<T::PollStart as crate::PollStart<_, _>>::poll_start(&poll_index).ok_or(Error::<T>::NotOngoing)?;
+			let eligible_since = <T::VoterEligibility as crate::VoterEligibility<_, _>>::eligible_since(&voter)
+				.ok_or(Error::<T>::NotEligible)?;
+			ensure!(eligible_since <= poll_start, Error::<T>::NotEligible);
+
+			T::Polls::try_access_poll(poll_index, |poll_status| {
+				let PollStatus::Ongoing(tally, _) = poll_status else {
+					return Err(Error::<T>::NotOngoing.into());
+				};
+				if let Some(previous_record) = VotingFor::<T>::take(&voter, poll_index) {
+					tally.remove(previous_record.vote);
+					VoteDepositCollector::<T>::free_deposit(previous_record.deposit)?;
+				}
+				let deposit = VoteDepositCollector::<T>::create_deposit(payer, Self::deposit_amount(&vote))
+					.map_err(|_| Error::<T>::InsufficientFunds)?;
+				tally.add(vote);
+				VotingFor::<T>::insert(&voter, poll_index, VoteRecordOf::<T> { vote, deposit });
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::<T>::Voted {
+				voter,
+				poll_index,
+				vote,
+			});
+
+			Ok(())
+		}
+
+		/// Remove a vote from a poll, releasing its deposit.
+		///
+		/// If the poll is still ongoing, the vote is removed from its tally.
+		/// If the poll has completed and the vote was on the winning side,
+		/// the vote can only be removed once its conviction lock expired.
+		///
+		/// The dispatch origin must be the voter who cast the vote.
+		///
+		/// Emits `VoteRemoved`.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_vote())]
+		pub fn remove_vote(origin: OriginFor<T>, poll_index: PollIndexOf<T>) -> DispatchResult {
+			let voter = T::EnsureOrigin::ensure_origin(origin)?.subject();
+			let record = VotingFor::<T>::get(&voter, poll_index).ok_or(Error::<T>::NotVoted)?;
+
+			Self::do_remove_vote(voter, poll_index, record)
+		}
+
+		/// Remove a vote from a poll, releasing its deposit, e.g., after the
+		/// DID that cast it has been deleted.
+		///
+		/// The vote can be removed under the same conditions as with
+		/// `remove_vote`.
+		///
+		/// The dispatch origin must be the account that paid for the vote's
+		/// deposit.
+		///
+		/// Emits `VoteRemoved`.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::reclaim_deposit())]
+		pub fn reclaim_deposit(origin: OriginFor<T>, voter: VoterOf<T>, poll_index: PollIndexOf<T>) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
+			let record = VotingFor::<T>::get(&voter, poll_index).ok_or(Error::<T>::NotVoted)?;
+			ensure!(record.deposit.owner == caller, Error::<T>::NotAuthorized);
+
+			Self::do_remove_vote(voter, poll_index, record)
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// The deposit held for the provided vote, which grows with its
+		/// conviction.
+		pub fn deposit_amount(vote: &DidVote) -> BalanceOf<T> {
+			T::ConvictionDeposit::get()
+				.saturating_mul(vote.conviction.multiplier().into())
+				.saturating_add(T::VoteDeposit::get())
+		}
+
+		/// Remove the provided vote from its poll and release its deposit, if
+		/// the vote is not locked.
+		fn do_remove_vote(voter: VoterOf<T>, poll_index: PollIndexOf<T>, record: VoteRecordOf<T>) -> DispatchResult {
+			T::Polls::try_access_poll(poll_index, |poll_status| match poll_status {
+				PollStatus::Ongoing(tally, _) => {
+					tally.remove(record.vote);
+					Ok(())
+				}
+				PollStatus::Completed(end, approved) if approved == record.vote.aye => {
+					let unlock_at = end.saturating_add(
+						T::VoteLockingPeriod::get().saturating_mul(record.vote.conviction.lock_periods().into()),
+					);
+					ensure!(
+						frame_system::Pallet::<T>::block_number() >= unlock_at,
+						Error::<T>::VoteLocked
+					);
+					Ok(())
+				}
+				_ => Ok(()),
+			})?;
+
+			VotingFor::<T>::remove(&voter, poll_index);
+			VoteDepositCollector::<T>::free_deposit(record.deposit)?;
+
+			Self::deposit_event(Event::<T>::VoteRemoved { voter, poll_index });
+
+			Ok(())
+		}
+	}
+
+	pub(crate) struct VoteDepositCollector<T: Config>(PhantomData<T>);
+	impl<T: Config> StorageDepositCollector<AccountIdOf<T>, (VoterOf<T>, PollIndexOf<T>), T::RuntimeHoldReason>
+		for VoteDepositCollector<T>
+	{
+		type Currency = T::Currency;
+		type Reason = HoldReason;
+
+		fn get_hashed_key(key: &(VoterOf<T>, PollIndexOf<T>)) -> Result<sp_std::vec::Vec<u8>, DispatchError> {
+			Ok(VotingFor::<T>::hashed_key_for(&key.0, key.1))
+		}
+
+		fn reason() -> Self::Reason {
+			HoldReason::Deposit
+		}
+
+		fn deposit(key: &(VoterOf<T>, PollIndexOf<T>)) -> Result<Deposit<AccountIdOf<T>, BalanceOf<T>>, DispatchError> {
+			let record = VotingFor::<T>::get(&key.0, key.1).ok_or(Error::<T>::NotVoted)?;
+
+			Ok(record.deposit)
+		}
+
+		fn deposit_amount(key: &(VoterOf<T>, PollIndexOf<T>)) -> BalanceOf<T> {
+			VotingFor::<T>::get(&key.0, key.1)
+				.map_or_else(T::VoteDeposit::get, |record| Pallet::<T>::deposit_amount(&record.vote))
+		}
+
+		fn store_deposit(
+			key: &(VoterOf<T>, PollIndexOf<T>),
+			deposit: Deposit<AccountIdOf<T>, BalanceOf<T>>,
+		) -> Result<(), DispatchError> {
+			VotingFor::<T>::try_mutate(&key.0, key.1, |record| {
+				let record = record.as_mut().ok_or(Error::<T>::NotVoted)?;
+				record.deposit = deposit;
+				Ok(())
+			})
+		}
+	}
+}
@@ -0,0 +1,319 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use frame_support::{
+	parameter_types,
+	traits::{PollStatus, Polling},
+};
+use kilt_support::mock::{mock_origin, SubjectId};
+use sp_runtime::{
+	traits::{BlakeTwo256, IdentifyAccount, IdentityLookup, Verify},
+	BuildStorage, DispatchError, MultiSignature,
+};
+use sp_std::{cell::RefCell, collections::btree_map::BTreeMap};
+
+use crate::{self as pallet_did_voting, TallyOf};
+
+type BlockNumber = u64;
+pub(crate) type Balance = u128;
+
+type Hash = sp_core::H256;
+type Signature = MultiSignature;
+type AccountPublic = <Signature as Verify>::Signer;
+pub(crate) type AccountId = <AccountPublic as IdentifyAccount>::AccountId;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		Balances: pallet_balances,
+		DidVoting: pallet_did_voting,
+		MockOrigin: mock_origin,
+	}
+);
+
+parameter_types! {
+	pub const SS58Prefix: u8 = 38;
+	pub const BlockHashCount: BlockNumber = 2400;
+}
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type Block = Block;
+	type Nonce = u64;
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Hash = Hash;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = BlockHashCount;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = SS58Prefix;
+	type OnSetCode = ();
+	type MaxConsumers = frame_support::traits::ConstU32<16>;
+}
+
+parameter_types! {
+	pub const ExistentialDeposit: Balance = 10;
+	pub const MaxLocks: u32 = 50;
+	pub const MaxReserves: u32 = 50;
+	pub const MaxHolds: u32 = 50;
+	pub const MaxFreezes: u32 = 50;
+}
+
+impl pallet_balances::Config for Test {
+	type FreezeIdentifier = RuntimeFreezeReason;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type MaxFreezes = MaxFreezes;
+	type MaxHolds = MaxHolds;
+	type Balance = Balance;
+	type DustRemoval = ();
+	type RuntimeEvent = RuntimeEvent;
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type MaxLocks = MaxLocks;
+	type MaxReserves = MaxReserves;
+	type ReserveIdentifier = [u8; 8];
+	type WeightInfo = ();
+}
+
+pub(crate) type TestPollStatus = PollStatus<TallyOf<Test>, BlockNumber, u8>;
+
+thread_local! {
+	static POLLS: RefCell<BTreeMap<u8, TestPollStatus>> = RefCell::new(BTreeMap::new());
+	static POLL_STARTS: RefCell<BTreeMap<u8, BlockNumber>> = RefCell::new(BTreeMap::new());
+	static ELIGIBLE_SINCE: RefCell<BTreeMap<AccountId, BlockNumber>> = RefCell::new(BTreeMap::new());
+}
+
+pub(crate) fn poll_status(index: u8) -> Option<TestPollStatus> {
+	POLLS.with(|polls| polls.borrow().get(&index).cloned())
+}
+
+pub(crate) fn set_poll_status(index: u8, status: TestPollStatus) {
+	POLLS.with(|polls| polls.borrow_mut().insert(index, status));
+}
+
+pub(crate) fn set_poll_start(index: u8, start: BlockNumber) {
+	POLL_STARTS.with(|starts| starts.borrow_mut().insert(index, start));
+}
+
+pub(crate) fn set_eligible_since(voter: SubjectId, since: BlockNumber) {
+	ELIGIBLE_SINCE.with(|eligible| eligible.borrow_mut().insert(voter.0, since));
+}
+
+/// Polls stored in memory, all belonging to class `0`.
+pub struct TestPolls;
+
+impl Polling<TallyOf<Test>> for TestPolls {
+	type Index = u8;
+	type Votes = u32;
+	type Class = u8;
+	type Moment = BlockNumber;
+
+	fn classes() -> Vec<u8> {
+		vec![0]
+	}
+
+	fn as_ongoing(index: u8) -> Option<(TallyOf<Test>, u8)> {
+		poll_status(index).and_then(|status| status.ensure_ongoing())
+	}
+
+	fn access_poll<R>(index: u8, f: impl FnOnce(PollStatus<&mut TallyOf<Test>, BlockNumber, u8>) -> R) -> R {
+		POLLS.with(|polls| {
+			let mut polls = polls.borrow_mut();
+			let mut status = polls.get(&index).cloned().unwrap_or(PollStatus::None);
+			let result = match status {
+				PollStatus::Ongoing(ref mut tally, class) => f(PollStatus::Ongoing(tally, class)),
+				PollStatus::Completed(end, approved) => f(PollStatus::Completed(end, approved)),
+				PollStatus::None => f(PollStatus::None),
+			};
+			polls.insert(index, status);
+			result
+		})
+	}
+
+	fn try_access_poll<R>(
+		index: u8,
+		f: impl FnOnce(PollStatus<&mut TallyOf<Test>, BlockNumber, u8>) -> Result<R, DispatchError>,
+	) -> Result<R, DispatchError> {
+		POLLS.with(|polls| {
+			let mut polls = polls.borrow_mut();
+			let mut status = polls.get(&index).cloned().unwrap_or(PollStatus::None);
+			let result = match status {
+				PollStatus::Ongoing(ref mut tally, class) => f(PollStatus::Ongoing(tally, class)),
+				PollStatus::Completed(end, approved) => f(PollStatus::Completed(end, approved)),
+				PollStatus::None => f(PollStatus::None),
+			}?;
+			polls.insert(index, status);
+			Ok(result)
+		})
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn create_ongoing(class: u8) -> Result<u8, ()> {
+		POLLS.with(|polls| {
+			let mut polls = polls.borrow_mut();
+			let index = polls.keys().next_back().map_or(0, |last| last.saturating_add(1));
+			polls.insert(
+				index,
+				PollStatus::Ongoing(
+					<TallyOf<Test> as frame_support::traits::VoteTally<u32, u8>>::new(class),
+					class,
+				),
+			);
+			set_poll_start(index, System::block_number());
+			Ok(index)
+		})
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn end_ongoing(index: u8, approved: bool) -> Result<(), ()> {
+		POLLS.with(|polls| {
+			let mut polls = polls.borrow_mut();
+			match polls.get(&index) {
+				Some(PollStatus::Ongoing(..)) => {
+					polls.insert(index, PollStatus::Completed(System::block_number(), approved));
+					Ok(())
+				}
+				_ => Err(()),
+			}
+		})
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn max_ongoing() -> (u8, u32) {
+		(0, 100)
+	}
+}
+
+/// Ongoing polls start at block `0`, unless set otherwise.
+pub struct TestPollStart;
+
+impl crate::PollStart<u8, BlockNumber> for TestPollStart {
+	fn poll_start(index: &u8) -> Option<BlockNumber> {
+		TestPolls::as_ongoing(*index)?;
+		Some(POLL_STARTS.with(|starts| starts.borrow().get(index).copied().unwrap_or_default()))
+	}
+}
+
+/// All DIDs but [`DID_02`] are allowed to vote, since block `0` unless set
+/// otherwise.
+pub struct TestVoterEligibility;
+
+impl crate::VoterEligibility<SubjectId, BlockNumber> for TestVoterEligibility {
+	fn eligible_since(voter: &SubjectId) -> Option<BlockNumber> {
+		if *voter == DID_02 {
+			return None;
+		}
+		Some(ELIGIBLE_SINCE.with(|eligible| eligible.borrow().get(&voter.0).copied().unwrap_or_default()))
+	}
+}
+
+parameter_types! {
+	pub const Electorate: u32 = 10;
+	pub const VoteLockingPeriod: BlockNumber = 10;
+	pub const VoteDeposit: Balance = 100;
+	pub const ConvictionDeposit: Balance = 10;
+}
+
+impl pallet_did_voting::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type Currency = Balances;
+	type Polls = TestPolls;
+	type PollStart = TestPollStart;
+	type EnsureOrigin = mock_origin::EnsureDoubleOrigin<AccountId, SubjectId>;
+	type OriginSuccess = mock_origin::DoubleOrigin<AccountId, SubjectId>;
+	type Voter = SubjectId;
+	type VoterEligibility = TestVoterEligibility;
+	type Electorate = Electorate;
+	type VoteLockingPeriod = VoteLockingPeriod;
+	type VoteDeposit = VoteDeposit;
+	type ConvictionDeposit = ConvictionDeposit;
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+}
+
+impl mock_origin::Config for Test {
+	type RuntimeOrigin = RuntimeOrigin;
+	type AccountId = AccountId;
+	type SubjectId = SubjectId;
+}
+
+pub(crate) const ACCOUNT_00: AccountId = AccountId::new([1u8; 32]);
+pub(crate) const ACCOUNT_01: AccountId = AccountId::new([2u8; 32]);
+pub(crate) const DID_00: SubjectId = SubjectId(AccountId::new([10u8; 32]));
+pub(crate) const DID_01: SubjectId = SubjectId(AccountId::new([11u8; 32]));
+pub(crate) const DID_02: SubjectId = SubjectId(AccountId::new([12u8; 32]));
+
+pub(crate) fn did_origin(did: SubjectId) -> RuntimeOrigin {
+	mock_origin::DoubleOrigin(ACCOUNT_00, did).into()
+}
+
+pub(crate) fn did_origin_paid_by(payer: AccountId, did: SubjectId) -> RuntimeOrigin {
+	mock_origin::DoubleOrigin(payer, did).into()
+}
+
+#[derive(Clone, Default)]
+pub struct ExtBuilder {
+	balances: Vec<(AccountId, Balance)>,
+	polls: Vec<(u8, TestPollStatus)>,
+}
+
+impl ExtBuilder {
+	#[must_use]
+	pub fn with_balances(mut self, balances: Vec<(AccountId, Balance)>) -> Self {
+		self.balances = balances;
+		self
+	}
+
+	#[must_use]
+	pub fn with_polls(mut self, polls: Vec<(u8, TestPollStatus)>) -> Self {
+		self.polls = polls;
+		self
+	}
+
+	pub fn build(self) -> sp_io::TestExternalities {
+		let mut storage = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+		pallet_balances::GenesisConfig::<Test> {
+			balances: self.balances,
+		}
+		.assimilate_storage(&mut storage)
+		.expect("assimilate should not fail");
+		let mut ext = sp_io::TestExternalities::new(storage);
+
+		POLLS.with(|polls| *polls.borrow_mut() = self.polls.into_iter().collect());
+		POLL_STARTS.with(|starts| starts.borrow_mut().clear());
+		ELIGIBLE_SINCE.with(|eligible| eligible.borrow_mut().clear());
+		ext.execute_with(|| System::set_block_number(1));
+		ext
+	}
+}
@@ -0,0 +1,293 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{fungible::InspectHold, Get, PollStatus, VoteTally},
+};
+use kilt_support::Deposit;
+use sp_runtime::{
+	traits::{BadOrigin, Zero},
+	Perbill,
+};
+
+use crate::{
+	mock::*,
+	vote::{Conviction, DidTally, DidVote, VoteRecord},
+	Error, HoldReason, Pallet, VoteRecordOf, VotingFor,
+};
+
+const AYE: DidVote = DidVote {
+	aye: true,
+	conviction: Conviction::Locked1x,
+};
+const NAY: DidVote = DidVote {
+	aye: false,
+	conviction: Conviction::Locked2x,
+};
+
+fn ongoing() -> TestPollStatus {
+	PollStatus::Ongoing(DidTally::from_parts(0, 0, 0), 0)
+}
+
+fn record(vote: DidVote, owner: AccountId) -> VoteRecordOf<Test> {
+	VoteRecord {
+		vote,
+		deposit: Deposit {
+			owner,
+			amount: Pallet::<Test>::deposit_amount(&vote),
+		},
+	}
+}
+
+fn held(account: &AccountId) -> Balance {
+	Balances::balance_on_hold(&HoldReason::Deposit.into(), account)
+}
+
+fn tally(index: u8) -> (u32, u32, u32) {
+	match poll_status(index) {
+		Some(PollStatus::Ongoing(tally, _)) => (tally.ayes, tally.nays, tally.support),
+		_ => panic!("Poll should be ongoing."),
+	}
+}
+
+#[test]
+fn test_vote() {
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, 1_000)])
+		.with_polls(vec![(0, ongoing())])
+		.build()
+		.execute_with(|| {
+			assert_ok!(Pallet::<Test>::vote(did_origin(DID_00), 0, AYE));
+			assert_ok!(Pallet::<Test>::vote(did_origin(DID_01), 0, NAY));
+
+			assert_eq!(VotingFor::<Test>::get(DID_00, 0), Some(record(AYE, ACCOUNT_00)));
+			assert_eq!(VotingFor::<Test>::get(DID_01, 0), Some(record(NAY, ACCOUNT_00)));
+			assert_eq!(tally(0), (10, 20, 1));
+			// The deposit grows with the conviction of each vote.
+			assert_eq!(held(&ACCOUNT_00), 2 * VoteDeposit::get() + 3 * ConvictionDeposit::get());
+		});
+}
+
+#[test]
+fn test_vote_replaces_previous_vote() {
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, 1_000), (ACCOUNT_01, 1_000)])
+		.with_polls(vec![(0, ongoing())])
+		.build()
+		.execute_with(|| {
+			assert_ok!(Pallet::<Test>::vote(did_origin(DID_00), 0, AYE));
+			assert_ok!(Pallet::<Test>::vote(did_origin_paid_by(ACCOUNT_01, DID_00), 0, NAY));
+
+			assert_eq!(VotingFor::<Test>::get(DID_00, 0), Some(record(NAY, ACCOUNT_01)));
+			assert_eq!(tally(0), (0, 20, 0));
+			// The deposit of the previous vote is released.
+			assert!(held(&ACCOUNT_00).is_zero());
+			assert_eq!(held(&ACCOUNT_01), Pallet::<Test>::deposit_amount(&NAY));
+		});
+}
+
+#[test]
+fn test_vote_not_eligible() {
+	ExtBuilder::default()
+		.with_polls(vec![(0, ongoing())])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				Pallet::<Test>::vote(did_origin(DID_02), 0, AYE),
+				Error::<Test>::NotEligible
+			);
+		});
+}
+
+#[test]
+fn test_vote_eligible_after_poll_start() {
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, 1_000)])
+		.with_polls(vec![(0, ongoing())])
+		.build()
+		.execute_with(|| {
+			set_poll_start(0, 5);
+			set_eligible_since(DID_00, 5);
+			set_eligible_since(DID_01, 6);
+
+			assert_ok!(Pallet::<Test>::vote(did_origin(DID_00), 0, AYE));
+			// Eligibility gained after the start of the poll does not count.
+			assert_noop!(
+				Pallet::<Test>::vote(did_origin(DID_01), 0, AYE),
+				Error::<Test>::NotEligible
+			);
+		});
+}
+
+#[test]
+fn test_vote_insufficient_funds() {
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, 100)])
+		.with_polls(vec![(0, ongoing())])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				Pallet::<Test>::vote(did_origin(DID_00), 0, AYE),
+				Error::<Test>::InsufficientFunds
+			);
+		});
+}
+
+#[test]
+fn test_vote_not_ongoing() {
+	ExtBuilder::default()
+		.with_polls(vec![(1, PollStatus::Completed(1, true))])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				Pallet::<Test>::vote(did_origin(DID_00), 0, AYE),
+				Error::<Test>::NotOngoing
+			);
+			assert_noop!(
+				Pallet::<Test>::vote(did_origin(DID_00), 1, AYE),
+				Error::<Test>::NotOngoing
+			);
+		});
+}
+
+#[test]
+fn test_vote_unauthorized() {
+	ExtBuilder::default()
+		.with_polls(vec![(0, ongoing())])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				Pallet::<Test>::vote(RuntimeOrigin::signed(ACCOUNT_00), 0, AYE),
+				BadOrigin
+			);
+		});
+}
+
+#[test]
+fn test_remove_vote_ongoing() {
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, 1_000)])
+		.with_polls(vec![(0, ongoing())])
+		.build()
+		.execute_with(|| {
+			assert_ok!(Pallet::<Test>::vote(did_origin(DID_00), 0, AYE));
+			assert_ok!(Pallet::<Test>::remove_vote(did_origin(DID_00), 0));
+
+			assert!(VotingFor::<Test>::get(DID_00, 0).is_none());
+			assert_eq!(tally(0), (0, 0, 0));
+			assert!(held(&ACCOUNT_00).is_zero());
+			assert_noop!(
+				Pallet::<Test>::remove_vote(did_origin(DID_00), 0),
+				Error::<Test>::NotVoted
+			);
+		});
+}
+
+#[test]
+fn test_remove_vote_completed() {
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, 1_000)])
+		.with_polls(vec![(0, ongoing())])
+		.build()
+		.execute_with(|| {
+			assert_ok!(Pallet::<Test>::vote(did_origin(DID_00), 0, AYE));
+			assert_ok!(Pallet::<Test>::vote(did_origin(DID_01), 0, NAY));
+			// The poll is approved at block 1, so the aye vote is locked for one period.
+			set_poll_status(0, PollStatus::Completed(1, true));
+
+			// The losing vote is never locked.
+			assert_ok!(Pallet::<Test>::remove_vote(did_origin(DID_01), 0));
+			assert_noop!(
+				Pallet::<Test>::remove_vote(did_origin(DID_00), 0),
+				Error::<Test>::VoteLocked
+			);
+			// The deposit of the locked vote stays held.
+			assert_eq!(held(&ACCOUNT_00), Pallet::<Test>::deposit_amount(&AYE));
+
+			System::set_block_number(1 + VoteLockingPeriod::get());
+			assert_ok!(Pallet::<Test>::remove_vote(did_origin(DID_00), 0));
+			assert!(VotingFor::<Test>::get(DID_00, 0).is_none());
+			assert!(held(&ACCOUNT_00).is_zero());
+		});
+}
+
+#[test]
+fn test_reclaim_deposit() {
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, 1_000)])
+		.with_polls(vec![(0, ongoing())])
+		.build()
+		.execute_with(|| {
+			assert_ok!(Pallet::<Test>::vote(did_origin(DID_00), 0, AYE));
+			set_poll_status(0, PollStatus::Completed(1, true));
+
+			assert_noop!(
+				Pallet::<Test>::reclaim_deposit(RuntimeOrigin::signed(ACCOUNT_00), DID_00, 0),
+				Error::<Test>::VoteLocked
+			);
+
+			System::set_block_number(1 + VoteLockingPeriod::get());
+			assert_ok!(Pallet::<Test>::reclaim_deposit(
+				RuntimeOrigin::signed(ACCOUNT_00),
+				DID_00,
+				0
+			));
+			assert!(VotingFor::<Test>::get(DID_00, 0).is_none());
+			assert!(held(&ACCOUNT_00).is_zero());
+			assert_noop!(
+				Pallet::<Test>::reclaim_deposit(RuntimeOrigin::signed(ACCOUNT_00), DID_00, 0),
+				Error::<Test>::NotVoted
+			);
+		});
+}
+
+#[test]
+fn test_reclaim_deposit_unauthorized() {
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, 1_000)])
+		.with_polls(vec![(0, ongoing())])
+		.build()
+		.execute_with(|| {
+			assert_ok!(Pallet::<Test>::vote(did_origin(DID_00), 0, AYE));
+
+			assert_noop!(
+				Pallet::<Test>::reclaim_deposit(RuntimeOrigin::signed(ACCOUNT_01), DID_00, 0),
+				Error::<Test>::NotAuthorized
+			);
+		});
+}
+
+#[test]
+fn test_tally() {
+	// DID_00 votes aye with no conviction, DID_01 votes aye and DID_02 votes nay
+	// with 1x conviction.
+	let tally = DidTally::<Electorate>::from_parts(11, 10, 2);
+
+	assert_eq!(VoteTally::<u32, u8>::ayes(&tally, 0), 11);
+	// Support counts DIDs regardless of their conviction.
+	assert_eq!(VoteTally::<u32, u8>::support(&tally, 0), Perbill::from_percent(20));
+	assert_eq!(
+		VoteTally::<u32, u8>::approval(&tally, 0),
+		Perbill::from_rational(11u32, 21u32)
+	);
+	assert_eq!(
+		VoteTally::<u32, u8>::approval(&DidTally::<Electorate>::from_parts(0, 0, 0), 0),
+		Perbill::zero()
+	);
+}
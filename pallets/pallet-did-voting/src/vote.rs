@@ -0,0 +1,201 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use frame_support::{
+	traits::{Get, VoteTally},
+	CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound,
+};
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::{Perbill, RuntimeDebug};
+use sp_std::marker::PhantomData;
+
+/// The conviction a DID votes with. A higher conviction multiplies the weight
+/// of the vote, at the cost of keeping the vote locked for longer if it ends up
+/// on the winning side.
+#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Copy, PartialEq, Eq, RuntimeDebug, Default)]
+pub enum Conviction {
+	/// 0.1x votes, no lock.
+	#[default]
+	None,
+	/// 1x votes, locked for one locking period.
+	Locked1x,
+	/// 2x votes, locked for two locking periods.
+	Locked2x,
+	/// 3x votes, locked for four locking periods.
+	Locked3x,
+	/// 4x votes, locked for eight locking periods.
+	Locked4x,
+	/// 5x votes, locked for 16 locking periods.
+	Locked5x,
+	/// 6x votes, locked for 32 locking periods.
+	Locked6x,
+}
+
+impl Conviction {
+	/// The votes a single DID casts with this conviction, in tenths of a
+	/// vote.
+	pub fn votes(self) -> u32 {
+		match self {
+			Conviction::None => 1,
+			Conviction::Locked1x => 10,
+			Conviction::Locked2x => 20,
+			Conviction::Locked3x => 30,
+			Conviction::Locked4x => 40,
+			Conviction::Locked5x => 50,
+			Conviction::Locked6x => 60,
+		}
+	}
+
+	/// The multiplier applied to the conviction deposit of a vote with this
+	/// conviction, i.e., the number of whole votes it casts.
+	pub fn multiplier(self) -> u32 {
+		self.votes() / Conviction::Locked1x.votes()
+	}
+
+	/// The number of locking periods a vote with this conviction stays locked
+	/// after the poll it was cast on ended in its favour.
+	pub fn lock_periods(self) -> u32 {
+		match self {
+			Conviction::None => 0,
+			Conviction::Locked1x => 1,
+			Conviction::Locked2x => 2,
+			Conviction::Locked3x => 4,
+			Conviction::Locked4x => 8,
+			Conviction::Locked5x => 16,
+			Conviction::Locked6x => 32,
+		}
+	}
+}
+
+/// The vote of a single DID on a poll.
+#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Copy, PartialEq, Eq, RuntimeDebug, Default)]
+pub struct DidVote {
+	/// Whether the DID votes in favour of the poll.
+	pub aye: bool,
+	/// The conviction of the vote.
+	pub conviction: Conviction,
+}
+
+/// The vote of a DID on a poll, together with the deposit held for it.
+#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct VoteRecord<Deposit> {
+	/// The vote cast by the DID.
+	pub vote: DidVote,
+	/// The deposit held for the vote.
+	pub deposit: Deposit,
+}
+
+/// The tally of a poll voted on by DIDs.
+///
+/// Approval is computed over the conviction-weighted votes, while support
+/// counts each DID voting aye exactly once, relative to the size of the
+/// `Electorate`.
+#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, CloneNoBound, PartialEqNoBound, EqNoBound, RuntimeDebugNoBound)]
+#[scale_info(skip_type_params(Electorate))]
+#[codec(mel_bound())]
+pub struct DidTally<Electorate> {
+	/// The conviction-weighted aye votes, in tenths of a vote.
+	pub ayes: u32,
+	/// The conviction-weighted nay votes, in tenths of a vote.
+	pub nays: u32,
+	/// The number of DIDs voting aye.
+	pub support: u32,
+	#[codec(skip)]
+	_phantom: PhantomData<Electorate>,
+}
+
+impl<Electorate> DidTally<Electorate> {
+	pub fn from_parts(ayes: u32, nays: u32, support: u32) -> Self {
+		Self {
+			ayes,
+			nays,
+			support,
+			_phantom: PhantomData,
+		}
+	}
+
+	pub(crate) fn add(&mut self, vote: DidVote) {
+		if vote.aye {
+			self.ayes = self.ayes.saturating_add(vote.conviction.votes());
+			self.support = self.support.saturating_add(1);
+		} else {
+			self.nays = self.nays.saturating_add(vote.conviction.votes());
+		}
+	}
+
+	pub(crate) fn remove(&mut self, vote: DidVote) {
+		if vote.aye {
+			self.ayes = self.ayes.saturating_sub(vote.conviction.votes());
+			self.support = self.support.saturating_sub(1);
+		} else {
+			self.nays = self.nays.saturating_sub(vote.conviction.votes());
+		}
+	}
+}
+
+impl<Electorate, Class> VoteTally<u32, Class> for DidTally<Electorate>
+where
+	Electorate: Get<u32>,
+{
+	fn new(_: Class) -> Self {
+		Self::from_parts(0, 0, 0)
+	}
+
+	fn ayes(&self, _: Class) -> u32 {
+		self.ayes
+	}
+
+	fn support(&self, _: Class) -> Perbill {
+		let electorate = Electorate::get();
+		if electorate == 0 {
+			return Perbill::zero();
+		}
+		Perbill::from_rational(self.support.min(electorate), electorate)
+	}
+
+	fn approval(&self, _: Class) -> Perbill {
+		let total = self.ayes.saturating_add(self.nays);
+		if total == 0 {
+			return Perbill::zero();
+		}
+		Perbill::from_rational(self.ayes, total)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn unanimity(_: Class) -> Self {
+		let electorate = Electorate::get();
+		Self::from_parts(electorate.saturating_mul(Conviction::Locked6x.votes()), 0, electorate)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn rejection(_: Class) -> Self {
+		Self::from_parts(0, Electorate::get().saturating_mul(Conviction::Locked6x.votes()), 0)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn from_requirements(support: Perbill, approval: Perbill, _: Class) -> Self {
+		let support = support.mul_ceil(Electorate::get());
+		let total = support.saturating_mul(Conviction::Locked1x.votes());
+		let ayes = approval.mul_ceil(total);
+		Self::from_parts(ayes, total.saturating_sub(ayes), support)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn setup(_: Class, _: Perbill) {}
+}
@@ -16,18 +16,21 @@
 
 // If you feel like getting in touch with us, you can do so at info@botlabs.org
 
-use crate::{traits::IdentityProofVerifier, Call, Config, IdentityEntries, Pallet};
+use crate::{traits::IdentityProofVerifier, Call, Config, IdentityEntries, LastVerifiedAt, Pallet};
 use frame_benchmarking::v2::*;
-use frame_system::RawOrigin;
+use frame_support::weights::Weight;
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
 use kilt_support::{
 	benchmark::IdentityContext,
 	traits::{GetWorstCase, Instanciate},
 };
+use sp_runtime::traits::{One, Zero};
 
 #[benchmarks(
 	where
 		T::AccountId: Instanciate,
 		T::Identifier: Instanciate,
+		T::LocalIdentityInfo: Default,
         <<T as Config>::ProofVerifier as IdentityProofVerifier<T>>::Proof: GetWorstCase<IdentityContext<T::Identifier, T::AccountId>>,
         <T as Config>::RuntimeCall: From<frame_system::Call<T>>,
 )]
@@ -71,6 +74,21 @@ mod benchmarks {
 		);
 	}
 
+	#[benchmark]
+	fn expire_identity_entry() {
+		let subject = T::Identifier::new(1);
+
+		IdentityEntries::<T>::insert(&subject, T::LocalIdentityInfo::default());
+		LastVerifiedAt::<T>::insert(&subject, BlockNumberFor::<T>::zero());
+
+		#[block]
+		{
+			Pallet::<T>::expire_identity_entries(BlockNumberFor::<T>::one(), BlockNumberFor::<T>::one(), Weight::MAX);
+		}
+
+		assert!(IdentityEntries::<T>::get(&subject).is_none());
+	}
+
 	#[cfg(test)]
 	mod benchmarks_tests {
 		use crate::Pallet;
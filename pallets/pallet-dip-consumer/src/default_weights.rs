@@ -31,35 +31,54 @@ use sp_std::marker::PhantomData;
 /// Weight functions needed for pallet_dip_consumer.
 pub trait WeightInfo {
 	fn dispatch_as() -> Weight;
+	fn expire_identity_entry() -> Weight;
 }
 
 /// Weights for pallet_dip_consumer using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// The weights below are not taken from the benchmark run above. They are
+	// estimated from the storage accesses of each call.
 	/// Storage: `PalletDipConsumer::IdentityEntries` (r:1 w:1)
 	/// Proof: `PalletDipConsumer::IdentityEntries` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `PalletDipConsumer::LastVerifiedAt` (r:0 w:1)
+	/// Proof: `PalletDipConsumer::LastVerifiedAt` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn dispatch_as() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `147`
-		//  Estimated: `3612`
-		// Minimum execution time: 127_413 nanoseconds.
 		Weight::from_parts(129_497_000, 3612)
 			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `PalletDipConsumer::IdentityEntries` (r:1 w:1)
+	/// Proof: `PalletDipConsumer::IdentityEntries` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `PalletDipConsumer::LastVerifiedAt` (r:1 w:1)
+	/// Proof: `PalletDipConsumer::LastVerifiedAt` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn expire_identity_entry() -> Weight {
+		Weight::from_parts(24_711_000, 7140)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
+	// The weights below are not taken from the benchmark run above. They are
+	// estimated from the storage accesses of each call.
 	/// Storage: `PalletDipConsumer::IdentityEntries` (r:1 w:1)
 	/// Proof: `PalletDipConsumer::IdentityEntries` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `PalletDipConsumer::LastVerifiedAt` (r:0 w:1)
+	/// Proof: `PalletDipConsumer::LastVerifiedAt` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn dispatch_as() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `147`
-		//  Estimated: `3612`
-		// Minimum execution time: 127_413 nanoseconds.
 		Weight::from_parts(129_497_000, 3612)
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `PalletDipConsumer::IdentityEntries` (r:1 w:1)
+	/// Proof: `PalletDipConsumer::IdentityEntries` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `PalletDipConsumer::LastVerifiedAt` (r:1 w:1)
+	/// Proof: `PalletDipConsumer::LastVerifiedAt` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn expire_identity_entry() -> Weight {
+		Weight::from_parts(24_711_000, 7140)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 }
@@ -21,24 +21,32 @@
 
 pub mod traits;
 
-mod default_weights;
+pub mod default_weights;
 
 #[cfg(test)]
 pub mod mock;
 
+#[cfg(test)]
+mod tests;
+
 #[cfg(feature = "runtime-benchmarks")]
 pub mod benchmarking;
 
 mod origin;
 
-pub use crate::{default_weights::WeightInfo, origin::*, pallet::*, traits::SuccessfulProofVerifier};
+pub use crate::{
+	default_weights::WeightInfo,
+	origin::*,
+	pallet::*,
+	traits::{DipOriginRequirement, SuccessfulProofVerifier},
+};
 
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
 
 	use frame_support::{
-		dispatch::{Dispatchable, GetDispatchInfo, PostDispatchInfo},
+		dispatch::{extract_actual_weight, Dispatchable, GetDispatchInfo, PostDispatchInfo},
 		pallet_prelude::*,
 		traits::{Contains, EnsureOriginWithArg},
 		Twox64Concat,
@@ -46,7 +54,8 @@ pub mod pallet {
 	use frame_system::pallet_prelude::*;
 	use parity_scale_codec::{FullCodec, MaxEncodedLen};
 	use scale_info::TypeInfo;
-	use sp_std::boxed::Box;
+	use sp_runtime::traits::{MaybeSerializeDeserialize, Saturating};
+	use sp_std::{boxed::Box, vec::Vec};
 
 	use crate::traits::IdentityProofVerifier;
 
@@ -73,6 +82,12 @@ pub mod pallet {
 			Self::Identifier,
 			Success = Self::AccountId,
 		>;
+		/// The number of blocks after which the identity entry of a subject
+		/// whose proof has not been verified in the meantime is removed by the
+		/// `on_idle` hook. If `None`, identity entries never expire.
+		/// If the `LocalIdentityInfo` is used for replay protection, the
+		/// lifetime must exceed the validity of any identity proof.
+		type EntryLifetime: Get<Option<BlockNumberFor<Self>>>;
 		/// The type of a subject identifier. This must match the definition of
 		/// `Identifier` the identity provider has defined in their deployment
 		/// of the provider pallet.
@@ -96,24 +111,87 @@ pub mod pallet {
 		type RuntimeCall: Parameter
 			+ Dispatchable<PostInfo = PostDispatchInfo, RuntimeOrigin = <Self as Config>::RuntimeOrigin>
 			+ GetDispatchInfo;
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 		/// The aggregated `Origin` type, which must include the origin exposed
 		/// by this pallet.
 		type RuntimeOrigin: From<Origin<Self>> + From<<Self as frame_system::Config>::RuntimeOrigin>;
 		type WeightInfo: WeightInfo;
 	}
 
-	/// The pallet contains a single storage element, the `IdentityEntries` map.
-	/// It maps from a subject `Identifier` to an instance of
-	/// `LocalIdentityInfo`.
+	/// The `IdentityEntries` map maps from a subject `Identifier` to an
+	/// instance of `LocalIdentityInfo`.
 	#[pallet::storage]
 	#[pallet::getter(fn identity_proofs)]
 	pub(crate) type IdentityEntries<T> =
 		StorageMap<_, Twox64Concat, <T as Config>::Identifier, <T as Config>::LocalIdentityInfo>;
 
+	/// The block number in which the proof of a subject with an identity entry
+	/// has last been verified. It is only tracked if `EntryLifetime` is set.
+	#[pallet::storage]
+	#[pallet::getter(fn last_verified_at)]
+	pub(crate) type LastVerifiedAt<T> = StorageMap<_, Twox64Concat, <T as Config>::Identifier, BlockNumberFor<T>>;
+
+	/// The last subject whose identity entry has been checked for expiry, from
+	/// which the next check in `on_idle` is resumed.
+	#[pallet::storage]
+	pub(crate) type ExpiryCursor<T> = StorageValue<_, <T as Config>::Identifier>;
+
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
+	#[pallet::genesis_config]
+	#[derive(frame_support::DefaultNoBound)]
+	pub struct GenesisConfig<T: Config>
+	where
+		T::Identifier: MaybeSerializeDeserialize,
+		T::LocalIdentityInfo: MaybeSerializeDeserialize,
+	{
+		/// The subjects, and their local identity info, that are registered
+		/// when the chain is started, e.g., for test networks or for subjects
+		/// migrated from a different identity system.
+		pub identity_entries: Vec<(T::Identifier, T::LocalIdentityInfo)>,
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T>
+	where
+		T::Identifier: MaybeSerializeDeserialize,
+		T::LocalIdentityInfo: MaybeSerializeDeserialize,
+	{
+		fn build(&self) {
+			for (identifier, local_info) in &self.identity_entries {
+				assert!(
+					!IdentityEntries::<T>::contains_key(identifier),
+					"Duplicate identity entry in the genesis config."
+				);
+				IdentityEntries::<T>::insert(identifier, local_info);
+			}
+		}
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// An identity entry has been removed since the proof of its subject
+		/// has not been verified for `EntryLifetime` blocks.
+		IdentityEntryExpired {
+			/// The identifier of the subject.
+			identifier: T::Identifier,
+		},
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let Some(entry_lifetime) = T::EntryLifetime::get() else {
+				return Weight::zero();
+			};
+			Self::expire_identity_entries(now, entry_lifetime, remaining_weight)
+		}
+	}
+
 	#[pallet::error]
 	pub enum Error<T> {
 		/// The identity proof provided could not be successfully verified.
@@ -140,6 +218,11 @@ pub mod pallet {
 		/// `Call`. If the whole execution terminates successfully, any changes
 		/// applied to the `LocalIdentityInfo` by the proof verifier are
 		/// persisted to the pallet storage.
+		///
+		/// The extrinsic is charged for the verification of a worst-case proof.
+		/// The difference between that and the weight actually consumed to
+		/// verify the provided proof, as reported by the proof verifier, is
+		/// refunded along with any unused weight of the dispatched call.
 		#[pallet::call_index(0)]
 		#[pallet::weight({
 			let extrinsic_weight = <T as Config>::WeightInfo::dispatch_as();
@@ -154,16 +237,27 @@ pub mod pallet {
 		) -> DispatchResultWithPostInfo {
 			let submitter = T::DispatchOriginCheck::ensure_origin(origin, &identifier)?;
 			ensure!(T::DipCallOriginFilter::contains(&*call), Error::<T>::Filtered);
-			let proof_verification_result = IdentityEntries::<T>::try_mutate(&identifier, |identity_entry| {
-				T::ProofVerifier::verify_proof_for_call_against_details(
-					&*call,
-					&identifier,
-					&submitter,
-					identity_entry,
-					proof,
-				)
-				.map_err(|e| Error::<T>::InvalidProof(e.into()))
-			})?;
+			let verification_weight =
+				T::ProofVerifier::consumed_verification_weight(&proof, <T as Config>::WeightInfo::dispatch_as());
+			let (proof_verification_result, is_entry_present) =
+				IdentityEntries::<T>::try_mutate(&identifier, |identity_entry| {
+					T::ProofVerifier::verify_proof_for_call_against_details(
+						&*call,
+						&identifier,
+						&submitter,
+						identity_entry,
+						proof,
+					)
+					.map(|verification_result| (verification_result, identity_entry.is_some()))
+					.map_err(|e| Error::<T>::InvalidProof(e.into()))
+				})?;
+			if T::EntryLifetime::get().is_some() {
+				if is_entry_present {
+					LastVerifiedAt::<T>::insert(&identifier, frame_system::Pallet::<T>::block_number());
+				} else {
+					LastVerifiedAt::<T>::remove(&identifier);
+				}
+			}
 			let did_origin: DipOrigin<
 				T::Identifier,
 				T::AccountId,
@@ -179,11 +273,70 @@ pub mod pallet {
 			// filters the consumer proof verifier has set.
 			cfg_if::cfg_if! {
 				if #[cfg(not(feature = "runtime-benchmark"))] {
-					call.dispatch(did_origin.into())
+					let call_info = call.get_dispatch_info();
+					let result = call.dispatch(did_origin.into());
+					let actual_weight = verification_weight.saturating_add(extract_actual_weight(&result, &call_info));
+					result
+						.map(|_| Some(actual_weight).into())
+						.map_err(|mut e| {
+							e.post_info = Some(actual_weight).into();
+							e
+						})
 				} else {
-					().into()
+					Ok(Some(verification_weight).into())
+				}
+			}
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Remove the identity entries whose proof has not been verified for
+		/// `entry_lifetime` blocks, resuming from the last checked subject and
+		/// checking as many entries as `remaining_weight` allows. Entries which
+		/// were never verified since expiry was enabled, e.g., those created
+		/// before, start being tracked from the current block.
+		pub(crate) fn expire_identity_entries(
+			now: BlockNumberFor<T>,
+			entry_lifetime: BlockNumberFor<T>,
+			remaining_weight: Weight,
+		) -> Weight {
+			let cursor_weight = T::DbWeight::get().reads_writes(1, 1);
+			let entry_weight = <T as Config>::WeightInfo::expire_identity_entry();
+			let mut consumed_weight = cursor_weight;
+			if remaining_weight.any_lt(consumed_weight.saturating_add(entry_weight)) {
+				return Weight::zero();
+			}
+
+			let mut entries = match ExpiryCursor::<T>::take() {
+				Some(cursor) => IdentityEntries::<T>::iter_keys_from(IdentityEntries::<T>::hashed_key_for(cursor)),
+				None => IdentityEntries::<T>::iter_keys(),
+			};
+			let mut last_checked = None;
+			while !remaining_weight.any_lt(consumed_weight.saturating_add(entry_weight)) {
+				let Some(identifier) = entries.next() else {
+					// All entries have been checked, so the next check starts from the beginning.
+					last_checked = None;
+					break;
+				};
+				consumed_weight.saturating_accrue(entry_weight);
+				match LastVerifiedAt::<T>::get(&identifier) {
+					Some(last_verified_at) if now.saturating_sub(last_verified_at) >= entry_lifetime => {
+						IdentityEntries::<T>::remove(&identifier);
+						LastVerifiedAt::<T>::remove(&identifier);
+						Self::deposit_event(Event::IdentityEntryExpired {
+							identifier: identifier.clone(),
+						});
+					}
+					Some(_) => {}
+					None => LastVerifiedAt::<T>::insert(&identifier, now),
 				}
+				last_checked = Some(identifier);
 			}
+			if let Some(identifier) = last_checked {
+				ExpiryCursor::<T>::put(identifier);
+			}
+
+			consumed_weight
 		}
 	}
 }
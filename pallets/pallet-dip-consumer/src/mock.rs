@@ -17,7 +17,7 @@
 // If you feel like getting in touch with us, you can do so at info@botlabs.org
 
 use frame_support::{
-	construct_runtime,
+	construct_runtime, parameter_types,
 	sp_runtime::{
 		testing::H256,
 		traits::{BlakeTwo256, IdentityLookup},
@@ -26,6 +26,7 @@ use frame_support::{
 	traits::{ConstU16, ConstU32, ConstU64, Contains, Everything},
 };
 use frame_system::{mocking::MockBlock, EnsureSigned};
+use kilt_support::mock::MockExtBuilder;
 
 use crate::traits::SuccessfulProofVerifier;
 
@@ -70,9 +71,17 @@ impl Contains<RuntimeCall> for CallFilter {
 	}
 }
 
+pub(crate) const ENTRY_LIFETIME: u64 = 10;
+
+parameter_types! {
+	pub const EntryLifetime: Option<u64> = Some(ENTRY_LIFETIME);
+}
+
 impl crate::Config for TestRuntime {
 	type RuntimeOrigin = RuntimeOrigin;
 	type RuntimeCall = RuntimeCall;
+	type RuntimeEvent = RuntimeEvent;
+	type EntryLifetime = EntryLifetime;
 	type ProofVerifier = SuccessfulProofVerifier;
 	type LocalIdentityInfo = u128;
 	type Identifier = AccountId32;
@@ -82,18 +91,28 @@ impl crate::Config for TestRuntime {
 }
 
 #[derive(Default)]
-pub(crate) struct ExtBuilder;
+pub(crate) struct ExtBuilder(Vec<(AccountId32, u128)>);
 
 impl ExtBuilder {
-	pub fn _build(self) -> sp_io::TestExternalities {
-		sp_io::TestExternalities::default()
+	pub fn with_identity_entries(mut self, identity_entries: Vec<(AccountId32, u128)>) -> Self {
+		self.0 = identity_entries;
+		self
+	}
+
+	fn builder(self) -> MockExtBuilder<TestRuntime> {
+		MockExtBuilder::default().with_genesis(crate::GenesisConfig::<TestRuntime> {
+			identity_entries: self.0,
+		})
+	}
+
+	pub fn build(self) -> sp_io::TestExternalities {
+		self.builder().build()
 	}
 
 	#[cfg(feature = "runtime-benchmarks")]
 	pub fn build_with_keystore(self) -> sp_io::TestExternalities {
-		let mut ext = self._build();
-		let keystore = sp_keystore::testing::MemoryKeystore::new();
-		ext.register_extension(sp_keystore::KeystoreExt(sp_std::sync::Arc::new(keystore)));
-		ext
+		self.builder().with_keystore().build()
 	}
 }
+
+pub(crate) const SUBJECT: AccountId32 = AccountId32::new([100u8; 32]);
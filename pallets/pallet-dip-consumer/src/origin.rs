@@ -22,6 +22,8 @@ use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use sp_std::marker::PhantomData;
 
+use crate::traits::DipOriginRequirement;
+
 /// An origin passed down to the to-be-dispatched `Call` upon successful DIP
 /// proof verification.
 #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
@@ -79,6 +81,72 @@ where
 	}
 }
 
+/// Implementation of the `EnsureOrigin` trait verifying that a given origin is
+/// a `DipOrigin` whose details satisfy the provided `Requirement`, e.g., that
+/// the DIP proof revealed an authentication key and the subject's web3name.
+/// Origins that do not satisfy the requirement are rejected as bad origins.
+pub struct EnsureDipOriginWith<Identifier, AccountId, Details, Requirement>(
+	PhantomData<(Identifier, AccountId, Details, Requirement)>,
+);
+
+#[cfg(not(feature = "runtime-benchmarks"))]
+impl<OuterOrigin, Identifier, AccountId, Details, Requirement> EnsureOrigin<OuterOrigin>
+	for EnsureDipOriginWith<Identifier, AccountId, Details, Requirement>
+where
+	OuterOrigin: From<DipOrigin<Identifier, AccountId, Details>>
+		+ Into<Result<DipOrigin<Identifier, AccountId, Details>, OuterOrigin>>,
+	Requirement: DipOriginRequirement<Details>,
+{
+	type Success = DipOrigin<Identifier, AccountId, Details>;
+
+	fn try_origin(o: OuterOrigin) -> Result<Self::Success, OuterOrigin> {
+		let dip_origin = EnsureDipOrigin::<Identifier, AccountId, Details>::try_origin(o)?;
+		if Requirement::is_satisfied_by(&dip_origin.details) {
+			Ok(dip_origin)
+		} else {
+			Err(OuterOrigin::from(dip_origin))
+		}
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl<OuterOrigin, Identifier, AccountId, Details, Requirement> EnsureOrigin<OuterOrigin>
+	for EnsureDipOriginWith<Identifier, AccountId, Details, Requirement>
+where
+	OuterOrigin: From<DipOrigin<Identifier, AccountId, Details>>
+		+ Into<Result<DipOrigin<Identifier, AccountId, Details>, OuterOrigin>>,
+	Requirement: DipOriginRequirement<Details>,
+	// Additional trait bounds only valid when benchmarking
+	Identifier: From<[u8; 32]>,
+	AccountId: From<[u8; 32]>,
+	Details: Default,
+{
+	type Success = DipOrigin<Identifier, AccountId, Details>;
+
+	fn try_origin(o: OuterOrigin) -> Result<Self::Success, OuterOrigin> {
+		let dip_origin = EnsureDipOrigin::<Identifier, AccountId, Details>::try_origin(o)?;
+		if Requirement::is_satisfied_by(&dip_origin.details) {
+			Ok(dip_origin)
+		} else {
+			Err(OuterOrigin::from(dip_origin))
+		}
+	}
+
+	fn try_successful_origin() -> Result<OuterOrigin, ()> {
+		let details = Details::default();
+		// The default details might not satisfy the requirement, in which case no
+		// successful origin can be generated.
+		if !Requirement::is_satisfied_by(&details) {
+			return Err(());
+		}
+		Ok(OuterOrigin::from(DipOrigin {
+			identifier: Identifier::from([0u8; 32]),
+			account_address: AccountId::from([0u8; 32]),
+			details,
+		}))
+	}
+}
+
 impl<Identifier, AccountId, Details> CallSources<AccountId, Identifier> for DipOrigin<Identifier, AccountId, Details>
 where
 	Identifier: Clone,
@@ -0,0 +1,167 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use frame_support::{
+	dispatch::{GetDispatchInfo, Pays, PostDispatchInfo},
+	traits::{EnsureOrigin, Hooks},
+	weights::Weight,
+};
+use sp_runtime::{AccountId32, DispatchErrorWithPostInfo};
+
+use crate::{
+	mock::*, DipOrigin, DipOriginRequirement, EnsureDipOriginWith, Event, ExpiryCursor, IdentityEntries,
+	LastVerifiedAt, WeightInfo,
+};
+
+#[test]
+fn genesis_identity_entries() {
+	ExtBuilder::default()
+		.with_identity_entries(vec![(SUBJECT, 10)])
+		.build()
+		.execute_with(|| {
+			assert_eq!(DipConsumer::identity_proofs(SUBJECT), Some(10));
+			assert_eq!(IdentityEntries::<TestRuntime>::iter().count(), 1);
+		});
+}
+
+#[test]
+#[should_panic = "Duplicate identity entry in the genesis config."]
+fn genesis_duplicate_identity_entries() {
+	ExtBuilder::default()
+		.with_identity_entries(vec![(SUBJECT, 10), (SUBJECT, 20)])
+		.build();
+}
+
+#[test]
+fn dispatch_as_returns_consumed_weight() {
+	ExtBuilder::default().build().execute_with(|| {
+		let call = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+		let call_weight = call.get_dispatch_info().weight;
+		// The remark call does not accept a DIP origin, so its dispatch fails after
+		// the proof has been verified.
+		let result = DipConsumer::dispatch_as(RuntimeOrigin::signed(SUBJECT), SUBJECT, (), Box::new(call));
+		assert_eq!(
+			result,
+			Err(DispatchErrorWithPostInfo {
+				post_info: PostDispatchInfo {
+					actual_weight: Some(<() as WeightInfo>::dispatch_as().saturating_add(call_weight)),
+					pays_fee: Pays::Yes,
+				},
+				error: sp_runtime::DispatchError::BadOrigin,
+			})
+		);
+	});
+}
+
+const OTHER_SUBJECT: AccountId32 = AccountId32::new([101u8; 32]);
+
+#[test]
+fn on_idle_expires_identity_entries() {
+	ExtBuilder::default()
+		.with_identity_entries(vec![(SUBJECT, 10), (OTHER_SUBJECT, 20)])
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+			// Entries without a verification block start being tracked.
+			DipConsumer::on_idle(1, Weight::MAX);
+			assert_eq!(DipConsumer::last_verified_at(SUBJECT), Some(1));
+			assert_eq!(DipConsumer::last_verified_at(OTHER_SUBJECT), Some(1));
+
+			LastVerifiedAt::<TestRuntime>::insert(OTHER_SUBJECT, 2);
+			let expiry_block = 1 + ENTRY_LIFETIME;
+			System::set_block_number(expiry_block);
+			DipConsumer::on_idle(expiry_block, Weight::MAX);
+
+			assert!(DipConsumer::identity_proofs(SUBJECT).is_none());
+			assert!(DipConsumer::last_verified_at(SUBJECT).is_none());
+			assert_eq!(DipConsumer::identity_proofs(OTHER_SUBJECT), Some(20));
+			assert_eq!(DipConsumer::last_verified_at(OTHER_SUBJECT), Some(2));
+			assert!(ExpiryCursor::<TestRuntime>::get().is_none());
+			System::assert_last_event(Event::<TestRuntime>::IdentityEntryExpired { identifier: SUBJECT }.into());
+		});
+}
+
+#[test]
+fn on_idle_expires_identity_entries_in_batches() {
+	ExtBuilder::default()
+		.with_identity_entries(vec![(SUBJECT, 10), (OTHER_SUBJECT, 20)])
+		.build()
+		.execute_with(|| {
+			LastVerifiedAt::<TestRuntime>::insert(SUBJECT, 0);
+			LastVerifiedAt::<TestRuntime>::insert(OTHER_SUBJECT, 0);
+			let entry_weight = <() as WeightInfo>::expire_identity_entry();
+
+			assert_eq!(
+				DipConsumer::on_idle(ENTRY_LIFETIME, entry_weight.saturating_sub(Weight::from_parts(1, 0))),
+				Weight::zero()
+			);
+			assert_eq!(IdentityEntries::<TestRuntime>::iter().count(), 2);
+
+			assert_eq!(DipConsumer::on_idle(ENTRY_LIFETIME, entry_weight), entry_weight);
+			assert_eq!(IdentityEntries::<TestRuntime>::iter().count(), 1);
+			assert!(ExpiryCursor::<TestRuntime>::get().is_some());
+
+			assert_eq!(DipConsumer::on_idle(ENTRY_LIFETIME, entry_weight), entry_weight);
+			assert_eq!(IdentityEntries::<TestRuntime>::iter().count(), 0);
+			assert_eq!(LastVerifiedAt::<TestRuntime>::iter().count(), 0);
+		});
+}
+
+struct Unsatisfiable;
+
+impl DipOriginRequirement<()> for Unsatisfiable {
+	fn is_satisfied_by(_details: &()) -> bool {
+		false
+	}
+}
+
+type EnsureDipOriginWithRequirement<Requirement> = EnsureDipOriginWith<AccountId32, AccountId32, (), Requirement>;
+
+fn dip_origin() -> RuntimeOrigin {
+	DipOrigin {
+		identifier: SUBJECT,
+		account_address: SUBJECT,
+		details: (),
+	}
+	.into()
+}
+
+#[test]
+fn ensure_dip_origin_with_satisfied_requirement() {
+	assert_eq!(
+		EnsureDipOriginWithRequirement::<()>::try_origin(dip_origin()).ok(),
+		Some(DipOrigin {
+			identifier: SUBJECT,
+			account_address: SUBJECT,
+			details: (),
+		})
+	);
+	assert!(EnsureDipOriginWithRequirement::<((), ())>::try_origin(dip_origin()).is_ok());
+}
+
+#[test]
+fn ensure_dip_origin_with_unsatisfied_requirement() {
+	assert!(EnsureDipOriginWithRequirement::<Unsatisfiable>::try_origin(dip_origin()).is_err());
+	assert!(EnsureDipOriginWithRequirement::<((), Unsatisfiable)>::try_origin(dip_origin()).is_err());
+	assert!(EnsureDipOriginWithRequirement::<((), (), Unsatisfiable)>::try_origin(dip_origin()).is_err());
+}
+
+#[test]
+fn ensure_dip_origin_with_non_dip_origin() {
+	assert!(EnsureDipOriginWithRequirement::<()>::try_origin(RuntimeOrigin::signed(SUBJECT)).is_err());
+}
@@ -16,7 +16,7 @@
 
 // If you feel like getting in touch with us, you can do so at info@botlabs.org
 
-use frame_support::Parameter;
+use frame_support::{weights::Weight, Parameter};
 
 use crate::{Config, RuntimeCallOf};
 
@@ -46,6 +46,15 @@ where
 		identity_details: &mut Option<Runtime::LocalIdentityInfo>,
 		proof: Self::Proof,
 	) -> Result<Self::VerificationResult, Self::Error>;
+
+	/// The weight consumed to verify the given proof, given the weight of
+	/// verifying a worst-case proof. The `dispatch_as` extrinsic charges the
+	/// worst-case weight upfront, and refunds the difference once the proof
+	/// has been verified. By default, the whole worst-case weight is
+	/// consumed.
+	fn consumed_verification_weight(_proof: &Self::Proof, worst_case_weight: Weight) -> Weight {
+		worst_case_weight
+	}
 }
 
 /// Dummy implementation of the [`IdentityProofVerifier`] trait which always
@@ -69,3 +78,42 @@ where
 		Ok(())
 	}
 }
+
+/// A requirement on the details of a DIP origin, e.g., that the subject has
+/// revealed a specific DID key or their web3name in the DIP proof. It is used
+/// by [`crate::EnsureDipOriginWith`] to only accept DIP origins that satisfy
+/// the requirement.
+pub trait DipOriginRequirement<Details> {
+	/// Whether the provided verification details satisfy the requirement.
+	fn is_satisfied_by(details: &Details) -> bool;
+}
+
+/// The empty requirement, satisfied by any details.
+impl<Details> DipOriginRequirement<Details> for () {
+	fn is_satisfied_by(_details: &Details) -> bool {
+		true
+	}
+}
+
+/// Two requirements that must both be satisfied.
+impl<Details, A, B> DipOriginRequirement<Details> for (A, B)
+where
+	A: DipOriginRequirement<Details>,
+	B: DipOriginRequirement<Details>,
+{
+	fn is_satisfied_by(details: &Details) -> bool {
+		A::is_satisfied_by(details) && B::is_satisfied_by(details)
+	}
+}
+
+/// Three requirements that must all be satisfied.
+impl<Details, A, B, C> DipOriginRequirement<Details> for (A, B, C)
+where
+	A: DipOriginRequirement<Details>,
+	B: DipOriginRequirement<Details>,
+	C: DipOriginRequirement<Details>,
+{
+	fn is_satisfied_by(details: &Details) -> bool {
+		A::is_satisfied_by(details) && B::is_satisfied_by(details) && C::is_satisfied_by(details)
+	}
+}
@@ -16,18 +16,30 @@
 
 // If you feel like getting in touch with us, you can do so at info@botlabs.org
 
-use crate::{traits::IdentityProvider, Call, Config, Pallet};
+use crate::{
+	migrations::InitializeIdentityCommitmentsCounter,
+	traits::{IdentityCommitmentGenerator, IdentityProvider},
+	BalanceOf, Call, Config, CounterForIdentityCommitments, IdentityScope, Pallet,
+};
 use frame_benchmarking::v2::*;
+use frame_support::traits::{
+	fungible::{Inspect, Mutate},
+	Get,
+};
+use frame_system::RawOrigin;
 use kilt_support::{
 	benchmark::IdentityContext,
+	migration::SteppedMigration,
 	traits::{GenerateBenchmarkOrigin, GetWorstCase, Instanciate},
 };
+use sp_runtime::traits::Saturating;
 
 #[benchmarks(
 	where
 		T::CommitOriginCheck: GenerateBenchmarkOrigin<T::RuntimeOrigin, T::AccountId, T::Identifier>,
 		T::AccountId: Instanciate,
 		T::Identifier: Instanciate,
+		T::Currency: Mutate<T::AccountId>,
 		<<T as Config>::IdentityProvider as IdentityProvider<T>>::Success: GetWorstCase<IdentityContext<T::Identifier, T::AccountId>>
 )]
 mod benchmarks {
@@ -39,11 +51,20 @@ mod benchmarks {
 
 	use super::*;
 
+	fn make_free_for_committer_deposit<T: Config>(account: &T::AccountId)
+	where
+		T::Currency: Mutate<T::AccountId>,
+	{
+		let balance: BalanceOf<T> =
+			<T::Currency as Inspect<T::AccountId>>::minimum_balance().saturating_add(T::CommitterDeposit::get());
+		T::Currency::set_balance(account, balance);
+	}
+
 	#[benchmark]
 	fn commit_identity() {
 		let submitter = T::AccountId::new(1);
 		let subject = T::Identifier::new(1);
-		let commitment_version = 0;
+		let commitment_version = T::IdentityCommitmentGenerator::worst_case_version();
 
 		let context = IdentityContext::<T::Identifier, T::AccountId> {
 			did: subject.clone(),
@@ -64,11 +85,45 @@ mod benchmarks {
 		assert!(Pallet::<T>::identity_commitments(&subject, commitment_version).is_some());
 	}
 
+	#[benchmark]
+	fn commit_identity_scoped() {
+		let submitter = T::AccountId::new(1);
+		let subject = T::Identifier::new(1);
+		let commitment_version = T::IdentityCommitmentGenerator::worst_case_version();
+
+		let context = IdentityContext::<T::Identifier, T::AccountId> {
+			did: subject.clone(),
+			submitter: submitter.clone(),
+		};
+
+		assert!(Pallet::<T>::identity_commitments(&subject, commitment_version).is_none());
+
+		let origin: T::RuntimeOrigin = T::CommitOriginCheck::generate_origin(submitter, subject.clone());
+
+		<IdentityOf<T> as GetWorstCase<IdentityContextOf<T>>>::worst_case(context);
+
+		let cloned_subject = subject.clone();
+
+		#[extrinsic_call]
+		Pallet::<T>::commit_identity_scoped(
+			origin as T::RuntimeOrigin,
+			cloned_subject,
+			Some(commitment_version),
+			IdentityScope::KeysAndWeb3Name,
+		);
+
+		assert!(Pallet::<T>::identity_commitments(&subject, commitment_version).is_some());
+		assert_eq!(
+			Pallet::<T>::identity_commitment_scopes(&subject, commitment_version),
+			IdentityScope::KeysAndWeb3Name
+		);
+	}
+
 	#[benchmark]
 	fn delete_identity_commitment() {
 		let submitter = T::AccountId::new(1);
 		let subject = T::Identifier::new(1);
-		let commitment_version = 0;
+		let commitment_version = T::IdentityCommitmentGenerator::worst_case_version();
 
 		let origin: T::RuntimeOrigin = T::CommitOriginCheck::generate_origin(submitter.clone(), subject.clone());
 
@@ -95,6 +150,93 @@ mod benchmarks {
 		assert!(Pallet::<T>::identity_commitments(&subject, commitment_version).is_none());
 	}
 
+	#[benchmark]
+	fn authorize_committer() {
+		let submitter = T::AccountId::new(1);
+		let committer = T::AccountId::new(2);
+		let subject = T::Identifier::new(1);
+		make_free_for_committer_deposit::<T>(&submitter);
+
+		let origin: T::RuntimeOrigin = T::CommitOriginCheck::generate_origin(submitter, subject.clone());
+
+		let cloned_subject = subject.clone();
+		let cloned_committer = committer.clone();
+
+		#[extrinsic_call]
+		Pallet::<T>::authorize_committer(origin as T::RuntimeOrigin, cloned_subject, cloned_committer);
+
+		assert!(Pallet::<T>::committers(&subject).iter().any(|c| c.account == committer));
+	}
+
+	#[benchmark]
+	fn revoke_committer() {
+		let submitter = T::AccountId::new(1);
+		let committer = T::AccountId::new(2);
+		let subject = T::Identifier::new(1);
+		make_free_for_committer_deposit::<T>(&submitter);
+
+		let origin: T::RuntimeOrigin = T::CommitOriginCheck::generate_origin(submitter, subject.clone());
+
+		Pallet::<T>::authorize_committer(origin.clone() as T::RuntimeOrigin, subject.clone(), committer.clone())
+			.expect("Authorizing committer should not fail.");
+
+		let cloned_subject = subject.clone();
+		let cloned_committer = committer.clone();
+
+		#[extrinsic_call]
+		Pallet::<T>::revoke_committer(origin as T::RuntimeOrigin, cloned_subject, cloned_committer);
+
+		assert!(!Pallet::<T>::committers(&subject).iter().any(|c| c.account == committer));
+	}
+
+	#[benchmark]
+	fn reclaim_committer_deposit() {
+		let submitter = T::AccountId::new(1);
+		let committer = T::AccountId::new(2);
+		let subject = T::Identifier::new(1);
+		make_free_for_committer_deposit::<T>(&submitter);
+
+		let origin: T::RuntimeOrigin = T::CommitOriginCheck::generate_origin(submitter.clone(), subject.clone());
+
+		Pallet::<T>::authorize_committer(origin as T::RuntimeOrigin, subject.clone(), committer.clone())
+			.expect("Authorizing committer should not fail.");
+
+		let cloned_subject = subject.clone();
+		let cloned_committer = committer.clone();
+
+		#[extrinsic_call]
+		Pallet::<T>::reclaim_committer_deposit(RawOrigin::Signed(submitter), cloned_subject, cloned_committer);
+
+		assert!(!Pallet::<T>::committers(&subject).iter().any(|c| c.account == committer));
+	}
+
+	#[benchmark]
+	fn initialize_identity_commitments_counter_step() {
+		let submitter = T::AccountId::new(1);
+		let subject = T::Identifier::new(1);
+		let commitment_version = 0;
+
+		let origin: T::RuntimeOrigin = T::CommitOriginCheck::generate_origin(submitter.clone(), subject.clone());
+
+		let context = IdentityContext::<T::Identifier, T::AccountId> {
+			did: subject.clone(),
+			submitter,
+		};
+
+		<IdentityOf<T> as GetWorstCase<IdentityContextOf<T>>>::worst_case(context);
+
+		Pallet::<T>::commit_identity(origin as T::RuntimeOrigin, subject, Some(commitment_version))
+			.expect("Inserting Identity should not fail.");
+		CounterForIdentityCommitments::<T>::kill();
+
+		#[block]
+		{
+			<InitializeIdentityCommitmentsCounter<T> as SteppedMigration>::step(None);
+		}
+
+		assert_eq!(Pallet::<T>::identity_commitments_count(), 1);
+	}
+
 	#[cfg(test)]
 	mod benchmarks_tests {
 		use crate::Pallet;
@@ -32,11 +32,18 @@ use sp_std::marker::PhantomData;
 pub trait WeightInfo {
 	fn commit_identity() -> Weight;
 	fn delete_identity_commitment() -> Weight;
+	fn commit_identity_scoped() -> Weight;
+	fn authorize_committer() -> Weight;
+	fn revoke_committer() -> Weight;
+	fn reclaim_committer_deposit() -> Weight;
+	fn initialize_identity_commitments_counter_step() -> Weight;
 }
 
 /// Weights for pallet_dip_provider using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// The weights below are not taken from the benchmark run above. They are
+	// estimated from the storage accesses of each call.
 	/// Storage: `Did::Did` (r:1 w:0)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2312), added: 4787, mode: `MaxEncodedLen`)
 	/// Storage: `Did::DidBlacklist` (r:1 w:0)
@@ -49,30 +56,93 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `DidLookup::ConnectedAccounts` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
 	/// Storage: `PalletDipProvider::IdentityCommitments` (r:1 w:1)
 	/// Proof: `PalletDipProvider::IdentityCommitments` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `PalletDipProvider::IdentityCommitmentScopes` (r:0 w:1)
+	/// Proof: `PalletDipProvider::IdentityCommitmentScopes` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `PalletDipProvider::Committers` (r:1 w:0)
+	/// Proof: `PalletDipProvider::Committers` (`max_values`: None, `max_size`: Some(841), added: 3316, mode: `MaxEncodedLen`)
 	fn commit_identity() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2781`
-		//  Estimated: `29282`
-		// Minimum execution time: 1_244_624 nanoseconds.
-		Weight::from_parts(1_401_381_000, 29282)
-			.saturating_add(T::DbWeight::get().reads(16_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+		Weight::from_parts(1_401_381_000, 29762)
+			.saturating_add(T::DbWeight::get().reads(17_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `PalletDipProvider::IdentityCommitments` (r:1 w:1)
 	/// Proof: `PalletDipProvider::IdentityCommitments` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `PalletDipProvider::IdentityCommitmentScopes` (r:0 w:1)
+	/// Proof: `PalletDipProvider::IdentityCommitmentScopes` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
 	fn delete_identity_commitment() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `250`
-		//  Estimated: `3715`
-		// Minimum execution time: 169_415 nanoseconds.
 		Weight::from_parts(190_131_000, 3715)
 			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Did::Did` (r:1 w:0)
+	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2312), added: 4787, mode: `MaxEncodedLen`)
+	/// Storage: `Did::DidBlacklist` (r:1 w:0)
+	/// Proof: `Did::DidBlacklist` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Web3Names::Names` (r:1 w:0)
+	/// Proof: `Web3Names::Names` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `Web3Names::Owner` (r:1 w:0)
+	/// Proof: `Web3Names::Owner` (`max_values`: None, `max_size`: Some(137), added: 2612, mode: `MaxEncodedLen`)
+	/// Storage: `DidLookup::ConnectedAccounts` (r:11 w:0)
+	/// Proof: `DidLookup::ConnectedAccounts` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
+	/// Storage: `PalletDipProvider::IdentityCommitments` (r:1 w:1)
+	/// Proof: `PalletDipProvider::IdentityCommitments` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `PalletDipProvider::IdentityCommitmentScopes` (r:0 w:2)
+	/// Proof: `PalletDipProvider::IdentityCommitmentScopes` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `PalletDipProvider::Committers` (r:1 w:0)
+	/// Proof: `PalletDipProvider::Committers` (`max_values`: None, `max_size`: Some(841), added: 3316, mode: `MaxEncodedLen`)
+	fn commit_identity_scoped() -> Weight {
+		Weight::from_parts(1_407_562_000, 29762)
+			.saturating_add(T::DbWeight::get().reads(17_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `PalletDipProvider::Committers` (r:1 w:1)
+	/// Proof: `PalletDipProvider::Committers` (`max_values`: None, `max_size`: Some(841), added: 3316, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(949), added: 3424, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	fn authorize_committer() -> Weight {
+		Weight::from_parts(45_117_000, 9347)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `PalletDipProvider::Committers` (r:1 w:1)
+	/// Proof: `PalletDipProvider::Committers` (`max_values`: None, `max_size`: Some(841), added: 3316, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(949), added: 3424, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	fn revoke_committer() -> Weight {
+		Weight::from_parts(46_388_000, 9347)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `PalletDipProvider::Committers` (r:1 w:1)
+	/// Proof: `PalletDipProvider::Committers` (`max_values`: None, `max_size`: Some(841), added: 3316, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(949), added: 3424, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	fn reclaim_committer_deposit() -> Weight {
+		Weight::from_parts(45_903_000, 9347)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `PalletDipProvider::IdentityCommitments` (r:1 w:0)
+	/// Proof: `PalletDipProvider::IdentityCommitments` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `PalletDipProvider::CounterForIdentityCommitments` (r:1 w:1)
+	/// Proof: `PalletDipProvider::CounterForIdentityCommitments` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn initialize_identity_commitments_counter_step() -> Weight {
+		Weight::from_parts(9_832_000, 3547)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
+	// The weights below are not taken from the benchmark run above. They are
+	// estimated from the storage accesses of each call.
 	/// Storage: `Did::Did` (r:1 w:0)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2312), added: 4787, mode: `MaxEncodedLen`)
 	/// Storage: `Did::DidBlacklist` (r:1 w:0)
@@ -85,24 +155,85 @@ impl WeightInfo for () {
 	/// Proof: `DidLookup::ConnectedAccounts` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
 	/// Storage: `PalletDipProvider::IdentityCommitments` (r:1 w:1)
 	/// Proof: `PalletDipProvider::IdentityCommitments` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `PalletDipProvider::IdentityCommitmentScopes` (r:0 w:1)
+	/// Proof: `PalletDipProvider::IdentityCommitmentScopes` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `PalletDipProvider::Committers` (r:1 w:0)
+	/// Proof: `PalletDipProvider::Committers` (`max_values`: None, `max_size`: Some(841), added: 3316, mode: `MaxEncodedLen`)
 	fn commit_identity() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2781`
-		//  Estimated: `29282`
-		// Minimum execution time: 1_244_624 nanoseconds.
-		Weight::from_parts(1_401_381_000, 29282)
-			.saturating_add(RocksDbWeight::get().reads(16_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		Weight::from_parts(1_401_381_000, 29762)
+			.saturating_add(RocksDbWeight::get().reads(17_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `PalletDipProvider::IdentityCommitments` (r:1 w:1)
 	/// Proof: `PalletDipProvider::IdentityCommitments` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `PalletDipProvider::IdentityCommitmentScopes` (r:0 w:1)
+	/// Proof: `PalletDipProvider::IdentityCommitmentScopes` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
 	fn delete_identity_commitment() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `250`
-		//  Estimated: `3715`
-		// Minimum execution time: 169_415 nanoseconds.
 		Weight::from_parts(190_131_000, 3715)
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Did::Did` (r:1 w:0)
+	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2312), added: 4787, mode: `MaxEncodedLen`)
+	/// Storage: `Did::DidBlacklist` (r:1 w:0)
+	/// Proof: `Did::DidBlacklist` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Web3Names::Names` (r:1 w:0)
+	/// Proof: `Web3Names::Names` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `Web3Names::Owner` (r:1 w:0)
+	/// Proof: `Web3Names::Owner` (`max_values`: None, `max_size`: Some(137), added: 2612, mode: `MaxEncodedLen`)
+	/// Storage: `DidLookup::ConnectedAccounts` (r:11 w:0)
+	/// Proof: `DidLookup::ConnectedAccounts` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
+	/// Storage: `PalletDipProvider::IdentityCommitments` (r:1 w:1)
+	/// Proof: `PalletDipProvider::IdentityCommitments` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `PalletDipProvider::IdentityCommitmentScopes` (r:0 w:2)
+	/// Proof: `PalletDipProvider::IdentityCommitmentScopes` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `PalletDipProvider::Committers` (r:1 w:0)
+	/// Proof: `PalletDipProvider::Committers` (`max_values`: None, `max_size`: Some(841), added: 3316, mode: `MaxEncodedLen`)
+	fn commit_identity_scoped() -> Weight {
+		Weight::from_parts(1_407_562_000, 29762)
+			.saturating_add(RocksDbWeight::get().reads(17_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `PalletDipProvider::Committers` (r:1 w:1)
+	/// Proof: `PalletDipProvider::Committers` (`max_values`: None, `max_size`: Some(841), added: 3316, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(949), added: 3424, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	fn authorize_committer() -> Weight {
+		Weight::from_parts(45_117_000, 9347)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `PalletDipProvider::Committers` (r:1 w:1)
+	/// Proof: `PalletDipProvider::Committers` (`max_values`: None, `max_size`: Some(841), added: 3316, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(949), added: 3424, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	fn revoke_committer() -> Weight {
+		Weight::from_parts(46_388_000, 9347)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `PalletDipProvider::Committers` (r:1 w:1)
+	/// Proof: `PalletDipProvider::Committers` (`max_values`: None, `max_size`: Some(841), added: 3316, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(949), added: 3424, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	fn reclaim_committer_deposit() -> Weight {
+		Weight::from_parts(45_903_000, 9347)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `PalletDipProvider::IdentityCommitments` (r:1 w:0)
+	/// Proof: `PalletDipProvider::IdentityCommitments` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `PalletDipProvider::CounterForIdentityCommitments` (r:1 w:1)
+	/// Proof: `PalletDipProvider::CounterForIdentityCommitments` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn initialize_identity_commitments_counter_step() -> Weight {
+		Weight::from_parts(9_832_000, 3547)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 }
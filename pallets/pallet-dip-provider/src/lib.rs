@@ -19,7 +19,8 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 #![doc = include_str!("../README.md")]
 
-mod default_weights;
+pub mod default_weights;
+pub mod migrations;
 pub mod traits;
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -41,8 +42,16 @@ pub mod pallet {
 	use super::*;
 
 	use default_weights::WeightInfo;
-	use frame_support::{pallet_prelude::*, traits::EnsureOriginWithArg};
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{
+			fungible::{Inspect, MutateHold},
+			tokens::Precision,
+			EnsureOriginWithArg,
+		},
+	};
 	use frame_system::pallet_prelude::*;
+	use kilt_support::Deposit;
 
 	use crate::traits::{IdentityCommitmentGenerator, IdentityProvider, ProviderHooks, SubmitterInfo};
 
@@ -51,9 +60,53 @@ pub mod pallet {
 	pub type IdentityProviderOf<T> = <T as Config>::IdentityProvider;
 	pub type IdentityOf<T> = <<T as Config>::IdentityProvider as IdentityProvider<T>>::Success;
 	pub type IdentityCommitmentVersion = u16;
+	pub type BalanceOf<T> = <<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+	pub type CommitterOf<T> = Committer<
+		<T as frame_system::Config>::AccountId,
+		Deposit<<T as frame_system::Config>::AccountId, BalanceOf<T>>,
+	>;
 
 	pub const LATEST_COMMITMENT_VERSION: IdentityCommitmentVersion = 0;
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+
+	/// The parts of an identity that are included in an identity commitment.
+	#[derive(
+		Clone, Copy, Default, Encode, Decode, RuntimeDebug, PartialEq, Eq, PartialOrd, Ord, TypeInfo, MaxEncodedLen,
+	)]
+	pub enum IdentityScope {
+		/// Only the identity keys are committed.
+		KeysOnly,
+		/// The identity keys and the web3name are committed, while linked
+		/// accounts are left out.
+		KeysAndWeb3Name,
+		/// The whole identity is committed.
+		#[default]
+		Full,
+	}
+
+	impl IdentityScope {
+		/// Whether the web3name is part of commitments with this scope.
+		pub fn includes_web3_name(&self) -> bool {
+			matches!(self, Self::KeysAndWeb3Name | Self::Full)
+		}
+
+		/// Whether linked accounts are part of commitments with this scope.
+		pub fn includes_linked_accounts(&self) -> bool {
+			matches!(self, Self::Full)
+		}
+	}
+
+	/// An account authorized to generate identity commitments on behalf of a
+	/// subject, together with the deposit taken for the authorization.
+	#[derive(Clone, Encode, Decode, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+	pub struct Committer<AccountId, Deposit> {
+		/// The authorized account.
+		pub account: AccountId,
+		/// The deposit taken from the account that granted the authorization.
+		pub deposit: Deposit,
+	}
+
+	const STORAGE_VERSION: StorageVersion =
+		StorageVersion::new(crate::migrations::IDENTITY_COMMITMENTS_COUNTER_STORAGE_VERSION);
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
@@ -78,15 +131,25 @@ pub mod pallet {
 		/// Customizable external logic to handle events in which a new identity
 		/// commitment is generated or removed.
 		type ProviderHooks: ProviderHooks<Self>;
+		/// The maximum number of accounts that can be authorized to commit
+		/// the identity of a single subject on its behalf.
+		#[pallet::constant]
+		type MaxCommitters: Get<u32>;
+		/// The amount of tokens held for each authorized committer.
+		#[pallet::constant]
+		type CommitterDeposit: Get<BalanceOf<Self>>;
+		/// The currency type to hold and release committer deposits.
+		type Currency: MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>;
+		/// Aggregated hold reason.
+		type RuntimeHoldReason: From<HoldReason>;
 		/// The aggregate `Event` type.
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 		type WeightInfo: WeightInfo;
 	}
 
-	/// The pallet contains a single storage element, the `IdentityCommitments`
-	/// double map. Its first key is the `Identifier` of subjects, while the
-	/// second key is the commitment version. The values are identity
-	/// commitments.
+	/// The `IdentityCommitments` double map. Its first key is the `Identifier`
+	/// of subjects, while the second key is the commitment version. The values
+	/// are identity commitments.
 	#[pallet::storage]
 	#[pallet::getter(fn identity_commitments)]
 	pub type IdentityCommitments<T> = StorageDoubleMap<
@@ -98,6 +161,45 @@ pub mod pallet {
 		IdentityCommitmentOf<T>,
 	>;
 
+	/// The scope of the identity commitments that do not include the whole
+	/// identity, with the same keys as `IdentityCommitments`. Commitments
+	/// without an entry have the [`IdentityScope::Full`] scope.
+	#[pallet::storage]
+	#[pallet::getter(fn identity_commitment_scopes)]
+	pub type IdentityCommitmentScopes<T> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		<T as Config>::Identifier,
+		Twox64Concat,
+		IdentityCommitmentVersion,
+		IdentityScope,
+		ValueQuery,
+	>;
+
+	/// The accounts authorized to generate identity commitments on behalf of
+	/// a subject, paying for the transaction fees and the deposits, together
+	/// with the deposit held for each authorization.
+	#[pallet::storage]
+	#[pallet::getter(fn committers)]
+	pub type Committers<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		<T as Config>::Identifier,
+		BoundedVec<CommitterOf<T>, <T as Config>::MaxCommitters>,
+		ValueQuery,
+	>;
+
+	#[pallet::composite_enum]
+	pub enum HoldReason {
+		CommitterDeposit,
+	}
+
+	/// The number of identity commitments currently stored, across all
+	/// subjects and versions.
+	#[pallet::storage]
+	#[pallet::getter(fn identity_commitments_count)]
+	pub type CounterForIdentityCommitments<T> = StorageValue<_, u32, ValueQuery>;
+
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
@@ -121,6 +223,21 @@ pub mod pallet {
 			/// The version of the commitment.
 			version: IdentityCommitmentVersion,
 		},
+		/// An account has been authorized to commit an identity.
+		CommitterAuthorized {
+			/// The identifier of the identity.
+			identifier: T::Identifier,
+			/// The authorized account.
+			committer: T::AccountId,
+		},
+		/// The authorization of an account to commit an identity has been
+		/// revoked.
+		CommitterRevoked {
+			/// The identifier of the identity.
+			identifier: T::Identifier,
+			/// The account whose authorization was revoked.
+			committer: T::AccountId,
+		},
 	}
 
 	#[pallet::error]
@@ -133,6 +250,19 @@ pub mod pallet {
 		IdentityCommitmentGenerator(u16),
 		/// Error inside the external hook logic.
 		Hook(u16),
+		/// The account is already authorized to commit the identity.
+		CommitterAlreadyAuthorized,
+		/// The account is not authorized to commit the identity.
+		CommitterNotFound,
+		/// The maximum number of committers for the identity has been reached.
+		TooManyCommitters,
+		/// Committers can only refresh an existing commitment, keeping the
+		/// scope chosen by the subject.
+		CommitterScopeNotAllowed,
+		/// The tx submitter does not have enough funds to pay for the deposit.
+		InsufficientFunds,
+		/// The actor cannot perform the specified operation.
+		NotAuthorized,
 	}
 
 	#[pallet::call]
@@ -141,6 +271,12 @@ pub mod pallet {
 		/// the provided `Identifier`. If an old commitment for the same version
 		/// is present, it is overridden. Hooks are called before the new
 		/// commitment is stored, and optionally before the old one is replaced.
+		///
+		/// Besides the origins accepted by `CommitOriginCheck`, the call can
+		/// be dispatched by any account authorized via
+		/// [`Pallet::authorize_committer`] for the given `Identifier`.
+		/// Committers can only refresh an existing commitment, which keeps the
+		/// scope it was stored with.
 		#[pallet::call_index(0)]
 		#[pallet::weight({
 			<T as Config>::WeightInfo::commit_identity()
@@ -150,32 +286,15 @@ pub mod pallet {
 			identifier: T::Identifier,
 			version: Option<IdentityCommitmentVersion>,
 		) -> DispatchResult {
-			let dispatcher = T::CommitOriginCheck::ensure_origin(origin, &identifier)
-				.map(|e: <T as Config>::CommitOrigin| e.submitter())?;
-
+			let (dispatcher, is_committer) = Self::ensure_commit_origin(origin, &identifier)?;
 			let commitment_version = version.unwrap_or(LATEST_COMMITMENT_VERSION);
-			let identity = T::IdentityProvider::retrieve(&identifier)
-				.map_err(|error| Error::<T>::IdentityProvider(error.into()))?;
-			let commitment =
-				T::IdentityCommitmentGenerator::generate_commitment(&identifier, &identity, commitment_version)
-					.map_err(|error| Error::<T>::IdentityCommitmentGenerator(error.into()))?;
-
-			match Self::delete_identity_commitment_storage_entry(&identifier, &dispatcher, commitment_version) {
-				// Ignore if there was no previous commitment.
-				Ok(_) | Err(Error::<T>::CommitmentNotFound) => (),
-				// If a different error is returned, bubble it up.
-				Err(e) => return Err(e.into()),
+			let scope = if is_committer {
+				Self::ensure_committer_scope(&identifier, commitment_version, None)?
+			} else {
+				IdentityScope::Full
 			};
 
-			IdentityCommitments::<T>::insert(&identifier, commitment_version, commitment.clone());
-			// Call hooks for new commitment.
-			T::ProviderHooks::on_identity_committed(&identifier, &dispatcher, &commitment, commitment_version)
-				.map_err(|e| Error::<T>::Hook(e.into()))?;
-			Self::deposit_event(Event::<T>::VersionedIdentityCommitted {
-				identifier: identifier.clone(),
-				commitment,
-				version: commitment_version,
-			});
+			Self::do_commit_identity(&identifier, &dispatcher, commitment_version, scope)?;
 			Ok(())
 		}
 
@@ -199,9 +318,221 @@ pub mod pallet {
 			Self::delete_identity_commitment_storage_entry(&identifier, &dispatcher, commitment_version)?;
 			Ok(())
 		}
+
+		/// Same as [`Pallet::commit_identity`], but only the parts of the
+		/// identity selected by the provided `scope` are committed, so that
+		/// the excluded information can never be revealed cross-chain using
+		/// this commitment. The scope is stored alongside the commitment.
+		///
+		/// As for [`Pallet::commit_identity`], the call can also be dispatched
+		/// by an authorized committer, but only to refresh an existing
+		/// commitment with the same scope it was stored with.
+		#[pallet::call_index(2)]
+		#[pallet::weight({
+			<T as Config>::WeightInfo::commit_identity_scoped()
+		})]
+		pub fn commit_identity_scoped(
+			origin: OriginFor<T>,
+			identifier: T::Identifier,
+			version: Option<IdentityCommitmentVersion>,
+			scope: IdentityScope,
+		) -> DispatchResult {
+			let (dispatcher, is_committer) = Self::ensure_commit_origin(origin, &identifier)?;
+			let commitment_version = version.unwrap_or(LATEST_COMMITMENT_VERSION);
+			if is_committer {
+				Self::ensure_committer_scope(&identifier, commitment_version, Some(scope))?;
+			}
+
+			Self::do_commit_identity(&identifier, &dispatcher, commitment_version, scope)?;
+			Ok(())
+		}
+
+		/// Authorize the `committer` account to generate identity commitments
+		/// for the provided `Identifier` via [`Pallet::commit_identity`] and
+		/// [`Pallet::commit_identity_scoped`]. The committer pays for the
+		/// transaction fees and for the deposits of the commitments it
+		/// generates, but it cannot delete commitments.
+		///
+		/// A deposit of `CommitterDeposit` is held from the submitter of the
+		/// call for as long as the authorization exists.
+		#[pallet::call_index(3)]
+		#[pallet::weight({
+			<T as Config>::WeightInfo::authorize_committer()
+		})]
+		pub fn authorize_committer(
+			origin: OriginFor<T>,
+			identifier: T::Identifier,
+			committer: T::AccountId,
+		) -> DispatchResult {
+			let payer = T::CommitOriginCheck::ensure_origin(origin, &identifier)?.submitter();
+
+			Committers::<T>::try_mutate(&identifier, |committers| {
+				ensure!(
+					!committers.iter().any(|c| c.account == committer),
+					Error::<T>::CommitterAlreadyAuthorized
+				);
+				let amount = T::CommitterDeposit::get();
+				T::Currency::hold(&HoldReason::CommitterDeposit.into(), &payer, amount)
+					.map_err(|_| Error::<T>::InsufficientFunds)?;
+				committers
+					.try_push(CommitterOf::<T> {
+						account: committer.clone(),
+						deposit: Deposit { owner: payer, amount },
+					})
+					.map_err(|_| Error::<T>::TooManyCommitters)
+			})?;
+
+			Self::deposit_event(Event::<T>::CommitterAuthorized { identifier, committer });
+			Ok(())
+		}
+
+		/// Revoke the authorization of the `committer` account to generate
+		/// identity commitments for the provided `Identifier`, releasing the
+		/// deposit taken for it. Commitments previously generated by the
+		/// committer are not affected.
+		#[pallet::call_index(4)]
+		#[pallet::weight({
+			<T as Config>::WeightInfo::revoke_committer()
+		})]
+		pub fn revoke_committer(
+			origin: OriginFor<T>,
+			identifier: T::Identifier,
+			committer: T::AccountId,
+		) -> DispatchResult {
+			T::CommitOriginCheck::ensure_origin(origin, &identifier)?;
+
+			Self::remove_committer(identifier, committer, |_| Ok(()))
+		}
+
+		/// Revoke the authorization of the `committer` account to generate
+		/// identity commitments for the provided `Identifier`, releasing the
+		/// deposit taken for it, e.g., after the subject has been deleted.
+		///
+		/// The dispatch origin must be the account that paid for the deposit.
+		#[pallet::call_index(5)]
+		#[pallet::weight({
+			<T as Config>::WeightInfo::reclaim_committer_deposit()
+		})]
+		pub fn reclaim_committer_deposit(
+			origin: OriginFor<T>,
+			identifier: T::Identifier,
+			committer: T::AccountId,
+		) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
+
+			Self::remove_committer(identifier, committer, |entry| {
+				ensure!(entry.deposit.owner == caller, Error::<T>::NotAuthorized);
+				Ok(())
+			})
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
+		/// Returns the account submitting a commitment for `identifier`, if
+		/// `origin` passes `CommitOriginCheck` or is signed by an authorized
+		/// committer, and whether the account is a committer.
+		fn ensure_commit_origin(
+			origin: OriginFor<T>,
+			identifier: &T::Identifier,
+		) -> Result<(T::AccountId, bool), DispatchError> {
+			if let Ok(commit_origin) = T::CommitOriginCheck::ensure_origin(origin.clone(), identifier) {
+				return Ok((commit_origin.submitter(), false));
+			}
+			let committer = ensure_signed(origin)?;
+			ensure!(
+				Committers::<T>::get(identifier).iter().any(|c| c.account == committer),
+				DispatchError::BadOrigin
+			);
+			Ok((committer, true))
+		}
+
+		/// Returns the scope a committer must use to refresh the commitment of
+		/// the given version, i.e., the scope the commitment is stored with,
+		/// failing if there is no such commitment or if a different scope is
+		/// requested.
+		fn ensure_committer_scope(
+			identifier: &T::Identifier,
+			version: IdentityCommitmentVersion,
+			requested_scope: Option<IdentityScope>,
+		) -> Result<IdentityScope, Error<T>> {
+			ensure!(
+				IdentityCommitments::<T>::contains_key(identifier, version),
+				Error::<T>::CommitmentNotFound
+			);
+			let stored_scope = IdentityCommitmentScopes::<T>::get(identifier, version);
+			ensure!(
+				requested_scope.map_or(true, |scope| scope == stored_scope),
+				Error::<T>::CommitterScopeNotAllowed
+			);
+			Ok(stored_scope)
+		}
+
+		/// Removes the authorization of `committer` for `identifier` if `check`
+		/// passes for it, releasing its deposit.
+		fn remove_committer(
+			identifier: T::Identifier,
+			committer: T::AccountId,
+			check: impl FnOnce(&CommitterOf<T>) -> Result<(), Error<T>>,
+		) -> DispatchResult {
+			let entry = Committers::<T>::try_mutate_exists(&identifier, |maybe_committers| {
+				let committers = maybe_committers.as_mut().ok_or(Error::<T>::CommitterNotFound)?;
+				let position = committers
+					.iter()
+					.position(|c| c.account == committer)
+					.ok_or(Error::<T>::CommitterNotFound)?;
+				check(&committers[position])?;
+				let entry = committers.remove(position);
+				if committers.is_empty() {
+					*maybe_committers = None;
+				}
+				Ok::<_, Error<T>>(entry)
+			})?;
+			T::Currency::release(
+				&HoldReason::CommitterDeposit.into(),
+				&entry.deposit.owner,
+				entry.deposit.amount,
+				Precision::BestEffort,
+			)?;
+
+			Self::deposit_event(Event::<T>::CommitterRevoked { identifier, committer });
+			Ok(())
+		}
+
+		fn do_commit_identity(
+			identifier: &T::Identifier,
+			dispatcher: &T::AccountId,
+			version: IdentityCommitmentVersion,
+			scope: IdentityScope,
+		) -> Result<IdentityCommitmentOf<T>, Error<T>> {
+			let identity = T::IdentityProvider::retrieve(identifier)
+				.map_err(|error| Error::<T>::IdentityProvider(error.into()))?;
+			let commitment =
+				T::IdentityCommitmentGenerator::generate_scoped_commitment(identifier, &identity, version, scope)
+					.map_err(|error| Error::<T>::IdentityCommitmentGenerator(error.into()))?;
+
+			match Self::delete_identity_commitment_storage_entry(identifier, dispatcher, version) {
+				// Ignore if there was no previous commitment.
+				Ok(_) | Err(Error::<T>::CommitmentNotFound) => (),
+				// If a different error is returned, bubble it up.
+				Err(e) => return Err(e),
+			};
+
+			IdentityCommitments::<T>::insert(identifier, version, commitment.clone());
+			if scope != IdentityScope::Full {
+				IdentityCommitmentScopes::<T>::insert(identifier, version, scope);
+			}
+			CounterForIdentityCommitments::<T>::mutate(|count| *count = count.saturating_add(1));
+			// Call hooks for new commitment.
+			T::ProviderHooks::on_identity_committed(identifier, dispatcher, &commitment, version)
+				.map_err(|e| Error::<T>::Hook(e.into()))?;
+			Self::deposit_event(Event::<T>::VersionedIdentityCommitted {
+				identifier: identifier.clone(),
+				commitment: commitment.clone(),
+				version,
+			});
+			Ok(commitment)
+		}
+
 		pub fn delete_identity_commitment_storage_entry(
 			identifier: &T::Identifier,
 			dispatcher: &T::AccountId,
@@ -219,6 +550,8 @@ pub mod pallet {
 		) -> Result<IdentityCommitmentOf<T>, Error<T>> {
 			let commitment =
 				IdentityCommitments::<T>::take(identifier, version).ok_or(Error::<T>::CommitmentNotFound)?;
+			IdentityCommitmentScopes::<T>::remove(identifier, version);
+			CounterForIdentityCommitments::<T>::mutate(|count| *count = count.saturating_sub(1));
 			Self::deposit_event(Event::<T>::VersionedIdentityDeleted {
 				identifier: identifier.clone(),
 				version,
@@ -0,0 +1,94 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use frame_support::weights::Weight;
+use kilt_support::migration::SteppedMigration;
+use sp_std::{marker::PhantomData, vec::Vec};
+
+use crate::{Config, CounterForIdentityCommitments, IdentityCommitments, Pallet, WeightInfo};
+
+/// The storage version which initialized the counter of the identity
+/// commitments.
+pub(crate) const IDENTITY_COMMITMENTS_COUNTER_STORAGE_VERSION: u16 = 1;
+
+/// Initializes the counter of the identity commitments, which were stored
+/// before they were counted, by counting one commitment per step.
+///
+/// The calls of the pallet are blocked until the migration has completed, so
+/// that no commitment is counted twice or missed.
+pub struct InitializeIdentityCommitmentsCounter<T>(PhantomData<T>);
+
+impl<T: Config> SteppedMigration for InitializeIdentityCommitmentsCounter<T> {
+	type Pallet = Pallet<T>;
+
+	const FROM: u16 = 0;
+	const TO: u16 = IDENTITY_COMMITMENTS_COUNTER_STORAGE_VERSION;
+
+	fn max_step_weight() -> Weight {
+		<T as Config>::WeightInfo::initialize_identity_commitments_counter_step()
+	}
+
+	fn step(cursor: Option<Vec<u8>>) -> Option<Vec<u8>> {
+		let (identifier, version) = match cursor {
+			Some(cursor) => IdentityCommitments::<T>::iter_keys_from(cursor).next(),
+			None => IdentityCommitments::<T>::iter_keys().next(),
+		}?;
+		CounterForIdentityCommitments::<T>::mutate(|count| *count = count.saturating_add(1));
+		Some(IdentityCommitments::<T>::hashed_key_for(&identifier, version))
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use frame_support::traits::GetStorageVersion;
+	use kilt_support::migration::SteppedMigration;
+
+	use crate::{migrations::InitializeIdentityCommitmentsCounter, mock::*, CounterForIdentityCommitments, Pallet};
+
+	#[test]
+	fn test_stepped_migration_bumps_one_version() {
+		assert_eq!(
+			<InitializeIdentityCommitmentsCounter<TestRuntime> as SteppedMigration>::TO,
+			<InitializeIdentityCommitmentsCounter<TestRuntime> as SteppedMigration>::FROM + 1
+		);
+		assert_eq!(
+			Pallet::<TestRuntime>::current_storage_version(),
+			<InitializeIdentityCommitmentsCounter<TestRuntime> as SteppedMigration>::TO
+		);
+	}
+
+	#[test]
+	fn test_initialize_identity_commitments_counter() {
+		ExtBuilder::default()
+			.with_commitments(vec![(DID, 0, u32::MAX), (DID, 1, u32::MAX - 1)])
+			.build()
+			.execute_with(|| {
+				// The commitments were stored before they were counted.
+				CounterForIdentityCommitments::<TestRuntime>::kill();
+
+				let mut cursor = None;
+				for counted in 1..=2 {
+					cursor = InitializeIdentityCommitmentsCounter::<TestRuntime>::step(cursor);
+					assert!(cursor.is_some());
+					assert_eq!(DipProvider::identity_commitments_count(), counted);
+				}
+				assert!(InitializeIdentityCommitmentsCounter::<TestRuntime>::step(cursor).is_none());
+				assert_eq!(DipProvider::identity_commitments_count(), 2);
+			});
+	}
+}
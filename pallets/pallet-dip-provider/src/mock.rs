@@ -23,7 +23,7 @@ use frame_support::{
 		traits::{BlakeTwo256, IdentityLookup},
 		AccountId32,
 	},
-	traits::{ConstU16, ConstU32, ConstU64, Everything},
+	traits::{ConstU128, ConstU16, ConstU32, ConstU64, Currency, Everything},
 };
 
 use frame_system::mocking::MockBlock;
@@ -37,13 +37,14 @@ use crate::{
 construct_runtime!(
 	pub struct TestRuntime {
 		System: frame_system,
+		Balances: pallet_balances,
 		DipProvider: crate,
 		MockOrigin: mock_origin,
 	}
 );
 
 impl frame_system::Config for TestRuntime {
-	type AccountData = ();
+	type AccountData = pallet_balances::AccountData<Balance>;
 	type AccountId = AccountId32;
 	type BaseCallFilter = Everything;
 	type Block = MockBlock<TestRuntime>;
@@ -68,14 +69,36 @@ impl frame_system::Config for TestRuntime {
 	type Version = ();
 }
 
+pub(crate) type Balance = u128;
+
+impl pallet_balances::Config for TestRuntime {
+	type AccountStore = System;
+	type Balance = Balance;
+	type DustRemoval = ();
+	type ExistentialDeposit = ConstU128<1>;
+	type FreezeIdentifier = RuntimeFreezeReason;
+	type MaxFreezes = ConstU32<50>;
+	type MaxHolds = ConstU32<50>;
+	type MaxLocks = ConstU32<50>;
+	type MaxReserves = ConstU32<50>;
+	type ReserveIdentifier = [u8; 8];
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type WeightInfo = ();
+}
+
 impl crate::Config for TestRuntime {
 	type CommitOrigin = DoubleOrigin<Self::AccountId, Self::Identifier>;
 	type CommitOriginCheck = EnsureDoubleOrigin<Self::AccountId, Self::Identifier>;
+	type CommitterDeposit = ConstU128<10>;
+	type Currency = Balances;
 	type Identifier = AccountId32;
 	type IdentityCommitmentGenerator = DefaultIdentityCommitmentGenerator<u32>;
 	type IdentityProvider = DefaultIdentityProvider<u32>;
+	type MaxCommitters = ConstU32<2>;
 	type ProviderHooks = ();
 	type RuntimeEvent = RuntimeEvent;
+	type RuntimeHoldReason = RuntimeHoldReason;
 	type WeightInfo = ();
 }
 
@@ -110,6 +133,7 @@ pub(crate) struct ExtBuilder(
 		IdentityCommitmentVersion,
 		IdentityCommitmentOf<TestRuntime>,
 	)>,
+	Vec<(AccountId32, Balance)>,
 );
 
 impl ExtBuilder {
@@ -125,12 +149,21 @@ impl ExtBuilder {
 		self
 	}
 
+	pub(crate) fn with_balances(mut self, balances: Vec<(AccountId32, Balance)>) -> Self {
+		self.1 = balances;
+		self
+	}
+
 	pub(crate) fn build(self) -> sp_io::TestExternalities {
 		let mut ext = sp_io::TestExternalities::default();
 
 		ext.execute_with(|| {
+			for (account, balance) in self.1 {
+				Balances::make_free_balance_be(&account, balance);
+			}
 			for (subject, commitment_version, commitment) in self.0 {
 				crate::pallet::IdentityCommitments::<TestRuntime>::insert(subject, commitment_version, commitment);
+				crate::pallet::CounterForIdentityCommitments::<TestRuntime>::mutate(|count| *count += 1);
 			}
 		});
 
@@ -52,6 +52,7 @@ fn commit_identity_multiple_commitments_for_same_subject() {
 			DipProvider::identity_commitments(&DID, 1),
 			Some(expected_identity_commitment)
 		);
+		assert_eq!(DipProvider::identity_commitments_count(), 2);
 	});
 }
 
@@ -75,5 +76,7 @@ fn commit_identity_override_same_version_commitment() {
 				DipProvider::identity_commitments(&DID, 0),
 				Some(expected_identity_commitment)
 			);
+			// Overriding a commitment does not change the number of stored commitments.
+			assert_eq!(DipProvider::identity_commitments_count(), 1);
 		});
 }
@@ -0,0 +1,101 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use frame_support::assert_ok;
+use kilt_support::mock::mock_origin::DoubleOrigin;
+
+use crate::{mock::*, IdentityScope};
+
+#[test]
+fn commit_identity_scoped_stores_scope() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(DipProvider::commit_identity_scoped(
+			DoubleOrigin(ACCOUNT_ID, DID).into(),
+			DID,
+			Some(0),
+			IdentityScope::KeysOnly,
+		));
+		assert_eq!(
+			DipProvider::identity_commitments(&DID, 0),
+			Some(get_expected_commitment_for(&DID, 0))
+		);
+		assert_eq!(
+			DipProvider::identity_commitment_scopes(&DID, 0),
+			IdentityScope::KeysOnly
+		);
+		assert_eq!(DipProvider::identity_commitments_count(), 1);
+	});
+}
+
+#[test]
+fn commit_identity_scoped_full_scope_is_not_stored() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(DipProvider::commit_identity_scoped(
+			DoubleOrigin(ACCOUNT_ID, DID).into(),
+			DID,
+			Some(0),
+			IdentityScope::Full,
+		));
+		assert!(!crate::pallet::IdentityCommitmentScopes::<TestRuntime>::contains_key(
+			&DID, 0
+		));
+		assert_eq!(DipProvider::identity_commitment_scopes(&DID, 0), IdentityScope::Full);
+	});
+}
+
+#[test]
+fn commit_identity_resets_previous_scope() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(DipProvider::commit_identity_scoped(
+			DoubleOrigin(ACCOUNT_ID, DID).into(),
+			DID,
+			Some(0),
+			IdentityScope::KeysAndWeb3Name,
+		));
+		assert_ok!(DipProvider::commit_identity(
+			DoubleOrigin(ACCOUNT_ID, DID).into(),
+			DID,
+			Some(0),
+		));
+		assert!(!crate::pallet::IdentityCommitmentScopes::<TestRuntime>::contains_key(
+			&DID, 0
+		));
+		assert_eq!(DipProvider::identity_commitments_count(), 1);
+	});
+}
+
+#[test]
+fn delete_identity_commitment_removes_scope() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(DipProvider::commit_identity_scoped(
+			DoubleOrigin(ACCOUNT_ID, DID).into(),
+			DID,
+			Some(0),
+			IdentityScope::KeysOnly,
+		));
+		assert_ok!(DipProvider::delete_identity_commitment(
+			DoubleOrigin(ACCOUNT_ID, DID).into(),
+			DID,
+			Some(0),
+		));
+		assert!(!crate::pallet::IdentityCommitmentScopes::<TestRuntime>::contains_key(
+			&DID, 0
+		));
+		assert_eq!(DipProvider::identity_commitments_count(), 0);
+	});
+}
@@ -0,0 +1,233 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use frame_support::{assert_noop, assert_ok, traits::fungible::InspectHold};
+use kilt_support::mock::mock_origin::DoubleOrigin;
+use sp_runtime::{traits::Zero, AccountId32, DispatchError};
+
+use crate::{mock::*, HoldReason, IdentityScope};
+
+const COMMITTER: AccountId32 = AccountId32::new([1u8; 32]);
+
+fn held(account: &AccountId32) -> Balance {
+	Balances::balance_on_hold(&HoldReason::CommitterDeposit.into(), account)
+}
+
+#[test]
+fn authorize_committer_successful() {
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_ID, 100)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(DipProvider::authorize_committer(
+				DoubleOrigin(ACCOUNT_ID, DID).into(),
+				DID,
+				COMMITTER,
+			));
+			let committers = DipProvider::committers(&DID).into_inner();
+			assert_eq!(committers.len(), 1);
+			assert_eq!(committers[0].account, COMMITTER);
+			assert_eq!(committers[0].deposit.owner, ACCOUNT_ID);
+			assert_eq!(held(&ACCOUNT_ID), 10);
+
+			assert_noop!(
+				DipProvider::authorize_committer(DoubleOrigin(ACCOUNT_ID, DID).into(), DID, COMMITTER),
+				crate::Error::<TestRuntime>::CommitterAlreadyAuthorized
+			);
+		});
+}
+
+#[test]
+fn authorize_committer_too_many() {
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_ID, 100)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(DipProvider::authorize_committer(
+				DoubleOrigin(ACCOUNT_ID, DID).into(),
+				DID,
+				AccountId32::new([1u8; 32]),
+			));
+			assert_ok!(DipProvider::authorize_committer(
+				DoubleOrigin(ACCOUNT_ID, DID).into(),
+				DID,
+				AccountId32::new([2u8; 32]),
+			));
+			assert_noop!(
+				DipProvider::authorize_committer(
+					DoubleOrigin(ACCOUNT_ID, DID).into(),
+					DID,
+					AccountId32::new([3u8; 32])
+				),
+				crate::Error::<TestRuntime>::TooManyCommitters
+			);
+		});
+}
+
+#[test]
+fn commit_identity_by_committer() {
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_ID, 100)])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				DipProvider::commit_identity(RuntimeOrigin::signed(COMMITTER), DID, Some(0)),
+				DispatchError::BadOrigin
+			);
+
+			assert_ok!(DipProvider::authorize_committer(
+				DoubleOrigin(ACCOUNT_ID, DID).into(),
+				DID,
+				COMMITTER,
+			));
+			// Committers can only refresh existing commitments.
+			assert_noop!(
+				DipProvider::commit_identity(RuntimeOrigin::signed(COMMITTER), DID, Some(0)),
+				crate::Error::<TestRuntime>::CommitmentNotFound
+			);
+
+			assert_ok!(DipProvider::commit_identity_scoped(
+				DoubleOrigin(ACCOUNT_ID, DID).into(),
+				DID,
+				Some(0),
+				IdentityScope::KeysOnly,
+			));
+			assert_ok!(DipProvider::commit_identity(
+				RuntimeOrigin::signed(COMMITTER),
+				DID,
+				Some(0)
+			));
+			assert_eq!(
+				DipProvider::identity_commitments(&DID, 0),
+				Some(get_expected_commitment_for(&DID, 0))
+			);
+			// The scope chosen by the subject is kept.
+			assert_eq!(
+				DipProvider::identity_commitment_scopes(&DID, 0),
+				IdentityScope::KeysOnly
+			);
+			assert_noop!(
+				DipProvider::commit_identity_scoped(
+					RuntimeOrigin::signed(COMMITTER),
+					DID,
+					Some(0),
+					IdentityScope::Full
+				),
+				crate::Error::<TestRuntime>::CommitterScopeNotAllowed
+			);
+			assert_ok!(DipProvider::commit_identity_scoped(
+				RuntimeOrigin::signed(COMMITTER),
+				DID,
+				Some(0),
+				IdentityScope::KeysOnly,
+			));
+
+			// The authorization is only valid for the subject that granted it.
+			assert_noop!(
+				DipProvider::commit_identity(RuntimeOrigin::signed(COMMITTER), ACCOUNT_ID, Some(0)),
+				DispatchError::BadOrigin
+			);
+			// Committers cannot delete commitments.
+			assert_noop!(
+				DipProvider::delete_identity_commitment(RuntimeOrigin::signed(COMMITTER), DID, Some(0)),
+				DispatchError::BadOrigin
+			);
+		});
+}
+
+#[test]
+fn revoke_committer_successful() {
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_ID, 100)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(DipProvider::authorize_committer(
+				DoubleOrigin(ACCOUNT_ID, DID).into(),
+				DID,
+				COMMITTER,
+			));
+			assert_ok!(DipProvider::revoke_committer(
+				DoubleOrigin(ACCOUNT_ID, DID).into(),
+				DID,
+				COMMITTER,
+			));
+			assert!(!crate::pallet::Committers::<TestRuntime>::contains_key(&DID));
+			assert!(held(&ACCOUNT_ID).is_zero());
+
+			assert_noop!(
+				DipProvider::commit_identity(RuntimeOrigin::signed(COMMITTER), DID, Some(0)),
+				DispatchError::BadOrigin
+			);
+		});
+}
+
+#[test]
+fn revoke_committer_not_found() {
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_ID, 100)])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				DipProvider::revoke_committer(DoubleOrigin(ACCOUNT_ID, DID).into(), DID, COMMITTER),
+				crate::Error::<TestRuntime>::CommitterNotFound
+			);
+		});
+}
+
+#[test]
+fn authorize_committer_insufficient_funds() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			DipProvider::authorize_committer(DoubleOrigin(ACCOUNT_ID, DID).into(), DID, COMMITTER),
+			crate::Error::<TestRuntime>::InsufficientFunds
+		);
+	});
+}
+
+#[test]
+fn reclaim_committer_deposit_successful() {
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_ID, 100)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(DipProvider::authorize_committer(
+				DoubleOrigin(ACCOUNT_ID, DID).into(),
+				DID,
+				COMMITTER,
+			));
+
+			// Only the deposit owner can reclaim the deposit.
+			assert_noop!(
+				DipProvider::reclaim_committer_deposit(RuntimeOrigin::signed(COMMITTER), DID, COMMITTER),
+				crate::Error::<TestRuntime>::NotAuthorized
+			);
+
+			assert_ok!(DipProvider::reclaim_committer_deposit(
+				RuntimeOrigin::signed(ACCOUNT_ID),
+				DID,
+				COMMITTER
+			));
+			assert!(!crate::pallet::Committers::<TestRuntime>::contains_key(&DID));
+			assert!(held(&ACCOUNT_ID).is_zero());
+
+			assert_noop!(
+				DipProvider::reclaim_committer_deposit(RuntimeOrigin::signed(ACCOUNT_ID), DID, COMMITTER),
+				crate::Error::<TestRuntime>::CommitterNotFound
+			);
+		});
+}
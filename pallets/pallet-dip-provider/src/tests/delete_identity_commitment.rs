@@ -36,6 +36,7 @@ fn delete_identity_commitment_multiple_versions() {
 				crate::pallet::IdentityCommitments::<TestRuntime>::iter_key_prefix(&DID).count(),
 				1
 			);
+			assert_eq!(DipProvider::identity_commitments_count(), 1);
 			assert_ok!(DipProvider::delete_identity_commitment(
 				DoubleOrigin(ACCOUNT_ID, DID).into(),
 				DID,
@@ -45,6 +46,7 @@ fn delete_identity_commitment_multiple_versions() {
 				crate::pallet::IdentityCommitments::<TestRuntime>::iter_key_prefix(&DID).count(),
 				0
 			);
+			assert_eq!(DipProvider::identity_commitments_count(), 0);
 		});
 }
 
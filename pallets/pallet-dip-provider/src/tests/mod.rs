@@ -17,4 +17,6 @@
 // If you feel like getting in touch with us, you can do so at info@botlabs.org
 
 mod commit_identity;
+mod commit_identity_scoped;
+mod committers;
 mod delete_identity_commitment;
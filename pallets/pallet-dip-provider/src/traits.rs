@@ -19,7 +19,7 @@
 use did::DidRawOrigin;
 use frame_support::sp_runtime::AccountId32;
 
-use crate::{Config, IdentityCommitmentOf, IdentityCommitmentVersion};
+use crate::{Config, IdentityCommitmentOf, IdentityCommitmentVersion, IdentityScope};
 
 pub use identity_provision::*;
 pub mod identity_provision {
@@ -87,6 +87,24 @@ pub mod identity_generation {
 			identity: &IdentityOf<Runtime>,
 			version: IdentityCommitmentVersion,
 		) -> Result<Self::Output, Self::Error>;
+
+		/// Return the identity commitment for the given version and identity
+		/// information, only including the parts of the identity selected by
+		/// the provided scope. Committing with [`IdentityScope::Full`] must
+		/// return the same value as [`Self::generate_commitment`].
+		fn generate_scoped_commitment(
+			identifier: &Runtime::Identifier,
+			identity: &IdentityOf<Runtime>,
+			version: IdentityCommitmentVersion,
+			scope: IdentityScope,
+		) -> Result<Self::Output, Self::Error>;
+
+		/// Return the supported commitment version that is the most expensive
+		/// to generate, which is used to benchmark identity commitments.
+		#[cfg(feature = "runtime-benchmarks")]
+		fn worst_case_version() -> IdentityCommitmentVersion {
+			crate::LATEST_COMMITMENT_VERSION
+		}
 	}
 
 	/// Implement the [`IdentityCommitmentGenerator`] trait by returning the
@@ -108,6 +126,15 @@ pub mod identity_generation {
 		) -> Result<Self::Output, Self::Error> {
 			Ok(Output::default())
 		}
+
+		fn generate_scoped_commitment(
+			_identifier: &Runtime::Identifier,
+			_identity: &IdentityOf<Runtime>,
+			_version: IdentityCommitmentVersion,
+			_scope: IdentityScope,
+		) -> Result<Self::Output, Self::Error> {
+			Ok(Output::default())
+		}
 	}
 }
 
@@ -105,6 +105,8 @@ benchmarks! {
 				owner: sender.clone(),
 				amount: MICROKILT.saturated_into(),
 			},
+			cid: None,
+			claimer_acknowledged: false,
 		};
 
 		pallet_balances::Pallet::<T>::set_balance(&sender, KILT.saturated_into());
@@ -43,23 +43,6 @@ pub trait WeightInfo {
 /// Weights for pallet_migration using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
-	/// Storage: Migration MigratedKeys (r:1 w:1)
-	/// Proof: Migration MigratedKeys (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
-	/// Storage: Attestation Attestations (r:1 w:0)
-	/// Proof: Attestation Attestations (max_values: None, max_size: Some(195), added: 2670, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	/// Storage: Balances Holds (r:1 w:1)
-	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
-	fn attestation_migration_weight() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `846`
-		//  Estimated: `4414`
-		// Minimum execution time: 69_529 nanoseconds.
-		Weight::from_parts(70_605_000, 4414)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
-	}
 	/// Storage: Migration MigratedKeys (r:1 w:0)
 	/// Proof: Migration MigratedKeys (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
 	fn delegation_migration_weight() -> Weight {
@@ -117,27 +100,25 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(22_874_000, 3513)
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 	}
-}
-
-// For backwards compatibility and tests
-impl WeightInfo for () {
+	// The weights below are not taken from the benchmark run above. They are
+	// estimated from the storage accesses of each call.
 	/// Storage: Migration MigratedKeys (r:1 w:1)
 	/// Proof: Migration MigratedKeys (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
 	/// Storage: Attestation Attestations (r:1 w:0)
-	/// Proof: Attestation Attestations (max_values: None, max_size: Some(195), added: 2670, mode: MaxEncodedLen)
+	/// Proof: Attestation Attestations (max_values: None, max_size: Some(263), added: 2738, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: Balances Holds (r:1 w:1)
 	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
 	fn attestation_migration_weight() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `846`
-		//  Estimated: `4414`
-		// Minimum execution time: 69_529 nanoseconds.
-		Weight::from_parts(70_605_000, 4414)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+		Weight::from_parts(70_605_000, 4482)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
 	/// Storage: Migration MigratedKeys (r:1 w:0)
 	/// Proof: Migration MigratedKeys (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
 	fn delegation_migration_weight() -> Weight {
@@ -195,4 +176,19 @@ impl WeightInfo for () {
 		Weight::from_parts(22_874_000, 3513)
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 	}
+	// The weights below are not taken from the benchmark run above. They are
+	// estimated from the storage accesses of each call.
+	/// Storage: Migration MigratedKeys (r:1 w:1)
+	/// Proof: Migration MigratedKeys (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Attestation Attestations (r:1 w:0)
+	/// Proof: Attestation Attestations (max_values: None, max_size: Some(263), added: 2738, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
+	fn attestation_migration_weight() -> Weight {
+		Weight::from_parts(70_605_000, 4482)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
 }
@@ -29,6 +29,32 @@ mod test;
 
 pub use crate::{default_weights::WeightInfo, pallet::*};
 
+use frame_support::traits::{Contains, GetCallMetadata};
+use kilt_support::migration::MigratedStorageKeys;
+use sp_std::marker::PhantomData;
+
+/// A call filter which blocks the calls accessing storage of a stepped
+/// migration which has not been migrated yet.
+///
+/// The storage keys accessed by a call are provided by `Keys`. Calls whose keys
+/// cannot be determined are blocked until all stepped migrations of their
+/// pallet have completed.
+pub struct BlockCallsDuringMigration<T, Keys = ()>(PhantomData<(T, Keys)>);
+
+impl<T: Config, Keys: MigratedStorageKeys<Call>, Call: GetCallMetadata> Contains<Call>
+	for BlockCallsDuringMigration<T, Keys>
+{
+	fn contains(call: &Call) -> bool {
+		let pallet_name = call.get_call_metadata().pallet_name;
+		match Keys::migrated_storage_keys(call) {
+			Some(keys) => !keys
+				.iter()
+				.any(|key| Pallet::<T>::is_key_pending_migration(pallet_name, key)),
+			None => !Pallet::<T>::is_pallet_migrating(pallet_name),
+		}
+	}
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -39,12 +65,16 @@ pub mod pallet {
 		traits::{fungible::Inspect, Currency, ReservableCurrency},
 	};
 	use frame_system::pallet_prelude::*;
-	use sp_runtime::{traits::Hash, SaturatedConversion};
+	use sp_runtime::{
+		traits::{Hash, Saturating},
+		SaturatedConversion,
+	};
+	use sp_std::vec::Vec;
 
 	use attestation::{Attestations, ClaimHashOf};
 	use delegation::{DelegationNodeIdOf, DelegationNodes};
 	use did::{Did, DidIdentifierOf};
-	use kilt_support::traits::BalanceMigrationManager;
+	use kilt_support::{migration::SteppedMigrations, traits::BalanceMigrationManager};
 	use pallet_did_lookup::{linkable_account::LinkableAccountId, ConnectedDids};
 	use pallet_web3_names::{Owner, Web3NameOf};
 	use public_credentials::{CredentialIdOf, Credentials, SubjectIdOf};
@@ -73,6 +103,20 @@ pub mod pallet {
 		pub public_credentials: BoundedVec<(SubjectIdOf<T>, CredentialIdOf<T>), <T as Config>::MaxMigrationsPerPallet>,
 	}
 
+	/// The progress of the stepped migrations.
+	#[derive(
+		Encode, Decode, TypeInfo, MaxEncodedLen, CloneNoBound, PartialEqNoBound, EqNoBound, RuntimeDebugNoBound,
+	)]
+	#[scale_info(skip_type_params(MaxCursorLength))]
+	pub struct SteppedMigrationCursor<MaxCursorLength: Get<u32>> {
+		/// The index of the migration which is currently executed.
+		pub index: u32,
+		/// The cursor of the migration, if it has already executed a step.
+		pub inner: Option<BoundedVec<u8, MaxCursorLength>>,
+	}
+
+	pub type SteppedMigrationCursorOf<T> = SteppedMigrationCursor<<T as Config>::MaxMigrationCursorLength>;
+
 	#[pallet::config]
 	pub trait Config:
 		frame_system::Config
@@ -95,6 +139,18 @@ pub mod pallet {
 
 		/// The currency module that takes care to release reserves
 		type Currency: ReservableCurrency<AccountIdOf<Self>>;
+
+		/// The migrations which are executed step by step over multiple blocks
+		/// after a runtime upgrade.
+		type SteppedMigrations: SteppedMigrations;
+
+		/// The maximum weight the stepped migrations can consume per block.
+		#[pallet::constant]
+		type MaxSteppedMigrationsWeight: Get<Weight>;
+
+		/// The maximum length of the cursor of a stepped migration.
+		#[pallet::constant]
+		type MaxMigrationCursorLength: Get<u32>;
 	}
 
 	#[pallet::error]
@@ -112,14 +168,56 @@ pub mod pallet {
 	#[pallet::getter(fn connected_dids)]
 	pub type MigratedKeys<T> = StorageMap<_, Blake2_128Concat, HashOf<T>, ()>;
 
+	/// The progress of the stepped migrations. Empty if no stepped migration is
+	/// in progress.
+	#[pallet::storage]
+	pub type SteppedMigrationProgress<T> = StorageValue<_, SteppedMigrationCursorOf<T>>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
 		EntriesUpdated(EntriesToMigrate<T>),
+		/// The stepped migration of a pallet has started.
+		SteppedMigrationStarted {
+			index: u32,
+			pallet: Vec<u8>,
+		},
+		/// Steps of the stepped migrations have been executed in this block.
+		SteppedMigrationAdvanced {
+			index: u32,
+			steps: u32,
+		},
+		/// The stepped migration of a pallet has completed and the pallet has
+		/// been set to the new storage version.
+		PalletMigrated {
+			index: u32,
+			pallet: Vec<u8>,
+			version: u16,
+		},
+		/// The stepped migration of a pallet could not be completed and was
+		/// skipped.
+		SteppedMigrationFailed {
+			index: u32,
+			pallet: Vec<u8>,
+		},
+		/// All stepped migrations have been executed.
+		SteppedMigrationsCompleted,
 	}
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_runtime_upgrade() -> Weight {
+			// Stepped migrations which are still in progress are continued.
+			if T::SteppedMigrations::len() > 0 && !SteppedMigrationProgress::<T>::exists() {
+				SteppedMigrationProgress::<T>::put(SteppedMigrationCursor { index: 0, inner: None });
+			}
+			T::DbWeight::get().reads_writes(1, 1)
+		}
+
+		fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+			Self::execute_stepped_migrations(T::MaxSteppedMigrationsWeight::get())
+		}
+	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T>
@@ -249,6 +347,122 @@ pub mod pallet {
 			let key_hash = <T as frame_system::Config>::Hashing::hash(key);
 			MigratedKeys::<T>::contains_key(key_hash)
 		}
+
+		/// Whether the pallet with the given name has a stepped migration which
+		/// has not completed yet.
+		pub fn is_pallet_migrating(pallet_name: &str) -> bool {
+			SteppedMigrationProgress::<T>::get().map_or(false, |progress| {
+				(progress.index..T::SteppedMigrations::len())
+					.any(|index| T::SteppedMigrations::pallet_name(index) == Some(pallet_name))
+			})
+		}
+
+		/// Whether the storage key of the pallet with the given name still has
+		/// to be migrated by a stepped migration.
+		///
+		/// Stepped migrations iterate over the storage in key order, so the keys
+		/// up to and including the cursor of the current migration have already
+		/// been migrated by it.
+		pub fn is_key_pending_migration(pallet_name: &str, key: &[u8]) -> bool {
+			SteppedMigrationProgress::<T>::get().map_or(false, |progress| {
+				(progress.index..T::SteppedMigrations::len())
+					.filter(|index| T::SteppedMigrations::pallet_name(*index) == Some(pallet_name))
+					.any(|index| match (index == progress.index, &progress.inner) {
+						(true, Some(cursor)) => key > cursor.as_slice(),
+						_ => true,
+					})
+			})
+		}
+
+		/// Execute as many steps of the stepped migrations as fit into the
+		/// weight limit, and return the consumed weight.
+		pub(crate) fn execute_stepped_migrations(limit: Weight) -> Weight {
+			let mut consumed = T::DbWeight::get().reads(1);
+			let Some(mut progress) = SteppedMigrationProgress::<T>::get() else {
+				return consumed;
+			};
+
+			let mut steps = 0u32;
+			loop {
+				let index = progress.index;
+				let Some(pallet) = T::SteppedMigrations::pallet_name(index) else {
+					SteppedMigrationProgress::<T>::kill();
+					Self::deposit_event(Event::SteppedMigrationsCompleted);
+					return consumed.saturating_add(T::DbWeight::get().writes(1));
+				};
+				let pallet_bytes = pallet.as_bytes().to_vec();
+
+				if progress.inner.is_none() {
+					consumed.saturating_accrue(T::DbWeight::get().reads(1));
+					if !T::SteppedMigrations::should_run(index) {
+						progress.index.saturating_inc();
+						continue;
+					}
+				}
+
+				let step_weight = T::SteppedMigrations::max_step_weight(index).unwrap_or_default();
+				// A step which can never be executed within the limit would block all
+				// following migrations.
+				if step_weight.any_gt(limit) {
+					Self::deposit_event(Event::SteppedMigrationFailed {
+						index,
+						pallet: pallet_bytes,
+					});
+					progress = SteppedMigrationCursor {
+						index: index.saturating_add(1),
+						inner: None,
+					};
+					continue;
+				}
+				if consumed.saturating_add(step_weight).any_gt(limit) {
+					break;
+				}
+
+				if progress.inner.is_none() {
+					Self::deposit_event(Event::SteppedMigrationStarted {
+						index,
+						pallet: pallet_bytes.clone(),
+					});
+				}
+
+				let cursor = progress.inner.take().map(BoundedVec::into_inner);
+				consumed.saturating_accrue(step_weight);
+				steps.saturating_inc();
+
+				match T::SteppedMigrations::step(index, cursor).flatten() {
+					Some(next_cursor) => {
+						if let Ok(next_cursor) = BoundedVec::try_from(next_cursor) {
+							progress.inner = Some(next_cursor);
+						} else {
+							Self::deposit_event(Event::SteppedMigrationFailed {
+								index,
+								pallet: pallet_bytes,
+							});
+							progress.index.saturating_inc();
+						}
+					}
+					None => {
+						T::SteppedMigrations::complete(index);
+						consumed.saturating_accrue(T::DbWeight::get().writes(1));
+						Self::deposit_event(Event::PalletMigrated {
+							index,
+							pallet: pallet_bytes,
+							version: T::SteppedMigrations::target_version(index).unwrap_or_default(),
+						});
+						progress.index.saturating_inc();
+					}
+				}
+			}
+
+			if steps > 0 {
+				Self::deposit_event(Event::SteppedMigrationAdvanced {
+					index: progress.index,
+					steps,
+				});
+			}
+			SteppedMigrationProgress::<T>::put(progress);
+			consumed.saturating_add(T::DbWeight::get().writes(1))
+		}
 	}
 
 	impl<T: Config> BalanceMigrationManager<AccountIdOf<T>, BalanceOf<T>> for Pallet<T> {
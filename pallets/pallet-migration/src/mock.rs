@@ -45,10 +45,11 @@ pub mod runtime {
 	use frame_support::{
 		ord_parameter_types, parameter_types,
 		traits::fungible::{Inspect, MutateHold},
-		weights::constants::RocksDbWeight,
+		weights::{constants::RocksDbWeight, Weight},
 	};
 	use frame_system::{EnsureRoot, EnsureSigned, EnsureSignedBy};
 	use kilt_support::{
+		migration::MigratedStorageKeys,
 		mock::{mock_origin, SubjectId},
 		signature::EqualVerify,
 	};
@@ -95,8 +96,21 @@ pub mod runtime {
 		}
 	);
 
+	pub struct SteppedMigrationKeys;
+
+	impl MigratedStorageKeys<RuntimeCall> for SteppedMigrationKeys {
+		fn migrated_storage_keys(call: &RuntimeCall) -> Option<Vec<Vec<u8>>> {
+			match call {
+				RuntimeCall::Did(call) => did::migrations::migrated_storage_keys::<Test>(call),
+				RuntimeCall::Attestation(call) => attestation::migrations::migrated_storage_keys::<Test>(call),
+				_ => None,
+			}
+		}
+	}
+
 	parameter_types! {
 		pub const MaxMigrationsPerPallet: u8 = 42;
+		pub const MaxSteppedMigrationsWeight: Weight = Weight::from_parts(1_000_000_000_000, 1_000_000);
 	}
 
 	impl Config for Test {
@@ -104,6 +118,17 @@ pub mod runtime {
 		type RuntimeEvent = ();
 		type WeightInfo = ();
 		type Currency = Balances;
+		type SteppedMigrations = (
+			did::migrations::BackfillKeyHistory<Test>,
+			did::migrations::RepriceDidDeposits<Test>,
+			did::migrations::InitializeDidCounter<Test>,
+			attestation::migrations::AddAttestationCid<Test>,
+			attestation::migrations::PopulateAttestationsByDelegation<Test>,
+			attestation::migrations::AddClaimerAcknowledgement<Test>,
+			attestation::migrations::InitializeAttestationCounter<Test>,
+		);
+		type MaxSteppedMigrationsWeight = MaxSteppedMigrationsWeight;
+		type MaxMigrationCursorLength = ConstU32<128>;
 	}
 
 	parameter_types! {
@@ -139,6 +164,7 @@ pub mod runtime {
 
 	parameter_types! {
 		pub const MaxDelegatedAttestations: u32 = 1000;
+		pub const MaxCidLength: u32 = 64;
 		pub const Deposit: Balance = MICRO_KILT;
 	}
 
@@ -150,11 +176,19 @@ pub mod runtime {
 		type RuntimeHoldReason = RuntimeHoldReason;
 		type Currency = Balances;
 		type Deposit = Deposit;
+		type IssuanceDeposit = Deposit;
 		type MaxDelegatedAttestations = MaxDelegatedAttestations;
+		type MaxCidLength = MaxCidLength;
 		type AttesterId = SubjectId;
 		type AuthorizationId = SubjectId;
 		type AccessControl = MockAccessControl<Self>;
 		type BalanceMigrationManager = Migration;
+		type AttesterAccreditation = ();
+		type ChallengeBond = ConstU128<MICRO_KILT>;
+		type ChallengeResponsePeriod = frame_support::traits::ConstU64<10>;
+		type ChallengeAdjudicatorOrigin = EnsureRoot<AccountId>;
+		type ClaimerSignature = (SubjectId, Vec<u8>);
+		type ClaimerSignatureVerification = EqualVerify<SubjectId, Vec<u8>>;
 	}
 
 	parameter_types! {
@@ -202,6 +236,11 @@ pub mod runtime {
 		type Currency = Balances;
 		type Fee = Fee;
 		type FeeCollector = ();
+		type RuntimeHoldReason = RuntimeHoldReason;
+		type MaxSchemaLength = ConstU32<1024>;
+		type SchemaBaseDeposit = ConstU128<1_000>;
+		type SchemaTierSize = ConstU32<256>;
+		type SchemaTierDeposit = ConstU128<1_000>;
 	}
 
 	parameter_types! {
@@ -292,6 +331,17 @@ pub mod runtime {
 		type MaxServiceUrlLength = MaxServiceUrlLength;
 		type MaxNumberOfTypesPerService = MaxNumberOfTypesPerService;
 		type MaxNumberOfUrlsPerService = MaxNumberOfUrlsPerService;
+		type MaxKeyHistoryLength = ConstU32<10>;
+		type MaxRemovedKeys = ConstU32<10>;
+		type KeyHistoryDeposit = KeyDeposit;
+		type MaxEndorsementsPerDid = ConstU32<10>;
+		type MaxAlsoKnownAs = ConstU32<10>;
+		type MaxAlsoKnownAsUriLength = ConstU32<256>;
+		type MaxDidDocumentSize = ConstU32<131_072>;
+		type MaxMultisigSignatories = ConstU32<64>;
+		type AllowedKeyTypes = frame_support::traits::Everything;
+		type FreeTrialOperations = ConstU32<0>;
+		type LegacySignatureDeadline = frame_support::traits::ConstU64<0>;
 		type BalanceMigrationManager = Migration;
 	}
 
@@ -304,11 +354,15 @@ pub mod runtime {
 		type RuntimeHoldReason = RuntimeHoldReason;
 		type Currency = Balances;
 		type Deposit = DidLookupDeposit;
+		type LabelDeposit = DidLookupDeposit;
 		type EnsureOrigin = mock_origin::EnsureDoubleOrigin<AccountId, SubjectId>;
 		type OriginSuccess = mock_origin::DoubleOrigin<AccountId, SubjectId>;
 		type DidIdentifier = SubjectId;
 		type WeightInfo = ();
 		type BalanceMigrationManager = Migration;
+		type ContractSignatureVerifier = ();
+		type MaxAssociationRemovals = ConstU32<10>;
+		type MaxProofValidity = frame_support::traits::ConstU64<1_000>;
 	}
 
 	pub(crate) type TestWeb3Name = AsciiWeb3Name<Test>;
@@ -323,10 +377,19 @@ pub mod runtime {
 		pub const MinNameLength: u32 = 3;
 		// Easier to setup insufficient funds for deposit but still above existential deposit
 		pub const Web3NameDeposit: Balance = MICRO_KILT;
+		pub const TreasuryAccount: AccountId = AccountId::new([250u8; 32]);
 	}
 
 	impl pallet_web3_names::Config for Test {
 		type BanOrigin = TestBanOrigin;
+		type BanAppealOrigin = TestBanOrigin;
+		type BanDelay = frame_support::traits::ConstU64<10>;
+		type MaxBansPerBlock = ConstU32<16>;
+		type RegistrarOrigin = TestBanOrigin;
+		type MaxClaimBatchSize = ConstU32<16>;
+		type RegistrarApprovalOrigin = TestBanOrigin;
+		type RegistrarTreasuryShare = ();
+		type TreasuryAccount = TreasuryAccount;
 		type OwnerOrigin = TestOwnerOrigin;
 		type OriginSuccess = TestOriginSuccess;
 		type Currency = Balances;
@@ -337,6 +400,10 @@ pub mod runtime {
 		type MinNameLength = MinNameLength;
 		type Web3Name = TestWeb3Name;
 		type Web3NameOwner = TestWeb3NameOwner;
+		type MaxRecordKeyLength = ConstU32<32>;
+		type MaxRecordValueLength = ConstU32<256>;
+		type MaxRecordsPerName = ConstU32<16>;
+		type RecordDeposit = Web3NameDeposit;
 		type WeightInfo = ();
 		type BalanceMigrationManager = Migration;
 	}
@@ -418,6 +485,7 @@ pub mod runtime {
 		type MaxEncodedClaimsLength = ConstU32<500>;
 		type MaxSubjectIdLength = ConstU32<100>;
 		type OriginSuccess = mock_origin::DoubleOrigin<AccountId, Self::AttesterId>;
+		type SubjectControllerOrigin = EnsureRoot<AccountId>;
 		type SubjectId = TestSubjectId;
 		type WeightInfo = ();
 		type BalanceMigrationManager = Migration;
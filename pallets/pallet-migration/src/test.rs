@@ -37,9 +37,14 @@ use frame_support::{
 	traits::{
 		fungible::{Inspect, InspectHold},
 		tokens::{Fortitude, Preservation},
+		Contains, Get, GetStorageVersion, OnRuntimeUpgrade, StorageVersion,
 	},
+	weights::Weight,
+};
+use kilt_support::{
+	migration::SteppedMigration,
+	mock::mock_origin::{self, DoubleOrigin},
 };
-use kilt_support::mock::mock_origin::{self, DoubleOrigin};
 use pallet_did_lookup::{
 	associate_account_request::{get_challenge, AssociateAccountRequest},
 	linkable_account::LinkableAccountId,
@@ -54,7 +59,9 @@ use public_credentials::{
 use sp_core::{ed25519, sr25519, Pair};
 use sp_runtime::{traits::IdentifyAccount, BoundedVec, MultiSignature, MultiSigner};
 
-use crate::{mock::*, EntriesToMigrate, MigratedKeys, Pallet};
+use crate::{mock::*, BlockCallsDuringMigration, EntriesToMigrate, MigratedKeys, Pallet, SteppedMigrationProgress};
+
+type MigrationCallFilter = BlockCallsDuringMigration<Test, SteppedMigrationKeys>;
 
 #[test]
 fn check_succesful_migration() {
@@ -323,6 +330,7 @@ fn check_excluded_keys_attestation() {
 				DoubleOrigin(ACCOUNT_00, attester).into(),
 				claim_hash,
 				ctype,
+				None,
 				None
 			));
 
@@ -451,9 +459,6 @@ fn check_excluded_keys_lookup() {
 	let pair_alice = sr25519::Pair::from_seed(b"Alice                           ");
 	let expire_at: BlockNumber = 500;
 	let account_hash_alice = MultiSigner::from(pair_alice.public()).into_account();
-	let sig_alice_0 = MultiSignature::from(
-		pair_alice.sign(&[b"<Bytes>", get_challenge(&DID_00, expire_at).as_bytes(), b"</Bytes>"].concat()[..]),
-	);
 
 	let linked_acc = LinkableAccountId::from(account_hash_alice.clone());
 
@@ -462,6 +467,16 @@ fn check_excluded_keys_lookup() {
 		.build()
 		.execute_with(|| {
 			let hashed_key = ConnectedDids::<Test>::hashed_key_for(&linked_acc);
+			let sig_alice_0 = MultiSignature::from(
+				pair_alice.sign(
+					&[
+						b"<Bytes>",
+						get_challenge(&DID_00, expire_at, DidLookup::genesis_hash().as_ref()).as_bytes(),
+						b"</Bytes>",
+					]
+					.concat()[..],
+				),
+			);
 
 			assert!(!Pallet::<Test>::is_key_migrated(&hashed_key));
 
@@ -799,3 +814,140 @@ fn migrate_key_by_update_deposit_attestation() {
 			assert_eq!(hold_balance, MICRO_KILT);
 		});
 }
+
+#[test]
+fn stepped_migrations_run_over_multiple_blocks() {
+	let alice_key = ed25519::Pair::from_seed(&ALICE_SEED);
+	let alice_did = get_did_identifier_from_ed25519_key(alice_key.public());
+	let bob_key = ed25519::Pair::from_seed(&BOB_SEED);
+	let bob_did = get_did_identifier_from_ed25519_key(bob_key.public());
+	let alice_details =
+		generate_base_did_details::<Test>(DidVerificationKey::from(alice_key.public()), Some(ACCOUNT_00));
+	let bob_details = generate_base_did_details::<Test>(DidVerificationKey::from(bob_key.public()), Some(ACCOUNT_00));
+	let did_call = RuntimeCall::Did(did::Call::update_deposit { did: alice_did.clone() });
+	let lookup_call = RuntimeCall::DidLookup(pallet_did_lookup::Call::remove_sender_association {});
+
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, KILT)])
+		.with_dids(vec![
+			(alice_did.clone(), alice_details.clone()),
+			(bob_did.clone(), bob_details.clone()),
+		])
+		.build()
+		.execute_with(|| {
+			StorageVersion::new(4).put::<Did>();
+			<Migration as OnRuntimeUpgrade>::on_runtime_upgrade();
+
+			assert!(Pallet::<Test>::is_pallet_migrating("Did"));
+			assert!(!Pallet::<Test>::is_pallet_migrating("DidLookup"));
+			assert!(!MigrationCallFilter::contains(&did_call));
+			assert!(MigrationCallFilter::contains(&lookup_call));
+
+			// Only a single step fits into the limit.
+			let limit = <did::migrations::BackfillKeyHistory<Test> as SteppedMigration>::max_step_weight()
+				.saturating_add(<Test as frame_system::Config>::DbWeight::get().reads(2));
+			Pallet::<Test>::execute_stepped_migrations(limit);
+
+			let progress = SteppedMigrationProgress::<Test>::get().expect("Migration should be in progress.");
+			assert_eq!(progress.index, 0);
+			assert!(progress.inner.is_some());
+			let migrated_dids = [&alice_did, &bob_did]
+				.into_iter()
+				.filter(|did| did::KeyHistory::<Test>::iter_prefix(did).next().is_some())
+				.count();
+			assert_eq!(migrated_dids, 1);
+			assert_eq!(Did::on_chain_storage_version(), StorageVersion::new(4));
+			// The DID still has to be migrated by the second migration.
+			assert!(!MigrationCallFilter::contains(&did_call));
+
+			Pallet::<Test>::execute_stepped_migrations(Weight::MAX);
+
+			assert!(SteppedMigrationProgress::<Test>::get().is_none());
+			assert!(did::KeyHistory::<Test>::iter_prefix(&alice_did).next().is_some());
+			assert!(did::KeyHistory::<Test>::iter_prefix(&bob_did).next().is_some());
+			assert_eq!(Did::on_chain_storage_version(), StorageVersion::new(7));
+			assert!(!Pallet::<Test>::is_pallet_migrating("Did"));
+			assert!(MigrationCallFilter::contains(&did_call));
+		});
+}
+
+#[test]
+fn stepped_migrations_only_block_calls_of_pending_keys() {
+	let alice_key = ed25519::Pair::from_seed(&ALICE_SEED);
+	let alice_did = get_did_identifier_from_ed25519_key(alice_key.public());
+	let bob_key = ed25519::Pair::from_seed(&BOB_SEED);
+	let bob_did = get_did_identifier_from_ed25519_key(bob_key.public());
+	let alice_details =
+		generate_base_did_details::<Test>(DidVerificationKey::from(alice_key.public()), Some(ACCOUNT_00));
+	let bob_details = generate_base_did_details::<Test>(DidVerificationKey::from(bob_key.public()), Some(ACCOUNT_00));
+	let update_deposit_call =
+		|did: &did::DidIdentifierOf<Test>| RuntimeCall::Did(did::Call::update_deposit { did: did.clone() });
+	let create_from_account_call = RuntimeCall::Did(did::Call::create_from_account {
+		authentication_key: DidVerificationKey::Account(ACCOUNT_00),
+	});
+
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, KILT)])
+		.with_dids(vec![
+			(alice_did.clone(), alice_details.clone()),
+			(bob_did.clone(), bob_details.clone()),
+		])
+		.build()
+		.execute_with(|| {
+			// Only the second migration has to be executed.
+			StorageVersion::new(5).put::<Did>();
+			<Migration as OnRuntimeUpgrade>::on_runtime_upgrade();
+
+			// Only a single step fits into the limit after skipping the first migration.
+			let limit = <did::migrations::RepriceDidDeposits<Test> as SteppedMigration>::max_step_weight()
+				.saturating_add(<Test as frame_system::Config>::DbWeight::get().reads(3));
+			Pallet::<Test>::execute_stepped_migrations(limit);
+
+			let progress = SteppedMigrationProgress::<Test>::get().expect("Migration should be in progress.");
+			assert_eq!(progress.index, 1);
+			let cursor = progress.inner.expect("Migration should have executed a step.");
+			let (migrated_did, pending_did) = if cursor.as_slice() == did::Did::<Test>::hashed_key_for(&alice_did) {
+				(&alice_did, &bob_did)
+			} else {
+				(&bob_did, &alice_did)
+			};
+
+			assert!(MigrationCallFilter::contains(&update_deposit_call(migrated_did)));
+			assert!(!MigrationCallFilter::contains(&update_deposit_call(pending_did)));
+			// The DID of the call cannot be determined without the origin.
+			assert!(!MigrationCallFilter::contains(&create_from_account_call));
+			// Without the storage keys, all calls of the pallet are blocked.
+			assert!(!BlockCallsDuringMigration::<Test, ()>::contains(&update_deposit_call(
+				migrated_did
+			)));
+
+			Pallet::<Test>::execute_stepped_migrations(Weight::MAX);
+
+			assert!(MigrationCallFilter::contains(&update_deposit_call(pending_did)));
+			assert!(MigrationCallFilter::contains(&create_from_account_call));
+		});
+}
+
+#[test]
+fn stepped_migrations_skip_migrated_pallets() {
+	let alice_key = ed25519::Pair::from_seed(&ALICE_SEED);
+	let alice_did = get_did_identifier_from_ed25519_key(alice_key.public());
+	let alice_details =
+		generate_base_did_details::<Test>(DidVerificationKey::from(alice_key.public()), Some(ACCOUNT_00));
+
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, KILT)])
+		.with_dids(vec![(alice_did.clone(), alice_details)])
+		.build()
+		.execute_with(|| {
+			StorageVersion::new(7).put::<Did>();
+			<Migration as OnRuntimeUpgrade>::on_runtime_upgrade();
+			assert!(Pallet::<Test>::is_pallet_migrating("Did"));
+
+			Pallet::<Test>::execute_stepped_migrations(Weight::MAX);
+
+			assert!(SteppedMigrationProgress::<Test>::get().is_none());
+			assert!(did::KeyHistory::<Test>::iter_prefix(&alice_did).next().is_none());
+			assert_eq!(Did::on_chain_storage_version(), StorageVersion::new(7));
+		});
+}
@@ -32,7 +32,7 @@ mod benchmarking;
 #[cfg(test)]
 mod mock;
 
-pub use crate::{default_weights::WeightInfo, pallet::*};
+pub use crate::{default_weights::WeightInfo, pallet::*, relay::RelayParentInfo};
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -87,6 +87,13 @@ pub mod pallet {
 		}
 	}
 
+	impl<T: Config> Pallet<T> {
+		/// Returns the number of the latest relaychain block stored, if any.
+		pub fn latest_relay_block_number() -> Option<u32> {
+			LatestBlockHeights::<T>::get().last().copied()
+		}
+	}
+
 	impl<T: Config> Pallet<T>
 	where
 		T: cumulus_pallet_parachain_system::Config,
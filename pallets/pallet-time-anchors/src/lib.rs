@@ -0,0 +1,105 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+//! Pallet to store anchors between block numbers and the timestamps of the
+//! respective blocks, fed by the timestamp inherent via
+//! [`OnTimestampSet`](frame_support::traits::OnTimestampSet).
+//!
+//! A new anchor is recorded at most once every `AnchorInterval` blocks, and
+//! only the last `MaxAnchors` anchors are kept. The pallet implements
+//! [`TimeAnchors`], so that it can be used with
+//! [`kilt_support::time::MomentToBlockNumber`] to configure expiration periods
+//! and deadlines in wall-clock time rather than in raw block counts.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+pub use crate::pallet::*;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use frame_support::{pallet_prelude::*, traits::OnTimestampSet};
+	use frame_system::pallet_prelude::*;
+	use kilt_support::time::TimeAnchors;
+	use sp_runtime::Saturating;
+	use sp_std::vec::Vec;
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+
+	pub type MomentOf<T> = <T as pallet_timestamp::Config>::Moment;
+
+	/// The anchors between block numbers and timestamps, sorted by ascending
+	/// block number. When full, the oldest anchor is replaced.
+	#[pallet::storage]
+	#[pallet::getter(fn anchors)]
+	pub type Anchors<T: Config> =
+		StorageValue<_, BoundedVec<(BlockNumberFor<T>, MomentOf<T>), <T as Config>::MaxAnchors>, ValueQuery>;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + pallet_timestamp::Config {
+		/// The minimum number of blocks between two anchors.
+		#[pallet::constant]
+		type AnchorInterval: Get<BlockNumberFor<Self>>;
+		/// The maximum number of anchors to store. When the limit is reached,
+		/// the oldest anchor is removed.
+		#[pallet::constant]
+		type MaxAnchors: Get<u32>;
+	}
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+			// Accounts for the anchors updated when the timestamp is set.
+			T::DbWeight::get().reads_writes(1, 1)
+		}
+	}
+
+	impl<T: Config> OnTimestampSet<MomentOf<T>> for Pallet<T> {
+		fn on_timestamp_set(moment: MomentOf<T>) {
+			let block_number = frame_system::Pallet::<T>::block_number();
+
+			Anchors::<T>::mutate(|anchors| {
+				let is_due = anchors.last().map_or(true, |(last_block_number, _)| {
+					block_number >= last_block_number.saturating_add(T::AnchorInterval::get())
+				});
+				if !is_due {
+					return;
+				}
+				if anchors.is_full() && !anchors.is_empty() {
+					anchors.remove(0);
+				}
+				// Only fails if `MaxAnchors` is zero, in which case no anchor is stored.
+				let _ = anchors.try_push((block_number, moment));
+			});
+		}
+	}
+
+	impl<T: Config> TimeAnchors<BlockNumberFor<T>, MomentOf<T>> for Pallet<T> {
+		fn anchors() -> Vec<(BlockNumberFor<T>, MomentOf<T>)> {
+			Anchors::<T>::get().into_inner()
+		}
+	}
+}
@@ -0,0 +1,92 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use frame_support::{
+	construct_runtime,
+	sp_runtime::{
+		testing::H256,
+		traits::{BlakeTwo256, IdentityLookup},
+		AccountId32,
+	},
+	traits::{ConstU16, ConstU32, ConstU64, Everything},
+};
+use frame_system::mocking::MockBlock;
+
+construct_runtime!(
+	pub struct TestRuntime {
+		System: frame_system,
+		Timestamp: pallet_timestamp,
+		TimeAnchors: crate,
+	}
+);
+
+impl frame_system::Config for TestRuntime {
+	type AccountData = ();
+	type AccountId = AccountId32;
+	type BaseCallFilter = Everything;
+	type Block = MockBlock<TestRuntime>;
+	type BlockHashCount = ConstU64<256>;
+	type BlockLength = ();
+	type BlockWeights = ();
+	type DbWeight = ();
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type MaxConsumers = ConstU32<16>;
+	type Nonce = u64;
+	type OnKilledAccount = ();
+	type OnNewAccount = ();
+	type OnSetCode = ();
+	type PalletInfo = PalletInfo;
+	type RuntimeCall = RuntimeCall;
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeOrigin = RuntimeOrigin;
+	type SS58Prefix = ConstU16<1>;
+	type SystemWeightInfo = ();
+	type Version = ();
+}
+
+impl pallet_timestamp::Config for TestRuntime {
+	type MinimumPeriod = ConstU64<1>;
+	type Moment = u64;
+	type OnTimestampSet = TimeAnchors;
+	type WeightInfo = ();
+}
+
+impl crate::Config for TestRuntime {
+	type AnchorInterval = ConstU64<10>;
+	type MaxAnchors = ConstU32<3>;
+}
+
+pub(crate) const BLOCK_TIME: u64 = 12_000;
+
+/// Advances to the given block and sets its timestamp, assuming a constant
+/// block time since the genesis block.
+pub(crate) fn produce_block(block_number: u64) {
+	System::set_block_number(block_number);
+	Timestamp::set_timestamp(block_number * BLOCK_TIME);
+}
+
+#[derive(Default)]
+pub(crate) struct ExtBuilder;
+
+impl ExtBuilder {
+	pub(crate) fn build(self) -> sp_io::TestExternalities {
+		sp_io::TestExternalities::default()
+	}
+}
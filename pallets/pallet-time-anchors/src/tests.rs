@@ -0,0 +1,75 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use frame_support::traits::ConstU64;
+use kilt_support::time::MomentToBlockNumber;
+use sp_runtime::traits::Convert;
+
+use crate::mock::*;
+
+#[test]
+fn anchor_recorded_every_interval() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert!(TimeAnchors::anchors().is_empty());
+
+		produce_block(1);
+		assert_eq!(TimeAnchors::anchors().into_inner(), vec![(1, BLOCK_TIME)]);
+
+		// No new anchor before the interval has elapsed.
+		for block_number in 2..11 {
+			produce_block(block_number);
+		}
+		assert_eq!(TimeAnchors::anchors().into_inner(), vec![(1, BLOCK_TIME)]);
+
+		produce_block(11);
+		assert_eq!(
+			TimeAnchors::anchors().into_inner(),
+			vec![(1, BLOCK_TIME), (11, 11 * BLOCK_TIME)]
+		);
+	});
+}
+
+#[test]
+fn oldest_anchor_replaced_when_full() {
+	ExtBuilder::default().build().execute_with(|| {
+		for block_number in [1, 11, 21, 31] {
+			produce_block(block_number);
+		}
+		assert_eq!(
+			TimeAnchors::anchors().into_inner(),
+			vec![(11, 11 * BLOCK_TIME), (21, 21 * BLOCK_TIME), (31, 31 * BLOCK_TIME)]
+		);
+	});
+}
+
+#[test]
+fn moment_to_block_number_with_anchors() {
+	type Converter = MomentToBlockNumber<TimeAnchors, ConstU64<BLOCK_TIME>>;
+
+	ExtBuilder::default().build().execute_with(|| {
+		produce_block(1);
+		// Blocks took twice as long as expected.
+		System::set_block_number(11);
+		Timestamp::set_timestamp(21 * BLOCK_TIME);
+
+		// Interpolated with the measured block time.
+		assert_eq!(<Converter as Convert<u64, u64>>::convert(11 * BLOCK_TIME), 6);
+		// Extrapolated with the expected block time.
+		assert_eq!(<Converter as Convert<u64, u64>>::convert(31 * BLOCK_TIME), 21);
+	});
+}
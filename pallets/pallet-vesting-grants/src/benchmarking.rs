@@ -0,0 +1,119 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite};
+use frame_support::{
+	traits::{Currency, EnsureOrigin, Get, VestingSchedule},
+	BoundedVec,
+};
+use frame_system::RawOrigin;
+use sp_runtime::traits::Bounded;
+use sp_std::vec::Vec;
+
+use crate::*;
+
+const SEED: u32 = 0;
+
+fn locked_amount<T: Config>() -> BalanceOf<T> {
+	(<T as pallet_vesting::Config>::Currency::minimum_balance() * 1_000u32.into())
+		.max(<T as pallet_vesting::Config>::MinVestedTransfer::get())
+}
+
+/// Endows the account and fills up its vesting schedules, so that granting
+/// to it requires merging two schedules first.
+///
+/// The merge is weighed separately by `force_merge_schedules`.
+fn fill_vesting_schedules<T: Config>(who: &AccountIdOf<T>) {
+	let locked = locked_amount::<T>();
+	let per_block = <T as pallet_vesting::Config>::Currency::minimum_balance();
+
+	<T as pallet_vesting::Config>::Currency::make_free_balance_be(who, locked * T::MAX_VESTING_SCHEDULES.into());
+	for i in 0..T::MAX_VESTING_SCHEDULES {
+		<pallet_vesting::Pallet<T> as VestingSchedule<AccountIdOf<T>>>::add_vesting_schedule(
+			who,
+			locked,
+			per_block,
+			(1_000u32 + i).into(),
+		)
+		.expect("Should add vesting schedule");
+	}
+}
+
+benchmarks! {
+	grant_vested_transfers {
+		let n in 1 .. T::MaxGrants::get();
+
+		let source: AccountIdOf<T> = account("source", 0, SEED);
+		<T as pallet_vesting::Config>::Currency::make_free_balance_be(&source, BalanceOf::<T>::max_value() / 2u32.into());
+
+		// Each target already has a vesting schedule, but no merge is needed.
+		let grants: Vec<_> = (0..n).map(|i| {
+			let target: AccountIdOf<T> = account("target", i, SEED);
+			<T as pallet_vesting::Config>::Currency::make_free_balance_be(&target, locked_amount::<T>());
+			<pallet_vesting::Pallet<T> as VestingSchedule<AccountIdOf<T>>>::add_vesting_schedule(
+				&target,
+				locked_amount::<T>(),
+				<T as pallet_vesting::Config>::Currency::minimum_balance(),
+				1_000u32.into(),
+			)
+			.expect("Should add vesting schedule");
+			VestingGrant {
+				target,
+				locked: locked_amount::<T>(),
+				per_block: <T as pallet_vesting::Config>::Currency::minimum_balance(),
+				starting_block: 2_000u32.into(),
+			}
+		}).collect();
+		let grants: BoundedVec<_, T::MaxGrants> = grants.try_into().expect("Should not exceed max grants");
+		let first_target = grants[0].target.clone();
+		let origin = T::AdminOrigin::try_successful_origin().expect("Should build successful origin");
+
+	}: _<T::RuntimeOrigin>(origin, source, grants)
+	verify {
+		assert_eq!(pallet_vesting::Vesting::<T>::decode_len(&first_target), Some(2));
+	}
+
+	force_merge_schedules {
+		let target: AccountIdOf<T> = account("target", 0, SEED);
+		fill_vesting_schedules::<T>(&target);
+		MergeConsents::<T>::insert(&target, ());
+		let origin = T::AdminOrigin::try_successful_origin().expect("Should build successful origin");
+
+	}: _<T::RuntimeOrigin>(origin, target.clone(), 0, 1)
+	verify {
+		assert_eq!(
+			pallet_vesting::Vesting::<T>::decode_len(&target),
+			Some(T::MAX_VESTING_SCHEDULES as usize - 1)
+		);
+	}
+
+	set_merge_consent {
+		let who: AccountIdOf<T> = account("who", 0, SEED);
+		let origin = RawOrigin::Signed(who.clone());
+
+	}: _(origin, true)
+	verify {
+		assert!(MergeConsents::<T>::contains_key(&who));
+	}
+}
+
+impl_benchmark_test_suite! {
+	Pallet,
+	crate::mock::ExtBuilder::default().build(),
+	crate::mock::TestRuntime,
+}
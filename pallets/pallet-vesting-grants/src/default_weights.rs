@@ -0,0 +1,119 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+//! Weights for pallet_vesting_grants
+//!
+//! These weights were not generated by a benchmark run. They are estimated
+//! from the storage accesses of each call.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_vesting_grants.
+pub trait WeightInfo {
+	fn grant_vested_transfers(n: u32, ) -> Weight;
+	fn force_merge_schedules() -> Weight;
+	fn set_merge_consent() -> Weight;
+}
+
+/// Estimated weights for pallet_vesting_grants.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: Vesting Vesting (r:250 w:250)
+	/// Proof: Vesting Vesting (max_values: None, max_size: Some(1169), added: 3644, mode: MaxEncodedLen)
+	/// Storage: Balances Locks (r:250 w:250)
+	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	/// Storage: System Account (r:250 w:250)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 250]`.
+	fn grant_vested_transfers(n: u32, ) -> Weight {
+		Weight::from_parts(19_807_366, 2607)
+			.saturating_add(Weight::from_parts(41_374_519, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 10025).saturating_mul(n.into()))
+	}
+	/// Storage: VestingGrants MergeConsents (r:1 w:0)
+	/// Proof: VestingGrants MergeConsents (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Vesting Vesting (r:1 w:1)
+	/// Proof: Vesting Vesting (max_values: None, max_size: Some(1169), added: 3644, mode: MaxEncodedLen)
+	/// Storage: Balances Locks (r:1 w:1)
+	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	fn force_merge_schedules() -> Weight {
+		Weight::from_parts(30_176_000, 12548)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: VestingGrants MergeConsents (r:0 w:1)
+	/// Proof: VestingGrants MergeConsents (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	fn set_merge_consent() -> Weight {
+		Weight::from_parts(11_251_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: Vesting Vesting (r:250 w:250)
+	/// Proof: Vesting Vesting (max_values: None, max_size: Some(1169), added: 3644, mode: MaxEncodedLen)
+	/// Storage: Balances Locks (r:250 w:250)
+	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	/// Storage: System Account (r:250 w:250)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 250]`.
+	fn grant_vested_transfers(n: u32, ) -> Weight {
+		Weight::from_parts(19_807_366, 2607)
+			.saturating_add(Weight::from_parts(41_374_519, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 10025).saturating_mul(n.into()))
+	}
+	/// Storage: VestingGrants MergeConsents (r:1 w:0)
+	/// Proof: VestingGrants MergeConsents (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Vesting Vesting (r:1 w:1)
+	/// Proof: Vesting Vesting (max_values: None, max_size: Some(1169), added: 3644, mode: MaxEncodedLen)
+	/// Storage: Balances Locks (r:1 w:1)
+	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	fn force_merge_schedules() -> Weight {
+		Weight::from_parts(30_176_000, 12548)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: VestingGrants MergeConsents (r:0 w:1)
+	/// Proof: VestingGrants MergeConsents (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	fn set_merge_consent() -> Weight {
+		Weight::from_parts(11_251_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+}
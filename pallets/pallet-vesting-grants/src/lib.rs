@@ -0,0 +1,275 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+//! # Vesting Grants Pallet
+//!
+//! Pallet to hand out vested funds in bulk, e.g., for token generation events,
+//! on top of the vesting pallet.
+//!
+//! An admin origin can create up to `MaxGrants` vesting schedules in a single
+//! transaction from a compactly encoded list of grants, with the funds being
+//! transferred from a source account. This replaces submitting one vested
+//! transfer per grant.
+//!
+//! Accounts receiving multiple grants can quickly reach the maximum number of
+//! vesting schedules. Accounts can consent to having their schedules merged by
+//! this pallet. Whenever a grant targets a consenting account with the maximum
+//! number of schedules, its two oldest schedules are merged first to make room
+//! for the new one. The admin origin can also merge schedules of consenting
+//! accounts explicitly.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod default_weights;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub use crate::{default_weights::WeightInfo, pallet::*};
+
+#[frame_support::pallet]
+pub mod pallet {
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{Currency, ExistenceRequirement, VestingSchedule},
+	};
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::traits::{Saturating, Zero};
+
+	use super::WeightInfo;
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+
+	pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
+	pub type BalanceOf<T> = <<T as pallet_vesting::Config>::Currency as Currency<AccountIdOf<T>>>::Balance;
+	pub type VestingGrantOf<T> = VestingGrant<AccountIdOf<T>, BalanceOf<T>, BlockNumberFor<T>>;
+
+	/// A single vesting grant, i.e., an amount transferred to the target
+	/// account which unlocks linearly from the starting block on.
+	#[derive(Clone, Debug, Encode, Decode, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+	pub struct VestingGrant<AccountId, Balance: HasCompact, BlockNumber: HasCompact> {
+		/// The account receiving the vested funds.
+		pub target: AccountId,
+		/// The amount transferred to and locked for the target account.
+		#[codec(compact)]
+		pub locked: Balance,
+		/// The amount unlocked per block.
+		#[codec(compact)]
+		pub per_block: Balance,
+		/// The block from which on the funds start to unlock.
+		#[codec(compact)]
+		pub starting_block: BlockNumber,
+	}
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + pallet_vesting::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		type WeightInfo: WeightInfo;
+
+		/// The origin allowed to create vesting grants and to merge vesting
+		/// schedules of consenting accounts.
+		type AdminOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+
+		/// The maximum number of grants that can be created in a single call.
+		#[pallet::constant]
+		type MaxGrants: Get<u32>;
+	}
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	/// The accounts that consented to having their vesting schedules merged
+	/// by this pallet.
+	#[pallet::storage]
+	#[pallet::getter(fn merge_consents)]
+	pub type MergeConsents<T> = StorageMap<_, Blake2_128Concat, AccountIdOf<T>, ()>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A batch of vested transfers has been made.
+		VestedTransfersGranted { source: AccountIdOf<T>, count: u32 },
+		/// Two vesting schedules of an account have been merged.
+		SchedulesMerged { who: AccountIdOf<T> },
+		/// An account has given or withdrawn its consent to having its
+		/// vesting schedules merged.
+		MergeConsentSet { who: AccountIdOf<T>, consent: bool },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The schedule parameters of a grant are invalid, i.e., the locked
+		/// amount or the amount unlocked per block is zero.
+		InvalidGrant,
+		/// The locked amount of a grant is below the minimum amount of a
+		/// vested transfer.
+		AmountLow,
+		/// The account has not consented to having its vesting schedules
+		/// merged.
+		NoMergeConsent,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Transfer funds from the source account to the target of each grant
+		/// and lock them according to the grant's vesting schedule.
+		///
+		/// If a target already has the maximum number of vesting schedules
+		/// and consented to merges, its two oldest schedules are merged before
+		/// the new one is added. Either all grants are created, or none.
+		///
+		/// The dispatch origin must be `AdminOrigin`.
+		///
+		/// Emits `VestedTransfersGranted`.
+		#[pallet::call_index(0)]
+		#[pallet::weight({
+			let count = grants.len() as u32;
+			<T as Config>::WeightInfo::grant_vested_transfers(count)
+				.saturating_add(<T as Config>::WeightInfo::force_merge_schedules().saturating_mul(count.into()))
+		})]
+		pub fn grant_vested_transfers(
+			origin: OriginFor<T>,
+			source: AccountIdOf<T>,
+			grants: BoundedVec<VestingGrantOf<T>, T::MaxGrants>,
+		) -> DispatchResultWithPostInfo {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			let count = grants.len() as u32;
+			let mut merges: u32 = 0;
+			for grant in grants.iter() {
+				if Self::do_grant(&source, grant)? {
+					merges.saturating_inc();
+				}
+			}
+
+			Self::deposit_event(Event::VestedTransfersGranted { source, count });
+
+			let post_weight = <T as Config>::WeightInfo::grant_vested_transfers(count)
+				.saturating_add(<T as Config>::WeightInfo::force_merge_schedules().saturating_mul(merges.into()));
+			Ok(Some(post_weight).into())
+		}
+
+		/// Merge two vesting schedules of the target account into one, as if
+		/// the target had called `merge_schedules` of the vesting pallet.
+		///
+		/// The target must have consented to merges via `set_merge_consent`.
+		///
+		/// The dispatch origin must be `AdminOrigin`.
+		///
+		/// Emits `SchedulesMerged`.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::force_merge_schedules())]
+		pub fn force_merge_schedules(
+			origin: OriginFor<T>,
+			target: AccountIdOf<T>,
+			schedule1_index: u32,
+			schedule2_index: u32,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			ensure!(MergeConsents::<T>::contains_key(&target), Error::<T>::NoMergeConsent);
+
+			pallet_vesting::Pallet::<T>::merge_schedules(
+				frame_system::RawOrigin::Signed(target.clone()).into(),
+				schedule1_index,
+				schedule2_index,
+			)?;
+
+			Self::deposit_event(Event::SchedulesMerged { who: target });
+
+			Ok(())
+		}
+
+		/// Give or withdraw the consent of the caller to having its vesting
+		/// schedules merged by this pallet, either explicitly by the admin
+		/// origin or when a new grant exceeds the maximum number of
+		/// schedules.
+		///
+		/// The dispatch origin must be signed.
+		///
+		/// Emits `MergeConsentSet`.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_merge_consent())]
+		pub fn set_merge_consent(origin: OriginFor<T>, consent: bool) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			if consent {
+				MergeConsents::<T>::insert(&who, ());
+			} else {
+				MergeConsents::<T>::remove(&who);
+			}
+
+			Self::deposit_event(Event::MergeConsentSet { who, consent });
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Creates the vesting grant and returns whether two schedules of the
+		/// target had to be merged for it.
+		fn do_grant(source: &AccountIdOf<T>, grant: &VestingGrantOf<T>) -> Result<bool, DispatchError> {
+			ensure!(
+				!grant.locked.is_zero() && !grant.per_block.is_zero(),
+				Error::<T>::InvalidGrant
+			);
+			ensure!(
+				grant.locked >= <T as pallet_vesting::Config>::MinVestedTransfer::get(),
+				Error::<T>::AmountLow
+			);
+
+			// Make room for the new schedule by merging the two oldest ones.
+			let schedules = pallet_vesting::Vesting::<T>::decode_len(&grant.target).unwrap_or_default();
+			let merge = schedules >= T::MAX_VESTING_SCHEDULES as usize;
+			if merge {
+				ensure!(
+					MergeConsents::<T>::contains_key(&grant.target),
+					Error::<T>::NoMergeConsent
+				);
+				pallet_vesting::Pallet::<T>::merge_schedules(
+					frame_system::RawOrigin::Signed(grant.target.clone()).into(),
+					0,
+					1,
+				)?;
+				Self::deposit_event(Event::SchedulesMerged {
+					who: grant.target.clone(),
+				});
+			}
+
+			<T as pallet_vesting::Config>::Currency::transfer(
+				source,
+				&grant.target,
+				grant.locked,
+				ExistenceRequirement::AllowDeath,
+			)?;
+			<pallet_vesting::Pallet<T> as VestingSchedule<AccountIdOf<T>>>::add_vesting_schedule(
+				&grant.target,
+				grant.locked,
+				grant.per_block,
+				grant.starting_block,
+			)?;
+
+			Ok(merge)
+		}
+	}
+}
@@ -0,0 +1,134 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use frame_support::{
+	construct_runtime, parameter_types,
+	sp_runtime::{
+		testing::H256,
+		traits::{BlakeTwo256, ConvertInto, IdentityLookup},
+		AccountId32, BuildStorage,
+	},
+	traits::{ConstU128, ConstU16, ConstU32, ConstU64, Everything, WithdrawReasons},
+};
+use frame_system::{mocking::MockBlock, EnsureRoot};
+
+pub(crate) type Balance = u128;
+pub(crate) type BlockNumber = u64;
+
+construct_runtime!(
+	pub struct TestRuntime {
+		System: frame_system,
+		Balances: pallet_balances,
+		Vesting: pallet_vesting,
+		VestingGrants: crate,
+	}
+);
+
+impl frame_system::Config for TestRuntime {
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type AccountId = AccountId32;
+	type BaseCallFilter = Everything;
+	type Block = MockBlock<TestRuntime>;
+	type BlockHashCount = ConstU64<256>;
+	type BlockLength = ();
+	type BlockWeights = ();
+	type DbWeight = ();
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type MaxConsumers = ConstU32<16>;
+	type Nonce = u64;
+	type OnKilledAccount = ();
+	type OnNewAccount = ();
+	type OnSetCode = ();
+	type PalletInfo = PalletInfo;
+	type RuntimeCall = RuntimeCall;
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeOrigin = RuntimeOrigin;
+	type SS58Prefix = ConstU16<1>;
+	type SystemWeightInfo = ();
+	type Version = ();
+}
+
+impl pallet_balances::Config for TestRuntime {
+	type AccountStore = System;
+	type Balance = Balance;
+	type DustRemoval = ();
+	type ExistentialDeposit = ConstU128<EXISTENTIAL_DEPOSIT>;
+	type FreezeIdentifier = ();
+	type MaxFreezes = ();
+	type MaxHolds = ();
+	type MaxLocks = ConstU32<50>;
+	type MaxReserves = ConstU32<50>;
+	type ReserveIdentifier = [u8; 8];
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeHoldReason = ();
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub UnvestedFundsAllowedWithdrawReasons: WithdrawReasons =
+		WithdrawReasons::except(WithdrawReasons::TRANSFER | WithdrawReasons::RESERVE);
+}
+
+impl pallet_vesting::Config for TestRuntime {
+	type BlockNumberToBalance = ConvertInto;
+	type Currency = Balances;
+	type MinVestedTransfer = ConstU128<100>;
+	type RuntimeEvent = RuntimeEvent;
+	type UnvestedFundsAllowedWithdrawReasons = UnvestedFundsAllowedWithdrawReasons;
+	type WeightInfo = ();
+	const MAX_VESTING_SCHEDULES: u32 = MAX_VESTING_SCHEDULES;
+}
+
+impl crate::Config for TestRuntime {
+	type AdminOrigin = EnsureRoot<AccountId32>;
+	type MaxGrants = ConstU32<10>;
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = ();
+}
+
+pub(crate) const EXISTENTIAL_DEPOSIT: Balance = 10;
+pub(crate) const MAX_VESTING_SCHEDULES: u32 = 3;
+
+pub(crate) const SOURCE: AccountId32 = AccountId32::new([1u8; 32]);
+pub(crate) const ALICE: AccountId32 = AccountId32::new([2u8; 32]);
+pub(crate) const BOB: AccountId32 = AccountId32::new([3u8; 32]);
+
+#[derive(Default)]
+pub(crate) struct ExtBuilder(Vec<(AccountId32, Balance)>);
+
+impl ExtBuilder {
+	pub(crate) fn with_balances(mut self, balances: Vec<(AccountId32, Balance)>) -> Self {
+		self.0 = balances;
+		self
+	}
+
+	pub(crate) fn build(self) -> sp_io::TestExternalities {
+		let mut storage = frame_system::GenesisConfig::<TestRuntime>::default()
+			.build_storage()
+			.unwrap();
+		pallet_balances::GenesisConfig::<TestRuntime> { balances: self.0 }
+			.assimilate_storage(&mut storage)
+			.unwrap();
+
+		let mut ext = sp_io::TestExternalities::new(storage);
+		ext.execute_with(|| System::set_block_number(1));
+		ext
+	}
+}
@@ -0,0 +1,281 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use frame_support::{assert_noop, assert_ok, traits::Currency, BoundedVec};
+use kilt_support::{assert_event_emitted_times, assert_last_event_matches};
+use pallet_vesting::VestingInfo;
+use sp_runtime::{traits::BadOrigin, TokenError};
+
+use crate::{mock::*, Error, Event, VestingGrant, VestingGrantOf};
+
+fn grant(target: AccountId32, locked: Balance, starting_block: BlockNumber) -> VestingGrantOf<TestRuntime> {
+	VestingGrant {
+		target,
+		locked,
+		per_block: 10,
+		starting_block,
+	}
+}
+
+fn grants(grants: Vec<VestingGrantOf<TestRuntime>>) -> BoundedVec<VestingGrantOf<TestRuntime>, ConstU32<10>> {
+	grants.try_into().expect("Too many grants for the test runtime")
+}
+
+#[test]
+fn grant_vested_transfers() {
+	ExtBuilder::default()
+		.with_balances(vec![(SOURCE, 10_000)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(VestingGrants::grant_vested_transfers(
+				RuntimeOrigin::root(),
+				SOURCE,
+				grants(vec![
+					grant(ALICE, 1_000, 10),
+					grant(BOB, 2_000, 20),
+					grant(ALICE, 500, 30)
+				]),
+			));
+
+			assert_eq!(Balances::free_balance(SOURCE), 6_500);
+			assert_eq!(Balances::free_balance(ALICE), 1_500);
+			assert_eq!(Balances::free_balance(BOB), 2_000);
+			assert_eq!(
+				pallet_vesting::Vesting::<TestRuntime>::get(ALICE).unwrap().into_inner(),
+				vec![VestingInfo::new(1_000, 10, 10), VestingInfo::new(500, 10, 30)]
+			);
+			assert_eq!(
+				pallet_vesting::Vesting::<TestRuntime>::get(BOB).unwrap().into_inner(),
+				vec![VestingInfo::new(2_000, 10, 20)]
+			);
+			// Nothing has been unlocked yet.
+			assert_eq!(Vesting::vesting_balance(&ALICE), Some(1_500));
+			assert_event_emitted_times!(
+				TestRuntime,
+				Event<TestRuntime>,
+				Event::VestedTransfersGranted { source, count: 3 } if source == SOURCE,
+				1
+			);
+		});
+}
+
+#[test]
+fn grant_vested_transfers_merges_schedules_when_full() {
+	ExtBuilder::default()
+		.with_balances(vec![(SOURCE, 10_000)])
+		.build()
+		.execute_with(|| {
+			let full: Vec<_> = (0..MAX_VESTING_SCHEDULES as u64)
+				.map(|i| grant(ALICE, 1_000, 10 * (i + 1)))
+				.collect();
+			assert_ok!(VestingGrants::grant_vested_transfers(
+				RuntimeOrigin::root(),
+				SOURCE,
+				grants(full)
+			));
+			assert_eq!(
+				pallet_vesting::Vesting::<TestRuntime>::decode_len(&ALICE),
+				Some(MAX_VESTING_SCHEDULES as usize)
+			);
+			assert_ok!(VestingGrants::set_merge_consent(RuntimeOrigin::signed(ALICE), true));
+
+			assert_ok!(VestingGrants::grant_vested_transfers(
+				RuntimeOrigin::root(),
+				SOURCE,
+				grants(vec![grant(ALICE, 1_000, 100)]),
+			));
+
+			// The two oldest schedules have been merged into one, ending with
+			// the later one.
+			assert_eq!(
+				pallet_vesting::Vesting::<TestRuntime>::get(ALICE).unwrap().into_inner(),
+				vec![
+					VestingInfo::new(1_000, 10, 30),
+					VestingInfo::new(2_000, 20, 20),
+					VestingInfo::new(1_000, 10, 100),
+				]
+			);
+			assert_eq!(Balances::free_balance(ALICE), 4_000);
+			assert_event_emitted_times!(
+				TestRuntime,
+				Event<TestRuntime>,
+				Event::SchedulesMerged { who } if who == ALICE,
+				1
+			);
+			assert_last_event_matches!(
+				TestRuntime,
+				Event<TestRuntime>,
+				Event::VestedTransfersGranted { count: 1, .. }
+			);
+		});
+}
+
+#[test]
+fn grant_vested_transfers_requires_merge_consent_when_full() {
+	ExtBuilder::default()
+		.with_balances(vec![(SOURCE, 10_000)])
+		.build()
+		.execute_with(|| {
+			let full: Vec<_> = (0..MAX_VESTING_SCHEDULES as u64)
+				.map(|i| grant(ALICE, 1_000, 10 * (i + 1)))
+				.collect();
+			assert_ok!(VestingGrants::grant_vested_transfers(
+				RuntimeOrigin::root(),
+				SOURCE,
+				grants(full)
+			));
+
+			assert_noop!(
+				VestingGrants::grant_vested_transfers(
+					RuntimeOrigin::root(),
+					SOURCE,
+					grants(vec![grant(ALICE, 1_000, 100)]),
+				),
+				Error::<TestRuntime>::NoMergeConsent
+			);
+		});
+}
+
+#[test]
+fn grant_vested_transfers_is_atomic() {
+	ExtBuilder::default()
+		.with_balances(vec![(SOURCE, 2_000)])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				VestingGrants::grant_vested_transfers(
+					RuntimeOrigin::root(),
+					SOURCE,
+					grants(vec![grant(ALICE, 1_000, 10), grant(BOB, 1_500, 10)]),
+				),
+				TokenError::FundsUnavailable
+			);
+			assert_eq!(Balances::free_balance(ALICE), 0);
+			assert!(pallet_vesting::Vesting::<TestRuntime>::get(ALICE).is_none());
+		});
+}
+
+#[test]
+fn grant_vested_transfers_invalid_grant() {
+	ExtBuilder::default()
+		.with_balances(vec![(SOURCE, 10_000)])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				VestingGrants::grant_vested_transfers(
+					RuntimeOrigin::root(),
+					SOURCE,
+					grants(vec![VestingGrant {
+						target: ALICE,
+						locked: 1_000,
+						per_block: 0,
+						starting_block: 10
+					}]),
+				),
+				Error::<TestRuntime>::InvalidGrant
+			);
+		});
+}
+
+#[test]
+fn grant_vested_transfers_amount_low() {
+	ExtBuilder::default()
+		.with_balances(vec![(SOURCE, 10_000)])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				VestingGrants::grant_vested_transfers(
+					RuntimeOrigin::root(),
+					SOURCE,
+					grants(vec![grant(ALICE, 1_000, 10), grant(BOB, 99, 10)]),
+				),
+				Error::<TestRuntime>::AmountLow
+			);
+		});
+}
+
+#[test]
+fn grant_vested_transfers_bad_origin() {
+	ExtBuilder::default()
+		.with_balances(vec![(SOURCE, 10_000)])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				VestingGrants::grant_vested_transfers(
+					RuntimeOrigin::signed(SOURCE),
+					SOURCE,
+					grants(vec![grant(ALICE, 1_000, 10)]),
+				),
+				BadOrigin
+			);
+		});
+}
+
+#[test]
+fn force_merge_schedules() {
+	ExtBuilder::default()
+		.with_balances(vec![(SOURCE, 10_000)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(VestingGrants::grant_vested_transfers(
+				RuntimeOrigin::root(),
+				SOURCE,
+				grants(vec![grant(ALICE, 1_000, 10), grant(ALICE, 2_000, 20)]),
+			));
+
+			assert_noop!(
+				VestingGrants::force_merge_schedules(RuntimeOrigin::signed(ALICE), ALICE, 0, 1),
+				BadOrigin
+			);
+			assert_noop!(
+				VestingGrants::force_merge_schedules(RuntimeOrigin::root(), ALICE, 0, 1),
+				Error::<TestRuntime>::NoMergeConsent
+			);
+
+			assert_ok!(VestingGrants::set_merge_consent(RuntimeOrigin::signed(ALICE), true));
+			assert_noop!(
+				VestingGrants::force_merge_schedules(RuntimeOrigin::root(), ALICE, 0, 2),
+				pallet_vesting::Error::<TestRuntime>::ScheduleIndexOutOfBounds
+			);
+
+			assert_ok!(VestingGrants::force_merge_schedules(RuntimeOrigin::root(), ALICE, 0, 1));
+			assert_eq!(
+				pallet_vesting::Vesting::<TestRuntime>::get(ALICE).unwrap().into_inner(),
+				vec![VestingInfo::new(3_000, 15, 20)]
+			);
+			assert_eq!(Balances::free_balance(ALICE), 3_000);
+		});
+}
+
+#[test]
+fn set_merge_consent() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(VestingGrants::set_merge_consent(RuntimeOrigin::signed(ALICE), true));
+		assert!(VestingGrants::merge_consents(ALICE).is_some());
+		assert_last_event_matches!(
+			TestRuntime,
+			Event<TestRuntime>,
+			Event::MergeConsentSet { who, consent: true } if who == ALICE
+		);
+
+		assert_ok!(VestingGrants::set_merge_consent(RuntimeOrigin::signed(ALICE), false));
+		assert!(VestingGrants::merge_consents(ALICE).is_none());
+
+		assert_noop!(VestingGrants::set_merge_consent(RuntimeOrigin::root(), true), BadOrigin);
+	});
+}
@@ -23,18 +23,22 @@ use frame_support::{
 	sp_runtime::SaturatedConversion,
 	traits::{
 		fungible::{Inspect, Mutate},
-		Get,
+		Get, Hooks,
 	},
 	BoundedVec,
 };
 use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
-use sp_runtime::app_crypto::sr25519;
+use sp_runtime::{
+	app_crypto::sr25519,
+	traits::{One, Saturating},
+};
 
-use kilt_support::{traits::GenerateBenchmarkOrigin, Deposit};
+use kilt_support::{migration::SteppedMigration, traits::GenerateBenchmarkOrigin, Deposit};
 
 use crate::{
-	mock::insert_raw_w3n, AccountIdOf, Banned, Call, Config, CurrencyOf, Names, Owner, Pallet, Web3NameOf,
-	Web3NameOwnerOf,
+	migrations, mock::insert_raw_w3n, web3_name::BanStatus, AccountIdOf, BalanceOf, Banned, Call, Config, CurrencyOf,
+	Names, Owner, Pallet, PendingClaims, Records, Registrars, ScheduledBans, Web3NameClaimBatchOf, Web3NameOf,
+	Web3NameOwnerOf, Web3NameRecordKeyOf, Web3NameRecordValueOf,
 };
 
 const CALLER_SEED: u32 = 0;
@@ -46,7 +50,8 @@ where
 {
 	let balance = <CurrencyOf<T> as Inspect<AccountIdOf<T>>>::minimum_balance()
 		+ <T as Config>::Deposit::get()
-		+ <T as Config>::Deposit::get();
+		+ <T as Config>::Deposit::get()
+		+ <T as Config>::RecordDeposit::get();
 	CurrencyOf::<T>::set_balance(account, balance);
 }
 
@@ -54,6 +59,32 @@ fn generate_web3_name_input(length: usize) -> Vec<u8> {
 	vec![b'1'; length]
 }
 
+/// Generate a name of the maximum length that is unique for each index below
+/// 1000.
+fn generate_indexed_web3_name_input<T: Config>(index: u32) -> BoundedVec<u8, T::MaxNameLength> {
+	let mut name = generate_web3_name_input(T::MaxNameLength::get().saturating_sub(3).saturated_into());
+	name.extend([
+		b'0' + (index / 100 % 10) as u8,
+		b'0' + (index / 10 % 10) as u8,
+		b'0' + (index % 10) as u8,
+	]);
+	BoundedVec::try_from(name).expect("BoundedVec creation should not fail.")
+}
+
+fn generate_record_key<T: Config>() -> Web3NameRecordKeyOf<T> {
+	BoundedVec::try_from(vec![b'k'; T::MaxRecordKeyLength::get().saturated_into()])
+		.expect("BoundedVec creation should not fail.")
+}
+
+fn generate_record_value<T: Config>() -> Web3NameRecordValueOf<T> {
+	BoundedVec::try_from(vec![b'v'; T::MaxRecordValueLength::get().saturated_into()])
+		.expect("BoundedVec creation should not fail.")
+}
+
+fn enactment_block<T: Config>() -> BlockNumberFor<T> {
+	frame_system::Pallet::<T>::block_number().saturating_add(T::BanDelay::get().max(BlockNumberFor::<T>::one()))
+}
+
 benchmarks! {
 	where_clause {
 		where
@@ -61,6 +92,8 @@ benchmarks! {
 		T::Web3NameOwner: From<T::AccountId>,
 		T::OwnerOrigin: GenerateBenchmarkOrigin<T::RuntimeOrigin, T::AccountId, T::Web3NameOwner>,
 		T::BanOrigin: EnsureOrigin<T::RuntimeOrigin>,
+		T::BanAppealOrigin: EnsureOrigin<T::RuntimeOrigin>,
+		T::RegistrarApprovalOrigin: EnsureOrigin<T::RuntimeOrigin>,
 		<T as Config>::Currency: Mutate<T::AccountId>,
 	}
 
@@ -127,9 +160,9 @@ benchmarks! {
 	}: _(ban_origin, web3_name_input_clone)
 	verify {
 		let web3_name = Web3NameOf::<T>::try_from(web3_name_input.to_vec()).unwrap();
-		assert!(Names::<T>::get(&owner).is_none());
-		assert!(Owner::<T>::get(&web3_name).is_none());
-		assert!(Banned::<T>::get(&web3_name).is_some());
+		assert!(Names::<T>::get(&owner).is_some());
+		assert!(ScheduledBans::<T>::get(&web3_name).is_some());
+		assert!(Banned::<T>::get(&web3_name).is_none());
 	}
 
 	unban {
@@ -141,7 +174,8 @@ benchmarks! {
 		let ban_origin = RawOrigin::Root;
 
 		make_free_for_did::<T>(&caller);
-		Pallet::<T>::ban(ban_origin.clone().into(), web3_name_input.clone()).expect("Should ban the web3 name.");
+		let web3_name = Web3NameOf::<T>::try_from(web3_name_input.to_vec()).unwrap();
+		Pallet::<T>::ban_name(&web3_name);
 	}: _(ban_origin, web3_name_input_clone)
 	verify {
 		let web3_name = Web3NameOf::<T>::try_from(web3_name_input.to_vec()).unwrap();
@@ -200,6 +234,226 @@ benchmarks! {
 		});
 	}
 
+	appeal_ban {
+		let n in (T::MinNameLength::get()) .. (T::MaxNameLength::get());
+		let caller: AccountIdOf<T> = account("caller", 0, CALLER_SEED);
+		let owner: Web3NameOwnerOf<T> = account("owner", 0, OWNER_SEED);
+		let web3_name_input: BoundedVec<u8, T::MaxNameLength> = BoundedVec::try_from(generate_web3_name_input(n.saturated_into())).expect("BoundedVec creation should not fail.");
+		let web3_name_input_clone = web3_name_input.clone();
+		let did_origin = T::OwnerOrigin::generate_origin(caller.clone(), owner.clone());
+		let ban_origin = RawOrigin::Root;
+
+		make_free_for_did::<T>(&caller);
+		Pallet::<T>::claim(did_origin.clone(), web3_name_input.clone()).expect("Should register the claimed web3 name.");
+		Pallet::<T>::ban(ban_origin.into(), web3_name_input.clone()).expect("Should schedule the ban of the web3 name.");
+	}: _<T::RuntimeOrigin>(did_origin, web3_name_input_clone)
+	verify {
+		let web3_name = Web3NameOf::<T>::try_from(web3_name_input.to_vec()).unwrap();
+		assert_eq!(ScheduledBans::<T>::get(&web3_name).map(|ban| ban.status), Some(BanStatus::Appealed));
+	}
+
+	resolve_ban_appeal {
+		let n in (T::MinNameLength::get()) .. (T::MaxNameLength::get());
+		let caller: AccountIdOf<T> = account("caller", 0, CALLER_SEED);
+		let owner: Web3NameOwnerOf<T> = account("owner", 0, OWNER_SEED);
+		let web3_name_input: BoundedVec<u8, T::MaxNameLength> = BoundedVec::try_from(generate_web3_name_input(n.saturated_into())).expect("BoundedVec creation should not fail.");
+		let web3_name_input_clone = web3_name_input.clone();
+		let did_origin = T::OwnerOrigin::generate_origin(caller.clone(), owner.clone());
+		let ban_origin = RawOrigin::Root;
+		let appeal_origin = RawOrigin::Root;
+
+		make_free_for_did::<T>(&caller);
+		Pallet::<T>::claim(did_origin.clone(), web3_name_input.clone()).expect("Should register the claimed web3 name.");
+		Pallet::<T>::ban(ban_origin.into(), web3_name_input.clone()).expect("Should schedule the ban of the web3 name.");
+		Pallet::<T>::appeal_ban(did_origin, web3_name_input.clone()).expect("Should appeal against the ban.");
+		// Worst case: the appeal is rejected after the enactment block, so the ban is enacted right away.
+		frame_system::Pallet::<T>::set_block_number(enactment_block::<T>());
+	}: _(appeal_origin, web3_name_input_clone, false)
+	verify {
+		let web3_name = Web3NameOf::<T>::try_from(web3_name_input.to_vec()).unwrap();
+		assert!(Names::<T>::get(&owner).is_none());
+		assert!(ScheduledBans::<T>::get(&web3_name).is_none());
+		assert!(Banned::<T>::get(&web3_name).is_some());
+	}
+
+	cancel_ban {
+		let n in (T::MinNameLength::get()) .. (T::MaxNameLength::get());
+		let web3_name_input: BoundedVec<u8, T::MaxNameLength> = BoundedVec::try_from(generate_web3_name_input(n.saturated_into())).expect("BoundedVec creation should not fail.");
+		let web3_name_input_clone = web3_name_input.clone();
+		let ban_origin = RawOrigin::Root;
+
+		Pallet::<T>::ban(ban_origin.clone().into(), web3_name_input.clone()).expect("Should schedule the ban of the web3 name.");
+	}: _(ban_origin, web3_name_input_clone)
+	verify {
+		let web3_name = Web3NameOf::<T>::try_from(web3_name_input.to_vec()).unwrap();
+		assert!(ScheduledBans::<T>::get(&web3_name).is_none());
+	}
+
+	enact_bans {
+		let n in 0 .. T::MaxBansPerBlock::get();
+		let ban_origin = RawOrigin::Root;
+		let enactment_block = enactment_block::<T>();
+
+		let web3_names = (0..n).map(|i| {
+			let caller: AccountIdOf<T> = account("caller", i, CALLER_SEED);
+			let owner: Web3NameOwnerOf<T> = account("owner", i, OWNER_SEED);
+			let web3_name_input = generate_indexed_web3_name_input::<T>(i);
+			let did_origin = T::OwnerOrigin::generate_origin(caller.clone(), owner);
+
+			make_free_for_did::<T>(&caller);
+			Pallet::<T>::claim(did_origin, web3_name_input.clone()).expect("Should register the claimed web3 name.");
+			Pallet::<T>::ban(ban_origin.clone().into(), web3_name_input.clone()).expect("Should schedule the ban of the web3 name.");
+			Web3NameOf::<T>::try_from(web3_name_input.to_vec()).unwrap()
+		}).collect::<Vec<_>>();
+	}: {
+		Pallet::<T>::on_initialize(enactment_block);
+	}
+	verify {
+		for web3_name in web3_names {
+			assert!(Owner::<T>::get(&web3_name).is_none());
+			assert!(Banned::<T>::get(&web3_name).is_some());
+		}
+	}
+
+	claim_batch {
+		let n in 0 .. T::MaxClaimBatchSize::get();
+		let registrar_origin = RawOrigin::Root;
+
+		let names = (0..n).map(|i| {
+			let owner: Web3NameOwnerOf<T> = account("owner", i, OWNER_SEED);
+			(generate_indexed_web3_name_input::<T>(i), owner)
+		}).collect::<Vec<_>>();
+		let names_clone: Web3NameClaimBatchOf<T> = BoundedVec::try_from(names.clone()).expect("BoundedVec creation should not fail.");
+	}: _(registrar_origin, names_clone)
+	verify {
+		for (web3_name_input, owner) in names {
+			let web3_name = Web3NameOf::<T>::try_from(web3_name_input.to_vec()).unwrap();
+			assert_eq!(PendingClaims::<T>::get(&owner), Some(web3_name));
+		}
+	}
+
+	approve_registrar {
+		let registrar: AccountIdOf<T> = account("registrar", 0, CALLER_SEED);
+		let fee = <T as Config>::Deposit::get();
+		let approval_origin = RawOrigin::Root;
+	}: _(approval_origin, registrar.clone(), fee)
+	verify {
+		assert!(Registrars::<T>::contains_key(&registrar));
+	}
+
+	remove_registrar {
+		let registrar: AccountIdOf<T> = account("registrar", 0, CALLER_SEED);
+		let fee = <T as Config>::Deposit::get();
+		let approval_origin = RawOrigin::Root;
+
+		Pallet::<T>::approve_registrar(approval_origin.clone().into(), registrar.clone(), fee).expect("Should approve the registrar.");
+	}: _(approval_origin, registrar.clone())
+	verify {
+		assert!(!Registrars::<T>::contains_key(&registrar));
+	}
+
+	claim_by_registrar {
+		let n in (T::MinNameLength::get()) .. (T::MaxNameLength::get());
+		let caller: AccountIdOf<T> = account("caller", 0, CALLER_SEED);
+		let registrar: AccountIdOf<T> = account("registrar", 0, CALLER_SEED);
+		let owner: Web3NameOwnerOf<T> = account("owner", 0, OWNER_SEED);
+		let web3_name_input: BoundedVec<u8, T::MaxNameLength> = BoundedVec::try_from(generate_web3_name_input(n.saturated_into())).expect("BoundedVec creation should not fail.");
+		let web3_name_input_clone = web3_name_input.clone();
+		let origin = T::OwnerOrigin::generate_origin(caller.clone(), owner.clone());
+		// A non-zero fee, so that both the registrar and the treasury transfers
+		// are accounted for.
+		let fee: BalanceOf<T> = <T as Config>::Deposit::get();
+
+		make_free_for_did::<T>(&caller);
+		CurrencyOf::<T>::set_balance(&T::TreasuryAccount::get(), <CurrencyOf<T> as Inspect<AccountIdOf<T>>>::minimum_balance());
+		CurrencyOf::<T>::set_balance(&registrar, <CurrencyOf<T> as Inspect<AccountIdOf<T>>>::minimum_balance());
+		Pallet::<T>::approve_registrar(RawOrigin::Root.into(), registrar.clone(), fee).expect("Should approve the registrar.");
+	}: _<T::RuntimeOrigin>(origin, web3_name_input_clone, registrar.clone(), fee)
+	verify {
+		let web3_name = Web3NameOf::<T>::try_from(web3_name_input.to_vec()).unwrap();
+		assert_eq!(Names::<T>::get(&owner), Some(web3_name.clone()));
+		assert_eq!(Registrars::<T>::get(&registrar).map(|details| details.names_issued), Some(1));
+	}
+
+	set_record {
+		let caller: AccountIdOf<T> = account("caller", 0, CALLER_SEED);
+		let owner: Web3NameOwnerOf<T> = account("owner", 0, OWNER_SEED);
+		let web3_name_input: BoundedVec<u8, T::MaxNameLength> = BoundedVec::try_from(generate_web3_name_input(T::MaxNameLength::get().saturated_into())).expect("BoundedVec creation should not fail.");
+		let origin = T::OwnerOrigin::generate_origin(caller.clone(), owner.clone());
+		let key = generate_record_key::<T>();
+		let key_clone = key.clone();
+		let value = generate_record_value::<T>();
+
+		make_free_for_did::<T>(&caller);
+		Pallet::<T>::claim(origin.clone(), web3_name_input.clone()).expect("Should register the claimed web3 name.");
+	}: _<T::RuntimeOrigin>(origin, key_clone, value)
+	verify {
+		let web3_name = Web3NameOf::<T>::try_from(web3_name_input.to_vec()).unwrap();
+		assert!(Records::<T>::get(&web3_name, &key).is_some());
+	}
+
+	clear_record {
+		let caller: AccountIdOf<T> = account("caller", 0, CALLER_SEED);
+		let owner: Web3NameOwnerOf<T> = account("owner", 0, OWNER_SEED);
+		let web3_name_input: BoundedVec<u8, T::MaxNameLength> = BoundedVec::try_from(generate_web3_name_input(T::MaxNameLength::get().saturated_into())).expect("BoundedVec creation should not fail.");
+		let origin = T::OwnerOrigin::generate_origin(caller.clone(), owner.clone());
+		let key = generate_record_key::<T>();
+		let key_clone = key.clone();
+
+		make_free_for_did::<T>(&caller);
+		Pallet::<T>::claim(origin.clone(), web3_name_input.clone()).expect("Should register the claimed web3 name.");
+		Pallet::<T>::set_record(origin.clone(), key.clone(), generate_record_value::<T>()).expect("Should set the record.");
+	}: _<T::RuntimeOrigin>(origin, key_clone)
+	verify {
+		let web3_name = Web3NameOf::<T>::try_from(web3_name_input.to_vec()).unwrap();
+		assert!(Records::<T>::get(&web3_name, &key).is_none());
+	}
+
+	accept_pending_claim {
+		let caller: AccountIdOf<T> = account("caller", 0, CALLER_SEED);
+		let owner: Web3NameOwnerOf<T> = account("owner", 0, OWNER_SEED);
+		let web3_name_input = generate_indexed_web3_name_input::<T>(0);
+		let origin = T::OwnerOrigin::generate_origin(caller.clone(), owner.clone());
+		let names: Web3NameClaimBatchOf<T> = BoundedVec::try_from(vec![(web3_name_input.clone(), owner.clone())]).expect("BoundedVec creation should not fail.");
+
+		make_free_for_did::<T>(&caller);
+		Pallet::<T>::claim_batch(RawOrigin::Root.into(), names).expect("Should propose the web3 name.");
+	}: _<T::RuntimeOrigin>(origin)
+	verify {
+		let web3_name = Web3NameOf::<T>::try_from(web3_name_input.to_vec()).unwrap();
+		assert!(PendingClaims::<T>::get(&owner).is_none());
+		assert_eq!(Names::<T>::get(&owner), Some(web3_name.clone()));
+		assert!(Owner::<T>::get(&web3_name).is_some());
+	}
+
+	decline_pending_claim {
+		let caller: AccountIdOf<T> = account("caller", 0, CALLER_SEED);
+		let owner: Web3NameOwnerOf<T> = account("owner", 0, OWNER_SEED);
+		let origin = T::OwnerOrigin::generate_origin(caller, owner.clone());
+		let names: Web3NameClaimBatchOf<T> = BoundedVec::try_from(vec![(generate_indexed_web3_name_input::<T>(0), owner.clone())]).expect("BoundedVec creation should not fail.");
+
+		Pallet::<T>::claim_batch(RawOrigin::Root.into(), names).expect("Should propose the web3 name.");
+	}: _<T::RuntimeOrigin>(origin)
+	verify {
+		assert!(PendingClaims::<T>::get(&owner).is_none());
+	}
+
+	initialize_owner_counter_step {
+		let caller: AccountIdOf<T> = account("caller", 0, CALLER_SEED);
+		let owner: Web3NameOwnerOf<T> = account("owner", 0, OWNER_SEED);
+		let web3_name_input: BoundedVec<u8, T::MaxNameLength> = BoundedVec::try_from(generate_web3_name_input(T::MaxNameLength::get().saturated_into())).expect("BoundedVec creation should not fail.");
+		let origin = T::OwnerOrigin::generate_origin(caller.clone(), owner);
+
+		make_free_for_did::<T>(&caller);
+		Pallet::<T>::claim(origin, web3_name_input).expect("Should register the claimed web3 name.");
+		frame_support::storage::unhashed::kill(&Owner::<T>::counter_storage_final_key());
+	}: {
+		<migrations::InitializeOwnerCounter<T> as SteppedMigration>::step(None);
+	}
+	verify {
+		assert_eq!(Owner::<T>::count(), 1);
+	}
+
 	impl_benchmark_test_suite!(
 		Pallet,
 		crate::mock::ExtBuilder::default().build_with_keystore(),
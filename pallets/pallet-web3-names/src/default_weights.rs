@@ -57,6 +57,19 @@ pub trait WeightInfo {
 	fn unban(n: u32, ) -> Weight;
 	fn change_deposit_owner() -> Weight;
 	fn update_deposit() -> Weight;
+	fn appeal_ban(n: u32, ) -> Weight;
+	fn resolve_ban_appeal(n: u32, ) -> Weight;
+	fn cancel_ban(n: u32, ) -> Weight;
+	fn enact_bans(n: u32, ) -> Weight;
+	fn claim_batch(n: u32, ) -> Weight;
+	fn approve_registrar() -> Weight;
+	fn remove_registrar() -> Weight;
+	fn claim_by_registrar(n: u32, ) -> Weight;
+	fn set_record() -> Weight;
+	fn clear_record() -> Weight;
+	fn accept_pending_claim() -> Weight;
+	fn decline_pending_claim() -> Weight;
+	fn initialize_owner_counter_step() -> Weight;
 }
 
 /// Weights for pallet_web3_names using the Substrate node and recommended hardware.
@@ -117,26 +130,6 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	}
 	/// Storage: Web3Names Banned (r:1 w:1)
 	/// Proof: Web3Names Banned (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
-	/// Storage: Web3Names Owner (r:1 w:1)
-	/// Proof: Web3Names Owner (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	/// Storage: Web3Names Names (r:0 w:1)
-	/// Proof: Web3Names Names (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
-	/// The range of component `n` is `[3, 32]`.
-	fn ban(n: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `342 + n * (1 ±0)`
-		//  Estimated: `7743`
-		// Minimum execution time: 18_069 nanoseconds.
-		Weight::from_parts(19_324_271, 7743)
-			// Standard Error: 12_698
-			.saturating_add(Weight::from_parts(62_295, 0).saturating_mul(n.into()))
-			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
-	}
-	/// Storage: Web3Names Banned (r:1 w:1)
-	/// Proof: Web3Names Banned (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
 	/// The range of component `n` is `[3, 32]`.
 	fn unban(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -177,6 +170,183 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
+	// The weights below are not taken from the benchmark run above. They are
+	// estimated from the storage accesses of each call.
+	/// Storage: Web3Names Banned (r:1 w:0)
+	/// Proof: Web3Names Banned (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Web3Names ScheduledBans (r:1 w:1)
+	/// Proof: Web3Names ScheduledBans (max_values: None, max_size: Some(58), added: 2533, mode: MaxEncodedLen)
+	/// Storage: Web3Names BanQueue (r:1 w:1)
+	/// Proof: Web3Names BanQueue (max_values: None, max_size: Some(545), added: 3020, mode: MaxEncodedLen)
+	/// The range of component `n` is `[3, 32]`.
+	fn ban(n: u32, ) -> Weight {
+		Weight::from_parts(21_376_517, 8077)
+			.saturating_add(Weight::from_parts(41_027, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: Web3Names Owner (r:1 w:0)
+	/// Proof: Web3Names Owner (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
+	/// Storage: Web3Names ScheduledBans (r:1 w:1)
+	/// Proof: Web3Names ScheduledBans (max_values: None, max_size: Some(58), added: 2533, mode: MaxEncodedLen)
+	/// The range of component `n` is `[3, 32]`.
+	fn appeal_ban(n: u32, ) -> Weight {
+		Weight::from_parts(18_935_517, 5145)
+			.saturating_add(Weight::from_parts(41_027, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Web3Names ScheduledBans (r:1 w:1)
+	/// Proof: Web3Names ScheduledBans (max_values: None, max_size: Some(58), added: 2533, mode: MaxEncodedLen)
+	/// Storage: Web3Names Owner (r:1 w:1)
+	/// Proof: Web3Names Owner (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: Web3Names Banned (r:0 w:1)
+	/// Proof: Web3Names Banned (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Web3Names Names (r:0 w:1)
+	/// Proof: Web3Names Names (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// The range of component `n` is `[3, 32]`.
+	fn resolve_ban_appeal(n: u32, ) -> Weight {
+		Weight::from_parts(30_412_517, 7752)
+			.saturating_add(Weight::from_parts(41_027, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: Web3Names ScheduledBans (r:1 w:1)
+	/// Proof: Web3Names ScheduledBans (max_values: None, max_size: Some(58), added: 2533, mode: MaxEncodedLen)
+	/// Storage: Web3Names BanQueue (r:1 w:1)
+	/// Proof: Web3Names BanQueue (max_values: None, max_size: Some(545), added: 3020, mode: MaxEncodedLen)
+	/// The range of component `n` is `[3, 32]`.
+	fn cancel_ban(n: u32, ) -> Weight {
+		Weight::from_parts(17_935_517, 5553)
+			.saturating_add(Weight::from_parts(41_027, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: Web3Names BanQueue (r:1 w:1)
+	/// Proof: Web3Names BanQueue (max_values: None, max_size: Some(545), added: 3020, mode: MaxEncodedLen)
+	/// The range of component `n` is `[0, 16]`.
+	fn enact_bans(n: u32, ) -> Weight {
+		Weight::from_parts(4_529_517, 3020)
+			.saturating_add(Weight::from_parts(24_937_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((5_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 7752).saturating_mul(n.into()))
+	}
+	/// Storage: Web3Names Names (r:50 w:0)
+	/// Proof: Web3Names Names (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: Web3Names Owner (r:50 w:0)
+	/// Proof: Web3Names Owner (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
+	/// Storage: Web3Names Banned (r:50 w:0)
+	/// Proof: Web3Names Banned (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Web3Names PendingClaims (r:0 w:50)
+	/// Proof: Web3Names PendingClaims (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// The range of component `n` is `[0, 50]`.
+	fn claim_batch(n: u32, ) -> Weight {
+		Weight::from_parts(5_893_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(Weight::from_parts(12_468_209, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 7692).saturating_mul(n.into()))
+	}
+	/// Storage: Web3Names Registrars (r:1 w:1)
+	/// Proof: Web3Names Registrars (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	fn approve_registrar() -> Weight {
+		Weight::from_parts(14_312_000, 0)
+			.saturating_add(Weight::from_parts(0, 3565))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: Web3Names Registrars (r:1 w:1)
+	/// Proof: Web3Names Registrars (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	fn remove_registrar() -> Weight {
+		Weight::from_parts(15_108_000, 0)
+			.saturating_add(Weight::from_parts(0, 3565))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: Web3Names Registrars (r:1 w:1)
+	/// Proof: Web3Names Registrars (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: Web3Names Names (r:1 w:1)
+	/// Proof: Web3Names Names (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: Web3Names Owner (r:1 w:1)
+	/// Proof: Web3Names Owner (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
+	/// Storage: Web3Names Banned (r:1 w:0)
+	/// Proof: Web3Names Banned (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: System Account (r:3 w:3)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	fn claim_by_registrar(_n: u32, ) -> Weight {
+		Weight::from_parts(93_137_000, 0)
+			.saturating_add(Weight::from_parts(0, 18088))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(6))
+	}
+	/// Storage: Web3Names Names (r:1 w:0)
+	/// Proof: Web3Names Names (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: Web3Names Records (r:1 w:1)
+	/// Proof: Web3Names Records (max_values: None, max_size: Some(404), added: 2879, mode: MaxEncodedLen)
+	/// Storage: Web3Names RecordsCount (r:1 w:1)
+	/// Proof: Web3Names RecordsCount (max_values: None, max_size: Some(53), added: 2528, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	fn set_record() -> Weight {
+		Weight::from_parts(41_837_000, 0)
+			.saturating_add(Weight::from_parts(0, 15417))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: Web3Names Names (r:1 w:0)
+	/// Proof: Web3Names Names (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: Web3Names Records (r:1 w:1)
+	/// Proof: Web3Names Records (max_values: None, max_size: Some(404), added: 2879, mode: MaxEncodedLen)
+	/// Storage: Web3Names RecordsCount (r:1 w:1)
+	/// Proof: Web3Names RecordsCount (max_values: None, max_size: Some(53), added: 2528, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	fn clear_record() -> Weight {
+		Weight::from_parts(38_204_000, 0)
+			.saturating_add(Weight::from_parts(0, 15417))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: Web3Names PendingClaims (r:1 w:1)
+	/// Proof: Web3Names PendingClaims (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: Web3Names Names (r:1 w:1)
+	/// Proof: Web3Names Names (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: Web3Names Owner (r:1 w:1)
+	/// Proof: Web3Names Owner (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
+	/// Storage: Web3Names Banned (r:1 w:0)
+	/// Proof: Web3Names Banned (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	fn accept_pending_claim() -> Weight {
+		Weight::from_parts(69_854_000, 0)
+			.saturating_add(Weight::from_parts(0, 12855))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: Web3Names PendingClaims (r:1 w:1)
+	/// Proof: Web3Names PendingClaims (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	fn decline_pending_claim() -> Weight {
+		Weight::from_parts(15_392_000, 0)
+			.saturating_add(Weight::from_parts(0, 3546))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: Web3Names Owner (r:1 w:0)
+	/// Proof: Web3Names Owner (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
+	/// Storage: Web3Names CounterForOwner (r:1 w:1)
+	/// Proof: Web3Names CounterForOwner (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	fn initialize_owner_counter_step() -> Weight {
+		Weight::from_parts(10_015_000, 0)
+			.saturating_add(Weight::from_parts(0, 3602))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
 
 // For backwards compatibility and tests
@@ -236,26 +406,6 @@ impl WeightInfo for () {
 	}
 	/// Storage: Web3Names Banned (r:1 w:1)
 	/// Proof: Web3Names Banned (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
-	/// Storage: Web3Names Owner (r:1 w:1)
-	/// Proof: Web3Names Owner (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	/// Storage: Web3Names Names (r:0 w:1)
-	/// Proof: Web3Names Names (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
-	/// The range of component `n` is `[3, 32]`.
-	fn ban(n: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `342 + n * (1 ±0)`
-		//  Estimated: `7743`
-		// Minimum execution time: 18_069 nanoseconds.
-		Weight::from_parts(19_324_271, 7743)
-			// Standard Error: 12_698
-			.saturating_add(Weight::from_parts(62_295, 0).saturating_mul(n.into()))
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
-	}
-	/// Storage: Web3Names Banned (r:1 w:1)
-	/// Proof: Web3Names Banned (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
 	/// The range of component `n` is `[3, 32]`.
 	fn unban(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -296,4 +446,181 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
+	// The weights below are not taken from the benchmark run above. They are
+	// estimated from the storage accesses of each call.
+	/// Storage: Web3Names Banned (r:1 w:0)
+	/// Proof: Web3Names Banned (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Web3Names ScheduledBans (r:1 w:1)
+	/// Proof: Web3Names ScheduledBans (max_values: None, max_size: Some(58), added: 2533, mode: MaxEncodedLen)
+	/// Storage: Web3Names BanQueue (r:1 w:1)
+	/// Proof: Web3Names BanQueue (max_values: None, max_size: Some(545), added: 3020, mode: MaxEncodedLen)
+	/// The range of component `n` is `[3, 32]`.
+	fn ban(n: u32, ) -> Weight {
+		Weight::from_parts(21_376_517, 8077)
+			.saturating_add(Weight::from_parts(41_027, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: Web3Names Owner (r:1 w:0)
+	/// Proof: Web3Names Owner (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
+	/// Storage: Web3Names ScheduledBans (r:1 w:1)
+	/// Proof: Web3Names ScheduledBans (max_values: None, max_size: Some(58), added: 2533, mode: MaxEncodedLen)
+	/// The range of component `n` is `[3, 32]`.
+	fn appeal_ban(n: u32, ) -> Weight {
+		Weight::from_parts(18_935_517, 5145)
+			.saturating_add(Weight::from_parts(41_027, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Web3Names ScheduledBans (r:1 w:1)
+	/// Proof: Web3Names ScheduledBans (max_values: None, max_size: Some(58), added: 2533, mode: MaxEncodedLen)
+	/// Storage: Web3Names Owner (r:1 w:1)
+	/// Proof: Web3Names Owner (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: Web3Names Banned (r:0 w:1)
+	/// Proof: Web3Names Banned (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Web3Names Names (r:0 w:1)
+	/// Proof: Web3Names Names (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// The range of component `n` is `[3, 32]`.
+	fn resolve_ban_appeal(n: u32, ) -> Weight {
+		Weight::from_parts(30_412_517, 7752)
+			.saturating_add(Weight::from_parts(41_027, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: Web3Names ScheduledBans (r:1 w:1)
+	/// Proof: Web3Names ScheduledBans (max_values: None, max_size: Some(58), added: 2533, mode: MaxEncodedLen)
+	/// Storage: Web3Names BanQueue (r:1 w:1)
+	/// Proof: Web3Names BanQueue (max_values: None, max_size: Some(545), added: 3020, mode: MaxEncodedLen)
+	/// The range of component `n` is `[3, 32]`.
+	fn cancel_ban(n: u32, ) -> Weight {
+		Weight::from_parts(17_935_517, 5553)
+			.saturating_add(Weight::from_parts(41_027, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: Web3Names BanQueue (r:1 w:1)
+	/// Proof: Web3Names BanQueue (max_values: None, max_size: Some(545), added: 3020, mode: MaxEncodedLen)
+	/// The range of component `n` is `[0, 16]`.
+	fn enact_bans(n: u32, ) -> Weight {
+		Weight::from_parts(4_529_517, 3020)
+			.saturating_add(Weight::from_parts(24_937_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((5_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 7752).saturating_mul(n.into()))
+	}
+	/// Storage: Web3Names Names (r:50 w:0)
+	/// Proof: Web3Names Names (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: Web3Names Owner (r:50 w:0)
+	/// Proof: Web3Names Owner (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
+	/// Storage: Web3Names Banned (r:50 w:0)
+	/// Proof: Web3Names Banned (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Web3Names PendingClaims (r:0 w:50)
+	/// Proof: Web3Names PendingClaims (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// The range of component `n` is `[0, 50]`.
+	fn claim_batch(n: u32, ) -> Weight {
+		Weight::from_parts(5_893_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(Weight::from_parts(12_468_209, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 7692).saturating_mul(n.into()))
+	}
+	/// Storage: Web3Names Registrars (r:1 w:1)
+	/// Proof: Web3Names Registrars (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	fn approve_registrar() -> Weight {
+		Weight::from_parts(14_312_000, 0)
+			.saturating_add(Weight::from_parts(0, 3565))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: Web3Names Registrars (r:1 w:1)
+	/// Proof: Web3Names Registrars (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	fn remove_registrar() -> Weight {
+		Weight::from_parts(15_108_000, 0)
+			.saturating_add(Weight::from_parts(0, 3565))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: Web3Names Registrars (r:1 w:1)
+	/// Proof: Web3Names Registrars (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: Web3Names Names (r:1 w:1)
+	/// Proof: Web3Names Names (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: Web3Names Owner (r:1 w:1)
+	/// Proof: Web3Names Owner (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
+	/// Storage: Web3Names Banned (r:1 w:0)
+	/// Proof: Web3Names Banned (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: System Account (r:3 w:3)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	fn claim_by_registrar(_n: u32, ) -> Weight {
+		Weight::from_parts(93_137_000, 0)
+			.saturating_add(Weight::from_parts(0, 18088))
+			.saturating_add(RocksDbWeight::get().reads(7))
+			.saturating_add(RocksDbWeight::get().writes(6))
+	}
+	/// Storage: Web3Names Names (r:1 w:0)
+	/// Proof: Web3Names Names (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: Web3Names Records (r:1 w:1)
+	/// Proof: Web3Names Records (max_values: None, max_size: Some(404), added: 2879, mode: MaxEncodedLen)
+	/// Storage: Web3Names RecordsCount (r:1 w:1)
+	/// Proof: Web3Names RecordsCount (max_values: None, max_size: Some(53), added: 2528, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	fn set_record() -> Weight {
+		Weight::from_parts(41_837_000, 0)
+			.saturating_add(Weight::from_parts(0, 15417))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: Web3Names Names (r:1 w:0)
+	/// Proof: Web3Names Names (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: Web3Names Records (r:1 w:1)
+	/// Proof: Web3Names Records (max_values: None, max_size: Some(404), added: 2879, mode: MaxEncodedLen)
+	/// Storage: Web3Names RecordsCount (r:1 w:1)
+	/// Proof: Web3Names RecordsCount (max_values: None, max_size: Some(53), added: 2528, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	fn clear_record() -> Weight {
+		Weight::from_parts(38_204_000, 0)
+			.saturating_add(Weight::from_parts(0, 15417))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: Web3Names PendingClaims (r:1 w:1)
+	/// Proof: Web3Names PendingClaims (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: Web3Names Names (r:1 w:1)
+	/// Proof: Web3Names Names (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: Web3Names Owner (r:1 w:1)
+	/// Proof: Web3Names Owner (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
+	/// Storage: Web3Names Banned (r:1 w:0)
+	/// Proof: Web3Names Banned (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	fn accept_pending_claim() -> Weight {
+		Weight::from_parts(69_854_000, 0)
+			.saturating_add(Weight::from_parts(0, 12855))
+			.saturating_add(RocksDbWeight::get().reads(5))
+			.saturating_add(RocksDbWeight::get().writes(4))
+	}
+	/// Storage: Web3Names PendingClaims (r:1 w:1)
+	/// Proof: Web3Names PendingClaims (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	fn decline_pending_claim() -> Weight {
+		Weight::from_parts(15_392_000, 0)
+			.saturating_add(Weight::from_parts(0, 3546))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: Web3Names Owner (r:1 w:0)
+	/// Proof: Web3Names Owner (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
+	/// Storage: Web3Names CounterForOwner (r:1 w:1)
+	/// Proof: Web3Names CounterForOwner (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	fn initialize_owner_counter_step() -> Weight {
+		Weight::from_parts(10_015_000, 0)
+			.saturating_add(Weight::from_parts(0, 3602))
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
 }
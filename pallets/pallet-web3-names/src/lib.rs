@@ -21,7 +21,7 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
-mod default_weights;
+pub mod default_weights;
 
 pub mod migrations;
 pub mod web3_name;
@@ -44,15 +44,20 @@ pub mod pallet {
 	use frame_support::{
 		pallet_prelude::*,
 		sp_runtime::SaturatedConversion,
+		storage::with_storage_layer,
 		traits::{
-			fungible::{Inspect, InspectHold, MutateHold},
+			fungible::{Inspect, Mutate, MutateHold},
+			tokens::Preservation,
 			StorageVersion,
 		},
 		Blake2_128Concat,
 	};
 	use frame_system::pallet_prelude::*;
 	use parity_scale_codec::FullCodec;
-	use sp_runtime::DispatchError;
+	use sp_runtime::{
+		traits::{One, Saturating, Zero},
+		DispatchError, Perbill,
+	};
 	use sp_std::{fmt::Debug, vec::Vec};
 
 	use kilt_support::{
@@ -61,9 +66,11 @@ pub mod pallet {
 	};
 
 	use super::WeightInfo;
-	use crate::web3_name::Web3NameOwnership;
+	use crate::web3_name::{BanStatus, RegistrarDetails, ScheduledBan, Web3NameOwnership, Web3NameRecord};
 
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+	/// The current storage version, which is set by the last stepped migration
+	/// of the pallet.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(crate::migrations::OWNER_COUNTER_STORAGE_VERSION);
 
 	pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
 	pub type Web3NameOwnerOf<T> = <T as Config>::Web3NameOwner;
@@ -71,6 +78,13 @@ pub mod pallet {
 	pub type Web3NameOf<T> = <T as Config>::Web3Name;
 	pub type Web3OwnershipOf<T> =
 		Web3NameOwnership<Web3NameOwnerOf<T>, Deposit<AccountIdOf<T>, BalanceOf<T>>, BlockNumberFor<T>>;
+	pub type ScheduledBanOf<T> = ScheduledBan<BlockNumberFor<T>>;
+	pub type RegistrarDetailsOf<T> = RegistrarDetails<BalanceOf<T>>;
+	pub type Web3NameRecordKeyOf<T> = BoundedVec<u8, <T as Config>::MaxRecordKeyLength>;
+	pub type Web3NameRecordValueOf<T> = BoundedVec<u8, <T as Config>::MaxRecordValueLength>;
+	pub type Web3NameRecordOf<T> = Web3NameRecord<Web3NameRecordValueOf<T>, Deposit<AccountIdOf<T>, BalanceOf<T>>>;
+	pub type Web3NameClaimBatchOf<T> =
+		BoundedVec<(Web3NameInput<T>, Web3NameOwnerOf<T>), <T as Config>::MaxClaimBatchSize>;
 
 	pub(crate) type BalanceMigrationManagerOf<T> = <T as Config>::BalanceMigrationManager;
 	pub(crate) type CurrencyOf<T> = <T as Config>::Currency;
@@ -80,10 +94,10 @@ pub mod pallet {
 	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
-	/// Map of name -> ownership details.
+	/// Map of name -> ownership details, counting the claimed names.
 	#[pallet::storage]
 	#[pallet::getter(fn owner)]
-	pub type Owner<T> = StorageMap<_, Blake2_128Concat, Web3NameOf<T>, Web3OwnershipOf<T>>;
+	pub type Owner<T> = CountedStorageMap<_, Blake2_128Concat, Web3NameOf<T>, Web3OwnershipOf<T>>;
 
 	/// Map of owner -> name.
 	#[pallet::storage]
@@ -97,6 +111,58 @@ pub mod pallet {
 	#[pallet::getter(fn is_banned)]
 	pub type Banned<T> = StorageMap<_, Blake2_128Concat, Web3NameOf<T>, ()>;
 
+	/// Map of name -> scheduled ban.
+	///
+	/// A name is present while a ban against it is waiting to be enacted,
+	/// either because its enactment block has not been reached yet or because
+	/// an appeal against it is still pending.
+	#[pallet::storage]
+	#[pallet::getter(fn scheduled_ban)]
+	pub type ScheduledBans<T> = StorageMap<_, Blake2_128Concat, Web3NameOf<T>, ScheduledBanOf<T>>;
+
+	/// Map of block number -> names whose ban is enacted at the beginning of
+	/// that block.
+	#[pallet::storage]
+	pub type BanQueue<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<Web3NameOf<T>, <T as Config>::MaxBansPerBlock>,
+		ValueQuery,
+	>;
+
+	/// Map of approved registrars to their fee and issuance statistics.
+	///
+	/// It maps from a registrar account to its details.
+	#[pallet::storage]
+	#[pallet::getter(fn registrar)]
+	pub type Registrars<T> = StorageMap<_, Blake2_128Concat, AccountIdOf<T>, RegistrarDetailsOf<T>>;
+
+	/// Map of owner -> name proposed to it in a claim batch.
+	///
+	/// An owner is present while it has not accepted nor declined the name
+	/// proposed to it.
+	#[pallet::storage]
+	#[pallet::getter(fn pending_claim)]
+	pub type PendingClaims<T> = StorageMap<_, Blake2_128Concat, Web3NameOwnerOf<T>, Web3NameOf<T>>;
+
+	/// Map of (name, record key) -> record.
+	#[pallet::storage]
+	#[pallet::getter(fn record)]
+	pub type Records<T> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		Web3NameOf<T>,
+		Blake2_128Concat,
+		Web3NameRecordKeyOf<T>,
+		Web3NameRecordOf<T>,
+	>;
+
+	/// Map of name -> number of records attached to it.
+	#[pallet::storage]
+	#[pallet::getter(fn records_count)]
+	pub type RecordsCount<T> = StorageMap<_, Blake2_128Concat, Web3NameOf<T>, u32, ValueQuery>;
+
 	#[pallet::composite_enum]
 	pub enum HoldReason {
 		Deposit,
@@ -106,6 +172,30 @@ pub mod pallet {
 	pub trait Config: frame_system::Config {
 		/// The origin allowed to ban names.
 		type BanOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// The origin allowed to decide on appeals against scheduled bans.
+		type BanAppealOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// The number of blocks between a ban being scheduled and it taking
+		/// effect, during which the owner of the name can appeal against it.
+		/// A delay of zero enacts the ban at the beginning of the next block.
+		#[pallet::constant]
+		type BanDelay: Get<BlockNumberFor<Self>>;
+		/// The maximum number of bans that can take effect in the same block.
+		#[pallet::constant]
+		type MaxBansPerBlock: Get<u32>;
+		/// The origin allowed to propose names in bulk to their owners.
+		type RegistrarOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// The maximum number of names that can be proposed in a single batch.
+		#[pallet::constant]
+		type MaxClaimBatchSize: Get<u32>;
+		/// The origin allowed to approve and remove registrars through which
+		/// names are claimed for a fee.
+		type RegistrarApprovalOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// The portion of each registrar fee that is routed to the treasury.
+		/// The rest is paid to the registrar.
+		#[pallet::constant]
+		type RegistrarTreasuryShare: Get<Perbill>;
+		/// The account receiving the treasury share of registrar fees.
+		type TreasuryAccount: Get<AccountIdOf<Self>>;
 		/// The origin allowed to perform regular operations.
 		type OwnerOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin, Success = Self::OriginSuccess>;
 		/// The type of origin after a successful origin check.
@@ -113,7 +203,7 @@ pub mod pallet {
 		/// Aggregated hold reason.
 		type RuntimeHoldReason: From<HoldReason>;
 		/// The currency type to reserve and release deposits.
-		type Currency: MutateHold<AccountIdOf<Self>, Reason = Self::RuntimeHoldReason>;
+		type Currency: MutateHold<AccountIdOf<Self>, Reason = Self::RuntimeHoldReason> + Mutate<AccountIdOf<Self>>;
 		/// The amount of KILT to deposit to claim a name.
 		#[pallet::constant]
 		type Deposit: Get<BalanceOf<Self>>;
@@ -138,6 +228,18 @@ pub mod pallet {
 			+ Ord;
 		/// The type of a name owner.
 		type Web3NameOwner: Parameter + MaxEncodedLen;
+		/// The max encoded length of a record key.
+		#[pallet::constant]
+		type MaxRecordKeyLength: Get<u32>;
+		/// The max encoded length of a record value.
+		#[pallet::constant]
+		type MaxRecordValueLength: Get<u32>;
+		/// The maximum number of records that can be attached to a name.
+		#[pallet::constant]
+		type MaxRecordsPerName: Get<u32>;
+		/// The amount of KILT to deposit for each record attached to a name.
+		#[pallet::constant]
+		type RecordDeposit: Get<BalanceOf<Self>>;
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 
@@ -146,7 +248,6 @@ pub mod pallet {
 	}
 
 	#[pallet::event]
-	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
 		/// A new name has been claimed.
 		Web3NameClaimed {
@@ -162,6 +263,63 @@ pub mod pallet {
 		Web3NameBanned { name: Web3NameOf<T> },
 		/// A name has been unbanned.
 		Web3NameUnbanned { name: Web3NameOf<T> },
+		/// A ban has been scheduled for a name.
+		Web3NameBanScheduled {
+			name: Web3NameOf<T>,
+			enactment_block: BlockNumberFor<T>,
+		},
+		/// A scheduled ban has been cancelled before taking effect.
+		Web3NameBanCancelled { name: Web3NameOf<T> },
+		/// The owner of a name has appealed against its scheduled ban.
+		Web3NameBanAppealed {
+			owner: Web3NameOwnerOf<T>,
+			name: Web3NameOf<T>,
+		},
+		/// An appeal has been accepted and the scheduled ban cancelled.
+		Web3NameBanAppealAccepted { name: Web3NameOf<T> },
+		/// An appeal has been rejected and the scheduled ban will take effect.
+		Web3NameBanAppealRejected { name: Web3NameOf<T> },
+		/// A name has been proposed to its owner in a claim batch.
+		Web3NameClaimProposed {
+			owner: Web3NameOwnerOf<T>,
+			name: Web3NameOf<T>,
+		},
+		/// The name at the given position of a claim batch could not be
+		/// proposed.
+		Web3NameBatchClaimFailed { index: u32, error: DispatchError },
+		/// A claim batch has been processed.
+		Web3NameBatchProposed { proposed: u32, failed: u32 },
+		/// The owner of a pending claim has declined the name proposed to it.
+		Web3NameClaimDeclined {
+			owner: Web3NameOwnerOf<T>,
+			name: Web3NameOf<T>,
+		},
+		/// A registrar has been approved to have names claimed through it for
+		/// a fee.
+		RegistrarApproved {
+			registrar: AccountIdOf<T>,
+			fee: BalanceOf<T>,
+		},
+		/// A registrar has been removed.
+		RegistrarRemoved { registrar: AccountIdOf<T> },
+		/// A name has been claimed through a registrar.
+		Web3NameClaimedByRegistrar {
+			registrar: AccountIdOf<T>,
+			owner: Web3NameOwnerOf<T>,
+			name: Web3NameOf<T>,
+			fee: BalanceOf<T>,
+			treasury_share: BalanceOf<T>,
+		},
+		/// A record of a name has been set.
+		Web3NameRecordSet {
+			name: Web3NameOf<T>,
+			key: Web3NameRecordKeyOf<T>,
+		},
+		/// A record of a name has been cleared.
+		Web3NameRecordCleared {
+			name: Web3NameOf<T>,
+			key: Web3NameRecordKeyOf<T>,
+		},
 	}
 
 	#[pallet::error]
@@ -191,16 +349,70 @@ pub mod pallet {
 		TooLong,
 		/// A name that contains not allowed characters is being claimed.
 		InvalidCharacter,
+		/// A ban has already been scheduled for the specified name.
+		BanAlreadyScheduled,
+		/// No ban has been scheduled for the specified name.
+		BanNotScheduled,
+		/// The scheduled ban has already been appealed against, or the appeal
+		/// has been rejected.
+		AppealNotAllowed,
+		/// There is no pending appeal against the scheduled ban.
+		NoPendingAppeal,
+		/// The maximum number of bans taking effect in the same block has been
+		/// reached.
+		TooManyScheduledBans,
+		/// The specified account is already an approved registrar.
+		RegistrarAlreadyApproved,
+		/// The specified account is not an approved registrar.
+		RegistrarNotFound,
+		/// The fee of the registrar is higher than the maximum fee accepted by
+		/// the sender.
+		RegistrarFeeTooHigh,
+		/// The specified record does not exist.
+		RecordNotFound,
+		/// The maximum number of records attached to a name has been reached.
+		TooManyRecords,
+		/// The specified owner has no pending claim.
+		PendingClaimNotFound,
 	}
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+			let queued_names = BanQueue::<T>::take(n);
+			let queued_names_count: u32 = queued_names.len().saturated_into();
+			let weight = <T as Config>::WeightInfo::enact_bans(queued_names_count)
+				.saturating_add(Self::records_removal_weight().saturating_mul(queued_names_count.into()));
+
+			for name in queued_names {
+				// Bans under appeal are enacted once the appeal is rejected.
+				if Self::scheduled_ban(&name).map_or(true, |ban| ban.status == BanStatus::Appealed) {
+					continue;
+				}
+				if let Err(e) = with_storage_layer(|| Self::enact_ban(&name)) {
+					log::error!("Failed to enact ban for web3 name {:?} with error {:?}", name, e);
+				}
+			}
+
+			weight
+		}
+
 		#[cfg(feature = "try-runtime")]
 		fn try_state(_n: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
 			crate::try_state::do_try_state::<T>()
 		}
 	}
 
+	kilt_support::impl_identity_event_deposit!();
+
+	impl<T: Config> Pallet<T> {
+		/// The worst-case weight of removing all the records of a name when it
+		/// is released.
+		pub(crate) fn records_removal_weight() -> Weight {
+			<T as Config>::WeightInfo::clear_record().saturating_mul(T::MaxRecordsPerName::get().into())
+		}
+	}
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Assign the specified name to the owner as specified in the
@@ -245,7 +457,7 @@ pub mod pallet {
 		/// - Writes: Names, Owner storage entries + currency deposit release
 		/// # </weight>
 		#[pallet::call_index(1)]
-		#[pallet::weight(<T as Config>::WeightInfo::release_by_owner())]
+		#[pallet::weight(<T as Config>::WeightInfo::release_by_owner().saturating_add(Pallet::<T>::records_removal_weight()))]
 		pub fn release_by_owner(origin: OriginFor<T>) -> DispatchResult {
 			let origin = T::OwnerOrigin::ensure_origin(origin)?;
 			let owner = origin.subject();
@@ -270,7 +482,7 @@ pub mod pallet {
 		/// - Writes: Names, Owner storage entries + currency deposit release
 		/// # </weight>
 		#[pallet::call_index(2)]
-		#[pallet::weight(<T as Config>::WeightInfo::reclaim_deposit(name.len().saturated_into()))]
+		#[pallet::weight(<T as Config>::WeightInfo::reclaim_deposit(name.len().saturated_into()).saturating_add(Pallet::<T>::records_removal_weight()))]
 		pub fn reclaim_deposit(origin: OriginFor<T>, name: Web3NameInput<T>) -> DispatchResult {
 			let caller = ensure_signed(origin)?;
 
@@ -281,35 +493,50 @@ pub mod pallet {
 			Ok(())
 		}
 
-		/// Ban a name.
+		/// Schedule the ban of a name.
 		///
-		/// A banned name cannot be claimed by anyone. The name's deposit
-		/// is returned to the original payer.
+		/// The ban takes effect `BanDelay` blocks later, unless it is
+		/// cancelled or the owner of the name successfully appeals against it
+		/// in the meantime. Once enacted, the name cannot be claimed by
+		/// anyone and its deposit is returned to the original payer.
 		///
 		/// The origin must be the ban origin.
 		///
-		/// Emits `Web3NameBanned` if the operation is carried out
+		/// Emits `Web3NameBanScheduled` if the operation is carried out
 		/// successfully.
 		///
 		/// # <weight>
 		/// Weight: O(1)
-		/// - Reads: Banned, Owner, Names storage entries + origin check
-		/// - Writes: Names, Owner, Banned storage entries + currency deposit
-		///   release
+		/// - Reads: Banned, ScheduledBans, BanQueue storage entries + origin
+		///   check
+		/// - Writes: ScheduledBans, BanQueue storage entries
 		/// # </weight>
 		#[pallet::call_index(3)]
 		#[pallet::weight(<T as Config>::WeightInfo::ban(name.len().saturated_into()))]
 		pub fn ban(origin: OriginFor<T>, name: Web3NameInput<T>) -> DispatchResult {
 			T::BanOrigin::ensure_origin(origin)?;
 
-			let (decoded_name, is_claimed) = Self::check_banning_preconditions(name)?;
-
-			if is_claimed {
-				Self::unregister_name(&decoded_name)?;
-			}
+			let decoded_name = Self::check_banning_preconditions(name)?;
+
+			let enactment_block = frame_system::Pallet::<T>::block_number()
+				.saturating_add(T::BanDelay::get().max(BlockNumberFor::<T>::one()));
+			BanQueue::<T>::try_mutate(enactment_block, |queue| {
+				queue
+					.try_push(decoded_name.clone())
+					.map_err(|_| Error::<T>::TooManyScheduledBans)
+			})?;
+			ScheduledBans::<T>::insert(
+				&decoded_name,
+				ScheduledBanOf::<T> {
+					enactment_block,
+					status: BanStatus::Scheduled,
+				},
+			);
 
-			Self::ban_name(&decoded_name);
-			Self::deposit_event(Event::<T>::Web3NameBanned { name: decoded_name });
+			Self::deposit_event(Event::<T>::Web3NameBanScheduled {
+				name: decoded_name,
+				enactment_block,
+			});
 
 			Ok(())
 		}
@@ -377,6 +604,438 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Appeal against the scheduled ban of a name.
+		///
+		/// The ban is not enacted until the appeal is resolved by the ban
+		/// appeal origin. A ban can only be appealed against once.
+		///
+		/// The subject of the call must be the owner of the name.
+		///
+		/// Emits `Web3NameBanAppealed` if the operation is carried out
+		/// successfully.
+		///
+		/// # <weight>
+		/// Weight: O(1)
+		/// - Reads: Owner, ScheduledBans storage entries + origin check
+		/// - Writes: ScheduledBans storage entry
+		/// # </weight>
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config>::WeightInfo::appeal_ban(name.len().saturated_into()))]
+		pub fn appeal_ban(origin: OriginFor<T>, name: Web3NameInput<T>) -> DispatchResult {
+			let origin = T::OwnerOrigin::ensure_origin(origin)?;
+			let owner = origin.subject();
+
+			let decoded_name = Web3NameOf::<T>::try_from(name.into_inner()).map_err(DispatchError::from)?;
+			let Web3NameOwnership { owner: name_owner, .. } =
+				Owner::<T>::get(&decoded_name).ok_or(Error::<T>::NotFound)?;
+			ensure!(name_owner == owner, Error::<T>::NotAuthorized);
+
+			ScheduledBans::<T>::try_mutate(&decoded_name, |scheduled_ban| -> DispatchResult {
+				let scheduled_ban = scheduled_ban.as_mut().ok_or(Error::<T>::BanNotScheduled)?;
+				ensure!(
+					scheduled_ban.status == BanStatus::Scheduled,
+					Error::<T>::AppealNotAllowed
+				);
+				scheduled_ban.status = BanStatus::Appealed;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::<T>::Web3NameBanAppealed {
+				owner,
+				name: decoded_name,
+			});
+
+			Ok(())
+		}
+
+		/// Resolve a pending appeal against the scheduled ban of a name.
+		///
+		/// If the appeal is accepted, the ban is cancelled. Otherwise, the ban
+		/// is enacted right away if its enactment block has already passed, or
+		/// at the scheduled block otherwise.
+		///
+		/// The origin must be the ban appeal origin.
+		///
+		/// Emits `Web3NameBanAppealAccepted` or `Web3NameBanAppealRejected`
+		/// if the operation is carried out successfully.
+		///
+		/// # <weight>
+		/// Weight: O(1)
+		/// - Reads: ScheduledBans, BanQueue, Owner storage entries + origin
+		///   check
+		/// - Writes: ScheduledBans, BanQueue, Names, Owner, Banned storage
+		///   entries + currency deposit release
+		/// # </weight>
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as Config>::WeightInfo::resolve_ban_appeal(name.len().saturated_into()).saturating_add(Pallet::<T>::records_removal_weight()))]
+		pub fn resolve_ban_appeal(origin: OriginFor<T>, name: Web3NameInput<T>, accepted: bool) -> DispatchResult {
+			T::BanAppealOrigin::ensure_origin(origin)?;
+
+			let decoded_name = Web3NameOf::<T>::try_from(name.into_inner()).map_err(DispatchError::from)?;
+			let scheduled_ban = ScheduledBans::<T>::get(&decoded_name).ok_or(Error::<T>::BanNotScheduled)?;
+			ensure!(scheduled_ban.status == BanStatus::Appealed, Error::<T>::NoPendingAppeal);
+
+			if accepted {
+				Self::remove_scheduled_ban(&decoded_name, scheduled_ban.enactment_block);
+				Self::deposit_event(Event::<T>::Web3NameBanAppealAccepted { name: decoded_name });
+			} else {
+				Self::deposit_event(Event::<T>::Web3NameBanAppealRejected {
+					name: decoded_name.clone(),
+				});
+				if scheduled_ban.enactment_block <= frame_system::Pallet::<T>::block_number() {
+					Self::enact_ban(&decoded_name)?;
+				} else {
+					ScheduledBans::<T>::insert(
+						&decoded_name,
+						ScheduledBanOf::<T> {
+							status: BanStatus::AppealRejected,
+							..scheduled_ban
+						},
+					);
+				}
+			}
+
+			Ok(())
+		}
+
+		/// Cancel the scheduled ban of a name before it takes effect.
+		///
+		/// The origin must be the ban origin.
+		///
+		/// Emits `Web3NameBanCancelled` if the operation is carried out
+		/// successfully.
+		///
+		/// # <weight>
+		/// Weight: O(1)
+		/// - Reads: ScheduledBans, BanQueue storage entries + origin check
+		/// - Writes: ScheduledBans, BanQueue storage entries
+		/// # </weight>
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_ban(name.len().saturated_into()))]
+		pub fn cancel_ban(origin: OriginFor<T>, name: Web3NameInput<T>) -> DispatchResult {
+			T::BanOrigin::ensure_origin(origin)?;
+
+			let decoded_name = Web3NameOf::<T>::try_from(name.into_inner()).map_err(DispatchError::from)?;
+			let scheduled_ban = ScheduledBans::<T>::get(&decoded_name).ok_or(Error::<T>::BanNotScheduled)?;
+
+			Self::remove_scheduled_ban(&decoded_name, scheduled_ban.enactment_block);
+			Self::deposit_event(Event::<T>::Web3NameBanCancelled { name: decoded_name });
+
+			Ok(())
+		}
+
+		/// Propose each of the specified names to the specified owner.
+		///
+		/// No name is assigned nor any deposit reserved by this call. Each
+		/// owner must accept the name proposed to it with
+		/// `accept_pending_claim`, paying the deposit like with `claim`.
+		/// Names that cannot be claimed do not make the whole batch fail, but
+		/// are reported individually instead. A new proposal for an owner
+		/// replaces the one it has not accepted yet.
+		///
+		/// The origin must be the registrar origin.
+		///
+		/// Emits `Web3NameClaimProposed` for each name proposed,
+		/// `Web3NameBatchClaimFailed` for each name that could not be
+		/// proposed, and `Web3NameBatchProposed` once the whole batch has been
+		/// processed.
+		///
+		/// # <weight>
+		/// Weight: O(N) where N is the number of names in the batch
+		/// - Reads: Names, Owner, Banned storage entries for each name + origin
+		///   check
+		/// - Writes: PendingClaims storage entry for each name
+		/// # </weight>
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as Config>::WeightInfo::claim_batch(names.len().saturated_into()))]
+		pub fn claim_batch(origin: OriginFor<T>, names: Web3NameClaimBatchOf<T>) -> DispatchResult {
+			T::RegistrarOrigin::ensure_origin(origin)?;
+
+			let mut proposed = 0u32;
+			let mut failed = 0u32;
+			for (index, (name, owner)) in names.into_iter().enumerate() {
+				let result = Web3NameOf::<T>::try_from(name.into_inner())
+					.map_err(DispatchError::from)
+					.and_then(|decoded_name| {
+						Self::check_name_availability(&decoded_name, &owner)?;
+						Ok(decoded_name)
+					});
+				match result {
+					Ok(decoded_name) => {
+						proposed.saturating_inc();
+						PendingClaims::<T>::insert(&owner, decoded_name.clone());
+						Self::deposit_event(Event::<T>::Web3NameClaimProposed {
+							owner,
+							name: decoded_name,
+						});
+					}
+					Err(error) => {
+						failed.saturating_inc();
+						Self::deposit_event(Event::<T>::Web3NameBatchClaimFailed {
+							index: index.saturated_into(),
+							error,
+						});
+					}
+				}
+			}
+
+			Self::deposit_event(Event::<T>::Web3NameBatchProposed { proposed, failed });
+
+			Ok(())
+		}
+
+		/// Approve the provided account as a registrar through which names
+		/// can be claimed for the provided fee.
+		///
+		/// The origin must be the registrar approval origin.
+		///
+		/// Emits `RegistrarApproved` if the operation is carried out
+		/// successfully.
+		///
+		/// # <weight>
+		/// Weight: O(1)
+		/// - Reads: Registrars storage entry + origin check
+		/// - Writes: Registrars storage entry
+		/// # </weight>
+		#[pallet::call_index(11)]
+		#[pallet::weight(<T as Config>::WeightInfo::approve_registrar())]
+		pub fn approve_registrar(origin: OriginFor<T>, registrar: AccountIdOf<T>, fee: BalanceOf<T>) -> DispatchResult {
+			T::RegistrarApprovalOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				!Registrars::<T>::contains_key(&registrar),
+				Error::<T>::RegistrarAlreadyApproved
+			);
+
+			Registrars::<T>::insert(
+				&registrar,
+				RegistrarDetailsOf::<T> {
+					fee,
+					names_issued: 0,
+					fees_collected: Zero::zero(),
+					fees_to_treasury: Zero::zero(),
+				},
+			);
+
+			Self::deposit_event(Event::<T>::RegistrarApproved { registrar, fee });
+
+			Ok(())
+		}
+
+		/// Remove the provided registrar, together with its issuance
+		/// statistics. Names previously claimed through the registrar are
+		/// not affected.
+		///
+		/// The origin must be the registrar approval origin.
+		///
+		/// Emits `RegistrarRemoved` if the operation is carried out
+		/// successfully.
+		///
+		/// # <weight>
+		/// Weight: O(1)
+		/// - Reads: Registrars storage entry + origin check
+		/// - Writes: Registrars storage entry
+		/// # </weight>
+		#[pallet::call_index(12)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_registrar())]
+		pub fn remove_registrar(origin: OriginFor<T>, registrar: AccountIdOf<T>) -> DispatchResult {
+			T::RegistrarApprovalOrigin::ensure_origin(origin)?;
+
+			Registrars::<T>::take(&registrar).ok_or(Error::<T>::RegistrarNotFound)?;
+
+			Self::deposit_event(Event::<T>::RegistrarRemoved { registrar });
+
+			Ok(())
+		}
+
+		/// Claim the provided name for the owner as specified in the origin,
+		/// through the provided registrar.
+		///
+		/// The name is claimed under the same conditions as with `claim`. The
+		/// sender of the call pays both the name deposit and the registrar
+		/// fee, of which the configured treasury share is transferred to the
+		/// treasury and the rest to the registrar. The registrar has no
+		/// control over the claimed name.
+		///
+		/// The call fails if the current fee of the registrar is higher than
+		/// `max_fee`, so that a fee raised by the registrar before the call is
+		/// included is never charged without the consent of the sender.
+		///
+		/// Emits `Web3NameClaimed` and `Web3NameClaimedByRegistrar` if the
+		/// operation is carried out successfully.
+		///
+		/// # <weight>
+		/// Weight: O(1)
+		/// - Reads: Registrars, Names, Owner, Banned storage entries +
+		///   available currency check + origin check
+		/// - Writes: Registrars, Names, Owner storage entries + currency
+		///   deposit reserve + registrar and treasury transfers
+		/// # </weight>
+		#[pallet::call_index(13)]
+		#[pallet::weight(<T as Config>::WeightInfo::claim_by_registrar(name.len().saturated_into()))]
+		pub fn claim_by_registrar(
+			origin: OriginFor<T>,
+			name: Web3NameInput<T>,
+			registrar: AccountIdOf<T>,
+			max_fee: BalanceOf<T>,
+		) -> DispatchResult {
+			let origin = T::OwnerOrigin::ensure_origin(origin)?;
+			let payer = origin.sender();
+			let owner = origin.subject();
+			let mut registrar_details = Registrars::<T>::get(&registrar).ok_or(Error::<T>::RegistrarNotFound)?;
+			ensure!(registrar_details.fee <= max_fee, Error::<T>::RegistrarFeeTooHigh);
+
+			let decoded_name = Self::check_claiming_preconditions(name, &owner, &payer)?;
+
+			Self::register_name(decoded_name.clone(), owner.clone(), payer.clone())?;
+
+			let fee = registrar_details.fee;
+			let treasury_share = T::RegistrarTreasuryShare::get().mul_floor(fee);
+			for (destination, amount) in [
+				(T::TreasuryAccount::get(), treasury_share),
+				(registrar.clone(), fee.saturating_sub(treasury_share)),
+			] {
+				if !amount.is_zero() {
+					CurrencyOf::<T>::transfer(&payer, &destination, amount, Preservation::Preserve)
+						.map_err(|_| Error::<T>::InsufficientFunds)?;
+				}
+			}
+
+			registrar_details.names_issued.saturating_inc();
+			registrar_details.fees_collected.saturating_accrue(fee);
+			registrar_details.fees_to_treasury.saturating_accrue(treasury_share);
+			Registrars::<T>::insert(&registrar, registrar_details);
+
+			Self::deposit_event(Event::<T>::Web3NameClaimedByRegistrar {
+				registrar,
+				owner,
+				name: decoded_name,
+				fee,
+				treasury_share,
+			});
+
+			Ok(())
+		}
+
+		/// Set the value of a record of the name owned by the subject of the
+		/// origin, e.g., an avatar hash, a URL or any other text record.
+		///
+		/// If the record does not exist yet, a deposit is reserved from the
+		/// sender of the call. Otherwise, its value is replaced and its
+		/// deposit is left untouched.
+		///
+		/// Emits `Web3NameRecordSet` if the operation is carried out
+		/// successfully.
+		///
+		/// # <weight>
+		/// Weight: O(1)
+		/// - Reads: Names, Records, RecordsCount storage entries + available
+		///   currency check + origin check
+		/// - Writes: Records, RecordsCount storage entries + currency deposit
+		///   reserve
+		/// # </weight>
+		#[pallet::call_index(14)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_record())]
+		pub fn set_record(
+			origin: OriginFor<T>,
+			key: Web3NameRecordKeyOf<T>,
+			value: Web3NameRecordValueOf<T>,
+		) -> DispatchResult {
+			let origin = T::OwnerOrigin::ensure_origin(origin)?;
+			let name = Names::<T>::get(origin.subject()).ok_or(Error::<T>::OwnerNotFound)?;
+
+			Self::insert_record(name, key, value, origin.sender())
+		}
+
+		/// Clear a record of the name owned by the subject of the origin,
+		/// returning its deposit to the original payer.
+		///
+		/// Emits `Web3NameRecordCleared` if the operation is carried out
+		/// successfully.
+		///
+		/// # <weight>
+		/// Weight: O(1)
+		/// - Reads: Names, Records, RecordsCount storage entries + origin check
+		/// - Writes: Records, RecordsCount storage entries + currency deposit
+		///   release
+		/// # </weight>
+		#[pallet::call_index(15)]
+		#[pallet::weight(<T as Config>::WeightInfo::clear_record())]
+		pub fn clear_record(origin: OriginFor<T>, key: Web3NameRecordKeyOf<T>) -> DispatchResult {
+			let origin = T::OwnerOrigin::ensure_origin(origin)?;
+			let name = Names::<T>::get(origin.subject()).ok_or(Error::<T>::OwnerNotFound)?;
+
+			let record = Records::<T>::take(&name, &key).ok_or(Error::<T>::RecordNotFound)?;
+			Web3NameRecordDepositCollector::<T>::free_deposit(record.deposit)?;
+			let records_count = RecordsCount::<T>::get(&name).saturating_sub(1);
+			if records_count.is_zero() {
+				RecordsCount::<T>::remove(&name);
+			} else {
+				RecordsCount::<T>::insert(&name, records_count);
+			}
+
+			Self::deposit_event(Event::<T>::Web3NameRecordCleared { name, key });
+
+			Ok(())
+		}
+
+		/// Accept the name proposed to the subject of the origin in a claim
+		/// batch, reserving its deposit from the sender of the call.
+		///
+		/// The name must still be claimable under the same conditions as with
+		/// `claim`.
+		///
+		/// Emits `Web3NameClaimed` if the operation is carried out
+		/// successfully.
+		///
+		/// # <weight>
+		/// Weight: O(1)
+		/// - Reads: PendingClaims, Names, Owner, Banned storage entries +
+		///   available currency check + origin check
+		/// - Writes: PendingClaims, Names, Owner storage entries + currency
+		///   deposit reserve
+		/// # </weight>
+		#[pallet::call_index(16)]
+		#[pallet::weight(<T as Config>::WeightInfo::accept_pending_claim())]
+		pub fn accept_pending_claim(origin: OriginFor<T>) -> DispatchResult {
+			let origin = T::OwnerOrigin::ensure_origin(origin)?;
+			let payer = origin.sender();
+			let owner = origin.subject();
+
+			let name = PendingClaims::<T>::take(&owner).ok_or(Error::<T>::PendingClaimNotFound)?;
+			Self::check_name_availability(&name, &owner)?;
+			ensure!(
+				Web3NameStorageDepositCollector::<T>::can_create_deposit(&payer, T::Deposit::get()),
+				Error::<T>::InsufficientFunds
+			);
+
+			Self::register_name(name, owner, payer)
+		}
+
+		/// Decline the name proposed to the subject of the origin in a claim
+		/// batch.
+		///
+		/// Emits `Web3NameClaimDeclined` if the operation is carried out
+		/// successfully.
+		///
+		/// # <weight>
+		/// Weight: O(1)
+		/// - Reads: PendingClaims storage entry + origin check
+		/// - Writes: PendingClaims storage entry
+		/// # </weight>
+		#[pallet::call_index(17)]
+		#[pallet::weight(<T as Config>::WeightInfo::decline_pending_claim())]
+		pub fn decline_pending_claim(origin: OriginFor<T>) -> DispatchResult {
+			let origin = T::OwnerOrigin::ensure_origin(origin)?;
+			let owner = origin.subject();
+
+			let name = PendingClaims::<T>::take(&owner).ok_or(Error::<T>::PendingClaimNotFound)?;
+
+			Self::deposit_event(Event::<T>::Web3NameClaimDeclined { owner, name });
+
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
@@ -393,22 +1052,29 @@ pub mod pallet {
 		) -> Result<Web3NameOf<T>, DispatchError> {
 			let name = Web3NameOf::<T>::try_from(name_input.into_inner()).map_err(DispatchError::from)?;
 
-			ensure!(!Names::<T>::contains_key(owner), Error::<T>::OwnerAlreadyExists);
-			ensure!(!Owner::<T>::contains_key(&name), Error::<T>::AlreadyExists);
-			ensure!(!Banned::<T>::contains_key(&name), Error::<T>::Banned);
+			Self::check_name_availability(&name, owner)?;
 
 			ensure!(
-				<T::Currency as InspectHold<AccountIdOf<T>>>::can_hold(
-					&HoldReason::Deposit.into(),
-					deposit_payer,
-					T::Deposit::get()
-				),
+				Web3NameStorageDepositCollector::<T>::can_create_deposit(deposit_payer, T::Deposit::get()),
 				Error::<T>::InsufficientFunds
 			);
 
 			Ok(name)
 		}
 
+		/// Verify that the provided name can be assigned to the provided
+		/// owner. Specifically:
+		/// - The name does not already exist
+		/// - The owner does not already own a name
+		/// - The name has not been banned
+		fn check_name_availability(name: &Web3NameOf<T>, owner: &Web3NameOwnerOf<T>) -> DispatchResult {
+			ensure!(!Names::<T>::contains_key(owner), Error::<T>::OwnerAlreadyExists);
+			ensure!(!Owner::<T>::contains_key(name), Error::<T>::AlreadyExists);
+			ensure!(!Banned::<T>::contains_key(name), Error::<T>::Banned);
+
+			Ok(())
+		}
+
 		/// Assign a name to the provided owner reserving the deposit from
 		/// the provided account. This function must be called after
 		/// `check_claiming_preconditions` as it does not verify all the
@@ -420,8 +1086,11 @@ pub mod pallet {
 		) -> DispatchResult {
 			let block_number = frame_system::Pallet::<T>::block_number();
 
-			let deposit = Web3NameStorageDepositCollector::<T>::create_deposit(deposit_payer, T::Deposit::get())?;
-			<T as Config>::BalanceMigrationManager::exclude_key_from_migration(&Owner::<T>::hashed_key_for(&name));
+			let deposit = Web3NameStorageDepositCollector::<T>::create_deposit_for_key::<BalanceMigrationManagerOf<T>>(
+				&name,
+				deposit_payer,
+				T::Deposit::get(),
+			)?;
 
 			Names::<T>::insert(&owner, name.clone());
 			Owner::<T>::insert(
@@ -437,6 +1106,31 @@ pub mod pallet {
 			Ok(())
 		}
 
+		/// Set the value of the provided record of a claimed name, reserving
+		/// the deposit from the provided account if the record does not exist
+		/// yet.
+		pub fn insert_record(
+			name: Web3NameOf<T>,
+			key: Web3NameRecordKeyOf<T>,
+			value: Web3NameRecordValueOf<T>,
+			deposit_payer: AccountIdOf<T>,
+		) -> DispatchResult {
+			if let Some(record) = Records::<T>::get(&name, &key) {
+				Records::<T>::insert(&name, &key, Web3NameRecordOf::<T> { value, ..record });
+			} else {
+				let records_count = RecordsCount::<T>::get(&name);
+				ensure!(records_count < T::MaxRecordsPerName::get(), Error::<T>::TooManyRecords);
+				let deposit =
+					Web3NameRecordDepositCollector::<T>::create_deposit(deposit_payer, T::RecordDeposit::get())
+						.map_err(|_| Error::<T>::InsufficientFunds)?;
+				Records::<T>::insert(&name, &key, Web3NameRecordOf::<T> { value, deposit });
+				RecordsCount::<T>::insert(&name, records_count.saturating_add(1));
+			}
+
+			Self::deposit_event(Event::<T>::Web3NameRecordSet { name, key });
+			Ok(())
+		}
+
 		/// Verify that the releasing preconditions for an owner are verified.
 		/// Specifically:
 		/// - The owner has a previously claimed name
@@ -464,24 +1158,22 @@ pub mod pallet {
 		}
 
 		/// Release the provided name and returns the deposit to the
-		/// original payer. This function must be called after
-		/// `check_releasing_preconditions` as it does not verify all the
-		/// preconditions again.
+		/// original payer, together with the deposits of all its records.
+		/// This function must be called after `check_releasing_preconditions`
+		/// as it does not verify all the preconditions again.
 		fn unregister_name(name: &Web3NameOf<T>) -> Result<Web3OwnershipOf<T>, DispatchError> {
 			let name_ownership = Owner::<T>::take(name).unwrap();
 			Names::<T>::remove(&name_ownership.owner);
 
-			let is_key_migrated =
-				<T as Config>::BalanceMigrationManager::is_key_migrated(&Owner::<T>::hashed_key_for(name));
-
-			if is_key_migrated {
-				Web3NameStorageDepositCollector::<T>::free_deposit(name_ownership.clone().deposit)?;
-			} else {
-				<T as Config>::BalanceMigrationManager::release_reserved_deposit(
-					&name_ownership.deposit.owner,
-					&name_ownership.deposit.amount,
-				)
+			for (_, record) in Records::<T>::drain_prefix(name) {
+				Web3NameRecordDepositCollector::<T>::free_deposit(record.deposit)?;
 			}
+			RecordsCount::<T>::remove(name);
+
+			Web3NameStorageDepositCollector::<T>::release_deposit::<BalanceMigrationManagerOf<T>>(
+				name,
+				name_ownership.clone().deposit,
+			)?;
 
 			Self::deposit_event(Event::<T>::Web3NameReleased {
 				owner: name_ownership.owner.clone(),
@@ -495,18 +1187,48 @@ pub mod pallet {
 		/// Specifically:
 		/// - The name input data can be decoded as a valid name
 		/// - The name must not be already banned
+		/// - The name must not have a ban scheduled already
 		///
-		/// If the preconditions are verified, return
-		/// a tuple containing the parsed name value and whether the name
-		/// being banned is currently assigned to someone or not.
-		fn check_banning_preconditions(name_input: Web3NameInput<T>) -> Result<(Web3NameOf<T>, bool), DispatchError> {
+		/// If the preconditions are verified, return the parsed name value.
+		fn check_banning_preconditions(name_input: Web3NameInput<T>) -> Result<Web3NameOf<T>, DispatchError> {
 			let name = Web3NameOf::<T>::try_from(name_input.into_inner()).map_err(DispatchError::from)?;
 
 			ensure!(!Banned::<T>::contains_key(&name), Error::<T>::AlreadyBanned);
+			ensure!(
+				!ScheduledBans::<T>::contains_key(&name),
+				Error::<T>::BanAlreadyScheduled
+			);
+
+			Ok(name)
+		}
+
+		/// Enact the scheduled ban of the provided name, releasing the name
+		/// first if it is currently claimed.
+		fn enact_ban(name: &Web3NameOf<T>) -> DispatchResult {
+			ScheduledBans::<T>::remove(name);
+
+			if Owner::<T>::contains_key(name) {
+				Self::unregister_name(name)?;
+			}
+
+			Self::ban_name(name);
+			Self::deposit_event(Event::<T>::Web3NameBanned { name: name.clone() });
+
+			Ok(())
+		}
 
-			let is_claimed = Owner::<T>::contains_key(&name);
+		/// Remove the scheduled ban of the provided name, together with its
+		/// entry in the ban queue if it has not been processed yet.
+		fn remove_scheduled_ban(name: &Web3NameOf<T>, enactment_block: BlockNumberFor<T>) {
+			ScheduledBans::<T>::remove(name);
 
-			Ok((name, is_claimed))
+			let mut queue = BanQueue::<T>::get(enactment_block);
+			queue.retain(|queued_name| queued_name != name);
+			if queue.is_empty() {
+				BanQueue::<T>::remove(enactment_block);
+			} else {
+				BanQueue::<T>::insert(enactment_block, queue);
+			}
 		}
 
 		/// Ban the provided name. This function must be called after
@@ -572,4 +1294,45 @@ pub mod pallet {
 			Ok(())
 		}
 	}
+
+	pub(crate) struct Web3NameRecordDepositCollector<T: Config>(PhantomData<T>);
+	impl<T: Config> StorageDepositCollector<AccountIdOf<T>, (T::Web3Name, Web3NameRecordKeyOf<T>), T::RuntimeHoldReason>
+		for Web3NameRecordDepositCollector<T>
+	{
+		type Currency = T::Currency;
+		type Reason = HoldReason;
+
+		fn get_hashed_key(key: &(T::Web3Name, Web3NameRecordKeyOf<T>)) -> Result<sp_std::vec::Vec<u8>, DispatchError> {
+			Ok(Records::<T>::hashed_key_for(&key.0, &key.1))
+		}
+
+		fn reason() -> Self::Reason {
+			HoldReason::Deposit
+		}
+
+		fn deposit(
+			key: &(T::Web3Name, Web3NameRecordKeyOf<T>),
+		) -> Result<Deposit<AccountIdOf<T>, <Self::Currency as Inspect<AccountIdOf<T>>>::Balance>, DispatchError> {
+			let record = Records::<T>::get(&key.0, &key.1).ok_or(Error::<T>::RecordNotFound)?;
+
+			Ok(record.deposit)
+		}
+
+		fn deposit_amount(
+			_key: &(T::Web3Name, Web3NameRecordKeyOf<T>),
+		) -> <Self::Currency as Inspect<AccountIdOf<T>>>::Balance {
+			T::RecordDeposit::get()
+		}
+
+		fn store_deposit(
+			key: &(T::Web3Name, Web3NameRecordKeyOf<T>),
+			deposit: Deposit<AccountIdOf<T>, <Self::Currency as Inspect<AccountIdOf<T>>>::Balance>,
+		) -> Result<(), DispatchError> {
+			Records::<T>::try_mutate(&key.0, &key.1, |record| {
+				let record = record.as_mut().ok_or(Error::<T>::RecordNotFound)?;
+				record.deposit = deposit;
+				Ok(())
+			})
+		}
+	}
 }
@@ -19,10 +19,15 @@
 use frame_support::{
 	pallet_prelude::DispatchResult,
 	traits::{fungible::Inspect, ReservableCurrency},
+	weights::Weight,
 };
-use kilt_support::migration::switch_reserved_to_hold;
+use kilt_support::migration::{switch_reserved_to_hold, SteppedMigration};
+use sp_std::{marker::PhantomData, vec::Vec};
 
-use crate::{AccountIdOf, Config, CurrencyOf, Error, HoldReason, Owner, Web3NameOf};
+use crate::{AccountIdOf, Config, CurrencyOf, Error, HoldReason, Owner, Pallet, Web3NameOf, WeightInfo};
+
+/// The storage version which initialized the counter of the web3 names.
+pub(crate) const OWNER_COUNTER_STORAGE_VERSION: u16 = 2;
 
 pub fn update_balance_for_w3n<T: Config>(key: &Web3NameOf<T>) -> DispatchResult
 where
@@ -37,15 +42,86 @@ where
 	)
 }
 
+/// Initializes the counter of the web3 names, which were stored before the map
+/// was counted, by counting one web3 name per step.
+///
+/// The calls of the pallet are blocked until the migration has completed, so
+/// that no web3 name is counted twice or missed.
+pub struct InitializeOwnerCounter<T>(PhantomData<T>);
+
+impl<T: Config> SteppedMigration for InitializeOwnerCounter<T> {
+	type Pallet = Pallet<T>;
+
+	const FROM: u16 = 1;
+	const TO: u16 = OWNER_COUNTER_STORAGE_VERSION;
+
+	fn max_step_weight() -> Weight {
+		<T as Config>::WeightInfo::initialize_owner_counter_step()
+	}
+
+	fn step(cursor: Option<Vec<u8>>) -> Option<Vec<u8>> {
+		let web3_name = match cursor {
+			Some(cursor) => Owner::<T>::iter_keys_from(cursor).next(),
+			None => Owner::<T>::iter_keys().next(),
+		}?;
+		frame_support::storage::unhashed::put(
+			&Owner::<T>::counter_storage_final_key(),
+			&Owner::<T>::count().saturating_add(1),
+		);
+		Some(Owner::<T>::hashed_key_for(&web3_name))
+	}
+}
+
 #[cfg(test)]
 pub mod test {
 	use frame_support::{
 		assert_noop,
-		traits::{fungible::InspectHold, ReservableCurrency},
+		traits::{fungible::InspectHold, GetStorageVersion, ReservableCurrency},
 	};
+	use kilt_support::migration::SteppedMigration;
 	use sp_runtime::traits::Zero;
 
-	use crate::{migrations::update_balance_for_w3n, mock::*, AccountIdOf, Config, Error, HoldReason, Owner};
+	use crate::{
+		migrations::{update_balance_for_w3n, InitializeOwnerCounter},
+		mock::*,
+		AccountIdOf, Config, Error, HoldReason, Owner, Pallet,
+	};
+
+	#[test]
+	fn test_stepped_migration_bumps_one_version() {
+		assert_eq!(
+			<InitializeOwnerCounter<Test> as SteppedMigration>::TO,
+			<InitializeOwnerCounter<Test> as SteppedMigration>::FROM + 1
+		);
+		assert_eq!(
+			Pallet::<Test>::current_storage_version(),
+			<InitializeOwnerCounter<Test> as SteppedMigration>::TO
+		);
+	}
+
+	#[test]
+	fn test_initialize_owner_counter() {
+		ExtBuilder::default()
+			.with_balances(vec![(ACCOUNT_00, Web3NameDeposit::get() * 3)])
+			.with_web3_names(vec![
+				(DID_00, get_web3_name(WEB3_NAME_00_INPUT), ACCOUNT_00),
+				(DID_01, get_web3_name(WEB3_NAME_01_INPUT), ACCOUNT_00),
+			])
+			.build_and_execute_with_sanity_tests(|| {
+				// The web3 names were stored before the map was counted.
+				frame_support::storage::unhashed::kill(&Owner::<Test>::counter_storage_final_key());
+				assert_eq!(Owner::<Test>::count(), 0);
+
+				let mut cursor = None;
+				for counted in 1..=2 {
+					cursor = InitializeOwnerCounter::<Test>::step(cursor);
+					assert!(cursor.is_some());
+					assert_eq!(Owner::<Test>::count(), counted);
+				}
+				assert!(InitializeOwnerCounter::<Test>::step(cursor).is_none());
+				assert_eq!(Owner::<Test>::count(), 2);
+			})
+	}
 
 	#[test]
 	fn test_setup() {
@@ -53,12 +53,12 @@ pub use crate::mock::runtime::*;
 // Mocks that are only used internally
 #[cfg(test)]
 pub(crate) mod runtime {
-	use frame_support::parameter_types;
-	use frame_system::EnsureRoot;
+	use frame_support::{ord_parameter_types, parameter_types};
+	use frame_system::{EnsureRoot, EnsureSignedBy};
 	use kilt_support::mock::{mock_origin, SubjectId};
 	use sp_runtime::{
 		traits::{BlakeTwo256, IdentifyAccount, IdentityLookup, Verify},
-		BuildStorage, MultiSignature,
+		BuildStorage, MultiSignature, Perbill,
 	};
 
 	use crate::{self as pallet_web3_names, web3_name::AsciiWeb3Name};
@@ -144,16 +144,40 @@ pub(crate) mod runtime {
 	pub(crate) type TestOwnerOrigin = mock_origin::EnsureDoubleOrigin<TestWeb3NamePayer, TestWeb3NameOwner>;
 	pub(crate) type TestOriginSuccess = mock_origin::DoubleOrigin<TestWeb3NamePayer, TestWeb3NameOwner>;
 	pub(crate) type TestBanOrigin = EnsureRoot<AccountId>;
+	pub(crate) type TestBanAppealOrigin = EnsureSignedBy<AppealCouncil, AccountId>;
+	pub(crate) type TestRegistrarOrigin = EnsureRoot<AccountId>;
+	pub(crate) type TestRegistrarApprovalOrigin = EnsureRoot<AccountId>;
 
 	parameter_types! {
 		pub const MaxNameLength: u32 = 32;
 		pub const MinNameLength: u32 = 3;
 		// Easier to setup insufficient funds for deposit but still above existential deposit
 		pub const Web3NameDeposit: Balance = 2 * ExistentialDeposit::get();
+		pub const BanDelay: BlockNumber = 10;
+		pub const MaxBansPerBlock: u32 = 2;
+		pub const MaxClaimBatchSize: u32 = 3;
+		pub const RegistrarTreasuryShare: Perbill = Perbill::from_percent(20);
+		pub const TreasuryAccount: AccountId = TREASURY;
+		pub const MaxRecordKeyLength: u32 = 16;
+		pub const MaxRecordValueLength: u32 = 32;
+		pub const MaxRecordsPerName: u32 = 2;
+		pub const RecordDeposit: Balance = ExistentialDeposit::get();
+	}
+
+	ord_parameter_types! {
+		pub const AppealCouncil: AccountId = APPEAL_COUNCIL;
 	}
 
 	impl pallet_web3_names::Config for Test {
 		type BanOrigin = TestBanOrigin;
+		type BanAppealOrigin = TestBanAppealOrigin;
+		type BanDelay = BanDelay;
+		type MaxBansPerBlock = MaxBansPerBlock;
+		type RegistrarOrigin = TestRegistrarOrigin;
+		type MaxClaimBatchSize = MaxClaimBatchSize;
+		type RegistrarApprovalOrigin = TestRegistrarApprovalOrigin;
+		type RegistrarTreasuryShare = RegistrarTreasuryShare;
+		type TreasuryAccount = TreasuryAccount;
 		type OwnerOrigin = TestOwnerOrigin;
 		type OriginSuccess = TestOriginSuccess;
 		type Currency = Balances;
@@ -164,6 +188,10 @@ pub(crate) mod runtime {
 		type MinNameLength = MinNameLength;
 		type Web3Name = TestWeb3Name;
 		type Web3NameOwner = TestWeb3NameOwner;
+		type MaxRecordKeyLength = MaxRecordKeyLength;
+		type MaxRecordValueLength = MaxRecordValueLength;
+		type MaxRecordsPerName = MaxRecordsPerName;
+		type RecordDeposit = RecordDeposit;
 		type WeightInfo = ();
 		type BalanceMigrationManager = ();
 	}
@@ -176,6 +204,9 @@ pub(crate) mod runtime {
 
 	pub(crate) const ACCOUNT_00: TestWeb3NamePayer = AccountId::new([1u8; 32]);
 	pub(crate) const ACCOUNT_01: TestWeb3NamePayer = AccountId::new([2u8; 32]);
+	pub(crate) const APPEAL_COUNCIL: AccountId = AccountId::new([100u8; 32]);
+	pub(crate) const REGISTRAR: AccountId = AccountId::new([200u8; 32]);
+	pub(crate) const TREASURY: AccountId = AccountId::new([250u8; 32]);
 	pub(crate) const DID_00: TestWeb3NameOwner = SubjectId(ACCOUNT_00);
 	pub(crate) const DID_01: TestWeb3NameOwner = SubjectId(ACCOUNT_01);
 	pub(crate) const WEB3_NAME_00_INPUT: &[u8; 12] = b"web3_name_00";
@@ -185,6 +216,15 @@ pub(crate) mod runtime {
 		AsciiWeb3Name::try_from(web3_name_input.to_vec()).expect("Invalid web3 name input.")
 	}
 
+	/// Advance to the provided block, running the pallet's `on_initialize`
+	/// hook for every block in between.
+	pub(crate) fn run_to_block(n: BlockNumber) {
+		while System::block_number() < n {
+			System::set_block_number(System::block_number() + 1);
+			<Web3Names as frame_support::traits::Hooks<BlockNumber>>::on_initialize(System::block_number());
+		}
+	}
+
 	#[derive(Clone, Default)]
 	pub struct ExtBuilder {
 		balances: Vec<(TestWeb3NamePayer, Balance)>,
@@ -25,7 +25,11 @@ use frame_system::RawOrigin;
 use kilt_support::mock::mock_origin;
 use sp_runtime::{traits::Zero, DispatchError};
 
-use crate::{mock::*, Banned, Error, HoldReason, Names, Owner, Pallet};
+use crate::{
+	mock::*,
+	web3_name::{BanStatus, ScheduledBan},
+	BanQueue, Banned, Error, HoldReason, Names, Owner, Pallet, ScheduledBans,
+};
 
 #[test]
 fn unbanning_successful() {
@@ -86,21 +90,249 @@ fn banning_successful() {
 		.with_balances(vec![(ACCOUNT_00, initial_balance)])
 		.with_web3_names(vec![(DID_00, web3_name_00.clone(), ACCOUNT_00)])
 		.build_and_execute_with_sanity_tests(|| {
-			// Ban a claimed name
+			// Schedule the ban of a claimed name
 			assert_ok!(Pallet::<Test>::ban(RawOrigin::Root.into(), web3_name_00.clone().0));
+			// Schedule the ban of an unclaimed name
+			assert_ok!(Pallet::<Test>::ban(RawOrigin::Root.into(), web3_name_01.clone().0));
+
+			let enactment_block = System::block_number() + BanDelay::get();
+			assert_eq!(
+				ScheduledBans::<Test>::get(&web3_name_00),
+				Some(ScheduledBan {
+					enactment_block,
+					status: BanStatus::Scheduled
+				})
+			);
+			assert_eq!(
+				BanQueue::<Test>::get(enactment_block).into_inner(),
+				vec![web3_name_00.clone(), web3_name_01.clone()]
+			);
 
+			// Nothing happens before the enactment block
+			run_to_block(enactment_block - 1);
+			assert!(Owner::<Test>::get(&web3_name_00).is_some());
+			assert!(Banned::<Test>::get(&web3_name_00).is_none());
+			assert!(Banned::<Test>::get(&web3_name_01).is_none());
+
+			run_to_block(enactment_block);
 			assert!(Names::<Test>::get(&DID_00).is_none());
 			assert!(Owner::<Test>::get(&web3_name_00).is_none());
 			assert!(Banned::<Test>::get(&web3_name_00).is_some());
+			assert!(Banned::<Test>::get(&web3_name_01).is_some());
+			assert!(ScheduledBans::<Test>::get(&web3_name_00).is_none());
+			assert!(ScheduledBans::<Test>::get(&web3_name_01).is_none());
+			assert!(BanQueue::<Test>::get(enactment_block).is_empty());
 
 			assert!(Balances::balance_on_hold(&HoldReason::Deposit.into(), &ACCOUNT_00).is_zero());
 			assert_eq!(Balances::balance(&ACCOUNT_00), initial_balance);
+		})
+}
 
-			// Ban an unclaimed name
-			assert_ok!(Pallet::<Test>::ban(RawOrigin::Root.into(), web3_name_01.clone().0));
+#[test]
+fn banning_already_scheduled() {
+	let web3_name_00 = get_web3_name(WEB3_NAME_00_INPUT);
+	ExtBuilder::default().build_and_execute_with_sanity_tests(|| {
+		assert_ok!(Pallet::<Test>::ban(RawOrigin::Root.into(), web3_name_00.clone().0));
+		assert_noop!(
+			Pallet::<Test>::ban(RawOrigin::Root.into(), web3_name_00.clone().0),
+			Error::<Test>::BanAlreadyScheduled
+		);
+	})
+}
 
-			assert!(Owner::<Test>::get(&web3_name_01).is_none());
-			assert!(Banned::<Test>::get(&web3_name_01).is_some());
+#[test]
+fn banning_too_many_in_same_block() {
+	let web3_name_00 = get_web3_name(WEB3_NAME_00_INPUT);
+	let web3_name_01 = get_web3_name(WEB3_NAME_01_INPUT);
+	let web3_name_02 = get_web3_name(b"web3_name_02");
+	ExtBuilder::default().build_and_execute_with_sanity_tests(|| {
+		assert_ok!(Pallet::<Test>::ban(RawOrigin::Root.into(), web3_name_00.0));
+		assert_ok!(Pallet::<Test>::ban(RawOrigin::Root.into(), web3_name_01.0));
+		assert_noop!(
+			Pallet::<Test>::ban(RawOrigin::Root.into(), web3_name_02.0),
+			Error::<Test>::TooManyScheduledBans
+		);
+	})
+}
+
+#[test]
+fn cancelling_ban_successful() {
+	let web3_name_00 = get_web3_name(WEB3_NAME_00_INPUT);
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, 100)])
+		.with_web3_names(vec![(DID_00, web3_name_00.clone(), ACCOUNT_00)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(Pallet::<Test>::ban(RawOrigin::Root.into(), web3_name_00.clone().0));
+			let enactment_block = System::block_number() + BanDelay::get();
+
+			assert_noop!(
+				Pallet::<Test>::cancel_ban(RawOrigin::Signed(APPEAL_COUNCIL).into(), web3_name_00.clone().0),
+				DispatchError::BadOrigin
+			);
+			assert_ok!(Pallet::<Test>::cancel_ban(
+				RawOrigin::Root.into(),
+				web3_name_00.clone().0
+			));
+			assert!(ScheduledBans::<Test>::get(&web3_name_00).is_none());
+			assert!(!BanQueue::<Test>::contains_key(enactment_block));
+
+			run_to_block(enactment_block);
+			assert!(Owner::<Test>::get(&web3_name_00).is_some());
+			assert!(Banned::<Test>::get(&web3_name_00).is_none());
+
+			assert_noop!(
+				Pallet::<Test>::cancel_ban(RawOrigin::Root.into(), web3_name_00.clone().0),
+				Error::<Test>::BanNotScheduled
+			);
+		})
+}
+
+#[test]
+fn appealing_ban_accepted() {
+	let web3_name_00 = get_web3_name(WEB3_NAME_00_INPUT);
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, 100)])
+		.with_web3_names(vec![(DID_00, web3_name_00.clone(), ACCOUNT_00)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(Pallet::<Test>::ban(RawOrigin::Root.into(), web3_name_00.clone().0));
+			let enactment_block = System::block_number() + BanDelay::get();
+
+			// Only the owner can appeal
+			assert_noop!(
+				Pallet::<Test>::appeal_ban(
+					mock_origin::DoubleOrigin(ACCOUNT_00, DID_01).into(),
+					web3_name_00.clone().0
+				),
+				Error::<Test>::NotAuthorized
+			);
+			assert_ok!(Pallet::<Test>::appeal_ban(
+				mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(),
+				web3_name_00.clone().0
+			));
+			assert_eq!(
+				ScheduledBans::<Test>::get(&web3_name_00).map(|ban| ban.status),
+				Some(BanStatus::Appealed)
+			);
+			// A ban can only be appealed once
+			assert_noop!(
+				Pallet::<Test>::appeal_ban(
+					mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(),
+					web3_name_00.clone().0
+				),
+				Error::<Test>::AppealNotAllowed
+			);
+
+			// The ban is not enacted while the appeal is pending
+			run_to_block(enactment_block);
+			assert!(Owner::<Test>::get(&web3_name_00).is_some());
+			assert!(Banned::<Test>::get(&web3_name_00).is_none());
+
+			assert_noop!(
+				Pallet::<Test>::resolve_ban_appeal(RawOrigin::Root.into(), web3_name_00.clone().0, true),
+				DispatchError::BadOrigin
+			);
+			assert_ok!(Pallet::<Test>::resolve_ban_appeal(
+				RawOrigin::Signed(APPEAL_COUNCIL).into(),
+				web3_name_00.clone().0,
+				true
+			));
+			assert!(ScheduledBans::<Test>::get(&web3_name_00).is_none());
+			assert!(Owner::<Test>::get(&web3_name_00).is_some());
+			assert!(Banned::<Test>::get(&web3_name_00).is_none());
+		})
+}
+
+#[test]
+fn appealing_ban_rejected_before_enactment() {
+	let web3_name_00 = get_web3_name(WEB3_NAME_00_INPUT);
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, 100)])
+		.with_web3_names(vec![(DID_00, web3_name_00.clone(), ACCOUNT_00)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(Pallet::<Test>::ban(RawOrigin::Root.into(), web3_name_00.clone().0));
+			let enactment_block = System::block_number() + BanDelay::get();
+
+			assert_ok!(Pallet::<Test>::appeal_ban(
+				mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(),
+				web3_name_00.clone().0
+			));
+			assert_ok!(Pallet::<Test>::resolve_ban_appeal(
+				RawOrigin::Signed(APPEAL_COUNCIL).into(),
+				web3_name_00.clone().0,
+				false
+			));
+			assert_eq!(
+				ScheduledBans::<Test>::get(&web3_name_00).map(|ban| ban.status),
+				Some(BanStatus::AppealRejected)
+			);
+			// A rejected appeal cannot be submitted again
+			assert_noop!(
+				Pallet::<Test>::appeal_ban(
+					mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(),
+					web3_name_00.clone().0
+				),
+				Error::<Test>::AppealNotAllowed
+			);
+			assert!(Owner::<Test>::get(&web3_name_00).is_some());
+
+			run_to_block(enactment_block);
+			assert!(Owner::<Test>::get(&web3_name_00).is_none());
+			assert!(Banned::<Test>::get(&web3_name_00).is_some());
+		})
+}
+
+#[test]
+fn appealing_ban_rejected_after_enactment_block() {
+	let web3_name_00 = get_web3_name(WEB3_NAME_00_INPUT);
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, 100)])
+		.with_web3_names(vec![(DID_00, web3_name_00.clone(), ACCOUNT_00)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(Pallet::<Test>::ban(RawOrigin::Root.into(), web3_name_00.clone().0));
+			let enactment_block = System::block_number() + BanDelay::get();
+
+			assert_noop!(
+				Pallet::<Test>::resolve_ban_appeal(
+					RawOrigin::Signed(APPEAL_COUNCIL).into(),
+					web3_name_00.clone().0,
+					false
+				),
+				Error::<Test>::NoPendingAppeal
+			);
+			assert_ok!(Pallet::<Test>::appeal_ban(
+				mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(),
+				web3_name_00.clone().0
+			));
+
+			run_to_block(enactment_block + 1);
+			assert!(Owner::<Test>::get(&web3_name_00).is_some());
+
+			// The ban is enacted right away
+			assert_ok!(Pallet::<Test>::resolve_ban_appeal(
+				RawOrigin::Signed(APPEAL_COUNCIL).into(),
+				web3_name_00.clone().0,
+				false
+			));
+			assert!(ScheduledBans::<Test>::get(&web3_name_00).is_none());
+			assert!(Owner::<Test>::get(&web3_name_00).is_none());
+			assert!(Banned::<Test>::get(&web3_name_00).is_some());
+		})
+}
+
+#[test]
+fn appealing_unscheduled_ban() {
+	let web3_name_00 = get_web3_name(WEB3_NAME_00_INPUT);
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, 100)])
+		.with_web3_names(vec![(DID_00, web3_name_00.clone(), ACCOUNT_00)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_noop!(
+				Pallet::<Test>::appeal_ban(
+					mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(),
+					web3_name_00.clone().0
+				),
+				Error::<Test>::BanNotScheduled
+			);
 		})
 }
 
@@ -0,0 +1,218 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::fungible::{Inspect, InspectHold},
+	BoundedVec,
+};
+use frame_system::RawOrigin;
+use kilt_support::mock::mock_origin;
+use sp_runtime::{traits::Zero, DispatchError};
+
+use crate::{mock::*, Error, Event, HoldReason, Names, Owner, Pallet, PendingClaims};
+
+#[test]
+fn claiming_batch_successful() {
+	let web3_name_00 = get_web3_name(WEB3_NAME_00_INPUT);
+	let web3_name_01 = get_web3_name(WEB3_NAME_01_INPUT);
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, 100)])
+		.build_and_execute_with_sanity_tests(|| {
+			System::set_block_number(1);
+			let batch =
+				BoundedVec::try_from(vec![(web3_name_00.clone().0, DID_00), (web3_name_01.clone().0, DID_01)]).unwrap();
+
+			assert_ok!(Pallet::<Test>::claim_batch(RawOrigin::Root.into(), batch));
+
+			// Names are only proposed, without any deposit being reserved.
+			assert_eq!(PendingClaims::<Test>::get(&DID_00), Some(web3_name_00.clone()));
+			assert_eq!(PendingClaims::<Test>::get(&DID_01), Some(web3_name_01.clone()));
+			assert!(Names::<Test>::get(&DID_00).is_none());
+			assert!(Names::<Test>::get(&DID_01).is_none());
+			assert!(Balances::balance_on_hold(&HoldReason::Deposit.into(), &ACCOUNT_00).is_zero());
+			System::assert_has_event(
+				Event::<Test>::Web3NameClaimProposed {
+					owner: DID_00,
+					name: web3_name_00,
+				}
+				.into(),
+			);
+			System::assert_last_event(Event::<Test>::Web3NameBatchProposed { proposed: 2, failed: 0 }.into());
+		})
+}
+
+#[test]
+fn claiming_batch_reports_failed_items() {
+	let web3_name_00 = get_web3_name(WEB3_NAME_00_INPUT);
+	let web3_name_01 = get_web3_name(WEB3_NAME_01_INPUT);
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, 100)])
+		.with_web3_names(vec![(DID_00, web3_name_00.clone(), ACCOUNT_00)])
+		.build_and_execute_with_sanity_tests(|| {
+			System::set_block_number(1);
+			let batch = BoundedVec::try_from(vec![
+				(web3_name_01.clone().0, DID_01),
+				// Name already claimed.
+				(web3_name_00.clone().0, DID_01),
+				// Owner already owns a name.
+				(web3_name_01.clone().0, DID_00),
+			])
+			.unwrap();
+
+			assert_ok!(Pallet::<Test>::claim_batch(RawOrigin::Root.into(), batch));
+
+			assert_eq!(PendingClaims::<Test>::get(&DID_01), Some(web3_name_01));
+			assert!(PendingClaims::<Test>::get(&DID_00).is_none());
+			System::assert_has_event(
+				Event::<Test>::Web3NameBatchClaimFailed {
+					index: 1,
+					error: Error::<Test>::AlreadyExists.into(),
+				}
+				.into(),
+			);
+			System::assert_has_event(
+				Event::<Test>::Web3NameBatchClaimFailed {
+					index: 2,
+					error: Error::<Test>::OwnerAlreadyExists.into(),
+				}
+				.into(),
+			);
+			System::assert_last_event(Event::<Test>::Web3NameBatchProposed { proposed: 1, failed: 2 }.into());
+		})
+}
+
+#[test]
+fn claiming_batch_unauthorized_origin() {
+	let web3_name_00 = get_web3_name(WEB3_NAME_00_INPUT);
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, 100)])
+		.build_and_execute_with_sanity_tests(|| {
+			let batch = BoundedVec::try_from(vec![(web3_name_00.0, DID_00)]).unwrap();
+
+			assert_noop!(
+				Pallet::<Test>::claim_batch(RawOrigin::Signed(ACCOUNT_00).into(), batch),
+				DispatchError::BadOrigin
+			);
+		})
+}
+
+#[test]
+fn accepting_pending_claim_successful() {
+	let web3_name_00 = get_web3_name(WEB3_NAME_00_INPUT);
+	let initial_balance: Balance = 100;
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_01, initial_balance)])
+		.build_and_execute_with_sanity_tests(|| {
+			let batch = BoundedVec::try_from(vec![(web3_name_00.clone().0, DID_00)]).unwrap();
+			assert_ok!(Pallet::<Test>::claim_batch(RawOrigin::Root.into(), batch));
+
+			// The deposit is paid by the sender of the call.
+			assert_ok!(Pallet::<Test>::accept_pending_claim(
+				mock_origin::DoubleOrigin(ACCOUNT_01, DID_00).into()
+			));
+
+			assert!(PendingClaims::<Test>::get(&DID_00).is_none());
+			assert_eq!(Names::<Test>::get(&DID_00), Some(web3_name_00.clone()));
+			let ownership = Owner::<Test>::get(&web3_name_00).expect("Owner should be stored.");
+			assert_eq!(ownership.owner, DID_00);
+			assert_eq!(ownership.deposit.owner, ACCOUNT_01);
+			assert_eq!(
+				Balances::balance_on_hold(&HoldReason::Deposit.into(), &ACCOUNT_01),
+				Web3NameDeposit::get()
+			);
+			assert_eq!(Balances::balance(&ACCOUNT_01), initial_balance - Web3NameDeposit::get());
+		})
+}
+
+#[test]
+fn accepting_pending_claim_not_found() {
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, 100)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_noop!(
+				Pallet::<Test>::accept_pending_claim(mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into()),
+				Error::<Test>::PendingClaimNotFound
+			);
+		})
+}
+
+#[test]
+fn accepting_pending_claim_name_claimed_in_the_meantime() {
+	let web3_name_00 = get_web3_name(WEB3_NAME_00_INPUT);
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, 100), (ACCOUNT_01, 100)])
+		.build_and_execute_with_sanity_tests(|| {
+			let batch = BoundedVec::try_from(vec![(web3_name_00.clone().0, DID_00)]).unwrap();
+			assert_ok!(Pallet::<Test>::claim_batch(RawOrigin::Root.into(), batch));
+			assert_ok!(Pallet::<Test>::claim(
+				mock_origin::DoubleOrigin(ACCOUNT_01, DID_01).into(),
+				web3_name_00.0,
+			));
+
+			assert_noop!(
+				Pallet::<Test>::accept_pending_claim(mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into()),
+				Error::<Test>::AlreadyExists
+			);
+		})
+}
+
+#[test]
+fn accepting_pending_claim_not_enough_funds() {
+	let web3_name_00 = get_web3_name(WEB3_NAME_00_INPUT);
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, Web3NameDeposit::get() - 1)])
+		.build_and_execute_with_sanity_tests(|| {
+			let batch = BoundedVec::try_from(vec![(web3_name_00.0, DID_00)]).unwrap();
+			assert_ok!(Pallet::<Test>::claim_batch(RawOrigin::Root.into(), batch));
+
+			assert_noop!(
+				Pallet::<Test>::accept_pending_claim(mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into()),
+				Error::<Test>::InsufficientFunds
+			);
+		})
+}
+
+#[test]
+fn declining_pending_claim_successful() {
+	let web3_name_00 = get_web3_name(WEB3_NAME_00_INPUT);
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, 100)])
+		.build_and_execute_with_sanity_tests(|| {
+			System::set_block_number(1);
+			let batch = BoundedVec::try_from(vec![(web3_name_00.clone().0, DID_00)]).unwrap();
+			assert_ok!(Pallet::<Test>::claim_batch(RawOrigin::Root.into(), batch));
+
+			assert_ok!(Pallet::<Test>::decline_pending_claim(
+				mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into()
+			));
+
+			assert!(PendingClaims::<Test>::get(&DID_00).is_none());
+			assert!(Owner::<Test>::get(&web3_name_00).is_none());
+			System::assert_last_event(
+				Event::<Test>::Web3NameClaimDeclined {
+					owner: DID_00,
+					name: web3_name_00,
+				}
+				.into(),
+			);
+			assert_noop!(
+				Pallet::<Test>::decline_pending_claim(mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into()),
+				Error::<Test>::PendingClaimNotFound
+			);
+		})
+}
@@ -16,12 +16,18 @@
 
 // If you feel like getting in touch with us, you can do so at info@botlabs.org
 
-use frame_support::{assert_noop, assert_ok, traits::fungible::InspectHold};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{
+		fungible::{InspectHold, MutateHold},
+		tokens::Precision,
+	},
+};
 
-use kilt_support::{mock::mock_origin, Deposit};
+use kilt_support::{mock::mock_origin, traits::StorageDepositCollector, Deposit};
 use sp_runtime::{traits::Zero, TokenError};
 
-use crate::{mock::*, Config, Error, HoldReason, Owner, Pallet};
+use crate::{mock::*, Config, Error, HoldReason, Owner, Pallet, Web3NameStorageDepositCollector};
 
 #[test]
 fn test_change_deposit_owner() {
@@ -146,3 +152,147 @@ fn test_update_deposit_unauthorized() {
 			);
 		})
 }
+
+#[test]
+fn test_top_up_deposit() {
+	let web3_name_00 = get_web3_name(WEB3_NAME_00_INPUT);
+	let initial_balance: Balance = <Test as Config>::Deposit::get() * 100;
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, initial_balance)])
+		.with_web3_names(vec![(DID_00, web3_name_00.clone(), ACCOUNT_00)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(Web3NameStorageDepositCollector::<Test>::top_up_deposit::<()>(
+				&web3_name_00,
+				<Test as Config>::Deposit::get()
+			));
+			assert_eq!(
+				Owner::<Test>::get(&web3_name_00)
+					.expect("w3n should be retained")
+					.deposit,
+				Deposit {
+					owner: ACCOUNT_00,
+					amount: <Test as Config>::Deposit::get() * 2
+				}
+			);
+			assert_eq!(
+				Balances::balance_on_hold(&HoldReason::Deposit.into(), &ACCOUNT_00),
+				<Test as Config>::Deposit::get() * 2
+			);
+		})
+}
+
+#[test]
+fn test_top_up_deposit_insufficient_balance() {
+	let web3_name_00 = get_web3_name(WEB3_NAME_00_INPUT);
+	let initial_balance: Balance = <Test as Config>::Deposit::get() * 100;
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, initial_balance)])
+		.with_web3_names(vec![(DID_00, web3_name_00.clone(), ACCOUNT_00)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_noop!(
+				Web3NameStorageDepositCollector::<Test>::top_up_deposit::<()>(&web3_name_00, initial_balance),
+				TokenError::FundsUnavailable
+			);
+		})
+}
+
+#[test]
+fn test_adjust_deposit_raised() {
+	let web3_name_00 = get_web3_name(WEB3_NAME_00_INPUT);
+	let initial_balance: Balance = <Test as Config>::Deposit::get() * 100;
+	let outdated_amount: Balance = <Test as Config>::Deposit::get() / 2;
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, initial_balance)])
+		.with_web3_names(vec![(DID_00, web3_name_00.clone(), ACCOUNT_00)])
+		.build_and_execute_with_sanity_tests(|| {
+			// Simulate a deposit taken before the deposit was raised.
+			assert_ok!(Balances::release(
+				&HoldReason::Deposit.into(),
+				&ACCOUNT_00,
+				<Test as Config>::Deposit::get() - outdated_amount,
+				Precision::Exact
+			));
+			Owner::<Test>::mutate(&web3_name_00, |entry| {
+				entry.as_mut().expect("w3n should exist").deposit.amount = outdated_amount;
+			});
+
+			assert_ok!(Web3NameStorageDepositCollector::<Test>::adjust_deposit::<()>(
+				&web3_name_00
+			));
+			assert_eq!(
+				Owner::<Test>::get(&web3_name_00)
+					.expect("w3n should be retained")
+					.deposit,
+				Deposit {
+					owner: ACCOUNT_00,
+					amount: <Test as Config>::Deposit::get()
+				}
+			);
+			assert_eq!(
+				Balances::balance_on_hold(&HoldReason::Deposit.into(), &ACCOUNT_00),
+				<Test as Config>::Deposit::get()
+			);
+		})
+}
+
+#[test]
+fn test_adjust_deposit_lowered() {
+	let web3_name_00 = get_web3_name(WEB3_NAME_00_INPUT);
+	let initial_balance: Balance = <Test as Config>::Deposit::get() * 100;
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, initial_balance)])
+		.with_web3_names(vec![(DID_00, web3_name_00.clone(), ACCOUNT_00)])
+		.build_and_execute_with_sanity_tests(|| {
+			// Simulate a deposit taken before the deposit was lowered.
+			assert_ok!(Web3NameStorageDepositCollector::<Test>::top_up_deposit::<()>(
+				&web3_name_00,
+				<Test as Config>::Deposit::get()
+			));
+
+			assert_ok!(Web3NameStorageDepositCollector::<Test>::adjust_deposit::<()>(
+				&web3_name_00
+			));
+			assert_eq!(
+				Owner::<Test>::get(&web3_name_00)
+					.expect("w3n should be retained")
+					.deposit,
+				Deposit {
+					owner: ACCOUNT_00,
+					amount: <Test as Config>::Deposit::get()
+				}
+			);
+			assert_eq!(
+				Balances::balance_on_hold(&HoldReason::Deposit.into(), &ACCOUNT_00),
+				<Test as Config>::Deposit::get()
+			);
+		})
+}
+
+#[test]
+fn test_adjust_deposit_up_to_date() {
+	let web3_name_00 = get_web3_name(WEB3_NAME_00_INPUT);
+	let initial_balance: Balance = <Test as Config>::Deposit::get() * 100;
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, initial_balance)])
+		.with_web3_names(vec![(DID_00, web3_name_00.clone(), ACCOUNT_00)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(Web3NameStorageDepositCollector::<Test>::adjust_deposit::<()>(
+				&web3_name_00
+			));
+			assert_eq!(
+				Balances::balance_on_hold(&HoldReason::Deposit.into(), &ACCOUNT_00),
+				<Test as Config>::Deposit::get()
+			);
+		})
+}
+
+#[test]
+fn test_adjust_deposit_not_found() {
+	let web3_name_00 = get_web3_name(WEB3_NAME_00_INPUT);
+	ExtBuilder::default().build_and_execute_with_sanity_tests(|| {
+		assert_noop!(
+			Web3NameStorageDepositCollector::<Test>::adjust_deposit::<()>(&web3_name_00),
+			Error::<Test>::NotFound
+		);
+	})
+}
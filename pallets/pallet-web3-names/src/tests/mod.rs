@@ -18,5 +18,8 @@
 
 mod ban;
 mod claim;
+mod claim_batch;
 mod deposit;
+mod records;
+mod registrar;
 mod release;
@@ -0,0 +1,235 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::fungible::{Inspect, InspectHold},
+};
+use kilt_support::{mock::mock_origin, Deposit};
+use sp_runtime::traits::Zero;
+
+use crate::{
+	mock::*, Config, Error, HoldReason, Pallet, Records, RecordsCount, Web3NameRecordKeyOf, Web3NameRecordValueOf,
+};
+
+fn get_record_key(key: &[u8]) -> Web3NameRecordKeyOf<Test> {
+	key.to_vec().try_into().expect("Invalid record key input.")
+}
+
+fn get_record_value(value: &[u8]) -> Web3NameRecordValueOf<Test> {
+	value.to_vec().try_into().expect("Invalid record value input.")
+}
+
+#[test]
+fn set_record_successful() {
+	let web3_name_00 = get_web3_name(WEB3_NAME_00_INPUT);
+	let avatar_key = get_record_key(b"avatar");
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, 100), (ACCOUNT_01, 100)])
+		.with_web3_names(vec![(DID_00, web3_name_00.clone(), ACCOUNT_00)])
+		.build_and_execute_with_sanity_tests(|| {
+			// Submitter != name deposit payer, owner == name owner
+			assert_ok!(Pallet::<Test>::set_record(
+				mock_origin::DoubleOrigin(ACCOUNT_01, DID_00).into(),
+				avatar_key.clone(),
+				get_record_value(b"ipfs://avatar-00"),
+			));
+			let record = Records::<Test>::get(&web3_name_00, &avatar_key).expect("Record should be stored.");
+			assert_eq!(record.value, get_record_value(b"ipfs://avatar-00"));
+			assert_eq!(
+				record.deposit,
+				Deposit {
+					owner: ACCOUNT_01,
+					amount: <Test as Config>::RecordDeposit::get()
+				}
+			);
+			assert_eq!(RecordsCount::<Test>::get(&web3_name_00), 1);
+			assert_eq!(
+				Balances::balance_on_hold(&HoldReason::Deposit.into(), &ACCOUNT_01),
+				<Test as Config>::RecordDeposit::get()
+			);
+
+			// Updating the record keeps its deposit.
+			assert_ok!(Pallet::<Test>::set_record(
+				mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(),
+				avatar_key.clone(),
+				get_record_value(b"ipfs://avatar-01"),
+			));
+			let record = Records::<Test>::get(&web3_name_00, &avatar_key).expect("Record should be stored.");
+			assert_eq!(record.value, get_record_value(b"ipfs://avatar-01"));
+			assert_eq!(record.deposit.owner, ACCOUNT_01);
+			assert_eq!(RecordsCount::<Test>::get(&web3_name_00), 1);
+			assert_eq!(
+				Balances::balance_on_hold(&HoldReason::Deposit.into(), &ACCOUNT_00),
+				<Test as Config>::Deposit::get()
+			);
+		})
+}
+
+#[test]
+fn set_record_owner_not_found() {
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, 100)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_noop!(
+				Pallet::<Test>::set_record(
+					mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(),
+					get_record_key(b"url"),
+					get_record_value(b"https://kilt.io"),
+				),
+				Error::<Test>::OwnerNotFound
+			);
+		})
+}
+
+#[test]
+fn set_record_too_many_records() {
+	let web3_name_00 = get_web3_name(WEB3_NAME_00_INPUT);
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, 100)])
+		.with_web3_names(vec![(DID_00, web3_name_00.clone(), ACCOUNT_00)])
+		.build_and_execute_with_sanity_tests(|| {
+			let max_records = <Test as Config>::MaxRecordsPerName::get();
+			for index in 0..max_records {
+				assert_ok!(Pallet::<Test>::set_record(
+					mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(),
+					get_record_key(&index.to_le_bytes()),
+					get_record_value(b"value"),
+				));
+			}
+			assert_eq!(RecordsCount::<Test>::get(&web3_name_00), max_records);
+
+			assert_noop!(
+				Pallet::<Test>::set_record(
+					mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(),
+					get_record_key(b"url"),
+					get_record_value(b"https://kilt.io"),
+				),
+				Error::<Test>::TooManyRecords
+			);
+			// Existing records can still be updated.
+			assert_ok!(Pallet::<Test>::set_record(
+				mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(),
+				get_record_key(&0u32.to_le_bytes()),
+				get_record_value(b"new value"),
+			));
+		})
+}
+
+#[test]
+fn set_record_insufficient_funds() {
+	let web3_name_00 = get_web3_name(WEB3_NAME_00_INPUT);
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, 100)])
+		.with_web3_names(vec![(DID_00, web3_name_00, ACCOUNT_00)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_noop!(
+				Pallet::<Test>::set_record(
+					mock_origin::DoubleOrigin(ACCOUNT_01, DID_00).into(),
+					get_record_key(b"url"),
+					get_record_value(b"https://kilt.io"),
+				),
+				Error::<Test>::InsufficientFunds
+			);
+		})
+}
+
+#[test]
+fn clear_record_successful() {
+	let web3_name_00 = get_web3_name(WEB3_NAME_00_INPUT);
+	let url_key = get_record_key(b"url");
+	let initial_balance: Balance = 100;
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, initial_balance), (ACCOUNT_01, initial_balance)])
+		.with_web3_names(vec![(DID_00, web3_name_00.clone(), ACCOUNT_00)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(Pallet::<Test>::set_record(
+				mock_origin::DoubleOrigin(ACCOUNT_01, DID_00).into(),
+				url_key.clone(),
+				get_record_value(b"https://kilt.io"),
+			));
+
+			assert_ok!(Pallet::<Test>::clear_record(
+				mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(),
+				url_key.clone(),
+			));
+			assert!(Records::<Test>::get(&web3_name_00, &url_key).is_none());
+			assert!(!RecordsCount::<Test>::contains_key(&web3_name_00));
+
+			// Test that the deposit was returned to the payer correctly.
+			assert!(Balances::balance_on_hold(&HoldReason::Deposit.into(), &ACCOUNT_01).is_zero());
+			assert_eq!(Balances::balance(&ACCOUNT_01), initial_balance);
+		})
+}
+
+#[test]
+fn clear_record_not_found() {
+	let web3_name_00 = get_web3_name(WEB3_NAME_00_INPUT);
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, 100)])
+		.with_web3_names(vec![(DID_00, web3_name_00, ACCOUNT_00)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_noop!(
+				Pallet::<Test>::clear_record(
+					mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(),
+					get_record_key(b"url"),
+				),
+				Error::<Test>::RecordNotFound
+			);
+			assert_noop!(
+				Pallet::<Test>::clear_record(
+					mock_origin::DoubleOrigin(ACCOUNT_01, DID_01).into(),
+					get_record_key(b"url"),
+				),
+				Error::<Test>::OwnerNotFound
+			);
+		})
+}
+
+#[test]
+fn releasing_name_clears_records() {
+	let web3_name_00 = get_web3_name(WEB3_NAME_00_INPUT);
+	let initial_balance: Balance = 100;
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, initial_balance), (ACCOUNT_01, initial_balance)])
+		.with_web3_names(vec![(DID_00, web3_name_00.clone(), ACCOUNT_00)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(Pallet::<Test>::set_record(
+				mock_origin::DoubleOrigin(ACCOUNT_01, DID_00).into(),
+				get_record_key(b"url"),
+				get_record_value(b"https://kilt.io"),
+			));
+			assert_ok!(Pallet::<Test>::set_record(
+				mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(),
+				get_record_key(b"avatar"),
+				get_record_value(b"ipfs://avatar-00"),
+			));
+
+			assert_ok!(Pallet::<Test>::release_by_owner(
+				mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(),
+			));
+			assert_eq!(Records::<Test>::iter_prefix(&web3_name_00).count(), 0);
+			assert!(!RecordsCount::<Test>::contains_key(&web3_name_00));
+
+			// Test that all the deposits were returned to their payers.
+			assert!(Balances::balance_on_hold(&HoldReason::Deposit.into(), &ACCOUNT_00).is_zero());
+			assert!(Balances::balance_on_hold(&HoldReason::Deposit.into(), &ACCOUNT_01).is_zero());
+			assert_eq!(Balances::balance(&ACCOUNT_00), initial_balance);
+			assert_eq!(Balances::balance(&ACCOUNT_01), initial_balance);
+		})
+}
@@ -0,0 +1,270 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::fungible::{Inspect, InspectHold},
+};
+use frame_system::RawOrigin;
+use kilt_support::mock::mock_origin;
+use sp_runtime::DispatchError;
+
+use crate::{mock::*, web3_name::RegistrarDetails, Error, Event, HoldReason, Names, Owner, Pallet, Registrars};
+
+const FEE: Balance = 50;
+
+#[test]
+fn approving_registrar_successful() {
+	ExtBuilder::default().build_and_execute_with_sanity_tests(|| {
+		System::set_block_number(1);
+
+		assert_ok!(Pallet::<Test>::approve_registrar(
+			RawOrigin::Root.into(),
+			REGISTRAR,
+			FEE
+		));
+
+		assert_eq!(
+			Registrars::<Test>::get(&REGISTRAR),
+			Some(RegistrarDetails {
+				fee: FEE,
+				names_issued: 0,
+				fees_collected: 0,
+				fees_to_treasury: 0,
+			})
+		);
+		System::assert_last_event(
+			Event::<Test>::RegistrarApproved {
+				registrar: REGISTRAR,
+				fee: FEE,
+			}
+			.into(),
+		);
+	})
+}
+
+#[test]
+fn approving_registrar_twice() {
+	ExtBuilder::default().build_and_execute_with_sanity_tests(|| {
+		assert_ok!(Pallet::<Test>::approve_registrar(
+			RawOrigin::Root.into(),
+			REGISTRAR,
+			FEE
+		));
+
+		assert_noop!(
+			Pallet::<Test>::approve_registrar(RawOrigin::Root.into(), REGISTRAR, FEE),
+			Error::<Test>::RegistrarAlreadyApproved
+		);
+	})
+}
+
+#[test]
+fn approving_registrar_unauthorized() {
+	ExtBuilder::default().build_and_execute_with_sanity_tests(|| {
+		assert_noop!(
+			Pallet::<Test>::approve_registrar(RawOrigin::Signed(ACCOUNT_00).into(), REGISTRAR, FEE),
+			DispatchError::BadOrigin
+		);
+	})
+}
+
+#[test]
+fn removing_registrar_successful() {
+	ExtBuilder::default().build_and_execute_with_sanity_tests(|| {
+		System::set_block_number(1);
+		assert_ok!(Pallet::<Test>::approve_registrar(
+			RawOrigin::Root.into(),
+			REGISTRAR,
+			FEE
+		));
+
+		assert_ok!(Pallet::<Test>::remove_registrar(RawOrigin::Root.into(), REGISTRAR));
+
+		assert!(Registrars::<Test>::get(&REGISTRAR).is_none());
+		System::assert_last_event(Event::<Test>::RegistrarRemoved { registrar: REGISTRAR }.into());
+		assert_noop!(
+			Pallet::<Test>::remove_registrar(RawOrigin::Root.into(), REGISTRAR),
+			Error::<Test>::RegistrarNotFound
+		);
+	})
+}
+
+#[test]
+fn claiming_by_registrar_successful() {
+	let web3_name_00 = get_web3_name(WEB3_NAME_00_INPUT);
+	let web3_name_01 = get_web3_name(WEB3_NAME_01_INPUT);
+	let initial_balance: Balance = 500;
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, initial_balance)])
+		.build_and_execute_with_sanity_tests(|| {
+			System::set_block_number(1);
+			assert_ok!(Pallet::<Test>::approve_registrar(
+				RawOrigin::Root.into(),
+				REGISTRAR,
+				FEE
+			));
+			let treasury_share = RegistrarTreasuryShare::get().mul_floor(FEE);
+
+			assert_ok!(Pallet::<Test>::claim_by_registrar(
+				mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(),
+				web3_name_00.clone().0,
+				REGISTRAR,
+				FEE
+			));
+
+			assert_eq!(Names::<Test>::get(&DID_00), Some(web3_name_00.clone()));
+			let ownership = Owner::<Test>::get(&web3_name_00).expect("Owner should be stored.");
+			assert_eq!(ownership.owner, DID_00);
+			// The registrar has no control over the name.
+			assert_eq!(ownership.deposit.owner, ACCOUNT_00);
+			assert_eq!(
+				Balances::balance_on_hold(&HoldReason::Deposit.into(), &ACCOUNT_00),
+				Web3NameDeposit::get()
+			);
+			assert_eq!(
+				Balances::balance(&ACCOUNT_00),
+				initial_balance - Web3NameDeposit::get() - FEE
+			);
+			assert_eq!(Balances::balance(&TREASURY), treasury_share);
+			assert_eq!(Balances::balance(&REGISTRAR), FEE - treasury_share);
+			System::assert_last_event(
+				Event::<Test>::Web3NameClaimedByRegistrar {
+					registrar: REGISTRAR,
+					owner: DID_00,
+					name: web3_name_00,
+					fee: FEE,
+					treasury_share,
+				}
+				.into(),
+			);
+			assert_noop!(
+				Pallet::<Test>::reclaim_deposit(
+					RawOrigin::Signed(REGISTRAR).into(),
+					get_web3_name(WEB3_NAME_00_INPUT).0
+				),
+				Error::<Test>::NotAuthorized
+			);
+
+			assert_ok!(Pallet::<Test>::claim_by_registrar(
+				mock_origin::DoubleOrigin(ACCOUNT_00, DID_01).into(),
+				web3_name_01.clone().0,
+				REGISTRAR,
+				FEE
+			));
+
+			assert_eq!(Balances::balance(&TREASURY), 2 * treasury_share);
+			assert_eq!(Balances::balance(&REGISTRAR), 2 * (FEE - treasury_share));
+			assert_eq!(
+				Registrars::<Test>::get(&REGISTRAR),
+				Some(RegistrarDetails {
+					fee: FEE,
+					names_issued: 2,
+					fees_collected: 2 * FEE,
+					fees_to_treasury: 2 * treasury_share,
+				})
+			);
+		})
+}
+
+#[test]
+fn claiming_by_registrar_not_approved() {
+	let web3_name_00 = get_web3_name(WEB3_NAME_00_INPUT);
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, 500)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_noop!(
+				Pallet::<Test>::claim_by_registrar(
+					mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(),
+					web3_name_00.0,
+					REGISTRAR,
+					FEE
+				),
+				Error::<Test>::RegistrarNotFound
+			);
+		})
+}
+
+#[test]
+fn claiming_by_registrar_unauthorized_origin() {
+	let web3_name_00 = get_web3_name(WEB3_NAME_00_INPUT);
+	ExtBuilder::default()
+		.with_balances(vec![(REGISTRAR, 500)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(Pallet::<Test>::approve_registrar(
+				RawOrigin::Root.into(),
+				REGISTRAR,
+				FEE
+			));
+
+			// The name cannot be claimed without the authorization of its owner.
+			assert_noop!(
+				Pallet::<Test>::claim_by_registrar(RawOrigin::Signed(REGISTRAR).into(), web3_name_00.0, REGISTRAR, FEE),
+				DispatchError::BadOrigin
+			);
+		})
+}
+
+#[test]
+fn claiming_by_registrar_not_enough_funds() {
+	let web3_name_00 = get_web3_name(WEB3_NAME_00_INPUT);
+	// Enough for the deposit, but not for the fee.
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, Web3NameDeposit::get() + ExistentialDeposit::get())])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(Pallet::<Test>::approve_registrar(
+				RawOrigin::Root.into(),
+				REGISTRAR,
+				FEE
+			));
+
+			assert_noop!(
+				Pallet::<Test>::claim_by_registrar(
+					mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(),
+					web3_name_00.0,
+					REGISTRAR,
+					FEE
+				),
+				Error::<Test>::InsufficientFunds
+			);
+		})
+}
+
+#[test]
+fn claiming_by_registrar_fee_too_high() {
+	let web3_name_00 = get_web3_name(WEB3_NAME_00_INPUT);
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, 500)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(Pallet::<Test>::approve_registrar(
+				RawOrigin::Root.into(),
+				REGISTRAR,
+				FEE
+			));
+
+			// The fee accepted by the sender is lower than the one of the registrar.
+			assert_noop!(
+				Pallet::<Test>::claim_by_registrar(
+					mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(),
+					web3_name_00.0,
+					REGISTRAR,
+					FEE - 1
+				),
+				Error::<Test>::RegistrarFeeTooHigh
+			);
+		})
+}
@@ -16,12 +16,15 @@
 
 // If you feel like getting in touch with us, you can do so at info@botlabs.org
 
-use frame_support::ensure;
+use frame_support::{ensure, traits::Get};
 use kilt_support::test_utils::log_and_return_error_message;
 use scale_info::prelude::format;
 use sp_runtime::TryRuntimeError;
 
-use crate::{Banned, Config, Names, Owner, Web3NameOf, Web3NameOwnerOf, Web3OwnershipOf};
+use crate::{
+	BanQueue, Banned, Config, Names, Owner, Records, RecordsCount, ScheduledBans, Web3NameOf, Web3NameOwnerOf,
+	Web3OwnershipOf,
+};
 
 pub fn do_try_state<T: Config>() -> Result<(), TryRuntimeError> {
 	// check if for each owner there is a name stored.
@@ -55,5 +58,50 @@ pub fn do_try_state<T: Config>() -> Result<(), TryRuntimeError> {
 			log_and_return_error_message(format!("Owner contains banned name {:?}", banned_w3n))
 		);
 		Ok(())
+	})?;
+
+	// a name with a scheduled ban should not be banned yet.
+	ScheduledBans::<T>::iter_keys().try_for_each(|scheduled_w3n| -> Result<(), TryRuntimeError> {
+		ensure!(
+			!Banned::<T>::contains_key(&scheduled_w3n),
+			log_and_return_error_message(format!("Name {:?} with scheduled ban is already banned", scheduled_w3n))
+		);
+		Ok(())
+	})?;
+
+	// each name with records should be owned and have a matching records count.
+	RecordsCount::<T>::iter().try_for_each(|(w3n, count)| -> Result<(), TryRuntimeError> {
+		ensure!(
+			Owner::<T>::contains_key(&w3n),
+			log_and_return_error_message(format!("Records found for unowned name {:?}", w3n))
+		);
+		ensure!(
+			Records::<T>::iter_prefix(&w3n).count() == count as usize && count <= T::MaxRecordsPerName::get(),
+			log_and_return_error_message(format!("Records count {} for name {:?} does not match", count, w3n))
+		);
+		Ok(())
+	})?;
+	Records::<T>::iter_keys().try_for_each(|(w3n, key)| -> Result<(), TryRuntimeError> {
+		ensure!(
+			RecordsCount::<T>::contains_key(&w3n),
+			log_and_return_error_message(format!("Record {:?} of name {:?} is not counted", key, w3n))
+		);
+		Ok(())
+	})?;
+
+	// each queued name should have a scheduled ban for the same block.
+	BanQueue::<T>::iter().try_for_each(|(block_number, queued_w3ns)| -> Result<(), TryRuntimeError> {
+		queued_w3ns
+			.into_iter()
+			.try_for_each(|queued_w3n| -> Result<(), TryRuntimeError> {
+				ensure!(
+					ScheduledBans::<T>::get(&queued_w3n).map(|ban| ban.enactment_block) == Some(block_number),
+					log_and_return_error_message(format!(
+						"Queued name {:?} has no scheduled ban at block {:?}",
+						queued_w3n, block_number
+					))
+				);
+				Ok(())
+			})
 	})
 }
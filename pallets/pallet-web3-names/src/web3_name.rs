@@ -137,6 +137,51 @@ pub struct Web3NameOwnership<Owner, Deposit: MaxEncodedLen, BlockNumber> {
 	pub deposit: Deposit,
 }
 
+/// The state of a scheduled web3 name ban.
+#[derive(Clone, Copy, Encode, Decode, Debug, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
+pub enum BanStatus {
+	/// The ban will be enacted at the scheduled block unless the owner of the
+	/// name appeals against it.
+	Scheduled,
+	/// The owner of the name has appealed against the ban, which will not be
+	/// enacted until the appeal is resolved.
+	Appealed,
+	/// The appeal has been rejected and the ban can no longer be appealed.
+	AppealRejected,
+}
+
+/// A web3 name ban that has been scheduled but not enacted yet.
+#[derive(Clone, Encode, Decode, Debug, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
+pub struct ScheduledBan<BlockNumber> {
+	/// The block at which the ban takes effect.
+	pub enactment_block: BlockNumber,
+	/// The current state of the ban.
+	pub status: BanStatus,
+}
+
+/// The details of a registrar through which names can be claimed.
+#[derive(Clone, Encode, Decode, Debug, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
+pub struct RegistrarDetails<Balance> {
+	/// The fee the registrar charges for each name claimed through it.
+	pub fee: Balance,
+	/// The number of names claimed through the registrar.
+	pub names_issued: u32,
+	/// The total amount of fees paid for names claimed through the
+	/// registrar.
+	pub fees_collected: Balance,
+	/// The portion of the collected fees routed to the treasury.
+	pub fees_to_treasury: Balance,
+}
+
+/// A text record attached to a web3 name, e.g., an avatar hash or a URL.
+#[derive(Clone, Encode, Decode, Debug, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
+pub struct Web3NameRecord<Value, Deposit> {
+	/// The value of the record.
+	pub value: Value,
+	/// The deposit associated with the record.
+	pub deposit: Deposit,
+}
+
 #[cfg(test)]
 mod tests {
 	use sp_runtime::SaturatedConversion;
@@ -17,14 +17,16 @@
 // If you feel like getting in touch with us, you can do so at info@botlabs.org
 
 use crate::{
-	types::BalanceOf, BlocksAuthored, BlocksRewarded, CandidatePool, Config, DelegatorState, InflationConfig, Pallet,
-	Rewards, TotalCollatorStake,
+	types::BalanceOf, BlocksAuthored, BlocksRewarded, CandidatePool, Config, DelegatorState, InflationConfig,
+	MaxSelectedCandidates, Pallet, Rewards, TopCandidates, TotalCollatorStake,
 };
-use frame_support::traits::fungible::Inspect;
+use frame_support::traits::{fungible::Inspect, Get};
+use kilt_runtime_api_staking::{CandidateRanking, CandidateRankingEntry};
 use sp_runtime::{
 	traits::{Saturating, Zero},
-	Perquintill,
+	Perquintill, SaturatedConversion,
 };
+use sp_std::vec::Vec;
 
 impl<T: Config> Pallet<T> {
 	/// Calculates the staking rewards for a given account address.
@@ -86,4 +88,51 @@ impl<T: Config> Pallet<T> {
 			delegator_reward_rate,
 		}
 	}
+
+	/// Returns the current ranking of the top candidates together with their
+	/// stake breakdown, whether they would be selected if the next session
+	/// started now, and the minimum total stake required to be selected.
+	///
+	/// At least used in Runtime API.
+	pub fn get_candidate_ranking() -> CandidateRanking<T::AccountId, BalanceOf<T>> {
+		let seats = MaxSelectedCandidates::<T>::get();
+		let top_n = seats.saturated_into::<usize>();
+
+		let candidates = TopCandidates::<T>::get()
+			.into_iter()
+			.enumerate()
+			.map(|(rank, stake)| {
+				let self_stake = CandidatePool::<T>::get(&stake.owner)
+					.map(|state| state.stake)
+					.unwrap_or_else(BalanceOf::<T>::zero);
+				CandidateRankingEntry {
+					selected: rank < top_n && stake.amount >= T::MinCollatorStake::get(),
+					delegator_stake: stake.amount.saturating_sub(self_stake),
+					total_stake: stake.amount,
+					self_stake,
+					account: stake.owner,
+				}
+			})
+			.collect::<Vec<_>>();
+
+		// If all seats are taken, a candidate needs to surpass the lowest selected
+		// stake. Otherwise, the minimum collator stake is enough.
+		let selected_count = candidates.iter().filter(|entry| entry.selected).count();
+		let cutoff = if selected_count >= top_n {
+			candidates
+				.iter()
+				.rev()
+				.find(|entry| entry.selected)
+				.map(|entry| entry.total_stake)
+				.unwrap_or_else(T::MinCollatorStake::get)
+		} else {
+			T::MinCollatorStake::get()
+		};
+
+		CandidateRanking {
+			candidates,
+			seats,
+			cutoff,
+		}
+	}
 }
@@ -18,16 +18,22 @@
 #![cfg(feature = "runtime-benchmarks")]
 
 //! Benchmarking
-use crate::{types::RoundInfo, *};
+use crate::{
+	traits::{LinkedAccountsInspect, SessionKeysOwnership},
+	types::RoundInfo,
+	*,
+};
 use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, Zero};
 use frame_support::{
 	assert_ok,
 	traits::{
 		fungible::{Inspect, Mutate},
-		Get, OnInitialize,
+		EnsureOrigin, Get, OnInitialize,
 	},
+	BoundedVec,
 };
 use frame_system::{pallet_prelude::BlockNumberFor, Pallet as System, RawOrigin};
+use kilt_support::traits::CallSources;
 use pallet_session::Pallet as Session;
 use sp_runtime::{
 	traits::{One, SaturatedConversion, StaticLookup},
@@ -123,6 +129,35 @@ where
 	assert!(Unstaking::<T>::get(who).len() <= T::MaxUnstakeRequests::get().try_into().unwrap());
 }
 
+/// Links `num_accounts` accounts to an identity and makes each of them
+/// delegate to one of the top candidates, which are filled up beforehand.
+///
+/// Returns the origin of the identity and the linked delegators.
+fn setup_linked_delegators<T: Config>(num_accounts: u32) -> (T::RuntimeOrigin, Vec<T::AccountId>)
+where
+	<T as Config>::Currency: Mutate<T::AccountId>,
+{
+	let candidates = setup_collator_candidates::<T>(T::MaxTopCandidates::get(), None);
+
+	let origin = T::EnsureIdentityOrigin::try_successful_origin().expect("Should build successful origin");
+	let identity = T::EnsureIdentityOrigin::try_origin(origin.clone())
+		.map_err(|_| ())
+		.expect("Successful origin should be accepted")
+		.subject();
+	let delegators = T::LinkedAccounts::link_accounts(&identity, num_accounts);
+
+	for (i, delegator) in delegators.iter().enumerate() {
+		T::Currency::set_balance(delegator, T::CurrencyBalance::from(10u128.pow(15)));
+		assert_ok!(Pallet::<T>::join_delegators(
+			RawOrigin::Signed(delegator.clone()).into(),
+			T::Lookup::unlookup(candidates[i % candidates.len()].clone()),
+			T::MinDelegatorStake::get(),
+		));
+	}
+
+	(origin, delegators)
+}
+
 benchmarks! {
 	where_clause { where u64: Into<BlockNumberFor<T>>,
 		<T as Config>::Currency: Mutate<T::AccountId>
@@ -637,6 +672,125 @@ benchmarks! {
 		assert!(new.delegator.reward_rate.annual < old.delegator.reward_rate.annual);
 	}
 
+	unstake_instantly {
+		let u in 1 .. (T::MaxUnstakeRequests::get() - 1);
+
+		let candidate = account("collator", 0u32, COLLATOR_ACCOUNT_SEED);
+		let free_balance = T::CurrencyBalance::from(10u128.pow(15));
+		let stake = T::MinCollatorCandidateStake::get();
+		T::Currency::set_balance(&candidate, free_balance);
+		assert_ok!(Pallet::<T>::join_candidates(
+			T::RuntimeOrigin::from(Some(candidate.clone()).into()),
+			stake,
+		));
+
+		// increase stake so we can unstake, because current stake is minimum
+		assert_ok!(Pallet::<T>::candidate_stake_more(RawOrigin::Signed(candidate.clone()).into(), stake));
+
+		// fill unstake BTreeMap by unstaked many entries of 1, none of which can be
+		// unlocked yet
+		fill_unstaking::<T>(&candidate, None, u as u64);
+		assert_eq!(pallet_balances::Pallet::<T>::usable_balance(&candidate), (free_balance - stake - stake).into());
+
+		let origin = RawOrigin::Signed(candidate.clone());
+	}: _(origin)
+	verify {
+		assert!(Unstaking::<T>::get(&candidate).is_empty());
+		assert!(pallet_balances::Pallet::<T>::usable_balance(&candidate) > (free_balance - stake - stake).into());
+	}
+
+	merge_unstaking {
+		let u in 1 .. (T::MaxUnstakeRequests::get() - 1);
+
+		let candidate = account("collator", 0u32, COLLATOR_ACCOUNT_SEED);
+		let free_balance = T::CurrencyBalance::from(10u128.pow(15));
+		let stake = T::MinCollatorCandidateStake::get();
+		T::Currency::set_balance(&candidate, free_balance);
+		assert_ok!(Pallet::<T>::join_candidates(
+			T::RuntimeOrigin::from(Some(candidate.clone()).into()),
+			stake,
+		));
+
+		// increase stake so we can unstake, because current stake is minimum
+		assert_ok!(Pallet::<T>::candidate_stake_more(RawOrigin::Signed(candidate.clone()).into(), stake));
+
+		// fill unstake BTreeMap by unstaked many entries of 1
+		fill_unstaking::<T>(&candidate, None, u as u64);
+
+		let origin = RawOrigin::Signed(candidate.clone());
+	}: _(origin)
+	verify {
+		let unstaking = Unstaking::<T>::get(&candidate);
+		assert_eq!(unstaking.len(), 1);
+		assert_eq!(unstaking.values().next().copied(), Some(T::CurrencyBalance::from(u as u64)));
+	}
+
+	transfer_delegation {
+		let candidates = setup_collator_candidates::<T>(1, None);
+		let collator = candidates[0].clone();
+		let delegator = fill_delegators::<T>(1, collator.clone(), COLLATOR_ACCOUNT_SEED)[0].clone();
+		T::Currency::set_balance(&delegator, T::CurrencyBalance::from(10u128.pow(15)));
+
+		let new_delegator: T::AccountId = account("new_delegator", 0u32, DELEGATOR_ACCOUNT_SEED);
+		T::Currency::set_balance(&new_delegator, T::MinDelegatorStake::get());
+
+		// the delegation can only be transferred in a later round
+		Round::<T>::mutate(|round| round.current += 1);
+
+		let origin = RawOrigin::Signed(delegator.clone());
+		let new_delegator_lookup = T::Lookup::unlookup(new_delegator.clone());
+	}: _(origin, new_delegator_lookup)
+	verify {
+		assert!(DelegatorState::<T>::get(&delegator).is_none());
+		assert_eq!(DelegatorState::<T>::get(&new_delegator).unwrap().amount, T::MinDelegatorStake::get());
+		let state = CandidatePool::<T>::get(&collator).unwrap();
+		assert!(state.delegators.into_iter().any(|x| x.owner == new_delegator));
+	}
+
+	set_session_keys {
+		let collator: T::AccountId = account("collator", 0u32, COLLATOR_ACCOUNT_SEED);
+		T::Currency::set_balance(&collator, T::MinCollatorCandidateStake::get());
+		let (keys, proof) = T::SessionKeysOwnership::generate_keys_and_proof(&collator);
+
+		let origin = RawOrigin::Signed(collator);
+	}: _(origin, keys.clone(), proof)
+	verify {
+		assert!(pallet_session::NextKeys::<T>::iter_values().any(|next_keys| next_keys == keys));
+	}
+
+	linked_accounts_stake_more {
+		let l in 1 .. T::MaxLinkedAccountsPerCall::get();
+
+		let (origin, delegators) = setup_linked_delegators::<T>(l);
+		let amount = T::MinDelegatorStake::get();
+		let stakes: BoundedVec<_, T::MaxLinkedAccountsPerCall> = delegators
+			.iter()
+			.map(|delegator| (delegator.clone(), amount))
+			.collect::<Vec<_>>()
+			.try_into()
+			.expect("Should not exceed the maximum number of linked accounts");
+	}: _<T::RuntimeOrigin>(origin, stakes)
+	verify {
+		for delegator in delegators {
+			assert_eq!(DelegatorState::<T>::get(&delegator).unwrap().amount, amount + amount);
+		}
+	}
+
+	linked_accounts_leave_delegators {
+		let l in 1 .. T::MaxLinkedAccountsPerCall::get();
+
+		let (origin, delegators) = setup_linked_delegators::<T>(l);
+		let leaving_delegators: BoundedVec<_, T::MaxLinkedAccountsPerCall> = delegators
+			.clone()
+			.try_into()
+			.expect("Should not exceed the maximum number of linked accounts");
+	}: _<T::RuntimeOrigin>(origin, leaving_delegators)
+	verify {
+		for delegator in delegators {
+			assert!(DelegatorState::<T>::get(&delegator).is_none());
+			assert_eq!(Unstaking::<T>::get(&delegator).len(), 1);
+		}
+	}
 }
 
 impl_benchmark_test_suite!(
@@ -74,6 +74,12 @@ pub trait WeightInfo {
 	fn increment_collator_rewards() -> Weight;
 	fn claim_rewards() -> Weight;
 	fn execute_scheduled_reward_change(n: u32, m: u32, ) -> Weight;
+	fn unstake_instantly(u: u32, ) -> Weight;
+	fn merge_unstaking(u: u32, ) -> Weight;
+	fn transfer_delegation() -> Weight;
+	fn set_session_keys() -> Weight;
+	fn linked_accounts_stake_more(l: u32, ) -> Weight;
+	fn linked_accounts_leave_delegators(l: u32, ) -> Weight;
 }
 
 /// Weights for parachain_staking using the Substrate node and recommended hardware.
@@ -722,6 +728,134 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(Weight::from_parts(0, 70593).saturating_mul(n.into()))
 			.saturating_add(Weight::from_parts(0, 129441).saturating_mul(m.into()))
 	}
+	// The weights below are not taken from the benchmark run above. They are
+	// estimated from the storage accesses of each call.
+	/// Storage: ParachainStaking Unstaking (r:1 w:1)
+	/// Proof: ParachainStaking Unstaking (max_values: None, max_size: Some(281), added: 2756, mode: MaxEncodedLen)
+	/// Storage: Balances Freezes (r:1 w:1)
+	/// Proof: Balances Freezes (max_values: None, max_size: Some(209), added: 2684, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// The range of component `u` is `[1, 9]`.
+	fn unstake_instantly(u: u32, ) -> Weight {
+		Weight::from_parts(38_204_117, 8047)
+			.saturating_add(Weight::from_parts(281_306, 0).saturating_mul(u.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: ParachainStaking Unstaking (r:1 w:1)
+	/// Proof: ParachainStaking Unstaking (max_values: None, max_size: Some(281), added: 2756, mode: MaxEncodedLen)
+	/// The range of component `u` is `[1, 9]`.
+	fn merge_unstaking(u: u32, ) -> Weight {
+		Weight::from_parts(10_417_562, 2756)
+			.saturating_add(Weight::from_parts(96_104, 0).saturating_mul(u.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: ParachainStaking DelegatorState (r:2 w:2)
+	/// Proof: ParachainStaking DelegatorState (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking CandidatePool (r:2 w:1)
+	/// Proof: ParachainStaking CandidatePool (max_values: None, max_size: Some(1790), added: 4265, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking LastDelegation (r:2 w:1)
+	/// Proof: ParachainStaking LastDelegation (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking Round (r:1 w:0)
+	/// Proof: ParachainStaking Round (max_values: Some(1), max_size: Some(20), added: 515, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking BlocksAuthored (r:1 w:0)
+	/// Proof: ParachainStaking BlocksAuthored (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking BlocksRewarded (r:2 w:2)
+	/// Proof: ParachainStaking BlocksRewarded (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking Rewards (r:1 w:1)
+	/// Proof: ParachainStaking Rewards (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: Balances Freezes (r:2 w:2)
+	/// Proof: Balances Freezes (max_values: None, max_size: Some(209), added: 2684, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	fn transfer_delegation() -> Weight {
+		Weight::from_parts(61_283_000, 0)
+			.saturating_add(Weight::from_parts(0, 39899))
+			.saturating_add(T::DbWeight::get().reads(15))
+			.saturating_add(T::DbWeight::get().writes(11))
+	}
+	/// Storage: System BlockHash (r:1 w:0)
+	/// Proof: System BlockHash (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Session NextKeys (r:1 w:1)
+	/// Proof Skipped: Session NextKeys (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Session KeyOwner (r:1 w:1)
+	/// Proof Skipped: Session KeyOwner (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	fn set_session_keys() -> Weight {
+		Weight::from_parts(67_539_000, 0)
+			.saturating_add(Weight::from_parts(0, 10676))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: DidLookup ConnectedAccounts (r:10 w:0)
+	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking DelegatorState (r:10 w:10)
+	/// Proof: ParachainStaking DelegatorState (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking CandidatePool (r:10 w:10)
+	/// Proof: ParachainStaking CandidatePool (max_values: None, max_size: Some(1790), added: 4265, mode: MaxEncodedLen)
+	/// Storage: System Account (r:10 w:10)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking Unstaking (r:10 w:10)
+	/// Proof: ParachainStaking Unstaking (max_values: None, max_size: Some(281), added: 2756, mode: MaxEncodedLen)
+	/// Storage: Balances Locks (r:10 w:10)
+	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking TopCandidates (r:1 w:1)
+	/// Proof: ParachainStaking TopCandidates (max_values: Some(1), max_size: Some(3602), added: 4097, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking MaxSelectedCandidates (r:1 w:0)
+	/// Proof: ParachainStaking MaxSelectedCandidates (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking TotalCollatorStake (r:1 w:1)
+	/// Proof: ParachainStaking TotalCollatorStake (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking BlocksAuthored (r:10 w:0)
+	/// Proof: ParachainStaking BlocksAuthored (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking BlocksRewarded (r:10 w:10)
+	/// Proof: ParachainStaking BlocksRewarded (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking Rewards (r:10 w:10)
+	/// Proof: ParachainStaking Rewards (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking InflationConfig (r:1 w:0)
+	/// Proof: ParachainStaking InflationConfig (max_values: Some(1), max_size: Some(96), added: 591, mode: MaxEncodedLen)
+	/// The range of component `l` is `[1, 10]`.
+	fn linked_accounts_stake_more(l: u32, ) -> Weight {
+		// Bounded by the worst case of the benchmarked `delegator_stake_more` and the
+		// lookup of the linked account, once per account.
+		Self::delegator_stake_more(75, 34, 9)
+			.saturating_add(Weight::from_parts(0, 2572))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_mul(l.into())
+	}
+	/// Storage: DidLookup ConnectedAccounts (r:10 w:0)
+	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking DelegatorState (r:10 w:10)
+	/// Proof: ParachainStaking DelegatorState (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking CandidatePool (r:10 w:10)
+	/// Proof: ParachainStaking CandidatePool (max_values: None, max_size: Some(1790), added: 4265, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking BlocksAuthored (r:10 w:0)
+	/// Proof: ParachainStaking BlocksAuthored (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking BlocksRewarded (r:10 w:10)
+	/// Proof: ParachainStaking BlocksRewarded (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking Rewards (r:10 w:10)
+	/// Proof: ParachainStaking Rewards (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking TotalCollatorStake (r:1 w:1)
+	/// Proof: ParachainStaking TotalCollatorStake (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking InflationConfig (r:1 w:0)
+	/// Proof: ParachainStaking InflationConfig (max_values: Some(1), max_size: Some(96), added: 591, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking Unstaking (r:10 w:10)
+	/// Proof: ParachainStaking Unstaking (max_values: None, max_size: Some(281), added: 2756, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking TopCandidates (r:1 w:1)
+	/// Proof: ParachainStaking TopCandidates (max_values: Some(1), max_size: Some(3602), added: 4097, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking MaxSelectedCandidates (r:1 w:0)
+	/// Proof: ParachainStaking MaxSelectedCandidates (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// The range of component `l` is `[1, 10]`.
+	fn linked_accounts_leave_delegators(l: u32, ) -> Weight {
+		// Bounded by the worst case of the benchmarked `leave_delegators` and the
+		// lookup of the linked account, once per account.
+		Self::leave_delegators(75, 34)
+			.saturating_add(Weight::from_parts(0, 2572))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_mul(l.into())
+	}
 }
 
 // For backwards compatibility and tests
@@ -1369,4 +1503,132 @@ impl WeightInfo for () {
 			.saturating_add(Weight::from_parts(0, 70593).saturating_mul(n.into()))
 			.saturating_add(Weight::from_parts(0, 129441).saturating_mul(m.into()))
 	}
+	// The weights below are not taken from the benchmark run above. They are
+	// estimated from the storage accesses of each call.
+	/// Storage: ParachainStaking Unstaking (r:1 w:1)
+	/// Proof: ParachainStaking Unstaking (max_values: None, max_size: Some(281), added: 2756, mode: MaxEncodedLen)
+	/// Storage: Balances Freezes (r:1 w:1)
+	/// Proof: Balances Freezes (max_values: None, max_size: Some(209), added: 2684, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// The range of component `u` is `[1, 9]`.
+	fn unstake_instantly(u: u32, ) -> Weight {
+		Weight::from_parts(38_204_117, 8047)
+			.saturating_add(Weight::from_parts(281_306, 0).saturating_mul(u.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: ParachainStaking Unstaking (r:1 w:1)
+	/// Proof: ParachainStaking Unstaking (max_values: None, max_size: Some(281), added: 2756, mode: MaxEncodedLen)
+	/// The range of component `u` is `[1, 9]`.
+	fn merge_unstaking(u: u32, ) -> Weight {
+		Weight::from_parts(10_417_562, 2756)
+			.saturating_add(Weight::from_parts(96_104, 0).saturating_mul(u.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: ParachainStaking DelegatorState (r:2 w:2)
+	/// Proof: ParachainStaking DelegatorState (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking CandidatePool (r:2 w:1)
+	/// Proof: ParachainStaking CandidatePool (max_values: None, max_size: Some(1790), added: 4265, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking LastDelegation (r:2 w:1)
+	/// Proof: ParachainStaking LastDelegation (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking Round (r:1 w:0)
+	/// Proof: ParachainStaking Round (max_values: Some(1), max_size: Some(20), added: 515, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking BlocksAuthored (r:1 w:0)
+	/// Proof: ParachainStaking BlocksAuthored (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking BlocksRewarded (r:2 w:2)
+	/// Proof: ParachainStaking BlocksRewarded (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking Rewards (r:1 w:1)
+	/// Proof: ParachainStaking Rewards (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: Balances Freezes (r:2 w:2)
+	/// Proof: Balances Freezes (max_values: None, max_size: Some(209), added: 2684, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	fn transfer_delegation() -> Weight {
+		Weight::from_parts(61_283_000, 0)
+			.saturating_add(Weight::from_parts(0, 39899))
+			.saturating_add(RocksDbWeight::get().reads(15))
+			.saturating_add(RocksDbWeight::get().writes(11))
+	}
+	/// Storage: System BlockHash (r:1 w:0)
+	/// Proof: System BlockHash (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Session NextKeys (r:1 w:1)
+	/// Proof Skipped: Session NextKeys (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Session KeyOwner (r:1 w:1)
+	/// Proof Skipped: Session KeyOwner (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	fn set_session_keys() -> Weight {
+		Weight::from_parts(67_539_000, 0)
+			.saturating_add(Weight::from_parts(0, 10676))
+			.saturating_add(RocksDbWeight::get().reads(4))
+			.saturating_add(RocksDbWeight::get().writes(3))
+	}
+	/// Storage: DidLookup ConnectedAccounts (r:10 w:0)
+	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking DelegatorState (r:10 w:10)
+	/// Proof: ParachainStaking DelegatorState (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking CandidatePool (r:10 w:10)
+	/// Proof: ParachainStaking CandidatePool (max_values: None, max_size: Some(1790), added: 4265, mode: MaxEncodedLen)
+	/// Storage: System Account (r:10 w:10)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking Unstaking (r:10 w:10)
+	/// Proof: ParachainStaking Unstaking (max_values: None, max_size: Some(281), added: 2756, mode: MaxEncodedLen)
+	/// Storage: Balances Locks (r:10 w:10)
+	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking TopCandidates (r:1 w:1)
+	/// Proof: ParachainStaking TopCandidates (max_values: Some(1), max_size: Some(3602), added: 4097, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking MaxSelectedCandidates (r:1 w:0)
+	/// Proof: ParachainStaking MaxSelectedCandidates (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking TotalCollatorStake (r:1 w:1)
+	/// Proof: ParachainStaking TotalCollatorStake (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking BlocksAuthored (r:10 w:0)
+	/// Proof: ParachainStaking BlocksAuthored (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking BlocksRewarded (r:10 w:10)
+	/// Proof: ParachainStaking BlocksRewarded (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking Rewards (r:10 w:10)
+	/// Proof: ParachainStaking Rewards (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking InflationConfig (r:1 w:0)
+	/// Proof: ParachainStaking InflationConfig (max_values: Some(1), max_size: Some(96), added: 591, mode: MaxEncodedLen)
+	/// The range of component `l` is `[1, 10]`.
+	fn linked_accounts_stake_more(l: u32, ) -> Weight {
+		// Bounded by the worst case of the benchmarked `delegator_stake_more` and the
+		// lookup of the linked account, once per account.
+		Self::delegator_stake_more(75, 34, 9)
+			.saturating_add(Weight::from_parts(0, 2572))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_mul(l.into())
+	}
+	/// Storage: DidLookup ConnectedAccounts (r:10 w:0)
+	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking DelegatorState (r:10 w:10)
+	/// Proof: ParachainStaking DelegatorState (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking CandidatePool (r:10 w:10)
+	/// Proof: ParachainStaking CandidatePool (max_values: None, max_size: Some(1790), added: 4265, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking BlocksAuthored (r:10 w:0)
+	/// Proof: ParachainStaking BlocksAuthored (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking BlocksRewarded (r:10 w:10)
+	/// Proof: ParachainStaking BlocksRewarded (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking Rewards (r:10 w:10)
+	/// Proof: ParachainStaking Rewards (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking TotalCollatorStake (r:1 w:1)
+	/// Proof: ParachainStaking TotalCollatorStake (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking InflationConfig (r:1 w:0)
+	/// Proof: ParachainStaking InflationConfig (max_values: Some(1), max_size: Some(96), added: 591, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking Unstaking (r:10 w:10)
+	/// Proof: ParachainStaking Unstaking (max_values: None, max_size: Some(281), added: 2756, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking TopCandidates (r:1 w:1)
+	/// Proof: ParachainStaking TopCandidates (max_values: Some(1), max_size: Some(3602), added: 4097, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking MaxSelectedCandidates (r:1 w:0)
+	/// Proof: ParachainStaking MaxSelectedCandidates (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// The range of component `l` is `[1, 10]`.
+	fn linked_accounts_leave_delegators(l: u32, ) -> Weight {
+		// Bounded by the worst case of the benchmarked `leave_delegators` and the
+		// lookup of the linked account, once per account.
+		Self::leave_delegators(75, 34)
+			.saturating_add(Weight::from_parts(0, 2572))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_mul(l.into())
+	}
 }
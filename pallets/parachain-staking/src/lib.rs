@@ -68,6 +68,11 @@
 //! - Requesting to leave the set of collator candidates.
 //! - Withdrawing your unstaked balance after waiting for a certain number of
 //!   blocks.
+//! - Withdrawing your unstaked balance immediately in exchange for a penalty,
+//!   or merging your pending unstaking requests into a single one.
+//! - Increasing the stake of or revoking the delegations of all accounts
+//!   linked to an identity (e.g., a DID) within a single identity-authorized
+//!   call.
 //!
 //! ### Terminology
 //!
@@ -130,6 +135,7 @@ mod try_state;
 pub mod api;
 mod inflation;
 mod set;
+pub mod traits;
 mod types;
 
 use frame_support::pallet;
@@ -148,7 +154,7 @@ pub mod pallet {
 		traits::{
 			fungible::Balanced,
 			tokens::{
-				fungible::{Inspect, MutateFreeze, Unbalanced},
+				fungible::{Inspect, InspectFreeze, MutateFreeze, Unbalanced},
 				Fortitude, Precision, Preservation,
 			},
 			BuildGenesisConfig, EstimateNextSessionRotation, Get, OnUnbalanced, StorageVersion,
@@ -174,8 +180,11 @@ pub mod pallet {
 			Stake, StakeOf, TotalStake,
 		},
 	};
+	use kilt_support::traits::CallSources;
 	use sp_std::{convert::TryInto, fmt::Debug};
 
+	use crate::traits::{LinkedAccountsInspect, SessionKeysOwnership};
+
 	/// The current storage version.
 	pub(crate) const STORAGE_VERSION: StorageVersion = StorageVersion::new(9);
 
@@ -317,6 +326,38 @@ pub mod pallet {
 		/// The beneficiary to receive the network rewards.
 		type NetworkRewardBeneficiary: OnUnbalanced<CreditOf<Self>>;
 
+		/// The penalty which is taken from the unstaked balance that is not
+		/// yet unlockable when calling `unstake_instantly`.
+		#[pallet::constant]
+		type InstantUnstakePenalty: Get<Perquintill>;
+
+		/// The beneficiary to receive the penalties of instant unstaking,
+		/// e.g., the treasury.
+		type InstantUnstakePenaltyBeneficiary: OnUnbalanced<CreditOf<Self>>;
+
+		/// The identifier of the identities (e.g., DIDs) that can manage the
+		/// stake of the accounts linked to them.
+		type IdentityId: Parameter + MaxEncodedLen;
+
+		/// The origin that can manage the stake of the accounts linked to an
+		/// identity.
+		type EnsureIdentityOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Self::IdentityOriginSuccess>;
+
+		/// The information that is returned by the identity origin check.
+		type IdentityOriginSuccess: CallSources<Self::AccountId, Self::IdentityId>;
+
+		/// The provider of the links between identities and accounts.
+		type LinkedAccounts: LinkedAccountsInspect<Self::IdentityId, Self::AccountId>;
+
+		/// The verifier of the proofs that session keys are owned by the
+		/// account registering them.
+		type SessionKeysOwnership: SessionKeysOwnership<Self::AccountId, <Self as pallet_session::Config>::Keys>;
+
+		/// Maximum number of linked accounts whose stake can be managed within
+		/// a single identity-authorized call.
+		#[pallet::constant]
+		type MaxLinkedAccountsPerCall: Get<u32>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 
@@ -404,7 +445,8 @@ pub mod pallet {
 		/// An invalid inflation configuration is trying to be set.
 		InvalidSchedule,
 		/// The staking reward being unlocked does not exist.
-		/// Max unlocking requests reached.
+		/// Max unlocking requests reached. Pending requests can be
+		/// consolidated via `merge_unstaking`.
 		NoMoreUnstaking,
 		/// The reward rate cannot be adjusted yet as an entire year has not
 		/// passed.
@@ -415,6 +457,13 @@ pub mod pallet {
 		UnstakingIsEmpty,
 		/// Cannot claim rewards if empty.
 		RewardsNotFound,
+		/// The account is not linked to the identity that authorized the call.
+		AccountNotLinked,
+		/// The provided proof does not prove possession of the session keys.
+		InvalidSessionKeysProof,
+		/// The delegation was made in the current round and cannot be
+		/// transferred yet.
+		DelegationNotMature,
 	}
 
 	#[pallet::event]
@@ -503,6 +552,27 @@ pub mod pallet {
 		/// \[round number, first block in the current round, old value, new
 		/// value\]
 		BlocksPerRoundSet(SessionIndex, BlockNumberFor<T>, BlockNumberFor<T>, BlockNumberFor<T>),
+		/// An account has unlocked all of its unstaked funds without waiting
+		/// for the unlock delay.
+		/// \[account, amount of funds unlocked, penalty paid for the funds
+		/// which were not yet unlockable\]
+		UnstakedInstantly(T::AccountId, BalanceOf<T>, BalanceOf<T>),
+		/// The pending unstaking requests of an account have been merged into
+		/// a single one. \[account, block number after which the merged
+		/// request can be unlocked, total amount of the merged request\]
+		UnstakingMerged(T::AccountId, BlockNumberFor<T>, BalanceOf<T>),
+		/// A delegation has been transferred to another account together with
+		/// its staked funds. \[previous delegator's account, new delegator's
+		/// account, collator's account, amount of the delegation\]
+		DelegationTransferred(T::AccountId, T::AccountId, T::AccountId, BalanceOf<T>),
+		/// The collators for an upcoming session have been selected.
+		/// \[session index, selected candidates, top candidates which were
+		/// not selected\]
+		CollatorsSelected(
+			SessionIndex,
+			BoundedVec<T::AccountId, T::MaxTopCandidates>,
+			BoundedVec<T::AccountId, T::MaxTopCandidates>,
+		),
 	}
 
 	#[pallet::hooks]
@@ -1468,13 +1538,8 @@ pub mod pallet {
 		))]
 		pub fn leave_delegators(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
 			let acc = ensure_signed(origin)?;
-			let delegator = DelegatorState::<T>::get(&acc).ok_or(Error::<T>::DelegatorNotFound)?;
-			let collator = delegator.owner;
-			Self::delegator_leaves_collator(acc.clone(), collator)?;
+			Self::do_leave_delegators(acc)?;
 
-			DelegatorState::<T>::remove(&acc);
-
-			Self::deposit_event(Event::DelegatorLeft(acc, delegator.amount));
 			Ok(Some(<T as pallet::Config>::WeightInfo::leave_delegators(
 				1,
 				T::MaxDelegatorsPerCollator::get(),
@@ -1496,48 +1561,8 @@ pub mod pallet {
 		)]
 		pub fn delegator_stake_more(origin: OriginFor<T>, more: BalanceOf<T>) -> DispatchResultWithPostInfo {
 			let delegator = ensure_signed(origin)?;
-			ensure!(!more.is_zero(), Error::<T>::ValStakeZero);
-
-			let mut delegation = DelegatorState::<T>::get(&delegator).ok_or(Error::<T>::DelegatorNotFound)?;
-			let candidate = delegation.owner.clone();
-			let mut collator = CandidatePool::<T>::get(&candidate).ok_or(Error::<T>::CandidateNotFound)?;
-			ensure!(!collator.is_leaving(), Error::<T>::CannotDelegateIfLeaving);
-			let stake_after = delegation
-				.try_increment(candidate.clone(), more)
-				.map_err(|_| Error::<T>::DelegationNotFound)?;
+			let (n, unstaking_len) = Self::do_delegator_stake_more(delegator, more)?;
 
-			// update lock
-			let unstaking_len = Self::increase_lock(&delegator, stake_after, more)?;
-
-			let CandidateOf::<T, _> {
-				stake: before_stake,
-				total: before_total,
-				..
-			} = collator;
-			collator.inc_delegator(delegator.clone(), more);
-			let after = collator.total;
-
-			// update top candidates and total amount at stake
-			let n = if collator.is_active() {
-				Self::update_top_candidates(
-					candidate.clone(),
-					before_stake,
-					// safe because total >= stake
-					before_total - before_stake,
-					collator.stake,
-					collator.total - collator.stake,
-				)
-			} else {
-				0u32
-			};
-
-			// increment rewards and update number of rewarded blocks
-			Self::do_inc_delegator_reward(&delegator, stake_after.saturating_sub(more), &candidate);
-
-			CandidatePool::<T>::insert(&candidate, collator);
-			DelegatorState::<T>::insert(&delegator, delegation);
-
-			Self::deposit_event(Event::DelegatorStakedMore(delegator, candidate, before_total, after));
 			Ok(Some(<T as pallet::Config>::WeightInfo::delegator_stake_more(
 				n,
 				T::MaxDelegatorsPerCollator::get(),
@@ -1767,6 +1792,168 @@ pub mod pallet {
 
 			Ok(Some(<T as pallet::Config>::WeightInfo::set_inflation(num_col, num_del)).into())
 		}
+
+		/// Increase the delegation stake of accounts linked to the identity
+		/// (e.g., a DID) that authorized the call.
+		///
+		/// Each entry consists of a delegator account and the additional
+		/// amount it should stake for its ongoing delegation. All accounts must
+		/// be linked to the identity, otherwise the whole call fails.
+		///
+		/// The funds are taken from each delegator account, exactly as if the
+		/// account had called `delegator_stake_more` itself.
+		///
+		/// Emits `DelegatorStakedMore` for each account.
+		#[pallet::call_index(21)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::linked_accounts_stake_more(
+			stakes.len().saturated_into::<u32>()
+		))]
+		pub fn linked_accounts_stake_more(
+			origin: OriginFor<T>,
+			stakes: BoundedVec<(T::AccountId, BalanceOf<T>), T::MaxLinkedAccountsPerCall>,
+		) -> DispatchResult {
+			let identity = T::EnsureIdentityOrigin::ensure_origin(origin)?.subject();
+
+			for (delegator, more) in stakes {
+				ensure!(
+					T::LinkedAccounts::is_linked(&identity, &delegator),
+					Error::<T>::AccountNotLinked
+				);
+				Self::do_delegator_stake_more(delegator, more)?;
+			}
+
+			Ok(())
+		}
+
+		/// Revoke the delegations of accounts linked to the identity (e.g., a
+		/// DID) that authorized the call, making them leave the set of
+		/// delegators.
+		///
+		/// All accounts must be linked to the identity, otherwise the whole
+		/// call fails.
+		///
+		/// As for `leave_delegators`, the staked funds are not unlocked
+		/// immediately but added to the queue of pending unstaking of each
+		/// account.
+		///
+		/// Emits `DelegatorLeft` for each account.
+		#[pallet::call_index(22)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::linked_accounts_leave_delegators(
+			delegators.len().saturated_into::<u32>()
+		))]
+		pub fn linked_accounts_leave_delegators(
+			origin: OriginFor<T>,
+			delegators: BoundedVec<T::AccountId, T::MaxLinkedAccountsPerCall>,
+		) -> DispatchResult {
+			let identity = T::EnsureIdentityOrigin::ensure_origin(origin)?.subject();
+
+			for delegator in delegators {
+				ensure!(
+					T::LinkedAccounts::is_linked(&identity, &delegator),
+					Error::<T>::AccountNotLinked
+				);
+				Self::do_leave_delegators(delegator)?;
+			}
+
+			Ok(())
+		}
+
+		/// Unlock all unstaked funds of the origin account, including the ones
+		/// for which `StakeDuration` blocks have not elapsed yet.
+		///
+		/// The funds which would not be unlockable with `unlock_unstaked` yet
+		/// are charged a penalty of `InstantUnstakePenalty`, which is moved to
+		/// the `InstantUnstakePenaltyBeneficiary`. Funds which are already
+		/// unlockable are released without any penalty.
+		///
+		/// Emits `UnstakedInstantly`.
+		#[pallet::call_index(23)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::unstake_instantly(
+			T::MaxUnstakeRequests::get().saturated_into::<u32>()
+		))]
+		pub fn unstake_instantly(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let unstaking_len = Self::do_unstake_instantly(&who)?;
+
+			Ok(Some(<T as pallet::Config>::WeightInfo::unstake_instantly(unstaking_len)).into())
+		}
+
+		/// Merge all pending unstaking requests of the origin account into a
+		/// single one, freeing up slots for new unstaking requests.
+		///
+		/// The merged request can only be unlocked once the latest of the
+		/// merged requests could have been unlocked. Hence, requests which are
+		/// already unlockable should be unlocked via `unlock_unstaked` first.
+		///
+		/// Emits `UnstakingMerged`.
+		#[pallet::call_index(24)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::merge_unstaking(
+			T::MaxUnstakeRequests::get().saturated_into::<u32>()
+		))]
+		pub fn merge_unstaking(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let unstaking_len = Self::do_merge_unstaking(&who)?;
+
+			Ok(Some(<T as pallet::Config>::WeightInfo::merge_unstaking(unstaking_len)).into())
+		}
+
+		/// Set the session keys of the origin account after verifying that
+		/// the account possesses the corresponding private keys.
+		///
+		/// The ownership proof is checked via `T::SessionKeysOwnership` before
+		/// the keys and the proof are forwarded to the session pallet. This
+		/// prevents a collator from registering the session keys of another
+		/// collator.
+		///
+		/// The dispatch origin must be signed.
+		#[pallet::call_index(25)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_session_keys())]
+		pub fn set_session_keys(
+			origin: OriginFor<T>,
+			keys: <T as pallet_session::Config>::Keys,
+			proof: Vec<u8>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin.clone())?;
+
+			ensure!(
+				T::SessionKeysOwnership::is_owned_by(&who, &keys, &proof),
+				Error::<T>::InvalidSessionKeysProof
+			);
+
+			pallet_session::Pallet::<T>::set_keys(origin, keys, proof)
+		}
+
+		/// Transfer the delegation of the origin account, together with its
+		/// staked funds, to another account without unstaking them.
+		///
+		/// The position keeps backing the same collator, such that neither the
+		/// collator's total stake nor the top candidates change. The new
+		/// delegator must neither be delegating nor be a collator candidate.
+		///
+		/// Only delegations which were made before the current round can be
+		/// transferred. The rewards of the position are accumulated for the
+		/// origin account up to the transfer, while the new delegator only
+		/// earns rewards for blocks authored afterwards. Moreover, the
+		/// transferred position counts towards the `MaxDelegationsPerRound`
+		/// of the new delegator and cannot be transferred again in the same
+		/// round.
+		///
+		/// Pending unstaking requests of either account are not affected.
+		///
+		/// Emits `DelegationTransferred`.
+		#[pallet::call_index(26)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::transfer_delegation())]
+		pub fn transfer_delegation(
+			origin: OriginFor<T>,
+			new_delegator: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResult {
+			let delegator = ensure_signed(origin)?;
+			let new_delegator = T::Lookup::lookup(new_delegator)?;
+
+			Self::do_transfer_delegation(delegator, new_delegator)
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
@@ -2013,6 +2200,69 @@ pub mod pallet {
 			(collators.len().saturated_into(), num_of_delegators)
 		}
 
+		/// Increase the stake of the delegator for their ongoing delegation.
+		///
+		/// Returns the number of top candidates and the number of unstaking
+		/// requests of the delegator, used for post-weight correction.
+		fn do_delegator_stake_more(delegator: T::AccountId, more: BalanceOf<T>) -> Result<(u32, u32), DispatchError> {
+			ensure!(!more.is_zero(), Error::<T>::ValStakeZero);
+
+			let mut delegation = DelegatorState::<T>::get(&delegator).ok_or(Error::<T>::DelegatorNotFound)?;
+			let candidate = delegation.owner.clone();
+			let mut collator = CandidatePool::<T>::get(&candidate).ok_or(Error::<T>::CandidateNotFound)?;
+			ensure!(!collator.is_leaving(), Error::<T>::CannotDelegateIfLeaving);
+			let stake_after = delegation
+				.try_increment(candidate.clone(), more)
+				.map_err(|_| Error::<T>::DelegationNotFound)?;
+
+			// update lock
+			let unstaking_len = Self::increase_lock(&delegator, stake_after, more)?;
+
+			let CandidateOf::<T, _> {
+				stake: before_stake,
+				total: before_total,
+				..
+			} = collator;
+			collator.inc_delegator(delegator.clone(), more);
+			let after = collator.total;
+
+			// update top candidates and total amount at stake
+			let n = if collator.is_active() {
+				Self::update_top_candidates(
+					candidate.clone(),
+					before_stake,
+					// safe because total >= stake
+					before_total - before_stake,
+					collator.stake,
+					collator.total - collator.stake,
+				)
+			} else {
+				0u32
+			};
+
+			// increment rewards and update number of rewarded blocks
+			Self::do_inc_delegator_reward(&delegator, stake_after.saturating_sub(more), &candidate);
+
+			CandidatePool::<T>::insert(&candidate, collator);
+			DelegatorState::<T>::insert(&delegator, delegation);
+
+			Self::deposit_event(Event::DelegatorStakedMore(delegator, candidate, before_total, after));
+			Ok((n, unstaking_len))
+		}
+
+		/// Remove the delegator from the set of delegators, revoking their
+		/// ongoing delegation.
+		fn do_leave_delegators(delegator: T::AccountId) -> DispatchResult {
+			let delegation = DelegatorState::<T>::get(&delegator).ok_or(Error::<T>::DelegatorNotFound)?;
+			let collator = delegation.owner;
+			Self::delegator_leaves_collator(delegator.clone(), collator)?;
+
+			DelegatorState::<T>::remove(&delegator);
+
+			Self::deposit_event(Event::DelegatorLeft(delegator, delegation.amount));
+			Ok(())
+		}
+
 		/// Update the collator's state by removing the delegator's stake and
 		/// starting the process to unlock the delegator's staked funds as well
 		/// as incrementing their accumulated rewards.
@@ -2074,6 +2324,17 @@ pub mod pallet {
 		/// the same total stake during sorting, we revert this swap to
 		/// prioritize collators over candidates.
 		pub fn selected_candidates() -> BoundedVec<T::AccountId, T::MaxTopCandidates> {
+			Self::candidate_selection().0
+		}
+
+		/// Split the top candidates into the best `MaxSelectedCandidates` many
+		/// candidates, which are selected as collators, and the remaining top
+		/// candidates, which are not.
+		#[allow(clippy::type_complexity)]
+		pub(crate) fn candidate_selection() -> (
+			BoundedVec<T::AccountId, T::MaxTopCandidates>,
+			BoundedVec<T::AccountId, T::MaxTopCandidates>,
+		) {
 			let candidates = TopCandidates::<T>::get();
 
 			// Should never fail since WASM usize are 32bits and native are either 32 or 64
@@ -2082,14 +2343,20 @@ pub mod pallet {
 			log::trace!("{} Candidates for {} Collator seats", candidates.len(), top_n);
 
 			// Choose the top MaxSelectedCandidates qualified candidates
-			let collators = candidates
+			let (collators, dropped): (Vec<_>, Vec<_>) = candidates
 				.into_iter()
-				.take(top_n)
-				.filter(|x| x.amount >= T::MinCollatorStake::get())
-				.map(|x| x.owner)
-				.collect::<Vec<T::AccountId>>();
+				.enumerate()
+				.partition(|(rank, x)| *rank < top_n && x.amount >= T::MinCollatorStake::get());
+			let into_owners = |stakes: Vec<(usize, StakeOf<T>)>| -> BoundedVec<T::AccountId, T::MaxTopCandidates> {
+				stakes
+					.into_iter()
+					.map(|(_, x)| x.owner)
+					.collect::<Vec<T::AccountId>>()
+					.try_into()
+					.expect("Did not extend TopCandidates q.e.d.")
+			};
 
-			collators.try_into().expect("Did not extend Collators q.e.d.")
+			(into_owners(collators), into_owners(dropped))
 		}
 
 		/// Attempts to add the stake to the set of delegators of a collator
@@ -2347,6 +2614,160 @@ pub mod pallet {
 			Ok(unstaking_len)
 		}
 
+		/// Withdraw all unstaked currency regardless of whether it was unstaked
+		/// at least `StakeDuration` blocks ago. The penalty for the currency
+		/// which is not unlockable yet is moved to the
+		/// `InstantUnstakePenaltyBeneficiary`.
+		fn do_unstake_instantly(who: &T::AccountId) -> Result<u32, DispatchError> {
+			let now = frame_system::Pallet::<T>::block_number();
+			let unstaking = Unstaking::<T>::get(who);
+			let unstaking_len = unstaking.len().saturated_into::<u32>();
+			ensure!(!unstaking.is_empty(), Error::<T>::UnstakingIsEmpty);
+
+			let (total_unstaked, total_pending) = unstaking.into_iter().fold(
+				(BalanceOf::<T>::zero(), BalanceOf::<T>::zero()),
+				|(total_unstaked, total_pending), (block_number, freezed_balance)| {
+					let total_pending = if block_number > now {
+						total_pending.saturating_add(freezed_balance)
+					} else {
+						total_pending
+					};
+					(total_unstaked.saturating_add(freezed_balance), total_pending)
+				},
+			);
+
+			let freeze_id = <T as pallet::Config>::FreezeIdentifier::from(FreezeReason::Staking);
+			let total_freezed = T::Currency::balance_frozen(&freeze_id, who).saturating_sub(total_unstaked);
+			if total_freezed.is_zero() {
+				T::Currency::thaw(&freeze_id, who)?;
+			} else {
+				T::Currency::set_freeze(&freeze_id, who, total_freezed)?;
+			}
+			Unstaking::<T>::remove(who);
+
+			let penalty = T::InstantUnstakePenalty::get() * total_pending;
+			if !penalty.is_zero() {
+				let credit = T::Currency::withdraw(
+					who,
+					penalty,
+					Precision::Exact,
+					Preservation::Preserve,
+					Fortitude::Polite,
+				)?;
+				T::InstantUnstakePenaltyBeneficiary::on_unbalanced(credit);
+			}
+
+			Self::deposit_event(Event::UnstakedInstantly(
+				who.clone(),
+				total_unstaked.saturating_sub(penalty),
+				penalty,
+			));
+
+			Ok(unstaking_len)
+		}
+
+		/// Replace all unstaking requests of the account with a single one
+		/// for their total amount, which can be unlocked at the latest block
+		/// of the replaced requests.
+		fn do_merge_unstaking(who: &T::AccountId) -> Result<u32, DispatchError> {
+			let unstaking = Unstaking::<T>::get(who);
+			let unstaking_len = unstaking.len().saturated_into::<u32>();
+
+			// the requests are sorted by their unlock block
+			let unlock_block = *unstaking.keys().last().ok_or(Error::<T>::UnstakingIsEmpty)?;
+			let total_unstaked = unstaking
+				.values()
+				.fold(BalanceOf::<T>::zero(), |total, amount| total.saturating_add(*amount));
+
+			let mut merged = BoundedBTreeMap::new();
+			merged
+				.try_insert(unlock_block, total_unstaked)
+				.map_err(|_| Error::<T>::NoMoreUnstaking)?;
+			Unstaking::<T>::insert(who, merged);
+
+			Self::deposit_event(Event::UnstakingMerged(who.clone(), unlock_block, total_unstaked));
+
+			Ok(unstaking_len)
+		}
+
+		/// Move the delegation and the staked funds of `delegator` to
+		/// `new_delegator`, settling the rewards of `delegator` beforehand.
+		fn do_transfer_delegation(delegator: T::AccountId, new_delegator: T::AccountId) -> DispatchResult {
+			let delegation = DelegatorState::<T>::get(&delegator).ok_or(Error::<T>::DelegatorNotFound)?;
+			ensure!(
+				DelegatorState::<T>::get(&new_delegator).is_none(),
+				Error::<T>::AlreadyDelegating
+			);
+			ensure!(
+				Self::is_active_candidate(&new_delegator).is_none(),
+				Error::<T>::CandidateExists
+			);
+			ensure!(
+				LastDelegation::<T>::get(&delegator).round < Round::<T>::get().current,
+				Error::<T>::DelegationNotMature
+			);
+			let delegation_counter = Self::get_delegation_counter(&new_delegator)?;
+
+			let collator = delegation.owner.clone();
+			let amount = delegation.amount;
+			let mut state = CandidatePool::<T>::get(&collator).ok_or(Error::<T>::CandidateNotFound)?;
+			state
+				.delegators
+				.remove(&Stake {
+					owner: delegator.clone(),
+					// amount is irrelevant for removal
+					amount: BalanceOf::<T>::one(),
+				})
+				.ok_or(Error::<T>::DelegatorNotFound)?;
+			let inserted = state
+				.delegators
+				.try_insert(Stake {
+					owner: new_delegator.clone(),
+					amount,
+				})
+				.map_err(|_| Error::<T>::TooManyDelegators)?;
+			// should never fail since the new delegator is not delegating
+			ensure!(inserted, Error::<T>::DelegatorExists);
+
+			// settle the rewards of the previous delegator such that the new delegator can
+			// only claim rewards for blocks authored after the transfer
+			Self::do_inc_delegator_reward(&delegator, amount, &collator);
+			BlocksRewarded::<T>::remove(&delegator);
+			BlocksRewarded::<T>::insert(&new_delegator, BlocksAuthored::<T>::get(&collator));
+
+			// move the staked funds while keeping the unstaking funds of both accounts
+			// frozen
+			let freeze_id = <T as pallet::Config>::FreezeIdentifier::from(FreezeReason::Staking);
+			let remaining_freezed = T::Currency::balance_frozen(&freeze_id, &delegator).saturating_sub(amount);
+			if remaining_freezed.is_zero() {
+				T::Currency::thaw(&freeze_id, &delegator)?;
+			} else {
+				T::Currency::set_freeze(&freeze_id, &delegator, remaining_freezed)?;
+			}
+			let credit = T::Currency::withdraw(
+				&delegator,
+				amount,
+				Precision::Exact,
+				Preservation::Preserve,
+				Fortitude::Polite,
+			)?;
+			T::Currency::resolve(&new_delegator, credit)
+				.map_err(|_| pallet_balances::Error::<T>::ExistentialDeposit)?;
+			T::Currency::set_freeze(
+				&freeze_id,
+				&new_delegator,
+				T::Currency::balance_frozen(&freeze_id, &new_delegator).saturating_add(amount),
+			)?;
+
+			CandidatePool::<T>::insert(&collator, state);
+			DelegatorState::<T>::remove(&delegator);
+			DelegatorState::<T>::insert(&new_delegator, delegation);
+			LastDelegation::<T>::insert(&new_delegator, delegation_counter);
+
+			Self::deposit_event(Event::DelegationTransferred(delegator, new_delegator, collator, amount));
+			Ok(())
+		}
+
 		/// Checks whether a delegator can still delegate in this round, e.g.,
 		/// if they have not delegated MaxDelegationsPerRound many times
 		/// already in this round.
@@ -2519,13 +2940,14 @@ pub mod pallet {
 				DispatchClass::Mandatory,
 			);
 
-			let collators = Pallet::<T>::selected_candidates().to_vec();
+			let (collators, dropped) = Pallet::<T>::candidate_selection();
 			if collators.is_empty() {
 				// we never want to pass an empty set of collators. This would brick the chain.
 				log::error!("💥 keeping old session because of empty collator set!");
 				None
 			} else {
-				Some(collators)
+				Pallet::<T>::deposit_event(Event::CollatorsSelected(new_index, collators.clone(), dropped));
+				Some(collators.to_vec())
 			}
 		}
 
@@ -20,13 +20,19 @@
 #![allow(clippy::from_over_into)]
 
 use super::*;
-use crate::{self as stake, types::CreditOf};
+use crate::{
+	self as stake,
+	traits::{LinkedAccountsInspect, SessionKeysOwnership},
+	types::CreditOf,
+};
 use frame_support::{
 	assert_ok, construct_runtime, parameter_types,
 	traits::{fungible::Balanced, OnFinalize, OnInitialize, OnUnbalanced},
 };
-use frame_system::pallet_prelude::BlockNumberFor;
+use frame_system::{pallet_prelude::BlockNumberFor, EnsureSigned};
+use kilt_support::mock::pallet_events;
 use pallet_authorship::EventHandler;
+use parity_scale_codec::Encode;
 use sp_consensus_aura::sr25519::AuthorityId;
 use sp_core::{ConstBool, H256};
 use sp_runtime::{
@@ -142,7 +148,9 @@ parameter_types! {
 	pub const MinDelegatorStake: Balance = 5;
 	pub const MaxUnstakeRequests: u32 = 6;
 	pub const NetworkRewardRate: Perquintill = Perquintill::from_percent(10);
+	pub const InstantUnstakePenalty: Perquintill = Perquintill::from_percent(10);
 	pub const NetworkRewardStart: BlockNumber = 5 * 5 * 60 * 24 * 36525 / 100;
+	pub const MaxLinkedAccountsPerCall: u32 = 3;
 }
 
 pub struct ToBeneficiary();
@@ -153,6 +161,37 @@ impl OnUnbalanced<CreditOf<Test>> for ToBeneficiary {
 	}
 }
 
+/// Links the accounts `100 * identity..100 * (identity + 1)` to `identity`,
+/// e.g., identity `1` has the accounts `100..=199` linked to it.
+pub struct MockLinkedAccounts;
+impl LinkedAccountsInspect<AccountId, AccountId> for MockLinkedAccounts {
+	fn is_linked(identity: &AccountId, account: &AccountId) -> bool {
+		account / 100 == *identity
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn link_accounts(identity: &AccountId, num_accounts: u32) -> Vec<AccountId> {
+		(0..u64::from(num_accounts)).map(|i| 100 * identity + i).collect()
+	}
+}
+
+/// Accepts a proof of ownership of session keys if it is the SCALE-encoded
+/// account registering the keys.
+pub struct MockSessionKeysOwnership;
+impl SessionKeysOwnership<AccountId, MockSessionKeys> for MockSessionKeysOwnership {
+	fn is_owned_by(who: &AccountId, _keys: &MockSessionKeys, proof: &[u8]) -> bool {
+		proof == who.encode()
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn generate_keys_and_proof(who: &AccountId) -> (MockSessionKeys, Vec<u8>) {
+		let keys = MockSessionKeys {
+			aura: UintAuthorityId(*who).to_public_key(),
+		};
+		(keys, who.encode())
+	}
+}
+
 impl Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
@@ -173,6 +212,14 @@ impl Config for Test {
 	type NetworkRewardRate = NetworkRewardRate;
 	type NetworkRewardStart = NetworkRewardStart;
 	type NetworkRewardBeneficiary = ToBeneficiary;
+	type InstantUnstakePenalty = InstantUnstakePenalty;
+	type InstantUnstakePenaltyBeneficiary = ToBeneficiary;
+	type IdentityId = AccountId;
+	type EnsureIdentityOrigin = EnsureSigned<AccountId>;
+	type IdentityOriginSuccess = AccountId;
+	type LinkedAccounts = MockLinkedAccounts;
+	type SessionKeysOwnership = MockSessionKeysOwnership;
+	type MaxLinkedAccountsPerCall = MaxLinkedAccountsPerCall;
 	type WeightInfo = ();
 	type FreezeIdentifier = RuntimeFreezeReason;
 	const BLOCKS_PER_YEAR: BlockNumberFor<Test> = 5 * 60 * 24 * 36525 / 100;
@@ -421,15 +468,5 @@ pub(crate) fn last_event() -> pallet::Event<Test> {
 }
 
 pub(crate) fn events() -> Vec<pallet::Event<Test>> {
-	System::events()
-		.into_iter()
-		.map(|r| r.event)
-		.filter_map(|e| {
-			if let RuntimeEvent::StakePallet(inner) = e {
-				Some(inner)
-			} else {
-				None
-			}
-		})
-		.collect::<Vec<_>>()
+	pallet_events::<Test, pallet::Event<Test>>()
 }
@@ -20,7 +20,8 @@
 
 use std::{convert::TryInto, iter};
 
-use frame_support::{assert_noop, assert_ok, storage::bounded_btree_map::BoundedBTreeMap, BoundedVec};
+use frame_support::{assert_noop, assert_ok, bounded_vec, storage::bounded_btree_map::BoundedBTreeMap, BoundedVec};
+use kilt_runtime_api_staking::{CandidateRanking, CandidateRankingEntry};
 use pallet_balances::Error as BalancesError;
 
 use sp_runtime::SaturatedConversion;
@@ -171,13 +172,18 @@ fn collator_exit_executes_after_delay() {
 			roll_to(25, vec![]);
 			let expected = vec![
 				Event::MaxSelectedCandidatesSet(2, 5),
+				Event::CollatorsSelected(2, bounded_vec![1, 2, 7], bounded_vec![]),
 				Event::NewRound(5, 1),
+				Event::CollatorsSelected(3, bounded_vec![1, 2, 7], bounded_vec![]),
 				Event::NewRound(10, 2),
 				Event::LeftTopCandidates(2),
 				Event::CollatorScheduledExit(2, 2, 4),
+				Event::CollatorsSelected(4, bounded_vec![1, 7], bounded_vec![]),
 				Event::NewRound(15, 3),
+				Event::CollatorsSelected(5, bounded_vec![1, 7], bounded_vec![]),
 				Event::NewRound(20, 4),
 				Event::CandidateLeft(2, 400),
+				Event::CollatorsSelected(6, bounded_vec![1, 7], bounded_vec![]),
 				Event::NewRound(25, 5),
 			];
 			assert_eq!(events(), expected);
@@ -219,7 +225,11 @@ fn collator_selection_chooses_top_candidates() {
 			roll_to(8, vec![]);
 			// should choose top MaxSelectedCandidates (5), in order
 			assert_eq!(StakePallet::selected_candidates().into_inner(), vec![1, 2, 3, 4, 5]);
-			let expected = vec![Event::MaxSelectedCandidatesSet(2, 5), Event::NewRound(5, 1)];
+			let expected = vec![
+				Event::MaxSelectedCandidatesSet(2, 5),
+				Event::CollatorsSelected(2, bounded_vec![1, 2, 3, 4, 5], bounded_vec![6]),
+				Event::NewRound(5, 1),
+			];
 			assert_eq!(events(), expected);
 			assert_ok!(StakePallet::init_leave_candidates(RuntimeOrigin::signed(6)));
 			assert_eq!(StakePallet::selected_candidates().into_inner(), vec![1, 2, 3, 4, 5],);
@@ -238,17 +248,22 @@ fn collator_selection_chooses_top_candidates() {
 			// should choose top MaxSelectedCandidates (5), in order
 			let expected = vec![
 				Event::MaxSelectedCandidatesSet(2, 5),
+				Event::CollatorsSelected(2, bounded_vec![1, 2, 3, 4, 5], bounded_vec![6]),
 				Event::NewRound(5, 1),
 				Event::LeftTopCandidates(6),
 				Event::CollatorScheduledExit(1, 6, 3),
 				// TotalCollatorStake is updated once candidate 6 left in `execute_delayed_collator_exits`
+				Event::CollatorsSelected(3, bounded_vec![1, 2, 3, 4, 5], bounded_vec![]),
 				Event::NewRound(10, 2),
+				Event::CollatorsSelected(4, bounded_vec![1, 2, 3, 4, 5], bounded_vec![]),
 				Event::NewRound(15, 3),
 				Event::CandidateLeft(6, 50),
+				Event::CollatorsSelected(5, bounded_vec![1, 2, 3, 4, 5], bounded_vec![]),
 				Event::NewRound(20, 4),
 				// 5 had staked 60 which was exceeded by 69 of 6
 				Event::EnteredTopCandidates(6),
 				Event::JoinedCollatorCandidates(6, 69),
+				Event::CollatorsSelected(6, bounded_vec![1, 2, 3, 4, 6], bounded_vec![5]),
 				Event::NewRound(25, 5),
 			];
 			assert_eq!(events(), expected);
@@ -1394,7 +1409,11 @@ fn exit_queue_with_events() {
 			roll_to(8, vec![]);
 			// should choose top MaxSelectedCandidates (5), in order
 			assert_eq!(StakePallet::selected_candidates().into_inner(), vec![1, 2, 3, 4, 5]);
-			let mut expected = vec![Event::MaxSelectedCandidatesSet(2, 5), Event::NewRound(5, 1)];
+			let mut expected = vec![
+				Event::MaxSelectedCandidatesSet(2, 5),
+				Event::CollatorsSelected(2, bounded_vec![1, 2, 3, 4, 5], bounded_vec![6]),
+				Event::NewRound(5, 1),
+			];
 			assert_eq!(events(), expected);
 			assert_ok!(StakePallet::init_leave_candidates(RuntimeOrigin::signed(6)));
 			assert_eq!(StakePallet::selected_candidates().into_inner(), vec![1, 2, 3, 4, 5]);
@@ -1430,17 +1449,22 @@ fn exit_queue_with_events() {
 			let mut new_events = vec![
 				Event::LeftTopCandidates(6),
 				Event::CollatorScheduledExit(1, 6, 3),
+				Event::CollatorsSelected(3, bounded_vec![1, 2, 3, 4, 5], bounded_vec![]),
 				Event::NewRound(10, 2),
 				Event::LeftTopCandidates(5),
 				Event::CollatorScheduledExit(2, 5, 4),
+				Event::CollatorsSelected(4, bounded_vec![1, 2, 3, 4], bounded_vec![]),
 				Event::NewRound(15, 3),
 				Event::CandidateLeft(6, 50),
 				Event::LeftTopCandidates(4),
 				Event::CollatorScheduledExit(3, 4, 5),
+				Event::CollatorsSelected(5, bounded_vec![1, 2, 3], bounded_vec![]),
 				Event::NewRound(20, 4),
 				Event::CandidateLeft(5, 60),
+				Event::CollatorsSelected(6, bounded_vec![1, 2, 3], bounded_vec![]),
 				Event::NewRound(25, 5),
 				Event::CandidateLeft(4, 70),
+				Event::CollatorsSelected(7, bounded_vec![1, 2, 3], bounded_vec![]),
 				Event::NewRound(30, 6),
 			];
 			expected.append(&mut new_events);
@@ -1544,3 +1568,54 @@ fn set_max_selected_candidates_safe_guards() {
 			));
 		});
 }
+
+#[test]
+fn candidate_ranking() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 1000), (2, 1000), (3, 1000), (4, 1000)])
+		.with_collators(vec![(1, 100), (2, 90), (3, 80)])
+		.with_delegators(vec![(4, 3, 30)])
+		.build_and_execute_with_sanity_tests(|| {
+			let ranking = StakePallet::get_candidate_ranking();
+			assert_eq!(
+				ranking,
+				CandidateRanking {
+					candidates: vec![
+						CandidateRankingEntry {
+							account: 3,
+							self_stake: 80,
+							delegator_stake: 30,
+							total_stake: 110,
+							selected: true,
+						},
+						CandidateRankingEntry {
+							account: 1,
+							self_stake: 100,
+							delegator_stake: 0,
+							total_stake: 100,
+							selected: true,
+						},
+						CandidateRankingEntry {
+							account: 2,
+							self_stake: 90,
+							delegator_stake: 0,
+							total_stake: 90,
+							selected: false,
+						},
+					],
+					seats: 2,
+					cutoff: 100,
+				}
+			);
+
+			// With more seats than candidates, the minimum collator stake is the cutoff
+			assert_ok!(StakePallet::set_max_selected_candidates(RuntimeOrigin::root(), 5));
+			let ranking = StakePallet::get_candidate_ranking();
+			assert!(ranking.candidates.iter().all(|entry| entry.selected));
+			assert_eq!(ranking.seats, 5);
+			assert_eq!(ranking.cutoff, <Test as Config>::MinCollatorStake::get());
+
+			roll_to(5, vec![]);
+			assert!(events().contains(&Event::CollatorsSelected(2, bounded_vec![3, 1, 2], bounded_vec![])));
+		});
+}
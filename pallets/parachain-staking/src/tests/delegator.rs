@@ -20,9 +20,11 @@
 
 use std::convert::TryInto;
 
-use frame_support::{assert_noop, assert_ok, storage::bounded_btree_map::BoundedBTreeMap, traits::fungible::Inspect};
+use frame_support::{
+	assert_noop, assert_ok, bounded_vec, storage::bounded_btree_map::BoundedBTreeMap, traits::fungible::Inspect,
+};
 
-use pallet_balances::Error as BalancesError;
+use pallet_balances::{Error as BalancesError, Freezes, IdAmount};
 
 use sp_runtime::{traits::Zero, SaturatedConversion};
 
@@ -33,7 +35,7 @@ use crate::{
 	},
 	set::OrderedSet,
 	types::{BalanceOf, DelegationCounter, Stake, StakeOf},
-	Config, Error, Event, Event as StakeEvent,
+	Config, Error, Event, Event as StakeEvent, FreezeReason,
 };
 
 #[test]
@@ -72,7 +74,11 @@ fn multiple_delegations() {
 			);
 			// chooses top MaxSelectedCandidates (5), in order
 			assert_eq!(StakePallet::selected_candidates().into_inner(), vec![1, 2, 3, 4, 5]);
-			let mut expected = vec![Event::MaxSelectedCandidatesSet(2, 5), Event::NewRound(5, 1)];
+			let mut expected = vec![
+				Event::MaxSelectedCandidatesSet(2, 5),
+				Event::CollatorsSelected(2, bounded_vec![1, 2, 3, 4, 5], bounded_vec![]),
+				Event::NewRound(5, 1),
+			];
 			assert_eq!(events(), expected);
 			assert_noop!(
 				StakePallet::join_delegators(RuntimeOrigin::signed(13), 2, 2),
@@ -96,7 +102,9 @@ fn multiple_delegations() {
 				Event::Delegation(13, 10, 2, 50),
 				Event::Delegation(14, 10, 4, 30),
 				Event::Delegation(15, 10, 3, 30),
+				Event::CollatorsSelected(3, bounded_vec![1, 2, 4, 3, 5], bounded_vec![]),
 				Event::NewRound(10, 2),
+				Event::CollatorsSelected(4, bounded_vec![1, 2, 4, 3, 5], bounded_vec![]),
 				Event::NewRound(15, 3),
 			];
 			expected.append(&mut new);
@@ -135,12 +143,14 @@ fn multiple_delegations() {
 			roll_to(26, vec![Some(1), Some(2), Some(3), Some(4), Some(5)]);
 			assert_eq!(StakePallet::selected_candidates().into_inner(), vec![2, 1, 4, 3, 5]);
 			let mut new2 = vec![
+				Event::CollatorsSelected(5, bounded_vec![1, 2, 4, 3, 5], bounded_vec![]),
 				Event::NewRound(20, 4),
 				Event::Delegation(16, 80, 2, 130),
 				Event::DelegationReplaced(17, 11, 13, 10, 2, 131),
 				Event::Delegation(17, 11, 2, 131),
 				Event::DelegationReplaced(11, 11, 9, 10, 2, 132),
 				Event::Delegation(11, 11, 2, 132),
+				Event::CollatorsSelected(6, bounded_vec![2, 1, 4, 3, 5], bounded_vec![]),
 				Event::NewRound(25, 5),
 			];
 			expected.append(&mut new2);
@@ -153,6 +163,7 @@ fn multiple_delegations() {
 			let mut new3 = vec![
 				Event::LeftTopCandidates(2),
 				Event::CollatorScheduledExit(5, 2, 7),
+				Event::CollatorsSelected(7, bounded_vec![1, 4, 3, 5], bounded_vec![]),
 				Event::NewRound(30, 6),
 			];
 			expected.append(&mut new3);
@@ -542,3 +553,106 @@ fn replace_lowest_delegator() {
 			assert!(StakePallet::delegator_state(6).is_some());
 		})
 }
+
+#[test]
+fn transfer_delegation() {
+	ExtBuilder::default()
+		.with_balances(vec![
+			(1, DECIMALS),
+			(2, DECIMALS),
+			(3, 2 * DECIMALS),
+			(4, DECIMALS),
+			(5, DECIMALS),
+		])
+		.with_collators(vec![(1, DECIMALS), (2, DECIMALS)])
+		.with_delegators(vec![(3, 1, DECIMALS), (5, 2, DECIMALS)])
+		.build_and_execute_with_sanity_tests(|| {
+			// delegations cannot be transferred in the round in which they were made
+			assert_noop!(
+				StakePallet::transfer_delegation(RuntimeOrigin::signed(3), 4),
+				Error::<Test>::DelegationNotMature
+			);
+			roll_to(5, vec![None, Some(1), Some(1), Some(1), Some(1)]);
+			assert_eq!(StakePallet::round().current, 1);
+			let blocks_authored = StakePallet::blocks_authored(1);
+			assert!(!blocks_authored.is_zero());
+			let collator_total = StakePallet::candidate_pool(1).unwrap().total;
+
+			assert_ok!(StakePallet::transfer_delegation(RuntimeOrigin::signed(3), 4));
+			assert_eq!(last_event(), StakeEvent::DelegationTransferred(3, 4, 1, DECIMALS));
+
+			assert!(StakePallet::delegator_state(3).is_none());
+			assert_eq!(StakePallet::delegator_state(4).unwrap().owner, 1);
+			assert_eq!(StakePallet::delegator_state(4).unwrap().amount, DECIMALS);
+			let state = StakePallet::candidate_pool(1).unwrap();
+			assert_eq!(state.total, collator_total);
+			assert_eq!(
+				state.delegators.into_bounded_vec().into_inner(),
+				vec![Stake {
+					owner: 4,
+					amount: DECIMALS
+				}]
+			);
+
+			// the staked funds are moved to the new delegator
+			assert_eq!(Balances::balance(&3), DECIMALS);
+			assert_eq!(Balances::balance(&4), 2 * DECIMALS);
+			assert!(Freezes::<Test>::get(3).is_empty());
+			assert_eq!(
+				Freezes::<Test>::get(4),
+				vec![IdAmount {
+					id: <Test as Config>::FreezeIdentifier::from(FreezeReason::Staking),
+					amount: DECIMALS,
+				}]
+			);
+			assert!(StakePallet::unstaking(3).is_empty());
+			assert!(StakePallet::unstaking(4).is_empty());
+
+			// the rewards up to the transfer belong to the previous delegator
+			assert!(!StakePallet::rewards(3).is_zero());
+			assert!(StakePallet::blocks_rewarded(3).is_zero());
+			assert_eq!(StakePallet::blocks_rewarded(4), blocks_authored);
+			assert_ok!(StakePallet::increment_delegator_rewards(RuntimeOrigin::signed(4)));
+			assert!(StakePallet::rewards(4).is_zero());
+
+			// the position cannot be transferred again in the same round
+			assert_noop!(
+				StakePallet::transfer_delegation(RuntimeOrigin::signed(4), 3),
+				Error::<Test>::DelegationNotMature
+			);
+		});
+}
+
+#[test]
+fn transfer_delegation_fails() {
+	ExtBuilder::default()
+		.with_balances(vec![
+			(1, DECIMALS),
+			(2, DECIMALS),
+			(3, DECIMALS),
+			(4, DECIMALS),
+			(5, DECIMALS),
+		])
+		.with_collators(vec![(1, DECIMALS), (2, DECIMALS)])
+		.with_delegators(vec![(3, 1, DECIMALS), (4, 2, DECIMALS)])
+		.build_and_execute_with_sanity_tests(|| {
+			roll_to(5, vec![]);
+
+			assert_noop!(
+				StakePallet::transfer_delegation(RuntimeOrigin::signed(5), 3),
+				Error::<Test>::DelegatorNotFound
+			);
+			assert_noop!(
+				StakePallet::transfer_delegation(RuntimeOrigin::signed(3), 3),
+				Error::<Test>::AlreadyDelegating
+			);
+			assert_noop!(
+				StakePallet::transfer_delegation(RuntimeOrigin::signed(3), 4),
+				Error::<Test>::AlreadyDelegating
+			);
+			assert_noop!(
+				StakePallet::transfer_delegation(RuntimeOrigin::signed(3), 2),
+				Error::<Test>::CandidateExists
+			);
+		});
+}
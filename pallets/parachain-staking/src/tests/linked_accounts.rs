@@ -0,0 +1,138 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Unit testing
+
+use frame_support::{assert_noop, assert_ok, BoundedVec};
+use sp_runtime::DispatchError;
+
+use crate::{
+	mock::{last_event, ExtBuilder, RuntimeOrigin, StakePallet, Test},
+	types::StakeOf,
+	Error, Event as StakeEvent,
+};
+
+#[test]
+fn linked_accounts_stake_more() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100), (100, 100), (101, 100)])
+		.with_collators(vec![(1, 10), (2, 10)])
+		.with_delegators(vec![(100, 1, 10), (101, 2, 10)])
+		.build_and_execute_with_sanity_tests(|| {
+			let stakes: BoundedVec<_, _> = vec![(100, 5), (101, 15)].try_into().unwrap();
+			assert_ok!(StakePallet::linked_accounts_stake_more(
+				RuntimeOrigin::signed(1),
+				stakes
+			));
+
+			assert_eq!(StakePallet::delegator_state(100).unwrap().amount, 15);
+			assert_eq!(StakePallet::delegator_state(101).unwrap().amount, 25);
+			assert_eq!(StakePallet::candidate_pool(1).unwrap().total, 25);
+			assert_eq!(StakePallet::candidate_pool(2).unwrap().total, 35);
+			assert_eq!(last_event(), StakeEvent::DelegatorStakedMore(101, 2, 20, 35));
+		});
+}
+
+#[test]
+fn linked_accounts_stake_more_not_linked() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100), (100, 100), (200, 100)])
+		.with_collators(vec![(1, 10), (2, 10)])
+		.with_delegators(vec![(100, 1, 10), (200, 2, 10)])
+		.build_and_execute_with_sanity_tests(|| {
+			// Account 200 is linked to identity 2, not identity 1
+			let stakes: BoundedVec<_, _> = vec![(100, 5), (200, 5)].try_into().unwrap();
+			assert_noop!(
+				StakePallet::linked_accounts_stake_more(RuntimeOrigin::signed(1), stakes),
+				Error::<Test>::AccountNotLinked
+			);
+		});
+}
+
+#[test]
+fn linked_accounts_stake_more_bad_origin() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100), (100, 100)])
+		.with_collators(vec![(1, 10), (2, 10)])
+		.with_delegators(vec![(100, 1, 10)])
+		.build_and_execute_with_sanity_tests(|| {
+			let stakes: BoundedVec<_, _> = vec![(100, 5)].try_into().unwrap();
+			assert_noop!(
+				StakePallet::linked_accounts_stake_more(RuntimeOrigin::root(), stakes),
+				DispatchError::BadOrigin
+			);
+		});
+}
+
+#[test]
+fn linked_accounts_leave_delegators() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100), (100, 100), (101, 100)])
+		.with_collators(vec![(1, 10), (2, 10)])
+		.with_delegators(vec![(100, 1, 10), (101, 2, 20)])
+		.build_and_execute_with_sanity_tests(|| {
+			let delegators: BoundedVec<_, _> = vec![100, 101].try_into().unwrap();
+			assert_ok!(StakePallet::linked_accounts_leave_delegators(
+				RuntimeOrigin::signed(1),
+				delegators
+			));
+
+			assert!(StakePallet::delegator_state(100).is_none());
+			assert!(StakePallet::delegator_state(101).is_none());
+			assert!(!StakePallet::candidate_pool(1)
+				.unwrap()
+				.delegators
+				.contains(&StakeOf::<Test> { owner: 100, amount: 10 }));
+			assert!(!StakePallet::candidate_pool(2)
+				.unwrap()
+				.delegators
+				.contains(&StakeOf::<Test> { owner: 101, amount: 20 }));
+			// Unstaked funds can be unlocked after `StakeDuration` blocks
+			assert_eq!(StakePallet::unstaking(100).get(&3), Some(&10));
+			assert_eq!(StakePallet::unstaking(101).get(&3), Some(&20));
+			assert_eq!(last_event(), StakeEvent::DelegatorLeft(101, 20));
+		});
+}
+
+#[test]
+fn linked_accounts_leave_delegators_not_linked() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100), (100, 100), (200, 100)])
+		.with_collators(vec![(1, 10), (2, 10)])
+		.with_delegators(vec![(100, 1, 10), (200, 2, 10)])
+		.build_and_execute_with_sanity_tests(|| {
+			let delegators: BoundedVec<_, _> = vec![100, 200].try_into().unwrap();
+			assert_noop!(
+				StakePallet::linked_accounts_leave_delegators(RuntimeOrigin::signed(1), delegators),
+				Error::<Test>::AccountNotLinked
+			);
+		});
+}
+
+#[test]
+fn linked_accounts_leave_delegators_not_delegating() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100), (100, 100), (101, 100)])
+		.with_collators(vec![(1, 10), (2, 10)])
+		.with_delegators(vec![(100, 1, 10)])
+		.build_and_execute_with_sanity_tests(|| {
+			let delegators: BoundedVec<_, _> = vec![100, 101].try_into().unwrap();
+			assert_noop!(
+				StakePallet::linked_accounts_leave_delegators(RuntimeOrigin::signed(1), delegators),
+				Error::<Test>::DelegatorNotFound
+			);
+		});
+}
@@ -22,7 +22,9 @@ mod collators;
 mod delegator;
 mod genesis;
 mod inflation;
+mod linked_accounts;
 mod rewards;
 mod round;
 mod session;
 mod stake;
+mod unstaking;
@@ -18,7 +18,7 @@
 
 //! Unit testing
 
-use frame_support::{assert_noop, assert_ok, traits::fungible::Inspect};
+use frame_support::{assert_noop, assert_ok, bounded_vec, traits::fungible::Inspect};
 use sp_runtime::Perquintill;
 
 use crate::{
@@ -62,7 +62,10 @@ fn round_transitions() {
 		.build_and_execute_with_sanity_tests(|| {
 			assert_eq!(inflation, StakePallet::inflation_config());
 			roll_to(5, vec![]);
-			let init = vec![Event::NewRound(5, 1)];
+			let init = vec![
+				Event::CollatorsSelected(2, bounded_vec![1, 7], bounded_vec![]),
+				Event::NewRound(5, 1),
+			];
 			assert_eq!(events(), init);
 			assert_ok!(StakePallet::set_blocks_per_round(RuntimeOrigin::root(), 3));
 			assert_noop!(
@@ -102,7 +105,10 @@ fn round_transitions() {
 			// 3 blocks
 			roll_to(6, vec![]);
 			// chooses top MaxSelectedCandidates (5), in order
-			let init = vec![Event::NewRound(5, 1)];
+			let init = vec![
+				Event::CollatorsSelected(2, bounded_vec![1, 7], bounded_vec![]),
+				Event::NewRound(5, 1),
+			];
 			assert_eq!(events(), init);
 			assert_ok!(StakePallet::set_blocks_per_round(RuntimeOrigin::root(), 3));
 			assert_eq!(last_event(), StakeEvent::BlocksPerRoundSet(1, 5, 5, 3));
@@ -139,7 +145,10 @@ fn round_transitions() {
 			assert_eq!(inflation, StakePallet::inflation_config());
 			roll_to(7, vec![]);
 			// chooses top MaxSelectedCandidates (5), in order
-			let init = vec![Event::NewRound(5, 1)];
+			let init = vec![
+				Event::CollatorsSelected(2, bounded_vec![1, 7], bounded_vec![]),
+				Event::NewRound(5, 1),
+			];
 			assert_eq!(events(), init);
 			assert_ok!(StakePallet::set_blocks_per_round(RuntimeOrigin::root(), 3));
 
@@ -18,11 +18,15 @@
 
 //! Unit testing
 
-use frame_support::traits::EstimateNextSessionRotation;
+use frame_support::{assert_noop, assert_ok, traits::EstimateNextSessionRotation};
 use pallet_session::ShouldEndSession;
-use sp_runtime::Permill;
+use parity_scale_codec::Encode;
+use sp_runtime::{testing::UintAuthorityId, Permill};
 
-use crate::mock::{ExtBuilder, StakePallet};
+use crate::{
+	mock::{ExtBuilder, MockSessionKeys, RuntimeOrigin, StakePallet, Test},
+	Error,
+};
 
 #[test]
 fn should_estimate_current_session_progress() {
@@ -152,3 +156,44 @@ fn should_end_session_when_appropriate() {
 			assert!(StakePallet::should_end_session(100));
 		});
 }
+
+#[test]
+fn set_session_keys_with_valid_proof() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 10), (2, 10)])
+		.with_collators(vec![(1, 10)])
+		.build_and_execute_with_sanity_tests(|| {
+			let keys = MockSessionKeys {
+				aura: UintAuthorityId(2).to_public_key(),
+			};
+
+			assert_ok!(StakePallet::set_session_keys(
+				RuntimeOrigin::signed(2),
+				keys.clone(),
+				2u64.encode()
+			));
+			assert_eq!(pallet_session::NextKeys::<Test>::get(2), Some(keys));
+		});
+}
+
+#[test]
+fn set_session_keys_with_invalid_proof() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 10), (2, 10)])
+		.with_collators(vec![(1, 10)])
+		.build_and_execute_with_sanity_tests(|| {
+			let keys = MockSessionKeys {
+				aura: UintAuthorityId(2).to_public_key(),
+			};
+
+			assert_noop!(
+				StakePallet::set_session_keys(RuntimeOrigin::signed(2), keys.clone(), 1u64.encode()),
+				Error::<Test>::InvalidSessionKeysProof
+			);
+			assert_noop!(
+				StakePallet::set_session_keys(RuntimeOrigin::signed(2), keys, vec![]),
+				Error::<Test>::InvalidSessionKeysProof
+			);
+			assert_eq!(pallet_session::NextKeys::<Test>::get(2), None);
+		});
+}
@@ -0,0 +1,133 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+//! Unit testing
+
+use frame_support::{assert_noop, assert_ok, storage::bounded_btree_map::BoundedBTreeMap, traits::fungible::Inspect};
+use pallet_balances::{Freezes, IdAmount};
+
+use crate::{
+	mock::{last_event, roll_to, Balances, BlockNumber, ExtBuilder, RuntimeOrigin, StakePallet, Test, TREASURY_ACC},
+	types::BalanceOf,
+	Config, Error, Event as StakeEvent, FreezeReason,
+};
+
+#[test]
+fn unstake_instantly() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100), (3, 200), (TREASURY_ACC, 100)])
+		.with_collators(vec![(1, 10), (2, 10)])
+		.with_delegators(vec![(3, 1, 100)])
+		.build_and_execute_with_sanity_tests(|| {
+			// unlockable in block 3
+			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(3), 50));
+			roll_to(2, vec![]);
+			// unlockable in block 4
+			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(3), 20));
+			roll_to(3, vec![]);
+
+			// only the pending 20 are penalized with 10%
+			assert_ok!(StakePallet::unstake_instantly(RuntimeOrigin::signed(3)));
+			assert_eq!(last_event(), StakeEvent::UnstakedInstantly(3, 68, 2));
+			assert!(StakePallet::unstaking(3).is_empty());
+			assert_eq!(
+				Freezes::<Test>::get(3),
+				vec![IdAmount {
+					id: <Test as Config>::FreezeIdentifier::from(FreezeReason::Staking),
+					amount: 30,
+				}]
+			);
+			assert_eq!(Balances::balance(&3), 198);
+			assert_eq!(Balances::balance(&TREASURY_ACC), 102);
+			assert_eq!(StakePallet::delegator_state(3).unwrap().amount, 30);
+
+			assert_noop!(
+				StakePallet::unstake_instantly(RuntimeOrigin::signed(3)),
+				Error::<Test>::UnstakingIsEmpty
+			);
+		});
+}
+
+#[test]
+fn unstake_instantly_after_leaving() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100), (3, 200), (TREASURY_ACC, 100)])
+		.with_collators(vec![(1, 10), (2, 10)])
+		.with_delegators(vec![(3, 1, 100)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(StakePallet::leave_delegators(RuntimeOrigin::signed(3)));
+
+			assert_ok!(StakePallet::unstake_instantly(RuntimeOrigin::signed(3)));
+			assert_eq!(last_event(), StakeEvent::UnstakedInstantly(3, 90, 10));
+			assert!(StakePallet::unstaking(3).is_empty());
+			assert!(Freezes::<Test>::get(3).is_empty());
+			assert_eq!(Balances::balance(&3), 190);
+			assert_eq!(Balances::balance(&TREASURY_ACC), 110);
+		});
+}
+
+#[test]
+fn merge_unstaking() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100), (3, 200)])
+		.with_collators(vec![(1, 10), (2, 10)])
+		.with_delegators(vec![(3, 1, 100)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_noop!(
+				StakePallet::merge_unstaking(RuntimeOrigin::signed(3)),
+				Error::<Test>::UnstakingIsEmpty
+			);
+
+			// fill up the manual unstaking requests
+			for block in 1..<Test as Config>::MaxUnstakeRequests::get() as BlockNumber {
+				roll_to(block, vec![]);
+				assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(3), 10));
+			}
+			assert_noop!(
+				StakePallet::delegator_stake_less(RuntimeOrigin::signed(3), 10),
+				Error::<Test>::NoMoreUnstaking
+			);
+
+			assert_ok!(StakePallet::merge_unstaking(RuntimeOrigin::signed(3)));
+			assert_eq!(last_event(), StakeEvent::UnstakingMerged(3, 7, 50));
+			let mut unstaking: BoundedBTreeMap<BlockNumber, BalanceOf<Test>, <Test as Config>::MaxUnstakeRequests> =
+				BoundedBTreeMap::new();
+			assert_ok!(unstaking.try_insert(7, 50));
+			assert_eq!(StakePallet::unstaking(3), unstaking);
+			// the frozen balance is not affected
+			assert_eq!(
+				Freezes::<Test>::get(3),
+				vec![IdAmount {
+					id: <Test as Config>::FreezeIdentifier::from(FreezeReason::Staking),
+					amount: 100,
+				}]
+			);
+
+			// new unstaking requests can be made again
+			roll_to(6, vec![]);
+			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(3), 10));
+			assert_eq!(StakePallet::unstaking(3).len(), 2);
+
+			// the merged request cannot be unlocked before its latest block
+			assert_ok!(StakePallet::unlock_unstaked(RuntimeOrigin::signed(3), 3));
+			assert_eq!(StakePallet::unstaking(3).len(), 2);
+			roll_to(7, vec![]);
+			assert_ok!(StakePallet::unlock_unstaked(RuntimeOrigin::signed(3), 3));
+			assert_eq!(StakePallet::unstaking(3).len(), 1);
+		});
+}
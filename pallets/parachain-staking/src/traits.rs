@@ -0,0 +1,78 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use parity_scale_codec::Decode;
+#[cfg(feature = "runtime-benchmarks")]
+use sp_runtime::traits::TrailingZeroInput;
+#[cfg(feature = "runtime-benchmarks")]
+use sp_std::vec::Vec;
+
+/// A trait to check whether an account is linked to a given identity, e.g.,
+/// a DID.
+///
+/// It is used to authorize an identity to manage the stake of all the accounts
+/// linked to it.
+pub trait LinkedAccountsInspect<IdentityId, AccountId> {
+	/// Return `true` if `account` is linked to `identity`, `false` otherwise.
+	fn is_linked(identity: &IdentityId, account: &AccountId) -> bool;
+
+	/// Link `num_accounts` new accounts to `identity` and return them, to
+	/// benchmark the calls managing the stake of linked accounts.
+	#[cfg(feature = "runtime-benchmarks")]
+	fn link_accounts(identity: &IdentityId, num_accounts: u32) -> Vec<AccountId>;
+}
+
+impl<IdentityId, AccountId> LinkedAccountsInspect<IdentityId, AccountId> for () {
+	fn is_linked(_identity: &IdentityId, _account: &AccountId) -> bool {
+		false
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn link_accounts(_identity: &IdentityId, _num_accounts: u32) -> Vec<AccountId> {
+		Vec::new()
+	}
+}
+
+/// A trait to verify that an account registering session keys is in
+/// possession of the corresponding private keys.
+///
+/// It prevents an account from registering the public keys of another
+/// collator as its own session keys.
+pub trait SessionKeysOwnership<AccountId, Keys> {
+	/// Return `true` if `proof` proves that `who` possesses the private keys
+	/// of `keys`, `false` otherwise.
+	fn is_owned_by(who: &AccountId, keys: &Keys, proof: &[u8]) -> bool;
+
+	/// Generate new session keys and a valid proof that `who` possesses
+	/// them, to benchmark the most expensive proof verification.
+	#[cfg(feature = "runtime-benchmarks")]
+	fn generate_keys_and_proof(who: &AccountId) -> (Keys, Vec<u8>);
+}
+
+impl<AccountId, Keys> SessionKeysOwnership<AccountId, Keys> for ()
+where
+	Keys: Decode,
+{
+	fn is_owned_by(_who: &AccountId, _keys: &Keys, _proof: &[u8]) -> bool {
+		true
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn generate_keys_and_proof(_who: &AccountId) -> (Keys, Vec<u8>) {
+		let keys = Keys::decode(&mut TrailingZeroInput::zeroes()).expect("Session keys must be decodable from zeroes.");
+		(keys, Vec::new())
+	}
+}
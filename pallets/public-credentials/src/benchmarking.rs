@@ -27,6 +27,7 @@ use sp_std::{boxed::Box, vec, vec::Vec};
 
 use ctype::CtypeEntryOf;
 use kilt_support::{
+	migration::SteppedMigration,
 	traits::{GenerateBenchmarkOrigin, GetWorstCase},
 	Deposit,
 };
@@ -291,6 +292,60 @@ benchmarks! {
 		);
 	}
 
+	set_subject_controller {
+		let controller: T::AttesterId = account("controller", 0, SEED);
+		let subject_id = <T as Config>::SubjectId::worst_case(());
+		let input_subject_id: InputSubjectIdOf<T> = subject_id.clone().into().try_into().expect("Input conversion should not fail.");
+
+		let origin = T::SubjectControllerOrigin::try_successful_origin().expect("Successful origin creation should not fail.");
+	}: _<T::RuntimeOrigin>(origin, input_subject_id, Some(controller.clone()))
+	verify {
+		assert_eq!(SubjectControllers::<T>::get(subject_id), Some(controller));
+	}
+
+	set_credential_visibility {
+		let deposit_owner: AccountIdOf<T> = account("caller", 0, SEED);
+		let attester: T::AttesterId = account("attester", 0, SEED);
+		let controller: T::AttesterId = account("controller", 0, SEED);
+		let ctype_hash: T::Hash = T::Hash::default();
+		let subject_id = <T as Config>::SubjectId::worst_case(());
+
+		reserve_balance::<T>(&deposit_owner);
+		let credential_entry = generate_base_credential_entry::<T>(
+			deposit_owner.clone(),
+			BlockNumberFor::<T>::zero(),
+			attester,
+			Some(ctype_hash),
+			None
+		);
+		let credential_id: CredentialIdOf<T> = Default::default();
+		insert_public_credentials::<T>(
+			subject_id.clone(),
+			credential_id.clone(),
+			credential_entry
+		);
+		SubjectControllers::<T>::insert(&subject_id, controller.clone());
+		let credential_id_clone = credential_id.clone();
+
+		let origin = <T as Config>::EnsureOrigin::generate_origin(deposit_owner, controller);
+	}: _<T::RuntimeOrigin>(origin, credential_id_clone, true)
+	verify {
+		assert!(HiddenCredentials::<T>::contains_key(&credential_id));
+	}
+
+	initialize_credential_subjects_counter_step {
+		let subject_id = <T as Config>::SubjectId::worst_case(());
+		let credential_id = CredentialIdOf::<T>::default();
+
+		CredentialSubjects::<T>::insert(&credential_id, subject_id);
+		frame_support::storage::unhashed::kill(&CredentialSubjects::<T>::counter_storage_final_key());
+	}: {
+		<migrations::InitializeCredentialSubjectsCounter<T> as SteppedMigration>::step(None);
+	}
+	verify {
+		assert_eq!(CredentialSubjects::<T>::count(), 1);
+	}
+
 	impl_benchmark_test_suite!(
 		Pallet,
 		crate::mock::ExtBuilder::default().build_with_keystore(),
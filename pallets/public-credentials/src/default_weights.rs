@@ -57,6 +57,9 @@ pub trait WeightInfo {
 	fn reclaim_deposit() -> Weight;
 	fn change_deposit_owner() -> Weight;
 	fn update_deposit() -> Weight;
+	fn set_subject_controller() -> Weight;
+	fn set_credential_visibility() -> Weight;
+	fn initialize_credential_subjects_counter_step() -> Weight;
 }
 
 /// Weights for public_credentials using the Substrate node and recommended hardware.
@@ -108,36 +111,6 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
-	/// Storage: PublicCredentials CredentialSubjects (r:1 w:1)
-	/// Proof: PublicCredentials CredentialSubjects (max_values: None, max_size: Some(312), added: 2787, mode: MaxEncodedLen)
-	/// Storage: PublicCredentials Credentials (r:1 w:1)
-	/// Proof: PublicCredentials Credentials (max_values: None, max_size: Some(475), added: 2950, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	fn remove() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1592`
-		//  Estimated: `8344`
-		// Minimum execution time: 27_101 nanoseconds.
-		Weight::from_parts(29_244_000, 8344)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
-	}
-	/// Storage: PublicCredentials CredentialSubjects (r:1 w:1)
-	/// Proof: PublicCredentials CredentialSubjects (max_values: None, max_size: Some(312), added: 2787, mode: MaxEncodedLen)
-	/// Storage: PublicCredentials Credentials (r:1 w:1)
-	/// Proof: PublicCredentials Credentials (max_values: None, max_size: Some(475), added: 2950, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	fn reclaim_deposit() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1592`
-		//  Estimated: `8344`
-		// Minimum execution time: 27_519 nanoseconds.
-		Weight::from_parts(28_728_000, 8344)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
-	}
 	/// Storage: PublicCredentials CredentialSubjects (r:1 w:0)
 	/// Proof: PublicCredentials CredentialSubjects (max_values: None, max_size: Some(312), added: 2787, mode: MaxEncodedLen)
 	/// Storage: PublicCredentials Credentials (r:1 w:1)
@@ -168,6 +141,60 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
+	// The weights below are not taken from the benchmark run above. They are
+	// estimated from the storage accesses of each call.
+	/// Storage: PublicCredentials CredentialSubjects (r:1 w:1)
+	/// Proof: PublicCredentials CredentialSubjects (max_values: None, max_size: Some(312), added: 2787, mode: MaxEncodedLen)
+	/// Storage: PublicCredentials Credentials (r:1 w:1)
+	/// Proof: PublicCredentials Credentials (max_values: None, max_size: Some(475), added: 2950, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PublicCredentials HiddenCredentials (r:0 w:1)
+	/// Proof: PublicCredentials HiddenCredentials (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	fn remove() -> Weight {
+		Weight::from_parts(29_244_000, 8344)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: PublicCredentials CredentialSubjects (r:1 w:1)
+	/// Proof: PublicCredentials CredentialSubjects (max_values: None, max_size: Some(312), added: 2787, mode: MaxEncodedLen)
+	/// Storage: PublicCredentials Credentials (r:1 w:1)
+	/// Proof: PublicCredentials Credentials (max_values: None, max_size: Some(475), added: 2950, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PublicCredentials HiddenCredentials (r:0 w:1)
+	/// Proof: PublicCredentials HiddenCredentials (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	fn reclaim_deposit() -> Weight {
+		Weight::from_parts(28_728_000, 8344)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: PublicCredentials SubjectControllers (r:0 w:1)
+	/// Proof: PublicCredentials SubjectControllers (max_values: None, max_size: Some(304), added: 2779, mode: MaxEncodedLen)
+	fn set_subject_controller() -> Weight {
+		Weight::from_parts(9_312_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: PublicCredentials CredentialSubjects (r:1 w:0)
+	/// Proof: PublicCredentials CredentialSubjects (max_values: None, max_size: Some(312), added: 2787, mode: MaxEncodedLen)
+	/// Storage: PublicCredentials SubjectControllers (r:1 w:0)
+	/// Proof: PublicCredentials SubjectControllers (max_values: None, max_size: Some(304), added: 2779, mode: MaxEncodedLen)
+	/// Storage: PublicCredentials HiddenCredentials (r:0 w:1)
+	/// Proof: PublicCredentials HiddenCredentials (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	fn set_credential_visibility() -> Weight {
+		Weight::from_parts(18_114_000, 6556)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: PublicCredentials CredentialSubjects (r:1 w:0)
+	/// Proof: PublicCredentials CredentialSubjects (max_values: None, max_size: Some(312), added: 2787, mode: MaxEncodedLen)
+	/// Storage: PublicCredentials CounterForCredentialSubjects (r:1 w:1)
+	/// Proof: PublicCredentials CounterForCredentialSubjects (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	fn initialize_credential_subjects_counter_step() -> Weight {
+		Weight::from_parts(10_379_000, 3777)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -218,36 +245,6 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
-	/// Storage: PublicCredentials CredentialSubjects (r:1 w:1)
-	/// Proof: PublicCredentials CredentialSubjects (max_values: None, max_size: Some(312), added: 2787, mode: MaxEncodedLen)
-	/// Storage: PublicCredentials Credentials (r:1 w:1)
-	/// Proof: PublicCredentials Credentials (max_values: None, max_size: Some(475), added: 2950, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	fn remove() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1592`
-		//  Estimated: `8344`
-		// Minimum execution time: 27_101 nanoseconds.
-		Weight::from_parts(29_244_000, 8344)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
-	}
-	/// Storage: PublicCredentials CredentialSubjects (r:1 w:1)
-	/// Proof: PublicCredentials CredentialSubjects (max_values: None, max_size: Some(312), added: 2787, mode: MaxEncodedLen)
-	/// Storage: PublicCredentials Credentials (r:1 w:1)
-	/// Proof: PublicCredentials Credentials (max_values: None, max_size: Some(475), added: 2950, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	fn reclaim_deposit() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1592`
-		//  Estimated: `8344`
-		// Minimum execution time: 27_519 nanoseconds.
-		Weight::from_parts(28_728_000, 8344)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
-	}
 	/// Storage: PublicCredentials CredentialSubjects (r:1 w:0)
 	/// Proof: PublicCredentials CredentialSubjects (max_values: None, max_size: Some(312), added: 2787, mode: MaxEncodedLen)
 	/// Storage: PublicCredentials Credentials (r:1 w:1)
@@ -278,4 +275,58 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
+	// The weights below are not taken from the benchmark run above. They are
+	// estimated from the storage accesses of each call.
+	/// Storage: PublicCredentials CredentialSubjects (r:1 w:1)
+	/// Proof: PublicCredentials CredentialSubjects (max_values: None, max_size: Some(312), added: 2787, mode: MaxEncodedLen)
+	/// Storage: PublicCredentials Credentials (r:1 w:1)
+	/// Proof: PublicCredentials Credentials (max_values: None, max_size: Some(475), added: 2950, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PublicCredentials HiddenCredentials (r:0 w:1)
+	/// Proof: PublicCredentials HiddenCredentials (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	fn remove() -> Weight {
+		Weight::from_parts(29_244_000, 8344)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: PublicCredentials CredentialSubjects (r:1 w:1)
+	/// Proof: PublicCredentials CredentialSubjects (max_values: None, max_size: Some(312), added: 2787, mode: MaxEncodedLen)
+	/// Storage: PublicCredentials Credentials (r:1 w:1)
+	/// Proof: PublicCredentials Credentials (max_values: None, max_size: Some(475), added: 2950, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: PublicCredentials HiddenCredentials (r:0 w:1)
+	/// Proof: PublicCredentials HiddenCredentials (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	fn reclaim_deposit() -> Weight {
+		Weight::from_parts(28_728_000, 8344)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: PublicCredentials SubjectControllers (r:0 w:1)
+	/// Proof: PublicCredentials SubjectControllers (max_values: None, max_size: Some(304), added: 2779, mode: MaxEncodedLen)
+	fn set_subject_controller() -> Weight {
+		Weight::from_parts(9_312_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: PublicCredentials CredentialSubjects (r:1 w:0)
+	/// Proof: PublicCredentials CredentialSubjects (max_values: None, max_size: Some(312), added: 2787, mode: MaxEncodedLen)
+	/// Storage: PublicCredentials SubjectControllers (r:1 w:0)
+	/// Proof: PublicCredentials SubjectControllers (max_values: None, max_size: Some(304), added: 2779, mode: MaxEncodedLen)
+	/// Storage: PublicCredentials HiddenCredentials (r:0 w:1)
+	/// Proof: PublicCredentials HiddenCredentials (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	fn set_credential_visibility() -> Weight {
+		Weight::from_parts(18_114_000, 6556)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: PublicCredentials CredentialSubjects (r:1 w:0)
+	/// Proof: PublicCredentials CredentialSubjects (max_values: None, max_size: Some(312), added: 2787, mode: MaxEncodedLen)
+	/// Storage: PublicCredentials CounterForCredentialSubjects (r:1 w:1)
+	/// Proof: PublicCredentials CounterForCredentialSubjects (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	fn initialize_credential_subjects_counter_step() -> Weight {
+		Weight::from_parts(10_379_000, 3777)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
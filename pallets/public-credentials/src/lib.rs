@@ -28,6 +28,10 @@
 //!
 //! - **Subject:**: The subject of a credential, i.e., the entity which the
 //!   claims in the credential refer to.
+//!
+//! - **Subject controller:**: The entity controlling a subject, e.g., the DID
+//!   of the owner of an asset. It can hide the credentials issued to the
+//!   subject from the runtime API, without removing them.
 #![cfg_attr(not(feature = "std"), no_std)]
 
 mod access_control;
@@ -78,7 +82,8 @@ pub mod pallet {
 	};
 
 	/// The current storage version.
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+	const STORAGE_VERSION: StorageVersion =
+		StorageVersion::new(crate::migrations::CREDENTIAL_SUBJECTS_COUNTER_STORAGE_VERSION);
 
 	// No easy way to check whether the two currencies are the same and check for
 	// `can_withdraw` conditions. Maybe with #[transactional] we could stop caring
@@ -140,6 +145,8 @@ pub mod pallet {
 		type AuthorizationId: Parameter + MaxEncodedLen;
 		/// The origin allowed to issue/revoke/remove public credentials.
 		type EnsureOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = <Self as Config>::OriginSuccess>;
+		/// The origin allowed to set the controller of a credential subject.
+		type SubjectControllerOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 		/// The ubiquitous event type.
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 		/// The hashing algorithm to derive a credential identifier from the
@@ -191,14 +198,31 @@ pub mod pallet {
 	/// A reverse index mapping from credential ID to the subject the credential
 	/// was issued to.
 	///
-	/// It it used to perform efficient lookup of credentials given their ID.
+	/// It it used to perform efficient lookup of credentials given their ID,
+	/// and to count the stored credentials.
 	#[pallet::storage]
 	#[pallet::getter(fn get_credential_subject)]
-	pub type CredentialSubjects<T> = StorageMap<_, Blake2_128Concat, CredentialIdOf<T>, SubjectIdOf<T>>;
+	pub type CredentialSubjects<T> = CountedStorageMap<_, Blake2_128Concat, CredentialIdOf<T>, SubjectIdOf<T>>;
+
+	/// The controller of a credential subject, e.g., the DID of the owner of
+	/// an asset.
+	///
+	/// The controller can hide and unhide the credentials issued to the
+	/// subject, without being able to change or remove them.
+	#[pallet::storage]
+	#[pallet::getter(fn get_subject_controller)]
+	pub type SubjectControllers<T> = StorageMap<_, Twox64Concat, SubjectIdOf<T>, AttesterOf<T>>;
+
+	/// The credentials that have been hidden by the controller of their
+	/// subject.
+	///
+	/// Hidden credentials are still stored on chain, but they are not returned
+	/// by the runtime API unless explicitly requested.
+	#[pallet::storage]
+	pub type HiddenCredentials<T> = StorageMap<_, Blake2_128Concat, CredentialIdOf<T>, ()>;
 
 	/// The events generated by this pallet.
 	#[pallet::event]
-	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
 		/// A new public credential has been issued.
 		CredentialStored {
@@ -224,6 +248,21 @@ pub mod pallet {
 			/// The id of the unrevoked credential.
 			credential_id: CredentialIdOf<T>,
 		},
+		/// The controller of a credential subject has been updated.
+		SubjectControllerSet {
+			/// The subject whose controller has been updated.
+			subject_id: T::SubjectId,
+			/// The new controller of the subject, if any.
+			controller: Option<AttesterOf<T>>,
+		},
+		/// The visibility of a public credential has been changed by the
+		/// controller of its subject.
+		CredentialVisibilityChanged {
+			/// The id of the credential.
+			credential_id: CredentialIdOf<T>,
+			/// Whether the credential is now hidden.
+			hidden: bool,
+		},
 	}
 
 	#[pallet::error]
@@ -253,6 +292,8 @@ pub mod pallet {
 		}
 	}
 
+	kilt_support::impl_identity_event_deposit!();
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Register a new public credential on chain.
@@ -309,14 +350,16 @@ pub mod pallet {
 				Error::<T>::AlreadyAttested
 			);
 
-			let deposit = PublicCredentialDepositCollector::<T>::create_deposit(payer, deposit_amount)
+			// The subject must be known to compute the storage key of the credential.
+			CredentialSubjects::<T>::insert(&credential_id, subject.clone());
+			let deposit =
+				PublicCredentialDepositCollector::<T>::create_deposit_for_key::<BalanceMigrationManagerOf<T>>(
+					&credential_id,
+					payer,
+					deposit_amount,
+				)
 				.map_err(|_| Error::<T>::UnableToPayFees)?;
 
-			<T as Config>::BalanceMigrationManager::exclude_key_from_migration(&Credentials::<T>::hashed_key_for(
-				&subject,
-				&credential_id,
-			));
-
 			let block_number = frame_system::Pallet::<T>::block_number();
 
 			Credentials::<T>::insert(
@@ -331,7 +374,6 @@ pub mod pallet {
 					authorization_id,
 				},
 			);
-			CredentialSubjects::<T>::insert(&credential_id, subject.clone());
 
 			Self::deposit_event(Event::CredentialStored {
 				subject_id: subject,
@@ -552,9 +594,82 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Set or clear the controller of a credential subject.
+		///
+		/// The controller is allowed to change the visibility of any
+		/// credential issued to the subject.
+		///
+		/// The dispatch origin must be `SubjectControllerOrigin`.
+		///
+		/// Emits `SubjectControllerSet`.
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_subject_controller())]
+		pub fn set_subject_controller(
+			origin: OriginFor<T>,
+			subject_id: InputSubjectIdOf<T>,
+			controller: Option<AttesterOf<T>>,
+		) -> DispatchResult {
+			T::SubjectControllerOrigin::ensure_origin(origin)?;
+
+			let subject = T::SubjectId::try_from(subject_id.into_inner()).map_err(|_| Error::<T>::InvalidInput)?;
+
+			SubjectControllers::<T>::set(&subject, controller.clone());
+
+			Self::deposit_event(Event::SubjectControllerSet {
+				subject_id: subject,
+				controller,
+			});
+
+			Ok(())
+		}
+
+		/// Hide or unhide a public credential.
+		///
+		/// Hidden credentials are not removed from the chain, but they are not
+		/// returned by the runtime API unless explicitly requested. Setting the
+		/// same visibility twice does not fail but simply results in a noop.
+		///
+		/// The subject of the dispatch origin must be the controller of the
+		/// credential subject.
+		///
+		/// Emits `CredentialVisibilityChanged`.
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_credential_visibility())]
+		pub fn set_credential_visibility(
+			origin: OriginFor<T>,
+			credential_id: CredentialIdOf<T>,
+			hidden: bool,
+		) -> DispatchResult {
+			let source = <T as Config>::EnsureOrigin::ensure_origin(origin)?;
+			let caller = source.subject();
+
+			let credential_subject = CredentialSubjects::<T>::get(&credential_id).ok_or(Error::<T>::NotFound)?;
+
+			ensure!(
+				SubjectControllers::<T>::get(&credential_subject) == Some(caller),
+				Error::<T>::NotAuthorized
+			);
+
+			if hidden {
+				HiddenCredentials::<T>::insert(&credential_id, ());
+			} else {
+				HiddenCredentials::<T>::remove(&credential_id);
+			}
+
+			Self::deposit_event(Event::CredentialVisibilityChanged { credential_id, hidden });
+
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
+		/// Check whether the specified credential has been hidden by the
+		/// controller of its subject.
+		pub fn is_credential_hidden(credential_id: &CredentialIdOf<T>) -> bool {
+			HiddenCredentials::<T>::contains_key(credential_id)
+		}
+
 		// Simple wrapper to remove entries from both storages when deleting a
 		// credential and generate a `CredentialRemoved` event.
 		fn remove_credential_entry(
@@ -562,20 +677,15 @@ pub mod pallet {
 			credential_id: CredentialIdOf<T>,
 			credential: CredentialEntryOf<T>,
 		) -> DispatchResult {
-			let details = Credentials::<T>::take(&credential_subject, &credential_id).ok_or(Error::<T>::NotFound)?;
+			Credentials::<T>::take(&credential_subject, &credential_id).ok_or(Error::<T>::NotFound)?;
+			// The deposit must be released before the credential subject is removed, since
+			// it is needed to compute the storage key of the credential.
+			PublicCredentialDepositCollector::<T>::release_deposit::<BalanceMigrationManagerOf<T>>(
+				&credential_id,
+				credential.deposit,
+			)?;
 			CredentialSubjects::<T>::remove(&credential_id);
-
-			let is_key_migrated = <T as Config>::BalanceMigrationManager::is_key_migrated(
-				&Credentials::<T>::hashed_key_for(&credential_subject, &credential_id),
-			);
-			if is_key_migrated {
-				PublicCredentialDepositCollector::<T>::free_deposit(credential.deposit)?;
-			} else {
-				<T as Config>::BalanceMigrationManager::release_reserved_deposit(
-					&details.deposit.owner,
-					&details.deposit.amount,
-				);
-			}
+			HiddenCredentials::<T>::remove(&credential_id);
 
 			Self::deposit_event(Event::CredentialRemoved {
 				subject_id: credential_subject,
@@ -16,11 +16,22 @@
 
 // If you feel like getting in touch with us, you can do so at info@botlabs.org
 
-use frame_support::traits::{fungible::Inspect, ReservableCurrency};
-use kilt_support::migration::switch_reserved_to_hold;
+use frame_support::{
+	traits::{fungible::Inspect, ReservableCurrency},
+	weights::Weight,
+};
+use kilt_support::migration::{switch_reserved_to_hold, SteppedMigration};
 use sp_runtime::DispatchResult;
+use sp_std::{marker::PhantomData, vec::Vec};
 
-use crate::{AccountIdOf, Config, CredentialIdOf, Credentials, CurrencyOf, Error, HoldReason, SubjectIdOf};
+use crate::{
+	AccountIdOf, Config, CredentialIdOf, CredentialSubjects, Credentials, CurrencyOf, Error, HoldReason, Pallet,
+	SubjectIdOf, WeightInfo,
+};
+
+/// The storage version which initialized the counter of the public
+/// credentials.
+pub(crate) const CREDENTIAL_SUBJECTS_COUNTER_STORAGE_VERSION: u16 = 2;
 
 pub fn update_balance_for_public_credentials<T: Config>(
 	key: &SubjectIdOf<T>,
@@ -38,22 +49,101 @@ where
 	)
 }
 
+/// Initializes the counter of the public credentials, which were stored before
+/// the map was counted, by counting one credential per step.
+///
+/// The calls of the pallet are blocked until the migration has completed, so
+/// that no credential is counted twice or missed.
+pub struct InitializeCredentialSubjectsCounter<T>(PhantomData<T>);
+
+impl<T: Config> SteppedMigration for InitializeCredentialSubjectsCounter<T> {
+	type Pallet = Pallet<T>;
+
+	const FROM: u16 = 1;
+	const TO: u16 = CREDENTIAL_SUBJECTS_COUNTER_STORAGE_VERSION;
+
+	fn max_step_weight() -> Weight {
+		<T as Config>::WeightInfo::initialize_credential_subjects_counter_step()
+	}
+
+	fn step(cursor: Option<Vec<u8>>) -> Option<Vec<u8>> {
+		let credential_id = match cursor {
+			Some(cursor) => CredentialSubjects::<T>::iter_keys_from(cursor).next(),
+			None => CredentialSubjects::<T>::iter_keys().next(),
+		}?;
+		frame_support::storage::unhashed::put(
+			&CredentialSubjects::<T>::counter_storage_final_key(),
+			&CredentialSubjects::<T>::count().saturating_add(1),
+		);
+		Some(CredentialSubjects::<T>::hashed_key_for(&credential_id))
+	}
+}
+
 #[cfg(test)]
 pub mod test {
 
 	use ctype::mock::get_ctype_hash;
 	use frame_support::{
 		assert_noop,
-		traits::{fungible::InspectHold, ReservableCurrency},
+		traits::{fungible::InspectHold, GetStorageVersion, ReservableCurrency},
 	};
+	use kilt_support::migration::SteppedMigration;
 	use sp_core::Get;
 	use sp_runtime::traits::Zero;
 
 	use crate::{
-		migrations::update_balance_for_public_credentials, mock::*, AccountIdOf, Config, CredentialIdOf, Credentials,
-		Error, HoldReason,
+		migrations::{update_balance_for_public_credentials, InitializeCredentialSubjectsCounter},
+		mock::*,
+		AccountIdOf, Config, CredentialIdOf, CredentialSubjects, Credentials, Error, HoldReason, Pallet,
 	};
 
+	#[test]
+	fn test_stepped_migration_bumps_one_version() {
+		assert_eq!(
+			<InitializeCredentialSubjectsCounter<Test> as SteppedMigration>::TO,
+			<InitializeCredentialSubjectsCounter<Test> as SteppedMigration>::FROM + 1
+		);
+		assert_eq!(
+			Pallet::<Test>::current_storage_version(),
+			<InitializeCredentialSubjectsCounter<Test> as SteppedMigration>::TO
+		);
+	}
+
+	#[test]
+	fn test_initialize_credential_subjects_counter() {
+		let attester = sr25519_did_from_seed(&ALICE_SEED);
+		let ctype_hash = get_ctype_hash::<Test>(true);
+		let credential =
+			generate_base_credential_entry::<Test>(ACCOUNT_00, 0, attester.clone(), Some(ctype_hash), None);
+		let deposit: Balance = <Test as Config>::Deposit::get();
+
+		ExtBuilder::default()
+			.with_balances(vec![(ACCOUNT_00, deposit * 2 + MIN_BALANCE)])
+			.with_public_credentials(vec![
+				(
+					SUBJECT_ID_00,
+					CredentialIdOf::<Test>::repeat_byte(1),
+					credential.clone(),
+				),
+				(SUBJECT_ID_01, CredentialIdOf::<Test>::repeat_byte(2), credential),
+			])
+			.with_ctypes(vec![(ctype_hash, attester)])
+			.build_and_execute_with_sanity_tests(|| {
+				// The credentials were stored before the map was counted.
+				frame_support::storage::unhashed::kill(&CredentialSubjects::<Test>::counter_storage_final_key());
+				assert_eq!(CredentialSubjects::<Test>::count(), 0);
+
+				let mut cursor = None;
+				for counted in 1..=2 {
+					cursor = InitializeCredentialSubjectsCounter::<Test>::step(cursor);
+					assert!(cursor.is_some());
+					assert_eq!(CredentialSubjects::<Test>::count(), counted);
+				}
+				assert!(InitializeCredentialSubjectsCounter::<Test>::step(cursor).is_none());
+				assert_eq!(CredentialSubjects::<Test>::count(), 2);
+			})
+	}
+
 	#[test]
 	fn test_setup() {
 		let attester = sr25519_did_from_seed(&ALICE_SEED);
@@ -189,7 +189,7 @@ pub(crate) mod runtime {
 		traits::{ConstU128, ConstU16, ConstU32, ConstU64},
 		weights::constants::RocksDbWeight,
 	};
-	use frame_system::EnsureSigned;
+	use frame_system::{EnsureRoot, EnsureSigned};
 	use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 	use scale_info::TypeInfo;
 	use sp_core::{sr25519, Pair};
@@ -344,6 +344,11 @@ pub(crate) mod runtime {
 		type Currency = Balances;
 		type Fee = ConstU128<500>;
 		type FeeCollector = ();
+		type RuntimeHoldReason = RuntimeHoldReason;
+		type MaxSchemaLength = ConstU32<1024>;
+		type SchemaBaseDeposit = ConstU128<1_000>;
+		type SchemaTierSize = ConstU32<256>;
+		type SchemaTierDeposit = ConstU128<1_000>;
 	}
 
 	impl Config for Test {
@@ -360,6 +365,7 @@ pub(crate) mod runtime {
 		type MaxEncodedClaimsLength = ConstU32<500>;
 		type MaxSubjectIdLength = ConstU32<100>;
 		type OriginSuccess = mock_origin::DoubleOrigin<AccountId, Self::AttesterId>;
+		type SubjectControllerOrigin = EnsureRoot<AccountId>;
 		type SubjectId = TestSubjectId;
 		type WeightInfo = ();
 		type BalanceMigrationManager = ();
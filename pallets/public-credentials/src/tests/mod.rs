@@ -20,3 +20,4 @@ mod claim;
 mod delete;
 mod deposit;
 mod revoke;
+mod visibility;
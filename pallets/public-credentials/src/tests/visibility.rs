@@ -0,0 +1,204 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use frame_support::{assert_noop, assert_ok, traits::Get};
+use frame_system::RawOrigin;
+use sp_runtime::DispatchError;
+
+use kilt_support::mock::mock_origin::DoubleOrigin;
+
+use crate::{mock::*, Config, CredentialIdOf, Error, HiddenCredentials, Pallet, SubjectControllers};
+
+#[test]
+fn set_subject_controller_successful() {
+	let controller = sr25519_did_from_seed(&BOB_SEED);
+	let subject_id: <Test as Config>::SubjectId = SUBJECT_ID_00;
+
+	ExtBuilder::default().build_and_execute_with_sanity_tests(|| {
+		assert_ok!(PublicCredentials::set_subject_controller(
+			RawOrigin::Root.into(),
+			subject_id.into(),
+			Some(controller.clone()),
+		));
+		assert_eq!(SubjectControllers::<Test>::get(subject_id), Some(controller));
+
+		// Clearing the controller removes the storage entry.
+		assert_ok!(PublicCredentials::set_subject_controller(
+			RawOrigin::Root.into(),
+			subject_id.into(),
+			None,
+		));
+		assert!(!SubjectControllers::<Test>::contains_key(subject_id));
+	});
+}
+
+#[test]
+fn set_subject_controller_bad_origin() {
+	let controller = sr25519_did_from_seed(&BOB_SEED);
+	let subject_id: <Test as Config>::SubjectId = SUBJECT_ID_00;
+
+	ExtBuilder::default().build_and_execute_with_sanity_tests(|| {
+		assert_noop!(
+			PublicCredentials::set_subject_controller(
+				DoubleOrigin(ACCOUNT_00, controller.clone()).into(),
+				subject_id.into(),
+				Some(controller),
+			),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn set_subject_controller_invalid_subject() {
+	let controller = sr25519_did_from_seed(&BOB_SEED);
+
+	ExtBuilder::default().build_and_execute_with_sanity_tests(|| {
+		assert_noop!(
+			PublicCredentials::set_subject_controller(
+				RawOrigin::Root.into(),
+				INVALID_SUBJECT_ID.into(),
+				Some(controller),
+			),
+			Error::<Test>::InvalidInput
+		);
+	});
+}
+
+#[test]
+fn set_credential_visibility_successful() {
+	let attester = sr25519_did_from_seed(&ALICE_SEED);
+	let controller = sr25519_did_from_seed(&BOB_SEED);
+	let subject_id: <Test as Config>::SubjectId = SUBJECT_ID_00;
+	let new_credential = generate_base_credential_entry::<Test>(ACCOUNT_00, 0, attester.clone(), None, None);
+	let credential_id: CredentialIdOf<Test> = CredentialIdOf::<Test>::default();
+	let deposit: Balance = <Test as Config>::Deposit::get();
+
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, deposit + MIN_BALANCE)])
+		.with_public_credentials(vec![(subject_id, credential_id, new_credential)])
+		.build_and_execute_with_sanity_tests(|| {
+			SubjectControllers::<Test>::insert(subject_id, controller.clone());
+
+			assert_ok!(PublicCredentials::set_credential_visibility(
+				DoubleOrigin(ACCOUNT_01, controller.clone()).into(),
+				credential_id,
+				true,
+			));
+			assert!(Pallet::<Test>::is_credential_hidden(&credential_id));
+
+			// Hiding the same credential twice does nothing
+			assert_ok!(PublicCredentials::set_credential_visibility(
+				DoubleOrigin(ACCOUNT_01, controller.clone()).into(),
+				credential_id,
+				true,
+			));
+			assert!(Pallet::<Test>::is_credential_hidden(&credential_id));
+
+			assert_ok!(PublicCredentials::set_credential_visibility(
+				DoubleOrigin(ACCOUNT_01, controller.clone()).into(),
+				credential_id,
+				false,
+			));
+			assert!(!Pallet::<Test>::is_credential_hidden(&credential_id));
+		});
+}
+
+#[test]
+fn set_credential_visibility_cleared_on_removal() {
+	let attester = sr25519_did_from_seed(&ALICE_SEED);
+	let controller = sr25519_did_from_seed(&BOB_SEED);
+	let subject_id: <Test as Config>::SubjectId = SUBJECT_ID_00;
+	let new_credential = generate_base_credential_entry::<Test>(ACCOUNT_00, 0, attester.clone(), None, None);
+	let credential_id: CredentialIdOf<Test> = CredentialIdOf::<Test>::default();
+	let deposit: Balance = <Test as Config>::Deposit::get();
+
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, deposit + MIN_BALANCE)])
+		.with_public_credentials(vec![(subject_id, credential_id, new_credential)])
+		.build_and_execute_with_sanity_tests(|| {
+			SubjectControllers::<Test>::insert(subject_id, controller.clone());
+
+			assert_ok!(PublicCredentials::set_credential_visibility(
+				DoubleOrigin(ACCOUNT_01, controller).into(),
+				credential_id,
+				true,
+			));
+			assert_ok!(PublicCredentials::remove(
+				DoubleOrigin(ACCOUNT_00, attester).into(),
+				credential_id,
+				None
+			));
+
+			assert!(!HiddenCredentials::<Test>::contains_key(credential_id));
+		});
+}
+
+#[test]
+fn set_credential_visibility_unauthorized() {
+	let attester = sr25519_did_from_seed(&ALICE_SEED);
+	let controller = sr25519_did_from_seed(&BOB_SEED);
+	let subject_id: <Test as Config>::SubjectId = SUBJECT_ID_00;
+	let new_credential = generate_base_credential_entry::<Test>(ACCOUNT_00, 0, attester.clone(), None, None);
+	let credential_id: CredentialIdOf<Test> = CredentialIdOf::<Test>::default();
+	let deposit: Balance = <Test as Config>::Deposit::get();
+
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, deposit + MIN_BALANCE)])
+		.with_public_credentials(vec![(subject_id, credential_id, new_credential)])
+		.build_and_execute_with_sanity_tests(|| {
+			// Fails if the subject has no controller
+			assert_noop!(
+				PublicCredentials::set_credential_visibility(
+					DoubleOrigin(ACCOUNT_01, controller.clone()).into(),
+					credential_id,
+					true,
+				),
+				Error::<Test>::NotAuthorized
+			);
+
+			SubjectControllers::<Test>::insert(subject_id, controller);
+
+			// The attester is not the controller of the subject
+			assert_noop!(
+				PublicCredentials::set_credential_visibility(
+					DoubleOrigin(ACCOUNT_00, attester).into(),
+					credential_id,
+					true,
+				),
+				Error::<Test>::NotAuthorized
+			);
+		});
+}
+
+#[test]
+fn set_credential_visibility_not_found() {
+	let controller = sr25519_did_from_seed(&BOB_SEED);
+	let credential_id: CredentialIdOf<Test> = CredentialIdOf::<Test>::default();
+
+	ExtBuilder::default().build_and_execute_with_sanity_tests(|| {
+		assert_noop!(
+			PublicCredentials::set_credential_visibility(
+				DoubleOrigin(ACCOUNT_00, controller).into(),
+				credential_id,
+				true,
+			),
+			Error::<Test>::NotFound
+		);
+	});
+}
@@ -16,7 +16,7 @@
 
 // If you feel like getting in touch with us, you can do so at info@botlabs.org
 
-use crate::{Config, CredentialSubjects, Credentials};
+use crate::{Config, CredentialSubjects, Credentials, HiddenCredentials};
 use frame_support::ensure;
 use kilt_support::test_utils::log_and_return_error_message;
 use scale_info::prelude::format;
@@ -46,5 +46,13 @@ pub(crate) fn do_try_state<T: Config>() -> Result<(), TryRuntimeError> {
 			log_and_return_error_message(format!("Unknown credential {:?}", credential_id))
 		);
 		Ok(())
+	})?;
+
+	HiddenCredentials::<T>::iter_keys().try_for_each(|credential_id| -> Result<(), TryRuntimeError> {
+		ensure!(
+			CredentialSubjects::<T>::contains_key(&credential_id),
+			log_and_return_error_message(format!("Hidden credential {:?} does not exist", credential_id))
+		);
+		Ok(())
 	})
 }
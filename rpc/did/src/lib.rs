@@ -0,0 +1,305 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+//! RPC methods to resolve a DID, together with its web3name, linked accounts
+//! and service endpoints, by the DID itself, by its web3name or by one of its
+//! linked accounts, and to subscribe to the changes of a DID document.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use jsonrpsee::{
+	core::{async_trait, RpcResult},
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject},
+};
+use parity_scale_codec::{Codec, MaxEncodedLen};
+use serde::{de::DeserializeOwned, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+
+use kilt_rpc_rate_limiter::RateLimiter;
+pub use kilt_runtime_api_did::Did as DidRuntimeApi;
+use kilt_runtime_api_did::{DidLinkedInfo, DidLookupKey, RawDidLinkedInfo, ServiceEndpoint};
+
+mod subscription;
+
+pub use subscription::{
+	DidDocumentChange, DidDocumentField, DidSubscription, DidSubscriptionApiClient, DidSubscriptionApiServer,
+};
+
+/// The DID linked information as returned by the RPC methods, with the
+/// web3name and service endpoint fields represented as strings.
+pub type RpcDidLinkedInfo<DidIdentifier, AccountId, LinkableAccountId, Balance, Key, BlockNumber> = DidLinkedInfo<
+	DidIdentifier,
+	AccountId,
+	LinkableAccountId,
+	String,
+	String,
+	String,
+	String,
+	Balance,
+	Key,
+	BlockNumber,
+>;
+
+#[rpc(client, server)]
+pub trait DidApi<BlockHash, DidIdentifier, AccountId, LinkableAccountId, Balance, Key: Ord, BlockNumber> {
+	/// Given a web3name this returns:
+	/// * the DID
+	/// * public keys stored for the did
+	/// * the web3name (optional)
+	/// * associated accounts
+	/// * service endpoints
+	#[method(name = "did_queryByWeb3Name")]
+	fn query_by_web3_name(
+		&self,
+		web3name: String,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<RpcDidLinkedInfo<DidIdentifier, AccountId, LinkableAccountId, Balance, Key, BlockNumber>>>;
+
+	/// Given an account this returns:
+	/// * the DID
+	/// * public keys stored for the did
+	/// * the web3name (optional)
+	/// * associated accounts
+	/// * service endpoints
+	#[method(name = "did_queryByAccount")]
+	fn query_by_account(
+		&self,
+		account: LinkableAccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<RpcDidLinkedInfo<DidIdentifier, AccountId, LinkableAccountId, Balance, Key, BlockNumber>>>;
+
+	/// Given a DID this returns:
+	/// * the DID
+	/// * public keys stored for the did
+	/// * the web3name (optional)
+	/// * associated accounts
+	/// * service endpoints
+	#[method(name = "did_query")]
+	fn query(
+		&self,
+		did: DidIdentifier,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<RpcDidLinkedInfo<DidIdentifier, AccountId, LinkableAccountId, Balance, Key, BlockNumber>>>;
+
+	/// Given a DID, a web3name or a linked account this returns:
+	/// * the DID
+	/// * public keys stored for the did
+	/// * the web3name (optional)
+	/// * associated accounts
+	/// * service endpoints
+	#[method(name = "did_queryLinkedInfo")]
+	fn query_linked_info(
+		&self,
+		key: DidLookupKey<DidIdentifier, String, LinkableAccountId>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<RpcDidLinkedInfo<DidIdentifier, AccountId, LinkableAccountId, Balance, Key, BlockNumber>>>;
+}
+
+/// Provides RPC methods to query DIDs and their linked information.
+pub struct DidQuery<C, B> {
+	client: Arc<C>,
+	rate_limiter: Option<Arc<RateLimiter>>,
+	_marker: PhantomData<B>,
+}
+
+impl<C, B> DidQuery<C, B> {
+	/// Creates a new instance of the DID RPC handler.
+	pub fn new(client: Arc<C>) -> Self {
+		Self {
+			client,
+			rate_limiter: None,
+			_marker: Default::default(),
+		}
+	}
+
+	/// Limit the rate of the served queries with the provided rate limiter.
+	pub fn with_rate_limiter(mut self, rate_limiter: Option<Arc<RateLimiter>>) -> Self {
+		self.rate_limiter = rate_limiter;
+		self
+	}
+
+	fn check_rate_limit(&self) -> RpcResult<()> {
+		match &self.rate_limiter {
+			Some(rate_limiter) => rate_limiter.check(Error::RateLimited.into()),
+			None => Ok(()),
+		}
+	}
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to the runtime failed.
+	RuntimeError,
+	/// The runtime returned data that cannot be represented in the RPC
+	/// response.
+	ConversionError,
+	/// The node is serving too many queries.
+	RateLimited,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+			Error::ConversionError => 2,
+			Error::RateLimited => 3,
+		}
+	}
+}
+
+fn runtime_error(message: &'static str, e: impl std::fmt::Debug) -> jsonrpsee::core::Error {
+	CallError::Custom(ErrorObject::owned(
+		Error::RuntimeError.into(),
+		message,
+		Some(format!("{:?}", e)),
+	))
+	.into()
+}
+
+fn raw_to_string(raw: Vec<u8>) -> Result<String, jsonrpsee::core::Error> {
+	String::from_utf8(raw).map_err(|e| {
+		CallError::Custom(ErrorObject::owned(
+			Error::ConversionError.into(),
+			"Unable to convert runtime bytes into a string.",
+			Some(e.to_string()),
+		))
+		.into()
+	})
+}
+
+fn raw_endpoint_to_rpc(
+	endpoint: ServiceEndpoint<Vec<u8>, Vec<u8>, Vec<u8>>,
+) -> Result<ServiceEndpoint<String, String, String>, jsonrpsee::core::Error> {
+	Ok(ServiceEndpoint {
+		id: raw_to_string(endpoint.id)?,
+		service_types: endpoint
+			.service_types
+			.into_iter()
+			.map(raw_to_string)
+			.collect::<Result<_, _>>()?,
+		urls: endpoint.urls.into_iter().map(raw_to_string).collect::<Result<_, _>>()?,
+	})
+}
+
+fn raw_linked_info_to_rpc<DidIdentifier, AccountId, LinkableAccountId, Balance, Key: Ord, BlockNumber>(
+	raw: Option<RawDidLinkedInfo<DidIdentifier, AccountId, LinkableAccountId, Balance, Key, BlockNumber>>,
+) -> RpcResult<Option<RpcDidLinkedInfo<DidIdentifier, AccountId, LinkableAccountId, Balance, Key, BlockNumber>>> {
+	let Some(raw) = raw else {
+		return Ok(None);
+	};
+
+	Ok(Some(DidLinkedInfo {
+		identifier: raw.identifier,
+		accounts: raw.accounts,
+		w3n: raw.w3n.map(raw_to_string).transpose()?,
+		service_endpoints: raw
+			.service_endpoints
+			.into_iter()
+			.map(raw_endpoint_to_rpc)
+			.collect::<Result<_, _>>()?,
+		details: raw.details,
+	}))
+}
+
+#[async_trait]
+impl<C, Block, DidIdentifier, AccountId, LinkableAccountId, Balance, Key, BlockNumber>
+	DidApiServer<<Block as BlockT>::Hash, DidIdentifier, AccountId, LinkableAccountId, Balance, Key, BlockNumber>
+	for DidQuery<C, Block>
+where
+	Block: BlockT,
+	C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	C::Api: DidRuntimeApi<Block, DidIdentifier, AccountId, LinkableAccountId, Balance, Key, BlockNumber>,
+	DidIdentifier: Codec + Send + Sync + 'static + Serialize + DeserializeOwned,
+	AccountId: Codec + Send + Sync + 'static + Serialize + DeserializeOwned,
+	LinkableAccountId: Codec + Send + Sync + 'static + Serialize + DeserializeOwned,
+	Balance: Codec + Send + Sync + 'static + Serialize + DeserializeOwned,
+	Key: Codec + Send + Sync + 'static + Serialize + DeserializeOwned + Ord,
+	BlockNumber: Codec + Send + Sync + 'static + Serialize + DeserializeOwned + MaxEncodedLen,
+{
+	fn query_by_web3_name(
+		&self,
+		web3name: String,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<RpcDidLinkedInfo<DidIdentifier, AccountId, LinkableAccountId, Balance, Key, BlockNumber>>> {
+		self.check_rate_limit()?;
+
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let raw = api
+			.query_by_web3_name(at, web3name.into_bytes())
+			.map_err(|e| runtime_error("Unable to query DID by web3name.", e))?;
+		raw_linked_info_to_rpc(raw)
+	}
+
+	fn query_by_account(
+		&self,
+		account: LinkableAccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<RpcDidLinkedInfo<DidIdentifier, AccountId, LinkableAccountId, Balance, Key, BlockNumber>>> {
+		self.check_rate_limit()?;
+
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let raw = api
+			.query_by_account(at, account)
+			.map_err(|e| runtime_error("Unable to query DID by account.", e))?;
+		raw_linked_info_to_rpc(raw)
+	}
+
+	fn query(
+		&self,
+		did: DidIdentifier,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<RpcDidLinkedInfo<DidIdentifier, AccountId, LinkableAccountId, Balance, Key, BlockNumber>>> {
+		self.check_rate_limit()?;
+
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let raw = api
+			.query(at, did)
+			.map_err(|e| runtime_error("Unable to query DID.", e))?;
+		raw_linked_info_to_rpc(raw)
+	}
+
+	fn query_linked_info(
+		&self,
+		key: DidLookupKey<DidIdentifier, String, LinkableAccountId>,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<RpcDidLinkedInfo<DidIdentifier, AccountId, LinkableAccountId, Balance, Key, BlockNumber>>> {
+		self.check_rate_limit()?;
+
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let key = match key {
+			DidLookupKey::Did(did) => DidLookupKey::Did(did),
+			DidLookupKey::Web3Name(web3name) => DidLookupKey::Web3Name(web3name.into_bytes()),
+			DidLookupKey::Account(account) => DidLookupKey::Account(account),
+		};
+		let raw = api
+			.linked_did_info(at, key)
+			.map_err(|e| runtime_error("Unable to query DID linked information.", e))?;
+		raw_linked_info_to_rpc(raw)
+	}
+}
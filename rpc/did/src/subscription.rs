@@ -0,0 +1,249 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+//! A subscription to the changes of a DID document, so that wallets can keep
+//! the resolved document up to date without polling.
+//!
+//! The subscription watches the storage of the DID, web3name and DID lookup
+//! pallets. Whenever a block changes a storage entry keyed by the subscribed
+//! DID, the document is resolved again at that block and, if any of its
+//! fields differ from the previously resolved document, the new document is
+//! pushed to the subscriber together with the list of changed fields.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use futures::{future::ready, FutureExt, StreamExt};
+use jsonrpsee::{proc_macros::rpc, types::SubscriptionResult, SubscriptionSink};
+use parity_scale_codec::{Codec, Encode, MaxEncodedLen};
+use sc_client_api::BlockchainEvents;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::{traits::SpawnNamed, twox_128};
+use sp_runtime::traits::Block as BlockT;
+
+use kilt_runtime_api_did::RawDidLinkedInfo;
+
+use crate::{raw_linked_info_to_rpc, runtime_error, DidRuntimeApi, RpcDidLinkedInfo};
+
+/// The pallets whose storage entries make up a DID document.
+const DID_PALLETS: [&str; 3] = ["Did", "Web3Names", "DidLookup"];
+
+/// A field of a resolved DID document.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DidDocumentField {
+	/// The keys, deposit and other details of the DID.
+	Details,
+	/// The web3name of the DID.
+	Web3Name,
+	/// The accounts linked to the DID.
+	Accounts,
+	/// The service endpoints of the DID.
+	ServiceEndpoints,
+}
+
+/// A change to a DID document, as pushed to the subscribers.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DidDocumentChange<BlockHash, Document> {
+	/// The block in which the document changed.
+	pub block: BlockHash,
+	/// The fields of the document that changed in the block.
+	pub changed: Vec<DidDocumentField>,
+	/// The document after the change, or `None` if the DID has been deleted.
+	pub document: Option<Document>,
+}
+
+#[rpc(client, server)]
+pub trait DidSubscriptionApi<BlockHash, DidIdentifier, AccountId, LinkableAccountId, Balance, Key: Ord, BlockNumber> {
+	/// Subscribe to the changes of the document of the given DID. Each
+	/// notification contains the fields that changed and the resolved
+	/// document after the change.
+	#[subscription(
+		name = "did_subscribeDocumentChanges" => "did_documentChanged",
+		unsubscribe = "did_unsubscribeDocumentChanges",
+		item = DidDocumentChange<BlockHash, RpcDidLinkedInfo<DidIdentifier, AccountId, LinkableAccountId, Balance, Key, BlockNumber>>
+	)]
+	fn subscribe_document_changes(&self, did: DidIdentifier);
+}
+
+/// Provides subscriptions to the changes of DID documents.
+pub struct DidSubscription<C, B> {
+	client: Arc<C>,
+	executor: Arc<dyn SpawnNamed>,
+	_marker: PhantomData<B>,
+}
+
+impl<C, B> DidSubscription<C, B> {
+	/// Creates a new instance of the DID subscription RPC handler, which
+	/// runs the subscriptions on the given executor.
+	pub fn new(client: Arc<C>, executor: Arc<dyn SpawnNamed>) -> Self {
+		Self {
+			client,
+			executor,
+			_marker: Default::default(),
+		}
+	}
+}
+
+/// Whether the given storage key belongs to one of the DID pallets and is
+/// keyed by the DID with the given encoding.
+fn is_did_key(key: &[u8], encoded_did: &[u8]) -> bool {
+	// Skip the pallet and storage item prefixes.
+	let Some(suffix) = key.get(32..) else {
+		return false;
+	};
+	DID_PALLETS
+		.iter()
+		.any(|pallet| key.starts_with(&twox_128(pallet.as_bytes())))
+		&& suffix.windows(encoded_did.len()).any(|window| window == encoded_did)
+}
+
+/// The fields of a resolved document, in the order of `DOCUMENT_FIELDS`.
+const DOCUMENT_FIELDS: [DidDocumentField; 4] = [
+	DidDocumentField::Details,
+	DidDocumentField::Web3Name,
+	DidDocumentField::Accounts,
+	DidDocumentField::ServiceEndpoints,
+];
+
+/// The SCALE encoding of each field of the resolved document, in the order of
+/// `DOCUMENT_FIELDS`, or `None` if the DID does not exist.
+type EncodedDocument = Option<[Vec<u8>; 4]>;
+
+fn encode_document<DidIdentifier, AccountId, LinkableAccountId, Balance, Key, BlockNumber>(
+	document: &Option<RawDidLinkedInfo<DidIdentifier, AccountId, LinkableAccountId, Balance, Key, BlockNumber>>,
+) -> EncodedDocument
+where
+	AccountId: Encode,
+	LinkableAccountId: Encode,
+	Balance: Encode,
+	Key: Encode + Ord,
+	BlockNumber: Encode + MaxEncodedLen,
+{
+	document.as_ref().map(|document| {
+		[
+			document.details.encode(),
+			document.w3n.encode(),
+			document.accounts.encode(),
+			document.service_endpoints.encode(),
+		]
+	})
+}
+
+/// The fields that differ between two resolutions of the same document.
+fn changed_fields(old: &EncodedDocument, new: &EncodedDocument) -> Vec<DidDocumentField> {
+	match (old, new) {
+		(Some(old), Some(new)) => DOCUMENT_FIELDS
+			.into_iter()
+			.zip(old.iter().zip(new.iter()))
+			.filter(|(_, (old, new))| old != new)
+			.map(|(field, _)| field)
+			.collect(),
+		// The DID has been created or deleted.
+		(Some(_), None) | (None, Some(_)) => DOCUMENT_FIELDS.to_vec(),
+		(None, None) => Vec::new(),
+	}
+}
+
+impl<C, Block, DidIdentifier, AccountId, LinkableAccountId, Balance, Key, BlockNumber>
+	DidSubscriptionApiServer<
+		<Block as BlockT>::Hash,
+		DidIdentifier,
+		AccountId,
+		LinkableAccountId,
+		Balance,
+		Key,
+		BlockNumber,
+	> for DidSubscription<C, Block>
+where
+	Block: BlockT,
+	C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block> + BlockchainEvents<Block>,
+	C::Api: DidRuntimeApi<Block, DidIdentifier, AccountId, LinkableAccountId, Balance, Key, BlockNumber>,
+	DidIdentifier: Codec + Clone + Send + Sync + 'static + Serialize + DeserializeOwned,
+	AccountId: Codec + Send + Sync + 'static + Serialize + DeserializeOwned,
+	LinkableAccountId: Codec + Send + Sync + 'static + Serialize + DeserializeOwned,
+	Balance: Codec + Send + Sync + 'static + Serialize + DeserializeOwned,
+	Key: Codec + Send + Sync + 'static + Serialize + DeserializeOwned + Ord,
+	BlockNumber: Codec + Send + Sync + 'static + Serialize + DeserializeOwned + MaxEncodedLen,
+{
+	fn subscribe_document_changes(&self, mut sink: SubscriptionSink, did: DidIdentifier) -> SubscriptionResult {
+		let client = self.client.clone();
+		let resolve = move |at: <Block as BlockT>::Hash, did: DidIdentifier| {
+			client
+				.runtime_api()
+				.query(at, did)
+				.map_err(|e| runtime_error("Unable to query DID.", e))
+		};
+
+		let mut previous = match resolve(self.client.info().best_hash, did.clone()) {
+			Ok(document) => encode_document(&document),
+			Err(e) => {
+				let _ = sink.reject(e);
+				return Ok(());
+			}
+		};
+		let storage_changes = match self.client.storage_changes_notification_stream(None, None) {
+			Ok(stream) => stream,
+			Err(e) => {
+				let _ = sink.reject(runtime_error("Unable to subscribe to storage changes.", e));
+				return Ok(());
+			}
+		};
+
+		let encoded_did = did.encode();
+		let stream = storage_changes
+			.filter_map(move |notification| {
+				let touches_did = notification
+					.changes
+					.iter()
+					.any(|(child_key, key, _)| child_key.is_none() && is_did_key(&key.0, &encoded_did));
+				if !touches_did {
+					return ready(None);
+				}
+				// Skip the block if the document cannot be resolved, e.g., because
+				// its state has already been pruned.
+				let Ok(document) = resolve(notification.block, did.clone()) else {
+					return ready(None);
+				};
+				let encoded_document = encode_document(&document);
+				let changed = changed_fields(&previous, &encoded_document);
+				if changed.is_empty() {
+					return ready(None);
+				}
+				previous = encoded_document;
+				ready(raw_linked_info_to_rpc(document).ok().map(|document| DidDocumentChange {
+					block: notification.block,
+					changed,
+					document,
+				}))
+			})
+			.boxed();
+
+		self.executor.spawn(
+			"kilt-did-document-subscription",
+			Some("rpc"),
+			async move {
+				sink.pipe_from_stream(stream).await;
+			}
+			.boxed(),
+		);
+		Ok(())
+	}
+}
@@ -0,0 +1,283 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+//! RPC method to fetch, in a single call, all the proofs a consumer chain
+//! transaction needs for a DIP origin: the relay chain state proof of the
+//! provider parachain head, the provider state proof of the identity
+//! commitment and the DID Merkle proof.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use cumulus_relay_chain_interface::{PHash, ParaId, RelayChainInterface};
+use jsonrpsee::{
+	core::{async_trait, RpcResult},
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject},
+};
+use parity_scale_codec::{Codec, Compact, Decode, Encode};
+use sc_client_api::ProofProvider;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::{
+	hashing::{twox_128, twox_64},
+	Bytes,
+};
+use sp_runtime::traits::{Block as BlockT, Header as HeaderT};
+
+use kilt_rpc_rate_limiter::RateLimiter;
+use kilt_runtime_api_dip_provider::{DipProofRequest, EncodedDipProofParts};
+
+use crate::{DipProviderRuntimeApi, Error};
+
+/// The version of the format of the proof bundles returned by
+/// `dip_proofBundle`.
+pub const DIP_PROOF_BUNDLE_VERSION: u8 = 0;
+
+/// All the proofs needed for a DIP origin on a consumer parachain, except for
+/// the DID signature, with each part SCALE-encoded as expected by the
+/// consumer's verifier.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct DipProofBundleResponse<ProviderBlockHash> {
+	/// The version of the bundle format.
+	pub version: u8,
+	/// The hash of the provider block whose header is stored in the relay
+	/// block the bundle was requested for.
+	pub provider_block_hash: ProviderBlockHash,
+	/// The relay block number, followed by the state proof of the provider
+	/// head in the relay block.
+	pub provider_head_proof: Bytes,
+	/// The state proof of the identity commitment in the provider block.
+	pub dip_commitment_proof: Bytes,
+	/// The Merkle proof of the revealed DID details.
+	pub dip_proof: Bytes,
+	/// The concatenation of the three proofs above.
+	pub encoded: Bytes,
+}
+
+/// Computes the storage key of the identity commitment of a subject in the
+/// provider runtime.
+pub trait IdentityCommitmentStorageKey<Identifier, Version> {
+	fn identity_commitment_storage_key(identifier: &Identifier, version: &Version) -> Vec<u8>;
+}
+
+#[rpc(client, server)]
+pub trait DipProofBundleApi<BlockHash, Identifier, Version, KeyId, AccountId> {
+	/// Generate all the proofs needed to verify a DIP origin for the given
+	/// subject on a consumer chain, anchored to the provider head stored in
+	/// the given relay block. The revealed details are specified as for
+	/// `dip_generateProof`.
+	#[method(name = "dip_proofBundle")]
+	async fn proof_bundle(
+		&self,
+		subject: Identifier,
+		consumer_relay_block: PHash,
+		version: Version,
+		keys: Vec<KeyId>,
+		accounts: Vec<AccountId>,
+		include_web3_name: bool,
+		include_also_known_as: Option<bool>,
+		include_web3_name_records: Option<bool>,
+	) -> RpcResult<DipProofBundleResponse<BlockHash>>;
+}
+
+/// Provides the RPC method to generate DIP proof bundles.
+pub struct DipProofBundle<C, B, K> {
+	client: Arc<C>,
+	relay_chain_interface: Arc<dyn RelayChainInterface>,
+	para_id: ParaId,
+	rate_limiter: Option<Arc<RateLimiter>>,
+	_marker: PhantomData<(B, K)>,
+}
+
+impl<C, B, K> DipProofBundle<C, B, K> {
+	/// Creates a new instance of the DIP proof bundle RPC handler, for the
+	/// provider parachain with the given ID.
+	pub fn new(client: Arc<C>, relay_chain_interface: Arc<dyn RelayChainInterface>, para_id: ParaId) -> Self {
+		Self {
+			client,
+			relay_chain_interface,
+			para_id,
+			rate_limiter: None,
+			_marker: Default::default(),
+		}
+	}
+
+	/// Limit the rate of the generated bundles with the provided rate
+	/// limiter.
+	pub fn with_rate_limiter(mut self, rate_limiter: Option<Arc<RateLimiter>>) -> Self {
+		self.rate_limiter = rate_limiter;
+		self
+	}
+}
+
+fn relay_chain_error(message: &str, e: impl std::fmt::Debug) -> CallError {
+	CallError::Custom(ErrorObject::owned(
+		Error::RelayChainError.into(),
+		message.to_owned(),
+		Some(format!("{:?}", e)),
+	))
+}
+
+#[async_trait]
+impl<C, Block, K, Identifier, Version, KeyId, AccountId, Success, ProofError>
+	DipProofBundleApiServer<<Block as BlockT>::Hash, Identifier, Version, KeyId, AccountId> for DipProofBundle<C, Block, K>
+where
+	Block: BlockT,
+	C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block> + ProofProvider<Block>,
+	C::Api: DipProviderRuntimeApi<Block, DipProofRequest<Identifier, Version, KeyId, AccountId>, Success, ProofError>,
+	K: IdentityCommitmentStorageKey<Identifier, Version> + Send + Sync + 'static,
+	Identifier: Codec + Send + Sync + 'static + DeserializeOwned,
+	Version: Codec + Send + Sync + 'static + DeserializeOwned,
+	KeyId: Codec + Send + Sync + 'static + DeserializeOwned,
+	AccountId: Codec + Send + Sync + 'static + DeserializeOwned,
+	Success: Codec + EncodedDipProofParts,
+	ProofError: Codec,
+{
+	async fn proof_bundle(
+		&self,
+		subject: Identifier,
+		consumer_relay_block: PHash,
+		version: Version,
+		keys: Vec<KeyId>,
+		accounts: Vec<AccountId>,
+		include_web3_name: bool,
+		include_also_known_as: Option<bool>,
+		include_web3_name_records: Option<bool>,
+	) -> RpcResult<DipProofBundleResponse<<Block as BlockT>::Hash>> {
+		if let Some(rate_limiter) = &self.rate_limiter {
+			rate_limiter.check(Error::RateLimited.into())?;
+		}
+
+		let relay_block_number_key = [twox_128(b"System"), twox_128(b"Number")].concat();
+		let relay_block_number = self
+			.relay_chain_interface
+			.get_storage_by_key(consumer_relay_block, &relay_block_number_key)
+			.await
+			.map_err(|e| relay_chain_error("Unable to read the relay block number.", e))?
+			.and_then(|encoded_number| u32::decode(&mut encoded_number.as_slice()).ok())
+			.ok_or_else(|| relay_chain_error("Relay block not found.", consumer_relay_block))?;
+
+		let encoded_para_id = self.para_id.encode();
+		let provider_head_key = [
+			twox_128(b"Paras").as_slice(),
+			twox_128(b"Heads").as_slice(),
+			twox_64(&encoded_para_id).as_slice(),
+			encoded_para_id.as_slice(),
+		]
+		.concat();
+		let provider_header = self
+			.relay_chain_interface
+			.get_storage_by_key(consumer_relay_block, &provider_head_key)
+			.await
+			.map_err(|e| relay_chain_error("Unable to read the provider head.", e))?
+			.and_then(|encoded_head_data| Vec::<u8>::decode(&mut encoded_head_data.as_slice()).ok())
+			.and_then(|head_data| <Block as BlockT>::Header::decode(&mut head_data.as_slice()).ok())
+			.ok_or_else(|| {
+				CallError::Custom(ErrorObject::owned(
+					Error::ProviderHeadNotFound.into(),
+					"No provider head found in the relay block.",
+					None::<()>,
+				))
+			})?;
+		let provider_head_proof = self
+			.relay_chain_interface
+			.prove_read(consumer_relay_block, &vec![provider_head_key])
+			.await
+			.map_err(|e| relay_chain_error("Unable to prove the provider head.", e))?
+			.into_iter_nodes()
+			.collect::<Vec<_>>();
+
+		let provider_block_hash = provider_header.hash();
+		self.client.header(provider_block_hash).ok().flatten().ok_or_else(|| {
+			CallError::Custom(ErrorObject::owned(
+				Error::ProviderBlockNotFound.into(),
+				"The provider block is not available on this node.",
+				Some(format!("{:?}", provider_block_hash)),
+			))
+		})?;
+
+		let commitment_key = K::identity_commitment_storage_key(&subject, &version);
+		let dip_commitment_proof = self
+			.client
+			.read_proof(provider_block_hash, &mut [commitment_key.as_slice()].into_iter())
+			.map_err(|e| {
+				CallError::Custom(ErrorObject::owned(
+					Error::RuntimeError.into(),
+					"Unable to prove the identity commitment.",
+					Some(format!("{:?}", e)),
+				))
+			})?
+			.into_iter_nodes()
+			.collect::<Vec<_>>();
+
+		let request = DipProofRequest {
+			identifier: subject,
+			version,
+			keys,
+			accounts,
+			should_include_web3_name: include_web3_name,
+			should_include_also_known_as: include_also_known_as.unwrap_or_default(),
+			should_include_web3_name_records: include_web3_name_records.unwrap_or_default(),
+		};
+		let proof = self
+			.client
+			.runtime_api()
+			.generate_proof(provider_block_hash, request)
+			.map_err(|e| {
+				CallError::Custom(ErrorObject::owned(
+					Error::RuntimeError.into(),
+					"Unable to generate DIP proof.",
+					Some(format!("{:?}", e)),
+				))
+			})?
+			.map_err(|e| {
+				CallError::Custom(ErrorObject::owned(
+					Error::ProofGenerationError.into(),
+					"DIP proof generation failed.",
+					Some(Bytes(e.encode())),
+				))
+			})?;
+
+		let encoded_provider_head_proof = (relay_block_number, provider_head_proof).encode();
+		let encoded_dip_commitment_proof = dip_commitment_proof.encode();
+		// The DID Merkle proof is encoded as its blinded nodes followed by its
+		// revealed leaves, which are returned already encoded by the runtime.
+		let revealed = proof.encoded_revealed();
+		let mut encoded_dip_proof = proof.encoded_blinded().encode();
+		Compact::<u32>(revealed.len() as u32).encode_to(&mut encoded_dip_proof);
+		revealed.into_iter().for_each(|leaf| encoded_dip_proof.extend(leaf));
+
+		let encoded = [
+			encoded_provider_head_proof.as_slice(),
+			encoded_dip_commitment_proof.as_slice(),
+			encoded_dip_proof.as_slice(),
+		]
+		.concat();
+
+		Ok(DipProofBundleResponse {
+			version: DIP_PROOF_BUNDLE_VERSION,
+			provider_block_hash,
+			provider_head_proof: encoded_provider_head_proof.into(),
+			dip_commitment_proof: encoded_dip_commitment_proof.into(),
+			dip_proof: encoded_dip_proof.into(),
+			encoded: encoded.into(),
+		})
+	}
+}
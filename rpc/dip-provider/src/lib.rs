@@ -0,0 +1,206 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+//! RPC methods to generate DIP proofs for a given subject from the state of
+//! the provider chain, so that consumer chain clients can fetch them from any
+//! provider node.
+
+mod bundle;
+
+pub use bundle::*;
+
+use std::{marker::PhantomData, sync::Arc};
+
+use jsonrpsee::{
+	core::{async_trait, RpcResult},
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject},
+};
+use parity_scale_codec::{Codec, Encode};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::Bytes;
+use sp_runtime::traits::Block as BlockT;
+use substrate_prometheus_endpoint::{Counter, U64};
+
+use kilt_rpc_rate_limiter::RateLimiter;
+pub use kilt_runtime_api_dip_provider::DipProvider as DipProviderRuntimeApi;
+use kilt_runtime_api_dip_provider::{DipProofRequest, EncodedDipProofParts};
+
+/// A DIP proof as returned by the RPC methods, with each part hex-encoded.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct DipProofResponse {
+	/// The SCALE-encoded identity commitment the proof verifies against.
+	pub root: Bytes,
+	/// The blinded trie nodes of the proof.
+	pub blinded: Vec<Bytes>,
+	/// The SCALE-encoded leaves revealed by the proof.
+	pub revealed: Vec<Bytes>,
+}
+
+#[rpc(client, server)]
+pub trait DipProviderApi<BlockHash, Identifier, Version, KeyId, AccountId> {
+	/// Generate a DIP proof for the given subject, revealing the specified
+	/// keys, linked accounts and, optionally, the web3name, the `alsoKnownAs`
+	/// URIs and the web3name records.
+	#[method(name = "dip_generateProof")]
+	fn generate_proof(
+		&self,
+		subject: Identifier,
+		version: Version,
+		keys: Vec<KeyId>,
+		accounts: Vec<AccountId>,
+		include_web3_name: bool,
+		at: Option<BlockHash>,
+		include_also_known_as: Option<bool>,
+		include_web3_name_records: Option<bool>,
+	) -> RpcResult<DipProofResponse>;
+}
+
+/// Provides RPC methods to generate DIP proofs.
+pub struct DipProof<C, B> {
+	client: Arc<C>,
+	proofs_generated: Option<Counter<U64>>,
+	rate_limiter: Option<Arc<RateLimiter>>,
+	_marker: PhantomData<B>,
+}
+
+impl<C, B> DipProof<C, B> {
+	/// Creates a new instance of the DIP proof RPC handler.
+	pub fn new(client: Arc<C>) -> Self {
+		Self {
+			client,
+			proofs_generated: None,
+			rate_limiter: None,
+			_marker: Default::default(),
+		}
+	}
+
+	/// Count the successfully generated proofs with the provided counter.
+	pub fn with_proofs_counter(mut self, proofs_generated: Option<Counter<U64>>) -> Self {
+		self.proofs_generated = proofs_generated;
+		self
+	}
+
+	/// Limit the rate of the generated proofs with the provided rate limiter.
+	pub fn with_rate_limiter(mut self, rate_limiter: Option<Arc<RateLimiter>>) -> Self {
+		self.rate_limiter = rate_limiter;
+		self
+	}
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to the runtime failed.
+	RuntimeError,
+	/// The runtime could not generate the requested proof.
+	ProofGenerationError,
+	/// The node is generating too many proofs.
+	RateLimited,
+	/// The call to the relay chain failed.
+	RelayChainError,
+	/// The relay block does not contain a head for the provider parachain.
+	ProviderHeadNotFound,
+	/// The provider block referenced by the relay block is not available.
+	ProviderBlockNotFound,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+			Error::ProofGenerationError => 2,
+			Error::RateLimited => 3,
+			Error::RelayChainError => 4,
+			Error::ProviderHeadNotFound => 5,
+			Error::ProviderBlockNotFound => 6,
+		}
+	}
+}
+
+#[async_trait]
+impl<C, Block, Identifier, Version, KeyId, AccountId, Success, ProofError>
+	DipProviderApiServer<<Block as BlockT>::Hash, Identifier, Version, KeyId, AccountId> for DipProof<C, Block>
+where
+	Block: BlockT,
+	C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	C::Api: DipProviderRuntimeApi<Block, DipProofRequest<Identifier, Version, KeyId, AccountId>, Success, ProofError>,
+	Identifier: Codec + Send + Sync + 'static + DeserializeOwned,
+	Version: Codec + Send + Sync + 'static + DeserializeOwned,
+	KeyId: Codec + Send + Sync + 'static + DeserializeOwned,
+	AccountId: Codec + Send + Sync + 'static + DeserializeOwned,
+	Success: Codec + EncodedDipProofParts,
+	ProofError: Codec,
+{
+	fn generate_proof(
+		&self,
+		subject: Identifier,
+		version: Version,
+		keys: Vec<KeyId>,
+		accounts: Vec<AccountId>,
+		include_web3_name: bool,
+		at: Option<<Block as BlockT>::Hash>,
+		include_also_known_as: Option<bool>,
+		include_web3_name_records: Option<bool>,
+	) -> RpcResult<DipProofResponse> {
+		if let Some(rate_limiter) = &self.rate_limiter {
+			rate_limiter.check(Error::RateLimited.into())?;
+		}
+
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let request = DipProofRequest {
+			identifier: subject,
+			version,
+			keys,
+			accounts,
+			should_include_web3_name: include_web3_name,
+			should_include_also_known_as: include_also_known_as.unwrap_or_default(),
+			should_include_web3_name_records: include_web3_name_records.unwrap_or_default(),
+		};
+		let proof = api
+			.generate_proof(at, request)
+			.map_err(|e| {
+				CallError::Custom(ErrorObject::owned(
+					Error::RuntimeError.into(),
+					"Unable to generate DIP proof.",
+					Some(format!("{:?}", e)),
+				))
+			})?
+			.map_err(|e| {
+				CallError::Custom(ErrorObject::owned(
+					Error::ProofGenerationError.into(),
+					"DIP proof generation failed.",
+					Some(Bytes(e.encode())),
+				))
+			})?;
+
+		if let Some(proofs_generated) = &self.proofs_generated {
+			proofs_generated.inc();
+		}
+
+		Ok(DipProofResponse {
+			root: proof.encoded_root().into(),
+			blinded: proof.encoded_blinded().into_iter().map(Into::into).collect(),
+			revealed: proof.encoded_revealed().into_iter().map(Into::into).collect(),
+		})
+	}
+}
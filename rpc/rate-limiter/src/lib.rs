@@ -0,0 +1,118 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+//! Rate limiting of RPC methods, to protect public nodes from crawlers
+//! issuing expensive identity resolution or proof generation calls.
+//!
+//! The limit is shared by all connections of the node.
+
+use std::{
+	num::NonZeroU32,
+	sync::{Mutex, PoisonError},
+	time::Instant,
+};
+
+use jsonrpsee::{
+	core::Error as JsonRpseeError,
+	types::error::{CallError, ErrorObject},
+};
+
+/// A token bucket that refills at the configured rate and allows bursts of up
+/// to one second worth of calls.
+pub struct RateLimiter {
+	calls_per_second: NonZeroU32,
+	bucket: Mutex<Bucket>,
+}
+
+struct Bucket {
+	available: f64,
+	last_refill: Instant,
+}
+
+impl RateLimiter {
+	/// Creates a new rate limiter allowing the given number of calls per
+	/// second.
+	pub fn new(calls_per_second: NonZeroU32) -> Self {
+		Self {
+			calls_per_second,
+			bucket: Mutex::new(Bucket {
+				available: f64::from(calls_per_second.get()),
+				last_refill: Instant::now(),
+			}),
+		}
+	}
+
+	/// Consumes one call from the budget, returning whether the call is
+	/// allowed.
+	pub fn try_acquire(&self) -> bool {
+		let mut bucket = self.bucket.lock().unwrap_or_else(PoisonError::into_inner);
+		let capacity = f64::from(self.calls_per_second.get());
+		let now = Instant::now();
+
+		let elapsed = now.duration_since(bucket.last_refill).as_secs_f64();
+		bucket.available = (bucket.available + elapsed * capacity).min(capacity);
+		bucket.last_refill = now;
+
+		if bucket.available >= 1.0 {
+			bucket.available -= 1.0;
+			true
+		} else {
+			false
+		}
+	}
+
+	/// Consumes one call from the budget, returning an RPC error with the
+	/// given code if the call is not allowed.
+	pub fn check(&self, error_code: i32) -> Result<(), JsonRpseeError> {
+		if self.try_acquire() {
+			Ok(())
+		} else {
+			Err(CallError::Custom(ErrorObject::owned(
+				error_code,
+				"Too many requests, try again later.",
+				None::<()>,
+			))
+			.into())
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn allows_burst_up_to_rate() {
+		let limiter = RateLimiter::new(NonZeroU32::new(3).unwrap());
+
+		assert!(limiter.try_acquire());
+		assert!(limiter.try_acquire());
+		assert!(limiter.try_acquire());
+		assert!(!limiter.try_acquire());
+	}
+
+	#[test]
+	fn refills_over_time() {
+		let limiter = RateLimiter::new(NonZeroU32::new(100).unwrap());
+		while limiter.try_acquire() {}
+
+		std::thread::sleep(std::time::Duration::from_millis(50));
+
+		assert!(limiter.try_acquire());
+	}
+}
@@ -0,0 +1,60 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use parity_scale_codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	/// The API to query attestations.
+	pub trait Attestation<ClaimHash, AttestationDetails> where
+		ClaimHash: Codec,
+		AttestationDetails: Codec,
+	{
+		/// Return the attestation for the specified claim hash, if found.
+		/// The attestation details include the content identifier of the
+		/// off-chain credential content, if one was provided by the attester,
+		/// and whether the claimer acknowledged the attestation.
+		fn attestation(claim_hash: ClaimHash) -> Option<AttestationDetails>;
+	}
+
+	/// The API to query the number of attestations issued per CType.
+	pub trait AttestationIssuance<AttesterId, CtypeHash, IssuanceCounter> where
+		AttesterId: Codec,
+		CtypeHash: Codec,
+		IssuanceCounter: Codec,
+	{
+		/// Return the issuance counter of the specified attester for the
+		/// specified CType, if the attester has ever attested it.
+		fn issuance_counter(attester: AttesterId, ctype_hash: CtypeHash) -> Option<IssuanceCounter>;
+		/// Return the total number of attestations ever issued for the
+		/// specified CType, across all attesters.
+		fn ctype_issuance(ctype_hash: CtypeHash) -> u64;
+	}
+
+	/// The API to query the attestations issued under a delegation.
+	pub trait DelegatedAttestations<DelegationNodeId, ClaimHash> where
+		DelegationNodeId: Codec,
+		ClaimHash: Codec,
+	{
+		/// Return the claim hashes of the attestations issued under the
+		/// specified delegation node which have not been revoked.
+		fn attestations_by_delegation(delegation_node_id: DelegationNodeId) -> Vec<ClaimHash>;
+	}
+}
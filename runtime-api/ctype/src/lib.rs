@@ -0,0 +1,37 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use parity_scale_codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	/// The API to query CTypes.
+	pub trait Ctype<CtypeHash, CtypeEntry> where
+		CtypeHash: Codec,
+		CtypeEntry: Codec,
+	{
+		/// Return the creation details of the CType with the specified hash,
+		/// if found.
+		fn ctype(ctype_hash: CtypeHash) -> Option<CtypeEntry>;
+		/// Return the schema of the CType with the specified hash, if it has
+		/// been stored on chain.
+		fn ctype_schema(ctype_hash: CtypeHash) -> Option<Vec<u8>>;
+	}
+}
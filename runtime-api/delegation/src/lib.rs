@@ -0,0 +1,74 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use parity_scale_codec::{Codec, Decode, Encode};
+use scale_info::TypeInfo;
+use sp_std::vec::Vec;
+
+/// A node of a delegation subtree.
+#[derive(Encode, Decode, TypeInfo, Clone, Debug, Eq, PartialEq)]
+pub struct DelegationSubtreeNode<DelegationNodeId, DelegatorId, Permissions> {
+	/// The ID of the delegation node.
+	pub id: DelegationNodeId,
+	/// The ID of the parent node, or `None` for the root of a hierarchy.
+	pub parent: Option<DelegationNodeId>,
+	/// The depth of the node relative to the root of the subtree, which has
+	/// depth 0.
+	pub depth: u32,
+	/// The owner of the delegation.
+	pub owner: DelegatorId,
+	/// The permissions granted to the owner of the delegation.
+	pub permissions: Permissions,
+	/// Whether the delegation has been revoked.
+	pub revoked: bool,
+}
+
+/// A page of the nodes of a delegation subtree.
+#[derive(Encode, Decode, TypeInfo, Clone, Debug, Eq, PartialEq)]
+pub struct DelegationSubtree<DelegationNodeId, DelegatorId, Permissions> {
+	/// The nodes in the page, in depth-first order with each node listed
+	/// before its children.
+	pub nodes: Vec<DelegationSubtreeNode<DelegationNodeId, DelegatorId, Permissions>>,
+	/// The cursor to request the next page with, if the subtree has more
+	/// nodes.
+	pub next_cursor: Option<DelegationNodeId>,
+}
+
+sp_api::decl_runtime_apis! {
+	/// The API to query delegation hierarchies.
+	pub trait Delegation<DelegationNodeId, DelegatorId, Permissions> where
+		DelegationNodeId: Codec,
+		DelegatorId: Codec,
+		Permissions: Codec,
+	{
+		/// Return the subtree rooted at the specified delegation node, down to
+		/// `max_depth` levels below it, if the node exists.
+		///
+		/// The number of nodes returned per call is bounded by the runtime.
+		/// If the subtree has more nodes, the returned `next_cursor` can be
+		/// passed as `cursor` to fetch the following page. `None` is also
+		/// returned if the cursor is not part of the subtree.
+		fn delegation_subtree(
+			node_id: DelegationNodeId,
+			max_depth: u32,
+			cursor: Option<DelegationNodeId>,
+		) -> Option<DelegationSubtree<DelegationNodeId, DelegatorId, Permissions>>;
+	}
+}
@@ -19,12 +19,20 @@
 use frame_system::pallet_prelude::BlockNumberFor;
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
 use sp_std::collections::{btree_map::BTreeMap, btree_set::BTreeSet};
 
 use did::{did_details::DidPublicKeyDetails, AccountIdOf, BalanceOf, KeyIdOf};
+
+pub use did::{
+	did_details::{DidKeyRelationship, DidSignature, DidVerificationKeyRelationship, KeyChange, KeyHistoryEntry},
+	DidDocumentEstimateError, DidDocumentOperation, DidDocumentSizeEstimate, EndorsementDetails,
+};
 use kilt_support::Deposit;
 
 #[derive(Encode, Decode, TypeInfo, Clone, Debug, Eq, PartialEq, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct DidDetails<Key: Ord, BlockNumber, AccountId, Balance> {
 	pub authentication_key: Key,
 	pub key_agreement_keys: BTreeSet<Key>,
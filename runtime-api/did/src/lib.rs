@@ -20,6 +20,8 @@
 
 use parity_scale_codec::{Codec, Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
 use sp_std::vec::Vec;
 
 mod did_details;
@@ -29,6 +31,7 @@ pub use did_details::*;
 pub use service_endpoint::*;
 
 #[derive(Encode, Decode, TypeInfo, Eq, PartialEq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct DidLinkedInfo<
 	DidIdentifier,
 	AccountId,
@@ -65,8 +68,25 @@ pub type RawDidLinkedInfo<DidIdentifier, AccountId, LinkableAccountId, Balance,
 	BlockNumber,
 >;
 
+/// Any of the identifiers a DID and its linked information can be resolved
+/// by.
+#[derive(Encode, Decode, TypeInfo, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub enum DidLookupKey<DidIdentifier, Web3Name, LinkableAccountId> {
+	/// The DID itself.
+	Did(DidIdentifier),
+	/// The web3name owned by the DID.
+	Web3Name(Web3Name),
+	/// An account linked to the DID.
+	Account(LinkableAccountId),
+}
+
+/// The DidLookupKey with a Web3Name represented as a byte array.
+pub type RawDidLookupKey<DidIdentifier, LinkableAccountId> = DidLookupKey<DidIdentifier, Vec<u8>, LinkableAccountId>;
+
 sp_api::decl_runtime_apis! {
-	#[api_version(2)]
+	#[api_version(11)]
 	pub trait Did<DidIdentifier, AccountId, LinkableAccountId, Balance, Key: Ord, BlockNumber: MaxEncodedLen> where
 		DidIdentifier: Codec,
 		AccountId: Codec,
@@ -102,5 +122,52 @@ sp_api::decl_runtime_apis! {
 		#[changed_in(2)]
 		fn query(did: DidIdentifier) -> Option<RawDidLinkedInfo<DidIdentifier, AccountId, AccountId, Balance, Key, BlockNumber>>;
 		fn query(did: DidIdentifier) -> Option<RawDidLinkedInfo<DidIdentifier, AccountId, LinkableAccountId, Balance, Key, BlockNumber>>;
+		/// Given a DID, a web3name or a linked account this returns:
+		/// * the DID
+		/// * public keys stored for the did
+		/// * the web3name (optional)
+		/// * associated accounts
+		/// * service endpoints
+		fn linked_did_info(key: RawDidLookupKey<DidIdentifier, LinkableAccountId>) -> Option<RawDidLinkedInfo<DidIdentifier, AccountId, LinkableAccountId, Balance, Key, BlockNumber>>;
+		/// Given a DID and the ID of one of its keys, this returns the latest
+		/// changes of the key, oldest first.
+		#[api_version(4)]
+		fn key_history(did: DidIdentifier, key_id: Key) -> Vec<KeyHistoryEntry<BlockNumber>>;
+		/// Given a DID, a payload and its signature, this returns the
+		/// verification relationships for which the signature was valid at the
+		/// given block. The result is empty if the signature was not generated
+		/// by any of the DID verification keys at that block.
+		#[api_version(5)]
+		fn verify_signature_at(did: DidIdentifier, payload: Vec<u8>, signature: DidSignature, block_number: BlockNumber) -> Vec<DidVerificationKeyRelationship>;
+		/// Given a DID, this returns all the accounts linked to it together
+		/// with the label of each link, if set.
+		#[api_version(6)]
+		fn linked_accounts_with_labels(did: DidIdentifier) -> Vec<(LinkableAccountId, Option<Vec<u8>>)>;
+		/// Given a DID, this returns all its service endpoints, both the ones
+		/// stored inline and the ones stored as the hash of an off-chain
+		/// descriptor.
+		#[api_version(7)]
+		fn service_endpoints(did: DidIdentifier) -> Vec<RawServiceEndpointEntry>;
+		/// Given a DID, this returns the DIDs that endorse it together with
+		/// the details of each endorsement, i.e., the hash of its context and
+		/// the block in which it was made. Endorsements by deleted DIDs are
+		/// not returned.
+		#[api_version(8)]
+		fn endorsements(did: DidIdentifier) -> Vec<(DidIdentifier, EndorsementDetails<Key, BlockNumber>)>;
+		/// Given a DID, this returns the `alsoKnownAs` URIs of its DID
+		/// Document, in the order they were added.
+		#[api_version(9)]
+		fn also_known_as(did: DidIdentifier) -> Vec<Vec<u8>>;
+		/// Given a DID and a batch of changes to its DID Document, this
+		/// returns the size of the resulting DID Document, the maximum size
+		/// allowed and the deposit it requires, without submitting anything.
+		/// If the size exceeds the maximum, the last operation that increases
+		/// it would fail on chain.
+		#[api_version(10)]
+		fn estimate_document_size(did: DidIdentifier, operations: Vec<DidDocumentOperation<AccountId, Key>>) -> Result<DidDocumentSizeEstimate<Balance>, DidDocumentEstimateError>;
+		/// Given a web3name, this returns the key and value of each of its
+		/// text records. Unknown names have no records.
+		#[api_version(11)]
+		fn web3_name_records(name: Vec<u8>) -> Vec<(Vec<u8>, Vec<u8>)>;
 	}
 }
@@ -18,9 +18,12 @@
 
 use parity_scale_codec::{Decode, Encode};
 use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
 use sp_std::vec::Vec;
 
 #[derive(Encode, Decode, TypeInfo, Eq, PartialEq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct ServiceEndpoint<Id, Type, Url> {
 	pub id: Id,
 	pub service_types: Vec<Type>,
@@ -46,3 +49,36 @@ impl<T: did::Config> From<did::service_endpoints::DidEndpoint<T>> for ServiceEnd
 		}
 	}
 }
+
+#[derive(Encode, Decode, TypeInfo, Eq, PartialEq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct HashedServiceEndpoint<Id, Tag, Hash> {
+	pub id: Id,
+	pub tag: Tag,
+	pub descriptor_hash: Hash,
+}
+
+impl<T: did::Config> From<did::service_endpoints::HashedDidEndpoint<T>>
+	for HashedServiceEndpoint<Vec<u8>, Vec<u8>, Vec<u8>>
+{
+	fn from(runtime_endpoint: did::service_endpoints::HashedDidEndpoint<T>) -> Self {
+		HashedServiceEndpoint {
+			id: runtime_endpoint.id.into_inner(),
+			tag: runtime_endpoint.tag.into_inner(),
+			descriptor_hash: runtime_endpoint.descriptor_hash.as_ref().to_vec(),
+		}
+	}
+}
+
+/// A service endpoint of a DID, either stored inline or as the hash of an
+/// off-chain descriptor.
+#[derive(Encode, Decode, TypeInfo, Eq, PartialEq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub enum ServiceEndpointEntry<Id, Type, Url, Hash> {
+	Inline(ServiceEndpoint<Id, Type, Url>),
+	Hashed(HashedServiceEndpoint<Id, Type, Hash>),
+}
+
+/// The ServiceEndpointEntry with all its fields represented as byte arrays.
+pub type RawServiceEndpointEntry = ServiceEndpointEntry<Vec<u8>, Vec<u8>, Vec<u8>, Vec<u8>>;
@@ -18,7 +18,44 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use parity_scale_codec::Codec;
+use parity_scale_codec::{Codec, Decode, Encode};
+use scale_info::TypeInfo;
+use sp_std::vec::Vec;
+
+/// Parameters for a DIP proof request.
+#[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, Debug)]
+pub struct DipProofRequest<Identifier, Version, KeyId, AccountId> {
+	/// The subject identifier for which to generate the DIP proof.
+	pub identifier: Identifier,
+	/// The DIP version.
+	pub version: Version,
+	/// The DID key IDs of the subject's DID Document to reveal in the DIP
+	/// proof.
+	pub keys: Vec<KeyId>,
+	/// The list of accounts linked to the subject's DID to reveal in the
+	/// DIP proof.
+	pub accounts: Vec<AccountId>,
+	/// A flag indicating whether the web3name claimed by the DID subject
+	/// should revealed in the DIP proof.
+	pub should_include_web3_name: bool,
+	/// A flag indicating whether the `alsoKnownAs` URIs of the subject's DID
+	/// Document should be revealed in the DIP proof.
+	pub should_include_also_known_as: bool,
+	/// A flag indicating whether the records of the web3name claimed by the
+	/// DID subject should be revealed in the DIP proof.
+	pub should_include_web3_name_records: bool,
+}
+
+/// A DIP proof that can be split into its SCALE-encoded parts, for clients
+/// that are not aware of the provider's proof types.
+pub trait EncodedDipProofParts {
+	/// The commitment the proof verifies against.
+	fn encoded_root(&self) -> Vec<u8>;
+	/// The blinded trie nodes of the proof.
+	fn encoded_blinded(&self) -> Vec<Vec<u8>>;
+	/// The leaves revealed by the proof.
+	fn encoded_revealed(&self) -> Vec<Vec<u8>>;
+}
 
 sp_api::decl_runtime_apis! {
 	/// Runtime API to generate a DIP proof with the provided parameters.
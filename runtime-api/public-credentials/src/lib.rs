@@ -25,6 +25,7 @@ use kilt_support::traits::ItemFilter;
 
 sp_api::decl_runtime_apis! {
 	/// The API to query public credentials for a subject.
+	#[api_version(2)]
 	pub trait PublicCredentials<SubjectId, CredentialId, CredentialEntry, Filter, Error> where
 		SubjectId: Codec,
 		CredentialId: Codec,
@@ -37,6 +38,12 @@ sp_api::decl_runtime_apis! {
 		/// Return all the public credentials linked to the specified subject.
 		/// An optional filter can be passed to be applied to the result before being returned to the client.
 		/// It returns an error if the provided specified subject ID is not valid.
+		#[changed_in(2)]
 		fn get_by_subject(subject: SubjectId, filter: Option<Filter>) -> Result<Vec<(CredentialId, CredentialEntry)>, Error>;
+		/// Return all the public credentials linked to the specified subject.
+		/// An optional filter can be passed to be applied to the result before being returned to the client.
+		/// Credentials hidden by the subject controller are only returned if `include_hidden` is `true`.
+		/// It returns an error if the provided specified subject ID is not valid.
+		fn get_by_subject(subject: SubjectId, filter: Option<Filter>, include_hidden: bool) -> Result<Vec<(CredentialId, CredentialEntry)>, Error>;
 	}
 }
@@ -21,6 +21,7 @@
 use parity_scale_codec::{Codec, Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use sp_runtime::Perquintill;
+use sp_std::vec::Vec;
 
 #[derive(Decode, Encode, TypeInfo, MaxEncodedLen, PartialEq, Eq, Debug)]
 pub struct StakingRates {
@@ -30,8 +31,38 @@ pub struct StakingRates {
 	pub delegator_reward_rate: Perquintill,
 }
 
+/// The position of a collator candidate in the current ranking of top
+/// candidates.
+#[derive(Decode, Encode, TypeInfo, PartialEq, Eq, Debug)]
+pub struct CandidateRankingEntry<AccountId, Balance> {
+	/// The account of the candidate.
+	pub account: AccountId,
+	/// The amount staked by the candidate themselves.
+	pub self_stake: Balance,
+	/// The amount staked by the delegators of the candidate.
+	pub delegator_stake: Balance,
+	/// The total backing of the candidate, which determines its rank.
+	pub total_stake: Balance,
+	/// Whether the candidate would be selected as collator if the next
+	/// session started now.
+	pub selected: bool,
+}
+
+/// The current ranking of the top collator candidates.
+#[derive(Decode, Encode, TypeInfo, PartialEq, Eq, Debug)]
+pub struct CandidateRanking<AccountId, Balance> {
+	/// The top candidates, ordered from the highest to the lowest total stake.
+	pub candidates: Vec<CandidateRankingEntry<AccountId, Balance>>,
+	/// The number of collator seats for the next session.
+	pub seats: u32,
+	/// The minimum total stake a candidate currently needs to be selected for
+	/// the next session.
+	pub cutoff: Balance,
+}
+
 sp_api::decl_runtime_apis! {
 	/// The API to query staking and reward rates.
+	#[api_version(2)]
 	pub trait Staking<AccountId, Balance>
 	where
 		AccountId: Codec,
@@ -42,5 +73,10 @@ sp_api::decl_runtime_apis! {
 		/// Returns the current staking and reward rates for collators and
 		/// delegators.
 		fn get_staking_rates() -> StakingRates;
+		/// Returns the current ranking of the top collator candidates,
+		/// including their stake breakdown and the stake required to be
+		/// selected for the next session.
+		#[api_version(2)]
+		fn get_candidate_ranking() -> CandidateRanking<AccountId, Balance>;
 	}
 }
@@ -0,0 +1,65 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+/// The storage usage of a single storage map.
+#[derive(Encode, Decode, TypeInfo, Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct StorageEntryStats {
+	/// The number of entries in the map.
+	pub entries: u32,
+	/// The approximate size, in bytes, of all the entries in the map.
+	///
+	/// The size is computed from the maximum encoded length of keys and
+	/// values, hence it is an upper bound of the actual storage used.
+	pub approximate_size: u64,
+}
+
+/// The storage usage of the identity-related pallets of a runtime.
+#[derive(Encode, Decode, TypeInfo, Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct IdentityStorageStats {
+	/// The full DIDs stored in the DID pallet.
+	pub dids: StorageEntryStats,
+	/// The names claimed in the web3 name pallet.
+	pub web3_names: StorageEntryStats,
+	/// The accounts linked to DIDs in the DID lookup pallet.
+	pub account_links: StorageEntryStats,
+	/// The attestations stored in the attestation pallet.
+	pub attestations: StorageEntryStats,
+	/// The credentials stored in the public credentials pallet.
+	pub public_credentials: StorageEntryStats,
+	/// The identity commitments stored in the DIP provider pallet, if the
+	/// runtime includes it.
+	pub identity_commitments: Option<StorageEntryStats>,
+}
+
+sp_api::decl_runtime_apis! {
+	/// The API to query the storage usage of the identity pallets.
+	pub trait StorageStats {
+		/// Return the number of entries and their approximate size for each
+		/// of the identity pallets.
+		fn identity_storage_stats() -> IdentityStorageStats;
+	}
+}
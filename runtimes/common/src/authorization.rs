@@ -15,12 +15,16 @@
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
 // If you feel like getting in touch with us, you can do so at info@botlabs.org
-use frame_support::dispatch::Weight;
+use frame_support::{
+	dispatch::Weight,
+	traits::{Contains, Get},
+};
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use sp_runtime::DispatchError;
+use sp_std::{marker::PhantomData, vec::Vec};
 
-use attestation::AttestationAccessControl;
+use attestation::{AttestationAccessControl, AttesterAccreditation};
 use public_credentials::PublicCredentialsAccessControl;
 
 #[derive(Clone, Debug, Encode, Decode, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
@@ -161,3 +165,28 @@ where
 		ac.can_remove_weight()
 	}
 }
+
+/// Attester accreditation backed by the `pallet_membership` instance `I`.
+///
+/// Only members of the instance are accredited to attest the CTypes returned
+/// by `RegulatedCtypes`, while any attester can attest any other CType.
+pub struct AccreditationByMembership<R, I, RegulatedCtypes>(PhantomData<(R, I, RegulatedCtypes)>);
+
+impl<R, I, RegulatedCtypes, Ctype> AttesterAccreditation<R::AccountId, Ctype>
+	for AccreditationByMembership<R, I, RegulatedCtypes>
+where
+	R: pallet_membership::Config<I>,
+	I: 'static,
+	pallet_membership::Pallet<R, I>: Contains<R::AccountId>,
+	RegulatedCtypes: Get<Vec<Ctype>>,
+	Ctype: PartialEq,
+{
+	fn is_accredited(attester: &R::AccountId, ctype: &Ctype) -> bool {
+		!RegulatedCtypes::get().contains(ctype) || pallet_membership::Pallet::<R, I>::contains(attester)
+	}
+
+	fn is_accredited_weight() -> Weight {
+		// The membership is only read if the CType is regulated.
+		<R as frame_system::Config>::DbWeight::get().reads(1)
+	}
+}
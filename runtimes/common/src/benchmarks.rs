@@ -15,10 +15,19 @@
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
 // If you feel like getting in touch with us, you can do so at info@botlabs.org
+use kilt_support::signature::AlwaysVerify;
+use pallet_did_lookup::{
+	account::AccountId20,
+	associate_account_request::{ContractSignature, ContractSignatureProof},
+};
 use parity_scale_codec::{Decode, Encode};
 use scale_info::TypeInfo;
 use sp_std::vec::Vec;
 
+/// Accepts all ERC-1271 contract signatures, so that accounts can be linked to
+/// DIDs in the benchmarks without a source of Ethereum state.
+pub type AlwaysVerifyContractSignature = AlwaysVerify<AccountId20, ContractSignatureProof, ContractSignature>;
+
 #[derive(Clone, Copy, Default, Debug, Encode, Decode, PartialEq, Eq, TypeInfo)]
 pub struct DummySignature;
 
@@ -21,7 +21,7 @@ use frame_support::{
 	traits::WithdrawReasons,
 	weights::{constants::WEIGHT_REF_TIME_PER_SECOND, Weight},
 };
-use sp_runtime::{Perbill, Percent, Perquintill};
+use sp_runtime::{Perbill, Percent, Permill, Perquintill};
 
 use parachain_staking::InflationInfo;
 
@@ -120,6 +120,8 @@ pub const MAX_VESTING_SCHEDULES: u32 = 28;
 parameter_types! {
 	/// Vesting Pallet. Copied from Kusama & Polkadot runtime
 	pub const MinVestedTransfer: Balance = 100 * MILLI_KILT;
+	/// Vesting Grants Pallet. The maximum number of grants created at once.
+	pub const MaxVestingGrants: u32 = 250;
 	pub UnvestedFundsAllowedWithdrawReasons: WithdrawReasons =
 		WithdrawReasons::except(WithdrawReasons::TRANSFER | WithdrawReasons::RESERVE);
 	/// Deposits per byte
@@ -130,16 +132,73 @@ parameter_types! {
 	pub const CtypeFee: Balance = MILLI_KILT;
 }
 
+pub mod assets {
+	use super::*;
+
+	/// The maximum length of the name and symbol of an asset.
+	pub const STRING_LIMIT: u32 = 50;
+
+	parameter_types! {
+		/// Deposit for the asset details: admin accounts, supply and status.
+		pub const AssetDeposit: Balance = deposit(1, 210);
+		/// Deposit for each account holding the asset.
+		pub const AssetAccountDeposit: Balance = deposit(1, 18);
+		pub const MetadataDepositBase: Balance = deposit(1, 68);
+		pub const MetadataDepositPerByte: Balance = deposit(0, 1);
+		pub const ApprovalDeposit: Balance = EXISTENTIAL_DEPOSIT;
+		pub const StringLimit: u32 = STRING_LIMIT;
+		pub const RemoveItemsLimit: u32 = 1000;
+	}
+}
+
 pub mod attestation {
 	use super::*;
 
 	/// The size is checked in the runtime by a test.
-	pub const MAX_ATTESTATION_BYTE_LENGTH: u32 = 179;
+	///
+	/// It includes the 67 bytes of an optional CID of `MaxCidLength`, so that
+	/// the deposit covers the largest attestation an attester can store with a
+	/// single `add`. Attestations stored before the CID was introduced keep
+	/// their deposit until it is updated via `update_deposit`.
+	pub const MAX_ATTESTATION_BYTE_LENGTH: u32 = 247;
 	pub const ATTESTATION_DEPOSIT: Balance = deposit(2, MAX_ATTESTATION_BYTE_LENGTH);
 
+	/// The size is checked in the runtime by a test.
+	///
+	/// It is the size of an issuance counter including its keys, which is
+	/// larger than the issuance limits of a CType including their key.
+	pub const MAX_ISSUANCE_ENTRY_BYTE_LENGTH: u32 = 133;
+	pub const ISSUANCE_DEPOSIT: Balance = deposit(1, MAX_ISSUANCE_ENTRY_BYTE_LENGTH);
+
 	parameter_types! {
 		pub const MaxDelegatedAttestations: u32 = 1000;
+		/// Long enough for a base32-encoded CIDv1 with a SHA2-256 digest.
+		pub const MaxCidLength: u32 = 64;
 		pub const AttestationDeposit: Balance = ATTESTATION_DEPOSIT;
+		pub const IssuanceDeposit: Balance = ISSUANCE_DEPOSIT;
+		/// The maximum number of attesters that can be accredited to attest
+		/// regulated CTypes.
+		pub const MaxAccreditedAttesters: u32 = 1_000;
+		/// The bond required to challenge or to contest the challenge of an
+		/// attestation.
+		pub const ChallengeBond: Balance = 50 * KILT;
+		/// The number of blocks the attester has to contest a challenge.
+		pub const ChallengeResponsePeriod: BlockNumber = 7 * DAYS;
+	}
+}
+
+pub mod ctype {
+	use super::*;
+
+	/// The size of each tier of a CType schema stored on chain.
+	pub const SCHEMA_TIER_SIZE: u32 = 1024;
+
+	parameter_types! {
+		/// The maximum length of a CType schema stored on chain.
+		pub const MaxSchemaLength: u32 = 64 * SCHEMA_TIER_SIZE;
+		pub const SchemaBaseDeposit: Balance = deposit(1, 0);
+		pub const SchemaTierSize: u32 = SCHEMA_TIER_SIZE;
+		pub const SchemaTierDeposit: Balance = deposit(0, SCHEMA_TIER_SIZE);
 	}
 }
 
@@ -154,6 +213,9 @@ pub mod delegation {
 	pub const MAX_REVOCATIONS: u32 = 5;
 	pub const MAX_REMOVALS: u32 = MAX_REVOCATIONS;
 	pub const MAX_CHILDREN: u32 = 1000;
+	/// The maximum number of nodes returned by a single call to the
+	/// delegation subtree runtime API.
+	pub const MAX_SUBTREE_PAGE_SIZE: u32 = 500;
 
 	parameter_types! {
 		pub const MaxSignatureByteLength: u16 = MAX_SIGNATURE_BYTE_LENGTH;
@@ -180,6 +242,9 @@ pub mod dip_provider {
 	// Commitment are 32-byte hashes.
 	pub const MAX_COMMITMENT_BYTE_LENGTH: u32 = 32;
 	pub const COMMITMENT_DEPOSIT: Balance = deposit(1, MAX_COMMITMENT_BYTE_LENGTH);
+	/// The size is checked in the runtime by a test.
+	pub const MAX_COMMITTER_BYTE_LENGTH: u32 = 80;
+	pub const COMMITTER_DEPOSIT: Balance = deposit(1, MAX_COMMITTER_BYTE_LENGTH);
 }
 
 pub mod staking {
@@ -247,6 +312,10 @@ pub mod staking {
 		pub const NetworkRewardStart: BlockNumber = super::treasury::INITIAL_PERIOD_LENGTH;
 		/// The rate in percent for the network rewards
 		pub const NetworkRewardRate: Perquintill = NETWORK_REWARD_RATE;
+		/// Unlocking unstaked balance before the end of the stake duration costs 10%
+		pub const InstantUnstakePenalty: Perquintill = Perquintill::from_percent(10);
+		/// Maximum number of linked accounts whose stake can be managed by a DID in a single call
+		pub const MaxLinkedAccountsPerCall: u32 = 10;
 	}
 }
 
@@ -341,6 +410,10 @@ pub mod pallet_migration {
 
 	parameter_types! {
 		pub const  MaxMigrationsPerPallet: u32 = 100;
+		/// Stepped migrations can use up to 10% of the block weight.
+		pub MaxSteppedMigrationsWeight: Weight = Perbill::from_percent(10) * MAXIMUM_BLOCK_WEIGHT;
+		/// Fits the raw storage key of any identity pallet entry.
+		pub const MaxMigrationCursorLength: u32 = 256;
 	}
 }
 
@@ -370,6 +443,16 @@ pub mod did {
 
 	pub const DID_BASE_DEPOSIT: Balance = 2 * KILT;
 	pub const KEY_DEPOSIT: Balance = deposit(0, MAX_KEY_LENGTH);
+	/// Max length of the history of a single key, including its storage keys.
+	/// It is the sum of:
+	/// - the storage key: the hashed and the plain DID identifier and key ID
+	/// - the maximum number of history entries * the length of an entry
+	/// - the compact-encoded length of the history
+	/// - the key ID in the removed keys of the DID
+	/// - the storage key and the maximum length of the historical public key
+	pub const MAX_KEY_HISTORY_BYTE_LENGTH: u32 =
+		8 + 32 + 16 + 32 + MAX_KEY_HISTORY_LENGTH * 11 + 1 + 32 + 8 + 32 + 16 + 32 + MAX_KEY_LENGTH;
+	pub const KEY_HISTORY_DEPOSIT: Balance = deposit(2, MAX_KEY_HISTORY_BYTE_LENGTH);
 	pub const SERVICE_ENDPOINT_DEPOSIT: Balance = deposit(1, MAX_SERVICE_ENDPOINT_BYTE_LENGTH);
 
 	pub const DID_FEE: Balance = 50 * MILLI_KILT;
@@ -387,6 +470,22 @@ pub mod did {
 	pub const MAX_NUMBER_OF_TYPES_PER_SERVICE: u32 = 1;
 	pub const MAX_SERVICE_URL_LENGTH: u32 = 2_000;
 	pub const MAX_NUMBER_OF_URLS_PER_SERVICE: u32 = 2;
+	pub const MAX_KEY_HISTORY_LENGTH: u32 = 20;
+	pub const MAX_REMOVED_KEYS: u32 = 20;
+	pub const MAX_ENDORSEMENTS_PER_DID: u32 = 100;
+	pub const MAX_ALSO_KNOWN_AS: u32 = 10;
+	// Must not exceed the length of the URIs that can be revealed in DIP proofs.
+	pub const MAX_ALSO_KNOWN_AS_URI_LENGTH: u32 = 256;
+	// Large enough for a DID with the maximum number of keys, service endpoints
+	// and `alsoKnownAs` URIs, as used in the benchmarks.
+	pub const MAX_DID_DOCUMENT_SIZE: u32 = 128 * 1024;
+	/// The number of DID management operations after the creation of a DID
+	/// whose fees are paid from the free trial pot.
+	pub const FREE_TRIAL_OPERATIONS: u32 = 3;
+	/// The block from which DID operations must be signed over their
+	/// canonical signing payload, and signatures over the bare SCALE-encoded
+	/// operation are rejected.
+	pub const LEGACY_SIGNATURE_DEADLINE: BlockNumber = 7_000_000;
 
 	parameter_types! {
 		#[derive(Debug, Clone, Eq, PartialEq, TypeInfo, Decode, Encode)]
@@ -405,8 +504,17 @@ pub mod did {
 		pub const MaxServiceIdLength: u32 = MAX_SERVICE_ID_LENGTH;
 		pub const MaxServiceTypeLength: u32 = MAX_SERVICE_TYPE_LENGTH;
 		pub const MaxServiceUrlLength: u32 = MAX_SERVICE_URL_LENGTH;
+		pub const MaxKeyHistoryLength: u32 = MAX_KEY_HISTORY_LENGTH;
+		pub const MaxRemovedKeys: u32 = MAX_REMOVED_KEYS;
+		pub const KeyHistoryDeposit: Balance = KEY_HISTORY_DEPOSIT;
+		pub const MaxEndorsementsPerDid: u32 = MAX_ENDORSEMENTS_PER_DID;
+		pub const MaxAlsoKnownAs: u32 = MAX_ALSO_KNOWN_AS;
+		pub const MaxAlsoKnownAsUriLength: u32 = MAX_ALSO_KNOWN_AS_URI_LENGTH;
+		pub const MaxDidDocumentSize: u32 = MAX_DID_DOCUMENT_SIZE;
 		pub const MaxNumberOfTypesPerService: u32 = MAX_NUMBER_OF_TYPES_PER_SERVICE;
 		pub const MaxNumberOfUrlsPerService: u32 = MAX_NUMBER_OF_URLS_PER_SERVICE;
+		pub const FreeTrialOperations: u32 = FREE_TRIAL_OPERATIONS;
+		pub const LegacySignatureDeadline: BlockNumber = LEGACY_SIGNATURE_DEADLINE;
 	}
 }
 
@@ -416,9 +524,53 @@ pub mod did_lookup {
 	/// The size is checked in the runtime by a test.
 	pub const MAX_CONNECTION_BYTE_LENGTH: u32 = 80;
 	pub const DID_CONNECTION_DEPOSIT: Balance = deposit(1, MAX_CONNECTION_BYTE_LENGTH);
+	/// The size is checked in the runtime by a test.
+	pub const MAX_LABEL_BYTE_LENGTH: u32 = 81;
+	pub const LABEL_DEPOSIT: Balance = deposit(1, MAX_LABEL_BYTE_LENGTH);
+	pub const MAX_ASSOCIATION_REMOVALS: u32 = 25;
 
 	parameter_types! {
 		pub const DidLookupDeposit: Balance = DID_CONNECTION_DEPOSIT;
+		pub const LabelDeposit: Balance = LABEL_DEPOSIT;
+		pub const MaxAssociationRemovals: u32 = MAX_ASSOCIATION_REMOVALS;
+		/// Association proofs cannot be valid for longer than a week.
+		pub const MaxProofValidity: BlockNumber = 7 * DAYS;
+	}
+}
+
+pub mod did_voting {
+	use super::*;
+
+	/// The size is checked in the runtime by a test.
+	pub const MAX_VOTE_BYTE_LENGTH: u32 = 86;
+	pub const VOTE_DEPOSIT: Balance = deposit(1, MAX_VOTE_BYTE_LENGTH);
+	/// The amount held per unit of conviction multiplier, so that voting
+	/// with conviction locks funds until the vote can be removed.
+	pub const CONVICTION_DEPOSIT: Balance = KILT;
+
+	parameter_types! {
+		pub const VoteDeposit: Balance = VOTE_DEPOSIT;
+		pub const ConvictionDeposit: Balance = CONVICTION_DEPOSIT;
+	}
+}
+
+pub mod did_scheduler {
+	use super::*;
+
+	/// The size is checked in the runtime by a test.
+	pub const MAX_SCHEDULED_CALL_BYTE_LENGTH: u32 = 112;
+	/// Upper bound of the agenda and lookup entries of a scheduled call in the
+	/// scheduler. The bound is checked in the runtime by a test.
+	pub const MAX_AGENDA_ENTRY_BYTE_LENGTH: u32 = 900;
+	pub const SCHEDULED_CALL_DEPOSIT: Balance =
+		deposit(3, MAX_SCHEDULED_CALL_BYTE_LENGTH + MAX_AGENDA_ENTRY_BYTE_LENGTH);
+	/// Limits how much of the agenda shared with governance a single DID can
+	/// take up.
+	pub const MAX_SCHEDULED_CALLS_PER_DID: u32 = 10;
+
+	parameter_types! {
+		pub const ScheduledCallDeposit: Balance = SCHEDULED_CALL_DEPOSIT;
+		pub const MaxScheduledCallsPerDid: u32 = MAX_SCHEDULED_CALLS_PER_DID;
 	}
 }
 
@@ -435,6 +587,26 @@ pub mod treasury {
 	}
 }
 
+pub mod bounties {
+	use super::*;
+
+	parameter_types! {
+		pub const BountyDepositBase: Balance = KILT;
+		pub const BountyDepositPayoutDelay: BlockNumber = 8 * DAYS;
+		pub const BountyUpdatePeriod: BlockNumber = 90 * DAYS;
+		pub const CuratorDepositMultiplier: Permill = Permill::from_percent(50);
+		pub const CuratorDepositMin: Option<Balance> = Some(10 * KILT);
+		pub const CuratorDepositMax: Option<Balance> = Some(200 * KILT);
+		pub const BountyValueMinimum: Balance = 100 * KILT;
+		pub const MaximumReasonLength: u32 = 16_384;
+		pub const MaxActiveChildBountyCount: u32 = 100;
+		pub const ChildBountyValueMinimum: Balance = 10 * KILT;
+		/// The maximum amount the treasury spend origin can approve for a single
+		/// bounty.
+		pub const MaxBountySpend: Balance = 100_000 * KILT;
+	}
+}
+
 pub mod proxy {
 	use super::*;
 
@@ -460,10 +632,54 @@ pub mod web3_names {
 	pub const MAX_NAME_BYTE_LENGTH: u32 = 121;
 	pub const DEPOSIT: Balance = deposit(2, MAX_NAME_BYTE_LENGTH);
 
+	pub const MAX_RECORD_KEY_LENGTH: u32 = 32;
+	pub const MAX_RECORD_VALUE_LENGTH: u32 = 256;
+	pub const MAX_RECORDS_PER_NAME: u32 = 16;
+
+	/// The size is checked in the runtime by a test.
+	pub const MAX_RECORD_BYTE_LENGTH: u32 = 372;
+	pub const RECORD_DEPOSIT: Balance = deposit(1, MAX_RECORD_BYTE_LENGTH);
+
 	parameter_types! {
 		pub const Web3NameDeposit: Balance = DEPOSIT;
 		pub const MinNameLength: u32 = MIN_LENGTH;
 		pub const MaxNameLength: u32 = MAX_LENGTH;
+		pub const MaxRecordKeyLength: u32 = MAX_RECORD_KEY_LENGTH;
+		pub const MaxRecordValueLength: u32 = MAX_RECORD_VALUE_LENGTH;
+		pub const MaxRecordsPerName: u32 = MAX_RECORDS_PER_NAME;
+		pub const RecordDeposit: Balance = RECORD_DEPOSIT;
+		/// The time window in which the owner of a name can appeal against
+		/// its scheduled ban.
+		pub const BanDelay: BlockNumber = 7 * DAYS;
+		pub const MaxBansPerBlock: u32 = 16;
+		/// The maximum number of names the registrar can claim in a single
+		/// batch.
+		pub const MaxClaimBatchSize: u32 = 50;
+		/// The portion of the registrar fees routed to the treasury.
+		pub const RegistrarTreasuryShare: Perbill = Perbill::from_percent(20);
+	}
+}
+
+pub mod identity_priority {
+	use sp_runtime::transaction_validity::TransactionPriority;
+
+	use super::*;
+
+	/// The priority added to DID calls from established web3name owners. It
+	/// is about the priority of a 1 MILLI_KILT tip on a transaction using a
+	/// thousandth of the block, so that higher tips still take precedence.
+	pub const PRIORITY_BOOST: TransactionPriority = 1_000 * MILLI_KILT as TransactionPriority;
+
+	/// The minimum number of blocks since a web3name was claimed for the calls
+	/// of its owner to be boosted.
+	#[cfg(feature = "fast-gov")]
+	pub const MIN_NAME_AGE: BlockNumber = 10 * MINUTES;
+	#[cfg(not(feature = "fast-gov"))]
+	pub const MIN_NAME_AGE: BlockNumber = 30 * DAYS;
+
+	parameter_types! {
+		pub const PriorityBoost: TransactionPriority = PRIORITY_BOOST;
+		pub const MinNameAge: BlockNumber = MIN_NAME_AGE;
 	}
 }
 
@@ -511,6 +727,20 @@ pub mod public_credentials {
 	}
 }
 
+pub mod time_anchors {
+	use super::*;
+
+	parameter_types! {
+		/// A new anchor between block numbers and timestamps is recorded once a
+		/// day.
+		pub const AnchorInterval: BlockNumber = DAYS;
+		/// The anchors of the last 30 days are kept.
+		pub const MaxAnchors: u32 = 30;
+		/// The block time used to convert timestamps beyond the known anchors.
+		pub const ExpectedBlockTime: u64 = MILLISECS_PER_BLOCK;
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
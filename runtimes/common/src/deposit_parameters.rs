@@ -0,0 +1,113 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+//! Deposit amounts that can be adjusted by governance via the
+//! `pallet_deposit_storage` pallet.
+
+use frame_support::parameter_types;
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+use sp_std::{vec, vec::Vec};
+
+use crate::{
+	constants::{
+		attestation::ATTESTATION_DEPOSIT, did::DID_BASE_DEPOSIT, did_lookup::DID_CONNECTION_DEPOSIT,
+		web3_names::DEPOSIT as WEB3_NAME_DEPOSIT,
+	},
+	Balance,
+};
+
+/// The factor by which a deposit can be decreased or increased compared to its
+/// default value.
+pub const MAX_ADJUSTMENT_FACTOR: Balance = 10;
+
+/// The deposits that can be adjusted by governance.
+#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Copy, PartialEq, Eq, RuntimeDebug, Default)]
+pub enum DepositParameter {
+	/// The base deposit of a DID.
+	#[default]
+	DidBase,
+	/// The deposit of a web3name.
+	Web3Name,
+	/// The deposit of an attestation.
+	Attestation,
+	/// The deposit of an account linked to a DID.
+	DidLookup,
+}
+
+impl DepositParameter {
+	/// The deposit amount used before the deposit was adjustable.
+	pub const fn default_value(&self) -> Balance {
+		match self {
+			Self::DidBase => DID_BASE_DEPOSIT,
+			Self::Web3Name => WEB3_NAME_DEPOSIT,
+			Self::Attestation => ATTESTATION_DEPOSIT,
+			Self::DidLookup => DID_CONNECTION_DEPOSIT,
+		}
+	}
+}
+
+/// Each deposit defaults to its previous constant value and can be adjusted
+/// by at most [`MAX_ADJUSTMENT_FACTOR`] in each direction.
+pub struct DepositParameterBounds;
+
+impl pallet_deposit_storage::traits::DepositParameterBounds<DepositParameter, Balance> for DepositParameterBounds {
+	fn default_value(parameter: &DepositParameter) -> Balance {
+		parameter.default_value()
+	}
+
+	fn bounds(parameter: &DepositParameter) -> (Balance, Balance) {
+		let default_value = parameter.default_value();
+		(
+			default_value / MAX_ADJUSTMENT_FACTOR,
+			default_value.saturating_mul(MAX_ADJUSTMENT_FACTOR),
+		)
+	}
+}
+
+parameter_types! {
+	pub const DidBaseDepositParameter: DepositParameter = DepositParameter::DidBase;
+	pub const Web3NameDepositParameter: DepositParameter = DepositParameter::Web3Name;
+	pub const AttestationDepositParameter: DepositParameter = DepositParameter::Attestation;
+	pub const DidLookupDepositParameter: DepositParameter = DepositParameter::DidLookup;
+	pub AllDepositParameters: Vec<DepositParameter> = vec![
+		DepositParameter::DidBase,
+		DepositParameter::Web3Name,
+		DepositParameter::Attestation,
+		DepositParameter::DidLookup,
+	];
+}
+
+#[cfg(test)]
+mod tests {
+	use frame_support::traits::Get;
+	use pallet_deposit_storage::traits::DepositParameterBounds as _;
+
+	use super::*;
+
+	#[test]
+	fn default_values_are_within_bounds() {
+		for parameter in AllDepositParameters::get() {
+			let (min, max) = DepositParameterBounds::bounds(&parameter);
+			let default_value = DepositParameterBounds::default_value(&parameter);
+			assert!(min > 0);
+			assert!(min <= default_value && default_value <= max);
+		}
+	}
+}
@@ -0,0 +1,54 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use frame_support::traits::IsSubType;
+use pallet_did_lookup::signed_extension::DidCallSubject;
+use sp_std::marker::PhantomData;
+
+/// Returns the DID of DID creations and DID-authorized calls, if they are
+/// submitted by the account authorized to do so.
+///
+/// DID deletions are ignored, so that accounts are never linked to a DID that
+/// has just been deleted.
+#[derive(Clone, PartialEq, Eq)]
+pub struct DidCallSubjectOf<R>(PhantomData<R>);
+
+impl<R> DidCallSubject<<R as frame_system::Config>::RuntimeCall, R::AccountId, did::DidIdentifierOf<R>>
+	for DidCallSubjectOf<R>
+where
+	R: did::Config,
+	<R as frame_system::Config>::RuntimeCall: IsSubType<did::Call<R>>,
+	<R as did::Config>::RuntimeCall: IsSubType<did::Call<R>>,
+{
+	fn did_subject(
+		call: &<R as frame_system::Config>::RuntimeCall,
+		submitter: &R::AccountId,
+	) -> Option<did::DidIdentifierOf<R>> {
+		match call.is_sub_type()? {
+			did::Call::create { details, .. } if details.submitter == *submitter => Some(details.did.clone()),
+			did::Call::create_from_account { .. } => Some(submitter.clone().into()),
+			did::Call::submit_did_call { did_call, .. }
+				if did_call.submitter == *submitter
+					&& !matches!(did_call.call.is_sub_type(), Some(did::Call::delete { .. })) =>
+			{
+				Some(did_call.did.clone())
+			}
+			_ => None,
+		}
+	}
+}
@@ -17,7 +17,7 @@
 // If you feel like getting in touch with us, you can do so at info@botlabs.org
 
 use frame_support::{construct_runtime, traits::Everything};
-use frame_system::{mocking::MockBlock, EnsureSigned, RawOrigin};
+use frame_system::{mocking::MockBlock, EnsureRoot, EnsureSigned, RawOrigin};
 use pallet_dip_provider::{DefaultIdentityCommitmentGenerator, DefaultIdentityProvider, IdentityCommitmentVersion};
 use sp_core::{ConstU128, ConstU32};
 use sp_runtime::traits::IdentityLookup;
@@ -85,11 +85,15 @@ impl pallet_balances::Config for TestRuntime {
 impl pallet_dip_provider::Config for TestRuntime {
 	type CommitOrigin = AccountId;
 	type CommitOriginCheck = EnsureSigned<AccountId>;
+	type CommitterDeposit = ConstU128<KILT>;
+	type Currency = Balances;
 	type Identifier = AccountId;
 	type IdentityCommitmentGenerator = DefaultIdentityCommitmentGenerator<u32>;
 	type IdentityProvider = DefaultIdentityProvider<u32>;
+	type MaxCommitters = ConstU32<10>;
 	type ProviderHooks = ();
 	type RuntimeEvent = RuntimeEvent;
+	type RuntimeHoldReason = RuntimeHoldReason;
 	type WeightInfo = ();
 }
 
@@ -97,8 +101,11 @@ impl pallet_deposit_storage::Config for TestRuntime {
 	type CheckOrigin = EnsureSigned<Self::AccountId>;
 	type Currency = Balances;
 	type DepositHooks = DepositHooks;
+	type DepositParameter = ();
+	type DepositParameterBounds = ();
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeHoldReason = RuntimeHoldReason;
+	type SetDepositParameterOrigin = EnsureRoot<AccountId>;
 	type MaxKeyLength = ConstU32<MAX_DEPOSIT_PALLET_KEY_LENGTH>;
 	type Namespace = DepositNamespace;
 	#[cfg(feature = "runtime-benchmarks")]
@@ -16,12 +16,13 @@
 
 // If you feel like getting in touch with us, you can do so at info@botlabs.org
 
-use did::did_details::DidDetails;
+use did::{did_details::DidDetails, AlsoKnownAsOf};
 use frame_support::ensure;
 use frame_system::pallet_prelude::BlockNumberFor;
 use kilt_dip_primitives::RevealedWeb3Name;
 use pallet_did_lookup::linkable_account::LinkableAccountId;
 use pallet_dip_provider::traits::IdentityProvider;
+use pallet_web3_names::{Web3NameRecordKeyOf, Web3NameRecordValueOf};
 use parity_scale_codec::{Decode, Encode};
 use scale_info::TypeInfo;
 use sp_core::ConstU32;
@@ -60,6 +61,12 @@ impl From<LinkedDidInfoProviderError> for u16 {
 pub type Web3OwnershipOf<Runtime> =
 	RevealedWeb3Name<<Runtime as pallet_web3_names::Config>::Web3Name, BlockNumberFor<Runtime>>;
 
+/// The text records of a web3name, as (key, value) pairs.
+pub type Web3NameRecordsOf<Runtime> = BoundedVec<
+	(Web3NameRecordKeyOf<Runtime>, Web3NameRecordValueOf<Runtime>),
+	<Runtime as pallet_web3_names::Config>::MaxRecordsPerName,
+>;
+
 /// Identity information related to a KILT DID relevant for cross-chain
 /// transactions via the DIP protocol.
 #[derive(Debug, Clone, PartialEq)]
@@ -74,6 +81,10 @@ where
 	/// The list of accounts the subject has previously linked via the linking
 	/// pallet.
 	pub linked_accounts: BoundedVec<LinkableAccountId, ConstU32<MAX_LINKED_ACCOUNTS>>,
+	/// The `alsoKnownAs` URIs of the subject's DID Document.
+	pub also_known_as: AlsoKnownAsOf<Runtime>,
+	/// The text records of the subject's web3name, if any.
+	pub web3_name_records: Web3NameRecordsOf<Runtime>,
 }
 
 /// Type implementing the [`IdentityProvider`] trait which is responsible for
@@ -102,10 +113,20 @@ where
 
 		let linked_accounts = retrieve_linked_accounts::<Runtime, MAX_LINKED_ACCOUNTS>(identifier)?;
 
+		let also_known_as = did::Pallet::<Runtime>::get_also_known_as(identifier);
+
+		let web3_name_records = web3_name_details
+			.as_ref()
+			.map(|details| retrieve_w3n_records::<Runtime>(&details.web3_name))
+			.transpose()?
+			.unwrap_or_default();
+
 		Ok(LinkedDidInfoOf {
 			did_details,
 			web3_name_details,
 			linked_accounts,
+			also_known_as,
+			web3_name_records,
 		})
 	}
 }
@@ -136,6 +157,25 @@ where
 	}))
 }
 
+fn retrieve_w3n_records<Runtime>(
+	web3_name: &<Runtime as pallet_web3_names::Config>::Web3Name,
+) -> Result<Web3NameRecordsOf<Runtime>, LinkedDidInfoProviderError>
+where
+	Runtime: pallet_web3_names::Config,
+{
+	pallet_web3_names::Records::<Runtime>::iter_prefix(web3_name)
+		.map(|(key, record)| (key, record.value))
+		.collect::<Vec<_>>()
+		.try_into()
+		.map_err(|_| {
+			log::error!(
+				"Inconsistent records count for web3name {:#?}. Found more than the maximum allowed records.",
+				web3_name
+			);
+			LinkedDidInfoProviderError::Internal
+		})
+}
+
 fn retrieve_linked_accounts<Runtime, const MAX_LINKED_ACCOUNTS: u32>(
 	identifier: &Runtime::Identifier,
 ) -> Result<BoundedVec<LinkableAccountId, ConstU32<MAX_LINKED_ACCOUNTS>>, LinkedDidInfoProviderError>
@@ -237,7 +277,7 @@ where
 			.expect("Inserting w3n into storage should not fail.");
 
 		let web3_name_details = Some(RevealedWeb3Name {
-			web3_name,
+			web3_name: web3_name.clone(),
 			claimed_at: BlockNumberFor::<Runtime>::zero(),
 		});
 
@@ -253,12 +293,56 @@ where
 			linked_accounts.push(linkable_id);
 		});
 
+		let max_uri_length = <Runtime as did::Config>::MaxAlsoKnownAsUriLength::get().saturated_into::<usize>();
+		let also_known_as: AlsoKnownAsOf<Runtime> = (0..<Runtime as did::Config>::MaxAlsoKnownAs::get())
+			.map(|index| {
+				let mut uri = b"did:web:".to_vec();
+				uri.extend_from_slice(&index.to_be_bytes().map(|byte| b'a' + byte % 26));
+				uri.resize(max_uri_length, b'a');
+				uri.try_into()
+					.expect("BoundedVec creation of alsoKnownAs URI should not fail.")
+			})
+			.collect::<Vec<_>>()
+			.try_into()
+			.expect("BoundedVec creation of alsoKnownAs URIs should not fail.");
+		did::AlsoKnownAs::<Runtime>::insert(&did, also_known_as.clone());
+
+		let max_record_key_length =
+			<Runtime as pallet_web3_names::Config>::MaxRecordKeyLength::get().saturated_into::<usize>();
+		let max_record_value_length =
+			<Runtime as pallet_web3_names::Config>::MaxRecordValueLength::get().saturated_into::<usize>();
+		let max_records = <Runtime as pallet_web3_names::Config>::MaxRecordsPerName::get();
+		let web3_name_records: Web3NameRecordsOf<Runtime> = (0..max_records)
+			.map(|index| {
+				let mut key = index.to_be_bytes().to_vec();
+				key.resize(max_record_key_length, b'k');
+				let key: Web3NameRecordKeyOf<Runtime> = key
+					.try_into()
+					.expect("BoundedVec creation of record key should not fail.");
+				let value: Web3NameRecordValueOf<Runtime> = vec![b'v'; max_record_value_length]
+					.try_into()
+					.expect("BoundedVec creation of record value should not fail.");
+				pallet_web3_names::Pallet::<Runtime>::insert_record(
+					web3_name.clone(),
+					key.clone(),
+					value.clone(),
+					submitter.clone(),
+				)
+				.expect("Inserting w3n record into storage should not fail.");
+				(key, value)
+			})
+			.collect::<Vec<_>>()
+			.try_into()
+			.expect("BoundedVec creation of w3n records should not fail.");
+
 		LinkedDidInfoOf {
 			did_details,
 			linked_accounts: linked_accounts
 				.try_into()
 				.expect("BoundedVec creation of linked accounts should not fail."),
 			web3_name_details,
+			also_known_as,
+			web3_name_records,
 		}
 	}
 }
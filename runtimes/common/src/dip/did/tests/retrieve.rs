@@ -35,6 +35,7 @@ fn linked_did_info_provider_retrieve_max_capacity() {
 		did_details,
 		web3_name_details,
 		linked_accounts,
+		..
 	} = create_linked_info(auth_key, Some(b"ntn_x2"), MAX_LINKED_ACCOUNTS);
 	let web3_name: Option<pallet_web3_names::web3_name::AsciiWeb3Name<TestRuntime>> =
 		web3_name_details.map(|n| n.web3_name);
@@ -115,6 +116,7 @@ fn linked_did_info_provider_retrieve_too_many_linked_accounts() {
 		did_details,
 		web3_name_details,
 		linked_accounts,
+		..
 	} = create_linked_info(auth_key, Some(b"ntn_x2"), MAX_LINKED_ACCOUNTS + 1);
 	let web3_name = web3_name_details.map(|n| n.web3_name);
 
@@ -20,18 +20,22 @@ use did::KeyIdOf;
 use frame_support::RuntimeDebug;
 use frame_system::pallet_prelude::BlockNumberFor;
 use kilt_dip_primitives::DidMerkleProof;
+use kilt_runtime_api_dip_provider::EncodedDipProofParts;
 use pallet_did_lookup::linkable_account::LinkableAccountId;
 use pallet_dip_provider::{
 	traits::{IdentityCommitmentGenerator, IdentityProvider},
-	IdentityCommitmentVersion, IdentityOf,
+	IdentityCommitmentVersion, IdentityOf, IdentityScope,
 };
 use parity_scale_codec::{Decode, Encode};
 use scale_info::TypeInfo;
-use sp_std::marker::PhantomData;
+use sp_runtime::BoundedVec;
+use sp_std::{marker::PhantomData, vec::Vec};
 
 use crate::dip::did::LinkedDidInfoOf;
 
 pub mod v0;
+pub mod v1;
+pub mod v2;
 
 #[cfg(test)]
 mod tests;
@@ -56,6 +60,29 @@ pub struct CompleteMerkleProof<Root, Proof> {
 	pub proof: Proof,
 }
 
+impl<Root, KeyId, AccountId, BlockNumber, Web3Name, LinkableAccountId> EncodedDipProofParts
+	for CompleteMerkleProof<Root, DidMerkleProof<KeyId, AccountId, BlockNumber, Web3Name, LinkableAccountId>>
+where
+	Root: Encode,
+	KeyId: Encode,
+	AccountId: Encode,
+	BlockNumber: Encode,
+	Web3Name: Encode,
+	LinkableAccountId: Encode,
+{
+	fn encoded_root(&self) -> Vec<u8> {
+		self.root.encode()
+	}
+
+	fn encoded_blinded(&self) -> Vec<Vec<u8>> {
+		self.proof.blinded().to_vec()
+	}
+
+	fn encoded_revealed(&self) -> Vec<Vec<u8>> {
+		self.proof.revealed().iter().map(Encode::encode).collect()
+	}
+}
+
 #[derive(Clone, RuntimeDebug, Encode, Decode, TypeInfo, PartialEq)]
 pub enum DidMerkleProofError {
 	UnsupportedVersion,
@@ -63,6 +90,8 @@ pub enum DidMerkleProofError {
 	LinkedAccountNotFound,
 	Web3NameNotFound,
 	TooManyLeaves,
+	AlsoKnownAsNotFound,
+	Web3NameRecordsNotFound,
 	Internal,
 }
 
@@ -78,6 +107,8 @@ impl From<DidMerkleProofError> for u16 {
 			DidMerkleProofError::LinkedAccountNotFound => 3,
 			DidMerkleProofError::Web3NameNotFound => 4,
 			DidMerkleProofError::TooManyLeaves => 5,
+			DidMerkleProofError::AlsoKnownAsNotFound => 6,
+			DidMerkleProofError::Web3NameRecordsNotFound => 7,
 			DidMerkleProofError::Internal => u16::MAX,
 		}
 	}
@@ -85,13 +116,55 @@ impl From<DidMerkleProofError> for u16 {
 
 /// Type implementing the [`IdentityCommitmentGenerator`] and generating a
 /// Merkle root of the provided identity details, according to the description
-/// provided in the [README.md](./README.md),
+/// provided in the [README.md](./README.md). Version `0` generates a
+/// Patricia-Merkle trie root, version `1` generates a ZK-friendly Poseidon
+/// Merkle root, and version `2` generates the same trie root as version `0`
+/// with an additional manifest leaf.
+///
+/// Scoped commitments are generated over the identity details restricted to
+/// the requested [`IdentityScope`]; for version `2`, the scope is also
+/// recorded in the manifest leaf.
 pub struct DidMerkleRootGenerator<T>(PhantomData<T>);
 
+/// Return a copy of the provided identity details without the parts that are
+/// not included in the given scope.
+pub fn restrict_to_scope<Runtime, const MAX_LINKED_ACCOUNT: u32>(
+	identity: &LinkedDidInfoOf<Runtime, MAX_LINKED_ACCOUNT>,
+	scope: IdentityScope,
+) -> LinkedDidInfoOf<Runtime, MAX_LINKED_ACCOUNT>
+where
+	Runtime: did::Config + pallet_web3_names::Config,
+{
+	LinkedDidInfoOf {
+		did_details: identity.did_details.clone(),
+		web3_name_details: if scope.includes_web3_name() {
+			identity.web3_name_details.clone()
+		} else {
+			None
+		},
+		linked_accounts: if scope.includes_linked_accounts() {
+			identity.linked_accounts.clone()
+		} else {
+			BoundedVec::default()
+		},
+		also_known_as: if scope == IdentityScope::Full {
+			identity.also_known_as.clone()
+		} else {
+			BoundedVec::default()
+		},
+		web3_name_records: if scope.includes_web3_name() {
+			identity.web3_name_records.clone()
+		} else {
+			BoundedVec::default()
+		},
+	}
+}
+
 impl<Runtime, const MAX_LINKED_ACCOUNT: u32> IdentityCommitmentGenerator<Runtime> for DidMerkleRootGenerator<Runtime>
 where
 	Runtime: did::Config + pallet_did_lookup::Config + pallet_web3_names::Config + pallet_dip_provider::Config,
 	Runtime::IdentityProvider: IdentityProvider<Runtime, Success = LinkedDidInfoOf<Runtime, MAX_LINKED_ACCOUNT>>,
+	Runtime::Hash: From<[u8; 32]>,
 {
 	type Error = DidMerkleProofError;
 	type Output = Runtime::Hash;
@@ -103,9 +176,36 @@ where
 	) -> Result<Self::Output, Self::Error> {
 		match version {
 			0 => v0::generate_commitment::<Runtime, MAX_LINKED_ACCOUNT>(identity),
+			1 => v1::generate_commitment::<Runtime, MAX_LINKED_ACCOUNT>(identity),
+			2 => v2::generate_commitment::<Runtime, MAX_LINKED_ACCOUNT>(identity),
+			_ => Err(DidMerkleProofError::UnsupportedVersion),
+		}
+	}
+
+	fn generate_scoped_commitment(
+		identifier: &Runtime::Identifier,
+		identity: &IdentityOf<Runtime>,
+		version: IdentityCommitmentVersion,
+		scope: IdentityScope,
+	) -> Result<Self::Output, Self::Error> {
+		if scope == IdentityScope::Full {
+			return Self::generate_commitment(identifier, identity, version);
+		}
+		let scoped_identity = restrict_to_scope(identity, scope);
+		match version {
+			0 => v0::generate_commitment::<Runtime, MAX_LINKED_ACCOUNT>(&scoped_identity),
+			1 => v1::generate_commitment::<Runtime, MAX_LINKED_ACCOUNT>(&scoped_identity),
+			2 => v2::generate_scoped_commitment::<Runtime, MAX_LINKED_ACCOUNT>(&scoped_identity, scope),
 			_ => Err(DidMerkleProofError::UnsupportedVersion),
 		}
 	}
+
+	// The Poseidon hashes of the ZK-friendly commitment are more expensive than
+	// the ones of the trie-based versions.
+	#[cfg(feature = "runtime-benchmarks")]
+	fn worst_case_version() -> IdentityCommitmentVersion {
+		1
+	}
 }
 
 impl<Runtime> DidMerkleRootGenerator<Runtime>
@@ -125,6 +225,63 @@ where
 	{
 		match version {
 			0 => v0::generate_proof(identity, key_ids, should_include_web3_name, account_ids),
+			2 => v2::generate_proof(identity, key_ids, should_include_web3_name, account_ids),
+			// Membership proofs for the ZK-friendly commitment are generated off-chain by
+			// the prover.
+			_ => Err(DidMerkleProofError::UnsupportedVersion),
+		}
+	}
+
+	/// Same as [`Self::generate_proof`], for a commitment that was generated
+	/// with the provided scope. Requesting to reveal identity details outside
+	/// of the scope fails as if the subject did not have them.
+	///
+	/// If `should_include_also_known_as` is set, all the `alsoKnownAs` URIs
+	/// of the subject are revealed as well. They are only part of commitments
+	/// with the [`IdentityScope::Full`] scope. Similarly, if
+	/// `should_include_web3_name_records` is set, all the records of the
+	/// subject's web3name are revealed, as long as the scope includes the
+	/// web3name.
+	pub fn generate_scoped_proof<'a, K, A, const MAX_LINKED_ACCOUNT: u32>(
+		identity: &LinkedDidInfoOf<Runtime, MAX_LINKED_ACCOUNT>,
+		version: IdentityCommitmentVersion,
+		scope: IdentityScope,
+		key_ids: K,
+		should_include_web3_name: bool,
+		account_ids: A,
+		should_include_also_known_as: bool,
+		should_include_web3_name_records: bool,
+	) -> Result<CompleteMerkleProof<Runtime::Hash, DidMerkleProofOf<Runtime>>, DidMerkleProofError>
+	where
+		K: Iterator<Item = &'a KeyIdOf<Runtime>>,
+		A: Iterator<Item = &'a LinkableAccountId>,
+	{
+		let scoped_identity;
+		let identity = if scope == IdentityScope::Full {
+			identity
+		} else {
+			scoped_identity = restrict_to_scope(identity, scope);
+			&scoped_identity
+		};
+		match version {
+			0 => v0::do_generate_proof(
+				identity,
+				key_ids,
+				should_include_web3_name,
+				account_ids,
+				should_include_also_known_as,
+				should_include_web3_name_records,
+				None,
+			),
+			2 => v2::generate_scoped_proof(
+				identity,
+				scope,
+				key_ids,
+				should_include_web3_name,
+				account_ids,
+				should_include_also_known_as,
+				should_include_web3_name_records,
+			),
 			_ => Err(DidMerkleProofError::UnsupportedVersion),
 		}
 	}
@@ -18,12 +18,12 @@
 
 use did::did_details::DidVerificationKey;
 use frame_support::assert_err;
-use pallet_dip_provider::traits::IdentityCommitmentGenerator;
+use pallet_dip_provider::{traits::IdentityCommitmentGenerator, IdentityScope};
 
 use crate::{
 	constants::dip_provider::MAX_LINKED_ACCOUNTS,
 	dip::{
-		merkle::{DidMerkleProofError, DidMerkleRootGenerator},
+		merkle::{restrict_to_scope, DidMerkleProofError, DidMerkleRootGenerator},
 		mock::{create_linked_info, TestRuntime, ACCOUNT, DID_IDENTIFIER},
 	},
 };
@@ -36,7 +36,61 @@ fn generate_commitment_unsupported_version() {
 		MAX_LINKED_ACCOUNTS,
 	);
 	assert_err!(
-		DidMerkleRootGenerator::<TestRuntime>::generate_commitment(&DID_IDENTIFIER, &linked_info, 1,),
+		DidMerkleRootGenerator::<TestRuntime>::generate_commitment(&DID_IDENTIFIER, &linked_info, 3,),
 		DidMerkleProofError::UnsupportedVersion
 	);
 }
+
+#[test]
+fn generate_scoped_commitment_full_scope() {
+	let linked_info = create_linked_info(
+		DidVerificationKey::Account(ACCOUNT),
+		Some(b"ntn_x2"),
+		MAX_LINKED_ACCOUNTS,
+	);
+	for version in [0, 1, 2] {
+		assert_eq!(
+			DidMerkleRootGenerator::<TestRuntime>::generate_scoped_commitment(
+				&DID_IDENTIFIER,
+				&linked_info,
+				version,
+				IdentityScope::Full
+			),
+			DidMerkleRootGenerator::<TestRuntime>::generate_commitment(&DID_IDENTIFIER, &linked_info, version)
+		);
+	}
+}
+
+#[test]
+fn generate_scoped_commitment_excludes_out_of_scope_details() {
+	let linked_info = create_linked_info(
+		DidVerificationKey::Account(ACCOUNT),
+		Some(b"ntn_x2"),
+		MAX_LINKED_ACCOUNTS,
+	);
+	let keys_only_info = restrict_to_scope(&linked_info, IdentityScope::KeysOnly);
+	assert!(keys_only_info.web3_name_details.is_none());
+	assert!(keys_only_info.linked_accounts.is_empty());
+
+	// Without a manifest, a scoped commitment is the same as a commitment over
+	// the restricted identity.
+	assert_eq!(
+		DidMerkleRootGenerator::<TestRuntime>::generate_scoped_commitment(
+			&DID_IDENTIFIER,
+			&linked_info,
+			0,
+			IdentityScope::KeysOnly
+		),
+		DidMerkleRootGenerator::<TestRuntime>::generate_commitment(&DID_IDENTIFIER, &keys_only_info, 0)
+	);
+	// With a manifest, the scope is part of the commitment.
+	assert_ne!(
+		DidMerkleRootGenerator::<TestRuntime>::generate_scoped_commitment(
+			&DID_IDENTIFIER,
+			&linked_info,
+			2,
+			IdentityScope::KeysOnly
+		),
+		DidMerkleRootGenerator::<TestRuntime>::generate_commitment(&DID_IDENTIFIER, &keys_only_info, 2)
+	);
+}
@@ -18,6 +18,7 @@
 
 use did::did_details::DidVerificationKey;
 use frame_support::assert_err;
+use pallet_dip_provider::IdentityScope;
 
 use crate::{
 	constants::dip_provider::MAX_LINKED_ACCOUNTS,
@@ -39,3 +40,121 @@ fn generate_proof_unsupported_version() {
 		DidMerkleProofError::UnsupportedVersion
 	);
 }
+
+#[test]
+fn generate_scoped_proof_out_of_scope_details() {
+	let linked_info = create_linked_info(
+		DidVerificationKey::Account(ACCOUNT),
+		Some(b"ntn_x2"),
+		MAX_LINKED_ACCOUNTS,
+	);
+	assert_err!(
+		DidMerkleRootGenerator::<TestRuntime>::generate_scoped_proof(
+			&linked_info,
+			2,
+			IdentityScope::KeysOnly,
+			[].into_iter(),
+			true,
+			[].into_iter(),
+			false,
+			false
+		),
+		DidMerkleProofError::Web3NameNotFound
+	);
+	assert_err!(
+		DidMerkleRootGenerator::<TestRuntime>::generate_scoped_proof(
+			&linked_info,
+			2,
+			IdentityScope::KeysAndWeb3Name,
+			[].into_iter(),
+			false,
+			linked_info.linked_accounts.iter().take(1),
+			false,
+			false
+		),
+		DidMerkleProofError::LinkedAccountNotFound
+	);
+}
+
+#[test]
+fn generate_scoped_proof_also_known_as_not_found() {
+	let mut linked_info = create_linked_info(
+		DidVerificationKey::Account(ACCOUNT),
+		Some(b"ntn_x2"),
+		MAX_LINKED_ACCOUNTS,
+	);
+	assert_err!(
+		DidMerkleRootGenerator::<TestRuntime>::generate_scoped_proof(
+			&linked_info,
+			2,
+			IdentityScope::Full,
+			[].into_iter(),
+			false,
+			[].into_iter(),
+			true,
+			false
+		),
+		DidMerkleProofError::AlsoKnownAsNotFound
+	);
+
+	// URIs are only part of the full scope.
+	linked_info.also_known_as = vec![b"did:web:example.com".to_vec().try_into().unwrap()]
+		.try_into()
+		.unwrap();
+	assert_err!(
+		DidMerkleRootGenerator::<TestRuntime>::generate_scoped_proof(
+			&linked_info,
+			0,
+			IdentityScope::KeysAndWeb3Name,
+			[].into_iter(),
+			false,
+			[].into_iter(),
+			true,
+			false
+		),
+		DidMerkleProofError::AlsoKnownAsNotFound
+	);
+}
+
+#[test]
+fn generate_scoped_proof_web3_name_records_not_found() {
+	let mut linked_info = create_linked_info(
+		DidVerificationKey::Account(ACCOUNT),
+		Some(b"ntn_x2"),
+		MAX_LINKED_ACCOUNTS,
+	);
+	assert_err!(
+		DidMerkleRootGenerator::<TestRuntime>::generate_scoped_proof(
+			&linked_info,
+			2,
+			IdentityScope::Full,
+			[].into_iter(),
+			false,
+			[].into_iter(),
+			false,
+			true
+		),
+		DidMerkleProofError::Web3NameRecordsNotFound
+	);
+
+	// Records are only part of scopes that include the web3name.
+	linked_info.web3_name_records = vec![(
+		b"url".to_vec().try_into().unwrap(),
+		b"https://example.com".to_vec().try_into().unwrap(),
+	)]
+	.try_into()
+	.unwrap();
+	assert_err!(
+		DidMerkleRootGenerator::<TestRuntime>::generate_scoped_proof(
+			&linked_info,
+			0,
+			IdentityScope::KeysOnly,
+			[].into_iter(),
+			false,
+			[].into_iter(),
+			false,
+			true
+		),
+		DidMerkleProofError::Web3NameRecordsNotFound
+	);
+}
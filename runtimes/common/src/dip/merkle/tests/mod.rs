@@ -18,3 +18,4 @@
 
 mod generate_commitment;
 mod generate_proof;
+mod roundtrip;
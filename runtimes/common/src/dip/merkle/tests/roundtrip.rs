@@ -0,0 +1,374 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+//! Differential tests between the provider-side commitment and proof
+//! generation and the consumer-side proof verification.
+//!
+//! Identities are generated from deterministic seeds, so that any failing
+//! identity can be reproduced from the seed reported in the assertion message.
+
+use did::{
+	did_details::{DidEncryptionKey, DidVerificationKey},
+	mock_utils::generate_base_did_details,
+	DidVerificationKeyRelationship, KeyIdOf,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use kilt_dip_primitives::{
+	DidKeyRelationship, DidMerkleProof, DipDidProofWithVerifiedSubjectCommitment, RevealedAccountId,
+	RevealedCommitmentManifest, RevealedDidKey, RevealedDidMerkleProofLeaf, RevealedWeb3Name, TimeBoundDidSignature,
+};
+use pallet_did_lookup::{
+	account::AccountId20, bitcoin::BitcoinAccountId, linkable_account::LinkableAccountId, solana::SolanaAccountId,
+};
+use pallet_dip_provider::{traits::IdentityCommitmentGenerator, IdentityCommitmentVersion, IdentityScope};
+use pallet_web3_names::Web3NameOf;
+use parity_scale_codec::{Decode, Encode};
+use sp_core::{blake2_256, ed25519, sr25519, Get, Pair, H256};
+use sp_runtime::{AccountId32, BoundedVec};
+
+use crate::{
+	constants::{did::MAX_PUBLIC_KEYS_PER_DID, dip_provider::MAX_LINKED_ACCOUNTS, web3_names::MinNameLength},
+	dip::{
+		did::LinkedDidInfoOf,
+		merkle::{CompleteMerkleProof, DidMerkleRootGenerator},
+		mock::{TestRuntime, DID_IDENTIFIER, SUBMITTER},
+	},
+	AccountId, BlockNumber, Hasher,
+};
+
+// Enough for all the leaves of a V2 proof, including the manifest.
+const MAX_LEAVES_REVEALED: u32 = MAX_LINKED_ACCOUNTS + MAX_PUBLIC_KEYS_PER_DID + 2;
+// The commitment versions for which proofs can be generated on-chain.
+const PROOF_VERSIONS: [IdentityCommitmentVersion; 2] = [0, 2];
+// The number of random identities to test. Each identity has at most 9
+// leaves, which results in at most 512 proofs per identity and version.
+const IDENTITIES_COUNT: u64 = 16;
+
+// The proof type as decoded by a consumer chain.
+type ConsumerDidMerkleProof =
+	DidMerkleProof<KeyIdOf<TestRuntime>, AccountId, BlockNumber, Web3NameOf<TestRuntime>, LinkableAccountId>;
+type ConsumerLeaf = RevealedDidMerkleProofLeaf<
+	KeyIdOf<TestRuntime>,
+	AccountId,
+	BlockNumber,
+	Web3NameOf<TestRuntime>,
+	LinkableAccountId,
+>;
+
+/// Deterministic source of randomness derived from a seed.
+struct Entropy {
+	seed: u64,
+	counter: u64,
+}
+
+impl Entropy {
+	fn new(seed: u64) -> Self {
+		Self { seed, counter: 0 }
+	}
+
+	fn next_bytes(&mut self) -> [u8; 32] {
+		self.counter += 1;
+		blake2_256(&(self.seed, self.counter).encode())
+	}
+
+	fn next_below(&mut self, bound: u32) -> u32 {
+		let bytes = self.next_bytes();
+		u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) % bound
+	}
+
+	fn next_bool(&mut self) -> bool {
+		self.next_below(2) == 1
+	}
+}
+
+/// A leaf of the identity commitment that can be requested in a proof.
+#[derive(Clone)]
+enum RequestableLeaf {
+	DidKey(KeyIdOf<TestRuntime>),
+	Web3Name,
+	LinkedAccount(LinkableAccountId),
+}
+
+fn random_linked_account(entropy: &mut Entropy) -> LinkableAccountId {
+	let bytes = entropy.next_bytes();
+	match entropy.next_below(4) {
+		0 => {
+			let mut buffer = <[u8; 20]>::default();
+			buffer.copy_from_slice(&bytes[..20]);
+			LinkableAccountId::AccountId20(AccountId20(buffer))
+		}
+		1 => LinkableAccountId::AccountId32(AccountId32::new(bytes)),
+		2 => LinkableAccountId::Solana(SolanaAccountId(bytes)),
+		_ => {
+			let mut buffer = [0u8; 33];
+			buffer[1..].copy_from_slice(&bytes);
+			LinkableAccountId::Bitcoin(BitcoinAccountId(buffer))
+		}
+	}
+}
+
+fn random_web3_name(entropy: &mut Entropy) -> Web3NameOf<TestRuntime> {
+	let length = MinNameLength::get() + entropy.next_below(6);
+	let name = (0..length)
+		.map(|_| b'a' + entropy.next_below(26) as u8)
+		.collect::<Vec<_>>();
+	name.try_into().expect("Generated web3name should be valid.")
+}
+
+/// Generate a random identity with a random authentication key, optional
+/// attestation and delegation keys, up to two key agreement keys, an optional
+/// web3name and up to three linked accounts.
+fn random_linked_info(entropy: &mut Entropy) -> (ed25519::Pair, LinkedDidInfoOf<TestRuntime, MAX_LINKED_ACCOUNTS>) {
+	let auth_key = ed25519::Pair::from_seed(&entropy.next_bytes());
+	let block_number = BlockNumberFor::<TestRuntime>::from(entropy.next_below(1_000));
+	let mut did_details = generate_base_did_details(DidVerificationKey::Ed25519(auth_key.public()), Some(SUBMITTER));
+	if entropy.next_bool() {
+		did_details
+			.update_attestation_key(
+				DidVerificationKey::Sr25519(sr25519::Public(entropy.next_bytes())),
+				block_number,
+			)
+			.expect("Should not fail to add attestation key to DID.");
+	}
+	if entropy.next_bool() {
+		did_details
+			.update_delegation_key(
+				DidVerificationKey::Account(AccountId::new(entropy.next_bytes())),
+				block_number,
+			)
+			.expect("Should not fail to add delegation key to DID.");
+	}
+	(0..entropy.next_below(3)).for_each(|_| {
+		did_details
+			.add_key_agreement_key(DidEncryptionKey::X25519(entropy.next_bytes()), block_number)
+			.expect("Should not fail to add key agreement key to DID.");
+	});
+	let web3_name_details = if entropy.next_bool() {
+		Some(RevealedWeb3Name {
+			web3_name: random_web3_name(entropy),
+			claimed_at: block_number,
+		})
+	} else {
+		None
+	};
+	let linked_accounts: BoundedVec<_, _> = (0..entropy.next_below(4))
+		.map(|_| random_linked_account(entropy))
+		.collect::<Vec<_>>()
+		.try_into()
+		.expect("Generated linked accounts should not exceed the maximum.");
+	(
+		auth_key,
+		LinkedDidInfoOf {
+			did_details,
+			web3_name_details,
+			linked_accounts,
+			also_known_as: BoundedVec::default(),
+			web3_name_records: BoundedVec::default(),
+		},
+	)
+}
+
+/// All the leaves that can be requested in a proof for the given identity.
+fn requestable_leaves(linked_info: &LinkedDidInfoOf<TestRuntime, MAX_LINKED_ACCOUNTS>) -> Vec<RequestableLeaf> {
+	let did_keys = linked_info
+		.did_details
+		.public_keys
+		.keys()
+		.cloned()
+		.map(RequestableLeaf::DidKey);
+	let web3_name = linked_info
+		.web3_name_details
+		.as_ref()
+		.map(|_| RequestableLeaf::Web3Name);
+	let linked_accounts = linked_info
+		.linked_accounts
+		.iter()
+		.cloned()
+		.map(RequestableLeaf::LinkedAccount);
+	did_keys.chain(web3_name).chain(linked_accounts).collect()
+}
+
+/// The leaves a consumer is expected to see when the provided leaves are
+/// requested, according to the provider's identity information.
+fn expected_leaves(
+	linked_info: &LinkedDidInfoOf<TestRuntime, MAX_LINKED_ACCOUNTS>,
+	requested_leaves: &[RequestableLeaf],
+) -> Vec<ConsumerLeaf> {
+	let did_details = &linked_info.did_details;
+	requested_leaves
+		.iter()
+		.flat_map(|leaf| -> Vec<ConsumerLeaf> {
+			match leaf {
+				RequestableLeaf::DidKey(key_id) => {
+					let details = did_details.public_keys.get(key_id).unwrap().clone();
+					let mut relationships = Vec::<DidKeyRelationship>::new();
+					if did_details.key_agreement_keys.contains(key_id) {
+						relationships.push(DidKeyRelationship::Encryption);
+					}
+					if did_details.authentication_key == *key_id {
+						relationships.push(DidVerificationKeyRelationship::Authentication.into());
+					}
+					if did_details.attestation_key == Some(*key_id) {
+						relationships.push(DidVerificationKeyRelationship::AssertionMethod.into());
+					}
+					if did_details.delegation_key == Some(*key_id) {
+						relationships.push(DidVerificationKeyRelationship::CapabilityDelegation.into());
+					}
+					relationships
+						.into_iter()
+						.map(|relationship| {
+							RevealedDidKey {
+								id: *key_id,
+								relationship,
+								details: details.clone(),
+							}
+							.into()
+						})
+						.collect()
+				}
+				RequestableLeaf::Web3Name => vec![linked_info.web3_name_details.clone().unwrap().into()],
+				RequestableLeaf::LinkedAccount(account) => vec![RevealedAccountId(account.clone()).into()],
+			}
+		})
+		.collect()
+}
+
+/// The manifest committed for the whole identity.
+fn full_manifest(linked_info: &LinkedDidInfoOf<TestRuntime, MAX_LINKED_ACCOUNTS>) -> RevealedCommitmentManifest {
+	let did_details = &linked_info.did_details;
+	let did_keys = 1
+		+ u32::from(did_details.attestation_key.is_some())
+		+ u32::from(did_details.delegation_key.is_some())
+		+ did_details.key_agreement_keys.len() as u32;
+	RevealedCommitmentManifest {
+		did_keys,
+		linked_accounts: linked_info.linked_accounts.len() as u32,
+		web3_name: linked_info.web3_name_details.is_some(),
+		scope: IdentityScope::Full,
+	}
+}
+
+// Generates a proof on the provider side for the requested leaves, sends it
+// over the wire, and verifies it on the consumer side, checking that exactly
+// the requested leaves are revealed.
+fn assert_round_trip(
+	seed: u64,
+	version: IdentityCommitmentVersion,
+	auth_key: &ed25519::Pair,
+	linked_info: &LinkedDidInfoOf<TestRuntime, MAX_LINKED_ACCOUNTS>,
+	requested_leaves: &[RequestableLeaf],
+) {
+	let key_ids = requested_leaves
+		.iter()
+		.filter_map(|leaf| match leaf {
+			RequestableLeaf::DidKey(key_id) => Some(key_id),
+			_ => None,
+		})
+		.collect::<Vec<_>>();
+	let should_include_web3_name = requested_leaves
+		.iter()
+		.any(|leaf| matches!(leaf, RequestableLeaf::Web3Name));
+	let account_ids = requested_leaves
+		.iter()
+		.filter_map(|leaf| match leaf {
+			RequestableLeaf::LinkedAccount(account) => Some(account),
+			_ => None,
+		})
+		.collect::<Vec<_>>();
+
+	let commitment = DidMerkleRootGenerator::<TestRuntime>::generate_commitment(&DID_IDENTIFIER, linked_info, version)
+		.unwrap_or_else(|e| panic!("Seed {seed}, version {version}: failed to generate commitment: {e:?}."));
+	let CompleteMerkleProof { root, proof } = DidMerkleRootGenerator::<TestRuntime>::generate_proof(
+		linked_info,
+		version,
+		key_ids.into_iter(),
+		should_include_web3_name,
+		account_ids.into_iter(),
+	)
+	.unwrap_or_else(|e| panic!("Seed {seed}, version {version}: failed to generate proof: {e:?}."));
+	assert_eq!(
+		root, commitment,
+		"Seed {seed}, version {version}: proof root differs from the identity commitment."
+	);
+
+	// The consumer only sees the encoded commitment and proof.
+	let consumer_root = H256::decode(&mut &commitment.encode()[..])
+		.unwrap_or_else(|e| panic!("Seed {seed}, version {version}: failed to decode commitment: {e:?}."));
+	let consumer_proof = ConsumerDidMerkleProof::decode(&mut &proof.encode()[..])
+		.unwrap_or_else(|e| panic!("Seed {seed}, version {version}: failed to decode proof: {e:?}."));
+	assert_eq!(
+		consumer_proof, proof,
+		"Seed {seed}, version {version}: decoded proof differs from the generated one."
+	);
+	assert!(
+		consumer_proof.verify_against_root::<Hasher>(&consumer_root).is_ok(),
+		"Seed {seed}, version {version}: proof does not verify against the commitment."
+	);
+
+	let signature = auth_key.sign(&().encode());
+	let revealed_details = DipDidProofWithVerifiedSubjectCommitment::new(
+		consumer_root,
+		consumer_proof,
+		TimeBoundDidSignature::<BlockNumber>::new(signature.into(), 100),
+	)
+	.verify_dip_proof::<Hasher, MAX_LEAVES_REVEALED>()
+	.unwrap_or_else(|e| panic!("Seed {seed}, version {version}: failed to verify proof: {e:?}."));
+
+	let revealed_leaves = revealed_details
+		.iter_leaves()
+		.filter(|leaf| !matches!(leaf, RevealedDidMerkleProofLeaf::Manifest(_)))
+		.collect::<Vec<_>>();
+	let expected_leaves = expected_leaves(linked_info, requested_leaves);
+	assert_eq!(
+		revealed_leaves.len(),
+		expected_leaves.len(),
+		"Seed {seed}, version {version}: unexpected number of revealed leaves."
+	);
+	assert!(
+		expected_leaves.iter().all(|leaf| revealed_leaves.contains(&leaf)),
+		"Seed {seed}, version {version}: revealed leaves differ from the requested ones."
+	);
+
+	match version {
+		0 => assert_eq!(revealed_details.manifest(), None),
+		_ => assert_eq!(
+			revealed_details.manifest(),
+			Some(&full_manifest(linked_info)),
+			"Seed {seed}, version {version}: revealed manifest differs from the identity."
+		),
+	}
+}
+
+#[test]
+fn provider_proofs_verify_on_consumer_for_all_revealed_subsets() {
+	for seed in 0..IDENTITIES_COUNT {
+		let mut entropy = Entropy::new(seed);
+		let (auth_key, linked_info) = random_linked_info(&mut entropy);
+		let leaves = requestable_leaves(&linked_info);
+		for version in PROOF_VERSIONS {
+			for subset in 0..(1u32 << leaves.len()) {
+				let requested_leaves = leaves
+					.iter()
+					.enumerate()
+					.filter(|(i, _)| subset & (1 << i) != 0)
+					.map(|(_, leaf)| leaf.clone())
+					.collect::<Vec<_>>();
+				assert_round_trip(seed, version, &auth_key, &linked_info, &requested_leaves);
+			}
+		}
+	}
+}
@@ -16,19 +16,21 @@
 
 // If you feel like getting in touch with us, you can do so at info@botlabs.org
 
-use did::{did_details::DidDetails, DidVerificationKeyRelationship, KeyIdOf};
+use did::{did_details::DidDetails, AlsoKnownAsOf, DidVerificationKeyRelationship, KeyIdOf};
+use frame_support::ensure;
 use frame_system::pallet_prelude::BlockNumberFor;
 use kilt_dip_primitives::{
-	DidKeyRelationship, RevealedAccountId, RevealedDidKey, RevealedDidMerkleProofLeaf, RevealedWeb3Name,
+	DidKeyRelationship, RevealedAccountId, RevealedAlsoKnownAs, RevealedCommitmentManifest, RevealedDidKey,
+	RevealedDidMerkleProofLeaf, RevealedWeb3Name, RevealedWeb3NameRecord,
 };
 use pallet_did_lookup::linkable_account::LinkableAccountId;
-use pallet_dip_provider::{traits::IdentityProvider, IdentityOf};
+use pallet_dip_provider::{traits::IdentityProvider, IdentityOf, IdentityScope};
 use pallet_web3_names::Web3NameOf;
 use sp_std::{prelude::ToOwned, vec, vec::Vec};
 use sp_trie::{generate_trie_proof, LayoutV1, MemoryDB, TrieDBMutBuilder, TrieHash, TrieMut};
 
 use crate::dip::{
-	did::{LinkedDidInfoOf, Web3OwnershipOf},
+	did::{LinkedDidInfoOf, Web3NameRecordsOf, Web3OwnershipOf},
 	merkle::{CompleteMerkleProof, DidMerkleProofError, DidMerkleProofOf},
 };
 
@@ -152,12 +154,68 @@ where
 	}
 }
 
-/// Given the provided DID info, it calculates the Merkle commitment (root)
-/// using the provided in-memory DB.
-pub(super) fn calculate_root_with_db<Runtime, const MAX_LINKED_ACCOUNT: u32>(
+fn get_also_known_as_leaves<Runtime>(
+	also_known_as: &AlsoKnownAsOf<Runtime>,
+) -> Result<impl Iterator<Item = RevealedAlsoKnownAs>, DidMerkleProofError>
+where
+	Runtime: did::Config,
+{
+	let uris = also_known_as
+		.iter()
+		.map(|uri| {
+			let uri = uri.to_vec().try_into().map_err(|_| {
+				log::error!("The alsoKnownAs URI {:#?} is too long to be part of a DIP leaf.", uri);
+				DidMerkleProofError::Internal
+			})?;
+			Ok(RevealedAlsoKnownAs(uri))
+		})
+		.collect::<Result<Vec<_>, _>>()?;
+	Ok(uris.into_iter())
+}
+
+fn get_web3_name_record_leaves<Runtime>(
+	web3_name_records: &Web3NameRecordsOf<Runtime>,
+) -> Result<impl Iterator<Item = RevealedWeb3NameRecord>, DidMerkleProofError>
+where
+	Runtime: pallet_web3_names::Config,
+{
+	let records = web3_name_records
+		.iter()
+		.map(|(key, value)| {
+			let key = key.to_vec().try_into().map_err(|_| {
+				log::error!(
+					"The web3name record key {:#?} is too long to be part of a DIP leaf.",
+					key
+				);
+				DidMerkleProofError::Internal
+			})?;
+			let value = value.to_vec().try_into().map_err(|_| {
+				log::error!(
+					"The web3name record value {:#?} is too long to be part of a DIP leaf.",
+					value
+				);
+				DidMerkleProofError::Internal
+			})?;
+			Ok(RevealedWeb3NameRecord { key, value })
+		})
+		.collect::<Result<Vec<_>, _>>()?;
+	Ok(records.into_iter())
+}
+
+/// Type of a leaf of the DIP identity commitment.
+pub(super) type DidMerkleLeafOf<Runtime> = RevealedDidMerkleProofLeaf<
+	KeyIdOf<Runtime>,
+	<Runtime as frame_system::Config>::AccountId,
+	BlockNumberFor<Runtime>,
+	Web3NameOf<Runtime>,
+	LinkableAccountId,
+>;
+
+/// Given the provided DID info, it returns all the leaves that are part of
+/// the identity commitment.
+pub(super) fn get_identity_leaves<Runtime, const MAX_LINKED_ACCOUNT: u32>(
 	identity: &LinkedDidInfoOf<Runtime, MAX_LINKED_ACCOUNT>,
-	db: &mut MemoryDB<Runtime::Hashing>,
-) -> Result<Runtime::Hash, DidMerkleProofError>
+) -> Result<impl Iterator<Item = DidMerkleLeafOf<Runtime>> + '_, DidMerkleProofError>
 where
 	Runtime: did::Config + pallet_did_lookup::Config + pallet_web3_names::Config,
 {
@@ -165,9 +223,9 @@ where
 		did_details,
 		web3_name_details,
 		linked_accounts,
+		also_known_as,
+		web3_name_records,
 	} = identity;
-	let mut trie = TrieHash::<LayoutV1<Runtime::Hashing>>::default();
-	let mut trie_builder = TrieDBMutBuilder::<LayoutV1<Runtime::Hashing>>::new(db, &mut trie).build();
 
 	// Authentication key.
 	let auth_leaves = get_auth_leaves(did_details)?;
@@ -181,8 +239,11 @@ where
 	let linked_accounts = get_linked_account_leaves(linked_accounts);
 	// Web3name.
 	let web3_name = web3_name_details.as_ref().map(get_web3name_leaf::<Runtime>);
+	// `alsoKnownAs` URIs.
+	let also_known_as = get_also_known_as_leaves::<Runtime>(also_known_as)?;
+	// Web3name records.
+	let web3_name_records = get_web3_name_record_leaves::<Runtime>(web3_name_records)?;
 
-	// Add all leaves to the proof builder.
 	let keys = auth_leaves
 		.chain(att_leaves)
 		.chain(del_leaves)
@@ -194,16 +255,81 @@ where
 		.unwrap_or_default()
 		.into_iter()
 		.map(RevealedDidMerkleProofLeaf::from);
+	let also_known_as = also_known_as.map(RevealedDidMerkleProofLeaf::from);
+	let web3_name_records = web3_name_records.map(RevealedDidMerkleProofLeaf::from);
 
-	keys.chain(linked_accounts).chain(web3_names).try_for_each(|leaf| {
-		trie_builder
-			.insert(leaf.encoded_key().as_slice(), leaf.encoded_value().as_slice())
-			.map_err(|_| {
-				log::error!("Failed to insert leaf in the trie builder. Leaf: {:#?}", leaf);
-				DidMerkleProofError::Internal
-			})?;
-		Ok(())
-	})?;
+	Ok(keys
+		.chain(linked_accounts)
+		.chain(web3_names)
+		.chain(also_known_as)
+		.chain(web3_name_records))
+}
+
+/// Given the provided DID info, it returns the manifest leaf listing how many
+/// leaves of each type are part of the identity commitment, and the scope of
+/// the commitment.
+pub(super) fn get_manifest_leaf<Runtime, const MAX_LINKED_ACCOUNT: u32>(
+	identity: &LinkedDidInfoOf<Runtime, MAX_LINKED_ACCOUNT>,
+	scope: IdentityScope,
+) -> Result<DidMerkleLeafOf<Runtime>, DidMerkleProofError>
+where
+	Runtime: did::Config + pallet_did_lookup::Config + pallet_web3_names::Config,
+{
+	let initial_manifest = RevealedCommitmentManifest {
+		scope,
+		..Default::default()
+	};
+	let manifest = get_identity_leaves(identity)?.fold(initial_manifest, |manifest, leaf| match leaf {
+		RevealedDidMerkleProofLeaf::DidKey(_) => RevealedCommitmentManifest {
+			did_keys: manifest.did_keys.saturating_add(1),
+			..manifest
+		},
+		RevealedDidMerkleProofLeaf::LinkedAccount(_) => RevealedCommitmentManifest {
+			linked_accounts: manifest.linked_accounts.saturating_add(1),
+			..manifest
+		},
+		RevealedDidMerkleProofLeaf::Web3Name(_) => RevealedCommitmentManifest {
+			web3_name: true,
+			..manifest
+		},
+		// `alsoKnownAs` and web3name record leaves are not counted in the manifest.
+		RevealedDidMerkleProofLeaf::Manifest(_)
+		| RevealedDidMerkleProofLeaf::AlsoKnownAs(_)
+		| RevealedDidMerkleProofLeaf::Web3NameRecord(_) => manifest,
+	});
+	Ok(manifest.into())
+}
+
+/// Given the provided DID info, it calculates the Merkle commitment (root)
+/// using the provided in-memory DB. If a `manifest_scope` is provided, the
+/// manifest leaf with that scope is part of the commitment.
+pub(super) fn calculate_root_with_db<Runtime, const MAX_LINKED_ACCOUNT: u32>(
+	identity: &LinkedDidInfoOf<Runtime, MAX_LINKED_ACCOUNT>,
+	db: &mut MemoryDB<Runtime::Hashing>,
+	manifest_scope: Option<IdentityScope>,
+) -> Result<Runtime::Hash, DidMerkleProofError>
+where
+	Runtime: did::Config + pallet_did_lookup::Config + pallet_web3_names::Config,
+{
+	let mut trie = TrieHash::<LayoutV1<Runtime::Hashing>>::default();
+	let mut trie_builder = TrieDBMutBuilder::<LayoutV1<Runtime::Hashing>>::new(db, &mut trie).build();
+
+	let manifest_leaf = manifest_scope
+		.map(|scope| get_manifest_leaf(identity, scope))
+		.transpose()?;
+
+	// Add all leaves to the proof builder.
+	get_identity_leaves(identity)?
+		.chain(manifest_leaf)
+		.try_for_each(|leaf| {
+			trie_builder
+				.insert(leaf.encoded_key().as_slice(), leaf.encoded_value().as_slice())
+				.map_err(|_| {
+					log::error!("Failed to insert leaf in the trie builder. Leaf: {:#?}", leaf);
+					DidMerkleProofError::Internal
+				})?;
+			Ok(())
+		})?;
 
 	trie_builder.commit();
 	Ok(trie_builder.root().to_owned())
@@ -220,6 +346,37 @@ pub(super) fn generate_proof<'a, Runtime, K, A, const MAX_LINKED_ACCOUNT: u32>(
 	should_include_web3_name: bool,
 	account_ids: A,
 ) -> Result<CompleteMerkleProof<Runtime::Hash, DidMerkleProofOf<Runtime>>, DidMerkleProofError>
+where
+	Runtime: did::Config + pallet_did_lookup::Config + pallet_web3_names::Config,
+	K: Iterator<Item = &'a KeyIdOf<Runtime>>,
+	A: Iterator<Item = &'a LinkableAccountId>,
+{
+	do_generate_proof(
+		identity,
+		key_ids,
+		should_include_web3_name,
+		account_ids,
+		false,
+		false,
+		None,
+	)
+}
+
+/// Same as [`generate_proof`], but if `should_include_also_known_as` is set
+/// all the `alsoKnownAs` URIs of the identity are revealed, failing if it has
+/// none, and likewise for the web3name records if
+/// `should_include_web3_name_records` is set. If a `manifest_scope` is
+/// provided the proof is generated over a commitment that includes the
+/// manifest leaf with that scope, which is always revealed.
+pub(super) fn do_generate_proof<'a, Runtime, K, A, const MAX_LINKED_ACCOUNT: u32>(
+	identity: &LinkedDidInfoOf<Runtime, MAX_LINKED_ACCOUNT>,
+	key_ids: K,
+	should_include_web3_name: bool,
+	account_ids: A,
+	should_include_also_known_as: bool,
+	should_include_web3_name_records: bool,
+	manifest_scope: Option<IdentityScope>,
+) -> Result<CompleteMerkleProof<Runtime::Hash, DidMerkleProofOf<Runtime>>, DidMerkleProofError>
 where
 	Runtime: did::Config + pallet_did_lookup::Config + pallet_web3_names::Config,
 	K: Iterator<Item = &'a KeyIdOf<Runtime>>,
@@ -229,10 +386,12 @@ where
 		did_details,
 		web3_name_details,
 		linked_accounts,
+		also_known_as,
+		web3_name_records,
 	} = identity;
 
 	let mut db = MemoryDB::default();
-	let root = calculate_root_with_db(identity, &mut db)?;
+	let root = calculate_root_with_db(identity, &mut db, manifest_scope)?;
 
 	let did_key_leaves_iter = key_ids.map(|key_id| -> Result<_, DidMerkleProofError> {
 		let key_details = did_details
@@ -302,6 +461,31 @@ where
 		(false, _) => {}
 	};
 
+	if should_include_also_known_as {
+		ensure!(!also_known_as.is_empty(), DidMerkleProofError::AlsoKnownAsNotFound);
+		leaves.push(
+			get_also_known_as_leaves::<Runtime>(also_known_as)?
+				.map(RevealedDidMerkleProofLeaf::from)
+				.collect(),
+		);
+	}
+
+	if should_include_web3_name_records {
+		ensure!(
+			!web3_name_records.is_empty(),
+			DidMerkleProofError::Web3NameRecordsNotFound
+		);
+		leaves.push(
+			get_web3_name_record_leaves::<Runtime>(web3_name_records)?
+				.map(RevealedDidMerkleProofLeaf::from)
+				.collect(),
+		);
+	}
+
+	if let Some(scope) = manifest_scope {
+		leaves.push(vec![get_manifest_leaf(identity, scope)?]);
+	}
+
 	let encoded_keys: Vec<Vec<u8>> = leaves.iter().flatten().map(|l| l.encoded_key()).collect();
 	let proof = generate_trie_proof::<LayoutV1<Runtime::Hashing>, _, _, _>(&db, root, &encoded_keys).map_err(|_| {
 		log::error!(
@@ -329,5 +513,5 @@ where
 	Runtime::IdentityProvider: IdentityProvider<Runtime, Success = LinkedDidInfoOf<Runtime, MAX_LINKED_ACCOUNT>>,
 {
 	let mut db = MemoryDB::default();
-	calculate_root_with_db(identity, &mut db)
+	calculate_root_with_db(identity, &mut db, None)
 }
@@ -0,0 +1,128 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use ark_bn254::Fr;
+use ark_crypto_primitives::sponge::{
+	poseidon::{PoseidonConfig, PoseidonSponge},
+	CryptographicSponge,
+};
+use ark_ff::{BigInteger, PrimeField, Zero};
+use frame_support::ensure;
+use kilt_dip_primitives::zk::{ZkLeaf, ZK_COMMITMENT_TREE_DEPTH};
+use pallet_dip_provider::{traits::IdentityProvider, IdentityOf};
+use sp_std::vec::Vec;
+
+use crate::dip::{
+	did::LinkedDidInfoOf,
+	merkle::{
+		v0::get_identity_leaves,
+		v1::constants::{POSEIDON_ARK, POSEIDON_MDS},
+		DidMerkleProofError,
+	},
+};
+
+mod constants;
+
+#[cfg(test)]
+mod tests;
+
+// Parameters for a Poseidon permutation of width 3 (rate 2, capacity 1) over
+// the BN254 scalar field, with the x^5 S-box.
+const POSEIDON_RATE: usize = 2;
+const POSEIDON_CAPACITY: usize = 1;
+const POSEIDON_FULL_ROUNDS: usize = 8;
+const POSEIDON_PARTIAL_ROUNDS: usize = 57;
+const POSEIDON_ALPHA: u64 = 5;
+
+/// Poseidon hasher over the BN254 scalar field.
+struct Poseidon(PoseidonConfig<Fr>);
+
+impl Poseidon {
+	fn new() -> Self {
+		Self(PoseidonConfig::new(
+			POSEIDON_FULL_ROUNDS,
+			POSEIDON_PARTIAL_ROUNDS,
+			POSEIDON_ALPHA,
+			POSEIDON_MDS.iter().map(|row| row.to_vec()).collect(),
+			POSEIDON_ARK.iter().map(|row| row.to_vec()).collect(),
+			POSEIDON_RATE,
+			POSEIDON_CAPACITY,
+		))
+	}
+
+	fn hash(&self, inputs: &[Fr]) -> Fr {
+		let mut sponge = PoseidonSponge::new(&self.0);
+		sponge.absorb(&inputs);
+		sponge.squeeze_field_elements::<Fr>(1)[0]
+	}
+
+	fn hash_leaf(&self, leaf: &ZkLeaf) -> Fr {
+		let elements = leaf
+			.field_elements()
+			.iter()
+			.map(|element| Fr::from_be_bytes_mod_order(element))
+			.collect::<Vec<_>>();
+		self.hash(&elements)
+	}
+}
+
+/// Given the provided DID info, it calculates the root of the fixed-depth
+/// Poseidon Merkle tree whose leaves are the ZK-encoded identity leaves, in the
+/// same order as the V0 commitment. Missing leaves are filled with zeros.
+pub(super) fn calculate_root<Runtime, const MAX_LINKED_ACCOUNT: u32>(
+	identity: &LinkedDidInfoOf<Runtime, MAX_LINKED_ACCOUNT>,
+) -> Result<[u8; 32], DidMerkleProofError>
+where
+	Runtime: did::Config + pallet_did_lookup::Config + pallet_web3_names::Config,
+{
+	let hasher = Poseidon::new();
+	let mut level = get_identity_leaves(identity)?
+		.map(|leaf| hasher.hash_leaf(&ZkLeaf::from(&leaf)))
+		.collect::<Vec<_>>();
+	ensure!(
+		level.len() <= 1usize << ZK_COMMITMENT_TREE_DEPTH,
+		DidMerkleProofError::TooManyLeaves
+	);
+
+	let mut empty_node = Fr::zero();
+	for _ in 0..ZK_COMMITMENT_TREE_DEPTH {
+		if level.len() % 2 == 1 {
+			level.push(empty_node);
+		}
+		level = level.chunks(2).map(|pair| hasher.hash(pair)).collect();
+		empty_node = hasher.hash(&[empty_node, empty_node]);
+	}
+	let root = level.first().copied().unwrap_or(empty_node);
+
+	root.into_bigint().to_bytes_be().try_into().map_err(|_| {
+		log::error!("Failed to convert Poseidon root into a 32-byte array.");
+		DidMerkleProofError::Internal
+	})
+}
+
+/// Given the provided DID info, generates a ZK-friendly commitment (root).
+pub(super) fn generate_commitment<Runtime, const MAX_LINKED_ACCOUNT: u32>(
+	identity: &IdentityOf<Runtime>,
+) -> Result<Runtime::Hash, DidMerkleProofError>
+where
+	Runtime: did::Config + pallet_did_lookup::Config + pallet_web3_names::Config + pallet_dip_provider::Config,
+	Runtime::IdentityProvider: IdentityProvider<Runtime, Success = LinkedDidInfoOf<Runtime, MAX_LINKED_ACCOUNT>>,
+	Runtime::Hash: From<[u8; 32]>,
+{
+	calculate_root(identity).map(Runtime::Hash::from)
+}
@@ -0,0 +1,66 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use did::did_details::DidVerificationKey;
+
+use crate::{
+	constants::dip_provider::MAX_LINKED_ACCOUNTS,
+	dip::{
+		merkle::{v0, v1::generate_commitment},
+		mock::{create_linked_info, TestRuntime, ACCOUNT},
+	},
+};
+
+#[test]
+fn generate_commitment_for_complete_info() {
+	let linked_info = create_linked_info(
+		DidVerificationKey::Account(ACCOUNT),
+		Some(b"ntn_x2"),
+		MAX_LINKED_ACCOUNTS,
+	);
+	let commitment_result = generate_commitment::<TestRuntime, MAX_LINKED_ACCOUNTS>(&linked_info);
+	assert!(commitment_result.is_ok());
+}
+
+#[test]
+fn generate_commitment_is_deterministic() {
+	let linked_info = create_linked_info(DidVerificationKey::Account(ACCOUNT), Some(b"ntn_x2"), 1);
+	assert_eq!(
+		generate_commitment::<TestRuntime, MAX_LINKED_ACCOUNTS>(&linked_info),
+		generate_commitment::<TestRuntime, MAX_LINKED_ACCOUNTS>(&linked_info)
+	);
+}
+
+#[test]
+fn generate_commitment_changes_with_identity() {
+	let without_web3_name = create_linked_info(DidVerificationKey::Account(ACCOUNT), Option::<Vec<u8>>::None, 0);
+	let with_web3_name = create_linked_info(DidVerificationKey::Account(ACCOUNT), Some(b"ntn_x2"), 0);
+	assert_ne!(
+		generate_commitment::<TestRuntime, MAX_LINKED_ACCOUNTS>(&without_web3_name).unwrap(),
+		generate_commitment::<TestRuntime, MAX_LINKED_ACCOUNTS>(&with_web3_name).unwrap()
+	);
+}
+
+#[test]
+fn generate_commitment_differs_from_v0() {
+	let linked_info = create_linked_info(DidVerificationKey::Account(ACCOUNT), Some(b"ntn_x2"), 0);
+	assert_ne!(
+		generate_commitment::<TestRuntime, MAX_LINKED_ACCOUNTS>(&linked_info).unwrap(),
+		v0::generate_commitment::<TestRuntime, MAX_LINKED_ACCOUNTS>(&linked_info).unwrap()
+	);
+}
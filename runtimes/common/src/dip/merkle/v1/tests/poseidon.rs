@@ -0,0 +1,38 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+use ark_bn254::Fr;
+use ark_crypto_primitives::sponge::poseidon::find_poseidon_ark_and_mds;
+use ark_ff::PrimeField;
+
+use crate::dip::merkle::v1::{
+	constants::{POSEIDON_ARK, POSEIDON_MDS},
+	POSEIDON_FULL_ROUNDS, POSEIDON_PARTIAL_ROUNDS, POSEIDON_RATE,
+};
+
+#[test]
+fn precomputed_poseidon_parameters() {
+	let (ark, mds) = find_poseidon_ark_and_mds::<Fr>(
+		Fr::MODULUS_BIT_SIZE as u64,
+		POSEIDON_RATE,
+		POSEIDON_FULL_ROUNDS as u64,
+		POSEIDON_PARTIAL_ROUNDS as u64,
+		0,
+	);
+	assert_eq!(ark, POSEIDON_ARK.iter().map(|row| row.to_vec()).collect::<Vec<_>>());
+	assert_eq!(mds, POSEIDON_MDS.iter().map(|row| row.to_vec()).collect::<Vec<_>>());
+}
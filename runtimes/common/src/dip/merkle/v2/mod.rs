@@ -0,0 +1,116 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use did::KeyIdOf;
+use pallet_did_lookup::linkable_account::LinkableAccountId;
+use pallet_dip_provider::{traits::IdentityProvider, IdentityOf, IdentityScope};
+use sp_trie::MemoryDB;
+
+use crate::dip::{
+	did::LinkedDidInfoOf,
+	merkle::{
+		v0::{calculate_root_with_db, do_generate_proof},
+		CompleteMerkleProof, DidMerkleProofError, DidMerkleProofOf,
+	},
+};
+
+#[cfg(test)]
+mod tests;
+
+/// Given the provided DID info, and a set of DID key IDs, account IDs and a
+/// web3name, generates a Merkle proof that reveals only the provided
+/// identity components, plus the commitment manifest.
+pub(super) fn generate_proof<'a, Runtime, K, A, const MAX_LINKED_ACCOUNT: u32>(
+	identity: &LinkedDidInfoOf<Runtime, MAX_LINKED_ACCOUNT>,
+	key_ids: K,
+	should_include_web3_name: bool,
+	account_ids: A,
+) -> Result<CompleteMerkleProof<Runtime::Hash, DidMerkleProofOf<Runtime>>, DidMerkleProofError>
+where
+	Runtime: did::Config + pallet_did_lookup::Config + pallet_web3_names::Config,
+	K: Iterator<Item = &'a KeyIdOf<Runtime>>,
+	A: Iterator<Item = &'a LinkableAccountId>,
+{
+	generate_scoped_proof(
+		identity,
+		IdentityScope::Full,
+		key_ids,
+		should_include_web3_name,
+		account_ids,
+		false,
+		false,
+	)
+}
+
+/// Same as [`generate_proof`], for a commitment generated with
+/// [`generate_scoped_commitment`]. The provided identity must already be
+/// restricted to the given scope. If `should_include_also_known_as` is set,
+/// all the `alsoKnownAs` URIs of the identity are revealed as well, and
+/// likewise for the web3name records if `should_include_web3_name_records` is
+/// set.
+pub(super) fn generate_scoped_proof<'a, Runtime, K, A, const MAX_LINKED_ACCOUNT: u32>(
+	identity: &LinkedDidInfoOf<Runtime, MAX_LINKED_ACCOUNT>,
+	scope: IdentityScope,
+	key_ids: K,
+	should_include_web3_name: bool,
+	account_ids: A,
+	should_include_also_known_as: bool,
+	should_include_web3_name_records: bool,
+) -> Result<CompleteMerkleProof<Runtime::Hash, DidMerkleProofOf<Runtime>>, DidMerkleProofError>
+where
+	Runtime: did::Config + pallet_did_lookup::Config + pallet_web3_names::Config,
+	K: Iterator<Item = &'a KeyIdOf<Runtime>>,
+	A: Iterator<Item = &'a LinkableAccountId>,
+{
+	do_generate_proof(
+		identity,
+		key_ids,
+		should_include_web3_name,
+		account_ids,
+		should_include_also_known_as,
+		should_include_web3_name_records,
+		Some(scope),
+	)
+}
+
+/// Given the provided DID info, generates a Merkle commitment (root) that
+/// includes the commitment manifest.
+pub(super) fn generate_commitment<Runtime, const MAX_LINKED_ACCOUNT: u32>(
+	identity: &IdentityOf<Runtime>,
+) -> Result<Runtime::Hash, DidMerkleProofError>
+where
+	Runtime: did::Config + pallet_did_lookup::Config + pallet_web3_names::Config + pallet_dip_provider::Config,
+	Runtime::IdentityProvider: IdentityProvider<Runtime, Success = LinkedDidInfoOf<Runtime, MAX_LINKED_ACCOUNT>>,
+{
+	generate_scoped_commitment::<Runtime, MAX_LINKED_ACCOUNT>(identity, IdentityScope::Full)
+}
+
+/// Same as [`generate_commitment`], but the manifest records the provided
+/// scope. The provided identity must already be restricted to the given
+/// scope.
+pub(super) fn generate_scoped_commitment<Runtime, const MAX_LINKED_ACCOUNT: u32>(
+	identity: &IdentityOf<Runtime>,
+	scope: IdentityScope,
+) -> Result<Runtime::Hash, DidMerkleProofError>
+where
+	Runtime: did::Config + pallet_did_lookup::Config + pallet_web3_names::Config + pallet_dip_provider::Config,
+	Runtime::IdentityProvider: IdentityProvider<Runtime, Success = LinkedDidInfoOf<Runtime, MAX_LINKED_ACCOUNT>>,
+{
+	let mut db = MemoryDB::default();
+	calculate_root_with_db(identity, &mut db, Some(scope))
+}
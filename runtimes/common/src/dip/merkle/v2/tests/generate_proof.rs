@@ -0,0 +1,266 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use did::did_details::DidVerificationKey;
+use kilt_dip_primitives::{
+	DipDidProofWithVerifiedSubjectCommitment, RevealedAlsoKnownAs, RevealedCommitmentManifest,
+	RevealedDidMerkleProofLeaf, RevealedWeb3NameRecord, TimeBoundDidSignature,
+};
+use pallet_dip_provider::IdentityScope;
+use parity_scale_codec::Encode;
+use sp_core::{ed25519, Pair};
+
+use crate::{
+	constants::{
+		did::{MAX_KEY_AGREEMENT_KEYS, MAX_PUBLIC_KEYS_PER_DID},
+		dip_provider::MAX_LINKED_ACCOUNTS,
+	},
+	dip::{
+		merkle::{
+			restrict_to_scope,
+			v2::{generate_proof, generate_scoped_proof},
+			CompleteMerkleProof,
+		},
+		mock::create_linked_info,
+	},
+	Hasher,
+};
+
+// One more than for V0 proofs, for the manifest leaf.
+const MAX_LEAVES_REVEALED: u32 = MAX_LINKED_ACCOUNTS + MAX_PUBLIC_KEYS_PER_DID + 2;
+
+#[test]
+fn generate_proof_reveals_manifest() {
+	let auth_key = ed25519::Pair::from_seed(&[10u8; 32]);
+	let did_auth_key = DidVerificationKey::Ed25519(auth_key.public());
+	let linked_info = create_linked_info(did_auth_key, Some(b"ntn_x2"), MAX_LINKED_ACCOUNTS);
+	let signature = auth_key.sign(&().encode());
+
+	// Only the authentication key is revealed, so all other leaves are omitted.
+	let CompleteMerkleProof { proof, root } = generate_proof(
+		&linked_info,
+		[linked_info.did_details.authentication_key].iter(),
+		false,
+		[].iter(),
+	)
+	.unwrap();
+	let dip_origin_info =
+		DipDidProofWithVerifiedSubjectCommitment::new(root, proof, TimeBoundDidSignature::new(signature.into(), 100))
+			.verify_dip_proof::<Hasher, MAX_LEAVES_REVEALED>()
+			.and_then(|r| r.verify_signature_time(&50))
+			.and_then(|r| r.retrieve_signing_leaf_for_payload(&().encode()))
+			.unwrap();
+
+	// The authentication key and the manifest.
+	assert_eq!(dip_origin_info.iter_leaves().count(), 2);
+	// All key agreement keys, plus authentication, attestation, and delegation key.
+	let did_keys = MAX_KEY_AGREEMENT_KEYS + 3;
+	assert_eq!(
+		dip_origin_info.manifest(),
+		Some(&RevealedCommitmentManifest {
+			did_keys,
+			linked_accounts: MAX_LINKED_ACCOUNTS,
+			web3_name: true,
+			scope: IdentityScope::Full,
+		})
+	);
+	assert_eq!(
+		dip_origin_info.omitted_leaves(),
+		Some(RevealedCommitmentManifest {
+			did_keys: did_keys - 1,
+			linked_accounts: MAX_LINKED_ACCOUNTS,
+			web3_name: true,
+			scope: IdentityScope::Full,
+		})
+	);
+}
+
+#[test]
+fn generate_proof_without_omitted_leaves() {
+	let auth_key = ed25519::Pair::from_seed(&[10u8; 32]);
+	let did_auth_key = DidVerificationKey::Ed25519(auth_key.public());
+	let linked_info = create_linked_info(did_auth_key, Option::<Vec<u8>>::None, 0);
+	let signature = auth_key.sign(&().encode());
+
+	let CompleteMerkleProof { proof, root } = generate_proof(
+		&linked_info,
+		linked_info.did_details.public_keys.keys(),
+		false,
+		[].iter(),
+	)
+	.unwrap();
+	let dip_origin_info =
+		DipDidProofWithVerifiedSubjectCommitment::new(root, proof, TimeBoundDidSignature::new(signature.into(), 100))
+			.verify_dip_proof::<Hasher, MAX_LEAVES_REVEALED>()
+			.and_then(|r| r.verify_signature_time(&50))
+			.and_then(|r| r.retrieve_signing_leaf_for_payload(&().encode()))
+			.unwrap();
+
+	// The subject has no web3name and no linked accounts, and all its keys are
+	// revealed.
+	assert_eq!(
+		dip_origin_info.omitted_leaves(),
+		Some(RevealedCommitmentManifest::default())
+	);
+}
+
+#[test]
+fn generate_scoped_proof_reveals_scope() {
+	let auth_key = ed25519::Pair::from_seed(&[10u8; 32]);
+	let did_auth_key = DidVerificationKey::Ed25519(auth_key.public());
+	let linked_info = create_linked_info(did_auth_key, Some(b"ntn_x2"), MAX_LINKED_ACCOUNTS);
+	let scoped_info = restrict_to_scope(&linked_info, IdentityScope::KeysAndWeb3Name);
+	let signature = auth_key.sign(&().encode());
+
+	let CompleteMerkleProof { proof, root } = generate_scoped_proof(
+		&scoped_info,
+		IdentityScope::KeysAndWeb3Name,
+		[scoped_info.did_details.authentication_key].iter(),
+		true,
+		[].iter(),
+		false,
+		false,
+	)
+	.unwrap();
+	let dip_origin_info =
+		DipDidProofWithVerifiedSubjectCommitment::new(root, proof, TimeBoundDidSignature::new(signature.into(), 100))
+			.verify_dip_proof::<Hasher, MAX_LEAVES_REVEALED>()
+			.and_then(|r| r.verify_signature_time(&50))
+			.and_then(|r| r.retrieve_signing_leaf_for_payload(&().encode()))
+			.unwrap();
+
+	// Linked accounts are not part of the commitment, so they are not omitted.
+	assert_eq!(
+		dip_origin_info.omitted_leaves(),
+		Some(RevealedCommitmentManifest {
+			did_keys: MAX_KEY_AGREEMENT_KEYS + 2,
+			linked_accounts: 0,
+			web3_name: false,
+			scope: IdentityScope::KeysAndWeb3Name,
+		})
+	);
+}
+
+#[test]
+fn generate_scoped_proof_reveals_also_known_as() {
+	let auth_key = ed25519::Pair::from_seed(&[10u8; 32]);
+	let did_auth_key = DidVerificationKey::Ed25519(auth_key.public());
+	let mut linked_info = create_linked_info(did_auth_key, Some(b"ntn_x2"), MAX_LINKED_ACCOUNTS);
+	let uris = [b"did:web:example.com".to_vec(), b"https://example.com/alice".to_vec()];
+	linked_info.also_known_as = uris
+		.iter()
+		.map(|uri| uri.clone().try_into().unwrap())
+		.collect::<Vec<_>>()
+		.try_into()
+		.unwrap();
+	let signature = auth_key.sign(&().encode());
+
+	let CompleteMerkleProof { proof, root } = generate_scoped_proof(
+		&linked_info,
+		IdentityScope::Full,
+		[linked_info.did_details.authentication_key].iter(),
+		false,
+		[].iter(),
+		true,
+		false,
+	)
+	.unwrap();
+	let dip_origin_info =
+		DipDidProofWithVerifiedSubjectCommitment::new(root, proof, TimeBoundDidSignature::new(signature.into(), 100))
+			.verify_dip_proof::<Hasher, MAX_LEAVES_REVEALED>()
+			.and_then(|r| r.verify_signature_time(&50))
+			.and_then(|r| r.retrieve_signing_leaf_for_payload(&().encode()))
+			.unwrap();
+
+	let revealed_uris = dip_origin_info
+		.iter_leaves()
+		.filter_map(|leaf| match leaf {
+			RevealedDidMerkleProofLeaf::AlsoKnownAs(RevealedAlsoKnownAs(uri)) => Some(uri.to_vec()),
+			_ => None,
+		})
+		.collect::<Vec<_>>();
+	assert_eq!(revealed_uris, uris.to_vec());
+	// `alsoKnownAs` leaves are not counted in the manifest.
+	assert_eq!(
+		dip_origin_info.omitted_leaves(),
+		Some(RevealedCommitmentManifest {
+			did_keys: MAX_KEY_AGREEMENT_KEYS + 2,
+			linked_accounts: MAX_LINKED_ACCOUNTS,
+			web3_name: true,
+			scope: IdentityScope::Full,
+		})
+	);
+}
+
+#[test]
+fn generate_scoped_proof_reveals_web3_name_records() {
+	let auth_key = ed25519::Pair::from_seed(&[10u8; 32]);
+	let did_auth_key = DidVerificationKey::Ed25519(auth_key.public());
+	let mut linked_info = create_linked_info(did_auth_key, Some(b"ntn_x2"), MAX_LINKED_ACCOUNTS);
+	let records = [
+		(b"avatar".to_vec(), b"ipfs://avatar".to_vec()),
+		(b"url".to_vec(), b"https://example.com".to_vec()),
+	];
+	linked_info.web3_name_records = records
+		.iter()
+		.map(|(key, value)| (key.clone().try_into().unwrap(), value.clone().try_into().unwrap()))
+		.collect::<Vec<_>>()
+		.try_into()
+		.unwrap();
+	let scoped_info = restrict_to_scope(&linked_info, IdentityScope::KeysAndWeb3Name);
+	let signature = auth_key.sign(&().encode());
+
+	let CompleteMerkleProof { proof, root } = generate_scoped_proof(
+		&scoped_info,
+		IdentityScope::KeysAndWeb3Name,
+		[scoped_info.did_details.authentication_key].iter(),
+		true,
+		[].iter(),
+		false,
+		true,
+	)
+	.unwrap();
+	let dip_origin_info =
+		DipDidProofWithVerifiedSubjectCommitment::new(root, proof, TimeBoundDidSignature::new(signature.into(), 100))
+			.verify_dip_proof::<Hasher, MAX_LEAVES_REVEALED>()
+			.and_then(|r| r.verify_signature_time(&50))
+			.and_then(|r| r.retrieve_signing_leaf_for_payload(&().encode()))
+			.unwrap();
+
+	let mut revealed_records = dip_origin_info
+		.iter_leaves()
+		.filter_map(|leaf| match leaf {
+			RevealedDidMerkleProofLeaf::Web3NameRecord(RevealedWeb3NameRecord { key, value }) => {
+				Some((key.to_vec(), value.to_vec()))
+			}
+			_ => None,
+		})
+		.collect::<Vec<_>>();
+	revealed_records.sort();
+	assert_eq!(revealed_records, records.to_vec());
+	// Web3name record leaves are not counted in the manifest.
+	assert_eq!(
+		dip_origin_info.omitted_leaves(),
+		Some(RevealedCommitmentManifest {
+			did_keys: MAX_KEY_AGREEMENT_KEYS + 2,
+			linked_accounts: 0,
+			web3_name: false,
+			scope: IdentityScope::KeysAndWeb3Name,
+		})
+	);
+}
@@ -22,13 +22,15 @@ use did::{
 	DeriveDidCallAuthorizationVerificationKeyRelationship,
 };
 use frame_support::{
-	construct_runtime,
+	construct_runtime, parameter_types,
 	traits::{Currency, Everything},
 	Hashable,
 };
 use frame_system::{mocking::MockBlock, pallet_prelude::BlockNumberFor, EnsureRoot, EnsureSigned};
 use kilt_dip_primitives::RevealedWeb3Name;
-use pallet_did_lookup::{account::AccountId20, linkable_account::LinkableAccountId};
+use pallet_did_lookup::{
+	account::AccountId20, bitcoin::BitcoinAccountId, linkable_account::LinkableAccountId, solana::SolanaAccountId,
+};
 use pallet_web3_names::{web3_name::AsciiWeb3Name, Web3NameOf};
 use sp_core::{sr25519, ConstU128, ConstU16, ConstU32, ConstU64};
 use sp_runtime::{traits::IdentityLookup, AccountId32, BoundedVec};
@@ -40,7 +42,7 @@ use crate::{
 			MaxPublicKeysPerDid, MaxServiceIdLength, MaxServiceTypeLength, MaxServiceUrlLength,
 			MaxTotalKeyAgreementKeys, MAX_KEY_AGREEMENT_KEYS,
 		},
-		dip_provider::MAX_LINKED_ACCOUNTS,
+		dip_provider::{COMMITTER_DEPOSIT, MAX_LINKED_ACCOUNTS},
 		web3_names::{MaxNameLength, MinNameLength},
 		KILT,
 	},
@@ -129,6 +131,17 @@ impl did::Config for TestRuntime {
 	type MaxNumberOfServicesPerDid = MaxNumberOfServicesPerDid;
 	type MaxNumberOfTypesPerService = MaxNumberOfTypesPerService;
 	type MaxNumberOfUrlsPerService = MaxNumberOfUrlsPerService;
+	type MaxKeyHistoryLength = ConstU32<10>;
+	type MaxRemovedKeys = ConstU32<10>;
+	type KeyHistoryDeposit = ConstU128<KILT>;
+	type MaxEndorsementsPerDid = ConstU32<10>;
+	type MaxAlsoKnownAs = ConstU32<10>;
+	type MaxAlsoKnownAsUriLength = ConstU32<256>;
+	type MaxDidDocumentSize = ConstU32<131_072>;
+	type MaxMultisigSignatories = ConstU32<64>;
+	type AllowedKeyTypes = Everything;
+	type FreeTrialOperations = ConstU32<0>;
+	type LegacySignatureDeadline = ConstU64<0>;
 	type MaxPublicKeysPerDid = MaxPublicKeysPerDid;
 	type MaxServiceIdLength = MaxServiceIdLength;
 	type MaxServiceTypeLength = MaxServiceTypeLength;
@@ -143,17 +156,33 @@ impl did::Config for TestRuntime {
 	type WeightInfo = ();
 }
 
+parameter_types! {
+	pub const TreasuryAccount: AccountId = AccountId::new([250u8; 32]);
+}
+
 impl pallet_web3_names::Config for TestRuntime {
 	type BalanceMigrationManager = ();
+	type BanAppealOrigin = EnsureRoot<AccountId>;
+	type BanDelay = ConstU64<10>;
 	type BanOrigin = EnsureRoot<AccountId>;
 	type Currency = Balances;
 	type Deposit = ConstU128<KILT>;
+	type MaxBansPerBlock = ConstU32<16>;
+	type MaxClaimBatchSize = ConstU32<16>;
 	type MaxNameLength = MaxNameLength;
+	type MaxRecordKeyLength = ConstU32<32>;
+	type MaxRecordValueLength = ConstU32<256>;
+	type MaxRecordsPerName = ConstU32<16>;
 	type MinNameLength = MinNameLength;
 	type OriginSuccess = AccountId;
 	type OwnerOrigin = EnsureSigned<AccountId>;
+	type RecordDeposit = ConstU128<KILT>;
+	type RegistrarApprovalOrigin = EnsureRoot<AccountId>;
+	type RegistrarOrigin = EnsureRoot<AccountId>;
+	type RegistrarTreasuryShare = ();
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeHoldReason = RuntimeHoldReason;
+	type TreasuryAccount = TreasuryAccount;
 	type Web3Name = AsciiWeb3Name<Self>;
 	type Web3NameOwner = DidIdentifier;
 	type WeightInfo = ();
@@ -161,10 +190,14 @@ impl pallet_web3_names::Config for TestRuntime {
 
 impl pallet_did_lookup::Config for TestRuntime {
 	type BalanceMigrationManager = ();
+	type ContractSignatureVerifier = ();
 	type Currency = Balances;
 	type Deposit = ConstU128<KILT>;
+	type LabelDeposit = ConstU128<KILT>;
 	type DidIdentifier = DidIdentifier;
 	type EnsureOrigin = EnsureSigned<AccountId>;
+	type MaxAssociationRemovals = ConstU32<10>;
+	type MaxProofValidity = ConstU64<1_000>;
 	type OriginSuccess = AccountId;
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeHoldReason = RuntimeHoldReason;
@@ -174,11 +207,15 @@ impl pallet_did_lookup::Config for TestRuntime {
 impl pallet_dip_provider::Config for TestRuntime {
 	type CommitOrigin = AccountId;
 	type CommitOriginCheck = EnsureSigned<AccountId>;
+	type CommitterDeposit = ConstU128<COMMITTER_DEPOSIT>;
+	type Currency = Balances;
 	type Identifier = DidIdentifier;
 	type IdentityCommitmentGenerator = DidMerkleRootGenerator<Self>;
 	type IdentityProvider = LinkedDidInfoProvider<MAX_LINKED_ACCOUNTS>;
+	type MaxCommitters = ConstU32<10>;
 	type ProviderHooks = ();
 	type RuntimeEvent = RuntimeEvent;
+	type RuntimeHoldReason = RuntimeHoldReason;
 	type WeightInfo = ();
 }
 
@@ -223,14 +260,27 @@ pub(crate) fn create_linked_info(
 	};
 	let linked_accounts_iter = (0..linked_accounts).map(|i| {
 		let bytes = i.to_be_bytes();
-		if i % 2 == 0 {
-			let mut buffer = <[u8; 20]>::default();
-			buffer[..4].copy_from_slice(&bytes);
-			LinkableAccountId::AccountId20(AccountId20(buffer))
-		} else {
-			let mut buffer = <[u8; 32]>::default();
-			buffer[..4].copy_from_slice(&bytes);
-			LinkableAccountId::AccountId32(AccountId32::new(buffer))
+		match i % 4 {
+			0 => {
+				let mut buffer = <[u8; 20]>::default();
+				buffer[..4].copy_from_slice(&bytes);
+				LinkableAccountId::AccountId20(AccountId20(buffer))
+			}
+			1 => {
+				let mut buffer = <[u8; 32]>::default();
+				buffer[..4].copy_from_slice(&bytes);
+				LinkableAccountId::AccountId32(AccountId32::new(buffer))
+			}
+			2 => {
+				let mut buffer = <[u8; 32]>::default();
+				buffer[..4].copy_from_slice(&bytes);
+				LinkableAccountId::Solana(SolanaAccountId(buffer))
+			}
+			_ => {
+				let mut buffer = [0u8; 33];
+				buffer[..4].copy_from_slice(&bytes);
+				LinkableAccountId::Bitcoin(BitcoinAccountId(buffer))
+			}
 		}
 	});
 	let linked_accounts: BoundedVec<LinkableAccountId, ConstU32<MAX_LINKED_ACCOUNTS>> =
@@ -247,6 +297,8 @@ pub(crate) fn create_linked_info(
 		did_details,
 		web3_name_details: web3_name,
 		linked_accounts,
+		also_known_as: BoundedVec::default(),
+		web3_name_records: BoundedVec::default(),
 	}
 }
 
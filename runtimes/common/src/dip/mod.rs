@@ -26,4 +26,4 @@ pub mod did;
 pub mod merkle;
 
 #[cfg(test)]
-mod mock;
+pub(crate) mod mock;
@@ -18,45 +18,81 @@
 
 use frame_support::{
 	dispatch::DispatchClass,
-	traits::{fungible::Balanced, Currency, Get, Imbalance, OnUnbalanced},
+	traits::{fungible::Balanced, Contains, Currency, Get, Imbalance, IsSubType, OnUnbalanced},
 	weights::{
 		Weight, WeightToFee as WeightToFeeT, WeightToFeeCoefficient, WeightToFeeCoefficients, WeightToFeePolynomial,
 	},
 };
 use pallet_balances::WeightInfo;
-use pallet_transaction_payment::OnChargeTransaction;
+use pallet_transaction_payment::{FeeDetails, InclusionFee, OnChargeTransaction, RuntimeDispatchInfo};
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
 use smallvec::smallvec;
-use sp_runtime::Perbill;
+use sp_runtime::{
+	traits::{AtLeast32BitUnsigned, DispatchInfoOf, PostDispatchInfoOf, Saturating, Zero},
+	transaction_validity::TransactionValidityError,
+	Perbill, Percent, RuntimeDebug,
+};
 
 use crate::{constants::MILLI_KILT, AccountId, Balance, CreditOf, NegativeImbalanceOf};
 
-/// Split two Imbalances between two unbalanced handlers.
-/// The first Imbalance will be split according to the given ratio. The second
-/// Imbalance will be handled by the second beneficiary.
+/// The shares of the transaction fees, excluding tips, that are burned and
+/// that go to the block author. The treasury receives the rest.
+#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub struct FeeSplit {
+	/// The share of the fees that is burned.
+	pub burn: Percent,
+	/// The share of the fees that goes to the block author.
+	pub author: Percent,
+}
+
+impl FeeSplit {
+	/// The share of the fees that goes to the treasury.
+	pub fn treasury(&self) -> Percent {
+		Percent::one().saturating_sub(self.burn).saturating_sub(self.author)
+	}
+}
+
+impl Default for FeeSplit {
+	/// Nothing is burned and the fees are split equally between the treasury
+	/// and the block author.
+	fn default() -> Self {
+		Self {
+			burn: Percent::zero(),
+			author: Percent::from_percent(50),
+		}
+	}
+}
+
+/// Burns part of the fees and splits the rest between the treasury and the
+/// block author according to the [`FeeSplit`] returned by `Split`. Tips go
+/// to the block author in full.
 ///
-/// In case of transaction payment, the first Imbalance is the fee and the
-/// second imbalance the tip.
-pub struct SplitFeesByRatio<R, Ratio, Beneficiary1, Beneficiary2>(
-	sp_std::marker::PhantomData<(R, Ratio, Beneficiary1, Beneficiary2)>,
-);
-impl<R, Ratio, Beneficiary1, Beneficiary2> OnUnbalanced<NegativeImbalanceOf<R>>
-	for SplitFeesByRatio<R, Ratio, Beneficiary1, Beneficiary2>
+/// If the shares of the burn and the author exceed 100%, the author only
+/// receives what is left after burning. Rounding differences go to the
+/// treasury.
+pub struct DealWithFees<R, Split, Treasury, Author>(sp_std::marker::PhantomData<(R, Split, Treasury, Author)>);
+
+impl<R, Split, Treasury, Author> OnUnbalanced<NegativeImbalanceOf<R>> for DealWithFees<R, Split, Treasury, Author>
 where
 	R: pallet_balances::Config,
-	Beneficiary1: OnUnbalanced<NegativeImbalanceOf<R>>,
-	Beneficiary2: OnUnbalanced<NegativeImbalanceOf<R>>,
-	Ratio: Get<(u32, u32)>,
+	Split: Get<FeeSplit>,
+	Treasury: OnUnbalanced<NegativeImbalanceOf<R>>,
+	Author: OnUnbalanced<NegativeImbalanceOf<R>>,
 {
 	fn on_unbalanceds<B>(mut fees_then_tips: impl Iterator<Item = NegativeImbalanceOf<R>>) {
-		let ratio = Ratio::get();
 		if let Some(fees) = fees_then_tips.next() {
-			let mut split = fees.ration(ratio.0, ratio.1);
+			let split = Split::get();
+			let total = fees.peek();
+			let (burned, rest) = fees.split(split.burn.mul_floor(total));
+			let (mut to_author, to_treasury) = rest.split(split.author.mul_floor(total));
 			if let Some(tips) = fees_then_tips.next() {
-				// for tips, if any, 100% to author
-				tips.merge_into(&mut split.1);
+				tips.merge_into(&mut to_author);
 			}
-			Beneficiary1::on_unbalanced(split.0);
-			Beneficiary2::on_unbalanced(split.1);
+			// Dropping the imbalance reduces the total issuance.
+			drop(burned);
+			Treasury::on_unbalanced(to_treasury);
+			Author::on_unbalanced(to_author);
 		}
 	}
 }
@@ -146,19 +182,196 @@ where
 	}
 }
 
+/// Charges transaction fees via `OnCharge`, but discounts the fees (not the
+/// tips) of the calls contained in `Calls` by `Discount`.
+///
+/// This keeps identity-related operations affordable when the fee multiplier
+/// rises due to congestion, while all other calls pay the full fee.
+pub struct DiscountedFees<OnCharge, Calls, Discount>(sp_std::marker::PhantomData<(OnCharge, Calls, Discount)>);
+
+impl<R, OnCharge, Calls, Discount> OnChargeTransaction<R> for DiscountedFees<OnCharge, Calls, Discount>
+where
+	R: pallet_transaction_payment::Config,
+	OnCharge: OnChargeTransaction<R>,
+	Calls: Contains<<R as frame_system::Config>::RuntimeCall>,
+	Discount: Get<Perbill>,
+{
+	type Balance = OnCharge::Balance;
+	type LiquidityInfo = (OnCharge::LiquidityInfo, Perbill);
+
+	fn withdraw_fee(
+		who: &R::AccountId,
+		call: &R::RuntimeCall,
+		dispatch_info: &DispatchInfoOf<R::RuntimeCall>,
+		fee: Self::Balance,
+		tip: Self::Balance,
+	) -> Result<Self::LiquidityInfo, TransactionValidityError> {
+		let discount = fee_discount::<_, Calls, Discount>(call);
+		let fee = discount_fee(fee, tip, discount);
+
+		OnCharge::withdraw_fee(who, call, dispatch_info, fee, tip).map(|liquidity_info| (liquidity_info, discount))
+	}
+
+	fn correct_and_deposit_fee(
+		who: &R::AccountId,
+		dispatch_info: &DispatchInfoOf<R::RuntimeCall>,
+		post_info: &PostDispatchInfoOf<R::RuntimeCall>,
+		corrected_fee: Self::Balance,
+		tip: Self::Balance,
+		(already_withdrawn, discount): Self::LiquidityInfo,
+	) -> Result<(), TransactionValidityError> {
+		let corrected_fee = discount_fee(corrected_fee, tip, discount);
+
+		OnCharge::correct_and_deposit_fee(who, dispatch_info, post_info, corrected_fee, tip, already_withdrawn)
+	}
+}
+
+/// Charges transaction fees via `OnCharge`, but lets the `Pot` account pay the
+/// fees of DID management operations submitted for DIDs that have free trial
+/// operations left, using up one of them.
+///
+/// The pot only pays once the DID signature of the operation has been
+/// verified, and only if the submitter holds the creation deposit of the DID.
+/// The weight of these checks is registered as extra weight of the block.
+///
+/// Operations with a tip are never paid by the pot. If the pot cannot pay the
+/// fees, they are charged to the submitter and no free trial operation is used
+/// up.
+pub struct FreeTrialFees<OnCharge, Pot>(sp_std::marker::PhantomData<(OnCharge, Pot)>);
+
+impl<R, OnCharge, Pot> OnChargeTransaction<R> for FreeTrialFees<OnCharge, Pot>
+where
+	R: pallet_transaction_payment::Config + did::Config,
+	R::AccountId: AsRef<[u8; 32]> + From<[u8; 32]>,
+	<R as frame_system::Config>::RuntimeCall: IsSubType<did::Call<R>>,
+	<R as did::Config>::RuntimeCall: IsSubType<did::Call<R>>,
+	OnCharge: OnChargeTransaction<R>,
+	Pot: Get<R::AccountId>,
+{
+	type Balance = OnCharge::Balance;
+	type LiquidityInfo = (OnCharge::LiquidityInfo, Option<R::AccountId>);
+
+	fn withdraw_fee(
+		who: &R::AccountId,
+		call: &<R as frame_system::Config>::RuntimeCall,
+		dispatch_info: &DispatchInfoOf<<R as frame_system::Config>::RuntimeCall>,
+		fee: Self::Balance,
+		tip: Self::Balance,
+	) -> Result<Self::LiquidityInfo, TransactionValidityError> {
+		let free_trial_did = match call.is_sub_type() {
+			Some(did::Call::submit_did_call { did_call, signature }) if tip.is_zero() => {
+				frame_system::Pallet::<R>::register_extra_weight_unchecked(
+					did::Pallet::<R>::free_trial_did_weight(did_call),
+					dispatch_info.class,
+				);
+				did::Pallet::<R>::free_trial_did(did_call, signature, who)
+			}
+			_ => None,
+		};
+
+		if let Some(did_subject) = free_trial_did {
+			let pot = Pot::get();
+			if let Ok(liquidity_info) = OnCharge::withdraw_fee(&pot, call, dispatch_info, fee, tip) {
+				did::Pallet::<R>::consume_free_trial_operation(&did_subject);
+				return Ok((liquidity_info, Some(pot)));
+			}
+		}
+
+		OnCharge::withdraw_fee(who, call, dispatch_info, fee, tip).map(|liquidity_info| (liquidity_info, None))
+	}
+
+	fn correct_and_deposit_fee(
+		who: &R::AccountId,
+		dispatch_info: &DispatchInfoOf<<R as frame_system::Config>::RuntimeCall>,
+		post_info: &PostDispatchInfoOf<<R as frame_system::Config>::RuntimeCall>,
+		corrected_fee: Self::Balance,
+		tip: Self::Balance,
+		(already_withdrawn, payer): Self::LiquidityInfo,
+	) -> Result<(), TransactionValidityError> {
+		// Refunds go back to whoever paid the fees.
+		let payer = payer.as_ref().unwrap_or(who);
+
+		OnCharge::correct_and_deposit_fee(payer, dispatch_info, post_info, corrected_fee, tip, already_withdrawn)
+	}
+}
+
+/// Returns the fee discount that applies to the given call.
+pub fn fee_discount<Call, Calls, Discount>(call: &Call) -> Perbill
+where
+	Calls: Contains<Call>,
+	Discount: Get<Perbill>,
+{
+	if Calls::contains(call) {
+		Discount::get()
+	} else {
+		Perbill::zero()
+	}
+}
+
+/// Applies the discount to the fee, excluding the tip.
+pub fn discount_fee<Balance: AtLeast32BitUnsigned + Copy>(fee: Balance, tip: Balance, discount: Perbill) -> Balance {
+	fee.saturating_sub(discount * fee.saturating_sub(tip))
+}
+
+/// Applies the discount to the partial fee of the dispatch info, as returned
+/// by the `TransactionPaymentApi`.
+pub fn discount_dispatch_info<Balance: AtLeast32BitUnsigned + Copy>(
+	info: RuntimeDispatchInfo<Balance>,
+	discount: Perbill,
+) -> RuntimeDispatchInfo<Balance> {
+	RuntimeDispatchInfo {
+		partial_fee: discount_fee(info.partial_fee, Zero::zero(), discount),
+		..info
+	}
+}
+
+/// Applies the discount to the inclusion fee of the fee details, as returned
+/// by the `TransactionPaymentApi`.
+///
+/// The discounted components add up to the same fee that is charged by
+/// [`DiscountedFees`].
+pub fn discount_fee_details<Balance: AtLeast32BitUnsigned + Copy>(
+	details: FeeDetails<Balance>,
+	discount: Perbill,
+) -> FeeDetails<Balance> {
+	let inclusion_fee = details.inclusion_fee.map(|fee| {
+		let total = fee
+			.base_fee
+			.saturating_add(fee.len_fee)
+			.saturating_add(fee.adjusted_weight_fee);
+		let discounted_total = discount_fee(total, Zero::zero(), discount);
+		let base_fee = discount_fee(fee.base_fee, Zero::zero(), discount);
+		let len_fee = discount_fee(fee.len_fee, Zero::zero(), discount);
+
+		InclusionFee {
+			base_fee,
+			len_fee,
+			// Rounding differences are accounted to the weight fee.
+			adjusted_weight_fee: discounted_total.saturating_sub(base_fee).saturating_sub(len_fee),
+		}
+	});
+
+	FeeDetails {
+		inclusion_fee,
+		tip: details.tip,
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
 	use crate::{
-		AccountId, BlockExecutionWeight, ExtrinsicBaseWeight, AVERAGE_ON_INITIALIZE_RATIO, MAXIMUM_BLOCK_WEIGHT,
+		AccountId, BlockExecutionWeight, BlockFullnessFeeUpdate, ExtrinsicBaseWeight, MinimumMultiplier,
+		SlowAdjustingFeeUpdate, TargetBlockFullness, AVERAGE_ON_INITIALIZE_RATIO, MAXIMUM_BLOCK_WEIGHT,
 		NORMAL_DISPATCH_RATIO,
 	};
 	use frame_support::{dispatch::DispatchClass, parameter_types, traits::FindAuthor};
 	use frame_system::limits;
+	use pallet_transaction_payment::Multiplier;
 	use sp_core::{ConstU64, H256};
 	use sp_runtime::{
-		traits::{BlakeTwo256, IdentityLookup},
-		BuildStorage, Perbill,
+		traits::{BlakeTwo256, Convert, IdentityLookup, One},
+		BuildStorage, FixedPointNumber, Perbill,
 	};
 
 	type Block = frame_system::mocking::MockBlock<Test>;
@@ -275,22 +488,211 @@ mod tests {
 	}
 
 	parameter_types! {
-		pub const Ratio: (u32, u32) = (50, 50);
+		pub static TestFeeSplit: FeeSplit = FeeSplit::default();
+	}
+
+	fn deal_with_fees(fee: u64, tip: u64) {
+		let fee = <Balances as Currency<<Test as frame_system::Config>::AccountId>>::issue(fee);
+		let tip = <Balances as Currency<<Test as frame_system::Config>::AccountId>>::issue(tip);
+		DealWithFees::<Test, TestFeeSplit, ToBeneficiary, ToAuthor<Test>>::on_unbalanceds(vec![fee, tip].into_iter());
 	}
 
 	#[test]
-	fn test_fees_and_tip_split() {
+	fn deal_with_fees_default_split() {
 		new_test_ext().execute_with(|| {
-			let fee = <Balances as Currency<<Test as frame_system::Config>::AccountId>>::issue(10);
-			let tip = <Balances as Currency<<Test as frame_system::Config>::AccountId>>::issue(20);
+			deal_with_fees(100, 20);
 
-			assert_eq!(Balances::free_balance(TREASURY_ACC), 0);
-			assert_eq!(Balances::free_balance(AUTHOR_ACC), 0);
+			assert_eq!(Balances::free_balance(TREASURY_ACC), 50);
+			assert_eq!(Balances::free_balance(AUTHOR_ACC), 70);
+			assert_eq!(Balances::total_issuance(), 120);
+		});
+	}
+
+	#[test]
+	fn deal_with_fees_burns_share_of_fees() {
+		new_test_ext().execute_with(|| {
+			TestFeeSplit::set(FeeSplit {
+				burn: Percent::from_percent(20),
+				author: Percent::from_percent(30),
+			});
+			assert_eq!(TestFeeSplit::get().treasury(), Percent::from_percent(50));
+
+			deal_with_fees(101, 10);
+
+			// Tips are never burned, and rounding differences go to the treasury.
+			assert_eq!(Balances::free_balance(AUTHOR_ACC), 40);
+			assert_eq!(Balances::free_balance(TREASURY_ACC), 51);
+			assert_eq!(Balances::total_issuance(), 91);
+		});
+		TestFeeSplit::set(FeeSplit::default());
+	}
+
+	#[test]
+	fn deal_with_fees_shares_exceeding_total() {
+		new_test_ext().execute_with(|| {
+			TestFeeSplit::set(FeeSplit {
+				burn: Percent::from_percent(80),
+				author: Percent::from_percent(50),
+			});
+			assert_eq!(TestFeeSplit::get().treasury(), Percent::zero());
 
-			SplitFeesByRatio::<Test, Ratio, ToBeneficiary, ToAuthor<Test>>::on_unbalanceds(vec![fee, tip].into_iter());
+			deal_with_fees(100, 5);
 
-			assert_eq!(Balances::free_balance(TREASURY_ACC), 5);
 			assert_eq!(Balances::free_balance(AUTHOR_ACC), 25);
+			assert_eq!(Balances::free_balance(TREASURY_ACC), 0);
+			assert_eq!(Balances::total_issuance(), 25);
+		});
+		TestFeeSplit::set(FeeSplit::default());
+	}
+
+	fn run_with_block_weight(weight: Weight, assertions: impl FnOnce()) {
+		new_test_ext().execute_with(|| {
+			System::set_block_consumed_resources(weight, 0);
+			assertions();
+		});
+	}
+
+	fn max_normal() -> Weight {
+		let weights = BlockWeights::get();
+		weights
+			.get(DispatchClass::Normal)
+			.max_total
+			.unwrap_or(weights.max_block)
+	}
+
+	fn target() -> Weight {
+		TargetBlockFullness::get() * max_normal()
+	}
+
+	#[test]
+	fn multiplier_stays_at_target_fullness() {
+		run_with_block_weight(target(), || {
+			let multiplier = Multiplier::one();
+			assert_eq!(SlowAdjustingFeeUpdate::<Test>::convert(multiplier), multiplier);
+		});
+	}
+
+	#[test]
+	fn multiplier_grows_above_target_fullness() {
+		run_with_block_weight(max_normal(), || {
+			let multiplier = Multiplier::one();
+			let next = SlowAdjustingFeeUpdate::<Test>::convert(multiplier);
+			assert!(next > multiplier);
+
+			// The increase is relative to the current multiplier.
+			let next_full = SlowAdjustingFeeUpdate::<Test>::convert(next);
+			assert!(next_full - next > next - multiplier);
+		});
+	}
+
+	#[test]
+	fn multiplier_shrinks_below_target_fullness() {
+		run_with_block_weight(Weight::zero(), || {
+			let multiplier = Multiplier::one();
+			assert!(SlowAdjustingFeeUpdate::<Test>::convert(multiplier) < multiplier);
+		});
+	}
+
+	#[test]
+	fn multiplier_never_falls_below_minimum() {
+		run_with_block_weight(Weight::zero(), || {
+			assert_eq!(
+				SlowAdjustingFeeUpdate::<Test>::convert(MinimumMultiplier::get()),
+				MinimumMultiplier::get()
+			);
+		});
+	}
+
+	#[test]
+	fn multiplier_can_grow_from_zero() {
+		run_with_block_weight(max_normal(), || {
+			assert!(SlowAdjustingFeeUpdate::<Test>::convert(MinimumMultiplier::get()) > MinimumMultiplier::get());
+		});
+	}
+
+	parameter_types! {
+		FastAdjustmentVariable: Multiplier = Multiplier::saturating_from_rational(6, 100_000);
+		CappedMultiplier: Multiplier = Multiplier::saturating_from_integer(2);
+	}
+
+	type FastFeeUpdate = BlockFullnessFeeUpdate<Test, FastAdjustmentVariable, CappedMultiplier>;
+
+	#[test]
+	fn block_fullness_multiplier_follows_block_fullness() {
+		let multiplier = Multiplier::one();
+		run_with_block_weight(target(), || {
+			assert_eq!(FastFeeUpdate::convert(multiplier), multiplier);
+		});
+		run_with_block_weight(max_normal(), || {
+			assert!(FastFeeUpdate::convert(multiplier) > multiplier);
+		});
+		run_with_block_weight(Weight::zero(), || {
+			assert!(FastFeeUpdate::convert(multiplier) < multiplier);
+		});
+	}
+
+	#[test]
+	fn block_fullness_multiplier_adjusts_faster_than_slow_adjusting() {
+		let multiplier = Multiplier::one();
+		run_with_block_weight(max_normal(), || {
+			assert!(FastFeeUpdate::convert(multiplier) > SlowAdjustingFeeUpdate::<Test>::convert(multiplier));
+		});
+		run_with_block_weight(Weight::zero(), || {
+			assert!(FastFeeUpdate::convert(multiplier) < SlowAdjustingFeeUpdate::<Test>::convert(multiplier));
+		});
+	}
+
+	#[test]
+	fn block_fullness_multiplier_never_exceeds_maximum() {
+		run_with_block_weight(max_normal(), || {
+			assert_eq!(FastFeeUpdate::convert(CappedMultiplier::get()), CappedMultiplier::get());
+		});
+	}
+
+	#[test]
+	fn block_fullness_multiplier_can_grow_from_zero() {
+		run_with_block_weight(max_normal(), || {
+			assert!(FastFeeUpdate::convert(MinimumMultiplier::get()) > MinimumMultiplier::get());
 		});
 	}
+
+	#[test]
+	fn discount_excludes_tip() {
+		assert_eq!(discount_fee(110u64, 10, Perbill::from_percent(50)), 60);
+		assert_eq!(discount_fee(110u64, 10, Perbill::zero()), 110);
+		assert_eq!(discount_fee(110u64, 10, Perbill::one()), 10);
+	}
+
+	#[test]
+	fn discounted_fee_details_match_discounted_fee() {
+		let details = FeeDetails {
+			inclusion_fee: Some(InclusionFee {
+				base_fee: 33u64,
+				len_fee: 33,
+				adjusted_weight_fee: 33,
+			}),
+			tip: 7,
+		};
+		let discount = Perbill::from_percent(50);
+		let expected = discount_fee(details.final_fee(), details.tip, discount);
+
+		assert_eq!(discount_fee_details(details, discount).final_fee(), expected);
+	}
+
+	struct EvenCalls;
+	impl Contains<u32> for EvenCalls {
+		fn contains(call: &u32) -> bool {
+			call % 2 == 0
+		}
+	}
+
+	parameter_types! {
+		const Discount: Perbill = Perbill::from_percent(30);
+	}
+
+	#[test]
+	fn discount_only_applies_to_contained_calls() {
+		assert_eq!(fee_discount::<_, EvenCalls, Discount>(&2), Perbill::from_percent(30));
+		assert_eq!(fee_discount::<_, EvenCalls, Discount>(&3), Perbill::zero());
+	}
 }
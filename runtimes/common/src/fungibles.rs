@@ -0,0 +1,173 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+//! Logic for fungible assets managed via `pallet_assets`, i.e., the assets
+//! issued on this chain and the foreign assets registered by governance.
+
+use frame_support::traits::{
+	fungibles::{Balanced, Credit, Inspect},
+	Contains, EnsureOrigin, EnsureOriginWithArg, Get,
+};
+use pallet_asset_tx_payment::{HandleCredit, OnChargeAssetTransaction};
+use parity_scale_codec::Decode;
+use sp_runtime::{
+	traits::{DispatchInfoOf, PostDispatchInfoOf},
+	transaction_validity::{InvalidTransaction, TransactionValidityError},
+	Perbill,
+};
+use sp_std::marker::PhantomData;
+
+use did::{DidRawOrigin, EnsureDidOrigin};
+
+use crate::fees::discount_fee;
+
+/// Ensures that the origin of an asset creation is a DID-authorized call.
+///
+/// The submitter of the DID call becomes the owner of the new asset and pays
+/// the asset deposit.
+pub struct EnsureDidAssetCreator<DidIdentifier, AccountId>(PhantomData<(DidIdentifier, AccountId)>);
+
+impl<OuterOrigin, AssetId, DidIdentifier, AccountId> EnsureOriginWithArg<OuterOrigin, AssetId>
+	for EnsureDidAssetCreator<DidIdentifier, AccountId>
+where
+	OuterOrigin: Into<Result<DidRawOrigin<DidIdentifier, AccountId>, OuterOrigin>>
+		+ From<DidRawOrigin<DidIdentifier, AccountId>>,
+	DidIdentifier: From<AccountId>,
+	AccountId: Clone + Decode,
+{
+	type Success = AccountId;
+
+	fn try_origin(o: OuterOrigin, _asset_id: &AssetId) -> Result<Self::Success, OuterOrigin> {
+		<EnsureDidOrigin<DidIdentifier, AccountId> as EnsureOrigin<OuterOrigin>>::try_origin(o)
+			.map(|did_origin| did_origin.submitter)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin(_asset_id: &AssetId) -> Result<OuterOrigin, ()> {
+		<EnsureDidOrigin<DidIdentifier, AccountId> as EnsureOrigin<OuterOrigin>>::try_successful_origin()
+	}
+}
+
+type AssetBalanceOf<R> =
+	<<R as pallet_asset_tx_payment::Config>::Fungibles as Inspect<<R as frame_system::Config>::AccountId>>::Balance;
+
+/// Charges transaction fees in foreign assets via `OnCharge`, but only for the
+/// calls contained in `Calls`. All other calls must be paid in KILT.
+///
+/// The fees are discounted by `Discount` in the same way as when they are paid
+/// in KILT.
+pub struct ForeignAssetFees<OnCharge, Calls, Discount>(PhantomData<(OnCharge, Calls, Discount)>);
+
+impl<R, OnCharge, Calls, Discount> OnChargeAssetTransaction<R> for ForeignAssetFees<OnCharge, Calls, Discount>
+where
+	R: pallet_asset_tx_payment::Config,
+	OnCharge: OnChargeAssetTransaction<R>,
+	Calls: Contains<<R as frame_system::Config>::RuntimeCall>,
+	Discount: Get<Perbill>,
+{
+	type AssetId = OnCharge::AssetId;
+	type Balance = OnCharge::Balance;
+	type LiquidityInfo = OnCharge::LiquidityInfo;
+
+	fn withdraw_fee(
+		who: &R::AccountId,
+		call: &R::RuntimeCall,
+		dispatch_info: &DispatchInfoOf<R::RuntimeCall>,
+		asset_id: Self::AssetId,
+		fee: Self::Balance,
+		tip: Self::Balance,
+	) -> Result<Self::LiquidityInfo, TransactionValidityError> {
+		if !Calls::contains(call) {
+			return Err(InvalidTransaction::Payment.into());
+		}
+		let fee = discount_fee(fee, tip, Discount::get());
+
+		OnCharge::withdraw_fee(who, call, dispatch_info, asset_id, fee, tip)
+	}
+
+	fn correct_and_deposit_fee(
+		who: &R::AccountId,
+		dispatch_info: &DispatchInfoOf<R::RuntimeCall>,
+		post_info: &PostDispatchInfoOf<R::RuntimeCall>,
+		corrected_fee: Self::Balance,
+		tip: Self::Balance,
+		already_withdrawn: Self::LiquidityInfo,
+	) -> Result<(AssetBalanceOf<R>, AssetBalanceOf<R>), TransactionValidityError> {
+		let corrected_fee = discount_fee(corrected_fee, tip, Discount::get());
+
+		OnCharge::correct_and_deposit_fee(who, dispatch_info, post_info, corrected_fee, tip, already_withdrawn)
+	}
+}
+
+/// Deposits the fees paid in foreign assets into the account returned by
+/// `Beneficiary`, e.g., the treasury.
+pub struct ResolveAssetsTo<Beneficiary>(PhantomData<Beneficiary>);
+
+impl<AccountId, Assets, Beneficiary> HandleCredit<AccountId, Assets> for ResolveAssetsTo<Beneficiary>
+where
+	Assets: Balanced<AccountId>,
+	Beneficiary: Get<AccountId>,
+{
+	fn handle_credit(credit: Credit<AccountId, Assets>) {
+		// If the credit cannot be deposited, e.g., because it is below the minimum
+		// balance of the asset, it is burned.
+		let _ = Assets::resolve(&Beneficiary::get(), credit);
+	}
+}
+
+/// Creates the foreign asset ids used in the `pallet_assets` benchmarks.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct ForeignAssetBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_assets::BenchmarkHelper<xcm::v3::MultiLocation> for ForeignAssetBenchmarkHelper {
+	fn create_asset_id_parameter(id: u32) -> xcm::v3::MultiLocation {
+		xcm::v3::MultiLocation::new(1, xcm::v3::Junctions::X1(xcm::v3::Junction::Parachain(id)))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use frame_support::{assert_ok, traits::EnsureOriginWithArg};
+	use frame_system::RawOrigin;
+
+	use crate::{dip::mock::RuntimeOrigin, AccountId, DidIdentifier};
+
+	use super::*;
+
+	type CreateOrigin = EnsureDidAssetCreator<DidIdentifier, AccountId>;
+
+	#[test]
+	fn did_origin_creates_asset_owned_by_submitter() {
+		let did = AccountId::new([1u8; 32]);
+		let submitter = AccountId::new([2u8; 32]);
+		let origin: RuntimeOrigin = DidRawOrigin::new(did, submitter.clone()).into();
+
+		assert_ok!(
+			<CreateOrigin as EnsureOriginWithArg<RuntimeOrigin, u32>>::try_origin(origin, &0),
+			submitter
+		);
+	}
+
+	#[test]
+	fn signed_origin_cannot_create_asset() {
+		let origin: RuntimeOrigin = RawOrigin::Signed(AccountId::new([2u8; 32])).into();
+
+		assert!(<CreateOrigin as EnsureOriginWithArg<RuntimeOrigin, u32>>::try_origin(origin, &0).is_err());
+	}
+}
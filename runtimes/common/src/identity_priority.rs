@@ -0,0 +1,232 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+//! Signed extension raising the priority of DID calls submitted on behalf of
+//! established identities, so that identity operations are less likely to be
+//! starved by spam when fees spike.
+//!
+//! The boost is bounded so that it cannot be used to front-run other
+//! transactions: it is a fixed amount, it only applies to normal
+//! `submit_did_call` transactions whose DID operation is valid, and only to
+//! DIDs that have owned a web3name for a minimum amount of time. Since the DID
+//! operation must use the next nonce of the DID, at most one boosted
+//! transaction per DID can be valid at any time.
+
+use frame_support::{
+	dispatch::{DispatchClass, DispatchInfo},
+	traits::{Get, IsSubType},
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{DispatchInfoOf, Dispatchable, SignedExtension},
+	transaction_validity::{TransactionPriority, TransactionValidity, TransactionValidityError, ValidTransaction},
+	Saturating,
+};
+use sp_std::marker::PhantomData;
+
+/// Adds `Boost` to the priority of the DID calls submitted by the account
+/// authorized in the DID operation, if the DID has owned a web3name for at
+/// least `MinNameAge` blocks.
+///
+/// The extension does not change the validity of any transaction, and it has
+/// no effect once the transaction is included in a block.
+#[derive(Encode, Decode, TypeInfo, Clone, Eq, PartialEq)]
+#[scale_info(skip_type_params(T, Boost, MinNameAge))]
+pub struct IdentityPriorityBoost<T, Boost, MinNameAge>(PhantomData<(T, Boost, MinNameAge)>);
+
+impl<T, Boost, MinNameAge> IdentityPriorityBoost<T, Boost, MinNameAge> {
+	/// Creates the extension.
+	pub fn new() -> Self {
+		Self(PhantomData)
+	}
+}
+
+impl<T, Boost, MinNameAge> Default for IdentityPriorityBoost<T, Boost, MinNameAge> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<T, Boost, MinNameAge> sp_std::fmt::Debug for IdentityPriorityBoost<T, Boost, MinNameAge> {
+	fn fmt(&self, f: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		write!(f, "IdentityPriorityBoost")
+	}
+}
+
+impl<T, Boost, MinNameAge> IdentityPriorityBoost<T, Boost, MinNameAge>
+where
+	T: did::Config + pallet_web3_names::Config<Web3NameOwner = did::DidIdentifierOf<T>>,
+	<T as frame_system::Config>::RuntimeCall: IsSubType<did::Call<T>>,
+	MinNameAge: Get<BlockNumberFor<T>>,
+{
+	/// Returns whether the given call is a valid DID call submitted by `who`
+	/// on behalf of a DID with an old enough web3name.
+	fn is_eligible(who: &T::AccountId, call: &<T as frame_system::Config>::RuntimeCall) -> bool {
+		let Some(did::Call::submit_did_call { did_call, signature }) = call.is_sub_type() else {
+			return false;
+		};
+		if did_call.submitter != *who {
+			return false;
+		}
+
+		let Some(ownership) =
+			pallet_web3_names::Names::<T>::get(&did_call.did).and_then(|name| pallet_web3_names::Owner::<T>::get(name))
+		else {
+			return false;
+		};
+		let current_block = frame_system::Pallet::<T>::block_number();
+		if ownership.claimed_at.saturating_add(MinNameAge::get()) > current_block {
+			return false;
+		}
+
+		did::Pallet::<T>::validate_did_call(did_call, signature).is_ok()
+	}
+}
+
+impl<T, Boost, MinNameAge> SignedExtension for IdentityPriorityBoost<T, Boost, MinNameAge>
+where
+	T: did::Config + pallet_web3_names::Config<Web3NameOwner = did::DidIdentifierOf<T>> + Send + Sync,
+	<T as frame_system::Config>::RuntimeCall: IsSubType<did::Call<T>> + Dispatchable<Info = DispatchInfo>,
+	Boost: Get<TransactionPriority> + Send + Sync + 'static,
+	MinNameAge: Get<BlockNumberFor<T>> + Send + Sync + 'static,
+{
+	const IDENTIFIER: &'static str = "IdentityPriorityBoost";
+
+	type AccountId = T::AccountId;
+	type Call = <T as frame_system::Config>::RuntimeCall;
+	type AdditionalSigned = ();
+	type Pre = ();
+
+	fn additional_signed(&self) -> Result<Self::AdditionalSigned, TransactionValidityError> {
+		Ok(())
+	}
+
+	fn validate(
+		&self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		_len: usize,
+	) -> TransactionValidity {
+		if info.class != DispatchClass::Normal || !Self::is_eligible(who, call) {
+			return Ok(ValidTransaction::default());
+		}
+		Ok(ValidTransaction {
+			priority: Boost::get(),
+			..Default::default()
+		})
+	}
+
+	// The priority is only relevant in the transaction pool, so the DID
+	// operation is not verified again when the transaction is dispatched.
+	fn pre_dispatch(
+		self,
+		_who: &Self::AccountId,
+		_call: &Self::Call,
+		_info: &DispatchInfoOf<Self::Call>,
+		_len: usize,
+	) -> Result<Self::Pre, TransactionValidityError> {
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use did::{did_details::DidVerificationKey, mock_utils::generate_base_did_details, DidSignature};
+	use frame_support::dispatch::GetDispatchInfo;
+	use sp_core::{ConstU64, Pair};
+
+	use crate::dip::mock::{ExtBuilder, RuntimeCall, System, TestRuntime, ACCOUNT, DID_IDENTIFIER, SUBMITTER};
+
+	use super::*;
+
+	type Extension = IdentityPriorityBoost<TestRuntime, ConstU64<100>, ConstU64<20>>;
+
+	fn submit_did_call(pair: &sp_core::sr25519::Pair, tx_counter: u64, block_number: u64) -> RuntimeCall {
+		let operation = did::did_details::DidAuthorizedCallOperation {
+			did: DID_IDENTIFIER,
+			tx_counter,
+			call: RuntimeCall::System(frame_system::Call::remark { remark: vec![] }),
+			block_number,
+			submitter: SUBMITTER,
+		};
+		let signature = pair.sign(&did::Pallet::<TestRuntime>::did_operation_signing_payload(&operation));
+		RuntimeCall::Did(did::Call::submit_did_call {
+			did_call: Box::new(operation),
+			signature: DidSignature::Sr25519(signature),
+		})
+	}
+
+	fn priority(who: &crate::AccountId, call: &RuntimeCall) -> TransactionPriority {
+		Extension::new()
+			.validate(who, call, &call.get_dispatch_info(), 0)
+			.expect("The extension should never invalidate a transaction.")
+			.priority
+	}
+
+	#[test]
+	fn boosts_valid_did_calls_from_established_names() {
+		let pair = sp_core::sr25519::Pair::from_seed(&[1u8; 32]);
+		let did_details = generate_base_did_details(DidVerificationKey::Sr25519(pair.public()), Some(SUBMITTER));
+
+		ExtBuilder::default()
+			.with_dids(vec![(
+				DID_IDENTIFIER,
+				did_details,
+				Some(b"ntn_x2".to_vec().try_into().unwrap()),
+				vec![],
+				SUBMITTER,
+			)])
+			.build()
+			.execute_with(|| {
+				System::set_block_number(19);
+				assert_eq!(priority(&SUBMITTER, &submit_did_call(&pair, 1, 19)), 0);
+
+				System::set_block_number(20);
+				assert_eq!(priority(&SUBMITTER, &submit_did_call(&pair, 1, 20)), 100);
+				// The DID operation must be valid and authorize the submitter.
+				assert_eq!(priority(&ACCOUNT, &submit_did_call(&pair, 1, 20)), 0);
+				assert_eq!(priority(&SUBMITTER, &submit_did_call(&pair, 2, 20)), 0);
+				let other_pair = sp_core::sr25519::Pair::from_seed(&[2u8; 32]);
+				assert_eq!(priority(&SUBMITTER, &submit_did_call(&other_pair, 1, 20)), 0);
+			});
+	}
+
+	#[test]
+	fn does_not_boost_did_calls_without_names() {
+		let pair = sp_core::sr25519::Pair::from_seed(&[1u8; 32]);
+		let did_details = generate_base_did_details(DidVerificationKey::Sr25519(pair.public()), Some(SUBMITTER));
+
+		ExtBuilder::default()
+			.with_dids(vec![(DID_IDENTIFIER, did_details, None, vec![], SUBMITTER)])
+			.build()
+			.execute_with(|| {
+				System::set_block_number(20);
+				assert_eq!(priority(&SUBMITTER, &submit_did_call(&pair, 1, 20)), 0);
+				assert_eq!(
+					priority(
+						&SUBMITTER,
+						&RuntimeCall::System(frame_system::Call::remark { remark: vec![] })
+					),
+					0
+				);
+			});
+	}
+}
@@ -19,7 +19,6 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use constants::{AVERAGE_ON_INITIALIZE_RATIO, MAXIMUM_BLOCK_WEIGHT, NORMAL_DISPATCH_RATIO};
-use fees::SplitFeesByRatio;
 
 pub use sp_consensus_aura::sr25519::AuthorityId;
 
@@ -47,11 +46,18 @@ use sp_std::marker::PhantomData;
 pub mod assets;
 pub mod authorization;
 pub mod constants;
+pub mod deposit_parameters;
+pub mod did_lookup;
 pub mod dip;
 pub mod errors;
 pub mod fees;
+pub mod fungibles;
+pub mod identity_priority;
+pub mod metadata_hash;
 pub mod migrations;
 pub mod pallet_id;
+pub mod staking;
+pub mod storage_stats;
 pub mod xcm_config;
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -158,20 +164,20 @@ parameter_types! {
 		})
 		.avg_block_initialization(AVERAGE_ON_INITIALIZE_RATIO)
 		.build_or_panic();
-
-	/// Fee split ratio between treasury and block author (order is important).
-	pub const FeeSplitRatio: (u32, u32) = (50, 50);
 }
 
-/// Split the fees using a preconfigured Ratio
-/// (`runtime_common::FeeSplitRatio`).
-pub type FeeSplit<R, B1, B2> = SplitFeesByRatio<R, FeeSplitRatio, B1, B2>;
-
 /// Parameterized slow adjusting fee updated based on
 /// <https://w3f-research.readthedocs.io/en/latest/polkadot/Token%20Economics.html#-2.-slow-adjusting-mechanism>
 pub type SlowAdjustingFeeUpdate<R> =
 	TargetedFeeAdjustment<R, TargetBlockFullness, AdjustmentVariable, MinimumMultiplier, MaximumMultiplier>;
 
+/// Fee multiplier update following the block fullness with the same mechanism
+/// as [`SlowAdjustingFeeUpdate`], but with the adjustment variable and the
+/// maximum multiplier provided by the runtime, e.g., as storage parameters that
+/// can be changed by governance.
+pub type BlockFullnessFeeUpdate<R, Variable, Maximum> =
+	TargetedFeeAdjustment<R, TargetBlockFullness, Variable, MinimumMultiplier, Maximum>;
+
 pub struct Tippers<R, I>(PhantomData<R>, PhantomData<I>);
 impl<R, I: 'static> ContainsLengthBound for Tippers<R, I>
 where
@@ -0,0 +1,132 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+//! Signed extension committing to the hash of the runtime metadata, so that
+//! hardware wallets using metadata shortening can verify the decoded
+//! transaction they display.
+//!
+//! The encoding is compatible with `frame-metadata-hash-extension`. The
+//! metadata hash is expected in the `RUNTIME_METADATA_HASH` environment
+//! variable when the runtime is built. If it is not set, transactions signed
+//! with the extension enabled are rejected because of a signature mismatch.
+
+use frame_support::{dispatch::DispatchInfo, traits::Get};
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{DispatchInfoOf, SignedExtension},
+	transaction_validity::TransactionValidityError,
+};
+use sp_std::marker::PhantomData;
+
+/// Whether the metadata hash is part of the signed payload.
+#[derive(Encode, Decode, TypeInfo, Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Mode {
+	/// The metadata hash is not signed.
+	Disabled,
+	/// The metadata hash is signed.
+	Enabled,
+}
+
+/// Includes the metadata hash in the signed payload if enabled by the
+/// signer.
+#[derive(Encode, Decode, TypeInfo, Clone, Eq, PartialEq)]
+#[scale_info(skip_type_params(T))]
+pub struct CheckMetadataHash<T> {
+	mode: Mode,
+	_phantom: PhantomData<T>,
+}
+
+impl<T> CheckMetadataHash<T> {
+	/// Creates the extension, signing the metadata hash if `enable` is true.
+	pub fn new(enable: bool) -> Self {
+		Self {
+			mode: if enable { Mode::Enabled } else { Mode::Disabled },
+			_phantom: PhantomData,
+		}
+	}
+}
+
+impl<T> sp_std::fmt::Debug for CheckMetadataHash<T> {
+	fn fmt(&self, f: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		write!(f, "CheckMetadataHash({:?})", self.mode)
+	}
+}
+
+/// The metadata hash of the runtime, as provided at build time.
+pub struct MetadataHash;
+
+impl Get<Option<[u8; 32]>> for MetadataHash {
+	fn get() -> Option<[u8; 32]> {
+		let hash = option_env!("RUNTIME_METADATA_HASH")?;
+		hex::FromHex::from_hex(hash.trim_start_matches("0x")).ok()
+	}
+}
+
+impl<T> SignedExtension for CheckMetadataHash<T>
+where
+	T: frame_system::Config + Send + Sync,
+	<T as frame_system::Config>::RuntimeCall: sp_runtime::traits::Dispatchable<Info = DispatchInfo>,
+{
+	const IDENTIFIER: &'static str = "CheckMetadataHash";
+
+	type AccountId = T::AccountId;
+	type Call = <T as frame_system::Config>::RuntimeCall;
+	type AdditionalSigned = Option<[u8; 32]>;
+	type Pre = ();
+
+	fn additional_signed(&self) -> Result<Self::AdditionalSigned, TransactionValidityError> {
+		match self.mode {
+			Mode::Disabled => Ok(None),
+			Mode::Enabled => Ok(MetadataHash::get()),
+		}
+	}
+
+	fn pre_dispatch(
+		self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		len: usize,
+	) -> Result<Self::Pre, TransactionValidityError> {
+		self.validate(who, call, info, len).map(|_| ())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn disabled_mode_signs_no_hash() {
+		let extension = CheckMetadataHash::<crate::dip::mock::TestRuntime>::new(false);
+		assert_eq!(extension.additional_signed(), Ok(None));
+	}
+
+	#[test]
+	fn mode_is_encoded_as_single_byte() {
+		assert_eq!(
+			CheckMetadataHash::<crate::dip::mock::TestRuntime>::new(false).encode(),
+			vec![0]
+		);
+		assert_eq!(
+			CheckMetadataHash::<crate::dip::mock::TestRuntime>::new(true).encode(),
+			vec![1]
+		);
+	}
+}
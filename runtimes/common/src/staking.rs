@@ -0,0 +1,244 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use frame_support::traits::Get;
+use frame_system::pallet_prelude::BlockNumberFor;
+use parity_scale_codec::{Decode, DecodeAll, Encode};
+use sp_runtime::{
+	traits::{OpaqueKeys, Verify, Zero},
+	AccountId32, MultiSignature,
+};
+use sp_std::{marker::PhantomData, vec::Vec};
+
+use pallet_did_lookup::linkable_account::LinkableAccountId;
+use parachain_staking::traits::{LinkedAccountsInspect, SessionKeysOwnership};
+
+use crate::Hash;
+
+/// Considers an account linked to a DID if it has been associated to it via
+/// the DID lookup pallet.
+pub struct LinkedDidAccounts<R>(PhantomData<R>);
+
+impl<R> LinkedAccountsInspect<R::DidIdentifier, R::AccountId> for LinkedDidAccounts<R>
+where
+	R: pallet_did_lookup::Config,
+	R::AccountId: Into<LinkableAccountId> + Clone,
+{
+	fn is_linked(identity: &R::DidIdentifier, account: &R::AccountId) -> bool {
+		pallet_did_lookup::ConnectedAccounts::<R>::contains_key(identity, account.clone().into())
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn link_accounts(identity: &R::DidIdentifier, num_accounts: u32) -> sp_std::vec::Vec<R::AccountId> {
+		(0..num_accounts)
+			.map(|i| {
+				let account: R::AccountId = frame_benchmarking::account("linked", i, 0);
+				pallet_did_lookup::ConnectedAccounts::<R>::insert(identity, account.clone().into(), ());
+				account
+			})
+			.collect()
+	}
+}
+
+/// The genesis hash of the chain, as stored by the system pallet.
+pub struct GenesisHash<R>(PhantomData<R>);
+
+impl<R> Get<Hash> for GenesisHash<R>
+where
+	R: frame_system::Config<Hash = Hash>,
+{
+	fn get() -> Hash {
+		frame_system::Pallet::<R>::block_hash(BlockNumberFor::<R>::zero())
+	}
+}
+
+/// The prefix of the payload signed by each session key to prove that it is
+/// owned by the account registering it.
+pub const SESSION_KEYS_OWNERSHIP_PREFIX: &[u8] = b"kilt-session-keys";
+
+/// Verifies that an account owns the session keys it registers.
+///
+/// The proof is the SCALE-encoded list of signatures, one for each key in
+/// the order of [`OpaqueKeys::key_ids`], over
+/// `(SESSION_KEYS_OWNERSHIP_PREFIX, genesis_hash, account).encode()`. The
+/// genesis hash binds the proof to a single chain, so that it cannot be
+/// replayed on another chain the keys are registered on.
+pub struct SessionKeysProofOfPossession<Genesis>(PhantomData<Genesis>);
+
+impl<Genesis> SessionKeysProofOfPossession<Genesis>
+where
+	Genesis: Get<Hash>,
+{
+	/// The payload each session key must sign to prove that it is owned by
+	/// `who`.
+	pub fn signing_payload<AccountId: Encode>(who: &AccountId) -> Vec<u8> {
+		(SESSION_KEYS_OWNERSHIP_PREFIX, Genesis::get(), who).encode()
+	}
+}
+
+impl<AccountId, Keys, Genesis> SessionKeysOwnership<AccountId, Keys> for SessionKeysProofOfPossession<Genesis>
+where
+	AccountId: Encode,
+	Keys: OpaqueKeys + Decode,
+	Genesis: Get<Hash>,
+{
+	fn is_owned_by(who: &AccountId, keys: &Keys, proof: &[u8]) -> bool {
+		let Ok(signatures) = Vec::<MultiSignature>::decode_all(&mut &proof[..]) else {
+			return false;
+		};
+		let key_ids = Keys::key_ids();
+		if signatures.len() != key_ids.len() {
+			return false;
+		}
+
+		let payload = Self::signing_payload(who);
+		key_ids.iter().zip(signatures.iter()).all(|(key_id, signature)| {
+			let Ok(raw_key) = <[u8; 32]>::try_from(keys.get_raw(*key_id)) else {
+				return false;
+			};
+			signature.verify(&payload[..], &AccountId32::from(raw_key))
+		})
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn generate_keys_and_proof(who: &AccountId) -> (Keys, Vec<u8>) {
+		let payload = Self::signing_payload(who);
+		let mut raw_keys = Vec::new();
+		let mut signatures = Vec::new();
+		for key_id in Keys::key_ids() {
+			let public = sp_io::crypto::sr25519_generate(*key_id, None);
+			let signature = sp_io::crypto::sr25519_sign(*key_id, &public, &payload)
+				.expect("The key has just been generated in the keystore.");
+			raw_keys.extend_from_slice(public.as_ref());
+			signatures.push(MultiSignature::from(signature));
+		}
+		let keys = Keys::decode(&mut &raw_keys[..]).expect("Session keys must be decodable from sr25519 public keys.");
+		(keys, signatures.encode())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	use frame_support::parameter_types;
+	use sp_core::{sr25519, Pair};
+	use sp_runtime::{impl_opaque_keys, BoundToRuntimeAppPublic};
+
+	use crate::AuthorityId;
+
+	pub struct AuraKey;
+	impl BoundToRuntimeAppPublic for AuraKey {
+		type Public = AuthorityId;
+	}
+
+	impl_opaque_keys! {
+		pub struct TestSessionKeys {
+			pub aura: AuraKey,
+		}
+	}
+
+	parameter_types! {
+		pub TestGenesisHash: Hash = Hash::repeat_byte(1);
+		pub OtherGenesisHash: Hash = Hash::repeat_byte(2);
+	}
+
+	type ProofOfPossession = SessionKeysProofOfPossession<TestGenesisHash>;
+
+	const ACCOUNT: AccountId32 = AccountId32::new([1u8; 32]);
+	const OTHER_ACCOUNT: AccountId32 = AccountId32::new([2u8; 32]);
+
+	fn keys_and_proof_for_chain(
+		pair: &sr25519::Pair,
+		account: &AccountId32,
+		genesis_hash: Hash,
+	) -> (TestSessionKeys, Vec<u8>) {
+		let keys = TestSessionKeys {
+			aura: pair.public().into(),
+		};
+		let signature: MultiSignature = pair
+			.sign(&(SESSION_KEYS_OWNERSHIP_PREFIX, genesis_hash, account).encode())
+			.into();
+		(keys, vec![signature].encode())
+	}
+
+	fn keys_and_proof(pair: &sr25519::Pair, account: &AccountId32) -> (TestSessionKeys, Vec<u8>) {
+		keys_and_proof_for_chain(pair, account, TestGenesisHash::get())
+	}
+
+	#[test]
+	fn valid_proof_is_accepted() {
+		let pair = sr25519::Pair::from_seed(&[10u8; 32]);
+		let (keys, proof) = keys_and_proof(&pair, &ACCOUNT);
+
+		assert!(<ProofOfPossession as SessionKeysOwnership<_, _>>::is_owned_by(
+			&ACCOUNT, &keys, &proof
+		));
+	}
+
+	#[test]
+	fn proof_for_other_account_is_rejected() {
+		let pair = sr25519::Pair::from_seed(&[10u8; 32]);
+		let (keys, proof) = keys_and_proof(&pair, &OTHER_ACCOUNT);
+
+		assert!(!<ProofOfPossession as SessionKeysOwnership<_, _>>::is_owned_by(
+			&ACCOUNT, &keys, &proof
+		));
+	}
+
+	#[test]
+	fn proof_for_other_chain_is_rejected() {
+		let pair = sr25519::Pair::from_seed(&[10u8; 32]);
+		let (keys, proof) = keys_and_proof_for_chain(&pair, &ACCOUNT, OtherGenesisHash::get());
+
+		assert!(!<ProofOfPossession as SessionKeysOwnership<_, _>>::is_owned_by(
+			&ACCOUNT, &keys, &proof
+		));
+	}
+
+	#[test]
+	fn proof_by_other_key_is_rejected() {
+		let pair = sr25519::Pair::from_seed(&[10u8; 32]);
+		let other_pair = sr25519::Pair::from_seed(&[11u8; 32]);
+		let (keys, _) = keys_and_proof(&pair, &ACCOUNT);
+		let (_, proof) = keys_and_proof(&other_pair, &ACCOUNT);
+
+		assert!(!<ProofOfPossession as SessionKeysOwnership<_, _>>::is_owned_by(
+			&ACCOUNT, &keys, &proof
+		));
+	}
+
+	#[test]
+	fn malformed_proof_is_rejected() {
+		let pair = sr25519::Pair::from_seed(&[10u8; 32]);
+		let (keys, proof) = keys_and_proof(&pair, &ACCOUNT);
+
+		// Missing signature
+		assert!(!<ProofOfPossession as SessionKeysOwnership<_, _>>::is_owned_by(
+			&ACCOUNT,
+			&keys,
+			&Vec::<MultiSignature>::new().encode()
+		));
+		// Trailing bytes
+		let mut proof_with_trailing_bytes = proof;
+		proof_with_trailing_bytes.push(0u8);
+		assert!(!<ProofOfPossession as SessionKeysOwnership<_, _>>::is_owned_by(
+			&ACCOUNT,
+			&keys,
+			&proof_with_trailing_bytes
+		));
+	}
+}
@@ -0,0 +1,133 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+//! Storage usage statistics of the identity pallets, exposed via the
+//! [`kilt_runtime_api_storage_stats::StorageStats`] runtime API.
+
+use frame_support::{Blake2_128Concat, StorageHasher, Twox64Concat};
+use kilt_runtime_api_storage_stats::{IdentityStorageStats, StorageEntryStats};
+use pallet_dip_provider::{IdentityCommitmentOf, IdentityCommitmentVersion};
+use parity_scale_codec::MaxEncodedLen;
+
+/// The length of the storage key prefix shared by all the entries of a
+/// storage item, i.e., the concatenation of the `twox128` hashes of the
+/// pallet and the storage item names.
+const STORAGE_PREFIX_LENGTH: u64 = 32;
+
+/// Return the statistics of a storage item with the given number of entries,
+/// each stored under a key of at most `key_length` bytes, excluding the
+/// storage prefix.
+pub fn storage_entry_stats<Value>(entries: u32, key_length: usize) -> StorageEntryStats
+where
+	Value: MaxEncodedLen,
+{
+	let entry_size = STORAGE_PREFIX_LENGTH
+		.saturating_add(key_length as u64)
+		.saturating_add(Value::max_encoded_len() as u64);
+	StorageEntryStats {
+		entries,
+		approximate_size: entry_size.saturating_mul(entries.into()),
+	}
+}
+
+/// Return the storage usage of the identity pallets that are deployed on all
+/// KILT runtimes. Identity commitments are left empty, and can be set with
+/// [`identity_commitments_stats`] by runtimes that include the DIP provider
+/// pallet.
+pub fn identity_storage_stats<Runtime>() -> IdentityStorageStats
+where
+	Runtime: did::Config
+		+ pallet_web3_names::Config
+		+ pallet_did_lookup::Config
+		+ attestation::Config
+		+ public_credentials::Config,
+{
+	let credentials_count = public_credentials::CredentialSubjects::<Runtime>::count();
+	// Each public credential is stored both in the `Credentials` double map and
+	// in the `CredentialSubjects` reverse index.
+	let credentials = storage_entry_stats::<public_credentials::CredentialEntryOf<Runtime>>(
+		credentials_count,
+		Twox64Concat::max_len::<public_credentials::SubjectIdOf<Runtime>>()
+			.saturating_add(Blake2_128Concat::max_len::<public_credentials::CredentialIdOf<Runtime>>()),
+	);
+	let credential_subjects = storage_entry_stats::<public_credentials::SubjectIdOf<Runtime>>(
+		credentials_count,
+		Blake2_128Concat::max_len::<public_credentials::CredentialIdOf<Runtime>>(),
+	);
+
+	IdentityStorageStats {
+		dids: storage_entry_stats::<did::did_details::DidDetails<Runtime>>(
+			did::Did::<Runtime>::count(),
+			Blake2_128Concat::max_len::<did::DidIdentifierOf<Runtime>>(),
+		),
+		web3_names: storage_entry_stats::<pallet_web3_names::Web3OwnershipOf<Runtime>>(
+			pallet_web3_names::Owner::<Runtime>::count(),
+			Blake2_128Concat::max_len::<pallet_web3_names::Web3NameOf<Runtime>>(),
+		),
+		account_links: storage_entry_stats::<pallet_did_lookup::ConnectionRecordOf<Runtime>>(
+			pallet_did_lookup::ConnectedDids::<Runtime>::count(),
+			Blake2_128Concat::max_len::<pallet_did_lookup::linkable_account::LinkableAccountId>(),
+		),
+		attestations: storage_entry_stats::<attestation::AttestationDetailsOf<Runtime>>(
+			attestation::Attestations::<Runtime>::count(),
+			Blake2_128Concat::max_len::<attestation::ClaimHashOf<Runtime>>(),
+		),
+		public_credentials: StorageEntryStats {
+			entries: credentials_count,
+			approximate_size: credentials
+				.approximate_size
+				.saturating_add(credential_subjects.approximate_size),
+		},
+		identity_commitments: None,
+	}
+}
+
+/// Return the storage usage of the identity commitments stored in the DIP
+/// provider pallet.
+pub fn identity_commitments_stats<Runtime>() -> StorageEntryStats
+where
+	Runtime: pallet_dip_provider::Config,
+	IdentityCommitmentOf<Runtime>: MaxEncodedLen,
+{
+	storage_entry_stats::<IdentityCommitmentOf<Runtime>>(
+		pallet_dip_provider::CounterForIdentityCommitments::<Runtime>::get(),
+		Twox64Concat::max_len::<<Runtime as pallet_dip_provider::Config>::Identifier>()
+			.saturating_add(Twox64Concat::max_len::<IdentityCommitmentVersion>()),
+	)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn storage_entry_stats_includes_prefix_key_and_value() {
+		// 32 bytes of prefix + 24 bytes of key + 4 bytes of value.
+		assert_eq!(
+			storage_entry_stats::<u32>(10, Blake2_128Concat::max_len::<u64>()),
+			StorageEntryStats {
+				entries: 10,
+				approximate_size: 600
+			}
+		);
+		assert_eq!(
+			storage_entry_stats::<u32>(0, Blake2_128Concat::max_len::<u64>()),
+			StorageEntryStats::default()
+		);
+	}
+}
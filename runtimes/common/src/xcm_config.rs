@@ -17,13 +17,28 @@
 // If you feel like getting in touch with us, you can do so at info@botlabs.org
 
 use core::{marker::PhantomData, ops::ControlFlow};
-use frame_support::{log, match_types, parameter_types, traits::ProcessMessageError, weights::Weight};
+use cumulus_primitives_utility::{ChargeWeightInFungibles, TakeFirstAssetTrader, XcmFeesTo32ByteAccount};
+use frame_support::{
+	log, match_types, parameter_types,
+	traits::{fungibles, tokens::ConversionToAssetBalance, Contains, ContainsPair, Get, ProcessMessageError},
+	weights::{Weight, WeightToFee as WeightToFeeT},
+};
 use polkadot_parachain::primitives::Sibling;
 use xcm::v3::prelude::*;
-use xcm_builder::{AccountId32Aliases, CurrencyAdapter, IsConcrete, ParentIsPreset, SiblingParachainConvertsVia};
+use xcm_builder::{
+	AccountId32Aliases, AsPrefixedGeneralIndex, ConvertedConcreteId, CurrencyAdapter, DescribeAllTerminal,
+	DescribeFamily, FungiblesAdapter, HashedDescription, IsConcrete, MatchedConvertedConcreteId, NoChecking,
+	ParentIsPreset, SiblingParachainConvertsVia,
+};
+use xcm_executor::traits::{ConvertLocation, Identity, JustTry};
 use xcm_executor::traits::{Properties, ShouldExecute};
 
-use crate::AccountId;
+use parity_scale_codec::Decode;
+
+use crate::{AccountId, Balance};
+
+/// The parachain id of AssetHub on both Polkadot and Rococo.
+pub const ASSET_HUB_PARA_ID: u32 = 1000;
 
 parameter_types! {
 	// One XCM operation is 1_000_000_000 weight, almost certainly a conservative estimate.
@@ -37,6 +52,14 @@ match_types! {
 	pub type ParentLegislative: impl Contains<MultiLocation> = {
 		MultiLocation { parents: 1, interior: X1(Plurality { id: BodyId::Legislative, .. }) }
 	};
+	// The AssetHub system parachain of our relay chain.
+	pub type AssetHub: impl Contains<MultiLocation> = {
+		MultiLocation { parents: 1, interior: X1(Parachain(ASSET_HUB_PARA_ID)) }
+	};
+	// An account on a sibling parachain, e.g., after a `DescendOrigin` instruction.
+	pub type SiblingParachainAccount: impl Contains<MultiLocation> = {
+		MultiLocation { parents: 1, interior: X2(Parachain(_), AccountId32 { .. }) }
+	};
 }
 
 // Note: This might move to polkadot's xcm module.
@@ -126,6 +149,69 @@ impl ShouldExecute for DenyReserveTransferToRelayChain {
 	}
 }
 
+/// Deny executing the xcm message if it contains a `Transact` of a call matched
+/// by `IdentityCalls` while `Paused` returns `true`. Messages without such a
+/// `Transact`, e.g., token transfers, are not affected. The instructions
+/// nested in `SetErrorHandler` and `SetAppendix` are inspected as well, since
+/// they are executed as part of the message.
+///
+/// `DecodedCall` is the runtime call type the `Transact` payload is decoded
+/// into. Payloads that cannot be decoded are left to the executor to reject.
+pub struct DenyIdentityTransactsWhilePaused<Paused, DecodedCall, IdentityCalls>(
+	PhantomData<(Paused, DecodedCall, IdentityCalls)>,
+);
+
+impl<Paused, DecodedCall, IdentityCalls> ShouldExecute
+	for DenyIdentityTransactsWhilePaused<Paused, DecodedCall, IdentityCalls>
+where
+	Paused: Get<bool>,
+	DecodedCall: Decode,
+	IdentityCalls: Contains<DecodedCall>,
+{
+	fn should_execute<RuntimeCall>(
+		origin: &MultiLocation,
+		message: &mut [Instruction<RuntimeCall>],
+		_max_weight: Weight,
+		_properties: &mut Properties,
+	) -> Result<(), ProcessMessageError> {
+		if !Paused::get() {
+			return Ok(());
+		}
+		if Self::targets_identity_pallets(message) {
+			log::warn!(
+				target: "xcm::barrier",
+				"Identity operation from {:?} denied while XCM identity operations are paused",
+				origin,
+			);
+			Err(ProcessMessageError::Unsupported)
+		} else {
+			Ok(())
+		}
+	}
+}
+
+impl<Paused, DecodedCall, IdentityCalls> DenyIdentityTransactsWhilePaused<Paused, DecodedCall, IdentityCalls>
+where
+	DecodedCall: Decode,
+	IdentityCalls: Contains<DecodedCall>,
+{
+	/// Whether any of the instructions, including the nested ones, is a
+	/// `Transact` of an identity call. The nesting depth is bounded by the
+	/// decoding of the message.
+	fn targets_identity_pallets<RuntimeCall>(instructions: &[Instruction<RuntimeCall>]) -> bool {
+		instructions.iter().any(|inst| match inst {
+			Transact { call, .. } => call
+				.clone()
+				.into::<DecodedCall>()
+				.take_decoded()
+				.map(|decoded_call| IdentityCalls::contains(&decoded_call))
+				.unwrap_or(false),
+			SetErrorHandler(nested) | SetAppendix(nested) => Self::targets_identity_pallets(&nested.0),
+			_ => false,
+		})
+	}
+}
+
 parameter_types! {
 	pub const RelayLocation: MultiLocation = MultiLocation::parent();
 	pub const HereLocation: MultiLocation = MultiLocation::here();
@@ -142,6 +228,9 @@ pub type LocationToAccountId<NetworkId> = (
 	SiblingParachainConvertsVia<Sibling, AccountId>,
 	// Straight up local `AccountId32` origins just alias directly to `AccountId`.
 	AccountId32Aliases<NetworkId, AccountId>,
+	// Accounts on other chains, e.g., sibling parachain accounts that used `DescendOrigin`, are converted into an
+	// `AccountId` by hashing their description.
+	HashedDescription<AccountId, DescribeFamily<DescribeAllTerminal>>,
 );
 
 /// Means for transacting assets on this chain.
@@ -157,3 +246,320 @@ pub type LocalAssetTransactor<Currency, NetworkId> = CurrencyAdapter<
 	// We don't track any teleports.
 	(),
 >;
+
+/// Means for transacting the fungible assets issued on this chain via
+/// `pallet_assets`.
+///
+/// An asset is identified by the location
+/// `X2(PalletInstance(<assets pallet index>), GeneralIndex(<asset id>))`
+/// relative to this chain, where `AssetsPalletLocation` is the location of the
+/// assets pallet. Teleports are not tracked, hence no checking account is
+/// used.
+pub type LocalFungiblesTransactor<Assets, AssetsPalletLocation, NetworkId> = FungiblesAdapter<
+	// Use this fungibles implementation:
+	Assets,
+	// Use this asset when its location is prefixed by the assets pallet location:
+	ConvertedConcreteId<u32, Balance, AsPrefixedGeneralIndex<AssetsPalletLocation, u32, JustTry>, JustTry>,
+	// Convert an XCM MultiLocation into a local account id:
+	LocationToAccountId<NetworkId>,
+	// Our chain's account ID type (we can't get away without mentioning it explicitly):
+	AccountId,
+	// We don't track any teleports.
+	NoChecking,
+	// The checking account, unused since teleports are not tracked.
+	CheckingAccount,
+>;
+
+parameter_types! {
+	pub CheckingAccount: AccountId = AccountId::new([0u8; 32]);
+}
+
+/// Matches the locations of the assets that are not native to this chain,
+/// i.e., the locations that are outside of this consensus system.
+pub struct IsForeignLocation;
+
+impl Contains<MultiLocation> for IsForeignLocation {
+	fn contains(location: &MultiLocation) -> bool {
+		location.parents > 0
+	}
+}
+
+/// Converts foreign assets, which are registered in `pallet_assets` under
+/// their location as seen from this chain.
+pub type ForeignAssetsConvertedConcreteId =
+	MatchedConvertedConcreteId<MultiLocation, Balance, IsForeignLocation, Identity, JustTry>;
+
+/// Means for transacting the foreign assets registered via `pallet_assets`,
+/// e.g., DOT or the stablecoins issued on AssetHub.
+///
+/// Foreign assets are registered by governance, hence an asset that has not
+/// been registered cannot be deposited on this chain.
+pub type ForeignFungiblesTransactor<ForeignAssets, NetworkId> = FungiblesAdapter<
+	// Use this fungibles implementation:
+	ForeignAssets,
+	// Use this asset when its location is outside of this chain:
+	ForeignAssetsConvertedConcreteId,
+	// Convert an XCM MultiLocation into a local account id:
+	LocationToAccountId<NetworkId>,
+	// Our chain's account ID type (we can't get away without mentioning it explicitly):
+	AccountId,
+	// We don't track any teleports.
+	NoChecking,
+	// The checking account, unused since teleports are not tracked.
+	CheckingAccount,
+>;
+
+/// Charges the weight of an XCM in a foreign asset. The weight is converted
+/// into KILT via `WeightToFee`, which is then converted into the foreign asset
+/// via `BalanceConverter`, which is the same conversion that is used for
+/// transaction fees paid in foreign assets.
+pub struct ForeignAssetFeeCharger<WeightToFee, BalanceConverter>(PhantomData<(WeightToFee, BalanceConverter)>);
+
+impl<ForeignAssets, WeightToFee, BalanceConverter> ChargeWeightInFungibles<AccountId, ForeignAssets>
+	for ForeignAssetFeeCharger<WeightToFee, BalanceConverter>
+where
+	ForeignAssets: fungibles::Inspect<AccountId, AssetId = MultiLocation, Balance = Balance>,
+	WeightToFee: WeightToFeeT<Balance = Balance>,
+	BalanceConverter: ConversionToAssetBalance<Balance, MultiLocation, Balance>,
+{
+	fn charge_weight_in_fungibles(asset_id: MultiLocation, weight: Weight) -> Result<Balance, XcmError> {
+		let amount = WeightToFee::weight_to_fee(&weight);
+		BalanceConverter::to_asset_balance(amount, asset_id).map_err(|_| XcmError::TooExpensive)
+	}
+}
+
+/// Buys XCM execution with the first foreign asset in the holding register.
+/// The fees are deposited into the account returned by `FeeBeneficiary`.
+pub type ForeignAssetsTrader<ForeignAssets, WeightToFee, BalanceConverter, NetworkId, FeeBeneficiary> =
+	TakeFirstAssetTrader<
+		AccountId,
+		ForeignAssetFeeCharger<WeightToFee, BalanceConverter>,
+		ForeignAssetsConvertedConcreteId,
+		ForeignAssets,
+		XcmFeesTo32ByteAccount<ForeignFungiblesTransactor<ForeignAssets, NetworkId>, AccountId, FeeBeneficiary>,
+	>;
+
+/// Accepts the relay chain token and the assets issued on AssetHub as reserve
+/// assets if they are transferred from `AssetHubLocation`.
+pub struct IsAssetHubReserve<AssetHubLocation>(PhantomData<AssetHubLocation>);
+
+impl<AssetHubLocation> ContainsPair<MultiAsset, MultiLocation> for IsAssetHubReserve<AssetHubLocation>
+where
+	AssetHubLocation: Get<MultiLocation>,
+{
+	fn contains(asset: &MultiAsset, origin: &MultiLocation) -> bool {
+		let asset_hub_location = AssetHubLocation::get();
+		if origin != &asset_hub_location {
+			return false;
+		}
+
+		match asset.id {
+			Concrete(location) => location == MultiLocation::parent() || location.starts_with(&asset_hub_location),
+			_ => false,
+		}
+	}
+}
+
+parameter_types! {
+	pub AssetHubLocation: MultiLocation = MultiLocation::new(1, X1(Parachain(ASSET_HUB_PARA_ID)));
+}
+
+/// Allows reserve transfers via `pallet_xcm` only if all the transferred
+/// assets are the native token of this chain, which is the reserve for them.
+///
+/// Note that `pallet_xcm` checks the origin of a reserve transfer against this
+/// filter, not its destination.
+pub struct OnlyNativeAssetReserveTransfers;
+
+impl Contains<(MultiLocation, sp_std::vec::Vec<MultiAsset>)> for OnlyNativeAssetReserveTransfers {
+	fn contains((_, assets): &(MultiLocation, sp_std::vec::Vec<MultiAsset>)) -> bool {
+		!assets.is_empty()
+			&& assets.iter().all(|asset| {
+				matches!(
+					asset,
+					MultiAsset {
+						id: Concrete(location),
+						fun: Fungible(_),
+					} if *location == HereLocation::get()
+				)
+			})
+	}
+}
+
+/// Converts only the locations of accounts on sibling parachains via
+/// `AccountConverter`, e.g., to let users of other chains control their own
+/// account on this chain without giving sibling parachains themselves control
+/// over an account.
+pub struct SiblingAccountsOnly<AccountConverter>(PhantomData<AccountConverter>);
+
+impl<AccountConverter> ConvertLocation<AccountId> for SiblingAccountsOnly<AccountConverter>
+where
+	AccountConverter: ConvertLocation<AccountId>,
+{
+	fn convert_location(location: &MultiLocation) -> Option<AccountId> {
+		if SiblingParachainAccount::contains(location) {
+			AccountConverter::convert_location(location)
+		} else {
+			None
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn asset_hub_reserve() {
+		let usdt = MultiLocation::new(
+			1,
+			X3(Parachain(ASSET_HUB_PARA_ID), PalletInstance(50), GeneralIndex(1984)),
+		);
+		let dot = MultiLocation::parent();
+		let other_para = MultiLocation::new(1, X1(Parachain(2000)));
+
+		assert!(IsAssetHubReserve::<AssetHubLocation>::contains(
+			&(usdt, 1).into(),
+			&AssetHubLocation::get()
+		));
+		assert!(IsAssetHubReserve::<AssetHubLocation>::contains(
+			&(dot, 1).into(),
+			&AssetHubLocation::get()
+		));
+		assert!(!IsAssetHubReserve::<AssetHubLocation>::contains(
+			&(other_para, 1).into(),
+			&AssetHubLocation::get()
+		));
+		assert!(!IsAssetHubReserve::<AssetHubLocation>::contains(
+			&(usdt, 1).into(),
+			&other_para
+		));
+	}
+
+	#[test]
+	fn sibling_parachain_account() {
+		let account = AccountId32 {
+			network: None,
+			id: [1u8; 32],
+		};
+
+		assert!(SiblingParachainAccount::contains(&MultiLocation::new(
+			1,
+			X2(Parachain(2000), account)
+		)));
+		assert!(!SiblingParachainAccount::contains(&MultiLocation::new(
+			1,
+			X1(Parachain(2000))
+		)));
+		assert!(!SiblingParachainAccount::contains(&MultiLocation::new(0, X1(account))));
+		assert!(!SiblingParachainAccount::contains(&MultiLocation::new(1, X1(account))));
+	}
+
+	#[test]
+	fn only_native_asset_reserve_transfers() {
+		let origin = MultiLocation::new(
+			0,
+			X1(AccountId32 {
+				network: None,
+				id: [1u8; 32],
+			}),
+		);
+		let native: MultiAsset = (HereLocation::get(), 1).into();
+		let foreign: MultiAsset = (MultiLocation::parent(), 1).into();
+
+		assert!(OnlyNativeAssetReserveTransfers::contains(&(
+			origin,
+			vec![native.clone()]
+		)));
+		assert!(!OnlyNativeAssetReserveTransfers::contains(&(
+			origin,
+			vec![foreign.clone()]
+		)));
+		assert!(!OnlyNativeAssetReserveTransfers::contains(&(
+			origin,
+			vec![native, foreign]
+		)));
+		assert!(!OnlyNativeAssetReserveTransfers::contains(&(origin, vec![])));
+	}
+
+	#[test]
+	fn deny_identity_transacts_while_paused() {
+		use frame_support::traits::ConstBool;
+		use parity_scale_codec::Encode;
+
+		// Calls are represented by a single byte, and `1` is the only identity call.
+		struct IsIdentityCall;
+		impl Contains<u8> for IsIdentityCall {
+			fn contains(call: &u8) -> bool {
+				*call == 1
+			}
+		}
+
+		fn should_execute<Barrier: ShouldExecute>(message: &mut [Instruction<()>]) -> Result<(), ProcessMessageError> {
+			Barrier::should_execute(
+				&AssetHubLocation::get(),
+				message,
+				Weight::zero(),
+				&mut Properties {
+					weight_credit: Weight::zero(),
+					message_id: None,
+				},
+			)
+		}
+
+		fn transact_message(call: u8) -> Vec<Instruction<()>> {
+			vec![
+				WithdrawAsset((Here, 1).into()),
+				Transact {
+					origin_kind: OriginKind::SovereignAccount,
+					require_weight_at_most: Weight::zero(),
+					call: call.encode().into(),
+				},
+			]
+		}
+
+		type Paused = DenyIdentityTransactsWhilePaused<ConstBool<true>, u8, IsIdentityCall>;
+		type NotPaused = DenyIdentityTransactsWhilePaused<ConstBool<false>, u8, IsIdentityCall>;
+
+		let mut transfer_message = vec![
+			WithdrawAsset((Here, 1).into()),
+			DepositAsset {
+				assets: All.into(),
+				beneficiary: AssetHubLocation::get(),
+			},
+		];
+
+		assert_eq!(
+			should_execute::<Paused>(&mut transact_message(1)),
+			Err(ProcessMessageError::Unsupported)
+		);
+		assert_eq!(should_execute::<Paused>(&mut transact_message(2)), Ok(()));
+		assert_eq!(should_execute::<Paused>(&mut transfer_message), Ok(()));
+		assert_eq!(should_execute::<NotPaused>(&mut transact_message(1)), Ok(()));
+
+		// Identity calls nested in an error handler or appendix are denied as well.
+		let mut error_handler_message = vec![SetErrorHandler(Xcm(transact_message(1)))];
+		let mut appendix_message = vec![SetAppendix(Xcm(vec![SetErrorHandler(Xcm(transact_message(1)))]))];
+		assert_eq!(
+			should_execute::<Paused>(&mut error_handler_message),
+			Err(ProcessMessageError::Unsupported)
+		);
+		assert_eq!(
+			should_execute::<Paused>(&mut appendix_message),
+			Err(ProcessMessageError::Unsupported)
+		);
+		let mut other_appendix_message = vec![SetAppendix(Xcm(transact_message(2)))];
+		assert_eq!(should_execute::<Paused>(&mut other_appendix_message), Ok(()));
+	}
+
+	#[test]
+	fn foreign_locations() {
+		assert!(IsForeignLocation::contains(&MultiLocation::parent()));
+		assert!(IsForeignLocation::contains(&AssetHubLocation::get()));
+		assert!(!IsForeignLocation::contains(&MultiLocation::here()));
+		assert!(!IsForeignLocation::contains(&MultiLocation::new(
+			0,
+			X2(PalletInstance(48), GeneralIndex(0))
+		)));
+	}
+}
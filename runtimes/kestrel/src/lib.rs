@@ -286,6 +286,7 @@ impl pallet_sudo::Config for Runtime {
 
 parameter_types! {
 	pub const MaxDelegatedAttestations: u32 = 1000;
+	pub const MaxCidLength: u32 = 64;
 	pub const AttestationDeposit: Balance = constants::attestation::ATTESTATION_DEPOSIT;
 }
 
@@ -297,11 +298,28 @@ impl attestation::Config for Runtime {
 	type WeightInfo = ();
 	type Currency = Balances;
 	type Deposit = AttestationDeposit;
+	type IssuanceDeposit = constants::attestation::IssuanceDeposit;
 	type MaxDelegatedAttestations = MaxDelegatedAttestations;
+	type MaxCidLength = MaxCidLength;
 	type AttesterId = DidIdentifier;
 	type AuthorizationId = AuthorizationId<<Runtime as delegation::Config>::DelegationNodeId>;
 	type AccessControl = PalletAuthorize<DelegationAc<Runtime>>;
 	type BalanceMigrationManager = ();
+	type AttesterAccreditation = ();
+	type ChallengeBond = constants::attestation::ChallengeBond;
+	type ChallengeResponsePeriod = constants::attestation::ChallengeResponsePeriod;
+	type ChallengeAdjudicatorOrigin = EnsureRoot<AccountId>;
+
+	#[cfg(not(feature = "runtime-benchmarks"))]
+	type ClaimerSignature = did::DidSignature;
+	#[cfg(not(feature = "runtime-benchmarks"))]
+	type ClaimerSignatureVerification = did::DidSignatureVerify<Self>;
+
+	#[cfg(feature = "runtime-benchmarks")]
+	type ClaimerSignature = runtime_common::benchmarks::DummySignature;
+	#[cfg(feature = "runtime-benchmarks")]
+	type ClaimerSignatureVerification =
+		kilt_support::signature::AlwaysVerify<AccountId, Vec<u8>, Self::ClaimerSignature>;
 }
 
 parameter_types! {
@@ -350,6 +368,11 @@ impl ctype::Config for Runtime {
 	type Currency = Balances;
 	type Fee = Fee;
 	type FeeCollector = runtime_common::fees::ToAuthorCredit<Runtime>;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type MaxSchemaLength = constants::ctype::MaxSchemaLength;
+	type SchemaBaseDeposit = constants::ctype::SchemaBaseDeposit;
+	type SchemaTierSize = constants::ctype::SchemaTierSize;
+	type SchemaTierDeposit = constants::ctype::SchemaTierDeposit;
 
 	type CtypeCreatorId = DidIdentifier;
 	type EnsureOrigin = did::EnsureDidOrigin<DidIdentifier, AccountId>;
@@ -375,6 +398,12 @@ parameter_types! {
 	pub const MaxServiceUrlLength: u32 = constants::did::MAX_SERVICE_URL_LENGTH;
 	pub const MaxNumberOfTypesPerService: u32 = constants::did::MAX_NUMBER_OF_TYPES_PER_SERVICE;
 	pub const MaxNumberOfUrlsPerService: u32 = constants::did::MAX_NUMBER_OF_URLS_PER_SERVICE;
+	pub const MaxKeyHistoryLength: u32 = constants::did::MAX_KEY_HISTORY_LENGTH;
+	pub const MaxRemovedKeys: u32 = constants::did::MAX_REMOVED_KEYS;
+	pub const MaxEndorsementsPerDid: u32 = constants::did::MAX_ENDORSEMENTS_PER_DID;
+	pub const MaxAlsoKnownAs: u32 = constants::did::MAX_ALSO_KNOWN_AS;
+	pub const MaxAlsoKnownAsUriLength: u32 = constants::did::MAX_ALSO_KNOWN_AS_URI_LENGTH;
+	pub const MaxDidDocumentSize: u32 = constants::did::MAX_DID_DOCUMENT_SIZE;
 }
 
 impl did::Config for Runtime {
@@ -410,6 +439,17 @@ impl did::Config for Runtime {
 	type MaxServiceUrlLength = MaxServiceUrlLength;
 	type MaxNumberOfTypesPerService = MaxNumberOfTypesPerService;
 	type MaxNumberOfUrlsPerService = MaxNumberOfUrlsPerService;
+	type MaxKeyHistoryLength = MaxKeyHistoryLength;
+	type MaxRemovedKeys = MaxRemovedKeys;
+	type KeyHistoryDeposit = constants::did::KeyHistoryDeposit;
+	type MaxEndorsementsPerDid = MaxEndorsementsPerDid;
+	type MaxAlsoKnownAs = MaxAlsoKnownAs;
+	type MaxAlsoKnownAsUriLength = MaxAlsoKnownAsUriLength;
+	type MaxDidDocumentSize = MaxDidDocumentSize;
+	type MaxMultisigSignatories = constants::multisig::MaxSignitors;
+	type AllowedKeyTypes = Everything;
+	type FreeTrialOperations = frame_support::traits::ConstU32<0>;
+	type LegacySignatureDeadline = constants::did::LegacySignatureDeadline;
 	type WeightInfo = ();
 	type BalanceMigrationManager = ();
 }
@@ -422,16 +462,36 @@ impl pallet_did_lookup::Config for Runtime {
 
 	type Currency = Balances;
 	type Deposit = constants::did_lookup::DidLookupDeposit;
+	type LabelDeposit = constants::did_lookup::LabelDeposit;
 
 	type EnsureOrigin = did::EnsureDidOrigin<DidIdentifier, AccountId>;
 	type OriginSuccess = did::DidRawOrigin<AccountId, DidIdentifier>;
 	type BalanceMigrationManager = ();
+	#[cfg(not(feature = "runtime-benchmarks"))]
+	type ContractSignatureVerifier = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type ContractSignatureVerifier = runtime_common::benchmarks::AlwaysVerifyContractSignature;
+	type MaxAssociationRemovals = constants::did_lookup::MaxAssociationRemovals;
+	type MaxProofValidity = constants::did_lookup::MaxProofValidity;
 	type WeightInfo = ();
 }
 
+parameter_types! {
+	pub const NoTreasuryAccount: AccountId = AccountId::new([0u8; 32]);
+}
+
 impl pallet_web3_names::Config for Runtime {
 	type RuntimeHoldReason = RuntimeHoldReason;
 	type BanOrigin = EnsureRoot<AccountId>;
+	type BanAppealOrigin = EnsureRoot<AccountId>;
+	type BanDelay = constants::web3_names::BanDelay;
+	type MaxBansPerBlock = constants::web3_names::MaxBansPerBlock;
+	type RegistrarOrigin = EnsureRoot<AccountId>;
+	type MaxClaimBatchSize = constants::web3_names::MaxClaimBatchSize;
+	type RegistrarApprovalOrigin = EnsureRoot<AccountId>;
+	// There is no treasury in this runtime, so registrars retain the whole fee.
+	type RegistrarTreasuryShare = ();
+	type TreasuryAccount = NoTreasuryAccount;
 	type OwnerOrigin = did::EnsureDidOrigin<DidIdentifier, AccountId>;
 	type OriginSuccess = did::DidRawOrigin<AccountId, DidIdentifier>;
 	type Currency = Balances;
@@ -441,6 +501,10 @@ impl pallet_web3_names::Config for Runtime {
 	type MinNameLength = constants::web3_names::MinNameLength;
 	type Web3Name = pallet_web3_names::web3_name::AsciiWeb3Name<Runtime>;
 	type Web3NameOwner = DidIdentifier;
+	type MaxRecordKeyLength = constants::web3_names::MaxRecordKeyLength;
+	type MaxRecordValueLength = constants::web3_names::MaxRecordValueLength;
+	type MaxRecordsPerName = constants::web3_names::MaxRecordsPerName;
+	type RecordDeposit = constants::web3_names::RecordDeposit;
 	type WeightInfo = ();
 	type BalanceMigrationManager = ();
 }
@@ -492,6 +556,7 @@ impl public_credentials::Config for Runtime {
 	type MaxSubjectIdLength = runtime_common::constants::public_credentials::MaxSubjectIdLength;
 	type OriginSuccess = did::DidRawOrigin<AccountId, DidIdentifier>;
 	type RuntimeEvent = RuntimeEvent;
+	type SubjectControllerOrigin = EnsureRoot<AccountId>;
 	type SubjectId = runtime_common::assets::AssetDid;
 	type WeightInfo = ();
 	type BalanceMigrationManager = ();
@@ -551,10 +616,15 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 				RuntimeCall::Attestation(
 						// Excludes `reclaim_deposit`
 						attestation::Call::add { .. }
+							| attestation::Call::add_with_claimer_ack { .. }
 							| attestation::Call::remove { .. }
 							| attestation::Call::revoke { .. }
 							| attestation::Call::change_deposit_owner { .. }
 							| attestation::Call::update_deposit { .. }
+							| attestation::Call::challenge { .. }
+							| attestation::Call::contest_challenge { .. }
+							| attestation::Call::resolve_challenge { .. }
+							| attestation::Call::expire_challenge { .. }
 					)
 					// Excludes `Balances`
 					| RuntimeCall::Ctype(..)
@@ -564,25 +634,34 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 							| delegation::Call::create_hierarchy { .. }
 							| delegation::Call::remove_delegation { .. }
 							| delegation::Call::revoke_delegation { .. }
+							| delegation::Call::revoke_hierarchy_paged { .. }
 							| delegation::Call::update_deposit { .. }
 							| delegation::Call::change_deposit_owner { .. }
 					)
 					| RuntimeCall::Did(
 						// Excludes `reclaim_deposit`
-						did::Call::add_key_agreement_key { .. }
+						did::Call::add_hashed_service_endpoint { .. }
+							| did::Call::add_key_agreement_key { .. }
+							| did::Call::add_also_known_as { .. }
 							| did::Call::add_service_endpoint { .. }
 							| did::Call::create { .. }
 							| did::Call::delete { .. }
+							| did::Call::promote_light_did { .. }
 							| did::Call::remove_attestation_key { .. }
 							| did::Call::remove_delegation_key { .. }
 							| did::Call::remove_key_agreement_key { .. }
+							| did::Call::remove_also_known_as { .. }
 							| did::Call::remove_service_endpoint { .. }
 							| did::Call::set_attestation_key { .. }
 							| did::Call::set_authentication_key { .. }
+							| did::Call::set_multisig_authentication_key { .. }
 							| did::Call::set_delegation_key { .. }
 							| did::Call::submit_did_call { .. }
 							| did::Call::update_deposit { .. }
 							| did::Call::change_deposit_owner { .. }
+							| did::Call::did_remark { .. }
+							| did::Call::endorse { .. }
+							| did::Call::revoke_endorsement { .. }
 					)
 					| RuntimeCall::DidLookup(
 						// Excludes `reclaim_deposit`
@@ -603,6 +682,8 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 						| public_credentials::Call::remove { .. }
 						| public_credentials::Call::update_deposit { .. }
 						| public_credentials::Call::change_deposit_owner { .. }
+						| public_credentials::Call::set_subject_controller { .. }
+						| public_credentials::Call::set_credential_visibility { .. }
 					)
 					| RuntimeCall::Session(..)
 					// Excludes `Sudo`
@@ -614,8 +695,14 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 						pallet_web3_names::Call::claim { .. }
 							| pallet_web3_names::Call::release_by_owner { .. }
 							| pallet_web3_names::Call::unban { .. }
+							| pallet_web3_names::Call::appeal_ban { .. }
+							| pallet_web3_names::Call::resolve_ban_appeal { .. }
+							| pallet_web3_names::Call::cancel_ban { .. }
 							| pallet_web3_names::Call::update_deposit { .. }
 							| pallet_web3_names::Call::change_deposit_owner { .. }
+							| pallet_web3_names::Call::claim_batch { .. }
+							| pallet_web3_names::Call::accept_pending_claim { .. }
+							| pallet_web3_names::Call::decline_pending_claim { .. }
 					),
 			),
 			ProxyType::CancelProxy => matches!(c, RuntimeCall::Proxy(pallet_proxy::Call::reject_announcement { .. })),
@@ -735,10 +822,21 @@ impl did::DeriveDidCallAuthorizationVerificationKeyRelationship for RuntimeCall
 			RuntimeCall::Ctype { .. } => Ok(did::DidVerificationKeyRelationship::AssertionMethod),
 			RuntimeCall::Delegation { .. } => Ok(did::DidVerificationKeyRelationship::CapabilityDelegation),
 			// DID creation is not allowed through the DID proxy.
-			RuntimeCall::Did(did::Call::create { .. }) => Err(did::RelationshipDeriveError::NotCallableByDid),
+			RuntimeCall::Did(did::Call::create { .. } | did::Call::promote_light_did { .. }) => {
+				Err(did::RelationshipDeriveError::NotCallableByDid)
+			}
+			// Anchoring content is an assertion made by the DID.
+			RuntimeCall::Did(did::Call::did_remark { .. }) => Ok(did::DidVerificationKeyRelationship::AssertionMethod),
+			// Endorsing other DIDs is an assertion made by the DID.
+			RuntimeCall::Did(did::Call::endorse { .. } | did::Call::revoke_endorsement { .. }) => {
+				Ok(did::DidVerificationKeyRelationship::AssertionMethod)
+			}
 			RuntimeCall::Did { .. } => Ok(did::DidVerificationKeyRelationship::Authentication),
 			RuntimeCall::Web3Names { .. } => Ok(did::DidVerificationKeyRelationship::Authentication),
 			RuntimeCall::DidLookup { .. } => Ok(did::DidVerificationKeyRelationship::Authentication),
+			RuntimeCall::PublicCredentials(public_credentials::Call::set_credential_visibility { .. }) => {
+				Ok(did::DidVerificationKeyRelationship::Authentication)
+			}
 			RuntimeCall::PublicCredentials { .. } => Ok(did::DidVerificationKeyRelationship::AssertionMethod),
 			RuntimeCall::Utility(pallet_utility::Call::batch { calls }) => single_key_relationship(&calls[..]),
 			RuntimeCall::Utility(pallet_utility::Call::batch_all { calls }) => single_key_relationship(&calls[..]),
@@ -1081,9 +1179,10 @@ impl_runtime_apis! {
 			public_credentials::Credentials::<Runtime>::get(subject, credential_id)
 		}
 
-		fn get_by_subject(subject: Vec<u8>, filter: Option<PublicCredentialsFilter<Hash, AccountId>>) -> Result<Vec<(Hash, public_credentials::CredentialEntry<Hash, DidIdentifier, BlockNumber, AccountId, Balance, AuthorizationId<<Runtime as delegation::Config>::DelegationNodeId>>)>, PublicCredentialsApiError> {
+		fn get_by_subject(subject: Vec<u8>, filter: Option<PublicCredentialsFilter<Hash, AccountId>>, include_hidden: bool) -> Result<Vec<(Hash, public_credentials::CredentialEntry<Hash, DidIdentifier, BlockNumber, AccountId, Balance, AuthorizationId<<Runtime as delegation::Config>::DelegationNodeId>>)>, PublicCredentialsApiError> {
 			let asset_did = AssetDid::try_from(subject).map_err(|_| PublicCredentialsApiError::InvalidSubjectId)?;
-			let credentials_prefix = public_credentials::Credentials::<Runtime>::iter_prefix(asset_did);
+			let credentials_prefix = public_credentials::Credentials::<Runtime>::iter_prefix(asset_did)
+				.filter(|(credential_id, _)| include_hidden || !public_credentials::Pallet::<Runtime>::is_credential_hidden(credential_id));
 			if let Some(filter) = filter {
 				Ok(credentials_prefix.filter(|(_, entry)| filter.should_include(entry)).collect())
 			} else {
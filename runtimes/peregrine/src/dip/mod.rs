@@ -17,9 +17,14 @@
 // If you feel like getting in touch with us, you can do so at info@botlabs.org
 
 use did::{DidRawOrigin, EnsureDidOrigin};
-use frame_system::EnsureSigned;
+use frame_support::traits::EitherOfDiverse;
+use frame_system::{EnsureRoot, EnsureSigned};
 use runtime_common::{
-	constants::{deposit_storage::MAX_DEPOSIT_PALLET_KEY_LENGTH, dip_provider::MAX_LINKED_ACCOUNTS},
+	constants::{
+		deposit_storage::MAX_DEPOSIT_PALLET_KEY_LENGTH,
+		dip_provider::{COMMITTER_DEPOSIT, MAX_LINKED_ACCOUNTS},
+	},
+	deposit_parameters::{DepositParameter, DepositParameterBounds},
 	dip::{
 		deposit::{DepositCollectorHooks, DepositHooks, DepositNamespace},
 		did::LinkedDidInfoProvider,
@@ -27,17 +32,19 @@ use runtime_common::{
 	},
 	AccountId, DidIdentifier,
 };
-use sp_core::ConstU32;
+use sp_core::{ConstU128, ConstU32};
 
-use crate::{weights, Balances, Runtime, RuntimeEvent, RuntimeHoldReason};
+use crate::{governance::EnsureIdentityParameters, weights, Balances, Runtime, RuntimeEvent, RuntimeHoldReason};
 
-pub(crate) mod runtime_api;
+pub mod runtime_api;
 
 impl pallet_dip_provider::Config for Runtime {
 	// Only DID origins can submit the commitment identity tx, which will go through
 	// only if the DID in the origin matches the identifier specified in the tx.
 	type CommitOriginCheck = EnsureDidOrigin<DidIdentifier, AccountId>;
 	type CommitOrigin = DidRawOrigin<DidIdentifier, AccountId>;
+	type CommitterDeposit = ConstU128<COMMITTER_DEPOSIT>;
+	type Currency = Balances;
 	type Identifier = DidIdentifier;
 	// The identity commitment is defined as the Merkle root of the linked identity
 	// info, as specified by the [`LinkedDidInfoProvider`].
@@ -45,8 +52,10 @@ impl pallet_dip_provider::Config for Runtime {
 	// Identity info is defined as the collection of DID keys, linked accounts, and
 	// the optional web3name of a given DID subject.
 	type IdentityProvider = LinkedDidInfoProvider<MAX_LINKED_ACCOUNTS>;
+	type MaxCommitters = ConstU32<10>;
 	type ProviderHooks = DepositCollectorHooks;
 	type RuntimeEvent = RuntimeEvent;
+	type RuntimeHoldReason = RuntimeHoldReason;
 	type WeightInfo = weights::pallet_dip_provider::WeightInfo<Runtime>;
 }
 
@@ -59,9 +68,14 @@ impl pallet_deposit_storage::Config for Runtime {
 	// The balances pallet is used to reserve/unreserve tokens.
 	type Currency = Balances;
 	type DepositHooks = DepositHooks;
+	type DepositParameter = DepositParameter;
+	type DepositParameterBounds = DepositParameterBounds;
 	type MaxKeyLength = ConstU32<MAX_DEPOSIT_PALLET_KEY_LENGTH>;
 	type Namespace = DepositNamespace;
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeHoldReason = RuntimeHoldReason;
+	// Deposit parameters can only be changed by governance, either by root or by
+	// a referendum on the identity parameters track.
+	type SetDepositParameterOrigin = EitherOfDiverse<EnsureRoot<AccountId>, EnsureIdentityParameters>;
 	type WeightInfo = weights::pallet_deposit_storage::WeightInfo<Runtime>;
 }
@@ -25,27 +25,16 @@ use runtime_common::{
 	DidIdentifier,
 };
 use scale_info::TypeInfo;
-use sp_std::vec::Vec;
 
 use crate::Runtime;
 
 /// Parameters for a DIP proof request.
-#[derive(Encode, Decode, TypeInfo)]
-pub struct DipProofRequest {
-	/// The subject identifier for which to generate the DIP proof.
-	pub(crate) identifier: DidIdentifier,
-	/// The DIP version.
-	pub(crate) version: IdentityCommitmentVersion,
-	/// The DID key IDs of the subject's DID Document to reveal in the DIP
-	/// proof.
-	pub(crate) keys: Vec<KeyIdOf<Runtime>>,
-	/// The list of accounts linked to the subject's DID to reveal in the
-	/// DIP proof.
-	pub(crate) accounts: Vec<LinkableAccountId>,
-	/// A flag indicating whether the web3name claimed by the DID subject
-	/// should revealed in the DIP proof.
-	pub(crate) should_include_web3_name: bool,
-}
+pub type DipProofRequest = kilt_runtime_api_dip_provider::DipProofRequest<
+	DidIdentifier,
+	IdentityCommitmentVersion,
+	KeyIdOf<Runtime>,
+	LinkableAccountId,
+>;
 
 #[derive(Encode, Decode, TypeInfo)]
 pub enum DipProofError {
@@ -0,0 +1,319 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+//! Configuration of the DID-weighted referenda used to change the deposit
+//! levels of the identity pallets and to perform identity maintenance
+//! operations, such as banning web3names.
+//!
+//! Referenda on the identity tracks are voted on by DIDs that own a web3name,
+//! following a one-DID-one-vote model with optional conviction. An approved
+//! referendum dispatches its call with the origin of its track, i.e.,
+//! `IdentityParameters` or `IdentityMaintenance`, which are only accepted by
+//! the calls configured for them. Since the removal of the sudo pallet, these
+//! origins replace the root origin for the calls they are configured for.
+
+use did::{DidRawOrigin, EnsureDidOrigin};
+use frame_support::{
+	parameter_types,
+	traits::{ConstU32, EitherOfDiverse, Get},
+};
+use frame_system::{EnsureRoot, EnsureSigned};
+use runtime_common::{
+	constants::{self, DAYS, HOURS, KILT},
+	AccountId, Balance, BlockNumber, DidIdentifier,
+};
+use sp_runtime::FixedI64;
+
+use crate::{
+	Balances, CouncilCollective, OriginCaller, Preimage, Referenda, Runtime, RuntimeCall, RuntimeEvent,
+	RuntimeHoldReason, Scheduler, Treasury,
+};
+
+pub use pallet_custom_origins::{
+	EnsureIdentityAccreditation, EnsureIdentityAppeals, EnsureIdentityMaintenance, EnsureIdentityParameters,
+	Origin as CustomOrigin,
+};
+
+/// Origins that can only be obtained through a referendum on the
+/// corresponding track.
+#[frame_support::pallet]
+pub mod pallet_custom_origins {
+	use frame_support::pallet_prelude::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[derive(PartialEq, Eq, Clone, MaxEncodedLen, Encode, Decode, TypeInfo, RuntimeDebug)]
+	#[pallet::origin]
+	pub enum Origin {
+		/// Origin allowed to change the deposit levels of the identity
+		/// pallets, i.e., the deposits of DIDs, web3names, attestations and
+		/// linked accounts. Other identity parameters, such as the name length
+		/// limits, are runtime constants and cannot be changed with it.
+		IdentityParameters,
+		/// Origin allowed to perform maintenance operations on identity
+		/// pallets, which were previously reserved to the root origin.
+		IdentityMaintenance,
+		/// Origin allowed to decide on appeals against maintenance decisions,
+		/// e.g., the scheduled ban of a web3name.
+		IdentityAppeals,
+		/// Origin allowed to manage the registry of accredited attesters.
+		IdentityAccreditation,
+	}
+
+	/// Implement [`EnsureOrigin`] for a struct that only accepts the given
+	/// variant of [`Origin`].
+	macro_rules! decl_ensure {
+		($name:ident, $variant:ident) => {
+			#[doc = concat!("Ensure that the origin is [`Origin::", stringify!($variant), "`].")]
+			pub struct $name;
+
+			impl<O: Into<Result<Origin, O>> + From<Origin>> EnsureOrigin<O> for $name {
+				type Success = ();
+
+				fn try_origin(o: O) -> Result<Self::Success, O> {
+					o.into().and_then(|origin| match origin {
+						Origin::$variant => Ok(()),
+						other => Err(O::from(other)),
+					})
+				}
+
+				#[cfg(feature = "runtime-benchmarks")]
+				fn try_successful_origin() -> Result<O, ()> {
+					Ok(O::from(Origin::$variant))
+				}
+			}
+		};
+	}
+
+	decl_ensure!(EnsureIdentityParameters, IdentityParameters);
+	decl_ensure!(EnsureIdentityMaintenance, IdentityMaintenance);
+	decl_ensure!(EnsureIdentityAppeals, IdentityAppeals);
+	decl_ensure!(EnsureIdentityAccreditation, IdentityAccreditation);
+}
+
+impl pallet_custom_origins::Config for Runtime {}
+
+/// The ID of the identity parameters track, used to change deposit levels.
+pub const IDENTITY_PARAMETERS_TRACK_ID: u16 = 0;
+/// The ID of the identity maintenance track.
+pub const IDENTITY_MAINTENANCE_TRACK_ID: u16 = 1;
+/// The ID of the identity appeals track.
+pub const IDENTITY_APPEALS_TRACK_ID: u16 = 2;
+/// The ID of the identity accreditation track.
+pub const IDENTITY_ACCREDITATION_TRACK_ID: u16 = 3;
+
+const fn percent(x: i32) -> FixedI64 {
+	FixedI64::from_rational(x as u128, 100)
+}
+
+const TRACKS_DATA: [(u16, pallet_referenda::TrackInfo<Balance, BlockNumber>); 4] = [
+	(
+		IDENTITY_PARAMETERS_TRACK_ID,
+		pallet_referenda::TrackInfo {
+			name: "identity_parameters",
+			max_deciding: 10,
+			decision_deposit: 100 * KILT,
+			prepare_period: 2 * HOURS,
+			decision_period: 7 * DAYS,
+			confirm_period: DAYS,
+			min_enactment_period: DAYS,
+			min_approval: pallet_referenda::Curve::make_reciprocal(4, 7, percent(80), percent(50), percent(100)),
+			min_support: pallet_referenda::Curve::make_linear(7, 7, percent(0), percent(10)),
+		},
+	),
+	(
+		IDENTITY_MAINTENANCE_TRACK_ID,
+		pallet_referenda::TrackInfo {
+			name: "identity_maintenance",
+			max_deciding: 10,
+			decision_deposit: 100 * KILT,
+			prepare_period: 2 * HOURS,
+			decision_period: 7 * DAYS,
+			// Maintenance operations, e.g., banning an offensive web3name, should be
+			// enacted without unnecessary delay once approved.
+			confirm_period: 12 * HOURS,
+			min_enactment_period: HOURS,
+			min_approval: pallet_referenda::Curve::make_reciprocal(4, 7, percent(80), percent(50), percent(100)),
+			min_support: pallet_referenda::Curve::make_linear(7, 7, percent(0), percent(10)),
+		},
+	),
+	(
+		IDENTITY_APPEALS_TRACK_ID,
+		pallet_referenda::TrackInfo {
+			name: "identity_appeals",
+			max_deciding: 10,
+			decision_deposit: 100 * KILT,
+			prepare_period: 2 * HOURS,
+			decision_period: 7 * DAYS,
+			// Appealed bans are frozen until the appeal is decided, so there is no need
+			// to rush the decision.
+			confirm_period: DAYS,
+			min_enactment_period: HOURS,
+			min_approval: pallet_referenda::Curve::make_reciprocal(4, 7, percent(80), percent(50), percent(100)),
+			min_support: pallet_referenda::Curve::make_linear(7, 7, percent(0), percent(10)),
+		},
+	),
+	(
+		IDENTITY_ACCREDITATION_TRACK_ID,
+		pallet_referenda::TrackInfo {
+			name: "identity_accreditation",
+			max_deciding: 10,
+			decision_deposit: 100 * KILT,
+			prepare_period: 2 * HOURS,
+			decision_period: 7 * DAYS,
+			confirm_period: DAYS,
+			min_enactment_period: DAYS,
+			min_approval: pallet_referenda::Curve::make_reciprocal(4, 7, percent(80), percent(50), percent(100)),
+			min_support: pallet_referenda::Curve::make_linear(7, 7, percent(0), percent(10)),
+		},
+	),
+];
+
+pub struct TracksInfo;
+
+impl pallet_referenda::TracksInfo<Balance, BlockNumber> for TracksInfo {
+	type Id = u16;
+	type RuntimeOrigin = OriginCaller;
+
+	fn tracks() -> &'static [(Self::Id, pallet_referenda::TrackInfo<Balance, BlockNumber>)] {
+		&TRACKS_DATA[..]
+	}
+
+	fn track_for(origin: &Self::RuntimeOrigin) -> Result<Self::Id, ()> {
+		match CustomOrigin::try_from(origin.clone()) {
+			Ok(CustomOrigin::IdentityParameters) => Ok(IDENTITY_PARAMETERS_TRACK_ID),
+			Ok(CustomOrigin::IdentityMaintenance) => Ok(IDENTITY_MAINTENANCE_TRACK_ID),
+			Ok(CustomOrigin::IdentityAppeals) => Ok(IDENTITY_APPEALS_TRACK_ID),
+			Ok(CustomOrigin::IdentityAccreditation) => Ok(IDENTITY_ACCREDITATION_TRACK_ID),
+			Err(_) => Err(()),
+		}
+	}
+}
+
+pallet_referenda::impl_tracksinfo_get!(TracksInfo, Balance, BlockNumber);
+
+parameter_types! {
+	pub const ReferendumSubmissionDeposit: Balance = 10 * KILT;
+	pub const UndecidingTimeout: BlockNumber = 14 * DAYS;
+	pub const AlarmInterval: BlockNumber = 1;
+}
+
+/// The DIDs that could take part in identity referenda, i.e., the number of
+/// claimed web3names, used to compute their support.
+pub struct IdentityElectorate;
+
+impl Get<u32> for IdentityElectorate {
+	fn get() -> u32 {
+		pallet_web3_names::Owner::<Runtime>::count()
+	}
+}
+
+impl pallet_referenda::Config for Runtime {
+	type WeightInfo = pallet_referenda::weights::SubstrateWeight<Runtime>;
+	type RuntimeCall = RuntimeCall;
+	type RuntimeEvent = RuntimeEvent;
+	type Scheduler = Scheduler;
+	type Currency = Balances;
+	type SubmitOrigin = EnsureSigned<AccountId>;
+	// To cancel a referendum, 2/3 of the council must agree to it.
+	type CancelOrigin = EitherOfDiverse<
+		EnsureRoot<AccountId>,
+		pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 2, 3>,
+	>;
+	// Killing a referendum slashes its deposits, which requires a unanimous council.
+	type KillOrigin = EitherOfDiverse<
+		EnsureRoot<AccountId>,
+		pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 1, 1>,
+	>;
+	type Slash = Treasury;
+	type Votes = u32;
+	type Tally = pallet_did_voting::vote::DidTally<IdentityElectorate>;
+	type SubmissionDeposit = ReferendumSubmissionDeposit;
+	type MaxQueued = ConstU32<100>;
+	type UndecidingTimeout = UndecidingTimeout;
+	type AlarmInterval = AlarmInterval;
+	type Tracks = TracksInfo;
+	type Preimages = Preimage;
+}
+
+/// Only DIDs that own a web3name can vote on identity referenda, and only
+/// since the web3name was claimed.
+pub struct Web3NameHolders;
+
+impl pallet_did_voting::VoterEligibility<DidIdentifier, BlockNumber> for Web3NameHolders {
+	fn eligible_since(did: &DidIdentifier) -> Option<BlockNumber> {
+		let name = pallet_web3_names::Names::<Runtime>::get(did)?;
+		pallet_web3_names::Owner::<Runtime>::get(name).map(|ownership| ownership.claimed_at)
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_did_voting::BenchmarkHelper<DidIdentifier> for Web3NameHolders {
+	fn make_eligible(did: &DidIdentifier) {
+		let name = pallet_web3_names::web3_name::AsciiWeb3Name::<Runtime>::try_from(b"voter".to_vec())
+			.expect("Should create a valid web3name.");
+		pallet_web3_names::Names::<Runtime>::insert(did, name.clone());
+		pallet_web3_names::Owner::<Runtime>::insert(
+			name,
+			pallet_web3_names::Web3OwnershipOf::<Runtime> {
+				owner: did.clone(),
+				claimed_at: 0,
+				deposit: kilt_support::Deposit {
+					owner: did.clone(),
+					amount: 0,
+				},
+			},
+		);
+	}
+}
+
+/// Identity referenda start when they are submitted.
+pub struct ReferendumStart;
+
+impl pallet_did_voting::PollStart<pallet_referenda::ReferendumIndex, BlockNumber> for ReferendumStart {
+	fn poll_start(index: &pallet_referenda::ReferendumIndex) -> Option<BlockNumber> {
+		match pallet_referenda::ReferendumInfoFor::<Runtime>::get(index)? {
+			pallet_referenda::ReferendumInfo::Ongoing(status) => Some(status.submitted),
+			_ => None,
+		}
+	}
+}
+
+impl pallet_did_voting::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type Currency = Balances;
+	type Polls = Referenda;
+	type PollStart = ReferendumStart;
+	// Only DID origins can vote, and the vote is cast on behalf of the DID subject.
+	type EnsureOrigin = EnsureDidOrigin<DidIdentifier, AccountId>;
+	type OriginSuccess = DidRawOrigin<DidIdentifier, AccountId>;
+	type Voter = DidIdentifier;
+	type VoterEligibility = Web3NameHolders;
+	type Electorate = IdentityElectorate;
+	type VoteLockingPeriod = constants::governance::VotingPeriod;
+	type VoteDeposit = constants::did_voting::VoteDeposit;
+	type ConvictionDeposit = constants::did_voting::ConvictionDeposit;
+	type WeightInfo = pallet_did_voting::default_weights::SubstrateWeight<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = Web3NameHolders;
+}
@@ -27,10 +27,10 @@ include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
 
 use frame_support::{
 	construct_runtime, parameter_types,
-	traits::{AsEnsureOriginWithArg, ConstU32, EitherOfDiverse, Everything, InstanceFilter, PrivilegeCmp},
+	traits::{AsEnsureOriginWithArg, ConstU32, Contains, EitherOfDiverse, Everything, InstanceFilter, PrivilegeCmp},
 	weights::{ConstantMultiplier, Weight},
 };
-use frame_system::{EnsureRoot, EnsureSigned};
+use frame_system::{EnsureNever, EnsureRoot, EnsureSigned, EnsureWithSuccess};
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 
 #[cfg(feature = "try-runtime")]
@@ -41,29 +41,40 @@ use sp_api::impl_runtime_apis;
 use sp_core::{ConstBool, OpaqueMetadata};
 use sp_runtime::{
 	create_runtime_str, generic, impl_opaque_keys,
-	traits::{AccountIdLookup, BlakeTwo256, Block as BlockT, ConvertInto, OpaqueKeys},
+	traits::{AccountIdConversion, AccountIdLookup, BlakeTwo256, Block as BlockT, Bounded, ConvertInto, OpaqueKeys},
 	transaction_validity::{TransactionSource, TransactionValidity},
-	ApplyExtrinsicResult, Perbill, Permill, RuntimeDebug,
+	ApplyExtrinsicResult, FixedPointNumber, Perbill, Permill, RuntimeDebug,
 };
 use sp_std::{cmp::Ordering, prelude::*};
 use sp_version::RuntimeVersion;
+use xcm::v3::MultiLocation;
 use xcm_executor::XcmExecutor;
 
 use delegation::DelegationAc;
-use kilt_support::traits::ItemFilter;
+use kilt_support::{migration::MigratedStorageKeys, traits::ItemFilter};
+use pallet_deposit_storage::DepositParameterOf;
 use pallet_did_lookup::linkable_account::LinkableAccountId;
+use pallet_transaction_payment::Multiplier;
 pub use parachain_staking::InflationInfo;
 pub use public_credentials;
 
 use runtime_common::{
 	assets::{AssetDid, PublicCredentialsFilter},
-	authorization::{AuthorizationId, PalletAuthorize},
+	authorization::{AccreditationByMembership, AuthorizationId, PalletAuthorize},
 	constants::{self, UnvestedFundsAllowedWithdrawReasons, EXISTENTIAL_DEPOSIT, KILT},
+	deposit_parameters::{
+		AllDepositParameters, AttestationDepositParameter, DidBaseDepositParameter, DidLookupDepositParameter,
+		Web3NameDepositParameter,
+	},
 	dip::merkle::{CompleteMerkleProof, DidMerkleProofOf, DidMerkleRootGenerator},
 	errors::PublicCredentialsApiError,
-	fees::{ToAuthor, WeightToFee},
-	pallet_id, AccountId, AuthorityId, Balance, BlockHashCount, BlockLength, BlockNumber, BlockWeights, DidIdentifier,
-	FeeSplit, Hash, Header, Nonce, Signature, SlowAdjustingFeeUpdate,
+	fees::{
+		discount_dispatch_info, discount_fee_details, fee_discount, DealWithFees, DiscountedFees, FeeSplit,
+		FreeTrialFees, ToAuthor, WeightToFee,
+	},
+	fungibles::{ForeignAssetFees, ResolveAssetsTo},
+	pallet_id, AccountId, AuthorityId, Balance, BlockFullnessFeeUpdate, BlockHashCount, BlockLength, BlockNumber,
+	BlockWeights, DidIdentifier, Hash, Header, Nonce, Signature,
 };
 
 use crate::xcm_config::{XcmConfig, XcmOriginToTransactDispatchOrigin};
@@ -79,7 +90,8 @@ pub use sp_runtime::BuildStorage;
 #[cfg(test)]
 mod tests;
 
-mod dip;
+pub mod dip;
+pub mod governance;
 mod weights;
 mod xcm_config;
 
@@ -95,10 +107,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	spec_name: create_runtime_str!("mashnet-node"),
 	impl_name: create_runtime_str!("mashnet-node"),
 	authoring_version: 4,
-	spec_version: 11300,
+	spec_version: 11400,
 	impl_version: 0,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 8,
+	transaction_version: 14,
 	state_version: 0,
 };
 
@@ -117,6 +129,33 @@ parameter_types! {
 	pub const SS58Prefix: u8 = 38;
 }
 
+/// Blocks the calls accessing storage which has not been migrated yet and
+/// the direct registration of session keys, which must go through `ParachainStaking::set_session_keys` to prove
+/// the ownership of the keys.
+pub struct BaseFilter;
+
+impl Contains<RuntimeCall> for BaseFilter {
+	fn contains(call: &RuntimeCall) -> bool {
+		!matches!(call, RuntimeCall::Session(pallet_session::Call::set_keys { .. }))
+			&& pallet_migration::BlockCallsDuringMigration::<Runtime, SteppedMigrationKeys>::contains(call)
+			// Identity calls can be paused individually in case of an incident.
+			&& pallet_configuration::UnpausedCalls::<Runtime, IdentityCalls>::contains(call)
+	}
+}
+
+/// The storage keys of the stepped migrations accessed by the calls.
+pub struct SteppedMigrationKeys;
+
+impl MigratedStorageKeys<RuntimeCall> for SteppedMigrationKeys {
+	fn migrated_storage_keys(call: &RuntimeCall) -> Option<Vec<Vec<u8>>> {
+		match call {
+			RuntimeCall::Did(call) => did::migrations::migrated_storage_keys::<Runtime>(call),
+			RuntimeCall::Attestation(call) => attestation::migrations::migrated_storage_keys::<Runtime>(call),
+			_ => None,
+		}
+	}
+}
+
 impl frame_system::Config for Runtime {
 	/// The identifier used to distinguish between accounts.
 	type AccountId = AccountId;
@@ -148,7 +187,7 @@ impl frame_system::Config for Runtime {
 	type OnNewAccount = ();
 	type OnKilledAccount = ();
 	type DbWeight = weights::rocksdb_weights::constants::RocksDbWeight;
-	type BaseCallFilter = Everything;
+	type BaseCallFilter = BaseFilter;
 	type SystemWeightInfo = weights::frame_system::WeightInfo<Runtime>;
 	type BlockWeights = BlockWeights;
 	type BlockLength = BlockLength;
@@ -165,11 +204,21 @@ parameter_types! {
 impl pallet_timestamp::Config for Runtime {
 	/// A timestamp: milliseconds since the unix epoch.
 	type Moment = u64;
-	type OnTimestampSet = ();
+	type OnTimestampSet = TimeAnchors;
 	type MinimumPeriod = MinimumPeriod;
 	type WeightInfo = weights::pallet_timestamp::WeightInfo<Runtime>;
 }
 
+impl pallet_time_anchors::Config for Runtime {
+	type AnchorInterval = constants::time_anchors::AnchorInterval;
+	type MaxAnchors = constants::time_anchors::MaxAnchors;
+}
+
+/// Converts a unix timestamp in milliseconds into the number of the block
+/// produced at that time, e.g., to configure expirations in wall-clock time.
+pub type MomentToBlockNumber =
+	kilt_support::time::MomentToBlockNumber<TimeAnchors, constants::time_anchors::ExpectedBlockTime>;
+
 parameter_types! {
 	pub const ExistentialDeposit: u128 = EXISTENTIAL_DEPOSIT;
 	pub const MaxLocks: u32 = 50;
@@ -188,11 +237,66 @@ impl pallet_multisig::Config for Runtime {
 	type WeightInfo = weights::pallet_multisig::WeightInfo<Runtime>;
 }
 
+impl pallet_assets::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type RemoveItemsLimit = constants::assets::RemoveItemsLimit;
+	type AssetId = u32;
+	type AssetIdParameter = parity_scale_codec::Compact<u32>;
+	type Currency = Balances;
+	type CreateOrigin = runtime_common::fungibles::EnsureDidAssetCreator<DidIdentifier, AccountId>;
+	type ForceOrigin = EnsureRoot<AccountId>;
+	type AssetDeposit = constants::assets::AssetDeposit;
+	type AssetAccountDeposit = constants::assets::AssetAccountDeposit;
+	type MetadataDepositBase = constants::assets::MetadataDepositBase;
+	type MetadataDepositPerByte = constants::assets::MetadataDepositPerByte;
+	type ApprovalDeposit = constants::assets::ApprovalDeposit;
+	type StringLimit = constants::assets::StringLimit;
+	type Freezer = ();
+	type Extra = ();
+	type CallbackHandle = ();
+	type WeightInfo = pallet_assets::weights::SubstrateWeight<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+}
+
+/// The instance of `pallet_assets` for the foreign assets registered by
+/// governance.
+pub type ForeignAssetsInstance = pallet_assets::Instance1;
+
+/// Foreign assets are identified by their location as seen from this chain and
+/// can only be registered by governance via `force_create`.
+impl pallet_assets::Config<ForeignAssetsInstance> for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type RemoveItemsLimit = constants::assets::RemoveItemsLimit;
+	type AssetId = MultiLocation;
+	type AssetIdParameter = MultiLocation;
+	type Currency = Balances;
+	type CreateOrigin = AsEnsureOriginWithArg<EnsureNever<AccountId>>;
+	type ForceOrigin = EnsureRoot<AccountId>;
+	type AssetDeposit = constants::assets::AssetDeposit;
+	type AssetAccountDeposit = constants::assets::AssetAccountDeposit;
+	type MetadataDepositBase = constants::assets::MetadataDepositBase;
+	type MetadataDepositPerByte = constants::assets::MetadataDepositPerByte;
+	type ApprovalDeposit = constants::assets::ApprovalDeposit;
+	type StringLimit = constants::assets::StringLimit;
+	type Freezer = ();
+	type Extra = ();
+	type CallbackHandle = ();
+	type WeightInfo = pallet_assets::weights::SubstrateWeight<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = runtime_common::fungibles::ForeignAssetBenchmarkHelper;
+}
+
 impl pallet_migration::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
 	type MaxMigrationsPerPallet = constants::pallet_migration::MaxMigrationsPerPallet;
 	type WeightInfo = weights::pallet_migration::WeightInfo<Runtime>;
+	type SteppedMigrations = SteppedMigrations;
+	type MaxSteppedMigrationsWeight = constants::pallet_migration::MaxSteppedMigrationsWeight;
+	type MaxMigrationCursorLength = constants::pallet_migration::MaxMigrationCursorLength;
 }
 
 impl pallet_indices::Config for Runtime {
@@ -222,20 +326,88 @@ impl pallet_balances::Config for Runtime {
 	type ReserveIdentifier = [u8; 8];
 }
 
+parameter_types! {
+	/// The discount on the transaction fees of identity-related calls. Can be
+	/// changed by governance via `set_storage`.
+	pub storage IdentityCallFeeDiscount: Perbill = Perbill::from_percent(50);
+	/// The shares of the transaction fees that are burned and that go to the
+	/// block author, the rest going to the treasury. Can be changed by
+	/// governance via `set_storage`.
+	pub storage TransactionFeeSplit: FeeSplit = FeeSplit::default();
+	/// How fast the fee multiplier follows the block fullness. It is twice the
+	/// common `AdjustmentVariable`, so that congestion prices out spam quickly
+	/// despite the discount on identity-related calls. Can be changed by
+	/// governance via `set_storage`.
+	pub storage FeeAdjustmentVariable: Multiplier = Multiplier::saturating_from_rational(6, 100_000);
+	/// The maximum fee multiplier. Can be changed by governance via
+	/// `set_storage`.
+	pub storage MaximumFeeMultiplier: Multiplier = Bounded::max_value();
+}
+
+/// The identity-related calls whose transaction fees are discounted and which
+/// can be paused via `pallet_configuration`.
+pub struct IdentityCalls;
+
+impl Contains<RuntimeCall> for IdentityCalls {
+	fn contains(call: &RuntimeCall) -> bool {
+		matches!(
+			call,
+			RuntimeCall::Attestation(..)
+				| RuntimeCall::Ctype(..)
+				| RuntimeCall::Delegation(..)
+				| RuntimeCall::Did(..)
+				| RuntimeCall::DidLookup(..)
+				| RuntimeCall::PublicCredentials(..)
+				| RuntimeCall::Web3Names(..)
+		)
+	}
+}
+
+parameter_types! {
+	/// The pre-funded account that pays the fees of the free trial operations
+	/// of new DIDs.
+	pub DidFreeTrialAccount: AccountId = pallet_id::DidFreeTrial::get().into_account_truncating();
+}
+
 impl pallet_transaction_payment::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
-	type OnChargeTransaction =
-		pallet_transaction_payment::CurrencyAdapter<Balances, FeeSplit<Runtime, Treasury, ToAuthor<Runtime>>>;
+	type OnChargeTransaction = FreeTrialFees<
+		DiscountedFees<
+			pallet_transaction_payment::CurrencyAdapter<
+				Balances,
+				DealWithFees<Runtime, TransactionFeeSplit, Treasury, ToAuthor<Runtime>>,
+			>,
+			IdentityCalls,
+			IdentityCallFeeDiscount,
+		>,
+		DidFreeTrialAccount,
+	>;
 	type OperationalFeeMultiplier = constants::fee::OperationalFeeMultiplier;
 	type WeightToFee = WeightToFee<Runtime>;
 	type LengthToFee = ConstantMultiplier<Balance, constants::fee::TransactionByteFee>;
-	type FeeMultiplierUpdate = SlowAdjustingFeeUpdate<Self>;
+	type FeeMultiplierUpdate = BlockFullnessFeeUpdate<Self, FeeAdjustmentVariable, MaximumFeeMultiplier>;
 }
 
-impl pallet_sudo::Config for Runtime {
-	type RuntimeCall = RuntimeCall;
+parameter_types! {
+	pub TreasuryAccount: AccountId = Treasury::account_id();
+}
+
+/// Allows paying the fees of identity-related calls in foreign assets, e.g., in
+/// DOT or in the stablecoins issued on AssetHub. The conversion rate of a
+/// foreign asset is the ratio between its minimum balance and the existential
+/// deposit of KILT, and only assets marked as sufficient can be used. Both are
+/// set by governance when registering the asset in `ForeignAssets`.
+impl pallet_asset_tx_payment::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
-	type WeightInfo = weights::pallet_sudo::WeightInfo<Runtime>;
+	type Fungibles = ForeignAssets;
+	type OnChargeAssetTransaction = ForeignAssetFees<
+		pallet_asset_tx_payment::FungiblesAdapter<
+			pallet_assets::BalanceToAssetBalance<Balances, Runtime, ConvertInto, ForeignAssetsInstance>,
+			ResolveAssetsTo<TreasuryAccount>,
+		>,
+		IdentityCalls,
+		IdentityCallFeeDiscount,
+	>;
 }
 
 parameter_types! {
@@ -321,6 +493,13 @@ impl pallet_vesting::Config for Runtime {
 	const MAX_VESTING_SCHEDULES: u32 = constants::MAX_VESTING_SCHEDULES;
 }
 
+impl pallet_vesting_grants::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = pallet_vesting_grants::default_weights::SubstrateWeight<Runtime>;
+	type AdminOrigin = EnsureRoot<AccountId>;
+	type MaxGrants = constants::MaxVestingGrants;
+}
+
 parameter_types! {
 	pub const MaxClaims: u32 = 50;
 	pub const UsableBalance: Balance = KILT;
@@ -383,6 +562,24 @@ impl pallet_scheduler::Config for Runtime {
 	type Preimages = Preimage;
 }
 
+/// DIDs schedule calls through `DidScheduler`, which dispatches them with the
+/// DID origin they were scheduled with. Each scheduled call holds a deposit
+/// and each DID can only take up a limited part of the agenda.
+impl pallet_did_scheduler::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type PalletsOrigin = OriginCaller;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type Currency = Balances;
+	type EnsureOrigin = did::EnsureDidOrigin<DidIdentifier, AccountId>;
+	type OriginSuccess = did::DidRawOrigin<DidIdentifier, AccountId>;
+	type DidIdentifier = DidIdentifier;
+	type Scheduler = Scheduler;
+	type MaxScheduledCallsPerDid = constants::did_scheduler::MaxScheduledCallsPerDid;
+	type Deposit = constants::did_scheduler::ScheduledCallDeposit;
+	type WeightInfo = pallet_did_scheduler::default_weights::SubstrateWeight<Runtime>;
+}
+
 parameter_types! {
 	pub const InstantAllowed: bool = true;
 	pub const MaxVotes: u32 = 100;
@@ -460,6 +657,22 @@ type MoreThanHalfCouncil = EitherOfDiverse<
 	pallet_collective::EnsureProportionMoreThan<AccountId, CouncilCollective, 1, 2>,
 >;
 
+/// Security fixes, e.g., to a broken proof verifier, can be fast-tracked
+/// without a referendum: two thirds of the technical committee whitelist the
+/// hash of the fixing call, which more than half of the council can then
+/// dispatch as root right away. No other call can be dispatched this way.
+impl pallet_whitelist::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type WhitelistOrigin = EitherOfDiverse<
+		EnsureRoot<AccountId>,
+		pallet_collective::EnsureProportionAtLeast<AccountId, TechnicalCollective, 2, 3>,
+	>;
+	type DispatchWhitelistedOrigin = MoreThanHalfCouncil;
+	type Preimages = Preimage;
+	type WeightInfo = pallet_whitelist::weights::SubstrateWeight<Runtime>;
+}
+
 impl pallet_treasury::Config for Runtime {
 	type PalletId = pallet_id::Treasury;
 	type Currency = Balances;
@@ -471,14 +684,77 @@ impl pallet_treasury::Config for Runtime {
 	type ProposalBondMinimum = ProposalBondMinimum;
 	type ProposalBondMaximum = ();
 	type SpendPeriod = SpendPeriod;
-	type SpendOrigin = frame_support::traits::NeverEnsureOrigin<Balance>;
+	type SpendOrigin = EnsureWithSuccess<ApproveOrigin, AccountId, constants::bounties::MaxBountySpend>;
 	type Burn = Burn;
 	type BurnDestination = ();
-	type SpendFunds = ();
+	type SpendFunds = Bounties;
 	type WeightInfo = weights::pallet_treasury::WeightInfo<Runtime>;
 	type MaxApprovals = MaxApprovals;
 }
 
+impl pallet_bounties::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type BountyDepositBase = constants::bounties::BountyDepositBase;
+	type BountyDepositPayoutDelay = constants::bounties::BountyDepositPayoutDelay;
+	type BountyUpdatePeriod = constants::bounties::BountyUpdatePeriod;
+	type CuratorDepositMultiplier = constants::bounties::CuratorDepositMultiplier;
+	type CuratorDepositMin = constants::bounties::CuratorDepositMin;
+	type CuratorDepositMax = constants::bounties::CuratorDepositMax;
+	type BountyValueMinimum = constants::bounties::BountyValueMinimum;
+	type DataDepositPerByte = constants::ByteDeposit;
+	type MaximumReasonLength = constants::bounties::MaximumReasonLength;
+	type WeightInfo = pallet_bounties::weights::SubstrateWeight<Runtime>;
+	type ChildBountyManager = ChildBounties;
+}
+
+impl pallet_child_bounties::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type MaxActiveChildBountyCount = constants::bounties::MaxActiveChildBountyCount;
+	type ChildBountyValueMinimum = constants::bounties::ChildBountyValueMinimum;
+	type WeightInfo = pallet_child_bounties::weights::SubstrateWeight<Runtime>;
+}
+
+/// The (child) bounty calls a DID can dispatch as curator.
+pub struct BountyCuratorCalls;
+
+impl Contains<RuntimeCall> for BountyCuratorCalls {
+	fn contains(call: &RuntimeCall) -> bool {
+		// The curator pallet is benchmarked with a remark.
+		#[cfg(feature = "runtime-benchmarks")]
+		if matches!(call, RuntimeCall::System(frame_system::Call::remark_with_event { .. })) {
+			return true;
+		}
+
+		matches!(
+			call,
+			RuntimeCall::Bounties(
+				pallet_bounties::Call::accept_curator { .. }
+					| pallet_bounties::Call::unassign_curator { .. }
+					| pallet_bounties::Call::award_bounty { .. }
+					| pallet_bounties::Call::extend_bounty_expiry { .. }
+			) | RuntimeCall::ChildBounties(
+				pallet_child_bounties::Call::add_child_bounty { .. }
+					| pallet_child_bounties::Call::propose_curator { .. }
+					| pallet_child_bounties::Call::accept_curator { .. }
+					| pallet_child_bounties::Call::unassign_curator { .. }
+					| pallet_child_bounties::Call::award_child_bounty { .. }
+					| pallet_child_bounties::Call::close_child_bounty { .. }
+			)
+		)
+	}
+}
+
+impl pallet_did_curator::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type EnsureOrigin = did::EnsureDidOrigin<DidIdentifier, AccountId>;
+	type OriginSuccess = did::DidRawOrigin<AccountId, DidIdentifier>;
+	type DidIdentifier = DidIdentifier;
+	type PalletId = pallet_id::DidCurator;
+	type CuratorCalls = BountyCuratorCalls;
+	type WeightInfo = pallet_did_curator::default_weights::SubstrateWeight<Runtime>;
+}
+
 type CouncilCollective = pallet_collective::Instance1;
 impl pallet_collective::Config<CouncilCollective> for Runtime {
 	type RuntimeOrigin = RuntimeOrigin;
@@ -535,6 +811,20 @@ impl pallet_membership::Config<TipsMembershipProvider> for Runtime {
 	type WeightInfo = weights::pallet_membership::WeightInfo<Runtime>;
 }
 
+type AccreditedAttestersProvider = pallet_membership::Instance3;
+impl pallet_membership::Config<AccreditedAttestersProvider> for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AddOrigin = EitherOfDiverse<EnsureRoot<AccountId>, governance::EnsureIdentityAccreditation>;
+	type RemoveOrigin = EitherOfDiverse<EnsureRoot<AccountId>, governance::EnsureIdentityAccreditation>;
+	type SwapOrigin = EitherOfDiverse<EnsureRoot<AccountId>, governance::EnsureIdentityAccreditation>;
+	type ResetOrigin = EitherOfDiverse<EnsureRoot<AccountId>, governance::EnsureIdentityAccreditation>;
+	type PrimeOrigin = EitherOfDiverse<EnsureRoot<AccountId>, governance::EnsureIdentityAccreditation>;
+	type MembershipInitialized = ();
+	type MembershipChanged = ();
+	type MaxMembers = constants::attestation::MaxAccreditedAttesters;
+	type WeightInfo = weights::pallet_membership::WeightInfo<Runtime>;
+}
+
 impl pallet_tips::Config for Runtime {
 	type MaximumReasonLength = constants::tips::MaximumReasonLength;
 	type DataDepositPerByte = constants::ByteDeposit;
@@ -550,6 +840,13 @@ impl pallet_configuration::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = weights::pallet_configuration::WeightInfo<Runtime>;
 	type EnsureOrigin = AsEnsureOriginWithArg<EnsureRoot<AccountId>>;
+	// Inbound XCM identity operations can be paused quickly in case of an incident,
+	// hence the technical committee can do so without a referendum.
+	type PauseOrigin = EitherOfDiverse<
+		EnsureRoot<AccountId>,
+		pallet_collective::EnsureProportionAtLeast<AccountId, TechnicalCollective, 2, 3>,
+	>;
+	type MaxPausedCalls = ConstU32<20>;
 }
 
 impl attestation::Config for Runtime {
@@ -560,12 +857,35 @@ impl attestation::Config for Runtime {
 	type WeightInfo = weights::attestation::WeightInfo<Runtime>;
 
 	type Currency = Balances;
-	type Deposit = constants::attestation::AttestationDeposit;
+	type Deposit = DepositParameterOf<Runtime, AttestationDepositParameter>;
+	type IssuanceDeposit = constants::attestation::IssuanceDeposit;
 	type MaxDelegatedAttestations = constants::attestation::MaxDelegatedAttestations;
+	type MaxCidLength = constants::attestation::MaxCidLength;
 	type AttesterId = DidIdentifier;
 	type AuthorizationId = AuthorizationId<<Runtime as delegation::Config>::DelegationNodeId>;
 	type AccessControl = PalletAuthorize<DelegationAc<Runtime>>;
 	type BalanceMigrationManager = Migration;
+	type AttesterAccreditation = AccreditationByMembership<Runtime, AccreditedAttestersProvider, RegulatedCtypes>;
+	type ChallengeBond = constants::attestation::ChallengeBond;
+	type ChallengeResponsePeriod = constants::attestation::ChallengeResponsePeriod;
+	type ChallengeAdjudicatorOrigin = EitherOfDiverse<EnsureRoot<AccountId>, governance::EnsureIdentityAppeals>;
+
+	#[cfg(not(feature = "runtime-benchmarks"))]
+	type ClaimerSignature = did::DidSignature;
+	#[cfg(not(feature = "runtime-benchmarks"))]
+	type ClaimerSignatureVerification = did::DidSignatureVerify<Runtime>;
+
+	#[cfg(feature = "runtime-benchmarks")]
+	type ClaimerSignature = DummySignature;
+	#[cfg(feature = "runtime-benchmarks")]
+	type ClaimerSignatureVerification = AlwaysVerify<AccountId, Vec<u8>, Self::ClaimerSignature>;
+}
+
+parameter_types! {
+	/// The CTypes that can only be attested by the DIDs in the
+	/// `AccreditedAttesters` registry. Can be changed by governance via
+	/// `set_storage`.
+	pub storage RegulatedCtypes: Vec<Hash> = Vec::new();
 }
 
 impl delegation::Config for Runtime {
@@ -603,10 +923,15 @@ impl ctype::Config for Runtime {
 	type Currency = Balances;
 	type Fee = constants::CtypeFee;
 	type FeeCollector = runtime_common::SendDustAndFeesToTreasury<Runtime>;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type MaxSchemaLength = constants::ctype::MaxSchemaLength;
+	type SchemaBaseDeposit = constants::ctype::SchemaBaseDeposit;
+	type SchemaTierSize = constants::ctype::SchemaTierSize;
+	type SchemaTierDeposit = constants::ctype::SchemaTierDeposit;
 
 	type EnsureOrigin = did::EnsureDidOrigin<DidIdentifier, AccountId>;
 	type OriginSuccess = did::DidRawOrigin<AccountId, DidIdentifier>;
-	type OverarchingOrigin = EnsureRoot<AccountId>;
+	type OverarchingOrigin = EitherOfDiverse<EnsureRoot<AccountId>, governance::EnsureIdentityMaintenance>;
 
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = weights::ctype::WeightInfo<Runtime>;
@@ -621,7 +946,7 @@ impl did::Config for Runtime {
 	type DidIdentifier = DidIdentifier;
 	type KeyDeposit = constants::did::KeyDeposit;
 	type ServiceEndpointDeposit = constants::did::ServiceEndpointDeposit;
-	type BaseDeposit = constants::did::DidBaseDeposit;
+	type BaseDeposit = DepositParameterOf<Runtime, DidBaseDepositParameter>;
 	type Fee = constants::did::DidFee;
 	type FeeCollector = runtime_common::SendDustAndFeesToTreasury<Runtime>;
 
@@ -645,6 +970,17 @@ impl did::Config for Runtime {
 	type MaxServiceUrlLength = constants::did::MaxServiceUrlLength;
 	type MaxNumberOfTypesPerService = constants::did::MaxNumberOfTypesPerService;
 	type MaxNumberOfUrlsPerService = constants::did::MaxNumberOfUrlsPerService;
+	type MaxKeyHistoryLength = constants::did::MaxKeyHistoryLength;
+	type MaxRemovedKeys = constants::did::MaxRemovedKeys;
+	type KeyHistoryDeposit = constants::did::KeyHistoryDeposit;
+	type MaxEndorsementsPerDid = constants::did::MaxEndorsementsPerDid;
+	type MaxAlsoKnownAs = constants::did::MaxAlsoKnownAs;
+	type MaxAlsoKnownAsUriLength = constants::did::MaxAlsoKnownAsUriLength;
+	type MaxDidDocumentSize = constants::did::MaxDidDocumentSize;
+	type MaxMultisigSignatories = constants::multisig::MaxSignitors;
+	type AllowedKeyTypes = Everything;
+	type FreeTrialOperations = constants::did::FreeTrialOperations;
+	type LegacySignatureDeadline = constants::did::LegacySignatureDeadline;
 	type WeightInfo = weights::did::WeightInfo<Runtime>;
 	type BalanceMigrationManager = Migration;
 }
@@ -656,27 +992,46 @@ impl pallet_did_lookup::Config for Runtime {
 	type DidIdentifier = DidIdentifier;
 
 	type Currency = Balances;
-	type Deposit = constants::did_lookup::DidLookupDeposit;
+	type Deposit = DepositParameterOf<Runtime, DidLookupDepositParameter>;
+	type LabelDeposit = constants::did_lookup::LabelDeposit;
 
 	type EnsureOrigin = did::EnsureDidOrigin<DidIdentifier, AccountId>;
 	type OriginSuccess = did::DidRawOrigin<AccountId, DidIdentifier>;
 
 	type WeightInfo = weights::pallet_did_lookup::WeightInfo<Runtime>;
 	type BalanceMigrationManager = Migration;
+	#[cfg(not(feature = "runtime-benchmarks"))]
+	type ContractSignatureVerifier = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type ContractSignatureVerifier = runtime_common::benchmarks::AlwaysVerifyContractSignature;
+	type MaxAssociationRemovals = constants::did_lookup::MaxAssociationRemovals;
+	type MaxProofValidity = constants::did_lookup::MaxProofValidity;
 }
 
 impl pallet_web3_names::Config for Runtime {
 	type RuntimeHoldReason = RuntimeHoldReason;
-	type BanOrigin = EnsureRoot<AccountId>;
+	type BanOrigin = EitherOfDiverse<EnsureRoot<AccountId>, governance::EnsureIdentityMaintenance>;
+	type BanAppealOrigin = EitherOfDiverse<EnsureRoot<AccountId>, governance::EnsureIdentityAppeals>;
+	type BanDelay = constants::web3_names::BanDelay;
+	type MaxBansPerBlock = constants::web3_names::MaxBansPerBlock;
+	type RegistrarOrigin = EnsureRoot<AccountId>;
+	type MaxClaimBatchSize = constants::web3_names::MaxClaimBatchSize;
+	type RegistrarApprovalOrigin = EitherOfDiverse<EnsureRoot<AccountId>, governance::EnsureIdentityMaintenance>;
+	type RegistrarTreasuryShare = constants::web3_names::RegistrarTreasuryShare;
+	type TreasuryAccount = TreasuryAccount;
 	type OwnerOrigin = did::EnsureDidOrigin<DidIdentifier, AccountId>;
 	type OriginSuccess = did::DidRawOrigin<AccountId, DidIdentifier>;
 	type Currency = Balances;
-	type Deposit = constants::web3_names::Web3NameDeposit;
+	type Deposit = DepositParameterOf<Runtime, Web3NameDepositParameter>;
 	type RuntimeEvent = RuntimeEvent;
 	type MaxNameLength = constants::web3_names::MaxNameLength;
 	type MinNameLength = constants::web3_names::MinNameLength;
 	type Web3Name = pallet_web3_names::web3_name::AsciiWeb3Name<Runtime>;
 	type Web3NameOwner = DidIdentifier;
+	type MaxRecordKeyLength = constants::web3_names::MaxRecordKeyLength;
+	type MaxRecordValueLength = constants::web3_names::MaxRecordValueLength;
+	type MaxRecordsPerName = constants::web3_names::MaxRecordsPerName;
+	type RecordDeposit = constants::web3_names::RecordDeposit;
 	type WeightInfo = weights::pallet_web3_names::WeightInfo<Runtime>;
 	type BalanceMigrationManager = Migration;
 }
@@ -710,6 +1065,15 @@ impl parachain_staking::Config for Runtime {
 	type NetworkRewardRate = constants::staking::NetworkRewardRate;
 	type NetworkRewardStart = constants::staking::NetworkRewardStart;
 	type NetworkRewardBeneficiary = runtime_common::SendDustAndFeesToTreasury<Runtime>;
+	type InstantUnstakePenalty = constants::staking::InstantUnstakePenalty;
+	type InstantUnstakePenaltyBeneficiary = runtime_common::SendDustAndFeesToTreasury<Runtime>;
+	type IdentityId = DidIdentifier;
+	type EnsureIdentityOrigin = did::EnsureDidOrigin<DidIdentifier, AccountId>;
+	type IdentityOriginSuccess = did::DidRawOrigin<AccountId, DidIdentifier>;
+	type LinkedAccounts = runtime_common::staking::LinkedDidAccounts<Runtime>;
+	type SessionKeysOwnership =
+		runtime_common::staking::SessionKeysProofOfPossession<runtime_common::staking::GenesisHash<Runtime>>;
+	type MaxLinkedAccountsPerCall = constants::staking::MaxLinkedAccountsPerCall;
 	type WeightInfo = weights::parachain_staking::WeightInfo<Runtime>;
 
 	const BLOCKS_PER_YEAR: BlockNumberFor<Self> = constants::BLOCKS_PER_YEAR;
@@ -736,6 +1100,7 @@ impl public_credentials::Config for Runtime {
 	type MaxSubjectIdLength = runtime_common::constants::public_credentials::MaxSubjectIdLength;
 	type OriginSuccess = did::DidRawOrigin<AccountId, DidIdentifier>;
 	type RuntimeEvent = RuntimeEvent;
+	type SubjectControllerOrigin = MoreThanHalfCouncil;
 	type SubjectId = runtime_common::assets::AssetDid;
 	type WeightInfo = weights::public_credentials::WeightInfo<Runtime>;
 	type BalanceMigrationManager = Migration;
@@ -759,6 +1124,9 @@ pub enum ProxyType {
 	/// Allow for calls that do not result in a deposit being claimed (e.g., for
 	/// attestations, delegations, or DIDs).
 	NonDepositClaiming,
+	/// Allow for calls that manage a DID, its web3name and its linked
+	/// accounts.
+	Identity,
 }
 
 impl Default for ProxyType {
@@ -771,57 +1139,78 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 	fn filter(&self, c: &RuntimeCall) -> bool {
 		match self {
 			ProxyType::Any => true,
-			ProxyType::NonTransfer => matches!(
-				c,
-				RuntimeCall::Attestation(..)
-					// Excludes `Balances`
-					| RuntimeCall::Council(..)
-					| RuntimeCall::Ctype(..)
-					| RuntimeCall::Delegation(..)
-					| RuntimeCall::Democracy(..)
-					| RuntimeCall::DepositStorage(..)
-					| RuntimeCall::Did(..)
-					| RuntimeCall::DidLookup(..)
-					| RuntimeCall::DipProvider(..)
-					| RuntimeCall::Indices(
-						// Excludes `force_transfer`, and `transfer`
-						pallet_indices::Call::claim { .. }
-							| pallet_indices::Call::free { .. }
-							| pallet_indices::Call::freeze { .. }
-					)
-					| RuntimeCall::Multisig(..)
-					| RuntimeCall::ParachainStaking(..)
-					// Excludes `ParachainSystem`
-					| RuntimeCall::Preimage(..)
-					| RuntimeCall::Proxy(..)
-					| RuntimeCall::PublicCredentials(..)
-					| RuntimeCall::Scheduler(..)
-					| RuntimeCall::Session(..)
-					| RuntimeCall::System(..)
-					| RuntimeCall::TechnicalCommittee(..)
-					| RuntimeCall::TechnicalMembership(..)
-					| RuntimeCall::TipsMembership(..)
-					| RuntimeCall::Timestamp(..)
-					| RuntimeCall::Treasury(..)
-					| RuntimeCall::Utility(..)
-					| RuntimeCall::Vesting(
-						// Excludes `force_vested_transfer`, `merge_schedules`, and `vested_transfer`
-						pallet_vesting::Call::vest { .. }
-							| pallet_vesting::Call::vest_other { .. }
-					)
-					| RuntimeCall::Web3Names(..),
-			),
+			ProxyType::NonTransfer => {
+				matches!(
+					c,
+					RuntimeCall::AccreditedAttesters(..)
+						| RuntimeCall::Attestation(..)
+						// Excludes `Balances`
+						| RuntimeCall::Bounties(..)
+						| RuntimeCall::ChildBounties(..)
+						| RuntimeCall::Council(..)
+						| RuntimeCall::Ctype(..)
+						| RuntimeCall::Delegation(..)
+						| RuntimeCall::Democracy(..)
+						| RuntimeCall::DepositStorage(..)
+						| RuntimeCall::Did(..)
+						| RuntimeCall::DidCurator(..)
+						| RuntimeCall::DidLookup(..)
+						| RuntimeCall::DidScheduler(..)
+						| RuntimeCall::DidVoting(..)
+						| RuntimeCall::DipProvider(..)
+						| RuntimeCall::Indices(
+							// Excludes `force_transfer`, and `transfer`
+							pallet_indices::Call::claim { .. }
+								| pallet_indices::Call::free { .. }
+								| pallet_indices::Call::freeze { .. }
+						)
+						| RuntimeCall::Multisig(..)
+						| RuntimeCall::ParachainStaking(..)
+						// Excludes `ParachainSystem`
+						| RuntimeCall::Preimage(..)
+						| RuntimeCall::Proxy(..)
+						| RuntimeCall::PublicCredentials(..)
+						| RuntimeCall::Referenda(..)
+						| RuntimeCall::Scheduler(..)
+						| RuntimeCall::Session(..)
+						| RuntimeCall::System(..)
+						| RuntimeCall::TechnicalCommittee(..)
+						| RuntimeCall::TechnicalMembership(..)
+						| RuntimeCall::TipsMembership(..)
+						| RuntimeCall::Timestamp(..)
+						| RuntimeCall::Treasury(..)
+						| RuntimeCall::Utility(..)
+						| RuntimeCall::Vesting(
+							// Excludes `force_vested_transfer`, `merge_schedules`, and `vested_transfer`
+							pallet_vesting::Call::vest { .. }
+								| pallet_vesting::Call::vest_other { .. }
+						)
+						| RuntimeCall::Web3Names(..)
+						| RuntimeCall::Whitelist(..),
+				) && !matches!(
+					c,
+					RuntimeCall::ParachainStaking(parachain_staking::Call::transfer_delegation { .. })
+				)
+			}
 			ProxyType::NonDepositClaiming => matches!(
 				c,
-				RuntimeCall::Attestation(
+				RuntimeCall::AccreditedAttesters(..)
+					| RuntimeCall::Attestation(
 						// Excludes `reclaim_deposit`
 						attestation::Call::add { .. }
+							| attestation::Call::add_with_claimer_ack { .. }
 							| attestation::Call::remove { .. }
 							| attestation::Call::revoke { .. }
 							| attestation::Call::change_deposit_owner { .. }
 							| attestation::Call::update_deposit { .. }
+							| attestation::Call::challenge { .. }
+							| attestation::Call::contest_challenge { .. }
+							| attestation::Call::resolve_challenge { .. }
+							| attestation::Call::expire_challenge { .. }
 					)
 					// Excludes `Balances`
+					| RuntimeCall::Bounties(..)
+					| RuntimeCall::ChildBounties(..)
 					| RuntimeCall::Council(..)
 					| RuntimeCall::Ctype(..)
 					| RuntimeCall::Delegation(
@@ -830,6 +1219,7 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 							| delegation::Call::create_hierarchy { .. }
 							| delegation::Call::remove_delegation { .. }
 							| delegation::Call::revoke_delegation { .. }
+							| delegation::Call::revoke_hierarchy_paged { .. }
 							| delegation::Call::update_deposit { .. }
 							| delegation::Call::change_deposit_owner { .. }
 					)
@@ -837,21 +1227,30 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 					// Excludes `DepositStorage`
 					| RuntimeCall::Did(
 						// Excludes `reclaim_deposit`
-						did::Call::add_key_agreement_key { .. }
+						did::Call::add_hashed_service_endpoint { .. }
+							| did::Call::add_key_agreement_key { .. }
+							| did::Call::add_also_known_as { .. }
 							| did::Call::add_service_endpoint { .. }
 							| did::Call::create { .. }
 							| did::Call::delete { .. }
+							| did::Call::promote_light_did { .. }
 							| did::Call::remove_attestation_key { .. }
 							| did::Call::remove_delegation_key { .. }
 							| did::Call::remove_key_agreement_key { .. }
+							| did::Call::remove_also_known_as { .. }
 							| did::Call::remove_service_endpoint { .. }
 							| did::Call::set_attestation_key { .. }
 							| did::Call::set_authentication_key { .. }
+							| did::Call::set_multisig_authentication_key { .. }
 							| did::Call::set_delegation_key { .. }
 							| did::Call::submit_did_call { .. }
 							| did::Call::update_deposit { .. }
 							| did::Call::change_deposit_owner { .. }
+							| did::Call::did_remark { .. }
+							| did::Call::endorse { .. }
+							| did::Call::revoke_endorsement { .. }
 					)
+					| RuntimeCall::DidCurator(..)
 					| RuntimeCall::DidLookup(
 						// Excludes `reclaim_deposit`
 						pallet_did_lookup::Call::associate_account { .. }
@@ -861,6 +1260,11 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 							| pallet_did_lookup::Call::update_deposit { .. }
 							| pallet_did_lookup::Call::change_deposit_owner { .. }
 					)
+					| RuntimeCall::DidScheduler(
+						// Excludes `reclaim_deposit`
+						pallet_did_scheduler::Call::schedule { .. } | pallet_did_scheduler::Call::remove { .. }
+					)
+					| RuntimeCall::DidVoting(..)
 					| RuntimeCall::DipProvider(..)
 					| RuntimeCall::Indices(..)
 					| RuntimeCall::Multisig(..)
@@ -876,10 +1280,21 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 						| public_credentials::Call::remove { .. }
 						| public_credentials::Call::update_deposit { .. }
 						| public_credentials::Call::change_deposit_owner { .. }
+						| public_credentials::Call::set_subject_controller { .. }
+						| public_credentials::Call::set_credential_visibility { .. }
+					)
+					| RuntimeCall::Referenda(
+						// Excludes `refund_decision_deposit`, and `refund_submission_deposit`
+						pallet_referenda::Call::submit { .. }
+							| pallet_referenda::Call::place_decision_deposit { .. }
+							| pallet_referenda::Call::cancel { .. }
+							| pallet_referenda::Call::kill { .. }
+							| pallet_referenda::Call::nudge_referendum { .. }
+							| pallet_referenda::Call::one_fewer_deciding { .. }
+							| pallet_referenda::Call::set_metadata { .. }
 					)
 					| RuntimeCall::Scheduler(..)
 					| RuntimeCall::Session(..)
-					// Excludes `Sudo`
 					| RuntimeCall::System(..)
 					| RuntimeCall::TechnicalCommittee(..)
 					| RuntimeCall::TechnicalMembership(..)
@@ -889,31 +1304,55 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 					| RuntimeCall::Utility(..)
 					| RuntimeCall::Vesting(..)
 					| RuntimeCall::Web3Names(
-						// Excludes `ban`, and `reclaim_deposit`
+						// Excludes `ban`, `reclaim_deposit`, and `claim_by_registrar`
 						pallet_web3_names::Call::claim { .. }
 							| pallet_web3_names::Call::release_by_owner { .. }
 							| pallet_web3_names::Call::unban { .. }
+							| pallet_web3_names::Call::appeal_ban { .. }
+							| pallet_web3_names::Call::resolve_ban_appeal { .. }
+							| pallet_web3_names::Call::cancel_ban { .. }
 							| pallet_web3_names::Call::update_deposit { .. }
 							| pallet_web3_names::Call::change_deposit_owner { .. }
-					),
+							| pallet_web3_names::Call::claim_batch { .. }
+							| pallet_web3_names::Call::accept_pending_claim { .. }
+							| pallet_web3_names::Call::decline_pending_claim { .. }
+							| pallet_web3_names::Call::approve_registrar { .. }
+							| pallet_web3_names::Call::remove_registrar { .. }
+					)
+					| RuntimeCall::Whitelist(..),
 			),
 			ProxyType::Governance => matches!(
 				c,
-				RuntimeCall::Council(..)
+				RuntimeCall::AccreditedAttesters(..)
+					| RuntimeCall::Bounties(..)
+					| RuntimeCall::ChildBounties(..)
+					| RuntimeCall::Council(..)
 					| RuntimeCall::Democracy(..)
+					| RuntimeCall::Referenda(..)
 					| RuntimeCall::TechnicalCommittee(..)
 					| RuntimeCall::TechnicalMembership(..)
 					| RuntimeCall::TipsMembership(..)
 					| RuntimeCall::Treasury(..)
 					| RuntimeCall::Utility(..)
+					| RuntimeCall::Whitelist(..)
 			),
 			ProxyType::ParachainStaking => {
 				matches!(
 					c,
 					RuntimeCall::ParachainStaking(..) | RuntimeCall::Session(..) | RuntimeCall::Utility(..)
+				) && !matches!(
+					c,
+					RuntimeCall::ParachainStaking(parachain_staking::Call::transfer_delegation { .. })
 				)
 			}
 			ProxyType::CancelProxy => matches!(c, RuntimeCall::Proxy(pallet_proxy::Call::reject_announcement { .. })),
+			ProxyType::Identity => matches!(
+				c,
+				RuntimeCall::Did(..)
+					| RuntimeCall::DidLookup(..)
+					| RuntimeCall::Utility(..)
+					| RuntimeCall::Web3Names(..)
+			),
 		}
 	}
 
@@ -925,6 +1364,8 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 			(_, ProxyType::Any) => false,
 			// reclaiming deposits is part of NonTransfer but not in NonDepositClaiming
 			(ProxyType::NonDepositClaiming, ProxyType::NonTransfer) => false,
+			// reclaiming deposits is part of Identity but not in NonDepositClaiming
+			(ProxyType::NonDepositClaiming, ProxyType::Identity) => false,
 			// everything except NonTransfer and Any is part of NonDepositClaiming
 			(ProxyType::NonDepositClaiming, _) => true,
 			// Transfers are part of NonDepositClaiming but not in NonTransfer
@@ -958,10 +1399,12 @@ construct_runtime! {
 		// DELETED: RandomnessCollectiveFlip: pallet_insecure_randomness_collective_flip = 1,
 
 		Timestamp: pallet_timestamp = 2,
+		TimeAnchors: pallet_time_anchors = 3,
 		Indices: pallet_indices exclude_parts { Config } = 5,
 		Balances: pallet_balances = 6,
 		TransactionPayment: pallet_transaction_payment exclude_parts { Config } = 7,
-		Sudo: pallet_sudo = 8,
+		AssetTxPayment: pallet_asset_tx_payment = 10,
+		// DELETED: Sudo: pallet_sudo = 8,
 		Configuration: pallet_configuration = 9,
 
 		// Consensus support.
@@ -981,6 +1424,8 @@ construct_runtime! {
 		Treasury: pallet_treasury = 35,
 		// DELETED: RelayMigration: pallet_relay_migration = 36,
 		// DELETED: DynFilter: pallet_dyn_filter = 37,
+		Bounties: pallet_bounties = 38,
+		ChildBounties: pallet_child_bounties = 39,
 
 		// A stateless pallet with helper extrinsics (batch extrinsics, send from different origins, ...)
 		Utility: pallet_utility = 40,
@@ -1002,6 +1447,22 @@ construct_runtime! {
 
 		Multisig: pallet_multisig = 47,
 
+		// Fungible assets, e.g., attestation fee vouchers.
+		Assets: pallet_assets = 48,
+		// Foreign assets registered by governance, e.g., to pay transaction fees.
+		ForeignAssets: pallet_assets::<Instance1> = 49,
+
+		// Referenda voted on by DIDs, dispatching calls with the origins of their tracks.
+		Referenda: pallet_referenda = 50,
+		DidVoting: pallet_did_voting = 51,
+		Origins: governance::pallet_custom_origins = 52,
+
+		// Calls whitelisted by the technical committee, e.g., security fixes, which the council can dispatch as root.
+		Whitelist: pallet_whitelist = 53,
+
+		// Bulk creation and merging of vesting schedules, e.g., for token generation events.
+		VestingGrants: pallet_vesting_grants = 54,
+
 		// KILT Pallets. Start indices 60 to leave room
 		// DELETED: KiltLaunch: kilt_launch = 60,
 		Ctype: ctype = 61,
@@ -1016,6 +1477,9 @@ construct_runtime! {
 		Migration: pallet_migration = 70,
 		DipProvider: pallet_dip_provider = 71,
 		DepositStorage: pallet_deposit_storage = 72,
+		DidCurator: pallet_did_curator = 73,
+		AccreditedAttesters: pallet_membership::<Instance3> = 74,
+		DidScheduler: pallet_did_scheduler = 75,
 
 		// Parachains pallets. Start indices at 80 to leave room.
 
@@ -1056,16 +1520,41 @@ impl did::DeriveDidCallAuthorizationVerificationKeyRelationship for RuntimeCall
 				})
 		}
 		match self {
+			RuntimeCall::Assets(pallet_assets::Call::create { .. }) => {
+				Ok(did::DidVerificationKeyRelationship::Authentication)
+			}
 			RuntimeCall::Attestation { .. } => Ok(did::DidVerificationKeyRelationship::AssertionMethod),
 			RuntimeCall::Ctype { .. } => Ok(did::DidVerificationKeyRelationship::AssertionMethod),
 			RuntimeCall::Delegation { .. } => Ok(did::DidVerificationKeyRelationship::CapabilityDelegation),
 			RuntimeCall::DipProvider { .. } => Ok(did::DidVerificationKeyRelationship::Authentication),
 			// DID creation is not allowed through the DID proxy.
-			RuntimeCall::Did(did::Call::create { .. }) => Err(did::RelationshipDeriveError::NotCallableByDid),
+			RuntimeCall::Did(did::Call::create { .. } | did::Call::promote_light_did { .. }) => {
+				Err(did::RelationshipDeriveError::NotCallableByDid)
+			}
+			// Anchoring content is an assertion made by the DID.
+			RuntimeCall::Did(did::Call::did_remark { .. }) => Ok(did::DidVerificationKeyRelationship::AssertionMethod),
+			// Endorsing other DIDs is an assertion made by the DID.
+			RuntimeCall::Did(did::Call::endorse { .. } | did::Call::revoke_endorsement { .. }) => {
+				Ok(did::DidVerificationKeyRelationship::AssertionMethod)
+			}
 			RuntimeCall::Did { .. } => Ok(did::DidVerificationKeyRelationship::Authentication),
 			RuntimeCall::Web3Names { .. } => Ok(did::DidVerificationKeyRelationship::Authentication),
+			RuntimeCall::PublicCredentials(public_credentials::Call::set_credential_visibility { .. }) => {
+				Ok(did::DidVerificationKeyRelationship::Authentication)
+			}
 			RuntimeCall::PublicCredentials { .. } => Ok(did::DidVerificationKeyRelationship::AssertionMethod),
+			RuntimeCall::DidCurator { .. } => Ok(did::DidVerificationKeyRelationship::Authentication),
 			RuntimeCall::DidLookup { .. } => Ok(did::DidVerificationKeyRelationship::Authentication),
+			RuntimeCall::DidVoting { .. } => Ok(did::DidVerificationKeyRelationship::Authentication),
+			RuntimeCall::ParachainStaking(
+				parachain_staking::Call::linked_accounts_stake_more { .. }
+				| parachain_staking::Call::linked_accounts_leave_delegators { .. },
+			) => Ok(did::DidVerificationKeyRelationship::Authentication),
+			// Scheduled calls require the same key that is required to dispatch them directly.
+			RuntimeCall::DidScheduler(pallet_did_scheduler::Call::schedule { call, .. }) => {
+				call.derive_verification_key_relationship()
+			}
+			RuntimeCall::DidScheduler { .. } => Ok(did::DidVerificationKeyRelationship::Authentication),
 			RuntimeCall::Utility(pallet_utility::Call::batch { calls }) => single_key_relationship(&calls[..]),
 			RuntimeCall::Utility(pallet_utility::Call::batch_all { calls }) => single_key_relationship(&calls[..]),
 			RuntimeCall::Utility(pallet_utility::Call::force_batch { calls }) => single_key_relationship(&calls[..]),
@@ -1102,7 +1591,17 @@ pub type SignedExtra = (
 	frame_system::CheckEra<Runtime>,
 	frame_system::CheckNonce<Runtime>,
 	frame_system::CheckWeight<Runtime>,
-	pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
+	pallet_asset_tx_payment::ChargeAssetTxPayment<Runtime>,
+	runtime_common::metadata_hash::CheckMetadataHash<Runtime>,
+	pallet_did_lookup::signed_extension::AutoLinkAccount<
+		Runtime,
+		runtime_common::did_lookup::DidCallSubjectOf<Runtime>,
+	>,
+	runtime_common::identity_priority::IdentityPriorityBoost<
+		Runtime,
+		constants::identity_priority::PriorityBoost,
+		constants::identity_priority::MinNameAge,
+	>,
 );
 /// Unchecked extrinsic type as expected by this runtime.
 pub type UncheckedExtrinsic = generic::UncheckedExtrinsic<Address, RuntimeCall, Signature, SignedExtra>;
@@ -1116,9 +1615,41 @@ pub type Executive = frame_executive::Executive<
 	Runtime,
 	// Executes pallet hooks in the order of definition in construct_runtime
 	AllPalletsWithSystem,
-	(),
+	Migrations,
 >;
 
+parameter_types! {
+	pub const SudoPalletName: &'static str = "Sudo";
+}
+
+/// The runtime migrations to execute with the next runtime upgrade.
+pub type Migrations = (
+	pallet_deposit_storage::migrations::InitializeDepositParameters<Runtime, AllDepositParameters>,
+	// Governance calls are only dispatched via the collectives and the identity
+	// referenda from now on.
+	frame_support::migrations::RemovePallet<SudoPalletName, <Runtime as frame_system::Config>::DbWeight>,
+);
+
+/// The migrations which are executed over multiple blocks after the next
+/// runtime upgrade. The calls of the migrated pallets are blocked until their
+/// migration has completed.
+pub type SteppedMigrations = (
+	// All the calls of these pallets are blocked during their migration, so they
+	// are migrated first.
+	pallet_web3_names::migrations::InitializeOwnerCounter<Runtime>,
+	pallet_did_lookup::migrations::InitializeConnectedDidsCounter<Runtime>,
+	public_credentials::migrations::InitializeCredentialSubjectsCounter<Runtime>,
+	pallet_dip_provider::migrations::InitializeIdentityCommitmentsCounter<Runtime>,
+	did::migrations::BackfillKeyHistory<Runtime>,
+	did::migrations::RepriceDidDeposits<Runtime>,
+	did::migrations::InitializeDidCounter<Runtime>,
+	attestation::migrations::AddAttestationCid<Runtime>,
+	attestation::migrations::PopulateAttestationsByDelegation<Runtime>,
+	attestation::migrations::AddClaimerAcknowledgement<Runtime>,
+	attestation::migrations::InitializeAttestationCounter<Runtime>,
+	attestation::migrations::InitializeIssuanceCounters<Runtime>,
+);
+
 #[cfg(feature = "runtime-benchmarks")]
 mod benches {
 	frame_benchmarking::define_benchmarks!(
@@ -1134,26 +1665,34 @@ mod benches {
 		[pallet_collective, TechnicalCommittee]
 		[pallet_membership, TechnicalMembership]
 		[pallet_treasury, Treasury]
-		[pallet_sudo, Sudo]
+		[pallet_bounties, Bounties]
+		[pallet_child_bounties, ChildBounties]
 		[pallet_utility, Utility]
 		[pallet_vesting, Vesting]
+		[pallet_vesting_grants, VestingGrants]
 		[pallet_scheduler, Scheduler]
 		[pallet_proxy, Proxy]
 		[pallet_preimage, Preimage]
+		[pallet_whitelist, Whitelist]
 		[pallet_tips, Tips]
 		[pallet_multisig, Multisig]
+		[pallet_assets, Assets]
 		[ctype, Ctype]
 		[attestation, Attestation]
 		[delegation, Delegation]
 		[did, Did]
 		[pallet_inflation, Inflation]
 		[pallet_did_lookup, DidLookup]
+		[pallet_did_curator, DidCurator]
+		[pallet_did_scheduler, DidScheduler]
 		[pallet_web3_names, Web3Names]
 		[public_credentials, PublicCredentials]
 		[pallet_xcm, PolkadotXcm]
 		[pallet_migration, Migration]
 		[pallet_dip_provider, DipProvider]
 		[pallet_deposit_storage, DepositStorage]
+		[pallet_referenda, Referenda]
+		[pallet_did_voting, DidVoting]
 		[frame_benchmarking::baseline, Baseline::<Runtime>]
 	);
 }
@@ -1198,11 +1737,13 @@ impl_runtime_apis! {
 			uxt: <Block as BlockT>::Extrinsic,
 			len: u32,
 		) -> pallet_transaction_payment_rpc_runtime_api::RuntimeDispatchInfo<Balance> {
-			TransactionPayment::query_info(uxt, len)
+			let discount = fee_discount::<_, IdentityCalls, IdentityCallFeeDiscount>(&uxt.function);
+			discount_dispatch_info(TransactionPayment::query_info(uxt, len), discount)
 		}
 
 		fn query_fee_details(uxt: <Block as BlockT>::Extrinsic, len: u32) -> pallet_transaction_payment::FeeDetails<Balance> {
-			TransactionPayment::query_fee_details(uxt, len)
+			let discount = fee_discount::<_, IdentityCalls, IdentityCallFeeDiscount>(&uxt.function);
+			discount_fee_details(TransactionPayment::query_fee_details(uxt, len), discount)
 		}
 
 		fn query_weight_to_fee(weight: Weight) -> Balance {
@@ -1220,13 +1761,15 @@ impl_runtime_apis! {
 			call: RuntimeCall,
 			len: u32,
 		) -> pallet_transaction_payment::RuntimeDispatchInfo<Balance> {
-			TransactionPayment::query_call_info(call, len)
+			let discount = fee_discount::<_, IdentityCalls, IdentityCallFeeDiscount>(&call);
+			discount_dispatch_info(TransactionPayment::query_call_info(call, len), discount)
 		}
 		fn query_call_fee_details(
 			call: RuntimeCall,
 			len: u32,
 		) -> pallet_transaction_payment::FeeDetails<Balance> {
-			TransactionPayment::query_call_fee_details(call, len)
+			let discount = fee_discount::<_, IdentityCalls, IdentityCallFeeDiscount>(&call);
+			discount_fee_details(TransactionPayment::query_call_fee_details(call, len), discount)
 		}
 		fn query_weight_to_fee(weight: Weight) -> Balance {
 			TransactionPayment::weight_to_fee(weight)
@@ -1391,6 +1934,157 @@ impl_runtime_apis! {
 				details: details.into(),
 			})
 		}
+
+		fn linked_did_info(key: kilt_runtime_api_did::RawDidLookupKey<DidIdentifier, LinkableAccountId>) -> Option<
+			kilt_runtime_api_did::RawDidLinkedInfo<
+				DidIdentifier,
+				AccountId,
+				LinkableAccountId,
+				Balance,
+				Hash,
+				BlockNumber
+			>
+		> {
+			let did = match key {
+				kilt_runtime_api_did::DidLookupKey::Did(did) => did,
+				kilt_runtime_api_did::DidLookupKey::Web3Name(name) => {
+					let name: pallet_web3_names::web3_name::AsciiWeb3Name<Runtime> = name.try_into().ok()?;
+					pallet_web3_names::Owner::<Runtime>::get(&name)?.owner
+				}
+				kilt_runtime_api_did::DidLookupKey::Account(account) => pallet_did_lookup::ConnectedDids::<Runtime>::get(account)?.did,
+			};
+			let details = did::Did::<Runtime>::get(&did)?;
+			let w3n = pallet_web3_names::Names::<Runtime>::get(&did).map(Into::into);
+			let accounts = pallet_did_lookup::ConnectedAccounts::<Runtime>::iter_key_prefix(&did).collect();
+			let service_endpoints = did::ServiceEndpoints::<Runtime>::iter_prefix(&did).map(|e| From::from(e.1)).collect();
+
+			Some(kilt_runtime_api_did::RawDidLinkedInfo {
+				identifier: did,
+				w3n,
+				accounts,
+				service_endpoints,
+				details: details.into(),
+			})
+		}
+
+		fn key_history(did: DidIdentifier, key_id: Hash) -> Vec<kilt_runtime_api_did::KeyHistoryEntry<BlockNumber>> {
+			did::KeyHistory::<Runtime>::get(did, key_id).into_inner()
+		}
+
+		fn verify_signature_at(
+			did: DidIdentifier,
+			payload: Vec<u8>,
+			signature: kilt_runtime_api_did::DidSignature,
+			block_number: BlockNumber
+		) -> Vec<kilt_runtime_api_did::DidVerificationKeyRelationship> {
+			did::Pallet::<Runtime>::verify_signature_at(&did, &payload, &signature, block_number)
+		}
+
+		fn linked_accounts_with_labels(did: DidIdentifier) -> Vec<(LinkableAccountId, Option<Vec<u8>>)> {
+			pallet_did_lookup::ConnectedAccounts::<Runtime>::iter_key_prefix(&did)
+				.map(|account| {
+					let label = pallet_did_lookup::AccountLabels::<Runtime>::get(&account).map(|record| record.label.into());
+					(account, label)
+				})
+				.collect()
+		}
+
+		fn service_endpoints(did: DidIdentifier) -> Vec<kilt_runtime_api_did::RawServiceEndpointEntry> {
+			let inline_endpoints = did::ServiceEndpoints::<Runtime>::iter_prefix_values(&did)
+				.map(|endpoint| kilt_runtime_api_did::ServiceEndpointEntry::Inline(endpoint.into()));
+			let hashed_endpoints = did::HashedServiceEndpoints::<Runtime>::iter_prefix_values(&did)
+				.map(|endpoint| kilt_runtime_api_did::ServiceEndpointEntry::Hashed(endpoint.into()));
+			inline_endpoints.chain(hashed_endpoints).collect()
+		}
+
+		fn endorsements(did: DidIdentifier) -> Vec<(DidIdentifier, kilt_runtime_api_did::EndorsementDetails<Hash, BlockNumber>)> {
+			did::Endorsements::<Runtime>::iter_prefix(&did)
+				.filter(|(endorser, _)| did::Did::<Runtime>::contains_key(endorser))
+				.collect()
+		}
+		fn also_known_as(did: DidIdentifier) -> Vec<Vec<u8>> {
+			did::AlsoKnownAs::<Runtime>::get(&did).into_iter().map(|uri| uri.into_inner()).collect()
+		}
+
+		fn estimate_document_size(
+			did: DidIdentifier,
+			operations: Vec<kilt_runtime_api_did::DidDocumentOperation<AccountId, Hash>>
+		) -> Result<kilt_runtime_api_did::DidDocumentSizeEstimate<Balance>, kilt_runtime_api_did::DidDocumentEstimateError> {
+			did::Pallet::<Runtime>::estimate_document_size(&did, operations)
+		}
+
+		fn web3_name_records(name: Vec<u8>) -> Vec<(Vec<u8>, Vec<u8>)> {
+			let Ok(name) = pallet_web3_names::web3_name::AsciiWeb3Name::<Runtime>::try_from(name) else {
+				return Vec::new();
+			};
+			pallet_web3_names::Records::<Runtime>::iter_prefix(&name)
+				.map(|(key, record)| (key.into_inner(), record.value.into_inner()))
+				.collect()
+		}
+
+	}
+
+	impl kilt_runtime_api_ctype::Ctype<Block, Hash, ctype::CtypeEntryOf<Runtime>> for Runtime {
+		fn ctype(ctype_hash: Hash) -> Option<ctype::CtypeEntryOf<Runtime>> {
+			ctype::Ctypes::<Runtime>::get(ctype_hash)
+		}
+
+		fn ctype_schema(ctype_hash: Hash) -> Option<Vec<u8>> {
+			ctype::CtypeSchemas::<Runtime>::get(ctype_hash).map(|schema| schema.content.into_inner())
+		}
+	}
+
+	impl kilt_runtime_api_attestation::Attestation<Block, Hash, attestation::AttestationDetailsOf<Runtime>> for Runtime {
+		fn attestation(claim_hash: Hash) -> Option<attestation::AttestationDetailsOf<Runtime>> {
+			attestation::Attestations::<Runtime>::get(claim_hash)
+		}
+	}
+
+	impl kilt_runtime_api_attestation::AttestationIssuance<Block, DidIdentifier, Hash, attestation::IssuanceCounterOf<Runtime>> for Runtime {
+		fn issuance_counter(attester: DidIdentifier, ctype_hash: Hash) -> Option<attestation::IssuanceCounterOf<Runtime>> {
+			attestation::IssuanceCounters::<Runtime>::get(ctype_hash, attester).map(|details| details.counter)
+		}
+
+		fn ctype_issuance(ctype_hash: Hash) -> u64 {
+			attestation::IssuanceCounters::<Runtime>::iter_prefix_values(ctype_hash)
+				.fold(0u64, |total, details| total.saturating_add(details.counter.total))
+		}
+	}
+
+	impl kilt_runtime_api_delegation::Delegation<Block, Hash, DidIdentifier, delegation::Permissions> for Runtime {
+		fn delegation_subtree(
+			node_id: Hash,
+			max_depth: u32,
+			cursor: Option<Hash>,
+		) -> Option<kilt_runtime_api_delegation::DelegationSubtree<Hash, DidIdentifier, delegation::Permissions>> {
+			let page = delegation::Pallet::<Runtime>::delegation_subtree_page(&node_id, max_depth, cursor, constants::delegation::MAX_SUBTREE_PAGE_SIZE)?;
+			Some(kilt_runtime_api_delegation::DelegationSubtree {
+				nodes: page.nodes.into_iter().map(|(id, depth, node)| kilt_runtime_api_delegation::DelegationSubtreeNode {
+					id,
+					parent: node.parent,
+					depth,
+					owner: node.details.owner,
+					permissions: node.details.permissions,
+					revoked: node.details.revoked,
+				}).collect(),
+				next_cursor: page.next_cursor,
+			})
+		}
+	}
+
+	impl kilt_runtime_api_attestation::DelegatedAttestations<Block, Hash, Hash> for Runtime {
+		fn attestations_by_delegation(delegation_node_id: Hash) -> Vec<Hash> {
+			attestation::AttestationsByDelegation::<Runtime>::get(AuthorizationId::Delegation(delegation_node_id)).into_inner()
+		}
+	}
+
+	impl kilt_runtime_api_storage_stats::StorageStats<Block> for Runtime {
+		fn identity_storage_stats() -> kilt_runtime_api_storage_stats::IdentityStorageStats {
+			kilt_runtime_api_storage_stats::IdentityStorageStats {
+				identity_commitments: Some(runtime_common::storage_stats::identity_commitments_stats::<Runtime>()),
+				..runtime_common::storage_stats::identity_storage_stats::<Runtime>()
+			}
+		}
 	}
 
 	impl kilt_runtime_api_public_credentials::PublicCredentials<Block, Vec<u8>, Hash, public_credentials::CredentialEntry<Hash, DidIdentifier, BlockNumber, AccountId, Balance, AuthorizationId<<Runtime as delegation::Config>::DelegationNodeId>>, PublicCredentialsFilter<Hash, AccountId>, PublicCredentialsApiError> for Runtime {
@@ -1399,9 +2093,10 @@ impl_runtime_apis! {
 			public_credentials::Credentials::<Runtime>::get(subject, credential_id)
 		}
 
-		fn get_by_subject(subject: Vec<u8>, filter: Option<PublicCredentialsFilter<Hash, AccountId>>) -> Result<Vec<(Hash, public_credentials::CredentialEntry<Hash, DidIdentifier, BlockNumber, AccountId, Balance, AuthorizationId<<Runtime as delegation::Config>::DelegationNodeId>>)>, PublicCredentialsApiError> {
+		fn get_by_subject(subject: Vec<u8>, filter: Option<PublicCredentialsFilter<Hash, AccountId>>, include_hidden: bool) -> Result<Vec<(Hash, public_credentials::CredentialEntry<Hash, DidIdentifier, BlockNumber, AccountId, Balance, AuthorizationId<<Runtime as delegation::Config>::DelegationNodeId>>)>, PublicCredentialsApiError> {
 			let asset_did = AssetDid::try_from(subject).map_err(|_| PublicCredentialsApiError::InvalidSubjectId)?;
-			let credentials_prefix = public_credentials::Credentials::<Runtime>::iter_prefix(asset_did);
+			let credentials_prefix = public_credentials::Credentials::<Runtime>::iter_prefix(asset_did)
+				.filter(|(credential_id, _)| include_hidden || !public_credentials::Pallet::<Runtime>::is_credential_hidden(credential_id));
 			if let Some(filter) = filter {
 				Ok(credentials_prefix.filter(|(_, entry)| filter.should_include(entry)).collect())
 			} else {
@@ -1418,6 +2113,10 @@ impl_runtime_apis! {
 		fn get_staking_rates() -> kilt_runtime_api_staking::StakingRates {
 			ParachainStaking::get_staking_rates()
 		}
+
+		fn get_candidate_ranking() -> kilt_runtime_api_staking::CandidateRanking<AccountId, Balance> {
+			ParachainStaking::get_candidate_ranking()
+		}
 	}
 
 	impl kilt_runtime_api_dip_provider::DipProvider<Block, dip::runtime_api::DipProofRequest, CompleteMerkleProof<Hash, DidMerkleProofOf<Runtime>>, dip::runtime_api::DipProofError> for Runtime {
@@ -1426,7 +2125,9 @@ impl_runtime_apis! {
 
 			let identity_details = pallet_dip_provider::IdentityProviderOf::<Runtime>::retrieve(&request.identifier).map_err(dip::runtime_api::DipProofError::IdentityProvider)?;
 
-			DidMerkleRootGenerator::<Runtime>::generate_proof(&identity_details, request.version, request.keys.iter(), request.should_include_web3_name, request.accounts.iter()).map_err(dip::runtime_api::DipProofError::MerkleProof)
+			let scope = pallet_dip_provider::IdentityCommitmentScopes::<Runtime>::get(&request.identifier, request.version);
+
+			DidMerkleRootGenerator::<Runtime>::generate_scoped_proof(&identity_details, request.version, scope, request.keys.iter(), request.should_include_web3_name, request.accounts.iter(), request.should_include_also_known_as, request.should_include_web3_name_records).map_err(dip::runtime_api::DipProofError::MerkleProof)
 		}
 	}
 
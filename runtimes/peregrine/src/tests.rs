@@ -16,30 +16,35 @@
 
 // If you feel like getting in touch with us, you can do so at info@botlabs.org
 
-use frame_support::{traits::Currency, BoundedVec};
-use pallet_dip_provider::IdentityCommitmentOf;
+use frame_support::{
+	traits::{schedule::v3::TaskName, Currency, EnsureOrigin, InstanceFilter},
+	BoundedVec,
+};
+use pallet_dip_provider::{CommitterOf, IdentityCommitmentOf};
 use parity_scale_codec::MaxEncodedLen;
 
 use did::DeriveDidCallAuthorizationVerificationKeyRelationship;
 use pallet_did_lookup::associate_account_request::AssociateAccountRequest;
 use pallet_treasury::BalanceOf;
-use pallet_web3_names::{Web3NameOf, Web3OwnershipOf};
+use pallet_web3_names::{Web3NameOf, Web3NameRecordKeyOf, Web3NameRecordOf, Web3OwnershipOf};
 use runtime_common::{
 	constants::{
-		attestation::MAX_ATTESTATION_BYTE_LENGTH,
+		attestation::{MAX_ATTESTATION_BYTE_LENGTH, MAX_ISSUANCE_ENTRY_BYTE_LENGTH},
 		deposit_storage::MAX_DEPOSIT_PALLET_KEY_LENGTH,
 		did::{MAX_KEY_LENGTH, MAX_SERVICE_ENDPOINT_BYTE_LENGTH},
-		did_lookup::MAX_CONNECTION_BYTE_LENGTH,
-		dip_provider::MAX_COMMITMENT_BYTE_LENGTH,
+		did_lookup::{MAX_CONNECTION_BYTE_LENGTH, MAX_LABEL_BYTE_LENGTH},
+		did_scheduler::{MAX_AGENDA_ENTRY_BYTE_LENGTH, MAX_SCHEDULED_CALL_BYTE_LENGTH},
+		did_voting::MAX_VOTE_BYTE_LENGTH,
+		dip_provider::{MAX_COMMITMENT_BYTE_LENGTH, MAX_COMMITTER_BYTE_LENGTH},
 		public_credentials::MAX_PUBLIC_CREDENTIAL_STORAGE_LENGTH,
-		web3_names::MAX_NAME_BYTE_LENGTH,
+		web3_names::{MAX_NAME_BYTE_LENGTH, MAX_RECORD_BYTE_LENGTH},
 		MAX_INDICES_BYTE_LENGTH,
 	},
 	dip::deposit::DepositKey,
 	AccountId, BlockNumber,
 };
 
-use super::{Runtime, RuntimeCall};
+use super::{ProxyType, Runtime, RuntimeCall};
 
 #[test]
 fn call_size() {
@@ -62,7 +67,15 @@ fn attestation_storage_sizes() {
 	assert_eq!(
 		attestation_record + delegation_record,
 		MAX_ATTESTATION_BYTE_LENGTH as usize
-	)
+	);
+
+	let ctype_hash = <Runtime as frame_system::Config>::Hash::max_encoded_len();
+	let issuance_counter = ctype_hash
+		+ attestation::AttesterOf::<Runtime>::max_encoded_len()
+		+ attestation::IssuanceCounterDetailsOf::<Runtime>::max_encoded_len();
+	let issuance_limits = ctype_hash + attestation::IssuanceLimitsDetailsOf::<Runtime>::max_encoded_len();
+	assert!(issuance_limits <= issuance_counter);
+	assert_eq!(issuance_counter, MAX_ISSUANCE_ENTRY_BYTE_LENGTH as usize);
 }
 
 #[test]
@@ -89,7 +102,38 @@ fn did_lookup_storage_sizes() {
 
 	let did_connection_size = DidConnection::max_encoded_len();
 
-	assert_eq!(did_connection_size, MAX_CONNECTION_BYTE_LENGTH as usize)
+	assert_eq!(did_connection_size, MAX_CONNECTION_BYTE_LENGTH as usize);
+
+	let label_size = pallet_did_lookup::LabelRecordOf::<Runtime>::max_encoded_len();
+
+	assert_eq!(label_size, MAX_LABEL_BYTE_LENGTH as usize)
+}
+
+#[test]
+fn did_scheduler_storage_sizes() {
+	let did_size = pallet_did_scheduler::DidIdentifierOf::<Runtime>::max_encoded_len();
+	let id_size = TaskName::max_encoded_len();
+	let deposit_size = pallet_did_scheduler::ScheduledCallOf::<Runtime>::max_encoded_len();
+
+	assert_eq!(
+		did_size + id_size + deposit_size,
+		MAX_SCHEDULED_CALL_BYTE_LENGTH as usize
+	);
+
+	let agenda_entry_size = pallet_scheduler::ScheduledOf::<Runtime>::max_encoded_len();
+	let lookup_entry_size =
+		TaskName::max_encoded_len() + pallet_scheduler::TaskAddress::<BlockNumber>::max_encoded_len();
+
+	assert!(agenda_entry_size + lookup_entry_size <= MAX_AGENDA_ENTRY_BYTE_LENGTH as usize);
+}
+
+#[test]
+fn did_voting_storage_sizes() {
+	let voter_size = pallet_did_voting::VoterOf::<Runtime>::max_encoded_len();
+	let index_size = pallet_did_voting::PollIndexOf::<Runtime>::max_encoded_len();
+	let record_size = pallet_did_voting::VoteRecordOf::<Runtime>::max_encoded_len();
+
+	assert_eq!(voter_size + index_size + record_size, MAX_VOTE_BYTE_LENGTH as usize)
 }
 
 #[test]
@@ -100,6 +144,15 @@ fn web3_name_storage_sizes() {
 	assert_eq!(owner_size + name_size, MAX_NAME_BYTE_LENGTH as usize)
 }
 
+#[test]
+fn web3_name_record_storage_sizes() {
+	let name_size = Web3NameOf::<Runtime>::max_encoded_len();
+	let key_size = Web3NameRecordKeyOf::<Runtime>::max_encoded_len();
+	let record_size = Web3NameRecordOf::<Runtime>::max_encoded_len();
+
+	assert_eq!(name_size + key_size + record_size, MAX_RECORD_BYTE_LENGTH as usize)
+}
+
 #[test]
 fn indices_storage_sizes() {
 	type Indices = (<Runtime as frame_system::Config>::AccountId, BalanceOf<Runtime>, bool);
@@ -135,6 +188,14 @@ fn pallet_dip_provider_commitment_max_length() {
 	)
 }
 
+#[test]
+fn pallet_dip_provider_committer_max_length() {
+	assert_eq!(
+		CommitterOf::<Runtime>::max_encoded_len(),
+		MAX_COMMITTER_BYTE_LENGTH as usize
+	)
+}
+
 #[test]
 fn test_derive_did_verification_relation_ctype() {
 	let c1 = RuntimeCall::Ctype(ctype::Call::add {
@@ -198,6 +259,50 @@ fn test_derive_did_key_lookup() {
 	);
 }
 
+#[test]
+fn test_derive_did_key_scheduler() {
+	let ctype_call = RuntimeCall::Ctype(ctype::Call::add {
+		ctype: vec![0, 1, 2, 3],
+	});
+
+	assert_eq!(
+		RuntimeCall::DidScheduler(pallet_did_scheduler::Call::schedule {
+			id: [0u8; 32],
+			when: 100,
+			call: Box::new(ctype_call),
+		})
+		.derive_verification_key_relationship(),
+		Ok(did::DidVerificationKeyRelationship::AssertionMethod)
+	);
+
+	assert_eq!(
+		RuntimeCall::DidScheduler(pallet_did_scheduler::Call::schedule {
+			id: [0u8; 32],
+			when: 100,
+			call: Box::new(RuntimeCall::Web3Names(pallet_web3_names::Call::release_by_owner {})),
+		})
+		.derive_verification_key_relationship(),
+		Ok(did::DidVerificationKeyRelationship::Authentication)
+	);
+
+	assert_eq!(
+		RuntimeCall::DidScheduler(pallet_did_scheduler::Call::remove { id: [0u8; 32] })
+			.derive_verification_key_relationship(),
+		Ok(did::DidVerificationKeyRelationship::Authentication)
+	);
+}
+
+#[test]
+fn test_scheduler_rejects_did_origin() {
+	let origin: crate::RuntimeOrigin = did::DidRawOrigin {
+		id: AccountId::new([1u8; 32]),
+		submitter: AccountId::new([2u8; 32]),
+	}
+	.into();
+
+	assert!(<<Runtime as pallet_scheduler::Config>::ScheduleOrigin as EnsureOrigin<_>>::try_origin(origin).is_err());
+}
+
 #[test]
 fn test_derive_did_verification_relation_fail() {
 	let c1 = RuntimeCall::Ctype(ctype::Call::add {
@@ -312,3 +417,489 @@ fn test_derive_did_verification_relation_empty() {
 		Err(did::RelationshipDeriveError::InvalidCallParameter)
 	);
 }
+
+#[test]
+fn identity_proxy_filter() {
+	let remove_key = RuntimeCall::Did(did::Call::remove_delegation_key {});
+	let release_name = RuntimeCall::Web3Names(pallet_web3_names::Call::release_by_owner {});
+	let unlink_sender = RuntimeCall::DidLookup(pallet_did_lookup::Call::remove_sender_association {});
+	let transfer = RuntimeCall::Balances(pallet_balances::Call::transfer_keep_alive {
+		dest: AccountId::new([1u8; 32]).into(),
+		value: 1,
+	});
+
+	assert!(ProxyType::Identity.filter(&remove_key));
+	assert!(ProxyType::Identity.filter(&release_name));
+	assert!(ProxyType::Identity.filter(&unlink_sender));
+	assert!(!ProxyType::Identity.filter(&transfer));
+}
+
+#[test]
+fn staking_proxies_cannot_transfer_delegations() {
+	let transfer_delegation = RuntimeCall::ParachainStaking(parachain_staking::Call::transfer_delegation {
+		new_delegator: AccountId::new([1u8; 32]).into(),
+	});
+	let claim_rewards = RuntimeCall::ParachainStaking(parachain_staking::Call::claim_rewards {});
+
+	assert!(ProxyType::Any.filter(&transfer_delegation));
+	assert!(!ProxyType::NonTransfer.filter(&transfer_delegation));
+	assert!(!ProxyType::ParachainStaking.filter(&transfer_delegation));
+	assert!(ProxyType::NonTransfer.filter(&claim_rewards));
+	assert!(ProxyType::ParachainStaking.filter(&claim_rewards));
+}
+
+#[test]
+fn identity_proxy_is_subset_of_non_transfer() {
+	assert!(ProxyType::Any.is_superset(&ProxyType::Identity));
+	assert!(ProxyType::NonTransfer.is_superset(&ProxyType::Identity));
+	assert!(!ProxyType::NonDepositClaiming.is_superset(&ProxyType::Identity));
+	assert!(!ProxyType::Identity.is_superset(&ProxyType::NonTransfer));
+}
+
+#[test]
+fn foreign_asset_fees_only_for_identity_calls() {
+	use pallet_asset_tx_payment::OnChargeAssetTransaction;
+	use sp_runtime::transaction_validity::{InvalidTransaction, TransactionValidityError};
+
+	type ForeignAssetFees = <Runtime as pallet_asset_tx_payment::Config>::OnChargeAssetTransaction;
+
+	let call = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+	assert_eq!(
+		ForeignAssetFees::withdraw_fee(
+			&AccountId::new([0u8; 32]),
+			&call,
+			&Default::default(),
+			xcm::v3::MultiLocation::parent(),
+			1_000,
+			0
+		)
+		.err(),
+		Some(TransactionValidityError::Invalid(InvalidTransaction::Payment))
+	);
+}
+
+#[test]
+fn free_trial_fees_require_a_signed_did_operation() {
+	use pallet_transaction_payment::OnChargeTransaction;
+	use runtime_common::constants::KILT;
+
+	use crate::{Balances, DidFreeTrialAccount};
+
+	type Fees = <Runtime as pallet_transaction_payment::Config>::OnChargeTransaction;
+
+	let did = AccountId::new([1u8; 32]);
+	let submitter = AccountId::new([2u8; 32]);
+	let call = RuntimeCall::Did(did::Call::submit_did_call {
+		did_call: Box::new(did::did_details::DidAuthorizedCallOperation {
+			did: did.clone(),
+			tx_counter: 1,
+			call: RuntimeCall::Did(did::Call::remove_delegation_key {}),
+			block_number: BlockNumber::default(),
+			submitter: submitter.clone(),
+		}),
+		signature: did::DidSignature::Sr25519(sp_core::sr25519::Signature([0; 64])),
+	});
+
+	sp_io::TestExternalities::default().execute_with(|| {
+		did::RemainingFreeTrialOperations::<Runtime>::insert(&did, 1);
+		Balances::make_free_balance_be(&DidFreeTrialAccount::get(), 100 * KILT);
+
+		// The operation is not signed by the DID, so the submitter has to pay.
+		assert!(Fees::withdraw_fee(&submitter, &call, &Default::default(), KILT, 0).is_err());
+		assert_eq!(Balances::free_balance(DidFreeTrialAccount::get()), 100 * KILT);
+		assert_eq!(did::RemainingFreeTrialOperations::<Runtime>::get(&did), 1);
+	});
+}
+
+#[test]
+fn xcm_safe_call_filter_only_allows_did_signed_calls() {
+	use frame_support::traits::Contains;
+
+	use crate::xcm_config::SafeCallFilter;
+
+	let web3_name_claim = RuntimeCall::Web3Names(pallet_web3_names::Call::claim {
+		name: b"test-name".to_vec().try_into().unwrap(),
+	});
+	let remark = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+	let did_call = |call: RuntimeCall| {
+		RuntimeCall::Did(did::Call::submit_did_call {
+			did_call: Box::new(did::did_details::DidAuthorizedCallOperation {
+				did: AccountId::new([1u8; 32]),
+				tx_counter: 1,
+				call,
+				block_number: BlockNumber::default(),
+				submitter: AccountId::new([2u8; 32]),
+			}),
+			signature: did::DidSignature::Sr25519(sp_core::sr25519::Signature([0; 64])),
+		})
+	};
+
+	assert!(!SafeCallFilter::contains(&web3_name_claim));
+	assert!(SafeCallFilter::contains(&did_call(web3_name_claim)));
+	assert!(!SafeCallFilter::contains(&remark));
+	assert!(!SafeCallFilter::contains(&did_call(remark)));
+}
+
+#[test]
+fn xcm_sibling_account_linked_to_did_is_not_converted_into_did_origin() {
+	use kilt_support::Deposit;
+	use pallet_did_lookup::{linkable_account::LinkableAccountId, ConnectedDids, ConnectionRecord};
+	use runtime_common::xcm_config::LocationToAccountId;
+	use xcm::v3::prelude::*;
+	use xcm_executor::traits::{ConvertLocation, ConvertOrigin};
+
+	use crate::{
+		xcm_config::{RelayNetworkId, XcmOriginToTransactDispatchOrigin},
+		RuntimeOrigin,
+	};
+
+	let location = MultiLocation::new(
+		1,
+		X2(
+			Parachain(2_000),
+			AccountId32 {
+				network: None,
+				id: [1u8; 32],
+			},
+		),
+	);
+	let account = LocationToAccountId::<RelayNetworkId>::convert_location(&location)
+		.expect("Sibling account should be converted into a local account.");
+
+	sp_io::TestExternalities::default().execute_with(|| {
+		ConnectedDids::<Runtime>::insert(
+			LinkableAccountId::from(account.clone()),
+			ConnectionRecord {
+				did: AccountId::new([2u8; 32]),
+				deposit: Deposit {
+					owner: account.clone(),
+					amount: 0,
+				},
+			},
+		);
+
+		// The link does not give the account authority over the DID.
+		assert!(
+			<XcmOriginToTransactDispatchOrigin as ConvertOrigin<RuntimeOrigin>>::convert_origin(
+				location,
+				OriginKind::Native
+			)
+			.is_err()
+		);
+		// The account can only act as itself, e.g., to submit calls signed by the DID key.
+		let origin = <XcmOriginToTransactDispatchOrigin as ConvertOrigin<RuntimeOrigin>>::convert_origin(
+			location,
+			OriginKind::SovereignAccount,
+		)
+		.expect("Sibling account should be converted into a signed origin.");
+		assert_eq!(frame_system::ensure_signed(origin), Ok(account));
+	});
+}
+
+#[test]
+fn xcm_identity_transacts_denied_while_paused() {
+	use frame_support::weights::Weight;
+	use parity_scale_codec::Encode;
+	use runtime_common::xcm_config::DenyIdentityTransactsWhilePaused;
+	use xcm::v3::prelude::*;
+	use xcm_executor::traits::{Properties, ShouldExecute};
+
+	use crate::xcm_config::IdentityCalls;
+
+	type Barrier = DenyIdentityTransactsWhilePaused<
+		pallet_configuration::IsXcmIdentityOpsPaused<Runtime>,
+		RuntimeCall,
+		IdentityCalls,
+	>;
+
+	let transact = |call: RuntimeCall| {
+		vec![Transact {
+			origin_kind: OriginKind::Native,
+			require_weight_at_most: Weight::zero(),
+			call: call.encode().into(),
+		}]
+	};
+	let should_execute = |mut message: Vec<Instruction<()>>| {
+		Barrier::should_execute(
+			&MultiLocation::parent(),
+			&mut message,
+			Weight::zero(),
+			&mut Properties {
+				weight_credit: Weight::zero(),
+				message_id: None,
+			},
+		)
+	};
+	let web3_name_claim = RuntimeCall::Web3Names(pallet_web3_names::Call::claim {
+		name: b"test-name".to_vec().try_into().unwrap(),
+	});
+	let remark = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+
+	sp_io::TestExternalities::default().execute_with(|| {
+		assert!(should_execute(transact(web3_name_claim.clone())).is_ok());
+
+		pallet_configuration::XcmIdentityOpsPaused::<Runtime>::set(true);
+		assert!(should_execute(transact(web3_name_claim.clone())).is_err());
+		assert!(should_execute(vec![SetAppendix(Xcm(transact(web3_name_claim)))]).is_err());
+		assert!(should_execute(transact(remark)).is_ok());
+	});
+}
+
+#[test]
+fn identity_calls_can_be_paused_individually() {
+	use frame_support::{assert_ok, traits::Contains};
+
+	use crate::{BaseFilter, RuntimeOrigin};
+
+	let did_delete = RuntimeCall::Did(did::Call::delete { endpoints_to_remove: 0 });
+	let did_reclaim = RuntimeCall::Did(did::Call::reclaim_deposit {
+		did_subject: AccountId::new([0u8; 32]),
+		endpoints_to_remove: 0,
+	});
+	let remark = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+	let full_call_name = |pallet_name: &[u8], call_name: &[u8]| -> pallet_configuration::FullCallName {
+		(
+			pallet_name.to_vec().try_into().unwrap(),
+			call_name.to_vec().try_into().unwrap(),
+		)
+	};
+
+	sp_io::TestExternalities::default().execute_with(|| {
+		assert!(BaseFilter::contains(&did_delete));
+
+		assert_ok!(pallet_configuration::Pallet::<Runtime>::pause_call(
+			RuntimeOrigin::root(),
+			full_call_name(b"Did", b"delete")
+		));
+		assert!(!BaseFilter::contains(&did_delete));
+		assert!(BaseFilter::contains(&did_reclaim));
+
+		// Calls of non-identity pallets cannot be paused.
+		assert_ok!(pallet_configuration::Pallet::<Runtime>::pause_call(
+			RuntimeOrigin::root(),
+			full_call_name(b"System", b"remark")
+		));
+		assert!(BaseFilter::contains(&remark));
+	});
+}
+
+#[test]
+fn identity_parameters_origin_has_dedicated_track() {
+	use frame_support::traits::EnsureOrigin;
+	use pallet_referenda::TracksInfo as _;
+
+	use crate::{
+		governance::{CustomOrigin, EnsureIdentityParameters, TracksInfo, IDENTITY_PARAMETERS_TRACK_ID},
+		OriginCaller, RuntimeOrigin,
+	};
+
+	assert_eq!(
+		TracksInfo::track_for(&OriginCaller::Origins(CustomOrigin::IdentityParameters)),
+		Ok(IDENTITY_PARAMETERS_TRACK_ID)
+	);
+	assert_eq!(
+		TracksInfo::track_for(&OriginCaller::system(frame_system::RawOrigin::Root)),
+		Err(())
+	);
+
+	assert!(EnsureIdentityParameters::try_origin(RuntimeOrigin::from(CustomOrigin::IdentityParameters)).is_ok());
+	assert!(EnsureIdentityParameters::try_origin(RuntimeOrigin::root()).is_err());
+}
+
+#[test]
+fn did_curator_calls_only_include_curator_actions() {
+	use frame_support::traits::Contains;
+
+	use crate::BountyCuratorCalls;
+
+	let accept_curator = RuntimeCall::Bounties(pallet_bounties::Call::accept_curator { bounty_id: 0 });
+	let award_child_bounty = RuntimeCall::ChildBounties(pallet_child_bounties::Call::award_child_bounty {
+		parent_bounty_id: 0,
+		child_bounty_id: 0,
+		beneficiary: AccountId::new([1u8; 32]).into(),
+	});
+	let approve_bounty = RuntimeCall::Bounties(pallet_bounties::Call::approve_bounty { bounty_id: 0 });
+	let remark = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+
+	assert!(BountyCuratorCalls::contains(&accept_curator));
+	assert!(BountyCuratorCalls::contains(&award_child_bounty));
+	assert!(!BountyCuratorCalls::contains(&approve_bounty));
+	assert!(!BountyCuratorCalls::contains(&remark));
+
+	let curator_call = RuntimeCall::DidCurator(pallet_did_curator::Call::dispatch_as_curator {
+		call: Box::new(accept_curator),
+	});
+	assert_eq!(
+		curator_call.derive_verification_key_relationship(),
+		Ok(did::DidVerificationKeyRelationship::Authentication)
+	);
+
+	// Curator calls are not dispatched from the account of the DID identifier.
+	let did = AccountId::new([2u8; 32]);
+	assert_ne!(crate::DidCurator::curator_account(&did), did);
+}
+
+#[test]
+fn identity_maintenance_origin_has_dedicated_track() {
+	use frame_support::traits::EnsureOrigin;
+	use pallet_referenda::TracksInfo as _;
+
+	use crate::{
+		governance::{CustomOrigin, EnsureIdentityMaintenance, TracksInfo, IDENTITY_MAINTENANCE_TRACK_ID},
+		OriginCaller, RuntimeOrigin,
+	};
+
+	assert_eq!(
+		TracksInfo::track_for(&OriginCaller::Origins(CustomOrigin::IdentityMaintenance)),
+		Ok(IDENTITY_MAINTENANCE_TRACK_ID)
+	);
+
+	assert!(EnsureIdentityMaintenance::try_origin(RuntimeOrigin::from(CustomOrigin::IdentityMaintenance)).is_ok());
+	assert!(EnsureIdentityMaintenance::try_origin(RuntimeOrigin::from(CustomOrigin::IdentityParameters)).is_err());
+	assert!(EnsureIdentityMaintenance::try_origin(RuntimeOrigin::root()).is_err());
+}
+
+#[test]
+fn identity_appeals_origin_has_dedicated_track() {
+	use frame_support::traits::EnsureOrigin;
+	use pallet_referenda::TracksInfo as _;
+
+	use crate::{
+		governance::{CustomOrigin, EnsureIdentityAppeals, TracksInfo, IDENTITY_APPEALS_TRACK_ID},
+		OriginCaller, RuntimeOrigin,
+	};
+
+	assert_eq!(
+		TracksInfo::track_for(&OriginCaller::Origins(CustomOrigin::IdentityAppeals)),
+		Ok(IDENTITY_APPEALS_TRACK_ID)
+	);
+
+	assert!(EnsureIdentityAppeals::try_origin(RuntimeOrigin::from(CustomOrigin::IdentityAppeals)).is_ok());
+	assert!(EnsureIdentityAppeals::try_origin(RuntimeOrigin::from(CustomOrigin::IdentityMaintenance)).is_err());
+	assert!(EnsureIdentityAppeals::try_origin(RuntimeOrigin::root()).is_err());
+}
+
+#[test]
+fn identity_accreditation_origin_has_dedicated_track() {
+	use frame_support::traits::EnsureOrigin;
+	use pallet_referenda::TracksInfo as _;
+
+	use crate::{
+		governance::{CustomOrigin, EnsureIdentityAccreditation, TracksInfo, IDENTITY_ACCREDITATION_TRACK_ID},
+		OriginCaller, RuntimeOrigin,
+	};
+
+	assert_eq!(
+		TracksInfo::track_for(&OriginCaller::Origins(CustomOrigin::IdentityAccreditation)),
+		Ok(IDENTITY_ACCREDITATION_TRACK_ID)
+	);
+
+	assert!(EnsureIdentityAccreditation::try_origin(RuntimeOrigin::from(CustomOrigin::IdentityAccreditation)).is_ok());
+	assert!(EnsureIdentityAccreditation::try_origin(RuntimeOrigin::from(CustomOrigin::IdentityMaintenance)).is_err());
+	assert!(EnsureIdentityAccreditation::try_origin(RuntimeOrigin::root()).is_err());
+}
+
+#[test]
+fn only_accredited_attesters_can_attest_regulated_ctypes() {
+	use attestation::AttesterAccreditation;
+	use frame_support::assert_ok;
+	use runtime_common::Hash;
+
+	use crate::{governance::CustomOrigin, AccreditedAttesters, RegulatedCtypes, RuntimeOrigin};
+
+	type Accreditation = <Runtime as attestation::Config>::AttesterAccreditation;
+
+	let accredited = AccountId::new([1u8; 32]);
+	let not_accredited = AccountId::new([2u8; 32]);
+	let regulated_ctype = Hash::repeat_byte(1);
+	let other_ctype = Hash::repeat_byte(2);
+
+	sp_io::TestExternalities::default().execute_with(|| {
+		RegulatedCtypes::set(&vec![regulated_ctype]);
+		assert_ok!(AccreditedAttesters::add_member(
+			RuntimeOrigin::from(CustomOrigin::IdentityAccreditation),
+			accredited.clone().into()
+		));
+
+		assert!(Accreditation::is_accredited(&accredited, &regulated_ctype));
+		assert!(!Accreditation::is_accredited(&not_accredited, &regulated_ctype));
+		assert!(Accreditation::is_accredited(&not_accredited, &other_ctype));
+	});
+}
+
+#[test]
+fn former_sudo_calls_are_dispatchable_through_governance_origins() {
+	use frame_support::{assert_noop, assert_ok};
+	use runtime_common::{deposit_parameters::DepositParameter, Hash};
+	use sp_runtime::DispatchError;
+
+	use crate::{governance::CustomOrigin, Ctype, DepositStorage, RuntimeOrigin, Web3Names};
+
+	let maintenance_origin = || RuntimeOrigin::from(CustomOrigin::IdentityMaintenance);
+	let parameters_origin = || RuntimeOrigin::from(CustomOrigin::IdentityParameters);
+	let signed_origin = || RuntimeOrigin::signed(AccountId::new([1u8; 32]));
+	let name: pallet_web3_names::Web3NameInput<Runtime> = b"banned-name".to_vec().try_into().unwrap();
+	let ctype_hash = Hash::repeat_byte(1);
+
+	sp_io::TestExternalities::default().execute_with(|| {
+		// Web3name bans.
+		assert_noop!(Web3Names::ban(signed_origin(), name.clone()), DispatchError::BadOrigin);
+		assert_ok!(Web3Names::ban(maintenance_origin(), name.clone()));
+		assert_noop!(
+			Web3Names::cancel_ban(parameters_origin(), name.clone()),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(Web3Names::cancel_ban(maintenance_origin(), name.clone()));
+		pallet_web3_names::Banned::<Runtime>::insert(
+			pallet_web3_names::Web3NameOf::<Runtime>::try_from(name.to_vec()).unwrap(),
+			(),
+		);
+		assert_noop!(
+			Web3Names::unban(parameters_origin(), name.clone()),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(Web3Names::unban(maintenance_origin(), name));
+
+		// CType maintenance.
+		ctype::Ctypes::<Runtime>::insert(
+			ctype_hash,
+			ctype::CtypeEntryOf::<Runtime> {
+				creator: AccountId::new([1u8; 32]),
+				created_at: 0,
+			},
+		);
+		assert_noop!(
+			Ctype::set_block_number(signed_origin(), ctype_hash, 10),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(Ctype::set_block_number(maintenance_origin(), ctype_hash, 10));
+
+		// Deposit repricing.
+		let web3_name_deposit = DepositParameter::Web3Name.default_value();
+		assert_noop!(
+			DepositStorage::set_deposit_parameter(maintenance_origin(), DepositParameter::Web3Name, web3_name_deposit),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(DepositStorage::set_deposit_parameter(
+			parameters_origin(),
+			DepositParameter::Web3Name,
+			web3_name_deposit
+		));
+	});
+}
+
+#[test]
+fn sudo_pallet_storage_is_removed() {
+	use frame_support::traits::OnRuntimeUpgrade;
+	use parity_scale_codec::Encode;
+
+	let sudo_key_storage = [sp_io::hashing::twox_128(b"Sudo"), sp_io::hashing::twox_128(b"Key")].concat();
+
+	sp_io::TestExternalities::default().execute_with(|| {
+		sp_io::storage::set(&sudo_key_storage, &AccountId::new([1u8; 32]).encode());
+
+		crate::Migrations::on_runtime_upgrade();
+
+		assert!(sp_io::storage::get(&sudo_key_storage).is_none());
+	});
+}
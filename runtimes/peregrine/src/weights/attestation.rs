@@ -52,89 +52,64 @@ use sp_std::marker::PhantomData;
 /// Weight functions for `attestation`.
 pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> attestation::WeightInfo for WeightInfo<T> {
-	/// Storage: Ctype Ctypes (r:1 w:0)
-	/// Proof: Ctype Ctypes (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
-	/// Storage: Attestation Attestations (r:1 w:1)
-	/// Proof: Attestation Attestations (max_values: None, max_size: Some(195), added: 2670, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	// The calls below are not covered by the benchmark run above and use the
+	// estimated weights of the pallet.
 	fn add() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `793`
-		//  Estimated: `7840`
-		// Minimum execution time: 23_737_000 picoseconds.
-		Weight::from_parts(23_737_000, 0)
-			.saturating_add(Weight::from_parts(0, 7840))
-			.saturating_add(T::DbWeight::get().reads(3))
-			.saturating_add(T::DbWeight::get().writes(2))
-	}
-	/// Storage: Attestation Attestations (r:1 w:1)
-	/// Proof: Attestation Attestations (max_values: None, max_size: Some(195), added: 2670, mode: MaxEncodedLen)
+		<attestation::default_weights::SubstrateWeight<T> as attestation::WeightInfo>::add()
+	}
 	fn revoke() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `227`
-		//  Estimated: `2670`
-		// Minimum execution time: 21_471_000 picoseconds.
-		Weight::from_parts(21_471_000, 0)
-			.saturating_add(Weight::from_parts(0, 2670))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
-	}
-	/// Storage: Attestation Attestations (r:1 w:1)
-	/// Proof: Attestation Attestations (max_values: None, max_size: Some(195), added: 2670, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+		<attestation::default_weights::SubstrateWeight<T> as attestation::WeightInfo>::revoke()
+	}
 	fn remove() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `905`
-		//  Estimated: `5277`
-		// Minimum execution time: 36_124_000 picoseconds.
-		Weight::from_parts(36_124_000, 0)
-			.saturating_add(Weight::from_parts(0, 5277))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(2))
-	}
-	/// Storage: Attestation Attestations (r:1 w:1)
-	/// Proof: Attestation Attestations (max_values: None, max_size: Some(195), added: 2670, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+		<attestation::default_weights::SubstrateWeight<T> as attestation::WeightInfo>::remove()
+	}
 	fn reclaim_deposit() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `905`
-		//  Estimated: `5277`
-		// Minimum execution time: 21_043_000 picoseconds.
-		Weight::from_parts(21_043_000, 0)
-			.saturating_add(Weight::from_parts(0, 5277))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(2))
-	}
-	/// Storage: Attestation Attestations (r:1 w:1)
-	/// Proof: Attestation Attestations (max_values: None, max_size: Some(195), added: 2670, mode: MaxEncodedLen)
-	/// Storage: System Account (r:2 w:2)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+		<attestation::default_weights::SubstrateWeight<T> as attestation::WeightInfo>::reclaim_deposit()
+	}
 	fn change_deposit_owner() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1484`
-		//  Estimated: `7884`
-		// Minimum execution time: 26_817_000 picoseconds.
-		Weight::from_parts(26_817_000, 0)
-			.saturating_add(Weight::from_parts(0, 7884))
-			.saturating_add(T::DbWeight::get().reads(3))
-			.saturating_add(T::DbWeight::get().writes(3))
-	}
-	/// Storage: Attestation Attestations (r:1 w:1)
-	/// Proof: Attestation Attestations (max_values: None, max_size: Some(195), added: 2670, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+		<attestation::default_weights::SubstrateWeight<T> as attestation::WeightInfo>::change_deposit_owner()
+	}
 	fn update_deposit() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `905`
-		//  Estimated: `5277`
-		// Minimum execution time: 24_307_000 picoseconds.
-		Weight::from_parts(24_307_000, 0)
-			.saturating_add(Weight::from_parts(0, 5277))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(2))
+		<attestation::default_weights::SubstrateWeight<T> as attestation::WeightInfo>::update_deposit()
+	}
+	fn challenge() -> Weight {
+		<attestation::default_weights::SubstrateWeight<T> as attestation::WeightInfo>::challenge()
+	}
+	fn contest_challenge() -> Weight {
+		<attestation::default_weights::SubstrateWeight<T> as attestation::WeightInfo>::contest_challenge()
+	}
+	fn resolve_challenge() -> Weight {
+		<attestation::default_weights::SubstrateWeight<T> as attestation::WeightInfo>::resolve_challenge()
+	}
+	fn adjudicate_challenge() -> Weight {
+		<attestation::default_weights::SubstrateWeight<T> as attestation::WeightInfo>::adjudicate_challenge()
+	}
+	fn expire_challenge() -> Weight {
+		<attestation::default_weights::SubstrateWeight<T> as attestation::WeightInfo>::expire_challenge()
+	}
+	fn set_issuance_limits() -> Weight {
+		<attestation::default_weights::SubstrateWeight<T> as attestation::WeightInfo>::set_issuance_limits()
+	}
+	fn add_with_claimer_ack() -> Weight {
+		<attestation::default_weights::SubstrateWeight<T> as attestation::WeightInfo>::add_with_claimer_ack()
+	}
+	fn remove_issuance_counter() -> Weight {
+		<attestation::default_weights::SubstrateWeight<T> as attestation::WeightInfo>::remove_issuance_counter()
+	}
+	fn add_attestation_cid_step() -> Weight {
+		<attestation::default_weights::SubstrateWeight<T> as attestation::WeightInfo>::add_attestation_cid_step()
+	}
+	fn populate_attestations_by_delegation_step() -> Weight {
+		<attestation::default_weights::SubstrateWeight<T> as attestation::WeightInfo>::populate_attestations_by_delegation_step()
+	}
+	fn add_claimer_acknowledgement_step() -> Weight {
+		<attestation::default_weights::SubstrateWeight<T> as attestation::WeightInfo>::add_claimer_acknowledgement_step()
+	}
+	fn initialize_attestation_counter_step() -> Weight {
+		<attestation::default_weights::SubstrateWeight<T> as attestation::WeightInfo>::initialize_attestation_counter_step()
+	}
+	fn initialize_issuance_counters_step() -> Weight {
+		<attestation::default_weights::SubstrateWeight<T> as attestation::WeightInfo>::initialize_issuance_counters_step()
 	}
 }
 
@@ -79,6 +79,14 @@ impl<T: frame_system::Config> ctype::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	// The calls below are not covered by the benchmark run above and use the
+	// estimated weights of the pallet.
+	fn register_with_content(l: u32, ) -> Weight {
+		<ctype::default_weights::SubstrateWeight<T> as ctype::WeightInfo>::register_with_content(l)
+	}
+	fn remove_content() -> Weight {
+		<ctype::default_weights::SubstrateWeight<T> as ctype::WeightInfo>::remove_content()
+	}
 }
 
 #[cfg(test)]
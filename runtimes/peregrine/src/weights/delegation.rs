@@ -225,6 +225,11 @@ impl<T: frame_system::Config> delegation::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
+	// The calls below are not covered by the benchmark run above and use the
+	// estimated weights of the pallet.
+	fn revoke_hierarchy_paged(n: u32, ) -> Weight {
+		<delegation::default_weights::SubstrateWeight<T> as delegation::WeightInfo>::revoke_hierarchy_paged(n)
+	}
 }
 
 #[cfg(test)]
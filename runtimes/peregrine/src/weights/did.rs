@@ -49,149 +49,6 @@ use sp_std::marker::PhantomData;
 /// Weight functions for `did`.
 pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> did::WeightInfo for WeightInfo<T> {
-	/// Storage: Did DidBlacklist (r:1 w:0)
-	/// Proof: Did DidBlacklist (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
-	/// Storage: Did Did (r:1 w:1)
-	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	/// Storage: System Account (r:2 w:2)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	/// Storage: Balances Holds (r:1 w:1)
-	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
-	/// Storage: Did DidEndpointsCount (r:0 w:1)
-	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
-	/// Storage: Did ServiceEndpoints (r:0 w:25)
-	/// Proof: Did ServiceEndpoints (max_values: None, max_size: Some(615), added: 3090, mode: MaxEncodedLen)
-	/// The range of component `n` is `[1, 10]`.
-	/// The range of component `c` is `[1, 25]`.
-	fn create_ed25519_keys(n: u32, c: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1131`
-		//  Estimated: `6204`
-		// Minimum execution time: 179_404_000 picoseconds.
-		Weight::from_parts(170_230_985, 0)
-			.saturating_add(Weight::from_parts(0, 6204))
-			// Standard Error: 40_649
-			.saturating_add(Weight::from_parts(296_994, 0).saturating_mul(n.into()))
-			// Standard Error: 15_716
-			.saturating_add(Weight::from_parts(10_509_026, 0).saturating_mul(c.into()))
-			.saturating_add(T::DbWeight::get().reads(5))
-			.saturating_add(T::DbWeight::get().writes(5))
-			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
-	}
-	/// Storage: Did DidBlacklist (r:1 w:0)
-	/// Proof: Did DidBlacklist (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
-	/// Storage: Did Did (r:1 w:1)
-	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	/// Storage: System Account (r:2 w:2)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	/// Storage: Balances Holds (r:1 w:1)
-	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
-	/// Storage: Did DidEndpointsCount (r:0 w:1)
-	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
-	/// Storage: Did ServiceEndpoints (r:0 w:25)
-	/// Proof: Did ServiceEndpoints (max_values: None, max_size: Some(615), added: 3090, mode: MaxEncodedLen)
-	/// The range of component `n` is `[1, 10]`.
-	/// The range of component `c` is `[1, 25]`.
-	fn create_sr25519_keys(n: u32, c: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1131`
-		//  Estimated: `6204`
-		// Minimum execution time: 181_049_000 picoseconds.
-		Weight::from_parts(161_298_980, 0)
-			.saturating_add(Weight::from_parts(0, 6204))
-			// Standard Error: 36_193
-			.saturating_add(Weight::from_parts(1_411_802, 0).saturating_mul(n.into()))
-			// Standard Error: 13_993
-			.saturating_add(Weight::from_parts(11_432_937, 0).saturating_mul(c.into()))
-			.saturating_add(T::DbWeight::get().reads(5))
-			.saturating_add(T::DbWeight::get().writes(5))
-			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
-	}
-	/// Storage: Did DidBlacklist (r:1 w:0)
-	/// Proof: Did DidBlacklist (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
-	/// Storage: Did Did (r:1 w:1)
-	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	/// Storage: System Account (r:2 w:2)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	/// Storage: Balances Holds (r:1 w:1)
-	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
-	/// Storage: Did DidEndpointsCount (r:0 w:1)
-	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
-	/// Storage: Did ServiceEndpoints (r:0 w:25)
-	/// Proof: Did ServiceEndpoints (max_values: None, max_size: Some(615), added: 3090, mode: MaxEncodedLen)
-	/// The range of component `n` is `[1, 10]`.
-	/// The range of component `c` is `[1, 25]`.
-	fn create_ecdsa_keys(n: u32, c: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1131`
-		//  Estimated: `6204`
-		// Minimum execution time: 164_997_000 picoseconds.
-		Weight::from_parts(143_724_779, 0)
-			.saturating_add(Weight::from_parts(0, 6204))
-			// Standard Error: 46_012
-			.saturating_add(Weight::from_parts(1_689_629, 0).saturating_mul(n.into()))
-			// Standard Error: 17_790
-			.saturating_add(Weight::from_parts(9_993_575, 0).saturating_mul(c.into()))
-			.saturating_add(T::DbWeight::get().reads(5))
-			.saturating_add(T::DbWeight::get().writes(5))
-			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
-	}
-	/// Storage: Did DidEndpointsCount (r:1 w:1)
-	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
-	/// Storage: Did ServiceEndpoints (r:25 w:25)
-	/// Proof: Did ServiceEndpoints (max_values: None, max_size: Some(615), added: 3090, mode: MaxEncodedLen)
-	/// Storage: Did Did (r:1 w:1)
-	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	/// Storage: Balances Holds (r:1 w:1)
-	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	/// Storage: Did DidBlacklist (r:0 w:1)
-	/// Proof: Did DidBlacklist (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
-	/// The range of component `c` is `[1, 25]`.
-	fn delete(c: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `969 + c * (105 ±0)`
-		//  Estimated: `5777 + c * (3090 ±0)`
-		// Minimum execution time: 53_190_000 picoseconds.
-		Weight::from_parts(54_199_033, 0)
-			.saturating_add(Weight::from_parts(0, 5777))
-			// Standard Error: 4_988
-			.saturating_add(Weight::from_parts(1_376_408, 0).saturating_mul(c.into()))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(c.into())))
-			.saturating_add(T::DbWeight::get().writes(5))
-			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
-			.saturating_add(Weight::from_parts(0, 3090).saturating_mul(c.into()))
-	}
-	/// Storage: Did Did (r:1 w:1)
-	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	/// Storage: Did DidEndpointsCount (r:1 w:1)
-	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
-	/// Storage: Did ServiceEndpoints (r:25 w:25)
-	/// Proof: Did ServiceEndpoints (max_values: None, max_size: Some(615), added: 3090, mode: MaxEncodedLen)
-	/// Storage: Balances Holds (r:1 w:1)
-	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	/// Storage: Did DidBlacklist (r:0 w:1)
-	/// Proof: Did DidBlacklist (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
-	/// The range of component `c` is `[1, 25]`.
-	fn reclaim_deposit(c: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `969 + c * (105 ±0)`
-		//  Estimated: `5777 + c * (3090 ±0)`
-		// Minimum execution time: 55_729_000 picoseconds.
-		Weight::from_parts(56_557_707, 0)
-			.saturating_add(Weight::from_parts(0, 5777))
-			// Standard Error: 4_643
-			.saturating_add(Weight::from_parts(1_397_138, 0).saturating_mul(c.into()))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(c.into())))
-			.saturating_add(T::DbWeight::get().writes(5))
-			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
-			.saturating_add(Weight::from_parts(0, 3090).saturating_mul(c.into()))
-	}
 	/// Storage: Did Did (r:1 w:1)
 	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
 	fn submit_did_call_ed25519_key() -> Weight {
@@ -228,531 +85,184 @@ impl<T: frame_system::Config> did::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
-	/// Storage: Did Did (r:1 w:1)
+	/// Storage: Did Did (r:1 w:0)
 	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	/// Storage: Did DidEndpointsCount (r:1 w:0)
-	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	/// Storage: Balances Holds (r:1 w:1)
-	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
-	fn set_ed25519_authentication_key() -> Weight {
+	/// The range of component `l` is `[1, 5242880]`.
+	fn signature_verification_sr25519(l: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `2170`
+		//  Measured:  `1626`
 		//  Estimated: `5777`
-		// Minimum execution time: 67_708_000 picoseconds.
-		Weight::from_parts(70_147_000, 0)
+		// Minimum execution time: 63_042_000 picoseconds.
+		Weight::from_parts(41_623_028, 0)
 			.saturating_add(Weight::from_parts(0, 5777))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(3))
+			// Standard Error: 0
+			.saturating_add(Weight::from_parts(4_301, 0).saturating_mul(l.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
 	}
-	/// Storage: Did Did (r:1 w:1)
+	/// Storage: Did Did (r:1 w:0)
 	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	/// Storage: Did DidEndpointsCount (r:1 w:0)
-	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	/// Storage: Balances Holds (r:1 w:1)
-	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
-	fn set_sr25519_authentication_key() -> Weight {
+	/// The range of component `l` is `[1, 5242880]`.
+	fn signature_verification_ed25519(l: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `2170`
+		//  Measured:  `1626`
 		//  Estimated: `5777`
-		// Minimum execution time: 68_784_000 picoseconds.
-		Weight::from_parts(70_860_000, 0)
+		// Minimum execution time: 61_831_000 picoseconds.
+		Weight::from_parts(38_865_643, 0)
 			.saturating_add(Weight::from_parts(0, 5777))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(3))
+			// Standard Error: 0
+			.saturating_add(Weight::from_parts(2_522, 0).saturating_mul(l.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
 	}
-	/// Storage: Did Did (r:1 w:1)
+	/// Storage: Did Did (r:1 w:0)
 	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	/// Storage: Did DidEndpointsCount (r:1 w:0)
-	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	/// Storage: Balances Holds (r:1 w:1)
-	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
-	fn set_ecdsa_authentication_key() -> Weight {
+	/// The range of component `l` is `[1, 5242880]`.
+	fn signature_verification_ecdsa(l: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `2107`
+		//  Measured:  `1627`
 		//  Estimated: `5777`
-		// Minimum execution time: 68_261_000 picoseconds.
-		Weight::from_parts(69_565_000, 0)
+		// Minimum execution time: 50_295_000 picoseconds.
+		Weight::from_parts(27_300_442, 0)
 			.saturating_add(Weight::from_parts(0, 5777))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(3))
+			// Standard Error: 0
+			.saturating_add(Weight::from_parts(1_153, 0).saturating_mul(l.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
 	}
-	/// Storage: Did Did (r:1 w:1)
+
+	/// Storage: Did Did (r:1 w:0)
 	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	/// Storage: Did DidEndpointsCount (r:1 w:0)
-	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	/// Storage: Balances Holds (r:1 w:1)
-	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
-	fn set_ed25519_delegation_key() -> Weight {
+	fn dispatch_as() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `2038`
+		//  Measured:  `353`
 		//  Estimated: `5777`
-		// Minimum execution time: 67_556_000 picoseconds.
-		Weight::from_parts(69_662_000, 0)
+		// Minimum execution time: 186_836_000 picoseconds.
+		Weight::from_parts(189_377_000, 0)
 			.saturating_add(Weight::from_parts(0, 5777))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(T::DbWeight::get().reads(1))
+	}
+	// The calls below are not covered by the benchmark run above and use the
+	// estimated weights of the pallet.
+	fn create_ed25519_keys(n: u32, c: u32, ) -> Weight {
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::create_ed25519_keys(n, c)
+	}
+	fn create_sr25519_keys(n: u32, c: u32, ) -> Weight {
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::create_sr25519_keys(n, c)
+	}
+	fn create_ecdsa_keys(n: u32, c: u32, ) -> Weight {
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::create_ecdsa_keys(n, c)
+	}
+	fn delete(c: u32, ) -> Weight {
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::delete(c)
+	}
+	fn reclaim_deposit(c: u32, ) -> Weight {
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::reclaim_deposit(c)
+	}
+	fn set_ed25519_authentication_key() -> Weight {
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::set_ed25519_authentication_key()
+	}
+	fn set_sr25519_authentication_key() -> Weight {
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::set_sr25519_authentication_key()
+	}
+	fn set_ecdsa_authentication_key() -> Weight {
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::set_ecdsa_authentication_key()
+	}
+	fn set_multisig_authentication_key(s: u32, ) -> Weight {
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::set_multisig_authentication_key(s)
+	}
+	fn set_ed25519_delegation_key() -> Weight {
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::set_ed25519_delegation_key()
 	}
-	/// Storage: Did Did (r:1 w:1)
-	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	/// Storage: Did DidEndpointsCount (r:1 w:0)
-	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	/// Storage: Balances Holds (r:1 w:1)
-	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
 	fn set_sr25519_delegation_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2165`
-		//  Estimated: `5777`
-		// Minimum execution time: 67_837_000 picoseconds.
-		Weight::from_parts(69_180_000, 0)
-			.saturating_add(Weight::from_parts(0, 5777))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(3))
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::set_sr25519_delegation_key()
 	}
-	/// Storage: Did Did (r:1 w:1)
-	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	/// Storage: Did DidEndpointsCount (r:1 w:0)
-	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	/// Storage: Balances Holds (r:1 w:1)
-	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
 	fn set_ecdsa_delegation_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2173`
-		//  Estimated: `5777`
-		// Minimum execution time: 68_540_000 picoseconds.
-		Weight::from_parts(69_956_000, 0)
-			.saturating_add(Weight::from_parts(0, 5777))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(3))
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::set_ecdsa_delegation_key()
 	}
-	/// Storage: Did Did (r:1 w:1)
-	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	/// Storage: Did DidEndpointsCount (r:1 w:0)
-	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	/// Storage: Balances Holds (r:1 w:1)
-	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
 	fn remove_ed25519_delegation_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2104`
-		//  Estimated: `5777`
-		// Minimum execution time: 65_127_000 picoseconds.
-		Weight::from_parts(66_929_000, 0)
-			.saturating_add(Weight::from_parts(0, 5777))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(3))
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::remove_ed25519_delegation_key()
 	}
-	/// Storage: Did Did (r:1 w:1)
-	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	/// Storage: Did DidEndpointsCount (r:1 w:0)
-	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	/// Storage: Balances Holds (r:1 w:1)
-	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
 	fn remove_sr25519_delegation_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2104`
-		//  Estimated: `5777`
-		// Minimum execution time: 64_797_000 picoseconds.
-		Weight::from_parts(65_517_000, 0)
-			.saturating_add(Weight::from_parts(0, 5777))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(3))
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::remove_sr25519_delegation_key()
 	}
-	/// Storage: Did Did (r:1 w:1)
-	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	/// Storage: Did DidEndpointsCount (r:1 w:0)
-	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	/// Storage: Balances Holds (r:1 w:1)
-	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
 	fn remove_ecdsa_delegation_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2173`
-		//  Estimated: `5777`
-		// Minimum execution time: 65_807_000 picoseconds.
-		Weight::from_parts(67_122_000, 0)
-			.saturating_add(Weight::from_parts(0, 5777))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(3))
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::remove_ecdsa_delegation_key()
 	}
-	/// Storage: Did Did (r:1 w:1)
-	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	/// Storage: Did DidEndpointsCount (r:1 w:0)
-	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	/// Storage: Balances Holds (r:1 w:1)
-	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
 	fn set_ed25519_attestation_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2105`
-		//  Estimated: `5777`
-		// Minimum execution time: 68_688_000 picoseconds.
-		Weight::from_parts(69_835_000, 0)
-			.saturating_add(Weight::from_parts(0, 5777))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(3))
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::set_ed25519_attestation_key()
 	}
-	/// Storage: Did Did (r:1 w:1)
-	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	/// Storage: Did DidEndpointsCount (r:1 w:0)
-	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	/// Storage: Balances Holds (r:1 w:1)
-	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
 	fn set_sr25519_attestation_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2137`
-		//  Estimated: `5777`
-		// Minimum execution time: 67_241_000 picoseconds.
-		Weight::from_parts(68_767_000, 0)
-			.saturating_add(Weight::from_parts(0, 5777))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(3))
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::set_sr25519_attestation_key()
 	}
-	/// Storage: Did Did (r:1 w:1)
-	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	/// Storage: Did DidEndpointsCount (r:1 w:0)
-	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	/// Storage: Balances Holds (r:1 w:1)
-	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
 	fn set_ecdsa_attestation_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2177`
-		//  Estimated: `5777`
-		// Minimum execution time: 66_623_000 picoseconds.
-		Weight::from_parts(68_381_000, 0)
-			.saturating_add(Weight::from_parts(0, 5777))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(3))
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::set_ecdsa_attestation_key()
 	}
-	/// Storage: Did Did (r:1 w:1)
-	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	/// Storage: Did DidEndpointsCount (r:1 w:0)
-	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	/// Storage: Balances Holds (r:1 w:1)
-	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
 	fn remove_ed25519_attestation_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2137`
-		//  Estimated: `5777`
-		// Minimum execution time: 64_750_000 picoseconds.
-		Weight::from_parts(67_215_000, 0)
-			.saturating_add(Weight::from_parts(0, 5777))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(3))
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::remove_ed25519_attestation_key()
 	}
-	/// Storage: Did Did (r:1 w:1)
-	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	/// Storage: Did DidEndpointsCount (r:1 w:0)
-	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	/// Storage: Balances Holds (r:1 w:1)
-	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
 	fn remove_sr25519_attestation_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2132`
-		//  Estimated: `5777`
-		// Minimum execution time: 65_858_000 picoseconds.
-		Weight::from_parts(67_393_000, 0)
-			.saturating_add(Weight::from_parts(0, 5777))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(3))
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::remove_sr25519_attestation_key()
 	}
-	/// Storage: Did Did (r:1 w:1)
-	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	/// Storage: Did DidEndpointsCount (r:1 w:0)
-	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	/// Storage: Balances Holds (r:1 w:1)
-	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
 	fn remove_ecdsa_attestation_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2112`
-		//  Estimated: `5777`
-		// Minimum execution time: 64_552_000 picoseconds.
-		Weight::from_parts(66_076_000, 0)
-			.saturating_add(Weight::from_parts(0, 5777))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(3))
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::remove_ecdsa_attestation_key()
 	}
-	/// Storage: Did Did (r:1 w:1)
-	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	/// Storage: Did DidEndpointsCount (r:1 w:0)
-	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	/// Storage: Balances Holds (r:1 w:1)
-	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
 	fn add_ed25519_key_agreement_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2026`
-		//  Estimated: `5777`
-		// Minimum execution time: 67_691_000 picoseconds.
-		Weight::from_parts(69_179_000, 0)
-			.saturating_add(Weight::from_parts(0, 5777))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(3))
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::add_ed25519_key_agreement_key()
 	}
-	/// Storage: Did Did (r:1 w:1)
-	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	/// Storage: Did DidEndpointsCount (r:1 w:0)
-	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	/// Storage: Balances Holds (r:1 w:1)
-	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
 	fn add_sr25519_key_agreement_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1966`
-		//  Estimated: `5777`
-		// Minimum execution time: 67_454_000 picoseconds.
-		Weight::from_parts(69_136_000, 0)
-			.saturating_add(Weight::from_parts(0, 5777))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(3))
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::add_sr25519_key_agreement_key()
 	}
-	/// Storage: Did Did (r:1 w:1)
-	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	/// Storage: Did DidEndpointsCount (r:1 w:0)
-	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	/// Storage: Balances Holds (r:1 w:1)
-	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
 	fn add_ecdsa_key_agreement_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2062`
-		//  Estimated: `5777`
-		// Minimum execution time: 66_065_000 picoseconds.
-		Weight::from_parts(68_090_000, 0)
-			.saturating_add(Weight::from_parts(0, 5777))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(3))
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::add_ecdsa_key_agreement_key()
 	}
-	/// Storage: Did Did (r:1 w:1)
-	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	/// Storage: Did DidEndpointsCount (r:1 w:0)
-	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	/// Storage: Balances Holds (r:1 w:1)
-	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
 	fn remove_ed25519_key_agreement_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2104`
-		//  Estimated: `5777`
-		// Minimum execution time: 64_706_000 picoseconds.
-		Weight::from_parts(67_009_000, 0)
-			.saturating_add(Weight::from_parts(0, 5777))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(3))
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::remove_ed25519_key_agreement_key()
 	}
-	/// Storage: Did Did (r:1 w:1)
-	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	/// Storage: Did DidEndpointsCount (r:1 w:0)
-	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	/// Storage: Balances Holds (r:1 w:1)
-	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
 	fn remove_sr25519_key_agreement_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2170`
-		//  Estimated: `5777`
-		// Minimum execution time: 65_290_000 picoseconds.
-		Weight::from_parts(67_110_000, 0)
-			.saturating_add(Weight::from_parts(0, 5777))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(3))
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::remove_sr25519_key_agreement_key()
 	}
-	/// Storage: Did Did (r:1 w:1)
-	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	/// Storage: Did DidEndpointsCount (r:1 w:0)
-	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	/// Storage: Balances Holds (r:1 w:1)
-	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
 	fn remove_ecdsa_key_agreement_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2107`
-		//  Estimated: `5777`
-		// Minimum execution time: 65_011_000 picoseconds.
-		Weight::from_parts(67_020_000, 0)
-			.saturating_add(Weight::from_parts(0, 5777))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(3))
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::remove_ecdsa_key_agreement_key()
 	}
-	/// Storage: Did Did (r:1 w:1)
-	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	/// Storage: Did DidEndpointsCount (r:1 w:1)
-	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
-	/// Storage: Did ServiceEndpoints (r:1 w:1)
-	/// Proof: Did ServiceEndpoints (max_values: None, max_size: Some(615), added: 3090, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	/// Storage: Balances Holds (r:1 w:1)
-	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
 	fn add_service_endpoint() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1504`
-		//  Estimated: `5777`
-		// Minimum execution time: 75_496_000 picoseconds.
-		Weight::from_parts(76_809_000, 0)
-			.saturating_add(Weight::from_parts(0, 5777))
-			.saturating_add(T::DbWeight::get().reads(5))
-			.saturating_add(T::DbWeight::get().writes(5))
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::add_service_endpoint()
 	}
-	/// Storage: Did Did (r:1 w:1)
-	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	/// Storage: Did ServiceEndpoints (r:1 w:1)
-	/// Proof: Did ServiceEndpoints (max_values: None, max_size: Some(615), added: 3090, mode: MaxEncodedLen)
-	/// Storage: Did DidEndpointsCount (r:1 w:1)
-	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	/// Storage: Balances Holds (r:1 w:1)
-	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
 	fn remove_service_endpoint() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2154`
-		//  Estimated: `5777`
-		// Minimum execution time: 74_014_000 picoseconds.
-		Weight::from_parts(75_355_000, 0)
-			.saturating_add(Weight::from_parts(0, 5777))
-			.saturating_add(T::DbWeight::get().reads(5))
-			.saturating_add(T::DbWeight::get().writes(5))
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::remove_service_endpoint()
 	}
-	/// Storage: Did Did (r:1 w:0)
-	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	/// The range of component `l` is `[1, 5242880]`.
-	fn signature_verification_sr25519(l: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1626`
-		//  Estimated: `5777`
-		// Minimum execution time: 63_042_000 picoseconds.
-		Weight::from_parts(41_623_028, 0)
-			.saturating_add(Weight::from_parts(0, 5777))
-			// Standard Error: 0
-			.saturating_add(Weight::from_parts(4_301, 0).saturating_mul(l.into()))
-			.saturating_add(T::DbWeight::get().reads(1))
+	fn change_deposit_owner() -> Weight {
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::change_deposit_owner()
 	}
-	/// Storage: Did Did (r:1 w:0)
-	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	/// The range of component `l` is `[1, 5242880]`.
-	fn signature_verification_ed25519(l: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1626`
-		//  Estimated: `5777`
-		// Minimum execution time: 61_831_000 picoseconds.
-		Weight::from_parts(38_865_643, 0)
-			.saturating_add(Weight::from_parts(0, 5777))
-			// Standard Error: 0
-			.saturating_add(Weight::from_parts(2_522, 0).saturating_mul(l.into()))
-			.saturating_add(T::DbWeight::get().reads(1))
+	fn update_deposit() -> Weight {
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::update_deposit()
 	}
-	/// Storage: Did Did (r:1 w:0)
-	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	/// The range of component `l` is `[1, 5242880]`.
-	fn signature_verification_ecdsa(l: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1627`
-		//  Estimated: `5777`
-		// Minimum execution time: 50_295_000 picoseconds.
-		Weight::from_parts(27_300_442, 0)
-			.saturating_add(Weight::from_parts(0, 5777))
-			// Standard Error: 0
-			.saturating_add(Weight::from_parts(1_153, 0).saturating_mul(l.into()))
-			.saturating_add(T::DbWeight::get().reads(1))
+	fn create_from_account() -> Weight {
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::create_from_account()
 	}
-	/// Storage: Did Did (r:1 w:1)
-	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	/// Storage: Balances Holds (r:1 w:1)
-	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
-	fn change_deposit_owner() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `822`
-		//  Estimated: `5777`
-		// Minimum execution time: 73_863_000 picoseconds.
-		Weight::from_parts(76_051_000, 0)
-			.saturating_add(Weight::from_parts(0, 5777))
-			.saturating_add(T::DbWeight::get().reads(3))
-			.saturating_add(T::DbWeight::get().writes(3))
+	fn add_hashed_service_endpoint() -> Weight {
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::add_hashed_service_endpoint()
 	}
-	/// Storage: Did Did (r:1 w:1)
-	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	/// Storage: Did DidEndpointsCount (r:1 w:0)
-	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	/// Storage: Balances Holds (r:1 w:1)
-	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
-	fn update_deposit() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `888`
-		//  Estimated: `5777`
-		// Minimum execution time: 45_375_000 picoseconds.
-		Weight::from_parts(46_464_000, 0)
-			.saturating_add(Weight::from_parts(0, 5777))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(3))
+	fn did_remark(l: u32, ) -> Weight {
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::did_remark(l)
 	}
-
-	/// Storage: Did Did (r:1 w:0)
-	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	fn dispatch_as() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `353`
-		//  Estimated: `5777`
-		// Minimum execution time: 186_836_000 picoseconds.
-		Weight::from_parts(189_377_000, 0)
-			.saturating_add(Weight::from_parts(0, 5777))
-			.saturating_add(T::DbWeight::get().reads(1))
+	fn endorse() -> Weight {
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::endorse()
 	}
-	/// Storage: Did DidBlacklist (r:1 w:0)
-	/// Proof: Did DidBlacklist (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
-	/// Storage: Did Did (r:1 w:1)
-	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	/// Storage: Did DidEndpointsCount (r:1 w:0)
-	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
-	/// Storage: System Account (r:2 w:2)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	/// Storage: Balances Holds (r:1 w:1)
-	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
-	fn create_from_account() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `999`
-		//  Estimated: `6204`
-		// Minimum execution time: 1_008_739_000 picoseconds.
-		Weight::from_parts(1_020_810_000, 0)
-			.saturating_add(Weight::from_parts(0, 6204))
-			.saturating_add(T::DbWeight::get().reads(6))
-			.saturating_add(T::DbWeight::get().writes(4))
+	fn revoke_endorsement() -> Weight {
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::revoke_endorsement()
+	}
+	fn add_also_known_as() -> Weight {
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::add_also_known_as()
+	}
+	fn remove_also_known_as() -> Weight {
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::remove_also_known_as()
+	}
+	fn backfill_key_history_step(n: u32, ) -> Weight {
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::backfill_key_history_step(n)
+	}
+	fn reprice_did_deposit_step() -> Weight {
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::reprice_did_deposit_step()
+	}
+	fn initialize_did_counter_step() -> Weight {
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::initialize_did_counter_step()
 	}
 }
 
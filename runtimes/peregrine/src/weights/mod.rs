@@ -37,7 +37,6 @@ pub mod pallet_preimage;
 pub mod pallet_proxy;
 pub mod pallet_scheduler;
 pub mod pallet_session;
-pub mod pallet_sudo;
 pub mod pallet_timestamp;
 pub mod pallet_tips;
 pub mod pallet_treasury;
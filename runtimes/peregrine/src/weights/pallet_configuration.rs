@@ -61,6 +61,20 @@ impl<T: frame_system::Config> pallet_configuration::WeightInfo for WeightInfo<T>
 			.saturating_add(Weight::from_parts(0, 0))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	// The calls below are not covered by the benchmark run above and use the
+	// estimated weights of the pallet.
+	fn pause_xcm_identity_ops() -> Weight {
+		<pallet_configuration::default_weights::SubstrateWeight<T> as pallet_configuration::WeightInfo>::pause_xcm_identity_ops()
+	}
+	fn resume_xcm_identity_ops() -> Weight {
+		<pallet_configuration::default_weights::SubstrateWeight<T> as pallet_configuration::WeightInfo>::resume_xcm_identity_ops()
+	}
+	fn pause_call() -> Weight {
+		<pallet_configuration::default_weights::SubstrateWeight<T> as pallet_configuration::WeightInfo>::pause_call()
+	}
+	fn unpause_call() -> Weight {
+		<pallet_configuration::default_weights::SubstrateWeight<T> as pallet_configuration::WeightInfo>::unpause_call()
+	}
 }
 
 #[cfg(test)]
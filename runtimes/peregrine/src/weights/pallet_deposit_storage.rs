@@ -67,6 +67,11 @@ impl<T: frame_system::Config> pallet_deposit_storage::WeightInfo for WeightInfo<
 			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(4))
 	}
+	// The calls below are not covered by the benchmark run above and use the
+	// estimated weights of the pallet.
+	fn set_deposit_parameter() -> Weight {
+		<pallet_deposit_storage::default_weights::SubstrateWeight<T> as pallet_deposit_storage::WeightInfo>::set_deposit_parameter()
+	}
 }
 
 #[cfg(test)]
@@ -52,145 +52,55 @@ use sp_std::marker::PhantomData;
 /// Weight functions for `pallet_did_lookup`.
 pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> pallet_did_lookup::WeightInfo for WeightInfo<T> {
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	/// Storage: DidLookup ConnectedDids (r:1 w:1)
-	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
-	/// Storage: DidLookup ConnectedAccounts (r:0 w:2)
-	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	// The calls below are not covered by the benchmark run above and use the
+	// estimated weights of the pallet.
 	fn associate_account_multisig_sr25519() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `449`
-		//  Estimated: `5211`
-		// Minimum execution time: 70_942_000 picoseconds.
-		Weight::from_parts(70_942_000, 0)
-			.saturating_add(Weight::from_parts(0, 5211))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(4))
+		<pallet_did_lookup::default_weights::SubstrateWeight<T> as pallet_did_lookup::WeightInfo>::associate_account_multisig_sr25519()
 	}
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	/// Storage: DidLookup ConnectedDids (r:1 w:1)
-	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
-	/// Storage: DidLookup ConnectedAccounts (r:0 w:2)
-	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
 	fn associate_account_multisig_ed25519() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `449`
-		//  Estimated: `5211`
-		// Minimum execution time: 66_569_000 picoseconds.
-		Weight::from_parts(66_569_000, 0)
-			.saturating_add(Weight::from_parts(0, 5211))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(4))
+		<pallet_did_lookup::default_weights::SubstrateWeight<T> as pallet_did_lookup::WeightInfo>::associate_account_multisig_ed25519()
 	}
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	/// Storage: DidLookup ConnectedDids (r:1 w:1)
-	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
-	/// Storage: DidLookup ConnectedAccounts (r:0 w:2)
-	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
 	fn associate_account_multisig_ecdsa() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `449`
-		//  Estimated: `5211`
-		// Minimum execution time: 71_977_000 picoseconds.
-		Weight::from_parts(71_977_000, 0)
-			.saturating_add(Weight::from_parts(0, 5211))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(4))
+		<pallet_did_lookup::default_weights::SubstrateWeight<T> as pallet_did_lookup::WeightInfo>::associate_account_multisig_ecdsa()
 	}
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	/// Storage: DidLookup ConnectedDids (r:1 w:1)
-	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
-	/// Storage: DidLookup ConnectedAccounts (r:0 w:2)
-	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
 	fn associate_eth_account() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `437`
-		//  Estimated: `5211`
-		// Minimum execution time: 68_618_000 picoseconds.
-		Weight::from_parts(68_618_000, 0)
-			.saturating_add(Weight::from_parts(0, 5211))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(4))
+		<pallet_did_lookup::default_weights::SubstrateWeight<T> as pallet_did_lookup::WeightInfo>::associate_eth_account()
+	}
+	fn associate_eth_contract_account() -> Weight {
+		<pallet_did_lookup::default_weights::SubstrateWeight<T> as pallet_did_lookup::WeightInfo>::associate_eth_contract_account()
+	}
+	fn associate_solana_account() -> Weight {
+		<pallet_did_lookup::default_weights::SubstrateWeight<T> as pallet_did_lookup::WeightInfo>::associate_solana_account()
+	}
+	fn associate_bitcoin_account() -> Weight {
+		<pallet_did_lookup::default_weights::SubstrateWeight<T> as pallet_did_lookup::WeightInfo>::associate_bitcoin_account()
 	}
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	/// Storage: DidLookup ConnectedDids (r:1 w:1)
-	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
-	/// Storage: DidLookup ConnectedAccounts (r:0 w:2)
-	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
 	fn associate_sender() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `449`
-		//  Estimated: `5211`
-		// Minimum execution time: 33_964_000 picoseconds.
-		Weight::from_parts(33_964_000, 0)
-			.saturating_add(Weight::from_parts(0, 5211))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(4))
+		<pallet_did_lookup::default_weights::SubstrateWeight<T> as pallet_did_lookup::WeightInfo>::associate_sender()
 	}
-	/// Storage: DidLookup ConnectedDids (r:1 w:1)
-	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	/// Storage: DidLookup ConnectedAccounts (r:0 w:1)
-	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
 	fn remove_sender_association() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `449`
-		//  Estimated: `5211`
-		// Minimum execution time: 20_715_000 picoseconds.
-		Weight::from_parts(20_715_000, 0)
-			.saturating_add(Weight::from_parts(0, 5211))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(3))
+		<pallet_did_lookup::default_weights::SubstrateWeight<T> as pallet_did_lookup::WeightInfo>::remove_sender_association()
 	}
-	/// Storage: DidLookup ConnectedDids (r:1 w:1)
-	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	/// Storage: DidLookup ConnectedAccounts (r:0 w:1)
-	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
 	fn remove_account_association() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `449`
-		//  Estimated: `5211`
-		// Minimum execution time: 19_749_000 picoseconds.
-		Weight::from_parts(19_749_000, 0)
-			.saturating_add(Weight::from_parts(0, 5211))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(3))
+		<pallet_did_lookup::default_weights::SubstrateWeight<T> as pallet_did_lookup::WeightInfo>::remove_account_association()
 	}
-	/// Storage: DidLookup ConnectedDids (r:1 w:1)
-	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
-	/// Storage: System Account (r:2 w:2)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	fn change_deposit_owner() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `862`
-		//  Estimated: `7818`
-		// Minimum execution time: 27_037_000 picoseconds.
-		Weight::from_parts(27_037_000, 0)
-			.saturating_add(Weight::from_parts(0, 7818))
-			.saturating_add(T::DbWeight::get().reads(3))
-			.saturating_add(T::DbWeight::get().writes(3))
+		<pallet_did_lookup::default_weights::SubstrateWeight<T> as pallet_did_lookup::WeightInfo>::change_deposit_owner()
 	}
-	/// Storage: DidLookup ConnectedDids (r:1 w:1)
-	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	fn update_deposit() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `449`
-		//  Estimated: `5211`
-		// Minimum execution time: 23_615_000 picoseconds.
-		Weight::from_parts(23_615_000, 0)
-			.saturating_add(Weight::from_parts(0, 5211))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(2))
+		<pallet_did_lookup::default_weights::SubstrateWeight<T> as pallet_did_lookup::WeightInfo>::update_deposit()
+	}
+	fn remove_all_account_associations(n: u32, ) -> Weight {
+		<pallet_did_lookup::default_weights::SubstrateWeight<T> as pallet_did_lookup::WeightInfo>::remove_all_account_associations(n)
+	}
+	fn migrate_association() -> Weight {
+		<pallet_did_lookup::default_weights::SubstrateWeight<T> as pallet_did_lookup::WeightInfo>::migrate_association()
+	}
+	fn set_account_label() -> Weight {
+		<pallet_did_lookup::default_weights::SubstrateWeight<T> as pallet_did_lookup::WeightInfo>::set_account_label()
+	}
+	fn initialize_connected_dids_counter_step() -> Weight {
+		<pallet_did_lookup::default_weights::SubstrateWeight<T> as pallet_did_lookup::WeightInfo>::initialize_connected_dids_counter_step()
 	}
 }
 
@@ -1,126 +0,0 @@
-// KILT Blockchain – https://botlabs.org
-// Copyright (C) 2019-2024 BOTLabs GmbH
-
-// The KILT Blockchain is free software: you can redistribute it and/or modify
-// it under the terms of the GNU General Public License as published by
-// the Free Software Foundation, either version 3 of the License, or
-// (at your option) any later version.
-
-// The KILT Blockchain is distributed in the hope that it will be useful,
-// but WITHOUT ANY WARRANTY; without even the implied warranty of
-// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
-// GNU General Public License for more details.
-
-// You should have received a copy of the GNU General Public License
-// along with this program.  If not, see <https://www.gnu.org/licenses/>.
-
-// If you feel like getting in touch with us, you can do so at info@botlabs.org
-
-//! Autogenerated weights for `pallet_sudo`
-//!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
-//! DATE: 2023-07-24, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `rust-2`, CPU: `12th Gen Intel(R) Core(TM) i9-12900K`
-//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
-
-// Executed Command:
-// ./target/release/kilt-parachain
-// benchmark
-// pallet
-// --chain=dev
-// --steps=50
-// --repeat=20
-// --pallet=pallet-sudo
-// --extrinsic=*
-// --execution=wasm
-// --wasm-execution=compiled
-// --heap-pages=4096
-// --output=./runtimes/peregrine/src/weights/pallet_sudo.rs
-// --template=.maintain/runtime-weight-template.hbs
-
-#![cfg_attr(rustfmt, rustfmt_skip)]
-#![allow(unused_parens)]
-#![allow(unused_imports)]
-
-use frame_support::{traits::Get, weights::Weight};
-use sp_std::marker::PhantomData;
-
-/// Weight functions for `pallet_sudo`.
-pub struct WeightInfo<T>(PhantomData<T>);
-impl<T: frame_system::Config> pallet_sudo::WeightInfo for WeightInfo<T> {
-	/// Storage: Sudo Key (r:1 w:1)
-	/// Proof: Sudo Key (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
-	fn set_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `165`
-		//  Estimated: `1517`
-		// Minimum execution time: 7_373_000 picoseconds.
-		Weight::from_parts(7_755_000, 0)
-			.saturating_add(Weight::from_parts(0, 1517))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
-	}
-	/// Storage: Sudo Key (r:1 w:0)
-	/// Proof: Sudo Key (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
-	fn sudo() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `165`
-		//  Estimated: `1517`
-		// Minimum execution time: 7_471_000 picoseconds.
-		Weight::from_parts(7_735_000, 0)
-			.saturating_add(Weight::from_parts(0, 1517))
-			.saturating_add(T::DbWeight::get().reads(1))
-	}
-	/// Storage: Sudo Key (r:1 w:0)
-	/// Proof: Sudo Key (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
-	fn sudo_as() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `165`
-		//  Estimated: `1517`
-		// Minimum execution time: 7_488_000 picoseconds.
-		Weight::from_parts(7_950_000, 0)
-			.saturating_add(Weight::from_parts(0, 1517))
-			.saturating_add(T::DbWeight::get().reads(1))
-	}
-}
-
-#[cfg(test)]
-mod tests {
-	#[test]
-	fn test_set_key() {
-		assert!(
-			<crate::Runtime as frame_system::Config>::BlockWeights::get()
-				.per_class
-				.get(frame_support::dispatch::DispatchClass::Normal)
-				.max_extrinsic
-				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
-				.proof_size()
-				> 1517
-		);
-	}
-	#[test]
-	fn test_sudo() {
-		assert!(
-			<crate::Runtime as frame_system::Config>::BlockWeights::get()
-				.per_class
-				.get(frame_support::dispatch::DispatchClass::Normal)
-				.max_extrinsic
-				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
-				.proof_size()
-				> 1517
-		);
-	}
-	#[test]
-	fn test_sudo_as() {
-		assert!(
-			<crate::Runtime as frame_system::Config>::BlockWeights::get()
-				.per_class
-				.get(frame_support::dispatch::DispatchClass::Normal)
-				.max_extrinsic
-				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
-				.proof_size()
-				> 1517
-		);
-	}
-}
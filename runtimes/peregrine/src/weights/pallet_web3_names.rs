@@ -52,25 +52,6 @@ use sp_std::marker::PhantomData;
 /// Weight functions for `pallet_web3_names`.
 pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> pallet_web3_names::WeightInfo for WeightInfo<T> {
-	/// Storage: Web3Names Names (r:1 w:1)
-	/// Proof: Web3Names Names (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
-	/// Storage: Web3Names Owner (r:1 w:1)
-	/// Proof: Web3Names Owner (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
-	/// Storage: Web3Names Banned (r:1 w:0)
-	/// Proof: Web3Names Banned (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	/// The range of component `n` is `[3, 32]`.
-	fn claim(_n: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `144`
-		//  Estimated: `10299`
-		// Minimum execution time: 48_474_000 picoseconds.
-		Weight::from_parts(62_936_000, 0)
-			.saturating_add(Weight::from_parts(0, 10299))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(3))
-	}
 	/// Storage: Web3Names Names (r:1 w:1)
 	/// Proof: Web3Names Names (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
 	/// Storage: Web3Names Owner (r:1 w:1)
@@ -106,25 +87,6 @@ impl<T: frame_system::Config> pallet_web3_names::WeightInfo for WeightInfo<T> {
 	}
 	/// Storage: Web3Names Banned (r:1 w:1)
 	/// Proof: Web3Names Banned (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
-	/// Storage: Web3Names Owner (r:1 w:1)
-	/// Proof: Web3Names Owner (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	/// Storage: Web3Names Names (r:0 w:1)
-	/// Proof: Web3Names Names (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
-	/// The range of component `n` is `[3, 32]`.
-	fn ban(_n: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `342 + n * (1 ±0)`
-		//  Estimated: `7743`
-		// Minimum execution time: 22_053_000 picoseconds.
-		Weight::from_parts(22_256_000, 0)
-			.saturating_add(Weight::from_parts(0, 7743))
-			.saturating_add(T::DbWeight::get().reads(3))
-			.saturating_add(T::DbWeight::get().writes(4))
-	}
-	/// Storage: Web3Names Banned (r:1 w:1)
-	/// Proof: Web3Names Banned (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
 	/// The range of component `n` is `[3, 32]`.
 	fn unban(_n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -136,35 +98,58 @@ impl<T: frame_system::Config> pallet_web3_names::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
-	/// Storage: Web3Names Names (r:1 w:0)
-	/// Proof: Web3Names Names (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
-	/// Storage: Web3Names Owner (r:1 w:1)
-	/// Proof: Web3Names Owner (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
-	/// Storage: System Account (r:2 w:2)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	// The calls below are not covered by the benchmark run above and use the
+	// estimated weights of the pallet.
+	fn claim(n: u32, ) -> Weight {
+		<pallet_web3_names::default_weights::SubstrateWeight<T> as pallet_web3_names::WeightInfo>::claim(n)
+	}
+	fn ban(n: u32, ) -> Weight {
+		<pallet_web3_names::default_weights::SubstrateWeight<T> as pallet_web3_names::WeightInfo>::ban(n)
+	}
 	fn change_deposit_owner() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `889`
-		//  Estimated: `10382`
-		// Minimum execution time: 28_302_000 picoseconds.
-		Weight::from_parts(28_302_000, 0)
-			.saturating_add(Weight::from_parts(0, 10382))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(3))
+		<pallet_web3_names::default_weights::SubstrateWeight<T> as pallet_web3_names::WeightInfo>::change_deposit_owner()
 	}
-	/// Storage: Web3Names Owner (r:1 w:1)
-	/// Proof: Web3Names Owner (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	fn update_deposit() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `374`
-		//  Estimated: `5219`
-		// Minimum execution time: 24_571_000 picoseconds.
-		Weight::from_parts(24_571_000, 0)
-			.saturating_add(Weight::from_parts(0, 5219))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(2))
+		<pallet_web3_names::default_weights::SubstrateWeight<T> as pallet_web3_names::WeightInfo>::update_deposit()
+	}
+	fn appeal_ban(n: u32, ) -> Weight {
+		<pallet_web3_names::default_weights::SubstrateWeight<T> as pallet_web3_names::WeightInfo>::appeal_ban(n)
+	}
+	fn resolve_ban_appeal(n: u32, ) -> Weight {
+		<pallet_web3_names::default_weights::SubstrateWeight<T> as pallet_web3_names::WeightInfo>::resolve_ban_appeal(n)
+	}
+	fn cancel_ban(n: u32, ) -> Weight {
+		<pallet_web3_names::default_weights::SubstrateWeight<T> as pallet_web3_names::WeightInfo>::cancel_ban(n)
+	}
+	fn enact_bans(n: u32, ) -> Weight {
+		<pallet_web3_names::default_weights::SubstrateWeight<T> as pallet_web3_names::WeightInfo>::enact_bans(n)
+	}
+	fn claim_batch(n: u32, ) -> Weight {
+		<pallet_web3_names::default_weights::SubstrateWeight<T> as pallet_web3_names::WeightInfo>::claim_batch(n)
+	}
+	fn approve_registrar() -> Weight {
+		<pallet_web3_names::default_weights::SubstrateWeight<T> as pallet_web3_names::WeightInfo>::approve_registrar()
+	}
+	fn remove_registrar() -> Weight {
+		<pallet_web3_names::default_weights::SubstrateWeight<T> as pallet_web3_names::WeightInfo>::remove_registrar()
+	}
+	fn claim_by_registrar(n: u32, ) -> Weight {
+		<pallet_web3_names::default_weights::SubstrateWeight<T> as pallet_web3_names::WeightInfo>::claim_by_registrar(n)
+	}
+	fn set_record() -> Weight {
+		<pallet_web3_names::default_weights::SubstrateWeight<T> as pallet_web3_names::WeightInfo>::set_record()
+	}
+	fn clear_record() -> Weight {
+		<pallet_web3_names::default_weights::SubstrateWeight<T> as pallet_web3_names::WeightInfo>::clear_record()
+	}
+	fn accept_pending_claim() -> Weight {
+		<pallet_web3_names::default_weights::SubstrateWeight<T> as pallet_web3_names::WeightInfo>::accept_pending_claim()
+	}
+	fn decline_pending_claim() -> Weight {
+		<pallet_web3_names::default_weights::SubstrateWeight<T> as pallet_web3_names::WeightInfo>::decline_pending_claim()
+	}
+	fn initialize_owner_counter_step() -> Weight {
+		<pallet_web3_names::default_weights::SubstrateWeight<T> as pallet_web3_names::WeightInfo>::initialize_owner_counter_step()
 	}
 }
 
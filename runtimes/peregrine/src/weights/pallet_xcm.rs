@@ -80,15 +80,12 @@ impl<T: frame_system::Config> pallet_xcm::WeightInfo for WeightInfo<T> {
 		Weight::from_parts(18_446_744_073_709_551_000, 0)
 			.saturating_add(Weight::from_parts(0, 0))
 	}
-	/// Storage: Benchmark Override (r:0 w:0)
-	/// Proof Skipped: Benchmark Override (max_values: None, max_size: None, mode: Measured)
+	/// Not benchmarked yet. A reserve transfer to the relay chain sends a
+	/// message like `send` and moves the funds to the sovereign account of
+	/// the destination like a balance transfer.
 	fn reserve_transfer_assets() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 18_446_744_073_709_551_000 picoseconds.
-		Weight::from_parts(18_446_744_073_709_551_000, 0)
-			.saturating_add(Weight::from_parts(0, 0))
+		<Self as pallet_xcm::WeightInfo>::send()
+			.saturating_add(<super::pallet_balances::WeightInfo<T> as pallet_balances::WeightInfo>::transfer_allow_death())
 	}
 	/// Storage: Benchmark Override (r:0 w:0)
 	/// Proof Skipped: Benchmark Override (max_values: None, max_size: None, mode: Measured)
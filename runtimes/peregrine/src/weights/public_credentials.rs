@@ -99,38 +99,6 @@ impl<T: frame_system::Config> public_credentials::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
-	/// Storage: PublicCredentials CredentialSubjects (r:1 w:1)
-	/// Proof: PublicCredentials CredentialSubjects (max_values: None, max_size: Some(312), added: 2787, mode: MaxEncodedLen)
-	/// Storage: PublicCredentials Credentials (r:1 w:1)
-	/// Proof: PublicCredentials Credentials (max_values: None, max_size: Some(475), added: 2950, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	fn remove() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1626`
-		//  Estimated: `8344`
-		// Minimum execution time: 37_102_000 picoseconds.
-		Weight::from_parts(37_102_000, 0)
-			.saturating_add(Weight::from_parts(0, 8344))
-			.saturating_add(T::DbWeight::get().reads(3))
-			.saturating_add(T::DbWeight::get().writes(3))
-	}
-	/// Storage: PublicCredentials CredentialSubjects (r:1 w:1)
-	/// Proof: PublicCredentials CredentialSubjects (max_values: None, max_size: Some(312), added: 2787, mode: MaxEncodedLen)
-	/// Storage: PublicCredentials Credentials (r:1 w:1)
-	/// Proof: PublicCredentials Credentials (max_values: None, max_size: Some(475), added: 2950, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	fn reclaim_deposit() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1626`
-		//  Estimated: `8344`
-		// Minimum execution time: 58_498_000 picoseconds.
-		Weight::from_parts(58_498_000, 0)
-			.saturating_add(Weight::from_parts(0, 8344))
-			.saturating_add(T::DbWeight::get().reads(3))
-			.saturating_add(T::DbWeight::get().writes(3))
-	}
 	/// Storage: PublicCredentials CredentialSubjects (r:1 w:0)
 	/// Proof: PublicCredentials CredentialSubjects (max_values: None, max_size: Some(312), added: 2787, mode: MaxEncodedLen)
 	/// Storage: PublicCredentials Credentials (r:1 w:1)
@@ -163,6 +131,23 @@ impl<T: frame_system::Config> public_credentials::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
+	// The calls below are not covered by the benchmark run above and use the
+	// estimated weights of the pallet.
+	fn remove() -> Weight {
+		<public_credentials::default_weights::SubstrateWeight<T> as public_credentials::WeightInfo>::remove()
+	}
+	fn reclaim_deposit() -> Weight {
+		<public_credentials::default_weights::SubstrateWeight<T> as public_credentials::WeightInfo>::reclaim_deposit()
+	}
+	fn set_subject_controller() -> Weight {
+		<public_credentials::default_weights::SubstrateWeight<T> as public_credentials::WeightInfo>::set_subject_controller()
+	}
+	fn set_credential_visibility() -> Weight {
+		<public_credentials::default_weights::SubstrateWeight<T> as public_credentials::WeightInfo>::set_credential_visibility()
+	}
+	fn initialize_credential_subjects_counter_step() -> Weight {
+		<public_credentials::default_weights::SubstrateWeight<T> as public_credentials::WeightInfo>::initialize_credential_subjects_counter_step()
+	}
 }
 
 #[cfg(test)]
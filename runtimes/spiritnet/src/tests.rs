@@ -16,26 +16,30 @@
 
 // If you feel like getting in touch with us, you can do so at info@botlabs.org
 
-use frame_support::{traits::Currency, BoundedVec};
+use frame_support::{
+	traits::{schedule::v3::TaskName, Currency, EnsureOrigin, InstanceFilter},
+	BoundedVec,
+};
 use parity_scale_codec::MaxEncodedLen;
 
 use did::DeriveDidCallAuthorizationVerificationKeyRelationship;
 use pallet_did_lookup::associate_account_request::AssociateAccountRequest;
 use pallet_treasury::BalanceOf;
-use pallet_web3_names::{Web3NameOf, Web3OwnershipOf};
+use pallet_web3_names::{Web3NameOf, Web3NameRecordKeyOf, Web3NameRecordOf, Web3OwnershipOf};
 use runtime_common::{
 	constants::{
-		attestation::MAX_ATTESTATION_BYTE_LENGTH,
+		attestation::{MAX_ATTESTATION_BYTE_LENGTH, MAX_ISSUANCE_ENTRY_BYTE_LENGTH},
 		did::{MAX_KEY_LENGTH, MAX_SERVICE_ENDPOINT_BYTE_LENGTH},
-		did_lookup::MAX_CONNECTION_BYTE_LENGTH,
+		did_lookup::{MAX_CONNECTION_BYTE_LENGTH, MAX_LABEL_BYTE_LENGTH},
+		did_scheduler::{MAX_AGENDA_ENTRY_BYTE_LENGTH, MAX_SCHEDULED_CALL_BYTE_LENGTH},
 		public_credentials::MAX_PUBLIC_CREDENTIAL_STORAGE_LENGTH,
-		web3_names::MAX_NAME_BYTE_LENGTH,
+		web3_names::{MAX_NAME_BYTE_LENGTH, MAX_RECORD_BYTE_LENGTH},
 		MAX_INDICES_BYTE_LENGTH,
 	},
 	AccountId, BlockNumber,
 };
 
-use crate::{Runtime, RuntimeCall};
+use crate::{ProxyType, Runtime, RuntimeCall};
 
 #[test]
 fn call_size() {
@@ -58,7 +62,15 @@ fn attestation_storage_sizes() {
 	assert_eq!(
 		attestation_record + delegation_record,
 		MAX_ATTESTATION_BYTE_LENGTH as usize
-	)
+	);
+
+	let ctype_hash = <Runtime as frame_system::Config>::Hash::max_encoded_len();
+	let issuance_counter = ctype_hash
+		+ attestation::AttesterOf::<Runtime>::max_encoded_len()
+		+ attestation::IssuanceCounterDetailsOf::<Runtime>::max_encoded_len();
+	let issuance_limits = ctype_hash + attestation::IssuanceLimitsDetailsOf::<Runtime>::max_encoded_len();
+	assert!(issuance_limits <= issuance_counter);
+	assert_eq!(issuance_counter, MAX_ISSUANCE_ENTRY_BYTE_LENGTH as usize);
 }
 
 #[test]
@@ -85,7 +97,29 @@ fn did_lookup_storage_sizes() {
 
 	let did_connection_size = DidConnection::max_encoded_len();
 
-	assert_eq!(did_connection_size, MAX_CONNECTION_BYTE_LENGTH as usize)
+	assert_eq!(did_connection_size, MAX_CONNECTION_BYTE_LENGTH as usize);
+
+	let label_size = pallet_did_lookup::LabelRecordOf::<Runtime>::max_encoded_len();
+
+	assert_eq!(label_size, MAX_LABEL_BYTE_LENGTH as usize)
+}
+
+#[test]
+fn did_scheduler_storage_sizes() {
+	let did_size = pallet_did_scheduler::DidIdentifierOf::<Runtime>::max_encoded_len();
+	let id_size = TaskName::max_encoded_len();
+	let deposit_size = pallet_did_scheduler::ScheduledCallOf::<Runtime>::max_encoded_len();
+
+	assert_eq!(
+		did_size + id_size + deposit_size,
+		MAX_SCHEDULED_CALL_BYTE_LENGTH as usize
+	);
+
+	let agenda_entry_size = pallet_scheduler::ScheduledOf::<Runtime>::max_encoded_len();
+	let lookup_entry_size =
+		TaskName::max_encoded_len() + pallet_scheduler::TaskAddress::<BlockNumber>::max_encoded_len();
+
+	assert!(agenda_entry_size + lookup_entry_size <= MAX_AGENDA_ENTRY_BYTE_LENGTH as usize);
 }
 
 #[test]
@@ -96,6 +130,15 @@ fn web3_name_storage_sizes() {
 	assert_eq!(owner_size + name_size, MAX_NAME_BYTE_LENGTH as usize)
 }
 
+#[test]
+fn web3_name_record_storage_sizes() {
+	let name_size = Web3NameOf::<Runtime>::max_encoded_len();
+	let key_size = Web3NameRecordKeyOf::<Runtime>::max_encoded_len();
+	let record_size = Web3NameRecordOf::<Runtime>::max_encoded_len();
+
+	assert_eq!(name_size + key_size + record_size, MAX_RECORD_BYTE_LENGTH as usize)
+}
+
 #[test]
 fn indices_storage_sizes() {
 	type Indices = (<Runtime as frame_system::Config>::AccountId, BalanceOf<Runtime>, bool);
@@ -181,6 +224,50 @@ fn test_derive_did_key_lookup() {
 	);
 }
 
+#[test]
+fn test_derive_did_key_scheduler() {
+	let ctype_call = RuntimeCall::Ctype(ctype::Call::add {
+		ctype: vec![0, 1, 2, 3],
+	});
+
+	assert_eq!(
+		RuntimeCall::DidScheduler(pallet_did_scheduler::Call::schedule {
+			id: [0u8; 32],
+			when: 100,
+			call: Box::new(ctype_call),
+		})
+		.derive_verification_key_relationship(),
+		Ok(did::DidVerificationKeyRelationship::AssertionMethod)
+	);
+
+	assert_eq!(
+		RuntimeCall::DidScheduler(pallet_did_scheduler::Call::schedule {
+			id: [0u8; 32],
+			when: 100,
+			call: Box::new(RuntimeCall::Web3Names(pallet_web3_names::Call::release_by_owner {})),
+		})
+		.derive_verification_key_relationship(),
+		Ok(did::DidVerificationKeyRelationship::Authentication)
+	);
+
+	assert_eq!(
+		RuntimeCall::DidScheduler(pallet_did_scheduler::Call::remove { id: [0u8; 32] })
+			.derive_verification_key_relationship(),
+		Ok(did::DidVerificationKeyRelationship::Authentication)
+	);
+}
+
+#[test]
+fn test_scheduler_rejects_did_origin() {
+	let origin: crate::RuntimeOrigin = did::DidRawOrigin {
+		id: AccountId::new([1u8; 32]),
+		submitter: AccountId::new([2u8; 32]),
+	}
+	.into();
+
+	assert!(<<Runtime as pallet_scheduler::Config>::ScheduleOrigin as EnsureOrigin<_>>::try_origin(origin).is_err());
+}
+
 #[test]
 fn test_derive_did_verification_relation_fail() {
 	let c1 = RuntimeCall::Ctype(ctype::Call::add {
@@ -295,3 +382,268 @@ fn test_derive_did_verification_relation_empty() {
 		Err(did::RelationshipDeriveError::InvalidCallParameter)
 	);
 }
+
+#[test]
+fn identity_proxy_filter() {
+	let remove_key = RuntimeCall::Did(did::Call::remove_delegation_key {});
+	let release_name = RuntimeCall::Web3Names(pallet_web3_names::Call::release_by_owner {});
+	let unlink_sender = RuntimeCall::DidLookup(pallet_did_lookup::Call::remove_sender_association {});
+	let transfer = RuntimeCall::Balances(pallet_balances::Call::transfer_keep_alive {
+		dest: AccountId::new([1u8; 32]).into(),
+		value: 1,
+	});
+
+	assert!(ProxyType::Identity.filter(&remove_key));
+	assert!(ProxyType::Identity.filter(&release_name));
+	assert!(ProxyType::Identity.filter(&unlink_sender));
+	assert!(!ProxyType::Identity.filter(&transfer));
+}
+
+#[test]
+fn staking_proxies_cannot_transfer_delegations() {
+	let transfer_delegation = RuntimeCall::ParachainStaking(parachain_staking::Call::transfer_delegation {
+		new_delegator: AccountId::new([1u8; 32]).into(),
+	});
+	let claim_rewards = RuntimeCall::ParachainStaking(parachain_staking::Call::claim_rewards {});
+
+	assert!(ProxyType::Any.filter(&transfer_delegation));
+	assert!(!ProxyType::NonTransfer.filter(&transfer_delegation));
+	assert!(!ProxyType::ParachainStaking.filter(&transfer_delegation));
+	assert!(ProxyType::NonTransfer.filter(&claim_rewards));
+	assert!(ProxyType::ParachainStaking.filter(&claim_rewards));
+}
+
+#[test]
+fn identity_proxy_is_subset_of_non_transfer() {
+	assert!(ProxyType::Any.is_superset(&ProxyType::Identity));
+	assert!(ProxyType::NonTransfer.is_superset(&ProxyType::Identity));
+	assert!(!ProxyType::NonDepositClaiming.is_superset(&ProxyType::Identity));
+	assert!(!ProxyType::Identity.is_superset(&ProxyType::NonTransfer));
+}
+
+#[test]
+fn foreign_asset_fees_only_for_identity_calls() {
+	use pallet_asset_tx_payment::OnChargeAssetTransaction;
+	use sp_runtime::transaction_validity::{InvalidTransaction, TransactionValidityError};
+
+	type ForeignAssetFees = <Runtime as pallet_asset_tx_payment::Config>::OnChargeAssetTransaction;
+
+	let call = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+	assert_eq!(
+		ForeignAssetFees::withdraw_fee(
+			&AccountId::new([0u8; 32]),
+			&call,
+			&Default::default(),
+			xcm::v3::MultiLocation::parent(),
+			1_000,
+			0
+		)
+		.err(),
+		Some(TransactionValidityError::Invalid(InvalidTransaction::Payment))
+	);
+}
+
+#[test]
+fn xcm_safe_call_filter_only_allows_did_signed_calls() {
+	use frame_support::traits::Contains;
+
+	use crate::xcm_config::SafeCallFilter;
+
+	let web3_name_claim = RuntimeCall::Web3Names(pallet_web3_names::Call::claim {
+		name: b"test-name".to_vec().try_into().unwrap(),
+	});
+	let remark = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+	let did_call = |call: RuntimeCall| {
+		RuntimeCall::Did(did::Call::submit_did_call {
+			did_call: Box::new(did::did_details::DidAuthorizedCallOperation {
+				did: AccountId::new([1u8; 32]),
+				tx_counter: 1,
+				call,
+				block_number: BlockNumber::default(),
+				submitter: AccountId::new([2u8; 32]),
+			}),
+			signature: did::DidSignature::Sr25519(sp_core::sr25519::Signature([0; 64])),
+		})
+	};
+
+	assert!(!SafeCallFilter::contains(&web3_name_claim));
+	assert!(SafeCallFilter::contains(&did_call(web3_name_claim)));
+	assert!(!SafeCallFilter::contains(&remark));
+	assert!(!SafeCallFilter::contains(&did_call(remark)));
+}
+
+#[test]
+fn xcm_sibling_account_linked_to_did_is_not_converted_into_did_origin() {
+	use kilt_support::Deposit;
+	use pallet_did_lookup::{linkable_account::LinkableAccountId, ConnectedDids, ConnectionRecord};
+	use runtime_common::xcm_config::LocationToAccountId;
+	use xcm::v3::prelude::*;
+	use xcm_executor::traits::{ConvertLocation, ConvertOrigin};
+
+	use crate::{
+		xcm_config::{RelayNetworkId, XcmOriginToTransactDispatchOrigin},
+		RuntimeOrigin,
+	};
+
+	let location = MultiLocation::new(
+		1,
+		X2(
+			Parachain(2_000),
+			AccountId32 {
+				network: None,
+				id: [1u8; 32],
+			},
+		),
+	);
+	let account = LocationToAccountId::<RelayNetworkId>::convert_location(&location)
+		.expect("Sibling account should be converted into a local account.");
+
+	sp_io::TestExternalities::default().execute_with(|| {
+		ConnectedDids::<Runtime>::insert(
+			LinkableAccountId::from(account.clone()),
+			ConnectionRecord {
+				did: AccountId::new([2u8; 32]),
+				deposit: Deposit {
+					owner: account.clone(),
+					amount: 0,
+				},
+			},
+		);
+
+		// The link does not give the account authority over the DID.
+		assert!(
+			<XcmOriginToTransactDispatchOrigin as ConvertOrigin<RuntimeOrigin>>::convert_origin(
+				location,
+				OriginKind::Native
+			)
+			.is_err()
+		);
+		// The account can only act as itself, e.g., to submit calls signed by the DID key.
+		let origin = <XcmOriginToTransactDispatchOrigin as ConvertOrigin<RuntimeOrigin>>::convert_origin(
+			location,
+			OriginKind::SovereignAccount,
+		)
+		.expect("Sibling account should be converted into a signed origin.");
+		assert_eq!(frame_system::ensure_signed(origin), Ok(account));
+	});
+}
+
+#[test]
+fn xcm_identity_transacts_denied_while_paused() {
+	use frame_support::weights::Weight;
+	use parity_scale_codec::Encode;
+	use runtime_common::xcm_config::DenyIdentityTransactsWhilePaused;
+	use xcm::v3::prelude::*;
+	use xcm_executor::traits::{Properties, ShouldExecute};
+
+	use crate::xcm_config::IdentityCalls;
+
+	type Barrier = DenyIdentityTransactsWhilePaused<
+		pallet_configuration::IsXcmIdentityOpsPaused<Runtime>,
+		RuntimeCall,
+		IdentityCalls,
+	>;
+
+	let transact = |call: RuntimeCall| {
+		vec![Transact {
+			origin_kind: OriginKind::Native,
+			require_weight_at_most: Weight::zero(),
+			call: call.encode().into(),
+		}]
+	};
+	let should_execute = |mut message: Vec<Instruction<()>>| {
+		Barrier::should_execute(
+			&MultiLocation::parent(),
+			&mut message,
+			Weight::zero(),
+			&mut Properties {
+				weight_credit: Weight::zero(),
+				message_id: None,
+			},
+		)
+	};
+	let web3_name_claim = RuntimeCall::Web3Names(pallet_web3_names::Call::claim {
+		name: b"test-name".to_vec().try_into().unwrap(),
+	});
+	let remark = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+
+	sp_io::TestExternalities::default().execute_with(|| {
+		assert!(should_execute(transact(web3_name_claim.clone())).is_ok());
+
+		pallet_configuration::XcmIdentityOpsPaused::<Runtime>::set(true);
+		assert!(should_execute(transact(web3_name_claim.clone())).is_err());
+		assert!(should_execute(vec![SetAppendix(Xcm(transact(web3_name_claim)))]).is_err());
+		assert!(should_execute(transact(remark)).is_ok());
+	});
+}
+
+#[test]
+fn did_curator_calls_only_include_curator_actions() {
+	use frame_support::traits::Contains;
+
+	use crate::BountyCuratorCalls;
+
+	let accept_curator = RuntimeCall::Bounties(pallet_bounties::Call::accept_curator { bounty_id: 0 });
+	let award_child_bounty = RuntimeCall::ChildBounties(pallet_child_bounties::Call::award_child_bounty {
+		parent_bounty_id: 0,
+		child_bounty_id: 0,
+		beneficiary: AccountId::new([1u8; 32]).into(),
+	});
+	let approve_bounty = RuntimeCall::Bounties(pallet_bounties::Call::approve_bounty { bounty_id: 0 });
+	let remark = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+
+	assert!(BountyCuratorCalls::contains(&accept_curator));
+	assert!(BountyCuratorCalls::contains(&award_child_bounty));
+	assert!(!BountyCuratorCalls::contains(&approve_bounty));
+	assert!(!BountyCuratorCalls::contains(&remark));
+
+	let curator_call = RuntimeCall::DidCurator(pallet_did_curator::Call::dispatch_as_curator {
+		call: Box::new(accept_curator),
+	});
+	assert_eq!(
+		curator_call.derive_verification_key_relationship(),
+		Ok(did::DidVerificationKeyRelationship::Authentication)
+	);
+
+	// Curator calls are not dispatched from the account of the DID identifier.
+	let did = AccountId::new([2u8; 32]);
+	assert_ne!(crate::DidCurator::curator_account(&did), did);
+}
+
+#[test]
+fn identity_calls_can_be_paused_individually() {
+	use frame_support::{assert_ok, traits::Contains};
+
+	use crate::{BaseFilter, RuntimeOrigin};
+
+	let did_delete = RuntimeCall::Did(did::Call::delete { endpoints_to_remove: 0 });
+	let did_reclaim = RuntimeCall::Did(did::Call::reclaim_deposit {
+		did_subject: AccountId::new([0u8; 32]),
+		endpoints_to_remove: 0,
+	});
+	let remark = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+	let full_call_name = |pallet_name: &[u8], call_name: &[u8]| -> pallet_configuration::FullCallName {
+		(
+			pallet_name.to_vec().try_into().unwrap(),
+			call_name.to_vec().try_into().unwrap(),
+		)
+	};
+
+	sp_io::TestExternalities::default().execute_with(|| {
+		assert!(BaseFilter::contains(&did_delete));
+
+		assert_ok!(pallet_configuration::Pallet::<Runtime>::pause_call(
+			RuntimeOrigin::root(),
+			full_call_name(b"Did", b"delete")
+		));
+		assert!(!BaseFilter::contains(&did_delete));
+		assert!(BaseFilter::contains(&did_reclaim));
+
+		// Calls of non-identity pallets cannot be paused.
+		assert_ok!(pallet_configuration::Pallet::<Runtime>::pause_call(
+			RuntimeOrigin::root(),
+			full_call_name(b"System", b"remark")
+		));
+		assert!(BaseFilter::contains(&remark));
+	});
+}
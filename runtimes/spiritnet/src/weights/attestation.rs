@@ -48,58 +48,63 @@ use sp_std::marker::PhantomData;
 /// Weights for `attestation`.
 pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> attestation::WeightInfo for WeightInfo<T> {
-	// Storage: Ctype Ctypes (r:1 w:0)
-	// Proof: Ctype Ctypes (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
-	// Storage: Attestation Attestations (r:1 w:1)
-	// Proof: Attestation Attestations (max_values: None, max_size: Some(195), added: 2670, mode: MaxEncodedLen)
-	// Storage: System Account (r:1 w:1)
-	// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	// The calls below are not covered by the benchmark run above and use the
+	// estimated weights of the pallet.
 	fn add() -> Weight {
-		Weight::from_parts(32_883_000 as u64, 0)
-			.saturating_add(T::DbWeight::get().reads(3 as u64))
-			.saturating_add(T::DbWeight::get().writes(2 as u64))
+		<attestation::default_weights::SubstrateWeight<T> as attestation::WeightInfo>::add()
 	}
-	// Storage: Attestation Attestations (r:1 w:1)
-	// Proof: Attestation Attestations (max_values: None, max_size: Some(195), added: 2670, mode: MaxEncodedLen)
 	fn revoke() -> Weight {
-		Weight::from_parts(18_271_000 as u64, 0)
-			.saturating_add(T::DbWeight::get().reads(1 as u64))
-			.saturating_add(T::DbWeight::get().writes(1 as u64))
-	}
-	// Storage: Attestation Attestations (r:1 w:1)
-	// Proof: Attestation Attestations (max_values: None, max_size: Some(195), added: 2670, mode: MaxEncodedLen)
-	// Storage: System Account (r:1 w:1)
-	// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+		<attestation::default_weights::SubstrateWeight<T> as attestation::WeightInfo>::revoke()
+	}
 	fn remove() -> Weight {
-		Weight::from_parts(31_556_000 as u64, 0)
-			.saturating_add(T::DbWeight::get().reads(2 as u64))
-			.saturating_add(T::DbWeight::get().writes(2 as u64))
-	}
-	// Storage: Attestation Attestations (r:1 w:1)
-	// Proof: Attestation Attestations (max_values: None, max_size: Some(195), added: 2670, mode: MaxEncodedLen)
-	// Storage: System Account (r:1 w:1)
-	// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+		<attestation::default_weights::SubstrateWeight<T> as attestation::WeightInfo>::remove()
+	}
 	fn reclaim_deposit() -> Weight {
-		Weight::from_parts(47_092_000 as u64, 0)
-			.saturating_add(T::DbWeight::get().reads(2 as u64))
-			.saturating_add(T::DbWeight::get().writes(2 as u64))
-	}
-	// Storage: Attestation Attestations (r:1 w:1)
-	// Proof: Attestation Attestations (max_values: None, max_size: Some(195), added: 2670, mode: MaxEncodedLen)
-	// Storage: System Account (r:2 w:2)
-	// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+		<attestation::default_weights::SubstrateWeight<T> as attestation::WeightInfo>::reclaim_deposit()
+	}
 	fn change_deposit_owner() -> Weight {
-		Weight::from_parts(44_125_000 as u64, 0)
-			.saturating_add(T::DbWeight::get().reads(3 as u64))
-			.saturating_add(T::DbWeight::get().writes(3 as u64))
-	}
-	// Storage: Attestation Attestations (r:1 w:1)
-	// Proof: Attestation Attestations (max_values: None, max_size: Some(195), added: 2670, mode: MaxEncodedLen)
-	// Storage: System Account (r:1 w:1)
-	// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+		<attestation::default_weights::SubstrateWeight<T> as attestation::WeightInfo>::change_deposit_owner()
+	}
 	fn update_deposit() -> Weight {
-		Weight::from_parts(39_086_000 as u64, 0)
-			.saturating_add(T::DbWeight::get().reads(2 as u64))
-			.saturating_add(T::DbWeight::get().writes(2 as u64))
+		<attestation::default_weights::SubstrateWeight<T> as attestation::WeightInfo>::update_deposit()
+	}
+	fn challenge() -> Weight {
+		<attestation::default_weights::SubstrateWeight<T> as attestation::WeightInfo>::challenge()
+	}
+	fn contest_challenge() -> Weight {
+		<attestation::default_weights::SubstrateWeight<T> as attestation::WeightInfo>::contest_challenge()
+	}
+	fn resolve_challenge() -> Weight {
+		<attestation::default_weights::SubstrateWeight<T> as attestation::WeightInfo>::resolve_challenge()
+	}
+	fn adjudicate_challenge() -> Weight {
+		<attestation::default_weights::SubstrateWeight<T> as attestation::WeightInfo>::adjudicate_challenge()
+	}
+	fn expire_challenge() -> Weight {
+		<attestation::default_weights::SubstrateWeight<T> as attestation::WeightInfo>::expire_challenge()
+	}
+	fn set_issuance_limits() -> Weight {
+		<attestation::default_weights::SubstrateWeight<T> as attestation::WeightInfo>::set_issuance_limits()
+	}
+	fn add_with_claimer_ack() -> Weight {
+		<attestation::default_weights::SubstrateWeight<T> as attestation::WeightInfo>::add_with_claimer_ack()
+	}
+	fn remove_issuance_counter() -> Weight {
+		<attestation::default_weights::SubstrateWeight<T> as attestation::WeightInfo>::remove_issuance_counter()
+	}
+	fn add_attestation_cid_step() -> Weight {
+		<attestation::default_weights::SubstrateWeight<T> as attestation::WeightInfo>::add_attestation_cid_step()
+	}
+	fn populate_attestations_by_delegation_step() -> Weight {
+		<attestation::default_weights::SubstrateWeight<T> as attestation::WeightInfo>::populate_attestations_by_delegation_step()
+	}
+	fn add_claimer_acknowledgement_step() -> Weight {
+		<attestation::default_weights::SubstrateWeight<T> as attestation::WeightInfo>::add_claimer_acknowledgement_step()
+	}
+	fn initialize_attestation_counter_step() -> Weight {
+		<attestation::default_weights::SubstrateWeight<T> as attestation::WeightInfo>::initialize_attestation_counter_step()
+	}
+	fn initialize_issuance_counters_step() -> Weight {
+		<attestation::default_weights::SubstrateWeight<T> as attestation::WeightInfo>::initialize_issuance_counters_step()
 	}
 }
@@ -168,4 +168,9 @@ impl<T: frame_system::Config> delegation::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(2 as u64))
 			.saturating_add(T::DbWeight::get().writes(2 as u64))
 	}
+	// The calls below are not covered by the benchmark run above and use the
+	// estimated weights of the pallet.
+	fn revoke_hierarchy_paged(n: u32, ) -> Weight {
+		<delegation::default_weights::SubstrateWeight<T> as delegation::WeightInfo>::revoke_hierarchy_paged(n)
+	}
 }
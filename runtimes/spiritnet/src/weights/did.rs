@@ -50,149 +50,6 @@ use sp_std::marker::PhantomData;
 /// Weight functions for `did`.
 pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> did::WeightInfo for WeightInfo<T> {
-	/// Storage: Did DidBlacklist (r:1 w:0)
-	/// Proof: Did DidBlacklist (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
-	/// Storage: Did Did (r:1 w:1)
-	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	/// Storage: System Account (r:2 w:2)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	/// Storage: Balances Holds (r:1 w:1)
-	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
-	/// Storage: Did DidEndpointsCount (r:0 w:1)
-	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
-	/// Storage: Did ServiceEndpoints (r:0 w:25)
-	/// Proof: Did ServiceEndpoints (max_values: None, max_size: Some(615), added: 3090, mode: MaxEncodedLen)
-	/// The range of component `n` is `[1, 10]`.
-	/// The range of component `c` is `[1, 25]`.
-	fn create_ed25519_keys(n: u32, c: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1131`
-		//  Estimated: `6204`
-		// Minimum execution time: 177_491_000 picoseconds.
-		Weight::from_parts(157_165_842, 0)
-			.saturating_add(Weight::from_parts(0, 6204))
-			// Standard Error: 12_830
-			.saturating_add(Weight::from_parts(1_302_124, 0).saturating_mul(n.into()))
-			// Standard Error: 4_960
-			.saturating_add(Weight::from_parts(10_348_415, 0).saturating_mul(c.into()))
-			.saturating_add(T::DbWeight::get().reads(5))
-			.saturating_add(T::DbWeight::get().writes(5))
-			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
-	}
-	/// Storage: Did DidBlacklist (r:1 w:0)
-	/// Proof: Did DidBlacklist (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
-	/// Storage: Did Did (r:1 w:1)
-	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	/// Storage: System Account (r:2 w:2)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	/// Storage: Balances Holds (r:1 w:1)
-	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
-	/// Storage: Did DidEndpointsCount (r:0 w:1)
-	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
-	/// Storage: Did ServiceEndpoints (r:0 w:25)
-	/// Proof: Did ServiceEndpoints (max_values: None, max_size: Some(615), added: 3090, mode: MaxEncodedLen)
-	/// The range of component `n` is `[1, 10]`.
-	/// The range of component `c` is `[1, 25]`.
-	fn create_sr25519_keys(n: u32, c: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1131`
-		//  Estimated: `6204`
-		// Minimum execution time: 180_865_000 picoseconds.
-		Weight::from_parts(157_142_218, 0)
-			.saturating_add(Weight::from_parts(0, 6204))
-			// Standard Error: 20_565
-			.saturating_add(Weight::from_parts(1_498_197, 0).saturating_mul(n.into()))
-			// Standard Error: 7_951
-			.saturating_add(Weight::from_parts(11_317_743, 0).saturating_mul(c.into()))
-			.saturating_add(T::DbWeight::get().reads(5))
-			.saturating_add(T::DbWeight::get().writes(5))
-			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
-	}
-	/// Storage: Did DidBlacklist (r:1 w:0)
-	/// Proof: Did DidBlacklist (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
-	/// Storage: Did Did (r:1 w:1)
-	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	/// Storage: System Account (r:2 w:2)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	/// Storage: Balances Holds (r:1 w:1)
-	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
-	/// Storage: Did DidEndpointsCount (r:0 w:1)
-	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
-	/// Storage: Did ServiceEndpoints (r:0 w:25)
-	/// Proof: Did ServiceEndpoints (max_values: None, max_size: Some(615), added: 3090, mode: MaxEncodedLen)
-	/// The range of component `n` is `[1, 10]`.
-	/// The range of component `c` is `[1, 25]`.
-	fn create_ecdsa_keys(n: u32, c: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1131`
-		//  Estimated: `6204`
-		// Minimum execution time: 165_151_000 picoseconds.
-		Weight::from_parts(147_963_958, 0)
-			.saturating_add(Weight::from_parts(0, 6204))
-			// Standard Error: 15_186
-			.saturating_add(Weight::from_parts(1_129_798, 0).saturating_mul(n.into()))
-			// Standard Error: 5_871
-			.saturating_add(Weight::from_parts(9_676_243, 0).saturating_mul(c.into()))
-			.saturating_add(T::DbWeight::get().reads(5))
-			.saturating_add(T::DbWeight::get().writes(5))
-			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
-	}
-	/// Storage: Did DidEndpointsCount (r:1 w:1)
-	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
-	/// Storage: Did ServiceEndpoints (r:25 w:25)
-	/// Proof: Did ServiceEndpoints (max_values: None, max_size: Some(615), added: 3090, mode: MaxEncodedLen)
-	/// Storage: Did Did (r:1 w:1)
-	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	/// Storage: Balances Holds (r:1 w:1)
-	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	/// Storage: Did DidBlacklist (r:0 w:1)
-	/// Proof: Did DidBlacklist (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
-	/// The range of component `c` is `[1, 25]`.
-	fn delete(c: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `969 + c * (105 ±0)`
-		//  Estimated: `5777 + c * (3090 ±0)`
-		// Minimum execution time: 52_816_000 picoseconds.
-		Weight::from_parts(53_595_561, 0)
-			.saturating_add(Weight::from_parts(0, 5777))
-			// Standard Error: 4_301
-			.saturating_add(Weight::from_parts(1_392_444, 0).saturating_mul(c.into()))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(c.into())))
-			.saturating_add(T::DbWeight::get().writes(5))
-			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
-			.saturating_add(Weight::from_parts(0, 3090).saturating_mul(c.into()))
-	}
-	/// Storage: Did Did (r:1 w:1)
-	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	/// Storage: Did DidEndpointsCount (r:1 w:1)
-	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
-	/// Storage: Did ServiceEndpoints (r:25 w:25)
-	/// Proof: Did ServiceEndpoints (max_values: None, max_size: Some(615), added: 3090, mode: MaxEncodedLen)
-	/// Storage: Balances Holds (r:1 w:1)
-	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	/// Storage: Did DidBlacklist (r:0 w:1)
-	/// Proof: Did DidBlacklist (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
-	/// The range of component `c` is `[1, 25]`.
-	fn reclaim_deposit(c: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `969 + c * (105 ±0)`
-		//  Estimated: `5777 + c * (3090 ±0)`
-		// Minimum execution time: 55_724_000 picoseconds.
-		Weight::from_parts(56_057_711, 0)
-			.saturating_add(Weight::from_parts(0, 5777))
-			// Standard Error: 4_280
-			.saturating_add(Weight::from_parts(1_391_321, 0).saturating_mul(c.into()))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(c.into())))
-			.saturating_add(T::DbWeight::get().writes(5))
-			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
-			.saturating_add(Weight::from_parts(0, 3090).saturating_mul(c.into()))
-	}
 	/// Storage: Did Did (r:1 w:1)
 	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
 	fn submit_did_call_ed25519_key() -> Weight {
@@ -229,531 +86,184 @@ impl<T: frame_system::Config> did::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
-	/// Storage: Did Did (r:1 w:1)
+	/// Storage: Did Did (r:1 w:0)
 	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	/// Storage: Did DidEndpointsCount (r:1 w:0)
-	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	/// Storage: Balances Holds (r:1 w:1)
-	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
-	fn set_ed25519_authentication_key() -> Weight {
+	/// The range of component `l` is `[1, 5242880]`.
+	fn signature_verification_sr25519(l: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `2099`
+		//  Measured:  `1626`
 		//  Estimated: `5777`
-		// Minimum execution time: 68_011_000 picoseconds.
-		Weight::from_parts(69_449_000, 0)
+		// Minimum execution time: 63_693_000 picoseconds.
+		Weight::from_parts(33_416_355, 0)
 			.saturating_add(Weight::from_parts(0, 5777))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(3))
+			// Standard Error: 0
+			.saturating_add(Weight::from_parts(4_311, 0).saturating_mul(l.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
 	}
-	/// Storage: Did Did (r:1 w:1)
+	/// Storage: Did Did (r:1 w:0)
 	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	/// Storage: Did DidEndpointsCount (r:1 w:0)
-	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	/// Storage: Balances Holds (r:1 w:1)
-	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
-	fn set_sr25519_authentication_key() -> Weight {
+	/// The range of component `l` is `[1, 5242880]`.
+	fn signature_verification_ed25519(l: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `2104`
+		//  Measured:  `1626`
 		//  Estimated: `5777`
-		// Minimum execution time: 68_093_000 picoseconds.
-		Weight::from_parts(69_344_000, 0)
+		// Minimum execution time: 62_149_000 picoseconds.
+		Weight::from_parts(36_450_104, 0)
 			.saturating_add(Weight::from_parts(0, 5777))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(3))
+			// Standard Error: 0
+			.saturating_add(Weight::from_parts(2_467, 0).saturating_mul(l.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
 	}
-	/// Storage: Did Did (r:1 w:1)
+	/// Storage: Did Did (r:1 w:0)
 	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	/// Storage: Did DidEndpointsCount (r:1 w:0)
-	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	/// Storage: Balances Holds (r:1 w:1)
-	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
-	fn set_ecdsa_authentication_key() -> Weight {
+	/// The range of component `l` is `[1, 5242880]`.
+	fn signature_verification_ecdsa(l: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `2140`
+		//  Measured:  `1627`
 		//  Estimated: `5777`
-		// Minimum execution time: 67_561_000 picoseconds.
-		Weight::from_parts(69_549_000, 0)
+		// Minimum execution time: 50_967_000 picoseconds.
+		Weight::from_parts(23_952_757, 0)
 			.saturating_add(Weight::from_parts(0, 5777))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(3))
+			// Standard Error: 0
+			.saturating_add(Weight::from_parts(1_158, 0).saturating_mul(l.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
 	}
-	/// Storage: Did Did (r:1 w:1)
+
+	/// Storage: Did Did (r:1 w:0)
 	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	/// Storage: Did DidEndpointsCount (r:1 w:0)
-	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	/// Storage: Balances Holds (r:1 w:1)
-	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
-	fn set_ed25519_delegation_key() -> Weight {
+	fn dispatch_as() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `2137`
+		//  Measured:  `353`
 		//  Estimated: `5777`
-		// Minimum execution time: 67_920_000 picoseconds.
-		Weight::from_parts(69_254_000, 0)
+		// Minimum execution time: 186_836_000 picoseconds.
+		Weight::from_parts(189_377_000, 0)
 			.saturating_add(Weight::from_parts(0, 5777))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(T::DbWeight::get().reads(1))
+	}
+	// The calls below are not covered by the benchmark run above and use the
+	// estimated weights of the pallet.
+	fn create_ed25519_keys(n: u32, c: u32, ) -> Weight {
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::create_ed25519_keys(n, c)
+	}
+	fn create_sr25519_keys(n: u32, c: u32, ) -> Weight {
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::create_sr25519_keys(n, c)
+	}
+	fn create_ecdsa_keys(n: u32, c: u32, ) -> Weight {
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::create_ecdsa_keys(n, c)
+	}
+	fn delete(c: u32, ) -> Weight {
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::delete(c)
+	}
+	fn reclaim_deposit(c: u32, ) -> Weight {
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::reclaim_deposit(c)
+	}
+	fn set_ed25519_authentication_key() -> Weight {
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::set_ed25519_authentication_key()
+	}
+	fn set_sr25519_authentication_key() -> Weight {
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::set_sr25519_authentication_key()
+	}
+	fn set_ecdsa_authentication_key() -> Weight {
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::set_ecdsa_authentication_key()
+	}
+	fn set_multisig_authentication_key(s: u32, ) -> Weight {
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::set_multisig_authentication_key(s)
+	}
+	fn set_ed25519_delegation_key() -> Weight {
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::set_ed25519_delegation_key()
 	}
-	/// Storage: Did Did (r:1 w:1)
-	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	/// Storage: Did DidEndpointsCount (r:1 w:0)
-	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	/// Storage: Balances Holds (r:1 w:1)
-	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
 	fn set_sr25519_delegation_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2138`
-		//  Estimated: `5777`
-		// Minimum execution time: 67_281_000 picoseconds.
-		Weight::from_parts(69_160_000, 0)
-			.saturating_add(Weight::from_parts(0, 5777))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(3))
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::set_sr25519_delegation_key()
 	}
-	/// Storage: Did Did (r:1 w:1)
-	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	/// Storage: Did DidEndpointsCount (r:1 w:0)
-	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	/// Storage: Balances Holds (r:1 w:1)
-	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
 	fn set_ecdsa_delegation_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2107`
-		//  Estimated: `5777`
-		// Minimum execution time: 68_013_000 picoseconds.
-		Weight::from_parts(69_708_000, 0)
-			.saturating_add(Weight::from_parts(0, 5777))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(3))
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::set_ecdsa_delegation_key()
 	}
-	/// Storage: Did Did (r:1 w:1)
-	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	/// Storage: Did DidEndpointsCount (r:1 w:0)
-	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	/// Storage: Balances Holds (r:1 w:1)
-	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
 	fn remove_ed25519_delegation_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2104`
-		//  Estimated: `5777`
-		// Minimum execution time: 64_728_000 picoseconds.
-		Weight::from_parts(66_761_000, 0)
-			.saturating_add(Weight::from_parts(0, 5777))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(3))
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::remove_ed25519_delegation_key()
 	}
-	/// Storage: Did Did (r:1 w:1)
-	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	/// Storage: Did DidEndpointsCount (r:1 w:0)
-	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	/// Storage: Balances Holds (r:1 w:1)
-	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
 	fn remove_sr25519_delegation_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2171`
-		//  Estimated: `5777`
-		// Minimum execution time: 65_658_000 picoseconds.
-		Weight::from_parts(66_743_000, 0)
-			.saturating_add(Weight::from_parts(0, 5777))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(3))
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::remove_sr25519_delegation_key()
 	}
-	/// Storage: Did Did (r:1 w:1)
-	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	/// Storage: Did DidEndpointsCount (r:1 w:0)
-	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	/// Storage: Balances Holds (r:1 w:1)
-	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
 	fn remove_ecdsa_delegation_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2168`
-		//  Estimated: `5777`
-		// Minimum execution time: 66_556_000 picoseconds.
-		Weight::from_parts(67_195_000, 0)
-			.saturating_add(Weight::from_parts(0, 5777))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(3))
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::remove_ecdsa_delegation_key()
 	}
-	/// Storage: Did Did (r:1 w:1)
-	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	/// Storage: Did DidEndpointsCount (r:1 w:0)
-	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	/// Storage: Balances Holds (r:1 w:1)
-	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
 	fn set_ed25519_attestation_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2033`
-		//  Estimated: `5777`
-		// Minimum execution time: 67_420_000 picoseconds.
-		Weight::from_parts(69_442_000, 0)
-			.saturating_add(Weight::from_parts(0, 5777))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(3))
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::set_ed25519_attestation_key()
 	}
-	/// Storage: Did Did (r:1 w:1)
-	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	/// Storage: Did DidEndpointsCount (r:1 w:0)
-	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	/// Storage: Balances Holds (r:1 w:1)
-	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
 	fn set_sr25519_attestation_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2104`
-		//  Estimated: `5777`
-		// Minimum execution time: 67_227_000 picoseconds.
-		Weight::from_parts(69_764_000, 0)
-			.saturating_add(Weight::from_parts(0, 5777))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(3))
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::set_sr25519_attestation_key()
 	}
-	/// Storage: Did Did (r:1 w:1)
-	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	/// Storage: Did DidEndpointsCount (r:1 w:0)
-	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	/// Storage: Balances Holds (r:1 w:1)
-	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
 	fn set_ecdsa_attestation_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2135`
-		//  Estimated: `5777`
-		// Minimum execution time: 67_431_000 picoseconds.
-		Weight::from_parts(69_135_000, 0)
-			.saturating_add(Weight::from_parts(0, 5777))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(3))
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::set_ecdsa_attestation_key()
 	}
-	/// Storage: Did Did (r:1 w:1)
-	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	/// Storage: Did DidEndpointsCount (r:1 w:0)
-	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	/// Storage: Balances Holds (r:1 w:1)
-	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
 	fn remove_ed25519_attestation_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2137`
-		//  Estimated: `5777`
-		// Minimum execution time: 64_771_000 picoseconds.
-		Weight::from_parts(66_211_000, 0)
-			.saturating_add(Weight::from_parts(0, 5777))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(3))
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::remove_ed25519_attestation_key()
 	}
-	/// Storage: Did Did (r:1 w:1)
-	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	/// Storage: Did DidEndpointsCount (r:1 w:0)
-	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	/// Storage: Balances Holds (r:1 w:1)
-	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
 	fn remove_sr25519_attestation_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2104`
-		//  Estimated: `5777`
-		// Minimum execution time: 65_332_000 picoseconds.
-		Weight::from_parts(66_744_000, 0)
-			.saturating_add(Weight::from_parts(0, 5777))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(3))
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::remove_sr25519_attestation_key()
 	}
-	/// Storage: Did Did (r:1 w:1)
-	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	/// Storage: Did DidEndpointsCount (r:1 w:0)
-	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	/// Storage: Balances Holds (r:1 w:1)
-	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
 	fn remove_ecdsa_attestation_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2107`
-		//  Estimated: `5777`
-		// Minimum execution time: 65_730_000 picoseconds.
-		Weight::from_parts(67_062_000, 0)
-			.saturating_add(Weight::from_parts(0, 5777))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(3))
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::remove_ecdsa_attestation_key()
 	}
-	/// Storage: Did Did (r:1 w:1)
-	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	/// Storage: Did DidEndpointsCount (r:1 w:0)
-	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	/// Storage: Balances Holds (r:1 w:1)
-	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
 	fn add_ed25519_key_agreement_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2032`
-		//  Estimated: `5777`
-		// Minimum execution time: 67_168_000 picoseconds.
-		Weight::from_parts(68_547_000, 0)
-			.saturating_add(Weight::from_parts(0, 5777))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(3))
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::add_ed25519_key_agreement_key()
 	}
-	/// Storage: Did Did (r:1 w:1)
-	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	/// Storage: Did DidEndpointsCount (r:1 w:0)
-	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	/// Storage: Balances Holds (r:1 w:1)
-	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
 	fn add_sr25519_key_agreement_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2032`
-		//  Estimated: `5777`
-		// Minimum execution time: 66_732_000 picoseconds.
-		Weight::from_parts(68_324_000, 0)
-			.saturating_add(Weight::from_parts(0, 5777))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(3))
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::add_sr25519_key_agreement_key()
 	}
-	/// Storage: Did Did (r:1 w:1)
-	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	/// Storage: Did DidEndpointsCount (r:1 w:0)
-	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	/// Storage: Balances Holds (r:1 w:1)
-	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
 	fn add_ecdsa_key_agreement_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2001`
-		//  Estimated: `5777`
-		// Minimum execution time: 66_688_000 picoseconds.
-		Weight::from_parts(68_169_000, 0)
-			.saturating_add(Weight::from_parts(0, 5777))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(3))
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::add_ecdsa_key_agreement_key()
 	}
-	/// Storage: Did Did (r:1 w:1)
-	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	/// Storage: Did DidEndpointsCount (r:1 w:0)
-	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	/// Storage: Balances Holds (r:1 w:1)
-	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
 	fn remove_ed25519_key_agreement_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2170`
-		//  Estimated: `5777`
-		// Minimum execution time: 65_451_000 picoseconds.
-		Weight::from_parts(67_307_000, 0)
-			.saturating_add(Weight::from_parts(0, 5777))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(3))
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::remove_ed25519_key_agreement_key()
 	}
-	/// Storage: Did Did (r:1 w:1)
-	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	/// Storage: Did DidEndpointsCount (r:1 w:0)
-	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	/// Storage: Balances Holds (r:1 w:1)
-	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
 	fn remove_sr25519_key_agreement_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2170`
-		//  Estimated: `5777`
-		// Minimum execution time: 65_532_000 picoseconds.
-		Weight::from_parts(67_332_000, 0)
-			.saturating_add(Weight::from_parts(0, 5777))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(3))
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::remove_sr25519_key_agreement_key()
 	}
-	/// Storage: Did Did (r:1 w:1)
-	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	/// Storage: Did DidEndpointsCount (r:1 w:0)
-	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	/// Storage: Balances Holds (r:1 w:1)
-	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
 	fn remove_ecdsa_key_agreement_key() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2141`
-		//  Estimated: `5777`
-		// Minimum execution time: 66_150_000 picoseconds.
-		Weight::from_parts(66_989_000, 0)
-			.saturating_add(Weight::from_parts(0, 5777))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(3))
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::remove_ecdsa_key_agreement_key()
 	}
-	/// Storage: Did Did (r:1 w:1)
-	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	/// Storage: Did DidEndpointsCount (r:1 w:1)
-	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
-	/// Storage: Did ServiceEndpoints (r:1 w:1)
-	/// Proof: Did ServiceEndpoints (max_values: None, max_size: Some(615), added: 3090, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	/// Storage: Balances Holds (r:1 w:1)
-	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
 	fn add_service_endpoint() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1438`
-		//  Estimated: `5777`
-		// Minimum execution time: 74_910_000 picoseconds.
-		Weight::from_parts(76_673_000, 0)
-			.saturating_add(Weight::from_parts(0, 5777))
-			.saturating_add(T::DbWeight::get().reads(5))
-			.saturating_add(T::DbWeight::get().writes(5))
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::add_service_endpoint()
 	}
-	/// Storage: Did Did (r:1 w:1)
-	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	/// Storage: Did ServiceEndpoints (r:1 w:1)
-	/// Proof: Did ServiceEndpoints (max_values: None, max_size: Some(615), added: 3090, mode: MaxEncodedLen)
-	/// Storage: Did DidEndpointsCount (r:1 w:1)
-	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	/// Storage: Balances Holds (r:1 w:1)
-	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
 	fn remove_service_endpoint() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2088`
-		//  Estimated: `5777`
-		// Minimum execution time: 73_804_000 picoseconds.
-		Weight::from_parts(75_667_000, 0)
-			.saturating_add(Weight::from_parts(0, 5777))
-			.saturating_add(T::DbWeight::get().reads(5))
-			.saturating_add(T::DbWeight::get().writes(5))
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::remove_service_endpoint()
 	}
-	/// Storage: Did Did (r:1 w:0)
-	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	/// The range of component `l` is `[1, 5242880]`.
-	fn signature_verification_sr25519(l: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1626`
-		//  Estimated: `5777`
-		// Minimum execution time: 63_693_000 picoseconds.
-		Weight::from_parts(33_416_355, 0)
-			.saturating_add(Weight::from_parts(0, 5777))
-			// Standard Error: 0
-			.saturating_add(Weight::from_parts(4_311, 0).saturating_mul(l.into()))
-			.saturating_add(T::DbWeight::get().reads(1))
+	fn change_deposit_owner() -> Weight {
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::change_deposit_owner()
 	}
-	/// Storage: Did Did (r:1 w:0)
-	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	/// The range of component `l` is `[1, 5242880]`.
-	fn signature_verification_ed25519(l: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1626`
-		//  Estimated: `5777`
-		// Minimum execution time: 62_149_000 picoseconds.
-		Weight::from_parts(36_450_104, 0)
-			.saturating_add(Weight::from_parts(0, 5777))
-			// Standard Error: 0
-			.saturating_add(Weight::from_parts(2_467, 0).saturating_mul(l.into()))
-			.saturating_add(T::DbWeight::get().reads(1))
+	fn update_deposit() -> Weight {
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::update_deposit()
 	}
-	/// Storage: Did Did (r:1 w:0)
-	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	/// The range of component `l` is `[1, 5242880]`.
-	fn signature_verification_ecdsa(l: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1627`
-		//  Estimated: `5777`
-		// Minimum execution time: 50_967_000 picoseconds.
-		Weight::from_parts(23_952_757, 0)
-			.saturating_add(Weight::from_parts(0, 5777))
-			// Standard Error: 0
-			.saturating_add(Weight::from_parts(1_158, 0).saturating_mul(l.into()))
-			.saturating_add(T::DbWeight::get().reads(1))
+	fn create_from_account() -> Weight {
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::create_from_account()
 	}
-	/// Storage: Did Did (r:1 w:1)
-	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	/// Storage: Balances Holds (r:1 w:1)
-	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
-	fn change_deposit_owner() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `883`
-		//  Estimated: `5777`
-		// Minimum execution time: 74_268_000 picoseconds.
-		Weight::from_parts(75_478_000, 0)
-			.saturating_add(Weight::from_parts(0, 5777))
-			.saturating_add(T::DbWeight::get().reads(3))
-			.saturating_add(T::DbWeight::get().writes(3))
+	fn add_hashed_service_endpoint() -> Weight {
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::add_hashed_service_endpoint()
 	}
-	/// Storage: Did Did (r:1 w:1)
-	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	/// Storage: Did DidEndpointsCount (r:1 w:0)
-	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	/// Storage: Balances Holds (r:1 w:1)
-	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
-	fn update_deposit() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `888`
-		//  Estimated: `5777`
-		// Minimum execution time: 45_191_000 picoseconds.
-		Weight::from_parts(46_236_000, 0)
-			.saturating_add(Weight::from_parts(0, 5777))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(3))
+	fn did_remark(l: u32, ) -> Weight {
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::did_remark(l)
 	}
-
-	/// Storage: Did Did (r:1 w:0)
-	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	fn dispatch_as() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `353`
-		//  Estimated: `5777`
-		// Minimum execution time: 186_836_000 picoseconds.
-		Weight::from_parts(189_377_000, 0)
-			.saturating_add(Weight::from_parts(0, 5777))
-			.saturating_add(T::DbWeight::get().reads(1))
+	fn endorse() -> Weight {
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::endorse()
 	}
-	/// Storage: Did DidBlacklist (r:1 w:0)
-	/// Proof: Did DidBlacklist (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
-	/// Storage: Did Did (r:1 w:1)
-	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
-	/// Storage: Did DidEndpointsCount (r:1 w:0)
-	/// Proof: Did DidEndpointsCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
-	/// Storage: System Account (r:2 w:2)
-	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	/// Storage: Balances Holds (r:1 w:1)
-	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
-	fn create_from_account() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `999`
-		//  Estimated: `6204`
-		// Minimum execution time: 1_008_739_000 picoseconds.
-		Weight::from_parts(1_020_810_000, 0)
-			.saturating_add(Weight::from_parts(0, 6204))
-			.saturating_add(T::DbWeight::get().reads(6))
-			.saturating_add(T::DbWeight::get().writes(4))
+	fn revoke_endorsement() -> Weight {
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::revoke_endorsement()
+	}
+	fn add_also_known_as() -> Weight {
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::add_also_known_as()
+	}
+	fn remove_also_known_as() -> Weight {
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::remove_also_known_as()
+	}
+	fn backfill_key_history_step(n: u32, ) -> Weight {
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::backfill_key_history_step(n)
+	}
+	fn reprice_did_deposit_step() -> Weight {
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::reprice_did_deposit_step()
+	}
+	fn initialize_did_counter_step() -> Weight {
+		<did::default_weights::SubstrateWeight<T> as did::WeightInfo>::initialize_did_counter_step()
 	}
 }
 
@@ -48,99 +48,54 @@ use sp_std::marker::PhantomData;
 /// Weights for `pallet_did_lookup`.
 pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> pallet_did_lookup::WeightInfo for WeightInfo<T> {
-	// Storage: System Account (r:1 w:1)
-	// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	// Storage: DidLookup ConnectedDids (r:1 w:1)
-	// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
-	// Storage: DidLookup ConnectedAccounts (r:0 w:2)
-	// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	// The calls below are not covered by the benchmark run above and use the
+	// estimated weights of the pallet.
 	fn associate_account_multisig_sr25519() -> Weight {
-		Weight::from_parts(104_704_000 as u64, 0)
-			.saturating_add(T::DbWeight::get().reads(2 as u64))
-			.saturating_add(T::DbWeight::get().writes(4 as u64))
-	}
-	// Storage: System Account (r:1 w:1)
-	// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	// Storage: DidLookup ConnectedDids (r:1 w:1)
-	// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
-	// Storage: DidLookup ConnectedAccounts (r:0 w:2)
-	// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+		<pallet_did_lookup::default_weights::SubstrateWeight<T> as pallet_did_lookup::WeightInfo>::associate_account_multisig_sr25519()
+	}
 	fn associate_account_multisig_ed25519() -> Weight {
-		Weight::from_parts(103_900_000 as u64, 0)
-			.saturating_add(T::DbWeight::get().reads(2 as u64))
-			.saturating_add(T::DbWeight::get().writes(4 as u64))
-	}
-	// Storage: System Account (r:1 w:1)
-	// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	// Storage: DidLookup ConnectedDids (r:1 w:1)
-	// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
-	// Storage: DidLookup ConnectedAccounts (r:0 w:2)
-	// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+		<pallet_did_lookup::default_weights::SubstrateWeight<T> as pallet_did_lookup::WeightInfo>::associate_account_multisig_ed25519()
+	}
 	fn associate_account_multisig_ecdsa() -> Weight {
-		Weight::from_parts(91_681_000 as u64, 0)
-			.saturating_add(T::DbWeight::get().reads(2 as u64))
-			.saturating_add(T::DbWeight::get().writes(4 as u64))
-	}
-	// Storage: System Account (r:1 w:1)
-	// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	// Storage: DidLookup ConnectedDids (r:1 w:1)
-	// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
-	// Storage: DidLookup ConnectedAccounts (r:0 w:2)
-	// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+		<pallet_did_lookup::default_weights::SubstrateWeight<T> as pallet_did_lookup::WeightInfo>::associate_account_multisig_ecdsa()
+	}
 	fn associate_eth_account() -> Weight {
-		Weight::from_parts(93_205_000 as u64, 0)
-			.saturating_add(T::DbWeight::get().reads(2 as u64))
-			.saturating_add(T::DbWeight::get().writes(4 as u64))
-	}
-	// Storage: System Account (r:1 w:1)
-	// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	// Storage: DidLookup ConnectedDids (r:1 w:1)
-	// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
-	// Storage: DidLookup ConnectedAccounts (r:0 w:2)
-	// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+		<pallet_did_lookup::default_weights::SubstrateWeight<T> as pallet_did_lookup::WeightInfo>::associate_eth_account()
+	}
+	fn associate_eth_contract_account() -> Weight {
+		<pallet_did_lookup::default_weights::SubstrateWeight<T> as pallet_did_lookup::WeightInfo>::associate_eth_contract_account()
+	}
+	fn associate_solana_account() -> Weight {
+		<pallet_did_lookup::default_weights::SubstrateWeight<T> as pallet_did_lookup::WeightInfo>::associate_solana_account()
+	}
+	fn associate_bitcoin_account() -> Weight {
+		<pallet_did_lookup::default_weights::SubstrateWeight<T> as pallet_did_lookup::WeightInfo>::associate_bitcoin_account()
+	}
 	fn associate_sender() -> Weight {
-		Weight::from_parts(44_691_000 as u64, 0)
-			.saturating_add(T::DbWeight::get().reads(2 as u64))
-			.saturating_add(T::DbWeight::get().writes(4 as u64))
-	}
-	// Storage: DidLookup ConnectedDids (r:1 w:1)
-	// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
-	// Storage: System Account (r:1 w:1)
-	// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	// Storage: DidLookup ConnectedAccounts (r:0 w:1)
-	// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+		<pallet_did_lookup::default_weights::SubstrateWeight<T> as pallet_did_lookup::WeightInfo>::associate_sender()
+	}
 	fn remove_sender_association() -> Weight {
-		Weight::from_parts(28_757_000 as u64, 0)
-			.saturating_add(T::DbWeight::get().reads(2 as u64))
-			.saturating_add(T::DbWeight::get().writes(3 as u64))
-	}
-	// Storage: DidLookup ConnectedDids (r:1 w:1)
-	// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
-	// Storage: System Account (r:1 w:1)
-	// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	// Storage: DidLookup ConnectedAccounts (r:0 w:1)
-	// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+		<pallet_did_lookup::default_weights::SubstrateWeight<T> as pallet_did_lookup::WeightInfo>::remove_sender_association()
+	}
 	fn remove_account_association() -> Weight {
-		Weight::from_parts(30_440_000 as u64, 0)
-			.saturating_add(T::DbWeight::get().reads(2 as u64))
-			.saturating_add(T::DbWeight::get().writes(3 as u64))
-	}
-	// Storage: DidLookup ConnectedDids (r:1 w:1)
-	// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
-	// Storage: System Account (r:2 w:2)
-	// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+		<pallet_did_lookup::default_weights::SubstrateWeight<T> as pallet_did_lookup::WeightInfo>::remove_account_association()
+	}
 	fn change_deposit_owner() -> Weight {
-		Weight::from_parts(40_342_000 as u64, 0)
-			.saturating_add(T::DbWeight::get().reads(3 as u64))
-			.saturating_add(T::DbWeight::get().writes(3 as u64))
-	}
-	// Storage: DidLookup ConnectedDids (r:1 w:1)
-	// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
-	// Storage: System Account (r:1 w:1)
-	// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+		<pallet_did_lookup::default_weights::SubstrateWeight<T> as pallet_did_lookup::WeightInfo>::change_deposit_owner()
+	}
 	fn update_deposit() -> Weight {
-		Weight::from_parts(57_126_000 as u64, 0)
-			.saturating_add(T::DbWeight::get().reads(2 as u64))
-			.saturating_add(T::DbWeight::get().writes(2 as u64))
+		<pallet_did_lookup::default_weights::SubstrateWeight<T> as pallet_did_lookup::WeightInfo>::update_deposit()
+	}
+	fn remove_all_account_associations(n: u32, ) -> Weight {
+		<pallet_did_lookup::default_weights::SubstrateWeight<T> as pallet_did_lookup::WeightInfo>::remove_all_account_associations(n)
+	}
+	fn migrate_association() -> Weight {
+		<pallet_did_lookup::default_weights::SubstrateWeight<T> as pallet_did_lookup::WeightInfo>::migrate_association()
+	}
+	fn set_account_label() -> Weight {
+		<pallet_did_lookup::default_weights::SubstrateWeight<T> as pallet_did_lookup::WeightInfo>::set_account_label()
+	}
+	fn initialize_connected_dids_counter_step() -> Weight {
+		<pallet_did_lookup::default_weights::SubstrateWeight<T> as pallet_did_lookup::WeightInfo>::initialize_connected_dids_counter_step()
 	}
 }
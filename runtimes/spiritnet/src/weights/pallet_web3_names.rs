@@ -48,19 +48,6 @@ use sp_std::marker::PhantomData;
 /// Weights for `pallet_web3_names`.
 pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> pallet_web3_names::WeightInfo for WeightInfo<T> {
-	// Storage: Web3Names Names (r:1 w:1)
-	// Proof: Web3Names Names (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
-	// Storage: Web3Names Owner (r:1 w:1)
-	// Proof: Web3Names Owner (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
-	// Storage: Web3Names Banned (r:1 w:0)
-	// Proof: Web3Names Banned (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
-	// Storage: System Account (r:1 w:1)
-	// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	fn claim(_n: u32, ) -> Weight {
-		Weight::from_parts(40_237_964 as u64, 0)
-			.saturating_add(T::DbWeight::get().reads(4 as u64))
-			.saturating_add(T::DbWeight::get().writes(3 as u64))
-	}
 	// Storage: Web3Names Names (r:1 w:1)
 	// Proof: Web3Names Names (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
 	// Storage: Web3Names Owner (r:1 w:1)
@@ -87,42 +74,62 @@ impl<T: frame_system::Config> pallet_web3_names::WeightInfo for WeightInfo<T> {
 	}
 	// Storage: Web3Names Banned (r:1 w:1)
 	// Proof: Web3Names Banned (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
-	// Storage: Web3Names Owner (r:1 w:1)
-	// Proof: Web3Names Owner (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
-	// Storage: System Account (r:1 w:1)
-	// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	// Storage: Web3Names Names (r:0 w:1)
-	// Proof: Web3Names Names (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
-	fn ban(_n: u32, ) -> Weight {
-		Weight::from_parts(43_007_574 as u64, 0)
-			.saturating_add(T::DbWeight::get().reads(3 as u64))
-			.saturating_add(T::DbWeight::get().writes(4 as u64))
-	}
-	// Storage: Web3Names Banned (r:1 w:1)
-	// Proof: Web3Names Banned (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
 	fn unban(_n: u32, ) -> Weight {
 		Weight::from_parts(17_954_246 as u64, 0)
 			.saturating_add(T::DbWeight::get().reads(1 as u64))
 			.saturating_add(T::DbWeight::get().writes(1 as u64))
 	}
-	// Storage: Web3Names Names (r:1 w:0)
-	// Proof: Web3Names Names (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
-	// Storage: Web3Names Owner (r:1 w:1)
-	// Proof: Web3Names Owner (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
-	// Storage: System Account (r:2 w:2)
-	// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	// The calls below are not covered by the benchmark run above and use the
+	// estimated weights of the pallet.
+	fn claim(n: u32, ) -> Weight {
+		<pallet_web3_names::default_weights::SubstrateWeight<T> as pallet_web3_names::WeightInfo>::claim(n)
+	}
+	fn ban(n: u32, ) -> Weight {
+		<pallet_web3_names::default_weights::SubstrateWeight<T> as pallet_web3_names::WeightInfo>::ban(n)
+	}
 	fn change_deposit_owner() -> Weight {
-		Weight::from_parts(66_017_000 as u64, 0)
-			.saturating_add(T::DbWeight::get().reads(4 as u64))
-			.saturating_add(T::DbWeight::get().writes(3 as u64))
+		<pallet_web3_names::default_weights::SubstrateWeight<T> as pallet_web3_names::WeightInfo>::change_deposit_owner()
 	}
-	// Storage: Web3Names Owner (r:1 w:1)
-	// Proof: Web3Names Owner (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
-	// Storage: System Account (r:1 w:1)
-	// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	fn update_deposit() -> Weight {
-		Weight::from_parts(57_911_000 as u64, 0)
-			.saturating_add(T::DbWeight::get().reads(2 as u64))
-			.saturating_add(T::DbWeight::get().writes(2 as u64))
+		<pallet_web3_names::default_weights::SubstrateWeight<T> as pallet_web3_names::WeightInfo>::update_deposit()
+	}
+	fn appeal_ban(n: u32, ) -> Weight {
+		<pallet_web3_names::default_weights::SubstrateWeight<T> as pallet_web3_names::WeightInfo>::appeal_ban(n)
+	}
+	fn resolve_ban_appeal(n: u32, ) -> Weight {
+		<pallet_web3_names::default_weights::SubstrateWeight<T> as pallet_web3_names::WeightInfo>::resolve_ban_appeal(n)
+	}
+	fn cancel_ban(n: u32, ) -> Weight {
+		<pallet_web3_names::default_weights::SubstrateWeight<T> as pallet_web3_names::WeightInfo>::cancel_ban(n)
+	}
+	fn enact_bans(n: u32, ) -> Weight {
+		<pallet_web3_names::default_weights::SubstrateWeight<T> as pallet_web3_names::WeightInfo>::enact_bans(n)
+	}
+	fn claim_batch(n: u32, ) -> Weight {
+		<pallet_web3_names::default_weights::SubstrateWeight<T> as pallet_web3_names::WeightInfo>::claim_batch(n)
+	}
+	fn approve_registrar() -> Weight {
+		<pallet_web3_names::default_weights::SubstrateWeight<T> as pallet_web3_names::WeightInfo>::approve_registrar()
+	}
+	fn remove_registrar() -> Weight {
+		<pallet_web3_names::default_weights::SubstrateWeight<T> as pallet_web3_names::WeightInfo>::remove_registrar()
+	}
+	fn claim_by_registrar(n: u32, ) -> Weight {
+		<pallet_web3_names::default_weights::SubstrateWeight<T> as pallet_web3_names::WeightInfo>::claim_by_registrar(n)
+	}
+	fn set_record() -> Weight {
+		<pallet_web3_names::default_weights::SubstrateWeight<T> as pallet_web3_names::WeightInfo>::set_record()
+	}
+	fn clear_record() -> Weight {
+		<pallet_web3_names::default_weights::SubstrateWeight<T> as pallet_web3_names::WeightInfo>::clear_record()
+	}
+	fn accept_pending_claim() -> Weight {
+		<pallet_web3_names::default_weights::SubstrateWeight<T> as pallet_web3_names::WeightInfo>::accept_pending_claim()
+	}
+	fn decline_pending_claim() -> Weight {
+		<pallet_web3_names::default_weights::SubstrateWeight<T> as pallet_web3_names::WeightInfo>::decline_pending_claim()
+	}
+	fn initialize_owner_counter_step() -> Weight {
+		<pallet_web3_names::default_weights::SubstrateWeight<T> as pallet_web3_names::WeightInfo>::initialize_owner_counter_step()
 	}
 }
@@ -552,4 +552,34 @@ impl<T: frame_system::Config> parachain_staking::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().writes((25 as u64).saturating_mul(n as u64)))
 			.saturating_add(T::DbWeight::get().writes((51 as u64).saturating_mul(m as u64)))
 	}
+	fn linked_accounts_stake_more(l: u32, ) -> Weight {
+		// Bounded by the worst case of the benchmarked `delegator_stake_more` and the
+		// lookup of the linked account, once per account.
+		<Self as parachain_staking::WeightInfo>::delegator_stake_more(75, 34, 9)
+			.saturating_add(Weight::from_parts(0, 2572))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_mul(l.into())
+	}
+	fn linked_accounts_leave_delegators(l: u32, ) -> Weight {
+		// Bounded by the worst case of the benchmarked `leave_delegators` and the
+		// lookup of the linked account, once per account.
+		<Self as parachain_staking::WeightInfo>::leave_delegators(75, 34)
+			.saturating_add(Weight::from_parts(0, 2572))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_mul(l.into())
+	}
+	// The calls below are not covered by the benchmark run above and use the
+	// estimated weights of the pallet.
+	fn unstake_instantly(u: u32, ) -> Weight {
+		<parachain_staking::default_weights::SubstrateWeight<T> as parachain_staking::WeightInfo>::unstake_instantly(u)
+	}
+	fn merge_unstaking(u: u32, ) -> Weight {
+		<parachain_staking::default_weights::SubstrateWeight<T> as parachain_staking::WeightInfo>::merge_unstaking(u)
+	}
+	fn transfer_delegation() -> Weight {
+		<parachain_staking::default_weights::SubstrateWeight<T> as parachain_staking::WeightInfo>::transfer_delegation()
+	}
+	fn set_session_keys() -> Weight {
+		<parachain_staking::default_weights::SubstrateWeight<T> as parachain_staking::WeightInfo>::set_session_keys()
+	}
 }
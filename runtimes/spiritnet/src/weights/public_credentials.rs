@@ -81,28 +81,6 @@ impl<T: frame_system::Config> public_credentials::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(2 as u64))
 			.saturating_add(T::DbWeight::get().writes(1 as u64))
 	}
-	// Storage: PublicCredentials CredentialSubjects (r:1 w:1)
-	// Proof: PublicCredentials CredentialSubjects (max_values: None, max_size: Some(312), added: 2787, mode: MaxEncodedLen)
-	// Storage: PublicCredentials Credentials (r:1 w:1)
-	// Proof: PublicCredentials Credentials (max_values: None, max_size: Some(475), added: 2950, mode: MaxEncodedLen)
-	// Storage: System Account (r:1 w:1)
-	// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	fn remove() -> Weight {
-		Weight::from_parts(45_902_000 as u64, 0)
-			.saturating_add(T::DbWeight::get().reads(3 as u64))
-			.saturating_add(T::DbWeight::get().writes(3 as u64))
-	}
-	// Storage: PublicCredentials CredentialSubjects (r:1 w:1)
-	// Proof: PublicCredentials CredentialSubjects (max_values: None, max_size: Some(312), added: 2787, mode: MaxEncodedLen)
-	// Storage: PublicCredentials Credentials (r:1 w:1)
-	// Proof: PublicCredentials Credentials (max_values: None, max_size: Some(475), added: 2950, mode: MaxEncodedLen)
-	// Storage: System Account (r:1 w:1)
-	// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
-	fn reclaim_deposit() -> Weight {
-		Weight::from_parts(68_877_000 as u64, 0)
-			.saturating_add(T::DbWeight::get().reads(3 as u64))
-			.saturating_add(T::DbWeight::get().writes(3 as u64))
-	}
 	// Storage: PublicCredentials CredentialSubjects (r:1 w:0)
 	// Proof: PublicCredentials CredentialSubjects (max_values: None, max_size: Some(312), added: 2787, mode: MaxEncodedLen)
 	// Storage: PublicCredentials Credentials (r:1 w:1)
@@ -125,4 +103,21 @@ impl<T: frame_system::Config> public_credentials::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(3 as u64))
 			.saturating_add(T::DbWeight::get().writes(2 as u64))
 	}
+	// The calls below are not covered by the benchmark run above and use the
+	// estimated weights of the pallet.
+	fn remove() -> Weight {
+		<public_credentials::default_weights::SubstrateWeight<T> as public_credentials::WeightInfo>::remove()
+	}
+	fn reclaim_deposit() -> Weight {
+		<public_credentials::default_weights::SubstrateWeight<T> as public_credentials::WeightInfo>::reclaim_deposit()
+	}
+	fn set_subject_controller() -> Weight {
+		<public_credentials::default_weights::SubstrateWeight<T> as public_credentials::WeightInfo>::set_subject_controller()
+	}
+	fn set_credential_visibility() -> Weight {
+		<public_credentials::default_weights::SubstrateWeight<T> as public_credentials::WeightInfo>::set_credential_visibility()
+	}
+	fn initialize_credential_subjects_counter_step() -> Weight {
+		<public_credentials::default_weights::SubstrateWeight<T> as public_credentials::WeightInfo>::initialize_credential_subjects_counter_step()
+	}
 }
@@ -17,27 +17,31 @@
 // If you feel like getting in touch with us, you can do so at info@botlabs.org
 
 use super::{
-	AccountId, AllPalletsWithSystem, Balances, ParachainInfo, ParachainSystem, PolkadotXcm, Runtime, RuntimeCall,
-	RuntimeEvent, RuntimeOrigin, Treasury, WeightToFee, XcmpQueue,
+	AccountId, AllPalletsWithSystem, Assets, Balances, ForeignAssets, ForeignAssetsInstance, ParachainInfo,
+	ParachainSystem, PolkadotXcm, Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin, Treasury, TreasuryAccount,
+	WeightToFee, XcmpQueue,
 };
 
 use frame_support::{
 	parameter_types,
-	traits::{Contains, Nothing},
+	traits::{Contains, Nothing, PalletInfoAccess},
 };
 use frame_system::EnsureRoot;
 use pallet_xcm::XcmPassthrough;
 use sp_core::ConstU32;
+use sp_runtime::traits::ConvertInto;
 use xcm::v3::prelude::*;
 use xcm_builder::{
 	AllowTopLevelPaidExecutionFrom, EnsureXcmOrigin, FixedWeightBounds, RelayChainAsNative, SiblingParachainAsNative,
-	SignedAccountId32AsNative, SignedToAccountId32, UsingComponents, WithComputedOrigin,
+	SignedAccountId32AsNative, SignedToAccountId32, SovereignSignedViaLocation, UsingComponents, WithComputedOrigin,
 };
 use xcm_executor::{traits::WithOriginFilter, XcmExecutor};
 
 use runtime_common::xcm_config::{
-	DenyReserveTransferToRelayChain, DenyThenTry, HereLocation, LocalAssetTransactor, LocationToAccountId,
-	MaxAssetsIntoHolding, MaxInstructions, ParentLegislative, UnitWeightCost,
+	AssetHub, AssetHubLocation, DenyIdentityTransactsWhilePaused, DenyReserveTransferToRelayChain, DenyThenTry,
+	ForeignAssetsTrader, ForeignFungiblesTransactor, HereLocation, IsAssetHubReserve, LocalAssetTransactor,
+	LocalFungiblesTransactor, LocationToAccountId, MaxAssetsIntoHolding, MaxInstructions,
+	OnlyNativeAssetReserveTransfers, ParentLegislative, SiblingAccountsOnly, SiblingParachainAccount, UnitWeightCost,
 };
 
 parameter_types! {
@@ -46,6 +50,8 @@ parameter_types! {
 	pub const RelayNetworkId: Option<NetworkId> = Some(NetworkId::Polkadot);
 	pub UniversalLocation: InteriorMultiLocation =
 		Parachain(ParachainInfo::parachain_id().into()).into();
+	// The location of the assets pallet, under which the local fungible assets are registered.
+	pub AssetsPalletLocation: MultiLocation = PalletInstance(<Assets as PalletInfoAccess>::index() as u8).into();
 }
 
 /// This is the type we use to convert an (incoming) XCM origin into a local
@@ -54,8 +60,9 @@ parameter_types! {
 /// `Origin` it will become.
 pub type XcmOriginToTransactDispatchOrigin = (
 	// We don't include `SovereignSignedViaLocation<LocationToAccountId, RuntimeOrigin>` since we don't want to allow
-	// other chains to manage accounts on our network.
-
+	// other chains to manage accounts on our network. Only the accounts of users on sibling parachains, e.g., after
+	// a `DescendOrigin`, are converted into the usual `Signed` origin of their derived account.
+	SovereignSignedViaLocation<SiblingAccountsOnly<LocationToAccountId<RelayNetworkId>>, RuntimeOrigin>,
 	// Native converter for Relay-chain (Parent) location which converts to a `Relay` origin when
 	// recognized.
 	RelayChainAsNative<RelayChainOrigin, RuntimeOrigin>,
@@ -69,22 +76,52 @@ pub type XcmOriginToTransactDispatchOrigin = (
 	XcmPassthrough<RuntimeOrigin>,
 );
 
-/// Explicitly deny ReserveTransfer to the relay chain. Allow calls from the
-/// relay chain governance.
+/// Explicitly deny ReserveTransfer to the relay chain, and identity operations
+/// while they are paused by governance. Allow calls from the relay chain
+/// governance.
 pub type XcmBarrier = DenyThenTry<
 	DenyReserveTransferToRelayChain,
-	WithComputedOrigin<
-		(
-			// We allow everything from the relay chain if it was sent by the relay chain legislative (i.e., democracy
-			// vote). Since the relaychain doesn't own KILTs and missing fees shouldn't prevent calls from the
-			// relaychain legislative, we allow unpaid execution.
-			AllowTopLevelPaidExecutionFrom<ParentLegislative>,
-		),
-		UniversalLocation,
-		ConstU32<8>,
+	DenyThenTry<
+		DenyIdentityTransactsWhilePaused<
+			pallet_configuration::IsXcmIdentityOpsPaused<Runtime>,
+			RuntimeCall,
+			IdentityCalls,
+		>,
+		WithComputedOrigin<
+			(
+				// We allow everything from the relay chain if it was sent by the relay chain legislative (i.e.,
+				// democracy vote). Since the relaychain doesn't own KILTs and missing fees shouldn't prevent calls
+				// from the relaychain legislative, we allow unpaid execution.
+				AllowTopLevelPaidExecutionFrom<ParentLegislative>,
+				// We allow paid execution from AssetHub, e.g., to receive reserve-transferred foreign assets.
+				AllowTopLevelPaidExecutionFrom<AssetHub>,
+				// We allow paid execution from accounts on sibling parachains, e.g., to submit DID calls remotely.
+				AllowTopLevelPaidExecutionFrom<SiblingParachainAccount>,
+			),
+			UniversalLocation,
+			ConstU32<8>,
+		>,
 	>,
 >;
 
+/// The calls to identity pallets, which are refused via XCM `Transact` while
+/// paused with `pause_xcm_identity_ops`.
+pub struct IdentityCalls;
+impl Contains<RuntimeCall> for IdentityCalls {
+	fn contains(call: &RuntimeCall) -> bool {
+		matches!(
+			call,
+			RuntimeCall::Attestation { .. }
+				| RuntimeCall::Ctype { .. }
+				| RuntimeCall::Delegation { .. }
+				| RuntimeCall::Did { .. }
+				| RuntimeCall::DidLookup { .. }
+				| RuntimeCall::PublicCredentials { .. }
+				| RuntimeCall::Web3Names { .. }
+		)
+	}
+}
+
 /// A call filter for the XCM Transact instruction. This is a temporary measure
 /// until we properly account for proof size weights.
 ///
@@ -93,10 +130,38 @@ pub type XcmBarrier = DenyThenTry<
 /// 2. Cannot lead to another call being made;
 /// 3. Have a defined proof size weight, e.g. no unbounded vecs in call
 /// parameters.
+///
+/// The only allowed calls are the identity operations that accounts on sibling
+/// parachains can perform on behalf of a DID, see [`RemoteDidCalls`]. They
+/// must be wrapped in a `submit_did_call`, so that they are authorized by a
+/// signature of the DID key. Linking an account to a DID does not give the
+/// account any authority over the DID.
 pub struct SafeCallFilter;
 impl Contains<RuntimeCall> for SafeCallFilter {
-	fn contains(_call: &RuntimeCall) -> bool {
-		false
+	fn contains(call: &RuntimeCall) -> bool {
+		match call {
+			RuntimeCall::Did(did::Call::submit_did_call { did_call, .. }) => RemoteDidCalls::contains(&did_call.call),
+			_ => false,
+		}
+	}
+}
+
+/// The calls that can be submitted on behalf of a DID via XCM.
+pub struct RemoteDidCalls;
+impl Contains<RuntimeCall> for RemoteDidCalls {
+	fn contains(call: &RuntimeCall) -> bool {
+		matches!(
+			call,
+			RuntimeCall::Did(did::Call::add_hashed_service_endpoint { .. })
+				| RuntimeCall::Did(did::Call::add_service_endpoint { .. })
+				| RuntimeCall::Did(did::Call::remove_service_endpoint { .. })
+				| RuntimeCall::Did(did::Call::add_also_known_as { .. })
+				| RuntimeCall::Did(did::Call::remove_also_known_as { .. })
+				| RuntimeCall::DidLookup(pallet_did_lookup::Call::associate_sender { .. })
+				| RuntimeCall::DidLookup(pallet_did_lookup::Call::remove_sender_association { .. })
+				| RuntimeCall::Web3Names(pallet_web3_names::Call::claim { .. })
+				| RuntimeCall::Web3Names(pallet_web3_names::Call::release_by_owner { .. })
+		)
 	}
 }
 
@@ -105,11 +170,18 @@ impl xcm_executor::Config for XcmConfig {
 	type RuntimeCall = RuntimeCall;
 	// How we send Xcm messages.
 	type XcmSender = XcmRouter;
-	// How to withdraw and deposit an asset.
-	type AssetTransactor = LocalAssetTransactor<Balances, RelayNetworkId>;
+	// How to withdraw and deposit an asset: KILT via the balances pallet, the
+	// fungible assets issued on this chain via the assets pallet, and the foreign
+	// assets registered by governance via the foreign assets pallet.
+	type AssetTransactor = (
+		LocalAssetTransactor<Balances, RelayNetworkId>,
+		LocalFungiblesTransactor<Assets, AssetsPalletLocation, RelayNetworkId>,
+		ForeignFungiblesTransactor<ForeignAssets, RelayNetworkId>,
+	);
 	type OriginConverter = XcmOriginToTransactDispatchOrigin;
-	// Reserving is disabled.
-	type IsReserve = ();
+	// Only the relay chain token and the assets issued on AssetHub are accepted
+	// as reserve assets, and only if they come from AssetHub.
+	type IsReserve = IsAssetHubReserve<AssetHubLocation>;
 	// Teleporting is disabled.
 	type IsTeleporter = ();
 	type UniversalLocation = UniversalLocation;
@@ -121,7 +193,18 @@ impl xcm_executor::Config for XcmConfig {
 	// How weight is transformed into fees. The fees are not taken out of the
 	// Balances pallet here. Balances is only used if fees are dropped without being
 	// used. In that case they are put into the treasury.
-	type Trader = UsingComponents<WeightToFee<Runtime>, HereLocation, AccountId, Balances, Treasury>;
+	// Execution can also be bought with foreign assets, which are converted with
+	// the same rate used for transaction fees and deposited into the treasury.
+	type Trader = (
+		UsingComponents<WeightToFee<Runtime>, HereLocation, AccountId, Balances, Treasury>,
+		ForeignAssetsTrader<
+			ForeignAssets,
+			WeightToFee<Runtime>,
+			pallet_assets::BalanceToAssetBalance<Balances, Runtime, ConvertInto, ForeignAssetsInstance>,
+			RelayNetworkId,
+			TreasuryAccount,
+		>,
+	);
 	type ResponseHandler = PolkadotXcm;
 	// What happens with assets that are left in the register after the XCM message
 	// was processed. PolkadotXcm has an AssetTrap that stores a hash of the asset
@@ -170,7 +253,9 @@ impl pallet_xcm::Config for Runtime {
 	// NOTE: For local testing this needs to be `Everything`.
 	type XcmExecuteFilter = Nothing;
 	type XcmTeleportFilter = Nothing;
-	type XcmReserveTransferFilter = Nothing;
+	// Only KILT can be reserve-transferred, e.g., to AssetHub. It can be sent back
+	// from AssetHub, which is allowed by the barrier.
+	type XcmReserveTransferFilter = OnlyNativeAssetReserveTransfers;
 	type AdminOrigin = EnsureRoot<AccountId>;
 	type XcmExecutor = XcmExecutor<XcmConfig>;
 	type Weigher = FixedWeightBounds<UnitWeightCost, RuntimeCall, MaxInstructions>;
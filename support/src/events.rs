@@ -0,0 +1,98 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+//! Topics used to index the events emitted by the KILT identity pallets.
+//!
+//! All identity pallets (DIDs, web3names, account linking, CTypes,
+//! attestations, delegations and public credentials) deposit their events
+//! tagged with [`IDENTITY_EVENTS_TOPIC`], so that light clients and indexers
+//! can subscribe to identity activity only, e.g., via
+//! `frame_system::EventTopics`, instead of filtering all the events of a block.
+//!
+//! The topic is the Blake2-256 hash of [`IDENTITY_EVENTS_TOPIC_PREIMAGE`]. The
+//! preimage contains a version, which must be increased whenever a breaking
+//! change to the encoding of any identity event is introduced, so that
+//! subscribers never receive events they cannot decode.
+//!
+//! Pallets replace the `deposit_event` function generated by
+//! `#[pallet::generate_deposit]` with the one generated by
+//! [`impl_identity_event_deposit`](crate::impl_identity_event_deposit).
+
+use frame_support::traits::IsType;
+use hex_literal::hex;
+
+/// The preimage of the topic under which all identity events are deposited.
+pub const IDENTITY_EVENTS_TOPIC_PREIMAGE: &[u8] = b"kilt:identity:events:v1";
+
+/// The topic under which all identity events are deposited, i.e.,
+/// `blake2_256(IDENTITY_EVENTS_TOPIC_PREIMAGE)`.
+pub const IDENTITY_EVENTS_TOPIC: [u8; 32] = hex!("356d5dce5b535bcc4e79a828c403c797600fa7ccd4e7be04b0da359dc9329e0e");
+
+/// Returns [`IDENTITY_EVENTS_TOPIC`] as the hash type of the given runtime.
+pub fn identity_events_topic<T: frame_system::Config>() -> T::Hash {
+	let mut topic = T::Hash::default();
+	let topic_bytes = topic.as_mut();
+	let len = topic_bytes.len().min(IDENTITY_EVENTS_TOPIC.len());
+	topic_bytes[..len].copy_from_slice(&IDENTITY_EVENTS_TOPIC[..len]);
+	topic
+}
+
+/// Deposits the given pallet event tagged with [`IDENTITY_EVENTS_TOPIC`], by
+/// converting it into the pallet's `RuntimeEvent` and then into the one of
+/// `frame_system`.
+pub fn deposit_identity_event<T, RuntimeEvent>(event: impl Into<RuntimeEvent>)
+where
+	T: frame_system::Config,
+	RuntimeEvent: IsType<<T as frame_system::Config>::RuntimeEvent>,
+{
+	frame_system::Pallet::<T>::deposit_event_indexed(&[identity_events_topic::<T>()], event.into().into());
+}
+
+/// Implements `deposit_event` for the `Pallet` of the FRAME pallet it is
+/// invoked in, depositing the pallet's events via [`deposit_identity_event`].
+///
+/// The pallet must not use `#[pallet::generate_deposit]`, and its `Config`
+/// must define a `RuntimeEvent` convertible from its `Event`.
+#[macro_export]
+macro_rules! impl_identity_event_deposit {
+	() => {
+		impl<T: Config> Pallet<T> {
+			/// Deposit an event tagged with the identity events topic, as
+			/// described in [`kilt_support::events`].
+			pub(super) fn deposit_event(event: Event<T>) {
+				$crate::events::deposit_identity_event::<T, <T as Config>::RuntimeEvent>(event);
+			}
+		}
+	};
+}
+
+#[cfg(test)]
+mod tests {
+	use sp_core::blake2_256;
+
+	use super::*;
+
+	#[test]
+	fn identity_events_topic_is_stable() {
+		assert_eq!(IDENTITY_EVENTS_TOPIC, blake2_256(IDENTITY_EVENTS_TOPIC_PREIMAGE));
+		assert_eq!(
+			IDENTITY_EVENTS_TOPIC_PREIMAGE, b"kilt:identity:events:v1",
+			"The identity events topic must not change without a version bump."
+		);
+	}
+}
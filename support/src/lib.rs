@@ -19,8 +19,10 @@
 
 mod deposit;
 pub use deposit::Deposit;
+pub mod events;
 pub mod migration;
 pub mod signature;
+pub mod time;
 pub mod traits;
 
 #[cfg(any(feature = "runtime-benchmarks", feature = "mock"))]
@@ -20,10 +20,12 @@ use frame_support::{
 	pallet_prelude::DispatchResult,
 	traits::{
 		fungible::{hold::Mutate as MutateHold, Inspect},
-		ReservableCurrency,
+		GetStorageVersion, PalletInfoAccess, ReservableCurrency, StorageVersion,
 	},
+	weights::Weight,
 };
 use sp_runtime::{traits::Zero, Saturating};
+use sp_std::vec::Vec;
 
 #[cfg(any(feature = "mock", feature = "runtime-benchmarks"))]
 use pallet_balances::{Config, Holds, Pallet};
@@ -64,3 +66,169 @@ where
 		})
 	});
 }
+
+/// A storage migration of a single pallet which is too heavy to be executed
+/// within a single block, and is hence executed step by step over multiple
+/// blocks.
+///
+/// The migration is only executed if the on-chain storage version of the
+/// pallet is `FROM`, and it sets the storage version to `TO` once all steps
+/// have been executed.
+pub trait SteppedMigration {
+	/// The pallet whose storage is migrated.
+	type Pallet: GetStorageVersion + PalletInfoAccess;
+
+	/// The storage version the pallet must have for the migration to run.
+	const FROM: u16;
+	/// The storage version of the pallet after the migration.
+	const TO: u16;
+
+	/// The maximum weight consumed by a single call to `step`.
+	fn max_step_weight() -> Weight;
+
+	/// Execute the next step of the migration, starting after the provided
+	/// cursor, or at the beginning if no cursor is provided.
+	///
+	/// Returns the cursor to continue from in the next step, or `None` if
+	/// the migration has completed.
+	fn step(cursor: Option<Vec<u8>>) -> Option<Vec<u8>>;
+}
+
+/// A list of [`SteppedMigration`]s which are executed one after the other,
+/// addressed by their index in the list.
+pub trait SteppedMigrations {
+	/// The number of migrations in the list.
+	fn len() -> u32;
+
+	/// The name of the pallet migrated by the migration at the given index.
+	fn pallet_name(index: u32) -> Option<&'static str>;
+
+	/// Whether the migration at the given index still has to be executed.
+	fn should_run(index: u32) -> bool;
+
+	/// The storage version of the pallet after the migration at the given
+	/// index.
+	fn target_version(index: u32) -> Option<u16>;
+
+	/// The maximum weight of a single step of the migration at the given
+	/// index.
+	fn max_step_weight(index: u32) -> Option<Weight>;
+
+	/// Execute the next step of the migration at the given index. Returns
+	/// `None` if there is no migration at the index.
+	fn step(index: u32, cursor: Option<Vec<u8>>) -> Option<Option<Vec<u8>>>;
+
+	/// Mark the migration at the given index as completed by setting the
+	/// storage version of its pallet.
+	fn complete(index: u32);
+}
+
+/// Provides the storage keys migrated by a [`SteppedMigration`] which are
+/// accessed by a call, so that the call only has to be blocked until those
+/// keys have been migrated instead of until the whole migration of its pallet
+/// has completed.
+pub trait MigratedStorageKeys<Call> {
+	/// Returns the raw storage keys accessed by the call, in the key space of
+	/// the cursors of the stepped migrations of the pallet of the call, or
+	/// `None` if the keys cannot be derived from the call alone.
+	fn migrated_storage_keys(call: &Call) -> Option<Vec<Vec<u8>>>;
+}
+
+impl<Call> MigratedStorageKeys<Call> for () {
+	fn migrated_storage_keys(_call: &Call) -> Option<Vec<Vec<u8>>> {
+		None
+	}
+}
+
+impl SteppedMigrations for () {
+	fn len() -> u32 {
+		0
+	}
+
+	fn pallet_name(_index: u32) -> Option<&'static str> {
+		None
+	}
+
+	fn should_run(_index: u32) -> bool {
+		false
+	}
+
+	fn target_version(_index: u32) -> Option<u16> {
+		None
+	}
+
+	fn max_step_weight(_index: u32) -> Option<Weight> {
+		None
+	}
+
+	fn step(_index: u32, _cursor: Option<Vec<u8>>) -> Option<Option<Vec<u8>>> {
+		None
+	}
+
+	fn complete(_index: u32) {}
+}
+
+macro_rules! impl_stepped_migrations_for_tuples {
+	($($migration:ident => $index:literal),+) => {
+		impl<$($migration: SteppedMigration),+> SteppedMigrations for ($($migration,)+) {
+			fn len() -> u32 {
+				[$($index),+].len() as u32
+			}
+
+			fn pallet_name(index: u32) -> Option<&'static str> {
+				match index {
+					$($index => Some(<<$migration as SteppedMigration>::Pallet as PalletInfoAccess>::name()),)+
+					_ => None,
+				}
+			}
+
+			fn should_run(index: u32) -> bool {
+				match index {
+					$($index => <<$migration as SteppedMigration>::Pallet as GetStorageVersion>::on_chain_storage_version() == <$migration as SteppedMigration>::FROM,)+
+					_ => false,
+				}
+			}
+
+			fn target_version(index: u32) -> Option<u16> {
+				match index {
+					$($index => Some(<$migration as SteppedMigration>::TO),)+
+					_ => None,
+				}
+			}
+
+			fn max_step_weight(index: u32) -> Option<Weight> {
+				match index {
+					$($index => Some(<$migration as SteppedMigration>::max_step_weight()),)+
+					_ => None,
+				}
+			}
+
+			fn step(index: u32, cursor: Option<Vec<u8>>) -> Option<Option<Vec<u8>>> {
+				match index {
+					$($index => Some(<$migration as SteppedMigration>::step(cursor)),)+
+					_ => None,
+				}
+			}
+
+			fn complete(index: u32) {
+				match index {
+					$($index => StorageVersion::new(<$migration as SteppedMigration>::TO).put::<<$migration as SteppedMigration>::Pallet>(),)+
+					_ => {}
+				}
+			}
+		}
+	};
+}
+
+impl_stepped_migrations_for_tuples!(A => 0);
+impl_stepped_migrations_for_tuples!(A => 0, B => 1);
+impl_stepped_migrations_for_tuples!(A => 0, B => 1, C => 2);
+impl_stepped_migrations_for_tuples!(A => 0, B => 1, C => 2, D => 3);
+impl_stepped_migrations_for_tuples!(A => 0, B => 1, C => 2, D => 3, E => 4);
+impl_stepped_migrations_for_tuples!(A => 0, B => 1, C => 2, D => 3, E => 4, F => 5);
+impl_stepped_migrations_for_tuples!(A => 0, B => 1, C => 2, D => 3, E => 4, F => 5, G => 6);
+impl_stepped_migrations_for_tuples!(A => 0, B => 1, C => 2, D => 3, E => 4, F => 5, G => 6, H => 7);
+impl_stepped_migrations_for_tuples!(A => 0, B => 1, C => 2, D => 3, E => 4, F => 5, G => 6, H => 7, I => 8);
+impl_stepped_migrations_for_tuples!(A => 0, B => 1, C => 2, D => 3, E => 4, F => 5, G => 6, H => 7, I => 8, J => 9);
+impl_stepped_migrations_for_tuples!(A => 0, B => 1, C => 2, D => 3, E => 4, F => 5, G => 6, H => 7, I => 8, J => 9, K => 10);
+impl_stepped_migrations_for_tuples!(A => 0, B => 1, C => 2, D => 3, E => 4, F => 5, G => 6, H => 7, I => 8, J => 9, K => 10, L => 11);
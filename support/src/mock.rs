@@ -23,6 +23,15 @@ use scale_info::TypeInfo;
 use sp_core::sr25519;
 use sp_runtime::AccountId32;
 
+#[cfg(all(feature = "mock", feature = "std"))]
+mod events;
+#[cfg(all(feature = "mock", feature = "std"))]
+mod ext_builder;
+#[cfg(all(feature = "mock", feature = "std"))]
+pub use events::{extract_pallet_events, last_pallet_event, pallet_events};
+#[cfg(all(feature = "mock", feature = "std"))]
+pub use ext_builder::{register_keystore, MockExtBuilder};
+
 /// This pallet only contains an origin which supports separated sender and
 /// subject.
 ///
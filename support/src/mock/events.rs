@@ -0,0 +1,110 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+//! Helpers to inspect and assert on the events deposited in the test
+//! externalities of mock runtimes, so that pallet test suites do not need to
+//! filter the system events for their own pallet events.
+
+/// Return the events of type `E`, usually the events of a single pallet,
+/// deposited so far in the current block.
+pub fn pallet_events<T, E>() -> Vec<E>
+where
+	T: frame_system::Config,
+	T::RuntimeEvent: TryInto<E>,
+{
+	frame_system::Pallet::<T>::events()
+		.into_iter()
+		.filter_map(|record| record.event.try_into().ok())
+		.collect()
+}
+
+/// Return the last event of type `E` deposited in the current block, if any.
+pub fn last_pallet_event<T, E>() -> Option<E>
+where
+	T: frame_system::Config,
+	T::RuntimeEvent: TryInto<E>,
+{
+	pallet_events::<T, E>().pop()
+}
+
+/// Return the values extracted with `extract` from the events of type `E`
+/// deposited so far in the current block, skipping the events for which it
+/// returns `None`.
+///
+/// ```ignore
+/// let created_ids = extract_pallet_events::<Test, Event<Test>, _>(|event| match event {
+/// 	Event::Created { id, .. } => Some(id),
+/// 	_ => None,
+/// });
+/// ```
+pub fn extract_pallet_events<T, E, R>(extract: impl FnMut(E) -> Option<R>) -> Vec<R>
+where
+	T: frame_system::Config,
+	T::RuntimeEvent: TryInto<E>,
+{
+	pallet_events::<T, E>().into_iter().filter_map(extract).collect()
+}
+
+/// Assert that the last event of type `$event` deposited in the current
+/// block of the mock runtime `$runtime` matches the given pattern, with an
+/// optional guard.
+///
+/// ```ignore
+/// assert_last_event_matches!(Test, Event<Test>, Event::Created { id, .. } if id == expected_id);
+/// ```
+#[macro_export]
+macro_rules! assert_last_event_matches {
+	($runtime:ty, $event:ty, $pattern:pat $(if $guard:expr)? $(,)?) => {
+		match $crate::mock::last_pallet_event::<$runtime, $event>() {
+			Some($pattern) $(if $guard)? => {}
+			other => panic!(
+				"Expected the last event to match `{}`, found {:?}.",
+				stringify!($pattern $(if $guard)?),
+				other
+			),
+		}
+	};
+}
+
+/// Assert that exactly `$times` events of type `$event` matching the given
+/// pattern, with an optional guard, have been deposited in the current block
+/// of the mock runtime `$runtime`.
+///
+/// ```ignore
+/// assert_event_emitted_times!(Test, Event<Test>, Event::Created { .. }, 2);
+/// ```
+#[macro_export]
+macro_rules! assert_event_emitted_times {
+	($runtime:ty, $event:ty, $pattern:pat $(if $guard:expr)?, $times:expr $(,)?) => {{
+		let emitted = $crate::mock::pallet_events::<$runtime, $event>()
+			.into_iter()
+			.filter_map(|event| match event {
+				$pattern $(if $guard)? => Some(()),
+				_ => None,
+			})
+			.count();
+		assert_eq!(
+			emitted,
+			$times,
+			"Expected `{}` to be emitted {} times, found {}.",
+			stringify!($pattern $(if $guard)?),
+			$times,
+			emitted
+		);
+	}};
+}
@@ -0,0 +1,102 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+//! Reusable building blocks for the test externalities of mock runtimes, so
+//! that pallets and runtimes built on top of KILT do not need to copy the
+//! same genesis and keystore boilerplate into each of their mocks.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use sp_io::TestExternalities;
+use sp_keystore::{testing::MemoryKeystore, KeystoreExt};
+use sp_runtime::BuildStorage;
+
+/// A builder for the test externalities of the mock runtime `T`.
+///
+/// The genesis state is assembled from the genesis configs of the pallets
+/// of the runtime. The externalities start at block 1, since no events are
+/// recorded in the genesis block.
+pub struct MockExtBuilder<T> {
+	genesis: Vec<Box<dyn BuildStorage>>,
+	keystore: bool,
+	_runtime: PhantomData<T>,
+}
+
+impl<T> Default for MockExtBuilder<T> {
+	fn default() -> Self {
+		Self {
+			genesis: Vec::new(),
+			keystore: false,
+			_runtime: PhantomData,
+		}
+	}
+}
+
+impl<T: frame_system::Config> MockExtBuilder<T> {
+	/// Add the genesis config of a pallet to the genesis state.
+	#[must_use]
+	pub fn with_genesis(mut self, genesis: impl BuildStorage + 'static) -> Self {
+		self.genesis.push(Box::new(genesis));
+		self
+	}
+
+	/// Register an in-memory keystore, as required, e.g., by benchmarks that
+	/// generate signatures.
+	#[must_use]
+	pub fn with_keystore(mut self) -> Self {
+		self.keystore = true;
+		self
+	}
+
+	/// Build the test externalities.
+	pub fn build(self) -> TestExternalities {
+		let mut storage = frame_system::GenesisConfig::<T>::default()
+			.build_storage()
+			.expect("Failed to build the system genesis storage.");
+		for genesis in self.genesis {
+			genesis
+				.assimilate_storage(&mut storage)
+				.expect("Failed to build the genesis storage.");
+		}
+
+		let mut ext = TestExternalities::new(storage);
+		if self.keystore {
+			register_keystore(&mut ext);
+		}
+		ext.execute_with(|| frame_system::Pallet::<T>::set_block_number(1u32.into()));
+		ext
+	}
+
+	/// Build the test externalities and run the given test in them.
+	pub fn build_and_execute(self, test: impl FnOnce()) {
+		self.build().execute_with(test)
+	}
+}
+
+impl<T: pallet_balances::Config> MockExtBuilder<T> {
+	/// Endow the given accounts with the given free balances.
+	#[must_use]
+	pub fn with_balances(self, balances: Vec<(T::AccountId, T::Balance)>) -> Self {
+		self.with_genesis(pallet_balances::GenesisConfig::<T> { balances })
+	}
+}
+
+/// Register an in-memory keystore in the given externalities.
+pub fn register_keystore(ext: &mut TestExternalities) {
+	ext.register_extension(KeystoreExt(Arc::new(MemoryKeystore::new())));
+}
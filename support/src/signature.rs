@@ -17,10 +17,14 @@
 // If you feel like getting in touch with us, you can do so at info@botlabs.org
 
 use frame_support::dispatch::Weight;
-use scale_info::TypeInfo;
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::{prelude::string::ToString, TypeInfo};
+use sp_core::hashing::keccak_256;
+use sp_runtime::traits::{IdentifyAccount, Verify};
+use sp_std::{marker::PhantomData, vec::Vec};
 
-#[cfg(any(test, feature = "mock", feature = "runtime-benchmarks"))]
-use sp_std::marker::PhantomData;
+/// The prefix Ethereum wallets add to messages signed via `personal_sign`.
+pub const ETHEREUM_SIGNATURE_PREFIX: &[u8; 26] = b"\x19Ethereum Signed Message:\n";
 
 /// The Result of the signature verification.
 pub type SignatureVerificationResult = Result<(), SignatureVerificationError>;
@@ -55,6 +59,127 @@ pub trait VerifySignature {
 	fn weight(payload_byte_length: usize) -> Weight;
 }
 
+/// Wraps the payload the same way Ethereum wallets do for `personal_sign`
+/// (EIP-191), i.e., prefixed with the Ethereum prefix and the payload length.
+pub fn ethereum_personal_sign_payload(payload: &[u8]) -> Vec<u8> {
+	ETHEREUM_SIGNATURE_PREFIX
+		.iter()
+		// eth wrapping also contains the length of the payload
+		.chain(payload.len().to_string().as_bytes().iter())
+		.chain(payload.iter())
+		.copied()
+		.collect()
+}
+
+/// Verifies signatures that were generated with Ethereum's `personal_sign`
+/// over the payload.
+pub struct EthereumPersonalSignVerify<Signature>(PhantomData<Signature>);
+
+impl<Signature> VerifySignature for EthereumPersonalSignVerify<Signature>
+where
+	Signature: Verify,
+	Signature::Signer: IdentifyAccount,
+{
+	type SignerId = <Signature::Signer as IdentifyAccount>::AccountId;
+	type Payload = Vec<u8>;
+	type Signature = Signature;
+
+	fn verify(
+		signer: &Self::SignerId,
+		payload: &Self::Payload,
+		signature: &Self::Signature,
+	) -> SignatureVerificationResult {
+		if signature.verify(&ethereum_personal_sign_payload(payload)[..], signer) {
+			Ok(())
+		} else {
+			Err(SignatureVerificationError::SignatureInvalid)
+		}
+	}
+
+	// The signature recovery is accounted for in the benchmarks of the calling
+	// pallet.
+	fn weight(_: usize) -> Weight {
+		Weight::zero()
+	}
+}
+
+/// A signature of an ERC-1271 contract wallet, together with the proof that
+/// the contract accepts it.
+///
+/// Contract wallets can't sign a message themselves. Instead, the wallet owners
+/// approve the message (possibly over several transactions) and the contract's
+/// `isValidSignature` method returns the ERC-1271 magic value for it
+/// afterwards. The proof attests this result, e.g., in the form of a storage
+/// proof against a known Ethereum state root.
+#[derive(Clone, Debug, Eq, PartialEq, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub struct Erc1271Signature<Signature, Proof> {
+	/// The signature that is passed to the contract's `isValidSignature`.
+	pub signature: Signature,
+	/// The proof that the contract accepts the signature.
+	pub proof: Proof,
+}
+
+/// Checks that an ERC-1271 contract accepts a signature for a message hash.
+pub trait VerifyErc1271Proof<Contract, Signature, Proof> {
+	/// Verifies, based on the provided proof, that the contract's
+	/// `isValidSignature(hash, signature)` returns the ERC-1271 magic value.
+	fn verify_is_valid_signature(
+		contract: &Contract,
+		hash: &[u8; 32],
+		signature: &Signature,
+		proof: &Proof,
+	) -> SignatureVerificationResult;
+
+	/// The weight of the proof verification.
+	fn weight(payload_byte_length: usize) -> Weight;
+}
+
+/// Rejects all contract signatures, since no source of Ethereum state is
+/// available to check them against.
+impl<Contract, Signature, Proof> VerifyErc1271Proof<Contract, Signature, Proof> for () {
+	fn verify_is_valid_signature(
+		_contract: &Contract,
+		_hash: &[u8; 32],
+		_signature: &Signature,
+		_proof: &Proof,
+	) -> SignatureVerificationResult {
+		Err(SignatureVerificationError::SignerInformationNotPresent)
+	}
+
+	fn weight(_: usize) -> Weight {
+		Weight::zero()
+	}
+}
+
+/// Verifies signatures of ERC-1271 contract wallets over `personal_sign`
+/// wrapped payloads.
+pub struct Erc1271Verify<Contract, Signature, Proof, ProofVerifier>(
+	PhantomData<(Contract, Signature, Proof, ProofVerifier)>,
+);
+
+impl<Contract, Signature, Proof, ProofVerifier> VerifySignature
+	for Erc1271Verify<Contract, Signature, Proof, ProofVerifier>
+where
+	ProofVerifier: VerifyErc1271Proof<Contract, Signature, Proof>,
+{
+	type SignerId = Contract;
+	type Payload = Vec<u8>;
+	type Signature = Erc1271Signature<Signature, Proof>;
+
+	fn verify(
+		signer: &Self::SignerId,
+		payload: &Self::Payload,
+		signature: &Self::Signature,
+	) -> SignatureVerificationResult {
+		let hash = keccak_256(&ethereum_personal_sign_payload(payload)[..]);
+		ProofVerifier::verify_is_valid_signature(signer, &hash, &signature.signature, &signature.proof)
+	}
+
+	fn weight(payload_byte_length: usize) -> Weight {
+		ProofVerifier::weight(payload_byte_length)
+	}
+}
+
 #[cfg(feature = "runtime-benchmarks")]
 pub struct AlwaysVerify<A, P, S>(PhantomData<(A, P, S)>);
 #[cfg(feature = "runtime-benchmarks")]
@@ -78,6 +203,24 @@ impl<Account, Payload, Signature: Default> VerifySignature for AlwaysVerify<Acco
 	}
 }
 
+#[cfg(feature = "runtime-benchmarks")]
+impl<Contract, Signature, Proof> VerifyErc1271Proof<Contract, Signature, Proof>
+	for AlwaysVerify<Contract, Proof, Signature>
+{
+	fn verify_is_valid_signature(
+		_contract: &Contract,
+		_hash: &[u8; 32],
+		_signature: &Signature,
+		_proof: &Proof,
+	) -> SignatureVerificationResult {
+		SignatureVerificationResult::Ok(())
+	}
+
+	fn weight(_: usize) -> Weight {
+		Weight::zero()
+	}
+}
+
 #[cfg(any(test, feature = "mock", feature = "runtime-benchmarks"))]
 pub struct EqualVerify<A, B>(PhantomData<(A, B)>);
 #[cfg(any(test, feature = "mock", feature = "runtime-benchmarks"))]
@@ -108,3 +251,16 @@ where
 		Weight::zero()
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::ethereum_personal_sign_payload;
+
+	#[test]
+	fn test_ethereum_personal_sign_payload() {
+		assert_eq!(
+			ethereum_personal_sign_payload(b"hello world"),
+			b"\x19Ethereum Signed Message:\n11hello world".to_vec()
+		);
+	}
+}
@@ -0,0 +1,176 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+//! Conversions between wall-clock time and block numbers.
+//!
+//! They allow expiration periods and deadlines to be configured in
+//! milliseconds instead of in raw block counts, which drift from wall-clock
+//! time whenever the actual block time differs from the expected one.
+
+use frame_support::traits::Get;
+use sp_runtime::traits::{AtLeast32BitUnsigned, Convert, UniqueSaturatedInto};
+use sp_std::{marker::PhantomData, vec::Vec};
+
+/// A source of anchors, i.e., pairs of block numbers and the timestamps of
+/// the respective blocks.
+pub trait TimeAnchors<BlockNumber, Moment> {
+	/// Returns the known anchors, sorted by ascending block number.
+	fn anchors() -> Vec<(BlockNumber, Moment)>;
+}
+
+/// Converts a timestamp into the number of the block produced at that time.
+///
+/// Timestamps between two anchors are linearly interpolated, while timestamps
+/// before the first or after the last anchor are extrapolated assuming one
+/// block every `ExpectedBlockTime`. If no anchor is known, the maximum block
+/// number is returned.
+pub struct MomentToBlockNumber<Anchors, ExpectedBlockTime>(PhantomData<(Anchors, ExpectedBlockTime)>);
+
+impl<Anchors, ExpectedBlockTime, BlockNumber, Moment> Convert<Moment, BlockNumber>
+	for MomentToBlockNumber<Anchors, ExpectedBlockTime>
+where
+	Anchors: TimeAnchors<BlockNumber, Moment>,
+	ExpectedBlockTime: Get<Moment>,
+	BlockNumber: AtLeast32BitUnsigned + Copy,
+	Moment: AtLeast32BitUnsigned + Copy,
+{
+	fn convert(moment: Moment) -> BlockNumber {
+		let anchors: Vec<(u128, u128)> = Anchors::anchors()
+			.into_iter()
+			.map(|(block, time)| (block.unique_saturated_into(), time.unique_saturated_into()))
+			.collect();
+		let (Some(&(first_block, first_time)), Some(&(last_block, last_time))) = (anchors.first(), anchors.last())
+		else {
+			return BlockNumber::max_value();
+		};
+
+		let moment: u128 = moment.unique_saturated_into();
+		let block_time: u128 = ExpectedBlockTime::get().unique_saturated_into().max(1);
+
+		let block = if moment <= first_time {
+			first_block.saturating_sub((first_time - moment) / block_time)
+		} else if moment >= last_time {
+			last_block.saturating_add((moment - last_time) / block_time)
+		} else {
+			anchors
+				.windows(2)
+				.find(|window| moment <= window[1].1)
+				.map(|window| {
+					let ((start_block, start_time), (end_block, end_time)) = (window[0], window[1]);
+					let elapsed_time = end_time.saturating_sub(start_time).max(1);
+					let elapsed_blocks = end_block.saturating_sub(start_block);
+					start_block
+						.saturating_add(moment.saturating_sub(start_time).saturating_mul(elapsed_blocks) / elapsed_time)
+				})
+				.unwrap_or(last_block)
+		};
+		block.unique_saturated_into()
+	}
+}
+
+/// Converts a duration into the number of blocks expected to be produced
+/// within it, assuming one block every `ExpectedBlockTime`.
+pub struct DurationToBlocks<ExpectedBlockTime>(PhantomData<ExpectedBlockTime>);
+
+impl<ExpectedBlockTime, BlockNumber, Moment> Convert<Moment, BlockNumber> for DurationToBlocks<ExpectedBlockTime>
+where
+	ExpectedBlockTime: Get<Moment>,
+	BlockNumber: AtLeast32BitUnsigned,
+	Moment: AtLeast32BitUnsigned,
+{
+	fn convert(duration: Moment) -> BlockNumber {
+		let duration: u128 = duration.unique_saturated_into();
+		let block_time: u128 = ExpectedBlockTime::get().unique_saturated_into().max(1);
+		(duration / block_time).unique_saturated_into()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use frame_support::traits::ConstU64;
+
+	use super::*;
+
+	struct MockAnchors;
+
+	impl TimeAnchors<u32, u64> for MockAnchors {
+		// The first two anchors are 10 blocks apart with a block time of 12 seconds,
+		// the last two with a block time of 6 seconds.
+		fn anchors() -> Vec<(u32, u64)> {
+			vec![(100, 1_000_000), (110, 1_120_000), (130, 1_240_000)]
+		}
+	}
+
+	struct NoAnchors;
+
+	impl TimeAnchors<u32, u64> for NoAnchors {
+		fn anchors() -> Vec<(u32, u64)> {
+			Vec::new()
+		}
+	}
+
+	type Converter = MomentToBlockNumber<MockAnchors, ConstU64<12_000>>;
+
+	#[test]
+	fn moment_to_block_number_at_anchors() {
+		assert_eq!(<Converter as Convert<u64, u32>>::convert(1_000_000), 100);
+		assert_eq!(<Converter as Convert<u64, u32>>::convert(1_120_000), 110);
+		assert_eq!(<Converter as Convert<u64, u32>>::convert(1_240_000), 130);
+	}
+
+	#[test]
+	fn moment_to_block_number_interpolated() {
+		assert_eq!(<Converter as Convert<u64, u32>>::convert(1_060_000), 105);
+		assert_eq!(<Converter as Convert<u64, u32>>::convert(1_180_000), 120);
+		assert_eq!(<Converter as Convert<u64, u32>>::convert(1_186_000), 121);
+	}
+
+	#[test]
+	fn moment_to_block_number_extrapolated() {
+		assert_eq!(<Converter as Convert<u64, u32>>::convert(1_360_000), 140);
+		assert_eq!(<Converter as Convert<u64, u32>>::convert(880_000), 90);
+		assert_eq!(<Converter as Convert<u64, u32>>::convert(0), 17);
+		// Saturates at the genesis block.
+		assert_eq!(
+			<MomentToBlockNumber<MockAnchors, ConstU64<1_000>> as Convert<u64, u32>>::convert(0),
+			0
+		);
+	}
+
+	#[test]
+	fn moment_to_block_number_without_anchors() {
+		assert_eq!(
+			<MomentToBlockNumber<NoAnchors, ConstU64<12_000>> as Convert<u64, u32>>::convert(1_000_000),
+			u32::MAX
+		);
+	}
+
+	#[test]
+	fn duration_to_blocks() {
+		assert_eq!(<DurationToBlocks<ConstU64<12_000>> as Convert<u64, u32>>::convert(0), 0);
+		assert_eq!(
+			<DurationToBlocks<ConstU64<12_000>> as Convert<u64, u32>>::convert(24 * 60 * 60 * 1_000),
+			7_200
+		);
+		// Partial blocks are not counted.
+		assert_eq!(
+			<DurationToBlocks<ConstU64<12_000>> as Convert<u64, u32>>::convert(23_999),
+			1
+		);
+	}
+}
@@ -17,10 +17,13 @@
 // If you feel like getting in touch with us, you can do so at info@botlabs.org
 
 use frame_support::traits::{
-	fungible::hold::Mutate,
-	tokens::fungible::{Inspect, MutateHold},
+	fungible::hold::{Inspect as InspectHold, Mutate},
+	tokens::{
+		fungible::{Inspect, MutateHold},
+		Precision,
+	},
 };
-use sp_runtime::DispatchError;
+use sp_runtime::{DispatchError, Saturating};
 use sp_std::vec::Vec;
 
 use crate::deposit::{free_deposit, reserve_deposit, Deposit};
@@ -186,6 +189,56 @@ pub trait StorageDepositCollector<AccountId, Key, RuntimeHoldReason> {
 		reserve_deposit::<AccountId, Self::Currency>(who, amount, &reason.into())
 	}
 
+	/// Returns whether the user can pay a new deposit of the given amount.
+	fn can_create_deposit(who: &AccountId, amount: <Self::Currency as Inspect<AccountId>>::Balance) -> bool {
+		Self::Currency::can_hold(&Self::reason().into(), who, amount)
+	}
+
+	/// Creates a new deposit for the storage entry behind the key.
+	///
+	/// Deposits created this way are held by the deposit collector, hence the
+	/// storage entry is excluded from the balance migration.
+	///
+	/// # Errors
+	/// Can fail if the user has not enough balance.
+	fn create_deposit_for_key<DepositBalanceMigrationManager>(
+		key: &Key,
+		who: AccountId,
+		amount: <Self::Currency as Inspect<AccountId>>::Balance,
+	) -> Result<Deposit<AccountId, <Self::Currency as Inspect<AccountId>>::Balance>, DispatchError>
+	where
+		DepositBalanceMigrationManager:
+			BalanceMigrationManager<AccountId, <Self::Currency as Inspect<AccountId>>::Balance>,
+	{
+		let hashed_key = Self::get_hashed_key(key)?;
+		let deposit = Self::create_deposit(who, amount)?;
+		DepositBalanceMigrationManager::exclude_key_from_migration(&hashed_key);
+		Ok(deposit)
+	}
+
+	/// Release the deposit of the storage entry behind the key.
+	///
+	/// Depending on whether the storage entry has already been migrated, the
+	/// deposit is either released from the hold or unreserved. The storage
+	/// entry itself is not touched, so the deposit must be provided by the
+	/// caller.
+	fn release_deposit<DepositBalanceMigrationManager>(
+		key: &Key,
+		deposit: Deposit<AccountId, <Self::Currency as Inspect<AccountId>>::Balance>,
+	) -> Result<(), DispatchError>
+	where
+		DepositBalanceMigrationManager:
+			BalanceMigrationManager<AccountId, <Self::Currency as Inspect<AccountId>>::Balance>,
+	{
+		let hashed_key = Self::get_hashed_key(key)?;
+		if DepositBalanceMigrationManager::is_key_migrated(&hashed_key) {
+			Self::free_deposit(deposit)?;
+		} else {
+			DepositBalanceMigrationManager::release_reserved_deposit(&deposit.owner, &deposit.amount);
+		}
+		Ok(())
+	}
+
 	/// Change the deposit owner.
 	///
 	/// The deposit balance of the current owner will be freed, while the
@@ -256,4 +309,96 @@ pub trait StorageDepositCollector<AccountId, Key, RuntimeHoldReason> {
 
 		Ok(())
 	}
+
+	/// Increase the deposit of the storage entry behind the key.
+	///
+	/// The additional amount is taken from the current deposit owner. In case
+	/// the storage entry has not been migrated yet, the whole deposit is moved
+	/// to the hold of the deposit collector.
+	fn top_up_deposit<DepositBalanceMigrationManager>(
+		key: &Key,
+		amount: <Self::Currency as Inspect<AccountId>>::Balance,
+	) -> Result<(), DispatchError>
+	where
+		DepositBalanceMigrationManager:
+			BalanceMigrationManager<AccountId, <Self::Currency as Inspect<AccountId>>::Balance>,
+	{
+		let deposit = Self::deposit(key)?;
+		let reason = Self::reason();
+		let hashed_key = Self::get_hashed_key(key)?;
+		let is_key_migrated = DepositBalanceMigrationManager::is_key_migrated(&hashed_key);
+
+		let deposit = Deposit {
+			amount: deposit.amount.saturating_add(amount),
+			..deposit
+		};
+
+		if is_key_migrated {
+			Self::Currency::hold(&reason.into(), &deposit.owner, amount)?;
+		} else {
+			DepositBalanceMigrationManager::release_reserved_deposit(
+				&deposit.owner,
+				&deposit.amount.saturating_sub(amount),
+			);
+			DepositBalanceMigrationManager::exclude_key_from_migration(&hashed_key);
+			Self::Currency::hold(&reason.into(), &deposit.owner, deposit.amount)?;
+		}
+
+		Self::store_deposit(key, deposit)?;
+
+		Ok(())
+	}
+
+	/// Adjust the deposit of the storage entry behind the key to the deposit
+	/// amount currently required for it.
+	///
+	/// This reconciles the stored deposit after the deposit parameters have
+	/// changed. Only the difference is held from or released to the current
+	/// deposit owner, and nothing happens if the deposit is already up to
+	/// date. In case the storage entry has not been migrated yet, the whole
+	/// deposit is moved to the hold of the deposit collector.
+	fn adjust_deposit<DepositBalanceMigrationManager>(key: &Key) -> Result<(), DispatchError>
+	where
+		DepositBalanceMigrationManager:
+			BalanceMigrationManager<AccountId, <Self::Currency as Inspect<AccountId>>::Balance>,
+	{
+		let deposit = Self::deposit(key)?;
+		let required_amount = Self::deposit_amount(key);
+
+		if required_amount > deposit.amount {
+			return Self::top_up_deposit::<DepositBalanceMigrationManager>(
+				key,
+				required_amount.saturating_sub(deposit.amount),
+			);
+		}
+		if required_amount == deposit.amount {
+			return Ok(());
+		}
+
+		let reason = Self::reason();
+		let hashed_key = Self::get_hashed_key(key)?;
+
+		if DepositBalanceMigrationManager::is_key_migrated(&hashed_key) {
+			Self::Currency::release(
+				&reason.into(),
+				&deposit.owner,
+				deposit.amount.saturating_sub(required_amount),
+				Precision::Exact,
+			)?;
+		} else {
+			DepositBalanceMigrationManager::release_reserved_deposit(&deposit.owner, &deposit.amount);
+			DepositBalanceMigrationManager::exclude_key_from_migration(&hashed_key);
+			Self::Currency::hold(&reason.into(), &deposit.owner, required_amount)?;
+		}
+
+		Self::store_deposit(
+			key,
+			Deposit {
+				amount: required_amount,
+				..deposit
+			},
+		)?;
+
+		Ok(())
+	}
 }